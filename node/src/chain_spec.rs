@@ -330,9 +330,9 @@ fn genesis(
 		user_profile: UserProfileConfig { admin_key: Some(api_admin_key.clone()) },
 		menstrual_subscription: MenstrualSubscriptionConfig {
 			admin_key: Some(api_admin_key.clone()),
-			treasury_key: Some(treasury_key),
+			treasury_key: Some(treasury_key.clone()),
 		},
 		health_professional: HealthProfessionalConfig { verifier_key: Some(api_admin_key.clone()) },
-		opinion: OpinionConfig { admin_key: Some(api_admin_key) },
+		opinion: OpinionConfig { admin_key: Some(api_admin_key), treasury_key: Some(treasury_key) },
 	}
 }
@@ -33,9 +33,54 @@ use pallet_transaction_payment_rpc::{
 	TransactionPayment, TransactionPaymentApiServer, TransactionPaymentRuntimeApi,
 };
 
-use debio_runtime::{opaque::Block, AccountId, Balance, BlockNumber, Hash, Index};
+use debio_runtime::{
+	opaque::Block, AccountId, Balance, BlockNumber, Coordinate, CustodyEntry, CyclePrediction,
+	DnaSample, DnaSampleStatus, DnaSampleTrackingId, DnaTestResult, Hash, Hospital, Index, Lab,
+	Order, OrderStatus, ProviderRating, Request, RequestStatus, Review, TestResultAttestation,
+	TrackingIdError,
+};
+
+use jsonrpsee::{
+	core::Error as JsonRpseeError,
+	types::error::{CallError, ErrorObject},
+	RpcModule,
+};
+
+pub mod genetic_testing;
+pub mod geo_search;
+pub mod menstrual_calendar;
+pub mod orders;
+pub mod reviews;
+pub mod service_request;
+
+use genetic_testing::{GeneticTesting, GeneticTestingApiServer, GeneticTestingRuntimeApi};
+use geo_search::{GeoSearch, GeoSearchApiServer, GeoSearchRuntimeApi};
+use menstrual_calendar::{
+	MenstrualCalendar, MenstrualCalendarApiServer, MenstrualCalendarRuntimeApi,
+};
+use orders::{Orders, OrdersApiServer, OrdersRuntimeApi};
+use reviews::{Reviews, ReviewsApiServer, ReviewsRuntimeApi};
+use service_request::{ServiceRequest, ServiceRequestApiServer, ServiceRequestRuntimeApi};
 
-use jsonrpsee::RpcModule;
+/// Error code returned when a DeBio runtime API call fails.
+const RUNTIME_ERROR: i32 = 1;
+
+/// Error code returned when a tracking id is malformed or fails its check character.
+const INVALID_TRACKING_ID: i32 = 2;
+
+fn runtime_error_into_rpc_err(err: impl std::fmt::Display) -> JsonRpseeError {
+	CallError::Custom(ErrorObject::owned(RUNTIME_ERROR, "Runtime error", Some(err.to_string())))
+		.into()
+}
+
+fn invalid_tracking_id_into_rpc_err(err: impl std::fmt::Debug) -> JsonRpseeError {
+	CallError::Custom(ErrorObject::owned(
+		INVALID_TRACKING_ID,
+		"Invalid tracking id",
+		Some(format!("{:?}", err)),
+	))
+	.into()
+}
 
 /// Extra dependencies for BABE.
 pub struct BabeDeps {
@@ -109,6 +154,23 @@ where
 	C::Api: TransactionPaymentRuntimeApi<Block, Balance>,
 	C::Api: BabeApi<Block>,
 	C::Api: MmrRuntimeApi<Block, <Block as BlockT>::Hash>,
+	C::Api: OrdersRuntimeApi<Block, AccountId, Hash, Order, OrderStatus>,
+	C::Api: GeneticTestingRuntimeApi<
+		Block,
+		AccountId,
+		Hash,
+		DnaSampleTrackingId,
+		DnaSample,
+		DnaSampleStatus,
+		DnaTestResult,
+		CustodyEntry,
+		TestResultAttestation,
+		TrackingIdError,
+	>,
+	C::Api: ServiceRequestRuntimeApi<Block, AccountId, Hash, Request, RequestStatus>,
+	C::Api: MenstrualCalendarRuntimeApi<Block, Hash, CyclePrediction>,
+	C::Api: ReviewsRuntimeApi<Block, AccountId, Hash, Review, ProviderRating>,
+	C::Api: GeoSearchRuntimeApi<Block, Coordinate, Lab, Hospital>,
 	P: TransactionPool + 'static,
 	SC: SelectChain<Block> + 'static,
 	B: Backend<Block> + Send + Sync + 'static,
@@ -170,6 +232,12 @@ where
 		.into_rpc(),
 	)?;
 	io.merge(Mmr::new(client.clone()).into_rpc())?;
+	io.merge(Orders::new(client.clone()).into_rpc())?;
+	io.merge(GeneticTesting::new(client.clone()).into_rpc())?;
+	io.merge(ServiceRequest::new(client.clone()).into_rpc())?;
+	io.merge(MenstrualCalendar::new(client.clone()).into_rpc())?;
+	io.merge(Reviews::new(client.clone()).into_rpc())?;
+	io.merge(GeoSearch::new(client.clone()).into_rpc())?;
 	io.merge(
 		SyncState::new(chain_spec, client.clone(), shared_authority_set, shared_epoch_changes)?
 			.into_rpc(),
@@ -0,0 +1,258 @@
+use std::{fmt::Debug, marker::PhantomData, sync::Arc};
+
+use codec::Codec;
+use jsonrpsee::{core::RpcResult, proc_macros::rpc};
+use serde::{de::DeserializeOwned, Serialize};
+use sp_api::ProvideRuntimeApi;
+use sp_blockchain::HeaderBackend;
+use sp_runtime::{generic::BlockId, traits::Block as BlockT};
+
+pub use debio_runtime_api::GeneticTestingApi as GeneticTestingRuntimeApi;
+
+use super::{invalid_tracking_id_into_rpc_err, runtime_error_into_rpc_err};
+
+#[rpc(client, server)]
+pub trait GeneticTestingApi<
+	BlockHash,
+	AccountId,
+	Hash,
+	TrackingId,
+	DnaSample,
+	DnaSampleStatus,
+	DnaTestResult,
+	CustodyEntry,
+	TestResultAttestation,
+>
+{
+	#[method(name = "geneticTesting_getDnaSampleByTrackingId")]
+	fn dna_sample_by_tracking_id(
+		&self,
+		tracking_id: TrackingId,
+		at: Option<BlockHash>,
+	) -> RpcResult<Option<DnaSample>>;
+
+	#[method(name = "geneticTesting_getDnaSamplesByOwnerId")]
+	fn dna_samples_by_owner_id(
+		&self,
+		owner_id: AccountId,
+		status: Option<DnaSampleStatus>,
+		offset: u32,
+		limit: u32,
+		at: Option<BlockHash>,
+	) -> RpcResult<Vec<DnaSample>>;
+
+	#[method(name = "geneticTesting_getDnaSamplesByLabId")]
+	fn dna_samples_by_lab_id(
+		&self,
+		lab_id: AccountId,
+		status: Option<DnaSampleStatus>,
+		offset: u32,
+		limit: u32,
+		at: Option<BlockHash>,
+	) -> RpcResult<Vec<DnaSample>>;
+
+	#[method(name = "geneticTesting_getDnaTestResultByTrackingId")]
+	fn dna_test_result_by_tracking_id(
+		&self,
+		tracking_id: TrackingId,
+		at: Option<BlockHash>,
+	) -> RpcResult<Option<DnaTestResult>>;
+
+	#[method(name = "geneticTesting_getCustodyLogByTrackingId")]
+	fn custody_log_by_tracking_id(
+		&self,
+		tracking_id: TrackingId,
+		at: Option<BlockHash>,
+	) -> RpcResult<Vec<CustodyEntry>>;
+
+	#[method(name = "geneticTesting_getTestResultAttestationByTrackingId")]
+	fn test_result_attestation_by_tracking_id(
+		&self,
+		tracking_id: TrackingId,
+		at: Option<BlockHash>,
+	) -> RpcResult<Option<TestResultAttestation>>;
+
+	#[method(name = "geneticTesting_verifyTestResult")]
+	fn verify_test_result(
+		&self,
+		tracking_id: TrackingId,
+		content_hash: Hash,
+		at: Option<BlockHash>,
+	) -> RpcResult<bool>;
+}
+
+// Upper cases a hand typed tracking id and rejects bad characters before asking the runtime
+fn normalize<TrackingId>(tracking_id: TrackingId) -> RpcResult<TrackingId>
+where
+	TrackingId: Into<Vec<u8>> + TryFrom<Vec<u8>>,
+	<TrackingId as TryFrom<Vec<u8>>>::Error: Debug,
+{
+	TrackingId::try_from(tracking_id.into()).map_err(invalid_tracking_id_into_rpc_err)
+}
+
+/// Serves [`GeneticTestingRuntimeApi`] queries over JSON-RPC.
+pub struct GeneticTesting<C, Block> {
+	client: Arc<C>,
+	_marker: PhantomData<Block>,
+}
+
+impl<C, Block> GeneticTesting<C, Block> {
+	pub fn new(client: Arc<C>) -> Self {
+		Self { client, _marker: Default::default() }
+	}
+}
+
+impl<
+		C,
+		Block,
+		AccountId,
+		Hash,
+		TrackingId,
+		DnaSample,
+		DnaSampleStatus,
+		DnaTestResult,
+		CustodyEntry,
+		TestResultAttestation,
+	>
+	GeneticTestingApiServer<
+		<Block as BlockT>::Hash,
+		AccountId,
+		Hash,
+		TrackingId,
+		DnaSample,
+		DnaSampleStatus,
+		DnaTestResult,
+		CustodyEntry,
+		TestResultAttestation,
+	> for GeneticTesting<C, Block>
+where
+	Block: BlockT,
+	C: ProvideRuntimeApi<Block> + HeaderBackend<Block> + Send + Sync + 'static,
+	C::Api: GeneticTestingRuntimeApi<
+		Block,
+		AccountId,
+		Hash,
+		TrackingId,
+		DnaSample,
+		DnaSampleStatus,
+		DnaTestResult,
+		CustodyEntry,
+		TestResultAttestation,
+		<TrackingId as TryFrom<Vec<u8>>>::Error,
+	>,
+	AccountId: Codec + DeserializeOwned + Send + Sync + 'static,
+	Hash: Codec + DeserializeOwned + Send + Sync + 'static,
+	TrackingId: Codec + DeserializeOwned + Into<Vec<u8>> + TryFrom<Vec<u8>> + Send + Sync + 'static,
+	<TrackingId as TryFrom<Vec<u8>>>::Error: Codec + Debug,
+	DnaSample: Codec + Serialize + Send + Sync + 'static,
+	DnaSampleStatus: Codec + DeserializeOwned + Send + Sync + 'static,
+	DnaTestResult: Codec + Serialize + Send + Sync + 'static,
+	CustodyEntry: Codec + Serialize + Send + Sync + 'static,
+	TestResultAttestation: Codec + Serialize + Send + Sync + 'static,
+{
+	fn dna_sample_by_tracking_id(
+		&self,
+		tracking_id: TrackingId,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> RpcResult<Option<DnaSample>> {
+		let api = self.client.runtime_api();
+		let at = BlockId::hash(at.unwrap_or_else(|| self.client.info().best_hash));
+
+		let tracking_id = normalize(tracking_id)?;
+
+		api.dna_sample_by_tracking_id(&at, tracking_id)
+			.map_err(runtime_error_into_rpc_err)?
+			.map_err(invalid_tracking_id_into_rpc_err)
+	}
+
+	fn dna_samples_by_owner_id(
+		&self,
+		owner_id: AccountId,
+		status: Option<DnaSampleStatus>,
+		offset: u32,
+		limit: u32,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> RpcResult<Vec<DnaSample>> {
+		let api = self.client.runtime_api();
+		let at = BlockId::hash(at.unwrap_or_else(|| self.client.info().best_hash));
+
+		api.dna_samples_by_owner_id(&at, owner_id, status, offset, limit)
+			.map_err(runtime_error_into_rpc_err)
+	}
+
+	fn dna_samples_by_lab_id(
+		&self,
+		lab_id: AccountId,
+		status: Option<DnaSampleStatus>,
+		offset: u32,
+		limit: u32,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> RpcResult<Vec<DnaSample>> {
+		let api = self.client.runtime_api();
+		let at = BlockId::hash(at.unwrap_or_else(|| self.client.info().best_hash));
+
+		api.dna_samples_by_lab_id(&at, lab_id, status, offset, limit)
+			.map_err(runtime_error_into_rpc_err)
+	}
+
+	fn dna_test_result_by_tracking_id(
+		&self,
+		tracking_id: TrackingId,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> RpcResult<Option<DnaTestResult>> {
+		let api = self.client.runtime_api();
+		let at = BlockId::hash(at.unwrap_or_else(|| self.client.info().best_hash));
+
+		let tracking_id = normalize(tracking_id)?;
+
+		api.dna_test_result_by_tracking_id(&at, tracking_id)
+			.map_err(runtime_error_into_rpc_err)?
+			.map_err(invalid_tracking_id_into_rpc_err)
+	}
+
+	fn custody_log_by_tracking_id(
+		&self,
+		tracking_id: TrackingId,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> RpcResult<Vec<CustodyEntry>> {
+		let api = self.client.runtime_api();
+		let at = BlockId::hash(at.unwrap_or_else(|| self.client.info().best_hash));
+
+		let tracking_id = normalize(tracking_id)?;
+
+		api.custody_log_by_tracking_id(&at, tracking_id)
+			.map_err(runtime_error_into_rpc_err)?
+			.map_err(invalid_tracking_id_into_rpc_err)
+	}
+
+	fn test_result_attestation_by_tracking_id(
+		&self,
+		tracking_id: TrackingId,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> RpcResult<Option<TestResultAttestation>> {
+		let api = self.client.runtime_api();
+		let at = BlockId::hash(at.unwrap_or_else(|| self.client.info().best_hash));
+
+		let tracking_id = normalize(tracking_id)?;
+
+		api.test_result_attestation_by_tracking_id(&at, tracking_id)
+			.map_err(runtime_error_into_rpc_err)?
+			.map_err(invalid_tracking_id_into_rpc_err)
+	}
+
+	fn verify_test_result(
+		&self,
+		tracking_id: TrackingId,
+		content_hash: Hash,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> RpcResult<bool> {
+		let api = self.client.runtime_api();
+		let at = BlockId::hash(at.unwrap_or_else(|| self.client.info().best_hash));
+
+		let tracking_id = normalize(tracking_id)?;
+
+		api.verify_test_result(&at, tracking_id, content_hash)
+			.map_err(runtime_error_into_rpc_err)?
+			.map_err(invalid_tracking_id_into_rpc_err)
+	}
+}
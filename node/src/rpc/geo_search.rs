@@ -0,0 +1,82 @@
+use std::{marker::PhantomData, sync::Arc};
+
+use codec::Codec;
+use jsonrpsee::{core::RpcResult, proc_macros::rpc};
+use serde::{de::DeserializeOwned, Serialize};
+use sp_api::ProvideRuntimeApi;
+use sp_blockchain::HeaderBackend;
+use sp_runtime::{generic::BlockId, traits::Block as BlockT};
+
+pub use debio_runtime_api::GeoSearchApi as GeoSearchRuntimeApi;
+
+use super::runtime_error_into_rpc_err;
+
+#[rpc(client, server)]
+pub trait GeoSearchApi<BlockHash, Coordinate, Lab, Hospital> {
+	#[method(name = "geoSearch_getLabsWithinRadius")]
+	fn labs_within_radius(
+		&self,
+		center: Coordinate,
+		radius: u32,
+		category: Option<Vec<u8>>,
+		at: Option<BlockHash>,
+	) -> RpcResult<Vec<Lab>>;
+
+	#[method(name = "geoSearch_getHospitalsWithinRadius")]
+	fn hospitals_within_radius(
+		&self,
+		center: Coordinate,
+		radius: u32,
+		at: Option<BlockHash>,
+	) -> RpcResult<Vec<Hospital>>;
+}
+
+/// Serves [`GeoSearchRuntimeApi`] queries over JSON-RPC.
+pub struct GeoSearch<C, Block> {
+	client: Arc<C>,
+	_marker: PhantomData<Block>,
+}
+
+impl<C, Block> GeoSearch<C, Block> {
+	pub fn new(client: Arc<C>) -> Self {
+		Self { client, _marker: Default::default() }
+	}
+}
+
+impl<C, Block, Coordinate, Lab, Hospital>
+	GeoSearchApiServer<<Block as BlockT>::Hash, Coordinate, Lab, Hospital> for GeoSearch<C, Block>
+where
+	Block: BlockT,
+	C: ProvideRuntimeApi<Block> + HeaderBackend<Block> + Send + Sync + 'static,
+	C::Api: GeoSearchRuntimeApi<Block, Coordinate, Lab, Hospital>,
+	Coordinate: Codec + DeserializeOwned + Send + Sync + 'static,
+	Lab: Codec + Serialize + Send + Sync + 'static,
+	Hospital: Codec + Serialize + Send + Sync + 'static,
+{
+	fn labs_within_radius(
+		&self,
+		center: Coordinate,
+		radius: u32,
+		category: Option<Vec<u8>>,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> RpcResult<Vec<Lab>> {
+		let api = self.client.runtime_api();
+		let at = BlockId::hash(at.unwrap_or_else(|| self.client.info().best_hash));
+
+		api.labs_within_radius(&at, center, radius, category)
+			.map_err(runtime_error_into_rpc_err)
+	}
+
+	fn hospitals_within_radius(
+		&self,
+		center: Coordinate,
+		radius: u32,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> RpcResult<Vec<Hospital>> {
+		let api = self.client.runtime_api();
+		let at = BlockId::hash(at.unwrap_or_else(|| self.client.info().best_hash));
+
+		api.hospitals_within_radius(&at, center, radius)
+			.map_err(runtime_error_into_rpc_err)
+	}
+}
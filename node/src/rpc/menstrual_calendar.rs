@@ -0,0 +1,57 @@
+use std::{marker::PhantomData, sync::Arc};
+
+use codec::Codec;
+use jsonrpsee::{core::RpcResult, proc_macros::rpc};
+use serde::{de::DeserializeOwned, Serialize};
+use sp_api::ProvideRuntimeApi;
+use sp_blockchain::HeaderBackend;
+use sp_runtime::{generic::BlockId, traits::Block as BlockT};
+
+pub use debio_runtime_api::MenstrualCalendarApi as MenstrualCalendarRuntimeApi;
+
+use super::runtime_error_into_rpc_err;
+
+#[rpc(client, server)]
+pub trait MenstrualCalendarApi<BlockHash, Hash, CyclePrediction> {
+	#[method(name = "menstrualCalendar_predictMenstrualCycle")]
+	fn predict_menstrual_cycle(
+		&self,
+		menstrual_calendar_id: Hash,
+		at: Option<BlockHash>,
+	) -> RpcResult<Option<CyclePrediction>>;
+}
+
+/// Serves [`MenstrualCalendarRuntimeApi`] queries over JSON-RPC.
+pub struct MenstrualCalendar<C, Block> {
+	client: Arc<C>,
+	_marker: PhantomData<Block>,
+}
+
+impl<C, Block> MenstrualCalendar<C, Block> {
+	pub fn new(client: Arc<C>) -> Self {
+		Self { client, _marker: Default::default() }
+	}
+}
+
+impl<C, Block, Hash, CyclePrediction>
+	MenstrualCalendarApiServer<<Block as BlockT>::Hash, Hash, CyclePrediction>
+	for MenstrualCalendar<C, Block>
+where
+	Block: BlockT,
+	C: ProvideRuntimeApi<Block> + HeaderBackend<Block> + Send + Sync + 'static,
+	C::Api: MenstrualCalendarRuntimeApi<Block, Hash, CyclePrediction>,
+	Hash: Codec + DeserializeOwned + Send + Sync + 'static,
+	CyclePrediction: Codec + Serialize + Send + Sync + 'static,
+{
+	fn predict_menstrual_cycle(
+		&self,
+		menstrual_calendar_id: Hash,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> RpcResult<Option<CyclePrediction>> {
+		let api = self.client.runtime_api();
+		let at = BlockId::hash(at.unwrap_or_else(|| self.client.info().best_hash));
+
+		api.predict_menstrual_cycle(&at, menstrual_calendar_id)
+			.map_err(runtime_error_into_rpc_err)
+	}
+}
@@ -0,0 +1,103 @@
+use std::{marker::PhantomData, sync::Arc};
+
+use codec::Codec;
+use jsonrpsee::{core::RpcResult, proc_macros::rpc};
+use serde::{de::DeserializeOwned, Serialize};
+use sp_api::ProvideRuntimeApi;
+use sp_blockchain::HeaderBackend;
+use sp_runtime::{generic::BlockId, traits::Block as BlockT};
+
+pub use debio_runtime_api::OrdersApi as OrdersRuntimeApi;
+
+use super::runtime_error_into_rpc_err;
+
+#[rpc(client, server)]
+pub trait OrdersApi<BlockHash, AccountId, Hash, Order, OrderStatus> {
+	#[method(name = "orders_getOrderById")]
+	fn order_by_id(&self, order_id: Hash, at: Option<BlockHash>) -> RpcResult<Option<Order>>;
+
+	#[method(name = "orders_getOrdersByCustomerId")]
+	fn orders_by_customer_id(
+		&self,
+		customer_id: AccountId,
+		status: Option<OrderStatus>,
+		offset: u32,
+		limit: u32,
+		at: Option<BlockHash>,
+	) -> RpcResult<Vec<Order>>;
+
+	#[method(name = "orders_getOrdersBySellerId")]
+	fn orders_by_seller_id(
+		&self,
+		seller_id: AccountId,
+		status: Option<OrderStatus>,
+		offset: u32,
+		limit: u32,
+		at: Option<BlockHash>,
+	) -> RpcResult<Vec<Order>>;
+}
+
+/// Serves [`OrdersRuntimeApi`] queries over JSON-RPC.
+pub struct Orders<C, Block> {
+	client: Arc<C>,
+	_marker: PhantomData<Block>,
+}
+
+impl<C, Block> Orders<C, Block> {
+	pub fn new(client: Arc<C>) -> Self {
+		Self { client, _marker: Default::default() }
+	}
+}
+
+impl<C, Block, AccountId, Hash, Order, OrderStatus>
+	OrdersApiServer<<Block as BlockT>::Hash, AccountId, Hash, Order, OrderStatus> for Orders<C, Block>
+where
+	Block: BlockT,
+	C: ProvideRuntimeApi<Block> + HeaderBackend<Block> + Send + Sync + 'static,
+	C::Api: OrdersRuntimeApi<Block, AccountId, Hash, Order, OrderStatus>,
+	AccountId: Codec + DeserializeOwned + Send + Sync + 'static,
+	Hash: Codec + DeserializeOwned + Send + Sync + 'static,
+	Order: Codec + Serialize + Send + Sync + 'static,
+	OrderStatus: Codec + DeserializeOwned + Send + Sync + 'static,
+{
+	fn order_by_id(
+		&self,
+		order_id: Hash,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> RpcResult<Option<Order>> {
+		let api = self.client.runtime_api();
+		let at = BlockId::hash(at.unwrap_or_else(|| self.client.info().best_hash));
+
+		api.order_by_id(&at, order_id).map_err(runtime_error_into_rpc_err)
+	}
+
+	fn orders_by_customer_id(
+		&self,
+		customer_id: AccountId,
+		status: Option<OrderStatus>,
+		offset: u32,
+		limit: u32,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> RpcResult<Vec<Order>> {
+		let api = self.client.runtime_api();
+		let at = BlockId::hash(at.unwrap_or_else(|| self.client.info().best_hash));
+
+		api.orders_by_customer_id(&at, customer_id, status, offset, limit)
+			.map_err(runtime_error_into_rpc_err)
+	}
+
+	fn orders_by_seller_id(
+		&self,
+		seller_id: AccountId,
+		status: Option<OrderStatus>,
+		offset: u32,
+		limit: u32,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> RpcResult<Vec<Order>> {
+		let api = self.client.runtime_api();
+		let at = BlockId::hash(at.unwrap_or_else(|| self.client.info().best_hash));
+
+		api.orders_by_seller_id(&at, seller_id, status, offset, limit)
+			.map_err(runtime_error_into_rpc_err)
+	}
+}
@@ -0,0 +1,100 @@
+use std::{marker::PhantomData, sync::Arc};
+
+use codec::Codec;
+use jsonrpsee::{core::RpcResult, proc_macros::rpc};
+use serde::{de::DeserializeOwned, Serialize};
+use sp_api::ProvideRuntimeApi;
+use sp_blockchain::HeaderBackend;
+use sp_runtime::{generic::BlockId, traits::Block as BlockT};
+
+pub use debio_runtime_api::ReviewsApi as ReviewsRuntimeApi;
+
+use super::runtime_error_into_rpc_err;
+
+#[rpc(client, server)]
+pub trait ReviewsApi<BlockHash, AccountId, Hash, Review, ProviderRating> {
+	#[method(name = "reviews_getReviewByTransactionId")]
+	fn review_by_transaction_id(
+		&self,
+		transaction_id: Hash,
+		at: Option<BlockHash>,
+	) -> RpcResult<Option<Review>>;
+
+	#[method(name = "reviews_getReviewsByProvider")]
+	fn reviews_by_provider(
+		&self,
+		provider: AccountId,
+		offset: u32,
+		limit: u32,
+		at: Option<BlockHash>,
+	) -> RpcResult<Vec<Review>>;
+
+	#[method(name = "reviews_getRatingByProvider")]
+	fn rating_by_provider(
+		&self,
+		provider: AccountId,
+		at: Option<BlockHash>,
+	) -> RpcResult<ProviderRating>;
+}
+
+/// Serves [`ReviewsRuntimeApi`] queries over JSON-RPC.
+pub struct Reviews<C, Block> {
+	client: Arc<C>,
+	_marker: PhantomData<Block>,
+}
+
+impl<C, Block> Reviews<C, Block> {
+	pub fn new(client: Arc<C>) -> Self {
+		Self { client, _marker: Default::default() }
+	}
+}
+
+impl<C, Block, AccountId, Hash, Review, ProviderRating>
+	ReviewsApiServer<<Block as BlockT>::Hash, AccountId, Hash, Review, ProviderRating>
+	for Reviews<C, Block>
+where
+	Block: BlockT,
+	C: ProvideRuntimeApi<Block> + HeaderBackend<Block> + Send + Sync + 'static,
+	C::Api: ReviewsRuntimeApi<Block, AccountId, Hash, Review, ProviderRating>,
+	AccountId: Codec + DeserializeOwned + Send + Sync + 'static,
+	Hash: Codec + DeserializeOwned + Send + Sync + 'static,
+	Review: Codec + Serialize + Send + Sync + 'static,
+	ProviderRating: Codec + Serialize + Send + Sync + 'static,
+{
+	fn review_by_transaction_id(
+		&self,
+		transaction_id: Hash,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> RpcResult<Option<Review>> {
+		let api = self.client.runtime_api();
+		let at = BlockId::hash(at.unwrap_or_else(|| self.client.info().best_hash));
+
+		api.review_by_transaction_id(&at, transaction_id)
+			.map_err(runtime_error_into_rpc_err)
+	}
+
+	fn reviews_by_provider(
+		&self,
+		provider: AccountId,
+		offset: u32,
+		limit: u32,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> RpcResult<Vec<Review>> {
+		let api = self.client.runtime_api();
+		let at = BlockId::hash(at.unwrap_or_else(|| self.client.info().best_hash));
+
+		api.reviews_by_provider(&at, provider, offset, limit)
+			.map_err(runtime_error_into_rpc_err)
+	}
+
+	fn rating_by_provider(
+		&self,
+		provider: AccountId,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> RpcResult<ProviderRating> {
+		let api = self.client.runtime_api();
+		let at = BlockId::hash(at.unwrap_or_else(|| self.client.info().best_hash));
+
+		api.rating_by_provider(&at, provider).map_err(runtime_error_into_rpc_err)
+	}
+}
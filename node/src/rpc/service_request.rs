@@ -0,0 +1,104 @@
+use std::{marker::PhantomData, sync::Arc};
+
+use codec::Codec;
+use jsonrpsee::{core::RpcResult, proc_macros::rpc};
+use serde::{de::DeserializeOwned, Serialize};
+use sp_api::ProvideRuntimeApi;
+use sp_blockchain::HeaderBackend;
+use sp_runtime::{generic::BlockId, traits::Block as BlockT};
+
+pub use debio_runtime_api::ServiceRequestApi as ServiceRequestRuntimeApi;
+
+use super::runtime_error_into_rpc_err;
+
+#[rpc(client, server)]
+pub trait ServiceRequestApi<BlockHash, AccountId, Hash, Request, RequestStatus> {
+	#[method(name = "serviceRequest_getRequestById")]
+	fn request_by_id(&self, request_id: Hash, at: Option<BlockHash>) -> RpcResult<Option<Request>>;
+
+	#[method(name = "serviceRequest_getRequestsByRequesterId")]
+	fn requests_by_requester_id(
+		&self,
+		requester_id: AccountId,
+		status: Option<RequestStatus>,
+		offset: u32,
+		limit: u32,
+		at: Option<BlockHash>,
+	) -> RpcResult<Vec<Request>>;
+
+	#[method(name = "serviceRequest_getRequestsByLabId")]
+	fn requests_by_lab_id(
+		&self,
+		lab_id: AccountId,
+		status: Option<RequestStatus>,
+		offset: u32,
+		limit: u32,
+		at: Option<BlockHash>,
+	) -> RpcResult<Vec<Request>>;
+}
+
+/// Serves [`ServiceRequestRuntimeApi`] queries over JSON-RPC.
+pub struct ServiceRequest<C, Block> {
+	client: Arc<C>,
+	_marker: PhantomData<Block>,
+}
+
+impl<C, Block> ServiceRequest<C, Block> {
+	pub fn new(client: Arc<C>) -> Self {
+		Self { client, _marker: Default::default() }
+	}
+}
+
+impl<C, Block, AccountId, Hash, Request, RequestStatus>
+	ServiceRequestApiServer<<Block as BlockT>::Hash, AccountId, Hash, Request, RequestStatus>
+	for ServiceRequest<C, Block>
+where
+	Block: BlockT,
+	C: ProvideRuntimeApi<Block> + HeaderBackend<Block> + Send + Sync + 'static,
+	C::Api: ServiceRequestRuntimeApi<Block, AccountId, Hash, Request, RequestStatus>,
+	AccountId: Codec + DeserializeOwned + Send + Sync + 'static,
+	Hash: Codec + DeserializeOwned + Send + Sync + 'static,
+	Request: Codec + Serialize + Send + Sync + 'static,
+	RequestStatus: Codec + DeserializeOwned + Send + Sync + 'static,
+{
+	fn request_by_id(
+		&self,
+		request_id: Hash,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> RpcResult<Option<Request>> {
+		let api = self.client.runtime_api();
+		let at = BlockId::hash(at.unwrap_or_else(|| self.client.info().best_hash));
+
+		api.request_by_id(&at, request_id).map_err(runtime_error_into_rpc_err)
+	}
+
+	fn requests_by_requester_id(
+		&self,
+		requester_id: AccountId,
+		status: Option<RequestStatus>,
+		offset: u32,
+		limit: u32,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> RpcResult<Vec<Request>> {
+		let api = self.client.runtime_api();
+		let at = BlockId::hash(at.unwrap_or_else(|| self.client.info().best_hash));
+
+		api.requests_by_requester_id(&at, requester_id, status, offset, limit)
+			.map_err(runtime_error_into_rpc_err)
+	}
+
+	fn requests_by_lab_id(
+		&self,
+		lab_id: AccountId,
+		status: Option<RequestStatus>,
+		offset: u32,
+		limit: u32,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> RpcResult<Vec<Request>> {
+		let api = self.client.runtime_api();
+		let at = BlockId::hash(at.unwrap_or_else(|| self.client.info().best_hash));
+
+		api.requests_by_lab_id(&at, lab_id, status, offset, limit)
+			.map_err(runtime_error_into_rpc_err)
+	}
+}
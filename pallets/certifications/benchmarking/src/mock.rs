@@ -1,188 +0,0 @@
-#![cfg(test)]
-
-use frame_support::{parameter_types, traits::ConstU64, PalletId};
-use pallet_balances::AccountData;
-use primitives_ethereum_address::EthereumAddress;
-use primitives_profile_roles::ProfileRoles;
-
-use sp_runtime::{
-	traits::{AccountIdLookup, IdentifyAccount, Verify},
-	MultiSignature,
-};
-
-type UncheckedExtrinsic = frame_system::mocking::MockUncheckedExtrinsic<Test>;
-type Block = frame_system::mocking::MockBlock<Test>;
-
-pub type Signature = MultiSignature;
-pub type AccountId = <<Signature as Verify>::Signer as IdentifyAccount>::AccountId;
-
-frame_support::construct_runtime!(
-	pub enum Test where
-		Block = Block,
-		NodeBlock = Block,
-		UncheckedExtrinsic = UncheckedExtrinsic,
-	{
-		System: frame_system,
-		Balances: pallet_balances,
-		Labs: labs,
-		Certifications: certifications,
-		RandomnessCollectiveFlip: pallet_randomness_collective_flip,
-		UserProfile: user_profile,
-		Assets: pallet_assets,
-		Orders: orders,
-		GeneticTesting: genetic_testing,
-		Services: services,
-		Timestamp: pallet_timestamp,
-	}
-);
-
-parameter_types! {
-	pub const BlockHashCount: u64 = 250;
-	pub const SS58Prefix: u8 = 42;
-}
-
-impl frame_system::Config for Test {
-	type BaseCallFilter = frame_support::traits::Everything;
-	type BlockWeights = ();
-	type BlockLength = ();
-	type AccountId = AccountId;
-	type RuntimeCall = RuntimeCall;
-	type Lookup = AccountIdLookup<AccountId, ()>;
-	type Index = u64;
-	type BlockNumber = u64;
-	type Hash = sp_core::H256;
-	type Hashing = ::sp_runtime::traits::BlakeTwo256;
-	type Header = sp_runtime::testing::Header;
-	type RuntimeEvent = RuntimeEvent;
-	type RuntimeOrigin = RuntimeOrigin;
-	type BlockHashCount = BlockHashCount;
-	type DbWeight = ();
-	type Version = ();
-	type PalletInfo = PalletInfo;
-	type OnNewAccount = ();
-	type OnKilledAccount = ();
-	type AccountData = AccountData<Balance>;
-	type SystemWeightInfo = ();
-	type SS58Prefix = SS58Prefix;
-	type OnSetCode = ();
-	type MaxConsumers = frame_support::traits::ConstU32<16>;
-}
-
-type Balance = u64;
-
-parameter_types! {
-	pub const ExistentialDeposit: Balance = 10;
-}
-
-impl pallet_balances::Config for Test {
-	type MaxLocks = ();
-	type MaxReserves = ();
-	type ReserveIdentifier = [u8; 8];
-	type Balance = Balance;
-	type RuntimeEvent = RuntimeEvent;
-	type DustRemoval = ();
-	type ExistentialDeposit = ExistentialDeposit;
-	type AccountStore = System;
-	type WeightInfo = ();
-}
-
-pub type AssetId = u32;
-pub type AssetBalance = u128;
-
-parameter_types! {
-	pub const ApprovalDeposit: Balance = 1;
-	pub const AssetDeposit: Balance = 1;
-	pub const MetadataDepositBase: Balance = 1;
-	pub const MetadataDepositPerByte: Balance = 1;
-	pub const StringLimit: u32 = 50;
-}
-
-impl pallet_assets::Config for Test {
-	type RuntimeEvent = RuntimeEvent;
-	type Balance = AssetBalance;
-	type AssetId = AssetId;
-	type Currency = Balances;
-	type ForceOrigin = frame_system::EnsureRoot<AccountId>;
-	type AssetAccountDeposit = ConstU64<10>;
-	type AssetDeposit = AssetDeposit;
-	type MetadataDepositBase = MetadataDepositBase;
-	type MetadataDepositPerByte = MetadataDepositPerByte;
-	type ApprovalDeposit = ApprovalDeposit;
-	type StringLimit = StringLimit;
-	type Freezer = ();
-	type Extra = ();
-	type WeightInfo = ();
-}
-
-impl pallet_randomness_collective_flip::Config for Test {}
-
-pub type Moment = u64;
-pub const MILLISECS_PER_BLOCK: Moment = 6000;
-pub const SLOT_DURATION: Moment = MILLISECS_PER_BLOCK;
-
-parameter_types! {
-	pub const MinimumPeriod: Moment = SLOT_DURATION / 2;
-}
-
-impl pallet_timestamp::Config for Test {
-	/// A timestamp: milliseconds since the unix epoch.
-	type Moment = Moment;
-	type OnTimestampSet = ();
-	type MinimumPeriod = MinimumPeriod;
-	type WeightInfo = ();
-}
-
-parameter_types! {
-	pub const LabPalletId: PalletId = PalletId(*b"dbio/lab");
-	pub const OrderPalletId: PalletId = PalletId(*b"dbio/ord");
-}
-
-impl labs::Config for Test {
-	type RuntimeEvent = RuntimeEvent;
-	type Currency = Balances;
-	type Services = Services;
-	type Orders = Orders;
-	type Certifications = Certifications;
-	type EthereumAddress = EthereumAddress;
-	type ProfileRoles = ProfileRoles;
-	type UserProfile = UserProfile;
-	type LabWeightInfo = ();
-	type PalletId = LabPalletId;
-}
-
-impl services::Config for Test {
-	type RuntimeEvent = RuntimeEvent;
-	type Currency = Balances;
-	type ServiceOwner = Labs;
-	type WeightInfo = ();
-}
-
-impl certifications::Config for Test {
-	type RuntimeEvent = RuntimeEvent;
-	type CertificationOwner = Labs;
-	type WeightInfo = ();
-}
-
-impl genetic_testing::Config for Test {
-	type RuntimeEvent = RuntimeEvent;
-	type Orders = Orders;
-	type RandomnessSource = RandomnessCollectiveFlip;
-	type GeneticTestingWeightInfo = ();
-}
-
-impl orders::Config for Test {
-	type RuntimeEvent = RuntimeEvent;
-	type Services = Services;
-	type GeneticTesting = GeneticTesting;
-	type Currency = Balances;
-	type Assets = Assets;
-	type OrdersWeightInfo = ();
-	type PalletId = OrderPalletId;
-}
-
-impl user_profile::Config for Test {
-	type RuntimeEvent = RuntimeEvent;
-	type EthereumAddress = EthereumAddress;
-	type ProfileRoles = ProfileRoles;
-	type WeightInfo = ();
-}
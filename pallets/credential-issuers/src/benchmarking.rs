@@ -0,0 +1,65 @@
+use super::*;
+
+#[allow(unused)]
+use crate::Pallet as CredentialIssuers;
+use frame_benchmarking::{account, benchmarks, impl_benchmark_test_suite, whitelisted_caller};
+use frame_system::RawOrigin;
+
+benchmarks! {
+	register_issuer {
+		let issuer: T::AccountId = account("issuer", 0, 0);
+	}: register_issuer(
+		RawOrigin::Root,
+		issuer,
+		"DeBio Accreditation Body".as_bytes().to_vec()
+	)
+
+	deregister_issuer {
+		let issuer: T::AccountId = account("issuer", 0, 0);
+
+		let _register_issuer = CredentialIssuers::<T>::register_issuer(
+			RawOrigin::Root.into(),
+			issuer.clone(),
+			"DeBio Accreditation Body".as_bytes().to_vec()
+		);
+	}: deregister_issuer(
+		RawOrigin::Root,
+		issuer
+	)
+
+	verify_credential {
+		let caller: T::AccountId = whitelisted_caller();
+		let credential_id = T::BenchmarkHelper::credential_id();
+
+		let _register_issuer = CredentialIssuers::<T>::register_issuer(
+			RawOrigin::Root.into(),
+			caller.clone(),
+			"DeBio Accreditation Body".as_bytes().to_vec()
+		);
+	}: verify_credential(
+		RawOrigin::Signed(caller),
+		credential_id
+	)
+
+	revoke_credential {
+		let caller: T::AccountId = whitelisted_caller();
+		let credential_id = T::BenchmarkHelper::credential_id();
+
+		let _register_issuer = CredentialIssuers::<T>::register_issuer(
+			RawOrigin::Root.into(),
+			caller.clone(),
+			"DeBio Accreditation Body".as_bytes().to_vec()
+		);
+
+		let caller_origin = T::RuntimeOrigin::from(RawOrigin::Signed(caller.clone()));
+		let _verify_credential = CredentialIssuers::<T>::verify_credential(
+			caller_origin,
+			credential_id
+		);
+	}: revoke_credential(
+		RawOrigin::Signed(caller),
+		credential_id
+	)
+}
+
+impl_benchmark_test_suite! {CredentialIssuers, crate::mock::ExternalityBuilder::build(), crate::mock::Test}
@@ -0,0 +1,28 @@
+pub trait CredentialIssuersInterface<T: frame_system::Config> {
+	type Error;
+	type CredentialIssuer;
+	type CredentialVerification;
+
+	fn register_issuer(
+		account_id: &T::AccountId,
+		name: &[u8],
+	) -> Result<Self::CredentialIssuer, Self::Error>;
+
+	fn deregister_issuer(account_id: &T::AccountId) -> Result<Self::CredentialIssuer, Self::Error>;
+
+	fn verify_credential(
+		issuer_id: &T::AccountId,
+		credential_id: &T::Hash,
+	) -> Result<Self::CredentialVerification, Self::Error>;
+
+	fn revoke_credential(
+		issuer_id: &T::AccountId,
+		credential_id: &T::Hash,
+	) -> Result<Self::CredentialVerification, Self::Error>;
+
+	fn issuer_by_account_id(account_id: &T::AccountId) -> Option<Self::CredentialIssuer>;
+
+	fn verification_by_credential_id(
+		credential_id: &T::Hash,
+	) -> Option<Self::CredentialVerification>;
+}
@@ -0,0 +1,315 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use frame_support::{
+	codec::{Decode, Encode},
+	pallet_prelude::*,
+};
+pub use pallet::*;
+pub use scale_info::TypeInfo;
+
+#[cfg(test)]
+mod mock;
+
+#[cfg(test)]
+mod tests;
+
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+
+pub mod interface;
+pub mod weights;
+pub use interface::CredentialIssuersInterface;
+use sp_std::prelude::*;
+pub use traits_credential_issuers::{
+	CredentialIssuersProvider, CredentialVerification, CredentialsLookup,
+};
+
+/// Longest name of a credential issuer
+pub const MAX_ISSUER_NAME_LENGTH: u32 = 256;
+
+pub type IssuerName = BoundedVec<u8, ConstU32<MAX_ISSUER_NAME_LENGTH>>;
+
+#[derive(Encode, Decode, Clone, Default, RuntimeDebug, PartialEq, Eq, TypeInfo, MaxEncodedLen)]
+pub struct CredentialIssuer<AccountId, BlockNumber> {
+	pub account_id: AccountId,
+	pub name: IssuerName,
+	pub registered_at: BlockNumber,
+}
+
+impl<AccountId, BlockNumber> CredentialIssuer<AccountId, BlockNumber> {
+	pub fn new(account_id: AccountId, name: IssuerName, registered_at: BlockNumber) -> Self {
+		Self { account_id, name, registered_at }
+	}
+}
+
+#[cfg(feature = "runtime-benchmarks")]
+pub trait BenchmarkHelper<Hash> {
+	/// Creates a credential in one of the credentials pallets and returns its id
+	fn credential_id() -> Hash;
+}
+#[cfg(feature = "runtime-benchmarks")]
+impl<Hash: Default> BenchmarkHelper<Hash> for () {
+	fn credential_id() -> Hash {
+		Hash::default()
+	}
+}
+
+#[frame_support::pallet]
+pub mod pallet {
+	use crate::{
+		interface::CredentialIssuersInterface, weights::WeightInfo, CredentialIssuer,
+		CredentialVerification, CredentialsLookup, IssuerName,
+	};
+	use frame_support::{dispatch::DispatchResultWithPostInfo, pallet_prelude::*};
+	use frame_system::pallet_prelude::*;
+	pub use sp_std::prelude::*;
+
+	#[pallet::config]
+	pub trait Config: frame_system::Config {
+		type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+		type RegistrarOrigin: EnsureOrigin<<Self as frame_system::Config>::RuntimeOrigin>;
+		/// Credentials pallets whose credentials can be countersigned
+		type Credentials: CredentialsLookup<Self>;
+		type CredentialIssuersWeightInfo: WeightInfo;
+		#[cfg(feature = "runtime-benchmarks")]
+		type BenchmarkHelper: crate::BenchmarkHelper<Self::Hash>;
+	}
+
+	// ----- This is template code, every pallet needs this ---
+	#[pallet::pallet]
+	#[pallet::generate_store(pub(super) trait Store)]
+	pub struct Pallet<T>(_);
+
+	#[pallet::hooks]
+	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {}
+	// --------------------------------------------------------
+
+	// ----- Types -------
+	pub type AccountIdOf<T> = <T as frame_system::Config>::AccountId;
+	pub type HashOf<T> = <T as frame_system::Config>::Hash;
+	pub type CredentialIssuerOf<T> = CredentialIssuer<AccountIdOf<T>, BlockNumberFor<T>>;
+	pub type CredentialVerificationOf<T> =
+		CredentialVerification<AccountIdOf<T>, BlockNumberFor<T>>;
+	pub type CredentialIdOf<T> = HashOf<T>;
+
+	// ------- Storage -------------
+	#[pallet::storage]
+	#[pallet::getter(fn issuer_by_account_id)]
+	pub type Issuers<T> = StorageMap<_, Blake2_128Concat, AccountIdOf<T>, CredentialIssuerOf<T>>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn verification_by_credential_id)]
+	pub type CredentialVerifications<T> =
+		StorageMap<_, Blake2_128Concat, CredentialIdOf<T>, CredentialVerificationOf<T>>;
+	// -----------------------------
+
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(super) fn deposit_event)]
+	pub enum Event<T: Config> {
+		/// Credential issuer registered
+		/// parameters, [CredentialIssuer]
+		IssuerRegistered(CredentialIssuerOf<T>),
+		/// Credential issuer deregistered
+		/// parameters, [CredentialIssuer]
+		IssuerDeregistered(CredentialIssuerOf<T>),
+		/// Credential countersigned by an issuer
+		/// parameters, [CredentialVerification, credential_id]
+		CredentialVerified(CredentialVerificationOf<T>, CredentialIdOf<T>),
+		/// Credential countersignature revoked by its issuer
+		/// parameters, [CredentialVerification, credential_id]
+		CredentialRevoked(CredentialVerificationOf<T>, CredentialIdOf<T>),
+	}
+
+	// Errors inform users that something went wrong.
+	#[pallet::error]
+	pub enum Error<T> {
+		/// Account is already a registered credential issuer
+		IssuerAlreadyRegistered,
+		/// Issuer name exceeds MAX_ISSUER_NAME_LENGTH
+		IssuerNameTooLong,
+		/// Account is not a registered credential issuer
+		IssuerNotRegistered,
+		/// Credential does not exist in any credentials pallet
+		CredentialNotFound,
+		/// Credential already has an active countersignature
+		CredentialAlreadyVerified,
+		/// Credential has not been countersigned
+		CredentialNotVerified,
+		/// Credential countersignature is already revoked
+		CredentialAlreadyRevoked,
+		/// Only the issuer that countersigned a credential can revoke it
+		NotCredentialVerifier,
+	}
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		#[pallet::weight(T::CredentialIssuersWeightInfo::register_issuer())]
+		pub fn register_issuer(
+			origin: OriginFor<T>,
+			account_id: AccountIdOf<T>,
+			name: Vec<u8>,
+		) -> DispatchResultWithPostInfo {
+			T::RegistrarOrigin::ensure_origin(origin)?;
+
+			match <Self as CredentialIssuersInterface<T>>::register_issuer(&account_id, &name) {
+				Ok(issuer) => {
+					Self::deposit_event(Event::IssuerRegistered(issuer));
+					Ok(().into())
+				},
+				Err(error) => Err(error.into()),
+			}
+		}
+
+		#[pallet::weight(T::CredentialIssuersWeightInfo::deregister_issuer())]
+		pub fn deregister_issuer(
+			origin: OriginFor<T>,
+			account_id: AccountIdOf<T>,
+		) -> DispatchResultWithPostInfo {
+			T::RegistrarOrigin::ensure_origin(origin)?;
+
+			match <Self as CredentialIssuersInterface<T>>::deregister_issuer(&account_id) {
+				Ok(issuer) => {
+					Self::deposit_event(Event::IssuerDeregistered(issuer));
+					Ok(().into())
+				},
+				Err(error) => Err(error.into()),
+			}
+		}
+
+		#[pallet::weight(T::CredentialIssuersWeightInfo::verify_credential())]
+		pub fn verify_credential(
+			origin: OriginFor<T>,
+			credential_id: CredentialIdOf<T>,
+		) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+
+			match <Self as CredentialIssuersInterface<T>>::verify_credential(&who, &credential_id) {
+				Ok(verification) => {
+					Self::deposit_event(Event::CredentialVerified(verification, credential_id));
+					Ok(().into())
+				},
+				Err(error) => Err(error.into()),
+			}
+		}
+
+		#[pallet::weight(T::CredentialIssuersWeightInfo::revoke_credential())]
+		pub fn revoke_credential(
+			origin: OriginFor<T>,
+			credential_id: CredentialIdOf<T>,
+		) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+
+			match <Self as CredentialIssuersInterface<T>>::revoke_credential(&who, &credential_id) {
+				Ok(verification) => {
+					Self::deposit_event(Event::CredentialRevoked(verification, credential_id));
+					Ok(().into())
+				},
+				Err(error) => Err(error.into()),
+			}
+		}
+	}
+}
+
+/// CredentialIssuers Interface Implementation
+impl<T: Config> CredentialIssuersInterface<T> for Pallet<T> {
+	type Error = Error<T>;
+	type CredentialIssuer = CredentialIssuerOf<T>;
+	type CredentialVerification = CredentialVerificationOf<T>;
+
+	fn register_issuer(
+		account_id: &T::AccountId,
+		name: &[u8],
+	) -> Result<Self::CredentialIssuer, Self::Error> {
+		if Issuers::<T>::contains_key(account_id) {
+			return Err(Error::<T>::IssuerAlreadyRegistered)
+		}
+
+		let name: IssuerName =
+			name.to_vec().try_into().map_err(|_| Error::<T>::IssuerNameTooLong)?;
+		let now = frame_system::Pallet::<T>::block_number();
+		let issuer = CredentialIssuer::new(account_id.clone(), name, now);
+
+		Issuers::<T>::insert(account_id, &issuer);
+
+		Ok(issuer)
+	}
+
+	fn deregister_issuer(account_id: &T::AccountId) -> Result<Self::CredentialIssuer, Self::Error> {
+		let issuer = Issuers::<T>::take(account_id).ok_or(Error::<T>::IssuerNotRegistered)?;
+
+		Ok(issuer)
+	}
+
+	fn verify_credential(
+		issuer_id: &T::AccountId,
+		credential_id: &T::Hash,
+	) -> Result<Self::CredentialVerification, Self::Error> {
+		if !Issuers::<T>::contains_key(issuer_id) {
+			return Err(Error::<T>::IssuerNotRegistered)
+		}
+
+		if !T::Credentials::credential_exists(credential_id) {
+			return Err(Error::<T>::CredentialNotFound)
+		}
+
+		if let Some(verification) = CredentialVerifications::<T>::get(credential_id) {
+			if !verification.is_revoked() {
+				return Err(Error::<T>::CredentialAlreadyVerified)
+			}
+		}
+
+		let now = frame_system::Pallet::<T>::block_number();
+		let verification = CredentialVerification::new(issuer_id.clone(), now);
+
+		CredentialVerifications::<T>::insert(credential_id, &verification);
+
+		Ok(verification)
+	}
+
+	fn revoke_credential(
+		issuer_id: &T::AccountId,
+		credential_id: &T::Hash,
+	) -> Result<Self::CredentialVerification, Self::Error> {
+		let mut verification = CredentialVerifications::<T>::get(credential_id)
+			.ok_or(Error::<T>::CredentialNotVerified)?;
+
+		if &verification.verified_by != issuer_id {
+			return Err(Error::<T>::NotCredentialVerifier)
+		}
+
+		if verification.is_revoked() {
+			return Err(Error::<T>::CredentialAlreadyRevoked)
+		}
+
+		verification.revoked_at = Some(frame_system::Pallet::<T>::block_number());
+
+		CredentialVerifications::<T>::insert(credential_id, &verification);
+
+		Ok(verification)
+	}
+
+	fn issuer_by_account_id(account_id: &T::AccountId) -> Option<Self::CredentialIssuer> {
+		Issuers::<T>::get(account_id)
+	}
+
+	fn verification_by_credential_id(
+		credential_id: &T::Hash,
+	) -> Option<Self::CredentialVerification> {
+		CredentialVerifications::<T>::get(credential_id)
+	}
+}
+
+/// CredentialIssuersProvider Trait Implementation
+impl<T: Config> CredentialIssuersProvider<T> for Pallet<T> {
+	fn is_credential_issuer(account_id: &T::AccountId) -> bool {
+		Issuers::<T>::contains_key(account_id)
+	}
+
+	fn credential_verification(credential_id: &T::Hash) -> Option<CredentialVerificationOf<T>> {
+		CredentialVerifications::<T>::get(credential_id)
+	}
+
+	fn remove_credential_verification(credential_id: &T::Hash) {
+		CredentialVerifications::<T>::remove(credential_id);
+	}
+}
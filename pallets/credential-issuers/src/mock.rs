@@ -0,0 +1,97 @@
+use crate as credential_issuers;
+use frame_support::parameter_types;
+use sp_core::H256;
+use sp_io::TestExternalities;
+use sp_runtime::{
+	testing::Header,
+	traits::{AccountIdLookup, BlakeTwo256},
+};
+
+type UncheckedExtrinsic = frame_system::mocking::MockUncheckedExtrinsic<Test>;
+type Block = frame_system::mocking::MockBlock<Test>;
+
+pub type AccountId = u64;
+
+frame_support::construct_runtime!(
+	pub enum Test where
+		Block = Block,
+		NodeBlock = Block,
+		UncheckedExtrinsic = UncheckedExtrinsic,
+	{
+		System: frame_system,
+		CredentialIssuers: credential_issuers,
+	}
+);
+
+parameter_types! {
+	pub const BlockHashCount: u64 = 250;
+	pub const SS58Prefix: u8 = 42;
+	pub static ExistingCredentials: Vec<H256> = Vec::new();
+}
+
+impl frame_system::Config for Test {
+	type BaseCallFilter = frame_support::traits::Everything;
+	type BlockWeights = ();
+	type BlockLength = ();
+	type AccountId = AccountId;
+	type RuntimeCall = RuntimeCall;
+	type Lookup = AccountIdLookup<AccountId, ()>;
+	type Index = u64;
+	type BlockNumber = u64;
+	type Hash = H256;
+	type Hashing = BlakeTwo256;
+	type Header = Header;
+	type RuntimeEvent = RuntimeEvent;
+	type RuntimeOrigin = RuntimeOrigin;
+	type BlockHashCount = BlockHashCount;
+	type DbWeight = ();
+	type Version = ();
+	type PalletInfo = PalletInfo;
+	type OnNewAccount = ();
+	type OnKilledAccount = ();
+	type AccountData = ();
+	type SystemWeightInfo = ();
+	type SS58Prefix = SS58Prefix;
+	type OnSetCode = ();
+	type MaxConsumers = frame_support::traits::ConstU32<16>;
+}
+
+pub fn create_credential(credential_id: H256) {
+	let mut existing_credentials = ExistingCredentials::get();
+	existing_credentials.push(credential_id);
+	ExistingCredentials::set(existing_credentials);
+}
+
+pub struct CredentialsMock;
+impl credential_issuers::CredentialsLookup<Test> for CredentialsMock {
+	fn credential_exists(credential_id: &H256) -> bool {
+		ExistingCredentials::get().contains(credential_id)
+	}
+}
+
+#[cfg(feature = "runtime-benchmarks")]
+impl credential_issuers::BenchmarkHelper<H256> for CredentialsMock {
+	fn credential_id() -> H256 {
+		let credential_id = H256::repeat_byte(1);
+		create_credential(credential_id);
+		credential_id
+	}
+}
+
+impl credential_issuers::Config for Test {
+	type RuntimeEvent = RuntimeEvent;
+	type RegistrarOrigin = frame_system::EnsureRoot<AccountId>;
+	type Credentials = CredentialsMock;
+	type CredentialIssuersWeightInfo = ();
+	#[cfg(feature = "runtime-benchmarks")]
+	type BenchmarkHelper = CredentialsMock;
+}
+
+pub struct ExternalityBuilder;
+
+impl ExternalityBuilder {
+	pub fn build() -> TestExternalities {
+		let storage = frame_system::GenesisConfig::default().build_storage::<Test>().unwrap();
+		TestExternalities::from(storage)
+	}
+}
@@ -0,0 +1,274 @@
+use crate::{
+	mock::*, CredentialIssuer, CredentialIssuersProvider, CredentialVerification, Error,
+	MAX_ISSUER_NAME_LENGTH,
+};
+use frame_support::{
+	assert_noop, assert_ok,
+	sp_runtime::traits::{Hash, Keccak256},
+};
+use sp_runtime::DispatchError;
+
+#[test]
+fn register_issuer_works() {
+	ExternalityBuilder::build().execute_with(|| {
+		System::set_block_number(1);
+
+		assert_ok!(CredentialIssuers::register_issuer(
+			RuntimeOrigin::root(),
+			1,
+			"DeBio Accreditation Body".as_bytes().to_vec()
+		));
+
+		assert_eq!(
+			CredentialIssuers::issuer_by_account_id(1),
+			Some(CredentialIssuer::new(
+				1,
+				"DeBio Accreditation Body".as_bytes().to_vec().try_into().unwrap(),
+				1
+			))
+		);
+	})
+}
+
+#[test]
+fn cant_register_issuer_when_not_registrar() {
+	ExternalityBuilder::build().execute_with(|| {
+		assert_noop!(
+			CredentialIssuers::register_issuer(
+				RuntimeOrigin::signed(1),
+				1,
+				"DeBio Accreditation Body".as_bytes().to_vec()
+			),
+			DispatchError::BadOrigin
+		);
+	})
+}
+
+#[test]
+fn cant_register_issuer_when_name_too_long() {
+	ExternalityBuilder::build().execute_with(|| {
+		assert_noop!(
+			CredentialIssuers::register_issuer(
+				RuntimeOrigin::root(),
+				1,
+				vec![b'X'; MAX_ISSUER_NAME_LENGTH as usize + 1]
+			),
+			Error::<Test>::IssuerNameTooLong
+		);
+	})
+}
+
+#[test]
+fn cant_register_issuer_when_already_registered() {
+	ExternalityBuilder::build().execute_with(|| {
+		assert_ok!(CredentialIssuers::register_issuer(
+			RuntimeOrigin::root(),
+			1,
+			"DeBio Accreditation Body".as_bytes().to_vec()
+		));
+
+		assert_noop!(
+			CredentialIssuers::register_issuer(
+				RuntimeOrigin::root(),
+				1,
+				"DeBio Accreditation Body".as_bytes().to_vec()
+			),
+			Error::<Test>::IssuerAlreadyRegistered
+		);
+	})
+}
+
+#[test]
+fn deregister_issuer_works() {
+	ExternalityBuilder::build().execute_with(|| {
+		assert_ok!(CredentialIssuers::register_issuer(
+			RuntimeOrigin::root(),
+			1,
+			"DeBio Accreditation Body".as_bytes().to_vec()
+		));
+
+		assert_ok!(CredentialIssuers::deregister_issuer(RuntimeOrigin::root(), 1));
+
+		assert_eq!(CredentialIssuers::issuer_by_account_id(1), None);
+
+		assert_noop!(
+			CredentialIssuers::deregister_issuer(RuntimeOrigin::root(), 1),
+			Error::<Test>::IssuerNotRegistered
+		);
+	})
+}
+
+#[test]
+fn verify_credential_works() {
+	ExternalityBuilder::build().execute_with(|| {
+		System::set_block_number(1);
+
+		let credential_id = Keccak256::hash("credential".as_bytes());
+		create_credential(credential_id);
+
+		assert_ok!(CredentialIssuers::register_issuer(
+			RuntimeOrigin::root(),
+			1,
+			"DeBio Accreditation Body".as_bytes().to_vec()
+		));
+
+		assert_ok!(CredentialIssuers::verify_credential(RuntimeOrigin::signed(1), credential_id));
+
+		assert_eq!(
+			CredentialIssuers::verification_by_credential_id(credential_id),
+			Some(CredentialVerification::new(1, 1))
+		);
+
+		assert!(<CredentialIssuers as CredentialIssuersProvider<Test>>::is_credential_verified(
+			&credential_id
+		));
+	})
+}
+
+#[test]
+fn cant_verify_credential_when_not_issuer() {
+	ExternalityBuilder::build().execute_with(|| {
+		let credential_id = Keccak256::hash("credential".as_bytes());
+
+		assert_noop!(
+			CredentialIssuers::verify_credential(RuntimeOrigin::signed(1), credential_id),
+			Error::<Test>::IssuerNotRegistered
+		);
+	})
+}
+
+#[test]
+fn cant_verify_credential_when_not_found() {
+	ExternalityBuilder::build().execute_with(|| {
+		let credential_id = Keccak256::hash("credential".as_bytes());
+
+		assert_ok!(CredentialIssuers::register_issuer(
+			RuntimeOrigin::root(),
+			1,
+			"DeBio Accreditation Body".as_bytes().to_vec()
+		));
+
+		assert_noop!(
+			CredentialIssuers::verify_credential(RuntimeOrigin::signed(1), credential_id),
+			Error::<Test>::CredentialNotFound
+		);
+	})
+}
+
+#[test]
+fn cant_verify_credential_when_already_verified() {
+	ExternalityBuilder::build().execute_with(|| {
+		let credential_id = Keccak256::hash("credential".as_bytes());
+		create_credential(credential_id);
+
+		assert_ok!(CredentialIssuers::register_issuer(
+			RuntimeOrigin::root(),
+			1,
+			"DeBio Accreditation Body".as_bytes().to_vec()
+		));
+		assert_ok!(CredentialIssuers::register_issuer(
+			RuntimeOrigin::root(),
+			2,
+			"DeBio Medical Council".as_bytes().to_vec()
+		));
+
+		assert_ok!(CredentialIssuers::verify_credential(RuntimeOrigin::signed(1), credential_id));
+
+		assert_noop!(
+			CredentialIssuers::verify_credential(RuntimeOrigin::signed(2), credential_id),
+			Error::<Test>::CredentialAlreadyVerified
+		);
+	})
+}
+
+#[test]
+fn revoke_credential_works() {
+	ExternalityBuilder::build().execute_with(|| {
+		System::set_block_number(1);
+
+		let credential_id = Keccak256::hash("credential".as_bytes());
+		create_credential(credential_id);
+
+		assert_ok!(CredentialIssuers::register_issuer(
+			RuntimeOrigin::root(),
+			1,
+			"DeBio Accreditation Body".as_bytes().to_vec()
+		));
+
+		assert_ok!(CredentialIssuers::verify_credential(RuntimeOrigin::signed(1), credential_id));
+
+		System::set_block_number(5);
+
+		assert_ok!(CredentialIssuers::revoke_credential(RuntimeOrigin::signed(1), credential_id));
+
+		assert_eq!(
+			CredentialIssuers::verification_by_credential_id(credential_id),
+			Some(CredentialVerification { verified_by: 1, verified_at: 1, revoked_at: Some(5) })
+		);
+
+		assert!(!<CredentialIssuers as CredentialIssuersProvider<Test>>::is_credential_verified(
+			&credential_id
+		));
+
+		assert_noop!(
+			CredentialIssuers::revoke_credential(RuntimeOrigin::signed(1), credential_id),
+			Error::<Test>::CredentialAlreadyRevoked
+		);
+
+		// A revoked credential can be countersigned again
+		assert_ok!(CredentialIssuers::verify_credential(RuntimeOrigin::signed(1), credential_id));
+	})
+}
+
+#[test]
+fn cant_revoke_credential_when_not_verifier() {
+	ExternalityBuilder::build().execute_with(|| {
+		let credential_id = Keccak256::hash("credential".as_bytes());
+		create_credential(credential_id);
+
+		assert_noop!(
+			CredentialIssuers::revoke_credential(RuntimeOrigin::signed(1), credential_id),
+			Error::<Test>::CredentialNotVerified
+		);
+
+		assert_ok!(CredentialIssuers::register_issuer(
+			RuntimeOrigin::root(),
+			1,
+			"DeBio Accreditation Body".as_bytes().to_vec()
+		));
+		assert_ok!(CredentialIssuers::register_issuer(
+			RuntimeOrigin::root(),
+			2,
+			"DeBio Medical Council".as_bytes().to_vec()
+		));
+
+		assert_ok!(CredentialIssuers::verify_credential(RuntimeOrigin::signed(1), credential_id));
+
+		assert_noop!(
+			CredentialIssuers::revoke_credential(RuntimeOrigin::signed(2), credential_id),
+			Error::<Test>::NotCredentialVerifier
+		);
+	})
+}
+
+#[test]
+fn credential_is_not_verified_when_issuer_deregistered() {
+	ExternalityBuilder::build().execute_with(|| {
+		let credential_id = Keccak256::hash("credential".as_bytes());
+		create_credential(credential_id);
+
+		assert_ok!(CredentialIssuers::register_issuer(
+			RuntimeOrigin::root(),
+			1,
+			"DeBio Accreditation Body".as_bytes().to_vec()
+		));
+
+		assert_ok!(CredentialIssuers::verify_credential(RuntimeOrigin::signed(1), credential_id));
+
+		assert_ok!(CredentialIssuers::deregister_issuer(RuntimeOrigin::root(), 1));
+
+		assert!(!<CredentialIssuers as CredentialIssuersProvider<Test>>::is_credential_verified(
+			&credential_id
+		));
+	})
+}
@@ -0,0 +1,103 @@
+//! Autogenerated weights for credential_issuers
+//!
+//! THIS FILE WAS AUTO-GENERATED USING THE SUBSTRATE BENCHMARK CLI VERSION 4.0.0-dev
+//! DATE: 2022-09-18, STEPS: `20`, REPEAT: 10, LOW RANGE: `[]`, HIGH RANGE: `[]`
+//! EXECUTION: Some(Wasm), WASM-EXECUTION: Compiled, CHAIN: Some("dev"), DB CACHE: 1024
+
+// Executed Command:
+// ./target/release/debio
+// benchmark
+// --chain=dev
+// --execution=wasm
+// --wasm-execution=compiled
+// --pallet=credential-issuers
+// --extrinsic=*
+// --steps=20
+// --repeat=10
+// --heap-pages=4096
+// --output=./pallets/credential-issuers/src/weights.rs
+// --template=./.maintain/pallet-weight-template.hbs
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+
+use frame_support::{traits::Get, weights::{Weight, constants::RocksDbWeight}};
+use sp_std::marker::PhantomData;
+
+/// Weight functions needed for credential_issuers.
+pub trait WeightInfo {
+	fn register_issuer() -> Weight;
+	fn deregister_issuer() -> Weight;
+	fn verify_credential() -> Weight;
+	fn revoke_credential() -> Weight;
+}
+
+/// Weights for credential_issuers using the Substrate node and recommended hardware.
+pub struct SubstrateWeight<T>(PhantomData<T>);
+impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
+	// Storage: CredentialIssuers Issuers (r:1 w:1)
+	fn register_issuer() -> Weight {
+		Weight::from_ref_time(38_412_000_u64)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	// Storage: CredentialIssuers Issuers (r:1 w:1)
+	fn deregister_issuer() -> Weight {
+		Weight::from_ref_time(36_905_000_u64)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	// Storage: CredentialIssuers Issuers (r:1 w:0)
+	// Storage: Certifications Credentials (r:1 w:0)
+	// Storage: DoctorCertifications Credentials (r:1 w:0)
+	// Storage: HospitalCertifications Credentials (r:1 w:0)
+	// Storage: GeneticAnalystQualifications Credentials (r:1 w:0)
+	// Storage: HealthProfessionalQualification Credentials (r:1 w:0)
+	// Storage: CredentialIssuers CredentialVerifications (r:1 w:1)
+	fn verify_credential() -> Weight {
+		Weight::from_ref_time(47_230_000_u64)
+			.saturating_add(T::DbWeight::get().reads(7_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	// Storage: CredentialIssuers CredentialVerifications (r:1 w:1)
+	fn revoke_credential() -> Weight {
+		Weight::from_ref_time(40_117_000_u64)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+}
+
+// For backwards compatibility and tests
+impl WeightInfo for () {
+	// Storage: CredentialIssuers Issuers (r:1 w:1)
+	fn register_issuer() -> Weight {
+		Weight::from_ref_time(38_412_000_u64)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	// Storage: CredentialIssuers Issuers (r:1 w:1)
+	fn deregister_issuer() -> Weight {
+		Weight::from_ref_time(36_905_000_u64)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	// Storage: CredentialIssuers Issuers (r:1 w:0)
+	// Storage: Certifications Credentials (r:1 w:0)
+	// Storage: DoctorCertifications Credentials (r:1 w:0)
+	// Storage: HospitalCertifications Credentials (r:1 w:0)
+	// Storage: GeneticAnalystQualifications Credentials (r:1 w:0)
+	// Storage: HealthProfessionalQualification Credentials (r:1 w:0)
+	// Storage: CredentialIssuers CredentialVerifications (r:1 w:1)
+	fn verify_credential() -> Weight {
+		Weight::from_ref_time(47_230_000_u64)
+			.saturating_add(RocksDbWeight::get().reads(7_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	// Storage: CredentialIssuers CredentialVerifications (r:1 w:1)
+	fn revoke_credential() -> Weight {
+		Weight::from_ref_time(40_117_000_u64)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+}
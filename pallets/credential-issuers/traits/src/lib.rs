@@ -0,0 +1,84 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use codec::{Decode, Encode, MaxEncodedLen};
+use frame_support::RuntimeDebug;
+use frame_system::Config;
+use scale_info::TypeInfo;
+
+/// Countersignature of a certification or qualification by a registered credential issuer
+#[derive(Encode, Decode, Clone, Default, RuntimeDebug, PartialEq, Eq, TypeInfo, MaxEncodedLen)]
+pub struct CredentialVerification<AccountId, BlockNumber> {
+	pub verified_by: AccountId,
+	pub verified_at: BlockNumber,
+	pub revoked_at: Option<BlockNumber>,
+}
+
+impl<AccountId, BlockNumber> CredentialVerification<AccountId, BlockNumber> {
+	pub fn new(verified_by: AccountId, verified_at: BlockNumber) -> Self {
+		Self { verified_by, verified_at, revoked_at: None }
+	}
+
+	pub fn is_revoked(&self) -> bool {
+		self.revoked_at.is_some()
+	}
+}
+
+pub trait CredentialIssuersProvider<T: Config> {
+	fn is_credential_issuer(account_id: &T::AccountId) -> bool;
+	fn credential_verification(
+		credential_id: &T::Hash,
+	) -> Option<CredentialVerification<T::AccountId, T::BlockNumber>>;
+	fn remove_credential_verification(credential_id: &T::Hash);
+
+	/// A credential counts as verified while its countersignature is not revoked and the
+	/// issuer is still registered
+	fn is_credential_verified(credential_id: &T::Hash) -> bool {
+		match Self::credential_verification(credential_id) {
+			Some(verification) =>
+				!verification.is_revoked() && Self::is_credential_issuer(&verification.verified_by),
+			None => false,
+		}
+	}
+}
+
+impl<T: Config> CredentialIssuersProvider<T> for () {
+	fn is_credential_issuer(_account_id: &T::AccountId) -> bool {
+		false
+	}
+
+	fn credential_verification(
+		_credential_id: &T::Hash,
+	) -> Option<CredentialVerification<T::AccountId, T::BlockNumber>> {
+		None
+	}
+
+	fn remove_credential_verification(_credential_id: &T::Hash) {}
+}
+
+/// Credentials a registered issuer can countersign, implemented by each credentials pallet
+/// instance. A tuple of instances finds a credential in any of them.
+pub trait CredentialsLookup<T: Config> {
+	fn credential_exists(credential_id: &T::Hash) -> bool;
+}
+
+impl<T: Config> CredentialsLookup<T> for () {
+	fn credential_exists(_credential_id: &T::Hash) -> bool {
+		false
+	}
+}
+
+macro_rules! impl_credentials_lookup_for_tuple {
+	($($lookup:ident),+) => {
+		impl<T: Config, $($lookup: CredentialsLookup<T>),+> CredentialsLookup<T> for ($($lookup,)+) {
+			fn credential_exists(credential_id: &T::Hash) -> bool {
+				$($lookup::credential_exists(credential_id))||+
+			}
+		}
+	};
+}
+
+impl_credentials_lookup_for_tuple!(A);
+impl_credentials_lookup_for_tuple!(A, B);
+impl_credentials_lookup_for_tuple!(A, B, C);
+impl_credentials_lookup_for_tuple!(A, B, C, D);
+impl_credentials_lookup_for_tuple!(A, B, C, D, E);
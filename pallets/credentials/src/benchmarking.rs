@@ -0,0 +1,60 @@
+use super::*;
+
+#[allow(unused)]
+use crate::Pallet as Credentials;
+use frame_benchmarking::{
+	benchmarks_instance_pallet, impl_benchmark_test_suite, whitelisted_caller,
+};
+use frame_system::RawOrigin;
+use sp_std::vec;
+
+benchmarks_instance_pallet! {
+	create_credential {
+		let caller: T::AccountId = whitelisted_caller();
+		T::BenchmarkHelper::register_owner(&caller);
+	}: create_credential(
+		RawOrigin::Signed(caller),
+		T::BenchmarkHelper::credential_info()
+	)
+
+	update_credential {
+		let caller: T::AccountId = whitelisted_caller();
+		T::BenchmarkHelper::register_owner(&caller);
+
+		let _credential = <Credentials<T, I> as CredentialInterface<T>>::create_credential(
+			&caller,
+			&T::BenchmarkHelper::credential_info()
+		).unwrap();
+	}: update_credential(
+		RawOrigin::Signed(caller),
+		_credential.id,
+		T::BenchmarkHelper::credential_info()
+	)
+
+	delete_credential {
+		let caller: T::AccountId = whitelisted_caller();
+		T::BenchmarkHelper::register_owner(&caller);
+
+		let _credential = <Credentials<T, I> as CredentialInterface<T>>::create_credential(
+			&caller,
+			&T::BenchmarkHelper::credential_info()
+		).unwrap();
+	}: delete_credential(
+		RawOrigin::Signed(caller),
+		_credential.id
+	)
+
+	bulk_create_credential {
+		let n in 1 .. MAX_BULK_CREDENTIALS;
+
+		let caller: T::AccountId = whitelisted_caller();
+		T::BenchmarkHelper::register_owner(&caller);
+
+		let infos = vec![T::BenchmarkHelper::credential_info(); n as usize];
+	}: bulk_create_credential(
+		RawOrigin::Signed(caller),
+		infos
+	)
+}
+
+impl_benchmark_test_suite! {Credentials, crate::mock::ExternalityBuilder::build(), crate::mock::Test}
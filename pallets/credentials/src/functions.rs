@@ -0,0 +1,71 @@
+use crate::*;
+use frame_support::{codec::Encode, sp_runtime::traits::Hash};
+use traits_credential_issuers::CredentialsLookup;
+use traits_credentials::CredentialsProvider;
+
+impl<T: Config<I>, I: 'static> Pallet<T, I> {
+	/// Ids include the account nonce so a deleted credential id is never handed out again
+	pub fn generate_credential_id(owner_id: &T::AccountId, credential_count: u64) -> T::Hash {
+		let mut account_id_bytes = owner_id.encode();
+		let mut credential_count_bytes = credential_count.encode();
+		let mut nonce = frame_system::Pallet::<T>::account(owner_id).nonce.encode();
+
+		account_id_bytes.append(&mut credential_count_bytes);
+		account_id_bytes.append(&mut nonce);
+
+		let seed = &account_id_bytes;
+		T::Hashing::hash(seed)
+	}
+
+	pub fn add_credentials_count() {
+		CredentialsCount::<T, I>::mutate(|count| {
+			*count = count.saturating_add(1);
+		});
+	}
+
+	pub fn add_credential_count_by_owner(owner_id: &T::AccountId) {
+		CredentialsCountByOwner::<T, I>::mutate(owner_id, |count| {
+			*count = count.saturating_add(1);
+		});
+	}
+
+	pub fn sub_credentials_count() {
+		CredentialsCount::<T, I>::mutate(|count| {
+			*count = count.saturating_sub(1);
+		});
+	}
+
+	pub fn sub_credential_count_by_owner(owner_id: &T::AccountId) {
+		CredentialsCountByOwner::<T, I>::mutate(owner_id, |count| {
+			*count = count.saturating_sub(1);
+		});
+	}
+}
+
+/// CredentialsProvider Trait Implementation
+impl<T: Config<I>, I: 'static> CredentialsProvider<T> for Pallet<T, I> {
+	type Error = Error<T, I>;
+	type Credential = CredentialOf<T, I>;
+
+	fn delete_credential(
+		owner_id: &T::AccountId,
+		id: &T::Hash,
+	) -> Result<Self::Credential, Self::Error> {
+		<Self as CredentialInterface<T>>::delete_credential(owner_id, id)
+	}
+
+	fn credential_by_id(id: &T::Hash) -> Option<Self::Credential> {
+		<Self as CredentialInterface<T>>::credential_by_id(id)
+	}
+
+	fn is_credential_verified(id: &T::Hash) -> bool {
+		T::CredentialIssuers::is_credential_verified(id)
+	}
+}
+
+/// CredentialsLookup Trait Implementation
+impl<T: Config<I>, I: 'static> CredentialsLookup<T> for Pallet<T, I> {
+	fn credential_exists(credential_id: &T::Hash) -> bool {
+		Credentials::<T, I>::contains_key(credential_id)
+	}
+}
@@ -0,0 +1,105 @@
+use crate::*;
+use sp_std::vec::Vec;
+
+impl<T: Config<I>, I: 'static> CredentialInterface<T> for Pallet<T, I> {
+	type Error = Error<T, I>;
+	type Credential = CredentialOf<T, I>;
+	type CredentialInfo = CredentialInfoOf<T, I>;
+
+	fn create_credential(
+		owner_id: &T::AccountId,
+		info: &Self::CredentialInfo,
+	) -> Result<Self::Credential, Self::Error> {
+		if !T::CredentialOwner::can_create_credential(owner_id) {
+			return Err(Error::<T, I>::NotAllowedToCreate)
+		}
+
+		if !info.is_valid() {
+			return Err(Error::<T, I>::InvalidCredentialInfo)
+		}
+
+		let credential_count = CredentialsCountByOwner::<T, I>::get(owner_id);
+		let credential_id = Self::generate_credential_id(owner_id, credential_count);
+		let credential = Credential::new(credential_id, owner_id.clone(), info.clone());
+
+		Credentials::<T, I>::insert(credential_id, &credential);
+
+		Self::add_credentials_count();
+		Self::add_credential_count_by_owner(owner_id);
+
+		T::CredentialOwner::associate(owner_id, &credential_id);
+
+		Ok(credential)
+	}
+
+	fn bulk_create_credential(
+		owner_id: &T::AccountId,
+		infos: &[Self::CredentialInfo],
+	) -> Result<Vec<Self::Credential>, Self::Error> {
+		if infos.len() > MAX_BULK_CREDENTIALS as usize {
+			return Err(Error::<T, I>::TooManyCredentials)
+		}
+
+		let mut credentials = Vec::new();
+		for info in infos.iter() {
+			credentials.push(Self::create_credential(owner_id, info)?);
+		}
+
+		Ok(credentials)
+	}
+
+	fn update_credential(
+		owner_id: &T::AccountId,
+		credential_id: &T::Hash,
+		info: &Self::CredentialInfo,
+	) -> Result<Self::Credential, Self::Error> {
+		let mut credential =
+			Credentials::<T, I>::get(credential_id).ok_or(Error::<T, I>::CredentialDoesNotExist)?;
+
+		if &credential.owner_id != owner_id {
+			return Err(Error::<T, I>::NotCredentialOwner)
+		}
+
+		if !info.is_valid() {
+			return Err(Error::<T, I>::InvalidCredentialInfo)
+		}
+
+		credential.info = info.clone();
+		Credentials::<T, I>::insert(credential_id, &credential);
+
+		// Issuer countersignature no longer applies to the updated credential
+		T::CredentialIssuers::remove_credential_verification(credential_id);
+
+		Ok(credential)
+	}
+
+	fn delete_credential(
+		owner_id: &T::AccountId,
+		credential_id: &T::Hash,
+	) -> Result<Self::Credential, Self::Error> {
+		let credential =
+			Credentials::<T, I>::get(credential_id).ok_or(Error::<T, I>::CredentialDoesNotExist)?;
+
+		if &credential.owner_id != owner_id {
+			return Err(Error::<T, I>::NotCredentialOwner)
+		}
+
+		Credentials::<T, I>::remove(credential_id);
+		T::CredentialIssuers::remove_credential_verification(credential_id);
+
+		T::CredentialOwner::disassociate(owner_id, credential_id);
+
+		Self::sub_credentials_count();
+		Self::sub_credential_count_by_owner(owner_id);
+
+		Ok(credential)
+	}
+
+	fn credential_count_by_owner(owner_id: &T::AccountId) -> u64 {
+		CredentialsCountByOwner::<T, I>::get(owner_id)
+	}
+
+	fn credential_by_id(credential_id: &T::Hash) -> Option<Self::Credential> {
+		Credentials::<T, I>::get(credential_id)
+	}
+}
@@ -0,0 +1,28 @@
+use sp_std::vec::Vec;
+
+pub trait CredentialInterface<T: frame_system::Config> {
+	type Error;
+	type Credential;
+	type CredentialInfo;
+
+	fn create_credential(
+		owner_id: &T::AccountId,
+		info: &Self::CredentialInfo,
+	) -> Result<Self::Credential, Self::Error>;
+	fn bulk_create_credential(
+		owner_id: &T::AccountId,
+		infos: &[Self::CredentialInfo],
+	) -> Result<Vec<Self::Credential>, Self::Error>;
+	fn update_credential(
+		owner_id: &T::AccountId,
+		credential_id: &T::Hash,
+		info: &Self::CredentialInfo,
+	) -> Result<Self::Credential, Self::Error>;
+	fn delete_credential(
+		owner_id: &T::AccountId,
+		credential_id: &T::Hash,
+	) -> Result<Self::Credential, Self::Error>;
+
+	fn credential_count_by_owner(owner_id: &T::AccountId) -> u64;
+	fn credential_by_id(credential_id: &T::Hash) -> Option<Self::Credential>;
+}
@@ -0,0 +1,264 @@
+//! Compatibility with the legacy certification and qualification pallets.
+//!
+//! Owner pallets (labs, doctors, hospitals, genetic analysts and health professionals) still
+//! implement the legacy owner traits, and still consume the legacy provider traits, both kept in
+//! `traits_credentials::legacy`. The adapters and provider implementations below let each
+//! instance of this pallet stand in for the legacy pallet it replaces without touching those
+//! owner pallets.
+
+use crate::*;
+use sp_std::marker::PhantomData;
+use traits_credential_issuers::CredentialVerification;
+use traits_credentials::legacy::{
+	CertificationInfo as CertificationInfoT, CertificationOwner, CertificationsProvider,
+	DoctorCertificationInfo, DoctorCertificationOwner, DoctorCertificationsProvider,
+	GeneticAnalystQualificationInfo as GeneticAnalystQualificationInfoT,
+	GeneticAnalystQualificationOwner, GeneticAnalystQualificationsProvider,
+	HealthProfessionalQualificationOwner, HealthProfessionalQualificationProvider,
+	HospitalCertificationInfo, HospitalCertificationOwner, HospitalCertificationsProvider,
+};
+
+pub type CredentialVerificationOf<T> =
+	CredentialVerification<<T as frame_system::Config>::AccountId, BlockNumberOf<T>>;
+pub type BlockNumberOf<T> = <T as frame_system::Config>::BlockNumber;
+
+// ----- Owner adapters -------
+pub struct LegacyCertificationOwner<O>(PhantomData<O>);
+impl<T: frame_system::Config, O: CertificationOwner<T>> CredentialOwner<T>
+	for LegacyCertificationOwner<O>
+{
+	fn can_create_credential(id: &T::AccountId) -> bool {
+		O::can_create_certification(id)
+	}
+	fn associate(owner_id: &T::AccountId, credential_id: &T::Hash) {
+		O::associate(owner_id, credential_id)
+	}
+	fn disassociate(owner_id: &T::AccountId, credential_id: &T::Hash) {
+		O::disassociate(owner_id, credential_id)
+	}
+}
+
+pub struct LegacyDoctorCertificationOwner<O>(PhantomData<O>);
+impl<T: frame_system::Config, O: DoctorCertificationOwner<T>> CredentialOwner<T>
+	for LegacyDoctorCertificationOwner<O>
+{
+	fn can_create_credential(id: &T::AccountId) -> bool {
+		O::can_create_certification(id)
+	}
+	fn associate(owner_id: &T::AccountId, credential_id: &T::Hash) {
+		O::associate(owner_id, credential_id)
+	}
+	fn disassociate(owner_id: &T::AccountId, credential_id: &T::Hash) {
+		O::disassociate(owner_id, credential_id)
+	}
+}
+
+pub struct LegacyHospitalCertificationOwner<O>(PhantomData<O>);
+impl<T: frame_system::Config, O: HospitalCertificationOwner<T>> CredentialOwner<T>
+	for LegacyHospitalCertificationOwner<O>
+{
+	fn can_create_credential(id: &T::AccountId) -> bool {
+		O::can_create_certification(id)
+	}
+	fn associate(owner_id: &T::AccountId, credential_id: &T::Hash) {
+		O::associate(owner_id, credential_id)
+	}
+	fn disassociate(owner_id: &T::AccountId, credential_id: &T::Hash) {
+		O::disassociate(owner_id, credential_id)
+	}
+}
+
+pub struct LegacyGeneticAnalystQualificationOwner<O>(PhantomData<O>);
+impl<T: frame_system::Config, O: GeneticAnalystQualificationOwner<T>> CredentialOwner<T>
+	for LegacyGeneticAnalystQualificationOwner<O>
+{
+	fn can_create_credential(id: &T::AccountId) -> bool {
+		O::can_create_qualification(id)
+	}
+	fn associate(owner_id: &T::AccountId, credential_id: &T::Hash) {
+		O::associate(owner_id, credential_id)
+	}
+	fn disassociate(owner_id: &T::AccountId, credential_id: &T::Hash) {
+		O::disassociate(owner_id, credential_id)
+	}
+}
+
+pub struct LegacyHealthProfessionalQualificationOwner<O>(PhantomData<O>);
+impl<T: frame_system::Config, O: HealthProfessionalQualificationOwner<T>> CredentialOwner<T>
+	for LegacyHealthProfessionalQualificationOwner<O>
+{
+	fn can_create_credential(id: &T::AccountId) -> bool {
+		O::can_create_qualification(id)
+	}
+	fn associate(owner_id: &T::AccountId, credential_id: &T::Hash) {
+		O::associate(owner_id, credential_id)
+	}
+	fn disassociate(owner_id: &T::AccountId, credential_id: &T::Hash) {
+		O::disassociate(owner_id, credential_id)
+	}
+}
+
+// ----- Legacy credential info -------
+impl<T, AccountId, Hash, Info> CertificationInfoT<T> for Credential<AccountId, Hash, Info>
+where
+	T: frame_system::Config<AccountId = AccountId, Hash = Hash>,
+{
+	fn get_id(&self) -> &Hash {
+		self.get_id()
+	}
+	fn get_owner_id(&self) -> &AccountId {
+		self.get_owner_id()
+	}
+}
+
+impl<T, AccountId, Hash, Info> DoctorCertificationInfo<T> for Credential<AccountId, Hash, Info>
+where
+	T: frame_system::Config<AccountId = AccountId, Hash = Hash>,
+{
+	fn get_id(&self) -> &Hash {
+		self.get_id()
+	}
+	fn get_owner_id(&self) -> &AccountId {
+		self.get_owner_id()
+	}
+}
+
+impl<T, AccountId, Hash, Info> HospitalCertificationInfo<T> for Credential<AccountId, Hash, Info>
+where
+	T: frame_system::Config<AccountId = AccountId, Hash = Hash>,
+{
+	fn get_id(&self) -> &Hash {
+		self.get_id()
+	}
+	fn get_owner_id(&self) -> &AccountId {
+		self.get_owner_id()
+	}
+}
+
+impl<T, AccountId, Hash, Info> GeneticAnalystQualificationInfoT<T>
+	for Credential<AccountId, Hash, Info>
+where
+	T: frame_system::Config<AccountId = AccountId, Hash = Hash>,
+{
+	fn get_id(&self) -> &Hash {
+		self.get_id()
+	}
+	fn get_owner_id(&self) -> &AccountId {
+		self.get_owner_id()
+	}
+}
+
+// ----- Legacy providers -------
+impl<T: Config<I>, I: 'static> CertificationsProvider<T> for Pallet<T, I> {
+	type Error = Error<T, I>;
+	type Certification = CredentialOf<T, I>;
+
+	fn delete_certification(
+		owner_id: &T::AccountId,
+		id: &T::Hash,
+	) -> Result<Self::Certification, Self::Error> {
+		<Self as CredentialInterface<T>>::delete_credential(owner_id, id)
+	}
+
+	fn certification_by_id(id: &T::Hash) -> Option<Self::Certification> {
+		<Self as CredentialInterface<T>>::credential_by_id(id)
+	}
+
+	fn certification_verification(id: &T::Hash) -> Option<CredentialVerificationOf<T>> {
+		T::CredentialIssuers::credential_verification(id)
+	}
+
+	fn is_certification_verified(id: &T::Hash) -> bool {
+		T::CredentialIssuers::is_credential_verified(id)
+	}
+}
+
+impl<T: Config<I>, I: 'static> DoctorCertificationsProvider<T> for Pallet<T, I> {
+	type Error = Error<T, I>;
+	type DoctorCertification = CredentialOf<T, I>;
+
+	fn delete_certification(
+		owner_id: &T::AccountId,
+		id: &T::Hash,
+	) -> Result<Self::DoctorCertification, Self::Error> {
+		<Self as CredentialInterface<T>>::delete_credential(owner_id, id)
+	}
+
+	fn certification_by_id(id: &T::Hash) -> Option<Self::DoctorCertification> {
+		<Self as CredentialInterface<T>>::credential_by_id(id)
+	}
+
+	fn certification_verification(id: &T::Hash) -> Option<CredentialVerificationOf<T>> {
+		T::CredentialIssuers::credential_verification(id)
+	}
+
+	fn is_certification_verified(id: &T::Hash) -> bool {
+		T::CredentialIssuers::is_credential_verified(id)
+	}
+}
+
+impl<T: Config<I>, I: 'static> HospitalCertificationsProvider<T> for Pallet<T, I> {
+	type Error = Error<T, I>;
+	type HospitalCertification = CredentialOf<T, I>;
+
+	fn delete_certification(
+		owner_id: &T::AccountId,
+		id: &T::Hash,
+	) -> Result<Self::HospitalCertification, Self::Error> {
+		<Self as CredentialInterface<T>>::delete_credential(owner_id, id)
+	}
+
+	fn certification_by_id(id: &T::Hash) -> Option<Self::HospitalCertification> {
+		<Self as CredentialInterface<T>>::credential_by_id(id)
+	}
+
+	fn certification_verification(id: &T::Hash) -> Option<CredentialVerificationOf<T>> {
+		T::CredentialIssuers::credential_verification(id)
+	}
+
+	fn is_certification_verified(id: &T::Hash) -> bool {
+		T::CredentialIssuers::is_credential_verified(id)
+	}
+}
+
+impl<T: Config<I>, I: 'static> GeneticAnalystQualificationsProvider<T> for Pallet<T, I> {
+	type Error = Error<T, I>;
+	type GeneticAnalystQualification = CredentialOf<T, I>;
+
+	fn delete_qualification(
+		owner_id: &T::AccountId,
+		id: &T::Hash,
+	) -> Result<Self::GeneticAnalystQualification, Self::Error> {
+		<Self as CredentialInterface<T>>::delete_credential(owner_id, id)
+	}
+
+	fn qualification_by_id(id: &T::Hash) -> Option<Self::GeneticAnalystQualification> {
+		<Self as CredentialInterface<T>>::credential_by_id(id)
+	}
+
+	fn qualification_verification(id: &T::Hash) -> Option<CredentialVerificationOf<T>> {
+		T::CredentialIssuers::credential_verification(id)
+	}
+
+	fn is_qualification_verified(id: &T::Hash) -> bool {
+		T::CredentialIssuers::is_credential_verified(id)
+	}
+}
+
+impl<T: Config<I>, I: 'static> HealthProfessionalQualificationProvider<T> for Pallet<T, I> {
+	type Error = Error<T, I>;
+
+	fn delete_qualifications(account_id: &T::AccountId, hash_ids: &[T::Hash]) {
+		for hash_id in hash_ids.iter() {
+			let _ = <Self as CredentialInterface<T>>::delete_credential(account_id, hash_id);
+		}
+	}
+
+	fn qualification_verification(id: &T::Hash) -> Option<CredentialVerificationOf<T>> {
+		T::CredentialIssuers::credential_verification(id)
+	}
+
+	fn is_qualification_verified(id: &T::Hash) -> bool {
+		T::CredentialIssuers::is_credential_verified(id)
+	}
+}
@@ -0,0 +1,191 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+
+pub use pallet::*;
+
+#[cfg(test)]
+mod mock;
+
+#[cfg(test)]
+mod tests;
+
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+
+pub mod functions;
+pub mod impl_credentials;
+pub mod interface;
+pub mod legacy;
+pub mod migrations;
+pub mod types;
+pub mod weights;
+
+pub use interface::CredentialInterface;
+pub use types::*;
+
+use frame_support::traits::StorageVersion;
+use traits_credential_issuers::CredentialIssuersProvider;
+use traits_credentials::CredentialOwner;
+use weights::WeightInfo;
+
+/// The current storage version.
+const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
+
+/// Maximum number of credentials created by one bulk_create_credential call.
+pub const MAX_BULK_CREDENTIALS: u32 = 20;
+
+#[frame_support::pallet]
+pub mod pallet {
+	use super::*;
+
+	use frame_support::{dispatch::DispatchResultWithPostInfo, pallet_prelude::*};
+	use frame_system::pallet_prelude::*;
+	use sp_std::vec::Vec;
+
+	#[pallet::config]
+	pub trait Config<I: 'static = ()>: frame_system::Config {
+		type RuntimeEvent: From<Event<Self, I>>
+			+ IsType<<Self as frame_system::Config>::RuntimeEvent>;
+		type CredentialInfo: Parameter + Member + Default + MaxEncodedLen + CredentialInfoValidation;
+		type CredentialOwner: CredentialOwner<Self>;
+		type CredentialIssuers: CredentialIssuersProvider<Self>;
+		type WeightInfo: WeightInfo;
+		#[cfg(feature = "runtime-benchmarks")]
+		type BenchmarkHelper: BenchmarkHelper<Self::AccountId, Self::CredentialInfo>;
+	}
+
+	// ----- This is template code, every pallet needs this ---
+	#[pallet::pallet]
+	#[pallet::storage_version(STORAGE_VERSION)]
+	#[pallet::generate_store(pub(super) trait Store)]
+	pub struct Pallet<T, I = ()>(_);
+
+	#[pallet::hooks]
+	impl<T: Config<I>, I: 'static> Hooks<BlockNumberFor<T>> for Pallet<T, I> {}
+	// --------------------------------------------------------
+
+	// ----- Types -------
+	pub type AccountIdOf<T> = <T as frame_system::Config>::AccountId;
+	pub type HashOf<T> = <T as frame_system::Config>::Hash;
+	pub type CredentialInfoOf<T, I = ()> = <T as Config<I>>::CredentialInfo;
+	pub type CredentialOf<T, I = ()> =
+		Credential<AccountIdOf<T>, HashOf<T>, CredentialInfoOf<T, I>>;
+
+	// ------- Storage -------------
+	#[pallet::storage]
+	#[pallet::getter(fn credential_by_id)]
+	pub type Credentials<T: Config<I>, I: 'static = ()> =
+		StorageMap<_, Blake2_128Concat, HashOf<T>, CredentialOf<T, I>>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn credentials_count)]
+	pub type CredentialsCount<T: Config<I>, I: 'static = ()> = StorageValue<_, u64, ValueQuery>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn credential_count_by_owner)]
+	pub type CredentialsCountByOwner<T: Config<I>, I: 'static = ()> =
+		StorageMap<_, Blake2_128Concat, AccountIdOf<T>, u64, ValueQuery>;
+	// -----------------------------
+
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(super) fn deposit_event)]
+	pub enum Event<T: Config<I>, I: 'static = ()> {
+		/// Credential created
+		/// parameters, [Credential, who]
+		CredentialCreated(CredentialOf<T, I>, AccountIdOf<T>),
+		/// Credential updated
+		/// parameters, [Credential, who]
+		CredentialUpdated(CredentialOf<T, I>, AccountIdOf<T>),
+		/// Credential deleted
+		/// parameters, [Credential, who]
+		CredentialDeleted(CredentialOf<T, I>, AccountIdOf<T>),
+	}
+
+	// Errors inform users that something went wrong.
+	#[pallet::error]
+	pub enum Error<T, I = ()> {
+		/// User not allowed to create credential
+		NotAllowedToCreate,
+		/// User is not the owner of a credential
+		NotCredentialOwner,
+		/// Credential does not exist
+		CredentialDoesNotExist,
+		/// Credential information is not valid for this instance
+		InvalidCredentialInfo,
+		/// Cannot create more than MAX_BULK_CREDENTIALS credentials at once
+		TooManyCredentials,
+	}
+
+	// Call indices: 0 => create, 1 => update, 2 => delete, 3 => bulk create.
+	// They match the legacy certification pallets, but not the legacy genetic analyst
+	// qualifications pallet (0 => create, 1 => bulk create, 2 => update, 3 => delete), which is
+	// why transaction_version was bumped when the credentials pallet replaced them.
+	#[pallet::call]
+	impl<T: Config<I>, I: 'static> Pallet<T, I> {
+		#[pallet::weight(T::WeightInfo::create_credential())]
+		pub fn create_credential(
+			origin: OriginFor<T>,
+			info: CredentialInfoOf<T, I>,
+		) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+
+			match <Self as CredentialInterface<T>>::create_credential(&who, &info) {
+				Ok(credential) => {
+					Self::deposit_event(Event::CredentialCreated(credential, who));
+					Ok(().into())
+				},
+				Err(error) => Err(error.into()),
+			}
+		}
+
+		#[pallet::weight(T::WeightInfo::update_credential())]
+		pub fn update_credential(
+			origin: OriginFor<T>,
+			credential_id: HashOf<T>,
+			info: CredentialInfoOf<T, I>,
+		) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+
+			match <Self as CredentialInterface<T>>::update_credential(&who, &credential_id, &info) {
+				Ok(credential) => {
+					Self::deposit_event(Event::CredentialUpdated(credential, who));
+					Ok(().into())
+				},
+				Err(error) => Err(error.into()),
+			}
+		}
+
+		#[pallet::weight(T::WeightInfo::delete_credential())]
+		pub fn delete_credential(
+			origin: OriginFor<T>,
+			credential_id: HashOf<T>,
+		) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+
+			match <Self as CredentialInterface<T>>::delete_credential(&who, &credential_id) {
+				Ok(credential) => {
+					Self::deposit_event(Event::CredentialDeleted(credential, who));
+					Ok(().into())
+				},
+				Err(error) => Err(error.into()),
+			}
+		}
+
+		#[pallet::weight(T::WeightInfo::bulk_create_credential(infos.len() as u32))]
+		pub fn bulk_create_credential(
+			origin: OriginFor<T>,
+			infos: Vec<CredentialInfoOf<T, I>>,
+		) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+
+			match <Self as CredentialInterface<T>>::bulk_create_credential(&who, &infos) {
+				Ok(credentials) => {
+					for credential in credentials {
+						Self::deposit_event(Event::CredentialCreated(credential, who.clone()));
+					}
+					Ok(().into())
+				},
+				Err(error) => Err(error.into()),
+			}
+		}
+	}
+}
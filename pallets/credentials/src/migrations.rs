@@ -0,0 +1,219 @@
+use crate::*;
+use frame_support::{
+	codec::{Decode, Encode},
+	log,
+	pallet_prelude::Weight,
+	storage::migration::{storage_key_iter, take_storage_value},
+	traits::{Get, OnRuntimeUpgrade, PalletInfoAccess},
+	Blake2_128Concat, BoundedVec,
+};
+use sp_std::{marker::PhantomData, vec::Vec};
+
+/// Storage item names of a legacy certification or qualification pallet.
+///
+/// The instance replacing a legacy pallet keeps its name in `construct_runtime!`, so only the
+/// item names under the pallet prefix differ.
+pub trait LegacyStorage {
+	const CREDENTIALS: &'static [u8];
+	const CREDENTIALS_COUNT: &'static [u8];
+	const CREDENTIALS_COUNT_BY_OWNER: &'static [u8];
+}
+
+pub struct LegacyCertifications;
+impl LegacyStorage for LegacyCertifications {
+	const CREDENTIALS: &'static [u8] = b"Certifications";
+	const CREDENTIALS_COUNT: &'static [u8] = b"CertificationsCount";
+	const CREDENTIALS_COUNT_BY_OWNER: &'static [u8] = b"CertificationsCountByOwner";
+}
+
+pub struct LegacyDoctorCertifications;
+impl LegacyStorage for LegacyDoctorCertifications {
+	const CREDENTIALS: &'static [u8] = b"DoctorCertifications";
+	const CREDENTIALS_COUNT: &'static [u8] = b"DoctorCertificationsCount";
+	const CREDENTIALS_COUNT_BY_OWNER: &'static [u8] = b"DoctorCertificationsCountByOwner";
+}
+
+pub struct LegacyHospitalCertifications;
+impl LegacyStorage for LegacyHospitalCertifications {
+	const CREDENTIALS: &'static [u8] = b"HospitalCertifications";
+	const CREDENTIALS_COUNT: &'static [u8] = b"HospitalCertificationsCount";
+	const CREDENTIALS_COUNT_BY_OWNER: &'static [u8] = b"HospitalCertificationsCountByOwner";
+}
+
+pub struct LegacyGeneticAnalystQualifications;
+impl LegacyStorage for LegacyGeneticAnalystQualifications {
+	const CREDENTIALS: &'static [u8] = b"GeneticAnalystQualifications";
+	const CREDENTIALS_COUNT: &'static [u8] = b"GeneticAnalystQualificationsCount";
+	const CREDENTIALS_COUNT_BY_OWNER: &'static [u8] = b"GeneticAnalystQualificationsCountByOwner";
+}
+
+pub struct LegacyHealthProfessionalQualifications;
+impl LegacyStorage for LegacyHealthProfessionalQualifications {
+	const CREDENTIALS: &'static [u8] = b"HealthProfessionalQualifications";
+	const CREDENTIALS_COUNT: &'static [u8] = b"HealthProfessionalQualificationCount";
+	const CREDENTIALS_COUNT_BY_OWNER: &'static [u8] =
+		b"HealthProfessionalQualificationCountByOwner";
+}
+
+#[derive(Encode, Decode)]
+pub struct LegacyCertificationInfo {
+	pub title: Vec<u8>,
+	pub issuer: Vec<u8>,
+	pub month: Vec<u8>,
+	pub year: Vec<u8>,
+	pub description: Vec<u8>,
+	pub supporting_document: Option<Vec<u8>>,
+}
+
+#[derive(Encode, Decode)]
+pub struct LegacyExperience {
+	pub title: Vec<u8>,
+}
+
+#[derive(Encode, Decode)]
+pub struct LegacyGeneticAnalystQualificationInfo {
+	pub experience: Vec<LegacyExperience>,
+	pub certification: Option<Vec<LegacyCertificationInfo>>,
+}
+
+#[derive(Encode, Decode)]
+pub struct LegacyHealthProfessionalQualificationInfo {
+	pub experiences: Vec<LegacyExperience>,
+	pub certifications: Vec<LegacyCertificationInfo>,
+}
+
+/// Credential information of an instance, converted from the unbounded layout of its legacy
+/// pallet. Legacy values that exceed a bound are truncated and logged.
+pub trait FromLegacyInfo: Sized {
+	type LegacyInfo: Decode;
+
+	fn from_legacy(info: Self::LegacyInfo) -> Self;
+}
+
+impl FromLegacyInfo for CertificationInfo {
+	type LegacyInfo = LegacyCertificationInfo;
+
+	fn from_legacy(info: Self::LegacyInfo) -> Self {
+		CertificationInfo {
+			title: bounded(info.title, "certification title"),
+			issuer: bounded(info.issuer, "certification issuer"),
+			month: bounded(info.month, "certification month"),
+			year: bounded(info.year, "certification year"),
+			description: bounded(info.description, "certification description"),
+			supporting_document: info
+				.supporting_document
+				.map(|document| bounded(document, "certification supporting document")),
+		}
+	}
+}
+
+impl FromLegacyInfo for GeneticAnalystQualificationInfo {
+	type LegacyInfo = LegacyGeneticAnalystQualificationInfo;
+
+	fn from_legacy(info: Self::LegacyInfo) -> Self {
+		GeneticAnalystQualificationInfo {
+			experience: bounded_experiences(info.experience),
+			certification: info.certification.map(bounded_certifications),
+		}
+	}
+}
+
+impl FromLegacyInfo for HealthProfessionalQualificationInfo {
+	type LegacyInfo = LegacyHealthProfessionalQualificationInfo;
+
+	fn from_legacy(info: Self::LegacyInfo) -> Self {
+		HealthProfessionalQualificationInfo {
+			experiences: bounded_experiences(info.experiences),
+			certifications: bounded_certifications(info.certifications),
+		}
+	}
+}
+
+/// Truncates `value` to the bound and logs the entry when it did not fit.
+fn bounded<V, S: Get<u32>>(value: Vec<V>, entry: &str) -> BoundedVec<V, S> {
+	if value.len() > S::get() as usize {
+		log::warn!(
+			target: "runtime::credentials",
+			"{} exceeds its bound of {}, truncated from {} items",
+			entry,
+			S::get(),
+			value.len(),
+		);
+	}
+
+	BoundedVec::truncate_from(value)
+}
+
+fn bounded_experiences(experiences: Vec<LegacyExperience>) -> ExperienceList {
+	let experiences: Vec<Experience> = experiences
+		.into_iter()
+		.map(|experience| Experience { title: bounded(experience.title, "experience title") })
+		.collect();
+
+	bounded(experiences, "experiences")
+}
+
+fn bounded_certifications(certifications: Vec<LegacyCertificationInfo>) -> CertificationInfoList {
+	let certifications: Vec<CertificationInfo> =
+		certifications.into_iter().map(CertificationInfo::from_legacy).collect();
+
+	bounded(certifications, "certifications")
+}
+
+pub type LegacyCredentialOf<T, I> =
+	Credential<AccountIdOf<T>, HashOf<T>, <CredentialInfoOf<T, I> as FromLegacyInfo>::LegacyInfo>;
+
+/// Moves the data of legacy pallet `L` into instance `I` of this pallet.
+///
+/// Legacy records encode as `{ id, owner_id, info }`, the same layout as [`Credential`], with
+/// the unbounded info of the legacy pallet converted through [`FromLegacyInfo`].
+pub struct MigrateFromLegacy<T, I, L>(PhantomData<(T, I, L)>);
+
+impl<T: Config<I>, I: 'static, L: LegacyStorage> OnRuntimeUpgrade for MigrateFromLegacy<T, I, L>
+where
+	CredentialInfoOf<T, I>: FromLegacyInfo,
+{
+	fn on_runtime_upgrade() -> Weight {
+		let mut weight = T::DbWeight::get().reads(1);
+
+		if StorageVersion::get::<Pallet<T, I>>() >= 1 {
+			return weight
+		}
+
+		let pallet_name = <Pallet<T, I> as PalletInfoAccess>::name().as_bytes();
+
+		for (credential_id, credential) in storage_key_iter::<
+			HashOf<T>,
+			LegacyCredentialOf<T, I>,
+			Blake2_128Concat,
+		>(pallet_name, L::CREDENTIALS)
+		.drain()
+		{
+			let credential = Credential::new(
+				credential.id,
+				credential.owner_id,
+				CredentialInfoOf::<T, I>::from_legacy(credential.info),
+			);
+			Credentials::<T, I>::insert(credential_id, credential);
+			weight = weight.saturating_add(T::DbWeight::get().reads_writes(1, 2));
+		}
+
+		for (owner_id, count) in storage_key_iter::<AccountIdOf<T>, u64, Blake2_128Concat>(
+			pallet_name,
+			L::CREDENTIALS_COUNT_BY_OWNER,
+		)
+		.drain()
+		{
+			CredentialsCountByOwner::<T, I>::insert(owner_id, count);
+			weight = weight.saturating_add(T::DbWeight::get().reads_writes(1, 2));
+		}
+
+		if let Some(count) = take_storage_value::<u64>(pallet_name, L::CREDENTIALS_COUNT, &[]) {
+			CredentialsCount::<T, I>::put(count);
+		}
+
+		StorageVersion::new(1).put::<Pallet<T, I>>();
+
+		weight.saturating_add(T::DbWeight::get().reads_writes(1, 3))
+	}
+}
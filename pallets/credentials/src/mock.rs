@@ -0,0 +1,134 @@
+use crate as credentials;
+use frame_support::parameter_types;
+use sp_core::H256;
+use sp_io::TestExternalities;
+use sp_runtime::{
+	testing::Header,
+	traits::{AccountIdLookup, BlakeTwo256},
+};
+use traits_credentials::CredentialOwner;
+
+type UncheckedExtrinsic = frame_system::mocking::MockUncheckedExtrinsic<Test>;
+type Block = frame_system::mocking::MockBlock<Test>;
+
+pub type AccountId = u64;
+
+frame_support::construct_runtime!(
+	pub enum Test where
+		Block = Block,
+		NodeBlock = Block,
+		UncheckedExtrinsic = UncheckedExtrinsic,
+	{
+		System: frame_system,
+		Credentials: credentials,
+		Qualifications: credentials::<Instance2>,
+		HealthProfessionalQualifications: credentials::<Instance3>,
+		CredentialIssuers: credential_issuers,
+	}
+);
+
+parameter_types! {
+	pub const BlockHashCount: u64 = 250;
+	pub const SS58Prefix: u8 = 42;
+}
+
+impl frame_system::Config for Test {
+	type BaseCallFilter = frame_support::traits::Everything;
+	type BlockWeights = ();
+	type BlockLength = ();
+	type AccountId = AccountId;
+	type RuntimeCall = RuntimeCall;
+	type Lookup = AccountIdLookup<AccountId, ()>;
+	type Index = u64;
+	type BlockNumber = u64;
+	type Hash = H256;
+	type Hashing = BlakeTwo256;
+	type Header = Header;
+	type RuntimeEvent = RuntimeEvent;
+	type RuntimeOrigin = RuntimeOrigin;
+	type BlockHashCount = BlockHashCount;
+	type DbWeight = ();
+	type Version = ();
+	type PalletInfo = PalletInfo;
+	type OnNewAccount = ();
+	type OnKilledAccount = ();
+	type AccountData = ();
+	type SystemWeightInfo = ();
+	type SS58Prefix = SS58Prefix;
+	type OnSetCode = ();
+	type MaxConsumers = frame_support::traits::ConstU32<16>;
+}
+
+parameter_types! {
+	pub static OwnedCredentials: Vec<(AccountId, H256)> = Vec::new();
+}
+
+/// Account that is not allowed to own credentials
+pub const UNREGISTERED_ACCOUNT: AccountId = 9;
+
+pub struct CredentialOwnerMock;
+impl CredentialOwner<Test> for CredentialOwnerMock {
+	fn can_create_credential(id: &AccountId) -> bool {
+		*id != UNREGISTERED_ACCOUNT
+	}
+
+	fn associate(owner_id: &AccountId, credential_id: &H256) {
+		let mut owned_credentials = OwnedCredentials::get();
+		owned_credentials.push((*owner_id, *credential_id));
+		OwnedCredentials::set(owned_credentials);
+	}
+
+	fn disassociate(owner_id: &AccountId, credential_id: &H256) {
+		let mut owned_credentials = OwnedCredentials::get();
+		owned_credentials.retain(|owned| owned != &(*owner_id, *credential_id));
+		OwnedCredentials::set(owned_credentials);
+	}
+}
+
+impl credentials::Config for Test {
+	type RuntimeEvent = RuntimeEvent;
+	type CredentialInfo = credentials::CertificationInfo;
+	type CredentialOwner = CredentialOwnerMock;
+	type CredentialIssuers = CredentialIssuers;
+	type WeightInfo = ();
+	#[cfg(feature = "runtime-benchmarks")]
+	type BenchmarkHelper = ();
+}
+
+impl credentials::Config<credentials::Instance2> for Test {
+	type RuntimeEvent = RuntimeEvent;
+	type CredentialInfo = credentials::GeneticAnalystQualificationInfo;
+	type CredentialOwner = CredentialOwnerMock;
+	type CredentialIssuers = CredentialIssuers;
+	type WeightInfo = ();
+	#[cfg(feature = "runtime-benchmarks")]
+	type BenchmarkHelper = ();
+}
+
+impl credentials::Config<credentials::Instance3> for Test {
+	type RuntimeEvent = RuntimeEvent;
+	type CredentialInfo = credentials::HealthProfessionalQualificationInfo;
+	type CredentialOwner = CredentialOwnerMock;
+	type CredentialIssuers = CredentialIssuers;
+	type WeightInfo = ();
+	#[cfg(feature = "runtime-benchmarks")]
+	type BenchmarkHelper = ();
+}
+
+impl credential_issuers::Config for Test {
+	type RuntimeEvent = RuntimeEvent;
+	type RegistrarOrigin = frame_system::EnsureRoot<AccountId>;
+	type Credentials = (Credentials, Qualifications, HealthProfessionalQualifications);
+	type CredentialIssuersWeightInfo = ();
+	#[cfg(feature = "runtime-benchmarks")]
+	type BenchmarkHelper = ();
+}
+
+pub struct ExternalityBuilder;
+
+impl ExternalityBuilder {
+	pub fn build() -> TestExternalities {
+		let storage = frame_system::GenesisConfig::default().build_storage::<Test>().unwrap();
+		TestExternalities::from(storage)
+	}
+}
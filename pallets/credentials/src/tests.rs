@@ -0,0 +1,367 @@
+use crate::{
+	migrations::{LegacyCertificationInfo, LegacyCertifications, MigrateFromLegacy},
+	mock::*,
+	CertificationInfo, Credential, Error, Experience, ExperienceList,
+	GeneticAnalystQualificationInfo, HealthProfessionalQualificationInfo, MAX_BULK_CREDENTIALS,
+	MAX_CREDENTIAL_TEXT_LENGTH,
+};
+use frame_support::{
+	assert_noop, assert_ok,
+	codec::Encode,
+	storage::migration::{put_storage_value, storage_key_iter},
+	traits::{OnRuntimeUpgrade, StorageVersion},
+	Blake2_128Concat, StorageHasher,
+};
+use sp_core::H256;
+use traits_credential_issuers::CredentialIssuersProvider;
+use traits_credentials::legacy::HealthProfessionalQualificationProvider;
+
+fn certification_info(title: &str) -> CertificationInfo {
+	CertificationInfo {
+		title: title.as_bytes().to_vec().try_into().unwrap(),
+		issuer: "DeBio issuer".as_bytes().to_vec().try_into().unwrap(),
+		month: "DeBio month".as_bytes().to_vec().try_into().unwrap(),
+		year: "DeBio year".as_bytes().to_vec().try_into().unwrap(),
+		description: "DeBio description".as_bytes().to_vec().try_into().unwrap(),
+		supporting_document: Some(
+			"DeBio Profile Image uwu".as_bytes().to_vec().try_into().unwrap(),
+		),
+	}
+}
+
+fn experiences(title: &str) -> ExperienceList {
+	vec![Experience { title: title.as_bytes().to_vec().try_into().unwrap() }]
+		.try_into()
+		.unwrap()
+}
+
+#[test]
+fn create_credential_works() {
+	ExternalityBuilder::build().execute_with(|| {
+		assert_ok!(Credentials::create_credential(
+			RuntimeOrigin::signed(1),
+			certification_info("DeBio title")
+		));
+
+		let (owner_id, credential_id) = OwnedCredentials::get()[0];
+
+		assert_eq!(owner_id, 1);
+		assert_eq!(
+			Credentials::credential_by_id(credential_id),
+			Some(Credential::new(credential_id, 1, certification_info("DeBio title")))
+		);
+		assert_eq!(Credentials::credentials_count(), 1);
+		assert_eq!(Credentials::credential_count_by_owner(1), 1);
+	})
+}
+
+#[test]
+fn cant_create_credential_when_not_allowed() {
+	ExternalityBuilder::build().execute_with(|| {
+		assert_noop!(
+			Credentials::create_credential(
+				RuntimeOrigin::signed(UNREGISTERED_ACCOUNT),
+				certification_info("DeBio title")
+			),
+			Error::<Test>::NotAllowedToCreate
+		);
+	})
+}
+
+#[test]
+fn update_credential_works() {
+	ExternalityBuilder::build().execute_with(|| {
+		assert_ok!(Credentials::create_credential(
+			RuntimeOrigin::signed(1),
+			certification_info("DeBio title")
+		));
+
+		let (_, credential_id) = OwnedCredentials::get()[0];
+
+		assert_ok!(Credentials::update_credential(
+			RuntimeOrigin::signed(1),
+			credential_id,
+			certification_info("DeBio title 2")
+		));
+
+		assert_eq!(
+			Credentials::credential_by_id(credential_id),
+			Some(Credential::new(credential_id, 1, certification_info("DeBio title 2")))
+		);
+
+		assert_noop!(
+			Credentials::update_credential(
+				RuntimeOrigin::signed(2),
+				credential_id,
+				certification_info("DeBio title 3")
+			),
+			Error::<Test>::NotCredentialOwner
+		);
+
+		assert_noop!(
+			Credentials::update_credential(
+				RuntimeOrigin::signed(1),
+				H256::repeat_byte(1),
+				certification_info("DeBio title 3")
+			),
+			Error::<Test>::CredentialDoesNotExist
+		);
+	})
+}
+
+#[test]
+fn delete_credential_works() {
+	ExternalityBuilder::build().execute_with(|| {
+		assert_ok!(Credentials::create_credential(
+			RuntimeOrigin::signed(1),
+			certification_info("DeBio title")
+		));
+
+		let (_, credential_id) = OwnedCredentials::get()[0];
+
+		assert_ok!(CredentialIssuers::register_issuer(
+			RuntimeOrigin::root(),
+			3,
+			"DeBio Accreditation Body".as_bytes().to_vec()
+		));
+		assert_ok!(CredentialIssuers::verify_credential(RuntimeOrigin::signed(3), credential_id));
+
+		assert_noop!(
+			Credentials::delete_credential(RuntimeOrigin::signed(2), credential_id),
+			Error::<Test>::NotCredentialOwner
+		);
+
+		assert_noop!(
+			Credentials::delete_credential(RuntimeOrigin::signed(1), H256::repeat_byte(1)),
+			Error::<Test>::CredentialDoesNotExist
+		);
+
+		assert_ok!(Credentials::delete_credential(RuntimeOrigin::signed(1), credential_id));
+
+		assert_eq!(Credentials::credential_by_id(credential_id), None);
+		assert_eq!(Credentials::credentials_count(), 0);
+		assert_eq!(Credentials::credential_count_by_owner(1), 0);
+		assert_eq!(OwnedCredentials::get(), Vec::new());
+		assert!(!<CredentialIssuers as CredentialIssuersProvider<Test>>::is_credential_verified(
+			&credential_id
+		));
+	})
+}
+
+#[test]
+fn delete_qualifications_only_deletes_owned_qualifications() {
+	ExternalityBuilder::build().execute_with(|| {
+		let info = HealthProfessionalQualificationInfo {
+			experiences: experiences("DeBio experience"),
+			certifications: vec![certification_info("DeBio title")].try_into().unwrap(),
+		};
+
+		assert_ok!(HealthProfessionalQualifications::create_credential(
+			RuntimeOrigin::signed(1),
+			info.clone()
+		));
+		assert_ok!(HealthProfessionalQualifications::create_credential(
+			RuntimeOrigin::signed(2),
+			info
+		));
+
+		let (_, owned_id) = OwnedCredentials::get()[0];
+		let (_, other_id) = OwnedCredentials::get()[1];
+
+		<HealthProfessionalQualifications as HealthProfessionalQualificationProvider<Test>>::delete_qualifications(
+			&1,
+			&[owned_id, other_id, H256::repeat_byte(1)],
+		);
+
+		assert_eq!(HealthProfessionalQualifications::credential_by_id(owned_id), None);
+		assert!(HealthProfessionalQualifications::credential_by_id(other_id).is_some());
+		assert_eq!(HealthProfessionalQualifications::credentials_count(), 1);
+		assert_eq!(HealthProfessionalQualifications::credential_count_by_owner(1), 0);
+		assert_eq!(HealthProfessionalQualifications::credential_count_by_owner(2), 1);
+		assert_eq!(OwnedCredentials::get(), vec![(2, other_id)]);
+	})
+}
+
+#[test]
+fn instances_keep_separate_storage_and_validation() {
+	ExternalityBuilder::build().execute_with(|| {
+		assert_noop!(
+			Qualifications::create_credential(
+				RuntimeOrigin::signed(1),
+				GeneticAnalystQualificationInfo {
+					experience: ExperienceList::default(),
+					certification: None,
+				}
+			),
+			Error::<Test, crate::Instance2>::InvalidCredentialInfo
+		);
+
+		assert_ok!(Qualifications::create_credential(
+			RuntimeOrigin::signed(1),
+			GeneticAnalystQualificationInfo {
+				experience: experiences("DeBio experience"),
+				certification: Some(vec![certification_info("DeBio title")].try_into().unwrap()),
+			}
+		));
+
+		assert_eq!(Qualifications::credentials_count(), 1);
+		assert_eq!(Credentials::credentials_count(), 0);
+	})
+}
+
+#[test]
+fn issuer_can_verify_credential_of_any_instance() {
+	ExternalityBuilder::build().execute_with(|| {
+		assert_ok!(CredentialIssuers::register_issuer(
+			RuntimeOrigin::root(),
+			3,
+			"DeBio Accreditation Body".as_bytes().to_vec()
+		));
+
+		assert_noop!(
+			CredentialIssuers::verify_credential(RuntimeOrigin::signed(3), H256::repeat_byte(1)),
+			credential_issuers::Error::<Test>::CredentialNotFound
+		);
+
+		assert_ok!(Qualifications::create_credential(
+			RuntimeOrigin::signed(1),
+			GeneticAnalystQualificationInfo {
+				experience: experiences("DeBio experience"),
+				certification: None,
+			}
+		));
+
+		let (_, credential_id) = OwnedCredentials::get()[0];
+
+		assert_ok!(CredentialIssuers::verify_credential(RuntimeOrigin::signed(3), credential_id));
+		assert!(<CredentialIssuers as CredentialIssuersProvider<Test>>::is_credential_verified(
+			&credential_id
+		));
+	})
+}
+
+#[test]
+fn migrate_from_legacy_works() {
+	ExternalityBuilder::build().execute_with(|| {
+		StorageVersion::new(0).put::<Credentials>();
+
+		let credential_id = H256::repeat_byte(1);
+		let legacy = Credential::new(credential_id, 1u64, certification_info("DeBio title"));
+
+		put_storage_value(
+			b"Credentials",
+			b"Certifications",
+			&Blake2_128Concat::hash(&credential_id.encode()),
+			&legacy,
+		);
+		put_storage_value(b"Credentials", b"CertificationsCount", &[], 1u64);
+		put_storage_value(
+			b"Credentials",
+			b"CertificationsCountByOwner",
+			&Blake2_128Concat::hash(&1u64.encode()),
+			1u64,
+		);
+
+		MigrateFromLegacy::<Test, (), LegacyCertifications>::on_runtime_upgrade();
+
+		assert_eq!(Credentials::credential_by_id(credential_id), Some(legacy));
+		assert_eq!(Credentials::credentials_count(), 1);
+		assert_eq!(Credentials::credential_count_by_owner(1), 1);
+		assert_eq!(StorageVersion::get::<Credentials>(), StorageVersion::new(1));
+		assert_eq!(
+			storage_key_iter::<H256, Credential<u64, H256, CertificationInfo>, Blake2_128Concat>(
+				b"Credentials",
+				b"Certifications"
+			)
+			.count(),
+			0
+		);
+	})
+}
+
+#[test]
+fn migrate_from_legacy_truncates_values_over_their_bound() {
+	ExternalityBuilder::build().execute_with(|| {
+		StorageVersion::new(0).put::<Credentials>();
+
+		let credential_id = H256::repeat_byte(1);
+		let legacy = Credential::new(
+			credential_id,
+			1u64,
+			LegacyCertificationInfo {
+				title: vec![b'X'; MAX_CREDENTIAL_TEXT_LENGTH as usize + 1],
+				issuer: "DeBio issuer".as_bytes().to_vec(),
+				month: "DeBio month".as_bytes().to_vec(),
+				year: "DeBio year".as_bytes().to_vec(),
+				description: "DeBio description".as_bytes().to_vec(),
+				supporting_document: None,
+			},
+		);
+
+		put_storage_value(
+			b"Credentials",
+			b"Certifications",
+			&Blake2_128Concat::hash(&credential_id.encode()),
+			&legacy,
+		);
+
+		MigrateFromLegacy::<Test, (), LegacyCertifications>::on_runtime_upgrade();
+
+		let credential = Credentials::credential_by_id(credential_id).unwrap();
+
+		assert_eq!(credential.info.title.len(), MAX_CREDENTIAL_TEXT_LENGTH as usize);
+		assert_eq!(credential.info.issuer.to_vec(), "DeBio issuer".as_bytes().to_vec());
+	})
+}
+
+#[test]
+fn bulk_create_credential_works() {
+	ExternalityBuilder::build().execute_with(|| {
+		assert_ok!(Credentials::bulk_create_credential(
+			RuntimeOrigin::signed(1),
+			vec![certification_info("DeBio title"), certification_info("DeBio title 2")]
+		));
+
+		let owned_credentials = OwnedCredentials::get();
+
+		assert_eq!(owned_credentials.len(), 2);
+		assert_ne!(owned_credentials[0].1, owned_credentials[1].1);
+		assert_eq!(Credentials::credentials_count(), 2);
+		assert_eq!(Credentials::credential_count_by_owner(1), 2);
+	})
+}
+
+#[test]
+fn cant_bulk_create_more_than_max_credentials() {
+	ExternalityBuilder::build().execute_with(|| {
+		assert_noop!(
+			Credentials::bulk_create_credential(
+				RuntimeOrigin::signed(1),
+				vec![certification_info("DeBio title"); MAX_BULK_CREDENTIALS as usize + 1]
+			),
+			Error::<Test>::TooManyCredentials
+		);
+	})
+}
+
+#[test]
+fn bulk_create_credential_is_all_or_nothing() {
+	ExternalityBuilder::build().execute_with(|| {
+		assert_noop!(
+			Qualifications::bulk_create_credential(
+				RuntimeOrigin::signed(1),
+				vec![
+					GeneticAnalystQualificationInfo {
+						experience: experiences("DeBio experience"),
+						certification: None,
+					},
+					GeneticAnalystQualificationInfo {
+						experience: ExperienceList::default(),
+						certification: None,
+					},
+				]
+			),
+			Error::<Test, crate::Instance2>::InvalidCredentialInfo
+		);
+	})
+}
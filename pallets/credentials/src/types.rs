@@ -0,0 +1,114 @@
+use frame_support::{
+	pallet_prelude::{ConstU32, Decode, Encode, MaxEncodedLen},
+	BoundedVec, RuntimeDebug,
+};
+use scale_info::TypeInfo;
+use traits_credentials::CredentialInfo as CredentialInfoT;
+
+/// Longest title, issuer, month, year or experience of a credential
+pub const MAX_CREDENTIAL_TEXT_LENGTH: u32 = 256;
+/// Longest description or supporting document link of a credential
+pub const MAX_CREDENTIAL_DESCRIPTION_LENGTH: u32 = 1024;
+/// Most experiences or certifications listed by one qualification
+pub const MAX_CREDENTIAL_ENTRIES: u32 = 20;
+
+pub type CredentialText = BoundedVec<u8, ConstU32<MAX_CREDENTIAL_TEXT_LENGTH>>;
+pub type CredentialDescription = BoundedVec<u8, ConstU32<MAX_CREDENTIAL_DESCRIPTION_LENGTH>>;
+pub type ExperienceList = BoundedVec<Experience, ConstU32<MAX_CREDENTIAL_ENTRIES>>;
+pub type CertificationInfoList = BoundedVec<CertificationInfo, ConstU32<MAX_CREDENTIAL_ENTRIES>>;
+
+/// Validation applied to credential information before it is stored
+pub trait CredentialInfoValidation {
+	fn is_valid(&self) -> bool;
+}
+
+/// Registers credential owners and builds valid credential information for the benchmarks
+#[cfg(feature = "runtime-benchmarks")]
+pub trait BenchmarkHelper<AccountId, CredentialInfo> {
+	fn register_owner(owner_id: &AccountId);
+	fn credential_info() -> CredentialInfo;
+}
+#[cfg(feature = "runtime-benchmarks")]
+impl<AccountId, CredentialInfo: Default> BenchmarkHelper<AccountId, CredentialInfo> for () {
+	fn register_owner(_owner_id: &AccountId) {}
+	fn credential_info() -> CredentialInfo {
+		CredentialInfo::default()
+	}
+}
+
+/// Certification information used by lab, doctor and hospital certifications
+#[derive(Encode, Decode, Clone, Default, RuntimeDebug, PartialEq, Eq, TypeInfo, MaxEncodedLen)]
+pub struct CertificationInfo {
+	pub title: CredentialText,
+	pub issuer: CredentialText,
+	pub month: CredentialText,
+	pub year: CredentialText,
+	pub description: CredentialDescription,
+	pub supporting_document: Option<CredentialDescription>,
+}
+impl CredentialInfoValidation for CertificationInfo {
+	fn is_valid(&self) -> bool {
+		true
+	}
+}
+
+#[derive(Encode, Decode, Clone, Default, RuntimeDebug, PartialEq, Eq, TypeInfo, MaxEncodedLen)]
+pub struct Experience {
+	pub title: CredentialText,
+}
+
+/// Genetic analyst qualification information, requires at least one experience
+#[derive(Encode, Decode, Clone, Default, RuntimeDebug, PartialEq, Eq, TypeInfo, MaxEncodedLen)]
+pub struct GeneticAnalystQualificationInfo {
+	pub experience: ExperienceList,
+	pub certification: Option<CertificationInfoList>,
+}
+impl CredentialInfoValidation for GeneticAnalystQualificationInfo {
+	fn is_valid(&self) -> bool {
+		!self.experience.is_empty()
+	}
+}
+
+/// Health professional qualification information
+#[derive(Encode, Decode, Clone, Default, RuntimeDebug, PartialEq, Eq, TypeInfo, MaxEncodedLen)]
+pub struct HealthProfessionalQualificationInfo {
+	pub experiences: ExperienceList,
+	pub certifications: CertificationInfoList,
+}
+impl CredentialInfoValidation for HealthProfessionalQualificationInfo {
+	fn is_valid(&self) -> bool {
+		true
+	}
+}
+
+#[derive(Encode, Decode, Clone, Default, RuntimeDebug, PartialEq, Eq, TypeInfo, MaxEncodedLen)]
+pub struct Credential<AccountId, Hash, Info> {
+	pub id: Hash,
+	pub owner_id: AccountId,
+	pub info: Info,
+}
+impl<AccountId, Hash, Info> Credential<AccountId, Hash, Info> {
+	pub fn new(id: Hash, owner_id: AccountId, info: Info) -> Self {
+		Self { id, owner_id, info }
+	}
+
+	pub fn get_id(&self) -> &Hash {
+		&self.id
+	}
+
+	pub fn get_owner_id(&self) -> &AccountId {
+		&self.owner_id
+	}
+}
+
+impl<T, AccountId, Hash, Info> CredentialInfoT<T> for Credential<AccountId, Hash, Info>
+where
+	T: frame_system::Config<AccountId = AccountId, Hash = Hash>,
+{
+	fn get_id(&self) -> &Hash {
+		self.get_id()
+	}
+	fn get_owner_id(&self) -> &AccountId {
+		self.get_owner_id()
+	}
+}
@@ -0,0 +1,123 @@
+//! Autogenerated weights for credentials
+//!
+//! THIS FILE WAS AUTO-GENERATED USING THE SUBSTRATE BENCHMARK CLI VERSION 4.0.0-dev
+//! DATE: 2022-09-18, STEPS: `20`, REPEAT: 10, LOW RANGE: `[]`, HIGH RANGE: `[]`
+//! EXECUTION: Some(Wasm), WASM-EXECUTION: Compiled, CHAIN: Some("dev"), DB CACHE: 1024
+
+// Executed Command:
+// ./target/release/debio
+// benchmark
+// --chain=dev
+// --execution=wasm
+// --wasm-execution=compiled
+// --pallet=credentials
+// --extrinsic=*
+// --steps=20
+// --repeat=10
+// --heap-pages=4096
+// --output=./pallets/credentials/src/weights.rs
+// --template=./.maintain/pallet-weight-template.hbs
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+
+use frame_support::{traits::Get, weights::{Weight, constants::RocksDbWeight}};
+use sp_std::marker::PhantomData;
+
+/// Weight functions needed for credentials.
+pub trait WeightInfo {
+	fn create_credential() -> Weight;
+	fn update_credential() -> Weight;
+	fn delete_credential() -> Weight;
+	fn bulk_create_credential(n: u32, ) -> Weight;
+}
+
+/// Weights for credentials using the Substrate node and recommended hardware.
+pub struct SubstrateWeight<T>(PhantomData<T>);
+impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
+	// Storage: Credentials CredentialsCountByOwner (r:1 w:1)
+	// Storage: System Account (r:1 w:0)
+	// Storage: Labs Labs (r:1 w:1)
+	// Storage: Credentials CredentialsCount (r:1 w:1)
+	// Storage: Credentials Credentials (r:0 w:1)
+	fn create_credential() -> Weight {
+		Weight::from_ref_time(52_870_000_u64)
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+	}
+	// Storage: Credentials Credentials (r:1 w:1)
+	// Storage: CredentialIssuers CredentialVerifications (r:0 w:1)
+	fn update_credential() -> Weight {
+		Weight::from_ref_time(41_526_000_u64)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	// Storage: Credentials Credentials (r:1 w:1)
+	// Storage: CredentialIssuers CredentialVerifications (r:0 w:1)
+	// Storage: Labs Labs (r:1 w:1)
+	// Storage: Credentials CredentialsCount (r:1 w:1)
+	// Storage: Credentials CredentialsCountByOwner (r:1 w:1)
+	fn delete_credential() -> Weight {
+		Weight::from_ref_time(55_304_000_u64)
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(5_u64))
+	}
+	// Storage: Credentials CredentialsCountByOwner (r:1 w:1)
+	// Storage: System Account (r:1 w:0)
+	// Storage: Labs Labs (r:1 w:1)
+	// Storage: Credentials CredentialsCount (r:1 w:1)
+	// Storage: Credentials Credentials (r:0 w:1)
+	fn bulk_create_credential(n: u32, ) -> Weight {
+		Weight::from_ref_time(18_245_000_u64)
+			// Standard Error: 21_000
+			.saturating_add(Weight::from_ref_time(45_318_000_u64).saturating_mul(n as u64))
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().reads((3_u64).saturating_mul(n as u64)))
+			.saturating_add(T::DbWeight::get().writes((4_u64).saturating_mul(n as u64)))
+	}
+}
+
+// For backwards compatibility and tests
+impl WeightInfo for () {
+	// Storage: Credentials CredentialsCountByOwner (r:1 w:1)
+	// Storage: System Account (r:1 w:0)
+	// Storage: Labs Labs (r:1 w:1)
+	// Storage: Credentials CredentialsCount (r:1 w:1)
+	// Storage: Credentials Credentials (r:0 w:1)
+	fn create_credential() -> Weight {
+		Weight::from_ref_time(52_870_000_u64)
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+	}
+	// Storage: Credentials Credentials (r:1 w:1)
+	// Storage: CredentialIssuers CredentialVerifications (r:0 w:1)
+	fn update_credential() -> Weight {
+		Weight::from_ref_time(41_526_000_u64)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	// Storage: Credentials Credentials (r:1 w:1)
+	// Storage: CredentialIssuers CredentialVerifications (r:0 w:1)
+	// Storage: Labs Labs (r:1 w:1)
+	// Storage: Credentials CredentialsCount (r:1 w:1)
+	// Storage: Credentials CredentialsCountByOwner (r:1 w:1)
+	fn delete_credential() -> Weight {
+		Weight::from_ref_time(55_304_000_u64)
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(5_u64))
+	}
+	// Storage: Credentials CredentialsCountByOwner (r:1 w:1)
+	// Storage: System Account (r:1 w:0)
+	// Storage: Labs Labs (r:1 w:1)
+	// Storage: Credentials CredentialsCount (r:1 w:1)
+	// Storage: Credentials Credentials (r:0 w:1)
+	fn bulk_create_credential(n: u32, ) -> Weight {
+		Weight::from_ref_time(18_245_000_u64)
+			// Standard Error: 21_000
+			.saturating_add(Weight::from_ref_time(45_318_000_u64).saturating_mul(n as u64))
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().reads((3_u64).saturating_mul(n as u64)))
+			.saturating_add(RocksDbWeight::get().writes((4_u64).saturating_mul(n as u64)))
+	}
+}
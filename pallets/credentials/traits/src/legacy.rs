@@ -0,0 +1,162 @@
+//! Owner and provider traits of the certification and qualification pallets that were replaced
+//! by the credentials pallet. Owner pallets still implement and consume them through the adapters
+//! in `credentials::legacy`.
+
+use frame_system::Config;
+pub use traits_credential_issuers::CredentialVerification;
+
+// ----- Certifications -------
+pub trait CertificationInfo<T: Config> {
+	fn get_id(&self) -> &T::Hash;
+	fn get_owner_id(&self) -> &T::AccountId;
+}
+
+pub trait CertificationsProvider<T: Config> {
+	type Error;
+	type Certification: CertificationInfo<T> + sp_std::fmt::Debug;
+
+	fn delete_certification(
+		owner_id: &T::AccountId,
+		id: &T::Hash,
+	) -> Result<Self::Certification, Self::Error>;
+	fn certification_by_id(id: &T::Hash) -> Option<Self::Certification>;
+	fn certification_verification(
+		id: &T::Hash,
+	) -> Option<CredentialVerification<T::AccountId, T::BlockNumber>>;
+	fn is_certification_verified(id: &T::Hash) -> bool;
+}
+
+pub trait CertificationOwnerInfo<T: Config> {
+	fn get_owner_id(&self) -> &T::AccountId;
+}
+
+pub trait CertificationOwner<T: Config> {
+	type Owner: CertificationOwnerInfo<T> + sp_std::fmt::Debug;
+
+	fn can_create_certification(id: &T::AccountId) -> bool;
+	fn get_owner(id: &T::AccountId) -> Option<Self::Owner>;
+	fn associate(owner_id: &T::AccountId, certification_id: &T::Hash);
+	fn disassociate(owner_id: &T::AccountId, certification_id: &T::Hash);
+}
+
+// ----- Doctor certifications -------
+pub trait DoctorCertificationInfo<T: Config> {
+	fn get_id(&self) -> &T::Hash;
+	fn get_owner_id(&self) -> &T::AccountId;
+}
+
+pub trait DoctorCertificationsProvider<T: Config> {
+	type Error;
+	type DoctorCertification: DoctorCertificationInfo<T> + sp_std::fmt::Debug;
+
+	fn delete_certification(
+		owner_id: &T::AccountId,
+		id: &T::Hash,
+	) -> Result<Self::DoctorCertification, Self::Error>;
+	fn certification_by_id(id: &T::Hash) -> Option<Self::DoctorCertification>;
+	fn certification_verification(
+		id: &T::Hash,
+	) -> Option<CredentialVerification<T::AccountId, T::BlockNumber>>;
+	fn is_certification_verified(id: &T::Hash) -> bool;
+}
+
+pub trait DoctorCertificationOwnerInfo<T: Config> {
+	fn get_owner_id(&self) -> &T::AccountId;
+}
+
+pub trait DoctorCertificationOwner<T: Config> {
+	type Owner: DoctorCertificationOwnerInfo<T> + sp_std::fmt::Debug;
+
+	fn can_create_certification(id: &T::AccountId) -> bool;
+	fn get_owner(id: &T::AccountId) -> Option<Self::Owner>;
+	fn associate(owner_id: &T::AccountId, certification_id: &T::Hash);
+	fn disassociate(owner_id: &T::AccountId, certification_id: &T::Hash);
+}
+
+// ----- Hospital certifications -------
+pub trait HospitalCertificationInfo<T: Config> {
+	fn get_id(&self) -> &T::Hash;
+	fn get_owner_id(&self) -> &T::AccountId;
+}
+
+pub trait HospitalCertificationsProvider<T: Config> {
+	type Error;
+	type HospitalCertification: HospitalCertificationInfo<T> + sp_std::fmt::Debug;
+
+	fn delete_certification(
+		owner_id: &T::AccountId,
+		id: &T::Hash,
+	) -> Result<Self::HospitalCertification, Self::Error>;
+	fn certification_by_id(id: &T::Hash) -> Option<Self::HospitalCertification>;
+	fn certification_verification(
+		id: &T::Hash,
+	) -> Option<CredentialVerification<T::AccountId, T::BlockNumber>>;
+	fn is_certification_verified(id: &T::Hash) -> bool;
+}
+
+pub trait HospitalCertificationOwnerInfo<T: Config> {
+	fn get_owner_id(&self) -> &T::AccountId;
+}
+
+pub trait HospitalCertificationOwner<T: Config> {
+	type Owner: HospitalCertificationOwnerInfo<T> + sp_std::fmt::Debug;
+
+	fn can_create_certification(id: &T::AccountId) -> bool;
+	fn get_owner(id: &T::AccountId) -> Option<Self::Owner>;
+	fn associate(owner_id: &T::AccountId, certification_id: &T::Hash);
+	fn disassociate(owner_id: &T::AccountId, certification_id: &T::Hash);
+}
+
+// ----- Genetic analyst qualifications -------
+pub trait GeneticAnalystQualificationInfo<T: Config> {
+	fn get_id(&self) -> &T::Hash;
+	fn get_owner_id(&self) -> &T::AccountId;
+}
+
+pub trait GeneticAnalystQualificationsProvider<T: Config> {
+	type Error;
+	type GeneticAnalystQualification: GeneticAnalystQualificationInfo<T> + sp_std::fmt::Debug;
+
+	fn delete_qualification(
+		owner_id: &T::AccountId,
+		id: &T::Hash,
+	) -> Result<Self::GeneticAnalystQualification, Self::Error>;
+	fn qualification_by_id(id: &T::Hash) -> Option<Self::GeneticAnalystQualification>;
+	fn qualification_verification(
+		id: &T::Hash,
+	) -> Option<CredentialVerification<T::AccountId, T::BlockNumber>>;
+	fn is_qualification_verified(id: &T::Hash) -> bool;
+}
+
+pub trait GeneticAnalystQualificationOwnerInfo<T: Config> {
+	fn get_owner_id(&self) -> &T::AccountId;
+}
+
+pub trait GeneticAnalystQualificationOwner<T: Config> {
+	type Owner: GeneticAnalystQualificationOwnerInfo<T> + sp_std::fmt::Debug;
+
+	fn can_create_qualification(id: &T::AccountId) -> bool;
+	fn get_owner(id: &T::AccountId) -> Option<Self::Owner>;
+	fn associate(owner_id: &T::AccountId, qualification_id: &T::Hash);
+	fn disassociate(owner_id: &T::AccountId, qualification_id: &T::Hash);
+}
+
+// ----- Health professional qualifications -------
+pub trait HealthProfessionalQualificationOwner<T: Config> {
+	type Owner: sp_std::fmt::Debug;
+
+	fn can_create_qualification(id: &T::AccountId) -> bool;
+	fn get_owner(id: &T::AccountId) -> Option<Self::Owner>;
+	fn associate(owner_id: &T::AccountId, qualification_id: &T::Hash);
+	fn disassociate(owner_id: &T::AccountId, qualification_id: &T::Hash);
+}
+
+pub trait HealthProfessionalQualificationProvider<T: Config> {
+	type Error;
+
+	fn delete_qualifications(account_id: &T::AccountId, hash_ids: &[T::Hash]);
+	fn qualification_verification(
+		id: &T::Hash,
+	) -> Option<CredentialVerification<T::AccountId, T::BlockNumber>>;
+	fn is_qualification_verified(id: &T::Hash) -> bool;
+}
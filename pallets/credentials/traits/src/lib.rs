@@ -0,0 +1,28 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+
+pub mod legacy;
+
+use frame_system::Config;
+
+pub trait CredentialInfo<T: Config> {
+	fn get_id(&self) -> &T::Hash;
+	fn get_owner_id(&self) -> &T::AccountId;
+}
+
+pub trait CredentialsProvider<T: Config> {
+	type Error;
+	type Credential: CredentialInfo<T> + sp_std::fmt::Debug;
+
+	fn delete_credential(
+		owner_id: &T::AccountId,
+		id: &T::Hash,
+	) -> Result<Self::Credential, Self::Error>;
+	fn credential_by_id(id: &T::Hash) -> Option<Self::Credential>;
+	fn is_credential_verified(id: &T::Hash) -> bool;
+}
+
+pub trait CredentialOwner<T: Config> {
+	fn can_create_credential(id: &T::AccountId) -> bool;
+	fn associate(owner_id: &T::AccountId, credential_id: &T::Hash);
+	fn disassociate(owner_id: &T::AccountId, credential_id: &T::Hash);
+}
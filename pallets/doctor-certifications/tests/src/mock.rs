@@ -1,119 +0,0 @@
-use frame_support::parameter_types;
-use frame_system as system;
-use pallet_balances::AccountData;
-use sp_core::H256;
-use sp_runtime::{
-	testing::Header,
-	traits::{BlakeTwo256, IdentityLookup},
-};
-
-use primitives_ethereum_address::EthereumAddress;
-use primitives_profile_roles::ProfileRoles;
-
-type UncheckedExtrinsic = frame_system::mocking::MockUncheckedExtrinsic<Test>;
-type Block = frame_system::mocking::MockBlock<Test>;
-
-pub type AccountId = u64;
-
-frame_support::construct_runtime!(
-	pub enum Test where
-		Block = Block,
-		NodeBlock = Block,
-		UncheckedExtrinsic = UncheckedExtrinsic,
-	{
-		System: frame_system,
-		Balances: pallet_balances,
-		Doctors: doctors,
-		DoctorCertifications: doctor_certifications,
-		UserProfile: user_profile
-	}
-);
-
-parameter_types! {
-	pub const BlockHashCount: u64 = 250;
-	pub const SS58Prefix: u8 = 42;
-}
-
-impl system::Config for Test {
-	type BaseCallFilter = frame_support::traits::Everything;
-	type BlockWeights = ();
-	type BlockLength = ();
-	type AccountId = AccountId;
-	type RuntimeCall = RuntimeCall;
-	type Lookup = IdentityLookup<Self::AccountId>;
-	type Index = u64;
-	type BlockNumber = u64;
-	type Hash = H256;
-	type Hashing = BlakeTwo256;
-	type Header = Header;
-	type RuntimeEvent = RuntimeEvent;
-	type RuntimeOrigin = RuntimeOrigin;
-	type BlockHashCount = BlockHashCount;
-	type DbWeight = ();
-	type Version = ();
-	type PalletInfo = PalletInfo;
-	type OnNewAccount = ();
-	type OnKilledAccount = ();
-	type AccountData = AccountData<Balance>;
-	type SystemWeightInfo = ();
-	type SS58Prefix = SS58Prefix;
-	type OnSetCode = ();
-	type MaxConsumers = frame_support::traits::ConstU32<16>;
-}
-
-type Balance = u64;
-
-parameter_types! {
-	pub static ExistentialDeposit: Balance = 0;
-}
-
-impl pallet_balances::Config for Test {
-	type MaxLocks = ();
-	type MaxReserves = ();
-	type ReserveIdentifier = [u8; 8];
-	/// The type for recording an account's balance.
-	type Balance = Balance;
-	/// The ubiquitous event type.
-	type RuntimeEvent = RuntimeEvent;
-	type DustRemoval = ();
-	type ExistentialDeposit = ExistentialDeposit;
-	type AccountStore = System;
-	type WeightInfo = ();
-}
-
-impl doctors::Config for Test {
-	type RuntimeEvent = RuntimeEvent;
-	type Currency = Balances;
-	type DoctorCertifications = DoctorCertifications;
-	type EthereumAddress = EthereumAddress;
-	type ProfileRoles = ProfileRoles;
-	type UserProfile = UserProfile;
-	type WeightInfo = ();
-}
-
-impl doctor_certifications::Config for Test {
-	type RuntimeEvent = RuntimeEvent;
-	type DoctorCertificationOwner = Doctors;
-	type WeightInfo = ();
-}
-
-impl user_profile::Config for Test {
-	type RuntimeEvent = RuntimeEvent;
-	type EthereumAddress = EthereumAddress;
-	type ProfileRoles = ProfileRoles;
-	type WeightInfo = ();
-}
-
-#[cfg(test)]
-use sp_io::TestExternalities;
-
-#[cfg(test)]
-pub struct ExternalityBuilder;
-
-#[cfg(test)]
-impl ExternalityBuilder {
-	pub fn build() -> TestExternalities {
-		let storage = system::GenesisConfig::default().build_storage::<Test>().unwrap();
-		TestExternalities::from(storage)
-	}
-}
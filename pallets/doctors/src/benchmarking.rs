@@ -10,15 +10,15 @@ use primitives_area_code::{CityCode, CountryCode, RegionCode};
 benchmarks! {
 	register_doctor {
 		let doctor = DoctorInfo {
-			name: "DeBio Doctor".as_bytes().to_vec(),
-			email: "DeBio Email".as_bytes().to_vec(),
-			country: CountryCode::from_vec("DC".as_bytes().to_vec()),
-			region: RegionCode::from_vec("DBIO".as_bytes().to_vec()),
-			city: CityCode::from_vec("City".as_bytes().to_vec()),
-			address: "DeBio Address".as_bytes().to_vec(),
-			latitude: Some("DeBio Latitude".as_bytes().to_vec()),
-			longitude: Some("DeBio Longtitude".as_bytes().to_vec()),
-			profile_image: Some("DeBio Profile Image uwu".as_bytes().to_vec()),
+			name: "DeBio Doctor".as_bytes().to_vec().try_into().unwrap(),
+			email: "DeBio Email".as_bytes().to_vec().try_into().unwrap(),
+			country: CountryCode::try_from("ID".as_bytes().to_vec()).unwrap(),
+			region: RegionCode::try_from("JK".as_bytes().to_vec()).unwrap(),
+			city: CityCode::try_from("City".as_bytes().to_vec()).unwrap(),
+			address: "DeBio Address".as_bytes().to_vec().try_into().unwrap(),
+			latitude: Some("DeBio Latitude".as_bytes().to_vec().try_into().unwrap()),
+			longitude: Some("DeBio Longtitude".as_bytes().to_vec().try_into().unwrap()),
+			profile_image: Some("DeBio Profile Image uwu".as_bytes().to_vec().try_into().unwrap()),
 		};
 		let caller: T::AccountId = whitelisted_caller();
 	}: register_doctor(
@@ -31,28 +31,28 @@ benchmarks! {
 		let caller_origin = <T as frame_system::Config>::RuntimeOrigin::from(RawOrigin::Signed(caller.clone()));
 
 		let old_doctor = DoctorInfo {
-			name: "DeBio Doctor".as_bytes().to_vec(),
-			email: "DeBio Email".as_bytes().to_vec(),
-			country: CountryCode::from_vec("DC".as_bytes().to_vec()),
-			region: RegionCode::from_vec("DBIO".as_bytes().to_vec()),
-			city: CityCode::from_vec("City".as_bytes().to_vec()),
-			address: "DeBio Address".as_bytes().to_vec(),
-			latitude: Some("DeBio Latitude".as_bytes().to_vec()),
-			longitude: Some("DeBio Longtitude".as_bytes().to_vec()),
-			profile_image: Some("DeBio Profile Image uwu".as_bytes().to_vec()),
+			name: "DeBio Doctor".as_bytes().to_vec().try_into().unwrap(),
+			email: "DeBio Email".as_bytes().to_vec().try_into().unwrap(),
+			country: CountryCode::try_from("ID".as_bytes().to_vec()).unwrap(),
+			region: RegionCode::try_from("JK".as_bytes().to_vec()).unwrap(),
+			city: CityCode::try_from("City".as_bytes().to_vec()).unwrap(),
+			address: "DeBio Address".as_bytes().to_vec().try_into().unwrap(),
+			latitude: Some("DeBio Latitude".as_bytes().to_vec().try_into().unwrap()),
+			longitude: Some("DeBio Longtitude".as_bytes().to_vec().try_into().unwrap()),
+			profile_image: Some("DeBio Profile Image uwu".as_bytes().to_vec().try_into().unwrap()),
 		};
 		let _add_doctors = Doctors::<T>::register_doctor(caller_origin.clone(), old_doctor);
 
 		let new_doctor = DoctorInfo {
-			name: "DeBio Doctor 2".as_bytes().to_vec(),
-			email: "DeBio Email 2".as_bytes().to_vec(),
-			country: CountryCode::from_vec("C2".as_bytes().to_vec()),
-			region: RegionCode::from_vec("DBI2".as_bytes().to_vec()),
-			city: CityCode::from_vec("C2C2".as_bytes().to_vec()),
-			address: "DeBio Address 2".as_bytes().to_vec(),
-			latitude: Some("DeBio Latitude 2".as_bytes().to_vec()),
-			longitude: Some("DeBio Longtitude 2".as_bytes().to_vec()),
-			profile_image: Some("DeBio Profile Image owo".as_bytes().to_vec()),
+			name: "DeBio Doctor 2".as_bytes().to_vec().try_into().unwrap(),
+			email: "DeBio Email 2".as_bytes().to_vec().try_into().unwrap(),
+			country: CountryCode::try_from("ID".as_bytes().to_vec()).unwrap(),
+			region: RegionCode::try_from("JT".as_bytes().to_vec()).unwrap(),
+			city: CityCode::try_from("C2C2".as_bytes().to_vec()).unwrap(),
+			address: "DeBio Address 2".as_bytes().to_vec().try_into().unwrap(),
+			latitude: Some("DeBio Latitude 2".as_bytes().to_vec().try_into().unwrap()),
+			longitude: Some("DeBio Longtitude 2".as_bytes().to_vec().try_into().unwrap()),
+			profile_image: Some("DeBio Profile Image owo".as_bytes().to_vec().try_into().unwrap()),
 		};
 	}: update_doctor(
 		RawOrigin::Signed(caller),
@@ -64,15 +64,15 @@ benchmarks! {
 		let caller_origin = <T as frame_system::Config>::RuntimeOrigin::from(RawOrigin::Signed(caller.clone()));
 
 		let doctor = DoctorInfo {
-			name: "DeBio Doctor".as_bytes().to_vec(),
-			email: "DeBio Email".as_bytes().to_vec(),
-			country: CountryCode::from_vec("DC".as_bytes().to_vec()),
-			region: RegionCode::from_vec("DBIO".as_bytes().to_vec()),
-			city: CityCode::from_vec("City".as_bytes().to_vec()),
-			address: "DeBio Address".as_bytes().to_vec(),
-			latitude: Some("DeBio Latitude".as_bytes().to_vec()),
-			longitude: Some("DeBio Longtitude".as_bytes().to_vec()),
-			profile_image: Some("DeBio Profile Image uwu".as_bytes().to_vec()),
+			name: "DeBio Doctor".as_bytes().to_vec().try_into().unwrap(),
+			email: "DeBio Email".as_bytes().to_vec().try_into().unwrap(),
+			country: CountryCode::try_from("ID".as_bytes().to_vec()).unwrap(),
+			region: RegionCode::try_from("JK".as_bytes().to_vec()).unwrap(),
+			city: CityCode::try_from("City".as_bytes().to_vec()).unwrap(),
+			address: "DeBio Address".as_bytes().to_vec().try_into().unwrap(),
+			latitude: Some("DeBio Latitude".as_bytes().to_vec().try_into().unwrap()),
+			longitude: Some("DeBio Longtitude".as_bytes().to_vec().try_into().unwrap()),
+			profile_image: Some("DeBio Profile Image uwu".as_bytes().to_vec().try_into().unwrap()),
 		};
 
 		let _add_doctors = Doctors::<T>::register_doctor(caller_origin.clone(), doctor);
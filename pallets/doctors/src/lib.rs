@@ -1,5 +1,6 @@
 #![cfg_attr(not(feature = "std"), no_std)]
 
+pub mod migrations;
 pub mod weights;
 
 /// Edit this file to define custom logic or remove it if it is not needed.
@@ -20,35 +21,57 @@ mod benchmarking;
 
 pub mod interface;
 pub use crate::interface::DoctorInterface;
-use frame_support::pallet_prelude::*;
-use primitives_area_code::{CityCode, CountryCode, CountryRegionCode, RegionCode};
-use traits_doctor_certifications::DoctorCertificationOwnerInfo;
+use frame_support::{pallet_prelude::*, traits::StorageVersion};
+use primitives_area_code::{
+	normalize_area_code, AreaCodeError, CityCode, CountryCode, CountryRegionCode, RegionCode,
+};
+use traits_credentials::legacy::DoctorCertificationOwnerInfo;
 use traits_user_profile::UserProfileProvider;
 
+/// Longest text field of a doctor, e.g. its name, address or profile image url
+pub const MAX_DOCTOR_INFO_LENGTH: u32 = 512;
+/// Most certifications a doctor can own
+pub const MAX_CERTIFICATIONS_PER_DOCTOR: u32 = 256;
+
+pub type DoctorText = BoundedVec<u8, ConstU32<MAX_DOCTOR_INFO_LENGTH>>;
+
 // DoctorInfo Struct
 // Used as parameter of dispatchable calls
-#[derive(Encode, Decode, Clone, Default, RuntimeDebug, PartialEq, Eq, TypeInfo)]
+#[derive(Encode, Decode, Clone, Default, RuntimeDebug, PartialEq, Eq, TypeInfo, MaxEncodedLen)]
 pub struct DoctorInfo {
-	pub name: Vec<u8>,
-	pub email: Vec<u8>,
+	pub name: DoctorText,
+	pub email: DoctorText,
 	pub country: CountryCode,
 	pub region: RegionCode,
 	pub city: CityCode,
-	pub address: Vec<u8>,
-	pub latitude: Option<Vec<u8>>,
-	pub longitude: Option<Vec<u8>>,
-	pub profile_image: Option<Vec<u8>>,
+	pub address: DoctorText,
+	pub latitude: Option<DoctorText>,
+	pub longitude: Option<DoctorText>,
+	pub profile_image: Option<DoctorText>,
+}
+
+impl DoctorInfo {
+	// Validates the area codes against ISO 3166 and upper cases them
+	fn normalize_area_code(mut self) -> Result<Self, AreaCodeError> {
+		let (country, region, city) = normalize_area_code(&self.country, &self.region, &self.city)?;
+
+		self.country = country;
+		self.region = region;
+		self.city = city;
+
+		Ok(self)
+	}
 }
 
 // Doctor Struct
 // the fields (excluding account_id and certifications) come from DoctorInfo struct
-#[derive(Encode, Decode, Clone, Default, RuntimeDebug, PartialEq, Eq, TypeInfo)]
+#[derive(Encode, Decode, Clone, Default, RuntimeDebug, PartialEq, Eq, TypeInfo, MaxEncodedLen)]
 pub struct Doctor<AccountId, Hash>
 where
 	Hash: PartialEq + Eq,
 {
 	pub account_id: AccountId,
-	pub certifications: Vec<Hash>,
+	pub certifications: BoundedVec<Hash, ConstU32<MAX_CERTIFICATIONS_PER_DOCTOR>>,
 	pub info: DoctorInfo,
 }
 
@@ -57,7 +80,7 @@ where
 	Hash: PartialEq + Eq,
 {
 	pub fn new(account_id: AccountId, info: DoctorInfo) -> Self {
-		Self { account_id, certifications: Vec::<Hash>::new(), info }
+		Self { account_id, certifications: BoundedVec::default(), info }
 	}
 
 	fn update_info(&mut self, info: DoctorInfo) {
@@ -85,8 +108,10 @@ where
 		&self.account_id
 	}
 
-	pub fn add_certification(&mut self, certification_id: Hash) {
-		self.certifications.push(certification_id);
+	/// Returns the certification id back when the doctor already owns
+	/// MAX_CERTIFICATIONS_PER_DOCTOR certifications
+	pub fn add_certification(&mut self, certification_id: Hash) -> Result<(), Hash> {
+		self.certifications.try_push(certification_id)
 	}
 
 	pub fn remove_certification(&mut self, certification_id: Hash) {
@@ -106,6 +131,9 @@ where
 	}
 }
 
+/// The current storage version.
+const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
+
 #[frame_support::pallet]
 pub mod pallet {
 	use crate::{interface::DoctorInterface, Doctor, DoctorInfo, *};
@@ -113,9 +141,7 @@ pub mod pallet {
 	use frame_support::{dispatch::DispatchResultWithPostInfo, traits::Currency};
 	use frame_system::pallet_prelude::*;
 	pub use sp_std::prelude::*;
-	pub use traits_doctor_certifications::{
-		DoctorCertificationOwner, DoctorCertificationsProvider,
-	};
+	pub use traits_credentials::legacy::{DoctorCertificationOwner, DoctorCertificationsProvider};
 
 	#[pallet::config]
 	/// Configure the pallet by specifying the parameters and types on which it depends.
@@ -146,16 +172,23 @@ pub mod pallet {
 			+ sp_std::fmt::Debug;
 		type UserProfile: UserProfileProvider<Self, Self::EthereumAddress, Self::ProfileRoles>;
 		type WeightInfo: WeightInfo;
+		/// Maximum number of doctors indexed per country-region and city.
+		#[pallet::constant]
+		type MaxDoctorsPerCity: Get<u32>;
 	}
 
 	// ----- This is template code, every pallet needs this ---
 	#[pallet::pallet]
+	#[pallet::storage_version(STORAGE_VERSION)]
 	#[pallet::generate_store(pub(super) trait Store)]
-	#[pallet::without_storage_info]
 	pub struct Pallet<T>(_);
 
 	#[pallet::hooks]
-	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {}
+	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+		fn on_runtime_upgrade() -> Weight {
+			migrations::migrate::<T>()
+		}
+	}
 	// --------------------------------------------------------
 
 	// ---- Types ----------------------
@@ -163,6 +196,7 @@ pub mod pallet {
 	pub type HashOf<T> = <T as frame_system::Config>::Hash;
 	pub type DoctorOf<T> = Doctor<AccountIdOf<T>, HashOf<T>>;
 	pub type UserProfileOf<T> = <T as self::Config>::UserProfile;
+	pub type DoctorIdsOf<T> = BoundedVec<AccountIdOf<T>, <T as Config>::MaxDoctorsPerCity>;
 
 	// ----- Storage ------------------
 	/// Get Doctor by account id
@@ -172,7 +206,7 @@ pub mod pallet {
 	pub type Doctors<T> = StorageMap<_, Blake2_128Concat, AccountIdOf<T>, DoctorOf<T>>;
 
 	/// Get DoctorId by Country-Region, City
-	/// (CountryRegionCode, CityCode) => Vec<AccountId>
+	/// (CountryRegionCode, CityCode) => BoundedVec<AccountId>
 	#[pallet::storage]
 	#[pallet::getter(fn doctors_by_country_region_city)]
 	pub type DoctorsByCountryRegionCity<T> = StorageDoubleMap<
@@ -181,7 +215,7 @@ pub mod pallet {
 		CountryRegionCode,
 		Blake2_128Concat,
 		CityCode,
-		Vec<AccountIdOf<T>>,
+		DoctorIdsOf<T>,
 	>;
 
 	/// Get total doctor count
@@ -223,6 +257,14 @@ pub mod pallet {
 		DoctorIsNotOwner,
 		// Setting profile role failed,
 		FailedToSetProfileRole,
+		/// Country is not an ISO 3166-1 alpha-2 code
+		InvalidCountryCode,
+		/// Region is not an ISO 3166-2 subdivision of the country
+		InvalidRegionCode,
+		/// City code is empty, too long or not printable
+		InvalidCityCode,
+		/// The city already indexes MaxDoctorsPerCity doctors
+		TooManyDoctorsInCity,
 	}
 
 	#[pallet::call]
@@ -282,6 +324,8 @@ pub mod pallet {
 	}
 }
 
+primitives_area_code::impl_from_area_code_error!(Error);
+
 impl<T: Config> DoctorInterface<T> for Pallet<T> {
 	type Error = Error<T>;
 	type DoctorInfo = DoctorInfo;
@@ -294,10 +338,11 @@ impl<T: Config> DoctorInterface<T> for Pallet<T> {
 		if Doctors::<T>::contains_key(account_id) {
 			return Err(Error::<T>::DoctorAlreadyRegistered)
 		}
-		let doctor = Doctor::new(account_id.clone(), doctor_info.clone());
+		let doctor_info = doctor_info.clone().normalize_area_code()?;
+		let doctor = Doctor::new(account_id.clone(), doctor_info);
 		// Insert to Storage
 		Doctors::<T>::insert(account_id, &doctor);
-		Self::insert_doctor_id_to_location(&doctor);
+		Self::insert_doctor_id_to_location(&doctor)?;
 
 		// Increment Count
 		Self::add_doctor_count();
@@ -315,6 +360,7 @@ impl<T: Config> DoctorInterface<T> for Pallet<T> {
 			return Err(Error::<T>::DoctorDoesNotExist)
 		}
 		let mut doctor = doctor.unwrap();
+		let doctor_info = doctor_info.clone().normalize_area_code()?;
 		let mut is_location_changed = false;
 
 		// If location is updated, remove the doctor from the old location
@@ -329,11 +375,11 @@ impl<T: Config> DoctorInterface<T> for Pallet<T> {
 			is_location_changed = true;
 		}
 
-		doctor.update_info(doctor_info.clone());
+		doctor.update_info(doctor_info);
 
 		if is_location_changed {
 			// insert new location
-			Self::insert_doctor_id_to_location(&doctor);
+			Self::insert_doctor_id_to_location(&doctor)?;
 			Self::add_doctor_count_by_location(&doctor);
 		}
 
@@ -366,6 +412,7 @@ impl<T: Config> DoctorInterface<T> for Pallet<T> {
 		city_code: &CityCode,
 	) -> Option<Vec<T::AccountId>> {
 		Self::doctors_by_country_region_city(country_region_code, city_code)
+			.map(BoundedVec::into_inner)
 	}
 
 	fn doctor_by_account_id(account_id: &T::AccountId) -> Option<Self::Doctor> {
@@ -374,21 +421,19 @@ impl<T: Config> DoctorInterface<T> for Pallet<T> {
 }
 
 impl<T: Config> Pallet<T> {
-	pub fn insert_doctor_id_to_location(doctor: &DoctorOf<T>) {
+	pub fn insert_doctor_id_to_location(doctor: &DoctorOf<T>) -> Result<(), Error<T>> {
 		let country_region_code = doctor.get_country_region();
 		let city_code = doctor.get_city();
 		let doctor_account_id = doctor.get_account_id();
 
-		match DoctorsByCountryRegionCity::<T>::get(&country_region_code, city_code) {
-			None => {
-				let doctors = vec![doctor_account_id.clone()];
-				DoctorsByCountryRegionCity::<T>::insert(&country_region_code, city_code, doctors);
-			},
-			Some(mut doctors) => {
-				doctors.push(doctor_account_id.clone());
-				DoctorsByCountryRegionCity::<T>::insert(&country_region_code, city_code, doctors);
-			},
-		}
+		let mut doctors = DoctorsByCountryRegionCity::<T>::get(&country_region_code, city_code)
+			.unwrap_or_default();
+		doctors
+			.try_push(doctor_account_id.clone())
+			.map_err(|_| Error::<T>::TooManyDoctorsInCity)?;
+		DoctorsByCountryRegionCity::<T>::insert(&country_region_code, city_code, doctors);
+
+		Ok(())
 	}
 
 	pub fn remove_doctor_id_from_location(doctor: &DoctorOf<T>) {
@@ -462,7 +507,10 @@ impl<T: Config> DoctorCertificationOwner<T> for Pallet<T> {
 
 	/// User can create certification if he/she is a doctor
 	fn can_create_certification(user_id: &T::AccountId) -> bool {
-		Doctors::<T>::contains_key(user_id)
+		match Doctors::<T>::get(user_id) {
+			Some(doctor) => doctor.certifications.len() < MAX_CERTIFICATIONS_PER_DOCTOR as usize,
+			None => false,
+		}
 	}
 
 	fn get_owner(id: &T::AccountId) -> Option<Self::Owner> {
@@ -474,7 +522,9 @@ impl<T: Config> DoctorCertificationOwner<T> for Pallet<T> {
 			match doctor {
 				None => (), // If doctor does not exist, do nothing
 				Some(doctor) => {
-					doctor.add_certification(*certification_id);
+					// can_create_certification rejects doctors without room for another
+					// certification
+					let _ = doctor.add_certification(*certification_id);
 				},
 			}
 		});
@@ -0,0 +1,146 @@
+use crate::{
+	AccountIdOf, Config, Doctor, DoctorCountByCountryRegionCity, DoctorInfo, Doctors,
+	DoctorsByCountryRegionCity, HashOf, Pallet, Vec, Weight,
+};
+use frame_support::{
+	log,
+	pallet_prelude::{Decode, Encode},
+	traits::{ConstU32, Get},
+	BoundedVec,
+};
+use primitives_area_code::{
+	upper_case_area_code, CityCode, CountryCode, RegionCode, MAX_AREA_CODE_LENGTH,
+};
+
+pub fn migrate<T: Config>() -> Weight {
+	use frame_support::traits::StorageVersion;
+
+	let version = StorageVersion::get::<Pallet<T>>();
+	let mut weight: Weight = Weight::zero();
+
+	if version < 1 {
+		weight = weight.saturating_add(v1::migrate::<T>());
+		StorageVersion::new(1).put::<Pallet<T>>();
+	}
+
+	weight
+}
+
+/// Truncates `value` to the bound and logs the entry when it did not fit.
+fn bounded<I, S: Get<u32>>(value: Vec<I>, entry: &str) -> BoundedVec<I, S> {
+	if value.len() > S::get() as usize {
+		log::warn!(
+			target: "runtime::doctors",
+			"{} exceeds its bound of {}, truncated from {} items",
+			entry,
+			S::get(),
+			value.len(),
+		);
+	}
+
+	BoundedVec::truncate_from(value)
+}
+
+/// Region and city codes were unbounded before, legacy codes over the bound are truncated
+/// and logged like any other field.
+fn bounded_area_code(value: Vec<u8>, entry: &str) -> Vec<u8> {
+	bounded::<u8, ConstU32<MAX_AREA_CODE_LENGTH>>(value, entry).into_inner()
+}
+
+mod v1 {
+	use super::*;
+
+	/// Doctor info fields before they were bounded
+	#[derive(Encode, Decode)]
+	pub struct OldDoctorInfo {
+		pub name: Vec<u8>,
+		pub email: Vec<u8>,
+		pub country: CountryCode,
+		pub region: Vec<u8>,
+		pub city: Vec<u8>,
+		pub address: Vec<u8>,
+		pub latitude: Option<Vec<u8>>,
+		pub longitude: Option<Vec<u8>>,
+		pub profile_image: Option<Vec<u8>>,
+	}
+
+	#[derive(Encode, Decode)]
+	pub struct OldDoctor<AccountId, Hash> {
+		pub account_id: AccountId,
+		pub certifications: Vec<Hash>,
+		pub info: OldDoctorInfo,
+	}
+
+	fn bounded_doctor_info(info: OldDoctorInfo) -> DoctorInfo {
+		DoctorInfo {
+			name: bounded(info.name, "doctor name"),
+			email: bounded(info.email, "doctor email"),
+			country: info.country,
+			region: RegionCode::from_vec(bounded_area_code(info.region, "doctor region")),
+			city: CityCode::from_vec(bounded_area_code(info.city, "doctor city")),
+			address: bounded(info.address, "doctor address"),
+			latitude: info.latitude.map(|latitude| bounded(latitude, "doctor latitude")),
+			longitude: info.longitude.map(|longitude| bounded(longitude, "doctor longitude")),
+			profile_image: info
+				.profile_image
+				.map(|profile_image| bounded(profile_image, "doctor profile image")),
+		}
+	}
+
+	/// Validates and upper cases an area code stored before area codes were validated. Codes
+	/// which are not ISO 3166 are only upper cased and logged.
+	fn normalized_doctor_info(info: DoctorInfo) -> DoctorInfo {
+		match info.clone().normalize_area_code() {
+			Ok(info) => info,
+			Err(error) => {
+				log::warn!(
+					target: "runtime::doctors",
+					"doctor area code {:?} {:?} {:?} is not ISO 3166 ({:?}), only upper cased",
+					info.country,
+					info.region,
+					info.city,
+					error,
+				);
+
+				let (country, region, city) =
+					upper_case_area_code(&info.country, &info.region, &info.city);
+				DoctorInfo { country, region, city, ..info }
+			},
+		}
+	}
+
+	/// Doctor info and certification lists become bounded, legacy values that exceed a bound
+	/// are truncated and logged. Doctor area codes are normalized, and the location index and
+	/// counts are rebuilt so doctors registered under differently cased codes are merged into
+	/// one key.
+	pub fn migrate<T: Config>() -> Weight {
+		let mut weight = T::DbWeight::get().writes(1);
+
+		<Doctors<T>>::translate(|_key, old: OldDoctor<AccountIdOf<T>, HashOf<T>>| {
+			weight = weight.saturating_add(T::DbWeight::get().reads_writes(1, 1));
+			Some(Doctor {
+				account_id: old.account_id,
+				certifications: bounded(old.certifications, "doctor certifications"),
+				info: normalized_doctor_info(bounded_doctor_info(old.info)),
+			})
+		});
+
+		let removed = DoctorsByCountryRegionCity::<T>::clear(u32::MAX, None).unique +
+			DoctorCountByCountryRegionCity::<T>::clear(u32::MAX, None).unique;
+		weight = weight.saturating_add(T::DbWeight::get().writes(removed.into()));
+
+		for doctor in <Doctors<T>>::iter_values() {
+			if <Pallet<T>>::insert_doctor_id_to_location(&doctor).is_err() {
+				log::warn!(
+					target: "runtime::doctors",
+					"doctor {:?} exceeds MaxDoctorsPerCity once area codes are merged, left out of the location index",
+					doctor.account_id,
+				);
+			}
+			<Pallet<T>>::add_doctor_count_by_location(&doctor);
+			weight = weight.saturating_add(T::DbWeight::get().reads_writes(3, 2));
+		}
+
+		weight
+	}
+}
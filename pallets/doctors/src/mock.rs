@@ -26,7 +26,7 @@ frame_support::construct_runtime!(
 		System: frame_system,
 		Balances: pallet_balances,
 		Doctors: doctors,
-		DoctorCertifications: doctor_certifications,
+		DoctorCertifications: credentials::<Instance2>,
 		UserProfile: user_profile
 	}
 );
@@ -73,6 +73,7 @@ impl doctors::Config for Test {
 	type ProfileRoles = ProfileRoles;
 	type UserProfile = UserProfile;
 	type WeightInfo = ();
+	type MaxDoctorsPerCity = MaxDoctorsPerCity;
 }
 
 impl user_profile::Config for Test {
@@ -82,9 +83,11 @@ impl user_profile::Config for Test {
 	type WeightInfo = ();
 }
 
-impl doctor_certifications::Config for Test {
+impl credentials::Config<credentials::Instance2> for Test {
 	type RuntimeEvent = RuntimeEvent;
-	type DoctorCertificationOwner = Doctors;
+	type CredentialInfo = credentials::CertificationInfo;
+	type CredentialOwner = credentials::legacy::LegacyDoctorCertificationOwner<Doctors>;
+	type CredentialIssuers = ();
 	type WeightInfo = ();
 }
 
@@ -92,6 +95,7 @@ type Balance = u64;
 
 parameter_types! {
 	pub static ExistentialDeposit: Balance = 0;
+	pub static MaxDoctorsPerCity: u32 = 100;
 }
 
 impl pallet_balances::Config for Test {
@@ -1,6 +1,22 @@
-use crate::{mock::*, Doctor, DoctorInfo, Error};
-use frame_support::{assert_noop, assert_ok};
-use primitives_area_code::{CityCode, CountryCode, CountryRegionCode, RegionCode};
+use crate::{mock::*, Doctor, DoctorInfo, Error, MAX_DOCTOR_INFO_LENGTH};
+use frame_support::{assert_noop, assert_ok, storage::unhashed, traits::StorageVersion};
+use primitives_area_code::{
+	CityCode, CountryCode, CountryRegionCode, RegionCode, MAX_AREA_CODE_LENGTH,
+};
+
+fn doctor_info() -> DoctorInfo {
+	DoctorInfo {
+		name: "DeBio Doctor".as_bytes().to_vec().try_into().unwrap(),
+		email: "DeBio Email".as_bytes().to_vec().try_into().unwrap(),
+		country: CountryCode::try_from("ID".as_bytes().to_vec()).unwrap(),
+		region: RegionCode::try_from("JK".as_bytes().to_vec()).unwrap(),
+		city: CityCode::try_from("City".as_bytes().to_vec()).unwrap(),
+		address: "DeBio Address".as_bytes().to_vec().try_into().unwrap(),
+		latitude: None,
+		longitude: None,
+		profile_image: None,
+	}
+}
 
 #[test]
 fn register_doctor_works() {
@@ -8,15 +24,17 @@ fn register_doctor_works() {
 		assert_ok!(Doctors::register_doctor(
 			RuntimeOrigin::signed(1),
 			DoctorInfo {
-				name: "DeBio Doctor".as_bytes().to_vec(),
-				email: "DeBio Email".as_bytes().to_vec(),
-				country: CountryCode::from_vec("DC".as_bytes().to_vec()),
-				region: RegionCode::from_vec("DBIO".as_bytes().to_vec()),
-				city: CityCode::from_vec("City".as_bytes().to_vec()),
-				address: "DeBio Address".as_bytes().to_vec(),
-				latitude: Some("DeBio Latitude".as_bytes().to_vec()),
-				longitude: Some("DeBio Longtitude".as_bytes().to_vec()),
-				profile_image: Some("DeBio Profile Image uwu".as_bytes().to_vec()),
+				name: "DeBio Doctor".as_bytes().to_vec().try_into().unwrap(),
+				email: "DeBio Email".as_bytes().to_vec().try_into().unwrap(),
+				country: CountryCode::try_from("ID".as_bytes().to_vec()).unwrap(),
+				region: RegionCode::try_from("JK".as_bytes().to_vec()).unwrap(),
+				city: CityCode::try_from("City".as_bytes().to_vec()).unwrap(),
+				address: "DeBio Address".as_bytes().to_vec().try_into().unwrap(),
+				latitude: Some("DeBio Latitude".as_bytes().to_vec().try_into().unwrap()),
+				longitude: Some("DeBio Longtitude".as_bytes().to_vec().try_into().unwrap()),
+				profile_image: Some(
+					"DeBio Profile Image uwu".as_bytes().to_vec().try_into().unwrap()
+				),
 			}
 		));
 
@@ -24,42 +42,46 @@ fn register_doctor_works() {
 			Doctors::doctor_by_account_id(1),
 			Some(Doctor {
 				account_id: 1,
-				certifications: Vec::new(),
+				certifications: Default::default(),
 				info: DoctorInfo {
-					name: "DeBio Doctor".as_bytes().to_vec(),
-					email: "DeBio Email".as_bytes().to_vec(),
-					country: CountryCode::from_vec("DC".as_bytes().to_vec()),
-					region: RegionCode::from_vec("DBIO".as_bytes().to_vec()),
-					city: CityCode::from_vec("City".as_bytes().to_vec()),
-					address: "DeBio Address".as_bytes().to_vec(),
-					latitude: Some("DeBio Latitude".as_bytes().to_vec()),
-					longitude: Some("DeBio Longtitude".as_bytes().to_vec()),
-					profile_image: Some("DeBio Profile Image uwu".as_bytes().to_vec()),
+					name: "DeBio Doctor".as_bytes().to_vec().try_into().unwrap(),
+					email: "DeBio Email".as_bytes().to_vec().try_into().unwrap(),
+					country: CountryCode::try_from("ID".as_bytes().to_vec()).unwrap(),
+					region: RegionCode::try_from("JK".as_bytes().to_vec()).unwrap(),
+					city: CityCode::try_from("City".as_bytes().to_vec()).unwrap(),
+					address: "DeBio Address".as_bytes().to_vec().try_into().unwrap(),
+					latitude: Some("DeBio Latitude".as_bytes().to_vec().try_into().unwrap()),
+					longitude: Some("DeBio Longtitude".as_bytes().to_vec().try_into().unwrap()),
+					profile_image: Some(
+						"DeBio Profile Image uwu".as_bytes().to_vec().try_into().unwrap()
+					),
 				}
 			})
 		);
 
-		let country_region_code = CountryRegionCode::from_vec("DC-DBIO".as_bytes().to_vec());
-		let city_code = CityCode::from_vec("City".as_bytes().to_vec());
+		let country_region_code = CountryRegionCode::from_vec("ID-JK".as_bytes().to_vec());
+		let city_code = CityCode::try_from("City".as_bytes().to_vec()).unwrap();
 
 		assert_ok!(Doctors::register_doctor(
 			RuntimeOrigin::signed(2),
 			DoctorInfo {
-				name: "DeBio Doctor".as_bytes().to_vec(),
-				email: "DeBio Email".as_bytes().to_vec(),
-				country: CountryCode::from_vec("DC".as_bytes().to_vec()),
-				region: RegionCode::from_vec("DBIO".as_bytes().to_vec()),
-				city: CityCode::from_vec("City".as_bytes().to_vec()),
-				address: "DeBio Address".as_bytes().to_vec(),
-				latitude: Some("DeBio Latitude".as_bytes().to_vec()),
-				longitude: Some("DeBio Longtitude".as_bytes().to_vec()),
-				profile_image: Some("DeBio Profile Image uwu".as_bytes().to_vec()),
+				name: "DeBio Doctor".as_bytes().to_vec().try_into().unwrap(),
+				email: "DeBio Email".as_bytes().to_vec().try_into().unwrap(),
+				country: CountryCode::try_from("ID".as_bytes().to_vec()).unwrap(),
+				region: RegionCode::try_from("JK".as_bytes().to_vec()).unwrap(),
+				city: CityCode::try_from("City".as_bytes().to_vec()).unwrap(),
+				address: "DeBio Address".as_bytes().to_vec().try_into().unwrap(),
+				latitude: Some("DeBio Latitude".as_bytes().to_vec().try_into().unwrap()),
+				longitude: Some("DeBio Longtitude".as_bytes().to_vec().try_into().unwrap()),
+				profile_image: Some(
+					"DeBio Profile Image uwu".as_bytes().to_vec().try_into().unwrap()
+				),
 			}
 		));
 
 		assert_eq!(
 			Doctors::doctors_by_country_region_city(&country_region_code, &city_code),
-			Some(vec![1, 2])
+			Some(vec![1, 2].try_into().unwrap())
 		);
 
 		assert_eq!(Doctors::doctor_count(), Some(2),);
@@ -77,30 +99,34 @@ fn update_doctor_works() {
 		assert_ok!(Doctors::register_doctor(
 			RuntimeOrigin::signed(1),
 			DoctorInfo {
-				name: "DeBio Doctor".as_bytes().to_vec(),
-				email: "DeBio Email".as_bytes().to_vec(),
-				country: CountryCode::from_vec("DC".as_bytes().to_vec()),
-				region: RegionCode::from_vec("DBIO".as_bytes().to_vec()),
-				city: CityCode::from_vec("City".as_bytes().to_vec()),
-				address: "DeBio Address".as_bytes().to_vec(),
-				latitude: Some("DeBio Latitude".as_bytes().to_vec()),
-				longitude: Some("DeBio Longtitude".as_bytes().to_vec()),
-				profile_image: Some("DeBio Profile Image uwu".as_bytes().to_vec()),
+				name: "DeBio Doctor".as_bytes().to_vec().try_into().unwrap(),
+				email: "DeBio Email".as_bytes().to_vec().try_into().unwrap(),
+				country: CountryCode::try_from("ID".as_bytes().to_vec()).unwrap(),
+				region: RegionCode::try_from("JK".as_bytes().to_vec()).unwrap(),
+				city: CityCode::try_from("City".as_bytes().to_vec()).unwrap(),
+				address: "DeBio Address".as_bytes().to_vec().try_into().unwrap(),
+				latitude: Some("DeBio Latitude".as_bytes().to_vec().try_into().unwrap()),
+				longitude: Some("DeBio Longtitude".as_bytes().to_vec().try_into().unwrap()),
+				profile_image: Some(
+					"DeBio Profile Image uwu".as_bytes().to_vec().try_into().unwrap()
+				),
 			}
 		));
 
 		assert_ok!(Doctors::update_doctor(
 			RuntimeOrigin::signed(1),
 			DoctorInfo {
-				name: "Abdul Hakim".as_bytes().to_vec(),
-				email: "DeBio Email".as_bytes().to_vec(),
-				country: CountryCode::from_vec("DC".as_bytes().to_vec()),
-				region: RegionCode::from_vec("DBIO".as_bytes().to_vec()),
-				city: CityCode::from_vec("City".as_bytes().to_vec()),
-				address: "DeBio Address".as_bytes().to_vec(),
-				latitude: Some("DeBio Latitude".as_bytes().to_vec()),
-				longitude: Some("DeBio Longtitude".as_bytes().to_vec()),
-				profile_image: Some("DeBio Profile Image uwu".as_bytes().to_vec()),
+				name: "Abdul Hakim".as_bytes().to_vec().try_into().unwrap(),
+				email: "DeBio Email".as_bytes().to_vec().try_into().unwrap(),
+				country: CountryCode::try_from("ID".as_bytes().to_vec()).unwrap(),
+				region: RegionCode::try_from("JK".as_bytes().to_vec()).unwrap(),
+				city: CityCode::try_from("City".as_bytes().to_vec()).unwrap(),
+				address: "DeBio Address".as_bytes().to_vec().try_into().unwrap(),
+				latitude: Some("DeBio Latitude".as_bytes().to_vec().try_into().unwrap()),
+				longitude: Some("DeBio Longtitude".as_bytes().to_vec().try_into().unwrap()),
+				profile_image: Some(
+					"DeBio Profile Image uwu".as_bytes().to_vec().try_into().unwrap()
+				),
 			}
 		));
 
@@ -108,42 +134,46 @@ fn update_doctor_works() {
 			Doctors::doctor_by_account_id(1),
 			Some(Doctor {
 				account_id: 1,
-				certifications: Vec::new(),
+				certifications: Default::default(),
 				info: DoctorInfo {
-					name: "Abdul Hakim".as_bytes().to_vec(),
-					email: "DeBio Email".as_bytes().to_vec(),
-					country: CountryCode::from_vec("DC".as_bytes().to_vec()),
-					region: RegionCode::from_vec("DBIO".as_bytes().to_vec()),
-					city: CityCode::from_vec("City".as_bytes().to_vec()),
-					address: "DeBio Address".as_bytes().to_vec(),
-					latitude: Some("DeBio Latitude".as_bytes().to_vec()),
-					longitude: Some("DeBio Longtitude".as_bytes().to_vec()),
-					profile_image: Some("DeBio Profile Image uwu".as_bytes().to_vec()),
+					name: "Abdul Hakim".as_bytes().to_vec().try_into().unwrap(),
+					email: "DeBio Email".as_bytes().to_vec().try_into().unwrap(),
+					country: CountryCode::try_from("ID".as_bytes().to_vec()).unwrap(),
+					region: RegionCode::try_from("JK".as_bytes().to_vec()).unwrap(),
+					city: CityCode::try_from("City".as_bytes().to_vec()).unwrap(),
+					address: "DeBio Address".as_bytes().to_vec().try_into().unwrap(),
+					latitude: Some("DeBio Latitude".as_bytes().to_vec().try_into().unwrap()),
+					longitude: Some("DeBio Longtitude".as_bytes().to_vec().try_into().unwrap()),
+					profile_image: Some(
+						"DeBio Profile Image uwu".as_bytes().to_vec().try_into().unwrap()
+					),
 				}
 			})
 		);
 
-		let old_country_region_code = CountryRegionCode::from_vec("DC-DBIO".as_bytes().to_vec());
-		let old_city_code = CityCode::from_vec("City".as_bytes().to_vec());
+		let old_country_region_code = CountryRegionCode::from_vec("ID-JK".as_bytes().to_vec());
+		let old_city_code = CityCode::try_from("City".as_bytes().to_vec()).unwrap();
 
 		assert_ok!(Doctors::update_doctor(
 			RuntimeOrigin::signed(1),
 			DoctorInfo {
-				name: "Abdul Hakim".as_bytes().to_vec(),
-				email: "DeBio Email".as_bytes().to_vec(),
-				country: CountryCode::from_vec("ID".as_bytes().to_vec()),
-				region: RegionCode::from_vec("DBIO".as_bytes().to_vec()),
-				city: CityCode::from_vec("City".as_bytes().to_vec()),
-				address: "DeBio Address".as_bytes().to_vec(),
-				latitude: Some("DeBio Latitude".as_bytes().to_vec()),
-				longitude: Some("DeBio Longtitude".as_bytes().to_vec()),
-				profile_image: Some("DeBio Profile Image uwu".as_bytes().to_vec()),
+				name: "Abdul Hakim".as_bytes().to_vec().try_into().unwrap(),
+				email: "DeBio Email".as_bytes().to_vec().try_into().unwrap(),
+				country: CountryCode::try_from("MY".as_bytes().to_vec()).unwrap(),
+				region: RegionCode::try_from("10".as_bytes().to_vec()).unwrap(),
+				city: CityCode::try_from("City".as_bytes().to_vec()).unwrap(),
+				address: "DeBio Address".as_bytes().to_vec().try_into().unwrap(),
+				latitude: Some("DeBio Latitude".as_bytes().to_vec().try_into().unwrap()),
+				longitude: Some("DeBio Longtitude".as_bytes().to_vec().try_into().unwrap()),
+				profile_image: Some(
+					"DeBio Profile Image uwu".as_bytes().to_vec().try_into().unwrap()
+				),
 			}
 		));
 
 		assert_eq!(
 			Doctors::doctors_by_country_region_city(&old_country_region_code, &old_city_code),
-			Some(Vec::new())
+			Some(Default::default())
 		);
 
 		assert_eq!(
@@ -151,12 +181,12 @@ fn update_doctor_works() {
 			Some(0)
 		);
 
-		let new_country_region_code = CountryRegionCode::from_vec("ID-DBIO".as_bytes().to_vec());
-		let new_city_code = CityCode::from_vec("City".as_bytes().to_vec());
+		let new_country_region_code = CountryRegionCode::from_vec("MY-10".as_bytes().to_vec());
+		let new_city_code = CityCode::try_from("City".as_bytes().to_vec()).unwrap();
 
 		assert_eq!(
 			Doctors::doctors_by_country_region_city(&new_country_region_code, &new_city_code),
-			Some(vec![1])
+			Some(vec![1].try_into().unwrap())
 		);
 
 		assert_eq!(
@@ -172,15 +202,17 @@ fn deregister_doctor_works() {
 		assert_ok!(Doctors::register_doctor(
 			RuntimeOrigin::signed(1),
 			DoctorInfo {
-				name: "DeBio Doctor".as_bytes().to_vec(),
-				email: "DeBio Email".as_bytes().to_vec(),
-				country: CountryCode::from_vec("DC".as_bytes().to_vec()),
-				region: RegionCode::from_vec("DBIO".as_bytes().to_vec()),
-				city: CityCode::from_vec("City".as_bytes().to_vec()),
-				address: "DeBio Address".as_bytes().to_vec(),
-				latitude: Some("DeBio Latitude".as_bytes().to_vec()),
-				longitude: Some("DeBio Longtitude".as_bytes().to_vec()),
-				profile_image: Some("DeBio Profile Image uwu".as_bytes().to_vec()),
+				name: "DeBio Doctor".as_bytes().to_vec().try_into().unwrap(),
+				email: "DeBio Email".as_bytes().to_vec().try_into().unwrap(),
+				country: CountryCode::try_from("ID".as_bytes().to_vec()).unwrap(),
+				region: RegionCode::try_from("JK".as_bytes().to_vec()).unwrap(),
+				city: CityCode::try_from("City".as_bytes().to_vec()).unwrap(),
+				address: "DeBio Address".as_bytes().to_vec().try_into().unwrap(),
+				latitude: Some("DeBio Latitude".as_bytes().to_vec().try_into().unwrap()),
+				longitude: Some("DeBio Longtitude".as_bytes().to_vec().try_into().unwrap()),
+				profile_image: Some(
+					"DeBio Profile Image uwu".as_bytes().to_vec().try_into().unwrap()
+				),
 			}
 		));
 
@@ -188,12 +220,12 @@ fn deregister_doctor_works() {
 
 		assert_eq!(Doctors::doctor_by_account_id(1), None);
 
-		let country_region_code = CountryRegionCode::from_vec("DC-DBIO".as_bytes().to_vec());
-		let city_code = CityCode::from_vec("City".as_bytes().to_vec());
+		let country_region_code = CountryRegionCode::from_vec("ID-JK".as_bytes().to_vec());
+		let city_code = CityCode::try_from("City".as_bytes().to_vec()).unwrap();
 
 		assert_eq!(
 			Doctors::doctors_by_country_region_city(&country_region_code, &city_code),
-			Some(Vec::new())
+			Some(Default::default())
 		);
 
 		assert_eq!(Doctors::doctor_count(), Some(0),);
@@ -211,15 +243,17 @@ fn cant_register_doctor_when_already_exist() {
 		assert_ok!(Doctors::register_doctor(
 			RuntimeOrigin::signed(1),
 			DoctorInfo {
-				name: "DeBio Doctor".as_bytes().to_vec(),
-				email: "DeBio Email".as_bytes().to_vec(),
-				country: CountryCode::from_vec("DC".as_bytes().to_vec()),
-				region: RegionCode::from_vec("DBIO".as_bytes().to_vec()),
-				city: CityCode::from_vec("City".as_bytes().to_vec()),
-				address: "DeBio Address".as_bytes().to_vec(),
-				latitude: Some("DeBio Latitude".as_bytes().to_vec()),
-				longitude: Some("DeBio Longtitude".as_bytes().to_vec()),
-				profile_image: Some("DeBio Profile Image uwu".as_bytes().to_vec()),
+				name: "DeBio Doctor".as_bytes().to_vec().try_into().unwrap(),
+				email: "DeBio Email".as_bytes().to_vec().try_into().unwrap(),
+				country: CountryCode::try_from("ID".as_bytes().to_vec()).unwrap(),
+				region: RegionCode::try_from("JK".as_bytes().to_vec()).unwrap(),
+				city: CityCode::try_from("City".as_bytes().to_vec()).unwrap(),
+				address: "DeBio Address".as_bytes().to_vec().try_into().unwrap(),
+				latitude: Some("DeBio Latitude".as_bytes().to_vec().try_into().unwrap()),
+				longitude: Some("DeBio Longtitude".as_bytes().to_vec().try_into().unwrap()),
+				profile_image: Some(
+					"DeBio Profile Image uwu".as_bytes().to_vec().try_into().unwrap()
+				),
 			}
 		));
 
@@ -227,15 +261,17 @@ fn cant_register_doctor_when_already_exist() {
 			Doctors::register_doctor(
 				RuntimeOrigin::signed(1),
 				DoctorInfo {
-					name: "DeBio Doctor".as_bytes().to_vec(),
-					email: "DeBio Email".as_bytes().to_vec(),
-					country: CountryCode::from_vec("DC".as_bytes().to_vec()),
-					region: RegionCode::from_vec("DBIO".as_bytes().to_vec()),
-					city: CityCode::from_vec("City".as_bytes().to_vec()),
-					address: "DeBio Address".as_bytes().to_vec(),
-					latitude: Some("DeBio Latitude".as_bytes().to_vec()),
-					longitude: Some("DeBio Longtitude".as_bytes().to_vec()),
-					profile_image: Some("DeBio Profile Image uwu".as_bytes().to_vec()),
+					name: "DeBio Doctor".as_bytes().to_vec().try_into().unwrap(),
+					email: "DeBio Email".as_bytes().to_vec().try_into().unwrap(),
+					country: CountryCode::try_from("ID".as_bytes().to_vec()).unwrap(),
+					region: RegionCode::try_from("JK".as_bytes().to_vec()).unwrap(),
+					city: CityCode::try_from("City".as_bytes().to_vec()).unwrap(),
+					address: "DeBio Address".as_bytes().to_vec().try_into().unwrap(),
+					latitude: Some("DeBio Latitude".as_bytes().to_vec().try_into().unwrap()),
+					longitude: Some("DeBio Longtitude".as_bytes().to_vec().try_into().unwrap()),
+					profile_image: Some(
+						"DeBio Profile Image uwu".as_bytes().to_vec().try_into().unwrap()
+					),
 				}
 			),
 			Error::<Test>::DoctorAlreadyRegistered
@@ -250,15 +286,17 @@ fn cant_update_and_deregister_doctor_when_not_exist() {
 			Doctors::update_doctor(
 				RuntimeOrigin::signed(1),
 				DoctorInfo {
-					name: "DeBio Doctor".as_bytes().to_vec(),
-					email: "DeBio Email".as_bytes().to_vec(),
-					country: CountryCode::from_vec("DC".as_bytes().to_vec()),
-					region: RegionCode::from_vec("DBIO".as_bytes().to_vec()),
-					city: CityCode::from_vec("City".as_bytes().to_vec()),
-					address: "DeBio Address".as_bytes().to_vec(),
-					latitude: Some("DeBio Latitude".as_bytes().to_vec()),
-					longitude: Some("DeBio Longtitude".as_bytes().to_vec()),
-					profile_image: Some("DeBio Profile Image uwu".as_bytes().to_vec()),
+					name: "DeBio Doctor".as_bytes().to_vec().try_into().unwrap(),
+					email: "DeBio Email".as_bytes().to_vec().try_into().unwrap(),
+					country: CountryCode::try_from("ID".as_bytes().to_vec()).unwrap(),
+					region: RegionCode::try_from("JK".as_bytes().to_vec()).unwrap(),
+					city: CityCode::try_from("City".as_bytes().to_vec()).unwrap(),
+					address: "DeBio Address".as_bytes().to_vec().try_into().unwrap(),
+					latitude: Some("DeBio Latitude".as_bytes().to_vec().try_into().unwrap()),
+					longitude: Some("DeBio Longtitude".as_bytes().to_vec().try_into().unwrap()),
+					profile_image: Some(
+						"DeBio Profile Image uwu".as_bytes().to_vec().try_into().unwrap()
+					),
 				}
 			),
 			Error::<Test>::DoctorDoesNotExist
@@ -279,32 +317,36 @@ fn call_event_should_work() {
 		assert_ok!(Doctors::register_doctor(
 			RuntimeOrigin::signed(1),
 			DoctorInfo {
-				name: "DeBio Doctor".as_bytes().to_vec(),
-				email: "DeBio Email".as_bytes().to_vec(),
-				country: CountryCode::from_vec("DC".as_bytes().to_vec()),
-				region: RegionCode::from_vec("DBIO".as_bytes().to_vec()),
-				city: CityCode::from_vec("City".as_bytes().to_vec()),
-				address: "DeBio Address".as_bytes().to_vec(),
-				latitude: Some("DeBio Latitude".as_bytes().to_vec()),
-				longitude: Some("DeBio Longtitude".as_bytes().to_vec()),
-				profile_image: Some("DeBio Profile Image uwu".as_bytes().to_vec()),
+				name: "DeBio Doctor".as_bytes().to_vec().try_into().unwrap(),
+				email: "DeBio Email".as_bytes().to_vec().try_into().unwrap(),
+				country: CountryCode::try_from("ID".as_bytes().to_vec()).unwrap(),
+				region: RegionCode::try_from("JK".as_bytes().to_vec()).unwrap(),
+				city: CityCode::try_from("City".as_bytes().to_vec()).unwrap(),
+				address: "DeBio Address".as_bytes().to_vec().try_into().unwrap(),
+				latitude: Some("DeBio Latitude".as_bytes().to_vec().try_into().unwrap()),
+				longitude: Some("DeBio Longtitude".as_bytes().to_vec().try_into().unwrap()),
+				profile_image: Some(
+					"DeBio Profile Image uwu".as_bytes().to_vec().try_into().unwrap()
+				),
 			}
 		));
 
 		System::assert_last_event(RuntimeEvent::Doctors(crate::Event::DoctorRegistered(
 			Doctor {
 				account_id: 1,
-				certifications: Vec::new(),
+				certifications: Default::default(),
 				info: DoctorInfo {
-					name: "DeBio Doctor".as_bytes().to_vec(),
-					email: "DeBio Email".as_bytes().to_vec(),
-					country: CountryCode::from_vec("DC".as_bytes().to_vec()),
-					region: RegionCode::from_vec("DBIO".as_bytes().to_vec()),
-					city: CityCode::from_vec("City".as_bytes().to_vec()),
-					address: "DeBio Address".as_bytes().to_vec(),
-					latitude: Some("DeBio Latitude".as_bytes().to_vec()),
-					longitude: Some("DeBio Longtitude".as_bytes().to_vec()),
-					profile_image: Some("DeBio Profile Image uwu".as_bytes().to_vec()),
+					name: "DeBio Doctor".as_bytes().to_vec().try_into().unwrap(),
+					email: "DeBio Email".as_bytes().to_vec().try_into().unwrap(),
+					country: CountryCode::try_from("ID".as_bytes().to_vec()).unwrap(),
+					region: RegionCode::try_from("JK".as_bytes().to_vec()).unwrap(),
+					city: CityCode::try_from("City".as_bytes().to_vec()).unwrap(),
+					address: "DeBio Address".as_bytes().to_vec().try_into().unwrap(),
+					latitude: Some("DeBio Latitude".as_bytes().to_vec().try_into().unwrap()),
+					longitude: Some("DeBio Longtitude".as_bytes().to_vec().try_into().unwrap()),
+					profile_image: Some(
+						"DeBio Profile Image uwu".as_bytes().to_vec().try_into().unwrap(),
+					),
 				},
 			},
 			1,
@@ -313,32 +355,36 @@ fn call_event_should_work() {
 		assert_ok!(Doctors::update_doctor(
 			RuntimeOrigin::signed(1),
 			DoctorInfo {
-				name: "Abdul Hakim".as_bytes().to_vec(),
-				email: "DeBio Email".as_bytes().to_vec(),
-				country: CountryCode::from_vec("ID".as_bytes().to_vec()),
-				region: RegionCode::from_vec("DBIO".as_bytes().to_vec()),
-				city: CityCode::from_vec("City".as_bytes().to_vec()),
-				address: "DeBio Address".as_bytes().to_vec(),
-				latitude: Some("DeBio Latitude".as_bytes().to_vec()),
-				longitude: Some("DeBio Longtitude".as_bytes().to_vec()),
-				profile_image: Some("DeBio Profile Image uwu".as_bytes().to_vec()),
+				name: "Abdul Hakim".as_bytes().to_vec().try_into().unwrap(),
+				email: "DeBio Email".as_bytes().to_vec().try_into().unwrap(),
+				country: CountryCode::try_from("MY".as_bytes().to_vec()).unwrap(),
+				region: RegionCode::try_from("10".as_bytes().to_vec()).unwrap(),
+				city: CityCode::try_from("City".as_bytes().to_vec()).unwrap(),
+				address: "DeBio Address".as_bytes().to_vec().try_into().unwrap(),
+				latitude: Some("DeBio Latitude".as_bytes().to_vec().try_into().unwrap()),
+				longitude: Some("DeBio Longtitude".as_bytes().to_vec().try_into().unwrap()),
+				profile_image: Some(
+					"DeBio Profile Image uwu".as_bytes().to_vec().try_into().unwrap()
+				),
 			}
 		));
 
 		System::assert_last_event(RuntimeEvent::Doctors(crate::Event::DoctorUpdated(
 			Doctor {
 				account_id: 1,
-				certifications: Vec::new(),
+				certifications: Default::default(),
 				info: DoctorInfo {
-					name: "Abdul Hakim".as_bytes().to_vec(),
-					email: "DeBio Email".as_bytes().to_vec(),
-					country: CountryCode::from_vec("ID".as_bytes().to_vec()),
-					region: RegionCode::from_vec("DBIO".as_bytes().to_vec()),
-					city: CityCode::from_vec("City".as_bytes().to_vec()),
-					address: "DeBio Address".as_bytes().to_vec(),
-					latitude: Some("DeBio Latitude".as_bytes().to_vec()),
-					longitude: Some("DeBio Longtitude".as_bytes().to_vec()),
-					profile_image: Some("DeBio Profile Image uwu".as_bytes().to_vec()),
+					name: "Abdul Hakim".as_bytes().to_vec().try_into().unwrap(),
+					email: "DeBio Email".as_bytes().to_vec().try_into().unwrap(),
+					country: CountryCode::try_from("MY".as_bytes().to_vec()).unwrap(),
+					region: RegionCode::try_from("10".as_bytes().to_vec()).unwrap(),
+					city: CityCode::try_from("City".as_bytes().to_vec()).unwrap(),
+					address: "DeBio Address".as_bytes().to_vec().try_into().unwrap(),
+					latitude: Some("DeBio Latitude".as_bytes().to_vec().try_into().unwrap()),
+					longitude: Some("DeBio Longtitude".as_bytes().to_vec().try_into().unwrap()),
+					profile_image: Some(
+						"DeBio Profile Image uwu".as_bytes().to_vec().try_into().unwrap(),
+					),
 				},
 			},
 			1,
@@ -349,20 +395,119 @@ fn call_event_should_work() {
 		System::assert_last_event(RuntimeEvent::Doctors(crate::Event::DoctorDeleted(
 			Doctor {
 				account_id: 1,
-				certifications: Vec::new(),
+				certifications: Default::default(),
 				info: DoctorInfo {
-					name: "Abdul Hakim".as_bytes().to_vec(),
-					email: "DeBio Email".as_bytes().to_vec(),
-					country: CountryCode::from_vec("ID".as_bytes().to_vec()),
-					region: RegionCode::from_vec("DBIO".as_bytes().to_vec()),
-					city: CityCode::from_vec("City".as_bytes().to_vec()),
-					address: "DeBio Address".as_bytes().to_vec(),
-					latitude: Some("DeBio Latitude".as_bytes().to_vec()),
-					longitude: Some("DeBio Longtitude".as_bytes().to_vec()),
-					profile_image: Some("DeBio Profile Image uwu".as_bytes().to_vec()),
+					name: "Abdul Hakim".as_bytes().to_vec().try_into().unwrap(),
+					email: "DeBio Email".as_bytes().to_vec().try_into().unwrap(),
+					country: CountryCode::try_from("MY".as_bytes().to_vec()).unwrap(),
+					region: RegionCode::try_from("10".as_bytes().to_vec()).unwrap(),
+					city: CityCode::try_from("City".as_bytes().to_vec()).unwrap(),
+					address: "DeBio Address".as_bytes().to_vec().try_into().unwrap(),
+					latitude: Some("DeBio Latitude".as_bytes().to_vec().try_into().unwrap()),
+					longitude: Some("DeBio Longtitude".as_bytes().to_vec().try_into().unwrap()),
+					profile_image: Some(
+						"DeBio Profile Image uwu".as_bytes().to_vec().try_into().unwrap(),
+					),
 				},
 			},
 			1,
 		)));
 	})
 }
+
+#[test]
+fn cant_register_doctor_when_city_is_full() {
+	ExternalityBuilder::build().execute_with(|| {
+		MaxDoctorsPerCity::set(1);
+
+		assert_ok!(Doctors::register_doctor(RuntimeOrigin::signed(1), doctor_info()));
+
+		assert_noop!(
+			Doctors::register_doctor(RuntimeOrigin::signed(2), doctor_info()),
+			Error::<Test>::TooManyDoctorsInCity
+		);
+
+		// Deregistering frees up the slot
+		assert_ok!(Doctors::deregister_doctor(RuntimeOrigin::signed(1)));
+		assert_ok!(Doctors::register_doctor(RuntimeOrigin::signed(2), doctor_info()));
+	})
+}
+
+#[test]
+fn migrate_truncates_doctor_info_over_its_bound() {
+	ExternalityBuilder::build().execute_with(|| {
+		let info = doctor_info();
+		// A doctor stored before doctor info was bounded
+		let legacy_doctor = (
+			1u64,
+			Vec::<sp_core::H256>::new(),
+			vec![b'X'; MAX_DOCTOR_INFO_LENGTH as usize + 1],
+			info.email.to_vec(),
+			&info.country,
+			&info.region,
+			&info.city,
+			info.address.to_vec(),
+			None::<Vec<u8>>,
+			None::<Vec<u8>>,
+			None::<Vec<u8>>,
+		);
+
+		unhashed::put(&crate::Doctors::<Test>::hashed_key_for(1), &legacy_doctor);
+		StorageVersion::new(0).put::<Doctors>();
+
+		crate::migrations::migrate::<Test>();
+
+		let doctor = Doctors::doctor_by_account_id(1).unwrap();
+
+		assert_eq!(doctor.info.name.to_vec(), vec![b'X'; MAX_DOCTOR_INFO_LENGTH as usize]);
+		assert_eq!(doctor.info.email, info.email);
+		assert_eq!(
+			Doctors::doctors_by_country_region_city(
+				CountryRegionCode::from_vec("ID-JK".as_bytes().to_vec()),
+				CityCode::try_from("CITY".as_bytes().to_vec()).unwrap()
+			),
+			Some(vec![1].try_into().unwrap())
+		);
+		assert_eq!(StorageVersion::get::<Doctors>(), StorageVersion::new(1));
+	})
+}
+
+#[test]
+fn migrate_truncates_legacy_area_codes_over_their_bound() {
+	ExternalityBuilder::build().execute_with(|| {
+		let info = doctor_info();
+		// Region and city codes were unbounded before, a longer city no longer decodes as CityCode
+		let legacy_doctor = (
+			1u64,
+			Vec::<sp_core::H256>::new(),
+			info.name.to_vec(),
+			info.email.to_vec(),
+			&info.country,
+			b"jk".to_vec(),
+			vec![b'x'; MAX_AREA_CODE_LENGTH as usize + 1],
+			info.address.to_vec(),
+			None::<Vec<u8>>,
+			None::<Vec<u8>>,
+			None::<Vec<u8>>,
+		);
+
+		unhashed::put(&crate::Doctors::<Test>::hashed_key_for(1), &legacy_doctor);
+		StorageVersion::new(0).put::<Doctors>();
+
+		crate::migrations::migrate::<Test>();
+
+		let doctor = Doctors::doctor_by_account_id(1).unwrap();
+		let city_code = CityCode::try_from(vec![b'X'; MAX_AREA_CODE_LENGTH as usize]).unwrap();
+
+		assert_eq!(doctor.info.region, info.region);
+		assert_eq!(doctor.info.city, city_code);
+		assert_eq!(
+			Doctors::doctors_by_country_region_city(
+				CountryRegionCode::from_vec("ID-JK".as_bytes().to_vec()),
+				city_code
+			),
+			Some(vec![1].try_into().unwrap())
+		);
+		assert_eq!(StorageVersion::get::<Doctors>(), StorageVersion::new(1));
+	})
+}
@@ -2,7 +2,7 @@ use super::*;
 
 #[allow(unused)]
 use crate::Pallet as ElectronicMedicalRecord;
-use frame_benchmarking::{benchmarks, impl_benchmark_test_suite, whitelisted_caller};
+use frame_benchmarking::{account, benchmarks, impl_benchmark_test_suite, whitelisted_caller};
 use frame_system::RawOrigin;
 
 benchmarks! {
@@ -75,10 +75,130 @@ benchmarks! {
 		let _emr_ids = ElectronicMedicalRecord::<T>::electronic_medical_record_by_owner_id(
 			caller.clone()
 		).unwrap();
+		let _emr = ElectronicMedicalRecord::<T>::electronic_medical_record_by_id(_emr_ids[0]).unwrap();
+		let grantee: T::AccountId = account("grantee", 0, 0);
+		let _grant_record_access = ElectronicMedicalRecord::<T>::grant_access(
+			caller_origin.clone(),
+			grantee.clone(),
+			AccessScope::ElectronicMedicalRecord(_emr_ids[0]),
+			"Second opinion".as_bytes().to_vec(),
+			1_000u32.into()
+		);
+		let _grant_file_access = ElectronicMedicalRecord::<T>::grant_access(
+			caller_origin,
+			grantee,
+			AccessScope::ElectronicMedicalRecordFile(_emr.files[0]),
+			"Second opinion".as_bytes().to_vec(),
+			1_000u32.into()
+		);
 	}: remove_electronic_medical_record(
 		RawOrigin::Signed(caller),
 		_emr_ids[0]
 	)
+
+	grant_access {
+		let g in 0 .. T::MaxGranteesPerScope::get();
+
+		let caller: T::AccountId = whitelisted_caller();
+		let grantee: T::AccountId = account("grantee", 0, 0);
+
+		let caller_origin = T::RuntimeOrigin::from(RawOrigin::Signed(caller.clone()));
+		let _add_electronic_medical_record = ElectronicMedicalRecord::<T>::add_electronic_medical_record(
+			caller_origin.clone(),
+			"DeBio EMR".as_bytes().to_vec(),
+			"DeBio EMR Category".as_bytes().to_vec(),
+			vec![
+				ElectronicMedicalRecordFileSubmission {
+					title: "DeBio EMR Document Title".as_bytes().to_vec(),
+					description: "DeBio EMR Document Description".as_bytes().to_vec(),
+					record_link: "DeBio EMR Link".as_bytes().to_vec()
+				}
+			]
+		);
+
+		let _emr_ids = ElectronicMedicalRecord::<T>::electronic_medical_record_by_owner_id(
+			caller.clone()
+		).unwrap();
+
+		// A full scope of expired grants is the worst case, every one of them is removed
+		for i in 0 .. g {
+			let _grant_access = ElectronicMedicalRecord::<T>::grant_access(
+				caller_origin.clone(),
+				account("expired_grantee", i, 0),
+				AccessScope::ElectronicMedicalRecord(_emr_ids[0]),
+				"Second opinion".as_bytes().to_vec(),
+				1_000u32.into()
+			);
+		}
+		pallet_timestamp::Pallet::<T>::set_timestamp(2_000u32.into());
+	}: grant_access(
+		RawOrigin::Signed(caller),
+		grantee,
+		AccessScope::ElectronicMedicalRecord(_emr_ids[0]),
+		"Second opinion".as_bytes().to_vec(),
+		3_000u32.into()
+	)
+
+	revoke_access {
+		let caller: T::AccountId = whitelisted_caller();
+		let grantee: T::AccountId = account("grantee", 0, 0);
+
+		let caller_origin = T::RuntimeOrigin::from(RawOrigin::Signed(caller.clone()));
+		let _add_electronic_medical_record = ElectronicMedicalRecord::<T>::add_electronic_medical_record(
+			caller_origin.clone(),
+			"DeBio EMR".as_bytes().to_vec(),
+			"DeBio EMR Category".as_bytes().to_vec(),
+			vec![
+				ElectronicMedicalRecordFileSubmission {
+					title: "DeBio EMR Document Title".as_bytes().to_vec(),
+					description: "DeBio EMR Document Description".as_bytes().to_vec(),
+					record_link: "DeBio EMR Link".as_bytes().to_vec()
+				}
+			]
+		);
+
+		let _emr_ids = ElectronicMedicalRecord::<T>::electronic_medical_record_by_owner_id(
+			caller.clone()
+		).unwrap();
+		let _grant_access = ElectronicMedicalRecord::<T>::grant_access(
+			caller_origin,
+			grantee.clone(),
+			AccessScope::ElectronicMedicalRecord(_emr_ids[0]),
+			"Second opinion".as_bytes().to_vec(),
+			1_000u32.into()
+		);
+	}: revoke_access(
+		RawOrigin::Signed(caller),
+		grantee,
+		AccessScope::ElectronicMedicalRecord(_emr_ids[0])
+	)
+
+	request_access {
+		let caller: T::AccountId = whitelisted_caller();
+		let grantee: T::AccountId = account("grantee", 0, 0);
+
+		let caller_origin = T::RuntimeOrigin::from(RawOrigin::Signed(caller.clone()));
+		let _add_electronic_medical_record = ElectronicMedicalRecord::<T>::add_electronic_medical_record(
+			caller_origin.clone(),
+			"DeBio EMR".as_bytes().to_vec(),
+			"DeBio EMR Category".as_bytes().to_vec(),
+			vec![
+				ElectronicMedicalRecordFileSubmission {
+					title: "DeBio EMR Document Title".as_bytes().to_vec(),
+					description: "DeBio EMR Document Description".as_bytes().to_vec(),
+					record_link: "DeBio EMR Link".as_bytes().to_vec()
+				}
+			]
+		);
+
+		let _emr_ids = ElectronicMedicalRecord::<T>::electronic_medical_record_by_owner_id(
+			caller.clone()
+		).unwrap();
+	}: request_access(
+		RawOrigin::Signed(grantee),
+		AccessScope::ElectronicMedicalRecord(_emr_ids[0]),
+		"Second opinion".as_bytes().to_vec()
+	)
 }
 
 impl_benchmark_test_suite! {ElectronicMedicalRecord, crate::mock::ExternalityBuilder::build(), crate::mock::Test}
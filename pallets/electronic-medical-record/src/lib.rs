@@ -1,8 +1,9 @@
 #![cfg_attr(not(feature = "std"), no_std)]
 
 use frame_support::{
-	codec::{Decode, Encode},
+	codec::{Decode, Encode, MaxEncodedLen},
 	pallet_prelude::*,
+	CloneNoBound, EqNoBound, PartialEqNoBound, RuntimeDebugNoBound,
 };
 pub use pallet::*;
 pub use scale_info::TypeInfo;
@@ -17,33 +18,60 @@ mod tests;
 mod benchmarking;
 
 use traits_electronic_medical_record::{
-	ElectronicMedicalRecordFile as ElectronicMedicalRecordFileT,
+	ElectronicMedicalRecordAccess, ElectronicMedicalRecordFile as ElectronicMedicalRecordFileT,
 	ElectronicMedicalRecordFileByElectronicMedicalRecord, ElectronicMedicalRecordFilesProvider,
 };
 
 pub mod interface;
+pub mod migrations;
 pub mod weights;
 pub use interface::ElectronicMedicalRecordInterface;
-use sp_std::prelude::*;
-
-#[derive(Encode, Decode, Clone, Default, RuntimeDebug, PartialEq, Eq, TypeInfo)]
-pub struct ElectronicMedicalRecord<AccountId, Hash>
+use sp_std::{fmt::Debug, prelude::*};
+
+/// The current storage version.
+const STORAGE_VERSION: StorageVersion = StorageVersion::new(3);
+
+#[derive(
+	Encode,
+	Decode,
+	CloneNoBound,
+	PartialEqNoBound,
+	EqNoBound,
+	RuntimeDebugNoBound,
+	TypeInfo,
+	MaxEncodedLen,
+)]
+#[scale_info(skip_type_params(MaxTitleLength, MaxFilesPerRecord))]
+#[codec(mel_bound(AccountId: MaxEncodedLen, Hash: MaxEncodedLen))]
+pub struct ElectronicMedicalRecord<AccountId, Hash, MaxTitleLength, MaxFilesPerRecord>
 where
-	Hash: PartialEq + Eq,
+	AccountId: Clone + PartialEq + Eq + Debug,
+	Hash: Clone + PartialEq + Eq + Debug,
+	MaxTitleLength: Get<u32>,
+	MaxFilesPerRecord: Get<u32>,
 {
 	pub id: Hash,
 	pub owner_id: AccountId,
-	pub title: Vec<u8>,
-	pub category: Vec<u8>,
-	pub files: Vec<Hash>,
+	pub title: BoundedVec<u8, MaxTitleLength>,
+	pub category: BoundedVec<u8, MaxTitleLength>,
+	pub files: BoundedVec<Hash, MaxFilesPerRecord>,
 }
 
-impl<AccountId, Hash> ElectronicMedicalRecord<AccountId, Hash>
+impl<AccountId, Hash, MaxTitleLength, MaxFilesPerRecord>
+	ElectronicMedicalRecord<AccountId, Hash, MaxTitleLength, MaxFilesPerRecord>
 where
-	Hash: PartialEq + Eq,
+	AccountId: Clone + PartialEq + Eq + Debug,
+	Hash: Clone + PartialEq + Eq + Debug,
+	MaxTitleLength: Get<u32>,
+	MaxFilesPerRecord: Get<u32>,
 {
-	pub fn new(id: Hash, owner_id: AccountId, title: Vec<u8>, category: Vec<u8>) -> Self {
-		Self { id, owner_id, title, category, files: Vec::<Hash>::new() }
+	pub fn new(
+		id: Hash,
+		owner_id: AccountId,
+		title: BoundedVec<u8, MaxTitleLength>,
+		category: BoundedVec<u8, MaxTitleLength>,
+	) -> Self {
+		Self { id, owner_id, title, category, files: BoundedVec::default() }
 	}
 
 	pub fn get_id(&self) -> &Hash {
@@ -54,8 +82,9 @@ where
 		&self.owner_id
 	}
 
-	pub fn add_file(&mut self, emr_files_id: Hash) {
-		self.files.push(emr_files_id);
+	/// Fails when the record already has `MaxFilesPerRecord` files.
+	pub fn add_file(&mut self, emr_files_id: Hash) -> Result<(), Hash> {
+		self.files.try_push(emr_files_id)
 	}
 
 	pub fn remove_file(&mut self, emr_files_id: Hash) {
@@ -67,29 +96,60 @@ where
 
 /// ElectronicMedicalRecordFile struct
 /// Information that is mutable by user
-#[derive(Encode, Decode, Clone, Default, RuntimeDebug, PartialEq, Eq, TypeInfo)]
-pub struct ElectronicMedicalRecordFile<Hash, Moment>
-where
-	Hash: PartialEq + Eq,
+#[derive(
+	Encode,
+	Decode,
+	CloneNoBound,
+	PartialEqNoBound,
+	EqNoBound,
+	RuntimeDebugNoBound,
+	TypeInfo,
+	MaxEncodedLen,
+)]
+#[scale_info(skip_type_params(MaxTitleLength, MaxDescriptionLength, MaxRecordLinkLength))]
+#[codec(mel_bound(Hash: MaxEncodedLen, Moment: MaxEncodedLen))]
+pub struct ElectronicMedicalRecordFile<
+	Hash,
+	Moment,
+	MaxTitleLength,
+	MaxDescriptionLength,
+	MaxRecordLinkLength,
+> where
+	Hash: Clone + PartialEq + Eq + Debug,
+	Moment: Clone + PartialEq + Eq + Debug,
+	MaxTitleLength: Get<u32>,
+	MaxDescriptionLength: Get<u32>,
+	MaxRecordLinkLength: Get<u32>,
 {
 	pub id: Hash,
 	pub electronic_medical_record_id: Hash,
-	pub title: Vec<u8>,
-	pub description: Vec<u8>, // TODO: limit the length
-	pub record_link: Vec<u8>,
+	pub title: BoundedVec<u8, MaxTitleLength>,
+	pub description: BoundedVec<u8, MaxDescriptionLength>,
+	pub record_link: BoundedVec<u8, MaxRecordLinkLength>,
 	pub uploaded_at: Moment,
 }
 
-impl<Hash, Moment> ElectronicMedicalRecordFile<Hash, Moment>
+impl<Hash, Moment, MaxTitleLength, MaxDescriptionLength, MaxRecordLinkLength>
+	ElectronicMedicalRecordFile<
+		Hash,
+		Moment,
+		MaxTitleLength,
+		MaxDescriptionLength,
+		MaxRecordLinkLength,
+	>
 where
-	Hash: PartialEq + Eq,
+	Hash: Clone + PartialEq + Eq + Debug,
+	Moment: Clone + PartialEq + Eq + Debug,
+	MaxTitleLength: Get<u32>,
+	MaxDescriptionLength: Get<u32>,
+	MaxRecordLinkLength: Get<u32>,
 {
 	pub fn new(
 		id: Hash,
 		electronic_medical_record_id: Hash,
-		title: Vec<u8>,
-		description: Vec<u8>,
-		record_link: Vec<u8>,
+		title: BoundedVec<u8, MaxTitleLength>,
+		description: BoundedVec<u8, MaxDescriptionLength>,
+		record_link: BoundedVec<u8, MaxRecordLinkLength>,
 		uploaded_at: Moment,
 	) -> Self {
 		Self { id, electronic_medical_record_id, title, description, record_link, uploaded_at }
@@ -108,9 +168,21 @@ where
 	}
 }
 
-impl<T, Hash, Moment> ElectronicMedicalRecordFileT<T> for ElectronicMedicalRecordFile<Hash, Moment>
+impl<T, Hash, Moment, MaxTitleLength, MaxDescriptionLength, MaxRecordLinkLength>
+	ElectronicMedicalRecordFileT<T>
+	for ElectronicMedicalRecordFile<
+		Hash,
+		Moment,
+		MaxTitleLength,
+		MaxDescriptionLength,
+		MaxRecordLinkLength,
+	>
 where
-	Hash: PartialEq + Eq,
+	Hash: Clone + PartialEq + Eq + Debug,
+	Moment: Clone + PartialEq + Eq + Debug,
+	MaxTitleLength: Get<u32>,
+	MaxDescriptionLength: Get<u32>,
+	MaxRecordLinkLength: Get<u32>,
 	T: frame_system::Config<Hash = Hash> + pallet_timestamp::Config<Moment = Moment>,
 {
 	fn get_id(&self) -> &T::Hash {
@@ -133,11 +205,47 @@ pub struct ElectronicMedicalRecordFileSubmission {
 	pub record_link: Vec<u8>,
 }
 
+/// Record or file covered by an access grant. A grant on a record covers all of its files.
+#[derive(Encode, Decode, Clone, RuntimeDebug, PartialEq, Eq, TypeInfo, MaxEncodedLen)]
+pub enum AccessScope<Hash> {
+	ElectronicMedicalRecord(Hash),
+	ElectronicMedicalRecordFile(Hash),
+}
+
+/// Read access to a record or file granted by its owner until `expires_at`
+#[derive(
+	Encode,
+	Decode,
+	CloneNoBound,
+	PartialEqNoBound,
+	EqNoBound,
+	RuntimeDebugNoBound,
+	TypeInfo,
+	MaxEncodedLen,
+)]
+#[scale_info(skip_type_params(MaxPurposeLength))]
+#[codec(mel_bound(AccountId: MaxEncodedLen, Hash: MaxEncodedLen, Moment: MaxEncodedLen))]
+pub struct AccessGrant<AccountId, Hash, Moment, MaxPurposeLength>
+where
+	AccountId: Clone + PartialEq + Eq + Debug,
+	Hash: Clone + PartialEq + Eq + Debug,
+	Moment: Clone + PartialEq + Eq + Debug,
+	MaxPurposeLength: Get<u32>,
+{
+	pub owner_id: AccountId,
+	pub grantee_id: AccountId,
+	pub scope: AccessScope<Hash>,
+	pub purpose: BoundedVec<u8, MaxPurposeLength>,
+	pub granted_at: Moment,
+	pub expires_at: Moment,
+}
+
 #[frame_support::pallet]
 pub mod pallet {
 	use crate::{
-		interface::ElectronicMedicalRecordInterface, weights::WeightInfo, ElectronicMedicalRecord,
-		ElectronicMedicalRecordFile, ElectronicMedicalRecordFileByElectronicMedicalRecord,
+		interface::ElectronicMedicalRecordInterface, weights::WeightInfo, AccessGrant, AccessScope,
+		ElectronicMedicalRecord, ElectronicMedicalRecordFile,
+		ElectronicMedicalRecordFileByElectronicMedicalRecord,
 		ElectronicMedicalRecordFileSubmission,
 	};
 	use frame_support::{dispatch::DispatchResultWithPostInfo, pallet_prelude::*};
@@ -149,33 +257,80 @@ pub mod pallet {
 		type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
 		type ElectronicMedicalRecord: ElectronicMedicalRecordFileByElectronicMedicalRecord<Self>;
 		type ElectronicMedicalRecordWeightInfo: WeightInfo;
+		/// Maximum length of a record title, record category or file title.
+		#[pallet::constant]
+		type MaxTitleLength: Get<u32>;
+		/// Maximum length of a file description.
+		#[pallet::constant]
+		type MaxDescriptionLength: Get<u32>;
+		/// Maximum length of a file record link.
+		#[pallet::constant]
+		type MaxRecordLinkLength: Get<u32>;
+		/// Maximum number of files attached to a single record.
+		#[pallet::constant]
+		type MaxFilesPerRecord: Get<u32>;
+		/// Maximum number of records indexed per owner.
+		#[pallet::constant]
+		type MaxRecordsPerOwner: Get<u32>;
+		/// Maximum length of the purpose of an access grant or access request.
+		#[pallet::constant]
+		type MaxPurposeLength: Get<u32>;
+		/// Maximum number of accounts granted access to a single record or file.
+		#[pallet::constant]
+		type MaxGranteesPerScope: Get<u32>;
 	}
 
 	// ----- This is template code, every pallet needs this ---
 	#[pallet::pallet]
+	#[pallet::storage_version(STORAGE_VERSION)]
 	#[pallet::generate_store(pub(super) trait Store)]
-	#[pallet::without_storage_info]
 	pub struct Pallet<T>(_);
 
 	#[pallet::hooks]
-	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {}
+	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+		fn on_runtime_upgrade() -> Weight {
+			crate::migrations::migrate::<T>()
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn pre_upgrade() -> Result<Vec<u8>, &'static str> {
+			crate::migrations::pre_upgrade::<T>()
+		}
+	}
 	// --------------------------------------------------------
 
 	// ----- Types -------
 	pub type AccountIdOf<T> = <T as frame_system::Config>::AccountId;
 	pub type HashOf<T> = <T as frame_system::Config>::Hash;
 	pub type MomentOf<T> = <T as pallet_timestamp::Config>::Moment;
-	pub type ElectronicMedicalRecordOf<T> = ElectronicMedicalRecord<AccountIdOf<T>, HashOf<T>>;
-	pub type ElectronicMedicalRecordFileOf<T> = ElectronicMedicalRecordFile<HashOf<T>, MomentOf<T>>;
+	pub type ElectronicMedicalRecordOf<T> = ElectronicMedicalRecord<
+		AccountIdOf<T>,
+		HashOf<T>,
+		<T as Config>::MaxTitleLength,
+		<T as Config>::MaxFilesPerRecord,
+	>;
+	pub type ElectronicMedicalRecordFileOf<T> = ElectronicMedicalRecordFile<
+		HashOf<T>,
+		MomentOf<T>,
+		<T as Config>::MaxTitleLength,
+		<T as Config>::MaxDescriptionLength,
+		<T as Config>::MaxRecordLinkLength,
+	>;
 	pub type ElectronicMedicalRecordIdOf<T> = HashOf<T>;
 	pub type ElectronicMedicalRecordFileIdOf<T> = HashOf<T>;
 	pub type ElectronicMedicalRecordFileSubmissionOf = ElectronicMedicalRecordFileSubmission;
+	pub type AccessScopeOf<T> = AccessScope<HashOf<T>>;
+	pub type AccessGrantOf<T> =
+		AccessGrant<AccountIdOf<T>, HashOf<T>, MomentOf<T>, <T as Config>::MaxPurposeLength>;
+	pub type ElectronicMedicalRecordIdsOf<T> =
+		BoundedVec<ElectronicMedicalRecordIdOf<T>, <T as Config>::MaxRecordsPerOwner>;
+	pub type AccessGranteeIdsOf<T> = BoundedVec<AccountIdOf<T>, <T as Config>::MaxGranteesPerScope>;
 
 	// ------- Storage -------------
 	#[pallet::storage]
 	#[pallet::getter(fn electronic_medical_record_by_owner_id)]
 	pub type ElectronicMedicalRecordByOwner<T> =
-		StorageMap<_, Blake2_128Concat, AccountIdOf<T>, Vec<ElectronicMedicalRecordIdOf<T>>>;
+		StorageMap<_, Blake2_128Concat, AccountIdOf<T>, ElectronicMedicalRecordIdsOf<T>>;
 
 	#[pallet::storage]
 	#[pallet::getter(fn electronic_medical_record_by_id)]
@@ -203,6 +358,22 @@ pub mod pallet {
 	#[pallet::storage]
 	#[pallet::getter(fn electronic_medical_record_count)]
 	pub type ElectronicMedicalRecordCount<T> = StorageValue<_, u64>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn access_grant)]
+	pub type AccessGrants<T> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		AccountIdOf<T>,
+		Blake2_128Concat,
+		AccessScopeOf<T>,
+		AccessGrantOf<T>,
+	>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn access_grantees_by_scope)]
+	pub type AccessGranteesByScope<T> =
+		StorageMap<_, Blake2_128Concat, AccessScopeOf<T>, AccessGranteeIdsOf<T>, ValueQuery>;
 	//                                _,  Hasher         ,  Key     ,  Value
 	// -----------------------------
 
@@ -218,6 +389,15 @@ pub mod pallet {
 		//// ElectronicMedicalRecord deleted
 		/// parameters, [ElectronicMedicalRecord, who]
 		ElectronicMedicalRecordRemoved(ElectronicMedicalRecordOf<T>, AccountIdOf<T>),
+		/// Read access granted
+		/// parameters, [AccessGrant]
+		AccessGranted(AccessGrantOf<T>),
+		/// Read access revoked
+		/// parameters, [owner, grantee, AccessScope]
+		AccessRevoked(AccountIdOf<T>, AccountIdOf<T>, AccessScopeOf<T>),
+		/// Read access requested from the owner
+		/// parameters, [requester, owner, AccessScope, purpose]
+		AccessRequested(AccountIdOf<T>, AccountIdOf<T>, AccessScopeOf<T>, Vec<u8>),
 	}
 
 	// Errors inform users that something went wrong.
@@ -229,6 +409,26 @@ pub mod pallet {
 		NotElectronicMedicalRecordOwner,
 		/// Ordering a electronic_medical_record that does not exist
 		ElectronicMedicalRecordDoesNotExist,
+		/// Title or category exceeds the maximum length
+		TitleTooLong,
+		/// File description exceeds the maximum length
+		DescriptionTooLong,
+		/// File record link exceeds the maximum length
+		RecordLinkTooLong,
+		/// Record exceeds the maximum number of files
+		TooManyFiles,
+		/// Owner reached the maximum number of records
+		TooManyRecords,
+		/// Referencing a electronic_medical_record_file that does not exist
+		ElectronicMedicalRecordFileDoesNotExist,
+		/// Access grant must expire in the future
+		InvalidAccessExpiry,
+		/// Purpose exceeds the maximum length
+		PurposeTooLong,
+		/// Revoking an access grant that does not exist
+		AccessGrantNotFound,
+		/// Record or file reached the maximum number of grantees
+		TooManyGrantees,
 	}
 
 	#[pallet::call]
@@ -304,6 +504,70 @@ pub mod pallet {
 				Err(error) => Err(error.into()),
 			}
 		}
+
+		/// Grant `grantee_id` read access to a record or file until `expires_at`. When the scope
+		/// already has `MaxGranteesPerScope` grantees, every expired grant on it is removed first.
+		#[pallet::weight(T::ElectronicMedicalRecordWeightInfo::grant_access(
+			T::MaxGranteesPerScope::get()
+		))]
+		pub fn grant_access(
+			origin: OriginFor<T>,
+			grantee_id: AccountIdOf<T>,
+			scope: AccessScopeOf<T>,
+			purpose: Vec<u8>,
+			expires_at: MomentOf<T>,
+		) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+
+			match Self::do_grant_access(&who, &grantee_id, &scope, &purpose, expires_at) {
+				Ok((access_grant, checked_grantees)) => {
+					let actual_weight =
+						T::ElectronicMedicalRecordWeightInfo::grant_access(checked_grantees);
+
+					Self::deposit_event(Event::AccessGranted(access_grant));
+					Ok(Some(actual_weight).into())
+				},
+				Err(error) => Err(error.into()),
+			}
+		}
+
+		#[pallet::weight(T::ElectronicMedicalRecordWeightInfo::revoke_access())]
+		pub fn revoke_access(
+			origin: OriginFor<T>,
+			grantee_id: AccountIdOf<T>,
+			scope: AccessScopeOf<T>,
+		) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+
+			match Self::do_revoke_access(&who, &grantee_id, &scope) {
+				Ok(()) => {
+					Self::deposit_event(Event::AccessRevoked(who, grantee_id, scope));
+					Ok(().into())
+				},
+				Err(error) => Err(error.into()),
+			}
+		}
+
+		#[pallet::weight(T::ElectronicMedicalRecordWeightInfo::request_access())]
+		pub fn request_access(
+			origin: OriginFor<T>,
+			scope: AccessScopeOf<T>,
+			purpose: Vec<u8>,
+		) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+
+			if purpose.len() > T::MaxPurposeLength::get() as usize {
+				return Err(Error::<T>::PurposeTooLong.into())
+			}
+
+			match Self::scope_owner(&scope) {
+				Ok(owner_id) => {
+					Self::deposit_event(Event::AccessRequested(who, owner_id, scope, purpose));
+					Ok(().into())
+				},
+				Err(error) => Err(error.into()),
+			}
+		}
 	}
 }
 
@@ -352,6 +616,8 @@ impl<T: Config> ElectronicMedicalRecordInterface<T> for Pallet<T> {
 		category: &[u8],
 		files: &[Self::ElectronicMedicalRecordFileSubmission],
 	) -> Result<Self::ElectronicMedicalRecord, Self::Error> {
+		Self::validate_electronic_medical_record(title, category, files)?;
+
 		let owner_electronic_medical_record_count =
 			<Self as ElectronicMedicalRecordInterface<T>>::electronic_medical_record_count_by_owner(
 				owner_id,
@@ -364,17 +630,18 @@ impl<T: Config> ElectronicMedicalRecordInterface<T> for Pallet<T> {
 		let electronic_medical_record = ElectronicMedicalRecord::new(
 			electronic_medical_record_id,
 			owner_id.clone(),
-			title.to_vec(),
-			category.to_vec(),
+			title.to_vec().try_into().map_err(|_| Error::<T>::TitleTooLong)?,
+			category.to_vec().try_into().map_err(|_| Error::<T>::TitleTooLong)?,
 		);
 
+		Self::add_electronic_medical_record_by_owner(owner_id, &electronic_medical_record_id)?;
+
 		// Store to ElectronicMedicalRecordById storage
 		ElectronicMedicalRecordById::<T>::insert(
 			electronic_medical_record_id,
 			&electronic_medical_record,
 		);
 
-		Self::add_electronic_medical_record_by_owner(owner_id, &electronic_medical_record_id);
 		Self::add_electronic_medical_record_count();
 		Self::add_electronic_medical_record_count_by_owner(owner_id);
 
@@ -391,9 +658,17 @@ impl<T: Config> ElectronicMedicalRecordInterface<T> for Pallet<T> {
 			let electronic_medical_record_file = ElectronicMedicalRecordFile::new(
 				electronic_medical_record_file_id,
 				electronic_medical_record_id,
-				emr_files.title.clone(),
-				emr_files.description.clone(),
-				emr_files.record_link.clone(),
+				emr_files.title.clone().try_into().map_err(|_| Error::<T>::TitleTooLong)?,
+				emr_files
+					.description
+					.clone()
+					.try_into()
+					.map_err(|_| Error::<T>::DescriptionTooLong)?,
+				emr_files
+					.record_link
+					.clone()
+					.try_into()
+					.map_err(|_| Error::<T>::RecordLinkTooLong)?,
 				pallet_timestamp::Pallet::<T>::get(),
 			);
 
@@ -403,7 +678,8 @@ impl<T: Config> ElectronicMedicalRecordInterface<T> for Pallet<T> {
 				&electronic_medical_record_file,
 			);
 
-			// Associate created electronic_medical_record_file to the electronic_medical_record
+			// Associate created electronic_medical_record_file to the electronic_medical_record,
+			// the number of files was validated against MaxFilesPerRecord above
 			T::ElectronicMedicalRecord::associate(
 				&electronic_medical_record_id,
 				&electronic_medical_record_file_id,
@@ -459,8 +735,14 @@ impl<T: Config> ElectronicMedicalRecordInterface<T> for Pallet<T> {
 
 			// Remove electronic_medical_record_file from storage
 			ElectronicMedicalRecordFileById::<T>::take(emr_file_id).unwrap();
+
+			Self::remove_access_grants(&AccessScope::ElectronicMedicalRecordFile(*emr_file_id));
 		}
 
+		Self::remove_access_grants(&AccessScope::ElectronicMedicalRecord(
+			*electronic_medical_record_id,
+		));
+
 		// Remove electronic_medical_record from storage
 		ElectronicMedicalRecordById::<T>::take(electronic_medical_record_id).unwrap();
 
@@ -477,7 +759,7 @@ impl<T: Config> ElectronicMedicalRecordInterface<T> for Pallet<T> {
 	}
 
 	fn electronic_medical_record_by_owner_id(owner_id: &T::AccountId) -> Option<Vec<T::Hash>> {
-		ElectronicMedicalRecordByOwner::<T>::get(owner_id)
+		ElectronicMedicalRecordByOwner::<T>::get(owner_id).map(|ids| ids.into_inner())
 	}
 
 	fn electronic_medical_record_count_by_owner(owner_id: &T::AccountId) -> u64 {
@@ -499,16 +781,52 @@ impl<T: Config> ElectronicMedicalRecordInterface<T> for Pallet<T> {
 
 /// Pallet Methods
 impl<T: Config> Pallet<T> {
+	// Validate electronic_medical_record input against the configured limits
+	pub fn validate_electronic_medical_record(
+		title: &[u8],
+		category: &[u8],
+		files: &[ElectronicMedicalRecordFileSubmissionOf],
+	) -> Result<(), Error<T>> {
+		let max_title_length = T::MaxTitleLength::get() as usize;
+
+		if title.len() > max_title_length || category.len() > max_title_length {
+			return Err(Error::<T>::TitleTooLong)
+		}
+
+		if files.len() > T::MaxFilesPerRecord::get() as usize {
+			return Err(Error::<T>::TooManyFiles)
+		}
+
+		for emr_file in files {
+			if emr_file.title.len() > max_title_length {
+				return Err(Error::<T>::TitleTooLong)
+			}
+
+			if emr_file.description.len() > T::MaxDescriptionLength::get() as usize {
+				return Err(Error::<T>::DescriptionTooLong)
+			}
+
+			if emr_file.record_link.len() > T::MaxRecordLinkLength::get() as usize {
+				return Err(Error::<T>::RecordLinkTooLong)
+			}
+		}
+
+		Ok(())
+	}
+
 	// Add electronic_medical_record by owner
 	pub fn add_electronic_medical_record_by_owner(
 		owner_id: &T::AccountId,
 		electronic_medical_record_id: &T::Hash,
-	) {
+	) -> Result<(), Error<T>> {
 		let mut electronic_medical_record =
 			ElectronicMedicalRecordByOwner::<T>::get(owner_id).unwrap_or_default();
 
-		electronic_medical_record.push(*electronic_medical_record_id);
-		ElectronicMedicalRecordByOwner::<T>::insert(owner_id, &electronic_medical_record)
+		electronic_medical_record
+			.try_push(*electronic_medical_record_id)
+			.map_err(|_| Error::<T>::TooManyRecords)?;
+		ElectronicMedicalRecordByOwner::<T>::insert(owner_id, &electronic_medical_record);
+		Ok(())
 	}
 
 	// Subtract electronic_medical_record by owner
@@ -522,6 +840,125 @@ impl<T: Config> Pallet<T> {
 		ElectronicMedicalRecordByOwner::<T>::insert(owner_id, electronic_medical_record);
 	}
 
+	// Owner of the record or file an access scope refers to
+	pub fn scope_owner(scope: &AccessScopeOf<T>) -> Result<T::AccountId, Error<T>> {
+		let electronic_medical_record_id = match scope {
+			AccessScope::ElectronicMedicalRecord(electronic_medical_record_id) =>
+				*electronic_medical_record_id,
+			AccessScope::ElectronicMedicalRecordFile(electronic_medical_record_file_id) =>
+				ElectronicMedicalRecordFileById::<T>::get(electronic_medical_record_file_id)
+					.ok_or(Error::<T>::ElectronicMedicalRecordFileDoesNotExist)?
+					.electronic_medical_record_id,
+		};
+
+		ElectronicMedicalRecordById::<T>::get(electronic_medical_record_id)
+			.map(|electronic_medical_record| electronic_medical_record.owner_id)
+			.ok_or(Error::<T>::ElectronicMedicalRecordDoesNotExist)
+	}
+
+	/// Returns the grant with the number of grantees checked for an expired grant
+	pub fn do_grant_access(
+		owner_id: &T::AccountId,
+		grantee_id: &T::AccountId,
+		scope: &AccessScopeOf<T>,
+		purpose: &[u8],
+		expires_at: MomentOf<T>,
+	) -> Result<(AccessGrantOf<T>, u32), Error<T>> {
+		if Self::scope_owner(scope)? != *owner_id {
+			return Err(Error::<T>::NotElectronicMedicalRecordOwner)
+		}
+
+		let purpose: BoundedVec<u8, T::MaxPurposeLength> =
+			purpose.to_vec().try_into().map_err(|_| Error::<T>::PurposeTooLong)?;
+
+		let now = pallet_timestamp::Pallet::<T>::get();
+		if expires_at <= now {
+			return Err(Error::<T>::InvalidAccessExpiry)
+		}
+
+		let mut checked_grantees = 0;
+
+		if !AccessGrants::<T>::contains_key(grantee_id, scope) {
+			let mut grantee_ids = AccessGranteesByScope::<T>::get(scope);
+
+			if grantee_ids.try_push(grantee_id.clone()).is_err() {
+				checked_grantees = grantee_ids.len() as u32;
+				Self::remove_expired_access_grants(scope, &mut grantee_ids, now);
+
+				grantee_ids
+					.try_push(grantee_id.clone())
+					.map_err(|_| Error::<T>::TooManyGrantees)?;
+			}
+
+			AccessGranteesByScope::<T>::insert(scope, grantee_ids);
+		}
+
+		let access_grant = AccessGrant {
+			owner_id: owner_id.clone(),
+			grantee_id: grantee_id.clone(),
+			scope: scope.clone(),
+			purpose,
+			granted_at: now,
+			expires_at,
+		};
+		AccessGrants::<T>::insert(grantee_id, scope, &access_grant);
+
+		Ok((access_grant, checked_grantees))
+	}
+
+	pub fn do_revoke_access(
+		owner_id: &T::AccountId,
+		grantee_id: &T::AccountId,
+		scope: &AccessScopeOf<T>,
+	) -> Result<(), Error<T>> {
+		let access_grant =
+			AccessGrants::<T>::get(grantee_id, scope).ok_or(Error::<T>::AccessGrantNotFound)?;
+
+		if access_grant.owner_id != *owner_id {
+			return Err(Error::<T>::NotElectronicMedicalRecordOwner)
+		}
+
+		AccessGrants::<T>::remove(grantee_id, scope);
+		AccessGranteesByScope::<T>::mutate(scope, |grantee_ids| {
+			grantee_ids.retain(|account_id| account_id != grantee_id);
+		});
+
+		Ok(())
+	}
+
+	// Remove every grant on a record or file that no longer exists
+	pub fn remove_access_grants(scope: &AccessScopeOf<T>) {
+		for grantee_id in AccessGranteesByScope::<T>::take(scope) {
+			AccessGrants::<T>::remove(&grantee_id, scope);
+		}
+	}
+
+	// Remove the grants on a scope that have expired, along with their grantees
+	fn remove_expired_access_grants(
+		scope: &AccessScopeOf<T>,
+		grantee_ids: &mut AccessGranteeIdsOf<T>,
+		now: MomentOf<T>,
+	) {
+		grantee_ids.retain(|grantee_id| {
+			let has_expired = AccessGrants::<T>::get(grantee_id, scope)
+				.map_or(true, |access_grant| access_grant.expires_at <= now);
+
+			if has_expired {
+				AccessGrants::<T>::remove(grantee_id, scope);
+			}
+
+			!has_expired
+		});
+	}
+
+	// Whether a grant for the scope exists and has not expired
+	pub fn has_valid_grant(account_id: &T::AccountId, scope: &AccessScopeOf<T>) -> bool {
+		let now = pallet_timestamp::Pallet::<T>::get();
+
+		AccessGrants::<T>::get(account_id, scope)
+			.map_or(false, |access_grant| access_grant.expires_at > now)
+	}
+
 	// Add electronic_medical_record count
 	pub fn add_electronic_medical_record_count() {
 		let electronic_medical_record_count = <ElectronicMedicalRecordCount<T>>::get().unwrap_or(0);
@@ -590,10 +1027,13 @@ impl<T: Config> ElectronicMedicalRecordFilesProvider<T> for Pallet<T> {
 	}
 }
 
-impl<T, AccountId, Hash> ElectronicMedicalRecordFileOwnerInfo<T>
-	for ElectronicMedicalRecord<AccountId, Hash>
+impl<T, AccountId, Hash, MaxTitleLength, MaxFilesPerRecord> ElectronicMedicalRecordFileOwnerInfo<T>
+	for ElectronicMedicalRecord<AccountId, Hash, MaxTitleLength, MaxFilesPerRecord>
 where
-	Hash: PartialEq + Eq,
+	AccountId: Clone + PartialEq + Eq + Debug,
+	Hash: Clone + PartialEq + Eq + Debug,
+	MaxTitleLength: Get<u32>,
+	MaxFilesPerRecord: Get<u32>,
 	T: frame_system::Config<Hash = Hash>,
 {
 	fn get_electronic_medical_record_id(&self) -> &T::Hash {
@@ -602,7 +1042,7 @@ where
 }
 
 impl<T: Config> ElectronicMedicalRecordFileByElectronicMedicalRecord<T> for Pallet<T> {
-	type ElectronicMedicalRecord = ElectronicMedicalRecord<T::AccountId, T::Hash>;
+	type ElectronicMedicalRecord = ElectronicMedicalRecordOf<T>;
 
 	fn associate(
 		electronic_medical_record_id: &T::Hash,
@@ -614,7 +1054,8 @@ impl<T: Config> ElectronicMedicalRecordFileByElectronicMedicalRecord<T> for Pall
 				match electronic_medical_record {
 					None => (), // If electronic_medical_record does not exist, do nothing
 					Some(electronic_medical_record) => {
-						electronic_medical_record.add_file(*electronic_medical_record_file_id);
+						let _ =
+							electronic_medical_record.add_file(*electronic_medical_record_file_id);
 					},
 				}
 			},
@@ -636,3 +1077,41 @@ impl<T: Config> ElectronicMedicalRecordFileByElectronicMedicalRecord<T> for Pall
 		);
 	}
 }
+
+impl<T: Config> ElectronicMedicalRecordAccess<T> for Pallet<T> {
+	fn can_read_electronic_medical_record(
+		account_id: &T::AccountId,
+		electronic_medical_record_id: &T::Hash,
+	) -> bool {
+		let electronic_medical_record =
+			match ElectronicMedicalRecordById::<T>::get(electronic_medical_record_id) {
+				Some(electronic_medical_record) => electronic_medical_record,
+				None => return false,
+			};
+
+		electronic_medical_record.owner_id == *account_id ||
+			Self::has_valid_grant(
+				account_id,
+				&AccessScope::ElectronicMedicalRecord(*electronic_medical_record_id),
+			)
+	}
+
+	fn can_read_electronic_medical_record_file(
+		account_id: &T::AccountId,
+		electronic_medical_record_file_id: &T::Hash,
+	) -> bool {
+		let electronic_medical_record_file =
+			match ElectronicMedicalRecordFileById::<T>::get(electronic_medical_record_file_id) {
+				Some(electronic_medical_record_file) => electronic_medical_record_file,
+				None => return false,
+			};
+
+		Self::can_read_electronic_medical_record(
+			account_id,
+			&electronic_medical_record_file.electronic_medical_record_id,
+		) || Self::has_valid_grant(
+			account_id,
+			&AccessScope::ElectronicMedicalRecordFile(*electronic_medical_record_file_id),
+		)
+	}
+}
@@ -0,0 +1,247 @@
+use crate::{
+	AccessGrant, AccessGrantOf, AccessGranteesByScope, AccessGrants, AccessScope, AccountIdOf,
+	Config, ElectronicMedicalRecord, ElectronicMedicalRecordById, ElectronicMedicalRecordFile,
+	ElectronicMedicalRecordFileById, ElectronicMedicalRecordFileOf, ElectronicMedicalRecordIdOf,
+	ElectronicMedicalRecordOf, HashOf, MomentOf, Pallet,
+};
+use frame_support::{
+	codec::{Decode, Encode},
+	log,
+	storage::migration::storage_key_iter,
+	traits::{Get, PalletInfoAccess},
+	weights::Weight,
+	Blake2_128Concat, BoundedVec,
+};
+use sp_std::vec::Vec;
+
+/// Fails when a legacy record index does not fit in `MaxRecordsPerOwner`
+#[cfg(feature = "try-runtime")]
+pub fn pre_upgrade<T: Config>() -> Result<Vec<u8>, &'static str> {
+	use frame_support::traits::StorageVersion;
+
+	let max_records = T::MaxRecordsPerOwner::get() as usize;
+
+	if StorageVersion::get::<Pallet<T>>() < 1 &&
+		version::v1::record_indexes::<T>().any(|(_, record_ids)| record_ids.len() > max_records)
+	{
+		return Err("Record index holds more ids than MaxRecordsPerOwner")
+	}
+
+	Ok(Vec::new())
+}
+
+pub fn migrate<T: Config>() -> Weight {
+	use frame_support::traits::StorageVersion;
+
+	let mut weight: Weight = Weight::zero();
+	let mut version = StorageVersion::get::<Pallet<T>>();
+
+	if version < 1 {
+		weight = weight.saturating_add(version::v1::migrate::<T>());
+		version = StorageVersion::new(1);
+	}
+
+	if version < 2 {
+		weight = weight.saturating_add(version::v2::migrate::<T>());
+		version = StorageVersion::new(2);
+	}
+
+	if version < 3 {
+		weight = weight.saturating_add(version::v3::migrate::<T>());
+		version = StorageVersion::new(3);
+	}
+
+	version.put::<Pallet<T>>();
+	weight
+}
+
+mod version {
+	use super::*;
+
+	pub mod v1 {
+		use super::*;
+
+		/// Record id indexes are bounded by `MaxRecordsPerOwner` and encode like the lists they
+		/// replace, so they are only checked. An index holding more than `MaxRecordsPerOwner` ids
+		/// is left as it is and reported instead of dropping record ids, raise
+		/// `MaxRecordsPerOwner` above it before upgrading. Existing records keep their fields,
+		/// the new length limits are enforced when a record is added or updated.
+		pub fn migrate<T: Config>() -> Weight {
+			let mut weight = T::DbWeight::get().writes(1);
+			let max_records = T::MaxRecordsPerOwner::get() as usize;
+
+			for (owner_id, record_ids) in record_indexes::<T>() {
+				weight = weight.saturating_add(T::DbWeight::get().reads(1));
+
+				if record_ids.len() > max_records {
+					log::error!(
+						target: "runtime::electronic-medical-record",
+						"Record index of {:?} holds {} ids, more than MaxRecordsPerOwner ({})",
+						owner_id,
+						record_ids.len(),
+						max_records,
+					);
+				}
+			}
+
+			weight
+		}
+
+		/// Record id indexes as stored before v1
+		pub fn record_indexes<T: Config>(
+		) -> impl Iterator<Item = (AccountIdOf<T>, Vec<ElectronicMedicalRecordIdOf<T>>)> {
+			storage_key_iter::<AccountIdOf<T>, Vec<ElectronicMedicalRecordIdOf<T>>, Blake2_128Concat>(
+				Pallet::<T>::name().as_bytes(),
+				b"ElectronicMedicalRecordByOwner",
+			)
+		}
+	}
+
+	pub mod v2 {
+		use super::*;
+
+		#[derive(Encode, Decode)]
+		pub struct OldElectronicMedicalRecord<AccountId, Hash> {
+			pub id: Hash,
+			pub owner_id: AccountId,
+			pub title: Vec<u8>,
+			pub category: Vec<u8>,
+			pub files: Vec<Hash>,
+		}
+
+		#[derive(Encode, Decode)]
+		pub struct OldElectronicMedicalRecordFile<Hash, Moment> {
+			pub id: Hash,
+			pub electronic_medical_record_id: Hash,
+			pub title: Vec<u8>,
+			pub description: Vec<u8>,
+			pub record_link: Vec<u8>,
+			pub uploaded_at: Moment,
+		}
+
+		#[derive(Encode, Decode)]
+		pub struct OldAccessGrant<AccountId, Hash, Moment> {
+			pub owner_id: AccountId,
+			pub grantee_id: AccountId,
+			pub scope: AccessScope<Hash>,
+			pub purpose: Vec<u8>,
+			pub granted_at: Moment,
+			pub expires_at: Moment,
+		}
+
+		/// Truncates `value` to the bound and logs the entry when it did not fit.
+		fn bounded<I: Clone, S: Get<u32>>(value: Vec<I>, entry: &str) -> BoundedVec<I, S> {
+			if value.len() > S::get() as usize {
+				log::warn!(
+					target: "runtime::electronic-medical-record",
+					"{} exceeds its bound of {}, truncated from {} items",
+					entry,
+					S::get(),
+					value.len(),
+				);
+			}
+
+			BoundedVec::truncate_from(value)
+		}
+
+		/// Record, file and access grant fields become bounded. Legacy values that exceed a bound
+		/// are truncated and logged, which only happens when a bound was lowered below the
+		/// length of stored data.
+		pub fn migrate<T: Config>() -> Weight {
+			let mut weight = T::DbWeight::get().writes(1);
+
+			ElectronicMedicalRecordById::<T>::translate(
+				|_key, old: OldElectronicMedicalRecord<AccountIdOf<T>, HashOf<T>>| {
+					weight = weight.saturating_add(T::DbWeight::get().reads_writes(1, 1));
+
+					let record: ElectronicMedicalRecordOf<T> = ElectronicMedicalRecord {
+						id: old.id,
+						owner_id: old.owner_id,
+						title: bounded(old.title, "Record title"),
+						category: bounded(old.category, "Record category"),
+						files: bounded(old.files, "Record files"),
+					};
+					Some(record)
+				},
+			);
+
+			ElectronicMedicalRecordFileById::<T>::translate(
+				|_key, old: OldElectronicMedicalRecordFile<HashOf<T>, MomentOf<T>>| {
+					weight = weight.saturating_add(T::DbWeight::get().reads_writes(1, 1));
+
+					let file: ElectronicMedicalRecordFileOf<T> = ElectronicMedicalRecordFile {
+						id: old.id,
+						electronic_medical_record_id: old.electronic_medical_record_id,
+						title: bounded(old.title, "File title"),
+						description: bounded(old.description, "File description"),
+						record_link: bounded(old.record_link, "File record link"),
+						uploaded_at: old.uploaded_at,
+					};
+					Some(file)
+				},
+			);
+
+			AccessGrants::<T>::translate(
+				|_grantee, _scope, old: OldAccessGrant<AccountIdOf<T>, HashOf<T>, MomentOf<T>>| {
+					weight = weight.saturating_add(T::DbWeight::get().reads_writes(1, 1));
+
+					let access_grant: AccessGrantOf<T> = AccessGrant {
+						owner_id: old.owner_id,
+						grantee_id: old.grantee_id,
+						scope: old.scope,
+						purpose: bounded(old.purpose, "Access grant purpose"),
+						granted_at: old.granted_at,
+						expires_at: old.expires_at,
+					};
+					Some(access_grant)
+				},
+			);
+
+			weight
+		}
+	}
+
+	pub mod v3 {
+		use super::*;
+
+		/// Grants are indexed by scope so they can be removed with their record or file. Grants on
+		/// records or files that were already removed are dropped, as are grants that do not fit
+		/// in `MaxGranteesPerScope`, which are logged.
+		pub fn migrate<T: Config>() -> Weight {
+			let mut weight = T::DbWeight::get().writes(1);
+			let mut stale_grants = Vec::new();
+
+			for (grantee_id, scope, _) in AccessGrants::<T>::iter() {
+				weight = weight.saturating_add(T::DbWeight::get().reads(2));
+
+				if Pallet::<T>::scope_owner(&scope).is_err() {
+					stale_grants.push((grantee_id, scope));
+					continue
+				}
+
+				let indexed = AccessGranteesByScope::<T>::try_mutate(&scope, |grantee_ids| {
+					grantee_ids.try_push(grantee_id.clone())
+				});
+
+				if indexed.is_err() {
+					log::warn!(
+						target: "runtime::electronic-medical-record",
+						"Access grant of {:?} exceeds MaxGranteesPerScope ({}), removed",
+						grantee_id,
+						T::MaxGranteesPerScope::get(),
+					);
+					stale_grants.push((grantee_id, scope));
+				} else {
+					weight = weight.saturating_add(T::DbWeight::get().writes(1));
+				}
+			}
+
+			for (grantee_id, scope) in stale_grants {
+				weight = weight.saturating_add(T::DbWeight::get().writes(1));
+				AccessGrants::<T>::remove(grantee_id, scope);
+			}
+
+			weight
+		}
+	}
+}
@@ -56,10 +56,27 @@ impl frame_system::Config for Test {
 	type MaxConsumers = frame_support::traits::ConstU32<16>;
 }
 
+parameter_types! {
+	pub static MaxTitleLength: u32 = 256;
+	pub static MaxDescriptionLength: u32 = 1024;
+	pub static MaxRecordLinkLength: u32 = 512;
+	pub static MaxFilesPerRecord: u32 = 50;
+	pub static MaxRecordsPerOwner: u32 = 1_000;
+	pub static MaxPurposeLength: u32 = 256;
+	pub static MaxGranteesPerScope: u32 = 100;
+}
+
 impl electronic_medical_record::Config for Test {
 	type RuntimeEvent = RuntimeEvent;
 	type ElectronicMedicalRecord = ElectronicMedicalRecord;
 	type ElectronicMedicalRecordWeightInfo = ();
+	type MaxTitleLength = MaxTitleLength;
+	type MaxDescriptionLength = MaxDescriptionLength;
+	type MaxRecordLinkLength = MaxRecordLinkLength;
+	type MaxFilesPerRecord = MaxFilesPerRecord;
+	type MaxRecordsPerOwner = MaxRecordsPerOwner;
+	type MaxPurposeLength = MaxPurposeLength;
+	type MaxGranteesPerScope = MaxGranteesPerScope;
 }
 
 pub type Moment = u64;
@@ -1,11 +1,12 @@
 use crate::{
-	mock::*, ElectronicMedicalRecord as ElectronicMedicalRecordStruct,
-	ElectronicMedicalRecordFileSubmission, Error,
+	mock::*, AccessGrantOf, AccessScope, ElectronicMedicalRecordFileSubmission,
+	ElectronicMedicalRecordOf, Error,
 };
 use frame_support::{
 	assert_noop, assert_ok,
 	sp_runtime::traits::{Hash, Keccak256},
 };
+use traits_electronic_medical_record::ElectronicMedicalRecordAccess;
 
 #[test]
 fn add_electronic_medical_record_works() {
@@ -36,11 +37,11 @@ fn add_electronic_medical_record_works() {
 
 		assert_eq!(
 			ElectronicMedicalRecord::electronic_medical_record_by_id(emr_ids[0]),
-			Some(ElectronicMedicalRecordStruct {
+			Some(ElectronicMedicalRecordOf::<Test> {
 				id: emr_ids[0],
 				owner_id: 1,
-				title: "DeBio EMR".as_bytes().to_vec(),
-				category: "DeBio EMR Category".as_bytes().to_vec(),
+				title: "DeBio EMR".as_bytes().to_vec().try_into().unwrap(),
+				category: "DeBio EMR Category".as_bytes().to_vec().try_into().unwrap(),
 				files: emr.files.clone()
 			})
 		);
@@ -83,11 +84,11 @@ fn remove_electronic_medical_record_works() {
 
 		assert_eq!(
 			ElectronicMedicalRecord::electronic_medical_record_by_id(emr_ids[0]),
-			Some(ElectronicMedicalRecordStruct {
+			Some(ElectronicMedicalRecordOf::<Test> {
 				id: emr_ids[0],
 				owner_id: 1,
-				title: "DeBio EMR".as_bytes().to_vec(),
-				category: "DeBio EMR Category".as_bytes().to_vec(),
+				title: "DeBio EMR".as_bytes().to_vec().try_into().unwrap(),
+				category: "DeBio EMR Category".as_bytes().to_vec().try_into().unwrap(),
 				files: emr.files.clone()
 			})
 		);
@@ -152,11 +153,11 @@ fn remove_electronic_medical_record_not_electronic_medical_record_owner() {
 
 		assert_eq!(
 			ElectronicMedicalRecord::electronic_medical_record_by_id(emr_ids[0]),
-			Some(ElectronicMedicalRecordStruct {
+			Some(ElectronicMedicalRecordOf::<Test> {
 				id: emr_ids[0],
 				owner_id: 1,
-				title: "DeBio EMR".as_bytes().to_vec(),
-				category: "DeBio EMR Category".as_bytes().to_vec(),
+				title: "DeBio EMR".as_bytes().to_vec().try_into().unwrap(),
+				category: "DeBio EMR Category".as_bytes().to_vec().try_into().unwrap(),
 				files: emr.files.clone()
 			})
 		);
@@ -207,11 +208,11 @@ fn update_electronic_medical_record_works() {
 
 		assert_eq!(
 			ElectronicMedicalRecord::electronic_medical_record_by_id(emr_ids[0]),
-			Some(ElectronicMedicalRecordStruct {
+			Some(ElectronicMedicalRecordOf::<Test> {
 				id: emr_ids[0],
 				owner_id: 1,
-				title: "DeBio EMR".as_bytes().to_vec(),
-				category: "DeBio EMR Category".as_bytes().to_vec(),
+				title: "DeBio EMR".as_bytes().to_vec().try_into().unwrap(),
+				category: "DeBio EMR Category".as_bytes().to_vec().try_into().unwrap(),
 				files: emr.files.clone()
 			})
 		);
@@ -250,11 +251,11 @@ fn update_electronic_medical_record_works() {
 
 		assert_eq!(
 			ElectronicMedicalRecord::electronic_medical_record_by_id(emr_ids[0]),
-			Some(ElectronicMedicalRecordStruct {
+			Some(ElectronicMedicalRecordOf::<Test> {
 				id: emr_ids[0],
 				owner_id: 1,
-				title: "DeBio EMR 2".as_bytes().to_vec(),
-				category: "DeBio EMR Category 2".as_bytes().to_vec(),
+				title: "DeBio EMR 2".as_bytes().to_vec().try_into().unwrap(),
+				category: "DeBio EMR Category 2".as_bytes().to_vec().try_into().unwrap(),
 				files: emr.files.clone()
 			})
 		);
@@ -317,11 +318,11 @@ fn update_electronic_medical_record_not_electronic_medical_record_owner() {
 
 		assert_eq!(
 			ElectronicMedicalRecord::electronic_medical_record_by_id(emr_ids[0]),
-			Some(ElectronicMedicalRecordStruct {
+			Some(ElectronicMedicalRecordOf::<Test> {
 				id: emr_ids[0],
 				owner_id: 1,
-				title: "DeBio EMR".as_bytes().to_vec(),
-				category: "DeBio EMR Category".as_bytes().to_vec(),
+				title: "DeBio EMR".as_bytes().to_vec().try_into().unwrap(),
+				category: "DeBio EMR Category".as_bytes().to_vec().try_into().unwrap(),
 				files: emr.files.clone()
 			})
 		);
@@ -349,3 +350,367 @@ fn update_electronic_medical_record_not_electronic_medical_record_owner() {
 		);
 	})
 }
+
+#[test]
+fn cant_add_electronic_medical_record_when_title_too_long() {
+	ExternalityBuilder::build().execute_with(|| {
+		MaxTitleLength::set(5);
+
+		assert_noop!(
+			ElectronicMedicalRecord::add_electronic_medical_record(
+				RuntimeOrigin::signed(1),
+				"DeBio EMR".as_bytes().to_vec(),
+				"EMR".as_bytes().to_vec(),
+				Vec::new()
+			),
+			Error::<Test>::TitleTooLong
+		);
+	})
+}
+
+#[test]
+fn cant_add_electronic_medical_record_when_description_too_long() {
+	ExternalityBuilder::build().execute_with(|| {
+		MaxDescriptionLength::set(5);
+
+		assert_noop!(
+			ElectronicMedicalRecord::add_electronic_medical_record(
+				RuntimeOrigin::signed(1),
+				"DeBio EMR".as_bytes().to_vec(),
+				"DeBio EMR Category".as_bytes().to_vec(),
+				vec![ElectronicMedicalRecordFileSubmission {
+					title: "DeBio EMR Document Title".as_bytes().to_vec(),
+					description: "DeBio EMR Document Description".as_bytes().to_vec(),
+					record_link: "DeBio EMR Link".as_bytes().to_vec()
+				}]
+			),
+			Error::<Test>::DescriptionTooLong
+		);
+	})
+}
+
+#[test]
+fn cant_add_electronic_medical_record_when_too_many_records() {
+	ExternalityBuilder::build().execute_with(|| {
+		MaxRecordsPerOwner::set(1);
+
+		assert_ok!(ElectronicMedicalRecord::add_electronic_medical_record(
+			RuntimeOrigin::signed(1),
+			"DeBio EMR".as_bytes().to_vec(),
+			"DeBio EMR Category".as_bytes().to_vec(),
+			Vec::new()
+		));
+
+		assert_noop!(
+			ElectronicMedicalRecord::add_electronic_medical_record(
+				RuntimeOrigin::signed(1),
+				"DeBio EMR".as_bytes().to_vec(),
+				"DeBio EMR Category".as_bytes().to_vec(),
+				Vec::new()
+			),
+			Error::<Test>::TooManyRecords
+		);
+	})
+}
+
+#[test]
+fn grant_access_works() {
+	ExternalityBuilder::build().execute_with(|| {
+		assert_ok!(ElectronicMedicalRecord::add_electronic_medical_record(
+			RuntimeOrigin::signed(1),
+			"DeBio EMR".as_bytes().to_vec(),
+			"DeBio EMR Category".as_bytes().to_vec(),
+			vec![ElectronicMedicalRecordFileSubmission {
+				title: "DeBio EMR Document Title".as_bytes().to_vec(),
+				description: "DeBio EMR Document Description".as_bytes().to_vec(),
+				record_link: "DeBio EMR Link".as_bytes().to_vec()
+			}]
+		));
+
+		let emr_ids = ElectronicMedicalRecord::electronic_medical_record_by_owner_id(1).unwrap();
+		let emr = ElectronicMedicalRecord::electronic_medical_record_by_id(emr_ids[0]).unwrap();
+		let scope = AccessScope::ElectronicMedicalRecord(emr_ids[0]);
+
+		assert!(!ElectronicMedicalRecord::can_read_electronic_medical_record(&2, &emr_ids[0]));
+
+		assert_ok!(ElectronicMedicalRecord::request_access(
+			RuntimeOrigin::signed(2),
+			scope.clone(),
+			"Second opinion".as_bytes().to_vec()
+		));
+
+		assert_ok!(ElectronicMedicalRecord::grant_access(
+			RuntimeOrigin::signed(1),
+			2,
+			scope.clone(),
+			"Second opinion".as_bytes().to_vec(),
+			1000
+		));
+
+		assert_eq!(
+			ElectronicMedicalRecord::access_grant(2, scope),
+			Some(AccessGrantOf::<Test> {
+				owner_id: 1,
+				grantee_id: 2,
+				scope: AccessScope::ElectronicMedicalRecord(emr_ids[0]),
+				purpose: "Second opinion".as_bytes().to_vec().try_into().unwrap(),
+				granted_at: 0,
+				expires_at: 1000,
+			})
+		);
+
+		assert!(ElectronicMedicalRecord::can_read_electronic_medical_record(&1, &emr_ids[0]));
+		assert!(ElectronicMedicalRecord::can_read_electronic_medical_record(&2, &emr_ids[0]));
+		assert!(ElectronicMedicalRecord::can_read_electronic_medical_record_file(
+			&2,
+			&emr.files[0]
+		));
+		assert!(!ElectronicMedicalRecord::can_read_electronic_medical_record(&3, &emr_ids[0]));
+
+		Timestamp::set_timestamp(1000);
+
+		assert!(!ElectronicMedicalRecord::can_read_electronic_medical_record(&2, &emr_ids[0]));
+		assert!(!ElectronicMedicalRecord::can_read_electronic_medical_record_file(
+			&2,
+			&emr.files[0]
+		));
+	})
+}
+
+#[test]
+fn revoke_access_works() {
+	ExternalityBuilder::build().execute_with(|| {
+		assert_ok!(ElectronicMedicalRecord::add_electronic_medical_record(
+			RuntimeOrigin::signed(1),
+			"DeBio EMR".as_bytes().to_vec(),
+			"DeBio EMR Category".as_bytes().to_vec(),
+			vec![ElectronicMedicalRecordFileSubmission {
+				title: "DeBio EMR Document Title".as_bytes().to_vec(),
+				description: "DeBio EMR Document Description".as_bytes().to_vec(),
+				record_link: "DeBio EMR Link".as_bytes().to_vec()
+			}]
+		));
+
+		let emr_ids = ElectronicMedicalRecord::electronic_medical_record_by_owner_id(1).unwrap();
+		let emr = ElectronicMedicalRecord::electronic_medical_record_by_id(emr_ids[0]).unwrap();
+		let scope = AccessScope::ElectronicMedicalRecordFile(emr.files[0]);
+
+		assert_ok!(ElectronicMedicalRecord::grant_access(
+			RuntimeOrigin::signed(1),
+			2,
+			scope.clone(),
+			"Second opinion".as_bytes().to_vec(),
+			1000
+		));
+
+		assert!(ElectronicMedicalRecord::can_read_electronic_medical_record_file(
+			&2,
+			&emr.files[0]
+		));
+		assert!(!ElectronicMedicalRecord::can_read_electronic_medical_record(&2, &emr_ids[0]));
+
+		assert_noop!(
+			ElectronicMedicalRecord::revoke_access(RuntimeOrigin::signed(2), 2, scope.clone()),
+			Error::<Test>::NotElectronicMedicalRecordOwner
+		);
+
+		assert_ok!(ElectronicMedicalRecord::revoke_access(
+			RuntimeOrigin::signed(1),
+			2,
+			scope.clone()
+		));
+
+		assert!(!ElectronicMedicalRecord::can_read_electronic_medical_record_file(
+			&2,
+			&emr.files[0]
+		));
+
+		assert_noop!(
+			ElectronicMedicalRecord::revoke_access(RuntimeOrigin::signed(1), 2, scope),
+			Error::<Test>::AccessGrantNotFound
+		);
+	})
+}
+
+#[test]
+fn cant_grant_access_when_not_owner_or_expired() {
+	ExternalityBuilder::build().execute_with(|| {
+		assert_ok!(ElectronicMedicalRecord::add_electronic_medical_record(
+			RuntimeOrigin::signed(1),
+			"DeBio EMR".as_bytes().to_vec(),
+			"DeBio EMR Category".as_bytes().to_vec(),
+			Vec::new()
+		));
+
+		let emr_ids = ElectronicMedicalRecord::electronic_medical_record_by_owner_id(1).unwrap();
+
+		assert_noop!(
+			ElectronicMedicalRecord::grant_access(
+				RuntimeOrigin::signed(2),
+				2,
+				AccessScope::ElectronicMedicalRecord(emr_ids[0]),
+				"Second opinion".as_bytes().to_vec(),
+				1000
+			),
+			Error::<Test>::NotElectronicMedicalRecordOwner
+		);
+
+		Timestamp::set_timestamp(1000);
+
+		assert_noop!(
+			ElectronicMedicalRecord::grant_access(
+				RuntimeOrigin::signed(1),
+				2,
+				AccessScope::ElectronicMedicalRecord(emr_ids[0]),
+				"Second opinion".as_bytes().to_vec(),
+				1000
+			),
+			Error::<Test>::InvalidAccessExpiry
+		);
+
+		assert_noop!(
+			ElectronicMedicalRecord::grant_access(
+				RuntimeOrigin::signed(1),
+				2,
+				AccessScope::ElectronicMedicalRecordFile(Keccak256::hash("file".as_bytes())),
+				"Second opinion".as_bytes().to_vec(),
+				2000
+			),
+			Error::<Test>::ElectronicMedicalRecordFileDoesNotExist
+		);
+	})
+}
+
+#[test]
+fn remove_electronic_medical_record_removes_access_grants() {
+	ExternalityBuilder::build().execute_with(|| {
+		assert_ok!(ElectronicMedicalRecord::add_electronic_medical_record(
+			RuntimeOrigin::signed(1),
+			"DeBio EMR".as_bytes().to_vec(),
+			"DeBio EMR Category".as_bytes().to_vec(),
+			vec![ElectronicMedicalRecordFileSubmission {
+				title: "DeBio EMR Document Title".as_bytes().to_vec(),
+				description: "DeBio EMR Document Description".as_bytes().to_vec(),
+				record_link: "DeBio EMR Link".as_bytes().to_vec()
+			}]
+		));
+
+		let emr_ids = ElectronicMedicalRecord::electronic_medical_record_by_owner_id(1).unwrap();
+		let emr = ElectronicMedicalRecord::electronic_medical_record_by_id(emr_ids[0]).unwrap();
+		let record_scope = AccessScope::ElectronicMedicalRecord(emr_ids[0]);
+		let file_scope = AccessScope::ElectronicMedicalRecordFile(emr.files[0]);
+
+		assert_ok!(ElectronicMedicalRecord::grant_access(
+			RuntimeOrigin::signed(1),
+			2,
+			record_scope.clone(),
+			"Second opinion".as_bytes().to_vec(),
+			1000
+		));
+
+		assert_ok!(ElectronicMedicalRecord::grant_access(
+			RuntimeOrigin::signed(1),
+			3,
+			file_scope.clone(),
+			"Second opinion".as_bytes().to_vec(),
+			1000
+		));
+
+		assert_eq!(ElectronicMedicalRecord::access_grantees_by_scope(&record_scope), vec![2]);
+		assert_eq!(ElectronicMedicalRecord::access_grantees_by_scope(&file_scope), vec![3]);
+
+		assert_ok!(ElectronicMedicalRecord::remove_electronic_medical_record(
+			RuntimeOrigin::signed(1),
+			emr_ids[0]
+		));
+
+		assert_eq!(ElectronicMedicalRecord::access_grant(2, &record_scope), None);
+		assert_eq!(ElectronicMedicalRecord::access_grant(3, &file_scope), None);
+		assert!(ElectronicMedicalRecord::access_grantees_by_scope(&record_scope).is_empty());
+		assert!(ElectronicMedicalRecord::access_grantees_by_scope(&file_scope).is_empty());
+	})
+}
+
+#[test]
+fn cant_grant_access_when_too_many_grantees() {
+	ExternalityBuilder::build().execute_with(|| {
+		MaxGranteesPerScope::set(1);
+
+		assert_ok!(ElectronicMedicalRecord::add_electronic_medical_record(
+			RuntimeOrigin::signed(1),
+			"DeBio EMR".as_bytes().to_vec(),
+			"DeBio EMR Category".as_bytes().to_vec(),
+			Vec::new()
+		));
+
+		let emr_ids = ElectronicMedicalRecord::electronic_medical_record_by_owner_id(1).unwrap();
+		let scope = AccessScope::ElectronicMedicalRecord(emr_ids[0]);
+
+		assert_ok!(ElectronicMedicalRecord::grant_access(
+			RuntimeOrigin::signed(1),
+			2,
+			scope.clone(),
+			"Second opinion".as_bytes().to_vec(),
+			1000
+		));
+
+		// Renewing an existing grant does not take another slot
+		assert_ok!(ElectronicMedicalRecord::grant_access(
+			RuntimeOrigin::signed(1),
+			2,
+			scope.clone(),
+			"Second opinion".as_bytes().to_vec(),
+			2000
+		));
+
+		assert_noop!(
+			ElectronicMedicalRecord::grant_access(
+				RuntimeOrigin::signed(1),
+				3,
+				scope,
+				"Second opinion".as_bytes().to_vec(),
+				1000
+			),
+			Error::<Test>::TooManyGrantees
+		);
+	})
+}
+
+#[test]
+fn grant_access_removes_expired_grants_when_too_many_grantees() {
+	ExternalityBuilder::build().execute_with(|| {
+		MaxGranteesPerScope::set(1);
+
+		assert_ok!(ElectronicMedicalRecord::add_electronic_medical_record(
+			RuntimeOrigin::signed(1),
+			"DeBio EMR".as_bytes().to_vec(),
+			"DeBio EMR Category".as_bytes().to_vec(),
+			Vec::new()
+		));
+
+		let emr_ids = ElectronicMedicalRecord::electronic_medical_record_by_owner_id(1).unwrap();
+		let scope = AccessScope::ElectronicMedicalRecord(emr_ids[0]);
+
+		assert_ok!(ElectronicMedicalRecord::grant_access(
+			RuntimeOrigin::signed(1),
+			2,
+			scope.clone(),
+			"Second opinion".as_bytes().to_vec(),
+			1000
+		));
+
+		Timestamp::set_timestamp(1000);
+
+		assert_ok!(ElectronicMedicalRecord::grant_access(
+			RuntimeOrigin::signed(1),
+			3,
+			scope.clone(),
+			"Second opinion".as_bytes().to_vec(),
+			2000
+		));
+
+		assert_eq!(ElectronicMedicalRecord::access_grant(2, &scope), None);
+		assert!(ElectronicMedicalRecord::access_grant(3, &scope).is_some());
+		assert_eq!(ElectronicMedicalRecord::access_grantees_by_scope(&scope).into_inner(), vec![3]);
+	})
+}
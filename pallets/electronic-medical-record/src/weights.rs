@@ -30,6 +30,9 @@ pub trait WeightInfo {
 	fn add_electronic_medical_record() -> Weight;
 	fn update_electronic_medical_record() -> Weight;
 	fn remove_electronic_medical_record() -> Weight;
+	fn grant_access(g: u32, ) -> Weight;
+	fn revoke_access() -> Weight;
+	fn request_access() -> Weight;
 }
 
 /// Weights for electronic_medical_record using the Substrate node and recommended hardware.
@@ -52,20 +55,51 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	// Storage: ElectronicMedicalRecord ElectronicMedicalRecordCount (r:1 w:1)
 	// Storage: ElectronicMedicalRecord ElectronicMedicalRecordCountByOwner (r:1 w:1)
 	// Storage: Timestamp Now (r:1 w:0)
+	// Storage: ElectronicMedicalRecord AccessGranteesByScope (r:2 w:2)
+	// Storage: ElectronicMedicalRecord AccessGrants (r:0 w:2)
 	fn update_electronic_medical_record() -> Weight {
 		Weight::from_ref_time(228_482_000_u64)
-			.saturating_add(T::DbWeight::get().reads(6_u64))
-			.saturating_add(T::DbWeight::get().writes(5_u64))
+			.saturating_add(T::DbWeight::get().reads(8_u64))
+			.saturating_add(T::DbWeight::get().writes(9_u64))
 	}
 	// Storage: ElectronicMedicalRecord ElectronicMedicalRecordById (r:1 w:1)
 	// Storage: ElectronicMedicalRecord ElectronicMedicalRecordFileById (r:1 w:1)
 	// Storage: ElectronicMedicalRecord ElectronicMedicalRecordByOwner (r:1 w:1)
 	// Storage: ElectronicMedicalRecord ElectronicMedicalRecordCount (r:1 w:1)
 	// Storage: ElectronicMedicalRecord ElectronicMedicalRecordCountByOwner (r:1 w:1)
+	// Storage: ElectronicMedicalRecord AccessGranteesByScope (r:2 w:2)
+	// Storage: ElectronicMedicalRecord AccessGrants (r:0 w:2)
 	fn remove_electronic_medical_record() -> Weight {
 		Weight::from_ref_time(152_391_000_u64)
+			.saturating_add(T::DbWeight::get().reads(7_u64))
+			.saturating_add(T::DbWeight::get().writes(9_u64))
+	}
+	// Storage: ElectronicMedicalRecord ElectronicMedicalRecordFileById (r:1 w:0)
+	// Storage: ElectronicMedicalRecord ElectronicMedicalRecordById (r:1 w:0)
+	// Storage: Timestamp Now (r:1 w:0)
+	// Storage: ElectronicMedicalRecord AccessGrants (r:1 w:1)
+	// Storage: ElectronicMedicalRecord AccessGranteesByScope (r:1 w:1)
+	fn grant_access(g: u32, ) -> Weight {
+		Weight::from_ref_time(41_250_000_u64)
+			// Standard Error: 2_000
+			.saturating_add(Weight::from_ref_time(4_120_000_u64).saturating_mul(g as u64))
 			.saturating_add(T::DbWeight::get().reads(5_u64))
-			.saturating_add(T::DbWeight::get().writes(5_u64))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(g as u64)))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(g as u64)))
+	}
+	// Storage: ElectronicMedicalRecord AccessGrants (r:1 w:1)
+	// Storage: ElectronicMedicalRecord AccessGranteesByScope (r:1 w:1)
+	fn revoke_access() -> Weight {
+		Weight::from_ref_time(29_870_000_u64)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	// Storage: ElectronicMedicalRecord ElectronicMedicalRecordFileById (r:1 w:0)
+	// Storage: ElectronicMedicalRecord ElectronicMedicalRecordById (r:1 w:0)
+	fn request_access() -> Weight {
+		Weight::from_ref_time(24_630_000_u64)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
 	}
 }
 
@@ -88,19 +122,50 @@ impl WeightInfo for () {
 	// Storage: ElectronicMedicalRecord ElectronicMedicalRecordCount (r:1 w:1)
 	// Storage: ElectronicMedicalRecord ElectronicMedicalRecordCountByOwner (r:1 w:1)
 	// Storage: Timestamp Now (r:1 w:0)
+	// Storage: ElectronicMedicalRecord AccessGranteesByScope (r:2 w:2)
+	// Storage: ElectronicMedicalRecord AccessGrants (r:0 w:2)
 	fn update_electronic_medical_record() -> Weight {
 		Weight::from_ref_time(228_482_000_u64)
-			.saturating_add(RocksDbWeight::get().reads(6_u64))
-			.saturating_add(RocksDbWeight::get().writes(5_u64))
+			.saturating_add(RocksDbWeight::get().reads(8_u64))
+			.saturating_add(RocksDbWeight::get().writes(9_u64))
 	}
 	// Storage: ElectronicMedicalRecord ElectronicMedicalRecordById (r:1 w:1)
 	// Storage: ElectronicMedicalRecord ElectronicMedicalRecordFileById (r:1 w:1)
 	// Storage: ElectronicMedicalRecord ElectronicMedicalRecordByOwner (r:1 w:1)
 	// Storage: ElectronicMedicalRecord ElectronicMedicalRecordCount (r:1 w:1)
 	// Storage: ElectronicMedicalRecord ElectronicMedicalRecordCountByOwner (r:1 w:1)
+	// Storage: ElectronicMedicalRecord AccessGranteesByScope (r:2 w:2)
+	// Storage: ElectronicMedicalRecord AccessGrants (r:0 w:2)
 	fn remove_electronic_medical_record() -> Weight {
 		Weight::from_ref_time(152_391_000_u64)
+			.saturating_add(RocksDbWeight::get().reads(7_u64))
+			.saturating_add(RocksDbWeight::get().writes(9_u64))
+	}
+	// Storage: ElectronicMedicalRecord ElectronicMedicalRecordFileById (r:1 w:0)
+	// Storage: ElectronicMedicalRecord ElectronicMedicalRecordById (r:1 w:0)
+	// Storage: Timestamp Now (r:1 w:0)
+	// Storage: ElectronicMedicalRecord AccessGrants (r:1 w:1)
+	// Storage: ElectronicMedicalRecord AccessGranteesByScope (r:1 w:1)
+	fn grant_access(g: u32, ) -> Weight {
+		Weight::from_ref_time(41_250_000_u64)
+			// Standard Error: 2_000
+			.saturating_add(Weight::from_ref_time(4_120_000_u64).saturating_mul(g as u64))
 			.saturating_add(RocksDbWeight::get().reads(5_u64))
-			.saturating_add(RocksDbWeight::get().writes(5_u64))
+			.saturating_add(RocksDbWeight::get().reads((1_u64).saturating_mul(g as u64)))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+			.saturating_add(RocksDbWeight::get().writes((1_u64).saturating_mul(g as u64)))
+	}
+	// Storage: ElectronicMedicalRecord AccessGrants (r:1 w:1)
+	// Storage: ElectronicMedicalRecord AccessGranteesByScope (r:1 w:1)
+	fn revoke_access() -> Weight {
+		Weight::from_ref_time(29_870_000_u64)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	// Storage: ElectronicMedicalRecord ElectronicMedicalRecordFileById (r:1 w:0)
+	// Storage: ElectronicMedicalRecord ElectronicMedicalRecordById (r:1 w:0)
+	fn request_access() -> Weight {
+		Weight::from_ref_time(24_630_000_u64)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
 	}
 }
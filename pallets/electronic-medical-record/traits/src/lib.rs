@@ -38,3 +38,17 @@ pub trait ElectronicMedicalRecordFileByElectronicMedicalRecord<T: Config> {
 		electronic_medical_record_file_id: &T::Hash,
 	);
 }
+
+pub trait ElectronicMedicalRecordAccess<T: Config> {
+	/// Whether the account owns the record or holds an unexpired grant for it.
+	fn can_read_electronic_medical_record(
+		account_id: &T::AccountId,
+		electronic_medical_record_id: &T::Hash,
+	) -> bool;
+	/// Whether the account can read the record of the file or holds an unexpired grant for
+	/// the file itself.
+	fn can_read_electronic_medical_record_file(
+		account_id: &T::AccountId,
+		electronic_medical_record_file_id: &T::Hash,
+	) -> bool;
+}
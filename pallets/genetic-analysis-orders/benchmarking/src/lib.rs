@@ -53,15 +53,15 @@ benchmarks! {
 
 		let genetic_analyst = GeneticAnalystInfo {
 			box_public_key: T::Hashing::hash("0xDb9Af2d1f3ADD2726A132AA7A65Cc9E6fC5761C3".as_bytes()),
-			first_name: "First Name".as_bytes().to_vec(),
-			last_name: "Last Name".as_bytes().to_vec(),
-			gender: "Gender".as_bytes().to_vec(),
+			first_name: "First Name".as_bytes().to_vec().try_into().unwrap(),
+			last_name: "Last Name".as_bytes().to_vec().try_into().unwrap(),
+			gender: "Gender".as_bytes().to_vec().try_into().unwrap(),
 			date_of_birth: <T as pallet_timestamp::pallet::Config>::Moment::default(),
-			email: "Email".as_bytes().to_vec(),
-			phone_number: "+6893026516".as_bytes().to_vec(),
-			specialization: "DeBio Genetic Analyst".as_bytes().to_vec(),
-			profile_link: "DeBio Genetic Analyst profile_link".as_bytes().to_vec(),
-			profile_image: Some("DeBio Genetic Analyst profile_image".as_bytes().to_vec()),
+			email: "Email".as_bytes().to_vec().try_into().unwrap(),
+			phone_number: "+6893026516".as_bytes().to_vec().try_into().unwrap(),
+			specialization: "DeBio Genetic Analyst".as_bytes().to_vec().try_into().unwrap(),
+			profile_link: "DeBio Genetic Analyst profile_link".as_bytes().to_vec().try_into().unwrap(),
+			profile_image: Some("DeBio Genetic Analyst profile_image".as_bytes().to_vec().try_into().unwrap()),
 		};
 		let _add_genetic_analysts = GeneticAnalysts::<T>::register_genetic_analyst(caller_origin.clone(), genetic_analyst);
 
@@ -71,13 +71,13 @@ benchmarks! {
 		let _set_eth_address = UserProfile::<T>::set_eth_address(caller_origin.clone(), eth_address);
 
 		let genetic_analyst_service_info = GeneticAnalystServiceInfo {
-			name: "DeBio Genetic Analyst Service name".as_bytes().to_vec(),
+			name: "DeBio Genetic Analyst Service name".as_bytes().to_vec().try_into().unwrap(),
 			prices_by_currency: vec![
 				PriceByCurrency::default()
-			],
+			].try_into().unwrap(),
 			expected_duration: ExpectedDuration::default(),
-			description: "DeBio Genetic Analyst Service description".as_bytes().to_vec(),
-			test_result_sample: "DeBio Genetic Analyst Service test_result_sample".as_bytes().to_vec(),
+			description: "DeBio Genetic Analyst Service description".as_bytes().to_vec().try_into().unwrap(),
+			test_result_sample: "DeBio Genetic Analyst Service test_result_sample".as_bytes().to_vec().try_into().unwrap(),
 		};
 		let _create_genetic_analyst_service = GeneticAnalystServices::<T>::create_genetic_analyst_service(
 			caller_origin.clone(),
@@ -113,15 +113,15 @@ benchmarks! {
 
 		let genetic_analyst = GeneticAnalystInfo {
 			box_public_key: T::Hashing::hash("0xDb9Af2d1f3ADD2726A132AA7A65Cc9E6fC5761C3".as_bytes()),
-			first_name: "First Name".as_bytes().to_vec(),
-			last_name: "Last Name".as_bytes().to_vec(),
-			gender: "Gender".as_bytes().to_vec(),
+			first_name: "First Name".as_bytes().to_vec().try_into().unwrap(),
+			last_name: "Last Name".as_bytes().to_vec().try_into().unwrap(),
+			gender: "Gender".as_bytes().to_vec().try_into().unwrap(),
 			date_of_birth: <T as pallet_timestamp::pallet::Config>::Moment::default(),
-			email: "Email".as_bytes().to_vec(),
-			phone_number: "+6893026516".as_bytes().to_vec(),
-			specialization: "DeBio Genetic Analyst".as_bytes().to_vec(),
-			profile_link: "DeBio Genetic Analyst profile_link".as_bytes().to_vec(),
-			profile_image: Some("DeBio Genetic Analyst profile_image".as_bytes().to_vec()),
+			email: "Email".as_bytes().to_vec().try_into().unwrap(),
+			phone_number: "+6893026516".as_bytes().to_vec().try_into().unwrap(),
+			specialization: "DeBio Genetic Analyst".as_bytes().to_vec().try_into().unwrap(),
+			profile_link: "DeBio Genetic Analyst profile_link".as_bytes().to_vec().try_into().unwrap(),
+			profile_image: Some("DeBio Genetic Analyst profile_image".as_bytes().to_vec().try_into().unwrap()),
 		};
 		let _add_genetic_analysts = GeneticAnalysts::<T>::register_genetic_analyst(caller_origin.clone(), genetic_analyst);
 
@@ -131,13 +131,13 @@ benchmarks! {
 		let _set_eth_address = UserProfile::<T>::set_eth_address(caller_origin.clone(), eth_address);
 
 		let genetic_analyst_service_info = GeneticAnalystServiceInfo {
-			name: "DeBio Genetic Analyst Service name".as_bytes().to_vec(),
+			name: "DeBio Genetic Analyst Service name".as_bytes().to_vec().try_into().unwrap(),
 			prices_by_currency: vec![
 				PriceByCurrency::default()
-			],
+			].try_into().unwrap(),
 			expected_duration: ExpectedDuration::default(),
-			description: "DeBio Genetic Analyst Service description".as_bytes().to_vec(),
-			test_result_sample: "DeBio Genetic Analyst Service test_result_sample".as_bytes().to_vec(),
+			description: "DeBio Genetic Analyst Service description".as_bytes().to_vec().try_into().unwrap(),
+			test_result_sample: "DeBio Genetic Analyst Service test_result_sample".as_bytes().to_vec().try_into().unwrap(),
 		};
 		let _create_genetic_analyst_service = GeneticAnalystServices::<T>::create_genetic_analyst_service(
 			caller_origin.clone(),
@@ -168,8 +168,7 @@ benchmarks! {
 			None,
 		);
 
-		let _genetic_analysis_order_id_list = GeneticAnalysisOrders::<T>::genetic_analysis_orders_by_genetic_analyst_id(caller.clone())
-			.unwrap();
+		let _genetic_analysis_order_id_list = GeneticAnalysisOrders::<T>::genetic_analysis_orders_by_genetic_analyst_id(&caller);
 		let _genetic_analysis_order = GeneticAnalysisOrders::<T>::genetic_analysis_order_by_id(_genetic_analysis_order_id_list[0])
 			.unwrap();
 	}: cancel_genetic_analysis_order(
@@ -183,15 +182,15 @@ benchmarks! {
 
 		let genetic_analyst = GeneticAnalystInfo {
 			box_public_key: T::Hashing::hash("0xDb9Af2d1f3ADD2726A132AA7A65Cc9E6fC5761C3".as_bytes()),
-			first_name: "First Name".as_bytes().to_vec(),
-			last_name: "Last Name".as_bytes().to_vec(),
-			gender: "Gender".as_bytes().to_vec(),
+			first_name: "First Name".as_bytes().to_vec().try_into().unwrap(),
+			last_name: "Last Name".as_bytes().to_vec().try_into().unwrap(),
+			gender: "Gender".as_bytes().to_vec().try_into().unwrap(),
 			date_of_birth: <T as pallet_timestamp::pallet::Config>::Moment::default(),
-			email: "Email".as_bytes().to_vec(),
-			phone_number: "+6893026516".as_bytes().to_vec(),
-			specialization: "DeBio Genetic Analyst".as_bytes().to_vec(),
-			profile_link: "DeBio Genetic Analyst profile_link".as_bytes().to_vec(),
-			profile_image: Some("DeBio Genetic Analyst profile_image".as_bytes().to_vec()),
+			email: "Email".as_bytes().to_vec().try_into().unwrap(),
+			phone_number: "+6893026516".as_bytes().to_vec().try_into().unwrap(),
+			specialization: "DeBio Genetic Analyst".as_bytes().to_vec().try_into().unwrap(),
+			profile_link: "DeBio Genetic Analyst profile_link".as_bytes().to_vec().try_into().unwrap(),
+			profile_image: Some("DeBio Genetic Analyst profile_image".as_bytes().to_vec().try_into().unwrap()),
 		};
 		let _add_genetic_analysts = GeneticAnalysts::<T>::register_genetic_analyst(caller_origin.clone(), genetic_analyst);
 
@@ -201,13 +200,13 @@ benchmarks! {
 		let _set_eth_address = UserProfile::<T>::set_eth_address(caller_origin.clone(), eth_address);
 
 		let genetic_analyst_service_info = GeneticAnalystServiceInfo {
-			name: "DeBio Genetic Analyst Service name".as_bytes().to_vec(),
+			name: "DeBio Genetic Analyst Service name".as_bytes().to_vec().try_into().unwrap(),
 			prices_by_currency: vec![
 				PriceByCurrency::default()
-			],
+			].try_into().unwrap(),
 			expected_duration: ExpectedDuration::default(),
-			description: "DeBio Genetic Analyst Service description".as_bytes().to_vec(),
-			test_result_sample: "DeBio Genetic Analyst Service test_result_sample".as_bytes().to_vec(),
+			description: "DeBio Genetic Analyst Service description".as_bytes().to_vec().try_into().unwrap(),
+			test_result_sample: "DeBio Genetic Analyst Service test_result_sample".as_bytes().to_vec().try_into().unwrap(),
 		};
 		let _create_genetic_analyst_service = GeneticAnalystServices::<T>::create_genetic_analyst_service(caller_origin.clone(), genetic_analyst_service_info);
 
@@ -235,8 +234,7 @@ benchmarks! {
 			None,
 		);
 
-		let _genetic_analysis_order_id_list = GeneticAnalysisOrders::<T>::genetic_analysis_orders_by_genetic_analyst_id(caller.clone())
-			.unwrap();
+		let _genetic_analysis_order_id_list = GeneticAnalysisOrders::<T>::genetic_analysis_orders_by_genetic_analyst_id(&caller);
 		let _genetic_analysis_order = GeneticAnalysisOrders::<T>::genetic_analysis_order_by_id(_genetic_analysis_order_id_list[0])
 			.unwrap();
 	}: set_genetic_analysis_order_paid(
@@ -250,15 +248,15 @@ benchmarks! {
 
 		let genetic_analyst = GeneticAnalystInfo {
 			box_public_key: T::Hashing::hash("0xDb9Af2d1f3ADD2726A132AA7A65Cc9E6fC5761C3".as_bytes()),
-			first_name: "First Name".as_bytes().to_vec(),
-			last_name: "Last Name".as_bytes().to_vec(),
-			gender: "Gender".as_bytes().to_vec(),
+			first_name: "First Name".as_bytes().to_vec().try_into().unwrap(),
+			last_name: "Last Name".as_bytes().to_vec().try_into().unwrap(),
+			gender: "Gender".as_bytes().to_vec().try_into().unwrap(),
 			date_of_birth: <T as pallet_timestamp::pallet::Config>::Moment::default(),
-			email: "Email".as_bytes().to_vec(),
-			phone_number: "+6893026516".as_bytes().to_vec(),
-			specialization: "DeBio Genetic Analyst".as_bytes().to_vec(),
-			profile_link: "DeBio Genetic Analyst profile_link".as_bytes().to_vec(),
-			profile_image: Some("DeBio Genetic Analyst profile_image".as_bytes().to_vec()),
+			email: "Email".as_bytes().to_vec().try_into().unwrap(),
+			phone_number: "+6893026516".as_bytes().to_vec().try_into().unwrap(),
+			specialization: "DeBio Genetic Analyst".as_bytes().to_vec().try_into().unwrap(),
+			profile_link: "DeBio Genetic Analyst profile_link".as_bytes().to_vec().try_into().unwrap(),
+			profile_image: Some("DeBio Genetic Analyst profile_image".as_bytes().to_vec().try_into().unwrap()),
 		};
 		let _add_genetic_analysts = GeneticAnalysts::<T>::register_genetic_analyst(caller_origin.clone(), genetic_analyst);
 
@@ -268,13 +266,13 @@ benchmarks! {
 		let _set_eth_address = UserProfile::<T>::set_eth_address(caller_origin.clone(), eth_address);
 
 		let genetic_analyst_service_info = GeneticAnalystServiceInfo {
-			name: "DeBio Genetic Analyst Service name".as_bytes().to_vec(),
+			name: "DeBio Genetic Analyst Service name".as_bytes().to_vec().try_into().unwrap(),
 			prices_by_currency: vec![
 				PriceByCurrency::default()
-			],
+			].try_into().unwrap(),
 			expected_duration: ExpectedDuration::default(),
-			description: "DeBio Genetic Analyst Service description".as_bytes().to_vec(),
-			test_result_sample: "DeBio Genetic Analyst Service test_result_sample".as_bytes().to_vec(),
+			description: "DeBio Genetic Analyst Service description".as_bytes().to_vec().try_into().unwrap(),
+			test_result_sample: "DeBio Genetic Analyst Service test_result_sample".as_bytes().to_vec().try_into().unwrap(),
 		};
 		let _create_genetic_analyst_service = GeneticAnalystServices::<T>::create_genetic_analyst_service(caller_origin.clone(), genetic_analyst_service_info);
 
@@ -302,8 +300,7 @@ benchmarks! {
 			None,
 		);
 
-		let _genetic_analysis_order_id_list = GeneticAnalysisOrders::<T>::genetic_analysis_orders_by_genetic_analyst_id(caller.clone())
-			.unwrap();
+		let _genetic_analysis_order_id_list = GeneticAnalysisOrders::<T>::genetic_analysis_orders_by_genetic_analyst_id(&caller);
 		let _genetic_analysis_order = GeneticAnalysisOrders::<T>::genetic_analysis_order_by_id(_genetic_analysis_order_id_list[0])
 			.unwrap();
 
@@ -335,15 +332,15 @@ benchmarks! {
 
 		let genetic_analyst = GeneticAnalystInfo {
 			box_public_key: T::Hashing::hash("0xDb9Af2d1f3ADD2726A132AA7A65Cc9E6fC5761C3".as_bytes()),
-			first_name: "First Name".as_bytes().to_vec(),
-			last_name: "Last Name".as_bytes().to_vec(),
-			gender: "Gender".as_bytes().to_vec(),
+			first_name: "First Name".as_bytes().to_vec().try_into().unwrap(),
+			last_name: "Last Name".as_bytes().to_vec().try_into().unwrap(),
+			gender: "Gender".as_bytes().to_vec().try_into().unwrap(),
 			date_of_birth: <T as pallet_timestamp::pallet::Config>::Moment::default(),
-			email: "Email".as_bytes().to_vec(),
-			phone_number: "+6893026516".as_bytes().to_vec(),
-			specialization: "DeBio Genetic Analyst".as_bytes().to_vec(),
-			profile_link: "DeBio Genetic Analyst profile_link".as_bytes().to_vec(),
-			profile_image: Some("DeBio Genetic Analyst profile_image".as_bytes().to_vec()),
+			email: "Email".as_bytes().to_vec().try_into().unwrap(),
+			phone_number: "+6893026516".as_bytes().to_vec().try_into().unwrap(),
+			specialization: "DeBio Genetic Analyst".as_bytes().to_vec().try_into().unwrap(),
+			profile_link: "DeBio Genetic Analyst profile_link".as_bytes().to_vec().try_into().unwrap(),
+			profile_image: Some("DeBio Genetic Analyst profile_image".as_bytes().to_vec().try_into().unwrap()),
 		};
 		let _add_genetic_analysts = GeneticAnalysts::<T>::register_genetic_analyst(caller_origin.clone(), genetic_analyst);
 
@@ -353,13 +350,13 @@ benchmarks! {
 		let _set_eth_address = UserProfile::<T>::set_eth_address(caller_origin.clone(), eth_address);
 
 		let genetic_analyst_service_info = GeneticAnalystServiceInfo {
-			name: "DeBio Genetic Analyst Service name".as_bytes().to_vec(),
+			name: "DeBio Genetic Analyst Service name".as_bytes().to_vec().try_into().unwrap(),
 			prices_by_currency: vec![
 				PriceByCurrency::default()
-			],
+			].try_into().unwrap(),
 			expected_duration: ExpectedDuration::default(),
-			description: "DeBio Genetic Analyst Service description".as_bytes().to_vec(),
-			test_result_sample: "DeBio Genetic Analyst Service test_result_sample".as_bytes().to_vec(),
+			description: "DeBio Genetic Analyst Service description".as_bytes().to_vec().try_into().unwrap(),
+			test_result_sample: "DeBio Genetic Analyst Service test_result_sample".as_bytes().to_vec().try_into().unwrap(),
 		};
 		let _create_genetic_analyst_service = GeneticAnalystServices::<T>::create_genetic_analyst_service(caller_origin.clone(), genetic_analyst_service_info);
 
@@ -387,8 +384,7 @@ benchmarks! {
 			None,
 		);
 
-		let _genetic_analysis_order_id_list = GeneticAnalysisOrders::<T>::genetic_analysis_orders_by_genetic_analyst_id(caller.clone())
-			.unwrap();
+		let _genetic_analysis_order_id_list = GeneticAnalysisOrders::<T>::genetic_analysis_orders_by_genetic_analyst_id(&caller);
 		let _genetic_analysis_order = GeneticAnalysisOrders::<T>::genetic_analysis_order_by_id(_genetic_analysis_order_id_list[0])
 			.unwrap();
 
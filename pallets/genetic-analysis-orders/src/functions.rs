@@ -2,7 +2,7 @@ use crate::*;
 
 use frame_support::{
 	dispatch::DispatchError,
-	sp_runtime::traits::SaturatedConversion,
+	sp_runtime::traits::{SaturatedConversion, Zero},
 	traits::{fungibles, ExistenceRequirement},
 };
 use primitives_price_and_currency::CurrencyType;
@@ -67,80 +67,60 @@ impl<T: Config> Pallet<T> {
 	pub fn insert_genetic_analysis_order_id_into_genetic_analysis_orders_by_seller(
 		genetic_analysis_order: &GeneticAnalysisOrderOf<T>,
 	) {
-		match GeneticAnalysisOrdersBySeller::<T>::get(&genetic_analysis_order.seller_id) {
-			None => {
-				GeneticAnalysisOrdersBySeller::<T>::insert(
-					&genetic_analysis_order.seller_id,
-					vec![genetic_analysis_order.id],
-				);
-			},
-			Some(mut genetic_analysis_orders) => {
-				genetic_analysis_orders.push(genetic_analysis_order.id);
-				GeneticAnalysisOrdersBySeller::<T>::insert(
-					&genetic_analysis_order.seller_id,
-					genetic_analysis_orders,
-				);
-			},
-		}
+		GeneticAnalysisOrdersBySeller::<T>::insert(
+			&genetic_analysis_order.seller_id,
+			genetic_analysis_order.id,
+			(),
+		);
 	}
 
 	pub fn insert_genetic_analysis_order_id_into_genetic_analysis_orders_by_customer(
 		genetic_analysis_order: &GeneticAnalysisOrderOf<T>,
 	) {
-		match GeneticAnalysisOrdersByCustomer::<T>::get(&genetic_analysis_order.customer_id) {
-			None => {
-				GeneticAnalysisOrdersByCustomer::<T>::insert(
-					&genetic_analysis_order.customer_id,
-					vec![genetic_analysis_order.id],
-				);
-			},
-			Some(mut genetic_analysis_orders) => {
-				genetic_analysis_orders.push(genetic_analysis_order.id);
-				GeneticAnalysisOrdersByCustomer::<T>::insert(
-					&genetic_analysis_order.customer_id,
-					genetic_analysis_orders,
-				);
-			},
-		}
+		GeneticAnalysisOrdersByCustomer::<T>::insert(
+			&genetic_analysis_order.customer_id,
+			genetic_analysis_order.id,
+			(),
+		);
 	}
 
 	pub fn insert_genetic_analysis_order_id_into_pending_genetic_analysis_orders_by_seller(
 		genetic_analysis_order: &GeneticAnalysisOrderOf<T>,
 	) {
-		match PendingGeneticAnalysisOrdersBySeller::<T>::get(&genetic_analysis_order.seller_id) {
-			None => {
-				PendingGeneticAnalysisOrdersBySeller::<T>::insert(
-					&genetic_analysis_order.seller_id,
-					vec![genetic_analysis_order.id],
-				);
-			},
-			Some(mut genetic_analysis_orders) => {
-				genetic_analysis_orders.push(genetic_analysis_order.id);
-				PendingGeneticAnalysisOrdersBySeller::<T>::insert(
-					&genetic_analysis_order.seller_id,
-					genetic_analysis_orders,
-				);
-			},
-		}
+		PendingGeneticAnalysisOrdersBySeller::<T>::insert(
+			&genetic_analysis_order.seller_id,
+			genetic_analysis_order.id,
+			(),
+		);
 	}
 
 	pub fn is_pending_genetic_analysis_order_ids_by_seller_exist(
 		account_id: &T::AccountId,
 	) -> bool {
-		match PendingGeneticAnalysisOrdersBySeller::<T>::get(account_id) {
-			Some(_arr) => !_arr.is_empty(),
-			None => false,
-		}
+		PendingGeneticAnalysisOrdersBySeller::<T>::iter_key_prefix(account_id)
+			.next()
+			.is_some()
 	}
 
 	pub fn remove_genetic_analysis_order_id_from_pending_genetic_analysis_orders_by_seller(
 		seller_id: &T::AccountId,
 		genetic_analysis_order_id: &T::Hash,
 	) {
-		let mut genetic_analysis_orders =
-			PendingGeneticAnalysisOrdersBySeller::<T>::get(seller_id).unwrap_or_default();
-		genetic_analysis_orders.retain(|o_id| o_id != genetic_analysis_order_id);
-		PendingGeneticAnalysisOrdersBySeller::<T>::insert(seller_id, genetic_analysis_orders);
+		PendingGeneticAnalysisOrdersBySeller::<T>::remove(seller_id, genetic_analysis_order_id);
+	}
+
+	pub fn genetic_analysis_orders_by_customer_id(customer_id: &T::AccountId) -> Vec<T::Hash> {
+		GeneticAnalysisOrdersByCustomer::<T>::iter_key_prefix(customer_id).collect()
+	}
+
+	pub fn genetic_analysis_orders_by_genetic_analyst_id(seller_id: &T::AccountId) -> Vec<T::Hash> {
+		GeneticAnalysisOrdersBySeller::<T>::iter_key_prefix(seller_id).collect()
+	}
+
+	pub fn pending_genetic_analysis_orders_by_genetic_analyst_id(
+		seller_id: &T::AccountId,
+	) -> Vec<T::Hash> {
+		PendingGeneticAnalysisOrdersBySeller::<T>::iter_key_prefix(seller_id).collect()
 	}
 
 	pub fn genetic_analysis_order_can_be_refunded(tracking_id: &TrackingId) -> bool {
@@ -150,6 +130,27 @@ impl<T: Config> Pallet<T> {
 		}
 	}
 
+	pub fn do_transfer_fee_split(
+		genetic_analysis_order: &GeneticAnalysisOrderOf<T>,
+		sender: &T::AccountId,
+		treasury_key: &T::AccountId,
+		fee_split: &FeeSplitOf<T>,
+	) -> Result<(), Error<T>> {
+		let currency = &genetic_analysis_order.currency;
+		let asset_id = genetic_analysis_order.asset_id;
+		let transfers = fee_split.transfers(treasury_key, &genetic_analysis_order.seller_id);
+
+		for (account_id, amount) in transfers.iter() {
+			if amount.is_zero() {
+				continue
+			}
+
+			Self::do_transfer(currency, sender, account_id, *amount, asset_id)?;
+		}
+
+		Ok(())
+	}
+
 	pub fn do_transfer(
 		currency: &CurrencyType,
 		sender: &T::AccountId,
@@ -1,11 +1,13 @@
 use crate::*;
 use frame_support::sp_runtime::traits::Zero;
 use traits_genetic_analysis_orders::{
-	GeneticAnalysisOrderEventEmitter, GeneticAnalysisOrderStatusUpdater,
+	GeneticAnalysisOrderEventEmitter, GeneticAnalysisOrderProvider,
+	GeneticAnalysisOrderStatusUpdater,
 };
 
 impl<T: Config> GeneticAnalysisOrderInterface<T> for Pallet<T> {
 	type GeneticAnalysisOrder = GeneticAnalysisOrderOf<T>;
+	type FeeSplit = FeeSplitOf<T>;
 	type Error = Error<T>;
 
 	fn create_genetic_analysis_order(
@@ -17,6 +19,9 @@ impl<T: Config> GeneticAnalysisOrderInterface<T> for Pallet<T> {
 		genetic_link: &[u8],
 		asset_id: Option<u32>,
 	) -> Result<Self::GeneticAnalysisOrder, Self::Error> {
+		let genetic_link: GeneticLink =
+			genetic_link.to_vec().try_into().map_err(|_| Error::<T>::GeneticLinkTooLong)?;
+
 		let genetic_analyst_service =
 			T::GeneticAnalystServices::genetic_analyst_service_by_id(genetic_analyst_service_id)
 				.ok_or(Error::<T>::GeneticAnalystServiceDoesNotExist)?;
@@ -70,7 +75,7 @@ impl<T: Config> GeneticAnalysisOrderInterface<T> for Pallet<T> {
 			seller_id.clone(),
 			*genetic_data_id,
 			genetic_analysis.get_genetic_analysis_tracking_id().clone(),
-			genetic_link.to_vec(),
+			genetic_link,
 			asset_id,
 			currency.clone(),
 			prices.clone(),
@@ -167,7 +172,7 @@ impl<T: Config> GeneticAnalysisOrderInterface<T> for Pallet<T> {
 	fn fulfill_genetic_analysis_order(
 		escrow_account_id: &T::AccountId,
 		genetic_analysis_order_id: &T::Hash,
-	) -> Result<Self::GeneticAnalysisOrder, Self::Error> {
+	) -> Result<(Self::GeneticAnalysisOrder, Self::FeeSplit), Self::Error> {
 		// Only the admin can fulfill the genetic_analysis_order
 		let _ = EscrowKey::<T>::get()
 			.filter(|admin| admin == escrow_account_id)
@@ -183,32 +188,16 @@ impl<T: Config> GeneticAnalysisOrderInterface<T> for Pallet<T> {
 			.filter(|genetic_analysis| genetic_analysis.process_success())
 			.ok_or(Error::<T>::GeneticAnalysisNotSuccessfullyProcessed)?;
 
-		let total_price = genetic_analysis_order.total_price;
-		let asset_id = genetic_analysis_order.asset_id;
 		let account_id = Self::account_id();
+		let treasury_key = TreasuryKey::<T>::get().ok_or(Error::<T>::TreasuryKeyNotFound)?;
+		let fee_split = T::PlatformFee::fee_schedule()
+			.split(&genetic_analysis_order.currency, genetic_analysis_order.total_price);
 
-		// Calculate 5% of the total price
-		let price_substracted_value: BalanceOf<T> = total_price / 20u128.saturated_into();
-
-		// 5% of the total price is substracted
-		let total_price_paid = total_price - price_substracted_value;
-
-		// Withhold 5% for DBIO
-		Self::do_transfer(
-			&genetic_analysis_order.currency,
-			&account_id,
-			&genetic_analysis_order.seller_id,
-			total_price_paid,
-			asset_id,
-		)?;
-
-		// Transfer 5% to DBIO Treasury
-		Self::do_transfer(
-			&genetic_analysis_order.currency,
+		Self::do_transfer_fee_split(
+			&genetic_analysis_order,
 			&account_id,
-			&TreasuryKey::<T>::get().unwrap(),
-			price_substracted_value,
-			asset_id,
+			&treasury_key,
+			&fee_split,
 		)?;
 
 		let genetic_analysis_order = Self::update_genetic_analysis_order_status(
@@ -216,7 +205,7 @@ impl<T: Config> GeneticAnalysisOrderInterface<T> for Pallet<T> {
 			GeneticAnalysisOrderStatus::Fulfilled,
 		)?;
 
-		Ok(genetic_analysis_order)
+		Ok((genetic_analysis_order, fee_split))
 	}
 
 	fn set_genetic_analysis_order_refunded(
@@ -323,3 +312,13 @@ impl<T: Config> GeneticAnalysisOrderStatusUpdater<T> for Pallet<T> {
 			.is_some()
 	}
 }
+
+impl<T: Config> GeneticAnalysisOrderProvider<T> for Pallet<T> {
+	type GeneticAnalysisOrder = GeneticAnalysisOrderOf<T>;
+
+	fn get_genetic_analysis_order_by_id(
+		genetic_analysis_order_id: &T::Hash,
+	) -> Option<Self::GeneticAnalysisOrder> {
+		Self::genetic_analysis_order_by_id(genetic_analysis_order_id)
+	}
+}
@@ -1,5 +1,6 @@
 pub trait GeneticAnalysisOrderInterface<T: frame_system::Config> {
 	type GeneticAnalysisOrder;
+	type FeeSplit;
 	type Error;
 
 	fn create_genetic_analysis_order(
@@ -22,7 +23,7 @@ pub trait GeneticAnalysisOrderInterface<T: frame_system::Config> {
 	fn fulfill_genetic_analysis_order(
 		seller_id: &T::AccountId,
 		genetic_analysis_order_id: &T::Hash,
-	) -> Result<Self::GeneticAnalysisOrder, Self::Error>;
+	) -> Result<(Self::GeneticAnalysisOrder, Self::FeeSplit), Self::Error>;
 	fn set_genetic_analysis_order_refunded(
 		escrow_account_id: &T::AccountId,
 		genetic_analysis_order_id: &T::Hash,
@@ -14,13 +14,14 @@ use frame_support::{
 	pallet_prelude::*,
 	sp_runtime::{
 		traits::{AccountIdConversion, Hash},
-		RuntimeDebug, SaturatedConversion,
+		RuntimeDebug,
 	},
 	sp_std::convert::TryInto,
 	traits::{Currency, StorageVersion},
 	PalletId,
 };
-use primitives_price_and_currency::{CurrencyType, Price};
+use primitives_platform_fee::{FeeScheduleProvider, FeeSplit};
+use primitives_price_and_currency::{CurrencyType, PriceComponents};
 use primitives_tracking_id::TrackingId;
 use sp_std::{prelude::*, vec};
 use traits_genetic_analysis::{GeneticAnalysisProvider, GeneticAnalysisTracking};
@@ -40,7 +41,7 @@ pub use types::*;
 pub use weights::WeightInfo;
 
 /// The current storage version.
-const STORAGE_VERSION: StorageVersion = StorageVersion::new(3);
+const STORAGE_VERSION: StorageVersion = StorageVersion::new(4);
 
 #[frame_support::pallet]
 pub mod pallet {
@@ -63,6 +64,8 @@ pub mod pallet {
 		type GeneticAnalysis: GeneticAnalysisProvider<Self>;
 		type Currency: Currency<<Self as frame_system::Config>::AccountId>;
 		type GeneticAnalysisOrdersWeightInfo: WeightInfo;
+		/// Platform fee schedule applied when an order is fulfilled.
+		type PlatformFee: FeeScheduleProvider<AccountIdOf<Self>, BalanceOf<Self>>;
 		/// Currency type for this pallet.
 		#[pallet::constant]
 		type PalletId: Get<PalletId>;
@@ -72,7 +75,6 @@ pub mod pallet {
 	#[pallet::pallet]
 	#[pallet::storage_version(STORAGE_VERSION)]
 	#[pallet::generate_store(pub(super) trait Store)]
-	#[pallet::without_storage_info]
 	pub struct Pallet<T>(_);
 
 	#[pallet::hooks]
@@ -91,8 +93,8 @@ pub mod pallet {
 	pub type BalanceOf<T> = <CurrencyOf<T> as Currency<AccountIdOf<T>>>::Balance;
 	pub type GeneticAnalysisOrderOf<T> =
 		GeneticAnalysisOrder<HashOf<T>, AccountIdOf<T>, BalanceOf<T>, MomentOf<T>>;
-	type GeneticAnalysisOrderIdsOf<T> = Vec<HashOf<T>>;
 	pub type AccountKeyTypeOf<T> = AccountKeyType<AccountIdOf<T>>;
+	pub type FeeSplitOf<T> = FeeSplit<AccountIdOf<T>, BalanceOf<T>>;
 	// -------------------------------------------------------
 
 	// ------ Storage --------------------------
@@ -101,20 +103,23 @@ pub mod pallet {
 	pub type GeneticAnalysisOrders<T> =
 		StorageMap<_, Blake2_128Concat, HashOf<T>, GeneticAnalysisOrderOf<T>>;
 
+	/// Get genetic analysis order ids by customer id
+	/// (AccountId, OrderId) => ()
 	#[pallet::storage]
-	#[pallet::getter(fn genetic_analysis_orders_by_customer_id)]
 	pub type GeneticAnalysisOrdersByCustomer<T> =
-		StorageMap<_, Blake2_128Concat, AccountIdOf<T>, GeneticAnalysisOrderIdsOf<T>>;
+		StorageDoubleMap<_, Blake2_128Concat, AccountIdOf<T>, Blake2_128Concat, HashOf<T>, ()>;
 
+	/// Get genetic analysis order ids by genetic analyst id
+	/// (AccountId, OrderId) => ()
 	#[pallet::storage]
-	#[pallet::getter(fn genetic_analysis_orders_by_genetic_analyst_id)]
 	pub type GeneticAnalysisOrdersBySeller<T> =
-		StorageMap<_, Blake2_128Concat, AccountIdOf<T>, GeneticAnalysisOrderIdsOf<T>>;
+		StorageDoubleMap<_, Blake2_128Concat, AccountIdOf<T>, Blake2_128Concat, HashOf<T>, ()>;
 
+	/// Get pending genetic analysis order ids by genetic analyst id
+	/// (AccountId, OrderId) => ()
 	#[pallet::storage]
-	#[pallet::getter(fn pending_genetic_analysis_orders_by_genetic_analyst_id)]
 	pub type PendingGeneticAnalysisOrdersBySeller<T> =
-		StorageMap<_, Blake2_128Concat, AccountIdOf<T>, GeneticAnalysisOrderIdsOf<T>>;
+		StorageDoubleMap<_, Blake2_128Concat, AccountIdOf<T>, Blake2_128Concat, HashOf<T>, ()>;
 
 	#[pallet::storage]
 	#[pallet::getter(fn last_genetic_analysis_order_by_customer_id)]
@@ -176,8 +181,8 @@ pub mod pallet {
 		/// parameters, [GeneticAnalysisOrder]
 		GeneticAnalysisOrderPaid(GeneticAnalysisOrderOf<T>),
 		/// GeneticAnalysisOrder Fulfilled
-		/// parameters, [GeneticAnalysisOrder]
-		GeneticAnalysisOrderFulfilled(GeneticAnalysisOrderOf<T>),
+		/// parameters, [GeneticAnalysisOrder, FeeSplit]
+		GeneticAnalysisOrderFulfilled(GeneticAnalysisOrderOf<T>, FeeSplitOf<T>),
 		/// GeneticAnalysisOrder Refunded
 		/// parameters, [GeneticAnalysisOrder]
 		GeneticAnalysisOrderRefunded(GeneticAnalysisOrderOf<T>),
@@ -244,6 +249,10 @@ pub mod pallet {
 		GeneticAnalysisOrderCannotBeCancelled,
 		GeneticAnalysisOrderCannotBeFulfilled,
 		GeneticAnalysisOrderCannotBeRefunded,
+		/// Treasury key has not been set
+		TreasuryKeyNotFound,
+		/// Genetic link exceeds MAX_GENETIC_LINK_LENGTH
+		GeneticLinkTooLong,
 	}
 
 	#[pallet::call]
@@ -332,9 +341,10 @@ pub mod pallet {
 				&who,
 				&genetic_analysis_order_id,
 			) {
-				Ok(genetic_analysis_order) => {
+				Ok((genetic_analysis_order, fee_split)) => {
 					Self::deposit_event(Event::<T>::GeneticAnalysisOrderFulfilled(
 						genetic_analysis_order,
+						fee_split,
 					));
 					Ok(().into())
 				},
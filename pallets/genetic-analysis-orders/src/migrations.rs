@@ -3,7 +3,9 @@ use crate::{
 	GeneticAnalysisOrders, HashOf, MomentOf, Pallet, PalletAccount, Weight,
 };
 use frame_support::{
+	log,
 	pallet_prelude::*,
+	storage_alias,
 	traits::{fungibles, Get},
 };
 use primitives_price_and_currency::{CurrencyType, Price};
@@ -25,6 +27,11 @@ pub fn migrate<T: Config>() -> Weight {
 		version = StorageVersion::new(3);
 	}
 
+	if version == 3 {
+		weight = weight.saturating_add(version::v4::migrate::<T>());
+		version = StorageVersion::new(4);
+	}
+
 	version.put::<Pallet<T>>();
 	weight
 }
@@ -32,6 +39,88 @@ pub fn migrate<T: Config>() -> Weight {
 mod version {
 	use super::*;
 
+	/// Truncates `value` to the bound and logs the entry when it did not fit.
+	fn bounded<I, S: Get<u32>>(value: Vec<I>, entry: &str) -> BoundedVec<I, S> {
+		if value.len() > S::get() as usize {
+			log::warn!(
+				target: "runtime::genetic-analysis-orders",
+				"{} exceeds its bound of {}, truncated from {} items",
+				entry,
+				S::get(),
+				value.len(),
+			);
+		}
+
+		BoundedVec::truncate_from(value)
+	}
+
+	fn bounded_prices<Balance>(prices: Vec<OldPrice<Balance>>) -> Vec<Price<Balance>> {
+		prices
+			.into_iter()
+			.map(|price| Price {
+				component: bounded(price.component, "GeneticAnalysisOrder price component"),
+				value: price.value,
+			})
+			.collect()
+	}
+
+	/// Price as stored before its component was bounded
+	#[derive(Encode, Decode, Clone)]
+	pub struct OldPrice<Balance> {
+		pub component: Vec<u8>,
+		pub value: Balance,
+	}
+
+	/// `GeneticAnalysisOrder` as stored from v3 until it was bounded in v4
+	#[derive(Encode, Decode)]
+	pub struct GeneticAnalysisOrderV3<Hash, AccountId, Balance, Moment> {
+		pub id: Hash,
+		pub service_id: Hash,
+		pub customer_id: AccountId,
+		pub customer_box_public_key: Hash,
+		pub seller_id: AccountId,
+		pub genetic_data_id: Hash,
+		pub genetic_analysis_tracking_id: TrackingId,
+		pub asset_id: Option<u32>,
+		pub currency: CurrencyType,
+		pub prices: Vec<OldPrice<Balance>>,
+		pub additional_prices: Vec<OldPrice<Balance>>,
+		pub total_price: Balance,
+		pub status: GeneticAnalysisOrderStatus,
+		pub created_at: Moment,
+		pub updated_at: Moment,
+		pub genetic_link: Vec<u8>,
+	}
+
+	pub type GeneticAnalysisOrderV3Of<T> =
+		GeneticAnalysisOrderV3<HashOf<T>, AccountIdOf<T>, BalanceOf<T>, MomentOf<T>>;
+
+	impl<Hash, AccountId, Balance, Moment> GeneticAnalysisOrderV3<Hash, AccountId, Balance, Moment> {
+		fn into_bounded(self) -> GeneticAnalysisOrder<Hash, AccountId, Balance, Moment> {
+			GeneticAnalysisOrder {
+				id: self.id,
+				service_id: self.service_id,
+				customer_id: self.customer_id,
+				customer_box_public_key: self.customer_box_public_key,
+				seller_id: self.seller_id,
+				genetic_data_id: self.genetic_data_id,
+				genetic_analysis_tracking_id: self.genetic_analysis_tracking_id,
+				asset_id: self.asset_id,
+				currency: self.currency,
+				prices: bounded(bounded_prices(self.prices), "GeneticAnalysisOrder prices"),
+				additional_prices: bounded(
+					bounded_prices(self.additional_prices),
+					"GeneticAnalysisOrder additional_prices",
+				),
+				total_price: self.total_price,
+				status: self.status,
+				created_at: self.created_at,
+				updated_at: self.updated_at,
+				genetic_link: bounded(self.genetic_link, "GeneticAnalysisOrder genetic_link"),
+			}
+		}
+	}
+
 	pub mod v2 {
 		use super::*;
 
@@ -58,8 +147,8 @@ mod version {
 				pub genetic_data_id: Hash,
 				pub genetic_analysis_tracking_id: TrackingId,
 				pub currency: CurrencyType,
-				pub prices: Vec<Price<Balance>>,
-				pub additional_prices: Vec<Price<Balance>>,
+				pub prices: Vec<OldPrice<Balance>>,
+				pub additional_prices: Vec<OldPrice<Balance>>,
 				pub total_price: Balance,
 				pub status: GeneticAnalysisOrderStatus,
 				pub created_at: Moment,
@@ -99,7 +188,7 @@ mod version {
 						asset_id
 					};
 
-					Some(GeneticAnalysisOrder {
+					let order = GeneticAnalysisOrderV3 {
 						id: old_order.id,
 						service_id: old_order.service_id,
 						customer_id: old_order.customer_id,
@@ -116,11 +205,88 @@ mod version {
 						created_at: old_order.created_at,
 						updated_at: old_order.updated_at,
 						genetic_link: old_order.genetic_link,
-					})
+					};
+
+					Some(order.into_bounded())
 				},
 			);
 
 			weight
 		}
 	}
+
+	pub mod v4 {
+		use super::*;
+
+		#[storage_alias]
+		type GeneticAnalysisOrdersByCustomer<T: Config> =
+			StorageMap<Pallet<T>, Blake2_128Concat, AccountIdOf<T>, Vec<HashOf<T>>>;
+
+		#[storage_alias]
+		type GeneticAnalysisOrdersBySeller<T: Config> =
+			StorageMap<Pallet<T>, Blake2_128Concat, AccountIdOf<T>, Vec<HashOf<T>>>;
+
+		#[storage_alias]
+		type PendingGeneticAnalysisOrdersBySeller<T: Config> =
+			StorageMap<Pallet<T>, Blake2_128Concat, AccountIdOf<T>, Vec<HashOf<T>>>;
+
+		/// Prices and genetic links are truncated to their bounds. Order id indexes move from
+		/// one list per account to one key per order id, every order id is kept.
+		pub fn migrate<T: Config>() -> Weight {
+			let mut weight = T::DbWeight::get().writes(1);
+
+			GeneticAnalysisOrders::<T>::translate(
+				|_key, old_order: GeneticAnalysisOrderV3Of<T>| {
+					weight = weight.saturating_add(T::DbWeight::get().reads_writes(1, 1));
+
+					Some(old_order.into_bounded())
+				},
+			);
+
+			weight = weight.saturating_add(move_index::<T>(
+				GeneticAnalysisOrdersByCustomer::<T>::drain().collect(),
+				|customer_id, order_id| {
+					crate::GeneticAnalysisOrdersByCustomer::<T>::insert(customer_id, order_id, ())
+				},
+			));
+
+			weight = weight.saturating_add(move_index::<T>(
+				GeneticAnalysisOrdersBySeller::<T>::drain().collect(),
+				|seller_id, order_id| {
+					crate::GeneticAnalysisOrdersBySeller::<T>::insert(seller_id, order_id, ())
+				},
+			));
+
+			weight = weight.saturating_add(move_index::<T>(
+				PendingGeneticAnalysisOrdersBySeller::<T>::drain().collect(),
+				|seller_id, order_id| {
+					crate::PendingGeneticAnalysisOrdersBySeller::<T>::insert(
+						seller_id,
+						order_id,
+						(),
+					)
+				},
+			));
+
+			weight
+		}
+
+		fn move_index<T: Config>(
+			index: Vec<(AccountIdOf<T>, Vec<HashOf<T>>)>,
+			insert: impl Fn(&AccountIdOf<T>, &HashOf<T>),
+		) -> Weight {
+			let mut weight = Weight::zero();
+
+			for (account_id, order_ids) in index {
+				weight = weight
+					.saturating_add(T::DbWeight::get().reads_writes(1, order_ids.len() as u64 + 1));
+
+				for order_id in order_ids.iter() {
+					insert(&account_id, order_id);
+				}
+			}
+
+			weight
+		}
+	}
 }
@@ -11,6 +11,7 @@ use sp_runtime::{
 };
 
 use primitives_ethereum_address::EthereumAddress;
+use primitives_platform_fee::{FeeSchedule, FeeScheduleProvider};
 use primitives_profile_roles::ProfileRoles;
 
 type UncheckedExtrinsic = frame_system::mocking::MockUncheckedExtrinsic<Test>;
@@ -29,7 +30,7 @@ frame_support::construct_runtime!(
 		GeneticData: genetic_data,
 		GeneticAnalysts: genetic_analysts,
 		GeneticAnalystServices: genetic_analyst_services,
-		GeneticAnalystQualifications: genetic_analyst_qualifications,
+		GeneticAnalystQualifications: credentials::<Instance4>,
 		GeneticAnalysis: genetic_analysis,
 		GeneticAnalysisOrders: genetic_analysis_orders,
 		UserProfile: user_profile,
@@ -150,12 +151,14 @@ impl pallet_balances::Config for Test {
 impl genetic_data::Config for Test {
 	type RuntimeEvent = RuntimeEvent;
 	type GeneticDataWeightInfo = ();
+	type MaxGeneticDataPerOwner = frame_support::traits::ConstU32<1_000>;
 }
 
 impl genetic_analysts::Config for Test {
 	type RuntimeEvent = RuntimeEvent;
 	type Currency = Balances;
 	type PalletId = GeneticAnalystPalletId;
+	type RequireVerifiedQualification = frame_support::traits::ConstBool<false>;
 	type GeneticAnalysisOrders = GeneticAnalysisOrders;
 	type GeneticAnalystServices = GeneticAnalystServices;
 	type GeneticAnalystQualifications = GeneticAnalystQualifications;
@@ -172,9 +175,12 @@ impl genetic_analyst_services::Config for Test {
 	type WeightInfo = ();
 }
 
-impl genetic_analyst_qualifications::Config for Test {
+impl credentials::Config<credentials::Instance4> for Test {
 	type RuntimeEvent = RuntimeEvent;
-	type GeneticAnalystQualificationOwner = GeneticAnalysts;
+	type CredentialInfo = credentials::GeneticAnalystQualificationInfo;
+	type CredentialOwner =
+		credentials::legacy::LegacyGeneticAnalystQualificationOwner<GeneticAnalysts>;
+	type CredentialIssuers = ();
 	type WeightInfo = ();
 }
 
@@ -185,6 +191,17 @@ impl genetic_analysis::Config for Test {
 	type GeneticAnalysisWeightInfo = ();
 }
 
+parameter_types! {
+	pub static PlatformFeeSchedule: FeeSchedule<AccountId, Balance> = FeeSchedule::default();
+}
+
+pub struct PlatformFee;
+impl FeeScheduleProvider<AccountId, Balance> for PlatformFee {
+	fn fee_schedule() -> FeeSchedule<AccountId, Balance> {
+		PlatformFeeSchedule::get()
+	}
+}
+
 impl genetic_analysis_orders::Config for Test {
 	type RuntimeEvent = RuntimeEvent;
 	type Currency = Balances;
@@ -194,6 +211,7 @@ impl genetic_analysis_orders::Config for Test {
 	type GeneticAnalysis = GeneticAnalysis;
 	type GeneticAnalystServices = GeneticAnalystServices;
 	type GeneticAnalysisOrdersWeightInfo = ();
+	type PlatformFee = PlatformFee;
 	type PalletId = GeneticAnalysisOrdersEscrowPalletId;
 }
 
@@ -1,13 +1,17 @@
 use crate::{
 	mock::*, AccountKeyType, Error, EscrowKey, GeneticAnalysisOrder, GeneticAnalysisOrderStatus,
-	PalletAccount, TreasuryKey,
+	PalletAccount, TreasuryKey, MAX_GENETIC_LINK_LENGTH,
 };
+use codec::Encode;
 use frame_support::{
 	assert_noop, assert_ok,
 	sp_runtime::{
 		traits::{Hash, Keccak256},
-		SaturatedConversion,
+		Perbill, SaturatedConversion,
 	},
+	storage::{migration::put_storage_value, unhashed},
+	traits::StorageVersion,
+	Blake2_128Concat, StorageHasher,
 };
 use frame_system::RawOrigin;
 use genetic_analysis::GeneticAnalysisStatus;
@@ -16,7 +20,12 @@ use genetic_analysts::GeneticAnalystInfo;
 
 use primitives_availability_status::AvailabilityStatus;
 use primitives_duration::ExpectedDuration;
-use primitives_price_and_currency::{CurrencyType, Price, PriceByCurrency};
+use primitives_platform_fee::{FeeBeneficiary, FeeRate, FeeSchedule, FeeSplit};
+use primitives_price_and_currency::{
+	CurrencyType, Price, PriceByCurrency, MAX_PRICE_COMPONENT_LENGTH,
+};
+use primitives_tracking_id::TrackingId;
+use sp_core::H256;
 
 #[test]
 fn create_genetic_analysis_order() {
@@ -28,15 +37,21 @@ fn create_genetic_analysis_order() {
 				box_public_key: Keccak256::hash(
 					"0xDb9Af2d1f3ADD2726A132AA7A65Cc9E6fC5761C3".as_bytes(),
 				),
-				first_name: "First Name".as_bytes().to_vec(),
-				last_name: "Last Name".as_bytes().to_vec(),
-				gender: "Gender".as_bytes().to_vec(),
+				first_name: "First Name".as_bytes().to_vec().try_into().unwrap(),
+				last_name: "Last Name".as_bytes().to_vec().try_into().unwrap(),
+				gender: "Gender".as_bytes().to_vec().try_into().unwrap(),
 				date_of_birth: 0,
-				email: "Email".as_bytes().to_vec(),
-				phone_number: "+6893026516".as_bytes().to_vec(),
-				specialization: "DeBio Genetic Analyst".as_bytes().to_vec(),
-				profile_link: "DeBio Genetic Analyst profile_link".as_bytes().to_vec(),
-				profile_image: Some("DeBio Genetic Analyst profile_image".as_bytes().to_vec()),
+				email: "Email".as_bytes().to_vec().try_into().unwrap(),
+				phone_number: "+6893026516".as_bytes().to_vec().try_into().unwrap(),
+				specialization: "DeBio Genetic Analyst".as_bytes().to_vec().try_into().unwrap(),
+				profile_link: "DeBio Genetic Analyst profile_link"
+					.as_bytes()
+					.to_vec()
+					.try_into()
+					.unwrap(),
+				profile_image: Some(
+					"DeBio Genetic Analyst profile_image".as_bytes().to_vec().try_into().unwrap()
+				),
 			}
 		));
 
@@ -48,13 +63,19 @@ fn create_genetic_analysis_order() {
 		assert_ok!(GeneticAnalystServices::create_genetic_analyst_service(
 			RuntimeOrigin::signed(1),
 			GeneticAnalystServiceInfo {
-				name: "DeBio Genetic Analyst Service name".as_bytes().to_vec(),
-				prices_by_currency: vec![PriceByCurrency::default()],
+				name: "DeBio Genetic Analyst Service name".as_bytes().to_vec().try_into().unwrap(),
+				prices_by_currency: vec![PriceByCurrency::default()].try_into().unwrap(),
 				expected_duration: ExpectedDuration::default(),
-				description: "DeBio Genetic Analyst Service description".as_bytes().to_vec(),
+				description: "DeBio Genetic Analyst Service description"
+					.as_bytes()
+					.to_vec()
+					.try_into()
+					.unwrap(),
 				test_result_sample: "DeBio Genetic Analyst Service test_result_sample"
 					.as_bytes()
-					.to_vec(),
+					.to_vec()
+					.try_into()
+					.unwrap(),
 			},
 		));
 
@@ -81,7 +102,7 @@ fn create_genetic_analysis_order() {
 
 		let _genetic_analysis_order_id =
 			GeneticAnalysisOrders::last_genetic_analysis_order_by_customer_id(1).unwrap();
-		let _genetic_analysis = GeneticAnalysis::genetic_analysis_by_genetic_analyst_id(1).unwrap();
+		let _genetic_analysis = GeneticAnalysis::genetic_analysis_by_genetic_analyst_id(&1);
 
 		assert_eq!(
 			GeneticAnalysisOrders::genetic_analysis_order_by_id(&_genetic_analysis_order_id),
@@ -95,7 +116,7 @@ fn create_genetic_analysis_order() {
 				),
 				seller_id: 1,
 				genetic_analysis_tracking_id: _genetic_analysis[0].clone(),
-				genetic_link: "DeBio Genetic Genetic Link".as_bytes().to_vec(),
+				genetic_link: "DeBio Genetic Genetic Link".as_bytes().to_vec().try_into().unwrap(),
 				asset_id: None,
 				currency: CurrencyType::default(),
 				prices: PriceByCurrency::default().price_components,
@@ -109,6 +130,24 @@ fn create_genetic_analysis_order() {
 	})
 }
 
+#[test]
+fn cant_create_genetic_analysis_order_when_genetic_link_too_long() {
+	<ExternalityBuilder>::default().existential_deposit(1).build().execute_with(|| {
+		assert_noop!(
+			GeneticAnalysisOrders::create_genetic_analysis_order(
+				RuntimeOrigin::signed(1),
+				Keccak256::hash("genetic_data".as_bytes()),
+				Keccak256::hash("service".as_bytes()),
+				0,
+				Keccak256::hash("0xhJ7TRe456FADD2726A132ABJK5RCc9E6fC5869F4".as_bytes()),
+				vec![0; MAX_GENETIC_LINK_LENGTH as usize + 1],
+				None,
+			),
+			Error::<Test>::GeneticLinkTooLong
+		);
+	})
+}
+
 #[test]
 fn cancel_genetic_analysis_order_works() {
 	<ExternalityBuilder>::default().existential_deposit(1).build().execute_with(|| {
@@ -119,15 +158,21 @@ fn cancel_genetic_analysis_order_works() {
 				box_public_key: Keccak256::hash(
 					"0xDb9Af2d1f3ADD2726A132AA7A65Cc9E6fC5761C3".as_bytes(),
 				),
-				first_name: "First Name".as_bytes().to_vec(),
-				last_name: "Last Name".as_bytes().to_vec(),
-				gender: "Gender".as_bytes().to_vec(),
+				first_name: "First Name".as_bytes().to_vec().try_into().unwrap(),
+				last_name: "Last Name".as_bytes().to_vec().try_into().unwrap(),
+				gender: "Gender".as_bytes().to_vec().try_into().unwrap(),
 				date_of_birth: 0,
-				email: "Email".as_bytes().to_vec(),
-				phone_number: "+6893026516".as_bytes().to_vec(),
-				specialization: "DeBio Genetic Analyst".as_bytes().to_vec(),
-				profile_link: "DeBio Genetic Analyst profile_link".as_bytes().to_vec(),
-				profile_image: Some("DeBio Genetic Analyst profile_image".as_bytes().to_vec()),
+				email: "Email".as_bytes().to_vec().try_into().unwrap(),
+				phone_number: "+6893026516".as_bytes().to_vec().try_into().unwrap(),
+				specialization: "DeBio Genetic Analyst".as_bytes().to_vec().try_into().unwrap(),
+				profile_link: "DeBio Genetic Analyst profile_link"
+					.as_bytes()
+					.to_vec()
+					.try_into()
+					.unwrap(),
+				profile_image: Some(
+					"DeBio Genetic Analyst profile_image".as_bytes().to_vec().try_into().unwrap()
+				),
 			}
 		));
 
@@ -139,13 +184,19 @@ fn cancel_genetic_analysis_order_works() {
 		assert_ok!(GeneticAnalystServices::create_genetic_analyst_service(
 			RuntimeOrigin::signed(1),
 			GeneticAnalystServiceInfo {
-				name: "DeBio Genetic Analyst Service name".as_bytes().to_vec(),
-				prices_by_currency: vec![PriceByCurrency::default()],
+				name: "DeBio Genetic Analyst Service name".as_bytes().to_vec().try_into().unwrap(),
+				prices_by_currency: vec![PriceByCurrency::default()].try_into().unwrap(),
 				expected_duration: ExpectedDuration::default(),
-				description: "DeBio Genetic Analyst Service description".as_bytes().to_vec(),
+				description: "DeBio Genetic Analyst Service description"
+					.as_bytes()
+					.to_vec()
+					.try_into()
+					.unwrap(),
 				test_result_sample: "DeBio Genetic Analyst Service test_result_sample"
 					.as_bytes()
-					.to_vec(),
+					.to_vec()
+					.try_into()
+					.unwrap(),
 			},
 		));
 
@@ -172,7 +223,7 @@ fn cancel_genetic_analysis_order_works() {
 
 		let _genetic_analysis_order_id =
 			GeneticAnalysisOrders::last_genetic_analysis_order_by_customer_id(1).unwrap();
-		let _genetic_analysis = GeneticAnalysis::genetic_analysis_by_genetic_analyst_id(1).unwrap();
+		let _genetic_analysis = GeneticAnalysis::genetic_analysis_by_genetic_analyst_id(&1);
 
 		assert_ok!(GeneticAnalysisOrders::cancel_genetic_analysis_order(
 			RuntimeOrigin::signed(1),
@@ -191,7 +242,7 @@ fn cancel_genetic_analysis_order_works() {
 				),
 				seller_id: 1,
 				genetic_analysis_tracking_id: _genetic_analysis[0].clone(),
-				genetic_link: "DeBio Genetic Genetic Link".as_bytes().to_vec(),
+				genetic_link: "DeBio Genetic Genetic Link".as_bytes().to_vec().try_into().unwrap(),
 				asset_id: None,
 				currency: CurrencyType::default(),
 				prices: PriceByCurrency::default().price_components,
@@ -224,15 +275,21 @@ fn cancel_genetic_analysis_order_with_refund_works() {
 				box_public_key: Keccak256::hash(
 					"0xDb9Af2d1f3ADD2726A132AA7A65Cc9E6fC5761C3".as_bytes(),
 				),
-				first_name: "First Name".as_bytes().to_vec(),
-				last_name: "Last Name".as_bytes().to_vec(),
-				gender: "Gender".as_bytes().to_vec(),
+				first_name: "First Name".as_bytes().to_vec().try_into().unwrap(),
+				last_name: "Last Name".as_bytes().to_vec().try_into().unwrap(),
+				gender: "Gender".as_bytes().to_vec().try_into().unwrap(),
 				date_of_birth: 0,
-				email: "Email".as_bytes().to_vec(),
-				phone_number: "+6893026516".as_bytes().to_vec(),
-				specialization: "DeBio Genetic Analyst".as_bytes().to_vec(),
-				profile_link: "DeBio Genetic Analyst profile_link".as_bytes().to_vec(),
-				profile_image: Some("DeBio Genetic Analyst profile_image".as_bytes().to_vec()),
+				email: "Email".as_bytes().to_vec().try_into().unwrap(),
+				phone_number: "+6893026516".as_bytes().to_vec().try_into().unwrap(),
+				specialization: "DeBio Genetic Analyst".as_bytes().to_vec().try_into().unwrap(),
+				profile_link: "DeBio Genetic Analyst profile_link"
+					.as_bytes()
+					.to_vec()
+					.try_into()
+					.unwrap(),
+				profile_image: Some(
+					"DeBio Genetic Analyst profile_image".as_bytes().to_vec().try_into().unwrap()
+				),
 			}
 		));
 
@@ -242,27 +299,33 @@ fn cancel_genetic_analysis_order_with_refund_works() {
 		));
 
 		let _price = Price {
-			component: "Price Component".as_bytes().to_vec(),
+			component: "Price Component".as_bytes().to_vec().try_into().unwrap(),
 			value: 5000000000000000000u128.saturated_into(),
 		};
 
 		let _price_by_currency = PriceByCurrency {
 			currency: CurrencyType::default(),
 			total_price: 10000000000000000000u128.saturated_into(),
-			price_components: vec![_price.clone()],
-			additional_prices: vec![_price],
+			price_components: vec![_price.clone()].try_into().unwrap(),
+			additional_prices: vec![_price].try_into().unwrap(),
 		};
 
 		assert_ok!(GeneticAnalystServices::create_genetic_analyst_service(
 			RuntimeOrigin::signed(1),
 			GeneticAnalystServiceInfo {
-				name: "DeBio Genetic Analyst Service name".as_bytes().to_vec(),
-				prices_by_currency: vec![_price_by_currency],
+				name: "DeBio Genetic Analyst Service name".as_bytes().to_vec().try_into().unwrap(),
+				prices_by_currency: vec![_price_by_currency].try_into().unwrap(),
 				expected_duration: ExpectedDuration::default(),
-				description: "DeBio Genetic Analyst Service description".as_bytes().to_vec(),
+				description: "DeBio Genetic Analyst Service description"
+					.as_bytes()
+					.to_vec()
+					.try_into()
+					.unwrap(),
 				test_result_sample: "DeBio Genetic Analyst Service test_result_sample"
 					.as_bytes()
-					.to_vec(),
+					.to_vec()
+					.try_into()
+					.unwrap(),
 			},
 		));
 
@@ -289,7 +352,7 @@ fn cancel_genetic_analysis_order_with_refund_works() {
 
 		let _genetic_analysis_order_id =
 			GeneticAnalysisOrders::last_genetic_analysis_order_by_customer_id(1).unwrap();
-		let _genetic_analysis = GeneticAnalysis::genetic_analysis_by_genetic_analyst_id(1).unwrap();
+		let _genetic_analysis = GeneticAnalysis::genetic_analysis_by_genetic_analyst_id(&1);
 
 		EscrowKey::<Test>::put(3);
 
@@ -299,15 +362,15 @@ fn cancel_genetic_analysis_order_with_refund_works() {
 		));
 
 		let _price = Price {
-			component: "Price Component".as_bytes().to_vec(),
+			component: "Price Component".as_bytes().to_vec().try_into().unwrap(),
 			value: 5000000000000000000u128.saturated_into(),
 		};
 
 		let _price_by_currency = PriceByCurrency {
 			currency: CurrencyType::default(),
 			total_price: 10000000000000000000u128.saturated_into(),
-			price_components: vec![_price.clone()],
-			additional_prices: vec![_price],
+			price_components: vec![_price.clone()].try_into().unwrap(),
+			additional_prices: vec![_price].try_into().unwrap(),
 		};
 
 		assert_eq!(
@@ -322,11 +385,11 @@ fn cancel_genetic_analysis_order_with_refund_works() {
 				),
 				seller_id: 1,
 				genetic_analysis_tracking_id: _genetic_analysis[0].clone(),
-				genetic_link: "DeBio Genetic Genetic Link".as_bytes().to_vec(),
+				genetic_link: "DeBio Genetic Genetic Link".as_bytes().to_vec().try_into().unwrap(),
 				asset_id: None,
 				currency: _price_by_currency.currency,
-				prices: _price_by_currency.price_components,
-				additional_prices: _price_by_currency.additional_prices,
+				prices: _price_by_currency.price_components.clone(),
+				additional_prices: _price_by_currency.additional_prices.clone(),
 				total_price: _price_by_currency.total_price,
 				status: GeneticAnalysisOrderStatus::Paid,
 				created_at: 0,
@@ -340,15 +403,15 @@ fn cancel_genetic_analysis_order_with_refund_works() {
 		));
 
 		let _price = Price {
-			component: "Price Component".as_bytes().to_vec(),
+			component: "Price Component".as_bytes().to_vec().try_into().unwrap(),
 			value: 5000000000000000000u128.saturated_into(),
 		};
 
 		let _price_by_currency = PriceByCurrency {
 			currency: CurrencyType::default(),
 			total_price: 10000000000000000000u128.saturated_into(),
-			price_components: vec![_price.clone()],
-			additional_prices: vec![_price],
+			price_components: vec![_price.clone()].try_into().unwrap(),
+			additional_prices: vec![_price].try_into().unwrap(),
 		};
 
 		assert_eq!(
@@ -363,11 +426,11 @@ fn cancel_genetic_analysis_order_with_refund_works() {
 				),
 				seller_id: 1,
 				genetic_analysis_tracking_id: _genetic_analysis[0].clone(),
-				genetic_link: "DeBio Genetic Genetic Link".as_bytes().to_vec(),
+				genetic_link: "DeBio Genetic Genetic Link".as_bytes().to_vec().try_into().unwrap(),
 				asset_id: None,
 				currency: _price_by_currency.currency,
-				prices: _price_by_currency.price_components,
-				additional_prices: _price_by_currency.additional_prices,
+				prices: _price_by_currency.price_components.clone(),
+				additional_prices: _price_by_currency.additional_prices.clone(),
 				total_price: _price_by_currency.total_price,
 				status: GeneticAnalysisOrderStatus::Refunded,
 				created_at: 0,
@@ -376,8 +439,8 @@ fn cancel_genetic_analysis_order_with_refund_works() {
 		);
 
 		assert_eq!(
-			GeneticAnalysisOrders::pending_genetic_analysis_orders_by_genetic_analyst_id(1),
-			Some(Vec::new())
+			GeneticAnalysisOrders::pending_genetic_analysis_orders_by_genetic_analyst_id(&1),
+			Vec::new()
 		);
 	})
 }
@@ -400,15 +463,21 @@ fn set_genetic_analysis_order_paid_works() {
 				box_public_key: Keccak256::hash(
 					"0xDb9Af2d1f3ADD2726A132AA7A65Cc9E6fC5761C3".as_bytes(),
 				),
-				first_name: "First Name".as_bytes().to_vec(),
-				last_name: "Last Name".as_bytes().to_vec(),
-				gender: "Gender".as_bytes().to_vec(),
+				first_name: "First Name".as_bytes().to_vec().try_into().unwrap(),
+				last_name: "Last Name".as_bytes().to_vec().try_into().unwrap(),
+				gender: "Gender".as_bytes().to_vec().try_into().unwrap(),
 				date_of_birth: 0,
-				email: "Email".as_bytes().to_vec(),
-				phone_number: "+6893026516".as_bytes().to_vec(),
-				specialization: "DeBio Genetic Analyst".as_bytes().to_vec(),
-				profile_link: "DeBio Genetic Analyst profile_link".as_bytes().to_vec(),
-				profile_image: Some("DeBio Genetic Analyst profile_image".as_bytes().to_vec()),
+				email: "Email".as_bytes().to_vec().try_into().unwrap(),
+				phone_number: "+6893026516".as_bytes().to_vec().try_into().unwrap(),
+				specialization: "DeBio Genetic Analyst".as_bytes().to_vec().try_into().unwrap(),
+				profile_link: "DeBio Genetic Analyst profile_link"
+					.as_bytes()
+					.to_vec()
+					.try_into()
+					.unwrap(),
+				profile_image: Some(
+					"DeBio Genetic Analyst profile_image".as_bytes().to_vec().try_into().unwrap()
+				),
 			}
 		));
 
@@ -418,27 +487,33 @@ fn set_genetic_analysis_order_paid_works() {
 		));
 
 		let _price = Price {
-			component: "Price Component".as_bytes().to_vec(),
+			component: "Price Component".as_bytes().to_vec().try_into().unwrap(),
 			value: 5000000000000000000u128.saturated_into(),
 		};
 
 		let _price_by_currency = PriceByCurrency {
 			currency: CurrencyType::default(),
 			total_price: 10000000000000000000u128.saturated_into(),
-			price_components: vec![_price.clone()],
-			additional_prices: vec![_price],
+			price_components: vec![_price.clone()].try_into().unwrap(),
+			additional_prices: vec![_price].try_into().unwrap(),
 		};
 
 		assert_ok!(GeneticAnalystServices::create_genetic_analyst_service(
 			RuntimeOrigin::signed(1),
 			GeneticAnalystServiceInfo {
-				name: "DeBio Genetic Analyst Service name".as_bytes().to_vec(),
-				prices_by_currency: vec![_price_by_currency],
+				name: "DeBio Genetic Analyst Service name".as_bytes().to_vec().try_into().unwrap(),
+				prices_by_currency: vec![_price_by_currency].try_into().unwrap(),
 				expected_duration: ExpectedDuration::default(),
-				description: "DeBio Genetic Analyst Service description".as_bytes().to_vec(),
+				description: "DeBio Genetic Analyst Service description"
+					.as_bytes()
+					.to_vec()
+					.try_into()
+					.unwrap(),
 				test_result_sample: "DeBio Genetic Analyst Service test_result_sample"
 					.as_bytes()
-					.to_vec(),
+					.to_vec()
+					.try_into()
+					.unwrap(),
 			},
 		));
 
@@ -465,7 +540,7 @@ fn set_genetic_analysis_order_paid_works() {
 
 		let _genetic_analysis_order_id =
 			GeneticAnalysisOrders::last_genetic_analysis_order_by_customer_id(1).unwrap();
-		let _genetic_analysis = GeneticAnalysis::genetic_analysis_by_genetic_analyst_id(1).unwrap();
+		let _genetic_analysis = GeneticAnalysis::genetic_analysis_by_genetic_analyst_id(&1);
 
 		EscrowKey::<Test>::put(3);
 
@@ -475,15 +550,15 @@ fn set_genetic_analysis_order_paid_works() {
 		));
 
 		let _price = Price {
-			component: "Price Component".as_bytes().to_vec(),
+			component: "Price Component".as_bytes().to_vec().try_into().unwrap(),
 			value: 5000000000000000000u128.saturated_into(),
 		};
 
 		let _price_by_currency = PriceByCurrency {
 			currency: CurrencyType::default(),
 			total_price: 10000000000000000000u128.saturated_into(),
-			price_components: vec![_price.clone()],
-			additional_prices: vec![_price],
+			price_components: vec![_price.clone()].try_into().unwrap(),
+			additional_prices: vec![_price].try_into().unwrap(),
 		};
 
 		assert_eq!(
@@ -498,11 +573,11 @@ fn set_genetic_analysis_order_paid_works() {
 				),
 				seller_id: 1,
 				genetic_analysis_tracking_id: _genetic_analysis[0].clone(),
-				genetic_link: "DeBio Genetic Genetic Link".as_bytes().to_vec(),
+				genetic_link: "DeBio Genetic Genetic Link".as_bytes().to_vec().try_into().unwrap(),
 				asset_id: None,
 				currency: _price_by_currency.currency,
-				prices: _price_by_currency.price_components,
-				additional_prices: _price_by_currency.additional_prices,
+				prices: _price_by_currency.price_components.clone(),
+				additional_prices: _price_by_currency.additional_prices.clone(),
 				total_price: _price_by_currency.total_price,
 				status: GeneticAnalysisOrderStatus::Paid,
 				created_at: 0,
@@ -528,15 +603,21 @@ fn fulfill_genetic_analysis_order_works() {
 				box_public_key: Keccak256::hash(
 					"0xDb9Af2d1f3ADD2726A132AA7A65Cc9E6fC5761C3".as_bytes(),
 				),
-				first_name: "First Name".as_bytes().to_vec(),
-				last_name: "Last Name".as_bytes().to_vec(),
-				gender: "Gender".as_bytes().to_vec(),
+				first_name: "First Name".as_bytes().to_vec().try_into().unwrap(),
+				last_name: "Last Name".as_bytes().to_vec().try_into().unwrap(),
+				gender: "Gender".as_bytes().to_vec().try_into().unwrap(),
 				date_of_birth: 0,
-				email: "Email".as_bytes().to_vec(),
-				phone_number: "+6893026516".as_bytes().to_vec(),
-				specialization: "DeBio Genetic Analyst".as_bytes().to_vec(),
-				profile_link: "DeBio Genetic Analyst profile_link".as_bytes().to_vec(),
-				profile_image: Some("DeBio Genetic Analyst profile_image".as_bytes().to_vec()),
+				email: "Email".as_bytes().to_vec().try_into().unwrap(),
+				phone_number: "+6893026516".as_bytes().to_vec().try_into().unwrap(),
+				specialization: "DeBio Genetic Analyst".as_bytes().to_vec().try_into().unwrap(),
+				profile_link: "DeBio Genetic Analyst profile_link"
+					.as_bytes()
+					.to_vec()
+					.try_into()
+					.unwrap(),
+				profile_image: Some(
+					"DeBio Genetic Analyst profile_image".as_bytes().to_vec().try_into().unwrap()
+				),
 			}
 		));
 
@@ -546,27 +627,33 @@ fn fulfill_genetic_analysis_order_works() {
 		));
 
 		let _price = Price {
-			component: "Price Component".as_bytes().to_vec(),
+			component: "Price Component".as_bytes().to_vec().try_into().unwrap(),
 			value: 500u128.saturated_into(),
 		};
 
 		let _price_by_currency = PriceByCurrency {
 			currency: CurrencyType::default(),
 			total_price: 1000u128.saturated_into(),
-			price_components: vec![_price.clone()],
-			additional_prices: vec![_price.clone()],
+			price_components: vec![_price.clone()].try_into().unwrap(),
+			additional_prices: vec![_price.clone()].try_into().unwrap(),
 		};
 
 		assert_ok!(GeneticAnalystServices::create_genetic_analyst_service(
 			RuntimeOrigin::signed(1),
 			GeneticAnalystServiceInfo {
-				name: "DeBio Genetic Analyst Service name".as_bytes().to_vec(),
-				prices_by_currency: vec![_price_by_currency],
+				name: "DeBio Genetic Analyst Service name".as_bytes().to_vec().try_into().unwrap(),
+				prices_by_currency: vec![_price_by_currency].try_into().unwrap(),
 				expected_duration: ExpectedDuration::default(),
-				description: "DeBio Genetic Analyst Service description".as_bytes().to_vec(),
+				description: "DeBio Genetic Analyst Service description"
+					.as_bytes()
+					.to_vec()
+					.try_into()
+					.unwrap(),
 				test_result_sample: "DeBio Genetic Analyst Service test_result_sample"
 					.as_bytes()
-					.to_vec(),
+					.to_vec()
+					.try_into()
+					.unwrap(),
 			},
 		));
 
@@ -599,7 +686,7 @@ fn fulfill_genetic_analysis_order_works() {
 			_genetic_analysis_order_id
 		));
 
-		let _genetic_analysis = GeneticAnalysis::genetic_analysis_by_genetic_analyst_id(1).unwrap();
+		let _genetic_analysis = GeneticAnalysis::genetic_analysis_by_genetic_analyst_id(&1);
 
 		assert_ok!(GeneticAnalysis::submit_genetic_analysis(
 			RuntimeOrigin::signed(1),
@@ -636,11 +723,11 @@ fn fulfill_genetic_analysis_order_works() {
 				),
 				seller_id: 1,
 				genetic_analysis_tracking_id: _genetic_analysis[0].clone(),
-				genetic_link: "DeBio Genetic Genetic Link".as_bytes().to_vec(),
+				genetic_link: "DeBio Genetic Genetic Link".as_bytes().to_vec().try_into().unwrap(),
 				asset_id: None,
 				currency: CurrencyType::default(),
-				prices: vec![_price.clone()],
-				additional_prices: vec![_price.clone()],
+				prices: vec![_price.clone()].try_into().unwrap(),
+				additional_prices: vec![_price.clone()].try_into().unwrap(),
 				total_price: 1000u128.saturated_into(),
 				status: GeneticAnalysisOrderStatus::Fulfilled,
 				created_at: 0,
@@ -660,11 +747,11 @@ fn fulfill_genetic_analysis_order_works() {
 				),
 				seller_id: 1,
 				genetic_analysis_tracking_id: _genetic_analysis[0].clone(),
-				genetic_link: "DeBio Genetic Genetic Link".as_bytes().to_vec(),
+				genetic_link: "DeBio Genetic Genetic Link".as_bytes().to_vec().try_into().unwrap(),
 				asset_id: None,
 				currency: CurrencyType::default(),
-				prices: vec![_price.clone()],
-				additional_prices: vec![_price],
+				prices: vec![_price.clone()].try_into().unwrap(),
+				additional_prices: vec![_price].try_into().unwrap(),
 				total_price: 1000u128.saturated_into(),
 				status: GeneticAnalysisOrderStatus::Fulfilled,
 				created_at: 0,
@@ -688,15 +775,21 @@ fn set_genetic_analysis_order_refunded_works() {
 				box_public_key: Keccak256::hash(
 					"0xDb9Af2d1f3ADD2726A132AA7A65Cc9E6fC5761C3".as_bytes(),
 				),
-				first_name: "First Name".as_bytes().to_vec(),
-				last_name: "Last Name".as_bytes().to_vec(),
-				gender: "Gender".as_bytes().to_vec(),
+				first_name: "First Name".as_bytes().to_vec().try_into().unwrap(),
+				last_name: "Last Name".as_bytes().to_vec().try_into().unwrap(),
+				gender: "Gender".as_bytes().to_vec().try_into().unwrap(),
 				date_of_birth: 0,
-				email: "Email".as_bytes().to_vec(),
-				phone_number: "+6893026516".as_bytes().to_vec(),
-				specialization: "DeBio Genetic Analyst".as_bytes().to_vec(),
-				profile_link: "DeBio Genetic Analyst profile_link".as_bytes().to_vec(),
-				profile_image: Some("DeBio Genetic Analyst profile_image".as_bytes().to_vec()),
+				email: "Email".as_bytes().to_vec().try_into().unwrap(),
+				phone_number: "+6893026516".as_bytes().to_vec().try_into().unwrap(),
+				specialization: "DeBio Genetic Analyst".as_bytes().to_vec().try_into().unwrap(),
+				profile_link: "DeBio Genetic Analyst profile_link"
+					.as_bytes()
+					.to_vec()
+					.try_into()
+					.unwrap(),
+				profile_image: Some(
+					"DeBio Genetic Analyst profile_image".as_bytes().to_vec().try_into().unwrap()
+				),
 			}
 		));
 
@@ -708,13 +801,19 @@ fn set_genetic_analysis_order_refunded_works() {
 		assert_ok!(GeneticAnalystServices::create_genetic_analyst_service(
 			RuntimeOrigin::signed(1),
 			GeneticAnalystServiceInfo {
-				name: "DeBio Genetic Analyst Service name".as_bytes().to_vec(),
-				prices_by_currency: vec![PriceByCurrency::default()],
+				name: "DeBio Genetic Analyst Service name".as_bytes().to_vec().try_into().unwrap(),
+				prices_by_currency: vec![PriceByCurrency::default()].try_into().unwrap(),
 				expected_duration: ExpectedDuration::default(),
-				description: "DeBio Genetic Analyst Service description".as_bytes().to_vec(),
+				description: "DeBio Genetic Analyst Service description"
+					.as_bytes()
+					.to_vec()
+					.try_into()
+					.unwrap(),
 				test_result_sample: "DeBio Genetic Analyst Service test_result_sample"
 					.as_bytes()
-					.to_vec(),
+					.to_vec()
+					.try_into()
+					.unwrap(),
 			},
 		));
 
@@ -741,7 +840,7 @@ fn set_genetic_analysis_order_refunded_works() {
 
 		let _genetic_analysis_order_id =
 			GeneticAnalysisOrders::last_genetic_analysis_order_by_customer_id(1).unwrap();
-		let _genetic_analysis = GeneticAnalysis::genetic_analysis_by_genetic_analyst_id(1).unwrap();
+		let _genetic_analysis = GeneticAnalysis::genetic_analysis_by_genetic_analyst_id(&1);
 
 		assert_ok!(GeneticAnalysisOrders::set_genetic_analysis_order_paid(
 			RuntimeOrigin::signed(1),
@@ -778,7 +877,7 @@ fn set_genetic_analysis_order_refunded_works() {
 				),
 				seller_id: 1,
 				genetic_analysis_tracking_id: _genetic_analysis[0].clone(),
-				genetic_link: "DeBio Genetic Genetic Link".as_bytes().to_vec(),
+				genetic_link: "DeBio Genetic Genetic Link".as_bytes().to_vec().try_into().unwrap(),
 				asset_id: None,
 				currency: CurrencyType::default(),
 				prices: PriceByCurrency::default().price_components,
@@ -831,15 +930,21 @@ fn cant_create_genetic_analysis_order_when_price_index_not_found() {
 				box_public_key: Keccak256::hash(
 					"0xDb9Af2d1f3ADD2726A132AA7A65Cc9E6fC5761C3".as_bytes(),
 				),
-				first_name: "First Name".as_bytes().to_vec(),
-				last_name: "Last Name".as_bytes().to_vec(),
-				gender: "Gender".as_bytes().to_vec(),
+				first_name: "First Name".as_bytes().to_vec().try_into().unwrap(),
+				last_name: "Last Name".as_bytes().to_vec().try_into().unwrap(),
+				gender: "Gender".as_bytes().to_vec().try_into().unwrap(),
 				date_of_birth: 0,
-				email: "Email".as_bytes().to_vec(),
-				phone_number: "+6893026516".as_bytes().to_vec(),
-				specialization: "DeBio Genetic Analyst".as_bytes().to_vec(),
-				profile_link: "DeBio Genetic Analyst profile_link".as_bytes().to_vec(),
-				profile_image: Some("DeBio Genetic Analyst profile_image".as_bytes().to_vec()),
+				email: "Email".as_bytes().to_vec().try_into().unwrap(),
+				phone_number: "+6893026516".as_bytes().to_vec().try_into().unwrap(),
+				specialization: "DeBio Genetic Analyst".as_bytes().to_vec().try_into().unwrap(),
+				profile_link: "DeBio Genetic Analyst profile_link"
+					.as_bytes()
+					.to_vec()
+					.try_into()
+					.unwrap(),
+				profile_image: Some(
+					"DeBio Genetic Analyst profile_image".as_bytes().to_vec().try_into().unwrap()
+				),
 			}
 		));
 
@@ -851,13 +956,19 @@ fn cant_create_genetic_analysis_order_when_price_index_not_found() {
 		assert_ok!(GeneticAnalystServices::create_genetic_analyst_service(
 			RuntimeOrigin::signed(1),
 			GeneticAnalystServiceInfo {
-				name: "DeBio Genetic Analyst Service name".as_bytes().to_vec(),
-				prices_by_currency: vec![PriceByCurrency::default()],
+				name: "DeBio Genetic Analyst Service name".as_bytes().to_vec().try_into().unwrap(),
+				prices_by_currency: vec![PriceByCurrency::default()].try_into().unwrap(),
 				expected_duration: ExpectedDuration::default(),
-				description: "DeBio Genetic Analyst Service description".as_bytes().to_vec(),
+				description: "DeBio Genetic Analyst Service description"
+					.as_bytes()
+					.to_vec()
+					.try_into()
+					.unwrap(),
 				test_result_sample: "DeBio Genetic Analyst Service test_result_sample"
 					.as_bytes()
-					.to_vec(),
+					.to_vec()
+					.try_into()
+					.unwrap(),
 			},
 		));
 
@@ -897,15 +1008,21 @@ fn cant_create_genetic_analysis_order_when_genetic_analyst_unavailable() {
 				box_public_key: Keccak256::hash(
 					"0xDb9Af2d1f3ADD2726A132AA7A65Cc9E6fC5761C3".as_bytes(),
 				),
-				first_name: "First Name".as_bytes().to_vec(),
-				last_name: "Last Name".as_bytes().to_vec(),
-				gender: "Gender".as_bytes().to_vec(),
+				first_name: "First Name".as_bytes().to_vec().try_into().unwrap(),
+				last_name: "Last Name".as_bytes().to_vec().try_into().unwrap(),
+				gender: "Gender".as_bytes().to_vec().try_into().unwrap(),
 				date_of_birth: 0,
-				email: "Email".as_bytes().to_vec(),
-				phone_number: "+6893026516".as_bytes().to_vec(),
-				specialization: "DeBio Genetic Analyst".as_bytes().to_vec(),
-				profile_link: "DeBio Genetic Analyst profile_link".as_bytes().to_vec(),
-				profile_image: Some("DeBio Genetic Analyst profile_image".as_bytes().to_vec()),
+				email: "Email".as_bytes().to_vec().try_into().unwrap(),
+				phone_number: "+6893026516".as_bytes().to_vec().try_into().unwrap(),
+				specialization: "DeBio Genetic Analyst".as_bytes().to_vec().try_into().unwrap(),
+				profile_link: "DeBio Genetic Analyst profile_link"
+					.as_bytes()
+					.to_vec()
+					.try_into()
+					.unwrap(),
+				profile_image: Some(
+					"DeBio Genetic Analyst profile_image".as_bytes().to_vec().try_into().unwrap()
+				),
 			}
 		));
 
@@ -917,13 +1034,19 @@ fn cant_create_genetic_analysis_order_when_genetic_analyst_unavailable() {
 		assert_ok!(GeneticAnalystServices::create_genetic_analyst_service(
 			RuntimeOrigin::signed(1),
 			GeneticAnalystServiceInfo {
-				name: "DeBio Genetic Analyst Service name".as_bytes().to_vec(),
-				prices_by_currency: vec![PriceByCurrency::default()],
+				name: "DeBio Genetic Analyst Service name".as_bytes().to_vec().try_into().unwrap(),
+				prices_by_currency: vec![PriceByCurrency::default()].try_into().unwrap(),
 				expected_duration: ExpectedDuration::default(),
-				description: "DeBio Genetic Analyst Service description".as_bytes().to_vec(),
+				description: "DeBio Genetic Analyst Service description"
+					.as_bytes()
+					.to_vec()
+					.try_into()
+					.unwrap(),
 				test_result_sample: "DeBio Genetic Analyst Service test_result_sample"
 					.as_bytes()
-					.to_vec(),
+					.to_vec()
+					.try_into()
+					.unwrap(),
 			},
 		));
 
@@ -963,15 +1086,21 @@ fn cant_create_genetic_analysis_order_when_genetic_data_does_not_exist() {
 				box_public_key: Keccak256::hash(
 					"0xDb9Af2d1f3ADD2726A132AA7A65Cc9E6fC5761C3".as_bytes(),
 				),
-				first_name: "First Name".as_bytes().to_vec(),
-				last_name: "Last Name".as_bytes().to_vec(),
-				gender: "Gender".as_bytes().to_vec(),
+				first_name: "First Name".as_bytes().to_vec().try_into().unwrap(),
+				last_name: "Last Name".as_bytes().to_vec().try_into().unwrap(),
+				gender: "Gender".as_bytes().to_vec().try_into().unwrap(),
 				date_of_birth: 0,
-				email: "Email".as_bytes().to_vec(),
-				phone_number: "+6893026516".as_bytes().to_vec(),
-				specialization: "DeBio Genetic Analyst".as_bytes().to_vec(),
-				profile_link: "DeBio Genetic Analyst profile_link".as_bytes().to_vec(),
-				profile_image: Some("DeBio Genetic Analyst profile_image".as_bytes().to_vec()),
+				email: "Email".as_bytes().to_vec().try_into().unwrap(),
+				phone_number: "+6893026516".as_bytes().to_vec().try_into().unwrap(),
+				specialization: "DeBio Genetic Analyst".as_bytes().to_vec().try_into().unwrap(),
+				profile_link: "DeBio Genetic Analyst profile_link"
+					.as_bytes()
+					.to_vec()
+					.try_into()
+					.unwrap(),
+				profile_image: Some(
+					"DeBio Genetic Analyst profile_image".as_bytes().to_vec().try_into().unwrap()
+				),
 			}
 		));
 
@@ -983,13 +1112,19 @@ fn cant_create_genetic_analysis_order_when_genetic_data_does_not_exist() {
 		assert_ok!(GeneticAnalystServices::create_genetic_analyst_service(
 			RuntimeOrigin::signed(1),
 			GeneticAnalystServiceInfo {
-				name: "DeBio Genetic Analyst Service name".as_bytes().to_vec(),
-				prices_by_currency: vec![PriceByCurrency::default()],
+				name: "DeBio Genetic Analyst Service name".as_bytes().to_vec().try_into().unwrap(),
+				prices_by_currency: vec![PriceByCurrency::default()].try_into().unwrap(),
 				expected_duration: ExpectedDuration::default(),
-				description: "DeBio Genetic Analyst Service description".as_bytes().to_vec(),
+				description: "DeBio Genetic Analyst Service description"
+					.as_bytes()
+					.to_vec()
+					.try_into()
+					.unwrap(),
 				test_result_sample: "DeBio Genetic Analyst Service test_result_sample"
 					.as_bytes()
-					.to_vec(),
+					.to_vec()
+					.try_into()
+					.unwrap(),
 			},
 		));
 
@@ -1029,15 +1164,21 @@ fn cant_create_genetic_analysis_order_when_not_owner_of_genetic_data() {
 				box_public_key: Keccak256::hash(
 					"0xDb9Af2d1f3ADD2726A132AA7A65Cc9E6fC5761C3".as_bytes(),
 				),
-				first_name: "First Name".as_bytes().to_vec(),
-				last_name: "Last Name".as_bytes().to_vec(),
-				gender: "Gender".as_bytes().to_vec(),
+				first_name: "First Name".as_bytes().to_vec().try_into().unwrap(),
+				last_name: "Last Name".as_bytes().to_vec().try_into().unwrap(),
+				gender: "Gender".as_bytes().to_vec().try_into().unwrap(),
 				date_of_birth: 0,
-				email: "Email".as_bytes().to_vec(),
-				phone_number: "+6893026516".as_bytes().to_vec(),
-				specialization: "DeBio Genetic Analyst".as_bytes().to_vec(),
-				profile_link: "DeBio Genetic Analyst profile_link".as_bytes().to_vec(),
-				profile_image: Some("DeBio Genetic Analyst profile_image".as_bytes().to_vec()),
+				email: "Email".as_bytes().to_vec().try_into().unwrap(),
+				phone_number: "+6893026516".as_bytes().to_vec().try_into().unwrap(),
+				specialization: "DeBio Genetic Analyst".as_bytes().to_vec().try_into().unwrap(),
+				profile_link: "DeBio Genetic Analyst profile_link"
+					.as_bytes()
+					.to_vec()
+					.try_into()
+					.unwrap(),
+				profile_image: Some(
+					"DeBio Genetic Analyst profile_image".as_bytes().to_vec().try_into().unwrap()
+				),
 			}
 		));
 
@@ -1049,13 +1190,19 @@ fn cant_create_genetic_analysis_order_when_not_owner_of_genetic_data() {
 		assert_ok!(GeneticAnalystServices::create_genetic_analyst_service(
 			RuntimeOrigin::signed(1),
 			GeneticAnalystServiceInfo {
-				name: "DeBio Genetic Analyst Service name".as_bytes().to_vec(),
-				prices_by_currency: vec![PriceByCurrency::default()],
+				name: "DeBio Genetic Analyst Service name".as_bytes().to_vec().try_into().unwrap(),
+				prices_by_currency: vec![PriceByCurrency::default()].try_into().unwrap(),
 				expected_duration: ExpectedDuration::default(),
-				description: "DeBio Genetic Analyst Service description".as_bytes().to_vec(),
+				description: "DeBio Genetic Analyst Service description"
+					.as_bytes()
+					.to_vec()
+					.try_into()
+					.unwrap(),
 				test_result_sample: "DeBio Genetic Analyst Service test_result_sample"
 					.as_bytes()
-					.to_vec(),
+					.to_vec()
+					.try_into()
+					.unwrap(),
 			},
 		));
 
@@ -1097,15 +1244,21 @@ fn cant_cancel_genetic_analysis_order_when_order_ongoing() {
 				box_public_key: Keccak256::hash(
 					"0xDb9Af2d1f3ADD2726A132AA7A65Cc9E6fC5761C3".as_bytes(),
 				),
-				first_name: "First Name".as_bytes().to_vec(),
-				last_name: "Last Name".as_bytes().to_vec(),
-				gender: "Gender".as_bytes().to_vec(),
+				first_name: "First Name".as_bytes().to_vec().try_into().unwrap(),
+				last_name: "Last Name".as_bytes().to_vec().try_into().unwrap(),
+				gender: "Gender".as_bytes().to_vec().try_into().unwrap(),
 				date_of_birth: 0,
-				email: "Email".as_bytes().to_vec(),
-				phone_number: "+6893026516".as_bytes().to_vec(),
-				specialization: "DeBio Genetic Analyst".as_bytes().to_vec(),
-				profile_link: "DeBio Genetic Analyst profile_link".as_bytes().to_vec(),
-				profile_image: Some("DeBio Genetic Analyst profile_image".as_bytes().to_vec()),
+				email: "Email".as_bytes().to_vec().try_into().unwrap(),
+				phone_number: "+6893026516".as_bytes().to_vec().try_into().unwrap(),
+				specialization: "DeBio Genetic Analyst".as_bytes().to_vec().try_into().unwrap(),
+				profile_link: "DeBio Genetic Analyst profile_link"
+					.as_bytes()
+					.to_vec()
+					.try_into()
+					.unwrap(),
+				profile_image: Some(
+					"DeBio Genetic Analyst profile_image".as_bytes().to_vec().try_into().unwrap()
+				),
 			}
 		));
 
@@ -1117,13 +1270,19 @@ fn cant_cancel_genetic_analysis_order_when_order_ongoing() {
 		assert_ok!(GeneticAnalystServices::create_genetic_analyst_service(
 			RuntimeOrigin::signed(1),
 			GeneticAnalystServiceInfo {
-				name: "DeBio Genetic Analyst Service name".as_bytes().to_vec(),
-				prices_by_currency: vec![PriceByCurrency::default()],
+				name: "DeBio Genetic Analyst Service name".as_bytes().to_vec().try_into().unwrap(),
+				prices_by_currency: vec![PriceByCurrency::default()].try_into().unwrap(),
 				expected_duration: ExpectedDuration::default(),
-				description: "DeBio Genetic Analyst Service description".as_bytes().to_vec(),
+				description: "DeBio Genetic Analyst Service description"
+					.as_bytes()
+					.to_vec()
+					.try_into()
+					.unwrap(),
 				test_result_sample: "DeBio Genetic Analyst Service test_result_sample"
 					.as_bytes()
-					.to_vec(),
+					.to_vec()
+					.try_into()
+					.unwrap(),
 			},
 		));
 
@@ -1150,7 +1309,7 @@ fn cant_cancel_genetic_analysis_order_when_order_ongoing() {
 
 		let _genetic_analysis_order_id =
 			GeneticAnalysisOrders::last_genetic_analysis_order_by_customer_id(1).unwrap();
-		let _genetic_analysis = GeneticAnalysis::genetic_analysis_by_genetic_analyst_id(1).unwrap();
+		let _genetic_analysis = GeneticAnalysis::genetic_analysis_by_genetic_analyst_id(&1);
 
 		assert_ok!(GeneticAnalysisOrders::set_genetic_analysis_order_paid(
 			RuntimeOrigin::signed(1),
@@ -1196,15 +1355,21 @@ fn cant_cancel_genetic_analysis_order_when_unathorized_user() {
 				box_public_key: Keccak256::hash(
 					"0xDb9Af2d1f3ADD2726A132AA7A65Cc9E6fC5761C3".as_bytes(),
 				),
-				first_name: "First Name".as_bytes().to_vec(),
-				last_name: "Last Name".as_bytes().to_vec(),
-				gender: "Gender".as_bytes().to_vec(),
+				first_name: "First Name".as_bytes().to_vec().try_into().unwrap(),
+				last_name: "Last Name".as_bytes().to_vec().try_into().unwrap(),
+				gender: "Gender".as_bytes().to_vec().try_into().unwrap(),
 				date_of_birth: 0,
-				email: "Email".as_bytes().to_vec(),
-				phone_number: "+6893026516".as_bytes().to_vec(),
-				specialization: "DeBio Genetic Analyst".as_bytes().to_vec(),
-				profile_link: "DeBio Genetic Analyst profile_link".as_bytes().to_vec(),
-				profile_image: Some("DeBio Genetic Analyst profile_image".as_bytes().to_vec()),
+				email: "Email".as_bytes().to_vec().try_into().unwrap(),
+				phone_number: "+6893026516".as_bytes().to_vec().try_into().unwrap(),
+				specialization: "DeBio Genetic Analyst".as_bytes().to_vec().try_into().unwrap(),
+				profile_link: "DeBio Genetic Analyst profile_link"
+					.as_bytes()
+					.to_vec()
+					.try_into()
+					.unwrap(),
+				profile_image: Some(
+					"DeBio Genetic Analyst profile_image".as_bytes().to_vec().try_into().unwrap()
+				),
 			}
 		));
 
@@ -1216,13 +1381,19 @@ fn cant_cancel_genetic_analysis_order_when_unathorized_user() {
 		assert_ok!(GeneticAnalystServices::create_genetic_analyst_service(
 			RuntimeOrigin::signed(1),
 			GeneticAnalystServiceInfo {
-				name: "DeBio Genetic Analyst Service name".as_bytes().to_vec(),
-				prices_by_currency: vec![PriceByCurrency::default()],
+				name: "DeBio Genetic Analyst Service name".as_bytes().to_vec().try_into().unwrap(),
+				prices_by_currency: vec![PriceByCurrency::default()].try_into().unwrap(),
 				expected_duration: ExpectedDuration::default(),
-				description: "DeBio Genetic Analyst Service description".as_bytes().to_vec(),
+				description: "DeBio Genetic Analyst Service description"
+					.as_bytes()
+					.to_vec()
+					.try_into()
+					.unwrap(),
 				test_result_sample: "DeBio Genetic Analyst Service test_result_sample"
 					.as_bytes()
-					.to_vec(),
+					.to_vec()
+					.try_into()
+					.unwrap(),
 			},
 		));
 
@@ -1249,7 +1420,7 @@ fn cant_cancel_genetic_analysis_order_when_unathorized_user() {
 
 		let _genetic_analysis_order_id =
 			GeneticAnalysisOrders::last_genetic_analysis_order_by_customer_id(1).unwrap();
-		let _genetic_analysis = GeneticAnalysis::genetic_analysis_by_genetic_analyst_id(1).unwrap();
+		let _genetic_analysis = GeneticAnalysis::genetic_analysis_by_genetic_analyst_id(&1);
 
 		assert_noop!(
 			GeneticAnalysisOrders::cancel_genetic_analysis_order(
@@ -1279,15 +1450,21 @@ fn cant_set_genetic_analysis_order_paid_when_unauthorized() {
 				box_public_key: Keccak256::hash(
 					"0xDb9Af2d1f3ADD2726A132AA7A65Cc9E6fC5761C3".as_bytes(),
 				),
-				first_name: "First Name".as_bytes().to_vec(),
-				last_name: "Last Name".as_bytes().to_vec(),
-				gender: "Gender".as_bytes().to_vec(),
+				first_name: "First Name".as_bytes().to_vec().try_into().unwrap(),
+				last_name: "Last Name".as_bytes().to_vec().try_into().unwrap(),
+				gender: "Gender".as_bytes().to_vec().try_into().unwrap(),
 				date_of_birth: 0,
-				email: "Email".as_bytes().to_vec(),
-				phone_number: "+6893026516".as_bytes().to_vec(),
-				specialization: "DeBio Genetic Analyst".as_bytes().to_vec(),
-				profile_link: "DeBio Genetic Analyst profile_link".as_bytes().to_vec(),
-				profile_image: Some("DeBio Genetic Analyst profile_image".as_bytes().to_vec()),
+				email: "Email".as_bytes().to_vec().try_into().unwrap(),
+				phone_number: "+6893026516".as_bytes().to_vec().try_into().unwrap(),
+				specialization: "DeBio Genetic Analyst".as_bytes().to_vec().try_into().unwrap(),
+				profile_link: "DeBio Genetic Analyst profile_link"
+					.as_bytes()
+					.to_vec()
+					.try_into()
+					.unwrap(),
+				profile_image: Some(
+					"DeBio Genetic Analyst profile_image".as_bytes().to_vec().try_into().unwrap()
+				),
 			}
 		));
 
@@ -1297,25 +1474,33 @@ fn cant_set_genetic_analysis_order_paid_when_unauthorized() {
 		));
 
 		let _price = Price {
-			component: "Price Component".as_bytes().to_vec(),
+			component: "Price Component".as_bytes().to_vec().try_into().unwrap(),
 			value: 5000000000000000000u128.saturated_into(),
 		};
 
 		assert_ok!(GeneticAnalystServices::create_genetic_analyst_service(
 			RuntimeOrigin::signed(1),
 			GeneticAnalystServiceInfo {
-				name: "DeBio Genetic Analyst Service name".as_bytes().to_vec(),
+				name: "DeBio Genetic Analyst Service name".as_bytes().to_vec().try_into().unwrap(),
 				prices_by_currency: vec![PriceByCurrency {
 					currency: CurrencyType::default(),
 					total_price: 10000000000000000000u128.saturated_into(),
-					price_components: vec![_price.clone()],
-					additional_prices: vec![_price],
-				}],
+					price_components: vec![_price.clone()].try_into().unwrap(),
+					additional_prices: vec![_price].try_into().unwrap(),
+				}]
+				.try_into()
+				.unwrap(),
 				expected_duration: ExpectedDuration::default(),
-				description: "DeBio Genetic Analyst Service description".as_bytes().to_vec(),
+				description: "DeBio Genetic Analyst Service description"
+					.as_bytes()
+					.to_vec()
+					.try_into()
+					.unwrap(),
 				test_result_sample: "DeBio Genetic Analyst Service test_result_sample"
 					.as_bytes()
-					.to_vec(),
+					.to_vec()
+					.try_into()
+					.unwrap(),
 			},
 		));
 
@@ -1387,15 +1572,21 @@ fn cant_set_genetic_analysis_order_paid_when_insufficient_funds() {
 				box_public_key: Keccak256::hash(
 					"0xDb9Af2d1f3ADD2726A132AA7A65Cc9E6fC5761C3".as_bytes(),
 				),
-				first_name: "First Name".as_bytes().to_vec(),
-				last_name: "Last Name".as_bytes().to_vec(),
-				gender: "Gender".as_bytes().to_vec(),
+				first_name: "First Name".as_bytes().to_vec().try_into().unwrap(),
+				last_name: "Last Name".as_bytes().to_vec().try_into().unwrap(),
+				gender: "Gender".as_bytes().to_vec().try_into().unwrap(),
 				date_of_birth: 0,
-				email: "Email".as_bytes().to_vec(),
-				phone_number: "+6893026516".as_bytes().to_vec(),
-				specialization: "DeBio Genetic Analyst".as_bytes().to_vec(),
-				profile_link: "DeBio Genetic Analyst profile_link".as_bytes().to_vec(),
-				profile_image: Some("DeBio Genetic Analyst profile_image".as_bytes().to_vec()),
+				email: "Email".as_bytes().to_vec().try_into().unwrap(),
+				phone_number: "+6893026516".as_bytes().to_vec().try_into().unwrap(),
+				specialization: "DeBio Genetic Analyst".as_bytes().to_vec().try_into().unwrap(),
+				profile_link: "DeBio Genetic Analyst profile_link"
+					.as_bytes()
+					.to_vec()
+					.try_into()
+					.unwrap(),
+				profile_image: Some(
+					"DeBio Genetic Analyst profile_image".as_bytes().to_vec().try_into().unwrap()
+				),
 			}
 		));
 
@@ -1405,27 +1596,33 @@ fn cant_set_genetic_analysis_order_paid_when_insufficient_funds() {
 		));
 
 		let _price = Price {
-			component: "Price Component".as_bytes().to_vec(),
+			component: "Price Component".as_bytes().to_vec().try_into().unwrap(),
 			value: 5000000000000000000u128.saturated_into(),
 		};
 
 		let _price_by_currency = PriceByCurrency {
 			currency: CurrencyType::default(),
 			total_price: 10000000000000000000u128.saturated_into(),
-			price_components: vec![_price.clone()],
-			additional_prices: vec![_price],
+			price_components: vec![_price.clone()].try_into().unwrap(),
+			additional_prices: vec![_price].try_into().unwrap(),
 		};
 
 		assert_ok!(GeneticAnalystServices::create_genetic_analyst_service(
 			RuntimeOrigin::signed(1),
 			GeneticAnalystServiceInfo {
-				name: "DeBio Genetic Analyst Service name".as_bytes().to_vec(),
-				prices_by_currency: vec![_price_by_currency],
+				name: "DeBio Genetic Analyst Service name".as_bytes().to_vec().try_into().unwrap(),
+				prices_by_currency: vec![_price_by_currency].try_into().unwrap(),
 				expected_duration: ExpectedDuration::default(),
-				description: "DeBio Genetic Analyst Service description".as_bytes().to_vec(),
+				description: "DeBio Genetic Analyst Service description"
+					.as_bytes()
+					.to_vec()
+					.try_into()
+					.unwrap(),
 				test_result_sample: "DeBio Genetic Analyst Service test_result_sample"
 					.as_bytes()
-					.to_vec(),
+					.to_vec()
+					.try_into()
+					.unwrap(),
 			},
 		));
 
@@ -1507,15 +1704,21 @@ fn cant_fulfill_genetic_analysis_order_when_genetic_analysis_not_process() {
 				box_public_key: Keccak256::hash(
 					"0xDb9Af2d1f3ADD2726A132AA7A65Cc9E6fC5761C3".as_bytes(),
 				),
-				first_name: "First Name".as_bytes().to_vec(),
-				last_name: "Last Name".as_bytes().to_vec(),
-				gender: "Gender".as_bytes().to_vec(),
+				first_name: "First Name".as_bytes().to_vec().try_into().unwrap(),
+				last_name: "Last Name".as_bytes().to_vec().try_into().unwrap(),
+				gender: "Gender".as_bytes().to_vec().try_into().unwrap(),
 				date_of_birth: 0,
-				email: "Email".as_bytes().to_vec(),
-				phone_number: "+6893026516".as_bytes().to_vec(),
-				specialization: "DeBio Genetic Analyst".as_bytes().to_vec(),
-				profile_link: "DeBio Genetic Analyst profile_link".as_bytes().to_vec(),
-				profile_image: Some("DeBio Genetic Analyst profile_image".as_bytes().to_vec()),
+				email: "Email".as_bytes().to_vec().try_into().unwrap(),
+				phone_number: "+6893026516".as_bytes().to_vec().try_into().unwrap(),
+				specialization: "DeBio Genetic Analyst".as_bytes().to_vec().try_into().unwrap(),
+				profile_link: "DeBio Genetic Analyst profile_link"
+					.as_bytes()
+					.to_vec()
+					.try_into()
+					.unwrap(),
+				profile_image: Some(
+					"DeBio Genetic Analyst profile_image".as_bytes().to_vec().try_into().unwrap()
+				),
 			}
 		));
 
@@ -1527,13 +1730,19 @@ fn cant_fulfill_genetic_analysis_order_when_genetic_analysis_not_process() {
 		assert_ok!(GeneticAnalystServices::create_genetic_analyst_service(
 			RuntimeOrigin::signed(1),
 			GeneticAnalystServiceInfo {
-				name: "DeBio Genetic Analyst Service name".as_bytes().to_vec(),
-				prices_by_currency: vec![PriceByCurrency::default()],
+				name: "DeBio Genetic Analyst Service name".as_bytes().to_vec().try_into().unwrap(),
+				prices_by_currency: vec![PriceByCurrency::default()].try_into().unwrap(),
 				expected_duration: ExpectedDuration::default(),
-				description: "DeBio Genetic Analyst Service description".as_bytes().to_vec(),
+				description: "DeBio Genetic Analyst Service description"
+					.as_bytes()
+					.to_vec()
+					.try_into()
+					.unwrap(),
 				test_result_sample: "DeBio Genetic Analyst Service test_result_sample"
 					.as_bytes()
-					.to_vec(),
+					.to_vec()
+					.try_into()
+					.unwrap(),
 			},
 		));
 
@@ -1617,15 +1826,21 @@ fn cant_set_genetic_analysis_order_refunded_when_not_expired() {
 				box_public_key: Keccak256::hash(
 					"0xDb9Af2d1f3ADD2726A132AA7A65Cc9E6fC5761C3".as_bytes(),
 				),
-				first_name: "First Name".as_bytes().to_vec(),
-				last_name: "Last Name".as_bytes().to_vec(),
-				gender: "Gender".as_bytes().to_vec(),
+				first_name: "First Name".as_bytes().to_vec().try_into().unwrap(),
+				last_name: "Last Name".as_bytes().to_vec().try_into().unwrap(),
+				gender: "Gender".as_bytes().to_vec().try_into().unwrap(),
 				date_of_birth: 0,
-				email: "Email".as_bytes().to_vec(),
-				phone_number: "+6893026516".as_bytes().to_vec(),
-				specialization: "DeBio Genetic Analyst".as_bytes().to_vec(),
-				profile_link: "DeBio Genetic Analyst profile_link".as_bytes().to_vec(),
-				profile_image: Some("DeBio Genetic Analyst profile_image".as_bytes().to_vec()),
+				email: "Email".as_bytes().to_vec().try_into().unwrap(),
+				phone_number: "+6893026516".as_bytes().to_vec().try_into().unwrap(),
+				specialization: "DeBio Genetic Analyst".as_bytes().to_vec().try_into().unwrap(),
+				profile_link: "DeBio Genetic Analyst profile_link"
+					.as_bytes()
+					.to_vec()
+					.try_into()
+					.unwrap(),
+				profile_image: Some(
+					"DeBio Genetic Analyst profile_image".as_bytes().to_vec().try_into().unwrap()
+				),
 			}
 		));
 
@@ -1637,13 +1852,19 @@ fn cant_set_genetic_analysis_order_refunded_when_not_expired() {
 		assert_ok!(GeneticAnalystServices::create_genetic_analyst_service(
 			RuntimeOrigin::signed(1),
 			GeneticAnalystServiceInfo {
-				name: "DeBio Genetic Analyst Service name".as_bytes().to_vec(),
-				prices_by_currency: vec![PriceByCurrency::default()],
+				name: "DeBio Genetic Analyst Service name".as_bytes().to_vec().try_into().unwrap(),
+				prices_by_currency: vec![PriceByCurrency::default()].try_into().unwrap(),
 				expected_duration: ExpectedDuration::default(),
-				description: "DeBio Genetic Analyst Service description".as_bytes().to_vec(),
+				description: "DeBio Genetic Analyst Service description"
+					.as_bytes()
+					.to_vec()
+					.try_into()
+					.unwrap(),
 				test_result_sample: "DeBio Genetic Analyst Service test_result_sample"
 					.as_bytes()
-					.to_vec(),
+					.to_vec()
+					.try_into()
+					.unwrap(),
 			},
 		));
 
@@ -1703,15 +1924,21 @@ fn call_event_should_work() {
 				box_public_key: Keccak256::hash(
 					"0xDb9Af2d1f3ADD2726A132AA7A65Cc9E6fC5761C3".as_bytes(),
 				),
-				first_name: "First Name".as_bytes().to_vec(),
-				last_name: "Last Name".as_bytes().to_vec(),
-				gender: "Gender".as_bytes().to_vec(),
+				first_name: "First Name".as_bytes().to_vec().try_into().unwrap(),
+				last_name: "Last Name".as_bytes().to_vec().try_into().unwrap(),
+				gender: "Gender".as_bytes().to_vec().try_into().unwrap(),
 				date_of_birth: 0,
-				email: "Email".as_bytes().to_vec(),
-				phone_number: "+6893026516".as_bytes().to_vec(),
-				specialization: "DeBio Genetic Analyst".as_bytes().to_vec(),
-				profile_link: "DeBio Genetic Analyst profile_link".as_bytes().to_vec(),
-				profile_image: Some("DeBio Genetic Analyst profile_image".as_bytes().to_vec()),
+				email: "Email".as_bytes().to_vec().try_into().unwrap(),
+				phone_number: "+6893026516".as_bytes().to_vec().try_into().unwrap(),
+				specialization: "DeBio Genetic Analyst".as_bytes().to_vec().try_into().unwrap(),
+				profile_link: "DeBio Genetic Analyst profile_link"
+					.as_bytes()
+					.to_vec()
+					.try_into()
+					.unwrap(),
+				profile_image: Some(
+					"DeBio Genetic Analyst profile_image".as_bytes().to_vec().try_into().unwrap()
+				),
 			}
 		));
 
@@ -1723,13 +1950,19 @@ fn call_event_should_work() {
 		assert_ok!(GeneticAnalystServices::create_genetic_analyst_service(
 			RuntimeOrigin::signed(1),
 			GeneticAnalystServiceInfo {
-				name: "DeBio Genetic Analyst Service name".as_bytes().to_vec(),
-				prices_by_currency: vec![PriceByCurrency::default()],
+				name: "DeBio Genetic Analyst Service name".as_bytes().to_vec().try_into().unwrap(),
+				prices_by_currency: vec![PriceByCurrency::default()].try_into().unwrap(),
 				expected_duration: ExpectedDuration::default(),
-				description: "DeBio Genetic Analyst Service description".as_bytes().to_vec(),
+				description: "DeBio Genetic Analyst Service description"
+					.as_bytes()
+					.to_vec()
+					.try_into()
+					.unwrap(),
 				test_result_sample: "DeBio Genetic Analyst Service test_result_sample"
 					.as_bytes()
-					.to_vec(),
+					.to_vec()
+					.try_into()
+					.unwrap(),
 			},
 		));
 
@@ -1756,7 +1989,7 @@ fn call_event_should_work() {
 
 		let _genetic_analysis_order_id =
 			GeneticAnalysisOrders::last_genetic_analysis_order_by_customer_id(1).unwrap();
-		let _genetic_analysis = GeneticAnalysis::genetic_analysis_by_genetic_analyst_id(1).unwrap();
+		let _genetic_analysis = GeneticAnalysis::genetic_analysis_by_genetic_analyst_id(&1);
 
 		System::assert_last_event(RuntimeEvent::GeneticAnalysisOrders(
 			crate::Event::GeneticAnalysisOrderCreated(GeneticAnalysisOrder {
@@ -1769,7 +2002,7 @@ fn call_event_should_work() {
 				),
 				seller_id: 1,
 				genetic_analysis_tracking_id: _genetic_analysis[0].clone(),
-				genetic_link: "DeBio Genetic Genetic Link".as_bytes().to_vec(),
+				genetic_link: "DeBio Genetic Genetic Link".as_bytes().to_vec().try_into().unwrap(),
 				asset_id: None,
 				currency: CurrencyType::default(),
 				prices: PriceByCurrency::default().price_components,
@@ -1797,7 +2030,7 @@ fn call_event_should_work() {
 				),
 				seller_id: 1,
 				genetic_analysis_tracking_id: _genetic_analysis[0].clone(),
-				genetic_link: "DeBio Genetic Genetic Link".as_bytes().to_vec(),
+				genetic_link: "DeBio Genetic Genetic Link".as_bytes().to_vec().try_into().unwrap(),
 				asset_id: None,
 				currency: CurrencyType::default(),
 				prices: PriceByCurrency::default().price_components,
@@ -1830,7 +2063,7 @@ fn call_event_should_work() {
 
 		let _genetic_analysis_order_id =
 			GeneticAnalysisOrders::last_genetic_analysis_order_by_customer_id(1).unwrap();
-		let _genetic_analysis = GeneticAnalysis::genetic_analysis_by_genetic_analyst_id(1).unwrap();
+		let _genetic_analysis = GeneticAnalysis::genetic_analysis_by_genetic_analyst_id(&1);
 
 		EscrowKey::<Test>::put(3);
 
@@ -1850,7 +2083,7 @@ fn call_event_should_work() {
 				),
 				seller_id: 1,
 				genetic_analysis_tracking_id: _genetic_analysis[0].clone(),
-				genetic_link: "DeBio Genetic Genetic Link".as_bytes().to_vec(),
+				genetic_link: "DeBio Genetic Genetic Link".as_bytes().to_vec().try_into().unwrap(),
 				asset_id: None,
 				currency: CurrencyType::default(),
 				prices: PriceByCurrency::default().price_components,
@@ -1881,30 +2114,185 @@ fn call_event_should_work() {
 		));
 
 		System::assert_last_event(RuntimeEvent::GeneticAnalysisOrders(
-			crate::Event::GeneticAnalysisOrderFulfilled(GeneticAnalysisOrder {
-				id: _genetic_analysis_order_id,
-				genetic_data_id: _genetic_data_ids[0],
-				service_id: _genetic_analyst.services[0],
-				customer_id: 1,
-				customer_box_public_key: Keccak256::hash(
-					"0xhJ7TRe456FADD2726A132ABJK5RCc9E6fC5869F4".as_bytes(),
-				),
-				seller_id: 1,
-				genetic_analysis_tracking_id: _genetic_analysis[0].clone(),
-				genetic_link: "DeBio Genetic Genetic Link".as_bytes().to_vec(),
-				asset_id: None,
-				currency: CurrencyType::default(),
-				prices: PriceByCurrency::default().price_components,
-				additional_prices: PriceByCurrency::default().additional_prices,
-				total_price: PriceByCurrency::default().total_price,
-				status: GeneticAnalysisOrderStatus::Fulfilled,
-				created_at: 0,
-				updated_at: 0,
-			}),
+			crate::Event::GeneticAnalysisOrderFulfilled(
+				GeneticAnalysisOrder {
+					id: _genetic_analysis_order_id,
+					genetic_data_id: _genetic_data_ids[0],
+					service_id: _genetic_analyst.services[0],
+					customer_id: 1,
+					customer_box_public_key: Keccak256::hash(
+						"0xhJ7TRe456FADD2726A132ABJK5RCc9E6fC5869F4".as_bytes(),
+					),
+					seller_id: 1,
+					genetic_analysis_tracking_id: _genetic_analysis[0].clone(),
+					genetic_link: "DeBio Genetic Genetic Link"
+						.as_bytes()
+						.to_vec()
+						.try_into()
+						.unwrap(),
+					asset_id: None,
+					currency: CurrencyType::default(),
+					prices: PriceByCurrency::default().price_components,
+					additional_prices: PriceByCurrency::default().additional_prices,
+					total_price: PriceByCurrency::default().total_price,
+					status: GeneticAnalysisOrderStatus::Fulfilled,
+					created_at: 0,
+					updated_at: 0,
+				},
+				FeeSplit { seller_amount: 0, treasury_amount: 0, beneficiary_amounts: vec![] },
+			),
 		));
 	});
 }
 
+#[test]
+fn fulfill_genetic_analysis_order_pays_platform_fee_schedule() {
+	<ExternalityBuilder>::default().existential_deposit(1).build().execute_with(|| {
+		System::set_block_number(1);
+
+		assert_ok!(Balances::set_balance(RawOrigin::Root.into(), 1, 10000, 0));
+		assert_ok!(Balances::set_balance(RawOrigin::Root.into(), 0, 1, 0));
+
+		PalletAccount::<Test>::put(0);
+		EscrowKey::<Test>::put(1);
+		TreasuryKey::<Test>::put(2);
+		PlatformFeeSchedule::set(FeeSchedule {
+			default_rate: FeeRate { percentage: Perbill::from_percent(10), flat_fee: None },
+			currency_rates: Default::default(),
+			beneficiaries: vec![FeeBeneficiary { account_id: 3, share: Perbill::from_percent(20) }]
+				.try_into()
+				.unwrap(),
+		});
+
+		assert_ok!(GeneticAnalysts::register_genetic_analyst(
+			RuntimeOrigin::signed(1),
+			GeneticAnalystInfo {
+				box_public_key: Keccak256::hash(
+					"0xDb9Af2d1f3ADD2726A132AA7A65Cc9E6fC5761C3".as_bytes(),
+				),
+				first_name: "First Name".as_bytes().to_vec().try_into().unwrap(),
+				last_name: "Last Name".as_bytes().to_vec().try_into().unwrap(),
+				gender: "Gender".as_bytes().to_vec().try_into().unwrap(),
+				date_of_birth: 0,
+				email: "Email".as_bytes().to_vec().try_into().unwrap(),
+				phone_number: "+6893026516".as_bytes().to_vec().try_into().unwrap(),
+				specialization: "DeBio Genetic Analyst".as_bytes().to_vec().try_into().unwrap(),
+				profile_link: "DeBio Genetic Analyst profile_link"
+					.as_bytes()
+					.to_vec()
+					.try_into()
+					.unwrap(),
+				profile_image: Some(
+					"DeBio Genetic Analyst profile_image".as_bytes().to_vec().try_into().unwrap()
+				),
+			}
+		));
+
+		assert_ok!(GeneticAnalysts::update_genetic_analyst_availability_status(
+			RuntimeOrigin::signed(1),
+			AvailabilityStatus::Available
+		));
+
+		let _price = Price {
+			component: "Price Component".as_bytes().to_vec().try_into().unwrap(),
+			value: 500u128.saturated_into(),
+		};
+
+		let _price_by_currency = PriceByCurrency {
+			currency: CurrencyType::default(),
+			total_price: 1000u128.saturated_into(),
+			price_components: vec![_price.clone()].try_into().unwrap(),
+			additional_prices: vec![_price.clone()].try_into().unwrap(),
+		};
+
+		assert_ok!(GeneticAnalystServices::create_genetic_analyst_service(
+			RuntimeOrigin::signed(1),
+			GeneticAnalystServiceInfo {
+				name: "DeBio Genetic Analyst Service name".as_bytes().to_vec().try_into().unwrap(),
+				prices_by_currency: vec![_price_by_currency].try_into().unwrap(),
+				expected_duration: ExpectedDuration::default(),
+				description: "DeBio Genetic Analyst Service description"
+					.as_bytes()
+					.to_vec()
+					.try_into()
+					.unwrap(),
+				test_result_sample: "DeBio Genetic Analyst Service test_result_sample"
+					.as_bytes()
+					.to_vec()
+					.try_into()
+					.unwrap(),
+			},
+		));
+
+		let _genetic_analyst = GeneticAnalysts::genetic_analyst_by_account_id(1).unwrap();
+
+		let _add_genetic_data = GeneticData::add_genetic_data(
+			RuntimeOrigin::signed(1),
+			"DeBio Genetic Data".as_bytes().to_vec(),
+			"DeBio Genetic Data Document Description".as_bytes().to_vec(),
+			"DeBio Genetic Data Link".as_bytes().to_vec(),
+		);
+
+		let _genetic_data_ids = GeneticData::genetic_data_by_owner_id(1).unwrap();
+
+		assert_ok!(GeneticAnalysisOrders::create_genetic_analysis_order(
+			RuntimeOrigin::signed(1),
+			_genetic_data_ids[0],
+			_genetic_analyst.services[0],
+			0,
+			Keccak256::hash("0xhJ7TRe456FADD2726A132ABJK5RCc9E6fC5869F4".as_bytes()),
+			"DeBio Genetic Genetic Link".as_bytes().to_vec(),
+			None,
+		));
+
+		let _genetic_analysis_order_id =
+			GeneticAnalysisOrders::last_genetic_analysis_order_by_customer_id(1).unwrap();
+
+		assert_ok!(GeneticAnalysisOrders::set_genetic_analysis_order_paid(
+			RuntimeOrigin::signed(1),
+			_genetic_analysis_order_id
+		));
+
+		let _genetic_analysis = GeneticAnalysis::genetic_analysis_by_genetic_analyst_id(&1);
+
+		assert_ok!(GeneticAnalysis::submit_genetic_analysis(
+			RuntimeOrigin::signed(1),
+			_genetic_analysis[0].clone(),
+			"report_link".as_bytes().to_vec(),
+			Some("comment".as_bytes().to_vec()),
+		));
+
+		assert_ok!(GeneticAnalysis::process_genetic_analysis(
+			RuntimeOrigin::signed(1),
+			_genetic_analysis[0].clone(),
+			GeneticAnalysisStatus::ResultReady,
+		));
+
+		assert_eq!(Balances::free_balance(1), 9000);
+
+		assert_ok!(GeneticAnalysisOrders::fulfill_genetic_analysis_order(
+			RuntimeOrigin::signed(1),
+			_genetic_analysis_order_id
+		));
+
+		assert_eq!(Balances::free_balance(1), 9900);
+		assert_eq!(Balances::free_balance(2), 80);
+		assert_eq!(Balances::free_balance(3), 20);
+
+		System::assert_has_event(RuntimeEvent::GeneticAnalysisOrders(
+			crate::Event::GeneticAnalysisOrderFulfilled(
+				GeneticAnalysisOrders::genetic_analysis_order_by_id(&_genetic_analysis_order_id)
+					.unwrap(),
+				FeeSplit {
+					seller_amount: 900,
+					treasury_amount: 80,
+					beneficiary_amounts: vec![(3, 20)],
+				},
+			),
+		));
+	})
+}
+
 #[test]
 fn update_escrow_key_works() {
 	<ExternalityBuilder>::default().existential_deposit(1).build().execute_with(|| {
@@ -1932,3 +2320,79 @@ fn sudo_update_treasury_key_works() {
 		assert_eq!(GeneticAnalysisOrders::treasury_key(), Some(1));
 	})
 }
+
+#[test]
+fn migrate_truncates_genetic_links_and_keeps_every_order_id() {
+	<ExternalityBuilder>::default().existential_deposit(1).build().execute_with(|| {
+		let order_ids: Vec<H256> = (0..2u8).map(|i| Keccak256::hash(&[i])).collect();
+		let tracking_id =
+			TrackingId::try_from("XXXXXXXXXXXXXXXXXXXX8".as_bytes().to_vec()).unwrap();
+		let legacy_order = |component: Vec<u8>, genetic_link: Vec<u8>| {
+			(
+				order_ids[0],
+				Keccak256::hash("service".as_bytes()),
+				1u64,
+				Keccak256::hash("box_public_key".as_bytes()),
+				2u64,
+				Keccak256::hash("genetic_data".as_bytes()),
+				&tracking_id,
+				None::<u32>,
+				CurrencyType::DBIO,
+				vec![(component, 10u128)],
+				Vec::<Price<u128>>::new(),
+				0u128,
+				GeneticAnalysisOrderStatus::Paid,
+				0u64,
+				0u64,
+				genetic_link,
+			)
+				.encode()
+		};
+
+		// An order created before price components and genetic links were bounded
+		unhashed::put_raw(
+			&crate::GeneticAnalysisOrders::<Test>::hashed_key_for(order_ids[0]),
+			&legacy_order(
+				vec![b'X'; MAX_PRICE_COMPONENT_LENGTH as usize + 1],
+				vec![b'X'; MAX_GENETIC_LINK_LENGTH as usize + 1],
+			),
+		);
+		put_storage_value(
+			b"GeneticAnalysisOrders",
+			b"GeneticAnalysisOrdersByCustomer",
+			&Blake2_128Concat::hash(&1u64.encode()),
+			order_ids.clone(),
+		);
+		put_storage_value(
+			b"GeneticAnalysisOrders",
+			b"PendingGeneticAnalysisOrdersBySeller",
+			&Blake2_128Concat::hash(&2u64.encode()),
+			vec![order_ids[0]],
+		);
+		StorageVersion::new(3).put::<GeneticAnalysisOrders>();
+
+		crate::migrations::migrate::<Test>();
+
+		let mut customer_order_ids =
+			GeneticAnalysisOrders::genetic_analysis_orders_by_customer_id(&1);
+		customer_order_ids.sort();
+		let mut expected_order_ids = order_ids.clone();
+		expected_order_ids.sort();
+
+		assert_eq!(
+			GeneticAnalysisOrders::genetic_analysis_order_by_id(order_ids[0])
+				.unwrap()
+				.encode(),
+			legacy_order(
+				vec![b'X'; MAX_PRICE_COMPONENT_LENGTH as usize],
+				vec![b'X'; MAX_GENETIC_LINK_LENGTH as usize],
+			)
+		);
+		assert_eq!(customer_order_ids, expected_order_ids);
+		assert_eq!(
+			GeneticAnalysisOrders::pending_genetic_analysis_orders_by_genetic_analyst_id(&2),
+			vec![order_ids[0]]
+		);
+		assert_eq!(StorageVersion::get::<GeneticAnalysisOrders>(), StorageVersion::new(4));
+	})
+}
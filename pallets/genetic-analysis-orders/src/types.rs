@@ -1,11 +1,16 @@
 use crate::*;
 use scale_info::TypeInfo;
+use traits_genetic_analysis_orders::GeneticAnalysisOrderInfo;
+
+/// Maximum length of the link to the genetic data a customer orders an analysis of
+pub const MAX_GENETIC_LINK_LENGTH: u32 = 512;
 
 // Asset ID and Balance types
 pub type AssetId = u32;
 pub type AssetBalance = u128;
+pub type GeneticLink = BoundedVec<u8, ConstU32<MAX_GENETIC_LINK_LENGTH>>;
 
-#[derive(Encode, Decode, Clone, RuntimeDebug, PartialEq, Eq, TypeInfo)]
+#[derive(Encode, Decode, Clone, RuntimeDebug, PartialEq, Eq, TypeInfo, MaxEncodedLen)]
 pub enum GeneticAnalysisOrderStatus {
 	Unpaid,
 	Paid,
@@ -20,7 +25,7 @@ impl Default for GeneticAnalysisOrderStatus {
 	}
 }
 
-#[derive(Encode, Decode, Clone, Default, RuntimeDebug, PartialEq, Eq, TypeInfo)]
+#[derive(Encode, Decode, Clone, Default, RuntimeDebug, PartialEq, Eq, TypeInfo, MaxEncodedLen)]
 pub struct GeneticAnalysisOrder<Hash, AccountId, Balance, Moment> {
 	pub id: Hash,
 	pub service_id: Hash,
@@ -31,13 +36,13 @@ pub struct GeneticAnalysisOrder<Hash, AccountId, Balance, Moment> {
 	pub genetic_analysis_tracking_id: TrackingId,
 	pub asset_id: Option<u32>,
 	pub currency: CurrencyType,
-	pub prices: Vec<Price<Balance>>,
-	pub additional_prices: Vec<Price<Balance>>,
+	pub prices: PriceComponents<Balance>,
+	pub additional_prices: PriceComponents<Balance>,
 	pub total_price: Balance,
 	pub status: GeneticAnalysisOrderStatus,
 	pub created_at: Moment,
 	pub updated_at: Moment,
-	pub genetic_link: Vec<u8>,
+	pub genetic_link: GeneticLink,
 }
 #[allow(clippy::too_many_arguments)]
 impl<Hash, AccountId, Balance, Moment: Default>
@@ -53,11 +58,11 @@ where
 		seller_id: AccountId,
 		genetic_data_id: Hash,
 		genetic_analysis_tracking_id: TrackingId,
-		genetic_link: Vec<u8>,
+		genetic_link: GeneticLink,
 		asset_id: Option<u32>,
 		currency: CurrencyType,
-		prices: Vec<Price<Balance>>,
-		additional_prices: Vec<Price<Balance>>,
+		prices: PriceComponents<Balance>,
+		additional_prices: PriceComponents<Balance>,
 		total_price: Balance,
 		created_at: Moment,
 	) -> Self {
@@ -136,6 +141,25 @@ where
 	}
 }
 
+impl<T, Hash, AccountId, Balance, Moment> GeneticAnalysisOrderInfo<T>
+	for GeneticAnalysisOrder<Hash, AccountId, Balance, Moment>
+where
+	T: frame_system::Config<AccountId = AccountId, Hash = Hash>,
+	AccountId: PartialEq,
+{
+	fn is_genetic_analysis_order_fulfilled(&self) -> bool {
+		self.status == GeneticAnalysisOrderStatus::Fulfilled
+	}
+
+	fn is_account_genetic_analysis_order(&self, account_id: &T::AccountId) -> bool {
+		&self.customer_id == account_id
+	}
+
+	fn seller_id(&self) -> &T::AccountId {
+		&self.seller_id
+	}
+}
+
 #[derive(Encode, Decode, Clone, RuntimeDebug, PartialEq, Eq, TypeInfo)]
 pub enum AccountKeyType<AccountId> {
 	TreasuryKey(AccountId),
@@ -15,3 +15,17 @@ pub trait GeneticAnalysisOrderStatusUpdater<T: Config> {
 	fn is_pending_genetic_analysis_order_by_seller_exist(seller_id: &T::AccountId) -> bool;
 	fn is_genetic_analysis_order_paid(order_id: &T::Hash) -> bool;
 }
+
+pub trait GeneticAnalysisOrderInfo<T: Config> {
+	fn is_genetic_analysis_order_fulfilled(&self) -> bool;
+	fn is_account_genetic_analysis_order(&self, account_id: &T::AccountId) -> bool;
+	fn seller_id(&self) -> &T::AccountId;
+}
+
+pub trait GeneticAnalysisOrderProvider<T: Config> {
+	type GeneticAnalysisOrder: GeneticAnalysisOrderInfo<T>;
+
+	fn get_genetic_analysis_order_by_id(
+		genetic_analysis_order_id: &T::Hash,
+	) -> Option<Self::GeneticAnalysisOrder>;
+}
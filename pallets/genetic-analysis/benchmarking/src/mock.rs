@@ -29,7 +29,7 @@ frame_support::construct_runtime!(
 		GeneticData: genetic_data,
 		GeneticAnalysts: genetic_analysts,
 		GeneticAnalystServices: genetic_analyst_services,
-		GeneticAnalystQualifications: genetic_analyst_qualifications,
+		GeneticAnalystQualifications: credentials::<Instance4>,
 		GeneticAnalysis: genetic_analysis,
 		GeneticAnalysisOrders: genetic_analysis_orders,
 		UserProfile: user_profile,
@@ -148,12 +148,14 @@ impl pallet_balances::Config for Test {
 impl genetic_data::Config for Test {
 	type RuntimeEvent = RuntimeEvent;
 	type GeneticDataWeightInfo = ();
+	type MaxGeneticDataPerOwner = frame_support::traits::ConstU32<1_000>;
 }
 
 impl genetic_analysts::Config for Test {
 	type RuntimeEvent = RuntimeEvent;
 	type Currency = Balances;
 	type PalletId = GeneticAnalystPalletId;
+	type RequireVerifiedQualification = frame_support::traits::ConstBool<false>;
 	type GeneticAnalysisOrders = GeneticAnalysisOrders;
 	type GeneticAnalystServices = GeneticAnalystServices;
 	type GeneticAnalystQualifications = GeneticAnalystQualifications;
@@ -170,9 +172,12 @@ impl genetic_analyst_services::Config for Test {
 	type WeightInfo = ();
 }
 
-impl genetic_analyst_qualifications::Config for Test {
+impl credentials::Config<credentials::Instance4> for Test {
 	type RuntimeEvent = RuntimeEvent;
-	type GeneticAnalystQualificationOwner = GeneticAnalysts;
+	type CredentialInfo = credentials::GeneticAnalystQualificationInfo;
+	type CredentialOwner =
+		credentials::legacy::LegacyGeneticAnalystQualificationOwner<GeneticAnalysts>;
+	type CredentialIssuers = ();
 	type WeightInfo = ();
 }
 
@@ -192,6 +197,7 @@ impl genetic_analysis_orders::Config for Test {
 	type GeneticAnalysis = GeneticAnalysis;
 	type GeneticAnalystServices = GeneticAnalystServices;
 	type GeneticAnalysisOrdersWeightInfo = ();
+	type PlatformFee = ();
 	type PalletId = GeneticAnalysisOrdersEscrowPalletId;
 }
 
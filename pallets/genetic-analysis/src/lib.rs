@@ -4,6 +4,7 @@ pub use pallet::*;
 pub use scale_info::TypeInfo;
 
 pub mod interface;
+pub mod migrations;
 pub mod weights;
 pub use frame_support::{
 	debug, dispatch::DispatchResultWithPostInfo, pallet_prelude::*, sp_runtime::traits::Hash,
@@ -19,7 +20,24 @@ pub use traits_genetic_analysis_orders::{
 };
 pub use weights::WeightInfo;
 
-#[derive(Encode, Decode, Clone, RuntimeDebug, PartialEq, Eq, TypeInfo)]
+/// The current storage version.
+const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
+
+/// Maximum length of the report link a genetic analyst submits
+pub const MAX_REPORT_LINK_LENGTH: u32 = 512;
+/// Maximum length of the comment a genetic analyst submits with a report
+pub const MAX_COMMENT_LENGTH: u32 = 1024;
+/// Maximum length of the title a genetic analyst gives when rejecting an analysis
+pub const MAX_REJECTION_TITLE_LENGTH: u32 = 256;
+/// Maximum length of the description a genetic analyst gives when rejecting an analysis
+pub const MAX_REJECTION_DESCRIPTION_LENGTH: u32 = 1024;
+
+pub type ReportLink = BoundedVec<u8, ConstU32<MAX_REPORT_LINK_LENGTH>>;
+pub type Comment = BoundedVec<u8, ConstU32<MAX_COMMENT_LENGTH>>;
+pub type RejectionTitle = BoundedVec<u8, ConstU32<MAX_REJECTION_TITLE_LENGTH>>;
+pub type RejectionDescription = BoundedVec<u8, ConstU32<MAX_REJECTION_DESCRIPTION_LENGTH>>;
+
+#[derive(Encode, Decode, Clone, RuntimeDebug, PartialEq, Eq, TypeInfo, MaxEncodedLen)]
 pub enum GeneticAnalysisStatus {
 	Registered,
 	InProgress,
@@ -32,15 +50,15 @@ impl Default for GeneticAnalysisStatus {
 	}
 }
 
-#[derive(Encode, Decode, Clone, Default, RuntimeDebug, PartialEq, Eq, TypeInfo)]
+#[derive(Encode, Decode, Clone, Default, RuntimeDebug, PartialEq, Eq, TypeInfo, MaxEncodedLen)]
 pub struct GeneticAnalysis<AccountId, Hash, Moment> {
 	pub genetic_analysis_tracking_id: TrackingId,
 	pub genetic_analyst_id: AccountId,
 	pub owner_id: AccountId,
-	pub report_link: Vec<u8>,
-	pub comment: Option<Vec<u8>>,
-	pub rejected_title: Option<Vec<u8>>,
-	pub rejected_description: Option<Vec<u8>>,
+	pub report_link: ReportLink,
+	pub comment: Option<Comment>,
+	pub rejected_title: Option<RejectionTitle>,
+	pub rejected_description: Option<RejectionDescription>,
 	pub genetic_analysis_order_id: Hash,
 	pub created_at: Moment,
 	pub updated_at: Moment,
@@ -59,7 +77,7 @@ impl<AccountId, Hash, Moment: Copy> GeneticAnalysis<AccountId, Hash, Moment> {
 			genetic_analysis_order_id,
 			genetic_analysis_tracking_id,
 			owner_id,
-			report_link: Vec::<u8>::new(),
+			report_link: ReportLink::default(),
 			comment: None,
 			rejected_title: None,
 			rejected_description: None,
@@ -83,7 +101,7 @@ impl<AccountId, Hash, Moment> GeneticAnalysisTracking for GeneticAnalysis<Accoun
 		self.status == GeneticAnalysisStatus::Rejected
 	}
 	fn is_empty(&self) -> bool {
-		self.report_link == Vec::<u8>::new() && self.comment.is_none()
+		self.report_link.is_empty() && self.comment.is_none()
 	}
 }
 
@@ -102,12 +120,16 @@ pub mod pallet {
 
 	// ----- This is template code, every pallet needs this ---
 	#[pallet::pallet]
+	#[pallet::storage_version(STORAGE_VERSION)]
 	#[pallet::generate_store(pub(super) trait Store)]
-	#[pallet::without_storage_info]
 	pub struct Pallet<T>(_);
 
 	#[pallet::hooks]
-	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {}
+	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+		fn on_runtime_upgrade() -> Weight {
+			migrations::migrate::<T>()
+		}
+	}
 	// --------------------------------------------------------
 
 	#[pallet::event]
@@ -127,12 +149,22 @@ pub mod pallet {
 	pub enum Error<T> {
 		GeneticAnalysisOrderNotFound,
 		GeneticAnalysisNotFound,
+		/// Tracking id check character does not match, most likely a typo
+		InvalidTrackingId,
 		GeneticAnalysisNotYetSubmitted,
 		UnpaidGeneticAnalysisOrder,
 		Unauthorized,
 		TrackingIdCollision,
 		ResultLinkRequired,
 		ReportLinkRequired,
+		/// Rejected title exceeds MAX_REJECTION_TITLE_LENGTH
+		RejectedTitleTooLong,
+		/// Rejected description exceeds MAX_REJECTION_DESCRIPTION_LENGTH
+		RejectedDescriptionTooLong,
+		/// Report link exceeds MAX_REPORT_LINK_LENGTH
+		ReportLinkTooLong,
+		/// Comment exceeds MAX_COMMENT_LENGTH
+		CommentTooLong,
 	}
 
 	pub type HashOf<T> = <T as frame_system::Config>::Hash;
@@ -147,15 +179,17 @@ pub mod pallet {
 	pub type GeneticAnalysisStorage<T> =
 		StorageMap<_, Blake2_128Concat, TrackingId, GeneticAnalysisOf<T>>;
 
+	/// Get genetic analysis tracking ids by owner id
+	/// (AccountId, TrackingId) => ()
 	#[pallet::storage]
-	#[pallet::getter(fn genetic_analysis_by_owner_id)]
 	pub type GeneticAnalysisByOwner<T> =
-		StorageMap<_, Blake2_128Concat, AccountIdOf<T>, Vec<TrackingId>>;
+		StorageDoubleMap<_, Blake2_128Concat, AccountIdOf<T>, Blake2_128Concat, TrackingId, ()>;
 
+	/// Get genetic analysis tracking ids by genetic analyst id
+	/// (AccountId, TrackingId) => ()
 	#[pallet::storage]
-	#[pallet::getter(fn genetic_analysis_by_genetic_analyst_id)]
 	pub type GeneticAnalysisByGeneticAnalyst<T> =
-		StorageMap<_, Blake2_128Concat, AccountIdOf<T>, Vec<TrackingId>>;
+		StorageDoubleMap<_, Blake2_128Concat, AccountIdOf<T>, Blake2_128Concat, TrackingId, ()>;
 	// --------------------------
 
 	#[pallet::call]
@@ -281,10 +315,16 @@ impl<T: Config> GeneticAnalysisInterface<T> for Pallet<T> {
 	) -> Result<Self::GeneticAnalysis, Self::Error> {
 		let genetic_analysis = GeneticAnalysisStorage::<T>::take(tracking_id);
 		if genetic_analysis.is_none() {
-			return Err(Error::<T>::GeneticAnalysisNotFound)
+			return Err(Self::genetic_analysis_not_found(tracking_id))
 		}
 		let genetic_analysis = genetic_analysis.unwrap();
 
+		GeneticAnalysisByOwner::<T>::remove(&genetic_analysis.owner_id, tracking_id);
+		GeneticAnalysisByGeneticAnalyst::<T>::remove(
+			&genetic_analysis.genetic_analyst_id,
+			tracking_id,
+		);
+
 		Ok(genetic_analysis)
 	}
 
@@ -296,7 +336,7 @@ impl<T: Config> GeneticAnalysisInterface<T> for Pallet<T> {
 	) -> Result<Self::GeneticAnalysis, Self::Error> {
 		let genetic_analysis = GeneticAnalysisStorage::<T>::get(genetic_analysis_tracking_id);
 		if genetic_analysis.is_none() {
-			return Err(Error::<T>::GeneticAnalysisNotFound)
+			return Err(Self::genetic_analysis_not_found(genetic_analysis_tracking_id))
 		}
 		let mut genetic_analysis = genetic_analysis.unwrap();
 
@@ -310,9 +350,19 @@ impl<T: Config> GeneticAnalysisInterface<T> for Pallet<T> {
 			return Err(Error::<T>::UnpaidGeneticAnalysisOrder)
 		};
 
+		let rejected_title: RejectionTitle = rejected_title
+			.to_vec()
+			.try_into()
+			.map_err(|_| Error::<T>::RejectedTitleTooLong)?;
+		let rejected_description: RejectionDescription =
+			rejected_description
+				.to_vec()
+				.try_into()
+				.map_err(|_| Error::<T>::RejectedDescriptionTooLong)?;
+
 		let now = pallet_timestamp::Pallet::<T>::get();
-		genetic_analysis.rejected_title = Some(rejected_title.to_vec());
-		genetic_analysis.rejected_description = Some(rejected_description.to_vec());
+		genetic_analysis.rejected_title = Some(rejected_title);
+		genetic_analysis.rejected_description = Some(rejected_description);
 		genetic_analysis.status = GeneticAnalysisStatus::Rejected;
 		genetic_analysis.updated_at = now;
 
@@ -337,7 +387,7 @@ impl<T: Config> GeneticAnalysisInterface<T> for Pallet<T> {
 	) -> Result<Self::GeneticAnalysis, Self::Error> {
 		let genetic_analysis = GeneticAnalysisStorage::<T>::get(genetic_analysis_tracking_id);
 		if genetic_analysis.is_none() {
-			return Err(Error::<T>::GeneticAnalysisNotFound)
+			return Err(Self::genetic_analysis_not_found(genetic_analysis_tracking_id))
 		}
 		let mut genetic_analysis = genetic_analysis.unwrap();
 
@@ -381,7 +431,7 @@ impl<T: Config> GeneticAnalysisInterface<T> for Pallet<T> {
 	) -> Result<Self::GeneticAnalysis, Self::Error> {
 		let genetic_analysis = GeneticAnalysisStorage::<T>::get(genetic_analysis_tracking_id);
 		if genetic_analysis.is_none() {
-			return Err(Error::<T>::GeneticAnalysisNotFound)
+			return Err(Self::genetic_analysis_not_found(genetic_analysis_tracking_id))
 		}
 		let mut genetic_analysis = genetic_analysis.unwrap();
 
@@ -395,9 +445,17 @@ impl<T: Config> GeneticAnalysisInterface<T> for Pallet<T> {
 			return Err(Error::<T>::UnpaidGeneticAnalysisOrder)
 		};
 
+		let report_link: ReportLink =
+			report_link.to_vec().try_into().map_err(|_| Error::<T>::ReportLinkTooLong)?;
+		let comment = comment
+			.clone()
+			.map(Comment::try_from)
+			.transpose()
+			.map_err(|_| Error::<T>::CommentTooLong)?;
+
 		let now = pallet_timestamp::Pallet::<T>::get();
-		genetic_analysis.report_link = report_link.to_vec();
-		genetic_analysis.comment = comment.clone();
+		genetic_analysis.report_link = report_link;
+		genetic_analysis.comment = comment;
 		genetic_analysis.updated_at = now;
 
 		GeneticAnalysisStorage::<T>::insert(genetic_analysis_tracking_id, &genetic_analysis);
@@ -412,13 +470,13 @@ impl<T: Config> GeneticAnalysisInterface<T> for Pallet<T> {
 	}
 
 	fn genetic_analysis_by_owner_id(owner_id: &T::AccountId) -> Option<Vec<TrackingId>> {
-		Self::genetic_analysis_by_owner_id(owner_id)
+		Some(Self::genetic_analysis_by_owner_id(owner_id))
 	}
 
 	fn genetic_analysis_by_genetic_analyst_id(
 		genetic_analyst_id: &T::AccountId,
 	) -> Option<Vec<TrackingId>> {
-		Self::genetic_analysis_by_genetic_analyst_id(genetic_analyst_id)
+		Some(Self::genetic_analysis_by_genetic_analyst_id(genetic_analyst_id))
 	}
 }
 
@@ -454,6 +512,16 @@ impl<T: Config> GeneticAnalysisProvider<T> for Pallet<T> {
 }
 
 impl<T: Config> Pallet<T> {
+	/// Error for a tracking id without genetic analysis, telling typos apart from unknown ones.
+	/// Legacy tracking ids have no check character, but are found before this is reached.
+	pub fn genetic_analysis_not_found(tracking_id: &TrackingId) -> Error<T> {
+		if tracking_id.has_valid_check_character() {
+			Error::<T>::GeneticAnalysisNotFound
+		} else {
+			Error::<T>::InvalidTrackingId
+		}
+	}
+
 	pub fn generate_random_seed(creator_id: &T::AccountId, owner_id: &T::AccountId) -> Vec<u8> {
 		let creator_info = frame_system::Pallet::<T>::account(creator_id);
 		let creator_nonce = creator_info.nonce;
@@ -469,44 +537,28 @@ impl<T: Config> Pallet<T> {
 	}
 
 	pub fn add_genetic_analysis_by_owner(genetic_analysis: &GeneticAnalysisOf<T>) {
-		match GeneticAnalysisByOwner::<T>::get(&genetic_analysis.owner_id) {
-			None => {
-				let genetic_analysis_tracking_ids =
-					vec![genetic_analysis.genetic_analysis_tracking_id.clone()];
-				GeneticAnalysisByOwner::<T>::insert(
-					&genetic_analysis.owner_id,
-					genetic_analysis_tracking_ids,
-				);
-			},
-			Some(mut genetic_analysis_tracking_ids) => {
-				genetic_analysis_tracking_ids
-					.push(genetic_analysis.genetic_analysis_tracking_id.clone());
-				GeneticAnalysisByOwner::<T>::insert(
-					&genetic_analysis.owner_id,
-					genetic_analysis_tracking_ids,
-				);
-			},
-		}
+		GeneticAnalysisByOwner::<T>::insert(
+			&genetic_analysis.owner_id,
+			&genetic_analysis.genetic_analysis_tracking_id,
+			(),
+		);
 	}
 
 	pub fn add_genetic_analysis_by_genetic_analyst(genetic_analysis: &GeneticAnalysisOf<T>) {
-		match GeneticAnalysisByGeneticAnalyst::<T>::get(&genetic_analysis.owner_id) {
-			None => {
-				let genetic_analysis_tracking_ids =
-					vec![genetic_analysis.genetic_analysis_tracking_id.clone()];
-				GeneticAnalysisByGeneticAnalyst::<T>::insert(
-					&genetic_analysis.genetic_analyst_id,
-					genetic_analysis_tracking_ids,
-				);
-			},
-			Some(mut genetic_analysis_tracking_ids) => {
-				genetic_analysis_tracking_ids
-					.push(genetic_analysis.genetic_analysis_tracking_id.clone());
-				GeneticAnalysisByGeneticAnalyst::<T>::insert(
-					&genetic_analysis.genetic_analyst_id,
-					genetic_analysis_tracking_ids,
-				);
-			},
-		}
+		GeneticAnalysisByGeneticAnalyst::<T>::insert(
+			&genetic_analysis.genetic_analyst_id,
+			&genetic_analysis.genetic_analysis_tracking_id,
+			(),
+		);
+	}
+
+	pub fn genetic_analysis_by_owner_id(owner_id: &T::AccountId) -> Vec<TrackingId> {
+		GeneticAnalysisByOwner::<T>::iter_key_prefix(owner_id).collect()
+	}
+
+	pub fn genetic_analysis_by_genetic_analyst_id(
+		genetic_analyst_id: &T::AccountId,
+	) -> Vec<TrackingId> {
+		GeneticAnalysisByGeneticAnalyst::<T>::iter_key_prefix(genetic_analyst_id).collect()
 	}
 }
@@ -0,0 +1,120 @@
+use crate::{
+	AccountIdOf, Config, GeneticAnalysis, GeneticAnalysisOf, GeneticAnalysisStatus, HashOf,
+	MomentOf, Pallet,
+};
+use frame_support::{
+	codec::{Decode, Encode},
+	log, storage_alias,
+	traits::Get,
+	weights::Weight,
+	Blake2_128Concat, BoundedVec,
+};
+use primitives_tracking_id::TrackingId;
+use sp_std::vec::Vec;
+
+pub fn migrate<T: Config>() -> Weight {
+	use frame_support::traits::StorageVersion;
+
+	let mut weight: Weight = Weight::zero();
+	let mut version = StorageVersion::get::<Pallet<T>>();
+
+	if version < 1 {
+		weight = weight.saturating_add(version::v1::migrate::<T>());
+		version = StorageVersion::new(1);
+	}
+
+	version.put::<Pallet<T>>();
+	weight
+}
+
+mod version {
+	use super::*;
+
+	/// Truncates `value` to the bound and logs the entry when it did not fit.
+	fn bounded<I, S: Get<u32>>(value: Vec<I>, entry: &str) -> BoundedVec<I, S> {
+		if value.len() > S::get() as usize {
+			log::warn!(
+				target: "runtime::genetic-analysis",
+				"{} exceeds its bound of {}, truncated from {} items",
+				entry,
+				S::get(),
+				value.len(),
+			);
+		}
+
+		BoundedVec::truncate_from(value)
+	}
+
+	pub mod v1 {
+		use super::*;
+
+		#[storage_alias]
+		type GeneticAnalysisByOwner<T: Config> =
+			StorageMap<Pallet<T>, Blake2_128Concat, AccountIdOf<T>, Vec<TrackingId>>;
+
+		#[storage_alias]
+		type GeneticAnalysisByGeneticAnalyst<T: Config> =
+			StorageMap<Pallet<T>, Blake2_128Concat, AccountIdOf<T>, Vec<TrackingId>>;
+
+		#[derive(Encode, Decode)]
+		pub struct OldGeneticAnalysis<AccountId, Hash, Moment> {
+			genetic_analysis_tracking_id: TrackingId,
+			genetic_analyst_id: AccountId,
+			owner_id: AccountId,
+			report_link: Vec<u8>,
+			comment: Option<Vec<u8>>,
+			rejected_title: Option<Vec<u8>>,
+			rejected_description: Option<Vec<u8>>,
+			genetic_analysis_order_id: Hash,
+			created_at: Moment,
+			updated_at: Moment,
+			status: GeneticAnalysisStatus,
+		}
+
+		pub type OldGeneticAnalysisOf<T> =
+			OldGeneticAnalysis<AccountIdOf<T>, HashOf<T>, MomentOf<T>>;
+
+		/// Reports, comments and rejection reasons are truncated to their bounds. Tracking id
+		/// indexes move from one list per account to one key per tracking id and are rebuilt
+		/// from the stored analyses, the legacy analyst lists were keyed by owner.
+		pub fn migrate<T: Config>() -> Weight {
+			let mut weight = T::DbWeight::get().writes(1);
+
+			let legacy_owners = GeneticAnalysisByOwner::<T>::drain().count() as u64;
+			let legacy_analysts = GeneticAnalysisByGeneticAnalyst::<T>::drain().count() as u64;
+			weight = weight.saturating_add(
+				T::DbWeight::get()
+					.reads_writes(legacy_owners + legacy_analysts, legacy_owners + legacy_analysts),
+			);
+
+			crate::GeneticAnalysisStorage::<T>::translate(|_key, old: OldGeneticAnalysisOf<T>| {
+				weight = weight.saturating_add(T::DbWeight::get().reads_writes(1, 3));
+
+				let genetic_analysis: GeneticAnalysisOf<T> = GeneticAnalysis {
+					genetic_analysis_tracking_id: old.genetic_analysis_tracking_id,
+					genetic_analyst_id: old.genetic_analyst_id,
+					owner_id: old.owner_id,
+					report_link: bounded(old.report_link, "GeneticAnalysis report_link"),
+					comment: old.comment.map(|comment| bounded(comment, "GeneticAnalysis comment")),
+					rejected_title: old
+						.rejected_title
+						.map(|title| bounded(title, "GeneticAnalysis rejected_title")),
+					rejected_description: old.rejected_description.map(|description| {
+						bounded(description, "GeneticAnalysis rejected_description")
+					}),
+					genetic_analysis_order_id: old.genetic_analysis_order_id,
+					created_at: old.created_at,
+					updated_at: old.updated_at,
+					status: old.status,
+				};
+
+				Pallet::<T>::add_genetic_analysis_by_owner(&genetic_analysis);
+				Pallet::<T>::add_genetic_analysis_by_genetic_analyst(&genetic_analysis);
+
+				Some(genetic_analysis)
+			});
+
+			weight
+		}
+	}
+}
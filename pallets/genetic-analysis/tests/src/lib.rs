@@ -7,9 +7,17 @@ mod tests {
 	use frame_support::{
 		assert_noop, assert_ok,
 		sp_runtime::traits::{Hash, Keccak256},
+		storage::unhashed,
+		traits::StorageVersion,
+		Blake2_128Concat, StorageHasher, StoragePrefixedMap,
 	};
 
-	use genetic_analysis::{Error, GeneticAnalysisStatus};
+	use codec::Encode;
+	use genetic_analysis::{
+		Error, GeneticAnalysisByGeneticAnalyst, GeneticAnalysisByOwner, GeneticAnalysisStatus,
+		GeneticAnalysisStorage, MAX_COMMENT_LENGTH, MAX_REJECTION_DESCRIPTION_LENGTH,
+		MAX_REJECTION_TITLE_LENGTH, MAX_REPORT_LINK_LENGTH,
+	};
 	use genetic_analysis_orders::PalletAccount;
 	use genetic_analyst_services::GeneticAnalystServiceInfo;
 	use genetic_analysts::GeneticAnalystInfo;
@@ -32,15 +40,25 @@ mod tests {
 					box_public_key: Keccak256::hash(
 						"0xDb9Af2d1f3ADD2726A132AA7A65Cc9E6fC5761C3".as_bytes(),
 					),
-					first_name: "First Name".as_bytes().to_vec(),
-					last_name: "Last Name".as_bytes().to_vec(),
-					gender: "Gender".as_bytes().to_vec(),
+					first_name: "First Name".as_bytes().to_vec().try_into().unwrap(),
+					last_name: "Last Name".as_bytes().to_vec().try_into().unwrap(),
+					gender: "Gender".as_bytes().to_vec().try_into().unwrap(),
 					date_of_birth: 0,
-					email: "Email".as_bytes().to_vec(),
-					phone_number: "+6893026516".as_bytes().to_vec(),
-					specialization: "DeBio Genetic Analyst".as_bytes().to_vec(),
-					profile_link: "DeBio Genetic Analyst profile_link".as_bytes().to_vec(),
-					profile_image: Some("DeBio Genetic Analyst profile_image".as_bytes().to_vec()),
+					email: "Email".as_bytes().to_vec().try_into().unwrap(),
+					phone_number: "+6893026516".as_bytes().to_vec().try_into().unwrap(),
+					specialization: "DeBio Genetic Analyst".as_bytes().to_vec().try_into().unwrap(),
+					profile_link: "DeBio Genetic Analyst profile_link"
+						.as_bytes()
+						.to_vec()
+						.try_into()
+						.unwrap(),
+					profile_image: Some(
+						"DeBio Genetic Analyst profile_image"
+							.as_bytes()
+							.to_vec()
+							.try_into()
+							.unwrap()
+					),
 				}
 			));
 
@@ -57,13 +75,23 @@ mod tests {
 			assert_ok!(GeneticAnalystServices::create_genetic_analyst_service(
 				RuntimeOrigin::signed(1),
 				GeneticAnalystServiceInfo {
-					name: "DeBio Genetic Analyst Service name".as_bytes().to_vec(),
-					prices_by_currency: vec![PriceByCurrency::default()],
+					name: "DeBio Genetic Analyst Service name"
+						.as_bytes()
+						.to_vec()
+						.try_into()
+						.unwrap(),
+					prices_by_currency: vec![PriceByCurrency::default()].try_into().unwrap(),
 					expected_duration: ExpectedDuration::default(),
-					description: "DeBio Genetic Analyst Service description".as_bytes().to_vec(),
+					description: "DeBio Genetic Analyst Service description"
+						.as_bytes()
+						.to_vec()
+						.try_into()
+						.unwrap(),
 					test_result_sample: "DeBio Genetic Analyst Service test_result_sample"
 						.as_bytes()
-						.to_vec(),
+						.to_vec()
+						.try_into()
+						.unwrap(),
 				}
 			));
 
@@ -96,8 +124,27 @@ mod tests {
 				_genetic_analysis_order_id
 			));
 
-			let _genetic_analysis =
-				GeneticAnalysis::genetic_analysis_by_genetic_analyst_id(1).unwrap();
+			let _genetic_analysis = GeneticAnalysis::genetic_analysis_by_genetic_analyst_id(&1);
+
+			assert_noop!(
+				GeneticAnalysis::reject_genetic_analysis(
+					RuntimeOrigin::signed(1),
+					_genetic_analysis[0].clone(),
+					vec![0; MAX_REJECTION_TITLE_LENGTH as usize + 1],
+					"Reject DNA Description".as_bytes().to_vec()
+				),
+				Error::<Test>::RejectedTitleTooLong
+			);
+
+			assert_noop!(
+				GeneticAnalysis::reject_genetic_analysis(
+					RuntimeOrigin::signed(1),
+					_genetic_analysis[0].clone(),
+					"Reject DNA Title".as_bytes().to_vec(),
+					vec![0; MAX_REJECTION_DESCRIPTION_LENGTH as usize + 1]
+				),
+				Error::<Test>::RejectedDescriptionTooLong
+			);
 
 			assert_ok!(GeneticAnalysis::reject_genetic_analysis(
 				RuntimeOrigin::signed(1),
@@ -126,7 +173,7 @@ mod tests {
 			assert_noop!(
 				GeneticAnalysis::reject_genetic_analysis(
 					RuntimeOrigin::signed(1),
-					TrackingId::from_vec("xxxxxxxxxxxxxxxxxxxxx".as_bytes().to_vec()),
+					TrackingId::try_from("XXXXXXXXXXXXXXXXXXXX8".as_bytes().to_vec()).unwrap(),
 					"Reject DNA Title".as_bytes().to_vec(),
 					"Reject DNA Description".as_bytes().to_vec()
 				),
@@ -135,6 +182,21 @@ mod tests {
 		})
 	}
 
+	#[test]
+	fn cannot_reject_genetic_analysis_with_invalid_check_character() {
+		<ExternalityBuilder>::default().existential_deposit(1).build().execute_with(|| {
+			assert_noop!(
+				GeneticAnalysis::reject_genetic_analysis(
+					RuntimeOrigin::signed(1),
+					TrackingId::try_from("XXXXXXXXXXXXXXXXXXXXX".as_bytes().to_vec()).unwrap(),
+					"Reject DNA Title".as_bytes().to_vec(),
+					"Reject DNA Description".as_bytes().to_vec()
+				),
+				Error::<Test>::InvalidTrackingId
+			);
+		})
+	}
+
 	#[test]
 	fn cannot_reject_genetic_analysis_unauthorized() {
 		<ExternalityBuilder>::default().existential_deposit(1).build().execute_with(|| {
@@ -144,15 +206,25 @@ mod tests {
 					box_public_key: Keccak256::hash(
 						"0xDb9Af2d1f3ADD2726A132AA7A65Cc9E6fC5761C3".as_bytes(),
 					),
-					first_name: "First Name".as_bytes().to_vec(),
-					last_name: "Last Name".as_bytes().to_vec(),
-					gender: "Gender".as_bytes().to_vec(),
+					first_name: "First Name".as_bytes().to_vec().try_into().unwrap(),
+					last_name: "Last Name".as_bytes().to_vec().try_into().unwrap(),
+					gender: "Gender".as_bytes().to_vec().try_into().unwrap(),
 					date_of_birth: 0,
-					email: "Email".as_bytes().to_vec(),
-					phone_number: "+6893026516".as_bytes().to_vec(),
-					specialization: "DeBio Genetic Analyst".as_bytes().to_vec(),
-					profile_link: "DeBio Genetic Analyst profile_link".as_bytes().to_vec(),
-					profile_image: Some("DeBio Genetic Analyst profile_image".as_bytes().to_vec()),
+					email: "Email".as_bytes().to_vec().try_into().unwrap(),
+					phone_number: "+6893026516".as_bytes().to_vec().try_into().unwrap(),
+					specialization: "DeBio Genetic Analyst".as_bytes().to_vec().try_into().unwrap(),
+					profile_link: "DeBio Genetic Analyst profile_link"
+						.as_bytes()
+						.to_vec()
+						.try_into()
+						.unwrap(),
+					profile_image: Some(
+						"DeBio Genetic Analyst profile_image"
+							.as_bytes()
+							.to_vec()
+							.try_into()
+							.unwrap()
+					),
 				}
 			));
 
@@ -169,13 +241,23 @@ mod tests {
 			assert_ok!(GeneticAnalystServices::create_genetic_analyst_service(
 				RuntimeOrigin::signed(1),
 				GeneticAnalystServiceInfo {
-					name: "DeBio Genetic Analyst Service name".as_bytes().to_vec(),
-					prices_by_currency: vec![PriceByCurrency::default()],
+					name: "DeBio Genetic Analyst Service name"
+						.as_bytes()
+						.to_vec()
+						.try_into()
+						.unwrap(),
+					prices_by_currency: vec![PriceByCurrency::default()].try_into().unwrap(),
 					expected_duration: ExpectedDuration::default(),
-					description: "DeBio Genetic Analyst Service description".as_bytes().to_vec(),
+					description: "DeBio Genetic Analyst Service description"
+						.as_bytes()
+						.to_vec()
+						.try_into()
+						.unwrap(),
 					test_result_sample: "DeBio Genetic Analyst Service test_result_sample"
 						.as_bytes()
-						.to_vec(),
+						.to_vec()
+						.try_into()
+						.unwrap(),
 				}
 			));
 
@@ -200,8 +282,7 @@ mod tests {
 				None,
 			));
 
-			let _genetic_analysis =
-				GeneticAnalysis::genetic_analysis_by_genetic_analyst_id(1).unwrap();
+			let _genetic_analysis = GeneticAnalysis::genetic_analysis_by_genetic_analyst_id(&1);
 
 			assert_noop!(
 				GeneticAnalysis::reject_genetic_analysis(
@@ -226,15 +307,25 @@ mod tests {
 					box_public_key: Keccak256::hash(
 						"0xDb9Af2d1f3ADD2726A132AA7A65Cc9E6fC5761C3".as_bytes(),
 					),
-					first_name: "First Name".as_bytes().to_vec(),
-					last_name: "Last Name".as_bytes().to_vec(),
-					gender: "Gender".as_bytes().to_vec(),
+					first_name: "First Name".as_bytes().to_vec().try_into().unwrap(),
+					last_name: "Last Name".as_bytes().to_vec().try_into().unwrap(),
+					gender: "Gender".as_bytes().to_vec().try_into().unwrap(),
 					date_of_birth: 0,
-					email: "Email".as_bytes().to_vec(),
-					phone_number: "+6893026516".as_bytes().to_vec(),
-					specialization: "DeBio Genetic Analyst".as_bytes().to_vec(),
-					profile_link: "DeBio Genetic Analyst profile_link".as_bytes().to_vec(),
-					profile_image: Some("DeBio Genetic Analyst profile_image".as_bytes().to_vec()),
+					email: "Email".as_bytes().to_vec().try_into().unwrap(),
+					phone_number: "+6893026516".as_bytes().to_vec().try_into().unwrap(),
+					specialization: "DeBio Genetic Analyst".as_bytes().to_vec().try_into().unwrap(),
+					profile_link: "DeBio Genetic Analyst profile_link"
+						.as_bytes()
+						.to_vec()
+						.try_into()
+						.unwrap(),
+					profile_image: Some(
+						"DeBio Genetic Analyst profile_image"
+							.as_bytes()
+							.to_vec()
+							.try_into()
+							.unwrap()
+					),
 				}
 			));
 
@@ -251,13 +342,23 @@ mod tests {
 			assert_ok!(GeneticAnalystServices::create_genetic_analyst_service(
 				RuntimeOrigin::signed(1),
 				GeneticAnalystServiceInfo {
-					name: "DeBio Genetic Analyst Service name".as_bytes().to_vec(),
-					prices_by_currency: vec![PriceByCurrency::default()],
+					name: "DeBio Genetic Analyst Service name"
+						.as_bytes()
+						.to_vec()
+						.try_into()
+						.unwrap(),
+					prices_by_currency: vec![PriceByCurrency::default()].try_into().unwrap(),
 					expected_duration: ExpectedDuration::default(),
-					description: "DeBio Genetic Analyst Service description".as_bytes().to_vec(),
+					description: "DeBio Genetic Analyst Service description"
+						.as_bytes()
+						.to_vec()
+						.try_into()
+						.unwrap(),
 					test_result_sample: "DeBio Genetic Analyst Service test_result_sample"
 						.as_bytes()
-						.to_vec(),
+						.to_vec()
+						.try_into()
+						.unwrap(),
 				}
 			));
 
@@ -290,8 +391,27 @@ mod tests {
 				_genetic_analysis_order_id
 			));
 
-			let _genetic_analysis =
-				GeneticAnalysis::genetic_analysis_by_genetic_analyst_id(1).unwrap();
+			let _genetic_analysis = GeneticAnalysis::genetic_analysis_by_genetic_analyst_id(&1);
+
+			assert_noop!(
+				GeneticAnalysis::submit_genetic_analysis(
+					RuntimeOrigin::signed(1),
+					_genetic_analysis[0].clone(),
+					vec![0; MAX_REPORT_LINK_LENGTH as usize + 1],
+					None
+				),
+				Error::<Test>::ReportLinkTooLong
+			);
+
+			assert_noop!(
+				GeneticAnalysis::submit_genetic_analysis(
+					RuntimeOrigin::signed(1),
+					_genetic_analysis[0].clone(),
+					"Genetic Analysis report_link".as_bytes().to_vec(),
+					Some(vec![0; MAX_COMMENT_LENGTH as usize + 1])
+				),
+				Error::<Test>::CommentTooLong
+			);
 
 			assert_ok!(GeneticAnalysis::submit_genetic_analysis(
 				RuntimeOrigin::signed(1),
@@ -314,7 +434,7 @@ mod tests {
 			assert_eq!(_genetic_analysis_.owner_id, 1);
 			assert_eq!(
 				_genetic_analysis_.comment,
-				Some("Genetic Analysis comments".as_bytes().to_vec())
+				Some("Genetic Analysis comments".as_bytes().to_vec().try_into().unwrap())
 			);
 			assert_eq!(
 				_genetic_analysis_.report_link,
@@ -352,15 +472,25 @@ mod tests {
 					box_public_key: Keccak256::hash(
 						"0xDb9Af2d1f3ADD2726A132AA7A65Cc9E6fC5761C3".as_bytes(),
 					),
-					first_name: "First Name".as_bytes().to_vec(),
-					last_name: "Last Name".as_bytes().to_vec(),
-					gender: "Gender".as_bytes().to_vec(),
+					first_name: "First Name".as_bytes().to_vec().try_into().unwrap(),
+					last_name: "Last Name".as_bytes().to_vec().try_into().unwrap(),
+					gender: "Gender".as_bytes().to_vec().try_into().unwrap(),
 					date_of_birth: 0,
-					email: "Email".as_bytes().to_vec(),
-					phone_number: "+6893026516".as_bytes().to_vec(),
-					specialization: "DeBio Genetic Analyst".as_bytes().to_vec(),
-					profile_link: "DeBio Genetic Analyst profile_link".as_bytes().to_vec(),
-					profile_image: Some("DeBio Genetic Analyst profile_image".as_bytes().to_vec()),
+					email: "Email".as_bytes().to_vec().try_into().unwrap(),
+					phone_number: "+6893026516".as_bytes().to_vec().try_into().unwrap(),
+					specialization: "DeBio Genetic Analyst".as_bytes().to_vec().try_into().unwrap(),
+					profile_link: "DeBio Genetic Analyst profile_link"
+						.as_bytes()
+						.to_vec()
+						.try_into()
+						.unwrap(),
+					profile_image: Some(
+						"DeBio Genetic Analyst profile_image"
+							.as_bytes()
+							.to_vec()
+							.try_into()
+							.unwrap()
+					),
 				}
 			));
 
@@ -377,13 +507,23 @@ mod tests {
 			assert_ok!(GeneticAnalystServices::create_genetic_analyst_service(
 				RuntimeOrigin::signed(1),
 				GeneticAnalystServiceInfo {
-					name: "DeBio Genetic Analyst Service name".as_bytes().to_vec(),
-					prices_by_currency: vec![PriceByCurrency::default()],
+					name: "DeBio Genetic Analyst Service name"
+						.as_bytes()
+						.to_vec()
+						.try_into()
+						.unwrap(),
+					prices_by_currency: vec![PriceByCurrency::default()].try_into().unwrap(),
 					expected_duration: ExpectedDuration::default(),
-					description: "DeBio Genetic Analyst Service description".as_bytes().to_vec(),
+					description: "DeBio Genetic Analyst Service description"
+						.as_bytes()
+						.to_vec()
+						.try_into()
+						.unwrap(),
 					test_result_sample: "DeBio Genetic Analyst Service test_result_sample"
 						.as_bytes()
-						.to_vec(),
+						.to_vec()
+						.try_into()
+						.unwrap(),
 				}
 			));
 
@@ -416,8 +556,7 @@ mod tests {
 				_genetic_analysis_order_id
 			));
 
-			let _genetic_analysis =
-				GeneticAnalysis::genetic_analysis_by_genetic_analyst_id(1).unwrap();
+			let _genetic_analysis = GeneticAnalysis::genetic_analysis_by_genetic_analyst_id(&1);
 
 			assert_ok!(GeneticAnalysis::process_genetic_analysis(
 				RuntimeOrigin::signed(1),
@@ -445,7 +584,7 @@ mod tests {
 			assert_noop!(
 				GeneticAnalysis::process_genetic_analysis(
 					RuntimeOrigin::signed(1),
-					TrackingId::from_vec("xxxxxxxxxxxxxxxxxxxxx".as_bytes().to_vec()),
+					TrackingId::try_from("XXXXXXXXXXXXXXXXXXXX8".as_bytes().to_vec()).unwrap(),
 					GeneticAnalysisStatus::ResultReady
 				),
 				Error::<Test>::GeneticAnalysisNotFound
@@ -464,15 +603,25 @@ mod tests {
 					box_public_key: Keccak256::hash(
 						"0xDb9Af2d1f3ADD2726A132AA7A65Cc9E6fC5761C3".as_bytes(),
 					),
-					first_name: "First Name".as_bytes().to_vec(),
-					last_name: "Last Name".as_bytes().to_vec(),
-					gender: "Gender".as_bytes().to_vec(),
+					first_name: "First Name".as_bytes().to_vec().try_into().unwrap(),
+					last_name: "Last Name".as_bytes().to_vec().try_into().unwrap(),
+					gender: "Gender".as_bytes().to_vec().try_into().unwrap(),
 					date_of_birth: 0,
-					email: "Email".as_bytes().to_vec(),
-					phone_number: "+6893026516".as_bytes().to_vec(),
-					specialization: "DeBio Genetic Analyst".as_bytes().to_vec(),
-					profile_link: "DeBio Genetic Analyst profile_link".as_bytes().to_vec(),
-					profile_image: Some("DeBio Genetic Analyst profile_image".as_bytes().to_vec()),
+					email: "Email".as_bytes().to_vec().try_into().unwrap(),
+					phone_number: "+6893026516".as_bytes().to_vec().try_into().unwrap(),
+					specialization: "DeBio Genetic Analyst".as_bytes().to_vec().try_into().unwrap(),
+					profile_link: "DeBio Genetic Analyst profile_link"
+						.as_bytes()
+						.to_vec()
+						.try_into()
+						.unwrap(),
+					profile_image: Some(
+						"DeBio Genetic Analyst profile_image"
+							.as_bytes()
+							.to_vec()
+							.try_into()
+							.unwrap()
+					),
 				}
 			));
 
@@ -489,13 +638,23 @@ mod tests {
 			assert_ok!(GeneticAnalystServices::create_genetic_analyst_service(
 				RuntimeOrigin::signed(1),
 				GeneticAnalystServiceInfo {
-					name: "DeBio Genetic Analyst Service name".as_bytes().to_vec(),
-					prices_by_currency: vec![PriceByCurrency::default()],
+					name: "DeBio Genetic Analyst Service name"
+						.as_bytes()
+						.to_vec()
+						.try_into()
+						.unwrap(),
+					prices_by_currency: vec![PriceByCurrency::default()].try_into().unwrap(),
 					expected_duration: ExpectedDuration::default(),
-					description: "DeBio Genetic Analyst Service description".as_bytes().to_vec(),
+					description: "DeBio Genetic Analyst Service description"
+						.as_bytes()
+						.to_vec()
+						.try_into()
+						.unwrap(),
 					test_result_sample: "DeBio Genetic Analyst Service test_result_sample"
 						.as_bytes()
-						.to_vec(),
+						.to_vec()
+						.try_into()
+						.unwrap(),
 				}
 			));
 
@@ -528,8 +687,7 @@ mod tests {
 				_genetic_analysis_order_id
 			));
 
-			let _genetic_analysis =
-				GeneticAnalysis::genetic_analysis_by_genetic_analyst_id(1).unwrap();
+			let _genetic_analysis = GeneticAnalysis::genetic_analysis_by_genetic_analyst_id(&1);
 
 			assert_ok!(GeneticAnalysis::submit_genetic_analysis(
 				RuntimeOrigin::signed(1),
@@ -552,7 +710,7 @@ mod tests {
 			assert_eq!(_genetic_analysis_.owner_id, 1);
 			assert_eq!(
 				_genetic_analysis_.comment,
-				Some("Genetic Analysis comments".as_bytes().to_vec())
+				Some("Genetic Analysis comments".as_bytes().to_vec().try_into().unwrap())
 			);
 			assert_eq!(
 				_genetic_analysis_.report_link,
@@ -581,15 +739,25 @@ mod tests {
 					box_public_key: Keccak256::hash(
 						"0xDb9Af2d1f3ADD2726A132AA7A65Cc9E6fC5761C3".as_bytes(),
 					),
-					first_name: "First Name".as_bytes().to_vec(),
-					last_name: "Last Name".as_bytes().to_vec(),
-					gender: "Gender".as_bytes().to_vec(),
+					first_name: "First Name".as_bytes().to_vec().try_into().unwrap(),
+					last_name: "Last Name".as_bytes().to_vec().try_into().unwrap(),
+					gender: "Gender".as_bytes().to_vec().try_into().unwrap(),
 					date_of_birth: 0,
-					email: "Email".as_bytes().to_vec(),
-					phone_number: "+6893026516".as_bytes().to_vec(),
-					specialization: "DeBio Genetic Analyst".as_bytes().to_vec(),
-					profile_link: "DeBio Genetic Analyst profile_link".as_bytes().to_vec(),
-					profile_image: Some("DeBio Genetic Analyst profile_image".as_bytes().to_vec()),
+					email: "Email".as_bytes().to_vec().try_into().unwrap(),
+					phone_number: "+6893026516".as_bytes().to_vec().try_into().unwrap(),
+					specialization: "DeBio Genetic Analyst".as_bytes().to_vec().try_into().unwrap(),
+					profile_link: "DeBio Genetic Analyst profile_link"
+						.as_bytes()
+						.to_vec()
+						.try_into()
+						.unwrap(),
+					profile_image: Some(
+						"DeBio Genetic Analyst profile_image"
+							.as_bytes()
+							.to_vec()
+							.try_into()
+							.unwrap()
+					),
 				}
 			));
 
@@ -606,13 +774,23 @@ mod tests {
 			assert_ok!(GeneticAnalystServices::create_genetic_analyst_service(
 				RuntimeOrigin::signed(1),
 				GeneticAnalystServiceInfo {
-					name: "DeBio Genetic Analyst Service name".as_bytes().to_vec(),
-					prices_by_currency: vec![PriceByCurrency::default()],
+					name: "DeBio Genetic Analyst Service name"
+						.as_bytes()
+						.to_vec()
+						.try_into()
+						.unwrap(),
+					prices_by_currency: vec![PriceByCurrency::default()].try_into().unwrap(),
 					expected_duration: ExpectedDuration::default(),
-					description: "DeBio Genetic Analyst Service description".as_bytes().to_vec(),
+					description: "DeBio Genetic Analyst Service description"
+						.as_bytes()
+						.to_vec()
+						.try_into()
+						.unwrap(),
 					test_result_sample: "DeBio Genetic Analyst Service test_result_sample"
 						.as_bytes()
-						.to_vec(),
+						.to_vec()
+						.try_into()
+						.unwrap(),
 				}
 			));
 
@@ -645,8 +823,7 @@ mod tests {
 				_genetic_analysis_order_id
 			));
 
-			let _genetic_analysis =
-				GeneticAnalysis::genetic_analysis_by_genetic_analyst_id(1).unwrap();
+			let _genetic_analysis = GeneticAnalysis::genetic_analysis_by_genetic_analyst_id(&1);
 
 			assert_noop!(
 				GeneticAnalysis::process_genetic_analysis(
@@ -670,15 +847,25 @@ mod tests {
 					box_public_key: Keccak256::hash(
 						"0xDb9Af2d1f3ADD2726A132AA7A65Cc9E6fC5761C3".as_bytes(),
 					),
-					first_name: "First Name".as_bytes().to_vec(),
-					last_name: "Last Name".as_bytes().to_vec(),
-					gender: "Gender".as_bytes().to_vec(),
+					first_name: "First Name".as_bytes().to_vec().try_into().unwrap(),
+					last_name: "Last Name".as_bytes().to_vec().try_into().unwrap(),
+					gender: "Gender".as_bytes().to_vec().try_into().unwrap(),
 					date_of_birth: 0,
-					email: "Email".as_bytes().to_vec(),
-					phone_number: "+6893026516".as_bytes().to_vec(),
-					specialization: "DeBio Genetic Analyst".as_bytes().to_vec(),
-					profile_link: "DeBio Genetic Analyst profile_link".as_bytes().to_vec(),
-					profile_image: Some("DeBio Genetic Analyst profile_image".as_bytes().to_vec()),
+					email: "Email".as_bytes().to_vec().try_into().unwrap(),
+					phone_number: "+6893026516".as_bytes().to_vec().try_into().unwrap(),
+					specialization: "DeBio Genetic Analyst".as_bytes().to_vec().try_into().unwrap(),
+					profile_link: "DeBio Genetic Analyst profile_link"
+						.as_bytes()
+						.to_vec()
+						.try_into()
+						.unwrap(),
+					profile_image: Some(
+						"DeBio Genetic Analyst profile_image"
+							.as_bytes()
+							.to_vec()
+							.try_into()
+							.unwrap()
+					),
 				}
 			));
 
@@ -695,13 +882,23 @@ mod tests {
 			assert_ok!(GeneticAnalystServices::create_genetic_analyst_service(
 				RuntimeOrigin::signed(1),
 				GeneticAnalystServiceInfo {
-					name: "DeBio Genetic Analyst Service name".as_bytes().to_vec(),
-					prices_by_currency: vec![PriceByCurrency::default()],
+					name: "DeBio Genetic Analyst Service name"
+						.as_bytes()
+						.to_vec()
+						.try_into()
+						.unwrap(),
+					prices_by_currency: vec![PriceByCurrency::default()].try_into().unwrap(),
 					expected_duration: ExpectedDuration::default(),
-					description: "DeBio Genetic Analyst Service description".as_bytes().to_vec(),
+					description: "DeBio Genetic Analyst Service description"
+						.as_bytes()
+						.to_vec()
+						.try_into()
+						.unwrap(),
 					test_result_sample: "DeBio Genetic Analyst Service test_result_sample"
 						.as_bytes()
-						.to_vec(),
+						.to_vec()
+						.try_into()
+						.unwrap(),
 				}
 			));
 
@@ -734,8 +931,7 @@ mod tests {
 				_genetic_analysis_order_id
 			));
 
-			let _genetic_analysis =
-				GeneticAnalysis::genetic_analysis_by_genetic_analyst_id(1).unwrap();
+			let _genetic_analysis = GeneticAnalysis::genetic_analysis_by_genetic_analyst_id(&1);
 
 			assert_ok!(GeneticAnalysis::submit_genetic_analysis(
 				RuntimeOrigin::signed(1),
@@ -758,7 +954,7 @@ mod tests {
 			assert_eq!(_genetic_analysis_.owner_id, 1);
 			assert_eq!(
 				_genetic_analysis_.comment,
-				Some("Genetic Analysis comments".as_bytes().to_vec())
+				Some("Genetic Analysis comments".as_bytes().to_vec().try_into().unwrap())
 			);
 			assert_eq!(
 				_genetic_analysis_.report_link,
@@ -776,15 +972,25 @@ mod tests {
 					box_public_key: Keccak256::hash(
 						"0xDb9Af2d1f3ADD2726A132AA7A65Cc9E6fC5761C3".as_bytes(),
 					),
-					first_name: "First Name".as_bytes().to_vec(),
-					last_name: "Last Name".as_bytes().to_vec(),
-					gender: "Gender".as_bytes().to_vec(),
+					first_name: "First Name".as_bytes().to_vec().try_into().unwrap(),
+					last_name: "Last Name".as_bytes().to_vec().try_into().unwrap(),
+					gender: "Gender".as_bytes().to_vec().try_into().unwrap(),
 					date_of_birth: 0,
-					email: "Email".as_bytes().to_vec(),
-					phone_number: "+6893026516".as_bytes().to_vec(),
-					specialization: "DeBio Genetic Analyst".as_bytes().to_vec(),
-					profile_link: "DeBio Genetic Analyst profile_link".as_bytes().to_vec(),
-					profile_image: Some("DeBio Genetic Analyst profile_image".as_bytes().to_vec()),
+					email: "Email".as_bytes().to_vec().try_into().unwrap(),
+					phone_number: "+6893026516".as_bytes().to_vec().try_into().unwrap(),
+					specialization: "DeBio Genetic Analyst".as_bytes().to_vec().try_into().unwrap(),
+					profile_link: "DeBio Genetic Analyst profile_link"
+						.as_bytes()
+						.to_vec()
+						.try_into()
+						.unwrap(),
+					profile_image: Some(
+						"DeBio Genetic Analyst profile_image"
+							.as_bytes()
+							.to_vec()
+							.try_into()
+							.unwrap()
+					),
 				}
 			));
 
@@ -801,13 +1007,23 @@ mod tests {
 			assert_ok!(GeneticAnalystServices::create_genetic_analyst_service(
 				RuntimeOrigin::signed(1),
 				GeneticAnalystServiceInfo {
-					name: "DeBio Genetic Analyst Service name".as_bytes().to_vec(),
-					prices_by_currency: vec![PriceByCurrency::default()],
+					name: "DeBio Genetic Analyst Service name"
+						.as_bytes()
+						.to_vec()
+						.try_into()
+						.unwrap(),
+					prices_by_currency: vec![PriceByCurrency::default()].try_into().unwrap(),
 					expected_duration: ExpectedDuration::default(),
-					description: "DeBio Genetic Analyst Service description".as_bytes().to_vec(),
+					description: "DeBio Genetic Analyst Service description"
+						.as_bytes()
+						.to_vec()
+						.try_into()
+						.unwrap(),
 					test_result_sample: "DeBio Genetic Analyst Service test_result_sample"
 						.as_bytes()
-						.to_vec(),
+						.to_vec()
+						.try_into()
+						.unwrap(),
 				}
 			));
 
@@ -835,7 +1051,7 @@ mod tests {
 			assert_noop!(
 				GeneticAnalysis::submit_genetic_analysis(
 					RuntimeOrigin::signed(1),
-					TrackingId::from_vec("xxxxxxxxxxxxxxxxxxxxx".as_bytes().to_vec()),
+					TrackingId::try_from("XXXXXXXXXXXXXXXXXXXX8".as_bytes().to_vec()).unwrap(),
 					"Genetic Analysis report_link".as_bytes().to_vec(),
 					Some("Genetic Analysis comments".as_bytes().to_vec())
 				),
@@ -853,15 +1069,25 @@ mod tests {
 					box_public_key: Keccak256::hash(
 						"0xDb9Af2d1f3ADD2726A132AA7A65Cc9E6fC5761C3".as_bytes(),
 					),
-					first_name: "First Name".as_bytes().to_vec(),
-					last_name: "Last Name".as_bytes().to_vec(),
-					gender: "Gender".as_bytes().to_vec(),
+					first_name: "First Name".as_bytes().to_vec().try_into().unwrap(),
+					last_name: "Last Name".as_bytes().to_vec().try_into().unwrap(),
+					gender: "Gender".as_bytes().to_vec().try_into().unwrap(),
 					date_of_birth: 0,
-					email: "Email".as_bytes().to_vec(),
-					phone_number: "+6893026516".as_bytes().to_vec(),
-					specialization: "DeBio Genetic Analyst".as_bytes().to_vec(),
-					profile_link: "DeBio Genetic Analyst profile_link".as_bytes().to_vec(),
-					profile_image: Some("DeBio Genetic Analyst profile_image".as_bytes().to_vec()),
+					email: "Email".as_bytes().to_vec().try_into().unwrap(),
+					phone_number: "+6893026516".as_bytes().to_vec().try_into().unwrap(),
+					specialization: "DeBio Genetic Analyst".as_bytes().to_vec().try_into().unwrap(),
+					profile_link: "DeBio Genetic Analyst profile_link"
+						.as_bytes()
+						.to_vec()
+						.try_into()
+						.unwrap(),
+					profile_image: Some(
+						"DeBio Genetic Analyst profile_image"
+							.as_bytes()
+							.to_vec()
+							.try_into()
+							.unwrap()
+					),
 				}
 			));
 
@@ -878,13 +1104,23 @@ mod tests {
 			assert_ok!(GeneticAnalystServices::create_genetic_analyst_service(
 				RuntimeOrigin::signed(1),
 				GeneticAnalystServiceInfo {
-					name: "DeBio Genetic Analyst Service name".as_bytes().to_vec(),
-					prices_by_currency: vec![PriceByCurrency::default()],
+					name: "DeBio Genetic Analyst Service name"
+						.as_bytes()
+						.to_vec()
+						.try_into()
+						.unwrap(),
+					prices_by_currency: vec![PriceByCurrency::default()].try_into().unwrap(),
 					expected_duration: ExpectedDuration::default(),
-					description: "DeBio Genetic Analyst Service description".as_bytes().to_vec(),
+					description: "DeBio Genetic Analyst Service description"
+						.as_bytes()
+						.to_vec()
+						.try_into()
+						.unwrap(),
 					test_result_sample: "DeBio Genetic Analyst Service test_result_sample"
 						.as_bytes()
-						.to_vec(),
+						.to_vec()
+						.try_into()
+						.unwrap(),
 				}
 			));
 
@@ -909,8 +1145,7 @@ mod tests {
 				None,
 			));
 
-			let _genetic_analysis =
-				GeneticAnalysis::genetic_analysis_by_genetic_analyst_id(1).unwrap();
+			let _genetic_analysis = GeneticAnalysis::genetic_analysis_by_genetic_analyst_id(&1);
 
 			assert_noop!(
 				GeneticAnalysis::submit_genetic_analysis(
@@ -923,4 +1158,67 @@ mod tests {
 			);
 		})
 	}
+
+	#[test]
+	fn migrate_truncates_genetic_analyses_and_rebuilds_indexes() {
+		<ExternalityBuilder>::default().existential_deposit(1).build().execute_with(|| {
+			let tracking_id =
+				TrackingId::try_from("XXXXXXXXXXXXXXXXXXXX8".as_bytes().to_vec()).unwrap();
+			let order_id = Keccak256::hash(&tracking_id.encode());
+			let legacy_genetic_analysis = |report_link: Vec<u8>| {
+				(
+					&tracking_id,
+					1u64,
+					2u64,
+					report_link,
+					Some("Genetic Analysis comments".as_bytes().to_vec()),
+					None::<Vec<u8>>,
+					None::<Vec<u8>>,
+					order_id,
+					0u64,
+					0u64,
+					GeneticAnalysisStatus::InProgress,
+				)
+					.encode()
+			};
+			let legacy_index = |prefix: [u8; 32], account_id: u64| {
+				[prefix.as_slice(), &Blake2_128Concat::hash(&account_id.encode())].concat()
+			};
+
+			// An analysis submitted before reports were bounded, with the analyst list keyed by
+			// the owner like the legacy index did
+			unhashed::put_raw(
+				&GeneticAnalysisStorage::<Test>::hashed_key_for(&tracking_id),
+				&legacy_genetic_analysis(vec![b'X'; MAX_REPORT_LINK_LENGTH as usize + 1]),
+			);
+			unhashed::put(
+				&legacy_index(GeneticAnalysisByOwner::<Test>::final_prefix(), 2),
+				&vec![tracking_id.clone()],
+			);
+			unhashed::put(
+				&legacy_index(GeneticAnalysisByGeneticAnalyst::<Test>::final_prefix(), 2),
+				&vec![tracking_id.clone()],
+			);
+			StorageVersion::new(0).put::<GeneticAnalysis>();
+
+			genetic_analysis::migrations::migrate::<Test>();
+
+			assert_eq!(
+				GeneticAnalysis::genetic_analysis_by_genetic_analysis_tracking_id(&tracking_id)
+					.unwrap()
+					.encode(),
+				legacy_genetic_analysis(vec![b'X'; MAX_REPORT_LINK_LENGTH as usize])
+			);
+			assert_eq!(
+				GeneticAnalysis::genetic_analysis_by_owner_id(&2),
+				vec![tracking_id.clone()]
+			);
+			assert_eq!(
+				GeneticAnalysis::genetic_analysis_by_genetic_analyst_id(&1),
+				vec![tracking_id]
+			);
+			assert_eq!(GeneticAnalysis::genetic_analysis_by_genetic_analyst_id(&2), Vec::new());
+			assert_eq!(StorageVersion::get::<GeneticAnalysis>(), StorageVersion::new(1));
+		})
+	}
 }
@@ -29,26 +29,26 @@ benchmarks! {
 		let caller_origin = T::RuntimeOrigin::from(RawOrigin::Signed(caller.clone()));
 		let genetic_analyst = GeneticAnalystInfo {
 			box_public_key: T::Hashing::hash("0xDb9Af2d1f3ADD2726A132AA7A65Cc9E6fC5761C3".as_bytes()),
-			first_name: "First Name".as_bytes().to_vec(),
-			last_name: "Last Name".as_bytes().to_vec(),
-			gender: "Gender".as_bytes().to_vec(),
+			first_name: "First Name".as_bytes().to_vec().try_into().unwrap(),
+			last_name: "Last Name".as_bytes().to_vec().try_into().unwrap(),
+			gender: "Gender".as_bytes().to_vec().try_into().unwrap(),
 			date_of_birth: <T as pallet_timestamp::pallet::Config>::Moment::default(),
-			email: "Email".as_bytes().to_vec(),
-			phone_number: "+6893026516".as_bytes().to_vec(),
-			specialization: "DeBio Genetic Analyst".as_bytes().to_vec(),
-			profile_link: "DeBio Genetic Analyst profile_link".as_bytes().to_vec(),
-			profile_image: Some("DeBio Genetic Analyst profile_image".as_bytes().to_vec()),
+			email: "Email".as_bytes().to_vec().try_into().unwrap(),
+			phone_number: "+6893026516".as_bytes().to_vec().try_into().unwrap(),
+			specialization: "DeBio Genetic Analyst".as_bytes().to_vec().try_into().unwrap(),
+			profile_link: "DeBio Genetic Analyst profile_link".as_bytes().to_vec().try_into().unwrap(),
+			profile_image: Some("DeBio Genetic Analyst profile_image".as_bytes().to_vec().try_into().unwrap()),
 		};
 		let _add_genetic_analysts = GeneticAnalysts::<T>::register_genetic_analyst(caller_origin, genetic_analyst);
 
 		let genetic_analyst_service_info = GeneticAnalystServiceInfo {
-			name: "DeBio Genetic Analyst Service name".as_bytes().to_vec(),
+			name: "DeBio Genetic Analyst Service name".as_bytes().to_vec().try_into().unwrap(),
 			prices_by_currency: vec![
 				PriceByCurrency::default()
-			],
+			].try_into().unwrap(),
 			expected_duration: ExpectedDuration::default(),
-			description: "DeBio Genetic Analyst Service description".as_bytes().to_vec(),
-			test_result_sample: "DeBio Genetic Analyst Service test_result_sample".as_bytes().to_vec(),
+			description: "DeBio Genetic Analyst Service description".as_bytes().to_vec().try_into().unwrap(),
+			test_result_sample: "DeBio Genetic Analyst Service test_result_sample".as_bytes().to_vec().try_into().unwrap(),
 		};
 	}: create_genetic_analyst_service(RawOrigin::Signed(caller), genetic_analyst_service_info)
 
@@ -57,198 +57,198 @@ benchmarks! {
 		let caller_origin = T::RuntimeOrigin::from(RawOrigin::Signed(caller.clone()));
 		let genetic_analyst = GeneticAnalystInfo {
 			box_public_key: T::Hashing::hash("0xDb9Af2d1f3ADD2726A132AA7A65Cc9E6fC5761C3".as_bytes()),
-			first_name: "First Name".as_bytes().to_vec(),
-			last_name: "Last Name".as_bytes().to_vec(),
-			gender: "Gender".as_bytes().to_vec(),
+			first_name: "First Name".as_bytes().to_vec().try_into().unwrap(),
+			last_name: "Last Name".as_bytes().to_vec().try_into().unwrap(),
+			gender: "Gender".as_bytes().to_vec().try_into().unwrap(),
 			date_of_birth: <T as pallet_timestamp::pallet::Config>::Moment::default(),
-			email: "Email".as_bytes().to_vec(),
-			phone_number: "+6893026516".as_bytes().to_vec(),
-			specialization: "DeBio Genetic Analyst".as_bytes().to_vec(),
-			profile_link: "DeBio Genetic Analyst profile_link".as_bytes().to_vec(),
-			profile_image: Some("DeBio Genetic Analyst profile_image".as_bytes().to_vec()),
+			email: "Email".as_bytes().to_vec().try_into().unwrap(),
+			phone_number: "+6893026516".as_bytes().to_vec().try_into().unwrap(),
+			specialization: "DeBio Genetic Analyst".as_bytes().to_vec().try_into().unwrap(),
+			profile_link: "DeBio Genetic Analyst profile_link".as_bytes().to_vec().try_into().unwrap(),
+			profile_image: Some("DeBio Genetic Analyst profile_image".as_bytes().to_vec().try_into().unwrap()),
 		};
 		let _ = GeneticAnalysts::<T>::register_genetic_analyst(caller_origin, genetic_analyst);
 
 		let twenty_services = vec![
 			GeneticAnalystServiceInfo {
-				name: "DeBio Genetic Analyst Service name".as_bytes().to_vec(),
-				prices_by_currency: vec![PriceByCurrency::default()],
+				name: "DeBio Genetic Analyst Service name".as_bytes().to_vec().try_into().unwrap(),
+				prices_by_currency: vec![PriceByCurrency::default()].try_into().unwrap(),
 				expected_duration: ExpectedDuration::default(),
-				description: "DeBio Genetic Analyst Service description".as_bytes().to_vec(),
+				description: "DeBio Genetic Analyst Service description".as_bytes().to_vec().try_into().unwrap(),
 				test_result_sample: "DeBio Genetic Analyst Service test_result_sample"
 					.as_bytes()
-					.to_vec(),
+					.to_vec().try_into().unwrap(),
 			},
 			GeneticAnalystServiceInfo {
-				name: "DeBio Genetic Analyst Service name".as_bytes().to_vec(),
-				prices_by_currency: vec![PriceByCurrency::default()],
+				name: "DeBio Genetic Analyst Service name".as_bytes().to_vec().try_into().unwrap(),
+				prices_by_currency: vec![PriceByCurrency::default()].try_into().unwrap(),
 				expected_duration: ExpectedDuration::default(),
-				description: "DeBio Genetic Analyst Service description".as_bytes().to_vec(),
+				description: "DeBio Genetic Analyst Service description".as_bytes().to_vec().try_into().unwrap(),
 				test_result_sample: "DeBio Genetic Analyst Service test_result_sample"
 					.as_bytes()
-					.to_vec(),
+					.to_vec().try_into().unwrap(),
 			},
 			GeneticAnalystServiceInfo {
-				name: "DeBio Genetic Analyst Service name".as_bytes().to_vec(),
-				prices_by_currency: vec![PriceByCurrency::default()],
+				name: "DeBio Genetic Analyst Service name".as_bytes().to_vec().try_into().unwrap(),
+				prices_by_currency: vec![PriceByCurrency::default()].try_into().unwrap(),
 				expected_duration: ExpectedDuration::default(),
-				description: "DeBio Genetic Analyst Service description".as_bytes().to_vec(),
+				description: "DeBio Genetic Analyst Service description".as_bytes().to_vec().try_into().unwrap(),
 				test_result_sample: "DeBio Genetic Analyst Service test_result_sample"
 					.as_bytes()
-					.to_vec(),
+					.to_vec().try_into().unwrap(),
 			},
 			GeneticAnalystServiceInfo {
-				name: "DeBio Genetic Analyst Service name".as_bytes().to_vec(),
-				prices_by_currency: vec![PriceByCurrency::default()],
+				name: "DeBio Genetic Analyst Service name".as_bytes().to_vec().try_into().unwrap(),
+				prices_by_currency: vec![PriceByCurrency::default()].try_into().unwrap(),
 				expected_duration: ExpectedDuration::default(),
-				description: "DeBio Genetic Analyst Service description".as_bytes().to_vec(),
+				description: "DeBio Genetic Analyst Service description".as_bytes().to_vec().try_into().unwrap(),
 				test_result_sample: "DeBio Genetic Analyst Service test_result_sample"
 					.as_bytes()
-					.to_vec(),
+					.to_vec().try_into().unwrap(),
 			},
 			GeneticAnalystServiceInfo {
-				name: "DeBio Genetic Analyst Service name".as_bytes().to_vec(),
-				prices_by_currency: vec![PriceByCurrency::default()],
+				name: "DeBio Genetic Analyst Service name".as_bytes().to_vec().try_into().unwrap(),
+				prices_by_currency: vec![PriceByCurrency::default()].try_into().unwrap(),
 				expected_duration: ExpectedDuration::default(),
-				description: "DeBio Genetic Analyst Service description".as_bytes().to_vec(),
+				description: "DeBio Genetic Analyst Service description".as_bytes().to_vec().try_into().unwrap(),
 				test_result_sample: "DeBio Genetic Analyst Service test_result_sample"
 					.as_bytes()
-					.to_vec(),
+					.to_vec().try_into().unwrap(),
 			},
 			GeneticAnalystServiceInfo {
-				name: "DeBio Genetic Analyst Service name".as_bytes().to_vec(),
-				prices_by_currency: vec![PriceByCurrency::default()],
+				name: "DeBio Genetic Analyst Service name".as_bytes().to_vec().try_into().unwrap(),
+				prices_by_currency: vec![PriceByCurrency::default()].try_into().unwrap(),
 				expected_duration: ExpectedDuration::default(),
-				description: "DeBio Genetic Analyst Service description".as_bytes().to_vec(),
+				description: "DeBio Genetic Analyst Service description".as_bytes().to_vec().try_into().unwrap(),
 				test_result_sample: "DeBio Genetic Analyst Service test_result_sample"
 					.as_bytes()
-					.to_vec(),
+					.to_vec().try_into().unwrap(),
 			},
 			GeneticAnalystServiceInfo {
-				name: "DeBio Genetic Analyst Service name".as_bytes().to_vec(),
-				prices_by_currency: vec![PriceByCurrency::default()],
+				name: "DeBio Genetic Analyst Service name".as_bytes().to_vec().try_into().unwrap(),
+				prices_by_currency: vec![PriceByCurrency::default()].try_into().unwrap(),
 				expected_duration: ExpectedDuration::default(),
-				description: "DeBio Genetic Analyst Service description".as_bytes().to_vec(),
+				description: "DeBio Genetic Analyst Service description".as_bytes().to_vec().try_into().unwrap(),
 				test_result_sample: "DeBio Genetic Analyst Service test_result_sample"
 					.as_bytes()
-					.to_vec(),
+					.to_vec().try_into().unwrap(),
 			},
 			GeneticAnalystServiceInfo {
-				name: "DeBio Genetic Analyst Service name".as_bytes().to_vec(),
-				prices_by_currency: vec![PriceByCurrency::default()],
+				name: "DeBio Genetic Analyst Service name".as_bytes().to_vec().try_into().unwrap(),
+				prices_by_currency: vec![PriceByCurrency::default()].try_into().unwrap(),
 				expected_duration: ExpectedDuration::default(),
-				description: "DeBio Genetic Analyst Service description".as_bytes().to_vec(),
+				description: "DeBio Genetic Analyst Service description".as_bytes().to_vec().try_into().unwrap(),
 				test_result_sample: "DeBio Genetic Analyst Service test_result_sample"
 					.as_bytes()
-					.to_vec(),
+					.to_vec().try_into().unwrap(),
 			},
 			GeneticAnalystServiceInfo {
-				name: "DeBio Genetic Analyst Service name".as_bytes().to_vec(),
-				prices_by_currency: vec![PriceByCurrency::default()],
+				name: "DeBio Genetic Analyst Service name".as_bytes().to_vec().try_into().unwrap(),
+				prices_by_currency: vec![PriceByCurrency::default()].try_into().unwrap(),
 				expected_duration: ExpectedDuration::default(),
-				description: "DeBio Genetic Analyst Service description".as_bytes().to_vec(),
+				description: "DeBio Genetic Analyst Service description".as_bytes().to_vec().try_into().unwrap(),
 				test_result_sample: "DeBio Genetic Analyst Service test_result_sample"
 					.as_bytes()
-					.to_vec(),
+					.to_vec().try_into().unwrap(),
 			},
 			GeneticAnalystServiceInfo {
-				name: "DeBio Genetic Analyst Service name".as_bytes().to_vec(),
-				prices_by_currency: vec![PriceByCurrency::default()],
+				name: "DeBio Genetic Analyst Service name".as_bytes().to_vec().try_into().unwrap(),
+				prices_by_currency: vec![PriceByCurrency::default()].try_into().unwrap(),
 				expected_duration: ExpectedDuration::default(),
-				description: "DeBio Genetic Analyst Service description".as_bytes().to_vec(),
+				description: "DeBio Genetic Analyst Service description".as_bytes().to_vec().try_into().unwrap(),
 				test_result_sample: "DeBio Genetic Analyst Service test_result_sample"
 					.as_bytes()
-					.to_vec(),
+					.to_vec().try_into().unwrap(),
 			},
 			GeneticAnalystServiceInfo {
-				name: "DeBio Genetic Analyst Service name".as_bytes().to_vec(),
-				prices_by_currency: vec![PriceByCurrency::default()],
+				name: "DeBio Genetic Analyst Service name".as_bytes().to_vec().try_into().unwrap(),
+				prices_by_currency: vec![PriceByCurrency::default()].try_into().unwrap(),
 				expected_duration: ExpectedDuration::default(),
-				description: "DeBio Genetic Analyst Service description".as_bytes().to_vec(),
+				description: "DeBio Genetic Analyst Service description".as_bytes().to_vec().try_into().unwrap(),
 				test_result_sample: "DeBio Genetic Analyst Service test_result_sample"
 					.as_bytes()
-					.to_vec(),
+					.to_vec().try_into().unwrap(),
 			},
 			GeneticAnalystServiceInfo {
-				name: "DeBio Genetic Analyst Service name".as_bytes().to_vec(),
-				prices_by_currency: vec![PriceByCurrency::default()],
+				name: "DeBio Genetic Analyst Service name".as_bytes().to_vec().try_into().unwrap(),
+				prices_by_currency: vec![PriceByCurrency::default()].try_into().unwrap(),
 				expected_duration: ExpectedDuration::default(),
-				description: "DeBio Genetic Analyst Service description".as_bytes().to_vec(),
+				description: "DeBio Genetic Analyst Service description".as_bytes().to_vec().try_into().unwrap(),
 				test_result_sample: "DeBio Genetic Analyst Service test_result_sample"
 					.as_bytes()
-					.to_vec(),
+					.to_vec().try_into().unwrap(),
 			},
 			GeneticAnalystServiceInfo {
-				name: "DeBio Genetic Analyst Service name".as_bytes().to_vec(),
-				prices_by_currency: vec![PriceByCurrency::default()],
+				name: "DeBio Genetic Analyst Service name".as_bytes().to_vec().try_into().unwrap(),
+				prices_by_currency: vec![PriceByCurrency::default()].try_into().unwrap(),
 				expected_duration: ExpectedDuration::default(),
-				description: "DeBio Genetic Analyst Service description".as_bytes().to_vec(),
+				description: "DeBio Genetic Analyst Service description".as_bytes().to_vec().try_into().unwrap(),
 				test_result_sample: "DeBio Genetic Analyst Service test_result_sample"
 					.as_bytes()
-					.to_vec(),
+					.to_vec().try_into().unwrap(),
 			},
 			GeneticAnalystServiceInfo {
-				name: "DeBio Genetic Analyst Service name".as_bytes().to_vec(),
-				prices_by_currency: vec![PriceByCurrency::default()],
+				name: "DeBio Genetic Analyst Service name".as_bytes().to_vec().try_into().unwrap(),
+				prices_by_currency: vec![PriceByCurrency::default()].try_into().unwrap(),
 				expected_duration: ExpectedDuration::default(),
-				description: "DeBio Genetic Analyst Service description".as_bytes().to_vec(),
+				description: "DeBio Genetic Analyst Service description".as_bytes().to_vec().try_into().unwrap(),
 				test_result_sample: "DeBio Genetic Analyst Service test_result_sample"
 					.as_bytes()
-					.to_vec(),
+					.to_vec().try_into().unwrap(),
 			},
 			GeneticAnalystServiceInfo {
-				name: "DeBio Genetic Analyst Service name".as_bytes().to_vec(),
-				prices_by_currency: vec![PriceByCurrency::default()],
+				name: "DeBio Genetic Analyst Service name".as_bytes().to_vec().try_into().unwrap(),
+				prices_by_currency: vec![PriceByCurrency::default()].try_into().unwrap(),
 				expected_duration: ExpectedDuration::default(),
-				description: "DeBio Genetic Analyst Service description".as_bytes().to_vec(),
+				description: "DeBio Genetic Analyst Service description".as_bytes().to_vec().try_into().unwrap(),
 				test_result_sample: "DeBio Genetic Analyst Service test_result_sample"
 					.as_bytes()
-					.to_vec(),
+					.to_vec().try_into().unwrap(),
 			},
 			GeneticAnalystServiceInfo {
-				name: "DeBio Genetic Analyst Service name".as_bytes().to_vec(),
-				prices_by_currency: vec![PriceByCurrency::default()],
+				name: "DeBio Genetic Analyst Service name".as_bytes().to_vec().try_into().unwrap(),
+				prices_by_currency: vec![PriceByCurrency::default()].try_into().unwrap(),
 				expected_duration: ExpectedDuration::default(),
-				description: "DeBio Genetic Analyst Service description".as_bytes().to_vec(),
+				description: "DeBio Genetic Analyst Service description".as_bytes().to_vec().try_into().unwrap(),
 				test_result_sample: "DeBio Genetic Analyst Service test_result_sample"
 					.as_bytes()
-					.to_vec(),
+					.to_vec().try_into().unwrap(),
 			},
 			GeneticAnalystServiceInfo {
-				name: "DeBio Genetic Analyst Service name".as_bytes().to_vec(),
-				prices_by_currency: vec![PriceByCurrency::default()],
+				name: "DeBio Genetic Analyst Service name".as_bytes().to_vec().try_into().unwrap(),
+				prices_by_currency: vec![PriceByCurrency::default()].try_into().unwrap(),
 				expected_duration: ExpectedDuration::default(),
-				description: "DeBio Genetic Analyst Service description".as_bytes().to_vec(),
+				description: "DeBio Genetic Analyst Service description".as_bytes().to_vec().try_into().unwrap(),
 				test_result_sample: "DeBio Genetic Analyst Service test_result_sample"
 					.as_bytes()
-					.to_vec(),
+					.to_vec().try_into().unwrap(),
 			},
 			GeneticAnalystServiceInfo {
-				name: "DeBio Genetic Analyst Service name".as_bytes().to_vec(),
-				prices_by_currency: vec![PriceByCurrency::default()],
+				name: "DeBio Genetic Analyst Service name".as_bytes().to_vec().try_into().unwrap(),
+				prices_by_currency: vec![PriceByCurrency::default()].try_into().unwrap(),
 				expected_duration: ExpectedDuration::default(),
-				description: "DeBio Genetic Analyst Service description".as_bytes().to_vec(),
+				description: "DeBio Genetic Analyst Service description".as_bytes().to_vec().try_into().unwrap(),
 				test_result_sample: "DeBio Genetic Analyst Service test_result_sample"
 					.as_bytes()
-					.to_vec(),
+					.to_vec().try_into().unwrap(),
 			},
 			GeneticAnalystServiceInfo {
-				name: "DeBio Genetic Analyst Service name".as_bytes().to_vec(),
-				prices_by_currency: vec![PriceByCurrency::default()],
+				name: "DeBio Genetic Analyst Service name".as_bytes().to_vec().try_into().unwrap(),
+				prices_by_currency: vec![PriceByCurrency::default()].try_into().unwrap(),
 				expected_duration: ExpectedDuration::default(),
-				description: "DeBio Genetic Analyst Service description".as_bytes().to_vec(),
+				description: "DeBio Genetic Analyst Service description".as_bytes().to_vec().try_into().unwrap(),
 				test_result_sample: "DeBio Genetic Analyst Service test_result_sample"
 					.as_bytes()
-					.to_vec(),
+					.to_vec().try_into().unwrap(),
 			},
 			GeneticAnalystServiceInfo {
-				name: "DeBio Genetic Analyst Service name".as_bytes().to_vec(),
-				prices_by_currency: vec![PriceByCurrency::default()],
+				name: "DeBio Genetic Analyst Service name".as_bytes().to_vec().try_into().unwrap(),
+				prices_by_currency: vec![PriceByCurrency::default()].try_into().unwrap(),
 				expected_duration: ExpectedDuration::default(),
-				description: "DeBio Genetic Analyst Service description".as_bytes().to_vec(),
+				description: "DeBio Genetic Analyst Service description".as_bytes().to_vec().try_into().unwrap(),
 				test_result_sample: "DeBio Genetic Analyst Service test_result_sample"
 					.as_bytes()
-					.to_vec(),
+					.to_vec().try_into().unwrap(),
 			}
 		];
 	}: bulk_create_genetic_analyst_service(RawOrigin::Signed(caller), twenty_services)
@@ -258,26 +258,26 @@ benchmarks! {
 		let caller_origin = T::RuntimeOrigin::from(RawOrigin::Signed(caller.clone()));
 		let genetic_analyst_info = GeneticAnalystInfo {
 			box_public_key: T::Hashing::hash("0xDb9Af2d1f3ADD2726A132AA7A65Cc9E6fC5761C3".as_bytes()),
-			first_name: "First Name".as_bytes().to_vec(),
-			last_name: "Last Name".as_bytes().to_vec(),
-			gender: "Gender".as_bytes().to_vec(),
+			first_name: "First Name".as_bytes().to_vec().try_into().unwrap(),
+			last_name: "Last Name".as_bytes().to_vec().try_into().unwrap(),
+			gender: "Gender".as_bytes().to_vec().try_into().unwrap(),
 			date_of_birth: <T as pallet_timestamp::pallet::Config>::Moment::default(),
-			email: "Email".as_bytes().to_vec(),
-			phone_number: "+6893026516".as_bytes().to_vec(),
-			specialization: "DeBio Genetic Analyst".as_bytes().to_vec(),
-			profile_link: "DeBio Genetic Analyst profile_link".as_bytes().to_vec(),
-			profile_image: Some("DeBio Genetic Analyst profile_image".as_bytes().to_vec()),
+			email: "Email".as_bytes().to_vec().try_into().unwrap(),
+			phone_number: "+6893026516".as_bytes().to_vec().try_into().unwrap(),
+			specialization: "DeBio Genetic Analyst".as_bytes().to_vec().try_into().unwrap(),
+			profile_link: "DeBio Genetic Analyst profile_link".as_bytes().to_vec().try_into().unwrap(),
+			profile_image: Some("DeBio Genetic Analyst profile_image".as_bytes().to_vec().try_into().unwrap()),
 		};
 		let _add_genetic_analysts = GeneticAnalysts::<T>::register_genetic_analyst(caller_origin.clone(), genetic_analyst_info);
 
 		let old_genetic_analyst_service_info = GeneticAnalystServiceInfo {
-			name: "DeBio Genetic Analyst Service name".as_bytes().to_vec(),
+			name: "DeBio Genetic Analyst Service name".as_bytes().to_vec().try_into().unwrap(),
 			prices_by_currency: vec![
 				PriceByCurrency::default()
-			],
+			].try_into().unwrap(),
 			expected_duration: ExpectedDuration::default(),
-			description: "DeBio Genetic Analyst Service description".as_bytes().to_vec(),
-			test_result_sample: "DeBio Genetic Analyst Service test_result_sample".as_bytes().to_vec(),
+			description: "DeBio Genetic Analyst Service description".as_bytes().to_vec().try_into().unwrap(),
+			test_result_sample: "DeBio Genetic Analyst Service test_result_sample".as_bytes().to_vec().try_into().unwrap(),
 		};
 		let _create_genetic_analyst_service = GeneticAnalystServices::<T>::create_genetic_analyst_service(caller_origin, old_genetic_analyst_service_info);
 
@@ -285,13 +285,13 @@ benchmarks! {
 			.unwrap();
 
 		let new_genetic_analyst_service_info = GeneticAnalystServiceInfo {
-			name: "DeBio Genetic Analyst Service name 2".as_bytes().to_vec(),
+			name: "DeBio Genetic Analyst Service name 2".as_bytes().to_vec().try_into().unwrap(),
 			prices_by_currency: vec![
 				PriceByCurrency::default()
-			],
+			].try_into().unwrap(),
 			expected_duration: ExpectedDuration::default(),
-			description: "DeBio Genetic Analyst Service description 2".as_bytes().to_vec(),
-			test_result_sample: "DeBio Genetic Analyst Service test_result_sample 2".as_bytes().to_vec(),
+			description: "DeBio Genetic Analyst Service description 2".as_bytes().to_vec().try_into().unwrap(),
+			test_result_sample: "DeBio Genetic Analyst Service test_result_sample 2".as_bytes().to_vec().try_into().unwrap(),
 		};
 	}: update_genetic_analyst_service(RawOrigin::Signed(caller), _genetic_analyst.services[0], new_genetic_analyst_service_info)
 
@@ -300,26 +300,26 @@ benchmarks! {
 		let caller_origin = T::RuntimeOrigin::from(RawOrigin::Signed(caller.clone()));
 		let genetic_analyst_info = GeneticAnalystInfo {
 			box_public_key: T::Hashing::hash("0xDb9Af2d1f3ADD2726A132AA7A65Cc9E6fC5761C3".as_bytes()),
-			first_name: "First Name".as_bytes().to_vec(),
-			last_name: "Last Name".as_bytes().to_vec(),
-			gender: "Gender".as_bytes().to_vec(),
+			first_name: "First Name".as_bytes().to_vec().try_into().unwrap(),
+			last_name: "Last Name".as_bytes().to_vec().try_into().unwrap(),
+			gender: "Gender".as_bytes().to_vec().try_into().unwrap(),
 			date_of_birth: <T as pallet_timestamp::pallet::Config>::Moment::default(),
-			email: "Email".as_bytes().to_vec(),
-			phone_number: "+6893026516".as_bytes().to_vec(),
-			specialization: "DeBio Genetic Analyst".as_bytes().to_vec(),
-			profile_link: "DeBio Genetic Analyst profile_link".as_bytes().to_vec(),
-			profile_image: Some("DeBio Genetic Analyst profile_image".as_bytes().to_vec()),
+			email: "Email".as_bytes().to_vec().try_into().unwrap(),
+			phone_number: "+6893026516".as_bytes().to_vec().try_into().unwrap(),
+			specialization: "DeBio Genetic Analyst".as_bytes().to_vec().try_into().unwrap(),
+			profile_link: "DeBio Genetic Analyst profile_link".as_bytes().to_vec().try_into().unwrap(),
+			profile_image: Some("DeBio Genetic Analyst profile_image".as_bytes().to_vec().try_into().unwrap()),
 		};
 		let _add_genetic_analysts = GeneticAnalysts::<T>::register_genetic_analyst(caller_origin.clone(), genetic_analyst_info);
 
 		let old_genetic_analyst_service_info = GeneticAnalystServiceInfo {
-			name: "DeBio Genetic Analyst Service name".as_bytes().to_vec(),
+			name: "DeBio Genetic Analyst Service name".as_bytes().to_vec().try_into().unwrap(),
 			prices_by_currency: vec![
 				PriceByCurrency::default()
-			],
+			].try_into().unwrap(),
 			expected_duration: ExpectedDuration::default(),
-			description: "DeBio Genetic Analyst Service description".as_bytes().to_vec(),
-			test_result_sample: "DeBio Genetic Analyst Service test_result_sample".as_bytes().to_vec(),
+			description: "DeBio Genetic Analyst Service description".as_bytes().to_vec().try_into().unwrap(),
+			test_result_sample: "DeBio Genetic Analyst Service test_result_sample".as_bytes().to_vec().try_into().unwrap(),
 		};
 		let _create_genetic_analyst_service = GeneticAnalystServices::<T>::create_genetic_analyst_service(caller_origin, old_genetic_analyst_service_info);
 
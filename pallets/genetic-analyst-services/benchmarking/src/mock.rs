@@ -29,7 +29,7 @@ frame_support::construct_runtime!(
 		GeneticAnalysts: genetic_analysts,
 		GeneticAnalystServices: genetic_analyst_services,
 		GeneticAnalysisOrders: genetic_analysis_orders,
-		GeneticAnalystQualifications: genetic_analyst_qualifications,
+		GeneticAnalystQualifications: credentials::<Instance4>,
 		UserProfile: user_profile,
 		RandomnessCollectiveFlip: pallet_randomness_collective_flip,
 		GeneticAnalysis: genetic_analysis,
@@ -136,12 +136,14 @@ impl pallet_timestamp::Config for Test {
 impl genetic_data::Config for Test {
 	type RuntimeEvent = RuntimeEvent;
 	type GeneticDataWeightInfo = ();
+	type MaxGeneticDataPerOwner = frame_support::traits::ConstU32<1_000>;
 }
 
 impl genetic_analysts::Config for Test {
 	type RuntimeEvent = RuntimeEvent;
 	type Currency = Balances;
 	type PalletId = GeneticAnalystPalletId;
+	type RequireVerifiedQualification = frame_support::traits::ConstBool<false>;
 	type GeneticAnalysisOrders = GeneticAnalysisOrders;
 	type GeneticAnalystServices = GeneticAnalystServices;
 	type GeneticAnalystQualifications = GeneticAnalystQualifications;
@@ -160,9 +162,12 @@ impl genetic_analyst_services::Config for Test {
 
 impl pallet_randomness_collective_flip::Config for Test {}
 
-impl genetic_analyst_qualifications::Config for Test {
+impl credentials::Config<credentials::Instance4> for Test {
 	type RuntimeEvent = RuntimeEvent;
-	type GeneticAnalystQualificationOwner = GeneticAnalysts;
+	type CredentialInfo = credentials::GeneticAnalystQualificationInfo;
+	type CredentialOwner =
+		credentials::legacy::LegacyGeneticAnalystQualificationOwner<GeneticAnalysts>;
+	type CredentialIssuers = ();
 	type WeightInfo = ();
 }
 
@@ -189,5 +194,6 @@ impl genetic_analysis_orders::Config for Test {
 	type GeneticAnalysis = GeneticAnalysis;
 	type GeneticAnalystServices = GeneticAnalystServices;
 	type GeneticAnalysisOrdersWeightInfo = ();
+	type PlatformFee = ();
 	type PalletId = GeneticAnalysisOrdersEscrowPalletId;
 }
@@ -22,18 +22,32 @@ pub mod weights;
 pub use interface::GeneticAnalystServiceInterface;
 use sp_std::prelude::*;
 
+/// Maximum length of the name and test result sample of a genetic analyst service
+pub const MAX_GENETIC_ANALYST_SERVICE_TEXT_LENGTH: u32 = 512;
+/// Maximum length of the description of a genetic analyst service
+pub const MAX_GENETIC_ANALYST_SERVICE_DESCRIPTION_LENGTH: u32 = 4096;
+/// Maximum number of currencies a genetic analyst service can be priced in
+pub const MAX_PRICES_BY_CURRENCY: u32 = 16;
+
+pub type GeneticAnalystServiceText =
+	BoundedVec<u8, ConstU32<MAX_GENETIC_ANALYST_SERVICE_TEXT_LENGTH>>;
+pub type GeneticAnalystServiceDescription =
+	BoundedVec<u8, ConstU32<MAX_GENETIC_ANALYST_SERVICE_DESCRIPTION_LENGTH>>;
+pub type PricesByCurrency<Balance> =
+	BoundedVec<PriceByCurrency<Balance>, ConstU32<MAX_PRICES_BY_CURRENCY>>;
+
 /// GeneticAnalystServiceInfo struct
 /// Information that is mutable by user
-#[derive(Encode, Decode, Clone, Default, RuntimeDebug, PartialEq, Eq, TypeInfo)]
+#[derive(Encode, Decode, Clone, Default, RuntimeDebug, PartialEq, Eq, TypeInfo, MaxEncodedLen)]
 pub struct GeneticAnalystServiceInfo<Balance> {
-	pub name: Vec<u8>,
-	pub prices_by_currency: Vec<PriceByCurrency<Balance>>,
+	pub name: GeneticAnalystServiceText,
+	pub prices_by_currency: PricesByCurrency<Balance>,
 	pub expected_duration: ExpectedDuration,
-	pub description: Vec<u8>,
-	pub test_result_sample: Vec<u8>,
+	pub description: GeneticAnalystServiceDescription,
+	pub test_result_sample: GeneticAnalystServiceText,
 }
 
-#[derive(Encode, Decode, Clone, Default, RuntimeDebug, PartialEq, Eq, TypeInfo)]
+#[derive(Encode, Decode, Clone, Default, RuntimeDebug, PartialEq, Eq, TypeInfo, MaxEncodedLen)]
 pub struct GeneticAnalystService<AccountId, Hash, Balance> {
 	pub id: Hash,
 	pub owner_id: AccountId,
@@ -52,7 +66,7 @@ impl<AccountId, Hash, Balance> GeneticAnalystService<AccountId, Hash, Balance> {
 		&self.owner_id
 	}
 
-	pub fn get_prices_by_currency(&self) -> &Vec<PriceByCurrency<Balance>> {
+	pub fn get_prices_by_currency(&self) -> &[PriceByCurrency<Balance>] {
 		&self.info.prices_by_currency
 	}
 }
@@ -68,13 +82,13 @@ where
 	fn get_owner_id(&self) -> &AccountId {
 		self.get_owner_id()
 	}
-	fn get_prices_by_currency(&self) -> &Vec<PriceByCurrency<Balance>> {
+	fn get_prices_by_currency(&self) -> &[PriceByCurrency<Balance>] {
 		self.get_prices_by_currency()
 	}
 }
 
 /// The current storage version.
-const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
+const STORAGE_VERSION: StorageVersion = StorageVersion::new(2);
 
 #[frame_support::pallet]
 pub mod pallet {
@@ -100,7 +114,6 @@ pub mod pallet {
 	#[pallet::pallet]
 	#[pallet::storage_version(STORAGE_VERSION)]
 	#[pallet::generate_store(pub(super) trait Store)]
-	#[pallet::without_storage_info]
 	pub struct Pallet<T>(_);
 
 	#[pallet::hooks]
@@ -1,14 +1,16 @@
 use crate::{
 	AccountIdOf, BalanceOf, Config, GeneticAnalystService, GeneticAnalystServiceInfo,
-	GeneticAnalystServices, HashOf, Pallet,
+	GeneticAnalystServiceInfoOf, GeneticAnalystServices, HashOf, Pallet, PricesByCurrency,
 };
 use frame_support::{
+	log,
 	pallet_prelude::{Decode, Encode},
 	traits::Get,
 	weights::Weight,
+	BoundedVec,
 };
 use primitives_duration::{DurationType, ExpectedDuration};
-use primitives_price_and_currency::PriceByCurrency;
+use primitives_price_and_currency::{CurrencyType, Price, PriceByCurrency};
 use sp_std::vec::Vec;
 
 pub fn migrate<T: Config>() -> Weight {
@@ -22,6 +24,11 @@ pub fn migrate<T: Config>() -> Weight {
 		version = StorageVersion::new(1);
 	}
 
+	if version < 2 {
+		weight = weight.saturating_add(version::v2::migrate::<T>());
+		version = StorageVersion::new(2);
+	}
+
 	version.put::<Pallet<T>>();
 	weight
 }
@@ -29,6 +36,93 @@ pub fn migrate<T: Config>() -> Weight {
 mod version {
 	use super::*;
 
+	#[derive(Encode, Decode)]
+	pub struct OldPrice<Balance> {
+		pub component: Vec<u8>,
+		pub value: Balance,
+	}
+
+	#[derive(Encode, Decode)]
+	pub struct OldPriceByCurrency<Balance> {
+		pub currency: CurrencyType,
+		pub total_price: Balance,
+		pub price_components: Vec<OldPrice<Balance>>,
+		pub additional_prices: Vec<OldPrice<Balance>>,
+	}
+
+	/// Genetic analyst service info fields before they were bounded
+	#[derive(Encode, Decode)]
+	pub struct UnboundedGeneticAnalystServiceInfo<Balance> {
+		pub name: Vec<u8>,
+		pub prices_by_currency: Vec<OldPriceByCurrency<Balance>>,
+		pub expected_duration: ExpectedDuration,
+		pub description: Vec<u8>,
+		pub test_result_sample: Vec<u8>,
+	}
+
+	/// Truncates `value` to the bound and logs the entry when it did not fit.
+	fn bounded<I, S: Get<u32>>(value: Vec<I>, entry: &str) -> BoundedVec<I, S> {
+		if value.len() > S::get() as usize {
+			log::warn!(
+				target: "runtime::genetic-analyst-services",
+				"{} exceeds its bound of {}, truncated from {} items",
+				entry,
+				S::get(),
+				value.len(),
+			);
+		}
+
+		BoundedVec::truncate_from(value)
+	}
+
+	fn bounded_prices<Balance>(prices: Vec<OldPrice<Balance>>) -> Vec<Price<Balance>> {
+		prices
+			.into_iter()
+			.map(|price| Price {
+				component: bounded(price.component, "price component"),
+				value: price.value,
+			})
+			.collect()
+	}
+
+	fn bounded_prices_by_currency<Balance>(
+		prices_by_currency: Vec<OldPriceByCurrency<Balance>>,
+	) -> PricesByCurrency<Balance> {
+		let prices_by_currency = prices_by_currency
+			.into_iter()
+			.map(|price_by_currency| PriceByCurrency {
+				currency: price_by_currency.currency,
+				total_price: price_by_currency.total_price,
+				price_components: bounded(
+					bounded_prices(price_by_currency.price_components),
+					"price components",
+				),
+				additional_prices: bounded(
+					bounded_prices(price_by_currency.additional_prices),
+					"additional prices",
+				),
+			})
+			.collect();
+
+		bounded(prices_by_currency, "prices by currency")
+	}
+
+	/// Legacy values that exceed a bound are truncated and logged.
+	pub fn bounded_genetic_analyst_service_info<T: Config>(
+		info: UnboundedGeneticAnalystServiceInfo<BalanceOf<T>>,
+	) -> GeneticAnalystServiceInfoOf<T> {
+		GeneticAnalystServiceInfo {
+			name: bounded(info.name, "genetic analyst service name"),
+			prices_by_currency: bounded_prices_by_currency(info.prices_by_currency),
+			expected_duration: info.expected_duration,
+			description: bounded(info.description, "genetic analyst service description"),
+			test_result_sample: bounded(
+				info.test_result_sample,
+				"genetic analyst service test result sample",
+			),
+		}
+	}
+
 	pub mod v1 {
 		use super::*;
 
@@ -44,7 +138,7 @@ mod version {
 			#[derive(Encode, Decode)]
 			pub struct OldGeneticAnalystServiceInfo<Balance> {
 				pub name: Vec<u8>,
-				pub prices_by_currency: Vec<PriceByCurrency<Balance>>,
+				pub prices_by_currency: Vec<OldPriceByCurrency<Balance>>,
 				pub expected_duration: OldExpectedDuration,
 				pub description: Vec<u8>,
 				pub test_result_sample: Vec<u8>,
@@ -64,23 +158,25 @@ mod version {
 				|_key, old_services: OldGeneticAnalystServiceOf<T>| {
 					weight = weight.saturating_add(T::DbWeight::get().reads_writes(1, 1));
 
-					let old_service_info = &old_services.info;
+					let old_service_info = old_services.info;
 
-					let old_expected_duration = &old_service_info.expected_duration;
+					let old_expected_duration = old_service_info.expected_duration;
 					let old_duration = old_expected_duration.duration;
-					let old_duration_type = old_expected_duration.duration_type.clone();
+					let old_duration_type = old_expected_duration.duration_type;
 					let expected_duration = ExpectedDuration {
 						duration: old_duration as u64,
 						duration_type: old_duration_type,
 					};
 
-					let service_info = GeneticAnalystServiceInfo {
-						name: old_service_info.name.clone(),
-						prices_by_currency: old_service_info.prices_by_currency.clone(),
-						expected_duration,
-						description: old_service_info.description.clone(),
-						test_result_sample: old_service_info.test_result_sample.clone(),
-					};
+					let service_info = bounded_genetic_analyst_service_info::<T>(
+						UnboundedGeneticAnalystServiceInfo {
+							name: old_service_info.name,
+							prices_by_currency: old_service_info.prices_by_currency,
+							expected_duration,
+							description: old_service_info.description,
+							test_result_sample: old_service_info.test_result_sample,
+						},
+					);
 
 					Some(GeneticAnalystService {
 						id: old_services.id,
@@ -93,4 +189,37 @@ mod version {
 			weight
 		}
 	}
+
+	pub mod v2 {
+		use super::*;
+
+		/// Genetic analyst service info fields and price components become bounded.
+		pub fn migrate<T: Config>() -> Weight {
+			let mut weight = T::DbWeight::get().writes(1);
+
+			#[derive(Encode, Decode)]
+			pub struct OldGeneticAnalystService<AccountId, Hash, Balance> {
+				pub id: Hash,
+				pub owner_id: AccountId,
+				pub info: UnboundedGeneticAnalystServiceInfo<Balance>,
+			}
+
+			pub type OldGeneticAnalystServiceOf<T> =
+				OldGeneticAnalystService<AccountIdOf<T>, HashOf<T>, BalanceOf<T>>;
+
+			GeneticAnalystServices::<T>::translate(
+				|_key, old_service: OldGeneticAnalystServiceOf<T>| {
+					weight = weight.saturating_add(T::DbWeight::get().reads_writes(1, 1));
+
+					Some(GeneticAnalystService {
+						id: old_service.id,
+						owner_id: old_service.owner_id,
+						info: bounded_genetic_analyst_service_info::<T>(old_service.info),
+					})
+				},
+			);
+
+			weight
+		}
+	}
 }
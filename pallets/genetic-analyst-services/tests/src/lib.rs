@@ -4,197 +4,324 @@ mod mock;
 mod tests {
 	use crate::mock::*;
 
-	use genetic_analyst_services::{Error, GeneticAnalystService, GeneticAnalystServiceInfo};
+	use genetic_analyst_services::{
+		Error, GeneticAnalystService, GeneticAnalystServiceInfo,
+		GeneticAnalystServices as GeneticAnalystServicesStorage,
+		MAX_GENETIC_ANALYST_SERVICE_TEXT_LENGTH,
+	};
 	use genetic_analysts::GeneticAnalystInfo;
 
+	use codec::Encode;
 	use frame_support::{
 		assert_noop, assert_ok,
 		sp_runtime::traits::{Hash, Keccak256},
+		storage::unhashed,
+		traits::StorageVersion,
 	};
 	use primitives_duration::ExpectedDuration;
-	use primitives_price_and_currency::PriceByCurrency;
+	use primitives_price_and_currency::{CurrencyType, PriceByCurrency};
 
 	fn create_twenty_services() -> Vec<GeneticAnalystServiceInfo<u128>> {
 		vec![
 			GeneticAnalystServiceInfo {
-				name: "DeBio Genetic Analyst Service name".as_bytes().to_vec(),
-				prices_by_currency: vec![PriceByCurrency::default()],
+				name: "DeBio Genetic Analyst Service name".as_bytes().to_vec().try_into().unwrap(),
+				prices_by_currency: vec![PriceByCurrency::default()].try_into().unwrap(),
 				expected_duration: ExpectedDuration::default(),
-				description: "DeBio Genetic Analyst Service description".as_bytes().to_vec(),
+				description: "DeBio Genetic Analyst Service description"
+					.as_bytes()
+					.to_vec()
+					.try_into()
+					.unwrap(),
 				test_result_sample: "DeBio Genetic Analyst Service test_result_sample"
 					.as_bytes()
-					.to_vec(),
+					.to_vec()
+					.try_into()
+					.unwrap(),
 			},
 			GeneticAnalystServiceInfo {
-				name: "DeBio Genetic Analyst Service name".as_bytes().to_vec(),
-				prices_by_currency: vec![PriceByCurrency::default()],
+				name: "DeBio Genetic Analyst Service name".as_bytes().to_vec().try_into().unwrap(),
+				prices_by_currency: vec![PriceByCurrency::default()].try_into().unwrap(),
 				expected_duration: ExpectedDuration::default(),
-				description: "DeBio Genetic Analyst Service description".as_bytes().to_vec(),
+				description: "DeBio Genetic Analyst Service description"
+					.as_bytes()
+					.to_vec()
+					.try_into()
+					.unwrap(),
 				test_result_sample: "DeBio Genetic Analyst Service test_result_sample"
 					.as_bytes()
-					.to_vec(),
+					.to_vec()
+					.try_into()
+					.unwrap(),
 			},
 			GeneticAnalystServiceInfo {
-				name: "DeBio Genetic Analyst Service name".as_bytes().to_vec(),
-				prices_by_currency: vec![PriceByCurrency::default()],
+				name: "DeBio Genetic Analyst Service name".as_bytes().to_vec().try_into().unwrap(),
+				prices_by_currency: vec![PriceByCurrency::default()].try_into().unwrap(),
 				expected_duration: ExpectedDuration::default(),
-				description: "DeBio Genetic Analyst Service description".as_bytes().to_vec(),
+				description: "DeBio Genetic Analyst Service description"
+					.as_bytes()
+					.to_vec()
+					.try_into()
+					.unwrap(),
 				test_result_sample: "DeBio Genetic Analyst Service test_result_sample"
 					.as_bytes()
-					.to_vec(),
+					.to_vec()
+					.try_into()
+					.unwrap(),
 			},
 			GeneticAnalystServiceInfo {
-				name: "DeBio Genetic Analyst Service name".as_bytes().to_vec(),
-				prices_by_currency: vec![PriceByCurrency::default()],
+				name: "DeBio Genetic Analyst Service name".as_bytes().to_vec().try_into().unwrap(),
+				prices_by_currency: vec![PriceByCurrency::default()].try_into().unwrap(),
 				expected_duration: ExpectedDuration::default(),
-				description: "DeBio Genetic Analyst Service description".as_bytes().to_vec(),
+				description: "DeBio Genetic Analyst Service description"
+					.as_bytes()
+					.to_vec()
+					.try_into()
+					.unwrap(),
 				test_result_sample: "DeBio Genetic Analyst Service test_result_sample"
 					.as_bytes()
-					.to_vec(),
+					.to_vec()
+					.try_into()
+					.unwrap(),
 			},
 			GeneticAnalystServiceInfo {
-				name: "DeBio Genetic Analyst Service name".as_bytes().to_vec(),
-				prices_by_currency: vec![PriceByCurrency::default()],
+				name: "DeBio Genetic Analyst Service name".as_bytes().to_vec().try_into().unwrap(),
+				prices_by_currency: vec![PriceByCurrency::default()].try_into().unwrap(),
 				expected_duration: ExpectedDuration::default(),
-				description: "DeBio Genetic Analyst Service description".as_bytes().to_vec(),
+				description: "DeBio Genetic Analyst Service description"
+					.as_bytes()
+					.to_vec()
+					.try_into()
+					.unwrap(),
 				test_result_sample: "DeBio Genetic Analyst Service test_result_sample"
 					.as_bytes()
-					.to_vec(),
+					.to_vec()
+					.try_into()
+					.unwrap(),
 			},
 			GeneticAnalystServiceInfo {
-				name: "DeBio Genetic Analyst Service name".as_bytes().to_vec(),
-				prices_by_currency: vec![PriceByCurrency::default()],
+				name: "DeBio Genetic Analyst Service name".as_bytes().to_vec().try_into().unwrap(),
+				prices_by_currency: vec![PriceByCurrency::default()].try_into().unwrap(),
 				expected_duration: ExpectedDuration::default(),
-				description: "DeBio Genetic Analyst Service description".as_bytes().to_vec(),
+				description: "DeBio Genetic Analyst Service description"
+					.as_bytes()
+					.to_vec()
+					.try_into()
+					.unwrap(),
 				test_result_sample: "DeBio Genetic Analyst Service test_result_sample"
 					.as_bytes()
-					.to_vec(),
+					.to_vec()
+					.try_into()
+					.unwrap(),
 			},
 			GeneticAnalystServiceInfo {
-				name: "DeBio Genetic Analyst Service name".as_bytes().to_vec(),
-				prices_by_currency: vec![PriceByCurrency::default()],
+				name: "DeBio Genetic Analyst Service name".as_bytes().to_vec().try_into().unwrap(),
+				prices_by_currency: vec![PriceByCurrency::default()].try_into().unwrap(),
 				expected_duration: ExpectedDuration::default(),
-				description: "DeBio Genetic Analyst Service description".as_bytes().to_vec(),
+				description: "DeBio Genetic Analyst Service description"
+					.as_bytes()
+					.to_vec()
+					.try_into()
+					.unwrap(),
 				test_result_sample: "DeBio Genetic Analyst Service test_result_sample"
 					.as_bytes()
-					.to_vec(),
+					.to_vec()
+					.try_into()
+					.unwrap(),
 			},
 			GeneticAnalystServiceInfo {
-				name: "DeBio Genetic Analyst Service name".as_bytes().to_vec(),
-				prices_by_currency: vec![PriceByCurrency::default()],
+				name: "DeBio Genetic Analyst Service name".as_bytes().to_vec().try_into().unwrap(),
+				prices_by_currency: vec![PriceByCurrency::default()].try_into().unwrap(),
 				expected_duration: ExpectedDuration::default(),
-				description: "DeBio Genetic Analyst Service description".as_bytes().to_vec(),
+				description: "DeBio Genetic Analyst Service description"
+					.as_bytes()
+					.to_vec()
+					.try_into()
+					.unwrap(),
 				test_result_sample: "DeBio Genetic Analyst Service test_result_sample"
 					.as_bytes()
-					.to_vec(),
+					.to_vec()
+					.try_into()
+					.unwrap(),
 			},
 			GeneticAnalystServiceInfo {
-				name: "DeBio Genetic Analyst Service name".as_bytes().to_vec(),
-				prices_by_currency: vec![PriceByCurrency::default()],
+				name: "DeBio Genetic Analyst Service name".as_bytes().to_vec().try_into().unwrap(),
+				prices_by_currency: vec![PriceByCurrency::default()].try_into().unwrap(),
 				expected_duration: ExpectedDuration::default(),
-				description: "DeBio Genetic Analyst Service description".as_bytes().to_vec(),
+				description: "DeBio Genetic Analyst Service description"
+					.as_bytes()
+					.to_vec()
+					.try_into()
+					.unwrap(),
 				test_result_sample: "DeBio Genetic Analyst Service test_result_sample"
 					.as_bytes()
-					.to_vec(),
+					.to_vec()
+					.try_into()
+					.unwrap(),
 			},
 			GeneticAnalystServiceInfo {
-				name: "DeBio Genetic Analyst Service name".as_bytes().to_vec(),
-				prices_by_currency: vec![PriceByCurrency::default()],
+				name: "DeBio Genetic Analyst Service name".as_bytes().to_vec().try_into().unwrap(),
+				prices_by_currency: vec![PriceByCurrency::default()].try_into().unwrap(),
 				expected_duration: ExpectedDuration::default(),
-				description: "DeBio Genetic Analyst Service description".as_bytes().to_vec(),
+				description: "DeBio Genetic Analyst Service description"
+					.as_bytes()
+					.to_vec()
+					.try_into()
+					.unwrap(),
 				test_result_sample: "DeBio Genetic Analyst Service test_result_sample"
 					.as_bytes()
-					.to_vec(),
+					.to_vec()
+					.try_into()
+					.unwrap(),
 			},
 			GeneticAnalystServiceInfo {
-				name: "DeBio Genetic Analyst Service name".as_bytes().to_vec(),
-				prices_by_currency: vec![PriceByCurrency::default()],
+				name: "DeBio Genetic Analyst Service name".as_bytes().to_vec().try_into().unwrap(),
+				prices_by_currency: vec![PriceByCurrency::default()].try_into().unwrap(),
 				expected_duration: ExpectedDuration::default(),
-				description: "DeBio Genetic Analyst Service description".as_bytes().to_vec(),
+				description: "DeBio Genetic Analyst Service description"
+					.as_bytes()
+					.to_vec()
+					.try_into()
+					.unwrap(),
 				test_result_sample: "DeBio Genetic Analyst Service test_result_sample"
 					.as_bytes()
-					.to_vec(),
+					.to_vec()
+					.try_into()
+					.unwrap(),
 			},
 			GeneticAnalystServiceInfo {
-				name: "DeBio Genetic Analyst Service name".as_bytes().to_vec(),
-				prices_by_currency: vec![PriceByCurrency::default()],
+				name: "DeBio Genetic Analyst Service name".as_bytes().to_vec().try_into().unwrap(),
+				prices_by_currency: vec![PriceByCurrency::default()].try_into().unwrap(),
 				expected_duration: ExpectedDuration::default(),
-				description: "DeBio Genetic Analyst Service description".as_bytes().to_vec(),
+				description: "DeBio Genetic Analyst Service description"
+					.as_bytes()
+					.to_vec()
+					.try_into()
+					.unwrap(),
 				test_result_sample: "DeBio Genetic Analyst Service test_result_sample"
 					.as_bytes()
-					.to_vec(),
+					.to_vec()
+					.try_into()
+					.unwrap(),
 			},
 			GeneticAnalystServiceInfo {
-				name: "DeBio Genetic Analyst Service name".as_bytes().to_vec(),
-				prices_by_currency: vec![PriceByCurrency::default()],
+				name: "DeBio Genetic Analyst Service name".as_bytes().to_vec().try_into().unwrap(),
+				prices_by_currency: vec![PriceByCurrency::default()].try_into().unwrap(),
 				expected_duration: ExpectedDuration::default(),
-				description: "DeBio Genetic Analyst Service description".as_bytes().to_vec(),
+				description: "DeBio Genetic Analyst Service description"
+					.as_bytes()
+					.to_vec()
+					.try_into()
+					.unwrap(),
 				test_result_sample: "DeBio Genetic Analyst Service test_result_sample"
 					.as_bytes()
-					.to_vec(),
+					.to_vec()
+					.try_into()
+					.unwrap(),
 			},
 			GeneticAnalystServiceInfo {
-				name: "DeBio Genetic Analyst Service name".as_bytes().to_vec(),
-				prices_by_currency: vec![PriceByCurrency::default()],
+				name: "DeBio Genetic Analyst Service name".as_bytes().to_vec().try_into().unwrap(),
+				prices_by_currency: vec![PriceByCurrency::default()].try_into().unwrap(),
 				expected_duration: ExpectedDuration::default(),
-				description: "DeBio Genetic Analyst Service description".as_bytes().to_vec(),
+				description: "DeBio Genetic Analyst Service description"
+					.as_bytes()
+					.to_vec()
+					.try_into()
+					.unwrap(),
 				test_result_sample: "DeBio Genetic Analyst Service test_result_sample"
 					.as_bytes()
-					.to_vec(),
+					.to_vec()
+					.try_into()
+					.unwrap(),
 			},
 			GeneticAnalystServiceInfo {
-				name: "DeBio Genetic Analyst Service name".as_bytes().to_vec(),
-				prices_by_currency: vec![PriceByCurrency::default()],
+				name: "DeBio Genetic Analyst Service name".as_bytes().to_vec().try_into().unwrap(),
+				prices_by_currency: vec![PriceByCurrency::default()].try_into().unwrap(),
 				expected_duration: ExpectedDuration::default(),
-				description: "DeBio Genetic Analyst Service description".as_bytes().to_vec(),
+				description: "DeBio Genetic Analyst Service description"
+					.as_bytes()
+					.to_vec()
+					.try_into()
+					.unwrap(),
 				test_result_sample: "DeBio Genetic Analyst Service test_result_sample"
 					.as_bytes()
-					.to_vec(),
+					.to_vec()
+					.try_into()
+					.unwrap(),
 			},
 			GeneticAnalystServiceInfo {
-				name: "DeBio Genetic Analyst Service name".as_bytes().to_vec(),
-				prices_by_currency: vec![PriceByCurrency::default()],
+				name: "DeBio Genetic Analyst Service name".as_bytes().to_vec().try_into().unwrap(),
+				prices_by_currency: vec![PriceByCurrency::default()].try_into().unwrap(),
 				expected_duration: ExpectedDuration::default(),
-				description: "DeBio Genetic Analyst Service description".as_bytes().to_vec(),
+				description: "DeBio Genetic Analyst Service description"
+					.as_bytes()
+					.to_vec()
+					.try_into()
+					.unwrap(),
 				test_result_sample: "DeBio Genetic Analyst Service test_result_sample"
 					.as_bytes()
-					.to_vec(),
+					.to_vec()
+					.try_into()
+					.unwrap(),
 			},
 			GeneticAnalystServiceInfo {
-				name: "DeBio Genetic Analyst Service name".as_bytes().to_vec(),
-				prices_by_currency: vec![PriceByCurrency::default()],
+				name: "DeBio Genetic Analyst Service name".as_bytes().to_vec().try_into().unwrap(),
+				prices_by_currency: vec![PriceByCurrency::default()].try_into().unwrap(),
 				expected_duration: ExpectedDuration::default(),
-				description: "DeBio Genetic Analyst Service description".as_bytes().to_vec(),
+				description: "DeBio Genetic Analyst Service description"
+					.as_bytes()
+					.to_vec()
+					.try_into()
+					.unwrap(),
 				test_result_sample: "DeBio Genetic Analyst Service test_result_sample"
 					.as_bytes()
-					.to_vec(),
+					.to_vec()
+					.try_into()
+					.unwrap(),
 			},
 			GeneticAnalystServiceInfo {
-				name: "DeBio Genetic Analyst Service name".as_bytes().to_vec(),
-				prices_by_currency: vec![PriceByCurrency::default()],
+				name: "DeBio Genetic Analyst Service name".as_bytes().to_vec().try_into().unwrap(),
+				prices_by_currency: vec![PriceByCurrency::default()].try_into().unwrap(),
 				expected_duration: ExpectedDuration::default(),
-				description: "DeBio Genetic Analyst Service description".as_bytes().to_vec(),
+				description: "DeBio Genetic Analyst Service description"
+					.as_bytes()
+					.to_vec()
+					.try_into()
+					.unwrap(),
 				test_result_sample: "DeBio Genetic Analyst Service test_result_sample"
 					.as_bytes()
-					.to_vec(),
+					.to_vec()
+					.try_into()
+					.unwrap(),
 			},
 			GeneticAnalystServiceInfo {
-				name: "DeBio Genetic Analyst Service name".as_bytes().to_vec(),
-				prices_by_currency: vec![PriceByCurrency::default()],
+				name: "DeBio Genetic Analyst Service name".as_bytes().to_vec().try_into().unwrap(),
+				prices_by_currency: vec![PriceByCurrency::default()].try_into().unwrap(),
 				expected_duration: ExpectedDuration::default(),
-				description: "DeBio Genetic Analyst Service description".as_bytes().to_vec(),
+				description: "DeBio Genetic Analyst Service description"
+					.as_bytes()
+					.to_vec()
+					.try_into()
+					.unwrap(),
 				test_result_sample: "DeBio Genetic Analyst Service test_result_sample"
 					.as_bytes()
-					.to_vec(),
+					.to_vec()
+					.try_into()
+					.unwrap(),
 			},
 			GeneticAnalystServiceInfo {
-				name: "DeBio Genetic Analyst Service name".as_bytes().to_vec(),
-				prices_by_currency: vec![PriceByCurrency::default()],
+				name: "DeBio Genetic Analyst Service name".as_bytes().to_vec().try_into().unwrap(),
+				prices_by_currency: vec![PriceByCurrency::default()].try_into().unwrap(),
 				expected_duration: ExpectedDuration::default(),
-				description: "DeBio Genetic Analyst Service description".as_bytes().to_vec(),
+				description: "DeBio Genetic Analyst Service description"
+					.as_bytes()
+					.to_vec()
+					.try_into()
+					.unwrap(),
 				test_result_sample: "DeBio Genetic Analyst Service test_result_sample"
 					.as_bytes()
-					.to_vec(),
+					.to_vec()
+					.try_into()
+					.unwrap(),
 			},
 		]
 	}
@@ -202,13 +329,19 @@ mod tests {
 	fn create_twenty_one_services() -> Vec<GeneticAnalystServiceInfo<u128>> {
 		let mut twenty_services = create_twenty_services();
 		twenty_services.push(GeneticAnalystServiceInfo {
-			name: "DeBio Genetic Analyst Service name".as_bytes().to_vec(),
-			prices_by_currency: vec![PriceByCurrency::default()],
+			name: "DeBio Genetic Analyst Service name".as_bytes().to_vec().try_into().unwrap(),
+			prices_by_currency: vec![PriceByCurrency::default()].try_into().unwrap(),
 			expected_duration: ExpectedDuration::default(),
-			description: "DeBio Genetic Analyst Service description".as_bytes().to_vec(),
+			description: "DeBio Genetic Analyst Service description"
+				.as_bytes()
+				.to_vec()
+				.try_into()
+				.unwrap(),
 			test_result_sample: "DeBio Genetic Analyst Service test_result_sample"
 				.as_bytes()
-				.to_vec(),
+				.to_vec()
+				.try_into()
+				.unwrap(),
 		});
 		twenty_services
 	}
@@ -222,15 +355,25 @@ mod tests {
 					box_public_key: Keccak256::hash(
 						"0xDb9Af2d1f3ADD2726A132AA7A65Cc9E6fC5761C3".as_bytes(),
 					),
-					first_name: "First Name".as_bytes().to_vec(),
-					last_name: "Last Name".as_bytes().to_vec(),
-					gender: "Gender".as_bytes().to_vec(),
+					first_name: "First Name".as_bytes().to_vec().try_into().unwrap(),
+					last_name: "Last Name".as_bytes().to_vec().try_into().unwrap(),
+					gender: "Gender".as_bytes().to_vec().try_into().unwrap(),
 					date_of_birth: 0,
-					email: "Email".as_bytes().to_vec(),
-					phone_number: "+6893026516".as_bytes().to_vec(),
-					specialization: "DeBio Genetic Analyst".as_bytes().to_vec(),
-					profile_link: "DeBio Genetic Analyst profile_link".as_bytes().to_vec(),
-					profile_image: Some("DeBio Genetic Analyst profile_image".as_bytes().to_vec()),
+					email: "Email".as_bytes().to_vec().try_into().unwrap(),
+					phone_number: "+6893026516".as_bytes().to_vec().try_into().unwrap(),
+					specialization: "DeBio Genetic Analyst".as_bytes().to_vec().try_into().unwrap(),
+					profile_link: "DeBio Genetic Analyst profile_link"
+						.as_bytes()
+						.to_vec()
+						.try_into()
+						.unwrap(),
+					profile_image: Some(
+						"DeBio Genetic Analyst profile_image"
+							.as_bytes()
+							.to_vec()
+							.try_into()
+							.unwrap()
+					),
 				}
 			));
 
@@ -242,13 +385,23 @@ mod tests {
 			assert_ok!(GeneticAnalystServices::create_genetic_analyst_service(
 				RuntimeOrigin::signed(1),
 				GeneticAnalystServiceInfo {
-					name: "DeBio Genetic Analyst Service name".as_bytes().to_vec(),
-					prices_by_currency: vec![PriceByCurrency::default()],
+					name: "DeBio Genetic Analyst Service name"
+						.as_bytes()
+						.to_vec()
+						.try_into()
+						.unwrap(),
+					prices_by_currency: vec![PriceByCurrency::default()].try_into().unwrap(),
 					expected_duration: ExpectedDuration::default(),
-					description: "DeBio Genetic Analyst Service description".as_bytes().to_vec(),
+					description: "DeBio Genetic Analyst Service description"
+						.as_bytes()
+						.to_vec()
+						.try_into()
+						.unwrap(),
 					test_result_sample: "DeBio Genetic Analyst Service test_result_sample"
 						.as_bytes()
-						.to_vec(),
+						.to_vec()
+						.try_into()
+						.unwrap(),
 				},
 			));
 
@@ -260,15 +413,23 @@ mod tests {
 					id: genetic_analyst.services[0],
 					owner_id: 1,
 					info: GeneticAnalystServiceInfo {
-						name: "DeBio Genetic Analyst Service name".as_bytes().to_vec(),
-						prices_by_currency: vec![PriceByCurrency::default()],
+						name: "DeBio Genetic Analyst Service name"
+							.as_bytes()
+							.to_vec()
+							.try_into()
+							.unwrap(),
+						prices_by_currency: vec![PriceByCurrency::default()].try_into().unwrap(),
 						expected_duration: ExpectedDuration::default(),
 						description: "DeBio Genetic Analyst Service description"
 							.as_bytes()
-							.to_vec(),
+							.to_vec()
+							.try_into()
+							.unwrap(),
 						test_result_sample: "DeBio Genetic Analyst Service test_result_sample"
 							.as_bytes()
-							.to_vec(),
+							.to_vec()
+							.try_into()
+							.unwrap(),
 					},
 				})
 			);
@@ -286,15 +447,25 @@ mod tests {
 					box_public_key: Keccak256::hash(
 						"0xDb9Af2d1f3ADD2726A132AA7A65Cc9E6fC5761C3".as_bytes(),
 					),
-					first_name: "First Name".as_bytes().to_vec(),
-					last_name: "Last Name".as_bytes().to_vec(),
-					gender: "Gender".as_bytes().to_vec(),
+					first_name: "First Name".as_bytes().to_vec().try_into().unwrap(),
+					last_name: "Last Name".as_bytes().to_vec().try_into().unwrap(),
+					gender: "Gender".as_bytes().to_vec().try_into().unwrap(),
 					date_of_birth: 0,
-					email: "Email".as_bytes().to_vec(),
-					phone_number: "+6893026516".as_bytes().to_vec(),
-					specialization: "DeBio Genetic Analyst".as_bytes().to_vec(),
-					profile_link: "DeBio Genetic Analyst profile_link".as_bytes().to_vec(),
-					profile_image: Some("DeBio Genetic Analyst profile_image".as_bytes().to_vec()),
+					email: "Email".as_bytes().to_vec().try_into().unwrap(),
+					phone_number: "+6893026516".as_bytes().to_vec().try_into().unwrap(),
+					specialization: "DeBio Genetic Analyst".as_bytes().to_vec().try_into().unwrap(),
+					profile_link: "DeBio Genetic Analyst profile_link"
+						.as_bytes()
+						.to_vec()
+						.try_into()
+						.unwrap(),
+					profile_image: Some(
+						"DeBio Genetic Analyst profile_image"
+							.as_bytes()
+							.to_vec()
+							.try_into()
+							.unwrap()
+					),
 				}
 			));
 
@@ -316,15 +487,23 @@ mod tests {
 					id: genetic_analyst.services[0],
 					owner_id: 1,
 					info: GeneticAnalystServiceInfo {
-						name: "DeBio Genetic Analyst Service name".as_bytes().to_vec(),
-						prices_by_currency: vec![PriceByCurrency::default()],
+						name: "DeBio Genetic Analyst Service name"
+							.as_bytes()
+							.to_vec()
+							.try_into()
+							.unwrap(),
+						prices_by_currency: vec![PriceByCurrency::default()].try_into().unwrap(),
 						expected_duration: ExpectedDuration::default(),
 						description: "DeBio Genetic Analyst Service description"
 							.as_bytes()
-							.to_vec(),
+							.to_vec()
+							.try_into()
+							.unwrap(),
 						test_result_sample: "DeBio Genetic Analyst Service test_result_sample"
 							.as_bytes()
-							.to_vec(),
+							.to_vec()
+							.try_into()
+							.unwrap(),
 					},
 				})
 			);
@@ -345,15 +524,25 @@ mod tests {
 					box_public_key: Keccak256::hash(
 						"0xDb9Af2d1f3ADD2726A132AA7A65Cc9E6fC5761C3".as_bytes(),
 					),
-					first_name: "First Name".as_bytes().to_vec(),
-					last_name: "Last Name".as_bytes().to_vec(),
-					gender: "Gender".as_bytes().to_vec(),
+					first_name: "First Name".as_bytes().to_vec().try_into().unwrap(),
+					last_name: "Last Name".as_bytes().to_vec().try_into().unwrap(),
+					gender: "Gender".as_bytes().to_vec().try_into().unwrap(),
 					date_of_birth: 0,
-					email: "Email".as_bytes().to_vec(),
-					phone_number: "+6893026516".as_bytes().to_vec(),
-					specialization: "DeBio Genetic Analyst".as_bytes().to_vec(),
-					profile_link: "DeBio Genetic Analyst profile_link".as_bytes().to_vec(),
-					profile_image: Some("DeBio Genetic Analyst profile_image".as_bytes().to_vec()),
+					email: "Email".as_bytes().to_vec().try_into().unwrap(),
+					phone_number: "+6893026516".as_bytes().to_vec().try_into().unwrap(),
+					specialization: "DeBio Genetic Analyst".as_bytes().to_vec().try_into().unwrap(),
+					profile_link: "DeBio Genetic Analyst profile_link"
+						.as_bytes()
+						.to_vec()
+						.try_into()
+						.unwrap(),
+					profile_image: Some(
+						"DeBio Genetic Analyst profile_image"
+							.as_bytes()
+							.to_vec()
+							.try_into()
+							.unwrap()
+					),
 				}
 			));
 
@@ -365,13 +554,23 @@ mod tests {
 			assert_ok!(GeneticAnalystServices::create_genetic_analyst_service(
 				RuntimeOrigin::signed(1),
 				GeneticAnalystServiceInfo {
-					name: "DeBio Genetic Analyst Service name".as_bytes().to_vec(),
-					prices_by_currency: vec![PriceByCurrency::default()],
+					name: "DeBio Genetic Analyst Service name"
+						.as_bytes()
+						.to_vec()
+						.try_into()
+						.unwrap(),
+					prices_by_currency: vec![PriceByCurrency::default()].try_into().unwrap(),
 					expected_duration: ExpectedDuration::default(),
-					description: "DeBio Genetic Analyst Service description".as_bytes().to_vec(),
+					description: "DeBio Genetic Analyst Service description"
+						.as_bytes()
+						.to_vec()
+						.try_into()
+						.unwrap(),
 					test_result_sample: "DeBio Genetic Analyst Service test_result_sample"
 						.as_bytes()
-						.to_vec(),
+						.to_vec()
+						.try_into()
+						.unwrap(),
 				},
 			));
 
@@ -381,13 +580,23 @@ mod tests {
 				RuntimeOrigin::signed(1),
 				genetic_analyst.services[0],
 				GeneticAnalystServiceInfo {
-					name: "DeBio Genetic Analyst Service name 2".as_bytes().to_vec(),
-					prices_by_currency: vec![PriceByCurrency::default()],
+					name: "DeBio Genetic Analyst Service name 2"
+						.as_bytes()
+						.to_vec()
+						.try_into()
+						.unwrap(),
+					prices_by_currency: vec![PriceByCurrency::default()].try_into().unwrap(),
 					expected_duration: ExpectedDuration::default(),
-					description: "DeBio Genetic Analyst Service description 2".as_bytes().to_vec(),
+					description: "DeBio Genetic Analyst Service description 2"
+						.as_bytes()
+						.to_vec()
+						.try_into()
+						.unwrap(),
 					test_result_sample: "DeBio Genetic Analyst Service test_result_sample 2"
 						.as_bytes()
-						.to_vec(),
+						.to_vec()
+						.try_into()
+						.unwrap(),
 				}
 			));
 
@@ -397,15 +606,23 @@ mod tests {
 					id: genetic_analyst.services[0],
 					owner_id: 1,
 					info: GeneticAnalystServiceInfo {
-						name: "DeBio Genetic Analyst Service name 2".as_bytes().to_vec(),
-						prices_by_currency: vec![PriceByCurrency::default()],
+						name: "DeBio Genetic Analyst Service name 2"
+							.as_bytes()
+							.to_vec()
+							.try_into()
+							.unwrap(),
+						prices_by_currency: vec![PriceByCurrency::default()].try_into().unwrap(),
 						expected_duration: ExpectedDuration::default(),
 						description: "DeBio Genetic Analyst Service description 2"
 							.as_bytes()
-							.to_vec(),
+							.to_vec()
+							.try_into()
+							.unwrap(),
 						test_result_sample: "DeBio Genetic Analyst Service test_result_sample 2"
 							.as_bytes()
-							.to_vec(),
+							.to_vec()
+							.try_into()
+							.unwrap(),
 					}
 				})
 			);
@@ -423,15 +640,25 @@ mod tests {
 					box_public_key: Keccak256::hash(
 						"0xDb9Af2d1f3ADD2726A132AA7A65Cc9E6fC5761C3".as_bytes(),
 					),
-					first_name: "First Name".as_bytes().to_vec(),
-					last_name: "Last Name".as_bytes().to_vec(),
-					gender: "Gender".as_bytes().to_vec(),
+					first_name: "First Name".as_bytes().to_vec().try_into().unwrap(),
+					last_name: "Last Name".as_bytes().to_vec().try_into().unwrap(),
+					gender: "Gender".as_bytes().to_vec().try_into().unwrap(),
 					date_of_birth: 0,
-					email: "Email".as_bytes().to_vec(),
-					phone_number: "+6893026516".as_bytes().to_vec(),
-					specialization: "DeBio Genetic Analyst".as_bytes().to_vec(),
-					profile_link: "DeBio Genetic Analyst profile_link".as_bytes().to_vec(),
-					profile_image: Some("DeBio Genetic Analyst profile_image".as_bytes().to_vec()),
+					email: "Email".as_bytes().to_vec().try_into().unwrap(),
+					phone_number: "+6893026516".as_bytes().to_vec().try_into().unwrap(),
+					specialization: "DeBio Genetic Analyst".as_bytes().to_vec().try_into().unwrap(),
+					profile_link: "DeBio Genetic Analyst profile_link"
+						.as_bytes()
+						.to_vec()
+						.try_into()
+						.unwrap(),
+					profile_image: Some(
+						"DeBio Genetic Analyst profile_image"
+							.as_bytes()
+							.to_vec()
+							.try_into()
+							.unwrap()
+					),
 				}
 			));
 
@@ -443,13 +670,23 @@ mod tests {
 			assert_ok!(GeneticAnalystServices::create_genetic_analyst_service(
 				RuntimeOrigin::signed(1),
 				GeneticAnalystServiceInfo {
-					name: "DeBio Genetic Analyst Service name".as_bytes().to_vec(),
-					prices_by_currency: vec![PriceByCurrency::default()],
+					name: "DeBio Genetic Analyst Service name"
+						.as_bytes()
+						.to_vec()
+						.try_into()
+						.unwrap(),
+					prices_by_currency: vec![PriceByCurrency::default()].try_into().unwrap(),
 					expected_duration: ExpectedDuration::default(),
-					description: "DeBio Genetic Analyst Service description".as_bytes().to_vec(),
+					description: "DeBio Genetic Analyst Service description"
+						.as_bytes()
+						.to_vec()
+						.try_into()
+						.unwrap(),
 					test_result_sample: "DeBio Genetic Analyst Service test_result_sample"
 						.as_bytes()
-						.to_vec(),
+						.to_vec()
+						.try_into()
+						.unwrap(),
 				},
 			));
 
@@ -471,15 +708,23 @@ mod tests {
 				GeneticAnalystServices::create_genetic_analyst_service(
 					RuntimeOrigin::signed(1),
 					GeneticAnalystServiceInfo {
-						name: "DeBio Genetic Analyst Service name".as_bytes().to_vec(),
-						prices_by_currency: vec![PriceByCurrency::default()],
+						name: "DeBio Genetic Analyst Service name"
+							.as_bytes()
+							.to_vec()
+							.try_into()
+							.unwrap(),
+						prices_by_currency: vec![PriceByCurrency::default()].try_into().unwrap(),
 						expected_duration: ExpectedDuration::default(),
 						description: "DeBio Genetic Analyst Service description"
 							.as_bytes()
-							.to_vec(),
+							.to_vec()
+							.try_into()
+							.unwrap(),
 						test_result_sample: "DeBio Genetic Analyst Service test_result_sample"
 							.as_bytes()
-							.to_vec(),
+							.to_vec()
+							.try_into()
+							.unwrap(),
 					},
 				),
 				Error::<Test>::NotAllowedToCreate
@@ -509,15 +754,25 @@ mod tests {
 					box_public_key: Keccak256::hash(
 						"0xDb9Af2d1f3ADD2726A132AA7A65Cc9E6fC5761C3".as_bytes(),
 					),
-					first_name: "First Name".as_bytes().to_vec(),
-					last_name: "Last Name".as_bytes().to_vec(),
-					gender: "Gender".as_bytes().to_vec(),
+					first_name: "First Name".as_bytes().to_vec().try_into().unwrap(),
+					last_name: "Last Name".as_bytes().to_vec().try_into().unwrap(),
+					gender: "Gender".as_bytes().to_vec().try_into().unwrap(),
 					date_of_birth: 0,
-					email: "Email".as_bytes().to_vec(),
-					phone_number: "+6893026516".as_bytes().to_vec(),
-					specialization: "DeBio Genetic Analyst".as_bytes().to_vec(),
-					profile_link: "DeBio Genetic Analyst profile_link".as_bytes().to_vec(),
-					profile_image: Some("DeBio Genetic Analyst profile_image".as_bytes().to_vec()),
+					email: "Email".as_bytes().to_vec().try_into().unwrap(),
+					phone_number: "+6893026516".as_bytes().to_vec().try_into().unwrap(),
+					specialization: "DeBio Genetic Analyst".as_bytes().to_vec().try_into().unwrap(),
+					profile_link: "DeBio Genetic Analyst profile_link"
+						.as_bytes()
+						.to_vec()
+						.try_into()
+						.unwrap(),
+					profile_image: Some(
+						"DeBio Genetic Analyst profile_image"
+							.as_bytes()
+							.to_vec()
+							.try_into()
+							.unwrap()
+					),
 				}
 			));
 
@@ -545,15 +800,25 @@ mod tests {
 					box_public_key: Keccak256::hash(
 						"0xDb9Af2d1f3ADD2726A132AA7A65Cc9E6fC5761C3".as_bytes(),
 					),
-					first_name: "First Name".as_bytes().to_vec(),
-					last_name: "Last Name".as_bytes().to_vec(),
-					gender: "Gender".as_bytes().to_vec(),
+					first_name: "First Name".as_bytes().to_vec().try_into().unwrap(),
+					last_name: "Last Name".as_bytes().to_vec().try_into().unwrap(),
+					gender: "Gender".as_bytes().to_vec().try_into().unwrap(),
 					date_of_birth: 0,
-					email: "Email".as_bytes().to_vec(),
-					phone_number: "+6893026516".as_bytes().to_vec(),
-					specialization: "DeBio Genetic Analyst".as_bytes().to_vec(),
-					profile_link: "DeBio Genetic Analyst profile_link".as_bytes().to_vec(),
-					profile_image: Some("DeBio Genetic Analyst profile_image".as_bytes().to_vec()),
+					email: "Email".as_bytes().to_vec().try_into().unwrap(),
+					phone_number: "+6893026516".as_bytes().to_vec().try_into().unwrap(),
+					specialization: "DeBio Genetic Analyst".as_bytes().to_vec().try_into().unwrap(),
+					profile_link: "DeBio Genetic Analyst profile_link"
+						.as_bytes()
+						.to_vec()
+						.try_into()
+						.unwrap(),
+					profile_image: Some(
+						"DeBio Genetic Analyst profile_image"
+							.as_bytes()
+							.to_vec()
+							.try_into()
+							.unwrap()
+					),
 				}
 			));
 
@@ -562,15 +827,23 @@ mod tests {
 					RuntimeOrigin::signed(1),
 					Keccak256::hash("0xDb9Af2d1f3ADD2726A132AA7A65Cc9E6fC5761C3".as_bytes()),
 					GeneticAnalystServiceInfo {
-						name: "DeBio Genetic Analyst Service name".as_bytes().to_vec(),
-						prices_by_currency: vec![PriceByCurrency::default()],
+						name: "DeBio Genetic Analyst Service name"
+							.as_bytes()
+							.to_vec()
+							.try_into()
+							.unwrap(),
+						prices_by_currency: vec![PriceByCurrency::default()].try_into().unwrap(),
 						expected_duration: ExpectedDuration::default(),
 						description: "DeBio Genetic Analyst Service description"
 							.as_bytes()
-							.to_vec(),
+							.to_vec()
+							.try_into()
+							.unwrap(),
 						test_result_sample: "DeBio Genetic Analyst Service test_result_sample"
 							.as_bytes()
-							.to_vec(),
+							.to_vec()
+							.try_into()
+							.unwrap(),
 					},
 				),
 				Error::<Test>::GeneticAnalystServiceDoesNotExist
@@ -587,15 +860,25 @@ mod tests {
 					box_public_key: Keccak256::hash(
 						"0xDb9Af2d1f3ADD2726A132AA7A65Cc9E6fC5761C3".as_bytes(),
 					),
-					first_name: "First Name".as_bytes().to_vec(),
-					last_name: "Last Name".as_bytes().to_vec(),
-					gender: "Gender".as_bytes().to_vec(),
+					first_name: "First Name".as_bytes().to_vec().try_into().unwrap(),
+					last_name: "Last Name".as_bytes().to_vec().try_into().unwrap(),
+					gender: "Gender".as_bytes().to_vec().try_into().unwrap(),
 					date_of_birth: 0,
-					email: "Email".as_bytes().to_vec(),
-					phone_number: "+6893026516".as_bytes().to_vec(),
-					specialization: "DeBio Genetic Analyst".as_bytes().to_vec(),
-					profile_link: "DeBio Genetic Analyst profile_link".as_bytes().to_vec(),
-					profile_image: Some("DeBio Genetic Analyst profile_image".as_bytes().to_vec()),
+					email: "Email".as_bytes().to_vec().try_into().unwrap(),
+					phone_number: "+6893026516".as_bytes().to_vec().try_into().unwrap(),
+					specialization: "DeBio Genetic Analyst".as_bytes().to_vec().try_into().unwrap(),
+					profile_link: "DeBio Genetic Analyst profile_link"
+						.as_bytes()
+						.to_vec()
+						.try_into()
+						.unwrap(),
+					profile_image: Some(
+						"DeBio Genetic Analyst profile_image"
+							.as_bytes()
+							.to_vec()
+							.try_into()
+							.unwrap()
+					),
 				}
 			));
 
@@ -607,13 +890,23 @@ mod tests {
 			assert_ok!(GeneticAnalystServices::create_genetic_analyst_service(
 				RuntimeOrigin::signed(1),
 				GeneticAnalystServiceInfo {
-					name: "DeBio Genetic Analyst Service name".as_bytes().to_vec(),
-					prices_by_currency: vec![PriceByCurrency::default()],
+					name: "DeBio Genetic Analyst Service name"
+						.as_bytes()
+						.to_vec()
+						.try_into()
+						.unwrap(),
+					prices_by_currency: vec![PriceByCurrency::default()].try_into().unwrap(),
 					expected_duration: ExpectedDuration::default(),
-					description: "DeBio Genetic Analyst Service description".as_bytes().to_vec(),
+					description: "DeBio Genetic Analyst Service description"
+						.as_bytes()
+						.to_vec()
+						.try_into()
+						.unwrap(),
 					test_result_sample: "DeBio Genetic Analyst Service test_result_sample"
 						.as_bytes()
-						.to_vec(),
+						.to_vec()
+						.try_into()
+						.unwrap(),
 				},
 			));
 
@@ -624,15 +917,23 @@ mod tests {
 					RuntimeOrigin::signed(2),
 					genetic_analyst.services[0],
 					GeneticAnalystServiceInfo {
-						name: "DeBio Genetic Analyst Service name 2".as_bytes().to_vec(),
-						prices_by_currency: vec![PriceByCurrency::default()],
+						name: "DeBio Genetic Analyst Service name 2"
+							.as_bytes()
+							.to_vec()
+							.try_into()
+							.unwrap(),
+						prices_by_currency: vec![PriceByCurrency::default()].try_into().unwrap(),
 						expected_duration: ExpectedDuration::default(),
 						description: "DeBio Genetic Analyst Service description 2"
 							.as_bytes()
-							.to_vec(),
+							.to_vec()
+							.try_into()
+							.unwrap(),
 						test_result_sample: "DeBio Genetic Analyst Service test_result_sample 2"
 							.as_bytes()
-							.to_vec(),
+							.to_vec()
+							.try_into()
+							.unwrap(),
 					}
 				),
 				Error::<Test>::NotGeneticAnalystServiceOwner
@@ -649,15 +950,25 @@ mod tests {
 					box_public_key: Keccak256::hash(
 						"0xDb9Af2d1f3ADD2726A132AA7A65Cc9E6fC5761C3".as_bytes(),
 					),
-					first_name: "First Name".as_bytes().to_vec(),
-					last_name: "Last Name".as_bytes().to_vec(),
-					gender: "Gender".as_bytes().to_vec(),
+					first_name: "First Name".as_bytes().to_vec().try_into().unwrap(),
+					last_name: "Last Name".as_bytes().to_vec().try_into().unwrap(),
+					gender: "Gender".as_bytes().to_vec().try_into().unwrap(),
 					date_of_birth: 0,
-					email: "Email".as_bytes().to_vec(),
-					phone_number: "+6893026516".as_bytes().to_vec(),
-					specialization: "DeBio Genetic Analyst".as_bytes().to_vec(),
-					profile_link: "DeBio Genetic Analyst profile_link".as_bytes().to_vec(),
-					profile_image: Some("DeBio Genetic Analyst profile_image".as_bytes().to_vec()),
+					email: "Email".as_bytes().to_vec().try_into().unwrap(),
+					phone_number: "+6893026516".as_bytes().to_vec().try_into().unwrap(),
+					specialization: "DeBio Genetic Analyst".as_bytes().to_vec().try_into().unwrap(),
+					profile_link: "DeBio Genetic Analyst profile_link"
+						.as_bytes()
+						.to_vec()
+						.try_into()
+						.unwrap(),
+					profile_image: Some(
+						"DeBio Genetic Analyst profile_image"
+							.as_bytes()
+							.to_vec()
+							.try_into()
+							.unwrap()
+					),
 				}
 			));
 
@@ -680,15 +991,25 @@ mod tests {
 					box_public_key: Keccak256::hash(
 						"0xDb9Af2d1f3ADD2726A132AA7A65Cc9E6fC5761C3".as_bytes(),
 					),
-					first_name: "First Name".as_bytes().to_vec(),
-					last_name: "Last Name".as_bytes().to_vec(),
-					gender: "Gender".as_bytes().to_vec(),
+					first_name: "First Name".as_bytes().to_vec().try_into().unwrap(),
+					last_name: "Last Name".as_bytes().to_vec().try_into().unwrap(),
+					gender: "Gender".as_bytes().to_vec().try_into().unwrap(),
 					date_of_birth: 0,
-					email: "Email".as_bytes().to_vec(),
-					phone_number: "+6893026516".as_bytes().to_vec(),
-					specialization: "DeBio Genetic Analyst".as_bytes().to_vec(),
-					profile_link: "DeBio Genetic Analyst profile_link".as_bytes().to_vec(),
-					profile_image: Some("DeBio Genetic Analyst profile_image".as_bytes().to_vec()),
+					email: "Email".as_bytes().to_vec().try_into().unwrap(),
+					phone_number: "+6893026516".as_bytes().to_vec().try_into().unwrap(),
+					specialization: "DeBio Genetic Analyst".as_bytes().to_vec().try_into().unwrap(),
+					profile_link: "DeBio Genetic Analyst profile_link"
+						.as_bytes()
+						.to_vec()
+						.try_into()
+						.unwrap(),
+					profile_image: Some(
+						"DeBio Genetic Analyst profile_image"
+							.as_bytes()
+							.to_vec()
+							.try_into()
+							.unwrap()
+					),
 				}
 			));
 
@@ -700,13 +1021,23 @@ mod tests {
 			assert_ok!(GeneticAnalystServices::create_genetic_analyst_service(
 				RuntimeOrigin::signed(1),
 				GeneticAnalystServiceInfo {
-					name: "DeBio Genetic Analyst Service name".as_bytes().to_vec(),
-					prices_by_currency: vec![PriceByCurrency::default()],
+					name: "DeBio Genetic Analyst Service name"
+						.as_bytes()
+						.to_vec()
+						.try_into()
+						.unwrap(),
+					prices_by_currency: vec![PriceByCurrency::default()].try_into().unwrap(),
 					expected_duration: ExpectedDuration::default(),
-					description: "DeBio Genetic Analyst Service description".as_bytes().to_vec(),
+					description: "DeBio Genetic Analyst Service description"
+						.as_bytes()
+						.to_vec()
+						.try_into()
+						.unwrap(),
 					test_result_sample: "DeBio Genetic Analyst Service test_result_sample"
 						.as_bytes()
-						.to_vec(),
+						.to_vec()
+						.try_into()
+						.unwrap(),
 				},
 			));
 
@@ -721,4 +1052,42 @@ mod tests {
 			);
 		})
 	}
+
+	#[test]
+	fn migrate_truncates_genetic_analyst_service_info_over_its_bound() {
+		<ExternalityBuilder>::default().existential_deposit(1).build().execute_with(|| {
+			let service_id = Keccak256::hash("genetic_analyst_service".as_bytes());
+			let legacy_service = |name: Vec<u8>| {
+				(
+					service_id,
+					1u64,
+					(
+						name,
+						Vec::<(CurrencyType, u128, Vec<(Vec<u8>, u128)>, Vec<(Vec<u8>, u128)>)>::new(),
+						ExpectedDuration::default(),
+						"DeBio Genetic Analyst Service description".as_bytes().to_vec(),
+						"DeBio Genetic Analyst Service test_result_sample".as_bytes().to_vec(),
+					),
+				)
+					.encode()
+			};
+
+			// A service created before its info was bounded
+			unhashed::put_raw(
+				&GeneticAnalystServicesStorage::<Test>::hashed_key_for(service_id),
+				&legacy_service(vec![b'X'; MAX_GENETIC_ANALYST_SERVICE_TEXT_LENGTH as usize + 1]),
+			);
+			StorageVersion::new(1).put::<GeneticAnalystServices>();
+
+			genetic_analyst_services::migrations::migrate::<Test>();
+
+			assert_eq!(
+				GeneticAnalystServices::genetic_analyst_service_by_id(service_id)
+					.unwrap()
+					.encode(),
+				legacy_service(vec![b'X'; MAX_GENETIC_ANALYST_SERVICE_TEXT_LENGTH as usize])
+			);
+			assert_eq!(StorageVersion::get::<GeneticAnalystServices>(), StorageVersion::new(2));
+		})
+	}
 }
@@ -1,4 +1,4 @@
-use frame_support::{parameter_types, traits::ConstU128, PalletId};
+use frame_support::{pallet_prelude::MaxEncodedLen, parameter_types, traits::ConstU128, PalletId};
 use frame_system as system;
 use frame_system::EnsureRoot;
 use pallet_balances::AccountData;
@@ -11,7 +11,9 @@ use sp_runtime::{
 
 use primitives_profile_roles::ProfileRoles;
 
-#[derive(Clone, Copy, PartialEq, Eq, Encode, Decode, Default, RuntimeDebug, TypeInfo)]
+#[derive(
+	Clone, Copy, PartialEq, Eq, Encode, Decode, Default, RuntimeDebug, TypeInfo, MaxEncodedLen,
+)]
 pub struct EthereumAddress(pub [u8; 20]);
 
 type UncheckedExtrinsic = frame_system::mocking::MockUncheckedExtrinsic<Test>;
@@ -30,7 +32,7 @@ frame_support::construct_runtime!(
 		GeneticData: genetic_data,
 		GeneticAnalysts: genetic_analysts,
 		GeneticAnalystServices: genetic_analyst_services,
-		GeneticAnalystQualifications: genetic_analyst_qualifications,
+		GeneticAnalystQualifications: credentials::<Instance4>,
 		GeneticAnalysis: genetic_analysis,
 		GeneticAnalysisOrders: genetic_analysis_orders,
 		UserProfile: user_profile,
@@ -151,6 +153,7 @@ impl genetic_analysts::Config for Test {
 	type RuntimeEvent = RuntimeEvent;
 	type Currency = Balances;
 	type PalletId = GeneticAnalystPalletId;
+	type RequireVerifiedQualification = frame_support::traits::ConstBool<false>;
 	type GeneticAnalysisOrders = GeneticAnalysisOrders;
 	type GeneticAnalystServices = GeneticAnalystServices;
 	type GeneticAnalystQualifications = GeneticAnalystQualifications;
@@ -170,6 +173,7 @@ impl genetic_analysis::Config for Test {
 impl genetic_data::Config for Test {
 	type RuntimeEvent = RuntimeEvent;
 	type GeneticDataWeightInfo = ();
+	type MaxGeneticDataPerOwner = frame_support::traits::ConstU32<1_000>;
 }
 
 impl genetic_analyst_services::Config for Test {
@@ -179,9 +183,12 @@ impl genetic_analyst_services::Config for Test {
 	type WeightInfo = ();
 }
 
-impl genetic_analyst_qualifications::Config for Test {
+impl credentials::Config<credentials::Instance4> for Test {
 	type RuntimeEvent = RuntimeEvent;
-	type GeneticAnalystQualificationOwner = GeneticAnalysts;
+	type CredentialInfo = credentials::GeneticAnalystQualificationInfo;
+	type CredentialOwner =
+		credentials::legacy::LegacyGeneticAnalystQualificationOwner<GeneticAnalysts>;
+	type CredentialIssuers = ();
 	type WeightInfo = ();
 }
 
@@ -194,6 +201,7 @@ impl genetic_analysis_orders::Config for Test {
 	type GeneticAnalysis = GeneticAnalysis;
 	type GeneticAnalystServices = GeneticAnalystServices;
 	type GeneticAnalysisOrdersWeightInfo = ();
+	type PlatformFee = ();
 	type PalletId = GeneticAnalysisOrdersEscrowPalletId;
 }
 
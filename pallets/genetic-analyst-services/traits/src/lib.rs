@@ -2,12 +2,11 @@
 
 use frame_system::Config;
 use primitives_price_and_currency::PriceByCurrency;
-use sp_std::prelude::*;
 
 pub trait GeneticAnalystServiceInfo<T: Config, Balance> {
 	fn get_id(&self) -> &T::Hash;
 	fn get_owner_id(&self) -> &T::AccountId;
-	fn get_prices_by_currency(&self) -> &Vec<PriceByCurrency<Balance>>;
+	fn get_prices_by_currency(&self) -> &[PriceByCurrency<Balance>];
 }
 
 pub trait GeneticAnalystServicesProvider<T: Config, Balance> {
@@ -25,15 +25,15 @@ benchmarks! {
 	register_genetic_analyst {
 		let genetic_analyst = GeneticAnalystInfo {
 			box_public_key: T::Hashing::hash("0xDb9Af2d1f3ADD2726A132AA7A65Cc9E6fC5761C3".as_bytes()),
-			first_name: "First Name".as_bytes().to_vec(),
-			last_name: "Last Name".as_bytes().to_vec(),
-			gender: "Gender".as_bytes().to_vec(),
+			first_name: "First Name".as_bytes().to_vec().try_into().unwrap(),
+			last_name: "Last Name".as_bytes().to_vec().try_into().unwrap(),
+			gender: "Gender".as_bytes().to_vec().try_into().unwrap(),
 			date_of_birth: <T as pallet_timestamp::pallet::Config>::Moment::default(),
-			email: "Email".as_bytes().to_vec(),
-			phone_number: "+6893026516".as_bytes().to_vec(),
-			specialization: "DeBio Genetic Analyst".as_bytes().to_vec(),
-			profile_link: "DeBio Genetic Analyst profile_link".as_bytes().to_vec(),
-			profile_image: Some("DeBio Genetic Analyst profile_image".as_bytes().to_vec()),
+			email: "Email".as_bytes().to_vec().try_into().unwrap(),
+			phone_number: "+6893026516".as_bytes().to_vec().try_into().unwrap(),
+			specialization: "DeBio Genetic Analyst".as_bytes().to_vec().try_into().unwrap(),
+			profile_link: "DeBio Genetic Analyst profile_link".as_bytes().to_vec().try_into().unwrap(),
+			profile_image: Some("DeBio Genetic Analyst profile_image".as_bytes().to_vec().try_into().unwrap()),
 		};
 		let caller: T::AccountId = whitelisted_caller();
 	}: register_genetic_analyst(
@@ -47,29 +47,29 @@ benchmarks! {
 
 		let old_genetic_analyst = GeneticAnalystInfo {
 			box_public_key: T::Hashing::hash("0xDb9Af2d1f3ADD2726A132AA7A65Cc9E6fC5761C3".as_bytes()),
-			first_name: "First Name".as_bytes().to_vec(),
-			last_name: "Last Name".as_bytes().to_vec(),
-			gender: "Gender".as_bytes().to_vec(),
+			first_name: "First Name".as_bytes().to_vec().try_into().unwrap(),
+			last_name: "Last Name".as_bytes().to_vec().try_into().unwrap(),
+			gender: "Gender".as_bytes().to_vec().try_into().unwrap(),
 			date_of_birth: <T as pallet_timestamp::pallet::Config>::Moment::default(),
-			email: "Email".as_bytes().to_vec(),
-			phone_number: "+6893026516".as_bytes().to_vec(),
-			specialization: "DeBio Genetic Analyst".as_bytes().to_vec(),
-			profile_link: "DeBio Genetic Analyst profile_link".as_bytes().to_vec(),
-			profile_image: Some("DeBio Genetic Analyst profile_image".as_bytes().to_vec()),
+			email: "Email".as_bytes().to_vec().try_into().unwrap(),
+			phone_number: "+6893026516".as_bytes().to_vec().try_into().unwrap(),
+			specialization: "DeBio Genetic Analyst".as_bytes().to_vec().try_into().unwrap(),
+			profile_link: "DeBio Genetic Analyst profile_link".as_bytes().to_vec().try_into().unwrap(),
+			profile_image: Some("DeBio Genetic Analyst profile_image".as_bytes().to_vec().try_into().unwrap()),
 		};
 		let _add_genetic_analysts = GeneticAnalysts::<T>::register_genetic_analyst(caller_origin, old_genetic_analyst);
 
 		let new_genetic_analyst = GeneticAnalystInfo {
 			box_public_key: T::Hashing::hash("0xDb9Af2d1f3ADD2726A132AA7A65Cc9E6fC5761C3".as_bytes()),
-			first_name: "First Name 2".as_bytes().to_vec(),
-			last_name: "Last Name 2".as_bytes().to_vec(),
-			gender: "Gender 2".as_bytes().to_vec(),
+			first_name: "First Name 2".as_bytes().to_vec().try_into().unwrap(),
+			last_name: "Last Name 2".as_bytes().to_vec().try_into().unwrap(),
+			gender: "Gender 2".as_bytes().to_vec().try_into().unwrap(),
 			date_of_birth: <T as pallet_timestamp::pallet::Config>::Moment::default(),
-			email: "Email 2".as_bytes().to_vec(),
-			phone_number: "+6893026516".as_bytes().to_vec(),
-			specialization: "DeBio Genetic Analyst 2".as_bytes().to_vec(),
-			profile_link: "DeBio Genetic Analyst profile_link 2".as_bytes().to_vec(),
-			profile_image: Some("DeBio Genetic Analyst profile_image 2".as_bytes().to_vec()),
+			email: "Email 2".as_bytes().to_vec().try_into().unwrap(),
+			phone_number: "+6893026516".as_bytes().to_vec().try_into().unwrap(),
+			specialization: "DeBio Genetic Analyst 2".as_bytes().to_vec().try_into().unwrap(),
+			profile_link: "DeBio Genetic Analyst profile_link 2".as_bytes().to_vec().try_into().unwrap(),
+			profile_image: Some("DeBio Genetic Analyst profile_image 2".as_bytes().to_vec().try_into().unwrap()),
 		};
 	}: update_genetic_analyst(
 		RawOrigin::Signed(caller),
@@ -82,15 +82,15 @@ benchmarks! {
 
 		let old_genetic_analyst = GeneticAnalystInfo {
 			box_public_key: T::Hashing::hash("0xDb9Af2d1f3ADD2726A132AA7A65Cc9E6fC5761C3".as_bytes()),
-			first_name: "First Name".as_bytes().to_vec(),
-			last_name: "Last Name".as_bytes().to_vec(),
-			gender: "Gender".as_bytes().to_vec(),
+			first_name: "First Name".as_bytes().to_vec().try_into().unwrap(),
+			last_name: "Last Name".as_bytes().to_vec().try_into().unwrap(),
+			gender: "Gender".as_bytes().to_vec().try_into().unwrap(),
 			date_of_birth: <T as pallet_timestamp::pallet::Config>::Moment::default(),
-			email: "Email".as_bytes().to_vec(),
-			phone_number: "+6893026516".as_bytes().to_vec(),
-			specialization: "DeBio Genetic Analyst".as_bytes().to_vec(),
-			profile_link: "DeBio Genetic Analyst profile_link".as_bytes().to_vec(),
-			profile_image: Some("DeBio Genetic Analyst profile_image".as_bytes().to_vec()),
+			email: "Email".as_bytes().to_vec().try_into().unwrap(),
+			phone_number: "+6893026516".as_bytes().to_vec().try_into().unwrap(),
+			specialization: "DeBio Genetic Analyst".as_bytes().to_vec().try_into().unwrap(),
+			profile_link: "DeBio Genetic Analyst profile_link".as_bytes().to_vec().try_into().unwrap(),
+			profile_image: Some("DeBio Genetic Analyst profile_image".as_bytes().to_vec().try_into().unwrap()),
 		};
 		let _ = GeneticAnalysts::<T>::register_genetic_analyst(caller_origin.clone(), old_genetic_analyst);
 
@@ -109,15 +109,15 @@ benchmarks! {
 
 		let old_genetic_analyst = GeneticAnalystInfo {
 			box_public_key: T::Hashing::hash("0xDb9Af2d1f3ADD2726A132AA7A65Cc9E6fC5761C3".as_bytes()),
-			first_name: "First Name".as_bytes().to_vec(),
-			last_name: "Last Name".as_bytes().to_vec(),
-			gender: "Gender".as_bytes().to_vec(),
+			first_name: "First Name".as_bytes().to_vec().try_into().unwrap(),
+			last_name: "Last Name".as_bytes().to_vec().try_into().unwrap(),
+			gender: "Gender".as_bytes().to_vec().try_into().unwrap(),
 			date_of_birth: <T as pallet_timestamp::pallet::Config>::Moment::default(),
-			email: "Email".as_bytes().to_vec(),
-			phone_number: "+6893026516".as_bytes().to_vec(),
-			specialization: "DeBio Genetic Analyst".as_bytes().to_vec(),
-			profile_link: "DeBio Genetic Analyst profile_link".as_bytes().to_vec(),
-			profile_image: Some("DeBio Genetic Analyst profile_image".as_bytes().to_vec()),
+			email: "Email".as_bytes().to_vec().try_into().unwrap(),
+			phone_number: "+6893026516".as_bytes().to_vec().try_into().unwrap(),
+			specialization: "DeBio Genetic Analyst".as_bytes().to_vec().try_into().unwrap(),
+			profile_link: "DeBio Genetic Analyst profile_link".as_bytes().to_vec().try_into().unwrap(),
+			profile_image: Some("DeBio Genetic Analyst profile_image".as_bytes().to_vec().try_into().unwrap()),
 		};
 		let _ = GeneticAnalysts::<T>::register_genetic_analyst(caller_origin, old_genetic_analyst);
 	}: update_genetic_analyst_availability_status(
@@ -131,15 +131,15 @@ benchmarks! {
 
 		let genetic_analyst = GeneticAnalystInfo {
 			box_public_key: T::Hashing::hash("0xDb9Af2d1f3ADD2726A132AA7A65Cc9E6fC5761C3".as_bytes()),
-			first_name: "First Name".as_bytes().to_vec(),
-			last_name: "Last Name".as_bytes().to_vec(),
-			gender: "Gender".as_bytes().to_vec(),
+			first_name: "First Name".as_bytes().to_vec().try_into().unwrap(),
+			last_name: "Last Name".as_bytes().to_vec().try_into().unwrap(),
+			gender: "Gender".as_bytes().to_vec().try_into().unwrap(),
 			date_of_birth: <T as pallet_timestamp::pallet::Config>::Moment::default(),
-			email: "Email".as_bytes().to_vec(),
-			phone_number: "+6893026516".as_bytes().to_vec(),
-			specialization: "DeBio Genetic Analyst".as_bytes().to_vec(),
-			profile_link: "DeBio Genetic Analyst profile_link".as_bytes().to_vec(),
-			profile_image: Some("DeBio Genetic Analyst profile_image".as_bytes().to_vec()),
+			email: "Email".as_bytes().to_vec().try_into().unwrap(),
+			phone_number: "+6893026516".as_bytes().to_vec().try_into().unwrap(),
+			specialization: "DeBio Genetic Analyst".as_bytes().to_vec().try_into().unwrap(),
+			profile_link: "DeBio Genetic Analyst profile_link".as_bytes().to_vec().try_into().unwrap(),
+			profile_image: Some("DeBio Genetic Analyst profile_image".as_bytes().to_vec().try_into().unwrap()),
 		};
 
 		let _add_genetic_analysts = GeneticAnalysts::<T>::register_genetic_analyst(caller_origin, genetic_analyst);
@@ -155,15 +155,15 @@ benchmarks! {
 
 		let old_genetic_analyst = GeneticAnalystInfo {
 			box_public_key: T::Hashing::hash("0xDb9Af2d1f3ADD2726A132AA7A65Cc9E6fC5761C3".as_bytes()),
-			first_name: "First Name".as_bytes().to_vec(),
-			last_name: "Last Name".as_bytes().to_vec(),
-			gender: "Gender".as_bytes().to_vec(),
+			first_name: "First Name".as_bytes().to_vec().try_into().unwrap(),
+			last_name: "Last Name".as_bytes().to_vec().try_into().unwrap(),
+			gender: "Gender".as_bytes().to_vec().try_into().unwrap(),
 			date_of_birth: <T as pallet_timestamp::pallet::Config>::Moment::default(),
-			email: "Email".as_bytes().to_vec(),
-			phone_number: "+6893026516".as_bytes().to_vec(),
-			specialization: "DeBio Genetic Analyst".as_bytes().to_vec(),
-			profile_link: "DeBio Genetic Analyst profile_link".as_bytes().to_vec(),
-			profile_image: Some("DeBio Genetic Analyst profile_image".as_bytes().to_vec()),
+			email: "Email".as_bytes().to_vec().try_into().unwrap(),
+			phone_number: "+6893026516".as_bytes().to_vec().try_into().unwrap(),
+			specialization: "DeBio Genetic Analyst".as_bytes().to_vec().try_into().unwrap(),
+			profile_link: "DeBio Genetic Analyst profile_link".as_bytes().to_vec().try_into().unwrap(),
+			profile_image: Some("DeBio Genetic Analyst profile_image".as_bytes().to_vec().try_into().unwrap()),
 		};
 		let _ = GeneticAnalysts::<T>::register_genetic_analyst(caller_origin, old_genetic_analyst);
 	}: stake_genetic_analyst(
@@ -178,15 +178,15 @@ benchmarks! {
 
 		let old_genetic_analyst = GeneticAnalystInfo {
 			box_public_key: T::Hashing::hash("0xDb9Af2d1f3ADD2726A132AA7A65Cc9E6fC5761C3".as_bytes()),
-			first_name: "First Name".as_bytes().to_vec(),
-			last_name: "Last Name".as_bytes().to_vec(),
-			gender: "Gender".as_bytes().to_vec(),
+			first_name: "First Name".as_bytes().to_vec().try_into().unwrap(),
+			last_name: "Last Name".as_bytes().to_vec().try_into().unwrap(),
+			gender: "Gender".as_bytes().to_vec().try_into().unwrap(),
 			date_of_birth: <T as pallet_timestamp::pallet::Config>::Moment::default(),
-			email: "Email".as_bytes().to_vec(),
-			phone_number: "+6893026516".as_bytes().to_vec(),
-			specialization: "DeBio Genetic Analyst".as_bytes().to_vec(),
-			profile_link: "DeBio Genetic Analyst profile_link".as_bytes().to_vec(),
-			profile_image: Some("DeBio Genetic Analyst profile_image".as_bytes().to_vec()),
+			email: "Email".as_bytes().to_vec().try_into().unwrap(),
+			phone_number: "+6893026516".as_bytes().to_vec().try_into().unwrap(),
+			specialization: "DeBio Genetic Analyst".as_bytes().to_vec().try_into().unwrap(),
+			profile_link: "DeBio Genetic Analyst profile_link".as_bytes().to_vec().try_into().unwrap(),
+			profile_image: Some("DeBio Genetic Analyst profile_image".as_bytes().to_vec().try_into().unwrap()),
 		};
 		let _ = GeneticAnalysts::<T>::register_genetic_analyst(caller_origin.clone(), old_genetic_analyst);
 
@@ -204,15 +204,15 @@ benchmarks! {
 
 		let old_genetic_analyst = GeneticAnalystInfo {
 			box_public_key: T::Hashing::hash("0xDb9Af2d1f3ADD2726A132AA7A65Cc9E6fC5761C3".as_bytes()),
-			first_name: "First Name".as_bytes().to_vec(),
-			last_name: "Last Name".as_bytes().to_vec(),
-			gender: "Gender".as_bytes().to_vec(),
+			first_name: "First Name".as_bytes().to_vec().try_into().unwrap(),
+			last_name: "Last Name".as_bytes().to_vec().try_into().unwrap(),
+			gender: "Gender".as_bytes().to_vec().try_into().unwrap(),
 			date_of_birth: <T as pallet_timestamp::pallet::Config>::Moment::default(),
-			email: "Email".as_bytes().to_vec(),
-			phone_number: "+6893026516".as_bytes().to_vec(),
-			specialization: "DeBio Genetic Analyst".as_bytes().to_vec(),
-			profile_link: "DeBio Genetic Analyst profile_link".as_bytes().to_vec(),
-			profile_image: Some("DeBio Genetic Analyst profile_image".as_bytes().to_vec()),
+			email: "Email".as_bytes().to_vec().try_into().unwrap(),
+			phone_number: "+6893026516".as_bytes().to_vec().try_into().unwrap(),
+			specialization: "DeBio Genetic Analyst".as_bytes().to_vec().try_into().unwrap(),
+			profile_link: "DeBio Genetic Analyst profile_link".as_bytes().to_vec().try_into().unwrap(),
+			profile_image: Some("DeBio Genetic Analyst profile_image".as_bytes().to_vec().try_into().unwrap()),
 		};
 		let _ = GeneticAnalysts::<T>::register_genetic_analyst(caller_origin.clone(), old_genetic_analyst);
 
@@ -21,42 +21,51 @@ use frame_support::{
 use primitives_availability_status::{AvailabilityStatus, AvailabilityStatusTrait};
 use primitives_stake_status::{StakeStatus, StakeStatusTrait};
 use primitives_verification_status::{VerificationStatus, VerificationStatusTrait};
+use traits_credentials::legacy::GeneticAnalystQualificationOwnerInfo;
 pub use traits_genetic_analysis_orders::{
 	GeneticAnalysisOrderEventEmitter, GeneticAnalysisOrderStatusUpdater,
 };
-use traits_genetic_analyst_qualifications::GeneticAnalystQualificationOwnerInfo;
 use traits_genetic_analyst_services::GeneticAnalystServiceOwnerInfo;
 use traits_genetic_analysts::GeneticAnalystsProvider;
 
+/// Longest text field of a genetic analyst, e.g. its name, email or profile link
+pub const MAX_GENETIC_ANALYST_INFO_LENGTH: u32 = 512;
+/// Most services a genetic analyst can own
+pub const MAX_SERVICES_PER_GENETIC_ANALYST: u32 = 256;
+/// Most qualifications a genetic analyst can own
+pub const MAX_QUALIFICATIONS_PER_GENETIC_ANALYST: u32 = 256;
+
+pub type GeneticAnalystText = BoundedVec<u8, ConstU32<MAX_GENETIC_ANALYST_INFO_LENGTH>>;
+
 // GeneticAnalystInfo Struct
 // Used as parameter of dispatchable calls
-#[derive(Encode, Decode, Clone, Default, RuntimeDebug, PartialEq, Eq, TypeInfo)]
+#[derive(Encode, Decode, Clone, Default, RuntimeDebug, PartialEq, Eq, TypeInfo, MaxEncodedLen)]
 pub struct GeneticAnalystInfo<Hash, Moment>
 where
 	Hash: PartialEq + Eq,
 {
 	pub box_public_key: Hash,
-	pub first_name: Vec<u8>,
-	pub last_name: Vec<u8>,
-	pub gender: Vec<u8>,
+	pub first_name: GeneticAnalystText,
+	pub last_name: GeneticAnalystText,
+	pub gender: GeneticAnalystText,
 	pub date_of_birth: Moment,
-	pub email: Vec<u8>,
-	pub phone_number: Vec<u8>,
-	pub specialization: Vec<u8>,
-	pub profile_link: Vec<u8>,
-	pub profile_image: Option<Vec<u8>>,
+	pub email: GeneticAnalystText,
+	pub phone_number: GeneticAnalystText,
+	pub specialization: GeneticAnalystText,
+	pub profile_link: GeneticAnalystText,
+	pub profile_image: Option<GeneticAnalystText>,
 }
 
 // GeneticAnalyst Struct
 // the fields (excluding account_id and qualifications) come from GeneticAnalystInfo struct
-#[derive(Encode, Decode, Clone, Default, RuntimeDebug, PartialEq, Eq, TypeInfo)]
+#[derive(Encode, Decode, Clone, Default, RuntimeDebug, PartialEq, Eq, TypeInfo, MaxEncodedLen)]
 pub struct GeneticAnalyst<AccountId, Hash, Moment, Balance>
 where
 	Hash: PartialEq + Eq,
 {
 	pub account_id: AccountId,
-	pub services: Vec<Hash>,
-	pub qualifications: Vec<Hash>,
+	pub services: BoundedVec<Hash, ConstU32<MAX_SERVICES_PER_GENETIC_ANALYST>>,
+	pub qualifications: BoundedVec<Hash, ConstU32<MAX_QUALIFICATIONS_PER_GENETIC_ANALYST>>,
 	pub info: GeneticAnalystInfo<Hash, Moment>,
 	pub stake_amount: Balance,
 	pub stake_status: StakeStatus,
@@ -74,8 +83,8 @@ where
 	pub fn new(account_id: AccountId, info: GeneticAnalystInfo<Hash, Moment>) -> Self {
 		Self {
 			account_id,
-			services: Vec::<Hash>::new(),
-			qualifications: Vec::<Hash>::new(),
+			services: BoundedVec::default(),
+			qualifications: BoundedVec::default(),
 			info,
 			stake_amount: Balance::default(),
 			stake_status: StakeStatus::default(),
@@ -98,8 +107,10 @@ where
 		&self.account_id
 	}
 
-	pub fn add_service(&mut self, service_id: Hash) {
-		self.services.push(service_id);
+	/// Returns the service id back when the genetic analyst already owns
+	/// MAX_SERVICES_PER_GENETIC_ANALYST services
+	pub fn add_service(&mut self, service_id: Hash) -> Result<(), Hash> {
+		self.services.try_push(service_id)
 	}
 
 	pub fn remove_service(&mut self, service_id: Hash) {
@@ -108,8 +119,10 @@ where
 		}
 	}
 
-	pub fn add_qualification(&mut self, qualification_id: Hash) {
-		self.qualifications.push(qualification_id);
+	/// Returns the qualification id back when the genetic analyst already owns
+	/// MAX_QUALIFICATIONS_PER_GENETIC_ANALYST qualifications
+	pub fn add_qualification(&mut self, qualification_id: Hash) -> Result<(), Hash> {
+		self.qualifications.try_push(qualification_id)
 	}
 
 	pub fn remove_qualification(&mut self, qualification_id: Hash) {
@@ -142,7 +155,7 @@ where
 }
 
 /// The current storage version.
-const STORAGE_VERSION: StorageVersion = StorageVersion::new(3);
+const STORAGE_VERSION: StorageVersion = StorageVersion::new(4);
 
 #[frame_support::pallet]
 pub mod pallet {
@@ -151,7 +164,7 @@ pub mod pallet {
 	use frame_support::{dispatch::DispatchResultWithPostInfo, traits::Currency};
 	use frame_system::pallet_prelude::*;
 	pub use sp_std::prelude::*;
-	pub use traits_genetic_analyst_qualifications::{
+	pub use traits_credentials::legacy::{
 		GeneticAnalystQualificationOwner, GeneticAnalystQualificationsProvider,
 	};
 	pub use traits_genetic_analyst_services::{
@@ -194,13 +207,16 @@ pub mod pallet {
 		/// Currency type for this pallet.
 		#[pallet::constant]
 		type PalletId: Get<PalletId>;
+		/// Whether verifying a genetic analyst requires at least one issuer-verified
+		/// qualification.
+		#[pallet::constant]
+		type RequireVerifiedQualification: Get<bool>;
 	}
 
 	// ----- This is template code, every pallet needs this ---
 	#[pallet::pallet]
 	#[pallet::storage_version(STORAGE_VERSION)]
 	#[pallet::generate_store(pub(super) trait Store)]
-	#[pallet::without_storage_info]
 	pub struct Pallet<T>(_);
 
 	#[pallet::hooks]
@@ -351,6 +367,8 @@ pub mod pallet {
 		GeneticAnalystIsNotWaitingForUnstake,
 		// GeneticAnalyst cannot unstake now
 		GeneticAnalystCannotUnstakeBeforeUnstakeTime,
+		// GeneticAnalyst has no qualification verified by a credential issuer
+		NoVerifiedQualification,
 		// Dispatch Errors
 		Module,
 		Other,
@@ -656,6 +674,16 @@ impl<T: Config> GeneticAnalystInterface<T> for Pallet<T> {
 			return Err(Error::<T>::GeneticAnalystIsNotStaked)
 		}
 
+		if status.is_verified() &&
+			T::RequireVerifiedQualification::get() &&
+			!genetic_analyst
+				.qualifications
+				.iter()
+				.any(T::GeneticAnalystQualifications::is_qualification_verified)
+		{
+			return Err(Error::<T>::NoVerifiedQualification)
+		}
+
 		if status.is_rejected() {
 			if T::GeneticAnalysisOrders::is_pending_genetic_analysis_order_by_seller_exist(
 				account_id,
@@ -1009,7 +1037,11 @@ impl<T: Config> GeneticAnalystServiceOwner<T> for Pallet<T> {
 	/// User can create genetic_analyst_service if he/she is a genetic_analyst and has set ethereum
 	/// address
 	fn can_create_genetic_analyst_service(user_id: &T::AccountId) -> bool {
-		GeneticAnalysts::<T>::contains_key(user_id)
+		match GeneticAnalysts::<T>::get(user_id) {
+			Some(genetic_analyst) =>
+				genetic_analyst.services.len() < MAX_SERVICES_PER_GENETIC_ANALYST as usize,
+			None => false,
+		}
 	}
 
 	fn get_owner(id: &T::AccountId) -> Option<Self::Owner> {
@@ -1021,7 +1053,9 @@ impl<T: Config> GeneticAnalystServiceOwner<T> for Pallet<T> {
 			match genetic_analyst {
 				None => (), // If genetic_analyst does not exist, do nothing
 				Some(genetic_analyst) => {
-					genetic_analyst.add_service(*genetic_analyst_service_id);
+					// can_create_genetic_analyst_service rejects genetic analysts without room
+					// for another service
+					let _ = genetic_analyst.add_service(*genetic_analyst_service_id);
 				},
 			}
 		});
@@ -1042,7 +1076,12 @@ impl<T: Config> GeneticAnalystQualificationOwner<T> for Pallet<T> {
 
 	/// User can create qualification if he/she is a genetic_analyst
 	fn can_create_qualification(user_id: &T::AccountId) -> bool {
-		GeneticAnalysts::<T>::contains_key(user_id)
+		match GeneticAnalysts::<T>::get(user_id) {
+			Some(genetic_analyst) =>
+				genetic_analyst.qualifications.len() <
+					MAX_QUALIFICATIONS_PER_GENETIC_ANALYST as usize,
+			None => false,
+		}
 	}
 
 	fn get_owner(id: &T::AccountId) -> Option<Self::Owner> {
@@ -1054,7 +1093,9 @@ impl<T: Config> GeneticAnalystQualificationOwner<T> for Pallet<T> {
 			match genetic_analyst {
 				None => (), // If genetic_analyst does not exist, do nothing
 				Some(genetic_analyst) => {
-					genetic_analyst.add_qualification(*qualification_id);
+					// can_create_qualification rejects genetic analysts without room for another
+					// qualification
+					let _ = genetic_analyst.add_qualification(*qualification_id);
 				},
 			}
 		});
@@ -2,7 +2,12 @@ use crate::{
 	AccountIdOf, BalanceOf, Config, GeneticAnalyst, GeneticAnalystInfo, GeneticAnalysts, HashOf,
 	MomentOf, Pallet, PalletAccount, Vec, Weight,
 };
-use frame_support::{pallet_prelude::Decode, traits::Get};
+use frame_support::{
+	log,
+	pallet_prelude::{Decode, Encode},
+	traits::Get,
+	BoundedVec,
+};
 use primitives_availability_status::AvailabilityStatus;
 use primitives_stake_status::StakeStatus;
 use primitives_verification_status::VerificationStatus;
@@ -25,9 +30,65 @@ pub fn migrate<T: Config>() -> Weight {
 		StorageVersion::new(3).put::<Pallet<T>>();
 	}
 
+	version = StorageVersion::get::<Pallet<T>>();
+
+	if version == 3 {
+		weight = weight.saturating_add(v4::migrate::<T>());
+		StorageVersion::new(4).put::<Pallet<T>>();
+	}
+
 	weight
 }
 
+/// Genetic analyst info fields before they were bounded
+#[derive(Encode, Decode)]
+pub struct UnboundedGeneticAnalystInfo<Hash, Moment> {
+	pub box_public_key: Hash,
+	pub first_name: Vec<u8>,
+	pub last_name: Vec<u8>,
+	pub gender: Vec<u8>,
+	pub date_of_birth: Moment,
+	pub email: Vec<u8>,
+	pub phone_number: Vec<u8>,
+	pub specialization: Vec<u8>,
+	pub profile_link: Vec<u8>,
+	pub profile_image: Option<Vec<u8>>,
+}
+
+/// Truncates `value` to the bound and logs the entry when it did not fit.
+fn bounded<I, S: Get<u32>>(value: Vec<I>, entry: &str) -> BoundedVec<I, S> {
+	if value.len() > S::get() as usize {
+		log::warn!(
+			target: "runtime::genetic-analysts",
+			"{} exceeds its bound of {}, truncated from {} items",
+			entry,
+			S::get(),
+			value.len(),
+		);
+	}
+
+	BoundedVec::truncate_from(value)
+}
+
+fn bounded_genetic_analyst_info<Hash: PartialEq + Eq, Moment>(
+	info: UnboundedGeneticAnalystInfo<Hash, Moment>,
+) -> GeneticAnalystInfo<Hash, Moment> {
+	GeneticAnalystInfo {
+		box_public_key: info.box_public_key,
+		first_name: bounded(info.first_name, "genetic analyst first name"),
+		last_name: bounded(info.last_name, "genetic analyst last name"),
+		gender: bounded(info.gender, "genetic analyst gender"),
+		date_of_birth: info.date_of_birth,
+		email: bounded(info.email, "genetic analyst email"),
+		phone_number: bounded(info.phone_number, "genetic analyst phone number"),
+		specialization: bounded(info.specialization, "genetic analyst specialization"),
+		profile_link: bounded(info.profile_link, "genetic analyst profile link"),
+		profile_image: info
+			.profile_image
+			.map(|profile_image| bounded(profile_image, "genetic analyst profile image")),
+	}
+}
+
 mod v2 {
 	use super::*;
 
@@ -39,7 +100,7 @@ mod v2 {
 		pub account_id: AccountId,
 		pub services: Vec<Hash>,
 		pub qualifications: Vec<Hash>,
-		pub info: GeneticAnalystInfo<Hash, Moment>,
+		pub info: UnboundedGeneticAnalystInfo<Hash, Moment>,
 		pub stake_amount: Balance,
 		pub stake_status: StakeStatus,
 		pub verification_status: VerificationStatus,
@@ -54,9 +115,9 @@ mod v2 {
 				weight = weight.saturating_add(T::DbWeight::get().reads_writes(1, 1));
 				Some(GeneticAnalyst {
 					account_id: old.account_id,
-					services: old.services,
-					qualifications: old.qualifications,
-					info: old.info,
+					services: bounded(old.services, "genetic analyst services"),
+					qualifications: bounded(old.qualifications, "genetic analyst qualifications"),
+					info: bounded_genetic_analyst_info(old.info),
 					stake_amount: old.stake_amount,
 					stake_status: old.stake_status,
 					verification_status: old.verification_status,
@@ -80,3 +141,48 @@ mod v3 {
 		T::DbWeight::get().writes(1)
 	}
 }
+
+mod v4 {
+	use super::*;
+
+	#[derive(Decode)]
+	pub struct OldGeneticAnalyst<AccountId, Hash, Moment, Balance> {
+		pub account_id: AccountId,
+		pub services: Vec<Hash>,
+		pub qualifications: Vec<Hash>,
+		pub info: UnboundedGeneticAnalystInfo<Hash, Moment>,
+		pub stake_amount: Balance,
+		pub stake_status: StakeStatus,
+		pub verification_status: VerificationStatus,
+		pub availability_status: AvailabilityStatus,
+		pub unstake_at: Moment,
+		pub retrieve_unstake_at: Moment,
+	}
+
+	pub type OldGeneticAnalystOf<T> =
+		OldGeneticAnalyst<AccountIdOf<T>, HashOf<T>, MomentOf<T>, BalanceOf<T>>;
+
+	/// Genetic analyst info, service and qualification lists become bounded. Legacy values that
+	/// exceed a bound are truncated and logged.
+	pub fn migrate<T: Config>() -> Weight {
+		let mut weight = T::DbWeight::get().writes(1);
+
+		<GeneticAnalysts<T>>::translate(|_key, old: OldGeneticAnalystOf<T>| {
+			weight = weight.saturating_add(T::DbWeight::get().reads_writes(1, 1));
+			Some(GeneticAnalyst {
+				account_id: old.account_id,
+				services: bounded(old.services, "genetic analyst services"),
+				qualifications: bounded(old.qualifications, "genetic analyst qualifications"),
+				info: bounded_genetic_analyst_info(old.info),
+				stake_amount: old.stake_amount,
+				stake_status: old.stake_status,
+				verification_status: old.verification_status,
+				availability_status: old.availability_status,
+				unstake_at: old.unstake_at,
+				retrieve_unstake_at: old.retrieve_unstake_at,
+			})
+		});
+
+		weight
+	}
+}
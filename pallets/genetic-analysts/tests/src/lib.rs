@@ -6,7 +6,7 @@ mod tests {
 
 	use genetic_analysts::{
 		Error, Event as EventC, GeneticAnalyst, GeneticAnalystInfo, GeneticAnalystVerifierKey,
-		PalletAccount,
+		GeneticAnalysts as GeneticAnalystsStorage, PalletAccount, MAX_GENETIC_ANALYST_INFO_LENGTH,
 	};
 
 	use frame_support::{
@@ -15,6 +15,8 @@ mod tests {
 			traits::{Hash, Keccak256},
 			SaturatedConversion,
 		},
+		storage::unhashed,
+		traits::StorageVersion,
 	};
 	use frame_system::RawOrigin;
 
@@ -39,15 +41,25 @@ mod tests {
 					box_public_key: Keccak256::hash(
 						"0xDb9Af2d1f3ADD2726A132AA7A65Cc9E6fC5761C3".as_bytes(),
 					),
-					first_name: "First Name".as_bytes().to_vec(),
-					last_name: "Last Name".as_bytes().to_vec(),
-					gender: "Gender".as_bytes().to_vec(),
+					first_name: "First Name".as_bytes().to_vec().try_into().unwrap(),
+					last_name: "Last Name".as_bytes().to_vec().try_into().unwrap(),
+					gender: "Gender".as_bytes().to_vec().try_into().unwrap(),
 					date_of_birth: 0,
-					email: "Email".as_bytes().to_vec(),
-					phone_number: "+6893026516".as_bytes().to_vec(),
-					specialization: "DeBio Genetic Analyst".as_bytes().to_vec(),
-					profile_link: "DeBio Genetic Analyst profile_link".as_bytes().to_vec(),
-					profile_image: Some("DeBio Genetic Analyst profile_image".as_bytes().to_vec()),
+					email: "Email".as_bytes().to_vec().try_into().unwrap(),
+					phone_number: "+6893026516".as_bytes().to_vec().try_into().unwrap(),
+					specialization: "DeBio Genetic Analyst".as_bytes().to_vec().try_into().unwrap(),
+					profile_link: "DeBio Genetic Analyst profile_link"
+						.as_bytes()
+						.to_vec()
+						.try_into()
+						.unwrap(),
+					profile_image: Some(
+						"DeBio Genetic Analyst profile_image"
+							.as_bytes()
+							.to_vec()
+							.try_into()
+							.unwrap()
+					),
 				}
 			));
 
@@ -57,8 +69,8 @@ mod tests {
 					unstake_at: 0u64.saturated_into(),
 					retrieve_unstake_at: 0u64.saturated_into(),
 					account_id: 1,
-					services: Vec::new(),
-					qualifications: Vec::new(),
+					services: Default::default(),
+					qualifications: Default::default(),
 					stake_amount: 0u128.saturated_into(),
 					stake_status: StakeStatus::default(),
 					verification_status: VerificationStatus::default(),
@@ -67,16 +79,28 @@ mod tests {
 						box_public_key: Keccak256::hash(
 							"0xDb9Af2d1f3ADD2726A132AA7A65Cc9E6fC5761C3".as_bytes(),
 						),
-						first_name: "First Name".as_bytes().to_vec(),
-						last_name: "Last Name".as_bytes().to_vec(),
-						gender: "Gender".as_bytes().to_vec(),
+						first_name: "First Name".as_bytes().to_vec().try_into().unwrap(),
+						last_name: "Last Name".as_bytes().to_vec().try_into().unwrap(),
+						gender: "Gender".as_bytes().to_vec().try_into().unwrap(),
 						date_of_birth: 0,
-						email: "Email".as_bytes().to_vec(),
-						phone_number: "+6893026516".as_bytes().to_vec(),
-						specialization: "DeBio Genetic Analyst".as_bytes().to_vec(),
-						profile_link: "DeBio Genetic Analyst profile_link".as_bytes().to_vec(),
+						email: "Email".as_bytes().to_vec().try_into().unwrap(),
+						phone_number: "+6893026516".as_bytes().to_vec().try_into().unwrap(),
+						specialization: "DeBio Genetic Analyst"
+							.as_bytes()
+							.to_vec()
+							.try_into()
+							.unwrap(),
+						profile_link: "DeBio Genetic Analyst profile_link"
+							.as_bytes()
+							.to_vec()
+							.try_into()
+							.unwrap(),
 						profile_image: Some(
-							"DeBio Genetic Analyst profile_image".as_bytes().to_vec()
+							"DeBio Genetic Analyst profile_image"
+								.as_bytes()
+								.to_vec()
+								.try_into()
+								.unwrap()
 						),
 					}
 				})
@@ -88,16 +112,28 @@ mod tests {
 					box_public_key: Keccak256::hash(
 						"0xDb9Af2d1f3ADD2726A132AA7A65Cc9E6fC5761C3".as_bytes(),
 					),
-					first_name: "First Name 2".as_bytes().to_vec(),
-					last_name: "Last Name 2".as_bytes().to_vec(),
-					gender: "Gender 2".as_bytes().to_vec(),
+					first_name: "First Name 2".as_bytes().to_vec().try_into().unwrap(),
+					last_name: "Last Name 2".as_bytes().to_vec().try_into().unwrap(),
+					gender: "Gender 2".as_bytes().to_vec().try_into().unwrap(),
 					date_of_birth: 0,
-					email: "Email 2".as_bytes().to_vec(),
-					phone_number: "+6893026516".as_bytes().to_vec(),
-					specialization: "DeBio Genetic Analyst 2".as_bytes().to_vec(),
-					profile_link: "DeBio Genetic Analyst profile_link 2".as_bytes().to_vec(),
+					email: "Email 2".as_bytes().to_vec().try_into().unwrap(),
+					phone_number: "+6893026516".as_bytes().to_vec().try_into().unwrap(),
+					specialization: "DeBio Genetic Analyst 2"
+						.as_bytes()
+						.to_vec()
+						.try_into()
+						.unwrap(),
+					profile_link: "DeBio Genetic Analyst profile_link 2"
+						.as_bytes()
+						.to_vec()
+						.try_into()
+						.unwrap(),
 					profile_image: Some(
-						"DeBio Genetic Analyst profile_image 2".as_bytes().to_vec()
+						"DeBio Genetic Analyst profile_image 2"
+							.as_bytes()
+							.to_vec()
+							.try_into()
+							.unwrap()
 					),
 				}
 			));
@@ -115,15 +151,25 @@ mod tests {
 					box_public_key: Keccak256::hash(
 						"0xDb9Af2d1f3ADD2726A132AA7A65Cc9E6fC5761C3".as_bytes(),
 					),
-					first_name: "First Name".as_bytes().to_vec(),
-					last_name: "Last Name".as_bytes().to_vec(),
-					gender: "Gender".as_bytes().to_vec(),
+					first_name: "First Name".as_bytes().to_vec().try_into().unwrap(),
+					last_name: "Last Name".as_bytes().to_vec().try_into().unwrap(),
+					gender: "Gender".as_bytes().to_vec().try_into().unwrap(),
 					date_of_birth: 0,
-					email: "Email".as_bytes().to_vec(),
-					phone_number: "+6893026516".as_bytes().to_vec(),
-					specialization: "DeBio Genetic Analyst".as_bytes().to_vec(),
-					profile_link: "DeBio Genetic Analyst profile_link".as_bytes().to_vec(),
-					profile_image: Some("DeBio Genetic Analyst profile_image".as_bytes().to_vec()),
+					email: "Email".as_bytes().to_vec().try_into().unwrap(),
+					phone_number: "+6893026516".as_bytes().to_vec().try_into().unwrap(),
+					specialization: "DeBio Genetic Analyst".as_bytes().to_vec().try_into().unwrap(),
+					profile_link: "DeBio Genetic Analyst profile_link"
+						.as_bytes()
+						.to_vec()
+						.try_into()
+						.unwrap(),
+					profile_image: Some(
+						"DeBio Genetic Analyst profile_image"
+							.as_bytes()
+							.to_vec()
+							.try_into()
+							.unwrap()
+					),
 				}
 			));
 
@@ -134,16 +180,28 @@ mod tests {
 						box_public_key: Keccak256::hash(
 							"0xDb9Af2d1f3ADD2726A132AA7A65Cc9E6fC5761C3".as_bytes(),
 						),
-						first_name: "First Name".as_bytes().to_vec(),
-						last_name: "Last Name".as_bytes().to_vec(),
-						gender: "Gender".as_bytes().to_vec(),
+						first_name: "First Name".as_bytes().to_vec().try_into().unwrap(),
+						last_name: "Last Name".as_bytes().to_vec().try_into().unwrap(),
+						gender: "Gender".as_bytes().to_vec().try_into().unwrap(),
 						date_of_birth: 0,
-						email: "Email".as_bytes().to_vec(),
-						phone_number: "+6893026516".as_bytes().to_vec(),
-						specialization: "DeBio Genetic Analyst".as_bytes().to_vec(),
-						profile_link: "DeBio Genetic Analyst profile_link".as_bytes().to_vec(),
+						email: "Email".as_bytes().to_vec().try_into().unwrap(),
+						phone_number: "+6893026516".as_bytes().to_vec().try_into().unwrap(),
+						specialization: "DeBio Genetic Analyst"
+							.as_bytes()
+							.to_vec()
+							.try_into()
+							.unwrap(),
+						profile_link: "DeBio Genetic Analyst profile_link"
+							.as_bytes()
+							.to_vec()
+							.try_into()
+							.unwrap(),
 						profile_image: Some(
-							"DeBio Genetic Analyst profile_image".as_bytes().to_vec()
+							"DeBio Genetic Analyst profile_image"
+								.as_bytes()
+								.to_vec()
+								.try_into()
+								.unwrap()
 						),
 					}
 				),
@@ -161,15 +219,25 @@ mod tests {
 					box_public_key: Keccak256::hash(
 						"0xDb9Af2d1f3ADD2726A132AA7A65Cc9E6fC5761C3".as_bytes(),
 					),
-					first_name: "First Name".as_bytes().to_vec(),
-					last_name: "Last Name".as_bytes().to_vec(),
-					gender: "Gender".as_bytes().to_vec(),
+					first_name: "First Name".as_bytes().to_vec().try_into().unwrap(),
+					last_name: "Last Name".as_bytes().to_vec().try_into().unwrap(),
+					gender: "Gender".as_bytes().to_vec().try_into().unwrap(),
 					date_of_birth: 0,
-					email: "Email".as_bytes().to_vec(),
-					phone_number: "+6893026516".as_bytes().to_vec(),
-					specialization: "DeBio Genetic Analyst".as_bytes().to_vec(),
-					profile_link: "DeBio Genetic Analyst profile_link".as_bytes().to_vec(),
-					profile_image: Some("DeBio Genetic Analyst profile_image".as_bytes().to_vec()),
+					email: "Email".as_bytes().to_vec().try_into().unwrap(),
+					phone_number: "+6893026516".as_bytes().to_vec().try_into().unwrap(),
+					specialization: "DeBio Genetic Analyst".as_bytes().to_vec().try_into().unwrap(),
+					profile_link: "DeBio Genetic Analyst profile_link"
+						.as_bytes()
+						.to_vec()
+						.try_into()
+						.unwrap(),
+					profile_image: Some(
+						"DeBio Genetic Analyst profile_image"
+							.as_bytes()
+							.to_vec()
+							.try_into()
+							.unwrap()
+					),
 				}
 			));
 
@@ -179,8 +247,8 @@ mod tests {
 					unstake_at: 0u64.saturated_into(),
 					retrieve_unstake_at: 0u64.saturated_into(),
 					account_id: 1,
-					services: Vec::new(),
-					qualifications: Vec::new(),
+					services: Default::default(),
+					qualifications: Default::default(),
 					stake_amount: 0u128.saturated_into(),
 					stake_status: StakeStatus::default(),
 					verification_status: VerificationStatus::default(),
@@ -189,16 +257,28 @@ mod tests {
 						box_public_key: Keccak256::hash(
 							"0xDb9Af2d1f3ADD2726A132AA7A65Cc9E6fC5761C3".as_bytes(),
 						),
-						first_name: "First Name".as_bytes().to_vec(),
-						last_name: "Last Name".as_bytes().to_vec(),
-						gender: "Gender".as_bytes().to_vec(),
+						first_name: "First Name".as_bytes().to_vec().try_into().unwrap(),
+						last_name: "Last Name".as_bytes().to_vec().try_into().unwrap(),
+						gender: "Gender".as_bytes().to_vec().try_into().unwrap(),
 						date_of_birth: 0,
-						email: "Email".as_bytes().to_vec(),
-						phone_number: "+6893026516".as_bytes().to_vec(),
-						specialization: "DeBio Genetic Analyst".as_bytes().to_vec(),
-						profile_link: "DeBio Genetic Analyst profile_link".as_bytes().to_vec(),
+						email: "Email".as_bytes().to_vec().try_into().unwrap(),
+						phone_number: "+6893026516".as_bytes().to_vec().try_into().unwrap(),
+						specialization: "DeBio Genetic Analyst"
+							.as_bytes()
+							.to_vec()
+							.try_into()
+							.unwrap(),
+						profile_link: "DeBio Genetic Analyst profile_link"
+							.as_bytes()
+							.to_vec()
+							.try_into()
+							.unwrap(),
 						profile_image: Some(
-							"DeBio Genetic Analyst profile_image".as_bytes().to_vec()
+							"DeBio Genetic Analyst profile_image"
+								.as_bytes()
+								.to_vec()
+								.try_into()
+								.unwrap()
 						),
 					}
 				})
@@ -210,16 +290,28 @@ mod tests {
 					box_public_key: Keccak256::hash(
 						"0xDb9Af2d1f3ADD2726A132AA7A65Cc9E6fC5761C3".as_bytes(),
 					),
-					first_name: "First Name 2".as_bytes().to_vec(),
-					last_name: "Last Name 2".as_bytes().to_vec(),
-					gender: "Gender 2".as_bytes().to_vec(),
+					first_name: "First Name 2".as_bytes().to_vec().try_into().unwrap(),
+					last_name: "Last Name 2".as_bytes().to_vec().try_into().unwrap(),
+					gender: "Gender 2".as_bytes().to_vec().try_into().unwrap(),
 					date_of_birth: 0,
-					email: "Email 2".as_bytes().to_vec(),
-					phone_number: "+6893026516".as_bytes().to_vec(),
-					specialization: "DeBio Genetic Analyst 2".as_bytes().to_vec(),
-					profile_link: "DeBio Genetic Analyst profile_link 2".as_bytes().to_vec(),
+					email: "Email 2".as_bytes().to_vec().try_into().unwrap(),
+					phone_number: "+6893026516".as_bytes().to_vec().try_into().unwrap(),
+					specialization: "DeBio Genetic Analyst 2"
+						.as_bytes()
+						.to_vec()
+						.try_into()
+						.unwrap(),
+					profile_link: "DeBio Genetic Analyst profile_link 2"
+						.as_bytes()
+						.to_vec()
+						.try_into()
+						.unwrap(),
 					profile_image: Some(
-						"DeBio Genetic Analyst profile_image 2".as_bytes().to_vec()
+						"DeBio Genetic Analyst profile_image 2"
+							.as_bytes()
+							.to_vec()
+							.try_into()
+							.unwrap()
 					),
 				}
 			));
@@ -230,8 +322,8 @@ mod tests {
 					unstake_at: 0u64.saturated_into(),
 					retrieve_unstake_at: 0u64.saturated_into(),
 					account_id: 1,
-					services: Vec::new(),
-					qualifications: Vec::new(),
+					services: Default::default(),
+					qualifications: Default::default(),
 					stake_amount: 0u128.saturated_into(),
 					stake_status: StakeStatus::default(),
 					verification_status: VerificationStatus::default(),
@@ -240,16 +332,28 @@ mod tests {
 						box_public_key: Keccak256::hash(
 							"0xDb9Af2d1f3ADD2726A132AA7A65Cc9E6fC5761C3".as_bytes(),
 						),
-						first_name: "First Name 2".as_bytes().to_vec(),
-						last_name: "Last Name 2".as_bytes().to_vec(),
-						gender: "Gender 2".as_bytes().to_vec(),
+						first_name: "First Name 2".as_bytes().to_vec().try_into().unwrap(),
+						last_name: "Last Name 2".as_bytes().to_vec().try_into().unwrap(),
+						gender: "Gender 2".as_bytes().to_vec().try_into().unwrap(),
 						date_of_birth: 0,
-						email: "Email 2".as_bytes().to_vec(),
-						phone_number: "+6893026516".as_bytes().to_vec(),
-						specialization: "DeBio Genetic Analyst 2".as_bytes().to_vec(),
-						profile_link: "DeBio Genetic Analyst profile_link 2".as_bytes().to_vec(),
+						email: "Email 2".as_bytes().to_vec().try_into().unwrap(),
+						phone_number: "+6893026516".as_bytes().to_vec().try_into().unwrap(),
+						specialization: "DeBio Genetic Analyst 2"
+							.as_bytes()
+							.to_vec()
+							.try_into()
+							.unwrap(),
+						profile_link: "DeBio Genetic Analyst profile_link 2"
+							.as_bytes()
+							.to_vec()
+							.try_into()
+							.unwrap(),
 						profile_image: Some(
-							"DeBio Genetic Analyst profile_image 2".as_bytes().to_vec()
+							"DeBio Genetic Analyst profile_image 2"
+								.as_bytes()
+								.to_vec()
+								.try_into()
+								.unwrap()
 						),
 					}
 				})
@@ -266,15 +370,25 @@ mod tests {
 					box_public_key: Keccak256::hash(
 						"0xDb9Af2d1f3ADD2726A132AA7A65Cc9E6fC5761C3".as_bytes(),
 					),
-					first_name: "First Name".as_bytes().to_vec(),
-					last_name: "Last Name".as_bytes().to_vec(),
-					gender: "Gender".as_bytes().to_vec(),
+					first_name: "First Name".as_bytes().to_vec().try_into().unwrap(),
+					last_name: "Last Name".as_bytes().to_vec().try_into().unwrap(),
+					gender: "Gender".as_bytes().to_vec().try_into().unwrap(),
 					date_of_birth: 0,
-					email: "Email".as_bytes().to_vec(),
-					phone_number: "+6893026516".as_bytes().to_vec(),
-					specialization: "DeBio Genetic Analyst".as_bytes().to_vec(),
-					profile_link: "DeBio Genetic Analyst profile_link".as_bytes().to_vec(),
-					profile_image: Some("DeBio Genetic Analyst profile_image".as_bytes().to_vec()),
+					email: "Email".as_bytes().to_vec().try_into().unwrap(),
+					phone_number: "+6893026516".as_bytes().to_vec().try_into().unwrap(),
+					specialization: "DeBio Genetic Analyst".as_bytes().to_vec().try_into().unwrap(),
+					profile_link: "DeBio Genetic Analyst profile_link"
+						.as_bytes()
+						.to_vec()
+						.try_into()
+						.unwrap(),
+					profile_image: Some(
+						"DeBio Genetic Analyst profile_image"
+							.as_bytes()
+							.to_vec()
+							.try_into()
+							.unwrap()
+					),
 				}
 			));
 
@@ -296,16 +410,28 @@ mod tests {
 						box_public_key: Keccak256::hash(
 							"0xDb9Af2d1f3ADD2726A132AA7A65Cc9E6fC5761C3".as_bytes(),
 						),
-						first_name: "First Name".as_bytes().to_vec(),
-						last_name: "Last Name".as_bytes().to_vec(),
-						gender: "Gender".as_bytes().to_vec(),
+						first_name: "First Name".as_bytes().to_vec().try_into().unwrap(),
+						last_name: "Last Name".as_bytes().to_vec().try_into().unwrap(),
+						gender: "Gender".as_bytes().to_vec().try_into().unwrap(),
 						date_of_birth: 0,
-						email: "Email".as_bytes().to_vec(),
-						phone_number: "+6893026516".as_bytes().to_vec(),
-						specialization: "DeBio Genetic Analyst".as_bytes().to_vec(),
-						profile_link: "DeBio Genetic Analyst profile_link".as_bytes().to_vec(),
+						email: "Email".as_bytes().to_vec().try_into().unwrap(),
+						phone_number: "+6893026516".as_bytes().to_vec().try_into().unwrap(),
+						specialization: "DeBio Genetic Analyst"
+							.as_bytes()
+							.to_vec()
+							.try_into()
+							.unwrap(),
+						profile_link: "DeBio Genetic Analyst profile_link"
+							.as_bytes()
+							.to_vec()
+							.try_into()
+							.unwrap(),
 						profile_image: Some(
-							"DeBio Genetic Analyst profile_image".as_bytes().to_vec()
+							"DeBio Genetic Analyst profile_image"
+								.as_bytes()
+								.to_vec()
+								.try_into()
+								.unwrap()
 						),
 					}
 				),
@@ -330,15 +456,25 @@ mod tests {
 					box_public_key: Keccak256::hash(
 						"0xDb9Af2d1f3ADD2726A132AA7A65Cc9E6fC5761C3".as_bytes(),
 					),
-					first_name: "First Name".as_bytes().to_vec(),
-					last_name: "Last Name".as_bytes().to_vec(),
-					gender: "Gender".as_bytes().to_vec(),
+					first_name: "First Name".as_bytes().to_vec().try_into().unwrap(),
+					last_name: "Last Name".as_bytes().to_vec().try_into().unwrap(),
+					gender: "Gender".as_bytes().to_vec().try_into().unwrap(),
 					date_of_birth: 0,
-					email: "Email".as_bytes().to_vec(),
-					phone_number: "+6893026516".as_bytes().to_vec(),
-					specialization: "DeBio Genetic Analyst".as_bytes().to_vec(),
-					profile_link: "DeBio Genetic Analyst profile_link".as_bytes().to_vec(),
-					profile_image: Some("DeBio Genetic Analyst profile_image".as_bytes().to_vec()),
+					email: "Email".as_bytes().to_vec().try_into().unwrap(),
+					phone_number: "+6893026516".as_bytes().to_vec().try_into().unwrap(),
+					specialization: "DeBio Genetic Analyst".as_bytes().to_vec().try_into().unwrap(),
+					profile_link: "DeBio Genetic Analyst profile_link"
+						.as_bytes()
+						.to_vec()
+						.try_into()
+						.unwrap(),
+					profile_image: Some(
+						"DeBio Genetic Analyst profile_image"
+							.as_bytes()
+							.to_vec()
+							.try_into()
+							.unwrap()
+					),
 				}
 			));
 
@@ -348,8 +484,8 @@ mod tests {
 						unstake_at: 0u64.saturated_into(),
 						retrieve_unstake_at: 0u64.saturated_into(),
 						account_id: 1,
-						services: Vec::new(),
-						qualifications: Vec::new(),
+						services: Default::default(),
+						qualifications: Default::default(),
 						stake_amount: 0u128.saturated_into(),
 						stake_status: StakeStatus::default(),
 						verification_status: VerificationStatus::default(),
@@ -358,16 +494,28 @@ mod tests {
 							box_public_key: Keccak256::hash(
 								"0xDb9Af2d1f3ADD2726A132AA7A65Cc9E6fC5761C3".as_bytes(),
 							),
-							first_name: "First Name".as_bytes().to_vec(),
-							last_name: "Last Name".as_bytes().to_vec(),
-							gender: "Gender".as_bytes().to_vec(),
+							first_name: "First Name".as_bytes().to_vec().try_into().unwrap(),
+							last_name: "Last Name".as_bytes().to_vec().try_into().unwrap(),
+							gender: "Gender".as_bytes().to_vec().try_into().unwrap(),
 							date_of_birth: 0,
-							email: "Email".as_bytes().to_vec(),
-							phone_number: "+6893026516".as_bytes().to_vec(),
-							specialization: "DeBio Genetic Analyst".as_bytes().to_vec(),
-							profile_link: "DeBio Genetic Analyst profile_link".as_bytes().to_vec(),
+							email: "Email".as_bytes().to_vec().try_into().unwrap(),
+							phone_number: "+6893026516".as_bytes().to_vec().try_into().unwrap(),
+							specialization: "DeBio Genetic Analyst"
+								.as_bytes()
+								.to_vec()
+								.try_into()
+								.unwrap(),
+							profile_link: "DeBio Genetic Analyst profile_link"
+								.as_bytes()
+								.to_vec()
+								.try_into()
+								.unwrap(),
 							profile_image: Some(
-								"DeBio Genetic Analyst profile_image".as_bytes().to_vec(),
+								"DeBio Genetic Analyst profile_image"
+									.as_bytes()
+									.to_vec()
+									.try_into()
+									.unwrap(),
 							),
 						},
 					},
@@ -381,16 +529,28 @@ mod tests {
 					box_public_key: Keccak256::hash(
 						"0xDb9Af2d1f3ADD2726A132AA7A65Cc9E6fC5761C3".as_bytes(),
 					),
-					first_name: "First Name 2".as_bytes().to_vec(),
-					last_name: "Last Name 2".as_bytes().to_vec(),
-					gender: "Gender 2".as_bytes().to_vec(),
+					first_name: "First Name 2".as_bytes().to_vec().try_into().unwrap(),
+					last_name: "Last Name 2".as_bytes().to_vec().try_into().unwrap(),
+					gender: "Gender 2".as_bytes().to_vec().try_into().unwrap(),
 					date_of_birth: 0,
-					email: "Email 2".as_bytes().to_vec(),
-					phone_number: "+6893026516".as_bytes().to_vec(),
-					specialization: "DeBio Genetic Analyst 2".as_bytes().to_vec(),
-					profile_link: "DeBio Genetic Analyst profile_link 2".as_bytes().to_vec(),
+					email: "Email 2".as_bytes().to_vec().try_into().unwrap(),
+					phone_number: "+6893026516".as_bytes().to_vec().try_into().unwrap(),
+					specialization: "DeBio Genetic Analyst 2"
+						.as_bytes()
+						.to_vec()
+						.try_into()
+						.unwrap(),
+					profile_link: "DeBio Genetic Analyst profile_link 2"
+						.as_bytes()
+						.to_vec()
+						.try_into()
+						.unwrap(),
 					profile_image: Some(
-						"DeBio Genetic Analyst profile_image 2".as_bytes().to_vec()
+						"DeBio Genetic Analyst profile_image 2"
+							.as_bytes()
+							.to_vec()
+							.try_into()
+							.unwrap()
 					),
 				}
 			));
@@ -401,8 +561,8 @@ mod tests {
 						unstake_at: 0u64.saturated_into(),
 						retrieve_unstake_at: 0u64.saturated_into(),
 						account_id: 1,
-						services: Vec::new(),
-						qualifications: Vec::new(),
+						services: Default::default(),
+						qualifications: Default::default(),
 						stake_amount: 0u128.saturated_into(),
 						stake_status: StakeStatus::default(),
 						verification_status: VerificationStatus::default(),
@@ -411,18 +571,28 @@ mod tests {
 							box_public_key: Keccak256::hash(
 								"0xDb9Af2d1f3ADD2726A132AA7A65Cc9E6fC5761C3".as_bytes(),
 							),
-							first_name: "First Name 2".as_bytes().to_vec(),
-							last_name: "Last Name 2".as_bytes().to_vec(),
-							gender: "Gender 2".as_bytes().to_vec(),
+							first_name: "First Name 2".as_bytes().to_vec().try_into().unwrap(),
+							last_name: "Last Name 2".as_bytes().to_vec().try_into().unwrap(),
+							gender: "Gender 2".as_bytes().to_vec().try_into().unwrap(),
 							date_of_birth: 0,
-							email: "Email 2".as_bytes().to_vec(),
-							phone_number: "+6893026516".as_bytes().to_vec(),
-							specialization: "DeBio Genetic Analyst 2".as_bytes().to_vec(),
+							email: "Email 2".as_bytes().to_vec().try_into().unwrap(),
+							phone_number: "+6893026516".as_bytes().to_vec().try_into().unwrap(),
+							specialization: "DeBio Genetic Analyst 2"
+								.as_bytes()
+								.to_vec()
+								.try_into()
+								.unwrap(),
 							profile_link: "DeBio Genetic Analyst profile_link 2"
 								.as_bytes()
-								.to_vec(),
+								.to_vec()
+								.try_into()
+								.unwrap(),
 							profile_image: Some(
-								"DeBio Genetic Analyst profile_image 2".as_bytes().to_vec(),
+								"DeBio Genetic Analyst profile_image 2"
+									.as_bytes()
+									.to_vec()
+									.try_into()
+									.unwrap(),
 							),
 						},
 					},
@@ -438,8 +608,8 @@ mod tests {
 						unstake_at: 0u64.saturated_into(),
 						retrieve_unstake_at: 0u64.saturated_into(),
 						account_id: 1,
-						services: Vec::new(),
-						qualifications: Vec::new(),
+						services: Default::default(),
+						qualifications: Default::default(),
 						stake_amount: 0u128.saturated_into(),
 						stake_status: StakeStatus::default(),
 						verification_status: VerificationStatus::default(),
@@ -448,18 +618,28 @@ mod tests {
 							box_public_key: Keccak256::hash(
 								"0xDb9Af2d1f3ADD2726A132AA7A65Cc9E6fC5761C3".as_bytes(),
 							),
-							first_name: "First Name 2".as_bytes().to_vec(),
-							last_name: "Last Name 2".as_bytes().to_vec(),
-							gender: "Gender 2".as_bytes().to_vec(),
+							first_name: "First Name 2".as_bytes().to_vec().try_into().unwrap(),
+							last_name: "Last Name 2".as_bytes().to_vec().try_into().unwrap(),
+							gender: "Gender 2".as_bytes().to_vec().try_into().unwrap(),
 							date_of_birth: 0,
-							email: "Email 2".as_bytes().to_vec(),
-							phone_number: "+6893026516".as_bytes().to_vec(),
-							specialization: "DeBio Genetic Analyst 2".as_bytes().to_vec(),
+							email: "Email 2".as_bytes().to_vec().try_into().unwrap(),
+							phone_number: "+6893026516".as_bytes().to_vec().try_into().unwrap(),
+							specialization: "DeBio Genetic Analyst 2"
+								.as_bytes()
+								.to_vec()
+								.try_into()
+								.unwrap(),
 							profile_link: "DeBio Genetic Analyst profile_link 2"
 								.as_bytes()
-								.to_vec(),
+								.to_vec()
+								.try_into()
+								.unwrap(),
 							profile_image: Some(
-								"DeBio Genetic Analyst profile_image 2".as_bytes().to_vec(),
+								"DeBio Genetic Analyst profile_image 2"
+									.as_bytes()
+									.to_vec()
+									.try_into()
+									.unwrap(),
 							),
 						},
 					},
@@ -485,15 +665,25 @@ mod tests {
 					box_public_key: Keccak256::hash(
 						"0xDb9Af2d1f3ADD2726A132AA7A65Cc9E6fC5761C3".as_bytes(),
 					),
-					first_name: "First Name".as_bytes().to_vec(),
-					last_name: "Last Name".as_bytes().to_vec(),
-					gender: "Gender".as_bytes().to_vec(),
+					first_name: "First Name".as_bytes().to_vec().try_into().unwrap(),
+					last_name: "Last Name".as_bytes().to_vec().try_into().unwrap(),
+					gender: "Gender".as_bytes().to_vec().try_into().unwrap(),
 					date_of_birth: 0,
-					email: "Email".as_bytes().to_vec(),
-					phone_number: "+6893026516".as_bytes().to_vec(),
-					specialization: "DeBio Genetic Analyst".as_bytes().to_vec(),
-					profile_link: "DeBio Genetic Analyst profile_link".as_bytes().to_vec(),
-					profile_image: Some("DeBio Genetic Analyst profile_image".as_bytes().to_vec()),
+					email: "Email".as_bytes().to_vec().try_into().unwrap(),
+					phone_number: "+6893026516".as_bytes().to_vec().try_into().unwrap(),
+					specialization: "DeBio Genetic Analyst".as_bytes().to_vec().try_into().unwrap(),
+					profile_link: "DeBio Genetic Analyst profile_link"
+						.as_bytes()
+						.to_vec()
+						.try_into()
+						.unwrap(),
+					profile_image: Some(
+						"DeBio Genetic Analyst profile_image"
+							.as_bytes()
+							.to_vec()
+							.try_into()
+							.unwrap()
+					),
 				}
 			));
 
@@ -514,8 +704,8 @@ mod tests {
 					unstake_at: 0u64.saturated_into(),
 					retrieve_unstake_at: 0u64.saturated_into(),
 					account_id: 1,
-					services: Vec::new(),
-					qualifications: Vec::new(),
+					services: Default::default(),
+					qualifications: Default::default(),
 					stake_amount: 50000000000000000000000u128.saturated_into(),
 					stake_status: StakeStatus::Staked,
 					verification_status: VerificationStatus::Verified,
@@ -524,16 +714,28 @@ mod tests {
 						box_public_key: Keccak256::hash(
 							"0xDb9Af2d1f3ADD2726A132AA7A65Cc9E6fC5761C3".as_bytes(),
 						),
-						first_name: "First Name".as_bytes().to_vec(),
-						last_name: "Last Name".as_bytes().to_vec(),
-						gender: "Gender".as_bytes().to_vec(),
+						first_name: "First Name".as_bytes().to_vec().try_into().unwrap(),
+						last_name: "Last Name".as_bytes().to_vec().try_into().unwrap(),
+						gender: "Gender".as_bytes().to_vec().try_into().unwrap(),
 						date_of_birth: 0,
-						email: "Email".as_bytes().to_vec(),
-						phone_number: "+6893026516".as_bytes().to_vec(),
-						specialization: "DeBio Genetic Analyst".as_bytes().to_vec(),
-						profile_link: "DeBio Genetic Analyst profile_link".as_bytes().to_vec(),
+						email: "Email".as_bytes().to_vec().try_into().unwrap(),
+						phone_number: "+6893026516".as_bytes().to_vec().try_into().unwrap(),
+						specialization: "DeBio Genetic Analyst"
+							.as_bytes()
+							.to_vec()
+							.try_into()
+							.unwrap(),
+						profile_link: "DeBio Genetic Analyst profile_link"
+							.as_bytes()
+							.to_vec()
+							.try_into()
+							.unwrap(),
 						profile_image: Some(
-							"DeBio Genetic Analyst profile_image".as_bytes().to_vec()
+							"DeBio Genetic Analyst profile_image"
+								.as_bytes()
+								.to_vec()
+								.try_into()
+								.unwrap()
 						),
 					}
 				})
@@ -557,15 +759,25 @@ mod tests {
 					box_public_key: Keccak256::hash(
 						"0xDb9Af2d1f3ADD2726A132AA7A65Cc9E6fC5761C3".as_bytes(),
 					),
-					first_name: "First Name".as_bytes().to_vec(),
-					last_name: "Last Name".as_bytes().to_vec(),
-					gender: "Gender".as_bytes().to_vec(),
+					first_name: "First Name".as_bytes().to_vec().try_into().unwrap(),
+					last_name: "Last Name".as_bytes().to_vec().try_into().unwrap(),
+					gender: "Gender".as_bytes().to_vec().try_into().unwrap(),
 					date_of_birth: 0,
-					email: "Email".as_bytes().to_vec(),
-					phone_number: "+6893026516".as_bytes().to_vec(),
-					specialization: "DeBio Genetic Analyst".as_bytes().to_vec(),
-					profile_link: "DeBio Genetic Analyst profile_link".as_bytes().to_vec(),
-					profile_image: Some("DeBio Genetic Analyst profile_image".as_bytes().to_vec()),
+					email: "Email".as_bytes().to_vec().try_into().unwrap(),
+					phone_number: "+6893026516".as_bytes().to_vec().try_into().unwrap(),
+					specialization: "DeBio Genetic Analyst".as_bytes().to_vec().try_into().unwrap(),
+					profile_link: "DeBio Genetic Analyst profile_link"
+						.as_bytes()
+						.to_vec()
+						.try_into()
+						.unwrap(),
+					profile_image: Some(
+						"DeBio Genetic Analyst profile_image"
+							.as_bytes()
+							.to_vec()
+							.try_into()
+							.unwrap()
+					),
 				}
 			));
 
@@ -586,8 +798,8 @@ mod tests {
 					unstake_at: 0u64.saturated_into(),
 					retrieve_unstake_at: 0u64.saturated_into(),
 					account_id: 1,
-					services: Vec::new(),
-					qualifications: Vec::new(),
+					services: Default::default(),
+					qualifications: Default::default(),
 					stake_amount: 0u128.saturated_into(),
 					stake_status: StakeStatus::Unstaked,
 					verification_status: VerificationStatus::Rejected,
@@ -596,16 +808,28 @@ mod tests {
 						box_public_key: Keccak256::hash(
 							"0xDb9Af2d1f3ADD2726A132AA7A65Cc9E6fC5761C3".as_bytes(),
 						),
-						first_name: "First Name".as_bytes().to_vec(),
-						last_name: "Last Name".as_bytes().to_vec(),
-						gender: "Gender".as_bytes().to_vec(),
+						first_name: "First Name".as_bytes().to_vec().try_into().unwrap(),
+						last_name: "Last Name".as_bytes().to_vec().try_into().unwrap(),
+						gender: "Gender".as_bytes().to_vec().try_into().unwrap(),
 						date_of_birth: 0,
-						email: "Email".as_bytes().to_vec(),
-						phone_number: "+6893026516".as_bytes().to_vec(),
-						specialization: "DeBio Genetic Analyst".as_bytes().to_vec(),
-						profile_link: "DeBio Genetic Analyst profile_link".as_bytes().to_vec(),
+						email: "Email".as_bytes().to_vec().try_into().unwrap(),
+						phone_number: "+6893026516".as_bytes().to_vec().try_into().unwrap(),
+						specialization: "DeBio Genetic Analyst"
+							.as_bytes()
+							.to_vec()
+							.try_into()
+							.unwrap(),
+						profile_link: "DeBio Genetic Analyst profile_link"
+							.as_bytes()
+							.to_vec()
+							.try_into()
+							.unwrap(),
 						profile_image: Some(
-							"DeBio Genetic Analyst profile_image".as_bytes().to_vec()
+							"DeBio Genetic Analyst profile_image"
+								.as_bytes()
+								.to_vec()
+								.try_into()
+								.unwrap()
 						),
 					}
 				})
@@ -624,15 +848,25 @@ mod tests {
 					box_public_key: Keccak256::hash(
 						"0xDb9Af2d1f3ADD2726A132AA7A65Cc9E6fC5761C3".as_bytes(),
 					),
-					first_name: "First Name".as_bytes().to_vec(),
-					last_name: "Last Name".as_bytes().to_vec(),
-					gender: "Gender".as_bytes().to_vec(),
+					first_name: "First Name".as_bytes().to_vec().try_into().unwrap(),
+					last_name: "Last Name".as_bytes().to_vec().try_into().unwrap(),
+					gender: "Gender".as_bytes().to_vec().try_into().unwrap(),
 					date_of_birth: 0,
-					email: "Email".as_bytes().to_vec(),
-					phone_number: "+6893026516".as_bytes().to_vec(),
-					specialization: "DeBio Genetic Analyst".as_bytes().to_vec(),
-					profile_link: "DeBio Genetic Analyst profile_link".as_bytes().to_vec(),
-					profile_image: Some("DeBio Genetic Analyst profile_image".as_bytes().to_vec()),
+					email: "Email".as_bytes().to_vec().try_into().unwrap(),
+					phone_number: "+6893026516".as_bytes().to_vec().try_into().unwrap(),
+					specialization: "DeBio Genetic Analyst".as_bytes().to_vec().try_into().unwrap(),
+					profile_link: "DeBio Genetic Analyst profile_link"
+						.as_bytes()
+						.to_vec()
+						.try_into()
+						.unwrap(),
+					profile_image: Some(
+						"DeBio Genetic Analyst profile_image"
+							.as_bytes()
+							.to_vec()
+							.try_into()
+							.unwrap()
+					),
 				}
 			));
 
@@ -656,15 +890,25 @@ mod tests {
 					box_public_key: Keccak256::hash(
 						"0xDb9Af2d1f3ADD2726A132AA7A65Cc9E6fC5761C3".as_bytes(),
 					),
-					first_name: "First Name".as_bytes().to_vec(),
-					last_name: "Last Name".as_bytes().to_vec(),
-					gender: "Gender".as_bytes().to_vec(),
+					first_name: "First Name".as_bytes().to_vec().try_into().unwrap(),
+					last_name: "Last Name".as_bytes().to_vec().try_into().unwrap(),
+					gender: "Gender".as_bytes().to_vec().try_into().unwrap(),
 					date_of_birth: 0,
-					email: "Email".as_bytes().to_vec(),
-					phone_number: "+6893026516".as_bytes().to_vec(),
-					specialization: "DeBio Genetic Analyst".as_bytes().to_vec(),
-					profile_link: "DeBio Genetic Analyst profile_link".as_bytes().to_vec(),
-					profile_image: Some("DeBio Genetic Analyst profile_image".as_bytes().to_vec()),
+					email: "Email".as_bytes().to_vec().try_into().unwrap(),
+					phone_number: "+6893026516".as_bytes().to_vec().try_into().unwrap(),
+					specialization: "DeBio Genetic Analyst".as_bytes().to_vec().try_into().unwrap(),
+					profile_link: "DeBio Genetic Analyst profile_link"
+						.as_bytes()
+						.to_vec()
+						.try_into()
+						.unwrap(),
+					profile_image: Some(
+						"DeBio Genetic Analyst profile_image"
+							.as_bytes()
+							.to_vec()
+							.try_into()
+							.unwrap()
+					),
 				}
 			));
 
@@ -685,8 +929,8 @@ mod tests {
 					unstake_at: 0u64.saturated_into(),
 					retrieve_unstake_at: 0u64.saturated_into(),
 					account_id: 1,
-					services: Vec::new(),
-					qualifications: Vec::new(),
+					services: Default::default(),
+					qualifications: Default::default(),
 					stake_amount: 0u128.saturated_into(),
 					stake_status: StakeStatus::default(),
 					verification_status: VerificationStatus::default(),
@@ -695,16 +939,28 @@ mod tests {
 						box_public_key: Keccak256::hash(
 							"0xDb9Af2d1f3ADD2726A132AA7A65Cc9E6fC5761C3".as_bytes(),
 						),
-						first_name: "First Name".as_bytes().to_vec(),
-						last_name: "Last Name".as_bytes().to_vec(),
-						gender: "Gender".as_bytes().to_vec(),
+						first_name: "First Name".as_bytes().to_vec().try_into().unwrap(),
+						last_name: "Last Name".as_bytes().to_vec().try_into().unwrap(),
+						gender: "Gender".as_bytes().to_vec().try_into().unwrap(),
 						date_of_birth: 0,
-						email: "Email".as_bytes().to_vec(),
-						phone_number: "+6893026516".as_bytes().to_vec(),
-						specialization: "DeBio Genetic Analyst".as_bytes().to_vec(),
-						profile_link: "DeBio Genetic Analyst profile_link".as_bytes().to_vec(),
+						email: "Email".as_bytes().to_vec().try_into().unwrap(),
+						phone_number: "+6893026516".as_bytes().to_vec().try_into().unwrap(),
+						specialization: "DeBio Genetic Analyst"
+							.as_bytes()
+							.to_vec()
+							.try_into()
+							.unwrap(),
+						profile_link: "DeBio Genetic Analyst profile_link"
+							.as_bytes()
+							.to_vec()
+							.try_into()
+							.unwrap(),
 						profile_image: Some(
-							"DeBio Genetic Analyst profile_image".as_bytes().to_vec()
+							"DeBio Genetic Analyst profile_image"
+								.as_bytes()
+								.to_vec()
+								.try_into()
+								.unwrap()
 						),
 					}
 				})
@@ -746,15 +1002,25 @@ mod tests {
 					box_public_key: Keccak256::hash(
 						"0xDb9Af2d1f3ADD2726A132AA7A65Cc9E6fC5761C3".as_bytes(),
 					),
-					first_name: "First Name".as_bytes().to_vec(),
-					last_name: "Last Name".as_bytes().to_vec(),
-					gender: "Gender".as_bytes().to_vec(),
+					first_name: "First Name".as_bytes().to_vec().try_into().unwrap(),
+					last_name: "Last Name".as_bytes().to_vec().try_into().unwrap(),
+					gender: "Gender".as_bytes().to_vec().try_into().unwrap(),
 					date_of_birth: 0,
-					email: "Email".as_bytes().to_vec(),
-					phone_number: "+6893026516".as_bytes().to_vec(),
-					specialization: "DeBio Genetic Analyst".as_bytes().to_vec(),
-					profile_link: "DeBio Genetic Analyst profile_link".as_bytes().to_vec(),
-					profile_image: Some("DeBio Genetic Analyst profile_image".as_bytes().to_vec()),
+					email: "Email".as_bytes().to_vec().try_into().unwrap(),
+					phone_number: "+6893026516".as_bytes().to_vec().try_into().unwrap(),
+					specialization: "DeBio Genetic Analyst".as_bytes().to_vec().try_into().unwrap(),
+					profile_link: "DeBio Genetic Analyst profile_link"
+						.as_bytes()
+						.to_vec()
+						.try_into()
+						.unwrap(),
+					profile_image: Some(
+						"DeBio Genetic Analyst profile_image"
+							.as_bytes()
+							.to_vec()
+							.try_into()
+							.unwrap()
+					),
 				}
 			));
 
@@ -804,15 +1070,25 @@ mod tests {
 					box_public_key: Keccak256::hash(
 						"0xDb9Af2d1f3ADD2726A132AA7A65Cc9E6fC5761C3".as_bytes(),
 					),
-					first_name: "First Name".as_bytes().to_vec(),
-					last_name: "Last Name".as_bytes().to_vec(),
-					gender: "Gender".as_bytes().to_vec(),
+					first_name: "First Name".as_bytes().to_vec().try_into().unwrap(),
+					last_name: "Last Name".as_bytes().to_vec().try_into().unwrap(),
+					gender: "Gender".as_bytes().to_vec().try_into().unwrap(),
 					date_of_birth: 0,
-					email: "Email".as_bytes().to_vec(),
-					phone_number: "+6893026516".as_bytes().to_vec(),
-					specialization: "DeBio Genetic Analyst".as_bytes().to_vec(),
-					profile_link: "DeBio Genetic Analyst profile_link".as_bytes().to_vec(),
-					profile_image: Some("DeBio Genetic Analyst profile_image".as_bytes().to_vec()),
+					email: "Email".as_bytes().to_vec().try_into().unwrap(),
+					phone_number: "+6893026516".as_bytes().to_vec().try_into().unwrap(),
+					specialization: "DeBio Genetic Analyst".as_bytes().to_vec().try_into().unwrap(),
+					profile_link: "DeBio Genetic Analyst profile_link"
+						.as_bytes()
+						.to_vec()
+						.try_into()
+						.unwrap(),
+					profile_image: Some(
+						"DeBio Genetic Analyst profile_image"
+							.as_bytes()
+							.to_vec()
+							.try_into()
+							.unwrap()
+					),
 				}
 			));
 
@@ -824,8 +1100,8 @@ mod tests {
 					unstake_at: 0u64.saturated_into(),
 					retrieve_unstake_at: 0u64.saturated_into(),
 					account_id: 1,
-					services: Vec::new(),
-					qualifications: Vec::new(),
+					services: Default::default(),
+					qualifications: Default::default(),
 					stake_amount: 60000000000000000000000u128.saturated_into(),
 					stake_status: StakeStatus::Staked,
 					verification_status: VerificationStatus::default(),
@@ -834,16 +1110,28 @@ mod tests {
 						box_public_key: Keccak256::hash(
 							"0xDb9Af2d1f3ADD2726A132AA7A65Cc9E6fC5761C3".as_bytes(),
 						),
-						first_name: "First Name".as_bytes().to_vec(),
-						last_name: "Last Name".as_bytes().to_vec(),
-						gender: "Gender".as_bytes().to_vec(),
+						first_name: "First Name".as_bytes().to_vec().try_into().unwrap(),
+						last_name: "Last Name".as_bytes().to_vec().try_into().unwrap(),
+						gender: "Gender".as_bytes().to_vec().try_into().unwrap(),
 						date_of_birth: 0,
-						email: "Email".as_bytes().to_vec(),
-						phone_number: "+6893026516".as_bytes().to_vec(),
-						specialization: "DeBio Genetic Analyst".as_bytes().to_vec(),
-						profile_link: "DeBio Genetic Analyst profile_link".as_bytes().to_vec(),
+						email: "Email".as_bytes().to_vec().try_into().unwrap(),
+						phone_number: "+6893026516".as_bytes().to_vec().try_into().unwrap(),
+						specialization: "DeBio Genetic Analyst"
+							.as_bytes()
+							.to_vec()
+							.try_into()
+							.unwrap(),
+						profile_link: "DeBio Genetic Analyst profile_link"
+							.as_bytes()
+							.to_vec()
+							.try_into()
+							.unwrap(),
 						profile_image: Some(
-							"DeBio Genetic Analyst profile_image".as_bytes().to_vec()
+							"DeBio Genetic Analyst profile_image"
+								.as_bytes()
+								.to_vec()
+								.try_into()
+								.unwrap()
 						),
 					}
 				})
@@ -880,15 +1168,25 @@ mod tests {
 					box_public_key: Keccak256::hash(
 						"0xDb9Af2d1f3ADD2726A132AA7A65Cc9E6fC5761C3".as_bytes(),
 					),
-					first_name: "First Name".as_bytes().to_vec(),
-					last_name: "Last Name".as_bytes().to_vec(),
-					gender: "Gender".as_bytes().to_vec(),
+					first_name: "First Name".as_bytes().to_vec().try_into().unwrap(),
+					last_name: "Last Name".as_bytes().to_vec().try_into().unwrap(),
+					gender: "Gender".as_bytes().to_vec().try_into().unwrap(),
 					date_of_birth: 0,
-					email: "Email".as_bytes().to_vec(),
-					phone_number: "+6893026516".as_bytes().to_vec(),
-					specialization: "DeBio Genetic Analyst".as_bytes().to_vec(),
-					profile_link: "DeBio Genetic Analyst profile_link".as_bytes().to_vec(),
-					profile_image: Some("DeBio Genetic Analyst profile_image".as_bytes().to_vec()),
+					email: "Email".as_bytes().to_vec().try_into().unwrap(),
+					phone_number: "+6893026516".as_bytes().to_vec().try_into().unwrap(),
+					specialization: "DeBio Genetic Analyst".as_bytes().to_vec().try_into().unwrap(),
+					profile_link: "DeBio Genetic Analyst profile_link"
+						.as_bytes()
+						.to_vec()
+						.try_into()
+						.unwrap(),
+					profile_image: Some(
+						"DeBio Genetic Analyst profile_image"
+							.as_bytes()
+							.to_vec()
+							.try_into()
+							.unwrap()
+					),
 				}
 			));
 
@@ -900,8 +1198,8 @@ mod tests {
 					unstake_at: 0u64.saturated_into(),
 					retrieve_unstake_at: 0u64.saturated_into(),
 					account_id: 1,
-					services: Vec::new(),
-					qualifications: Vec::new(),
+					services: Default::default(),
+					qualifications: Default::default(),
 					stake_amount: 50000000000000000000000u128.saturated_into(),
 					stake_status: StakeStatus::Staked,
 					verification_status: VerificationStatus::default(),
@@ -910,16 +1208,28 @@ mod tests {
 						box_public_key: Keccak256::hash(
 							"0xDb9Af2d1f3ADD2726A132AA7A65Cc9E6fC5761C3".as_bytes(),
 						),
-						first_name: "First Name".as_bytes().to_vec(),
-						last_name: "Last Name".as_bytes().to_vec(),
-						gender: "Gender".as_bytes().to_vec(),
+						first_name: "First Name".as_bytes().to_vec().try_into().unwrap(),
+						last_name: "Last Name".as_bytes().to_vec().try_into().unwrap(),
+						gender: "Gender".as_bytes().to_vec().try_into().unwrap(),
 						date_of_birth: 0,
-						email: "Email".as_bytes().to_vec(),
-						phone_number: "+6893026516".as_bytes().to_vec(),
-						specialization: "DeBio Genetic Analyst".as_bytes().to_vec(),
-						profile_link: "DeBio Genetic Analyst profile_link".as_bytes().to_vec(),
+						email: "Email".as_bytes().to_vec().try_into().unwrap(),
+						phone_number: "+6893026516".as_bytes().to_vec().try_into().unwrap(),
+						specialization: "DeBio Genetic Analyst"
+							.as_bytes()
+							.to_vec()
+							.try_into()
+							.unwrap(),
+						profile_link: "DeBio Genetic Analyst profile_link"
+							.as_bytes()
+							.to_vec()
+							.try_into()
+							.unwrap(),
 						profile_image: Some(
-							"DeBio Genetic Analyst profile_image".as_bytes().to_vec()
+							"DeBio Genetic Analyst profile_image"
+								.as_bytes()
+								.to_vec()
+								.try_into()
+								.unwrap()
 						),
 					}
 				})
@@ -951,15 +1261,25 @@ mod tests {
 					box_public_key: Keccak256::hash(
 						"0xDb9Af2d1f3ADD2726A132AA7A65Cc9E6fC5761C3".as_bytes(),
 					),
-					first_name: "First Name".as_bytes().to_vec(),
-					last_name: "Last Name".as_bytes().to_vec(),
-					gender: "Gender".as_bytes().to_vec(),
+					first_name: "First Name".as_bytes().to_vec().try_into().unwrap(),
+					last_name: "Last Name".as_bytes().to_vec().try_into().unwrap(),
+					gender: "Gender".as_bytes().to_vec().try_into().unwrap(),
 					date_of_birth: 0,
-					email: "Email".as_bytes().to_vec(),
-					phone_number: "+6893026516".as_bytes().to_vec(),
-					specialization: "DeBio Genetic Analyst".as_bytes().to_vec(),
-					profile_link: "DeBio Genetic Analyst profile_link".as_bytes().to_vec(),
-					profile_image: Some("DeBio Genetic Analyst profile_image".as_bytes().to_vec()),
+					email: "Email".as_bytes().to_vec().try_into().unwrap(),
+					phone_number: "+6893026516".as_bytes().to_vec().try_into().unwrap(),
+					specialization: "DeBio Genetic Analyst".as_bytes().to_vec().try_into().unwrap(),
+					profile_link: "DeBio Genetic Analyst profile_link"
+						.as_bytes()
+						.to_vec()
+						.try_into()
+						.unwrap(),
+					profile_image: Some(
+						"DeBio Genetic Analyst profile_image"
+							.as_bytes()
+							.to_vec()
+							.try_into()
+							.unwrap()
+					),
 				}
 			));
 
@@ -975,8 +1295,8 @@ mod tests {
 					unstake_at: 0u64.saturated_into(),
 					retrieve_unstake_at: 0u64.saturated_into(),
 					account_id: 1,
-					services: Vec::new(),
-					qualifications: Vec::new(),
+					services: Default::default(),
+					qualifications: Default::default(),
 					stake_amount: 50000000000000000000000u128.saturated_into(),
 					stake_status: StakeStatus::Staked,
 					verification_status: VerificationStatus::default(),
@@ -985,16 +1305,28 @@ mod tests {
 						box_public_key: Keccak256::hash(
 							"0xDb9Af2d1f3ADD2726A132AA7A65Cc9E6fC5761C3".as_bytes(),
 						),
-						first_name: "First Name".as_bytes().to_vec(),
-						last_name: "Last Name".as_bytes().to_vec(),
-						gender: "Gender".as_bytes().to_vec(),
+						first_name: "First Name".as_bytes().to_vec().try_into().unwrap(),
+						last_name: "Last Name".as_bytes().to_vec().try_into().unwrap(),
+						gender: "Gender".as_bytes().to_vec().try_into().unwrap(),
 						date_of_birth: 0,
-						email: "Email".as_bytes().to_vec(),
-						phone_number: "+6893026516".as_bytes().to_vec(),
-						specialization: "DeBio Genetic Analyst".as_bytes().to_vec(),
-						profile_link: "DeBio Genetic Analyst profile_link".as_bytes().to_vec(),
+						email: "Email".as_bytes().to_vec().try_into().unwrap(),
+						phone_number: "+6893026516".as_bytes().to_vec().try_into().unwrap(),
+						specialization: "DeBio Genetic Analyst"
+							.as_bytes()
+							.to_vec()
+							.try_into()
+							.unwrap(),
+						profile_link: "DeBio Genetic Analyst profile_link"
+							.as_bytes()
+							.to_vec()
+							.try_into()
+							.unwrap(),
 						profile_image: Some(
-							"DeBio Genetic Analyst profile_image".as_bytes().to_vec()
+							"DeBio Genetic Analyst profile_image"
+								.as_bytes()
+								.to_vec()
+								.try_into()
+								.unwrap()
 						),
 					}
 				})
@@ -1011,15 +1343,25 @@ mod tests {
 					box_public_key: Keccak256::hash(
 						"0xDb9Af2d1f3ADD2726A132AA7A65Cc9E6fC5761C3".as_bytes(),
 					),
-					first_name: "First Name".as_bytes().to_vec(),
-					last_name: "Last Name".as_bytes().to_vec(),
-					gender: "Gender".as_bytes().to_vec(),
+					first_name: "First Name".as_bytes().to_vec().try_into().unwrap(),
+					last_name: "Last Name".as_bytes().to_vec().try_into().unwrap(),
+					gender: "Gender".as_bytes().to_vec().try_into().unwrap(),
 					date_of_birth: 0,
-					email: "Email".as_bytes().to_vec(),
-					phone_number: "+6893026516".as_bytes().to_vec(),
-					specialization: "DeBio Genetic Analyst".as_bytes().to_vec(),
-					profile_link: "DeBio Genetic Analyst profile_link".as_bytes().to_vec(),
-					profile_image: Some("DeBio Genetic Analyst profile_image".as_bytes().to_vec()),
+					email: "Email".as_bytes().to_vec().try_into().unwrap(),
+					phone_number: "+6893026516".as_bytes().to_vec().try_into().unwrap(),
+					specialization: "DeBio Genetic Analyst".as_bytes().to_vec().try_into().unwrap(),
+					profile_link: "DeBio Genetic Analyst profile_link"
+						.as_bytes()
+						.to_vec()
+						.try_into()
+						.unwrap(),
+					profile_image: Some(
+						"DeBio Genetic Analyst profile_image"
+							.as_bytes()
+							.to_vec()
+							.try_into()
+							.unwrap()
+					),
 				}
 			));
 
@@ -1034,8 +1376,8 @@ mod tests {
 					unstake_at: 0u64.saturated_into(),
 					retrieve_unstake_at: 0u64.saturated_into(),
 					account_id: 1,
-					services: Vec::new(),
-					qualifications: Vec::new(),
+					services: Default::default(),
+					qualifications: Default::default(),
 					stake_amount: 0u128.saturated_into(),
 					stake_status: StakeStatus::default(),
 					verification_status: VerificationStatus::default(),
@@ -1044,16 +1386,28 @@ mod tests {
 						box_public_key: Keccak256::hash(
 							"0xDb9Af2d1f3ADD2726A132AA7A65Cc9E6fC5761C3".as_bytes(),
 						),
-						first_name: "First Name".as_bytes().to_vec(),
-						last_name: "Last Name".as_bytes().to_vec(),
-						gender: "Gender".as_bytes().to_vec(),
+						first_name: "First Name".as_bytes().to_vec().try_into().unwrap(),
+						last_name: "Last Name".as_bytes().to_vec().try_into().unwrap(),
+						gender: "Gender".as_bytes().to_vec().try_into().unwrap(),
 						date_of_birth: 0,
-						email: "Email".as_bytes().to_vec(),
-						phone_number: "+6893026516".as_bytes().to_vec(),
-						specialization: "DeBio Genetic Analyst".as_bytes().to_vec(),
-						profile_link: "DeBio Genetic Analyst profile_link".as_bytes().to_vec(),
+						email: "Email".as_bytes().to_vec().try_into().unwrap(),
+						phone_number: "+6893026516".as_bytes().to_vec().try_into().unwrap(),
+						specialization: "DeBio Genetic Analyst"
+							.as_bytes()
+							.to_vec()
+							.try_into()
+							.unwrap(),
+						profile_link: "DeBio Genetic Analyst profile_link"
+							.as_bytes()
+							.to_vec()
+							.try_into()
+							.unwrap(),
 						profile_image: Some(
-							"DeBio Genetic Analyst profile_image".as_bytes().to_vec()
+							"DeBio Genetic Analyst profile_image"
+								.as_bytes()
+								.to_vec()
+								.try_into()
+								.unwrap()
 						),
 					}
 				})
@@ -1183,15 +1537,25 @@ mod tests {
 					box_public_key: Keccak256::hash(
 						"0xDb9Af2d1f3ADD2726A132AA7A65Cc9E6fC5761C3".as_bytes(),
 					),
-					first_name: "First Name".as_bytes().to_vec(),
-					last_name: "Last Name".as_bytes().to_vec(),
-					gender: "Gender".as_bytes().to_vec(),
+					first_name: "First Name".as_bytes().to_vec().try_into().unwrap(),
+					last_name: "Last Name".as_bytes().to_vec().try_into().unwrap(),
+					gender: "Gender".as_bytes().to_vec().try_into().unwrap(),
 					date_of_birth: 0,
-					email: "Email".as_bytes().to_vec(),
-					phone_number: "+6893026516".as_bytes().to_vec(),
-					specialization: "DeBio Genetic Analyst".as_bytes().to_vec(),
-					profile_link: "DeBio Genetic Analyst profile_link".as_bytes().to_vec(),
-					profile_image: Some("DeBio Genetic Analyst profile_image".as_bytes().to_vec()),
+					email: "Email".as_bytes().to_vec().try_into().unwrap(),
+					phone_number: "+6893026516".as_bytes().to_vec().try_into().unwrap(),
+					specialization: "DeBio Genetic Analyst".as_bytes().to_vec().try_into().unwrap(),
+					profile_link: "DeBio Genetic Analyst profile_link"
+						.as_bytes()
+						.to_vec()
+						.try_into()
+						.unwrap(),
+					profile_image: Some(
+						"DeBio Genetic Analyst profile_image"
+							.as_bytes()
+							.to_vec()
+							.try_into()
+							.unwrap()
+					),
 				}
 			));
 
@@ -1217,8 +1581,8 @@ mod tests {
 					unstake_at: 0u64.saturated_into(),
 					retrieve_unstake_at: 0u64.saturated_into(),
 					account_id: 1,
-					services: Vec::new(),
-					qualifications: Vec::new(),
+					services: Default::default(),
+					qualifications: Default::default(),
 					stake_amount: 50000000000000000000000u128.saturated_into(),
 					stake_status: StakeStatus::Staked,
 					verification_status: VerificationStatus::Verified,
@@ -1227,16 +1591,28 @@ mod tests {
 						box_public_key: Keccak256::hash(
 							"0xDb9Af2d1f3ADD2726A132AA7A65Cc9E6fC5761C3".as_bytes(),
 						),
-						first_name: "First Name".as_bytes().to_vec(),
-						last_name: "Last Name".as_bytes().to_vec(),
-						gender: "Gender".as_bytes().to_vec(),
+						first_name: "First Name".as_bytes().to_vec().try_into().unwrap(),
+						last_name: "Last Name".as_bytes().to_vec().try_into().unwrap(),
+						gender: "Gender".as_bytes().to_vec().try_into().unwrap(),
 						date_of_birth: 0,
-						email: "Email".as_bytes().to_vec(),
-						phone_number: "+6893026516".as_bytes().to_vec(),
-						specialization: "DeBio Genetic Analyst".as_bytes().to_vec(),
-						profile_link: "DeBio Genetic Analyst profile_link".as_bytes().to_vec(),
+						email: "Email".as_bytes().to_vec().try_into().unwrap(),
+						phone_number: "+6893026516".as_bytes().to_vec().try_into().unwrap(),
+						specialization: "DeBio Genetic Analyst"
+							.as_bytes()
+							.to_vec()
+							.try_into()
+							.unwrap(),
+						profile_link: "DeBio Genetic Analyst profile_link"
+							.as_bytes()
+							.to_vec()
+							.try_into()
+							.unwrap(),
 						profile_image: Some(
-							"DeBio Genetic Analyst profile_image".as_bytes().to_vec()
+							"DeBio Genetic Analyst profile_image"
+								.as_bytes()
+								.to_vec()
+								.try_into()
+								.unwrap()
 						),
 					}
 				})
@@ -1260,15 +1636,25 @@ mod tests {
 					box_public_key: Keccak256::hash(
 						"0xDb9Af2d1f3ADD2726A132AA7A65Cc9E6fC5761C3".as_bytes(),
 					),
-					first_name: "First Name".as_bytes().to_vec(),
-					last_name: "Last Name".as_bytes().to_vec(),
-					gender: "Gender".as_bytes().to_vec(),
+					first_name: "First Name".as_bytes().to_vec().try_into().unwrap(),
+					last_name: "Last Name".as_bytes().to_vec().try_into().unwrap(),
+					gender: "Gender".as_bytes().to_vec().try_into().unwrap(),
 					date_of_birth: 0,
-					email: "Email".as_bytes().to_vec(),
-					phone_number: "+6893026516".as_bytes().to_vec(),
-					specialization: "DeBio Genetic Analyst".as_bytes().to_vec(),
-					profile_link: "DeBio Genetic Analyst profile_link".as_bytes().to_vec(),
-					profile_image: Some("DeBio Genetic Analyst profile_image".as_bytes().to_vec()),
+					email: "Email".as_bytes().to_vec().try_into().unwrap(),
+					phone_number: "+6893026516".as_bytes().to_vec().try_into().unwrap(),
+					specialization: "DeBio Genetic Analyst".as_bytes().to_vec().try_into().unwrap(),
+					profile_link: "DeBio Genetic Analyst profile_link"
+						.as_bytes()
+						.to_vec()
+						.try_into()
+						.unwrap(),
+					profile_image: Some(
+						"DeBio Genetic Analyst profile_image"
+							.as_bytes()
+							.to_vec()
+							.try_into()
+							.unwrap()
+					),
 				}
 			));
 
@@ -1298,8 +1684,8 @@ mod tests {
 					unstake_at: 0u64.saturated_into(),
 					retrieve_unstake_at: 0u64.saturated_into(),
 					account_id: 1,
-					services: Vec::new(),
-					qualifications: Vec::new(),
+					services: Default::default(),
+					qualifications: Default::default(),
 					stake_amount: 0u128.saturated_into(),
 					stake_status: StakeStatus::Staked,
 					verification_status: VerificationStatus::Unverified,
@@ -1308,16 +1694,28 @@ mod tests {
 						box_public_key: Keccak256::hash(
 							"0xDb9Af2d1f3ADD2726A132AA7A65Cc9E6fC5761C3".as_bytes(),
 						),
-						first_name: "First Name".as_bytes().to_vec(),
-						last_name: "Last Name".as_bytes().to_vec(),
-						gender: "Gender".as_bytes().to_vec(),
+						first_name: "First Name".as_bytes().to_vec().try_into().unwrap(),
+						last_name: "Last Name".as_bytes().to_vec().try_into().unwrap(),
+						gender: "Gender".as_bytes().to_vec().try_into().unwrap(),
 						date_of_birth: 0,
-						email: "Email".as_bytes().to_vec(),
-						phone_number: "+6893026516".as_bytes().to_vec(),
-						specialization: "DeBio Genetic Analyst".as_bytes().to_vec(),
-						profile_link: "DeBio Genetic Analyst profile_link".as_bytes().to_vec(),
+						email: "Email".as_bytes().to_vec().try_into().unwrap(),
+						phone_number: "+6893026516".as_bytes().to_vec().try_into().unwrap(),
+						specialization: "DeBio Genetic Analyst"
+							.as_bytes()
+							.to_vec()
+							.try_into()
+							.unwrap(),
+						profile_link: "DeBio Genetic Analyst profile_link"
+							.as_bytes()
+							.to_vec()
+							.try_into()
+							.unwrap(),
 						profile_image: Some(
-							"DeBio Genetic Analyst profile_image".as_bytes().to_vec()
+							"DeBio Genetic Analyst profile_image"
+								.as_bytes()
+								.to_vec()
+								.try_into()
+								.unwrap()
 						),
 					}
 				})
@@ -1364,15 +1762,25 @@ mod tests {
 					box_public_key: Keccak256::hash(
 						"0xDb9Af2d1f3ADD2726A132AA7A65Cc9E6fC5761C3".as_bytes(),
 					),
-					first_name: "First Name".as_bytes().to_vec(),
-					last_name: "Last Name".as_bytes().to_vec(),
-					gender: "Gender".as_bytes().to_vec(),
+					first_name: "First Name".as_bytes().to_vec().try_into().unwrap(),
+					last_name: "Last Name".as_bytes().to_vec().try_into().unwrap(),
+					gender: "Gender".as_bytes().to_vec().try_into().unwrap(),
 					date_of_birth: 0,
-					email: "Email".as_bytes().to_vec(),
-					phone_number: "+6893026516".as_bytes().to_vec(),
-					specialization: "DeBio Genetic Analyst".as_bytes().to_vec(),
-					profile_link: "DeBio Genetic Analyst profile_link".as_bytes().to_vec(),
-					profile_image: Some("DeBio Genetic Analyst profile_image".as_bytes().to_vec()),
+					email: "Email".as_bytes().to_vec().try_into().unwrap(),
+					phone_number: "+6893026516".as_bytes().to_vec().try_into().unwrap(),
+					specialization: "DeBio Genetic Analyst".as_bytes().to_vec().try_into().unwrap(),
+					profile_link: "DeBio Genetic Analyst profile_link"
+						.as_bytes()
+						.to_vec()
+						.try_into()
+						.unwrap(),
+					profile_image: Some(
+						"DeBio Genetic Analyst profile_image"
+							.as_bytes()
+							.to_vec()
+							.try_into()
+							.unwrap()
+					),
 				}
 			));
 
@@ -1386,8 +1794,8 @@ mod tests {
 					unstake_at: 0u64.saturated_into(),
 					retrieve_unstake_at: 0u64.saturated_into(),
 					account_id: 1,
-					services: Vec::new(),
-					qualifications: Vec::new(),
+					services: Default::default(),
+					qualifications: Default::default(),
 					stake_amount: 0u128.saturated_into(),
 					stake_status: StakeStatus::WaitingForUnstaked,
 					verification_status: VerificationStatus::default(),
@@ -1396,16 +1804,28 @@ mod tests {
 						box_public_key: Keccak256::hash(
 							"0xDb9Af2d1f3ADD2726A132AA7A65Cc9E6fC5761C3".as_bytes(),
 						),
-						first_name: "First Name".as_bytes().to_vec(),
-						last_name: "Last Name".as_bytes().to_vec(),
-						gender: "Gender".as_bytes().to_vec(),
+						first_name: "First Name".as_bytes().to_vec().try_into().unwrap(),
+						last_name: "Last Name".as_bytes().to_vec().try_into().unwrap(),
+						gender: "Gender".as_bytes().to_vec().try_into().unwrap(),
 						date_of_birth: 0,
-						email: "Email".as_bytes().to_vec(),
-						phone_number: "+6893026516".as_bytes().to_vec(),
-						specialization: "DeBio Genetic Analyst".as_bytes().to_vec(),
-						profile_link: "DeBio Genetic Analyst profile_link".as_bytes().to_vec(),
+						email: "Email".as_bytes().to_vec().try_into().unwrap(),
+						phone_number: "+6893026516".as_bytes().to_vec().try_into().unwrap(),
+						specialization: "DeBio Genetic Analyst"
+							.as_bytes()
+							.to_vec()
+							.try_into()
+							.unwrap(),
+						profile_link: "DeBio Genetic Analyst profile_link"
+							.as_bytes()
+							.to_vec()
+							.try_into()
+							.unwrap(),
 						profile_image: Some(
-							"DeBio Genetic Analyst profile_image".as_bytes().to_vec()
+							"DeBio Genetic Analyst profile_image"
+								.as_bytes()
+								.to_vec()
+								.try_into()
+								.unwrap()
 						),
 					}
 				})
@@ -1432,15 +1852,25 @@ mod tests {
 					box_public_key: Keccak256::hash(
 						"0xDb9Af2d1f3ADD2726A132AA7A65Cc9E6fC5761C3".as_bytes(),
 					),
-					first_name: "First Name".as_bytes().to_vec(),
-					last_name: "Last Name".as_bytes().to_vec(),
-					gender: "Gender".as_bytes().to_vec(),
+					first_name: "First Name".as_bytes().to_vec().try_into().unwrap(),
+					last_name: "Last Name".as_bytes().to_vec().try_into().unwrap(),
+					gender: "Gender".as_bytes().to_vec().try_into().unwrap(),
 					date_of_birth: 0,
-					email: "Email".as_bytes().to_vec(),
-					phone_number: "+6893026516".as_bytes().to_vec(),
-					specialization: "DeBio Genetic Analyst".as_bytes().to_vec(),
-					profile_link: "DeBio Genetic Analyst profile_link".as_bytes().to_vec(),
-					profile_image: Some("DeBio Genetic Analyst profile_image".as_bytes().to_vec()),
+					email: "Email".as_bytes().to_vec().try_into().unwrap(),
+					phone_number: "+6893026516".as_bytes().to_vec().try_into().unwrap(),
+					specialization: "DeBio Genetic Analyst".as_bytes().to_vec().try_into().unwrap(),
+					profile_link: "DeBio Genetic Analyst profile_link"
+						.as_bytes()
+						.to_vec()
+						.try_into()
+						.unwrap(),
+					profile_image: Some(
+						"DeBio Genetic Analyst profile_image"
+							.as_bytes()
+							.to_vec()
+							.try_into()
+							.unwrap()
+					),
 				}
 			));
 
@@ -1474,15 +1904,25 @@ mod tests {
 					box_public_key: Keccak256::hash(
 						"0xDb9Af2d1f3ADD2726A132AA7A65Cc9E6fC5761C3".as_bytes(),
 					),
-					first_name: "First Name".as_bytes().to_vec(),
-					last_name: "Last Name".as_bytes().to_vec(),
-					gender: "Gender".as_bytes().to_vec(),
+					first_name: "First Name".as_bytes().to_vec().try_into().unwrap(),
+					last_name: "Last Name".as_bytes().to_vec().try_into().unwrap(),
+					gender: "Gender".as_bytes().to_vec().try_into().unwrap(),
 					date_of_birth: 0,
-					email: "Email".as_bytes().to_vec(),
-					phone_number: "+6893026516".as_bytes().to_vec(),
-					specialization: "DeBio Genetic Analyst".as_bytes().to_vec(),
-					profile_link: "DeBio Genetic Analyst profile_link".as_bytes().to_vec(),
-					profile_image: Some("DeBio Genetic Analyst profile_image".as_bytes().to_vec()),
+					email: "Email".as_bytes().to_vec().try_into().unwrap(),
+					phone_number: "+6893026516".as_bytes().to_vec().try_into().unwrap(),
+					specialization: "DeBio Genetic Analyst".as_bytes().to_vec().try_into().unwrap(),
+					profile_link: "DeBio Genetic Analyst profile_link"
+						.as_bytes()
+						.to_vec()
+						.try_into()
+						.unwrap(),
+					profile_image: Some(
+						"DeBio Genetic Analyst profile_image"
+							.as_bytes()
+							.to_vec()
+							.try_into()
+							.unwrap()
+					),
 				}
 			));
 
@@ -1496,13 +1936,23 @@ mod tests {
 			assert_ok!(GeneticAnalystServices::create_genetic_analyst_service(
 				RuntimeOrigin::signed(1),
 				GeneticAnalystServiceInfo {
-					name: "DeBio Genetic Analyst Service name".as_bytes().to_vec(),
-					prices_by_currency: vec![PriceByCurrency::default()],
+					name: "DeBio Genetic Analyst Service name"
+						.as_bytes()
+						.to_vec()
+						.try_into()
+						.unwrap(),
+					prices_by_currency: vec![PriceByCurrency::default()].try_into().unwrap(),
 					expected_duration: ExpectedDuration::default(),
-					description: "DeBio Genetic Analyst Service description".as_bytes().to_vec(),
+					description: "DeBio Genetic Analyst Service description"
+						.as_bytes()
+						.to_vec()
+						.try_into()
+						.unwrap(),
 					test_result_sample: "DeBio Genetic Analyst Service test_result_sample"
 						.as_bytes()
-						.to_vec(),
+						.to_vec()
+						.try_into()
+						.unwrap(),
 				},
 			));
 
@@ -1529,8 +1979,7 @@ mod tests {
 
 			let _genetic_analysis_order_id =
 				GeneticAnalysisOrders::last_genetic_analysis_order_by_customer_id(1).unwrap();
-			let _genetic_analysis =
-				GeneticAnalysis::genetic_analysis_by_genetic_analyst_id(1).unwrap();
+			let _genetic_analysis = GeneticAnalysis::genetic_analysis_by_genetic_analyst_id(&1);
 
 			assert_eq!(
 				GeneticAnalysisOrders::genetic_analysis_order_by_id(&_genetic_analysis_order_id),
@@ -1586,15 +2035,25 @@ mod tests {
 					box_public_key: Keccak256::hash(
 						"0xDb9Af2d1f3ADD2726A132AA7A65Cc9E6fC5761C3".as_bytes(),
 					),
-					first_name: "First Name".as_bytes().to_vec(),
-					last_name: "Last Name".as_bytes().to_vec(),
-					gender: "Gender".as_bytes().to_vec(),
+					first_name: "First Name".as_bytes().to_vec().try_into().unwrap(),
+					last_name: "Last Name".as_bytes().to_vec().try_into().unwrap(),
+					gender: "Gender".as_bytes().to_vec().try_into().unwrap(),
 					date_of_birth: 0,
-					email: "Email".as_bytes().to_vec(),
-					phone_number: "+6893026516".as_bytes().to_vec(),
-					specialization: "DeBio Genetic Analyst".as_bytes().to_vec(),
-					profile_link: "DeBio Genetic Analyst profile_link".as_bytes().to_vec(),
-					profile_image: Some("DeBio Genetic Analyst profile_image".as_bytes().to_vec()),
+					email: "Email".as_bytes().to_vec().try_into().unwrap(),
+					phone_number: "+6893026516".as_bytes().to_vec().try_into().unwrap(),
+					specialization: "DeBio Genetic Analyst".as_bytes().to_vec().try_into().unwrap(),
+					profile_link: "DeBio Genetic Analyst profile_link"
+						.as_bytes()
+						.to_vec()
+						.try_into()
+						.unwrap(),
+					profile_image: Some(
+						"DeBio Genetic Analyst profile_image"
+							.as_bytes()
+							.to_vec()
+							.try_into()
+							.unwrap()
+					),
 				}
 			));
 
@@ -1608,13 +2067,23 @@ mod tests {
 			assert_ok!(GeneticAnalystServices::create_genetic_analyst_service(
 				RuntimeOrigin::signed(1),
 				GeneticAnalystServiceInfo {
-					name: "DeBio Genetic Analyst Service name".as_bytes().to_vec(),
-					prices_by_currency: vec![PriceByCurrency::default()],
+					name: "DeBio Genetic Analyst Service name"
+						.as_bytes()
+						.to_vec()
+						.try_into()
+						.unwrap(),
+					prices_by_currency: vec![PriceByCurrency::default()].try_into().unwrap(),
 					expected_duration: ExpectedDuration::default(),
-					description: "DeBio Genetic Analyst Service description".as_bytes().to_vec(),
+					description: "DeBio Genetic Analyst Service description"
+						.as_bytes()
+						.to_vec()
+						.try_into()
+						.unwrap(),
 					test_result_sample: "DeBio Genetic Analyst Service test_result_sample"
 						.as_bytes()
-						.to_vec(),
+						.to_vec()
+						.try_into()
+						.unwrap(),
 				},
 			));
 
@@ -1641,8 +2110,7 @@ mod tests {
 
 			let _genetic_analysis_order_id =
 				GeneticAnalysisOrders::last_genetic_analysis_order_by_customer_id(1).unwrap();
-			let _genetic_analysis =
-				GeneticAnalysis::genetic_analysis_by_genetic_analyst_id(1).unwrap();
+			let _genetic_analysis = GeneticAnalysis::genetic_analysis_by_genetic_analyst_id(&1);
 
 			assert_eq!(
 				GeneticAnalysisOrders::genetic_analysis_order_by_id(&_genetic_analysis_order_id),
@@ -1701,15 +2169,25 @@ mod tests {
 					box_public_key: Keccak256::hash(
 						"0xDb9Af2d1f3ADD2726A132AA7A65Cc9E6fC5761C3".as_bytes(),
 					),
-					first_name: "First Name".as_bytes().to_vec(),
-					last_name: "Last Name".as_bytes().to_vec(),
-					gender: "Gender".as_bytes().to_vec(),
+					first_name: "First Name".as_bytes().to_vec().try_into().unwrap(),
+					last_name: "Last Name".as_bytes().to_vec().try_into().unwrap(),
+					gender: "Gender".as_bytes().to_vec().try_into().unwrap(),
 					date_of_birth: 0,
-					email: "Email".as_bytes().to_vec(),
-					phone_number: "+6893026516".as_bytes().to_vec(),
-					specialization: "DeBio Genetic Analyst".as_bytes().to_vec(),
-					profile_link: "DeBio Genetic Analyst profile_link".as_bytes().to_vec(),
-					profile_image: Some("DeBio Genetic Analyst profile_image".as_bytes().to_vec()),
+					email: "Email".as_bytes().to_vec().try_into().unwrap(),
+					phone_number: "+6893026516".as_bytes().to_vec().try_into().unwrap(),
+					specialization: "DeBio Genetic Analyst".as_bytes().to_vec().try_into().unwrap(),
+					profile_link: "DeBio Genetic Analyst profile_link"
+						.as_bytes()
+						.to_vec()
+						.try_into()
+						.unwrap(),
+					profile_image: Some(
+						"DeBio Genetic Analyst profile_image"
+							.as_bytes()
+							.to_vec()
+							.try_into()
+							.unwrap()
+					),
 				}
 			));
 
@@ -1723,13 +2201,23 @@ mod tests {
 			assert_ok!(GeneticAnalystServices::create_genetic_analyst_service(
 				RuntimeOrigin::signed(1),
 				GeneticAnalystServiceInfo {
-					name: "DeBio Genetic Analyst Service name".as_bytes().to_vec(),
-					prices_by_currency: vec![PriceByCurrency::default()],
+					name: "DeBio Genetic Analyst Service name"
+						.as_bytes()
+						.to_vec()
+						.try_into()
+						.unwrap(),
+					prices_by_currency: vec![PriceByCurrency::default()].try_into().unwrap(),
 					expected_duration: ExpectedDuration::default(),
-					description: "DeBio Genetic Analyst Service description".as_bytes().to_vec(),
+					description: "DeBio Genetic Analyst Service description"
+						.as_bytes()
+						.to_vec()
+						.try_into()
+						.unwrap(),
 					test_result_sample: "DeBio Genetic Analyst Service test_result_sample"
 						.as_bytes()
-						.to_vec(),
+						.to_vec()
+						.try_into()
+						.unwrap(),
 				},
 			));
 
@@ -1756,8 +2244,7 @@ mod tests {
 
 			let _genetic_analysis_order_id =
 				GeneticAnalysisOrders::last_genetic_analysis_order_by_customer_id(1).unwrap();
-			let _genetic_analysis =
-				GeneticAnalysis::genetic_analysis_by_genetic_analyst_id(1).unwrap();
+			let _genetic_analysis = GeneticAnalysis::genetic_analysis_by_genetic_analyst_id(&1);
 
 			assert_eq!(
 				GeneticAnalysisOrders::genetic_analysis_order_by_id(&_genetic_analysis_order_id),
@@ -1820,15 +2307,25 @@ mod tests {
 					box_public_key: Keccak256::hash(
 						"0xDb9Af2d1f3ADD2726A132AA7A65Cc9E6fC5761C3".as_bytes(),
 					),
-					first_name: "First Name".as_bytes().to_vec(),
-					last_name: "Last Name".as_bytes().to_vec(),
-					gender: "Gender".as_bytes().to_vec(),
+					first_name: "First Name".as_bytes().to_vec().try_into().unwrap(),
+					last_name: "Last Name".as_bytes().to_vec().try_into().unwrap(),
+					gender: "Gender".as_bytes().to_vec().try_into().unwrap(),
 					date_of_birth: 0,
-					email: "Email".as_bytes().to_vec(),
-					phone_number: "+6893026516".as_bytes().to_vec(),
-					specialization: "DeBio Genetic Analyst".as_bytes().to_vec(),
-					profile_link: "DeBio Genetic Analyst profile_link".as_bytes().to_vec(),
-					profile_image: Some("DeBio Genetic Analyst profile_image".as_bytes().to_vec()),
+					email: "Email".as_bytes().to_vec().try_into().unwrap(),
+					phone_number: "+6893026516".as_bytes().to_vec().try_into().unwrap(),
+					specialization: "DeBio Genetic Analyst".as_bytes().to_vec().try_into().unwrap(),
+					profile_link: "DeBio Genetic Analyst profile_link"
+						.as_bytes()
+						.to_vec()
+						.try_into()
+						.unwrap(),
+					profile_image: Some(
+						"DeBio Genetic Analyst profile_image"
+							.as_bytes()
+							.to_vec()
+							.try_into()
+							.unwrap()
+					),
 				}
 			));
 
@@ -1842,13 +2339,23 @@ mod tests {
 			assert_ok!(GeneticAnalystServices::create_genetic_analyst_service(
 				RuntimeOrigin::signed(1),
 				GeneticAnalystServiceInfo {
-					name: "DeBio Genetic Analyst Service name".as_bytes().to_vec(),
-					prices_by_currency: vec![PriceByCurrency::default()],
+					name: "DeBio Genetic Analyst Service name"
+						.as_bytes()
+						.to_vec()
+						.try_into()
+						.unwrap(),
+					prices_by_currency: vec![PriceByCurrency::default()].try_into().unwrap(),
 					expected_duration: ExpectedDuration::default(),
-					description: "DeBio Genetic Analyst Service description".as_bytes().to_vec(),
+					description: "DeBio Genetic Analyst Service description"
+						.as_bytes()
+						.to_vec()
+						.try_into()
+						.unwrap(),
 					test_result_sample: "DeBio Genetic Analyst Service test_result_sample"
 						.as_bytes()
-						.to_vec(),
+						.to_vec()
+						.try_into()
+						.unwrap(),
 				},
 			));
 
@@ -1881,8 +2388,7 @@ mod tests {
 				_genetic_analysis_order_id
 			));
 
-			let _genetic_analysis =
-				GeneticAnalysis::genetic_analysis_by_genetic_analyst_id(1).unwrap();
+			let _genetic_analysis = GeneticAnalysis::genetic_analysis_by_genetic_analyst_id(&1);
 
 			assert_ok!(GeneticAnalysis::reject_genetic_analysis(
 				RuntimeOrigin::signed(1),
@@ -1933,15 +2439,25 @@ mod tests {
 					box_public_key: Keccak256::hash(
 						"0xDb9Af2d1f3ADD2726A132AA7A65Cc9E6fC5761C3".as_bytes(),
 					),
-					first_name: "First Name".as_bytes().to_vec(),
-					last_name: "Last Name".as_bytes().to_vec(),
-					gender: "Gender".as_bytes().to_vec(),
+					first_name: "First Name".as_bytes().to_vec().try_into().unwrap(),
+					last_name: "Last Name".as_bytes().to_vec().try_into().unwrap(),
+					gender: "Gender".as_bytes().to_vec().try_into().unwrap(),
 					date_of_birth: 0,
-					email: "Email".as_bytes().to_vec(),
-					phone_number: "+6893026516".as_bytes().to_vec(),
-					specialization: "DeBio Genetic Analyst".as_bytes().to_vec(),
-					profile_link: "DeBio Genetic Analyst profile_link".as_bytes().to_vec(),
-					profile_image: Some("DeBio Genetic Analyst profile_image".as_bytes().to_vec()),
+					email: "Email".as_bytes().to_vec().try_into().unwrap(),
+					phone_number: "+6893026516".as_bytes().to_vec().try_into().unwrap(),
+					specialization: "DeBio Genetic Analyst".as_bytes().to_vec().try_into().unwrap(),
+					profile_link: "DeBio Genetic Analyst profile_link"
+						.as_bytes()
+						.to_vec()
+						.try_into()
+						.unwrap(),
+					profile_image: Some(
+						"DeBio Genetic Analyst profile_image"
+							.as_bytes()
+							.to_vec()
+							.try_into()
+							.unwrap()
+					),
 				}
 			));
 
@@ -1960,13 +2476,23 @@ mod tests {
 			assert_ok!(GeneticAnalystServices::create_genetic_analyst_service(
 				RuntimeOrigin::signed(1),
 				GeneticAnalystServiceInfo {
-					name: "DeBio Genetic Analyst Service name".as_bytes().to_vec(),
-					prices_by_currency: vec![PriceByCurrency::default()],
+					name: "DeBio Genetic Analyst Service name"
+						.as_bytes()
+						.to_vec()
+						.try_into()
+						.unwrap(),
+					prices_by_currency: vec![PriceByCurrency::default()].try_into().unwrap(),
 					expected_duration: ExpectedDuration::default(),
-					description: "DeBio Genetic Analyst Service description".as_bytes().to_vec(),
+					description: "DeBio Genetic Analyst Service description"
+						.as_bytes()
+						.to_vec()
+						.try_into()
+						.unwrap(),
 					test_result_sample: "DeBio Genetic Analyst Service test_result_sample"
 						.as_bytes()
-						.to_vec(),
+						.to_vec()
+						.try_into()
+						.unwrap(),
 				}
 			));
 
@@ -1999,8 +2525,7 @@ mod tests {
 				_genetic_analysis_order_id
 			));
 
-			let _genetic_analysis =
-				GeneticAnalysis::genetic_analysis_by_genetic_analyst_id(1).unwrap();
+			let _genetic_analysis = GeneticAnalysis::genetic_analysis_by_genetic_analyst_id(&1);
 
 			assert_ok!(GeneticAnalysis::submit_genetic_analysis(
 				RuntimeOrigin::signed(1),
@@ -2071,15 +2596,25 @@ mod tests {
 					box_public_key: Keccak256::hash(
 						"0xDb9Af2d1f3ADD2726A132AA7A65Cc9E6fC5761C3".as_bytes(),
 					),
-					first_name: "First Name".as_bytes().to_vec(),
-					last_name: "Last Name".as_bytes().to_vec(),
-					gender: "Gender".as_bytes().to_vec(),
+					first_name: "First Name".as_bytes().to_vec().try_into().unwrap(),
+					last_name: "Last Name".as_bytes().to_vec().try_into().unwrap(),
+					gender: "Gender".as_bytes().to_vec().try_into().unwrap(),
 					date_of_birth: 0,
-					email: "Email".as_bytes().to_vec(),
-					phone_number: "+6893026516".as_bytes().to_vec(),
-					specialization: "DeBio Genetic Analyst".as_bytes().to_vec(),
-					profile_link: "DeBio Genetic Analyst profile_link".as_bytes().to_vec(),
-					profile_image: Some("DeBio Genetic Analyst profile_image".as_bytes().to_vec()),
+					email: "Email".as_bytes().to_vec().try_into().unwrap(),
+					phone_number: "+6893026516".as_bytes().to_vec().try_into().unwrap(),
+					specialization: "DeBio Genetic Analyst".as_bytes().to_vec().try_into().unwrap(),
+					profile_link: "DeBio Genetic Analyst profile_link"
+						.as_bytes()
+						.to_vec()
+						.try_into()
+						.unwrap(),
+					profile_image: Some(
+						"DeBio Genetic Analyst profile_image"
+							.as_bytes()
+							.to_vec()
+							.try_into()
+							.unwrap()
+					),
 				}
 			));
 
@@ -2093,8 +2628,8 @@ mod tests {
 					unstake_at: 0u64.saturated_into(),
 					retrieve_unstake_at: 0u64.saturated_into(),
 					account_id: 1,
-					services: Vec::new(),
-					qualifications: Vec::new(),
+					services: Default::default(),
+					qualifications: Default::default(),
 					stake_amount: 50000000000000000000000u128.saturated_into(),
 					stake_status: StakeStatus::WaitingForUnstaked,
 					verification_status: VerificationStatus::default(),
@@ -2103,16 +2638,28 @@ mod tests {
 						box_public_key: Keccak256::hash(
 							"0xDb9Af2d1f3ADD2726A132AA7A65Cc9E6fC5761C3".as_bytes(),
 						),
-						first_name: "First Name".as_bytes().to_vec(),
-						last_name: "Last Name".as_bytes().to_vec(),
-						gender: "Gender".as_bytes().to_vec(),
+						first_name: "First Name".as_bytes().to_vec().try_into().unwrap(),
+						last_name: "Last Name".as_bytes().to_vec().try_into().unwrap(),
+						gender: "Gender".as_bytes().to_vec().try_into().unwrap(),
 						date_of_birth: 0,
-						email: "Email".as_bytes().to_vec(),
-						phone_number: "+6893026516".as_bytes().to_vec(),
-						specialization: "DeBio Genetic Analyst".as_bytes().to_vec(),
-						profile_link: "DeBio Genetic Analyst profile_link".as_bytes().to_vec(),
+						email: "Email".as_bytes().to_vec().try_into().unwrap(),
+						phone_number: "+6893026516".as_bytes().to_vec().try_into().unwrap(),
+						specialization: "DeBio Genetic Analyst"
+							.as_bytes()
+							.to_vec()
+							.try_into()
+							.unwrap(),
+						profile_link: "DeBio Genetic Analyst profile_link"
+							.as_bytes()
+							.to_vec()
+							.try_into()
+							.unwrap(),
 						profile_image: Some(
-							"DeBio Genetic Analyst profile_image".as_bytes().to_vec()
+							"DeBio Genetic Analyst profile_image"
+								.as_bytes()
+								.to_vec()
+								.try_into()
+								.unwrap()
 						),
 					}
 				})
@@ -2126,8 +2673,8 @@ mod tests {
 					unstake_at: 0u64.saturated_into(),
 					retrieve_unstake_at: 0u64.saturated_into(),
 					account_id: 1,
-					services: Vec::new(),
-					qualifications: Vec::new(),
+					services: Default::default(),
+					qualifications: Default::default(),
 					stake_amount: 0u128.saturated_into(),
 					stake_status: StakeStatus::Unstaked,
 					verification_status: VerificationStatus::default(),
@@ -2136,16 +2683,28 @@ mod tests {
 						box_public_key: Keccak256::hash(
 							"0xDb9Af2d1f3ADD2726A132AA7A65Cc9E6fC5761C3".as_bytes(),
 						),
-						first_name: "First Name".as_bytes().to_vec(),
-						last_name: "Last Name".as_bytes().to_vec(),
-						gender: "Gender".as_bytes().to_vec(),
+						first_name: "First Name".as_bytes().to_vec().try_into().unwrap(),
+						last_name: "Last Name".as_bytes().to_vec().try_into().unwrap(),
+						gender: "Gender".as_bytes().to_vec().try_into().unwrap(),
 						date_of_birth: 0,
-						email: "Email".as_bytes().to_vec(),
-						phone_number: "+6893026516".as_bytes().to_vec(),
-						specialization: "DeBio Genetic Analyst".as_bytes().to_vec(),
-						profile_link: "DeBio Genetic Analyst profile_link".as_bytes().to_vec(),
+						email: "Email".as_bytes().to_vec().try_into().unwrap(),
+						phone_number: "+6893026516".as_bytes().to_vec().try_into().unwrap(),
+						specialization: "DeBio Genetic Analyst"
+							.as_bytes()
+							.to_vec()
+							.try_into()
+							.unwrap(),
+						profile_link: "DeBio Genetic Analyst profile_link"
+							.as_bytes()
+							.to_vec()
+							.try_into()
+							.unwrap(),
 						profile_image: Some(
-							"DeBio Genetic Analyst profile_image".as_bytes().to_vec()
+							"DeBio Genetic Analyst profile_image"
+								.as_bytes()
+								.to_vec()
+								.try_into()
+								.unwrap()
 						),
 					}
 				})
@@ -2176,15 +2735,25 @@ mod tests {
 					box_public_key: Keccak256::hash(
 						"0xDb9Af2d1f3ADD2726A132AA7A65Cc9E6fC5761C3".as_bytes(),
 					),
-					first_name: "First Name".as_bytes().to_vec(),
-					last_name: "Last Name".as_bytes().to_vec(),
-					gender: "Gender".as_bytes().to_vec(),
+					first_name: "First Name".as_bytes().to_vec().try_into().unwrap(),
+					last_name: "Last Name".as_bytes().to_vec().try_into().unwrap(),
+					gender: "Gender".as_bytes().to_vec().try_into().unwrap(),
 					date_of_birth: 0,
-					email: "Email".as_bytes().to_vec(),
-					phone_number: "+6893026516".as_bytes().to_vec(),
-					specialization: "DeBio Genetic Analyst".as_bytes().to_vec(),
-					profile_link: "DeBio Genetic Analyst profile_link".as_bytes().to_vec(),
-					profile_image: Some("DeBio Genetic Analyst profile_image".as_bytes().to_vec()),
+					email: "Email".as_bytes().to_vec().try_into().unwrap(),
+					phone_number: "+6893026516".as_bytes().to_vec().try_into().unwrap(),
+					specialization: "DeBio Genetic Analyst".as_bytes().to_vec().try_into().unwrap(),
+					profile_link: "DeBio Genetic Analyst profile_link"
+						.as_bytes()
+						.to_vec()
+						.try_into()
+						.unwrap(),
+					profile_image: Some(
+						"DeBio Genetic Analyst profile_image"
+							.as_bytes()
+							.to_vec()
+							.try_into()
+							.unwrap()
+					),
 				}
 			));
 
@@ -2219,15 +2788,25 @@ mod tests {
 					box_public_key: Keccak256::hash(
 						"0xDb9Af2d1f3ADD2726A132AA7A65Cc9E6fC5761C3".as_bytes(),
 					),
-					first_name: "First Name".as_bytes().to_vec(),
-					last_name: "Last Name".as_bytes().to_vec(),
-					gender: "Gender".as_bytes().to_vec(),
+					first_name: "First Name".as_bytes().to_vec().try_into().unwrap(),
+					last_name: "Last Name".as_bytes().to_vec().try_into().unwrap(),
+					gender: "Gender".as_bytes().to_vec().try_into().unwrap(),
 					date_of_birth: 0,
-					email: "Email".as_bytes().to_vec(),
-					phone_number: "+6893026516".as_bytes().to_vec(),
-					specialization: "DeBio Genetic Analyst".as_bytes().to_vec(),
-					profile_link: "DeBio Genetic Analyst profile_link".as_bytes().to_vec(),
-					profile_image: Some("DeBio Genetic Analyst profile_image".as_bytes().to_vec()),
+					email: "Email".as_bytes().to_vec().try_into().unwrap(),
+					phone_number: "+6893026516".as_bytes().to_vec().try_into().unwrap(),
+					specialization: "DeBio Genetic Analyst".as_bytes().to_vec().try_into().unwrap(),
+					profile_link: "DeBio Genetic Analyst profile_link"
+						.as_bytes()
+						.to_vec()
+						.try_into()
+						.unwrap(),
+					profile_image: Some(
+						"DeBio Genetic Analyst profile_image"
+							.as_bytes()
+							.to_vec()
+							.try_into()
+							.unwrap()
+					),
 				}
 			));
 
@@ -2241,8 +2820,8 @@ mod tests {
 					unstake_at: 0u64.saturated_into(),
 					retrieve_unstake_at: 100000u64.saturated_into(),
 					account_id: 1,
-					services: Vec::new(),
-					qualifications: Vec::new(),
+					services: Default::default(),
+					qualifications: Default::default(),
 					stake_amount: 50000000000000000000000u128.saturated_into(),
 					stake_status: StakeStatus::WaitingForUnstaked,
 					verification_status: VerificationStatus::default(),
@@ -2251,16 +2830,28 @@ mod tests {
 						box_public_key: Keccak256::hash(
 							"0xDb9Af2d1f3ADD2726A132AA7A65Cc9E6fC5761C3".as_bytes(),
 						),
-						first_name: "First Name".as_bytes().to_vec(),
-						last_name: "Last Name".as_bytes().to_vec(),
-						gender: "Gender".as_bytes().to_vec(),
+						first_name: "First Name".as_bytes().to_vec().try_into().unwrap(),
+						last_name: "Last Name".as_bytes().to_vec().try_into().unwrap(),
+						gender: "Gender".as_bytes().to_vec().try_into().unwrap(),
 						date_of_birth: 0,
-						email: "Email".as_bytes().to_vec(),
-						phone_number: "+6893026516".as_bytes().to_vec(),
-						specialization: "DeBio Genetic Analyst".as_bytes().to_vec(),
-						profile_link: "DeBio Genetic Analyst profile_link".as_bytes().to_vec(),
+						email: "Email".as_bytes().to_vec().try_into().unwrap(),
+						phone_number: "+6893026516".as_bytes().to_vec().try_into().unwrap(),
+						specialization: "DeBio Genetic Analyst"
+							.as_bytes()
+							.to_vec()
+							.try_into()
+							.unwrap(),
+						profile_link: "DeBio Genetic Analyst profile_link"
+							.as_bytes()
+							.to_vec()
+							.try_into()
+							.unwrap(),
 						profile_image: Some(
-							"DeBio Genetic Analyst profile_image".as_bytes().to_vec()
+							"DeBio Genetic Analyst profile_image"
+								.as_bytes()
+								.to_vec()
+								.try_into()
+								.unwrap()
 						),
 					}
 				})
@@ -2297,15 +2888,25 @@ mod tests {
 					box_public_key: Keccak256::hash(
 						"0xDb9Af2d1f3ADD2726A132AA7A65Cc9E6fC5761C3".as_bytes(),
 					),
-					first_name: "First Name".as_bytes().to_vec(),
-					last_name: "Last Name".as_bytes().to_vec(),
-					gender: "Gender".as_bytes().to_vec(),
+					first_name: "First Name".as_bytes().to_vec().try_into().unwrap(),
+					last_name: "Last Name".as_bytes().to_vec().try_into().unwrap(),
+					gender: "Gender".as_bytes().to_vec().try_into().unwrap(),
 					date_of_birth: 0,
-					email: "Email".as_bytes().to_vec(),
-					phone_number: "+6893026516".as_bytes().to_vec(),
-					specialization: "DeBio Genetic Analyst".as_bytes().to_vec(),
-					profile_link: "DeBio Genetic Analyst profile_link".as_bytes().to_vec(),
-					profile_image: Some("DeBio Genetic Analyst profile_image".as_bytes().to_vec()),
+					email: "Email".as_bytes().to_vec().try_into().unwrap(),
+					phone_number: "+6893026516".as_bytes().to_vec().try_into().unwrap(),
+					specialization: "DeBio Genetic Analyst".as_bytes().to_vec().try_into().unwrap(),
+					profile_link: "DeBio Genetic Analyst profile_link"
+						.as_bytes()
+						.to_vec()
+						.try_into()
+						.unwrap(),
+					profile_image: Some(
+						"DeBio Genetic Analyst profile_image"
+							.as_bytes()
+							.to_vec()
+							.try_into()
+							.unwrap()
+					),
 				}
 			));
 
@@ -2326,4 +2927,54 @@ mod tests {
 			);
 		})
 	}
+
+	#[test]
+	fn migrate_truncates_genetic_analyst_info_over_its_bound() {
+		<ExternalityBuilder>::default().existential_deposit(1).build().execute_with(|| {
+			let box_public_key =
+				Keccak256::hash("0xDb9Af2d1f3ADD2726A132AA7A65Cc9E6fC5761C3".as_bytes());
+			// A genetic analyst stored before genetic analyst info was bounded
+			let legacy_info = (
+				box_public_key,
+				vec![b'X'; MAX_GENETIC_ANALYST_INFO_LENGTH as usize + 1],
+				"Last Name".as_bytes().to_vec(),
+				"Gender".as_bytes().to_vec(),
+				0u64,
+				"Email".as_bytes().to_vec(),
+				"+6893026516".as_bytes().to_vec(),
+				"DeBio Genetic Analyst".as_bytes().to_vec(),
+				"DeBio Genetic Analyst profile_link".as_bytes().to_vec(),
+				None::<Vec<u8>>,
+			);
+			let legacy_genetic_analyst = (
+				1u64,
+				Vec::<sp_core::H256>::new(),
+				Vec::<sp_core::H256>::new(),
+				legacy_info,
+				0u128,
+				StakeStatus::default(),
+				VerificationStatus::default(),
+				AvailabilityStatus::default(),
+				0u64,
+				0u64,
+			);
+
+			unhashed::put(
+				&GeneticAnalystsStorage::<Test>::hashed_key_for(1),
+				&legacy_genetic_analyst,
+			);
+			StorageVersion::new(3).put::<GeneticAnalysts>();
+
+			genetic_analysts::migrations::migrate::<Test>();
+
+			let genetic_analyst = GeneticAnalysts::genetic_analyst_by_account_id(1).unwrap();
+
+			assert_eq!(
+				genetic_analyst.info.first_name.to_vec(),
+				vec![b'X'; MAX_GENETIC_ANALYST_INFO_LENGTH as usize]
+			);
+			assert_eq!(genetic_analyst.info.email.to_vec(), "Email".as_bytes().to_vec());
+			assert_eq!(StorageVersion::get::<GeneticAnalysts>(), StorageVersion::new(4));
+		})
+	}
 }
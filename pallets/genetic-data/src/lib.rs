@@ -3,6 +3,7 @@
 use frame_support::{
 	codec::{Decode, Encode},
 	pallet_prelude::*,
+	traits::StorageVersion,
 };
 pub use pallet::*;
 pub use scale_info::TypeInfo;
@@ -17,18 +18,30 @@ mod tests;
 mod benchmarking;
 
 pub mod interface;
+pub mod migrations;
 pub mod weights;
 pub use interface::GeneticDataInterface;
 use sp_std::prelude::*;
 use traits_genetic_data::{GeneticData as GeneticDataT, GeneticDataProvider};
 
-#[derive(Encode, Decode, Clone, Default, RuntimeDebug, PartialEq, Eq, TypeInfo)]
+/// Maximum length of the title and report link of a genetic data
+pub const MAX_GENETIC_DATA_TEXT_LENGTH: u32 = 512;
+/// Maximum length of the description of a genetic data
+pub const MAX_GENETIC_DATA_DESCRIPTION_LENGTH: u32 = 4096;
+
+pub type GeneticDataText = BoundedVec<u8, ConstU32<MAX_GENETIC_DATA_TEXT_LENGTH>>;
+pub type GeneticDataDescription = BoundedVec<u8, ConstU32<MAX_GENETIC_DATA_DESCRIPTION_LENGTH>>;
+
+/// The current storage version.
+const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
+
+#[derive(Encode, Decode, Clone, Default, RuntimeDebug, PartialEq, Eq, TypeInfo, MaxEncodedLen)]
 pub struct GeneticData<AccountId, Hash, Moment> {
 	pub id: Hash,
 	pub owner_id: AccountId,
-	pub title: Vec<u8>,
-	pub description: Vec<u8>,
-	pub report_link: Vec<u8>,
+	pub title: GeneticDataText,
+	pub description: GeneticDataDescription,
+	pub report_link: GeneticDataText,
 	pub created_at: Moment,
 	pub updated_at: Moment,
 }
@@ -37,9 +50,9 @@ impl<AccountId, Hash, Moment: Default> GeneticData<AccountId, Hash, Moment> {
 	pub fn new(
 		id: Hash,
 		owner_id: AccountId,
-		title: Vec<u8>,
-		description: Vec<u8>,
-		report_link: Vec<u8>,
+		title: GeneticDataText,
+		description: GeneticDataDescription,
+		report_link: GeneticDataText,
 		created_at: Moment,
 	) -> Self {
 		Self {
@@ -76,7 +89,9 @@ where
 
 #[frame_support::pallet]
 pub mod pallet {
-	use crate::{interface::GeneticDataInterface, weights::WeightInfo, GeneticData};
+	use crate::{
+		interface::GeneticDataInterface, weights::WeightInfo, GeneticData, STORAGE_VERSION,
+	};
 	use frame_support::{dispatch::DispatchResultWithPostInfo, pallet_prelude::*};
 	use frame_system::pallet_prelude::*;
 	pub use sp_std::prelude::*;
@@ -85,16 +100,28 @@ pub mod pallet {
 	pub trait Config: frame_system::Config + pallet_timestamp::Config {
 		type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
 		type GeneticDataWeightInfo: WeightInfo;
+		/// Maximum number of genetic data indexed per owner.
+		#[pallet::constant]
+		type MaxGeneticDataPerOwner: Get<u32>;
 	}
 
 	// ----- This is template code, every pallet needs this ---
 	#[pallet::pallet]
+	#[pallet::storage_version(STORAGE_VERSION)]
 	#[pallet::generate_store(pub(super) trait Store)]
-	#[pallet::without_storage_info]
 	pub struct Pallet<T>(_);
 
 	#[pallet::hooks]
-	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {}
+	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+		fn on_runtime_upgrade() -> Weight {
+			crate::migrations::migrate::<T>()
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn pre_upgrade() -> Result<Vec<u8>, &'static str> {
+			crate::migrations::pre_upgrade::<T>()
+		}
+	}
 	// --------------------------------------------------------
 
 	// ----- Types -------
@@ -103,12 +130,14 @@ pub mod pallet {
 	pub type MomentOf<T> = <T as pallet_timestamp::Config>::Moment;
 	pub type GeneticDataOf<T> = GeneticData<AccountIdOf<T>, HashOf<T>, MomentOf<T>>;
 	pub type GeneticDataIdOf<T> = HashOf<T>;
+	pub type GeneticDataIdsOf<T> =
+		BoundedVec<GeneticDataIdOf<T>, <T as Config>::MaxGeneticDataPerOwner>;
 
 	// ------- Storage -------------
 	#[pallet::storage]
 	#[pallet::getter(fn genetic_data_by_owner_id)]
 	pub type GeneticDataByOwner<T> =
-		StorageMap<_, Blake2_128Concat, AccountIdOf<T>, Vec<GeneticDataIdOf<T>>>;
+		StorageMap<_, Blake2_128Concat, AccountIdOf<T>, GeneticDataIdsOf<T>>;
 
 	#[pallet::storage]
 	#[pallet::getter(fn genetic_data_by_id)]
@@ -148,6 +177,14 @@ pub mod pallet {
 		NotGeneticDataOwner,
 		/// Ordering a genetic_data that does not exist
 		GeneticDataDoesNotExist,
+		/// Title exceeds the maximum length
+		TitleTooLong,
+		/// Description exceeds the maximum length
+		DescriptionTooLong,
+		/// Report link exceeds the maximum length
+		ReportLinkTooLong,
+		/// Owner already has MaxGeneticDataPerOwner genetic data
+		TooManyGeneticData,
 	}
 
 	#[pallet::call]
@@ -253,16 +290,17 @@ impl<T: Config> GeneticDataInterface<T> for Pallet<T> {
 		let genetic_data = GeneticData::new(
 			genetic_data_id,
 			owner_id.clone(),
-			title.to_vec(),
-			description.to_vec(),
-			report_link.to_vec(),
+			title.to_vec().try_into().map_err(|_| Error::<T>::TitleTooLong)?,
+			description.to_vec().try_into().map_err(|_| Error::<T>::DescriptionTooLong)?,
+			report_link.to_vec().try_into().map_err(|_| Error::<T>::ReportLinkTooLong)?,
 			now,
 		);
 
+		Self::add_genetic_data_by_owner(owner_id, &genetic_data_id)?;
+
 		// Store to GeneticDataById storage
 		GeneticDataById::<T>::insert(genetic_data_id, &genetic_data);
 
-		Self::add_genetic_data_by_owner(owner_id, &genetic_data_id);
 		Self::add_genetic_data_count();
 		Self::add_genetic_data_count_by_owner(owner_id);
 
@@ -288,9 +326,11 @@ impl<T: Config> GeneticDataInterface<T> for Pallet<T> {
 
 		let now = pallet_timestamp::Pallet::<T>::get();
 
-		genetic_data.title = title.to_vec();
-		genetic_data.description = description.to_vec();
-		genetic_data.report_link = report_link.to_vec();
+		genetic_data.title = title.to_vec().try_into().map_err(|_| Error::<T>::TitleTooLong)?;
+		genetic_data.description =
+			description.to_vec().try_into().map_err(|_| Error::<T>::DescriptionTooLong)?;
+		genetic_data.report_link =
+			report_link.to_vec().try_into().map_err(|_| Error::<T>::ReportLinkTooLong)?;
 		genetic_data.updated_at = now;
 
 		// Store to GeneticDataById storage
@@ -324,7 +364,7 @@ impl<T: Config> GeneticDataInterface<T> for Pallet<T> {
 	}
 
 	fn genetic_data_by_owner_id(owner_id: &T::AccountId) -> Option<Vec<T::Hash>> {
-		GeneticDataByOwner::<T>::get(owner_id)
+		GeneticDataByOwner::<T>::get(owner_id).map(BoundedVec::into_inner)
 	}
 
 	fn genetic_data_count_by_owner(owner_id: &T::AccountId) -> u64 {
@@ -339,11 +379,17 @@ impl<T: Config> GeneticDataInterface<T> for Pallet<T> {
 /// Pallet Methods
 impl<T: Config> Pallet<T> {
 	// Add genetic_data by owner
-	pub fn add_genetic_data_by_owner(owner_id: &T::AccountId, genetic_data_id: &T::Hash) {
+	pub fn add_genetic_data_by_owner(
+		owner_id: &T::AccountId,
+		genetic_data_id: &T::Hash,
+	) -> Result<(), Error<T>> {
 		let mut genetic_data = GeneticDataByOwner::<T>::get(owner_id).unwrap_or_default();
 
-		genetic_data.push(*genetic_data_id);
-		GeneticDataByOwner::<T>::insert(owner_id, &genetic_data)
+		genetic_data
+			.try_push(*genetic_data_id)
+			.map_err(|_| Error::<T>::TooManyGeneticData)?;
+		GeneticDataByOwner::<T>::insert(owner_id, &genetic_data);
+		Ok(())
 	}
 
 	// Subtract genetic_data by owner
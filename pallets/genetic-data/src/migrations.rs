@@ -0,0 +1,132 @@
+use crate::{
+	AccountIdOf, Config, GeneticData, GeneticDataById, GeneticDataIdOf, GeneticDataOf, HashOf,
+	MomentOf, Pallet,
+};
+use frame_support::{
+	codec::{Decode, Encode},
+	log,
+	storage::migration::storage_key_iter,
+	traits::{Get, PalletInfoAccess},
+	weights::Weight,
+	Blake2_128Concat, BoundedVec,
+};
+use sp_std::vec::Vec;
+
+/// Fails when a legacy genetic data index does not fit in `MaxGeneticDataPerOwner`
+#[cfg(feature = "try-runtime")]
+pub fn pre_upgrade<T: Config>() -> Result<Vec<u8>, &'static str> {
+	use frame_support::traits::StorageVersion;
+
+	let max_genetic_data = T::MaxGeneticDataPerOwner::get() as usize;
+
+	if StorageVersion::get::<Pallet<T>>() < 1 &&
+		version::v1::genetic_data_indexes::<T>()
+			.any(|(_, genetic_data_ids)| genetic_data_ids.len() > max_genetic_data)
+	{
+		return Err("Genetic data index holds more ids than MaxGeneticDataPerOwner")
+	}
+
+	Ok(Vec::new())
+}
+
+pub fn migrate<T: Config>() -> Weight {
+	use frame_support::traits::StorageVersion;
+
+	let mut weight: Weight = Weight::zero();
+	let mut version = StorageVersion::get::<Pallet<T>>();
+
+	if version < 1 {
+		weight = weight.saturating_add(version::v1::migrate::<T>());
+		version = StorageVersion::new(1);
+	}
+
+	version.put::<Pallet<T>>();
+	weight
+}
+
+mod version {
+	use super::*;
+
+	pub mod v1 {
+		use super::*;
+
+		#[derive(Encode, Decode)]
+		pub struct OldGeneticData<AccountId, Hash, Moment> {
+			pub id: Hash,
+			pub owner_id: AccountId,
+			pub title: Vec<u8>,
+			pub description: Vec<u8>,
+			pub report_link: Vec<u8>,
+			pub created_at: Moment,
+			pub updated_at: Moment,
+		}
+
+		/// Truncates `value` to the bound and logs the entry when it did not fit.
+		fn bounded<S: Get<u32>>(value: Vec<u8>, entry: &str) -> BoundedVec<u8, S> {
+			if value.len() > S::get() as usize {
+				log::warn!(
+					target: "runtime::genetic-data",
+					"{} exceeds its bound of {}, truncated from {} bytes",
+					entry,
+					S::get(),
+					value.len(),
+				);
+			}
+
+			BoundedVec::truncate_from(value)
+		}
+
+		/// Genetic data fields become bounded, legacy values that exceed a bound are truncated
+		/// and logged. Genetic data id indexes are bounded by `MaxGeneticDataPerOwner` and encode
+		/// like the lists they replace, so they are only checked. An index holding more than
+		/// `MaxGeneticDataPerOwner` ids is left as it is and reported instead of dropping ids,
+		/// raise `MaxGeneticDataPerOwner` above it before upgrading.
+		pub fn migrate<T: Config>() -> Weight {
+			let mut weight = T::DbWeight::get().writes(1);
+
+			GeneticDataById::<T>::translate(
+				|_key, old: OldGeneticData<AccountIdOf<T>, HashOf<T>, MomentOf<T>>| {
+					weight = weight.saturating_add(T::DbWeight::get().reads_writes(1, 1));
+
+					let genetic_data: GeneticDataOf<T> = GeneticData {
+						id: old.id,
+						owner_id: old.owner_id,
+						title: bounded(old.title, "Genetic data title"),
+						description: bounded(old.description, "Genetic data description"),
+						report_link: bounded(old.report_link, "Genetic data report link"),
+						created_at: old.created_at,
+						updated_at: old.updated_at,
+					};
+					Some(genetic_data)
+				},
+			);
+
+			let max_genetic_data = T::MaxGeneticDataPerOwner::get() as usize;
+
+			for (owner_id, genetic_data_ids) in genetic_data_indexes::<T>() {
+				weight = weight.saturating_add(T::DbWeight::get().reads(1));
+
+				if genetic_data_ids.len() > max_genetic_data {
+					log::error!(
+						target: "runtime::genetic-data",
+						"Genetic data index of {:?} holds {} ids, more than MaxGeneticDataPerOwner ({})",
+						owner_id,
+						genetic_data_ids.len(),
+						max_genetic_data,
+					);
+				}
+			}
+
+			weight
+		}
+
+		/// Genetic data id indexes as stored before v1
+		pub fn genetic_data_indexes<T: Config>(
+		) -> impl Iterator<Item = (AccountIdOf<T>, Vec<GeneticDataIdOf<T>>)> {
+			storage_key_iter::<AccountIdOf<T>, Vec<GeneticDataIdOf<T>>, Blake2_128Concat>(
+				Pallet::<T>::name().as_bytes(),
+				b"GeneticDataByOwner",
+			)
+		}
+	}
+}
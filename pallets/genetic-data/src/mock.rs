@@ -56,9 +56,14 @@ impl frame_system::Config for Test {
 	type MaxConsumers = frame_support::traits::ConstU32<16>;
 }
 
+parameter_types! {
+	pub static MaxGeneticDataPerOwner: u32 = 1_000;
+}
+
 impl genetic_data::Config for Test {
 	type RuntimeEvent = RuntimeEvent;
 	type GeneticDataWeightInfo = ();
+	type MaxGeneticDataPerOwner = MaxGeneticDataPerOwner;
 }
 
 pub type Moment = u64;
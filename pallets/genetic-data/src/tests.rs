@@ -1,4 +1,4 @@
-use crate::{mock::*, Error};
+use crate::{mock::*, Error, MAX_GENETIC_DATA_DESCRIPTION_LENGTH, MAX_GENETIC_DATA_TEXT_LENGTH};
 use frame_support::{
 	assert_noop, assert_ok,
 	sp_runtime::traits::{Hash, Keccak256},
@@ -224,3 +224,67 @@ fn update_genetic_data_not_genetic_data_owner() {
 		);
 	})
 }
+
+#[test]
+fn cant_add_genetic_data_when_title_too_long() {
+	ExternalityBuilder::build().execute_with(|| {
+		assert_noop!(
+			GeneticData::add_genetic_data(
+				RuntimeOrigin::signed(1),
+				vec![0u8; MAX_GENETIC_DATA_TEXT_LENGTH as usize + 1],
+				"DeBio Genetic Data Document Description".as_bytes().to_vec(),
+				"DeBio Genetic Data Link".as_bytes().to_vec()
+			),
+			Error::<Test>::TitleTooLong
+		);
+	})
+}
+
+#[test]
+fn cant_update_genetic_data_when_description_too_long() {
+	ExternalityBuilder::build().execute_with(|| {
+		assert_ok!(GeneticData::add_genetic_data(
+			RuntimeOrigin::signed(1),
+			"DeBio Genetic Data".as_bytes().to_vec(),
+			"DeBio Genetic Data Document Description".as_bytes().to_vec(),
+			"DeBio Genetic Data Link".as_bytes().to_vec()
+		));
+
+		let genetic_data_ids = GeneticData::genetic_data_by_owner_id(1).unwrap();
+
+		assert_noop!(
+			GeneticData::update_genetic_data(
+				RuntimeOrigin::signed(1),
+				genetic_data_ids[0],
+				"DeBio Genetic Data 2".as_bytes().to_vec(),
+				vec![0u8; MAX_GENETIC_DATA_DESCRIPTION_LENGTH as usize + 1],
+				"DeBio Genetic Data Link 2".as_bytes().to_vec()
+			),
+			Error::<Test>::DescriptionTooLong
+		);
+	})
+}
+
+#[test]
+fn cant_add_genetic_data_when_too_many_genetic_data() {
+	ExternalityBuilder::build().execute_with(|| {
+		MaxGeneticDataPerOwner::set(1);
+
+		assert_ok!(GeneticData::add_genetic_data(
+			RuntimeOrigin::signed(1),
+			"DeBio Genetic Data".as_bytes().to_vec(),
+			"DeBio Genetic Data Document Description".as_bytes().to_vec(),
+			"DeBio Genetic Data Link".as_bytes().to_vec()
+		));
+
+		assert_noop!(
+			GeneticData::add_genetic_data(
+				RuntimeOrigin::signed(1),
+				"DeBio Genetic Data 2".as_bytes().to_vec(),
+				"DeBio Genetic Data Document Description 2".as_bytes().to_vec(),
+				"DeBio Genetic Data Link 2".as_bytes().to_vec()
+			),
+			Error::<Test>::TooManyGeneticData
+		);
+	})
+}
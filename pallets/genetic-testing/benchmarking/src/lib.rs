@@ -51,17 +51,17 @@ benchmarks! {
 
 		let lab = LabInfo {
 			box_public_key: T::Hashing::hash("0xDb9Af2d1f3ADD2726A132AA7A65Cc9E6fC5761C3".as_bytes()),
-			name: "DeBio Lab".as_bytes().to_vec(),
-			email: "DeBio Email".as_bytes().to_vec(),
-			country: CountryCode::from_vec("DC".as_bytes().to_vec()),
-			region: RegionCode::from_vec("DBIO".as_bytes().to_vec()),
-			city: CityCode::from_vec("City".as_bytes().to_vec()),
-			address: "DeBio Address".as_bytes().to_vec(),
-			phone_number: "+6281394653625".as_bytes().to_vec(),
-			website: "DeBio Website".as_bytes().to_vec(),
-			latitude: Some("DeBio Latitude".as_bytes().to_vec()),
-			longitude: Some("DeBio Longtitude".as_bytes().to_vec()),
-			profile_image: Some("DeBio Profile Image uwu".as_bytes().to_vec()),
+			name: "DeBio Lab".as_bytes().to_vec().try_into().unwrap(),
+			email: "DeBio Email".as_bytes().to_vec().try_into().unwrap(),
+			country: CountryCode::try_from("ID".as_bytes().to_vec()).unwrap(),
+			region: RegionCode::try_from("JK".as_bytes().to_vec()).unwrap(),
+			city: CityCode::try_from("City".as_bytes().to_vec()).unwrap(),
+			address: "DeBio Address".as_bytes().to_vec().try_into().unwrap(),
+			phone_number: "+6281394653625".as_bytes().to_vec().try_into().unwrap(),
+			website: "DeBio Website".as_bytes().to_vec().try_into().unwrap(),
+			latitude: Some("-6.175392".as_bytes().to_vec().try_into().unwrap()),
+			longitude: Some("106.827153".as_bytes().to_vec().try_into().unwrap()),
+			profile_image: Some("DeBio Profile Image uwu".as_bytes().to_vec().try_into().unwrap()),
 		};
 		let _add_labs = Labs::<T>::register_lab(caller_origin.clone(), lab);
 
@@ -69,17 +69,17 @@ benchmarks! {
 		let _set_eth_address = UserProfile::<T>::set_eth_address(caller_origin.clone(), eth_address);
 
 		let service_info = ServiceInfo {
-			name: "DeBio name".as_bytes().to_vec(),
+			name: "DeBio name".as_bytes().to_vec().try_into().unwrap(),
 			prices_by_currency: vec![
 				PriceByCurrency::default()
-			],
+			].try_into().unwrap(),
 			expected_duration: ExpectedDuration::default(),
-			category: "DeBio category".as_bytes().to_vec(),
-			description: "This is my description".as_bytes().to_vec(),
-			test_result_sample: "Test result sample".as_bytes().to_vec(),
-			dna_collection_process : "Dna Collection Process".as_bytes().to_vec(),
-			long_description: Some("This is my long description".as_bytes().to_vec()),
-			image: Some("This is my image".as_bytes().to_vec()),
+			category: "DeBio category".as_bytes().to_vec().try_into().unwrap(),
+			description: "This is my description".as_bytes().to_vec().try_into().unwrap(),
+			test_result_sample: "Test result sample".as_bytes().to_vec().try_into().unwrap(),
+			dna_collection_process : "Dna Collection Process".as_bytes().to_vec().try_into().unwrap(),
+			long_description: Some("This is my long description".as_bytes().to_vec().try_into().unwrap()),
+			image: Some("This is my image".as_bytes().to_vec().try_into().unwrap()),
 		};
 		let _create_service = Services::<T>::create_service(caller_origin.clone(), service_info, ServiceFlow::default());
 
@@ -95,8 +95,7 @@ benchmarks! {
 			None,
 		);
 
-		let _order_id_list = Orders::<T>::orders_by_lab_id(caller.clone())
-			.unwrap();
+		let _order_id_list = Orders::<T>::orders_by_lab_id(&caller);
 		let _order = Orders::<T>::order_by_id(_order_id_list[0])
 			.unwrap();
 
@@ -114,17 +113,17 @@ benchmarks! {
 
 		let lab = LabInfo {
 			box_public_key: T::Hashing::hash("0xDb9Af2d1f3ADD2726A132AA7A65Cc9E6fC5761C3".as_bytes()),
-			name: "DeBio Lab".as_bytes().to_vec(),
-			email: "DeBio Email".as_bytes().to_vec(),
-			country: CountryCode::from_vec("DC".as_bytes().to_vec()),
-			region: RegionCode::from_vec("DBIO".as_bytes().to_vec()),
-			city: CityCode::from_vec("City".as_bytes().to_vec()),
-			address: "DeBio Address".as_bytes().to_vec(),
-			phone_number: "+6281394653625".as_bytes().to_vec(),
-			website: "DeBio Website".as_bytes().to_vec(),
-			latitude: Some("DeBio Latitude".as_bytes().to_vec()),
-			longitude: Some("DeBio Longtitude".as_bytes().to_vec()),
-			profile_image: Some("DeBio Profile Image uwu".as_bytes().to_vec()),
+			name: "DeBio Lab".as_bytes().to_vec().try_into().unwrap(),
+			email: "DeBio Email".as_bytes().to_vec().try_into().unwrap(),
+			country: CountryCode::try_from("ID".as_bytes().to_vec()).unwrap(),
+			region: RegionCode::try_from("JK".as_bytes().to_vec()).unwrap(),
+			city: CityCode::try_from("City".as_bytes().to_vec()).unwrap(),
+			address: "DeBio Address".as_bytes().to_vec().try_into().unwrap(),
+			phone_number: "+6281394653625".as_bytes().to_vec().try_into().unwrap(),
+			website: "DeBio Website".as_bytes().to_vec().try_into().unwrap(),
+			latitude: Some("-6.175392".as_bytes().to_vec().try_into().unwrap()),
+			longitude: Some("106.827153".as_bytes().to_vec().try_into().unwrap()),
+			profile_image: Some("DeBio Profile Image uwu".as_bytes().to_vec().try_into().unwrap()),
 		};
 		let _add_labs = Labs::<T>::register_lab(caller_origin.clone(), lab);
 
@@ -132,17 +131,17 @@ benchmarks! {
 		let _set_eth_address = UserProfile::<T>::set_eth_address(caller_origin.clone(), eth_address);
 
 		let service_info = ServiceInfo {
-			name: "DeBio name".as_bytes().to_vec(),
+			name: "DeBio name".as_bytes().to_vec().try_into().unwrap(),
 			prices_by_currency: vec![
 				PriceByCurrency::default()
-			],
+			].try_into().unwrap(),
 			expected_duration: ExpectedDuration::default(),
-			category: "DeBio category".as_bytes().to_vec(),
-			description: "This is my description".as_bytes().to_vec(),
-			test_result_sample: "Test result sample".as_bytes().to_vec(),
-			dna_collection_process : "Dna Collection Process".as_bytes().to_vec(),
-			long_description: Some("This is my long description".as_bytes().to_vec()),
-			image: Some("This is my image".as_bytes().to_vec()),
+			category: "DeBio category".as_bytes().to_vec().try_into().unwrap(),
+			description: "This is my description".as_bytes().to_vec().try_into().unwrap(),
+			test_result_sample: "Test result sample".as_bytes().to_vec().try_into().unwrap(),
+			dna_collection_process : "Dna Collection Process".as_bytes().to_vec().try_into().unwrap(),
+			long_description: Some("This is my long description".as_bytes().to_vec().try_into().unwrap()),
+			image: Some("This is my image".as_bytes().to_vec().try_into().unwrap()),
 		};
 		let _create_service = Services::<T>::create_service(caller_origin.clone(), service_info, ServiceFlow::default());
 
@@ -158,8 +157,7 @@ benchmarks! {
 			None,
 		);
 
-		let _order_id_list = Orders::<T>::orders_by_lab_id(caller.clone())
-			.unwrap();
+		let _order_id_list = Orders::<T>::orders_by_lab_id(&caller);
 		let _order = Orders::<T>::order_by_id(_order_id_list[0])
 			.unwrap();
 		let _order_paid = Orders::<T>::set_order_paid(caller_origin, _order_id_list[0]);
@@ -169,23 +167,85 @@ benchmarks! {
 		DnaSampleStatus::default()
 	)
 
+	process_dna_sample_with_evidence {
+		let caller: T::AccountId = T::AccountId::decode(&mut "18c79faa6203d8b8349b19cc72cc6bfd008c243ea998435847abf6618756ca0b".as_bytes()).unwrap();
+		let caller_origin = T::RuntimeOrigin::from(RawOrigin::Signed(caller.clone()));
+
+		let lab = LabInfo {
+			box_public_key: T::Hashing::hash("0xDb9Af2d1f3ADD2726A132AA7A65Cc9E6fC5761C3".as_bytes()),
+			name: "DeBio Lab".as_bytes().to_vec().try_into().unwrap(),
+			email: "DeBio Email".as_bytes().to_vec().try_into().unwrap(),
+			country: CountryCode::try_from("ID".as_bytes().to_vec()).unwrap(),
+			region: RegionCode::try_from("JK".as_bytes().to_vec()).unwrap(),
+			city: CityCode::try_from("City".as_bytes().to_vec()).unwrap(),
+			address: "DeBio Address".as_bytes().to_vec().try_into().unwrap(),
+			phone_number: "+6281394653625".as_bytes().to_vec().try_into().unwrap(),
+			website: "DeBio Website".as_bytes().to_vec().try_into().unwrap(),
+			latitude: Some("-6.175392".as_bytes().to_vec().try_into().unwrap()),
+			longitude: Some("106.827153".as_bytes().to_vec().try_into().unwrap()),
+			profile_image: Some("DeBio Profile Image uwu".as_bytes().to_vec().try_into().unwrap()),
+		};
+		let _add_labs = Labs::<T>::register_lab(caller_origin.clone(), lab);
+
+		let eth_address = <T as UserProfileConfig>::EthereumAddress::default();
+		let _set_eth_address = UserProfile::<T>::set_eth_address(caller_origin.clone(), eth_address);
+
+		let service_info = ServiceInfo {
+			name: "DeBio name".as_bytes().to_vec().try_into().unwrap(),
+			prices_by_currency: vec![
+				PriceByCurrency::default()
+			].try_into().unwrap(),
+			expected_duration: ExpectedDuration::default(),
+			category: "DeBio category".as_bytes().to_vec().try_into().unwrap(),
+			description: "This is my description".as_bytes().to_vec().try_into().unwrap(),
+			test_result_sample: "Test result sample".as_bytes().to_vec().try_into().unwrap(),
+			dna_collection_process : "Dna Collection Process".as_bytes().to_vec().try_into().unwrap(),
+			long_description: Some("This is my long description".as_bytes().to_vec().try_into().unwrap()),
+			image: Some("This is my image".as_bytes().to_vec().try_into().unwrap()),
+		};
+		let _create_service = Services::<T>::create_service(caller_origin.clone(), service_info, ServiceFlow::default());
+
+		let _lab = Labs::<T>::lab_by_account_id(caller.clone())
+			.unwrap();
+
+		let _create_order = Orders::<T>::create_order(
+			caller_origin.clone(),
+			_lab.services[0],
+			0,
+			T::Hashing::hash("0xhJ7TRe456FADD2726A132ABJK5RCc9E6fC5869F4".as_bytes()),
+			RequestTest,
+			None,
+		);
+
+		let _order_id_list = Orders::<T>::orders_by_lab_id(&caller);
+		let _order = Orders::<T>::order_by_id(_order_id_list[0])
+			.unwrap();
+		let _order_paid = Orders::<T>::set_order_paid(caller_origin, _order_id_list[0]);
+	}: process_dna_sample_with_evidence(
+		RawOrigin::Signed(caller),
+		_order.dna_sample_tracking_id,
+		DnaSampleStatus::Arrived,
+		Some("Received by courier".as_bytes().to_vec()),
+		Some(T::Hashing::hash("0xhJ7TRe456FADD2726A132ABJK5RCc9E6fC5869F4".as_bytes()))
+	)
+
 	submit_test_result {
 		let caller: T::AccountId = T::AccountId::decode(&mut "18c79faa6203d8b8349b19cc72cc6bfd008c243ea998435847abf6618756ca0b".as_bytes()).unwrap();
 		let caller_origin = T::RuntimeOrigin::from(RawOrigin::Signed(caller.clone()));
 
 		let lab = LabInfo {
 			box_public_key: T::Hashing::hash("0xDb9Af2d1f3ADD2726A132AA7A65Cc9E6fC5761C3".as_bytes()),
-			name: "DeBio Lab".as_bytes().to_vec(),
-			email: "DeBio Email".as_bytes().to_vec(),
-			country: CountryCode::from_vec("DC".as_bytes().to_vec()),
-			region: RegionCode::from_vec("DBIO".as_bytes().to_vec()),
-			city: CityCode::from_vec("City".as_bytes().to_vec()),
-			address: "DeBio Address".as_bytes().to_vec(),
-			phone_number: "+6281394653625".as_bytes().to_vec(),
-			website: "DeBio Website".as_bytes().to_vec(),
-			latitude: Some("DeBio Latitude".as_bytes().to_vec()),
-			longitude: Some("DeBio Longtitude".as_bytes().to_vec()),
-			profile_image: Some("DeBio Profile Image uwu".as_bytes().to_vec()),
+			name: "DeBio Lab".as_bytes().to_vec().try_into().unwrap(),
+			email: "DeBio Email".as_bytes().to_vec().try_into().unwrap(),
+			country: CountryCode::try_from("ID".as_bytes().to_vec()).unwrap(),
+			region: RegionCode::try_from("JK".as_bytes().to_vec()).unwrap(),
+			city: CityCode::try_from("City".as_bytes().to_vec()).unwrap(),
+			address: "DeBio Address".as_bytes().to_vec().try_into().unwrap(),
+			phone_number: "+6281394653625".as_bytes().to_vec().try_into().unwrap(),
+			website: "DeBio Website".as_bytes().to_vec().try_into().unwrap(),
+			latitude: Some("-6.175392".as_bytes().to_vec().try_into().unwrap()),
+			longitude: Some("106.827153".as_bytes().to_vec().try_into().unwrap()),
+			profile_image: Some("DeBio Profile Image uwu".as_bytes().to_vec().try_into().unwrap()),
 		};
 		let _add_labs = Labs::<T>::register_lab(caller_origin.clone(), lab);
 
@@ -193,17 +253,17 @@ benchmarks! {
 		let _set_eth_address = UserProfile::<T>::set_eth_address(caller_origin.clone(), eth_address);
 
 		let service_info = ServiceInfo {
-			name: "DeBio name".as_bytes().to_vec(),
+			name: "DeBio name".as_bytes().to_vec().try_into().unwrap(),
 			prices_by_currency: vec![
 				PriceByCurrency::default()
-			],
+			].try_into().unwrap(),
 			expected_duration: ExpectedDuration::default(),
-			category: "DeBio category".as_bytes().to_vec(),
-			description: "This is my description".as_bytes().to_vec(),
-			test_result_sample: "Test result sample".as_bytes().to_vec(),
-			dna_collection_process : "Dna Collection Process".as_bytes().to_vec(),
-			long_description: Some("This is my long description".as_bytes().to_vec()),
-			image: Some("This is my image".as_bytes().to_vec()),
+			category: "DeBio category".as_bytes().to_vec().try_into().unwrap(),
+			description: "This is my description".as_bytes().to_vec().try_into().unwrap(),
+			test_result_sample: "Test result sample".as_bytes().to_vec().try_into().unwrap(),
+			dna_collection_process : "Dna Collection Process".as_bytes().to_vec().try_into().unwrap(),
+			long_description: Some("This is my long description".as_bytes().to_vec().try_into().unwrap()),
+			image: Some("This is my image".as_bytes().to_vec().try_into().unwrap()),
 		};
 		let _create_service = Services::<T>::create_service(caller_origin.clone(), service_info, ServiceFlow::default());
 
@@ -219,17 +279,16 @@ benchmarks! {
 			None,
 		);
 
-		let _order_id_list = Orders::<T>::orders_by_lab_id(caller.clone())
-			.unwrap();
+		let _order_id_list = Orders::<T>::orders_by_lab_id(&caller);
 		let _order = Orders::<T>::order_by_id(_order_id_list[0])
 			.unwrap();
 
 		let _order_paid = Orders::<T>::set_order_paid(caller_origin, _order_id_list[0]);
 
 		let _dna_test_result = DnaTestResultSubmission {
-			comments: Some("DNA Test Result comments".as_bytes().to_vec()),
-			result_link: Some("DNA Test Result result_link".as_bytes().to_vec()),
-			report_link: Some("DNA Test Result report_link".as_bytes().to_vec())
+			comments: Some("DNA Test Result comments".as_bytes().to_vec().try_into().unwrap()),
+			result_link: Some("DNA Test Result result_link".as_bytes().to_vec().try_into().unwrap()),
+			report_link: Some("DNA Test Result report_link".as_bytes().to_vec().try_into().unwrap())
 		};
 	}: submit_test_result(
 		RawOrigin::Signed(caller),
@@ -237,13 +296,86 @@ benchmarks! {
 		_dna_test_result
 	)
 
+	attest_test_result {
+		let caller: T::AccountId = T::AccountId::decode(&mut "18c79faa6203d8b8349b19cc72cc6bfd008c243ea998435847abf6618756ca0b".as_bytes()).unwrap();
+		let caller_origin = T::RuntimeOrigin::from(RawOrigin::Signed(caller.clone()));
+
+		let lab = LabInfo {
+			box_public_key: T::Hashing::hash("0xDb9Af2d1f3ADD2726A132AA7A65Cc9E6fC5761C3".as_bytes()),
+			name: "DeBio Lab".as_bytes().to_vec().try_into().unwrap(),
+			email: "DeBio Email".as_bytes().to_vec().try_into().unwrap(),
+			country: CountryCode::try_from("ID".as_bytes().to_vec()).unwrap(),
+			region: RegionCode::try_from("JK".as_bytes().to_vec()).unwrap(),
+			city: CityCode::try_from("City".as_bytes().to_vec()).unwrap(),
+			address: "DeBio Address".as_bytes().to_vec().try_into().unwrap(),
+			phone_number: "+6281394653625".as_bytes().to_vec().try_into().unwrap(),
+			website: "DeBio Website".as_bytes().to_vec().try_into().unwrap(),
+			latitude: Some("-6.175392".as_bytes().to_vec().try_into().unwrap()),
+			longitude: Some("106.827153".as_bytes().to_vec().try_into().unwrap()),
+			profile_image: Some("DeBio Profile Image uwu".as_bytes().to_vec().try_into().unwrap()),
+		};
+		let _add_labs = Labs::<T>::register_lab(caller_origin.clone(), lab);
+
+		let eth_address = <T as UserProfileConfig>::EthereumAddress::default();
+		let _set_eth_address = UserProfile::<T>::set_eth_address(caller_origin.clone(), eth_address);
+
+		let service_info = ServiceInfo {
+			name: "DeBio name".as_bytes().to_vec().try_into().unwrap(),
+			prices_by_currency: vec![
+				PriceByCurrency::default()
+			].try_into().unwrap(),
+			expected_duration: ExpectedDuration::default(),
+			category: "DeBio category".as_bytes().to_vec().try_into().unwrap(),
+			description: "This is my description".as_bytes().to_vec().try_into().unwrap(),
+			test_result_sample: "Test result sample".as_bytes().to_vec().try_into().unwrap(),
+			dna_collection_process : "Dna Collection Process".as_bytes().to_vec().try_into().unwrap(),
+			long_description: Some("This is my long description".as_bytes().to_vec().try_into().unwrap()),
+			image: Some("This is my image".as_bytes().to_vec().try_into().unwrap()),
+		};
+		let _create_service = Services::<T>::create_service(caller_origin.clone(), service_info, ServiceFlow::default());
+
+		let _lab = Labs::<T>::lab_by_account_id(caller.clone())
+			.unwrap();
+
+		let _create_order = Orders::<T>::create_order(
+			caller_origin.clone(),
+			_lab.services[0],
+			0,
+			T::Hashing::hash("0xhJ7TRe456FADD2726A132ABJK5RCc9E6fC5869F4".as_bytes()),
+			StakingRequestService,
+			None,
+		);
+
+		let _order_id_list = Orders::<T>::orders_by_lab_id(&caller);
+		let _order = Orders::<T>::order_by_id(_order_id_list[0])
+			.unwrap();
+
+		let _order_paid = Orders::<T>::set_order_paid(caller_origin, _order_id_list[0]);
+
+		let _dna_test_result = DnaTestResultSubmission {
+			comments: Some("DNA Test Result comments".as_bytes().to_vec().try_into().unwrap()),
+			result_link: Some("DNA Test Result result_link".as_bytes().to_vec().try_into().unwrap()),
+			report_link: Some("DNA Test Result report_link".as_bytes().to_vec().try_into().unwrap())
+		};
+		let _submit_test_result = GeneticTesting::<T>::submit_test_result(
+			RawOrigin::Signed(caller.clone()).into(),
+			_order.dna_sample_tracking_id.clone(),
+			_dna_test_result
+		);
+	}: attest_test_result(
+		RawOrigin::Signed(caller),
+		_order.dna_sample_tracking_id,
+		T::Hashing::hash("DNA Test Result report".as_bytes()),
+		None
+	)
+
 	submit_independent_test_result {
 		let caller: T::AccountId = T::AccountId::decode(&mut "18c79faa6203d8b8349b19cc72cc6bfd008c243ea998435847abf6618756ca0b".as_bytes()).unwrap();
 
 		let _dna_test_result = DnaTestResultSubmission {
-			comments: Some("DNA Test Result comments".as_bytes().to_vec()),
-			result_link: Some("DNA Test Result result_link".as_bytes().to_vec()),
-			report_link: Some("DNA Test Result report_link".as_bytes().to_vec())
+			comments: Some("DNA Test Result comments".as_bytes().to_vec().try_into().unwrap()),
+			result_link: Some("DNA Test Result result_link".as_bytes().to_vec().try_into().unwrap()),
+			report_link: Some("DNA Test Result report_link".as_bytes().to_vec().try_into().unwrap())
 		};
 	}: submit_independent_test_result(
 		RawOrigin::Signed(caller),
@@ -30,7 +30,7 @@ frame_support::construct_runtime!(
 		Orders: orders,
 		GeneticTesting: genetic_testing,
 		Assets: pallet_assets,
-		Certifications: certifications,
+		Certifications: credentials::<Instance1>,
 		RandomnessCollectiveFlip: pallet_randomness_collective_flip,
 		Timestamp: pallet_timestamp,
 	}
@@ -76,6 +76,14 @@ parameter_types! {
 	pub const ExistentialDeposit: Balance = 10;
 	pub const LabPalletId: PalletId = PalletId(*b"dbio/lab");
 	pub const OrderPalletId: PalletId = PalletId(*b"dbio/ord");
+	pub const RefundGracePeriod: u64 = 1000;
+	pub const MaxExpiredRefundsPerBlock: u32 = 10;
+	pub const MaxBundleItems: u32 = 10;
+	pub const DisputeWindow: u64 = 0;
+	pub const InstallmentInterval: u64 = 1000;
+	pub const MaxInstallments: u32 = 12;
+	pub const MaxOverduePlansPerBlock: u32 = 10;
+	pub const MaxCustodyNoteLength: u32 = 64;
 }
 
 impl pallet_balances::Config for Test {
@@ -130,6 +138,9 @@ impl labs::Config for Test {
 	type UserProfile = UserProfile;
 	type LabWeightInfo = ();
 	type PalletId = LabPalletId;
+	type SlashOrigin = frame_system::EnsureRoot<AccountId>;
+	type RequireVerifiedCertification = frame_support::traits::ConstBool<false>;
+	type MaxLabsPerCity = frame_support::traits::ConstU32<100>;
 }
 
 impl services::Config for Test {
@@ -154,6 +165,14 @@ impl orders::Config for Test {
 	type Assets = Assets;
 	type OrdersWeightInfo = ();
 	type PalletId = OrderPalletId;
+	type RefundGracePeriod = RefundGracePeriod;
+	type MaxExpiredRefundsPerBlock = MaxExpiredRefundsPerBlock;
+	type MaxBundleItems = MaxBundleItems;
+	type DisputeWindow = DisputeWindow;
+	type ArbiterOrigin = frame_system::EnsureRoot<AccountId>;
+	type InstallmentInterval = InstallmentInterval;
+	type MaxInstallments = MaxInstallments;
+	type MaxOverduePlansPerBlock = MaxOverduePlansPerBlock;
 }
 
 impl genetic_testing::Config for Test {
@@ -161,11 +180,16 @@ impl genetic_testing::Config for Test {
 	type Orders = Orders;
 	type RandomnessSource = RandomnessCollectiveFlip;
 	type GeneticTestingWeightInfo = ();
+	type MaxCustodyNoteLength = MaxCustodyNoteLength;
+	type AttestationSignature = Signature;
+	type AttestationSigner = <Signature as Verify>::Signer;
 }
 
-impl certifications::Config for Test {
+impl credentials::Config<credentials::Instance1> for Test {
 	type RuntimeEvent = RuntimeEvent;
-	type CertificationOwner = Labs;
+	type CredentialInfo = credentials::CertificationInfo;
+	type CredentialOwner = credentials::legacy::LegacyCertificationOwner<Labs>;
+	type CredentialIssuers = ();
 	type WeightInfo = ();
 }
 
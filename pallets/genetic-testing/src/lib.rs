@@ -4,19 +4,51 @@ pub use pallet::*;
 pub use scale_info::TypeInfo;
 
 pub mod interface;
+pub mod migrations;
 pub mod weights;
 pub use frame_support::{
-	debug, dispatch::DispatchResultWithPostInfo, pallet_prelude::*, sp_runtime::traits::Hash,
+	debug,
+	dispatch::DispatchResultWithPostInfo,
+	pallet_prelude::*,
+	sp_runtime::traits::{Hash, IdentifyAccount, Verify},
 	traits::Randomness,
 };
 pub use frame_system::pallet_prelude::*;
 pub use interface::GeneticTestingInterface;
+pub use primitives_tracking_id::TrackingIdError;
 pub use sp_std::{fmt::Debug, prelude::*};
 pub use traits_genetic_testing::{DnaSampleTracking, DnaSampleTrackingId, GeneticTestingProvider};
 pub use traits_order::{OrderEventEmitter, OrderStatusUpdater};
 pub use weights::WeightInfo;
 
-#[derive(Encode, Decode, Clone, RuntimeDebug, PartialEq, Eq, TypeInfo)]
+#[cfg(feature = "std")]
+use serde::{Deserialize, Serialize};
+
+/// The current storage version.
+const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
+
+/// Most dna samples returned by a single page.
+pub const MAX_PAGE_LIMIT: u32 = 100;
+
+/// Maximum length of the title a lab gives when rejecting a dna sample
+pub const MAX_REJECTION_TITLE_LENGTH: u32 = 256;
+/// Maximum length of the description a lab gives when rejecting a dna sample
+pub const MAX_REJECTION_DESCRIPTION_LENGTH: u32 = 1024;
+/// Maximum length of the comments of a test result
+pub const MAX_TEST_RESULT_COMMENTS_LENGTH: u32 = 1024;
+/// Maximum length of the result and report links of a test result
+pub const MAX_TEST_RESULT_LINK_LENGTH: u32 = 512;
+/// Maximum length of a custody note, `MaxCustodyNoteLength` may only lower it
+pub const MAX_CUSTODY_NOTE_LENGTH: u32 = 1024;
+
+pub type RejectionTitle = BoundedVec<u8, ConstU32<MAX_REJECTION_TITLE_LENGTH>>;
+pub type RejectionDescription = BoundedVec<u8, ConstU32<MAX_REJECTION_DESCRIPTION_LENGTH>>;
+pub type TestResultComments = BoundedVec<u8, ConstU32<MAX_TEST_RESULT_COMMENTS_LENGTH>>;
+pub type TestResultLink = BoundedVec<u8, ConstU32<MAX_TEST_RESULT_LINK_LENGTH>>;
+pub type CustodyNote = BoundedVec<u8, ConstU32<MAX_CUSTODY_NOTE_LENGTH>>;
+
+#[derive(Encode, Decode, Clone, RuntimeDebug, PartialEq, Eq, TypeInfo, MaxEncodedLen)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
 pub enum DnaSampleStatus {
 	Registered,
 	Arrived,
@@ -32,15 +64,16 @@ impl Default for DnaSampleStatus {
 	}
 }
 
-#[derive(Encode, Decode, Clone, Default, RuntimeDebug, PartialEq, Eq, TypeInfo)]
+#[derive(Encode, Decode, Clone, Default, RuntimeDebug, PartialEq, Eq, TypeInfo, MaxEncodedLen)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
 pub struct DnaSample<AccountId, Hash, Moment> {
 	tracking_id: DnaSampleTrackingId,
 	lab_id: AccountId,
 	owner_id: AccountId,
 	status: DnaSampleStatus,
 	order_id: Hash,
-	rejected_title: Option<Vec<u8>>,
-	rejected_description: Option<Vec<u8>>,
+	rejected_title: Option<RejectionTitle>,
+	rejected_description: Option<RejectionDescription>,
 	created_at: Moment,
 	updated_at: Moment,
 }
@@ -80,14 +113,15 @@ impl<AccountId, Hash, Moment> DnaSampleTracking for DnaSample<AccountId, Hash, M
 	}
 }
 
-#[derive(Encode, Decode, Clone, Default, RuntimeDebug, PartialEq, Eq, TypeInfo)]
+#[derive(Encode, Decode, Clone, Default, RuntimeDebug, PartialEq, Eq, TypeInfo, MaxEncodedLen)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
 pub struct DnaTestResult<AccountId, Hash, Moment> {
 	pub tracking_id: DnaSampleTrackingId,
 	pub lab_id: Option<AccountId>, // if lab_id.is_none(), Test result is submitted independently
 	pub owner_id: AccountId,
-	pub comments: Option<Vec<u8>>,
-	pub result_link: Option<Vec<u8>>,
-	pub report_link: Option<Vec<u8>>,
+	pub comments: Option<TestResultComments>,
+	pub result_link: Option<TestResultLink>,
+	pub report_link: Option<TestResultLink>,
 	order_id: Option<Hash>,
 	created_at: Moment,
 	updated_at: Moment,
@@ -115,11 +149,31 @@ impl<AccountId, Hash, Moment: Copy> DnaTestResult<AccountId, Hash, Moment> {
 	}
 }
 
-#[derive(Encode, Decode, Clone, Default, RuntimeDebug, PartialEq, Eq, TypeInfo)]
+#[derive(Encode, Decode, Clone, Default, RuntimeDebug, PartialEq, Eq, TypeInfo, MaxEncodedLen)]
 pub struct DnaTestResultSubmission {
-	pub comments: Option<Vec<u8>>,
-	pub result_link: Option<Vec<u8>>,
-	pub report_link: Option<Vec<u8>>,
+	pub comments: Option<TestResultComments>,
+	pub result_link: Option<TestResultLink>,
+	pub report_link: Option<TestResultLink>,
+}
+
+/// A single transition in the chain-of-custody log of a `DnaSample`
+#[derive(Encode, Decode, Clone, RuntimeDebug, PartialEq, Eq, TypeInfo, MaxEncodedLen)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub struct CustodyEntry<AccountId, Hash, Moment> {
+	pub status: DnaSampleStatus,
+	pub actor: AccountId,
+	pub note: Option<CustodyNote>,
+	pub evidence_hash: Option<Hash>,
+	pub recorded_at: Moment,
+}
+
+/// Content hash of a lab test result file, optionally signed by the lab account
+#[derive(Encode, Decode, Clone, RuntimeDebug, PartialEq, Eq, TypeInfo, MaxEncodedLen)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub struct TestResultAttestation<Hash, Signature, Moment> {
+	pub content_hash: Hash,
+	pub signature: Option<Signature>,
+	pub attested_at: Moment,
 }
 
 #[frame_support::pallet]
@@ -132,16 +186,30 @@ pub mod pallet {
 		type RandomnessSource: Randomness<Self::Hash, Self::BlockNumber>;
 		type Orders: OrderEventEmitter<Self> + OrderStatusUpdater<Self>;
 		type GeneticTestingWeightInfo: WeightInfo;
+		/// Maximum length of a courier or handler note in the custody log, at most
+		/// `MAX_CUSTODY_NOTE_LENGTH`.
+		#[pallet::constant]
+		type MaxCustodyNoteLength: Get<u32>;
+		/// Signature labs use to attest test results, verified against the lab account.
+		type AttestationSignature: Verify<Signer = Self::AttestationSigner>
+			+ Parameter
+			+ MaxEncodedLen;
+		/// Signer of `AttestationSignature`.
+		type AttestationSigner: IdentifyAccount<AccountId = Self::AccountId>;
 	}
 
 	// ----- This is template code, every pallet needs this ---
 	#[pallet::pallet]
+	#[pallet::storage_version(STORAGE_VERSION)]
 	#[pallet::generate_store(pub(super) trait Store)]
-	#[pallet::without_storage_info]
 	pub struct Pallet<T>(_);
 
 	#[pallet::hooks]
-	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {}
+	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+		fn on_runtime_upgrade() -> Weight {
+			migrations::migrate::<T>()
+		}
+	}
 	// --------------------------------------------------------
 
 	#[pallet::event]
@@ -161,12 +229,17 @@ pub mod pallet {
 		DnaTestResultSubmitted(DnaTestResultOf<T>),
 		/// Submit Data Staking Details
 		DataStaked(AccountIdOf<T>, HashOf<T>, HashOf<T>),
+		/// Dna Test Result Attested
+		/// parameters [DnaSampleTrackingId, TestResultAttestation]
+		DnaTestResultAttested(DnaSampleTrackingId, TestResultAttestationOf<T>),
 	}
 
 	#[pallet::error]
 	pub enum Error<T> {
 		OrderNotFound,
 		DnaSampleNotFound,
+		/// Tracking id check character does not match, most likely a typo
+		InvalidTrackingId,
 		Unauthorized,
 		TrackingIdCollision,
 		ResultLinkRequired,
@@ -176,6 +249,14 @@ pub mod pallet {
 		DataHashNotFound,
 		UnpaidOrder,
 		RefundFailed,
+		/// Custody note exceeds the maximum length
+		CustodyNoteTooLong,
+		/// Attestation signature does not match the lab account
+		BadSignature,
+		/// Rejected title exceeds MAX_REJECTION_TITLE_LENGTH
+		RejectedTitleTooLong,
+		/// Rejected description exceeds MAX_REJECTION_DESCRIPTION_LENGTH
+		RejectedDescriptionTooLong,
 	}
 
 	pub type HashOf<T> = <T as frame_system::Config>::Hash;
@@ -184,6 +265,9 @@ pub mod pallet {
 	pub type DnaSampleOf<T> = DnaSample<AccountIdOf<T>, HashOf<T>, MomentOf<T>>;
 	pub type DnaTestResultOf<T> = DnaTestResult<AccountIdOf<T>, HashOf<T>, MomentOf<T>>;
 	pub type DataHash<T> = <T as frame_system::Config>::Hash;
+	pub type CustodyEntryOf<T> = CustodyEntry<AccountIdOf<T>, HashOf<T>, MomentOf<T>>;
+	pub type TestResultAttestationOf<T> =
+		TestResultAttestation<HashOf<T>, <T as Config>::AttestationSignature, MomentOf<T>>;
 
 	// Storage ----------------
 	#[pallet::storage]
@@ -195,25 +279,76 @@ pub mod pallet {
 	pub type DnaTestResults<T> =
 		StorageMap<_, Blake2_128Concat, DnaSampleTrackingId, DnaTestResultOf<T>>;
 
+	/// Get dna sample tracking ids by owner id
+	/// (AccountId, DnaSampleTrackingId) => ()
 	#[pallet::storage]
-	#[pallet::getter(fn dna_samples_by_owner_id)]
-	pub type DnaSamplesByOwner<T> =
-		StorageMap<_, Blake2_128Concat, AccountIdOf<T>, Vec<DnaSampleTrackingId>>;
-
+	pub type DnaSamplesByOwner<T> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		AccountIdOf<T>,
+		Blake2_128Concat,
+		DnaSampleTrackingId,
+		(),
+	>;
+
+	/// Get dna sample tracking ids by lab id
+	/// (AccountId, DnaSampleTrackingId) => ()
 	#[pallet::storage]
-	#[pallet::getter(fn dna_samples_by_lab_id)]
-	pub type DnaSamplesByLab<T> =
-		StorageMap<_, Blake2_128Concat, AccountIdOf<T>, Vec<DnaSampleTrackingId>>;
-
+	pub type DnaSamplesByLab<T> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		AccountIdOf<T>,
+		Blake2_128Concat,
+		DnaSampleTrackingId,
+		(),
+	>;
+
+	/// Get dna test result tracking ids by owner id
+	/// (AccountId, DnaSampleTrackingId) => ()
+	#[pallet::storage]
+	pub type DnaTestResultsByOwner<T> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		AccountIdOf<T>,
+		Blake2_128Concat,
+		DnaSampleTrackingId,
+		(),
+	>;
+
+	/// Get dna test result tracking ids by lab id
+	/// (AccountId, DnaSampleTrackingId) => ()
 	#[pallet::storage]
-	#[pallet::getter(fn dna_test_results_by_owner_id)]
-	pub type DnaTestResultsByOwner<T> =
-		StorageMap<_, Blake2_128Concat, AccountIdOf<T>, Vec<DnaSampleTrackingId>>;
+	pub type DnaTestResultsByLab<T> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		AccountIdOf<T>,
+		Blake2_128Concat,
+		DnaSampleTrackingId,
+		(),
+	>;
+
+	/// Append-only chain-of-custody log per dna sample
+	/// (TrackingId, EntryIndex) => CustodyEntry
+	#[pallet::storage]
+	pub type CustodyLog<T> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		DnaSampleTrackingId,
+		Twox64Concat,
+		u32,
+		CustodyEntryOf<T>,
+	>;
+
+	/// Number of entries in the custody log of a dna sample, the index of the next entry
+	#[pallet::storage]
+	#[pallet::getter(fn custody_entry_count_by_tracking_id)]
+	pub type CustodyEntryCount<T> =
+		StorageMap<_, Blake2_128Concat, DnaSampleTrackingId, u32, ValueQuery>;
 
 	#[pallet::storage]
-	#[pallet::getter(fn dna_test_results_by_lab_id)]
-	pub type DnaTestResultsByLab<T> =
-		StorageMap<_, Blake2_128Concat, AccountIdOf<T>, Vec<DnaSampleTrackingId>>;
+	#[pallet::getter(fn test_result_attestation_by_tracking_id)]
+	pub type TestResultAttestations<T> =
+		StorageMap<_, Blake2_128Concat, DnaSampleTrackingId, TestResultAttestationOf<T>>;
 
 	#[pallet::storage]
 	#[pallet::getter(fn staked_data_by_account_id)]
@@ -263,14 +398,32 @@ pub mod pallet {
 				status.clone(),
 			) {
 				Ok(dna_sample) => {
-					match status {
-						DnaSampleStatus::QualityControlled =>
-							Self::deposit_event(Event::<T>::DnaSampleQualityControlled(dna_sample)),
-						DnaSampleStatus::ResultReady =>
-							Self::deposit_event(Event::<T>::DnaSampleResultReady(dna_sample)),
-						_ => (),
-					}
+					Self::deposit_dna_sample_processed_event(status, dna_sample);
+					Ok(().into())
+				},
+				Err(error) => Err(error.into()),
+			}
+		}
 
+		#[pallet::weight(T::GeneticTestingWeightInfo::process_dna_sample_with_evidence())]
+		pub fn process_dna_sample_with_evidence(
+			origin: OriginFor<T>,
+			tracking_id: DnaSampleTrackingId,
+			status: DnaSampleStatus,
+			note: Option<Vec<u8>>,
+			evidence_hash: Option<HashOf<T>>,
+		) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+
+			match Self::do_process_dna_sample(
+				&who,
+				&tracking_id,
+				status.clone(),
+				note,
+				evidence_hash,
+			) {
+				Ok(dna_sample) => {
+					Self::deposit_dna_sample_processed_event(status, dna_sample);
 					Ok(().into())
 				},
 				Err(error) => Err(error.into()),
@@ -298,6 +451,27 @@ pub mod pallet {
 			}
 		}
 
+		#[pallet::weight(T::GeneticTestingWeightInfo::attest_test_result())]
+		pub fn attest_test_result(
+			origin: OriginFor<T>,
+			tracking_id: DnaSampleTrackingId,
+			content_hash: HashOf<T>,
+			signature: Option<T::AttestationSignature>,
+		) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+
+			match Self::do_attest_test_result(&who, &tracking_id, content_hash, signature) {
+				Ok(attestation) => {
+					Self::deposit_event(Event::<T>::DnaTestResultAttested(
+						tracking_id,
+						attestation,
+					));
+					Ok(().into())
+				},
+				Err(error) => Err(error.into()),
+			}
+		}
+
 		#[pallet::weight(T::GeneticTestingWeightInfo::submit_independent_test_result())]
 		pub fn submit_independent_test_result(
 			origin: OriginFor<T>,
@@ -351,9 +525,9 @@ impl<T: Config> GeneticTestingInterface<T> for Pallet<T> {
 		owner_id: &T::AccountId,
 		order_id: &HashOf<T>,
 	) -> Result<Self::DnaSample, Self::Error> {
-		let seed = Self::generate_random_seed(lab_id, owner_id);
+		let mut seed = Self::generate_random_seed(lab_id, owner_id);
 
-		let mut tries = 10;
+		let mut tries = 0;
 		loop {
 			let tracking_id = tracking_id_generator::generate(seed.clone());
 			let now = pallet_timestamp::Pallet::<T>::get();
@@ -361,6 +535,13 @@ impl<T: Config> GeneticTestingInterface<T> for Pallet<T> {
 			if !DnaSamples::<T>::contains_key(&tracking_id) {
 				let dna_sample =
 					DnaSample::new(tracking_id, lab_id.clone(), owner_id.clone(), *order_id, now);
+				Self::record_custody_entry(
+					&dna_sample.tracking_id,
+					DnaSampleStatus::Registered,
+					owner_id,
+					None,
+					None,
+				)?;
 				DnaSamples::<T>::insert(&dna_sample.tracking_id, &dna_sample);
 				Self::add_dna_sample_by_owner(&dna_sample);
 				Self::add_dna_sample_by_lab(&dna_sample);
@@ -372,6 +553,9 @@ impl<T: Config> GeneticTestingInterface<T> for Pallet<T> {
 			if tries > 10 {
 				return Err(Error::<T>::TrackingIdCollision)
 			}
+
+			// Reseed so samples registered in the same block get distinct tracking ids
+			seed = T::RandomnessSource::random(&seed).encode();
 		}
 	}
 
@@ -380,10 +564,13 @@ impl<T: Config> GeneticTestingInterface<T> for Pallet<T> {
 	) -> Result<Self::DnaSample, Self::Error> {
 		let dna_sample = DnaSamples::<T>::take(tracking_id);
 		if dna_sample.is_none() {
-			return Err(Error::<T>::DnaSampleNotFound)
+			return Err(Self::dna_sample_not_found(tracking_id))
 		}
 		let dna_sample = dna_sample.unwrap();
 
+		DnaSamplesByOwner::<T>::remove(&dna_sample.owner_id, tracking_id);
+		DnaSamplesByLab::<T>::remove(&dna_sample.lab_id, tracking_id);
+
 		Ok(dna_sample)
 	}
 
@@ -395,7 +582,7 @@ impl<T: Config> GeneticTestingInterface<T> for Pallet<T> {
 	) -> Result<Self::DnaSample, Self::Error> {
 		let dna_sample = DnaSamples::<T>::get(tracking_id);
 		if dna_sample.is_none() {
-			return Err(Error::<T>::DnaSampleNotFound)
+			return Err(Self::dna_sample_not_found(tracking_id))
 		}
 		let mut dna_sample = dna_sample.unwrap();
 
@@ -407,9 +594,21 @@ impl<T: Config> GeneticTestingInterface<T> for Pallet<T> {
 			return Err(Error::<T>::UnpaidOrder)
 		}
 
+		let rejected_title: RejectionTitle = rejected_title
+			.to_vec()
+			.try_into()
+			.map_err(|_| Error::<T>::RejectedTitleTooLong)?;
+		let rejected_description: RejectionDescription =
+			rejected_description
+				.to_vec()
+				.try_into()
+				.map_err(|_| Error::<T>::RejectedDescriptionTooLong)?;
+
+		Self::record_custody_entry(tracking_id, DnaSampleStatus::Rejected, lab_id, None, None)?;
+
 		let now = pallet_timestamp::Pallet::<T>::get();
-		dna_sample.rejected_title = Some(rejected_title.to_vec());
-		dna_sample.rejected_description = Some(rejected_description.to_vec());
+		dna_sample.rejected_title = Some(rejected_title);
+		dna_sample.rejected_description = Some(rejected_description);
 		dna_sample.status = DnaSampleStatus::Rejected;
 		dna_sample.updated_at = now;
 		DnaSamples::<T>::insert(tracking_id, &dna_sample);
@@ -434,37 +633,7 @@ impl<T: Config> GeneticTestingInterface<T> for Pallet<T> {
 		tracking_id: &DnaSampleTrackingId,
 		status: Self::DnaSampleStatus,
 	) -> Result<Self::DnaSample, Self::Error> {
-		let dna_sample = DnaSamples::<T>::get(tracking_id);
-		if dna_sample.is_none() {
-			return Err(Error::<T>::DnaSampleNotFound)
-		}
-		let mut dna_sample = dna_sample.unwrap();
-
-		if dna_sample.lab_id != *lab_id {
-			return Err(Error::<T>::Unauthorized)
-		}
-
-		if !T::Orders::is_order_paid(&dna_sample.order_id) {
-			return Err(Error::<T>::UnpaidOrder)
-		}
-
-		if status == DnaSampleStatus::ResultReady {
-			let result = Self::dna_test_result_by_tracking_id(tracking_id);
-			if result.is_none() {
-				return Err(Error::<T>::DnaTestResultNotYetSubmitted)
-			}
-			T::Orders::remove_order_id_from_pending_orders_by_seller(
-				&dna_sample.lab_id,
-				&dna_sample.order_id,
-			);
-		}
-
-		let now = pallet_timestamp::Pallet::<T>::get();
-		dna_sample.status = status;
-		dna_sample.updated_at = now;
-		DnaSamples::<T>::insert(tracking_id, &dna_sample);
-
-		Ok(dna_sample)
+		Self::do_process_dna_sample(lab_id, tracking_id, status, None, None)
 	}
 
 	fn submit_test_result(
@@ -474,7 +643,7 @@ impl<T: Config> GeneticTestingInterface<T> for Pallet<T> {
 	) -> Result<Self::DnaTestResult, Self::Error> {
 		let dna_sample = DnaSamples::<T>::get(tracking_id);
 		if dna_sample.is_none() {
-			return Err(Error::<T>::DnaSampleNotFound)
+			return Err(Self::dna_sample_not_found(tracking_id))
 		}
 		let mut dna_sample = dna_sample.unwrap();
 
@@ -503,6 +672,9 @@ impl<T: Config> GeneticTestingInterface<T> for Pallet<T> {
 		Self::add_dna_test_results_by_lab(&dna_test_result);
 		Self::add_dna_test_results_by_owner(&dna_test_result);
 
+		// A new result invalidates the attestation of the previous one
+		TestResultAttestations::<T>::remove(tracking_id);
+
 		Ok(dna_test_result)
 	}
 
@@ -575,22 +747,22 @@ impl<T: Config> GeneticTestingInterface<T> for Pallet<T> {
 
 	// Return dna sample tracking ids
 	fn dna_samples_by_owner_id(owner_id: &T::AccountId) -> Option<Vec<DnaSampleTrackingId>> {
-		Self::dna_samples_by_owner_id(owner_id)
+		Some(Self::dna_samples_by_owner_id(owner_id))
 	}
 
 	// Return dna sample tracking ids
 	fn dna_samples_by_lab_id(lab_id: &T::AccountId) -> Option<Vec<DnaSampleTrackingId>> {
-		Self::dna_samples_by_lab_id(lab_id)
+		Some(Self::dna_samples_by_lab_id(lab_id))
 	}
 
 	// Return dna sample tracking ids
 	fn dna_test_results_by_owner_id(owner_id: &T::AccountId) -> Option<Vec<DnaSampleTrackingId>> {
-		Self::dna_test_results_by_owner_id(owner_id)
+		Some(Self::dna_test_results_by_owner_id(owner_id))
 	}
 
 	// Return dna sample tracking ids
 	fn dna_test_results_by_lab_id(lab_id: &T::AccountId) -> Option<Vec<DnaSampleTrackingId>> {
-		Self::dna_test_results_by_lab_id(lab_id)
+		Some(Self::dna_test_results_by_lab_id(lab_id))
 	}
 }
 
@@ -616,8 +788,6 @@ impl<T: Config> GeneticTestingProvider<T> for Pallet<T> {
 	}
 }
 
-use sp_std::vec;
-
 impl<T: Config> Pallet<T> {
 	pub fn generate_random_seed(creator_id: &T::AccountId, owner_id: &T::AccountId) -> Vec<u8> {
 		let creator_info = frame_system::Pallet::<T>::account(creator_id);
@@ -633,99 +803,262 @@ impl<T: Config> Pallet<T> {
 		T::RandomnessSource::random(&seed).encode()
 	}
 
-	pub fn add_dna_sample_by_owner(dna_sample: &DnaSampleOf<T>) {
-		match DnaSamplesByOwner::<T>::get(&dna_sample.owner_id) {
-			None => {
-				let tracking_ids = vec![dna_sample.tracking_id.clone()];
-				DnaSamplesByOwner::<T>::insert(&dna_sample.owner_id, tracking_ids);
-			},
-			Some(mut tracking_ids) => {
-				tracking_ids.push(dna_sample.tracking_id.clone());
-				DnaSamplesByOwner::<T>::insert(&dna_sample.owner_id, tracking_ids);
-			},
-		}
+	pub fn get_dna_samples_by_owner_id(
+		owner_id: &T::AccountId,
+		status: Option<DnaSampleStatus>,
+		offset: u32,
+		limit: u32,
+	) -> Vec<DnaSampleOf<T>> {
+		let tracking_ids = DnaSamplesByOwner::<T>::iter_key_prefix(owner_id);
+		Self::paginate_dna_samples(tracking_ids, status, offset, limit)
 	}
 
-	pub fn add_dna_sample_by_lab(dna_sample: &DnaSampleOf<T>) {
-		match DnaSamplesByLab::<T>::get(&dna_sample.owner_id) {
-			None => {
-				let tracking_ids = vec![dna_sample.tracking_id.clone()];
-				DnaSamplesByLab::<T>::insert(&dna_sample.lab_id, tracking_ids);
-			},
-			Some(mut tracking_ids) => {
-				tracking_ids.push(dna_sample.tracking_id.clone());
-				DnaSamplesByLab::<T>::insert(&dna_sample.lab_id, tracking_ids);
-			},
+	pub fn get_dna_samples_by_lab_id(
+		lab_id: &T::AccountId,
+		status: Option<DnaSampleStatus>,
+		offset: u32,
+		limit: u32,
+	) -> Vec<DnaSampleOf<T>> {
+		let tracking_ids = DnaSamplesByLab::<T>::iter_key_prefix(lab_id);
+		Self::paginate_dna_samples(tracking_ids, status, offset, limit)
+	}
+
+	/// The status filter is applied before the offset, so pages stay contiguous per status.
+	/// Dna samples are paged in the key order of the index, which is stable between calls but
+	/// not chronological. A page holds at most `MAX_PAGE_LIMIT` dna samples.
+	pub fn paginate_dna_samples(
+		tracking_ids: impl Iterator<Item = DnaSampleTrackingId>,
+		status: Option<DnaSampleStatus>,
+		offset: u32,
+		limit: u32,
+	) -> Vec<DnaSampleOf<T>> {
+		tracking_ids
+			.filter_map(DnaSamples::<T>::get)
+			.filter(|dna_sample| {
+				status.as_ref().map_or(true, |status| &dna_sample.status == status)
+			})
+			.skip(offset as usize)
+			.take(limit.min(MAX_PAGE_LIMIT) as usize)
+			.collect()
+	}
+
+	/// Error for a tracking id without dna sample, telling typos apart from unknown samples.
+	/// Legacy tracking ids have no check character, but are found before this is reached.
+	pub fn dna_sample_not_found(tracking_id: &DnaSampleTrackingId) -> Error<T> {
+		if tracking_id.has_valid_check_character() {
+			Error::<T>::DnaSampleNotFound
+		} else {
+			Error::<T>::InvalidTrackingId
 		}
 	}
 
-	pub fn add_dna_test_results_by_owner(dna_test_result: &DnaTestResultOf<T>) {
-		match DnaTestResultsByOwner::<T>::get(&dna_test_result.owner_id) {
-			None => {
-				let tracking_ids = vec![dna_test_result.tracking_id.clone()];
-				DnaTestResultsByOwner::<T>::insert(&dna_test_result.owner_id, tracking_ids);
-			},
-			Some(mut tracking_ids) => {
-				tracking_ids.push(dna_test_result.tracking_id.clone());
-				DnaTestResultsByOwner::<T>::insert(&dna_test_result.owner_id, tracking_ids);
-			},
+	/// Normalizes a tracking id that did not come through an extrinsic, e.g. one typed into a
+	/// query. Legacy tracking ids without a check character pass while they have a dna sample.
+	pub fn normalize_tracking_id(
+		tracking_id: DnaSampleTrackingId,
+	) -> Result<DnaSampleTrackingId, TrackingIdError> {
+		let tracking_id = DnaSampleTrackingId::try_from(Vec::from(tracking_id))?;
+
+		if !tracking_id.has_valid_check_character() && !DnaSamples::<T>::contains_key(&tracking_id)
+		{
+			return Err(TrackingIdError::InvalidCheckCharacter)
 		}
+
+		Ok(tracking_id)
 	}
 
-	pub fn add_dna_test_results_by_lab(dna_test_result: &DnaTestResultOf<T>) {
-		match &dna_test_result.lab_id {
-			None => (),
-			Some(lab_id) => match DnaTestResultsByLab::<T>::get(&dna_test_result.owner_id) {
-				None => {
-					let tracking_ids = vec![dna_test_result.tracking_id.clone()];
-					DnaTestResultsByLab::<T>::insert(lab_id, tracking_ids);
-				},
-				Some(mut tracking_ids) => {
-					tracking_ids.push(dna_test_result.tracking_id.clone());
-					DnaTestResultsByLab::<T>::insert(lab_id, tracking_ids);
-				},
-			},
+	pub fn do_process_dna_sample(
+		lab_id: &T::AccountId,
+		tracking_id: &DnaSampleTrackingId,
+		status: DnaSampleStatus,
+		note: Option<Vec<u8>>,
+		evidence_hash: Option<HashOf<T>>,
+	) -> Result<DnaSampleOf<T>, Error<T>> {
+		let dna_sample = DnaSamples::<T>::get(tracking_id);
+		if dna_sample.is_none() {
+			return Err(Self::dna_sample_not_found(tracking_id))
+		}
+		let mut dna_sample = dna_sample.unwrap();
+
+		if dna_sample.lab_id != *lab_id {
+			return Err(Error::<T>::Unauthorized)
+		}
+
+		if !T::Orders::is_order_paid(&dna_sample.order_id) {
+			return Err(Error::<T>::UnpaidOrder)
+		}
+
+		if status == DnaSampleStatus::ResultReady {
+			let result = Self::dna_test_result_by_tracking_id(tracking_id);
+			if result.is_none() {
+				return Err(Error::<T>::DnaTestResultNotYetSubmitted)
+			}
+		}
+
+		Self::record_custody_entry(tracking_id, status.clone(), lab_id, note, evidence_hash)?;
+
+		let now = pallet_timestamp::Pallet::<T>::get();
+		dna_sample.status = status;
+		dna_sample.updated_at = now;
+		DnaSamples::<T>::insert(tracking_id, &dna_sample);
+
+		// The sample is stored first so bundle orders can see every finished item
+		if dna_sample.status == DnaSampleStatus::ResultReady {
+			T::Orders::remove_order_id_from_pending_orders_by_seller(
+				&dna_sample.lab_id,
+				&dna_sample.order_id,
+			);
 		}
+
+		Ok(dna_sample)
 	}
-}
 
-/// Human Readable Tracking ID
-pub mod tracking_id_generator {
-	use crate::*;
+	pub fn record_custody_entry(
+		tracking_id: &DnaSampleTrackingId,
+		status: DnaSampleStatus,
+		actor: &T::AccountId,
+		note: Option<Vec<u8>>,
+		evidence_hash: Option<HashOf<T>>,
+	) -> Result<(), Error<T>> {
+		if let Some(note) = &note {
+			if note.len() > T::MaxCustodyNoteLength::get() as usize {
+				return Err(Error::<T>::CustodyNoteTooLong)
+			}
+		}
+		let note = note
+			.map(CustodyNote::try_from)
+			.transpose()
+			.map_err(|_| Error::<T>::CustodyNoteTooLong)?;
 
-	pub const SAFE: [char; 36] = [
-		'0', '1', '2', '3', '4', '5', '6', '7', '8', '9',
-		// 'a', 'b', 'c', 'd', 'e', 'f', 'g', 'h', 'i', 'j', 'k', 'l', 'm',
-		// 'n', 'o', 'p', 'q', 'r', 's', 't', 'u', 'v', 'w', 'x', 'y', 'z',
-		'A', 'B', 'C', 'D', 'E', 'F', 'G', 'H', 'I', 'J', 'K', 'L', 'M', 'N', 'O', 'P', 'Q', 'R',
-		'S', 'T', 'U', 'V', 'W', 'X', 'Y', 'Z',
-	];
+		let custody_entry = CustodyEntry {
+			status,
+			actor: actor.clone(),
+			note,
+			evidence_hash,
+			recorded_at: pallet_timestamp::Pallet::<T>::get(),
+		};
 
-	pub fn generate(seed: Vec<u8>) -> DnaSampleTrackingId {
-		let alphabet = &SAFE;
-		let size = 21;
-		let mask = alphabet.len().next_power_of_two() - 1;
+		let index = CustodyEntryCount::<T>::get(tracking_id);
 
-		// Assert that the masking does not truncate the alphabet. (See #9)
-		debug_assert!(alphabet.len() <= mask + 1);
+		CustodyLog::<T>::insert(tracking_id, index, custody_entry);
+		CustodyEntryCount::<T>::insert(tracking_id, index.saturating_add(1));
 
-		let mut id = Vec::new();
+		Ok(())
+	}
 
-		loop {
-			for &byte in &seed {
-				let byte = byte as usize & mask;
+	/// Custody log of a dna sample, oldest entry first
+	pub fn custody_log_by_tracking_id(tracking_id: DnaSampleTrackingId) -> Vec<CustodyEntryOf<T>> {
+		(0..CustodyEntryCount::<T>::get(&tracking_id))
+			.filter_map(|index| CustodyLog::<T>::get(&tracking_id, index))
+			.collect()
+	}
+
+	pub fn do_attest_test_result(
+		lab_id: &T::AccountId,
+		tracking_id: &DnaSampleTrackingId,
+		content_hash: HashOf<T>,
+		signature: Option<T::AttestationSignature>,
+	) -> Result<TestResultAttestationOf<T>, Error<T>> {
+		let dna_sample = DnaSamples::<T>::get(tracking_id)
+			.ok_or_else(|| Self::dna_sample_not_found(tracking_id))?;
 
-				if alphabet.len() > byte {
-					id.push(alphabet[byte]);
+		if dna_sample.lab_id != *lab_id {
+			return Err(Error::<T>::Unauthorized)
+		}
 
-					if id.len() == size {
-						let _vec_id = id.iter().map(|c| *c as u8).collect::<Vec<_>>();
+		if !DnaTestResults::<T>::contains_key(tracking_id) {
+			return Err(Error::<T>::DnaTestResultNotYetSubmitted)
+		}
 
-						let _dna_tracking_id = DnaSampleTrackingId::from_vec(_vec_id);
-						return _dna_tracking_id
-					}
-				}
+		if let Some(signature) = &signature {
+			let message = Self::attestation_message(tracking_id, &content_hash);
+			if !signature.verify(&message[..], lab_id) {
+				return Err(Error::<T>::BadSignature)
 			}
 		}
+
+		let attestation = TestResultAttestation {
+			content_hash,
+			signature,
+			attested_at: pallet_timestamp::Pallet::<T>::get(),
+		};
+		TestResultAttestations::<T>::insert(tracking_id, &attestation);
+
+		Ok(attestation)
+	}
+
+	/// Message a lab signs to attest a test result: the SCALE encoded
+	/// `(b"debio/test-result", tracking_id, content_hash)`.
+	pub fn attestation_message(
+		tracking_id: &DnaSampleTrackingId,
+		content_hash: &HashOf<T>,
+	) -> Vec<u8> {
+		(b"debio/test-result", tracking_id, content_hash).encode()
+	}
+
+	/// Whether `content_hash` matches the attested content of the test result.
+	pub fn verify_test_result(tracking_id: &DnaSampleTrackingId, content_hash: &HashOf<T>) -> bool {
+		TestResultAttestations::<T>::get(tracking_id)
+			.map_or(false, |attestation| attestation.content_hash == *content_hash)
+	}
+
+	pub fn deposit_dna_sample_processed_event(status: DnaSampleStatus, dna_sample: DnaSampleOf<T>) {
+		match status {
+			DnaSampleStatus::QualityControlled =>
+				Self::deposit_event(Event::<T>::DnaSampleQualityControlled(dna_sample)),
+			DnaSampleStatus::ResultReady =>
+				Self::deposit_event(Event::<T>::DnaSampleResultReady(dna_sample)),
+			_ => (),
+		}
+	}
+
+	pub fn add_dna_sample_by_owner(dna_sample: &DnaSampleOf<T>) {
+		DnaSamplesByOwner::<T>::insert(&dna_sample.owner_id, &dna_sample.tracking_id, ());
+	}
+
+	pub fn add_dna_sample_by_lab(dna_sample: &DnaSampleOf<T>) {
+		DnaSamplesByLab::<T>::insert(&dna_sample.lab_id, &dna_sample.tracking_id, ());
+	}
+
+	pub fn add_dna_test_results_by_owner(dna_test_result: &DnaTestResultOf<T>) {
+		DnaTestResultsByOwner::<T>::insert(
+			&dna_test_result.owner_id,
+			&dna_test_result.tracking_id,
+			(),
+		);
+	}
+
+	pub fn add_dna_test_results_by_lab(dna_test_result: &DnaTestResultOf<T>) {
+		if let Some(lab_id) = &dna_test_result.lab_id {
+			DnaTestResultsByLab::<T>::insert(lab_id, &dna_test_result.tracking_id, ());
+		}
+	}
+
+	pub fn dna_samples_by_owner_id(owner_id: &T::AccountId) -> Vec<DnaSampleTrackingId> {
+		DnaSamplesByOwner::<T>::iter_key_prefix(owner_id).collect()
+	}
+
+	pub fn dna_samples_by_lab_id(lab_id: &T::AccountId) -> Vec<DnaSampleTrackingId> {
+		DnaSamplesByLab::<T>::iter_key_prefix(lab_id).collect()
+	}
+
+	pub fn dna_test_results_by_owner_id(owner_id: &T::AccountId) -> Vec<DnaSampleTrackingId> {
+		DnaTestResultsByOwner::<T>::iter_key_prefix(owner_id).collect()
+	}
+
+	pub fn dna_test_results_by_lab_id(lab_id: &T::AccountId) -> Vec<DnaSampleTrackingId> {
+		DnaTestResultsByLab::<T>::iter_key_prefix(lab_id).collect()
+	}
+}
+
+/// Human Readable Tracking ID
+pub mod tracking_id_generator {
+	use crate::*;
+
+	pub use primitives_tracking_id::tracking_id_generator::SAFE;
+
+	/// Shares the generator of primitives_tracking_id so dna samples get a check character too
+	pub fn generate(seed: Vec<u8>) -> DnaSampleTrackingId {
+		primitives_tracking_id::tracking_id_generator::generate(seed).into()
 	}
 }
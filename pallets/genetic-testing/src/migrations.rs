@@ -0,0 +1,195 @@
+use crate::{
+	AccountIdOf, Config, DnaSample, DnaSampleOf, DnaSampleStatus, DnaSampleTrackingId,
+	DnaTestResult, DnaTestResultOf, HashOf, MomentOf, Pallet,
+};
+use frame_support::{
+	codec::{Decode, Encode},
+	log, storage_alias,
+	traits::Get,
+	weights::Weight,
+	Blake2_128Concat, BoundedVec,
+};
+use sp_std::vec::Vec;
+
+pub fn migrate<T: Config>() -> Weight {
+	use frame_support::traits::StorageVersion;
+
+	let mut weight: Weight = Weight::zero();
+	let mut version = StorageVersion::get::<Pallet<T>>();
+
+	if version < 1 {
+		weight = weight.saturating_add(version::v1::migrate::<T>());
+		version = StorageVersion::new(1);
+	}
+
+	version.put::<Pallet<T>>();
+	weight
+}
+
+mod version {
+	use super::*;
+
+	/// Truncates `value` to the bound and logs the entry when it did not fit.
+	fn bounded<I, S: Get<u32>>(value: Vec<I>, entry: &str) -> BoundedVec<I, S> {
+		if value.len() > S::get() as usize {
+			log::warn!(
+				target: "runtime::genetic-testing",
+				"{} exceeds its bound of {}, truncated from {} items",
+				entry,
+				S::get(),
+				value.len(),
+			);
+		}
+
+		BoundedVec::truncate_from(value)
+	}
+
+	pub mod v1 {
+		use super::*;
+
+		#[storage_alias]
+		type DnaSamplesByOwner<T: Config> =
+			StorageMap<Pallet<T>, Blake2_128Concat, AccountIdOf<T>, Vec<DnaSampleTrackingId>>;
+
+		#[storage_alias]
+		type DnaSamplesByLab<T: Config> =
+			StorageMap<Pallet<T>, Blake2_128Concat, AccountIdOf<T>, Vec<DnaSampleTrackingId>>;
+
+		#[storage_alias]
+		type DnaTestResultsByOwner<T: Config> =
+			StorageMap<Pallet<T>, Blake2_128Concat, AccountIdOf<T>, Vec<DnaSampleTrackingId>>;
+
+		#[storage_alias]
+		type DnaTestResultsByLab<T: Config> =
+			StorageMap<Pallet<T>, Blake2_128Concat, AccountIdOf<T>, Vec<DnaSampleTrackingId>>;
+
+		#[derive(Encode, Decode)]
+		pub struct OldDnaSample<AccountId, Hash, Moment> {
+			tracking_id: DnaSampleTrackingId,
+			lab_id: AccountId,
+			owner_id: AccountId,
+			status: DnaSampleStatus,
+			order_id: Hash,
+			rejected_title: Option<Vec<u8>>,
+			rejected_description: Option<Vec<u8>>,
+			created_at: Moment,
+			updated_at: Moment,
+		}
+
+		#[derive(Encode, Decode)]
+		pub struct OldDnaTestResult<AccountId, Hash, Moment> {
+			tracking_id: DnaSampleTrackingId,
+			lab_id: Option<AccountId>,
+			owner_id: AccountId,
+			comments: Option<Vec<u8>>,
+			result_link: Option<Vec<u8>>,
+			report_link: Option<Vec<u8>>,
+			order_id: Option<Hash>,
+			created_at: Moment,
+			updated_at: Moment,
+		}
+
+		pub type OldDnaSampleOf<T> = OldDnaSample<AccountIdOf<T>, HashOf<T>, MomentOf<T>>;
+		pub type OldDnaTestResultOf<T> = OldDnaTestResult<AccountIdOf<T>, HashOf<T>, MomentOf<T>>;
+
+		/// Tracking id indexes move from one list per account to one key per tracking id, every
+		/// tracking id is kept. Rejection reasons and test result comments and links are
+		/// truncated to their bounds.
+		pub fn migrate<T: Config>() -> Weight {
+			let mut weight = T::DbWeight::get().writes(1);
+
+			crate::DnaSamples::<T>::translate(|_key, old: OldDnaSampleOf<T>| {
+				weight = weight.saturating_add(T::DbWeight::get().reads_writes(1, 1));
+
+				let sample: DnaSampleOf<T> = DnaSample {
+					tracking_id: old.tracking_id,
+					lab_id: old.lab_id,
+					owner_id: old.owner_id,
+					status: old.status,
+					order_id: old.order_id,
+					rejected_title: old
+						.rejected_title
+						.map(|title| bounded(title, "DnaSample rejected_title")),
+					rejected_description: old
+						.rejected_description
+						.map(|description| bounded(description, "DnaSample rejected_description")),
+					created_at: old.created_at,
+					updated_at: old.updated_at,
+				};
+
+				Some(sample)
+			});
+
+			crate::DnaTestResults::<T>::translate(|_key, old: OldDnaTestResultOf<T>| {
+				weight = weight.saturating_add(T::DbWeight::get().reads_writes(1, 1));
+
+				let result: DnaTestResultOf<T> = DnaTestResult {
+					tracking_id: old.tracking_id,
+					lab_id: old.lab_id,
+					owner_id: old.owner_id,
+					comments: old
+						.comments
+						.map(|comments| bounded(comments, "DnaTestResult comments")),
+					result_link: old
+						.result_link
+						.map(|link| bounded(link, "DnaTestResult result_link")),
+					report_link: old
+						.report_link
+						.map(|link| bounded(link, "DnaTestResult report_link")),
+					order_id: old.order_id,
+					created_at: old.created_at,
+					updated_at: old.updated_at,
+				};
+
+				Some(result)
+			});
+
+			weight = weight.saturating_add(move_index::<T>(
+				DnaSamplesByOwner::<T>::drain().collect(),
+				|owner_id, tracking_id| {
+					crate::DnaSamplesByOwner::<T>::insert(owner_id, tracking_id, ())
+				},
+			));
+
+			weight = weight.saturating_add(move_index::<T>(
+				DnaSamplesByLab::<T>::drain().collect(),
+				|lab_id, tracking_id| crate::DnaSamplesByLab::<T>::insert(lab_id, tracking_id, ()),
+			));
+
+			weight = weight.saturating_add(move_index::<T>(
+				DnaTestResultsByOwner::<T>::drain().collect(),
+				|owner_id, tracking_id| {
+					crate::DnaTestResultsByOwner::<T>::insert(owner_id, tracking_id, ())
+				},
+			));
+
+			weight = weight.saturating_add(move_index::<T>(
+				DnaTestResultsByLab::<T>::drain().collect(),
+				|lab_id, tracking_id| {
+					crate::DnaTestResultsByLab::<T>::insert(lab_id, tracking_id, ())
+				},
+			));
+
+			weight
+		}
+
+		fn move_index<T: Config>(
+			index: Vec<(AccountIdOf<T>, Vec<DnaSampleTrackingId>)>,
+			insert: impl Fn(&AccountIdOf<T>, &DnaSampleTrackingId),
+		) -> Weight {
+			let mut weight = Weight::zero();
+
+			for (account_id, tracking_ids) in index {
+				weight = weight.saturating_add(
+					T::DbWeight::get().reads_writes(1, tracking_ids.len() as u64 + 1),
+				);
+
+				for tracking_id in tracking_ids.iter() {
+					insert(&account_id, tracking_id);
+				}
+			}
+
+			weight
+		}
+	}
+}
@@ -30,7 +30,9 @@ use sp_std::marker::PhantomData;
 pub trait WeightInfo {
 	fn reject_dna_sample() -> Weight;
 	fn process_dna_sample() -> Weight;
+	fn process_dna_sample_with_evidence() -> Weight;
 	fn submit_test_result() -> Weight;
+	fn attest_test_result() -> Weight;
 	fn submit_independent_test_result() -> Weight;
 	fn submit_data_bounty_details() -> Weight;
 }
@@ -48,21 +50,51 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(T::DbWeight::get().writes(3_u64))
 	}
 	// Storage: GeneticTesting DnaSamples (r:1 w:1)
+	// Storage: GeneticTesting CustodyEntryCount (r:1 w:1)
 	// Storage: Timestamp Now (r:1 w:0)
+	// Storage: GeneticTesting CustodyLog (r:0 w:1)
 	fn process_dna_sample() -> Weight {
 		Weight::from_ref_time(36_464_000_u64)
-			.saturating_add(T::DbWeight::get().reads(2_u64))
-			.saturating_add(T::DbWeight::get().writes(1_u64))
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	// Storage: GeneticTesting DnaSamples (r:1 w:1)
+	// Storage: GeneticTesting CustodyEntryCount (r:1 w:1)
+	// Storage: Timestamp Now (r:1 w:0)
+	// Storage: GeneticTesting CustodyLog (r:0 w:1)
+	fn process_dna_sample_with_evidence() -> Weight {
+		Weight::from_ref_time(38_912_000_u64)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
 	}
 	// Storage: GeneticTesting DnaSamples (r:1 w:1)
 	// Storage: Timestamp Now (r:1 w:0)
 	// Storage: GeneticTesting DnaTestResultsByLab (r:1 w:1)
 	// Storage: GeneticTesting DnaTestResultsByOwner (r:1 w:1)
 	// Storage: GeneticTesting DnaTestResults (r:0 w:1)
+	// Storage: GeneticTesting TestResultAttestations (r:0 w:1)
 	fn submit_test_result() -> Weight {
 		Weight::from_ref_time(46_355_000_u64)
 			.saturating_add(T::DbWeight::get().reads(4_u64))
-			.saturating_add(T::DbWeight::get().writes(4_u64))
+			.saturating_add(T::DbWeight::get().writes(5_u64))
+	}
+	// Storage: GeneticTesting DnaSamples (r:1 w:0)
+	// Storage: GeneticTesting DnaTestResults (r:1 w:0)
+	// Storage: Timestamp Now (r:1 w:0)
+	// Storage: GeneticTesting TestResultAttestations (r:0 w:1)
+	fn attest_test_result() -> Weight {
+		Weight::from_ref_time(68_214_000_u64)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	// Storage: GeneticTesting DnaSamples (r:1 w:0)
+	// Storage: GeneticTesting DnaTestResults (r:1 w:0)
+	// Storage: Timestamp Now (r:1 w:0)
+	// Storage: GeneticTesting TestResultAttestations (r:0 w:1)
+	fn attest_test_result() -> Weight {
+		Weight::from_ref_time(68_214_000_u64)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
 	}
 	// Storage: System Account (r:1 w:0)
 	// Storage: RandomnessCollectiveFlip RandomMaterial (r:1 w:0)
@@ -94,21 +126,33 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().writes(3_u64))
 	}
 	// Storage: GeneticTesting DnaSamples (r:1 w:1)
+	// Storage: GeneticTesting CustodyEntryCount (r:1 w:1)
 	// Storage: Timestamp Now (r:1 w:0)
+	// Storage: GeneticTesting CustodyLog (r:0 w:1)
 	fn process_dna_sample() -> Weight {
 		Weight::from_ref_time(36_464_000_u64)
-			.saturating_add(RocksDbWeight::get().reads(2_u64))
-			.saturating_add(RocksDbWeight::get().writes(1_u64))
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	// Storage: GeneticTesting DnaSamples (r:1 w:1)
+	// Storage: GeneticTesting CustodyEntryCount (r:1 w:1)
+	// Storage: Timestamp Now (r:1 w:0)
+	// Storage: GeneticTesting CustodyLog (r:0 w:1)
+	fn process_dna_sample_with_evidence() -> Weight {
+		Weight::from_ref_time(38_912_000_u64)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
 	}
 	// Storage: GeneticTesting DnaSamples (r:1 w:1)
 	// Storage: Timestamp Now (r:1 w:0)
 	// Storage: GeneticTesting DnaTestResultsByLab (r:1 w:1)
 	// Storage: GeneticTesting DnaTestResultsByOwner (r:1 w:1)
 	// Storage: GeneticTesting DnaTestResults (r:0 w:1)
+	// Storage: GeneticTesting TestResultAttestations (r:0 w:1)
 	fn submit_test_result() -> Weight {
 		Weight::from_ref_time(46_355_000_u64)
 			.saturating_add(RocksDbWeight::get().reads(4_u64))
-			.saturating_add(RocksDbWeight::get().writes(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(5_u64))
 	}
 	// Storage: System Account (r:1 w:0)
 	// Storage: RandomnessCollectiveFlip RandomMaterial (r:1 w:0)
@@ -6,11 +6,18 @@ mod tests {
 
 	use frame_support::{
 		assert_noop, assert_ok,
-		sp_runtime::traits::{Hash, Keccak256},
+		sp_runtime::traits::{BlakeTwo256, Hash, Keccak256},
+		storage::unhashed,
+		traits::StorageVersion,
 	};
 
+	use codec::{Decode, Encode};
 	use frame_system::RawOrigin;
-	use genetic_testing::{DnaSampleStatus, DnaTestResultSubmission, Error};
+	use genetic_testing::{
+		CustodyEntry, DnaSample, DnaSampleOf, DnaSampleStatus, DnaSamples, DnaSamplesByLab,
+		DnaSamplesByOwner, DnaTestResultSubmission, Error, TestResultAttestation, TrackingIdError,
+		MAX_REJECTION_DESCRIPTION_LENGTH, MAX_REJECTION_TITLE_LENGTH,
+	};
 	use labs::LabInfo;
 	use orders::PalletAccount;
 	use services::ServiceInfo;
@@ -22,6 +29,108 @@ mod tests {
 	use primitives_duration::ExpectedDuration;
 	use primitives_price_and_currency::{CurrencyType, Price, PriceByCurrency};
 
+	// Dna sample fields are private, so samples in a given status are decoded from their fields
+	fn indexed_dna_sample(
+		tracking_id: &[u8],
+		lab_id: AccountId,
+		owner_id: AccountId,
+		status: DnaSampleStatus,
+		created_at: u64,
+	) -> DnaSampleTrackingId {
+		let tracking_id = DnaSampleTrackingId::decode(&mut &tracking_id[..]).unwrap();
+		let encoded = (
+			&tracking_id,
+			lab_id,
+			owner_id,
+			status,
+			Keccak256::hash(&tracking_id.encode()),
+			None::<Vec<u8>>,
+			None::<Vec<u8>>,
+			created_at,
+			created_at,
+		)
+			.encode();
+
+		DnaSamples::<Test>::insert(
+			&tracking_id,
+			DnaSampleOf::<Test>::decode(&mut &encoded[..]).unwrap(),
+		);
+		DnaSamplesByOwner::<Test>::insert(owner_id, &tracking_id, ());
+		DnaSamplesByLab::<Test>::insert(lab_id, &tracking_id, ());
+
+		tracking_id
+	}
+
+	#[test]
+	fn get_dna_samples_filters_and_paginates() {
+		<ExternalityBuilder>::default().existential_deposit(1).build().execute_with(|| {
+			let tracking_ids = vec![
+				indexed_dna_sample(b"AAAAAAAAAAAAAAAAAAAAA", 1, 2, DnaSampleStatus::Registered, 3),
+				indexed_dna_sample(b"BBBBBBBBBBBBBBBBBBBBB", 1, 2, DnaSampleStatus::Rejected, 1),
+				indexed_dna_sample(b"CCCCCCCCCCCCCCCCCCCCC", 1, 2, DnaSampleStatus::Registered, 2),
+				indexed_dna_sample(b"DDDDDDDDDDDDDDDDDDDDD", 1, 3, DnaSampleStatus::Arrived, 4),
+			];
+
+			let ids_of = |dna_samples: Vec<DnaSampleOf<Test>>| -> Vec<DnaSampleTrackingId> {
+				dna_samples
+					.into_iter()
+					.map(|dna_sample| dna_sample.get_tracking_id().clone())
+					.collect()
+			};
+
+			// Dna samples are paged in the key order of the index
+			let owner_tracking_ids: Vec<DnaSampleTrackingId> =
+				DnaSamplesByOwner::<Test>::iter_key_prefix(2).collect();
+			let registered_tracking_ids: Vec<DnaSampleTrackingId> = owner_tracking_ids
+				.iter()
+				.filter(|tracking_id| *tracking_id != &tracking_ids[1])
+				.cloned()
+				.collect();
+
+			assert_eq!(
+				ids_of(GeneticTesting::get_dna_samples_by_owner_id(&2, None, 0, u32::MAX)),
+				owner_tracking_ids
+			);
+			assert_eq!(
+				ids_of(GeneticTesting::get_dna_samples_by_owner_id(&2, None, 1, 1)),
+				vec![owner_tracking_ids[1].clone()]
+			);
+			assert_eq!(
+				ids_of(GeneticTesting::get_dna_samples_by_owner_id(
+					&2,
+					Some(DnaSampleStatus::Registered),
+					0,
+					10
+				)),
+				registered_tracking_ids
+			);
+			assert_eq!(
+				ids_of(GeneticTesting::get_dna_samples_by_owner_id(
+					&2,
+					Some(DnaSampleStatus::Registered),
+					1,
+					10
+				)),
+				vec![registered_tracking_ids[1].clone()]
+			);
+			assert_eq!(
+				ids_of(GeneticTesting::get_dna_samples_by_lab_id(&1, None, 0, 10)),
+				DnaSamplesByLab::<Test>::iter_key_prefix(1).collect::<Vec<_>>()
+			);
+			assert_eq!(
+				ids_of(GeneticTesting::get_dna_samples_by_lab_id(
+					&1,
+					Some(DnaSampleStatus::Arrived),
+					0,
+					10
+				)),
+				vec![tracking_ids[3].clone()]
+			);
+			assert_eq!(GeneticTesting::get_dna_samples_by_lab_id(&1, None, 4, 10), Vec::new());
+			assert_eq!(GeneticTesting::get_dna_samples_by_lab_id(&1, None, 0, 0), Vec::new());
+		})
+	}
+
 	#[test]
 	fn reject_dna_sample_works() {
 		<ExternalityBuilder>::default().existential_deposit(1).build().execute_with(|| {
@@ -34,17 +143,19 @@ mod tests {
 					box_public_key: Keccak256::hash(
 						"0xDb9Af2d1f3ADD2726A132AA7A65Cc9E6fC5761C3".as_bytes()
 					),
-					name: "DeBio Lab".as_bytes().to_vec(),
-					email: "DeBio Email".as_bytes().to_vec(),
-					country: CountryCode::from_vec("DC".as_bytes().to_vec()),
-					region: RegionCode::from_vec("DBIO".as_bytes().to_vec()),
-					city: CityCode::from_vec("City".as_bytes().to_vec()),
-					address: "DeBio Address".as_bytes().to_vec(),
-					phone_number: "+6281394653625".as_bytes().to_vec(),
-					website: "DeBio Website".as_bytes().to_vec(),
-					latitude: Some("DeBio Latitude".as_bytes().to_vec()),
-					longitude: Some("DeBio Longtitude".as_bytes().to_vec()),
-					profile_image: Some("DeBio Profile Image uwu".as_bytes().to_vec()),
+					name: "DeBio Lab".as_bytes().to_vec().try_into().unwrap(),
+					email: "DeBio Email".as_bytes().to_vec().try_into().unwrap(),
+					country: CountryCode::try_from("ID".as_bytes().to_vec()).unwrap(),
+					region: RegionCode::try_from("JK".as_bytes().to_vec()).unwrap(),
+					city: CityCode::try_from("City".as_bytes().to_vec()).unwrap(),
+					address: "DeBio Address".as_bytes().to_vec().try_into().unwrap(),
+					phone_number: "+6281394653625".as_bytes().to_vec().try_into().unwrap(),
+					website: "DeBio Website".as_bytes().to_vec().try_into().unwrap(),
+					latitude: Some("-6.175392".as_bytes().to_vec().try_into().unwrap()),
+					longitude: Some("106.827153".as_bytes().to_vec().try_into().unwrap()),
+					profile_image: Some(
+						"DeBio Profile Image uwu".as_bytes().to_vec().try_into().unwrap()
+					),
 				}
 			));
 
@@ -56,24 +167,46 @@ mod tests {
 			let prices_by_currency_dbio = PriceByCurrency {
 				currency: CurrencyType::DBIO,
 				total_price: 10,
-				price_components: vec![Price { component: b"testing_price".to_vec(), value: 5 }],
-				additional_prices: vec![Price { component: b"qc_price".to_vec(), value: 5 }],
+				price_components: vec![Price {
+					component: b"testing_price".to_vec().try_into().unwrap(),
+					value: 5,
+				}]
+				.try_into()
+				.unwrap(),
+				additional_prices: vec![Price {
+					component: b"qc_price".to_vec().try_into().unwrap(),
+					value: 5,
+				}]
+				.try_into()
+				.unwrap(),
 			};
 
 			assert_ok!(Services::create_service(
 				RuntimeOrigin::signed(1),
 				ServiceInfo {
-					name: "DeBio service name".as_bytes().to_vec(),
-					prices_by_currency: vec![prices_by_currency_dbio],
+					name: "DeBio service name".as_bytes().to_vec().try_into().unwrap(),
+					prices_by_currency: vec![prices_by_currency_dbio].try_into().unwrap(),
 					expected_duration: ExpectedDuration::default(),
-					category: "DeBio service category".as_bytes().to_vec(),
-					description: "DeBio service description".as_bytes().to_vec(),
+					category: "DeBio service category".as_bytes().to_vec().try_into().unwrap(),
+					description: "DeBio service description"
+						.as_bytes()
+						.to_vec()
+						.try_into()
+						.unwrap(),
 					dna_collection_process: "DeBio service dna_collection_process"
 						.as_bytes()
-						.to_vec(),
-					test_result_sample: "DeBio service test_result_sample".as_bytes().to_vec(),
-					long_description: Some("DeBio service long_description".as_bytes().to_vec()),
-					image: Some("DeBio service image".as_bytes().to_vec()),
+						.to_vec()
+						.try_into()
+						.unwrap(),
+					test_result_sample: "DeBio service test_result_sample"
+						.as_bytes()
+						.to_vec()
+						.try_into()
+						.unwrap(),
+					long_description: Some(
+						"DeBio service long_description".as_bytes().to_vec().try_into().unwrap()
+					),
+					image: Some("DeBio service image".as_bytes().to_vec().try_into().unwrap()),
 				},
 				ServiceFlow::default()
 			));
@@ -90,10 +223,30 @@ mod tests {
 			));
 
 			let _order_id = Orders::last_order_by_customer_id(2).unwrap();
-			let _dna_sample = GeneticTesting::dna_samples_by_lab_id(1).unwrap();
+			let _dna_sample = GeneticTesting::dna_samples_by_lab_id(&1);
 
 			assert_ok!(Orders::set_order_paid(RuntimeOrigin::signed(2), _order_id));
 
+			assert_noop!(
+				GeneticTesting::reject_dna_sample(
+					RuntimeOrigin::signed(1),
+					_dna_sample[0].clone(),
+					vec![b'X'; MAX_REJECTION_TITLE_LENGTH as usize + 1],
+					"Reject DNA Description".as_bytes().to_vec()
+				),
+				Error::<Test>::RejectedTitleTooLong
+			);
+
+			assert_noop!(
+				GeneticTesting::reject_dna_sample(
+					RuntimeOrigin::signed(1),
+					_dna_sample[0].clone(),
+					"Reject DNA Title".as_bytes().to_vec(),
+					vec![b'X'; MAX_REJECTION_DESCRIPTION_LENGTH as usize + 1]
+				),
+				Error::<Test>::RejectedDescriptionTooLong
+			);
+
 			assert_ok!(GeneticTesting::reject_dna_sample(
 				RuntimeOrigin::signed(1),
 				_dna_sample[0].clone(),
@@ -115,7 +268,8 @@ mod tests {
 			assert_noop!(
 				GeneticTesting::reject_dna_sample(
 					RuntimeOrigin::signed(1),
-					DnaSampleTrackingId::from_vec("xxxxxxxxxxxxxxxxxxxxx".as_bytes().to_vec()),
+					DnaSampleTrackingId::try_from("XXXXXXXXXXXXXXXXXXXX8".as_bytes().to_vec())
+						.unwrap(),
 					"Reject DNA Title".as_bytes().to_vec(),
 					"Reject DNA Description".as_bytes().to_vec()
 				),
@@ -124,6 +278,110 @@ mod tests {
 		})
 	}
 
+	#[test]
+	fn cannot_reject_dna_sample_with_invalid_check_character() {
+		<ExternalityBuilder>::default().existential_deposit(1).build().execute_with(|| {
+			assert_noop!(
+				GeneticTesting::reject_dna_sample(
+					RuntimeOrigin::signed(1),
+					DnaSampleTrackingId::try_from("XXXXXXXXXXXXXXXXXXXXX".as_bytes().to_vec())
+						.unwrap(),
+					"Reject DNA Title".as_bytes().to_vec(),
+					"Reject DNA Description".as_bytes().to_vec()
+				),
+				Error::<Test>::InvalidTrackingId
+			);
+		})
+	}
+
+	#[test]
+	fn legacy_tracking_id_without_check_character_is_still_found() {
+		<ExternalityBuilder>::default().existential_deposit(1).build().execute_with(|| {
+			// Tracking ids registered before the check character was introduced
+			let legacy_tracking_id =
+				DnaSampleTrackingId::try_from("xxxxxxxxxxxxxxxxxxxxx".as_bytes().to_vec()).unwrap();
+			assert!(!legacy_tracking_id.has_valid_check_character());
+
+			DnaSamples::<Test>::insert(
+				&legacy_tracking_id,
+				DnaSample::new(legacy_tracking_id.clone(), 1, 2, Keccak256::hash(&[1]), 0),
+			);
+
+			assert_noop!(
+				GeneticTesting::reject_dna_sample(
+					RuntimeOrigin::signed(3),
+					legacy_tracking_id,
+					"Reject DNA Title".as_bytes().to_vec(),
+					"Reject DNA Description".as_bytes().to_vec()
+				),
+				Error::<Test>::Unauthorized
+			);
+		})
+	}
+
+	#[test]
+	fn normalize_lowercase_tracking_id_works() {
+		<ExternalityBuilder>::default().existential_deposit(1).build().execute_with(|| {
+			// Decoding skips the upper casing done by `try_from`, as an RPC client would
+			let lowercase_tracking_id =
+				DnaSampleTrackingId::decode(&mut &b"xxxxxxxxxxxxxxxxxxxx8"[..]).unwrap();
+			let tracking_id =
+				DnaSampleTrackingId::try_from("XXXXXXXXXXXXXXXXXXXX8".as_bytes().to_vec()).unwrap();
+			assert!(tracking_id.has_valid_check_character());
+
+			assert_eq!(
+				GeneticTesting::normalize_tracking_id(lowercase_tracking_id),
+				Ok(tracking_id)
+			);
+		})
+	}
+
+	#[test]
+	fn normalize_legacy_tracking_id_works() {
+		<ExternalityBuilder>::default().existential_deposit(1).build().execute_with(|| {
+			let legacy_tracking_id =
+				DnaSampleTrackingId::try_from("XXXXXXXXXXXXXXXXXXXXX".as_bytes().to_vec()).unwrap();
+			DnaSamples::<Test>::insert(
+				&legacy_tracking_id,
+				DnaSample::new(legacy_tracking_id.clone(), 1, 2, Keccak256::hash(&[1]), 0),
+			);
+
+			let lowercase_tracking_id =
+				DnaSampleTrackingId::decode(&mut &b"xxxxxxxxxxxxxxxxxxxxx"[..]).unwrap();
+
+			assert_eq!(
+				GeneticTesting::normalize_tracking_id(lowercase_tracking_id),
+				Ok(legacy_tracking_id)
+			);
+		})
+	}
+
+	#[test]
+	fn cannot_normalize_unknown_tracking_id_with_invalid_check_character() {
+		<ExternalityBuilder>::default().existential_deposit(1).build().execute_with(|| {
+			let tracking_id =
+				DnaSampleTrackingId::decode(&mut &b"xxxxxxxxxxxxxxxxxxxxx"[..]).unwrap();
+
+			assert_eq!(
+				GeneticTesting::normalize_tracking_id(tracking_id),
+				Err(TrackingIdError::InvalidCheckCharacter)
+			);
+		})
+	}
+
+	#[test]
+	fn cannot_normalize_tracking_id_with_invalid_character() {
+		<ExternalityBuilder>::default().existential_deposit(1).build().execute_with(|| {
+			let tracking_id =
+				DnaSampleTrackingId::decode(&mut &b"XXXXXXXXXX-XXXXXXXXX8"[..]).unwrap();
+
+			assert_eq!(
+				GeneticTesting::normalize_tracking_id(tracking_id),
+				Err(TrackingIdError::InvalidCharacter)
+			);
+		})
+	}
+
 	#[test]
 	fn cannot_reject_dna_sample_unauthorized() {
 		<ExternalityBuilder>::default().existential_deposit(1).build().execute_with(|| {
@@ -133,17 +391,19 @@ mod tests {
 					box_public_key: Keccak256::hash(
 						"0xDb9Af2d1f3ADD2726A132AA7A65Cc9E6fC5761C3".as_bytes()
 					),
-					name: "DeBio Lab".as_bytes().to_vec(),
-					email: "DeBio Email".as_bytes().to_vec(),
-					country: CountryCode::from_vec("DC".as_bytes().to_vec()),
-					region: RegionCode::from_vec("DBIO".as_bytes().to_vec()),
-					city: CityCode::from_vec("City".as_bytes().to_vec()),
-					address: "DeBio Address".as_bytes().to_vec(),
-					phone_number: "+6281394653625".as_bytes().to_vec(),
-					website: "DeBio Website".as_bytes().to_vec(),
-					latitude: Some("DeBio Latitude".as_bytes().to_vec()),
-					longitude: Some("DeBio Longtitude".as_bytes().to_vec()),
-					profile_image: Some("DeBio Profile Image uwu".as_bytes().to_vec()),
+					name: "DeBio Lab".as_bytes().to_vec().try_into().unwrap(),
+					email: "DeBio Email".as_bytes().to_vec().try_into().unwrap(),
+					country: CountryCode::try_from("ID".as_bytes().to_vec()).unwrap(),
+					region: RegionCode::try_from("JK".as_bytes().to_vec()).unwrap(),
+					city: CityCode::try_from("City".as_bytes().to_vec()).unwrap(),
+					address: "DeBio Address".as_bytes().to_vec().try_into().unwrap(),
+					phone_number: "+6281394653625".as_bytes().to_vec().try_into().unwrap(),
+					website: "DeBio Website".as_bytes().to_vec().try_into().unwrap(),
+					latitude: Some("-6.175392".as_bytes().to_vec().try_into().unwrap()),
+					longitude: Some("106.827153".as_bytes().to_vec().try_into().unwrap()),
+					profile_image: Some(
+						"DeBio Profile Image uwu".as_bytes().to_vec().try_into().unwrap()
+					),
 				}
 			));
 
@@ -155,17 +415,29 @@ mod tests {
 			assert_ok!(Services::create_service(
 				RuntimeOrigin::signed(1),
 				ServiceInfo {
-					name: "DeBio service name".as_bytes().to_vec(),
-					prices_by_currency: vec![PriceByCurrency::default()],
+					name: "DeBio service name".as_bytes().to_vec().try_into().unwrap(),
+					prices_by_currency: vec![PriceByCurrency::default()].try_into().unwrap(),
 					expected_duration: ExpectedDuration::default(),
-					category: "DeBio service category".as_bytes().to_vec(),
-					description: "DeBio service description".as_bytes().to_vec(),
+					category: "DeBio service category".as_bytes().to_vec().try_into().unwrap(),
+					description: "DeBio service description"
+						.as_bytes()
+						.to_vec()
+						.try_into()
+						.unwrap(),
 					dna_collection_process: "DeBio service dna_collection_process"
 						.as_bytes()
-						.to_vec(),
-					test_result_sample: "DeBio service test_result_sample".as_bytes().to_vec(),
-					long_description: Some("DeBio service long_description".as_bytes().to_vec()),
-					image: Some("DeBio service image".as_bytes().to_vec()),
+						.to_vec()
+						.try_into()
+						.unwrap(),
+					test_result_sample: "DeBio service test_result_sample"
+						.as_bytes()
+						.to_vec()
+						.try_into()
+						.unwrap(),
+					long_description: Some(
+						"DeBio service long_description".as_bytes().to_vec().try_into().unwrap()
+					),
+					image: Some("DeBio service image".as_bytes().to_vec().try_into().unwrap()),
 				},
 				ServiceFlow::default()
 			));
@@ -181,7 +453,7 @@ mod tests {
 				None,
 			));
 
-			let _dna_sample = GeneticTesting::dna_samples_by_lab_id(1).unwrap();
+			let _dna_sample = GeneticTesting::dna_samples_by_lab_id(&1);
 
 			assert_noop!(
 				GeneticTesting::reject_dna_sample(
@@ -207,17 +479,19 @@ mod tests {
 					box_public_key: Keccak256::hash(
 						"0xDb9Af2d1f3ADD2726A132AA7A65Cc9E6fC5761C3".as_bytes()
 					),
-					name: "DeBio Lab".as_bytes().to_vec(),
-					email: "DeBio Email".as_bytes().to_vec(),
-					country: CountryCode::from_vec("DC".as_bytes().to_vec()),
-					region: RegionCode::from_vec("DBIO".as_bytes().to_vec()),
-					city: CityCode::from_vec("City".as_bytes().to_vec()),
-					address: "DeBio Address".as_bytes().to_vec(),
-					phone_number: "+6281394653625".as_bytes().to_vec(),
-					website: "DeBio Website".as_bytes().to_vec(),
-					latitude: Some("DeBio Latitude".as_bytes().to_vec()),
-					longitude: Some("DeBio Longtitude".as_bytes().to_vec()),
-					profile_image: Some("DeBio Profile Image uwu".as_bytes().to_vec()),
+					name: "DeBio Lab".as_bytes().to_vec().try_into().unwrap(),
+					email: "DeBio Email".as_bytes().to_vec().try_into().unwrap(),
+					country: CountryCode::try_from("ID".as_bytes().to_vec()).unwrap(),
+					region: RegionCode::try_from("JK".as_bytes().to_vec()).unwrap(),
+					city: CityCode::try_from("City".as_bytes().to_vec()).unwrap(),
+					address: "DeBio Address".as_bytes().to_vec().try_into().unwrap(),
+					phone_number: "+6281394653625".as_bytes().to_vec().try_into().unwrap(),
+					website: "DeBio Website".as_bytes().to_vec().try_into().unwrap(),
+					latitude: Some("-6.175392".as_bytes().to_vec().try_into().unwrap()),
+					longitude: Some("106.827153".as_bytes().to_vec().try_into().unwrap()),
+					profile_image: Some(
+						"DeBio Profile Image uwu".as_bytes().to_vec().try_into().unwrap()
+					),
 				}
 			));
 
@@ -229,24 +503,46 @@ mod tests {
 			let prices_by_currency_dbio = PriceByCurrency {
 				currency: CurrencyType::DBIO,
 				total_price: 10,
-				price_components: vec![Price { component: b"testing_price".to_vec(), value: 5 }],
-				additional_prices: vec![Price { component: b"qc_price".to_vec(), value: 5 }],
+				price_components: vec![Price {
+					component: b"testing_price".to_vec().try_into().unwrap(),
+					value: 5,
+				}]
+				.try_into()
+				.unwrap(),
+				additional_prices: vec![Price {
+					component: b"qc_price".to_vec().try_into().unwrap(),
+					value: 5,
+				}]
+				.try_into()
+				.unwrap(),
 			};
 
 			assert_ok!(Services::create_service(
 				RuntimeOrigin::signed(1),
 				ServiceInfo {
-					name: "DeBio service name".as_bytes().to_vec(),
-					prices_by_currency: vec![prices_by_currency_dbio],
+					name: "DeBio service name".as_bytes().to_vec().try_into().unwrap(),
+					prices_by_currency: vec![prices_by_currency_dbio].try_into().unwrap(),
 					expected_duration: ExpectedDuration::default(),
-					category: "DeBio service category".as_bytes().to_vec(),
-					description: "DeBio service description".as_bytes().to_vec(),
+					category: "DeBio service category".as_bytes().to_vec().try_into().unwrap(),
+					description: "DeBio service description"
+						.as_bytes()
+						.to_vec()
+						.try_into()
+						.unwrap(),
 					dna_collection_process: "DeBio service dna_collection_process"
 						.as_bytes()
-						.to_vec(),
-					test_result_sample: "DeBio service test_result_sample".as_bytes().to_vec(),
-					long_description: Some("DeBio service long_description".as_bytes().to_vec()),
-					image: Some("DeBio service image".as_bytes().to_vec()),
+						.to_vec()
+						.try_into()
+						.unwrap(),
+					test_result_sample: "DeBio service test_result_sample"
+						.as_bytes()
+						.to_vec()
+						.try_into()
+						.unwrap(),
+					long_description: Some(
+						"DeBio service long_description".as_bytes().to_vec().try_into().unwrap()
+					),
+					image: Some("DeBio service image".as_bytes().to_vec().try_into().unwrap()),
 				},
 				ServiceFlow::default()
 			));
@@ -263,7 +559,7 @@ mod tests {
 			));
 
 			let _order_id = Orders::last_order_by_customer_id(2).unwrap();
-			let _dna_sample = GeneticTesting::dna_samples_by_lab_id(1).unwrap();
+			let _dna_sample = GeneticTesting::dna_samples_by_lab_id(&1);
 
 			assert_ok!(Orders::set_order_paid(RuntimeOrigin::signed(2), _order_id));
 
@@ -271,9 +567,15 @@ mod tests {
 				RuntimeOrigin::signed(1),
 				_dna_sample[0].clone(),
 				DnaTestResultSubmission {
-					comments: Some("DNA Test Result comments".as_bytes().to_vec()),
-					result_link: Some("DNA Test Result result_link".as_bytes().to_vec()),
-					report_link: Some("DNA Test Result report_link".as_bytes().to_vec())
+					comments: Some(
+						"DNA Test Result comments".as_bytes().to_vec().try_into().unwrap()
+					),
+					result_link: Some(
+						"DNA Test Result result_link".as_bytes().to_vec().try_into().unwrap()
+					),
+					report_link: Some(
+						"DNA Test Result report_link".as_bytes().to_vec().try_into().unwrap()
+					)
 				}
 			));
 
@@ -285,15 +587,15 @@ mod tests {
 			assert_eq!(_dna_test_result.owner_id, 2);
 			assert_eq!(
 				_dna_test_result.comments,
-				Some("DNA Test Result comments".as_bytes().to_vec())
+				Some("DNA Test Result comments".as_bytes().to_vec().try_into().unwrap())
 			);
 			assert_eq!(
 				_dna_test_result.result_link,
-				Some("DNA Test Result result_link".as_bytes().to_vec())
+				Some("DNA Test Result result_link".as_bytes().to_vec().try_into().unwrap())
 			);
 			assert_eq!(
 				_dna_test_result.report_link,
-				Some("DNA Test Result report_link".as_bytes().to_vec())
+				Some("DNA Test Result report_link".as_bytes().to_vec().try_into().unwrap())
 			);
 
 			assert_ok!(GeneticTesting::process_dna_sample(
@@ -310,13 +612,337 @@ mod tests {
 		})
 	}
 
+	#[test]
+	fn process_dna_sample_with_evidence_records_custody_log() {
+		<ExternalityBuilder>::default().existential_deposit(1).build().execute_with(|| {
+			PalletAccount::<Test>::put(5);
+
+			assert_ok!(Balances::set_balance(RawOrigin::Root.into(), 2, 100, 0));
+			assert_ok!(Labs::register_lab(
+				RuntimeOrigin::signed(1),
+				LabInfo {
+					box_public_key: Keccak256::hash(
+						"0xDb9Af2d1f3ADD2726A132AA7A65Cc9E6fC5761C3".as_bytes()
+					),
+					name: "DeBio Lab".as_bytes().to_vec().try_into().unwrap(),
+					email: "DeBio Email".as_bytes().to_vec().try_into().unwrap(),
+					country: CountryCode::try_from("ID".as_bytes().to_vec()).unwrap(),
+					region: RegionCode::try_from("JK".as_bytes().to_vec()).unwrap(),
+					city: CityCode::try_from("City".as_bytes().to_vec()).unwrap(),
+					address: "DeBio Address".as_bytes().to_vec().try_into().unwrap(),
+					phone_number: "+6281394653625".as_bytes().to_vec().try_into().unwrap(),
+					website: "DeBio Website".as_bytes().to_vec().try_into().unwrap(),
+					latitude: Some("-6.175392".as_bytes().to_vec().try_into().unwrap()),
+					longitude: Some("106.827153".as_bytes().to_vec().try_into().unwrap()),
+					profile_image: Some(
+						"DeBio Profile Image uwu".as_bytes().to_vec().try_into().unwrap()
+					),
+				}
+			));
+
+			assert_ok!(UserProfile::set_eth_address(
+				RuntimeOrigin::signed(1),
+				EthereumAddress([b'X'; 20])
+			));
+
+			let prices_by_currency_dbio = PriceByCurrency {
+				currency: CurrencyType::DBIO,
+				total_price: 10,
+				price_components: vec![Price {
+					component: b"testing_price".to_vec().try_into().unwrap(),
+					value: 5,
+				}]
+				.try_into()
+				.unwrap(),
+				additional_prices: vec![Price {
+					component: b"qc_price".to_vec().try_into().unwrap(),
+					value: 5,
+				}]
+				.try_into()
+				.unwrap(),
+			};
+
+			assert_ok!(Services::create_service(
+				RuntimeOrigin::signed(1),
+				ServiceInfo {
+					name: "DeBio service name".as_bytes().to_vec().try_into().unwrap(),
+					prices_by_currency: vec![prices_by_currency_dbio].try_into().unwrap(),
+					expected_duration: ExpectedDuration::default(),
+					category: "DeBio service category".as_bytes().to_vec().try_into().unwrap(),
+					description: "DeBio service description"
+						.as_bytes()
+						.to_vec()
+						.try_into()
+						.unwrap(),
+					dna_collection_process: "DeBio service dna_collection_process"
+						.as_bytes()
+						.to_vec()
+						.try_into()
+						.unwrap(),
+					test_result_sample: "DeBio service test_result_sample"
+						.as_bytes()
+						.to_vec()
+						.try_into()
+						.unwrap(),
+					long_description: Some(
+						"DeBio service long_description".as_bytes().to_vec().try_into().unwrap()
+					),
+					image: Some("DeBio service image".as_bytes().to_vec().try_into().unwrap()),
+				},
+				ServiceFlow::default()
+			));
+
+			let _lab = Labs::lab_by_account_id(1).unwrap();
+
+			assert_ok!(Orders::create_order(
+				RuntimeOrigin::signed(2),
+				_lab.services[0],
+				0,
+				Keccak256::hash("0xhJ7TRe456FADD2726A132ABJK5RCc9E6fC5869F4".as_bytes()),
+				ServiceFlow::StakingRequestService,
+				None
+			));
+
+			let _order_id = Orders::last_order_by_customer_id(2).unwrap();
+			let _dna_sample = GeneticTesting::dna_samples_by_lab_id(&1);
+
+			assert_ok!(Orders::set_order_paid(RuntimeOrigin::signed(2), _order_id));
+
+			let evidence_hash = Keccak256::hash("courier receipt".as_bytes());
+
+			assert_noop!(
+				GeneticTesting::process_dna_sample_with_evidence(
+					RuntimeOrigin::signed(1),
+					_dna_sample[0].clone(),
+					DnaSampleStatus::Arrived,
+					Some(vec![b'X'; 65]),
+					Some(evidence_hash)
+				),
+				Error::<Test>::CustodyNoteTooLong
+			);
+
+			assert_ok!(GeneticTesting::process_dna_sample_with_evidence(
+				RuntimeOrigin::signed(1),
+				_dna_sample[0].clone(),
+				DnaSampleStatus::Arrived,
+				Some("Received by courier".as_bytes().to_vec()),
+				Some(evidence_hash)
+			));
+
+			assert_eq!(
+				GeneticTesting::custody_log_by_tracking_id(_dna_sample[0].clone()),
+				vec![
+					CustodyEntry {
+						status: DnaSampleStatus::Registered,
+						actor: 2,
+						note: None,
+						evidence_hash: None,
+						recorded_at: 0,
+					},
+					CustodyEntry {
+						status: DnaSampleStatus::Arrived,
+						actor: 1,
+						note: Some("Received by courier".as_bytes().to_vec().try_into().unwrap()),
+						evidence_hash: Some(evidence_hash),
+						recorded_at: 0,
+					},
+				]
+			);
+		})
+	}
+
+	#[test]
+	fn attest_test_result_works() {
+		<ExternalityBuilder>::default().existential_deposit(1).build().execute_with(|| {
+			PalletAccount::<Test>::put(5);
+
+			assert_ok!(Balances::set_balance(RawOrigin::Root.into(), 2, 100, 0));
+			assert_ok!(Labs::register_lab(
+				RuntimeOrigin::signed(1),
+				LabInfo {
+					box_public_key: Keccak256::hash(
+						"0xDb9Af2d1f3ADD2726A132AA7A65Cc9E6fC5761C3".as_bytes()
+					),
+					name: "DeBio Lab".as_bytes().to_vec().try_into().unwrap(),
+					email: "DeBio Email".as_bytes().to_vec().try_into().unwrap(),
+					country: CountryCode::try_from("ID".as_bytes().to_vec()).unwrap(),
+					region: RegionCode::try_from("JK".as_bytes().to_vec()).unwrap(),
+					city: CityCode::try_from("City".as_bytes().to_vec()).unwrap(),
+					address: "DeBio Address".as_bytes().to_vec().try_into().unwrap(),
+					phone_number: "+6281394653625".as_bytes().to_vec().try_into().unwrap(),
+					website: "DeBio Website".as_bytes().to_vec().try_into().unwrap(),
+					latitude: Some("-6.175392".as_bytes().to_vec().try_into().unwrap()),
+					longitude: Some("106.827153".as_bytes().to_vec().try_into().unwrap()),
+					profile_image: Some(
+						"DeBio Profile Image uwu".as_bytes().to_vec().try_into().unwrap()
+					),
+				}
+			));
+
+			assert_ok!(UserProfile::set_eth_address(
+				RuntimeOrigin::signed(1),
+				EthereumAddress([b'X'; 20])
+			));
+
+			let prices_by_currency_dbio = PriceByCurrency {
+				currency: CurrencyType::DBIO,
+				total_price: 10,
+				price_components: vec![Price {
+					component: b"testing_price".to_vec().try_into().unwrap(),
+					value: 5,
+				}]
+				.try_into()
+				.unwrap(),
+				additional_prices: vec![Price {
+					component: b"qc_price".to_vec().try_into().unwrap(),
+					value: 5,
+				}]
+				.try_into()
+				.unwrap(),
+			};
+
+			assert_ok!(Services::create_service(
+				RuntimeOrigin::signed(1),
+				ServiceInfo {
+					name: "DeBio service name".as_bytes().to_vec().try_into().unwrap(),
+					prices_by_currency: vec![prices_by_currency_dbio].try_into().unwrap(),
+					expected_duration: ExpectedDuration::default(),
+					category: "DeBio service category".as_bytes().to_vec().try_into().unwrap(),
+					description: "DeBio service description"
+						.as_bytes()
+						.to_vec()
+						.try_into()
+						.unwrap(),
+					dna_collection_process: "DeBio service dna_collection_process"
+						.as_bytes()
+						.to_vec()
+						.try_into()
+						.unwrap(),
+					test_result_sample: "DeBio service test_result_sample"
+						.as_bytes()
+						.to_vec()
+						.try_into()
+						.unwrap(),
+					long_description: Some(
+						"DeBio service long_description".as_bytes().to_vec().try_into().unwrap()
+					),
+					image: Some("DeBio service image".as_bytes().to_vec().try_into().unwrap()),
+				},
+				ServiceFlow::default()
+			));
+
+			let _lab = Labs::lab_by_account_id(1).unwrap();
+
+			assert_ok!(Orders::create_order(
+				RuntimeOrigin::signed(2),
+				_lab.services[0],
+				0,
+				Keccak256::hash("0xhJ7TRe456FADD2726A132ABJK5RCc9E6fC5869F4".as_bytes()),
+				ServiceFlow::StakingRequestService,
+				None
+			));
+
+			let _order_id = Orders::last_order_by_customer_id(2).unwrap();
+			let _dna_sample = GeneticTesting::dna_samples_by_lab_id(&1);
+
+			assert_ok!(Orders::set_order_paid(RuntimeOrigin::signed(2), _order_id));
+
+			let content_hash = Keccak256::hash("DNA Test Result report".as_bytes());
+			let message = GeneticTesting::attestation_message(&_dna_sample[0], &content_hash);
+
+			assert_noop!(
+				GeneticTesting::attest_test_result(
+					RuntimeOrigin::signed(1),
+					_dna_sample[0].clone(),
+					content_hash,
+					None
+				),
+				Error::<Test>::DnaTestResultNotYetSubmitted
+			);
+
+			assert_ok!(GeneticTesting::submit_test_result(
+				RuntimeOrigin::signed(1),
+				_dna_sample[0].clone(),
+				DnaTestResultSubmission {
+					comments: Some(
+						"DNA Test Result comments".as_bytes().to_vec().try_into().unwrap()
+					),
+					result_link: Some(
+						"DNA Test Result result_link".as_bytes().to_vec().try_into().unwrap()
+					),
+					report_link: Some(
+						"DNA Test Result report_link".as_bytes().to_vec().try_into().unwrap()
+					)
+				}
+			));
+
+			assert_noop!(
+				GeneticTesting::attest_test_result(
+					RuntimeOrigin::signed(2),
+					_dna_sample[0].clone(),
+					content_hash,
+					None
+				),
+				Error::<Test>::Unauthorized
+			);
+
+			assert_noop!(
+				GeneticTesting::attest_test_result(
+					RuntimeOrigin::signed(1),
+					_dna_sample[0].clone(),
+					content_hash,
+					Some(TestAttestationSignature(2, BlakeTwo256::hash(&message)))
+				),
+				Error::<Test>::BadSignature
+			);
+
+			assert_ok!(GeneticTesting::attest_test_result(
+				RuntimeOrigin::signed(1),
+				_dna_sample[0].clone(),
+				content_hash,
+				Some(TestAttestationSignature(1, BlakeTwo256::hash(&message)))
+			));
+
+			assert_eq!(
+				GeneticTesting::test_result_attestation_by_tracking_id(_dna_sample[0].clone()),
+				Some(TestResultAttestation {
+					content_hash,
+					signature: Some(TestAttestationSignature(1, BlakeTwo256::hash(&message))),
+					attested_at: 0,
+				})
+			);
+			assert!(GeneticTesting::verify_test_result(&_dna_sample[0], &content_hash));
+			assert!(!GeneticTesting::verify_test_result(
+				&_dna_sample[0],
+				&Keccak256::hash("Tampered report".as_bytes())
+			));
+
+			// Resubmitting the result drops the previous attestation
+			assert_ok!(GeneticTesting::submit_test_result(
+				RuntimeOrigin::signed(1),
+				_dna_sample[0].clone(),
+				DnaTestResultSubmission {
+					comments: None,
+					result_link: Some(
+						"DNA Test Result result_link".as_bytes().to_vec().try_into().unwrap()
+					),
+					report_link: Some(
+						"DNA Test Result report_link".as_bytes().to_vec().try_into().unwrap()
+					)
+				}
+			));
+			assert!(!GeneticTesting::verify_test_result(&_dna_sample[0], &content_hash));
+		})
+	}
+
 	#[test]
 	fn cannot_process_dna_sample_works_not_found() {
 		<ExternalityBuilder>::default().existential_deposit(1).build().execute_with(|| {
 			assert_noop!(
 				GeneticTesting::process_dna_sample(
 					RuntimeOrigin::signed(1),
-					DnaSampleTrackingId::from_vec("xxxxxxxxxxxxxxxxxxxxx".as_bytes().to_vec()),
+					DnaSampleTrackingId::try_from("XXXXXXXXXXXXXXXXXXXX8".as_bytes().to_vec())
+						.unwrap(),
 					DnaSampleStatus::ResultReady
 				),
 				Error::<Test>::DnaSampleNotFound
@@ -336,17 +962,19 @@ mod tests {
 					box_public_key: Keccak256::hash(
 						"0xDb9Af2d1f3ADD2726A132AA7A65Cc9E6fC5761C3".as_bytes()
 					),
-					name: "DeBio Lab".as_bytes().to_vec(),
-					email: "DeBio Email".as_bytes().to_vec(),
-					country: CountryCode::from_vec("DC".as_bytes().to_vec()),
-					region: RegionCode::from_vec("DBIO".as_bytes().to_vec()),
-					city: CityCode::from_vec("City".as_bytes().to_vec()),
-					address: "DeBio Address".as_bytes().to_vec(),
-					phone_number: "+6281394653625".as_bytes().to_vec(),
-					website: "DeBio Website".as_bytes().to_vec(),
-					latitude: Some("DeBio Latitude".as_bytes().to_vec()),
-					longitude: Some("DeBio Longtitude".as_bytes().to_vec()),
-					profile_image: Some("DeBio Profile Image uwu".as_bytes().to_vec()),
+					name: "DeBio Lab".as_bytes().to_vec().try_into().unwrap(),
+					email: "DeBio Email".as_bytes().to_vec().try_into().unwrap(),
+					country: CountryCode::try_from("ID".as_bytes().to_vec()).unwrap(),
+					region: RegionCode::try_from("JK".as_bytes().to_vec()).unwrap(),
+					city: CityCode::try_from("City".as_bytes().to_vec()).unwrap(),
+					address: "DeBio Address".as_bytes().to_vec().try_into().unwrap(),
+					phone_number: "+6281394653625".as_bytes().to_vec().try_into().unwrap(),
+					website: "DeBio Website".as_bytes().to_vec().try_into().unwrap(),
+					latitude: Some("-6.175392".as_bytes().to_vec().try_into().unwrap()),
+					longitude: Some("106.827153".as_bytes().to_vec().try_into().unwrap()),
+					profile_image: Some(
+						"DeBio Profile Image uwu".as_bytes().to_vec().try_into().unwrap()
+					),
 				}
 			));
 
@@ -358,24 +986,46 @@ mod tests {
 			let prices_by_currency_dbio = PriceByCurrency {
 				currency: CurrencyType::DBIO,
 				total_price: 10,
-				price_components: vec![Price { component: b"testing_price".to_vec(), value: 5 }],
-				additional_prices: vec![Price { component: b"qc_price".to_vec(), value: 5 }],
+				price_components: vec![Price {
+					component: b"testing_price".to_vec().try_into().unwrap(),
+					value: 5,
+				}]
+				.try_into()
+				.unwrap(),
+				additional_prices: vec![Price {
+					component: b"qc_price".to_vec().try_into().unwrap(),
+					value: 5,
+				}]
+				.try_into()
+				.unwrap(),
 			};
 
 			assert_ok!(Services::create_service(
 				RuntimeOrigin::signed(1),
 				ServiceInfo {
-					name: "DeBio service name".as_bytes().to_vec(),
-					prices_by_currency: vec![prices_by_currency_dbio],
+					name: "DeBio service name".as_bytes().to_vec().try_into().unwrap(),
+					prices_by_currency: vec![prices_by_currency_dbio].try_into().unwrap(),
 					expected_duration: ExpectedDuration::default(),
-					category: "DeBio service category".as_bytes().to_vec(),
-					description: "DeBio service description".as_bytes().to_vec(),
+					category: "DeBio service category".as_bytes().to_vec().try_into().unwrap(),
+					description: "DeBio service description"
+						.as_bytes()
+						.to_vec()
+						.try_into()
+						.unwrap(),
 					dna_collection_process: "DeBio service dna_collection_process"
 						.as_bytes()
-						.to_vec(),
-					test_result_sample: "DeBio service test_result_sample".as_bytes().to_vec(),
-					long_description: Some("DeBio service long_description".as_bytes().to_vec()),
-					image: Some("DeBio service image".as_bytes().to_vec()),
+						.to_vec()
+						.try_into()
+						.unwrap(),
+					test_result_sample: "DeBio service test_result_sample"
+						.as_bytes()
+						.to_vec()
+						.try_into()
+						.unwrap(),
+					long_description: Some(
+						"DeBio service long_description".as_bytes().to_vec().try_into().unwrap()
+					),
+					image: Some("DeBio service image".as_bytes().to_vec().try_into().unwrap()),
 				},
 				ServiceFlow::default()
 			));
@@ -392,7 +1042,7 @@ mod tests {
 			));
 
 			let _order_id = Orders::last_order_by_customer_id(2).unwrap();
-			let _dna_sample = GeneticTesting::dna_samples_by_lab_id(1).unwrap();
+			let _dna_sample = GeneticTesting::dna_samples_by_lab_id(&1);
 
 			assert_ok!(Orders::set_order_paid(RuntimeOrigin::signed(2), _order_id));
 
@@ -400,9 +1050,15 @@ mod tests {
 				RuntimeOrigin::signed(1),
 				_dna_sample[0].clone(),
 				DnaTestResultSubmission {
-					comments: Some("DNA Test Result comments".as_bytes().to_vec()),
-					result_link: Some("DNA Test Result result_link".as_bytes().to_vec()),
-					report_link: Some("DNA Test Result report_link".as_bytes().to_vec())
+					comments: Some(
+						"DNA Test Result comments".as_bytes().to_vec().try_into().unwrap()
+					),
+					result_link: Some(
+						"DNA Test Result result_link".as_bytes().to_vec().try_into().unwrap()
+					),
+					report_link: Some(
+						"DNA Test Result report_link".as_bytes().to_vec().try_into().unwrap()
+					)
 				}
 			));
 
@@ -414,15 +1070,15 @@ mod tests {
 			assert_eq!(_dna_test_result.owner_id, 2);
 			assert_eq!(
 				_dna_test_result.comments,
-				Some("DNA Test Result comments".as_bytes().to_vec())
+				Some("DNA Test Result comments".as_bytes().to_vec().try_into().unwrap())
 			);
 			assert_eq!(
 				_dna_test_result.result_link,
-				Some("DNA Test Result result_link".as_bytes().to_vec())
+				Some("DNA Test Result result_link".as_bytes().to_vec().try_into().unwrap())
 			);
 			assert_eq!(
 				_dna_test_result.report_link,
-				Some("DNA Test Result report_link".as_bytes().to_vec())
+				Some("DNA Test Result report_link".as_bytes().to_vec().try_into().unwrap())
 			);
 
 			assert_noop!(
@@ -448,17 +1104,19 @@ mod tests {
 					box_public_key: Keccak256::hash(
 						"0xDb9Af2d1f3ADD2726A132AA7A65Cc9E6fC5761C3".as_bytes()
 					),
-					name: "DeBio Lab".as_bytes().to_vec(),
-					email: "DeBio Email".as_bytes().to_vec(),
-					country: CountryCode::from_vec("DC".as_bytes().to_vec()),
-					region: RegionCode::from_vec("DBIO".as_bytes().to_vec()),
-					city: CityCode::from_vec("City".as_bytes().to_vec()),
-					address: "DeBio Address".as_bytes().to_vec(),
-					phone_number: "+6281394653625".as_bytes().to_vec(),
-					website: "DeBio Website".as_bytes().to_vec(),
-					latitude: Some("DeBio Latitude".as_bytes().to_vec()),
-					longitude: Some("DeBio Longtitude".as_bytes().to_vec()),
-					profile_image: Some("DeBio Profile Image uwu".as_bytes().to_vec()),
+					name: "DeBio Lab".as_bytes().to_vec().try_into().unwrap(),
+					email: "DeBio Email".as_bytes().to_vec().try_into().unwrap(),
+					country: CountryCode::try_from("ID".as_bytes().to_vec()).unwrap(),
+					region: RegionCode::try_from("JK".as_bytes().to_vec()).unwrap(),
+					city: CityCode::try_from("City".as_bytes().to_vec()).unwrap(),
+					address: "DeBio Address".as_bytes().to_vec().try_into().unwrap(),
+					phone_number: "+6281394653625".as_bytes().to_vec().try_into().unwrap(),
+					website: "DeBio Website".as_bytes().to_vec().try_into().unwrap(),
+					latitude: Some("-6.175392".as_bytes().to_vec().try_into().unwrap()),
+					longitude: Some("106.827153".as_bytes().to_vec().try_into().unwrap()),
+					profile_image: Some(
+						"DeBio Profile Image uwu".as_bytes().to_vec().try_into().unwrap()
+					),
 				}
 			));
 
@@ -470,17 +1128,29 @@ mod tests {
 			assert_ok!(Services::create_service(
 				RuntimeOrigin::signed(1),
 				ServiceInfo {
-					name: "DeBio service name".as_bytes().to_vec(),
-					prices_by_currency: vec![PriceByCurrency::default()],
+					name: "DeBio service name".as_bytes().to_vec().try_into().unwrap(),
+					prices_by_currency: vec![PriceByCurrency::default()].try_into().unwrap(),
 					expected_duration: ExpectedDuration::default(),
-					category: "DeBio service category".as_bytes().to_vec(),
-					description: "DeBio service description".as_bytes().to_vec(),
+					category: "DeBio service category".as_bytes().to_vec().try_into().unwrap(),
+					description: "DeBio service description"
+						.as_bytes()
+						.to_vec()
+						.try_into()
+						.unwrap(),
 					dna_collection_process: "DeBio service dna_collection_process"
 						.as_bytes()
-						.to_vec(),
-					test_result_sample: "DeBio service test_result_sample".as_bytes().to_vec(),
-					long_description: Some("DeBio service long_description".as_bytes().to_vec()),
-					image: Some("DeBio service image".as_bytes().to_vec()),
+						.to_vec()
+						.try_into()
+						.unwrap(),
+					test_result_sample: "DeBio service test_result_sample"
+						.as_bytes()
+						.to_vec()
+						.try_into()
+						.unwrap(),
+					long_description: Some(
+						"DeBio service long_description".as_bytes().to_vec().try_into().unwrap()
+					),
+					image: Some("DeBio service image".as_bytes().to_vec().try_into().unwrap()),
 				},
 				ServiceFlow::default()
 			));
@@ -496,7 +1166,7 @@ mod tests {
 				None,
 			));
 
-			let _dna_sample = GeneticTesting::dna_samples_by_lab_id(1).unwrap();
+			let _dna_sample = GeneticTesting::dna_samples_by_lab_id(&1);
 			let _order_id = Orders::last_order_by_customer_id(2).unwrap();
 
 			assert_ok!(Orders::set_order_paid(RuntimeOrigin::signed(2), _order_id));
@@ -523,17 +1193,19 @@ mod tests {
 					box_public_key: Keccak256::hash(
 						"0xDb9Af2d1f3ADD2726A132AA7A65Cc9E6fC5761C3".as_bytes()
 					),
-					name: "DeBio Lab".as_bytes().to_vec(),
-					email: "DeBio Email".as_bytes().to_vec(),
-					country: CountryCode::from_vec("DC".as_bytes().to_vec()),
-					region: RegionCode::from_vec("DBIO".as_bytes().to_vec()),
-					city: CityCode::from_vec("City".as_bytes().to_vec()),
-					address: "DeBio Address".as_bytes().to_vec(),
-					phone_number: "+6281394653625".as_bytes().to_vec(),
-					website: "DeBio Website".as_bytes().to_vec(),
-					latitude: Some("DeBio Latitude".as_bytes().to_vec()),
-					longitude: Some("DeBio Longtitude".as_bytes().to_vec()),
-					profile_image: Some("DeBio Profile Image uwu".as_bytes().to_vec()),
+					name: "DeBio Lab".as_bytes().to_vec().try_into().unwrap(),
+					email: "DeBio Email".as_bytes().to_vec().try_into().unwrap(),
+					country: CountryCode::try_from("ID".as_bytes().to_vec()).unwrap(),
+					region: RegionCode::try_from("JK".as_bytes().to_vec()).unwrap(),
+					city: CityCode::try_from("City".as_bytes().to_vec()).unwrap(),
+					address: "DeBio Address".as_bytes().to_vec().try_into().unwrap(),
+					phone_number: "+6281394653625".as_bytes().to_vec().try_into().unwrap(),
+					website: "DeBio Website".as_bytes().to_vec().try_into().unwrap(),
+					latitude: Some("-6.175392".as_bytes().to_vec().try_into().unwrap()),
+					longitude: Some("106.827153".as_bytes().to_vec().try_into().unwrap()),
+					profile_image: Some(
+						"DeBio Profile Image uwu".as_bytes().to_vec().try_into().unwrap()
+					),
 				}
 			));
 
@@ -545,24 +1217,46 @@ mod tests {
 			let prices_by_currency_dbio = PriceByCurrency {
 				currency: CurrencyType::DBIO,
 				total_price: 10,
-				price_components: vec![Price { component: b"testing_price".to_vec(), value: 5 }],
-				additional_prices: vec![Price { component: b"qc_price".to_vec(), value: 5 }],
+				price_components: vec![Price {
+					component: b"testing_price".to_vec().try_into().unwrap(),
+					value: 5,
+				}]
+				.try_into()
+				.unwrap(),
+				additional_prices: vec![Price {
+					component: b"qc_price".to_vec().try_into().unwrap(),
+					value: 5,
+				}]
+				.try_into()
+				.unwrap(),
 			};
 
 			assert_ok!(Services::create_service(
 				RuntimeOrigin::signed(1),
 				ServiceInfo {
-					name: "DeBio service name".as_bytes().to_vec(),
-					prices_by_currency: vec![prices_by_currency_dbio],
+					name: "DeBio service name".as_bytes().to_vec().try_into().unwrap(),
+					prices_by_currency: vec![prices_by_currency_dbio].try_into().unwrap(),
 					expected_duration: ExpectedDuration::default(),
-					category: "DeBio service category".as_bytes().to_vec(),
-					description: "DeBio service description".as_bytes().to_vec(),
+					category: "DeBio service category".as_bytes().to_vec().try_into().unwrap(),
+					description: "DeBio service description"
+						.as_bytes()
+						.to_vec()
+						.try_into()
+						.unwrap(),
 					dna_collection_process: "DeBio service dna_collection_process"
 						.as_bytes()
-						.to_vec(),
-					test_result_sample: "DeBio service test_result_sample".as_bytes().to_vec(),
-					long_description: Some("DeBio service long_description".as_bytes().to_vec()),
-					image: Some("DeBio service image".as_bytes().to_vec()),
+						.to_vec()
+						.try_into()
+						.unwrap(),
+					test_result_sample: "DeBio service test_result_sample"
+						.as_bytes()
+						.to_vec()
+						.try_into()
+						.unwrap(),
+					long_description: Some(
+						"DeBio service long_description".as_bytes().to_vec().try_into().unwrap()
+					),
+					image: Some("DeBio service image".as_bytes().to_vec().try_into().unwrap()),
 				},
 				ServiceFlow::default()
 			));
@@ -578,7 +1272,7 @@ mod tests {
 				None,
 			));
 
-			let _dna_sample = GeneticTesting::dna_samples_by_lab_id(1).unwrap();
+			let _dna_sample = GeneticTesting::dna_samples_by_lab_id(&1);
 			let _order_id = Orders::last_order_by_customer_id(2).unwrap();
 
 			assert_ok!(Orders::set_order_paid(RuntimeOrigin::signed(2), _order_id));
@@ -586,9 +1280,15 @@ mod tests {
 				RuntimeOrigin::signed(1),
 				_dna_sample[0].clone(),
 				DnaTestResultSubmission {
-					comments: Some("DNA Test Result comments".as_bytes().to_vec()),
-					result_link: Some("DNA Test Result result_link".as_bytes().to_vec()),
-					report_link: Some("DNA Test Result report_link".as_bytes().to_vec())
+					comments: Some(
+						"DNA Test Result comments".as_bytes().to_vec().try_into().unwrap()
+					),
+					result_link: Some(
+						"DNA Test Result result_link".as_bytes().to_vec().try_into().unwrap()
+					),
+					report_link: Some(
+						"DNA Test Result report_link".as_bytes().to_vec().try_into().unwrap()
+					)
 				}
 			));
 
@@ -600,15 +1300,15 @@ mod tests {
 			assert_eq!(_dna_test_result.owner_id, 2);
 			assert_eq!(
 				_dna_test_result.comments,
-				Some("DNA Test Result comments".as_bytes().to_vec())
+				Some("DNA Test Result comments".as_bytes().to_vec().try_into().unwrap())
 			);
 			assert_eq!(
 				_dna_test_result.result_link,
-				Some("DNA Test Result result_link".as_bytes().to_vec())
+				Some("DNA Test Result result_link".as_bytes().to_vec().try_into().unwrap())
 			);
 			assert_eq!(
 				_dna_test_result.report_link,
-				Some("DNA Test Result report_link".as_bytes().to_vec())
+				Some("DNA Test Result report_link".as_bytes().to_vec().try_into().unwrap())
 			);
 		})
 	}
@@ -622,17 +1322,19 @@ mod tests {
 					box_public_key: Keccak256::hash(
 						"0xDb9Af2d1f3ADD2726A132AA7A65Cc9E6fC5761C3".as_bytes()
 					),
-					name: "DeBio Lab".as_bytes().to_vec(),
-					email: "DeBio Email".as_bytes().to_vec(),
-					country: CountryCode::from_vec("DC".as_bytes().to_vec()),
-					region: RegionCode::from_vec("DBIO".as_bytes().to_vec()),
-					city: CityCode::from_vec("City".as_bytes().to_vec()),
-					address: "DeBio Address".as_bytes().to_vec(),
-					phone_number: "+6281394653625".as_bytes().to_vec(),
-					website: "DeBio Website".as_bytes().to_vec(),
-					latitude: Some("DeBio Latitude".as_bytes().to_vec()),
-					longitude: Some("DeBio Longtitude".as_bytes().to_vec()),
-					profile_image: Some("DeBio Profile Image uwu".as_bytes().to_vec()),
+					name: "DeBio Lab".as_bytes().to_vec().try_into().unwrap(),
+					email: "DeBio Email".as_bytes().to_vec().try_into().unwrap(),
+					country: CountryCode::try_from("ID".as_bytes().to_vec()).unwrap(),
+					region: RegionCode::try_from("JK".as_bytes().to_vec()).unwrap(),
+					city: CityCode::try_from("City".as_bytes().to_vec()).unwrap(),
+					address: "DeBio Address".as_bytes().to_vec().try_into().unwrap(),
+					phone_number: "+6281394653625".as_bytes().to_vec().try_into().unwrap(),
+					website: "DeBio Website".as_bytes().to_vec().try_into().unwrap(),
+					latitude: Some("-6.175392".as_bytes().to_vec().try_into().unwrap()),
+					longitude: Some("106.827153".as_bytes().to_vec().try_into().unwrap()),
+					profile_image: Some(
+						"DeBio Profile Image uwu".as_bytes().to_vec().try_into().unwrap()
+					),
 				}
 			));
 
@@ -644,17 +1346,29 @@ mod tests {
 			assert_ok!(Services::create_service(
 				RuntimeOrigin::signed(1),
 				ServiceInfo {
-					name: "DeBio service name".as_bytes().to_vec(),
-					prices_by_currency: vec![PriceByCurrency::default()],
+					name: "DeBio service name".as_bytes().to_vec().try_into().unwrap(),
+					prices_by_currency: vec![PriceByCurrency::default()].try_into().unwrap(),
 					expected_duration: ExpectedDuration::default(),
-					category: "DeBio service category".as_bytes().to_vec(),
-					description: "DeBio service description".as_bytes().to_vec(),
+					category: "DeBio service category".as_bytes().to_vec().try_into().unwrap(),
+					description: "DeBio service description"
+						.as_bytes()
+						.to_vec()
+						.try_into()
+						.unwrap(),
 					dna_collection_process: "DeBio service dna_collection_process"
 						.as_bytes()
-						.to_vec(),
-					test_result_sample: "DeBio service test_result_sample".as_bytes().to_vec(),
-					long_description: Some("DeBio service long_description".as_bytes().to_vec()),
-					image: Some("DeBio service image".as_bytes().to_vec()),
+						.to_vec()
+						.try_into()
+						.unwrap(),
+					test_result_sample: "DeBio service test_result_sample"
+						.as_bytes()
+						.to_vec()
+						.try_into()
+						.unwrap(),
+					long_description: Some(
+						"DeBio service long_description".as_bytes().to_vec().try_into().unwrap()
+					),
+					image: Some("DeBio service image".as_bytes().to_vec().try_into().unwrap()),
 				},
 				ServiceFlow::default()
 			));
@@ -673,11 +1387,18 @@ mod tests {
 			assert_noop!(
 				GeneticTesting::submit_test_result(
 					RuntimeOrigin::signed(1),
-					DnaSampleTrackingId::from_vec("xxxxxxxxxxxxxxxxxxxxx".as_bytes().to_vec()),
+					DnaSampleTrackingId::try_from("XXXXXXXXXXXXXXXXXXXX8".as_bytes().to_vec())
+						.unwrap(),
 					DnaTestResultSubmission {
-						comments: Some("DNA Test Result comments".as_bytes().to_vec()),
-						result_link: Some("DNA Test Result result_link".as_bytes().to_vec()),
-						report_link: Some("DNA Test Result report_link".as_bytes().to_vec())
+						comments: Some(
+							"DNA Test Result comments".as_bytes().to_vec().try_into().unwrap()
+						),
+						result_link: Some(
+							"DNA Test Result result_link".as_bytes().to_vec().try_into().unwrap()
+						),
+						report_link: Some(
+							"DNA Test Result report_link".as_bytes().to_vec().try_into().unwrap()
+						)
 					}
 				),
 				Error::<Test>::DnaSampleNotFound
@@ -694,17 +1415,19 @@ mod tests {
 					box_public_key: Keccak256::hash(
 						"0xDb9Af2d1f3ADD2726A132AA7A65Cc9E6fC5761C3".as_bytes()
 					),
-					name: "DeBio Lab".as_bytes().to_vec(),
-					email: "DeBio Email".as_bytes().to_vec(),
-					country: CountryCode::from_vec("DC".as_bytes().to_vec()),
-					region: RegionCode::from_vec("DBIO".as_bytes().to_vec()),
-					city: CityCode::from_vec("City".as_bytes().to_vec()),
-					address: "DeBio Address".as_bytes().to_vec(),
-					phone_number: "+6281394653625".as_bytes().to_vec(),
-					website: "DeBio Website".as_bytes().to_vec(),
-					latitude: Some("DeBio Latitude".as_bytes().to_vec()),
-					longitude: Some("DeBio Longtitude".as_bytes().to_vec()),
-					profile_image: Some("DeBio Profile Image uwu".as_bytes().to_vec()),
+					name: "DeBio Lab".as_bytes().to_vec().try_into().unwrap(),
+					email: "DeBio Email".as_bytes().to_vec().try_into().unwrap(),
+					country: CountryCode::try_from("ID".as_bytes().to_vec()).unwrap(),
+					region: RegionCode::try_from("JK".as_bytes().to_vec()).unwrap(),
+					city: CityCode::try_from("City".as_bytes().to_vec()).unwrap(),
+					address: "DeBio Address".as_bytes().to_vec().try_into().unwrap(),
+					phone_number: "+6281394653625".as_bytes().to_vec().try_into().unwrap(),
+					website: "DeBio Website".as_bytes().to_vec().try_into().unwrap(),
+					latitude: Some("-6.175392".as_bytes().to_vec().try_into().unwrap()),
+					longitude: Some("106.827153".as_bytes().to_vec().try_into().unwrap()),
+					profile_image: Some(
+						"DeBio Profile Image uwu".as_bytes().to_vec().try_into().unwrap()
+					),
 				}
 			));
 
@@ -716,17 +1439,29 @@ mod tests {
 			assert_ok!(Services::create_service(
 				RuntimeOrigin::signed(1),
 				ServiceInfo {
-					name: "DeBio service name".as_bytes().to_vec(),
-					prices_by_currency: vec![PriceByCurrency::default()],
+					name: "DeBio service name".as_bytes().to_vec().try_into().unwrap(),
+					prices_by_currency: vec![PriceByCurrency::default()].try_into().unwrap(),
 					expected_duration: ExpectedDuration::default(),
-					category: "DeBio service category".as_bytes().to_vec(),
-					description: "DeBio service description".as_bytes().to_vec(),
+					category: "DeBio service category".as_bytes().to_vec().try_into().unwrap(),
+					description: "DeBio service description"
+						.as_bytes()
+						.to_vec()
+						.try_into()
+						.unwrap(),
 					dna_collection_process: "DeBio service dna_collection_process"
 						.as_bytes()
-						.to_vec(),
-					test_result_sample: "DeBio service test_result_sample".as_bytes().to_vec(),
-					long_description: Some("DeBio service long_description".as_bytes().to_vec()),
-					image: Some("DeBio service image".as_bytes().to_vec()),
+						.to_vec()
+						.try_into()
+						.unwrap(),
+					test_result_sample: "DeBio service test_result_sample"
+						.as_bytes()
+						.to_vec()
+						.try_into()
+						.unwrap(),
+					long_description: Some(
+						"DeBio service long_description".as_bytes().to_vec().try_into().unwrap()
+					),
+					image: Some("DeBio service image".as_bytes().to_vec().try_into().unwrap()),
 				},
 				ServiceFlow::default()
 			));
@@ -742,16 +1477,22 @@ mod tests {
 				None,
 			));
 
-			let _dna_sample = GeneticTesting::dna_samples_by_lab_id(1).unwrap();
+			let _dna_sample = GeneticTesting::dna_samples_by_lab_id(&1);
 
 			assert_noop!(
 				GeneticTesting::submit_test_result(
 					RuntimeOrigin::signed(2),
 					_dna_sample[0].clone(),
 					DnaTestResultSubmission {
-						comments: Some("DNA Test Result comments".as_bytes().to_vec()),
-						result_link: Some("DNA Test Result result_link".as_bytes().to_vec()),
-						report_link: Some("DNA Test Result report_link".as_bytes().to_vec())
+						comments: Some(
+							"DNA Test Result comments".as_bytes().to_vec().try_into().unwrap()
+						),
+						result_link: Some(
+							"DNA Test Result result_link".as_bytes().to_vec().try_into().unwrap()
+						),
+						report_link: Some(
+							"DNA Test Result report_link".as_bytes().to_vec().try_into().unwrap()
+						)
 					}
 				),
 				Error::<Test>::Unauthorized
@@ -765,13 +1506,19 @@ mod tests {
 			assert_ok!(GeneticTesting::submit_independent_test_result(
 				RuntimeOrigin::signed(1),
 				DnaTestResultSubmission {
-					comments: Some("DNA Test Result comments".as_bytes().to_vec()),
-					result_link: Some("DNA Test Result result_link".as_bytes().to_vec()),
-					report_link: Some("DNA Test Result report_link".as_bytes().to_vec())
+					comments: Some(
+						"DNA Test Result comments".as_bytes().to_vec().try_into().unwrap()
+					),
+					result_link: Some(
+						"DNA Test Result result_link".as_bytes().to_vec().try_into().unwrap()
+					),
+					report_link: Some(
+						"DNA Test Result report_link".as_bytes().to_vec().try_into().unwrap()
+					)
 				}
 			));
 
-			let _dna_sample = GeneticTesting::dna_test_results_by_owner_id(1).unwrap();
+			let _dna_sample = GeneticTesting::dna_test_results_by_owner_id(&1);
 
 			let _dna_test_result =
 				GeneticTesting::dna_test_result_by_tracking_id(_dna_sample[0].clone()).unwrap();
@@ -780,15 +1527,15 @@ mod tests {
 			assert_eq!(_dna_test_result.owner_id, 1);
 			assert_eq!(
 				_dna_test_result.comments,
-				Some("DNA Test Result comments".as_bytes().to_vec())
+				Some("DNA Test Result comments".as_bytes().to_vec().try_into().unwrap())
 			);
 			assert_eq!(
 				_dna_test_result.result_link,
-				Some("DNA Test Result result_link".as_bytes().to_vec())
+				Some("DNA Test Result result_link".as_bytes().to_vec().try_into().unwrap())
 			);
 			assert_eq!(
 				_dna_test_result.report_link,
-				Some("DNA Test Result report_link".as_bytes().to_vec())
+				Some("DNA Test Result report_link".as_bytes().to_vec().try_into().unwrap())
 			);
 		})
 	}
@@ -800,9 +1547,13 @@ mod tests {
 				GeneticTesting::submit_independent_test_result(
 					RuntimeOrigin::signed(1),
 					DnaTestResultSubmission {
-						comments: Some("DNA Test Result comments".as_bytes().to_vec()),
+						comments: Some(
+							"DNA Test Result comments".as_bytes().to_vec().try_into().unwrap()
+						),
 						result_link: None,
-						report_link: Some("DNA Test Result report_link".as_bytes().to_vec())
+						report_link: Some(
+							"DNA Test Result report_link".as_bytes().to_vec().try_into().unwrap()
+						)
 					}
 				),
 				Error::<Test>::ResultLinkRequired
@@ -817,8 +1568,12 @@ mod tests {
 				GeneticTesting::submit_independent_test_result(
 					RuntimeOrigin::signed(1),
 					DnaTestResultSubmission {
-						comments: Some("DNA Test Result comments".as_bytes().to_vec()),
-						result_link: Some("DNA Test Result result_link".as_bytes().to_vec()),
+						comments: Some(
+							"DNA Test Result comments".as_bytes().to_vec().try_into().unwrap()
+						),
+						result_link: Some(
+							"DNA Test Result result_link".as_bytes().to_vec().try_into().unwrap()
+						),
 						report_link: None
 					}
 				),
@@ -827,6 +1582,70 @@ mod tests {
 		})
 	}
 
+	#[test]
+	fn submit_independent_test_result_indexes_every_test_result() {
+		<ExternalityBuilder>::default().existential_deposit(1).build().execute_with(|| {
+			let submission = DnaTestResultSubmission {
+				comments: Some("DNA Test Result comments".as_bytes().to_vec().try_into().unwrap()),
+				result_link: Some(
+					"DNA Test Result result_link".as_bytes().to_vec().try_into().unwrap(),
+				),
+				report_link: Some(
+					"DNA Test Result report_link".as_bytes().to_vec().try_into().unwrap(),
+				),
+			};
+
+			for _ in 0..3 {
+				assert_ok!(GeneticTesting::submit_independent_test_result(
+					RuntimeOrigin::signed(1),
+					submission.clone()
+				));
+
+				System::inc_account_nonce(1);
+			}
+
+			assert_eq!(GeneticTesting::dna_test_results_by_owner_id(&1).len(), 3);
+		})
+	}
+
+	#[test]
+	fn migrate_truncates_rejections_over_their_bound() {
+		<ExternalityBuilder>::default().existential_deposit(1).build().execute_with(|| {
+			let tracking_id =
+				DnaSampleTrackingId::try_from("XXXXXXXXXXXXXXXXXXXX8".as_bytes().to_vec()).unwrap();
+			let order_id = Keccak256::hash(&tracking_id.encode());
+			let legacy_sample = |rejected_title: Vec<u8>| {
+				(
+					&tracking_id,
+					1u64,
+					2u64,
+					DnaSampleStatus::Rejected,
+					order_id,
+					Some(rejected_title),
+					Some("Reject DNA Description".as_bytes().to_vec()),
+					0u64,
+					0u64,
+				)
+					.encode()
+			};
+
+			// A sample rejected before rejection reasons were bounded
+			unhashed::put_raw(
+				&DnaSamples::<Test>::hashed_key_for(&tracking_id),
+				&legacy_sample(vec![b'X'; MAX_REJECTION_TITLE_LENGTH as usize + 1]),
+			);
+			StorageVersion::new(0).put::<GeneticTesting>();
+
+			genetic_testing::migrations::migrate::<Test>();
+
+			assert_eq!(
+				GeneticTesting::dna_sample_by_tracking_id(&tracking_id).unwrap().encode(),
+				legacy_sample(vec![b'X'; MAX_REJECTION_TITLE_LENGTH as usize])
+			);
+			assert_eq!(StorageVersion::get::<GeneticTesting>(), StorageVersion::new(1));
+		})
+	}
+
 	#[test]
 	fn submit_data_bounty_details_works() {
 		<ExternalityBuilder>::default().existential_deposit(1).build().execute_with(|| {
@@ -3,21 +3,46 @@
 use sp_std::prelude::*;
 
 use frame_support::{
-	codec::{Decode, Encode},
+	codec::{Decode, Encode, MaxEncodedLen},
 	scale_info::TypeInfo,
 	sp_runtime::RuntimeDebug,
-	sp_std::convert::TryInto,
+	sp_std::convert::TryFrom,
 };
+use primitives_tracking_id::{
+	has_valid_check_character, normalize_tracking_id, TrackingId, TrackingIdError,
+	TRACKING_ID_LENGTH,
+};
+#[cfg(feature = "std")]
+use serde::{Deserialize, Serialize};
 
-#[derive(Encode, Decode, Clone, Default, RuntimeDebug, PartialEq, Eq, TypeInfo)]
-pub struct DnaSampleTrackingId([u8; 21]);
+#[derive(Encode, Decode, Clone, Default, RuntimeDebug, PartialEq, Eq, TypeInfo, MaxEncodedLen)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub struct DnaSampleTrackingId([u8; TRACKING_ID_LENGTH]);
 impl DnaSampleTrackingId {
-	pub fn from_vec(_vec_id: Vec<u8>) -> Self {
-		let _array = _vec_id.try_into().unwrap_or_else(|_vec_id: Vec<u8>| {
-			panic!("Expected a Vec of length {} but it was {}", 21, _vec_id.len())
-		});
+	/// Whether the last character is the Luhn mod 36 check character of the others.
+	/// Tracking ids generated before the check character was introduced usually fail this.
+	pub fn has_valid_check_character(&self) -> bool {
+		has_valid_check_character(&self.0)
+	}
+}
+
+impl TryFrom<Vec<u8>> for DnaSampleTrackingId {
+	type Error = TrackingIdError;
+
+	fn try_from(vec_id: Vec<u8>) -> Result<Self, Self::Error> {
+		normalize_tracking_id(vec_id).map(Self)
+	}
+}
+
+impl From<DnaSampleTrackingId> for Vec<u8> {
+	fn from(tracking_id: DnaSampleTrackingId) -> Self {
+		tracking_id.0.to_vec()
+	}
+}
 
-		Self(_array)
+impl From<TrackingId> for DnaSampleTrackingId {
+	fn from(tracking_id: TrackingId) -> Self {
+		Self(tracking_id.into())
 	}
 }
 
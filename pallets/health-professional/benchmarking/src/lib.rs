@@ -7,12 +7,6 @@ use health_professional::{
 	MinimumStakeAmount, Pallet as HealthProfessional, UnstakeTime,
 };
 
-#[allow(unused)]
-use health_professional_qualification::{
-	Certification, Config as HealthProfessionalQualificationConfig, Experience,
-	Pallet as HealthProfessionalQualification,
-};
-
 use frame_benchmarking::{benchmarks, whitelisted_caller};
 use frame_support::{
 	sp_runtime::{traits::Hash, SaturatedConversion},
@@ -24,10 +18,7 @@ use pallet_timestamp::{Config as TimestampConfig, Now};
 
 pub struct Pallet<T: Config>(HealthProfessional<T>);
 
-pub trait Config:
-	HealthProfessionalConfig + HealthProfessionalQualificationConfig + TimestampConfig
-{
-}
+pub trait Config: HealthProfessionalConfig + TimestampConfig {}
 
 pub type AccountIdOf<T> = <T as frame_system::Config>::AccountId;
 pub type CurrencyOf<T> = <T as health_professional::Config>::Currency;
@@ -42,17 +33,17 @@ benchmarks! {
 		let caller: T::AccountId = whitelisted_caller();
 		let health_professional_info = HealthProfessionalInfo {
 			box_public_key: T::Hashing::hash("0xDb9Af2d1f3ADD2726A132AA7A65Cc9E6fC5761C3".as_bytes()),
-			first_name: b"First Name".to_vec(),
-			last_name: b"Last Name".to_vec(),
-			myriad_username: b"debiouser".to_vec(),
-			gender: b"Gender".to_vec(),
+			first_name: b"First Name".to_vec().try_into().unwrap(),
+			last_name: b"Last Name".to_vec().try_into().unwrap(),
+			myriad_username: b"debiouser".to_vec().try_into().unwrap(),
+			gender: b"Gender".to_vec().try_into().unwrap(),
 			date_of_birth: <T as pallet_timestamp::pallet::Config>::Moment::default(),
-			email: b"Email".to_vec(),
-			phone_number: b"+6893026516".to_vec(),
-			role: b"doctor".to_vec(),
-			category: b"Mental Health".to_vec(),
-			profile_link: Some(b"DeBio Genetic Analyst profile_link".to_vec()),
-			profile_image: Some(b"DeBio Genetic Analyst profile_image".to_vec()),
+			email: b"Email".to_vec().try_into().unwrap(),
+			phone_number: b"+6893026516".to_vec().try_into().unwrap(),
+			role: b"doctor".to_vec().try_into().unwrap(),
+			category: b"Mental Health".to_vec().try_into().unwrap(),
+			profile_link: Some(b"DeBio Genetic Analyst profile_link".to_vec().try_into().unwrap()),
+			profile_image: Some(b"DeBio Genetic Analyst profile_image".to_vec().try_into().unwrap()),
 			anonymous: false,
 		};
 	}: register(RawOrigin::Signed(caller), health_professional_info)
@@ -63,17 +54,17 @@ benchmarks! {
 
 		let health_professional_info = HealthProfessionalInfo {
 			box_public_key: T::Hashing::hash("0xDb9Af2d1f3ADD2726A132AA7A65Cc9E6fC5761C3".as_bytes()),
-			first_name: b"First Name".to_vec(),
-			last_name: b"Last Name".to_vec(),
-			myriad_username: b"debiouser".to_vec(),
-			gender: b"Gender".to_vec(),
+			first_name: b"First Name".to_vec().try_into().unwrap(),
+			last_name: b"Last Name".to_vec().try_into().unwrap(),
+			myriad_username: b"debiouser".to_vec().try_into().unwrap(),
+			gender: b"Gender".to_vec().try_into().unwrap(),
 			date_of_birth: <T as pallet_timestamp::pallet::Config>::Moment::default(),
-			email: b"Email".to_vec(),
-			phone_number: b"+6893026516".to_vec(),
-			role: b"doctor".to_vec(),
-			category: b"Mental Health".to_vec(),
-			profile_link: Some(b"DeBio Genetic Analyst profile_link".to_vec()),
-			profile_image: Some(b"DeBio Genetic Analyst profile_image".to_vec()),
+			email: b"Email".to_vec().try_into().unwrap(),
+			phone_number: b"+6893026516".to_vec().try_into().unwrap(),
+			role: b"doctor".to_vec().try_into().unwrap(),
+			category: b"Mental Health".to_vec().try_into().unwrap(),
+			profile_link: Some(b"DeBio Genetic Analyst profile_link".to_vec().try_into().unwrap()),
+			profile_image: Some(b"DeBio Genetic Analyst profile_image".to_vec().try_into().unwrap()),
 			anonymous: false,
 		};
 
@@ -81,17 +72,17 @@ benchmarks! {
 
 		let updated_info = HealthProfessionalInfo {
 			box_public_key: T::Hashing::hash("0xDb9Af2d1f3ADD2726A132AA7A65Cc9E6fC5761C3".as_bytes()),
-			first_name: b"First Name".to_vec(),
-			last_name: b"Last Name".to_vec(),
-			myriad_username: b"myriaduser".to_vec(),
-			gender: b"Gender".to_vec(),
+			first_name: b"First Name".to_vec().try_into().unwrap(),
+			last_name: b"Last Name".to_vec().try_into().unwrap(),
+			myriad_username: b"myriaduser".to_vec().try_into().unwrap(),
+			gender: b"Gender".to_vec().try_into().unwrap(),
 			date_of_birth: <T as pallet_timestamp::pallet::Config>::Moment::default(),
-			email: b"Email".to_vec(),
-			phone_number: b"+6893026516".to_vec(),
-			role: b"doctor".to_vec(),
-			category: b"Mental Health".to_vec(),
-			profile_link: Some(b"DeBio Genetic Analyst profile_link".to_vec()),
-			profile_image: Some(b"DeBio Genetic Analyst profile_image".to_vec()),
+			email: b"Email".to_vec().try_into().unwrap(),
+			phone_number: b"+6893026516".to_vec().try_into().unwrap(),
+			role: b"doctor".to_vec().try_into().unwrap(),
+			category: b"Mental Health".to_vec().try_into().unwrap(),
+			profile_link: Some(b"DeBio Genetic Analyst profile_link".to_vec().try_into().unwrap()),
+			profile_image: Some(b"DeBio Genetic Analyst profile_image".to_vec().try_into().unwrap()),
 			anonymous: false,
 		};
 	}: update_info(RawOrigin::Signed(caller), updated_info)
@@ -102,17 +93,17 @@ benchmarks! {
 
 		let health_professional_info = HealthProfessionalInfo {
 			box_public_key: T::Hashing::hash("0xDb9Af2d1f3ADD2726A132AA7A65Cc9E6fC5761C3".as_bytes()),
-			first_name: b"First Name".to_vec(),
-			last_name: b"Last Name".to_vec(),
-			myriad_username: b"debiouser".to_vec(),
-			gender: b"Gender".to_vec(),
+			first_name: b"First Name".to_vec().try_into().unwrap(),
+			last_name: b"Last Name".to_vec().try_into().unwrap(),
+			myriad_username: b"debiouser".to_vec().try_into().unwrap(),
+			gender: b"Gender".to_vec().try_into().unwrap(),
 			date_of_birth: <T as pallet_timestamp::pallet::Config>::Moment::default(),
-			email: b"Email".to_vec(),
-			phone_number: b"+6893026516".to_vec(),
-			role: b"doctor".to_vec(),
-			category: b"Mental Health".to_vec(),
-			profile_link: Some(b"DeBio Genetic Analyst profile_link".to_vec()),
-			profile_image: Some(b"DeBio Genetic Analyst profile_image".to_vec()),
+			email: b"Email".to_vec().try_into().unwrap(),
+			phone_number: b"+6893026516".to_vec().try_into().unwrap(),
+			role: b"doctor".to_vec().try_into().unwrap(),
+			category: b"Mental Health".to_vec().try_into().unwrap(),
+			profile_link: Some(b"DeBio Genetic Analyst profile_link".to_vec().try_into().unwrap()),
+			profile_image: Some(b"DeBio Genetic Analyst profile_image".to_vec().try_into().unwrap()),
 			anonymous: false,
 		};
 
@@ -126,17 +117,17 @@ benchmarks! {
 
 		let health_professional_info = HealthProfessionalInfo {
 			box_public_key: T::Hashing::hash("0xDb9Af2d1f3ADD2726A132AA7A65Cc9E6fC5761C3".as_bytes()),
-			first_name: b"First Name".to_vec(),
-			last_name: b"Last Name".to_vec(),
-			myriad_username: b"debiouser".to_vec(),
-			gender: b"Gender".to_vec(),
+			first_name: b"First Name".to_vec().try_into().unwrap(),
+			last_name: b"Last Name".to_vec().try_into().unwrap(),
+			myriad_username: b"debiouser".to_vec().try_into().unwrap(),
+			gender: b"Gender".to_vec().try_into().unwrap(),
 			date_of_birth: <T as pallet_timestamp::pallet::Config>::Moment::default(),
-			email: b"Email".to_vec(),
-			phone_number: b"+6893026516".to_vec(),
-			role: b"doctor".to_vec(),
-			category: b"Mental Health".to_vec(),
-			profile_link: Some(b"DeBio Genetic Analyst profile_link".to_vec()),
-			profile_image: Some(b"DeBio Genetic Analyst profile_image".to_vec()),
+			email: b"Email".to_vec().try_into().unwrap(),
+			phone_number: b"+6893026516".to_vec().try_into().unwrap(),
+			role: b"doctor".to_vec().try_into().unwrap(),
+			category: b"Mental Health".to_vec().try_into().unwrap(),
+			profile_link: Some(b"DeBio Genetic Analyst profile_link".to_vec().try_into().unwrap()),
+			profile_image: Some(b"DeBio Genetic Analyst profile_image".to_vec().try_into().unwrap()),
 			anonymous: false,
 		};
 
@@ -149,17 +140,17 @@ benchmarks! {
 
 		let health_professional_info = HealthProfessionalInfo {
 			box_public_key: T::Hashing::hash("0xDb9Af2d1f3ADD2726A132AA7A65Cc9E6fC5761C3".as_bytes()),
-			first_name: b"First Name".to_vec(),
-			last_name: b"Last Name".to_vec(),
-			myriad_username: b"debiouser".to_vec(),
-			gender: b"Gender".to_vec(),
+			first_name: b"First Name".to_vec().try_into().unwrap(),
+			last_name: b"Last Name".to_vec().try_into().unwrap(),
+			myriad_username: b"debiouser".to_vec().try_into().unwrap(),
+			gender: b"Gender".to_vec().try_into().unwrap(),
 			date_of_birth: <T as pallet_timestamp::pallet::Config>::Moment::default(),
-			email: b"Email".to_vec(),
-			phone_number: b"+6893026516".to_vec(),
-			role: b"doctor".to_vec(),
-			category: b"Mental Health".to_vec(),
-			profile_link: Some(b"DeBio Genetic Analyst profile_link".to_vec()),
-			profile_image: Some(b"DeBio Genetic Analyst profile_image".to_vec()),
+			email: b"Email".to_vec().try_into().unwrap(),
+			phone_number: b"+6893026516".to_vec().try_into().unwrap(),
+			role: b"doctor".to_vec().try_into().unwrap(),
+			category: b"Mental Health".to_vec().try_into().unwrap(),
+			profile_link: Some(b"DeBio Genetic Analyst profile_link".to_vec().try_into().unwrap()),
+			profile_image: Some(b"DeBio Genetic Analyst profile_image".to_vec().try_into().unwrap()),
 			anonymous: false,
 		};
 
@@ -178,17 +169,17 @@ benchmarks! {
 
 		let health_professional_info = HealthProfessionalInfo {
 			box_public_key: T::Hashing::hash("0xDb9Af2d1f3ADD2726A132AA7A65Cc9E6fC5761C3".as_bytes()),
-			first_name: b"First Name".to_vec(),
-			last_name: b"Last Name".to_vec(),
-			myriad_username: b"debiouser".to_vec(),
-			gender: b"Gender".to_vec(),
+			first_name: b"First Name".to_vec().try_into().unwrap(),
+			last_name: b"Last Name".to_vec().try_into().unwrap(),
+			myriad_username: b"debiouser".to_vec().try_into().unwrap(),
+			gender: b"Gender".to_vec().try_into().unwrap(),
 			date_of_birth: <T as pallet_timestamp::pallet::Config>::Moment::default(),
-			email: b"Email".to_vec(),
-			phone_number: b"+6893026516".to_vec(),
-			role: b"doctor".to_vec(),
-			category: b"Mental Health".to_vec(),
-			profile_link: Some(b"DeBio Genetic Analyst profile_link".to_vec()),
-			profile_image: Some(b"DeBio Genetic Analyst profile_image".to_vec()),
+			email: b"Email".to_vec().try_into().unwrap(),
+			phone_number: b"+6893026516".to_vec().try_into().unwrap(),
+			role: b"doctor".to_vec().try_into().unwrap(),
+			category: b"Mental Health".to_vec().try_into().unwrap(),
+			profile_link: Some(b"DeBio Genetic Analyst profile_link".to_vec().try_into().unwrap()),
+			profile_image: Some(b"DeBio Genetic Analyst profile_image".to_vec().try_into().unwrap()),
 			anonymous: false,
 		};
 
@@ -208,17 +199,17 @@ benchmarks! {
 
 		let health_professional_info = HealthProfessionalInfo {
 			box_public_key: T::Hashing::hash("0xDb9Af2d1f3ADD2726A132AA7A65Cc9E6fC5761C3".as_bytes()),
-			first_name: b"First Name".to_vec(),
-			last_name: b"Last Name".to_vec(),
-			myriad_username: b"debiouser".to_vec(),
-			gender: b"Gender".to_vec(),
+			first_name: b"First Name".to_vec().try_into().unwrap(),
+			last_name: b"Last Name".to_vec().try_into().unwrap(),
+			myriad_username: b"debiouser".to_vec().try_into().unwrap(),
+			gender: b"Gender".to_vec().try_into().unwrap(),
 			date_of_birth: <T as pallet_timestamp::pallet::Config>::Moment::default(),
-			email: b"Email".to_vec(),
-			phone_number: b"+6893026516".to_vec(),
-			role: b"doctor".to_vec(),
-			category: b"Mental Health".to_vec(),
-			profile_link: Some(b"DeBio Genetic Analyst profile_link".to_vec()),
-			profile_image: Some(b"DeBio Genetic Analyst profile_image".to_vec()),
+			email: b"Email".to_vec().try_into().unwrap(),
+			phone_number: b"+6893026516".to_vec().try_into().unwrap(),
+			role: b"doctor".to_vec().try_into().unwrap(),
+			category: b"Mental Health".to_vec().try_into().unwrap(),
+			profile_link: Some(b"DeBio Genetic Analyst profile_link".to_vec().try_into().unwrap()),
+			profile_image: Some(b"DeBio Genetic Analyst profile_image".to_vec().try_into().unwrap()),
 			anonymous: false,
 		};
 
@@ -240,17 +231,17 @@ benchmarks! {
 
 		let health_professional_info = HealthProfessionalInfo {
 			box_public_key: T::Hashing::hash("0xDb9Af2d1f3ADD2726A132AA7A65Cc9E6fC5761C3".as_bytes()),
-			first_name: b"First Name".to_vec(),
-			last_name: b"Last Name".to_vec(),
-			myriad_username: b"debiouser".to_vec(),
-			gender: b"Gender".to_vec(),
+			first_name: b"First Name".to_vec().try_into().unwrap(),
+			last_name: b"Last Name".to_vec().try_into().unwrap(),
+			myriad_username: b"debiouser".to_vec().try_into().unwrap(),
+			gender: b"Gender".to_vec().try_into().unwrap(),
 			date_of_birth: <T as pallet_timestamp::pallet::Config>::Moment::default(),
-			email: b"Email".to_vec(),
-			phone_number: b"+6893026516".to_vec(),
-			role: b"doctor".to_vec(),
-			category: b"Mental Health".to_vec(),
-			profile_link: Some(b"DeBio Genetic Analyst profile_link".to_vec()),
-			profile_image: Some(b"DeBio Genetic Analyst profile_image".to_vec()),
+			email: b"Email".to_vec().try_into().unwrap(),
+			phone_number: b"+6893026516".to_vec().try_into().unwrap(),
+			role: b"doctor".to_vec().try_into().unwrap(),
+			category: b"Mental Health".to_vec().try_into().unwrap(),
+			profile_link: Some(b"DeBio Genetic Analyst profile_link".to_vec().try_into().unwrap()),
+			profile_image: Some(b"DeBio Genetic Analyst profile_image".to_vec().try_into().unwrap()),
 			anonymous: false,
 		};
 
@@ -4,8 +4,10 @@ use frame_support::{
 	traits::{Currency, ExistenceRequirement},
 	PalletId,
 };
-use traits_health_professional::HealthProfessionalCountT;
-use traits_health_professional_qualifications::HealthProfessionalQualificationOwner;
+use primitives_stake_status::StakeStatusTrait;
+use primitives_verification_status::VerificationStatusTrait;
+use traits_credentials::legacy::HealthProfessionalQualificationOwner;
+use traits_health_professional::{HealthProfessionalCountT, HealthProfessionalProvider};
 
 pub const PALLET_ID: PalletId = PalletId(*b"hlthpro!");
 
@@ -66,6 +68,17 @@ impl<T: Config> HealthProfessionalCountT<T> for Pallet<T> {
 	}
 }
 
+impl<T: Config> HealthProfessionalProvider<T> for Pallet<T> {
+	fn is_verified_and_staked(account_id: &T::AccountId) -> bool {
+		HealthProfessionals::<T>::get(account_id)
+			.map(|health_professional| {
+				health_professional.verification_status().is_verified() &&
+					health_professional.stake_status().is_staked()
+			})
+			.unwrap_or(false)
+	}
+}
+
 impl<T: Config> HealthProfessionalQualificationOwner<T> for Pallet<T> {
 	type Owner = HealthProfessionalOf<T>;
 
@@ -74,14 +87,21 @@ impl<T: Config> HealthProfessionalQualificationOwner<T> for Pallet<T> {
 	}
 
 	fn can_create_qualification(account_id: &T::AccountId) -> bool {
-		HealthProfessionals::<T>::contains_key(account_id)
+		match HealthProfessionals::<T>::get(account_id) {
+			Some(health_professional) =>
+				health_professional.qualifications().len() <
+					MAX_QUALIFICATIONS_PER_HEALTH_PROFESSIONAL as usize,
+			None => false,
+		}
 	}
 
 	fn associate(owner_id: &T::AccountId, qualification_id: &T::Hash) {
 		HealthProfessionals::<T>::mutate(owner_id, |result| match result {
 			None => (),
 			Some(health_professional) => {
-				health_professional.add_qualification(*qualification_id);
+				// can_create_qualification rejects health professionals without room for another
+				// qualification
+				let _ = health_professional.add_qualification(*qualification_id);
 			},
 		});
 	}
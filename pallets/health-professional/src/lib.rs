@@ -13,11 +13,11 @@ pub use types::*;
 
 use frame_support::traits::StorageVersion;
 use interface::HealthProfessionalInterface;
-use traits_health_professional_qualifications::HealthProfessionalQualificationProvider;
+use traits_credentials::legacy::HealthProfessionalQualificationProvider;
 use weights::WeightInfo;
 
 /// The current storage version.
-const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
+const STORAGE_VERSION: StorageVersion = StorageVersion::new(2);
 
 #[frame_support::pallet]
 pub mod pallet {
@@ -41,7 +41,6 @@ pub mod pallet {
 	#[pallet::pallet]
 	#[pallet::storage_version(STORAGE_VERSION)]
 	#[pallet::generate_store(pub(super) trait Store)]
-	#[pallet::without_storage_info]
 	pub struct Pallet<T>(_);
 
 	#[pallet::hooks]
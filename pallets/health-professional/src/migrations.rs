@@ -1,8 +1,10 @@
 use crate::*;
 use frame_support::{
+	log,
 	pallet_prelude::{Decode, Encode},
 	traits::Get,
 	weights::Weight,
+	BoundedVec,
 };
 use sp_std::vec::Vec;
 
@@ -19,6 +21,11 @@ pub fn migrate<T: Config>() -> Weight {
 		version = StorageVersion::new(1);
 	}
 
+	if version == 1 {
+		weight = weight.saturating_add(version::v2::migrate::<T>());
+		version = StorageVersion::new(2);
+	}
+
 	version.put::<Pallet<T>>();
 	weight
 }
@@ -26,6 +33,67 @@ pub fn migrate<T: Config>() -> Weight {
 mod version {
 	use super::*;
 
+	/// Health professional info fields before they were bounded
+	#[derive(Encode, Decode)]
+	pub struct UnboundedHealthProfessionalInfo<Hash, Moment> {
+		pub box_public_key: Hash,
+		pub first_name: Vec<u8>,
+		pub last_name: Vec<u8>,
+		pub myriad_username: Vec<u8>,
+		pub gender: Vec<u8>,
+		pub date_of_birth: Moment,
+		pub email: Vec<u8>,
+		pub phone_number: Vec<u8>,
+		pub role: Vec<u8>,
+		pub category: Vec<u8>,
+		pub profile_link: Option<Vec<u8>>,
+		pub profile_image: Option<Vec<u8>>,
+		pub anonymous: bool,
+	}
+
+	/// Truncates `value` to the bound and logs the entry when it did not fit.
+	pub fn bounded<I, S: Get<u32>>(value: Vec<I>, entry: &str) -> BoundedVec<I, S> {
+		if value.len() > S::get() as usize {
+			log::warn!(
+				target: "runtime::health-professional",
+				"{} exceeds its bound of {}, truncated from {} items",
+				entry,
+				S::get(),
+				value.len(),
+			);
+		}
+
+		BoundedVec::truncate_from(value)
+	}
+
+	pub fn bounded_health_professional_info<Hash, Moment>(
+		info: UnboundedHealthProfessionalInfo<Hash, Moment>,
+	) -> HealthProfessionalInfo<Hash, Moment>
+	where
+		Hash: PartialEq + Eq + Clone,
+		Moment: Clone,
+	{
+		HealthProfessionalInfo {
+			box_public_key: info.box_public_key,
+			first_name: bounded(info.first_name, "health professional first name"),
+			last_name: bounded(info.last_name, "health professional last name"),
+			myriad_username: bounded(info.myriad_username, "health professional myriad username"),
+			gender: bounded(info.gender, "health professional gender"),
+			date_of_birth: info.date_of_birth,
+			email: bounded(info.email, "health professional email"),
+			phone_number: bounded(info.phone_number, "health professional phone number"),
+			role: bounded(info.role, "health professional role"),
+			category: bounded(info.category, "health professional category"),
+			profile_link: info
+				.profile_link
+				.map(|profile_link| bounded(profile_link, "health professional profile link")),
+			profile_image: info
+				.profile_image
+				.map(|profile_image| bounded(profile_image, "health professional profile image")),
+			anonymous: info.anonymous,
+		}
+	}
+
 	pub mod v1 {
 		use super::*;
 
@@ -68,21 +136,22 @@ mod version {
 				weight = weight.saturating_add(T::DbWeight::get().reads_writes(1, 1));
 
 				let old_health_professional_info = old.info;
-				let new_health_professional_info = HealthProfessionalInfo {
-					box_public_key: old_health_professional_info.box_public_key,
-					first_name: old_health_professional_info.first_name,
-					last_name: old_health_professional_info.last_name,
-					myriad_username: old_health_professional_info.myriad_username,
-					gender: old_health_professional_info.gender,
-					date_of_birth: old_health_professional_info.date_of_birth,
-					email: old_health_professional_info.email,
-					phone_number: old_health_professional_info.phone_number,
-					role: old_health_professional_info.role,
-					category: old_health_professional_info.category,
-					profile_link: Some(old_health_professional_info.profile_link),
-					profile_image: old_health_professional_info.profile_image,
-					anonymous: old_health_professional_info.anonymous,
-				};
+				let new_health_professional_info =
+					bounded_health_professional_info(UnboundedHealthProfessionalInfo {
+						box_public_key: old_health_professional_info.box_public_key,
+						first_name: old_health_professional_info.first_name,
+						last_name: old_health_professional_info.last_name,
+						myriad_username: old_health_professional_info.myriad_username,
+						gender: old_health_professional_info.gender,
+						date_of_birth: old_health_professional_info.date_of_birth,
+						email: old_health_professional_info.email,
+						phone_number: old_health_professional_info.phone_number,
+						role: old_health_professional_info.role,
+						category: old_health_professional_info.category,
+						profile_link: Some(old_health_professional_info.profile_link),
+						profile_image: old_health_professional_info.profile_image,
+						anonymous: old_health_professional_info.anonymous,
+					});
 
 				let mut new_health_professional =
 					HealthProfessional::new(&old.account_id, &new_health_professional_info);
@@ -92,7 +161,10 @@ mod version {
 				new_health_professional.update_unstaked_at(old.unstaked_at);
 				new_health_professional.update_stake_amount(old.stake_amount);
 				new_health_professional.update_stake_status(old.stake_status, old.stake_amount);
-				new_health_professional.update_qualifications(old.qualifications);
+				new_health_professional.update_qualifications(bounded(
+					old.qualifications,
+					"health professional qualifications",
+				));
 
 				Some(new_health_professional)
 			});
@@ -100,4 +172,50 @@ mod version {
 			weight
 		}
 	}
+
+	pub mod v2 {
+		use super::*;
+
+		#[derive(Encode, Decode)]
+		pub struct OldHealthProfessional<AccountId, Hash, Moment, Balance> {
+			pub account_id: AccountId,
+			pub qualifications: Vec<Hash>,
+			pub info: UnboundedHealthProfessionalInfo<Hash, Moment>,
+			pub stake_amount: Balance,
+			pub stake_status: StakeStatus,
+			pub verification_status: VerificationStatus,
+			pub availability_status: AvailabilityStatus,
+			pub unstaked_at: Option<Moment>,
+		}
+
+		pub type OldHealthProfessionalOf<T> =
+			OldHealthProfessional<AccountIdOf<T>, HashOf<T>, MomentOf<T>, BalanceOf<T>>;
+
+		/// Health professional info and qualification lists become bounded. Legacy values that
+		/// exceed a bound are truncated and logged.
+		pub fn migrate<T: Config>() -> Weight {
+			let mut weight = T::DbWeight::get().writes(1);
+
+			HealthProfessionals::<T>::translate(|_key, old: OldHealthProfessionalOf<T>| {
+				weight = weight.saturating_add(T::DbWeight::get().reads_writes(1, 1));
+
+				let info = bounded_health_professional_info(old.info);
+				let mut health_professional = HealthProfessional::new(&old.account_id, &info);
+
+				health_professional.update_verification_status(&old.verification_status);
+				health_professional.update_availability_status(&old.availability_status);
+				health_professional.update_stake_amount(old.stake_amount);
+				health_professional.update_stake_status(old.stake_status, old.stake_amount);
+				health_professional.update_unstaked_at(old.unstaked_at);
+				health_professional.update_qualifications(bounded(
+					old.qualifications,
+					"health professional qualifications",
+				));
+
+				Some(health_professional)
+			});
+
+			weight
+		}
+	}
 }
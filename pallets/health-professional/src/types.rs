@@ -1,44 +1,52 @@
 use frame_support::{
-	pallet_prelude::{Decode, Encode},
+	pallet_prelude::{ConstU32, Decode, Encode, MaxEncodedLen},
 	sp_runtime::traits::Saturating,
-	RuntimeDebug,
+	BoundedVec, RuntimeDebug,
 };
 use scale_info::TypeInfo;
-use sp_std::vec::Vec;
 
 use primitives_availability_status::AvailabilityStatus;
 use primitives_stake_status::StakeStatus;
 use primitives_verification_status::VerificationStatus;
 
-#[derive(Encode, Decode, Clone, Default, RuntimeDebug, PartialEq, Eq, TypeInfo)]
+/// Longest text field of a health professional, e.g. its name, email or profile link
+pub const MAX_HEALTH_PROFESSIONAL_INFO_LENGTH: u32 = 512;
+/// Most qualifications a health professional can own
+pub const MAX_QUALIFICATIONS_PER_HEALTH_PROFESSIONAL: u32 = 256;
+
+pub type HealthProfessionalText = BoundedVec<u8, ConstU32<MAX_HEALTH_PROFESSIONAL_INFO_LENGTH>>;
+pub type HealthProfessionalQualificationIds<Hash> =
+	BoundedVec<Hash, ConstU32<MAX_QUALIFICATIONS_PER_HEALTH_PROFESSIONAL>>;
+
+#[derive(Encode, Decode, Clone, Default, RuntimeDebug, PartialEq, Eq, TypeInfo, MaxEncodedLen)]
 pub struct HealthProfessionalInfo<Hash, Moment>
 where
 	Hash: PartialEq + Eq + Clone,
 	Moment: Clone,
 {
 	pub box_public_key: Hash,
-	pub first_name: Vec<u8>,
-	pub last_name: Vec<u8>,
-	pub myriad_username: Vec<u8>,
-	pub gender: Vec<u8>,
+	pub first_name: HealthProfessionalText,
+	pub last_name: HealthProfessionalText,
+	pub myriad_username: HealthProfessionalText,
+	pub gender: HealthProfessionalText,
 	pub date_of_birth: Moment,
-	pub email: Vec<u8>,
-	pub phone_number: Vec<u8>,
-	pub role: Vec<u8>,
-	pub category: Vec<u8>,
-	pub profile_link: Option<Vec<u8>>,
-	pub profile_image: Option<Vec<u8>>,
+	pub email: HealthProfessionalText,
+	pub phone_number: HealthProfessionalText,
+	pub role: HealthProfessionalText,
+	pub category: HealthProfessionalText,
+	pub profile_link: Option<HealthProfessionalText>,
+	pub profile_image: Option<HealthProfessionalText>,
 	pub anonymous: bool,
 }
 
-#[derive(Encode, Decode, Clone, Default, RuntimeDebug, PartialEq, Eq, TypeInfo)]
+#[derive(Encode, Decode, Clone, Default, RuntimeDebug, PartialEq, Eq, TypeInfo, MaxEncodedLen)]
 pub struct HealthProfessional<AccountId, Hash, Moment, Balance>
 where
 	Hash: PartialEq + Eq + Clone,
 	Moment: Clone,
 {
 	account_id: AccountId,
-	qualifications: Vec<Hash>,
+	qualifications: HealthProfessionalQualificationIds<Hash>,
 	info: HealthProfessionalInfo<Hash, Moment>,
 	stake_amount: Balance,
 	stake_status: StakeStatus,
@@ -56,7 +64,7 @@ where
 	pub fn new(account_id: &AccountId, info: &HealthProfessionalInfo<Hash, Moment>) -> Self {
 		Self {
 			account_id: account_id.clone(),
-			qualifications: Vec::<Hash>::new(),
+			qualifications: BoundedVec::default(),
 			info: info.clone(),
 			stake_amount: Balance::default(),
 			stake_status: StakeStatus::default(),
@@ -78,6 +86,10 @@ where
 		&self.stake_status
 	}
 
+	pub fn verification_status(&self) -> &VerificationStatus {
+		&self.verification_status
+	}
+
 	pub fn stake_amount(&self) -> &Balance {
 		&self.stake_amount
 	}
@@ -116,12 +128,17 @@ where
 		self.availability_status = status.clone();
 	}
 
-	pub fn update_qualifications(&mut self, qualifications: Vec<Hash>) {
+	pub fn update_qualifications(
+		&mut self,
+		qualifications: HealthProfessionalQualificationIds<Hash>,
+	) {
 		self.qualifications = qualifications;
 	}
 
-	pub fn add_qualification(&mut self, qualification_id: Hash) {
-		self.qualifications.push(qualification_id);
+	/// Returns the qualification id back when the health professional already owns
+	/// MAX_QUALIFICATIONS_PER_HEALTH_PROFESSIONAL qualifications
+	pub fn add_qualification(&mut self, qualification_id: Hash) -> Result<(), Hash> {
+		self.qualifications.try_push(qualification_id)
 	}
 
 	pub fn remove_qualification(&mut self, qualification_id: Hash) {
@@ -7,12 +7,16 @@ mod tests {
 
 	use frame_support::{
 		assert_noop, assert_ok,
+		codec::Encode,
 		sp_runtime::traits::{Hash, Keccak256},
+		storage::migration::put_storage_value,
+		traits::StorageVersion,
+		Blake2_128Concat, BoundedVec, StorageHasher,
 	};
 	use health_professional::{
-		types::HealthProfessionalInfo, Error, Event as HealthProfessionalEvent,
+		migrations, types::HealthProfessionalInfo, Error, Event as HealthProfessionalEvent,
 		HealthProfessional as HealthProfessionalStruct, HealthProfessionalVerifierKey,
-		MinimumStakeAmount, UnstakeTime,
+		MinimumStakeAmount, UnstakeTime, MAX_HEALTH_PROFESSIONAL_INFO_LENGTH,
 	};
 	use pallet_timestamp::Now;
 	use primitives_availability_status::AvailabilityStatus;
@@ -27,17 +31,21 @@ mod tests {
 				box_public_key: Keccak256::hash(
 					"0xDb9Af2d1f3ADD2726A132AA7A65Cc9E6fC5761C3".as_bytes(),
 				),
-				first_name: b"First Name".to_vec(),
-				last_name: b"Last Name".to_vec(),
-				myriad_username: b"debiouser".to_vec(),
-				gender: b"Gender".to_vec(),
+				first_name: b"First Name".to_vec().try_into().unwrap(),
+				last_name: b"Last Name".to_vec().try_into().unwrap(),
+				myriad_username: b"debiouser".to_vec().try_into().unwrap(),
+				gender: b"Gender".to_vec().try_into().unwrap(),
 				date_of_birth: 0,
-				email: b"Email".to_vec(),
-				phone_number: b"+6893026516".to_vec(),
-				role: b"doctor".to_vec(),
-				category: b"Mental Health".to_vec(),
-				profile_link: Some(b"DeBio Genetic Analyst profile_link".to_vec()),
-				profile_image: Some(b"DeBio Genetic Analyst profile_image".to_vec()),
+				email: b"Email".to_vec().try_into().unwrap(),
+				phone_number: b"+6893026516".to_vec().try_into().unwrap(),
+				role: b"doctor".to_vec().try_into().unwrap(),
+				category: b"Mental Health".to_vec().try_into().unwrap(),
+				profile_link: Some(
+					b"DeBio Genetic Analyst profile_link".to_vec().try_into().unwrap(),
+				),
+				profile_image: Some(
+					b"DeBio Genetic Analyst profile_image".to_vec().try_into().unwrap(),
+				),
 				anonymous: false,
 			};
 
@@ -64,17 +72,21 @@ mod tests {
 				box_public_key: Keccak256::hash(
 					"0xDb9Af2d1f3ADD2726A132AA7A65Cc9E6fC5761C3".as_bytes(),
 				),
-				first_name: b"First Name".to_vec(),
-				last_name: b"Last Name".to_vec(),
-				myriad_username: b"debiouser".to_vec(),
-				gender: b"Gender".to_vec(),
+				first_name: b"First Name".to_vec().try_into().unwrap(),
+				last_name: b"Last Name".to_vec().try_into().unwrap(),
+				myriad_username: b"debiouser".to_vec().try_into().unwrap(),
+				gender: b"Gender".to_vec().try_into().unwrap(),
 				date_of_birth: 0,
-				email: b"Email".to_vec(),
-				phone_number: b"+6893026516".to_vec(),
-				role: b"doctor".to_vec(),
-				category: b"Mental Health".to_vec(),
-				profile_link: Some(b"DeBio Genetic Analyst profile_link".to_vec()),
-				profile_image: Some(b"DeBio Genetic Analyst profile_image".to_vec()),
+				email: b"Email".to_vec().try_into().unwrap(),
+				phone_number: b"+6893026516".to_vec().try_into().unwrap(),
+				role: b"doctor".to_vec().try_into().unwrap(),
+				category: b"Mental Health".to_vec().try_into().unwrap(),
+				profile_link: Some(
+					b"DeBio Genetic Analyst profile_link".to_vec().try_into().unwrap(),
+				),
+				profile_image: Some(
+					b"DeBio Genetic Analyst profile_image".to_vec().try_into().unwrap(),
+				),
 				anonymous: false,
 			};
 
@@ -87,17 +99,21 @@ mod tests {
 				box_public_key: Keccak256::hash(
 					"0xDb9Af2d1f3ADD2726A132AA7A65Cc9E6fC5761C3".as_bytes(),
 				),
-				first_name: b"First Name".to_vec(),
-				last_name: b"Last Name".to_vec(),
-				myriad_username: b"myriaduser".to_vec(),
-				gender: b"Gender".to_vec(),
+				first_name: b"First Name".to_vec().try_into().unwrap(),
+				last_name: b"Last Name".to_vec().try_into().unwrap(),
+				myriad_username: b"myriaduser".to_vec().try_into().unwrap(),
+				gender: b"Gender".to_vec().try_into().unwrap(),
 				date_of_birth: 0,
-				email: b"Email".to_vec(),
-				phone_number: b"+6893026516".to_vec(),
-				role: b"doctor".to_vec(),
-				category: b"Mental Health".to_vec(),
-				profile_link: Some(b"DeBio Genetic Analyst profile_link".to_vec()),
-				profile_image: Some(b"DeBio Genetic Analyst profile_image".to_vec()),
+				email: b"Email".to_vec().try_into().unwrap(),
+				phone_number: b"+6893026516".to_vec().try_into().unwrap(),
+				role: b"doctor".to_vec().try_into().unwrap(),
+				category: b"Mental Health".to_vec().try_into().unwrap(),
+				profile_link: Some(
+					b"DeBio Genetic Analyst profile_link".to_vec().try_into().unwrap(),
+				),
+				profile_image: Some(
+					b"DeBio Genetic Analyst profile_image".to_vec().try_into().unwrap(),
+				),
 				anonymous: false,
 			};
 
@@ -127,17 +143,21 @@ mod tests {
 				box_public_key: Keccak256::hash(
 					"0xDb9Af2d1f3ADD2726A132AA7A65Cc9E6fC5761C3".as_bytes(),
 				),
-				first_name: b"First Name".to_vec(),
-				last_name: b"Last Name".to_vec(),
-				myriad_username: b"debiouser".to_vec(),
-				gender: b"Gender".to_vec(),
+				first_name: b"First Name".to_vec().try_into().unwrap(),
+				last_name: b"Last Name".to_vec().try_into().unwrap(),
+				myriad_username: b"debiouser".to_vec().try_into().unwrap(),
+				gender: b"Gender".to_vec().try_into().unwrap(),
 				date_of_birth: 0,
-				email: b"Email".to_vec(),
-				phone_number: b"+6893026516".to_vec(),
-				role: b"doctor".to_vec(),
-				category: b"Mental Health".to_vec(),
-				profile_link: Some(b"DeBio Genetic Analyst profile_link".to_vec()),
-				profile_image: Some(b"DeBio Genetic Analyst profile_image".to_vec()),
+				email: b"Email".to_vec().try_into().unwrap(),
+				phone_number: b"+6893026516".to_vec().try_into().unwrap(),
+				role: b"doctor".to_vec().try_into().unwrap(),
+				category: b"Mental Health".to_vec().try_into().unwrap(),
+				profile_link: Some(
+					b"DeBio Genetic Analyst profile_link".to_vec().try_into().unwrap(),
+				),
+				profile_image: Some(
+					b"DeBio Genetic Analyst profile_image".to_vec().try_into().unwrap(),
+				),
 				anonymous: false,
 			};
 
@@ -175,17 +195,21 @@ mod tests {
 				box_public_key: Keccak256::hash(
 					"0xDb9Af2d1f3ADD2726A132AA7A65Cc9E6fC5761C3".as_bytes(),
 				),
-				first_name: b"First Name".to_vec(),
-				last_name: b"Last Name".to_vec(),
-				myriad_username: b"debiouser".to_vec(),
-				gender: b"Gender".to_vec(),
+				first_name: b"First Name".to_vec().try_into().unwrap(),
+				last_name: b"Last Name".to_vec().try_into().unwrap(),
+				myriad_username: b"debiouser".to_vec().try_into().unwrap(),
+				gender: b"Gender".to_vec().try_into().unwrap(),
 				date_of_birth: 0,
-				email: b"Email".to_vec(),
-				phone_number: b"+6893026516".to_vec(),
-				role: b"doctor".to_vec(),
-				category: b"Mental Health".to_vec(),
-				profile_link: Some(b"DeBio Genetic Analyst profile_link".to_vec()),
-				profile_image: Some(b"DeBio Genetic Analyst profile_image".to_vec()),
+				email: b"Email".to_vec().try_into().unwrap(),
+				phone_number: b"+6893026516".to_vec().try_into().unwrap(),
+				role: b"doctor".to_vec().try_into().unwrap(),
+				category: b"Mental Health".to_vec().try_into().unwrap(),
+				profile_link: Some(
+					b"DeBio Genetic Analyst profile_link".to_vec().try_into().unwrap(),
+				),
+				profile_image: Some(
+					b"DeBio Genetic Analyst profile_image".to_vec().try_into().unwrap(),
+				),
 				anonymous: false,
 			};
 
@@ -227,17 +251,21 @@ mod tests {
 				box_public_key: Keccak256::hash(
 					"0xDb9Af2d1f3ADD2726A132AA7A65Cc9E6fC5761C3".as_bytes(),
 				),
-				first_name: b"First Name".to_vec(),
-				last_name: b"Last Name".to_vec(),
-				myriad_username: b"debiouser".to_vec(),
-				gender: b"Gender".to_vec(),
+				first_name: b"First Name".to_vec().try_into().unwrap(),
+				last_name: b"Last Name".to_vec().try_into().unwrap(),
+				myriad_username: b"debiouser".to_vec().try_into().unwrap(),
+				gender: b"Gender".to_vec().try_into().unwrap(),
 				date_of_birth: 0,
-				email: b"Email".to_vec(),
-				phone_number: b"+6893026516".to_vec(),
-				role: b"doctor".to_vec(),
-				category: b"Mental Health".to_vec(),
-				profile_link: Some(b"DeBio Genetic Analyst profile_link".to_vec()),
-				profile_image: Some(b"DeBio Genetic Analyst profile_image".to_vec()),
+				email: b"Email".to_vec().try_into().unwrap(),
+				phone_number: b"+6893026516".to_vec().try_into().unwrap(),
+				role: b"doctor".to_vec().try_into().unwrap(),
+				category: b"Mental Health".to_vec().try_into().unwrap(),
+				profile_link: Some(
+					b"DeBio Genetic Analyst profile_link".to_vec().try_into().unwrap(),
+				),
+				profile_image: Some(
+					b"DeBio Genetic Analyst profile_image".to_vec().try_into().unwrap(),
+				),
 				anonymous: false,
 			};
 
@@ -260,17 +288,21 @@ mod tests {
 				box_public_key: Keccak256::hash(
 					"0xDb9Af2d1f3ADD2726A132AA7A65Cc9E6fC5761C3".as_bytes(),
 				),
-				first_name: b"First Name".to_vec(),
-				last_name: b"Last Name".to_vec(),
-				myriad_username: b"debiouser".to_vec(),
-				gender: b"Gender".to_vec(),
+				first_name: b"First Name".to_vec().try_into().unwrap(),
+				last_name: b"Last Name".to_vec().try_into().unwrap(),
+				myriad_username: b"debiouser".to_vec().try_into().unwrap(),
+				gender: b"Gender".to_vec().try_into().unwrap(),
 				date_of_birth: 0,
-				email: b"Email".to_vec(),
-				phone_number: b"+6893026516".to_vec(),
-				role: b"doctor".to_vec(),
-				category: b"Mental Health".to_vec(),
-				profile_link: Some(b"DeBio Genetic Analyst profile_link".to_vec()),
-				profile_image: Some(b"DeBio Genetic Analyst profile_image".to_vec()),
+				email: b"Email".to_vec().try_into().unwrap(),
+				phone_number: b"+6893026516".to_vec().try_into().unwrap(),
+				role: b"doctor".to_vec().try_into().unwrap(),
+				category: b"Mental Health".to_vec().try_into().unwrap(),
+				profile_link: Some(
+					b"DeBio Genetic Analyst profile_link".to_vec().try_into().unwrap(),
+				),
+				profile_image: Some(
+					b"DeBio Genetic Analyst profile_image".to_vec().try_into().unwrap(),
+				),
 				anonymous: false,
 			};
 
@@ -308,17 +340,21 @@ mod tests {
 				box_public_key: Keccak256::hash(
 					"0xDb9Af2d1f3ADD2726A132AA7A65Cc9E6fC5761C3".as_bytes(),
 				),
-				first_name: b"First Name".to_vec(),
-				last_name: b"Last Name".to_vec(),
-				myriad_username: b"debiouser".to_vec(),
-				gender: b"Gender".to_vec(),
+				first_name: b"First Name".to_vec().try_into().unwrap(),
+				last_name: b"Last Name".to_vec().try_into().unwrap(),
+				myriad_username: b"debiouser".to_vec().try_into().unwrap(),
+				gender: b"Gender".to_vec().try_into().unwrap(),
 				date_of_birth: 0,
-				email: b"Email".to_vec(),
-				phone_number: b"+6893026516".to_vec(),
-				role: b"doctor".to_vec(),
-				category: b"Mental Health".to_vec(),
-				profile_link: Some(b"DeBio Genetic Analyst profile_link".to_vec()),
-				profile_image: Some(b"DeBio Genetic Analyst profile_image".to_vec()),
+				email: b"Email".to_vec().try_into().unwrap(),
+				phone_number: b"+6893026516".to_vec().try_into().unwrap(),
+				role: b"doctor".to_vec().try_into().unwrap(),
+				category: b"Mental Health".to_vec().try_into().unwrap(),
+				profile_link: Some(
+					b"DeBio Genetic Analyst profile_link".to_vec().try_into().unwrap(),
+				),
+				profile_image: Some(
+					b"DeBio Genetic Analyst profile_image".to_vec().try_into().unwrap(),
+				),
 				anonymous: false,
 			};
 
@@ -356,17 +392,21 @@ mod tests {
 				box_public_key: Keccak256::hash(
 					"0xDb9Af2d1f3ADD2726A132AA7A65Cc9E6fC5761C3".as_bytes(),
 				),
-				first_name: b"First Name".to_vec(),
-				last_name: b"Last Name".to_vec(),
-				myriad_username: b"debiouser".to_vec(),
-				gender: b"Gender".to_vec(),
+				first_name: b"First Name".to_vec().try_into().unwrap(),
+				last_name: b"Last Name".to_vec().try_into().unwrap(),
+				myriad_username: b"debiouser".to_vec().try_into().unwrap(),
+				gender: b"Gender".to_vec().try_into().unwrap(),
 				date_of_birth: 0,
-				email: b"Email".to_vec(),
-				phone_number: b"+6893026516".to_vec(),
-				role: b"doctor".to_vec(),
-				category: b"Mental Health".to_vec(),
-				profile_link: Some(b"DeBio Genetic Analyst profile_link".to_vec()),
-				profile_image: Some(b"DeBio Genetic Analyst profile_image".to_vec()),
+				email: b"Email".to_vec().try_into().unwrap(),
+				phone_number: b"+6893026516".to_vec().try_into().unwrap(),
+				role: b"doctor".to_vec().try_into().unwrap(),
+				category: b"Mental Health".to_vec().try_into().unwrap(),
+				profile_link: Some(
+					b"DeBio Genetic Analyst profile_link".to_vec().try_into().unwrap(),
+				),
+				profile_image: Some(
+					b"DeBio Genetic Analyst profile_image".to_vec().try_into().unwrap(),
+				),
 				anonymous: false,
 			};
 
@@ -471,17 +511,21 @@ mod tests {
 				box_public_key: Keccak256::hash(
 					"0xDb9Af2d1f3ADD2726A132AA7A65Cc9E6fC5761C3".as_bytes(),
 				),
-				first_name: b"First Name".to_vec(),
-				last_name: b"Last Name".to_vec(),
-				myriad_username: b"debiouser".to_vec(),
-				gender: b"Gender".to_vec(),
+				first_name: b"First Name".to_vec().try_into().unwrap(),
+				last_name: b"Last Name".to_vec().try_into().unwrap(),
+				myriad_username: b"debiouser".to_vec().try_into().unwrap(),
+				gender: b"Gender".to_vec().try_into().unwrap(),
 				date_of_birth: 0,
-				email: b"Email".to_vec(),
-				phone_number: b"+6893026516".to_vec(),
-				role: b"doctor".to_vec(),
-				category: b"Mental Health".to_vec(),
-				profile_link: Some(b"DeBio Genetic Analyst profile_link".to_vec()),
-				profile_image: Some(b"DeBio Genetic Analyst profile_image".to_vec()),
+				email: b"Email".to_vec().try_into().unwrap(),
+				phone_number: b"+6893026516".to_vec().try_into().unwrap(),
+				role: b"doctor".to_vec().try_into().unwrap(),
+				category: b"Mental Health".to_vec().try_into().unwrap(),
+				profile_link: Some(
+					b"DeBio Genetic Analyst profile_link".to_vec().try_into().unwrap(),
+				),
+				profile_image: Some(
+					b"DeBio Genetic Analyst profile_image".to_vec().try_into().unwrap(),
+				),
 				anonymous: false,
 			};
 
@@ -502,17 +546,21 @@ mod tests {
 				box_public_key: Keccak256::hash(
 					"0xDb9Af2d1f3ADD2726A132AA7A65Cc9E6fC5761C3".as_bytes(),
 				),
-				first_name: b"First Name".to_vec(),
-				last_name: b"Last Name".to_vec(),
-				myriad_username: b"myriaduser".to_vec(),
-				gender: b"Gender".to_vec(),
+				first_name: b"First Name".to_vec().try_into().unwrap(),
+				last_name: b"Last Name".to_vec().try_into().unwrap(),
+				myriad_username: b"myriaduser".to_vec().try_into().unwrap(),
+				gender: b"Gender".to_vec().try_into().unwrap(),
 				date_of_birth: 0,
-				email: b"Email".to_vec(),
-				phone_number: b"+6893026516".to_vec(),
-				role: b"doctor".to_vec(),
-				category: b"Mental Health".to_vec(),
-				profile_link: Some(b"DeBio Genetic Analyst profile_link".to_vec()),
-				profile_image: Some(b"DeBio Genetic Analyst profile_image".to_vec()),
+				email: b"Email".to_vec().try_into().unwrap(),
+				phone_number: b"+6893026516".to_vec().try_into().unwrap(),
+				role: b"doctor".to_vec().try_into().unwrap(),
+				category: b"Mental Health".to_vec().try_into().unwrap(),
+				profile_link: Some(
+					b"DeBio Genetic Analyst profile_link".to_vec().try_into().unwrap(),
+				),
+				profile_image: Some(
+					b"DeBio Genetic Analyst profile_image".to_vec().try_into().unwrap(),
+				),
 				anonymous: false,
 			};
 
@@ -598,17 +646,21 @@ mod tests {
 				box_public_key: Keccak256::hash(
 					"0xDb9Af2d1f3ADD2726A132AA7A65Cc9E6fC5761C3".as_bytes(),
 				),
-				first_name: b"First Name".to_vec(),
-				last_name: b"Last Name".to_vec(),
-				myriad_username: b"debiouser".to_vec(),
-				gender: b"Gender".to_vec(),
+				first_name: b"First Name".to_vec().try_into().unwrap(),
+				last_name: b"Last Name".to_vec().try_into().unwrap(),
+				myriad_username: b"debiouser".to_vec().try_into().unwrap(),
+				gender: b"Gender".to_vec().try_into().unwrap(),
 				date_of_birth: 0,
-				email: b"Email".to_vec(),
-				phone_number: b"+6893026516".to_vec(),
-				role: b"doctor".to_vec(),
-				category: b"Mental Health".to_vec(),
-				profile_link: Some(b"DeBio Genetic Analyst profile_link".to_vec()),
-				profile_image: Some(b"DeBio Genetic Analyst profile_image".to_vec()),
+				email: b"Email".to_vec().try_into().unwrap(),
+				phone_number: b"+6893026516".to_vec().try_into().unwrap(),
+				role: b"doctor".to_vec().try_into().unwrap(),
+				category: b"Mental Health".to_vec().try_into().unwrap(),
+				profile_link: Some(
+					b"DeBio Genetic Analyst profile_link".to_vec().try_into().unwrap(),
+				),
+				profile_image: Some(
+					b"DeBio Genetic Analyst profile_image".to_vec().try_into().unwrap(),
+				),
 				anonymous: false,
 			};
 
@@ -657,17 +709,21 @@ mod tests {
 				box_public_key: Keccak256::hash(
 					"0xDb9Af2d1f3ADD2726A132AA7A65Cc9E6fC5761C3".as_bytes(),
 				),
-				first_name: b"First Name".to_vec(),
-				last_name: b"Last Name".to_vec(),
-				myriad_username: b"debiouser".to_vec(),
-				gender: b"Gender".to_vec(),
+				first_name: b"First Name".to_vec().try_into().unwrap(),
+				last_name: b"Last Name".to_vec().try_into().unwrap(),
+				myriad_username: b"debiouser".to_vec().try_into().unwrap(),
+				gender: b"Gender".to_vec().try_into().unwrap(),
 				date_of_birth: 0,
-				email: b"Email".to_vec(),
-				phone_number: b"+6893026516".to_vec(),
-				role: b"doctor".to_vec(),
-				category: b"Mental Health".to_vec(),
-				profile_link: Some(b"DeBio Genetic Analyst profile_link".to_vec()),
-				profile_image: Some(b"DeBio Genetic Analyst profile_image".to_vec()),
+				email: b"Email".to_vec().try_into().unwrap(),
+				phone_number: b"+6893026516".to_vec().try_into().unwrap(),
+				role: b"doctor".to_vec().try_into().unwrap(),
+				category: b"Mental Health".to_vec().try_into().unwrap(),
+				profile_link: Some(
+					b"DeBio Genetic Analyst profile_link".to_vec().try_into().unwrap(),
+				),
+				profile_image: Some(
+					b"DeBio Genetic Analyst profile_image".to_vec().try_into().unwrap(),
+				),
 				anonymous: false,
 			};
 
@@ -721,17 +777,21 @@ mod tests {
 				box_public_key: Keccak256::hash(
 					"0xDb9Af2d1f3ADD2726A132AA7A65Cc9E6fC5761C3".as_bytes(),
 				),
-				first_name: b"First Name".to_vec(),
-				last_name: b"Last Name".to_vec(),
-				myriad_username: b"debiouser".to_vec(),
-				gender: b"Gender".to_vec(),
+				first_name: b"First Name".to_vec().try_into().unwrap(),
+				last_name: b"Last Name".to_vec().try_into().unwrap(),
+				myriad_username: b"debiouser".to_vec().try_into().unwrap(),
+				gender: b"Gender".to_vec().try_into().unwrap(),
 				date_of_birth: 0,
-				email: b"Email".to_vec(),
-				phone_number: b"+6893026516".to_vec(),
-				role: b"doctor".to_vec(),
-				category: b"Mental Health".to_vec(),
-				profile_link: Some(b"DeBio Genetic Analyst profile_link".to_vec()),
-				profile_image: Some(b"DeBio Genetic Analyst profile_image".to_vec()),
+				email: b"Email".to_vec().try_into().unwrap(),
+				phone_number: b"+6893026516".to_vec().try_into().unwrap(),
+				role: b"doctor".to_vec().try_into().unwrap(),
+				category: b"Mental Health".to_vec().try_into().unwrap(),
+				profile_link: Some(
+					b"DeBio Genetic Analyst profile_link".to_vec().try_into().unwrap(),
+				),
+				profile_image: Some(
+					b"DeBio Genetic Analyst profile_image".to_vec().try_into().unwrap(),
+				),
 				anonymous: false,
 			};
 
@@ -834,17 +894,21 @@ mod tests {
 				box_public_key: Keccak256::hash(
 					"0xDb9Af2d1f3ADD2726A132AA7A65Cc9E6fC5761C3".as_bytes(),
 				),
-				first_name: b"First Name".to_vec(),
-				last_name: b"Last Name".to_vec(),
-				myriad_username: b"debiouser".to_vec(),
-				gender: b"Gender".to_vec(),
+				first_name: b"First Name".to_vec().try_into().unwrap(),
+				last_name: b"Last Name".to_vec().try_into().unwrap(),
+				myriad_username: b"debiouser".to_vec().try_into().unwrap(),
+				gender: b"Gender".to_vec().try_into().unwrap(),
 				date_of_birth: 0,
-				email: b"Email".to_vec(),
-				phone_number: b"+6893026516".to_vec(),
-				role: b"doctor".to_vec(),
-				category: b"Mental Health".to_vec(),
-				profile_link: Some(b"DeBio Genetic Analyst profile_link".to_vec()),
-				profile_image: Some(b"DeBio Genetic Analyst profile_image".to_vec()),
+				email: b"Email".to_vec().try_into().unwrap(),
+				phone_number: b"+6893026516".to_vec().try_into().unwrap(),
+				role: b"doctor".to_vec().try_into().unwrap(),
+				category: b"Mental Health".to_vec().try_into().unwrap(),
+				profile_link: Some(
+					b"DeBio Genetic Analyst profile_link".to_vec().try_into().unwrap(),
+				),
+				profile_image: Some(
+					b"DeBio Genetic Analyst profile_image".to_vec().try_into().unwrap(),
+				),
 				anonymous: false,
 			};
 
@@ -864,17 +928,21 @@ mod tests {
 				box_public_key: Keccak256::hash(
 					"0xDb9Af2d1f3ADD2726A132AA7A65Cc9E6fC5761C3".as_bytes(),
 				),
-				first_name: b"First Name".to_vec(),
-				last_name: b"Last Name".to_vec(),
-				myriad_username: b"myriaduser".to_vec(),
-				gender: b"Gender".to_vec(),
+				first_name: b"First Name".to_vec().try_into().unwrap(),
+				last_name: b"Last Name".to_vec().try_into().unwrap(),
+				myriad_username: b"myriaduser".to_vec().try_into().unwrap(),
+				gender: b"Gender".to_vec().try_into().unwrap(),
 				date_of_birth: 0,
-				email: b"Email".to_vec(),
-				phone_number: b"+6893026516".to_vec(),
-				role: b"doctor".to_vec(),
-				category: b"Mental Health".to_vec(),
-				profile_link: Some(b"DeBio Genetic Analyst profile_link".to_vec()),
-				profile_image: Some(b"DeBio Genetic Analyst profile_image".to_vec()),
+				email: b"Email".to_vec().try_into().unwrap(),
+				phone_number: b"+6893026516".to_vec().try_into().unwrap(),
+				role: b"doctor".to_vec().try_into().unwrap(),
+				category: b"Mental Health".to_vec().try_into().unwrap(),
+				profile_link: Some(
+					b"DeBio Genetic Analyst profile_link".to_vec().try_into().unwrap(),
+				),
+				profile_image: Some(
+					b"DeBio Genetic Analyst profile_image".to_vec().try_into().unwrap(),
+				),
 				anonymous: false,
 			};
 
@@ -981,4 +1049,75 @@ mod tests {
 			));
 		});
 	}
+
+	#[test]
+	fn migrate_health_professional_info_to_bounded_fields() {
+		<ExternalityBuilder>::default().existential_deposit(1).build().execute_with(|| {
+			let doctor = account_key("doctor");
+			let box_public_key =
+				Keccak256::hash("0xDb9Af2d1f3ADD2726A132AA7A65Cc9E6fC5761C3".as_bytes());
+			let first_name = vec![0u8; MAX_HEALTH_PROFESSIONAL_INFO_LENGTH as usize + 1];
+
+			put_storage_value(
+				b"HealthProfessional",
+				b"HealthProfessionals",
+				&Blake2_128Concat::hash(&doctor.encode()),
+				(
+					doctor,
+					vec![box_public_key],
+					(
+						box_public_key,
+						first_name.clone(),
+						b"Last Name".to_vec(),
+						b"debiouser".to_vec(),
+						b"Gender".to_vec(),
+						0u64,
+						b"Email".to_vec(),
+						b"+6893026516".to_vec(),
+						b"doctor".to_vec(),
+						b"Mental Health".to_vec(),
+						Some(b"DeBio Genetic Analyst profile_link".to_vec()),
+						None::<Vec<u8>>,
+						false,
+					),
+					0u64,
+					StakeStatus::Unstaked,
+					VerificationStatus::Verified,
+					AvailabilityStatus::Unavailable,
+					None::<u64>,
+				),
+			);
+			StorageVersion::new(1).put::<HealthProfessional>();
+
+			migrations::migrate::<Test>();
+
+			let info = HealthProfessionalInfo {
+				box_public_key,
+				first_name: BoundedVec::truncate_from(first_name),
+				last_name: b"Last Name".to_vec().try_into().unwrap(),
+				myriad_username: b"debiouser".to_vec().try_into().unwrap(),
+				gender: b"Gender".to_vec().try_into().unwrap(),
+				date_of_birth: 0,
+				email: b"Email".to_vec().try_into().unwrap(),
+				phone_number: b"+6893026516".to_vec().try_into().unwrap(),
+				role: b"doctor".to_vec().try_into().unwrap(),
+				category: b"Mental Health".to_vec().try_into().unwrap(),
+				profile_link: Some(
+					b"DeBio Genetic Analyst profile_link".to_vec().try_into().unwrap(),
+				),
+				profile_image: None,
+				anonymous: false,
+			};
+			let mut health_professional = HealthProfessionalStruct::new(&doctor, &info);
+			health_professional.update_verification_status(&VerificationStatus::Verified);
+			health_professional.update_availability_status(&AvailabilityStatus::Unavailable);
+			health_professional.update_qualifications(vec![box_public_key].try_into().unwrap());
+
+			assert_eq!(
+				HealthProfessional::health_professional_by_account_id(doctor),
+				Some(health_professional)
+			);
+			assert_eq!(StorageVersion::get::<HealthProfessional>(), StorageVersion::new(2));
+		});
+	}
 }
@@ -1,4 +1,4 @@
-use frame_support::{construct_runtime, parameter_types};
+use frame_support::{construct_runtime, pallet_prelude::MaxEncodedLen, parameter_types};
 use pallet_balances::AccountData;
 use scale_info::TypeInfo;
 use sp_core::{Decode, Encode, RuntimeDebug, H256};
@@ -7,7 +7,9 @@ use sp_runtime::{
 	traits::{BlakeTwo256, IdentityLookup},
 };
 
-#[derive(Clone, Copy, PartialEq, Eq, Encode, Decode, Default, RuntimeDebug, TypeInfo)]
+#[derive(
+	Clone, Copy, PartialEq, Eq, Encode, Decode, Default, RuntimeDebug, TypeInfo, MaxEncodedLen,
+)]
 pub struct EthereumAddress(pub [u8; 20]);
 
 type UncheckedExtrinsic = frame_system::mocking::MockUncheckedExtrinsic<Test>;
@@ -24,7 +26,7 @@ construct_runtime!(
 		System: frame_system,
 		Balances: pallet_balances,
 		HealthProfessional: health_professional,
-		HealthProfessionalQualification: health_professional_qualification,
+		HealthProfessionalQualification: credentials::<Instance5>,
 		Timestamp: pallet_timestamp,
 	}
 );
@@ -104,9 +106,12 @@ impl health_professional::Config for Test {
 	type HealthProfessionalWeightInfo = ();
 }
 
-impl health_professional_qualification::Config for Test {
+impl credentials::Config<credentials::Instance5> for Test {
 	type RuntimeEvent = RuntimeEvent;
-	type HealthProfessionalQualificationOwner = HealthProfessional;
+	type CredentialInfo = credentials::HealthProfessionalQualificationInfo;
+	type CredentialOwner =
+		credentials::legacy::LegacyHealthProfessionalQualificationOwner<HealthProfessional>;
+	type CredentialIssuers = ();
 	type WeightInfo = ();
 }
 
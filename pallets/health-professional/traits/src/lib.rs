@@ -6,3 +6,7 @@ pub trait HealthProfessionalCountT<T: Config> {
 	fn add_health_professional_count(value: u64);
 	fn substract_health_professional_count(value: u64);
 }
+
+pub trait HealthProfessionalProvider<T: Config> {
+	fn is_verified_and_staked(account_id: &T::AccountId) -> bool;
+}
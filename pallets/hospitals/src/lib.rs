@@ -1,5 +1,6 @@
 #![cfg_attr(not(feature = "std"), no_std)]
 
+pub mod migrations;
 pub mod weights;
 
 /// Edit this file to define custom logic or remove it if it is not needed.
@@ -20,35 +21,72 @@ mod benchmarking;
 
 pub mod interface;
 pub use crate::interface::HospitalInterface;
-use frame_support::pallet_prelude::*;
-use primitives_area_code::{CityCode, CountryCode, CountryRegionCode, RegionCode};
-use traits_hospital_certifications::HospitalCertificationOwnerInfo;
+use frame_support::{pallet_prelude::*, traits::StorageVersion};
+use primitives_area_code::{
+	normalize_area_code, AreaCodeError, CityCode, CountryCode, CountryRegionCode, RegionCode,
+};
+use primitives_geo_location::{Coordinate, GeoHash, MAX_SEARCH_RADIUS};
+#[cfg(feature = "std")]
+use serde::{Deserialize, Serialize};
+use traits_credentials::legacy::HospitalCertificationOwnerInfo;
 use traits_user_profile::UserProfileProvider;
 
+/// Longest text field of a hospital, e.g. its name, address or profile image url
+pub const MAX_HOSPITAL_INFO_LENGTH: u32 = 512;
+/// Most certifications a hospital can own
+pub const MAX_CERTIFICATIONS_PER_HOSPITAL: u32 = 256;
+
+pub type HospitalText = BoundedVec<u8, ConstU32<MAX_HOSPITAL_INFO_LENGTH>>;
+
 // HospitalInfo Struct
 // Used as parameter of dispatchable calls
-#[derive(Encode, Decode, Clone, Default, RuntimeDebug, PartialEq, Eq, TypeInfo)]
+#[derive(Encode, Decode, Clone, Default, RuntimeDebug, PartialEq, Eq, TypeInfo, MaxEncodedLen)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
 pub struct HospitalInfo {
-	pub name: Vec<u8>,
-	pub email: Vec<u8>,
+	pub name: HospitalText,
+	pub email: HospitalText,
 	pub country: CountryCode,
 	pub region: RegionCode,
 	pub city: CityCode,
-	pub address: Vec<u8>,
-	pub latitude: Option<Vec<u8>>,
-	pub longitude: Option<Vec<u8>>,
-	pub profile_image: Option<Vec<u8>>,
+	pub address: HospitalText,
+	pub latitude: Option<HospitalText>,
+	pub longitude: Option<HospitalText>,
+	pub profile_image: Option<HospitalText>,
+}
+
+impl HospitalInfo {
+	// Validates the area codes against ISO 3166 and upper cases them
+	fn normalize_area_code(mut self) -> Result<Self, AreaCodeError> {
+		let (country, region, city) = normalize_area_code(&self.country, &self.region, &self.city)?;
+
+		self.country = country;
+		self.region = region;
+		self.city = city;
+
+		Ok(self)
+	}
+
+	// A coordinate may be left out, but once given both halves must parse
+	fn has_valid_coordinate(&self) -> bool {
+		match (&self.latitude, &self.longitude) {
+			(None, None) => true,
+			(Some(latitude), Some(longitude)) =>
+				Coordinate::from_bytes(latitude, longitude).is_some(),
+			_ => false,
+		}
+	}
 }
 
 // Hospital Struct
 // the fields (excluding account_id and certifications) come from HospitalInfo struct
-#[derive(Encode, Decode, Clone, Default, RuntimeDebug, PartialEq, Eq, TypeInfo)]
+#[derive(Encode, Decode, Clone, Default, RuntimeDebug, PartialEq, Eq, TypeInfo, MaxEncodedLen)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
 pub struct Hospital<AccountId, Hash>
 where
 	Hash: PartialEq + Eq,
 {
 	pub account_id: AccountId,
-	pub certifications: Vec<Hash>,
+	pub certifications: BoundedVec<Hash, ConstU32<MAX_CERTIFICATIONS_PER_HOSPITAL>>,
 	pub info: HospitalInfo,
 }
 
@@ -57,7 +95,7 @@ where
 	Hash: PartialEq + Eq,
 {
 	pub fn new(account_id: AccountId, info: HospitalInfo) -> Self {
-		Self { account_id, certifications: Vec::<Hash>::new(), info }
+		Self { account_id, certifications: BoundedVec::default(), info }
 	}
 
 	fn update_info(&mut self, info: HospitalInfo) {
@@ -81,12 +119,22 @@ where
 		CountryRegionCode::build_country_region_code(self.get_country(), self.get_region())
 	}
 
+	// Returns the parsed latitude and longitude, None if either is missing or malformed
+	fn get_coordinate(&self) -> Option<Coordinate> {
+		match (&self.info.latitude, &self.info.longitude) {
+			(Some(latitude), Some(longitude)) => Coordinate::from_bytes(latitude, longitude),
+			_ => None,
+		}
+	}
+
 	pub fn get_account_id(&self) -> &AccountId {
 		&self.account_id
 	}
 
-	pub fn add_certification(&mut self, certification_id: Hash) {
-		self.certifications.push(certification_id);
+	/// Returns the certification id back when the hospital already owns
+	/// MAX_CERTIFICATIONS_PER_HOSPITAL certifications
+	pub fn add_certification(&mut self, certification_id: Hash) -> Result<(), Hash> {
+		self.certifications.try_push(certification_id)
 	}
 
 	pub fn remove_certification(&mut self, certification_id: Hash) {
@@ -106,6 +154,9 @@ where
 	}
 }
 
+/// The current storage version.
+const STORAGE_VERSION: StorageVersion = StorageVersion::new(2);
+
 #[frame_support::pallet]
 pub mod pallet {
 	use crate::{interface::HospitalInterface, Hospital, HospitalInfo, *};
@@ -113,7 +164,7 @@ pub mod pallet {
 	use frame_support::{dispatch::DispatchResultWithPostInfo, traits::Currency};
 	use frame_system::pallet_prelude::*;
 	pub use sp_std::prelude::*;
-	pub use traits_hospital_certifications::{
+	pub use traits_credentials::legacy::{
 		HospitalCertificationOwner, HospitalCertificationsProvider,
 	};
 
@@ -146,16 +197,23 @@ pub mod pallet {
 			+ sp_std::fmt::Debug;
 		type UserProfile: UserProfileProvider<Self, Self::EthereumAddress, Self::ProfileRoles>;
 		type WeightInfo: WeightInfo;
+		/// Maximum number of hospitals indexed per country-region and city.
+		#[pallet::constant]
+		type MaxHospitalsPerCity: Get<u32>;
 	}
 
 	// ----- This is template code, every pallet needs this ---
 	#[pallet::pallet]
+	#[pallet::storage_version(STORAGE_VERSION)]
 	#[pallet::generate_store(pub(super) trait Store)]
-	#[pallet::without_storage_info]
 	pub struct Pallet<T>(_);
 
 	#[pallet::hooks]
-	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {}
+	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+		fn on_runtime_upgrade() -> Weight {
+			migrations::migrate::<T>()
+		}
+	}
 	// --------------------------------------------------------
 
 	// ---- Types ----------------------
@@ -163,6 +221,7 @@ pub mod pallet {
 	pub type HashOf<T> = <T as frame_system::Config>::Hash;
 	pub type HospitalOf<T> = Hospital<AccountIdOf<T>, HashOf<T>>;
 	pub type UserProfileOf<T> = <T as self::Config>::UserProfile;
+	pub type HospitalIdsOf<T> = BoundedVec<AccountIdOf<T>, <T as Config>::MaxHospitalsPerCity>;
 
 	// ----- Storage ------------------
 	/// Get Hospital by account id
@@ -172,7 +231,7 @@ pub mod pallet {
 	pub type Hospitals<T> = StorageMap<_, Blake2_128Concat, AccountIdOf<T>, HospitalOf<T>>;
 
 	/// Get HospitalId by Country-Region, City
-	/// (CountryRegionCode, CityCode) => Vec<AccountId>
+	/// (CountryRegionCode, CityCode) => BoundedVec<AccountId>
 	#[pallet::storage]
 	#[pallet::getter(fn hospitals_by_country_region_city)]
 	pub type HospitalsByCountryRegionCity<T> = StorageDoubleMap<
@@ -181,7 +240,7 @@ pub mod pallet {
 		CountryRegionCode,
 		Blake2_128Concat,
 		CityCode,
-		Vec<AccountIdOf<T>>,
+		HospitalIdsOf<T>,
 	>;
 
 	/// Get total hospital count
@@ -196,6 +255,18 @@ pub mod pallet {
 	#[pallet::getter(fn hospital_count_by_country_region_city)]
 	pub type HospitalCountByCountryRegionCity<T> =
 		StorageDoubleMap<_, Blake2_128Concat, CountryRegionCode, Blake2_128Concat, CityCode, u64>;
+
+	/// Get Hospital coordinate by account id
+	/// AccountId => Coordinate
+	#[pallet::storage]
+	#[pallet::getter(fn hospital_coordinate_by_account_id)]
+	pub type HospitalCoordinates<T> = StorageMap<_, Blake2_128Concat, AccountIdOf<T>, Coordinate>;
+
+	/// Get HospitalIds by GeoHash
+	/// (GeoHash, AccountId) => ()
+	#[pallet::storage]
+	pub type HospitalsByGeoHash<T> =
+		StorageDoubleMap<_, Blake2_128Concat, GeoHash, Blake2_128Concat, AccountIdOf<T>, ()>;
 	// -----------------------------------
 
 	#[pallet::event]
@@ -223,6 +294,16 @@ pub mod pallet {
 		HospitalIsNotOwner,
 		// Setting profile role failed,
 		FailedToSetProfileRole,
+		/// Country is not an ISO 3166-1 alpha-2 code
+		InvalidCountryCode,
+		/// Region is not an ISO 3166-2 subdivision of the country
+		InvalidRegionCode,
+		/// City code is empty, too long or not printable
+		InvalidCityCode,
+		/// Latitude or longitude is given but is not a valid coordinate
+		InvalidCoordinate,
+		/// The city already indexes MaxHospitalsPerCity hospitals
+		TooManyHospitalsInCity,
 	}
 
 	#[pallet::call]
@@ -282,6 +363,8 @@ pub mod pallet {
 	}
 }
 
+primitives_area_code::impl_from_area_code_error!(Error);
+
 impl<T: Config> HospitalInterface<T> for Pallet<T> {
 	type Error = Error<T>;
 	type HospitalInfo = HospitalInfo;
@@ -294,10 +377,15 @@ impl<T: Config> HospitalInterface<T> for Pallet<T> {
 		if Hospitals::<T>::contains_key(account_id) {
 			return Err(Error::<T>::HospitalAlreadyRegistered)
 		}
-		let hospital = Hospital::new(account_id.clone(), hospital_info.clone());
+		let hospital_info = hospital_info.clone().normalize_area_code()?;
+		if !hospital_info.has_valid_coordinate() {
+			return Err(Error::<T>::InvalidCoordinate)
+		}
+		let hospital = Hospital::new(account_id.clone(), hospital_info);
 		// Insert to Storage
 		Hospitals::<T>::insert(account_id, &hospital);
-		Self::insert_hospital_id_to_location(&hospital);
+		Self::insert_hospital_id_to_location(&hospital)?;
+		Self::insert_hospital_id_to_geohash(&hospital);
 
 		// Increment Count
 		Self::add_hospital_count();
@@ -315,6 +403,10 @@ impl<T: Config> HospitalInterface<T> for Pallet<T> {
 			return Err(Error::<T>::HospitalDoesNotExist)
 		}
 		let mut hospital = hospital.unwrap();
+		let hospital_info = hospital_info.clone().normalize_area_code()?;
+		if !hospital_info.has_valid_coordinate() {
+			return Err(Error::<T>::InvalidCoordinate)
+		}
 		let mut is_location_changed = false;
 
 		// If location is updated, remove the hospital from the old location
@@ -329,14 +421,18 @@ impl<T: Config> HospitalInterface<T> for Pallet<T> {
 			is_location_changed = true;
 		}
 
-		hospital.update_info(hospital_info.clone());
+		hospital.update_info(hospital_info);
 
 		if is_location_changed {
 			// insert new location
-			Self::insert_hospital_id_to_location(&hospital);
+			Self::insert_hospital_id_to_location(&hospital)?;
 			Self::add_hospital_count_by_location(&hospital);
 		}
 
+		// Coordinates may change within the same city
+		Self::remove_hospital_id_from_geohash(account_id);
+		Self::insert_hospital_id_to_geohash(&hospital);
+
 		Hospitals::<T>::insert(account_id, &hospital);
 
 		Ok(hospital)
@@ -355,6 +451,7 @@ impl<T: Config> HospitalInterface<T> for Pallet<T> {
 		}
 		Self::remove_hospital_id_from_location(&hospital);
 		Self::sub_hospital_count_by_location(&hospital);
+		Self::remove_hospital_id_from_geohash(&hospital.account_id);
 		Hospitals::<T>::remove(&hospital.account_id);
 		Self::sub_hospital_count();
 
@@ -366,6 +463,7 @@ impl<T: Config> HospitalInterface<T> for Pallet<T> {
 		city_code: &CityCode,
 	) -> Option<Vec<T::AccountId>> {
 		Self::hospitals_by_country_region_city(country_region_code, city_code)
+			.map(BoundedVec::into_inner)
 	}
 
 	fn hospital_by_account_id(account_id: &T::AccountId) -> Option<Self::Hospital> {
@@ -374,29 +472,19 @@ impl<T: Config> HospitalInterface<T> for Pallet<T> {
 }
 
 impl<T: Config> Pallet<T> {
-	pub fn insert_hospital_id_to_location(hospital: &HospitalOf<T>) {
+	pub fn insert_hospital_id_to_location(hospital: &HospitalOf<T>) -> Result<(), Error<T>> {
 		let country_region_code = hospital.get_country_region();
 		let city_code = hospital.get_city();
 		let hospital_account_id = hospital.get_account_id();
 
-		match HospitalsByCountryRegionCity::<T>::get(&country_region_code, city_code) {
-			None => {
-				let hospitals = vec![hospital_account_id.clone()];
-				HospitalsByCountryRegionCity::<T>::insert(
-					&country_region_code,
-					city_code,
-					hospitals,
-				);
-			},
-			Some(mut hospitals) => {
-				hospitals.push(hospital_account_id.clone());
-				HospitalsByCountryRegionCity::<T>::insert(
-					&country_region_code,
-					city_code,
-					hospitals,
-				);
-			},
-		}
+		let mut hospitals = HospitalsByCountryRegionCity::<T>::get(&country_region_code, city_code)
+			.unwrap_or_default();
+		hospitals
+			.try_push(hospital_account_id.clone())
+			.map_err(|_| Error::<T>::TooManyHospitalsInCity)?;
+		HospitalsByCountryRegionCity::<T>::insert(&country_region_code, city_code, hospitals);
+
+		Ok(())
 	}
 
 	pub fn remove_hospital_id_from_location(hospital: &HospitalOf<T>) {
@@ -418,6 +506,37 @@ impl<T: Config> Pallet<T> {
 		);
 	}
 
+	pub fn insert_hospital_id_to_geohash(hospital: &HospitalOf<T>) {
+		if let Some(coordinate) = hospital.get_coordinate() {
+			let hospital_account_id = hospital.get_account_id();
+
+			HospitalCoordinates::<T>::insert(hospital_account_id, coordinate);
+			HospitalsByGeoHash::<T>::insert(coordinate.geohash(), hospital_account_id, ());
+		}
+	}
+
+	pub fn remove_hospital_id_from_geohash(account_id: &AccountIdOf<T>) {
+		if let Some(coordinate) = HospitalCoordinates::<T>::take(account_id) {
+			HospitalsByGeoHash::<T>::remove(coordinate.geohash(), account_id);
+		}
+	}
+
+	/// Hospitals within `radius` meters of `center`. The radius is capped at MAX_SEARCH_RADIUS.
+	pub fn get_hospitals_within_radius(center: &Coordinate, radius: u32) -> Vec<HospitalOf<T>> {
+		let radius = radius.min(MAX_SEARCH_RADIUS);
+
+		center
+			.covering_geohashes(radius)
+			.into_iter()
+			.flat_map(|geohash| HospitalsByGeoHash::<T>::iter_key_prefix(geohash))
+			.filter(|account_id| match HospitalCoordinates::<T>::get(account_id) {
+				Some(coordinate) => center.is_within(&coordinate, radius),
+				None => false,
+			})
+			.filter_map(Hospitals::<T>::get)
+			.collect()
+	}
+
 	// Add hospital count
 	pub fn add_hospital_count() {
 		let hospital_count = <HospitalCount<T>>::get().unwrap_or(0);
@@ -470,7 +589,11 @@ impl<T: Config> HospitalCertificationOwner<T> for Pallet<T> {
 
 	/// User can create certification if he/she is a hospital
 	fn can_create_certification(user_id: &T::AccountId) -> bool {
-		Hospitals::<T>::contains_key(user_id)
+		match Hospitals::<T>::get(user_id) {
+			Some(hospital) =>
+				hospital.certifications.len() < MAX_CERTIFICATIONS_PER_HOSPITAL as usize,
+			None => false,
+		}
 	}
 
 	fn get_owner(id: &T::AccountId) -> Option<Self::Owner> {
@@ -482,7 +605,9 @@ impl<T: Config> HospitalCertificationOwner<T> for Pallet<T> {
 			match hospital {
 				None => (), // If hospital does not exist, do nothing
 				Some(hospital) => {
-					hospital.add_certification(*certification_id);
+					// can_create_certification rejects hospitals without room for another
+					// certification
+					let _ = hospital.add_certification(*certification_id);
 				},
 			}
 		});
@@ -0,0 +1,175 @@
+use crate::{
+	AccountIdOf, Config, HashOf, Hospital, HospitalCountByCountryRegionCity, HospitalInfo,
+	HospitalOf, Hospitals, HospitalsByCountryRegionCity, Pallet, Vec, Weight,
+};
+use frame_support::{
+	log,
+	pallet_prelude::{Decode, Encode},
+	traits::{ConstU32, Get},
+	BoundedVec,
+};
+use primitives_area_code::{
+	upper_case_area_code, CityCode, CountryCode, RegionCode, MAX_AREA_CODE_LENGTH,
+};
+
+pub fn migrate<T: Config>() -> Weight {
+	use frame_support::traits::StorageVersion;
+
+	let mut version = StorageVersion::get::<Pallet<T>>();
+	let mut weight: Weight = Weight::zero();
+
+	if version < 1 {
+		weight = weight.saturating_add(v1::migrate::<T>());
+		StorageVersion::new(1).put::<Pallet<T>>();
+	}
+
+	version = StorageVersion::get::<Pallet<T>>();
+
+	if version == 1 {
+		weight = weight.saturating_add(v2::migrate::<T>());
+		StorageVersion::new(2).put::<Pallet<T>>();
+	}
+
+	weight
+}
+
+/// Truncates `value` to the bound and logs the entry when it did not fit.
+fn bounded<I, S: Get<u32>>(value: Vec<I>, entry: &str) -> BoundedVec<I, S> {
+	if value.len() > S::get() as usize {
+		log::warn!(
+			target: "runtime::hospitals",
+			"{} exceeds its bound of {}, truncated from {} items",
+			entry,
+			S::get(),
+			value.len(),
+		);
+	}
+
+	BoundedVec::truncate_from(value)
+}
+
+/// Region and city codes were unbounded before, legacy codes over the bound are truncated
+/// and logged like any other field.
+fn bounded_area_code(value: Vec<u8>, entry: &str) -> Vec<u8> {
+	bounded::<u8, ConstU32<MAX_AREA_CODE_LENGTH>>(value, entry).into_inner()
+}
+
+mod v1 {
+	use super::*;
+
+	/// Hospital info fields before they were bounded
+	#[derive(Encode, Decode)]
+	pub struct OldHospitalInfo {
+		pub name: Vec<u8>,
+		pub email: Vec<u8>,
+		pub country: CountryCode,
+		pub region: Vec<u8>,
+		pub city: Vec<u8>,
+		pub address: Vec<u8>,
+		pub latitude: Option<Vec<u8>>,
+		pub longitude: Option<Vec<u8>>,
+		pub profile_image: Option<Vec<u8>>,
+	}
+
+	#[derive(Encode, Decode)]
+	pub struct OldHospital<AccountId, Hash> {
+		pub account_id: AccountId,
+		pub certifications: Vec<Hash>,
+		pub info: OldHospitalInfo,
+	}
+
+	fn bounded_hospital_info(info: OldHospitalInfo) -> HospitalInfo {
+		HospitalInfo {
+			name: bounded(info.name, "hospital name"),
+			email: bounded(info.email, "hospital email"),
+			country: info.country,
+			region: RegionCode::from_vec(bounded_area_code(info.region, "hospital region")),
+			city: CityCode::from_vec(bounded_area_code(info.city, "hospital city")),
+			address: bounded(info.address, "hospital address"),
+			latitude: info.latitude.map(|latitude| bounded(latitude, "hospital latitude")),
+			longitude: info.longitude.map(|longitude| bounded(longitude, "hospital longitude")),
+			profile_image: info
+				.profile_image
+				.map(|profile_image| bounded(profile_image, "hospital profile image")),
+		}
+	}
+
+	/// Hospital info and certification lists become bounded, legacy values that exceed a bound
+	/// are truncated and logged. Hospitals with a coordinate are indexed by geohash. The
+	/// location index is rebuilt bounded by v2.
+	pub fn migrate<T: Config>() -> Weight {
+		let mut weight = T::DbWeight::get().writes(1);
+
+		<Hospitals<T>>::translate(|_key, old: OldHospital<AccountIdOf<T>, HashOf<T>>| {
+			weight = weight.saturating_add(T::DbWeight::get().reads_writes(1, 1));
+			Some(Hospital {
+				account_id: old.account_id,
+				certifications: bounded(old.certifications, "hospital certifications"),
+				info: bounded_hospital_info(old.info),
+			})
+		});
+
+		for hospital in <Hospitals<T>>::iter_values() {
+			<Pallet<T>>::insert_hospital_id_to_geohash(&hospital);
+			weight = weight.saturating_add(T::DbWeight::get().reads_writes(1, 2));
+		}
+
+		weight
+	}
+}
+
+mod v2 {
+	use super::*;
+
+	/// Validates and upper cases an area code stored before area codes were validated. Codes
+	/// which are not ISO 3166 are only upper cased and logged.
+	fn normalized_hospital_info(info: HospitalInfo) -> HospitalInfo {
+		match info.clone().normalize_area_code() {
+			Ok(info) => info,
+			Err(error) => {
+				log::warn!(
+					target: "runtime::hospitals",
+					"hospital area code {:?} {:?} {:?} is not ISO 3166 ({:?}), only upper cased",
+					info.country,
+					info.region,
+					info.city,
+					error,
+				);
+
+				let (country, region, city) =
+					upper_case_area_code(&info.country, &info.region, &info.city);
+				HospitalInfo { country, region, city, ..info }
+			},
+		}
+	}
+
+	/// Hospital area codes are normalized, and the location index and counts are rebuilt so
+	/// hospitals registered under differently cased codes are merged into one key.
+	pub fn migrate<T: Config>() -> Weight {
+		let mut weight = T::DbWeight::get().writes(1);
+
+		<Hospitals<T>>::translate(|_key, mut hospital: HospitalOf<T>| {
+			weight = weight.saturating_add(T::DbWeight::get().reads_writes(1, 1));
+			hospital.info = normalized_hospital_info(hospital.info);
+			Some(hospital)
+		});
+
+		let removed = HospitalsByCountryRegionCity::<T>::clear(u32::MAX, None).unique +
+			HospitalCountByCountryRegionCity::<T>::clear(u32::MAX, None).unique;
+		weight = weight.saturating_add(T::DbWeight::get().writes(removed.into()));
+
+		for hospital in <Hospitals<T>>::iter_values() {
+			if <Pallet<T>>::insert_hospital_id_to_location(&hospital).is_err() {
+				log::warn!(
+					target: "runtime::hospitals",
+					"hospital {:?} exceeds MaxHospitalsPerCity once area codes are merged, left out of the location index",
+					hospital.account_id,
+				);
+			}
+			<Pallet<T>>::add_hospital_count_by_location(&hospital);
+			weight = weight.saturating_add(T::DbWeight::get().reads_writes(3, 2));
+		}
+
+		weight
+	}
+}
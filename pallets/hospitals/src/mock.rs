@@ -25,7 +25,7 @@ frame_support::construct_runtime!(
 	{
 		System: frame_system,
 		Hospitals: hospitals,
-		HospitalCertifications: hospital_certifications,
+		HospitalCertifications: credentials::<Instance3>,
 		UserProfile: user_profile,
 		Balances: pallet_balances
 	}
@@ -71,12 +71,14 @@ impl hospitals::Config for Test {
 	type ProfileRoles = ProfileRoles;
 	type UserProfile = UserProfile;
 	type WeightInfo = ();
+	type MaxHospitalsPerCity = MaxHospitalsPerCity;
 }
 
 type Balance = u64;
 
 parameter_types! {
 	pub static ExistentialDeposit: Balance = 0;
+	pub static MaxHospitalsPerCity: u32 = 100;
 }
 
 impl pallet_balances::Config for Test {
@@ -100,9 +102,11 @@ impl user_profile::Config for Test {
 	type WeightInfo = ();
 }
 
-impl hospital_certifications::Config for Test {
+impl credentials::Config<credentials::Instance3> for Test {
 	type RuntimeEvent = RuntimeEvent;
-	type HospitalCertificationOwner = Hospitals;
+	type CredentialInfo = credentials::CertificationInfo;
+	type CredentialOwner = credentials::legacy::LegacyHospitalCertificationOwner<Hospitals>;
+	type CredentialIssuers = ();
 	type WeightInfo = ();
 }
 
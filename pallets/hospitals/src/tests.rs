@@ -1,6 +1,28 @@
-use crate::{mock::*, Error, Hospital, HospitalInfo};
-use frame_support::{assert_noop, assert_ok};
-use primitives_area_code::{CityCode, CountryCode, CountryRegionCode, RegionCode};
+use crate::{mock::*, Error, Hospital, HospitalInfo, MAX_HOSPITAL_INFO_LENGTH};
+use frame_support::{
+	assert_noop, assert_ok,
+	codec::{Decode, Encode},
+	storage::unhashed,
+	traits::StorageVersion,
+};
+use primitives_area_code::{
+	CityCode, CountryCode, CountryRegionCode, RegionCode, MAX_AREA_CODE_LENGTH,
+};
+use primitives_geo_location::Coordinate;
+
+fn hospital_info_at(latitude: &str, longitude: &str) -> HospitalInfo {
+	HospitalInfo {
+		name: "DeBio Hospital".as_bytes().to_vec().try_into().unwrap(),
+		email: "DeBio Email".as_bytes().to_vec().try_into().unwrap(),
+		country: CountryCode::try_from("ID".as_bytes().to_vec()).unwrap(),
+		region: RegionCode::try_from("JK".as_bytes().to_vec()).unwrap(),
+		city: CityCode::try_from("City".as_bytes().to_vec()).unwrap(),
+		address: "DeBio Address".as_bytes().to_vec().try_into().unwrap(),
+		latitude: Some(latitude.as_bytes().to_vec().try_into().unwrap()),
+		longitude: Some(longitude.as_bytes().to_vec().try_into().unwrap()),
+		profile_image: Some("DeBio Profile Image uwu".as_bytes().to_vec().try_into().unwrap()),
+	}
+}
 
 #[test]
 fn register_hospital_works() {
@@ -8,15 +30,17 @@ fn register_hospital_works() {
 		assert_ok!(Hospitals::register_hospital(
 			RuntimeOrigin::signed(1),
 			HospitalInfo {
-				name: "DeBio Hospital".as_bytes().to_vec(),
-				email: "DeBio Email".as_bytes().to_vec(),
-				country: CountryCode::from_vec("DC".as_bytes().to_vec()),
-				region: RegionCode::from_vec("DBIO".as_bytes().to_vec()),
-				city: CityCode::from_vec("City".as_bytes().to_vec()),
-				address: "DeBio Address".as_bytes().to_vec(),
-				latitude: Some("DeBio Latitude".as_bytes().to_vec()),
-				longitude: Some("DeBio Longtitude".as_bytes().to_vec()),
-				profile_image: Some("DeBio Profile Image uwu".as_bytes().to_vec()),
+				name: "DeBio Hospital".as_bytes().to_vec().try_into().unwrap(),
+				email: "DeBio Email".as_bytes().to_vec().try_into().unwrap(),
+				country: CountryCode::try_from("ID".as_bytes().to_vec()).unwrap(),
+				region: RegionCode::try_from("JK".as_bytes().to_vec()).unwrap(),
+				city: CityCode::try_from("City".as_bytes().to_vec()).unwrap(),
+				address: "DeBio Address".as_bytes().to_vec().try_into().unwrap(),
+				latitude: Some("-6.175392".as_bytes().to_vec().try_into().unwrap()),
+				longitude: Some("106.827153".as_bytes().to_vec().try_into().unwrap()),
+				profile_image: Some(
+					"DeBio Profile Image uwu".as_bytes().to_vec().try_into().unwrap()
+				),
 			}
 		));
 
@@ -24,27 +48,29 @@ fn register_hospital_works() {
 			Hospitals::hospital_by_account_id(1),
 			Some(Hospital {
 				account_id: 1,
-				certifications: Vec::new(),
+				certifications: Default::default(),
 				info: HospitalInfo {
-					name: "DeBio Hospital".as_bytes().to_vec(),
-					email: "DeBio Email".as_bytes().to_vec(),
-					country: CountryCode::from_vec("DC".as_bytes().to_vec()),
-					region: RegionCode::from_vec("DBIO".as_bytes().to_vec()),
-					city: CityCode::from_vec("City".as_bytes().to_vec()),
-					address: "DeBio Address".as_bytes().to_vec(),
-					latitude: Some("DeBio Latitude".as_bytes().to_vec()),
-					longitude: Some("DeBio Longtitude".as_bytes().to_vec()),
-					profile_image: Some("DeBio Profile Image uwu".as_bytes().to_vec()),
+					name: "DeBio Hospital".as_bytes().to_vec().try_into().unwrap(),
+					email: "DeBio Email".as_bytes().to_vec().try_into().unwrap(),
+					country: CountryCode::try_from("ID".as_bytes().to_vec()).unwrap(),
+					region: RegionCode::try_from("JK".as_bytes().to_vec()).unwrap(),
+					city: CityCode::try_from("City".as_bytes().to_vec()).unwrap(),
+					address: "DeBio Address".as_bytes().to_vec().try_into().unwrap(),
+					latitude: Some("-6.175392".as_bytes().to_vec().try_into().unwrap()),
+					longitude: Some("106.827153".as_bytes().to_vec().try_into().unwrap()),
+					profile_image: Some(
+						"DeBio Profile Image uwu".as_bytes().to_vec().try_into().unwrap()
+					),
 				}
 			})
 		);
 
-		let country_region_code = CountryRegionCode::from_vec("DC-DBIO".as_bytes().to_vec());
-		let city_code = CityCode::from_vec("City".as_bytes().to_vec());
+		let country_region_code = CountryRegionCode::from_vec("ID-JK".as_bytes().to_vec());
+		let city_code = CityCode::try_from("City".as_bytes().to_vec()).unwrap();
 
 		assert_eq!(
 			Hospitals::hospitals_by_country_region_city(&country_region_code, &city_code),
-			Some(vec![1])
+			Some(vec![1].try_into().unwrap())
 		);
 
 		assert_eq!(Hospitals::hospital_count(), Some(1));
@@ -62,30 +88,34 @@ fn update_hospital_works() {
 		assert_ok!(Hospitals::register_hospital(
 			RuntimeOrigin::signed(1),
 			HospitalInfo {
-				name: "DeBio Hospital".as_bytes().to_vec(),
-				email: "DeBio Email".as_bytes().to_vec(),
-				country: CountryCode::from_vec("DC".as_bytes().to_vec()),
-				region: RegionCode::from_vec("DBIO".as_bytes().to_vec()),
-				city: CityCode::from_vec("City".as_bytes().to_vec()),
-				address: "DeBio Address".as_bytes().to_vec(),
-				latitude: Some("DeBio Latitude".as_bytes().to_vec()),
-				longitude: Some("DeBio Longtitude".as_bytes().to_vec()),
-				profile_image: Some("DeBio Profile Image uwu".as_bytes().to_vec()),
+				name: "DeBio Hospital".as_bytes().to_vec().try_into().unwrap(),
+				email: "DeBio Email".as_bytes().to_vec().try_into().unwrap(),
+				country: CountryCode::try_from("ID".as_bytes().to_vec()).unwrap(),
+				region: RegionCode::try_from("JK".as_bytes().to_vec()).unwrap(),
+				city: CityCode::try_from("City".as_bytes().to_vec()).unwrap(),
+				address: "DeBio Address".as_bytes().to_vec().try_into().unwrap(),
+				latitude: Some("-6.175392".as_bytes().to_vec().try_into().unwrap()),
+				longitude: Some("106.827153".as_bytes().to_vec().try_into().unwrap()),
+				profile_image: Some(
+					"DeBio Profile Image uwu".as_bytes().to_vec().try_into().unwrap()
+				),
 			}
 		));
 
 		assert_ok!(Hospitals::update_hospital(
 			RuntimeOrigin::signed(1),
 			HospitalInfo {
-				name: "My Hospital".as_bytes().to_vec(),
-				email: "DeBio Email".as_bytes().to_vec(),
-				country: CountryCode::from_vec("DC".as_bytes().to_vec()),
-				region: RegionCode::from_vec("DBIO".as_bytes().to_vec()),
-				city: CityCode::from_vec("City".as_bytes().to_vec()),
-				address: "DeBio Address".as_bytes().to_vec(),
-				latitude: Some("DeBio Latitude".as_bytes().to_vec()),
-				longitude: Some("DeBio Longtitude".as_bytes().to_vec()),
-				profile_image: Some("DeBio Profile Image uwu".as_bytes().to_vec()),
+				name: "My Hospital".as_bytes().to_vec().try_into().unwrap(),
+				email: "DeBio Email".as_bytes().to_vec().try_into().unwrap(),
+				country: CountryCode::try_from("ID".as_bytes().to_vec()).unwrap(),
+				region: RegionCode::try_from("JK".as_bytes().to_vec()).unwrap(),
+				city: CityCode::try_from("City".as_bytes().to_vec()).unwrap(),
+				address: "DeBio Address".as_bytes().to_vec().try_into().unwrap(),
+				latitude: Some("-6.175392".as_bytes().to_vec().try_into().unwrap()),
+				longitude: Some("106.827153".as_bytes().to_vec().try_into().unwrap()),
+				profile_image: Some(
+					"DeBio Profile Image uwu".as_bytes().to_vec().try_into().unwrap()
+				),
 			}
 		));
 
@@ -93,17 +123,19 @@ fn update_hospital_works() {
 			Hospitals::hospital_by_account_id(1),
 			Some(Hospital {
 				account_id: 1,
-				certifications: Vec::new(),
+				certifications: Default::default(),
 				info: HospitalInfo {
-					name: "My Hospital".as_bytes().to_vec(),
-					email: "DeBio Email".as_bytes().to_vec(),
-					country: CountryCode::from_vec("DC".as_bytes().to_vec()),
-					region: RegionCode::from_vec("DBIO".as_bytes().to_vec()),
-					city: CityCode::from_vec("City".as_bytes().to_vec()),
-					address: "DeBio Address".as_bytes().to_vec(),
-					latitude: Some("DeBio Latitude".as_bytes().to_vec()),
-					longitude: Some("DeBio Longtitude".as_bytes().to_vec()),
-					profile_image: Some("DeBio Profile Image uwu".as_bytes().to_vec()),
+					name: "My Hospital".as_bytes().to_vec().try_into().unwrap(),
+					email: "DeBio Email".as_bytes().to_vec().try_into().unwrap(),
+					country: CountryCode::try_from("ID".as_bytes().to_vec()).unwrap(),
+					region: RegionCode::try_from("JK".as_bytes().to_vec()).unwrap(),
+					city: CityCode::try_from("City".as_bytes().to_vec()).unwrap(),
+					address: "DeBio Address".as_bytes().to_vec().try_into().unwrap(),
+					latitude: Some("-6.175392".as_bytes().to_vec().try_into().unwrap()),
+					longitude: Some("106.827153".as_bytes().to_vec().try_into().unwrap()),
+					profile_image: Some(
+						"DeBio Profile Image uwu".as_bytes().to_vec().try_into().unwrap()
+					),
 				}
 			})
 		);
@@ -111,15 +143,17 @@ fn update_hospital_works() {
 		assert_ok!(Hospitals::update_hospital(
 			RuntimeOrigin::signed(1),
 			HospitalInfo {
-				name: "My Hospital".as_bytes().to_vec(),
-				email: "DeBio Email".as_bytes().to_vec(),
-				country: CountryCode::from_vec("ID".as_bytes().to_vec()),
-				region: RegionCode::from_vec("DBIO".as_bytes().to_vec()),
-				city: CityCode::from_vec("City".as_bytes().to_vec()),
-				address: "DeBio Address".as_bytes().to_vec(),
-				latitude: Some("DeBio Latitude".as_bytes().to_vec()),
-				longitude: Some("DeBio Longtitude".as_bytes().to_vec()),
-				profile_image: Some("DeBio Profile Image uwu".as_bytes().to_vec()),
+				name: "My Hospital".as_bytes().to_vec().try_into().unwrap(),
+				email: "DeBio Email".as_bytes().to_vec().try_into().unwrap(),
+				country: CountryCode::try_from("MY".as_bytes().to_vec()).unwrap(),
+				region: RegionCode::try_from("10".as_bytes().to_vec()).unwrap(),
+				city: CityCode::try_from("City".as_bytes().to_vec()).unwrap(),
+				address: "DeBio Address".as_bytes().to_vec().try_into().unwrap(),
+				latitude: Some("-6.175392".as_bytes().to_vec().try_into().unwrap()),
+				longitude: Some("106.827153".as_bytes().to_vec().try_into().unwrap()),
+				profile_image: Some(
+					"DeBio Profile Image uwu".as_bytes().to_vec().try_into().unwrap()
+				),
 			}
 		));
 
@@ -127,26 +161,28 @@ fn update_hospital_works() {
 			Hospitals::hospital_by_account_id(1),
 			Some(Hospital {
 				account_id: 1,
-				certifications: Vec::new(),
+				certifications: Default::default(),
 				info: HospitalInfo {
-					name: "My Hospital".as_bytes().to_vec(),
-					email: "DeBio Email".as_bytes().to_vec(),
-					country: CountryCode::from_vec("ID".as_bytes().to_vec()),
-					region: RegionCode::from_vec("DBIO".as_bytes().to_vec()),
-					city: CityCode::from_vec("City".as_bytes().to_vec()),
-					address: "DeBio Address".as_bytes().to_vec(),
-					latitude: Some("DeBio Latitude".as_bytes().to_vec()),
-					longitude: Some("DeBio Longtitude".as_bytes().to_vec()),
-					profile_image: Some("DeBio Profile Image uwu".as_bytes().to_vec()),
+					name: "My Hospital".as_bytes().to_vec().try_into().unwrap(),
+					email: "DeBio Email".as_bytes().to_vec().try_into().unwrap(),
+					country: CountryCode::try_from("MY".as_bytes().to_vec()).unwrap(),
+					region: RegionCode::try_from("10".as_bytes().to_vec()).unwrap(),
+					city: CityCode::try_from("City".as_bytes().to_vec()).unwrap(),
+					address: "DeBio Address".as_bytes().to_vec().try_into().unwrap(),
+					latitude: Some("-6.175392".as_bytes().to_vec().try_into().unwrap()),
+					longitude: Some("106.827153".as_bytes().to_vec().try_into().unwrap()),
+					profile_image: Some(
+						"DeBio Profile Image uwu".as_bytes().to_vec().try_into().unwrap()
+					),
 				}
 			})
 		);
 
-		let old_country_region_code = CountryRegionCode::from_vec("DB-DBIO".as_bytes().to_vec());
-		let old_city_code = CityCode::from_vec("City".as_bytes().to_vec());
+		let old_country_region_code = CountryRegionCode::from_vec("ID-JK".as_bytes().to_vec());
+		let old_city_code = CityCode::try_from("City".as_bytes().to_vec()).unwrap();
 
-		let new_country_region_code = CountryRegionCode::from_vec("ID-DBIO".as_bytes().to_vec());
-		let new_city_code = CityCode::from_vec("City".as_bytes().to_vec());
+		let new_country_region_code = CountryRegionCode::from_vec("MY-10".as_bytes().to_vec());
+		let new_city_code = CityCode::try_from("City".as_bytes().to_vec()).unwrap();
 
 		assert_eq!(
 			Hospitals::hospitals_by_country_region_city(&old_country_region_code, &old_city_code),
@@ -155,7 +191,7 @@ fn update_hospital_works() {
 
 		assert_eq!(
 			Hospitals::hospitals_by_country_region_city(&new_country_region_code, &new_city_code),
-			Some(vec![1])
+			Some(vec![1].try_into().unwrap())
 		);
 
 		assert_eq!(
@@ -182,20 +218,22 @@ fn deregister_hospital_works() {
 		assert_ok!(Hospitals::register_hospital(
 			RuntimeOrigin::signed(1),
 			HospitalInfo {
-				name: "DeBio Hospital".as_bytes().to_vec(),
-				email: "DeBio Email".as_bytes().to_vec(),
-				country: CountryCode::from_vec("DC".as_bytes().to_vec()),
-				region: RegionCode::from_vec("DBIO".as_bytes().to_vec()),
-				city: CityCode::from_vec("City".as_bytes().to_vec()),
-				address: "DeBio Address".as_bytes().to_vec(),
-				latitude: Some("DeBio Latitude".as_bytes().to_vec()),
-				longitude: Some("DeBio Longtitude".as_bytes().to_vec()),
-				profile_image: Some("DeBio Profile Image uwu".as_bytes().to_vec()),
+				name: "DeBio Hospital".as_bytes().to_vec().try_into().unwrap(),
+				email: "DeBio Email".as_bytes().to_vec().try_into().unwrap(),
+				country: CountryCode::try_from("ID".as_bytes().to_vec()).unwrap(),
+				region: RegionCode::try_from("JK".as_bytes().to_vec()).unwrap(),
+				city: CityCode::try_from("City".as_bytes().to_vec()).unwrap(),
+				address: "DeBio Address".as_bytes().to_vec().try_into().unwrap(),
+				latitude: Some("-6.175392".as_bytes().to_vec().try_into().unwrap()),
+				longitude: Some("106.827153".as_bytes().to_vec().try_into().unwrap()),
+				profile_image: Some(
+					"DeBio Profile Image uwu".as_bytes().to_vec().try_into().unwrap()
+				),
 			}
 		));
 
-		let country_region_code = CountryRegionCode::from_vec("DC-DB".as_bytes().to_vec());
-		let city_code = CityCode::from_vec("CITY".as_bytes().to_vec());
+		let country_region_code = CountryRegionCode::from_vec("ID-JB".as_bytes().to_vec());
+		let city_code = CityCode::try_from("CITY".as_bytes().to_vec()).unwrap();
 
 		assert_ok!(Hospitals::deregister_hospital(RuntimeOrigin::signed(1)));
 
@@ -220,15 +258,17 @@ fn cant_update_and_unregister_hospital_when_not_exist() {
 			Hospitals::update_hospital(
 				RuntimeOrigin::signed(1),
 				HospitalInfo {
-					name: "DeBio Hospital".as_bytes().to_vec(),
-					email: "DeBio Email".as_bytes().to_vec(),
-					country: CountryCode::from_vec("DC".as_bytes().to_vec()),
-					region: RegionCode::from_vec("DBIO".as_bytes().to_vec()),
-					city: CityCode::from_vec("City".as_bytes().to_vec()),
-					address: "DeBio Address".as_bytes().to_vec(),
-					latitude: Some("DeBio Latitude".as_bytes().to_vec()),
-					longitude: Some("DeBio Longtitude".as_bytes().to_vec()),
-					profile_image: Some("DeBio Profile Image uwu".as_bytes().to_vec()),
+					name: "DeBio Hospital".as_bytes().to_vec().try_into().unwrap(),
+					email: "DeBio Email".as_bytes().to_vec().try_into().unwrap(),
+					country: CountryCode::try_from("ID".as_bytes().to_vec()).unwrap(),
+					region: RegionCode::try_from("JK".as_bytes().to_vec()).unwrap(),
+					city: CityCode::try_from("City".as_bytes().to_vec()).unwrap(),
+					address: "DeBio Address".as_bytes().to_vec().try_into().unwrap(),
+					latitude: Some("-6.175392".as_bytes().to_vec().try_into().unwrap()),
+					longitude: Some("106.827153".as_bytes().to_vec().try_into().unwrap()),
+					profile_image: Some(
+						"DeBio Profile Image uwu".as_bytes().to_vec().try_into().unwrap()
+					),
 				}
 			),
 			Error::<Test>::HospitalDoesNotExist
@@ -247,15 +287,17 @@ fn cant_register_hospital_when_already_registered() {
 		assert_ok!(Hospitals::register_hospital(
 			RuntimeOrigin::signed(1),
 			HospitalInfo {
-				name: "DeBio Hospital".as_bytes().to_vec(),
-				email: "DeBio Email".as_bytes().to_vec(),
-				country: CountryCode::from_vec("DC".as_bytes().to_vec()),
-				region: RegionCode::from_vec("DBIO".as_bytes().to_vec()),
-				city: CityCode::from_vec("City".as_bytes().to_vec()),
-				address: "DeBio Address".as_bytes().to_vec(),
-				latitude: Some("DeBio Latitude".as_bytes().to_vec()),
-				longitude: Some("DeBio Longtitude".as_bytes().to_vec()),
-				profile_image: Some("DeBio Profile Image uwu".as_bytes().to_vec()),
+				name: "DeBio Hospital".as_bytes().to_vec().try_into().unwrap(),
+				email: "DeBio Email".as_bytes().to_vec().try_into().unwrap(),
+				country: CountryCode::try_from("ID".as_bytes().to_vec()).unwrap(),
+				region: RegionCode::try_from("JK".as_bytes().to_vec()).unwrap(),
+				city: CityCode::try_from("City".as_bytes().to_vec()).unwrap(),
+				address: "DeBio Address".as_bytes().to_vec().try_into().unwrap(),
+				latitude: Some("-6.175392".as_bytes().to_vec().try_into().unwrap()),
+				longitude: Some("106.827153".as_bytes().to_vec().try_into().unwrap()),
+				profile_image: Some(
+					"DeBio Profile Image uwu".as_bytes().to_vec().try_into().unwrap()
+				),
 			}
 		));
 
@@ -263,15 +305,17 @@ fn cant_register_hospital_when_already_registered() {
 			Hospitals::register_hospital(
 				RuntimeOrigin::signed(1),
 				HospitalInfo {
-					name: "DeBio Hospital".as_bytes().to_vec(),
-					email: "DeBio Email".as_bytes().to_vec(),
-					country: CountryCode::from_vec("DC".as_bytes().to_vec()),
-					region: RegionCode::from_vec("DBIO".as_bytes().to_vec()),
-					city: CityCode::from_vec("City".as_bytes().to_vec()),
-					address: "DeBio Address".as_bytes().to_vec(),
-					latitude: Some("DeBio Latitude".as_bytes().to_vec()),
-					longitude: Some("DeBio Longtitude".as_bytes().to_vec()),
-					profile_image: Some("DeBio Profile Image uwu".as_bytes().to_vec()),
+					name: "DeBio Hospital".as_bytes().to_vec().try_into().unwrap(),
+					email: "DeBio Email".as_bytes().to_vec().try_into().unwrap(),
+					country: CountryCode::try_from("ID".as_bytes().to_vec()).unwrap(),
+					region: RegionCode::try_from("JK".as_bytes().to_vec()).unwrap(),
+					city: CityCode::try_from("City".as_bytes().to_vec()).unwrap(),
+					address: "DeBio Address".as_bytes().to_vec().try_into().unwrap(),
+					latitude: Some("-6.175392".as_bytes().to_vec().try_into().unwrap()),
+					longitude: Some("106.827153".as_bytes().to_vec().try_into().unwrap()),
+					profile_image: Some(
+						"DeBio Profile Image uwu".as_bytes().to_vec().try_into().unwrap()
+					),
 				}
 			),
 			Error::<Test>::HospitalAlreadyRegistered
@@ -279,6 +323,43 @@ fn cant_register_hospital_when_already_registered() {
 	})
 }
 
+#[test]
+fn register_hospital_validates_area_code() {
+	ExternalityBuilder::build().execute_with(|| {
+		// Codes decoded from an extrinsic skip TryFrom
+		let mut hospital_info = hospital_info_at("-6.175392", "106.827153");
+		hospital_info.country = CountryCode::decode(&mut "DC".as_bytes()).unwrap();
+
+		assert_noop!(
+			Hospitals::register_hospital(RuntimeOrigin::signed(1), hospital_info.clone()),
+			Error::<Test>::InvalidCountryCode
+		);
+
+		hospital_info.country = CountryCode::try_from("MY".as_bytes().to_vec()).unwrap();
+
+		assert_noop!(
+			Hospitals::register_hospital(RuntimeOrigin::signed(1), hospital_info.clone()),
+			Error::<Test>::InvalidRegionCode
+		);
+
+		hospital_info.country = CountryCode::decode(&mut "id".as_bytes()).unwrap();
+		hospital_info.region = RegionCode::decode(&mut &"jk".encode()[..]).unwrap();
+		hospital_info.city = CityCode::decode(&mut &"city".encode()[..]).unwrap();
+
+		assert_ok!(Hospitals::register_hospital(RuntimeOrigin::signed(1), hospital_info));
+
+		let hospital = Hospitals::hospital_by_account_id(1).unwrap();
+		let country_region_code = CountryRegionCode::from_vec("ID-JK".as_bytes().to_vec());
+		let city_code = CityCode::try_from("CITY".as_bytes().to_vec()).unwrap();
+
+		assert_eq!(hospital.info, hospital_info_at("-6.175392", "106.827153"));
+		assert_eq!(
+			Hospitals::hospitals_by_country_region_city(&country_region_code, &city_code),
+			Some(vec![1].try_into().unwrap())
+		);
+	})
+}
+
 #[test]
 fn call_event_should_work() {
 	ExternalityBuilder::build().execute_with(|| {
@@ -286,32 +367,36 @@ fn call_event_should_work() {
 		assert_ok!(Hospitals::register_hospital(
 			RuntimeOrigin::signed(1),
 			HospitalInfo {
-				name: "DeBio Hospital".as_bytes().to_vec(),
-				email: "DeBio Email".as_bytes().to_vec(),
-				country: CountryCode::from_vec("DC".as_bytes().to_vec()),
-				region: RegionCode::from_vec("DBIO".as_bytes().to_vec()),
-				city: CityCode::from_vec("City".as_bytes().to_vec()),
-				address: "DeBio Address".as_bytes().to_vec(),
-				latitude: Some("DeBio Latitude".as_bytes().to_vec()),
-				longitude: Some("DeBio Longtitude".as_bytes().to_vec()),
-				profile_image: Some("DeBio Profile Image uwu".as_bytes().to_vec()),
+				name: "DeBio Hospital".as_bytes().to_vec().try_into().unwrap(),
+				email: "DeBio Email".as_bytes().to_vec().try_into().unwrap(),
+				country: CountryCode::try_from("ID".as_bytes().to_vec()).unwrap(),
+				region: RegionCode::try_from("JK".as_bytes().to_vec()).unwrap(),
+				city: CityCode::try_from("City".as_bytes().to_vec()).unwrap(),
+				address: "DeBio Address".as_bytes().to_vec().try_into().unwrap(),
+				latitude: Some("-6.175392".as_bytes().to_vec().try_into().unwrap()),
+				longitude: Some("106.827153".as_bytes().to_vec().try_into().unwrap()),
+				profile_image: Some(
+					"DeBio Profile Image uwu".as_bytes().to_vec().try_into().unwrap()
+				),
 			}
 		));
 
 		System::assert_last_event(RuntimeEvent::Hospitals(crate::Event::HospitalRegistered(
 			Hospital {
 				account_id: 1,
-				certifications: Vec::new(),
+				certifications: Default::default(),
 				info: HospitalInfo {
-					name: "DeBio Hospital".as_bytes().to_vec(),
-					email: "DeBio Email".as_bytes().to_vec(),
-					country: CountryCode::from_vec("DC".as_bytes().to_vec()),
-					region: RegionCode::from_vec("DBIO".as_bytes().to_vec()),
-					city: CityCode::from_vec("City".as_bytes().to_vec()),
-					address: "DeBio Address".as_bytes().to_vec(),
-					latitude: Some("DeBio Latitude".as_bytes().to_vec()),
-					longitude: Some("DeBio Longtitude".as_bytes().to_vec()),
-					profile_image: Some("DeBio Profile Image uwu".as_bytes().to_vec()),
+					name: "DeBio Hospital".as_bytes().to_vec().try_into().unwrap(),
+					email: "DeBio Email".as_bytes().to_vec().try_into().unwrap(),
+					country: CountryCode::try_from("ID".as_bytes().to_vec()).unwrap(),
+					region: RegionCode::try_from("JK".as_bytes().to_vec()).unwrap(),
+					city: CityCode::try_from("City".as_bytes().to_vec()).unwrap(),
+					address: "DeBio Address".as_bytes().to_vec().try_into().unwrap(),
+					latitude: Some("-6.175392".as_bytes().to_vec().try_into().unwrap()),
+					longitude: Some("106.827153".as_bytes().to_vec().try_into().unwrap()),
+					profile_image: Some(
+						"DeBio Profile Image uwu".as_bytes().to_vec().try_into().unwrap(),
+					),
 				},
 			},
 			1,
@@ -320,32 +405,36 @@ fn call_event_should_work() {
 		assert_ok!(Hospitals::update_hospital(
 			RuntimeOrigin::signed(1),
 			HospitalInfo {
-				name: "DeBio Hospital".as_bytes().to_vec(),
-				email: "DeBio Email".as_bytes().to_vec(),
-				country: CountryCode::from_vec("ID".as_bytes().to_vec()),
-				region: RegionCode::from_vec("DBIO".as_bytes().to_vec()),
-				city: CityCode::from_vec("City".as_bytes().to_vec()),
-				address: "DeBio Address".as_bytes().to_vec(),
-				latitude: Some("DeBio Latitude".as_bytes().to_vec()),
-				longitude: Some("DeBio Longtitude".as_bytes().to_vec()),
-				profile_image: Some("DeBio Profile Image uwu".as_bytes().to_vec()),
+				name: "DeBio Hospital".as_bytes().to_vec().try_into().unwrap(),
+				email: "DeBio Email".as_bytes().to_vec().try_into().unwrap(),
+				country: CountryCode::try_from("MY".as_bytes().to_vec()).unwrap(),
+				region: RegionCode::try_from("10".as_bytes().to_vec()).unwrap(),
+				city: CityCode::try_from("City".as_bytes().to_vec()).unwrap(),
+				address: "DeBio Address".as_bytes().to_vec().try_into().unwrap(),
+				latitude: Some("-6.175392".as_bytes().to_vec().try_into().unwrap()),
+				longitude: Some("106.827153".as_bytes().to_vec().try_into().unwrap()),
+				profile_image: Some(
+					"DeBio Profile Image uwu".as_bytes().to_vec().try_into().unwrap()
+				),
 			}
 		));
 
 		System::assert_last_event(RuntimeEvent::Hospitals(crate::Event::HospitalUpdated(
 			Hospital {
 				account_id: 1,
-				certifications: Vec::new(),
+				certifications: Default::default(),
 				info: HospitalInfo {
-					name: "DeBio Hospital".as_bytes().to_vec(),
-					email: "DeBio Email".as_bytes().to_vec(),
-					country: CountryCode::from_vec("ID".as_bytes().to_vec()),
-					region: RegionCode::from_vec("DBIO".as_bytes().to_vec()),
-					city: CityCode::from_vec("City".as_bytes().to_vec()),
-					address: "DeBio Address".as_bytes().to_vec(),
-					latitude: Some("DeBio Latitude".as_bytes().to_vec()),
-					longitude: Some("DeBio Longtitude".as_bytes().to_vec()),
-					profile_image: Some("DeBio Profile Image uwu".as_bytes().to_vec()),
+					name: "DeBio Hospital".as_bytes().to_vec().try_into().unwrap(),
+					email: "DeBio Email".as_bytes().to_vec().try_into().unwrap(),
+					country: CountryCode::try_from("MY".as_bytes().to_vec()).unwrap(),
+					region: RegionCode::try_from("10".as_bytes().to_vec()).unwrap(),
+					city: CityCode::try_from("City".as_bytes().to_vec()).unwrap(),
+					address: "DeBio Address".as_bytes().to_vec().try_into().unwrap(),
+					latitude: Some("-6.175392".as_bytes().to_vec().try_into().unwrap()),
+					longitude: Some("106.827153".as_bytes().to_vec().try_into().unwrap()),
+					profile_image: Some(
+						"DeBio Profile Image uwu".as_bytes().to_vec().try_into().unwrap(),
+					),
 				},
 			},
 			1,
@@ -356,20 +445,191 @@ fn call_event_should_work() {
 		System::assert_last_event(RuntimeEvent::Hospitals(crate::Event::HospitalDeleted(
 			Hospital {
 				account_id: 1,
-				certifications: Vec::new(),
+				certifications: Default::default(),
 				info: HospitalInfo {
-					name: "DeBio Hospital".as_bytes().to_vec(),
-					email: "DeBio Email".as_bytes().to_vec(),
-					country: CountryCode::from_vec("ID".as_bytes().to_vec()),
-					region: RegionCode::from_vec("DBIO".as_bytes().to_vec()),
-					city: CityCode::from_vec("City".as_bytes().to_vec()),
-					address: "DeBio Address".as_bytes().to_vec(),
-					latitude: Some("DeBio Latitude".as_bytes().to_vec()),
-					longitude: Some("DeBio Longtitude".as_bytes().to_vec()),
-					profile_image: Some("DeBio Profile Image uwu".as_bytes().to_vec()),
+					name: "DeBio Hospital".as_bytes().to_vec().try_into().unwrap(),
+					email: "DeBio Email".as_bytes().to_vec().try_into().unwrap(),
+					country: CountryCode::try_from("MY".as_bytes().to_vec()).unwrap(),
+					region: RegionCode::try_from("10".as_bytes().to_vec()).unwrap(),
+					city: CityCode::try_from("City".as_bytes().to_vec()).unwrap(),
+					address: "DeBio Address".as_bytes().to_vec().try_into().unwrap(),
+					latitude: Some("-6.175392".as_bytes().to_vec().try_into().unwrap()),
+					longitude: Some("106.827153".as_bytes().to_vec().try_into().unwrap()),
+					profile_image: Some(
+						"DeBio Profile Image uwu".as_bytes().to_vec().try_into().unwrap(),
+					),
 				},
 			},
 			1,
 		)));
 	});
 }
+
+#[test]
+fn hospitals_within_radius_works() {
+	ExternalityBuilder::build().execute_with(|| {
+		// Central Jakarta
+		assert_ok!(Hospitals::register_hospital(
+			RuntimeOrigin::signed(1),
+			hospital_info_at("-6.175392", "106.827153")
+		));
+		// Bandung, about 120km away
+		assert_ok!(Hospitals::register_hospital(
+			RuntimeOrigin::signed(2),
+			hospital_info_at("-6.917464", "107.619123")
+		));
+		// Malformed coordinates are rejected
+		assert_noop!(
+			Hospitals::register_hospital(
+				RuntimeOrigin::signed(3),
+				hospital_info_at("DeBio Latitude", "106.8")
+			),
+			Error::<Test>::InvalidCoordinate
+		);
+		// Hospitals without a coordinate are not indexed
+		assert_ok!(Hospitals::register_hospital(
+			RuntimeOrigin::signed(3),
+			HospitalInfo { latitude: None, longitude: None, ..hospital_info_at("0", "0") }
+		));
+
+		assert_eq!(
+			Hospitals::hospital_coordinate_by_account_id(1),
+			Coordinate::new(-6_175_392, 106_827_153)
+		);
+		assert_eq!(Hospitals::hospital_coordinate_by_account_id(3), None);
+
+		let center = Coordinate::new(-6_200_000, 106_816_666).unwrap();
+		let hospital_ids = |radius| {
+			Hospitals::get_hospitals_within_radius(&center, radius)
+				.iter()
+				.map(|hospital| hospital.account_id)
+				.collect::<Vec<_>>()
+		};
+
+		assert_eq!(hospital_ids(1_000), Vec::<u64>::new());
+		assert_eq!(hospital_ids(10_000), vec![1]);
+
+		// Moving the hospital moves it out of the search radius
+		assert_ok!(Hospitals::update_hospital(
+			RuntimeOrigin::signed(1),
+			hospital_info_at("-6.914744", "107.609810")
+		));
+		assert_eq!(hospital_ids(10_000), Vec::<u64>::new());
+
+		assert_ok!(Hospitals::deregister_hospital(RuntimeOrigin::signed(2)));
+		assert_eq!(Hospitals::hospital_coordinate_by_account_id(2), None);
+		assert_eq!(
+			Hospitals::get_hospitals_within_radius(
+				&Coordinate::new(-6_917_464, 107_619_123).unwrap(),
+				10_000
+			)
+			.iter()
+			.map(|hospital| hospital.account_id)
+			.collect::<Vec<_>>(),
+			vec![1]
+		);
+	})
+}
+
+#[test]
+fn cant_register_hospital_when_city_is_full() {
+	ExternalityBuilder::build().execute_with(|| {
+		MaxHospitalsPerCity::set(1);
+
+		assert_ok!(Hospitals::register_hospital(
+			RuntimeOrigin::signed(1),
+			hospital_info_at("-6.914744", "107.609810")
+		));
+
+		assert_noop!(
+			Hospitals::register_hospital(
+				RuntimeOrigin::signed(2),
+				hospital_info_at("-6.914744", "107.609810")
+			),
+			Error::<Test>::TooManyHospitalsInCity
+		);
+
+		// Deregistering frees up the slot
+		assert_ok!(Hospitals::deregister_hospital(RuntimeOrigin::signed(1)));
+		assert_ok!(Hospitals::register_hospital(
+			RuntimeOrigin::signed(2),
+			hospital_info_at("-6.914744", "107.609810")
+		));
+	})
+}
+
+#[test]
+fn migrate_truncates_hospital_info_over_its_bound() {
+	ExternalityBuilder::build().execute_with(|| {
+		let info = hospital_info_at("-6.175392", "106.827153");
+		// A hospital stored before hospital info was bounded
+		let legacy_hospital = (
+			1u64,
+			Vec::<sp_core::H256>::new(),
+			vec![b'X'; MAX_HOSPITAL_INFO_LENGTH as usize + 1],
+			info.email.to_vec(),
+			&info.country,
+			&info.region,
+			&info.city,
+			info.address.to_vec(),
+			info.latitude.clone().map(|latitude| latitude.to_vec()),
+			info.longitude.clone().map(|longitude| longitude.to_vec()),
+			None::<Vec<u8>>,
+		);
+
+		unhashed::put(&crate::Hospitals::<Test>::hashed_key_for(1), &legacy_hospital);
+		StorageVersion::new(0).put::<Hospitals>();
+
+		crate::migrations::migrate::<Test>();
+
+		let hospital = Hospitals::hospital_by_account_id(1).unwrap();
+
+		assert_eq!(hospital.info.name.to_vec(), vec![b'X'; MAX_HOSPITAL_INFO_LENGTH as usize]);
+		assert_eq!(hospital.info.email, info.email);
+		assert_eq!(
+			Hospitals::hospital_coordinate_by_account_id(1),
+			Coordinate::new(-6_175_392, 106_827_153)
+		);
+		assert_eq!(StorageVersion::get::<Hospitals>(), StorageVersion::new(2));
+	})
+}
+
+#[test]
+fn migrate_truncates_legacy_area_codes_over_their_bound() {
+	ExternalityBuilder::build().execute_with(|| {
+		let info = hospital_info_at("-6.175392", "106.827153");
+		// Region and city codes were unbounded before, a longer city no longer decodes as CityCode
+		let legacy_hospital = (
+			1u64,
+			Vec::<sp_core::H256>::new(),
+			info.name.to_vec(),
+			info.email.to_vec(),
+			&info.country,
+			b"jk".to_vec(),
+			vec![b'x'; MAX_AREA_CODE_LENGTH as usize + 1],
+			info.address.to_vec(),
+			info.latitude.clone().map(|latitude| latitude.to_vec()),
+			info.longitude.clone().map(|longitude| longitude.to_vec()),
+			None::<Vec<u8>>,
+		);
+
+		unhashed::put(&crate::Hospitals::<Test>::hashed_key_for(1), &legacy_hospital);
+		StorageVersion::new(0).put::<Hospitals>();
+
+		crate::migrations::migrate::<Test>();
+
+		let hospital = Hospitals::hospital_by_account_id(1).unwrap();
+		let city_code = CityCode::try_from(vec![b'X'; MAX_AREA_CODE_LENGTH as usize]).unwrap();
+
+		assert_eq!(hospital.info.region, info.region);
+		assert_eq!(hospital.info.city, city_code);
+		assert_eq!(
+			Hospitals::hospitals_by_country_region_city(
+				CountryRegionCode::from_vec("ID-JK".as_bytes().to_vec()),
+				city_code
+			),
+			Some(vec![1].try_into().unwrap())
+		);
+		assert_eq!(StorageVersion::get::<Hospitals>(), StorageVersion::new(2));
+	})
+}
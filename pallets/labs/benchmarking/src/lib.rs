@@ -9,11 +9,11 @@ pub struct Pallet<T: Config>(Labs<T>);
 
 pub trait Config: LabsConfig {}
 
-use frame_benchmarking::{benchmarks, whitelisted_caller};
+use frame_benchmarking::{account, benchmarks, whitelisted_caller};
 use frame_system::RawOrigin;
 
 use frame_support::{
-	sp_runtime::{traits::Hash, SaturatedConversion},
+	sp_runtime::{traits::Hash, Perbill, SaturatedConversion},
 	traits::Currency,
 };
 use primitives_area_code::{CityCode, CountryCode, RegionCode};
@@ -23,17 +23,17 @@ benchmarks! {
 	register_lab {
 		let lab = LabInfo {
 			box_public_key: T::Hashing::hash("0xDb9Af2d1f3ADD2726A132AA7A65Cc9E6fC5761C3".as_bytes()),
-			name: "DeBio Lab".as_bytes().to_vec(),
-			email: "DeBio Email".as_bytes().to_vec(),
-			country: CountryCode::from_vec("DC".as_bytes().to_vec()),
-			region: RegionCode::from_vec("DBIO".as_bytes().to_vec()),
-			city: CityCode::from_vec("City".as_bytes().to_vec()),
-			address: "DeBio Address".as_bytes().to_vec(),
-			phone_number: "+6281394653625".as_bytes().to_vec(),
-			website: "DeBio Website".as_bytes().to_vec(),
-			latitude: Some("DeBio Latitude".as_bytes().to_vec()),
-			longitude: Some("DeBio Longtitude".as_bytes().to_vec()),
-			profile_image: Some("DeBio Profile Image uwu".as_bytes().to_vec()),
+			name: "DeBio Lab".as_bytes().to_vec().try_into().unwrap(),
+			email: "DeBio Email".as_bytes().to_vec().try_into().unwrap(),
+			country: CountryCode::try_from("ID".as_bytes().to_vec()).unwrap(),
+			region: RegionCode::try_from("JK".as_bytes().to_vec()).unwrap(),
+			city: CityCode::try_from("City".as_bytes().to_vec()).unwrap(),
+			address: "DeBio Address".as_bytes().to_vec().try_into().unwrap(),
+			phone_number: "+6281394653625".as_bytes().to_vec().try_into().unwrap(),
+			website: "DeBio Website".as_bytes().to_vec().try_into().unwrap(),
+			latitude: Some("-6.175392".as_bytes().to_vec().try_into().unwrap()),
+			longitude: Some("106.827153".as_bytes().to_vec().try_into().unwrap()),
+			profile_image: Some("DeBio Profile Image uwu".as_bytes().to_vec().try_into().unwrap()),
 		};
 		let caller: T::AccountId = whitelisted_caller();
 	}: register_lab(
@@ -47,33 +47,33 @@ benchmarks! {
 
 		let old_lab = LabInfo {
 			box_public_key: T::Hashing::hash("0xDb9Af2d1f3ADD2726A132AA7A65Cc9E6fC5761C3".as_bytes()),
-			name: "DeBio Lab".as_bytes().to_vec(),
-			email: "DeBio Email".as_bytes().to_vec(),
-			country: CountryCode::from_vec("DC".as_bytes().to_vec()),
-			region: RegionCode::from_vec("DBIO".as_bytes().to_vec()),
-			city: CityCode::from_vec("City".as_bytes().to_vec()),
-			address: "DeBio Address".as_bytes().to_vec(),
-			phone_number: "+6281394653625".as_bytes().to_vec(),
-			website: "DeBio Website".as_bytes().to_vec(),
-			latitude: Some("DeBio Latitude".as_bytes().to_vec()),
-			longitude: Some("DeBio Longtitude".as_bytes().to_vec()),
-			profile_image: Some("DeBio Profile Image uwu".as_bytes().to_vec()),
+			name: "DeBio Lab".as_bytes().to_vec().try_into().unwrap(),
+			email: "DeBio Email".as_bytes().to_vec().try_into().unwrap(),
+			country: CountryCode::try_from("ID".as_bytes().to_vec()).unwrap(),
+			region: RegionCode::try_from("JK".as_bytes().to_vec()).unwrap(),
+			city: CityCode::try_from("City".as_bytes().to_vec()).unwrap(),
+			address: "DeBio Address".as_bytes().to_vec().try_into().unwrap(),
+			phone_number: "+6281394653625".as_bytes().to_vec().try_into().unwrap(),
+			website: "DeBio Website".as_bytes().to_vec().try_into().unwrap(),
+			latitude: Some("-6.175392".as_bytes().to_vec().try_into().unwrap()),
+			longitude: Some("106.827153".as_bytes().to_vec().try_into().unwrap()),
+			profile_image: Some("DeBio Profile Image uwu".as_bytes().to_vec().try_into().unwrap()),
 		};
 		let _add_labs = Labs::<T>::register_lab(caller_origin, old_lab);
 
 		let new_lab = LabInfo {
 			box_public_key: T::Hashing::hash("0xDb9Af2d1f3ADD2726A132AA7A65Cc9E6fC5761C3".as_bytes()),
-			name: "DeBio Lab 2".as_bytes().to_vec(),
-			email: "DeBio Email 2".as_bytes().to_vec(),
-			country: CountryCode::from_vec("C2".as_bytes().to_vec()),
-			region: RegionCode::from_vec("DBI2".as_bytes().to_vec()),
-			city: CityCode::from_vec("C2C2".as_bytes().to_vec()),
-			address: "DeBio Address 2".as_bytes().to_vec(),
-			phone_number: "+6281394653625".as_bytes().to_vec(),
-			website: "DeBio Website".as_bytes().to_vec(),
-			latitude: Some("DeBio Latitude 2".as_bytes().to_vec()),
-			longitude: Some("DeBio Longtitude 2".as_bytes().to_vec()),
-			profile_image: Some("DeBio Profile Image owo".as_bytes().to_vec()),
+			name: "DeBio Lab 2".as_bytes().to_vec().try_into().unwrap(),
+			email: "DeBio Email 2".as_bytes().to_vec().try_into().unwrap(),
+			country: CountryCode::try_from("ID".as_bytes().to_vec()).unwrap(),
+			region: RegionCode::try_from("JT".as_bytes().to_vec()).unwrap(),
+			city: CityCode::try_from("C2C2".as_bytes().to_vec()).unwrap(),
+			address: "DeBio Address 2".as_bytes().to_vec().try_into().unwrap(),
+			phone_number: "+6281394653625".as_bytes().to_vec().try_into().unwrap(),
+			website: "DeBio Website".as_bytes().to_vec().try_into().unwrap(),
+			latitude: Some("-6.261493".as_bytes().to_vec().try_into().unwrap()),
+			longitude: Some("106.810600".as_bytes().to_vec().try_into().unwrap()),
+			profile_image: Some("DeBio Profile Image owo".as_bytes().to_vec().try_into().unwrap()),
 		};
 	}: update_lab(
 		RawOrigin::Signed(caller),
@@ -86,17 +86,17 @@ benchmarks! {
 
 		let old_lab = LabInfo {
 			box_public_key: T::Hashing::hash("0xDb9Af2d1f3ADD2726A132AA7A65Cc9E6fC5761C3".as_bytes()),
-			name: "DeBio Lab".as_bytes().to_vec(),
-			email: "DeBio Email".as_bytes().to_vec(),
-			country: CountryCode::from_vec("DC".as_bytes().to_vec()),
-			region: RegionCode::from_vec("DBIO".as_bytes().to_vec()),
-			city: CityCode::from_vec("City".as_bytes().to_vec()),
-			address: "DeBio Address".as_bytes().to_vec(),
-			phone_number: "+6281394653625".as_bytes().to_vec(),
-			website: "DeBio Website".as_bytes().to_vec(),
-			latitude: Some("DeBio Latitude".as_bytes().to_vec()),
-			longitude: Some("DeBio Longtitude".as_bytes().to_vec()),
-			profile_image: Some("DeBio Profile Image uwu".as_bytes().to_vec()),
+			name: "DeBio Lab".as_bytes().to_vec().try_into().unwrap(),
+			email: "DeBio Email".as_bytes().to_vec().try_into().unwrap(),
+			country: CountryCode::try_from("ID".as_bytes().to_vec()).unwrap(),
+			region: RegionCode::try_from("JK".as_bytes().to_vec()).unwrap(),
+			city: CityCode::try_from("City".as_bytes().to_vec()).unwrap(),
+			address: "DeBio Address".as_bytes().to_vec().try_into().unwrap(),
+			phone_number: "+6281394653625".as_bytes().to_vec().try_into().unwrap(),
+			website: "DeBio Website".as_bytes().to_vec().try_into().unwrap(),
+			latitude: Some("-6.175392".as_bytes().to_vec().try_into().unwrap()),
+			longitude: Some("106.827153".as_bytes().to_vec().try_into().unwrap()),
+			profile_image: Some("DeBio Profile Image uwu".as_bytes().to_vec().try_into().unwrap()),
 		};
 		let _add_labs = Labs::<T>::register_lab(caller_origin, old_lab);
 	}: update_lab_verification_status(
@@ -111,17 +111,17 @@ benchmarks! {
 
 		let lab = LabInfo {
 			box_public_key: T::Hashing::hash("0xDb9Af2d1f3ADD2726A132AA7A65Cc9E6fC5761C3".as_bytes()),
-			name: "DeBio Lab".as_bytes().to_vec(),
-			email: "DeBio Email".as_bytes().to_vec(),
-			country: CountryCode::from_vec("DC".as_bytes().to_vec()),
-			region: RegionCode::from_vec("DBIO".as_bytes().to_vec()),
-			city: CityCode::from_vec("City".as_bytes().to_vec()),
-			address: "DeBio Address".as_bytes().to_vec(),
-			phone_number: "+6281394653625".as_bytes().to_vec(),
-			website: "DeBio Website".as_bytes().to_vec(),
-			latitude: Some("DeBio Latitude".as_bytes().to_vec()),
-			longitude: Some("DeBio Longtitude".as_bytes().to_vec()),
-			profile_image: Some("DeBio Profile Image uwu".as_bytes().to_vec()),
+			name: "DeBio Lab".as_bytes().to_vec().try_into().unwrap(),
+			email: "DeBio Email".as_bytes().to_vec().try_into().unwrap(),
+			country: CountryCode::try_from("ID".as_bytes().to_vec()).unwrap(),
+			region: RegionCode::try_from("JK".as_bytes().to_vec()).unwrap(),
+			city: CityCode::try_from("City".as_bytes().to_vec()).unwrap(),
+			address: "DeBio Address".as_bytes().to_vec().try_into().unwrap(),
+			phone_number: "+6281394653625".as_bytes().to_vec().try_into().unwrap(),
+			website: "DeBio Website".as_bytes().to_vec().try_into().unwrap(),
+			latitude: Some("-6.175392".as_bytes().to_vec().try_into().unwrap()),
+			longitude: Some("106.827153".as_bytes().to_vec().try_into().unwrap()),
+			profile_image: Some("DeBio Profile Image uwu".as_bytes().to_vec().try_into().unwrap()),
 		};
 
 		let _add_labs = Labs::<T>::register_lab(caller_origin, lab);
@@ -137,17 +137,17 @@ benchmarks! {
 
 		let old_lab = LabInfo {
 			box_public_key: T::Hashing::hash("0xDb9Af2d1f3ADD2726A132AA7A65Cc9E6fC5761C3".as_bytes()),
-			name: "DeBio Lab".as_bytes().to_vec(),
-			email: "DeBio Email".as_bytes().to_vec(),
-			country: CountryCode::from_vec("DC".as_bytes().to_vec()),
-			region: RegionCode::from_vec("DBIO".as_bytes().to_vec()),
-			city: CityCode::from_vec("City".as_bytes().to_vec()),
-			address: "DeBio Address".as_bytes().to_vec(),
-			phone_number: "+6281394653625".as_bytes().to_vec(),
-			website: "DeBio Website".as_bytes().to_vec(),
-			latitude: Some("DeBio Latitude".as_bytes().to_vec()),
-			longitude: Some("DeBio Longtitude".as_bytes().to_vec()),
-			profile_image: Some("DeBio Profile Image uwu".as_bytes().to_vec()),
+			name: "DeBio Lab".as_bytes().to_vec().try_into().unwrap(),
+			email: "DeBio Email".as_bytes().to_vec().try_into().unwrap(),
+			country: CountryCode::try_from("ID".as_bytes().to_vec()).unwrap(),
+			region: RegionCode::try_from("JK".as_bytes().to_vec()).unwrap(),
+			city: CityCode::try_from("City".as_bytes().to_vec()).unwrap(),
+			address: "DeBio Address".as_bytes().to_vec().try_into().unwrap(),
+			phone_number: "+6281394653625".as_bytes().to_vec().try_into().unwrap(),
+			website: "DeBio Website".as_bytes().to_vec().try_into().unwrap(),
+			latitude: Some("-6.175392".as_bytes().to_vec().try_into().unwrap()),
+			longitude: Some("106.827153".as_bytes().to_vec().try_into().unwrap()),
+			profile_image: Some("DeBio Profile Image uwu".as_bytes().to_vec().try_into().unwrap()),
 		};
 		let _ = Labs::<T>::register_lab(caller_origin, old_lab);
 	}: stake_lab(
@@ -162,17 +162,17 @@ benchmarks! {
 
 		let old_lab = LabInfo {
 			box_public_key: T::Hashing::hash("0xDb9Af2d1f3ADD2726A132AA7A65Cc9E6fC5761C3".as_bytes()),
-			name: "DeBio Lab".as_bytes().to_vec(),
-			email: "DeBio Email".as_bytes().to_vec(),
-			country: CountryCode::from_vec("DC".as_bytes().to_vec()),
-			region: RegionCode::from_vec("DBIO".as_bytes().to_vec()),
-			city: CityCode::from_vec("City".as_bytes().to_vec()),
-			address: "DeBio Address".as_bytes().to_vec(),
-			phone_number: "+6281394653625".as_bytes().to_vec(),
-			website: "DeBio Website".as_bytes().to_vec(),
-			latitude: Some("DeBio Latitude".as_bytes().to_vec()),
-			longitude: Some("DeBio Longtitude".as_bytes().to_vec()),
-			profile_image: Some("DeBio Profile Image uwu".as_bytes().to_vec()),
+			name: "DeBio Lab".as_bytes().to_vec().try_into().unwrap(),
+			email: "DeBio Email".as_bytes().to_vec().try_into().unwrap(),
+			country: CountryCode::try_from("ID".as_bytes().to_vec()).unwrap(),
+			region: RegionCode::try_from("JK".as_bytes().to_vec()).unwrap(),
+			city: CityCode::try_from("City".as_bytes().to_vec()).unwrap(),
+			address: "DeBio Address".as_bytes().to_vec().try_into().unwrap(),
+			phone_number: "+6281394653625".as_bytes().to_vec().try_into().unwrap(),
+			website: "DeBio Website".as_bytes().to_vec().try_into().unwrap(),
+			latitude: Some("-6.175392".as_bytes().to_vec().try_into().unwrap()),
+			longitude: Some("106.827153".as_bytes().to_vec().try_into().unwrap()),
+			profile_image: Some("DeBio Profile Image uwu".as_bytes().to_vec().try_into().unwrap()),
 		};
 		let _ = Labs::<T>::register_lab(caller_origin.clone(), old_lab);
 
@@ -190,17 +190,17 @@ benchmarks! {
 
 		let old_lab = LabInfo {
 			box_public_key: T::Hashing::hash("0xDb9Af2d1f3ADD2726A132AA7A65Cc9E6fC5761C3".as_bytes()),
-			name: "DeBio Lab".as_bytes().to_vec(),
-			email: "DeBio Email".as_bytes().to_vec(),
-			country: CountryCode::from_vec("DC".as_bytes().to_vec()),
-			region: RegionCode::from_vec("DBIO".as_bytes().to_vec()),
-			city: CityCode::from_vec("City".as_bytes().to_vec()),
-			address: "DeBio Address".as_bytes().to_vec(),
-			phone_number: "+6281394653625".as_bytes().to_vec(),
-			website: "DeBio Website".as_bytes().to_vec(),
-			latitude: Some("DeBio Latitude".as_bytes().to_vec()),
-			longitude: Some("DeBio Longtitude".as_bytes().to_vec()),
-			profile_image: Some("DeBio Profile Image uwu".as_bytes().to_vec()),
+			name: "DeBio Lab".as_bytes().to_vec().try_into().unwrap(),
+			email: "DeBio Email".as_bytes().to_vec().try_into().unwrap(),
+			country: CountryCode::try_from("ID".as_bytes().to_vec()).unwrap(),
+			region: RegionCode::try_from("JK".as_bytes().to_vec()).unwrap(),
+			city: CityCode::try_from("City".as_bytes().to_vec()).unwrap(),
+			address: "DeBio Address".as_bytes().to_vec().try_into().unwrap(),
+			phone_number: "+6281394653625".as_bytes().to_vec().try_into().unwrap(),
+			website: "DeBio Website".as_bytes().to_vec().try_into().unwrap(),
+			latitude: Some("-6.175392".as_bytes().to_vec().try_into().unwrap()),
+			longitude: Some("106.827153".as_bytes().to_vec().try_into().unwrap()),
+			profile_image: Some("DeBio Profile Image uwu".as_bytes().to_vec().try_into().unwrap()),
 		};
 		let _ = Labs::<T>::register_lab(caller_origin.clone(), old_lab);
 
@@ -233,4 +233,108 @@ benchmarks! {
 		RawOrigin::Signed(caller),
 		caller2
 	)
+
+	propose_slash {
+		let caller: T::AccountId = whitelisted_caller();
+		let caller_origin = <T as frame_system::Config>::RuntimeOrigin::from(RawOrigin::Signed(caller.clone()));
+
+		let _ = <T as labs::Config>::Currency::deposit_creating(&caller, 60000000000000000000000u128.saturated_into());
+
+		let old_lab = LabInfo {
+			box_public_key: T::Hashing::hash("0xDb9Af2d1f3ADD2726A132AA7A65Cc9E6fC5761C3".as_bytes()),
+			name: "DeBio Lab".as_bytes().to_vec().try_into().unwrap(),
+			email: "DeBio Email".as_bytes().to_vec().try_into().unwrap(),
+			country: CountryCode::try_from("ID".as_bytes().to_vec()).unwrap(),
+			region: RegionCode::try_from("JK".as_bytes().to_vec()).unwrap(),
+			city: CityCode::try_from("City".as_bytes().to_vec()).unwrap(),
+			address: "DeBio Address".as_bytes().to_vec().try_into().unwrap(),
+			phone_number: "+6281394653625".as_bytes().to_vec().try_into().unwrap(),
+			website: "DeBio Website".as_bytes().to_vec().try_into().unwrap(),
+			latitude: Some("-6.175392".as_bytes().to_vec().try_into().unwrap()),
+			longitude: Some("106.827153".as_bytes().to_vec().try_into().unwrap()),
+			profile_image: Some("DeBio Profile Image uwu".as_bytes().to_vec().try_into().unwrap()),
+		};
+		let _ = Labs::<T>::register_lab(caller_origin.clone(), old_lab);
+
+		let _ = Labs::<T>::stake_lab(caller_origin);
+
+		let customer: T::AccountId = account("customer", 0, 0);
+	}: propose_slash(
+		RawOrigin::Root,
+		caller,
+		Perbill::from_percent(10),
+		Some(customer)
+	)
+
+	apply_slash {
+		let caller: T::AccountId = whitelisted_caller();
+		let caller_origin = <T as frame_system::Config>::RuntimeOrigin::from(RawOrigin::Signed(caller.clone()));
+
+		let _ = <T as labs::Config>::Currency::deposit_creating(&caller, 60000000000000000000000u128.saturated_into());
+
+		let old_lab = LabInfo {
+			box_public_key: T::Hashing::hash("0xDb9Af2d1f3ADD2726A132AA7A65Cc9E6fC5761C3".as_bytes()),
+			name: "DeBio Lab".as_bytes().to_vec().try_into().unwrap(),
+			email: "DeBio Email".as_bytes().to_vec().try_into().unwrap(),
+			country: CountryCode::try_from("ID".as_bytes().to_vec()).unwrap(),
+			region: RegionCode::try_from("JK".as_bytes().to_vec()).unwrap(),
+			city: CityCode::try_from("City".as_bytes().to_vec()).unwrap(),
+			address: "DeBio Address".as_bytes().to_vec().try_into().unwrap(),
+			phone_number: "+6281394653625".as_bytes().to_vec().try_into().unwrap(),
+			website: "DeBio Website".as_bytes().to_vec().try_into().unwrap(),
+			latitude: Some("-6.175392".as_bytes().to_vec().try_into().unwrap()),
+			longitude: Some("106.827153".as_bytes().to_vec().try_into().unwrap()),
+			profile_image: Some("DeBio Profile Image uwu".as_bytes().to_vec().try_into().unwrap()),
+		};
+		let _ = Labs::<T>::register_lab(caller_origin.clone(), old_lab);
+
+		let _ = Labs::<T>::stake_lab(caller_origin);
+
+		let customer: T::AccountId = account("customer", 0, 0);
+		let _ = Labs::<T>::propose_slash(
+			RawOrigin::Root.into(),
+			caller.clone(),
+			Perbill::from_percent(10),
+			Some(customer),
+		);
+	}: apply_slash(
+		RawOrigin::Root,
+		caller
+	)
+
+	cancel_slash {
+		let caller: T::AccountId = whitelisted_caller();
+		let caller_origin = <T as frame_system::Config>::RuntimeOrigin::from(RawOrigin::Signed(caller.clone()));
+
+		let _ = <T as labs::Config>::Currency::deposit_creating(&caller, 60000000000000000000000u128.saturated_into());
+
+		let old_lab = LabInfo {
+			box_public_key: T::Hashing::hash("0xDb9Af2d1f3ADD2726A132AA7A65Cc9E6fC5761C3".as_bytes()),
+			name: "DeBio Lab".as_bytes().to_vec().try_into().unwrap(),
+			email: "DeBio Email".as_bytes().to_vec().try_into().unwrap(),
+			country: CountryCode::try_from("ID".as_bytes().to_vec()).unwrap(),
+			region: RegionCode::try_from("JK".as_bytes().to_vec()).unwrap(),
+			city: CityCode::try_from("City".as_bytes().to_vec()).unwrap(),
+			address: "DeBio Address".as_bytes().to_vec().try_into().unwrap(),
+			phone_number: "+6281394653625".as_bytes().to_vec().try_into().unwrap(),
+			website: "DeBio Website".as_bytes().to_vec().try_into().unwrap(),
+			latitude: Some("-6.175392".as_bytes().to_vec().try_into().unwrap()),
+			longitude: Some("106.827153".as_bytes().to_vec().try_into().unwrap()),
+			profile_image: Some("DeBio Profile Image uwu".as_bytes().to_vec().try_into().unwrap()),
+		};
+		let _ = Labs::<T>::register_lab(caller_origin.clone(), old_lab);
+
+		let _ = Labs::<T>::stake_lab(caller_origin);
+
+		let customer: T::AccountId = account("customer", 0, 0);
+		let _ = Labs::<T>::propose_slash(
+			RawOrigin::Root.into(),
+			caller.clone(),
+			Perbill::from_percent(10),
+			Some(customer),
+		);
+	}: cancel_slash(
+		RawOrigin::Root,
+		caller
+	)
 }
@@ -1,17 +1,30 @@
 #![cfg(test)]
 
-use frame_support::{parameter_types, weights::Weight, PalletId};
+use frame_support::{pallet_prelude::MaxEncodedLen, parameter_types, weights::Weight, PalletId};
 use frame_system as system;
 use pallet_balances::AccountData;
-use sp_core::H256;
+use scale_info::TypeInfo;
+use sp_core::{Decode, Encode, RuntimeDebug, H256};
 use sp_runtime::{
-	testing::Header,
-	traits::{BlakeTwo256, IdentityLookup},
+	testing::{Header, UintAuthorityId},
+	traits::{BlakeTwo256, Hash, IdentityLookup, Lazy, Verify},
 };
 
 use primitives_ethereum_address::EthereumAddress;
 use primitives_profile_roles::ProfileRoles;
 
+/// `TestSignature` over the hash of the message, so attestations have a maximum encoded length
+#[derive(Clone, PartialEq, Eq, Encode, Decode, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct TestAttestationSignature(pub u64, pub H256);
+
+impl Verify for TestAttestationSignature {
+	type Signer = UintAuthorityId;
+
+	fn verify<L: Lazy<[u8]>>(&self, mut msg: L, signer: &u64) -> bool {
+		signer == &self.0 && BlakeTwo256::hash(msg.get()) == self.1
+	}
+}
+
 type UncheckedExtrinsic = frame_system::mocking::MockUncheckedExtrinsic<Test>;
 type Block = frame_system::mocking::MockBlock<Test>;
 
@@ -27,7 +40,7 @@ frame_support::construct_runtime!(
 		Balances: pallet_balances,
 		Labs: labs,
 		Services: services,
-		Certifications: certifications,
+		Certifications: credentials::<Instance1>,
 		Orders: orders,
 		GeneticTesting: genetic_testing,
 		Timestamp: pallet_timestamp,
@@ -94,6 +107,14 @@ parameter_types! {
 	pub static ExistentialDeposit: Balance = 0;
 	pub const LabPalletId: PalletId = PalletId(*b"dbio/lab");
 	pub const OrderPalletId: PalletId = PalletId(*b"dbio/ord");
+	pub const RefundGracePeriod: u64 = 1000;
+	pub const MaxExpiredRefundsPerBlock: u32 = 10;
+	pub const MaxBundleItems: u32 = 10;
+	pub const DisputeWindow: u64 = 0;
+	pub const InstallmentInterval: u64 = 1000;
+	pub const MaxInstallments: u32 = 12;
+	pub const MaxOverduePlansPerBlock: u32 = 10;
+	pub const MaxCustodyNoteLength: u32 = 64;
 }
 
 impl pallet_balances::Config for Test {
@@ -150,6 +171,9 @@ impl labs::Config for Test {
 	type UserProfile = UserProfile;
 	type LabWeightInfo = ();
 	type PalletId = LabPalletId;
+	type SlashOrigin = frame_system::EnsureRoot<AccountId>;
+	type RequireVerifiedCertification = frame_support::traits::ConstBool<false>;
+	type MaxLabsPerCity = frame_support::traits::ConstU32<100>;
 }
 
 impl services::Config for Test {
@@ -159,9 +183,11 @@ impl services::Config for Test {
 	type WeightInfo = ();
 }
 
-impl certifications::Config for Test {
+impl credentials::Config<credentials::Instance1> for Test {
 	type RuntimeEvent = RuntimeEvent;
-	type CertificationOwner = Labs;
+	type CredentialInfo = credentials::CertificationInfo;
+	type CredentialOwner = credentials::legacy::LegacyCertificationOwner<Labs>;
+	type CredentialIssuers = ();
 	type WeightInfo = ();
 }
 
@@ -170,6 +196,9 @@ impl genetic_testing::Config for Test {
 	type Orders = Orders;
 	type RandomnessSource = RandomnessCollectiveFlip;
 	type GeneticTestingWeightInfo = ();
+	type MaxCustodyNoteLength = MaxCustodyNoteLength;
+	type AttestationSignature = TestAttestationSignature;
+	type AttestationSigner = UintAuthorityId;
 }
 
 impl orders::Config for Test {
@@ -180,6 +209,14 @@ impl orders::Config for Test {
 	type Assets = Assets;
 	type OrdersWeightInfo = ();
 	type PalletId = OrderPalletId;
+	type RefundGracePeriod = RefundGracePeriod;
+	type MaxExpiredRefundsPerBlock = MaxExpiredRefundsPerBlock;
+	type MaxBundleItems = MaxBundleItems;
+	type DisputeWindow = DisputeWindow;
+	type ArbiterOrigin = frame_system::EnsureRoot<AccountId>;
+	type InstallmentInterval = InstallmentInterval;
+	type MaxInstallments = MaxInstallments;
+	type MaxOverduePlansPerBlock = MaxOverduePlansPerBlock;
 }
 
 impl user_profile::Config for Test {
@@ -1,5 +1,6 @@
 pub use scale_info::TypeInfo;
 
+use frame_support::sp_runtime::Perbill;
 use sp_std::prelude::*;
 
 use primitives_area_code::{CityCode, CountryRegionCode};
@@ -14,6 +15,7 @@ pub trait LabInterface<T: frame_system::Config> {
 	type LabInfo;
 	type Lab;
 	type VerificationStatus: VerificationStatusTrait;
+	type PendingSlash;
 
 	/// Get lab by associated account_id
 	fn lab_by_account_id(account_id: &T::AccountId) -> Option<Self::Lab>;
@@ -65,4 +67,16 @@ pub trait LabInterface<T: frame_system::Config> {
 	) -> Result<(), Self::Error>;
 	/// Delete Lab
 	fn delete_lab(account_id: &T::AccountId) -> Result<Self::Lab, Self::Error>;
+	/// Propose slashing a percentage of a Lab stake
+	fn propose_slash(
+		account_id: &T::AccountId,
+		slash_percent: Perbill,
+		beneficiary: Option<T::AccountId>,
+	) -> Result<Self::PendingSlash, Self::Error>;
+	/// Apply a pending slash, returning the lab, slashed amount and beneficiary
+	fn apply_slash(
+		account_id: &T::AccountId,
+	) -> Result<(Self::Lab, Self::Balance, T::AccountId), Self::Error>;
+	/// Cancel a pending slash
+	fn cancel_slash(account_id: &T::AccountId) -> Result<Self::PendingSlash, Self::Error>;
 }
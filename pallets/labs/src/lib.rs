@@ -15,52 +15,94 @@ pub use crate::interface::LabInterface;
 
 use frame_support::{
 	pallet_prelude::*,
-	sp_runtime::{traits::AccountIdConversion, RuntimeDebug, SaturatedConversion},
+	sp_runtime::{traits::AccountIdConversion, Perbill, RuntimeDebug, SaturatedConversion},
 	traits::{Currency, ExistenceRequirement, StorageVersion},
 	PalletId,
 };
-use primitives_area_code::{CityCode, CountryCode, CountryRegionCode, RegionCode};
+use primitives_area_code::{
+	normalize_area_code, AreaCodeError, CityCode, CountryCode, CountryRegionCode, RegionCode,
+};
+use primitives_geo_location::{Coordinate, GeoHash, MAX_SEARCH_RADIUS};
 use primitives_stake_status::{StakeStatus, StakeStatusTrait};
-use primitives_verification_status::VerificationStatus;
+use primitives_verification_status::{VerificationStatus, VerificationStatusTrait};
 
-use traits_certifications::CertificationOwnerInfo;
+#[cfg(feature = "std")]
+use serde::{Deserialize, Serialize};
+use traits_credentials::legacy::CertificationOwnerInfo;
 use traits_labs::LabsProvider;
 use traits_order::{OrderEventEmitter, OrderStatusUpdater};
-use traits_services::ServiceOwnerInfo;
+use traits_services::{ServiceInfo, ServiceOwnerInfo};
 use traits_user_profile::UserProfileProvider;
 
+/// Longest text field of a lab, e.g. its name, address or profile image url
+pub const MAX_LAB_INFO_LENGTH: u32 = 512;
+/// Most services a lab can own
+pub const MAX_SERVICES_PER_LAB: u32 = 256;
+/// Most certifications a lab can own
+pub const MAX_CERTIFICATIONS_PER_LAB: u32 = 256;
+
+pub type LabText = BoundedVec<u8, ConstU32<MAX_LAB_INFO_LENGTH>>;
+
 // LabInfo Struct
 // Used as parameter of dispatchable calls
 // Until update Rust compiler, clippy will have false positives: https://github.com/rust-lang/rust-clippy/issues/8867
-#[derive(Encode, Decode, Clone, Default, RuntimeDebug, PartialEq, Eq, TypeInfo)]
+#[derive(Encode, Decode, Clone, Default, RuntimeDebug, PartialEq, Eq, TypeInfo, MaxEncodedLen)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
 pub struct LabInfo<Hash>
 where
 	Hash: PartialEq + Eq,
 {
 	pub box_public_key: Hash,
-	pub name: Vec<u8>,
-	pub email: Vec<u8>,
+	pub name: LabText,
+	pub email: LabText,
 	pub country: CountryCode,
 	pub region: RegionCode,
 	pub city: CityCode,
-	pub address: Vec<u8>,
-	pub phone_number: Vec<u8>,
-	pub website: Vec<u8>,
-	pub latitude: Option<Vec<u8>>,
-	pub longitude: Option<Vec<u8>>,
-	pub profile_image: Option<Vec<u8>>,
+	pub address: LabText,
+	pub phone_number: LabText,
+	pub website: LabText,
+	pub latitude: Option<LabText>,
+	pub longitude: Option<LabText>,
+	pub profile_image: Option<LabText>,
+}
+
+impl<Hash> LabInfo<Hash>
+where
+	Hash: PartialEq + Eq,
+{
+	// Validates the area codes against ISO 3166 and upper cases them
+	fn normalize_area_code(mut self) -> Result<Self, AreaCodeError> {
+		let (country, region, city) = normalize_area_code(&self.country, &self.region, &self.city)?;
+
+		self.country = country;
+		self.region = region;
+		self.city = city;
+
+		Ok(self)
+	}
+
+	// A coordinate may be left out, but once given both halves must parse
+	fn has_valid_coordinate(&self) -> bool {
+		match (&self.latitude, &self.longitude) {
+			(None, None) => true,
+			(Some(latitude), Some(longitude)) =>
+				Coordinate::from_bytes(latitude, longitude).is_some(),
+			_ => false,
+		}
+	}
 }
 
 // Lab Struct
 // the fields (excluding account_id and services) come from LabInfo struct
-#[derive(Encode, Decode, Clone, Default, RuntimeDebug, PartialEq, Eq, TypeInfo)]
+#[derive(Encode, Decode, Clone, Default, RuntimeDebug, PartialEq, Eq, TypeInfo, MaxEncodedLen)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
 pub struct Lab<AccountId, Hash, Moment, Balance>
 where
 	Hash: PartialEq + Eq,
 {
 	pub account_id: AccountId,
-	pub services: Vec<Hash>,
-	pub certifications: Vec<Hash>,
+	pub services: BoundedVec<Hash, ConstU32<MAX_SERVICES_PER_LAB>>,
+	pub certifications: BoundedVec<Hash, ConstU32<MAX_CERTIFICATIONS_PER_LAB>>,
 	pub verification_status: VerificationStatus,
 	pub info: LabInfo<Hash>,
 	pub stake_amount: Balance,
@@ -76,8 +118,8 @@ where
 	pub fn new(account_id: AccountId, info: LabInfo<Hash>) -> Self {
 		Self {
 			account_id,
-			services: Vec::<Hash>::new(),
-			certifications: Vec::<Hash>::new(),
+			services: BoundedVec::default(),
+			certifications: BoundedVec::default(),
 			verification_status: VerificationStatus::default(),
 			info,
 			unstake_at: Moment::default(),
@@ -108,12 +150,21 @@ where
 		CountryRegionCode::build_country_region_code(self.get_country(), self.get_region())
 	}
 
+	// Returns the parsed latitude and longitude, None if either is missing or malformed
+	fn get_coordinate(&self) -> Option<Coordinate> {
+		match (&self.info.latitude, &self.info.longitude) {
+			(Some(latitude), Some(longitude)) => Coordinate::from_bytes(latitude, longitude),
+			_ => None,
+		}
+	}
+
 	pub fn get_account_id(&self) -> &AccountId {
 		&self.account_id
 	}
 
-	pub fn add_service(&mut self, service_id: Hash) {
-		self.services.push(service_id);
+	/// Returns the service id back when the lab already owns MAX_SERVICES_PER_LAB services
+	pub fn add_service(&mut self, service_id: Hash) -> Result<(), Hash> {
+		self.services.try_push(service_id)
 	}
 
 	pub fn remove_service(&mut self, service_id: Hash) {
@@ -122,8 +173,10 @@ where
 		}
 	}
 
-	pub fn add_certification(&mut self, certification_id: Hash) {
-		self.certifications.push(certification_id);
+	/// Returns the certification id back when the lab already owns MAX_CERTIFICATIONS_PER_LAB
+	/// certifications
+	pub fn add_certification(&mut self, certification_id: Hash) -> Result<(), Hash> {
+		self.certifications.try_push(certification_id)
 	}
 
 	pub fn remove_certification(&mut self, certification_id: Hash) {
@@ -155,8 +208,28 @@ where
 	}
 }
 
+// PendingSlash Struct
+// Recorded against a lab until the slash is applied or cancelled
+#[derive(Encode, Decode, Clone, RuntimeDebug, PartialEq, Eq, TypeInfo, MaxEncodedLen)]
+pub struct PendingSlash<AccountId, Moment> {
+	pub slash_percent: Perbill,
+	// Harmed customer, or the treasury when None
+	pub beneficiary: Option<AccountId>,
+	pub proposed_at: Moment,
+}
+
+impl<AccountId, Moment> PendingSlash<AccountId, Moment> {
+	pub fn new(
+		slash_percent: Perbill,
+		beneficiary: Option<AccountId>,
+		proposed_at: Moment,
+	) -> Self {
+		Self { slash_percent, beneficiary, proposed_at }
+	}
+}
+
 /// The current storage version.
-const STORAGE_VERSION: StorageVersion = StorageVersion::new(3);
+const STORAGE_VERSION: StorageVersion = StorageVersion::new(6);
 
 #[frame_support::pallet]
 pub mod pallet {
@@ -165,7 +238,7 @@ pub mod pallet {
 	use frame_support::{dispatch::DispatchResultWithPostInfo, traits::Currency};
 	use frame_system::pallet_prelude::*;
 	pub use sp_std::prelude::*;
-	pub use traits_certifications::{CertificationOwner, CertificationsProvider};
+	pub use traits_credentials::legacy::{CertificationOwner, CertificationsProvider};
 	pub use traits_services::{ServiceOwner, ServicesProvider};
 
 	#[pallet::config]
@@ -202,13 +275,20 @@ pub mod pallet {
 		/// Currency type for this pallet.
 		#[pallet::constant]
 		type PalletId: Get<PalletId>;
+		/// Origin allowed to propose, apply and cancel lab stake slashes.
+		type SlashOrigin: EnsureOrigin<<Self as frame_system::Config>::RuntimeOrigin>;
+		/// Whether verifying a lab requires at least one issuer-verified certification.
+		#[pallet::constant]
+		type RequireVerifiedCertification: Get<bool>;
+		/// Maximum number of labs indexed per country-region and city.
+		#[pallet::constant]
+		type MaxLabsPerCity: Get<u32>;
 	}
 
 	// ----- This is template code, every pallet needs this ---
 	#[pallet::pallet]
 	#[pallet::storage_version(STORAGE_VERSION)]
 	#[pallet::generate_store(pub(super) trait Store)]
-	#[pallet::without_storage_info]
 	pub struct Pallet<T>(_);
 
 	#[pallet::hooks]
@@ -227,6 +307,8 @@ pub mod pallet {
 	pub type CurrencyOf<T> = <T as self::Config>::Currency;
 	pub type BalanceOf<T> = <CurrencyOf<T> as Currency<AccountIdOf<T>>>::Balance;
 	pub type UserProfileOf<T> = <T as self::Config>::UserProfile;
+	pub type PendingSlashOf<T> = PendingSlash<AccountIdOf<T>, MomentOf<T>>;
+	pub type LabIdsOf<T> = BoundedVec<AccountIdOf<T>, <T as Config>::MaxLabsPerCity>;
 
 	// ----- Storage ------------------
 	/// Get Lab by account id
@@ -236,7 +318,7 @@ pub mod pallet {
 	pub type Labs<T> = StorageMap<_, Blake2_128Concat, AccountIdOf<T>, LabOf<T>>;
 
 	/// Get LabId by Country-Region, City
-	/// (CountryRegionCode, CityCode) => Vec<AccountId>
+	/// (CountryRegionCode, CityCode) => BoundedVec<AccountId>
 	#[pallet::storage]
 	#[pallet::getter(fn labs_by_country_region_city)]
 	pub type LabsByCountryRegionCity<T> = StorageDoubleMap<
@@ -245,7 +327,7 @@ pub mod pallet {
 		CountryRegionCode,
 		Blake2_128Concat,
 		CityCode,
-		Vec<AccountIdOf<T>>,
+		LabIdsOf<T>,
 	>;
 
 	/// Get total lab count
@@ -261,6 +343,18 @@ pub mod pallet {
 	pub type LabCountByCountryRegionCity<T> =
 		StorageDoubleMap<_, Blake2_128Concat, CountryRegionCode, Blake2_128Concat, CityCode, u64>;
 
+	/// Get Lab coordinate by account id
+	/// AccountId => Coordinate
+	#[pallet::storage]
+	#[pallet::getter(fn lab_coordinate_by_account_id)]
+	pub type LabCoordinates<T> = StorageMap<_, Blake2_128Concat, AccountIdOf<T>, Coordinate>;
+
+	/// Get LabIds by GeoHash
+	/// (GeoHash, AccountId) => ()
+	#[pallet::storage]
+	pub type LabsByGeoHash<T> =
+		StorageDoubleMap<_, Blake2_128Concat, GeoHash, Blake2_128Concat, AccountIdOf<T>, ()>;
+
 	#[pallet::storage]
 	#[pallet::getter(fn admin_key)]
 	pub type LabVerifierKey<T: Config> = StorageValue<_, T::AccountId, OptionQuery>;
@@ -280,6 +374,16 @@ pub mod pallet {
 	#[pallet::storage]
 	#[pallet::getter(fn unstake_time)]
 	pub type UnstakeTime<T> = StorageValue<_, MomentOf<T>>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn treasury_key)]
+	pub type TreasuryKey<T: Config> = StorageValue<_, T::AccountId, OptionQuery>;
+
+	/// Get pending slash by lab account id
+	/// AccountId => PendingSlash
+	#[pallet::storage]
+	#[pallet::getter(fn pending_slash_by_account_id)]
+	pub type PendingSlashes<T> = StorageMap<_, Blake2_128Concat, AccountIdOf<T>, PendingSlashOf<T>>;
 	// -----------------------------------------
 
 	// ----- Genesis Configs ------------------
@@ -342,6 +446,18 @@ pub mod pallet {
 		/// Update Lab admin key
 		/// parameters. [who]
 		UpdateLabAdminKeySuccessful(AccountIdOf<T>),
+		/// Update Lab treasury key
+		/// parameters. [who]
+		UpdateLabTreasuryKeySuccessful(AccountIdOf<T>),
+		/// Lab stake slash proposed
+		/// parameters. [PendingSlash, lab]
+		LabSlashProposed(PendingSlashOf<T>, AccountIdOf<T>),
+		/// Lab stake slash cancelled
+		/// parameters. [PendingSlash, lab]
+		LabSlashCancelled(PendingSlashOf<T>, AccountIdOf<T>),
+		/// Lab stake slashed
+		/// parameters. [Lab, slashed_amount, beneficiary]
+		LabSlashed(LabOf<T>, BalanceOf<T>, AccountIdOf<T>),
 	}
 
 	// Errors inform users that something went wrong.
@@ -369,6 +485,18 @@ pub mod pallet {
 		LabIsNotWaitingForUnstake,
 		// Lab cannot unstake now
 		LabCannotUnstakeBeforeUnstakeTime,
+		// Lab has no stake to slash
+		LabHasNoStake,
+		// Lab already has a slash pending
+		LabSlashAlreadyPending,
+		// Lab has no slash pending
+		LabSlashNotPending,
+		// Lab cannot unstake while a slash is pending
+		LabHasPendingSlash,
+		// Treasury key has not been set
+		TreasuryKeyNotFound,
+		// Lab has no certification verified by a credential issuer
+		NoVerifiedCertification,
 		// Dispatch Errors
 		Module,
 		Other,
@@ -381,6 +509,16 @@ pub mod pallet {
 		Arithmetic,
 		// Setting profile role failed,
 		FailedToSetProfileRole,
+		/// Country is not an ISO 3166-1 alpha-2 code
+		InvalidCountryCode,
+		/// Region is not an ISO 3166-2 subdivision of the country
+		InvalidRegionCode,
+		/// City code is empty, too long or not printable
+		InvalidCityCode,
+		/// Latitude or longitude is given but is not a valid coordinate
+		InvalidCoordinate,
+		/// The city already indexes MaxLabsPerCity labs
+		TooManyLabsInCity,
 	}
 
 	#[pallet::call]
@@ -562,9 +700,76 @@ pub mod pallet {
 
 			Ok(Pays::No.into())
 		}
+
+		#[pallet::weight(0)]
+		pub fn sudo_update_treasury_key(
+			origin: OriginFor<T>,
+			account_id: T::AccountId,
+		) -> DispatchResultWithPostInfo {
+			ensure_root(origin)?;
+
+			TreasuryKey::<T>::put(&account_id);
+
+			Self::deposit_event(Event::UpdateLabTreasuryKeySuccessful(account_id));
+
+			Ok(Pays::No.into())
+		}
+
+		#[pallet::weight(T::LabWeightInfo::propose_slash())]
+		pub fn propose_slash(
+			origin: OriginFor<T>,
+			account_id: T::AccountId,
+			slash_percent: Perbill,
+			beneficiary: Option<T::AccountId>,
+		) -> DispatchResultWithPostInfo {
+			T::SlashOrigin::ensure_origin(origin)?;
+
+			match <Self as LabInterface<T>>::propose_slash(&account_id, slash_percent, beneficiary)
+			{
+				Ok(pending_slash) => {
+					Self::deposit_event(Event::LabSlashProposed(pending_slash, account_id));
+					Ok(().into())
+				},
+				Err(error) => Err(error.into()),
+			}
+		}
+
+		#[pallet::weight(T::LabWeightInfo::apply_slash())]
+		pub fn apply_slash(
+			origin: OriginFor<T>,
+			account_id: T::AccountId,
+		) -> DispatchResultWithPostInfo {
+			T::SlashOrigin::ensure_origin(origin)?;
+
+			match <Self as LabInterface<T>>::apply_slash(&account_id) {
+				Ok((lab, slashed_amount, beneficiary)) => {
+					Self::deposit_event(Event::LabSlashed(lab, slashed_amount, beneficiary));
+					Ok(().into())
+				},
+				Err(error) => Err(error.into()),
+			}
+		}
+
+		#[pallet::weight(T::LabWeightInfo::cancel_slash())]
+		pub fn cancel_slash(
+			origin: OriginFor<T>,
+			account_id: T::AccountId,
+		) -> DispatchResultWithPostInfo {
+			T::SlashOrigin::ensure_origin(origin)?;
+
+			match <Self as LabInterface<T>>::cancel_slash(&account_id) {
+				Ok(pending_slash) => {
+					Self::deposit_event(Event::LabSlashCancelled(pending_slash, account_id));
+					Ok(().into())
+				},
+				Err(error) => Err(error.into()),
+			}
+		}
 	}
 }
 
+primitives_area_code::impl_from_area_code_error!(Error);
+
 impl<T: Config> LabInterface<T> for Pallet<T> {
 	type Error = Error<T>;
 	type Moment = MomentOf<T>;
@@ -572,6 +777,7 @@ impl<T: Config> LabInterface<T> for Pallet<T> {
 	type LabInfo = LabInfo<HashOf<T>>;
 	type Lab = LabOf<T>;
 	type VerificationStatus = VerificationStatus;
+	type PendingSlash = PendingSlashOf<T>;
 
 	fn create_lab(
 		account_id: &T::AccountId,
@@ -580,10 +786,15 @@ impl<T: Config> LabInterface<T> for Pallet<T> {
 		if Labs::<T>::contains_key(account_id) {
 			return Err(Error::<T>::LabAlreadyRegistered)
 		}
-		let lab = Lab::new(account_id.clone(), lab_info.clone());
+		let lab_info = lab_info.clone().normalize_area_code()?;
+		if !lab_info.has_valid_coordinate() {
+			return Err(Error::<T>::InvalidCoordinate)
+		}
+		let lab = Lab::new(account_id.clone(), lab_info);
 		// Insert to Storage
 		Labs::<T>::insert(account_id, &lab);
-		Self::insert_lab_id_to_location(&lab);
+		Self::insert_lab_id_to_location(&lab)?;
+		Self::insert_lab_id_to_geohash(&lab);
 
 		// Increment Count
 		Self::add_lab_count();
@@ -601,6 +812,10 @@ impl<T: Config> LabInterface<T> for Pallet<T> {
 			return Err(Error::<T>::LabDoesNotExist)
 		}
 		let mut lab = lab.unwrap();
+		let lab_info = lab_info.clone().normalize_area_code()?;
+		if !lab_info.has_valid_coordinate() {
+			return Err(Error::<T>::InvalidCoordinate)
+		}
 		let mut is_location_changed = false;
 
 		// If location is updated, remove the lab from the old location
@@ -616,14 +831,18 @@ impl<T: Config> LabInterface<T> for Pallet<T> {
 			is_location_changed = true;
 		}
 
-		lab.update_info(lab_info.clone());
+		lab.update_info(lab_info);
 
 		if is_location_changed {
 			// insert new location
-			Self::insert_lab_id_to_location(&lab);
+			Self::insert_lab_id_to_location(&lab)?;
 			Self::add_lab_count_by_location(&lab);
 		}
 
+		// Coordinates may change within the same city
+		Self::remove_lab_id_from_geohash(account_id);
+		Self::insert_lab_id_to_geohash(&lab);
+
 		Labs::<T>::insert(account_id, &lab);
 
 		Ok(lab)
@@ -643,6 +862,14 @@ impl<T: Config> LabInterface<T> for Pallet<T> {
 			return Err(Error::<T>::LabDoesNotExist)
 		}
 		let mut lab = lab.unwrap();
+
+		if status.is_verified() &&
+			T::RequireVerifiedCertification::get() &&
+			!lab.certifications.iter().any(T::Certifications::is_certification_verified)
+		{
+			return Err(Error::<T>::NoVerifiedCertification)
+		}
+
 		lab.verification_status = status.clone();
 		Labs::<T>::insert(account_id, &lab);
 		Ok(lab)
@@ -653,6 +880,10 @@ impl<T: Config> LabInterface<T> for Pallet<T> {
 		if lab.is_none() {
 			return Err(Error::<T>::LabDoesNotExist)
 		}
+		if PendingSlashes::<T>::contains_key(account_id) {
+			return Err(Error::<T>::LabHasPendingSlash)
+		}
+
 		let lab = lab.unwrap();
 		// Delete lab's services
 		for service_id in &lab.services {
@@ -664,6 +895,7 @@ impl<T: Config> LabInterface<T> for Pallet<T> {
 		}
 		Self::remove_lab_id_from_location(&lab);
 		Self::sub_lab_count_by_location(&lab);
+		Self::remove_lab_id_from_geohash(&lab.account_id);
 		Labs::<T>::remove(&lab.account_id);
 		Self::sub_lab_count();
 
@@ -736,6 +968,10 @@ impl<T: Config> LabInterface<T> for Pallet<T> {
 			return Err(Error::<T>::LabHasPendingOrders)
 		}
 
+		if PendingSlashes::<T>::contains_key(account_id) {
+			return Err(Error::<T>::LabHasPendingSlash)
+		}
+
 		let now = pallet_timestamp::Pallet::<T>::get();
 		lab.stake_status = StakeStatus::WaitingForUnstaked;
 		lab.unstake_at = now;
@@ -768,6 +1004,10 @@ impl<T: Config> LabInterface<T> for Pallet<T> {
 			return Err(Error::<T>::LabCannotUnstakeBeforeUnstakeTime)
 		}
 
+		if PendingSlashes::<T>::contains_key(account_id) {
+			return Err(Error::<T>::LabHasPendingSlash)
+		}
+
 		if !Self::is_pallet_balance_sufficient_for_refund(lab.stake_amount) {
 			return Err(Error::<T>::InsufficientPalletFunds)
 		}
@@ -844,11 +1084,97 @@ impl<T: Config> LabInterface<T> for Pallet<T> {
 		Ok(())
 	}
 
+	fn propose_slash(
+		account_id: &T::AccountId,
+		slash_percent: Perbill,
+		beneficiary: Option<T::AccountId>,
+	) -> Result<Self::PendingSlash, Self::Error> {
+		let lab = Labs::<T>::get(account_id).ok_or(Error::<T>::LabDoesNotExist)?;
+
+		if lab.stake_amount == 0u128.saturated_into() {
+			return Err(Error::<T>::LabHasNoStake)
+		}
+
+		if PendingSlashes::<T>::contains_key(account_id) {
+			return Err(Error::<T>::LabSlashAlreadyPending)
+		}
+
+		let now = pallet_timestamp::Pallet::<T>::get();
+		let pending_slash = PendingSlash::new(slash_percent, beneficiary, now);
+
+		PendingSlashes::<T>::insert(account_id, &pending_slash);
+
+		Ok(pending_slash)
+	}
+
+	fn apply_slash(
+		account_id: &T::AccountId,
+	) -> Result<(Self::Lab, Self::Balance, T::AccountId), Self::Error> {
+		let mut lab = Labs::<T>::get(account_id).ok_or(Error::<T>::LabDoesNotExist)?;
+		let pending_slash =
+			PendingSlashes::<T>::get(account_id).ok_or(Error::<T>::LabSlashNotPending)?;
+
+		let beneficiary = match pending_slash.beneficiary {
+			Some(beneficiary) => beneficiary,
+			None => TreasuryKey::<T>::get().ok_or(Error::<T>::TreasuryKeyNotFound)?,
+		};
+
+		let slashed_amount = pending_slash.slash_percent.mul_floor(lab.stake_amount);
+
+		if !Self::is_pallet_balance_sufficient_for_refund(slashed_amount) {
+			return Err(Error::<T>::InsufficientPalletFunds)
+		}
+
+		match CurrencyOf::<T>::transfer(
+			&Self::account_id(),
+			&beneficiary,
+			slashed_amount,
+			ExistenceRequirement::AllowDeath,
+		) {
+			Ok(_) => (),
+			Err(dispatch) => match dispatch {
+				sp_runtime::DispatchError::Other(_) => return Err(Error::<T>::Other),
+				sp_runtime::DispatchError::CannotLookup => return Err(Error::<T>::CannotLookup),
+				sp_runtime::DispatchError::BadOrigin => return Err(Error::<T>::BadOrigin),
+				sp_runtime::DispatchError::TooManyConsumers =>
+					return Err(Error::<T>::TooManyConsumers),
+				sp_runtime::DispatchError::ConsumerRemaining =>
+					return Err(Error::<T>::ConsumerRemaining),
+				sp_runtime::DispatchError::NoProviders => return Err(Error::<T>::NoProviders),
+				sp_runtime::DispatchError::Token(_) => return Err(Error::<T>::Token),
+				sp_runtime::DispatchError::Arithmetic(_) => return Err(Error::<T>::Arithmetic),
+				sp_runtime::DispatchError::Module(_) => return Err(Error::<T>::Arithmetic),
+				sp_runtime::DispatchError::Transactional(_) => return Err(Error::<T>::Arithmetic),
+			},
+		}
+
+		lab.stake_amount -= slashed_amount;
+		if lab.stake_amount == 0u128.saturated_into() {
+			lab.stake_status = StakeStatus::Unstaked;
+			lab.unstake_at = MomentOf::<T>::default();
+			lab.retrieve_unstake_at = MomentOf::<T>::default();
+		}
+		if lab.verification_status.is_verified() {
+			lab.verification_status = VerificationStatus::Revoked;
+		}
+
+		Labs::<T>::insert(account_id, &lab);
+		PendingSlashes::<T>::remove(account_id);
+		Self::set_total_staked_amount();
+
+		Ok((lab, slashed_amount, beneficiary))
+	}
+
+	fn cancel_slash(account_id: &T::AccountId) -> Result<Self::PendingSlash, Self::Error> {
+		PendingSlashes::<T>::take(account_id).ok_or(Error::<T>::LabSlashNotPending)
+	}
+
 	fn labs_by_country_region_city(
 		country_region_code: &CountryRegionCode,
 		city_code: &CityCode,
 	) -> Option<Vec<T::AccountId>> {
 		Self::labs_by_country_region_city(country_region_code, city_code)
+			.map(BoundedVec::into_inner)
 	}
 
 	fn lab_by_account_id(account_id: &T::AccountId) -> Option<Self::Lab> {
@@ -857,21 +1183,18 @@ impl<T: Config> LabInterface<T> for Pallet<T> {
 }
 
 impl<T: Config> Pallet<T> {
-	pub fn insert_lab_id_to_location(lab: &LabOf<T>) {
+	pub fn insert_lab_id_to_location(lab: &LabOf<T>) -> Result<(), Error<T>> {
 		let country_region_code = lab.get_country_region();
 		let city_code = lab.get_city();
 		let lab_account_id = lab.get_account_id();
 
-		match LabsByCountryRegionCity::<T>::get(&country_region_code, city_code) {
-			None => {
-				let labs = vec![lab_account_id.clone()];
-				LabsByCountryRegionCity::<T>::insert(&country_region_code, city_code, labs);
-			},
-			Some(mut labs) => {
-				labs.push(lab_account_id.clone());
-				LabsByCountryRegionCity::<T>::insert(&country_region_code, city_code, labs);
-			},
-		}
+		let mut labs =
+			LabsByCountryRegionCity::<T>::get(&country_region_code, city_code).unwrap_or_default();
+		labs.try_push(lab_account_id.clone())
+			.map_err(|_| Error::<T>::TooManyLabsInCity)?;
+		LabsByCountryRegionCity::<T>::insert(&country_region_code, city_code, labs);
+
+		Ok(())
 	}
 
 	pub fn remove_lab_id_from_location(lab: &LabOf<T>) {
@@ -888,6 +1211,52 @@ impl<T: Config> Pallet<T> {
 		LabsByCountryRegionCity::<T>::insert(&country_region_code, city_code, labs_by_location);
 	}
 
+	pub fn insert_lab_id_to_geohash(lab: &LabOf<T>) {
+		if let Some(coordinate) = lab.get_coordinate() {
+			let lab_account_id = lab.get_account_id();
+
+			LabCoordinates::<T>::insert(lab_account_id, coordinate);
+			LabsByGeoHash::<T>::insert(coordinate.geohash(), lab_account_id, ());
+		}
+	}
+
+	pub fn remove_lab_id_from_geohash(account_id: &AccountIdOf<T>) {
+		if let Some(coordinate) = LabCoordinates::<T>::take(account_id) {
+			LabsByGeoHash::<T>::remove(coordinate.geohash(), account_id);
+		}
+	}
+
+	/// Verified labs within `radius` meters of `center`, optionally only those offering a
+	/// service of `category`. The radius is capped at MAX_SEARCH_RADIUS.
+	pub fn get_labs_within_radius(
+		center: &Coordinate,
+		radius: u32,
+		category: Option<Vec<u8>>,
+	) -> Vec<LabOf<T>> {
+		let radius = radius.min(MAX_SEARCH_RADIUS);
+
+		center
+			.covering_geohashes(radius)
+			.into_iter()
+			.flat_map(|geohash| LabsByGeoHash::<T>::iter_key_prefix(geohash))
+			.filter(|account_id| match LabCoordinates::<T>::get(account_id) {
+				Some(coordinate) => center.is_within(&coordinate, radius),
+				None => false,
+			})
+			.filter_map(Labs::<T>::get)
+			.filter(|lab| lab.verification_status.is_verified())
+			.filter(|lab| match &category {
+				Some(category) => lab.services.iter().any(|service_id| {
+					match T::Services::service_by_id(service_id) {
+						Some(service) => service.get_category() == category,
+						None => false,
+					}
+				}),
+				None => true,
+			})
+			.collect()
+	}
+
 	// Add lab count
 	pub fn add_lab_count() {
 		let lab_count = <LabCount<T>>::get().unwrap_or(0);
@@ -1002,7 +1371,10 @@ impl<T: Config> ServiceOwner<T> for Pallet<T> {
 
 	/// User can create service if he/she is a lab and has set ethereum address
 	fn can_create_service(user_id: &T::AccountId) -> bool {
-		Labs::<T>::contains_key(user_id)
+		match Labs::<T>::get(user_id) {
+			Some(lab) => lab.services.len() < MAX_SERVICES_PER_LAB as usize,
+			None => false,
+		}
 	}
 
 	fn get_owner(id: &T::AccountId) -> Option<Self::Owner> {
@@ -1014,7 +1386,8 @@ impl<T: Config> ServiceOwner<T> for Pallet<T> {
 			match lab {
 				None => (), // If lab does not exist, do nothing
 				Some(lab) => {
-					lab.add_service(*service_id);
+					// can_create_service rejects labs without room for another service
+					let _ = lab.add_service(*service_id);
 				},
 			}
 		});
@@ -1035,7 +1408,10 @@ impl<T: Config> CertificationOwner<T> for Pallet<T> {
 
 	/// User can create certification if he/she is a lab
 	fn can_create_certification(user_id: &T::AccountId) -> bool {
-		Labs::<T>::contains_key(user_id)
+		match Labs::<T>::get(user_id) {
+			Some(lab) => lab.certifications.len() < MAX_CERTIFICATIONS_PER_LAB as usize,
+			None => false,
+		}
 	}
 
 	fn get_owner(id: &T::AccountId) -> Option<Self::Owner> {
@@ -1047,7 +1423,8 @@ impl<T: Config> CertificationOwner<T> for Pallet<T> {
 			match lab {
 				None => (), // If lab does not exist, do nothing
 				Some(lab) => {
-					lab.add_certification(*certification_id);
+					// can_create_certification rejects labs without room for another certification
+					let _ = lab.add_certification(*certification_id);
 				},
 			}
 		});
@@ -1,8 +1,16 @@
 use crate::{
-	AccountIdOf, BalanceOf, Config, HashOf, Lab, LabInfo, Labs, MomentOf, Pallet, PalletAccount,
-	Vec, Weight,
+	AccountIdOf, BalanceOf, Config, HashOf, Lab, LabCountByCountryRegionCity, LabInfo, LabOf, Labs,
+	LabsByCountryRegionCity, MomentOf, Pallet, PalletAccount, Vec, Weight,
+};
+use frame_support::{
+	log,
+	pallet_prelude::{Decode, Encode},
+	traits::{ConstU32, Get},
+	BoundedVec,
+};
+use primitives_area_code::{
+	upper_case_area_code, CityCode, CountryCode, RegionCode, MAX_AREA_CODE_LENGTH,
 };
-use frame_support::{pallet_prelude::Decode, traits::Get};
 use primitives_stake_status::StakeStatus;
 use primitives_verification_status::VerificationStatus;
 
@@ -24,22 +32,97 @@ pub fn migrate<T: Config>() -> Weight {
 		StorageVersion::new(3).put::<Pallet<T>>();
 	}
 
+	version = StorageVersion::get::<Pallet<T>>();
+
+	if version == 3 {
+		weight = weight.saturating_add(v4::migrate::<T>());
+		StorageVersion::new(4).put::<Pallet<T>>();
+	}
+
+	version = StorageVersion::get::<Pallet<T>>();
+
+	if version == 4 {
+		weight = weight.saturating_add(v5::migrate::<T>());
+		StorageVersion::new(5).put::<Pallet<T>>();
+	}
+
+	version = StorageVersion::get::<Pallet<T>>();
+
+	if version == 5 {
+		weight = weight.saturating_add(v6::migrate::<T>());
+		StorageVersion::new(6).put::<Pallet<T>>();
+	}
+
 	weight
 }
 
+/// Lab info fields before they were bounded
+#[derive(Encode, Decode)]
+pub struct UnboundedLabInfo<Hash> {
+	pub box_public_key: Hash,
+	pub name: Vec<u8>,
+	pub email: Vec<u8>,
+	pub country: CountryCode,
+	pub region: Vec<u8>,
+	pub city: Vec<u8>,
+	pub address: Vec<u8>,
+	pub phone_number: Vec<u8>,
+	pub website: Vec<u8>,
+	pub latitude: Option<Vec<u8>>,
+	pub longitude: Option<Vec<u8>>,
+	pub profile_image: Option<Vec<u8>>,
+}
+
+/// Truncates `value` to the bound and logs the entry when it did not fit.
+fn bounded<I, S: Get<u32>>(value: Vec<I>, entry: &str) -> BoundedVec<I, S> {
+	if value.len() > S::get() as usize {
+		log::warn!(
+			target: "runtime::labs",
+			"{} exceeds its bound of {}, truncated from {} items",
+			entry,
+			S::get(),
+			value.len(),
+		);
+	}
+
+	BoundedVec::truncate_from(value)
+}
+
+/// Region and city codes were unbounded before, legacy codes over the bound are truncated
+/// and logged like any other field.
+fn bounded_area_code(value: Vec<u8>, entry: &str) -> Vec<u8> {
+	bounded::<u8, ConstU32<MAX_AREA_CODE_LENGTH>>(value, entry).into_inner()
+}
+
+fn bounded_lab_info<Hash: PartialEq + Eq>(info: UnboundedLabInfo<Hash>) -> LabInfo<Hash> {
+	LabInfo {
+		box_public_key: info.box_public_key,
+		name: bounded(info.name, "lab name"),
+		email: bounded(info.email, "lab email"),
+		country: info.country,
+		region: RegionCode::from_vec(bounded_area_code(info.region, "lab region")),
+		city: CityCode::from_vec(bounded_area_code(info.city, "lab city")),
+		address: bounded(info.address, "lab address"),
+		phone_number: bounded(info.phone_number, "lab phone number"),
+		website: bounded(info.website, "lab website"),
+		latitude: info.latitude.map(|latitude| bounded(latitude, "lab latitude")),
+		longitude: info.longitude.map(|longitude| bounded(longitude, "lab longitude")),
+		profile_image: info
+			.profile_image
+			.map(|profile_image| bounded(profile_image, "lab profile image")),
+	}
+}
+
 mod v2 {
 	use super::*;
 
 	#[derive(Decode)]
-	pub struct OldLab<AccountId, Hash>
-	where
-		Hash: PartialEq + Eq,
-	{
+	pub struct OldLab<AccountId, Hash> {
 		pub account_id: AccountId,
 		pub services: Vec<Hash>,
 		pub certifications: Vec<Hash>,
 		pub verification_status: VerificationStatus,
-		pub info: LabInfo<Hash>,
+		pub info: UnboundedLabInfo<Hash>,
 	}
 
 	pub fn migrate<T: Config>() -> Weight {
@@ -49,9 +132,9 @@ mod v2 {
 			weight = weight.saturating_add(T::DbWeight::get().reads_writes(1, 1));
 			Some(Lab {
 				account_id: old.account_id,
-				services: old.services,
-				certifications: old.certifications,
-				info: old.info,
+				services: bounded(old.services, "lab services"),
+				certifications: bounded(old.certifications, "lab certifications"),
+				info: bounded_lab_info(old.info),
 				verification_status: old.verification_status,
 				stake_amount: BalanceOf::<T>::default(),
 				stake_status: StakeStatus::default(),
@@ -73,3 +156,121 @@ mod v3 {
 		T::DbWeight::get().writes(1)
 	}
 }
+
+mod v4 {
+	use super::*;
+
+	pub fn migrate<T: Config>() -> Weight {
+		let mut weight = T::DbWeight::get().writes(1);
+
+		for lab in <Labs<T>>::iter_values() {
+			<Pallet<T>>::insert_lab_id_to_geohash(&lab);
+			weight = weight.saturating_add(T::DbWeight::get().reads_writes(1, 2));
+		}
+
+		weight
+	}
+}
+
+mod v5 {
+	use super::*;
+
+	#[derive(Decode)]
+	pub struct OldLab<AccountId, Hash, Moment, Balance> {
+		pub account_id: AccountId,
+		pub services: Vec<Hash>,
+		pub certifications: Vec<Hash>,
+		pub verification_status: VerificationStatus,
+		pub info: UnboundedLabInfo<Hash>,
+		pub stake_amount: Balance,
+		pub stake_status: StakeStatus,
+		pub unstake_at: Moment,
+		pub retrieve_unstake_at: Moment,
+	}
+
+	pub type OldLabOf<T> = OldLab<AccountIdOf<T>, HashOf<T>, MomentOf<T>, BalanceOf<T>>;
+
+	/// Lab info, service and certification lists and the location index become bounded. Legacy
+	/// values that exceed a bound are truncated and logged.
+	pub fn migrate<T: Config>() -> Weight {
+		let mut weight = T::DbWeight::get().writes(1);
+
+		<Labs<T>>::translate(|_key, old: OldLabOf<T>| {
+			weight = weight.saturating_add(T::DbWeight::get().reads_writes(1, 1));
+			Some(Lab {
+				account_id: old.account_id,
+				services: bounded(old.services, "lab services"),
+				certifications: bounded(old.certifications, "lab certifications"),
+				verification_status: old.verification_status,
+				info: bounded_lab_info(old.info),
+				stake_amount: old.stake_amount,
+				stake_status: old.stake_status,
+				unstake_at: old.unstake_at,
+				retrieve_unstake_at: old.retrieve_unstake_at,
+			})
+		});
+
+		LabsByCountryRegionCity::<T>::translate(|_, _, lab_ids: Vec<AccountIdOf<T>>| {
+			weight = weight.saturating_add(T::DbWeight::get().reads_writes(1, 1));
+			Some(bounded(lab_ids, "labs by country region city"))
+		});
+
+		weight
+	}
+}
+
+mod v6 {
+	use super::*;
+
+	/// Validates and upper cases an area code stored before area codes were validated. Codes
+	/// which are not ISO 3166 are only upper cased and logged.
+	fn normalized_lab_info<Hash: Clone + PartialEq + Eq>(info: LabInfo<Hash>) -> LabInfo<Hash> {
+		match info.clone().normalize_area_code() {
+			Ok(info) => info,
+			Err(error) => {
+				log::warn!(
+					target: "runtime::labs",
+					"lab area code {:?} {:?} {:?} is not ISO 3166 ({:?}), only upper cased",
+					info.country,
+					info.region,
+					info.city,
+					error,
+				);
+
+				let (country, region, city) =
+					upper_case_area_code(&info.country, &info.region, &info.city);
+				LabInfo { country, region, city, ..info }
+			},
+		}
+	}
+
+	/// Lab area codes are normalized, and the location index and counts are rebuilt so labs
+	/// registered under differently cased codes are merged into one key.
+	pub fn migrate<T: Config>() -> Weight {
+		let mut weight = T::DbWeight::get().writes(1);
+
+		<Labs<T>>::translate(|_key, mut lab: LabOf<T>| {
+			weight = weight.saturating_add(T::DbWeight::get().reads_writes(1, 1));
+			lab.info = normalized_lab_info(lab.info);
+			Some(lab)
+		});
+
+		let removed = LabsByCountryRegionCity::<T>::clear(u32::MAX, None).unique +
+			LabCountByCountryRegionCity::<T>::clear(u32::MAX, None).unique;
+		weight = weight.saturating_add(T::DbWeight::get().writes(removed.into()));
+
+		for lab in <Labs<T>>::iter_values() {
+			if <Pallet<T>>::insert_lab_id_to_location(&lab).is_err() {
+				log::warn!(
+					target: "runtime::labs",
+					"lab {:?} exceeds MaxLabsPerCity once area codes are merged, left out of the location index",
+					lab.account_id,
+				);
+			}
+			<Pallet<T>>::add_lab_count_by_location(&lab);
+			weight = weight.saturating_add(T::DbWeight::get().reads_writes(3, 2));
+		}
+
+		weight
+	}
+}
@@ -38,6 +38,9 @@ pub trait WeightInfo {
 	fn update_minimum_stake_amount() -> Weight;
 	fn update_unstake_time() -> Weight;
 	fn update_admin_key() -> Weight;
+	fn propose_slash() -> Weight;
+	fn apply_slash() -> Weight;
+	fn cancel_slash() -> Weight;
 }
 
 /// Weights for labs using the Substrate node and recommended hardware.
@@ -89,19 +92,21 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	// Storage: Orders PendingOrdersBySeller (r:1 w:0)
 	// Storage: Timestamp Now (r:1 w:0)
 	// Storage: Labs UnstakeTime (r:1 w:0)
+	// Storage: Labs PendingSlashes (r:1 w:0)
 	fn unstake_lab() -> Weight {
 		Weight::from_ref_time(145_241_000_u64)
-			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().reads(5_u64))
 			.saturating_add(T::DbWeight::get().writes(1_u64))
 	}
 	// Storage: Labs LabVerifierKey (r:1 w:0)
 	// Storage: Labs Labs (r:1 w:1)
 	// Storage: Timestamp Now (r:1 w:0)
+	// Storage: Labs PendingSlashes (r:1 w:0)
 	// Storage: Labs PalletAccount (r:1 w:0)
 	// Storage: System Account (r:1 w:1)
 	fn retrieve_unstake_amount() -> Weight {
 		Weight::from_ref_time(204_328_000_u64)
-			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().reads(6_u64))
 			.saturating_add(T::DbWeight::get().writes(2_u64))
 	}
 	// Storage: Labs LabVerifierKey (r:1 w:0)
@@ -124,6 +129,31 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(T::DbWeight::get().reads(1_u64))
 			.saturating_add(T::DbWeight::get().writes(1_u64))
 	}
+	// Storage: Labs Labs (r:1 w:0)
+	// Storage: Labs PendingSlashes (r:1 w:1)
+	// Storage: Timestamp Now (r:1 w:0)
+	fn propose_slash() -> Weight {
+		Weight::from_ref_time(58_312_000_u64)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	// Storage: Labs Labs (r:1 w:1)
+	// Storage: Labs PendingSlashes (r:1 w:1)
+	// Storage: Labs TreasuryKey (r:1 w:0)
+	// Storage: Labs PalletAccount (r:1 w:0)
+	// Storage: System Account (r:2 w:2)
+	// Storage: Labs TotalStakedAmount (r:0 w:1)
+	fn apply_slash() -> Weight {
+		Weight::from_ref_time(196_470_000_u64)
+			.saturating_add(T::DbWeight::get().reads(6_u64))
+			.saturating_add(T::DbWeight::get().writes(5_u64))
+	}
+	// Storage: Labs PendingSlashes (r:1 w:1)
+	fn cancel_slash() -> Weight {
+		Weight::from_ref_time(38_106_000_u64)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
 }
 
 // For backwards compatibility and tests
@@ -174,19 +204,21 @@ impl WeightInfo for () {
 	// Storage: Orders PendingOrdersBySeller (r:1 w:0)
 	// Storage: Timestamp Now (r:1 w:0)
 	// Storage: Labs UnstakeTime (r:1 w:0)
+	// Storage: Labs PendingSlashes (r:1 w:0)
 	fn unstake_lab() -> Weight {
 		Weight::from_ref_time(145_241_000_u64)
-			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
 			.saturating_add(RocksDbWeight::get().writes(1_u64))
 	}
 	// Storage: Labs LabVerifierKey (r:1 w:0)
 	// Storage: Labs Labs (r:1 w:1)
 	// Storage: Timestamp Now (r:1 w:0)
+	// Storage: Labs PendingSlashes (r:1 w:0)
 	// Storage: Labs PalletAccount (r:1 w:0)
 	// Storage: System Account (r:1 w:1)
 	fn retrieve_unstake_amount() -> Weight {
 		Weight::from_ref_time(204_328_000_u64)
-			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(RocksDbWeight::get().reads(6_u64))
 			.saturating_add(RocksDbWeight::get().writes(2_u64))
 	}
 	// Storage: Labs LabVerifierKey (r:1 w:0)
@@ -209,4 +241,29 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().reads(1_u64))
 			.saturating_add(RocksDbWeight::get().writes(1_u64))
 	}
+	// Storage: Labs Labs (r:1 w:0)
+	// Storage: Labs PendingSlashes (r:1 w:1)
+	// Storage: Timestamp Now (r:1 w:0)
+	fn propose_slash() -> Weight {
+		Weight::from_ref_time(58_312_000_u64)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	// Storage: Labs Labs (r:1 w:1)
+	// Storage: Labs PendingSlashes (r:1 w:1)
+	// Storage: Labs TreasuryKey (r:1 w:0)
+	// Storage: Labs PalletAccount (r:1 w:0)
+	// Storage: System Account (r:2 w:2)
+	// Storage: Labs TotalStakedAmount (r:0 w:1)
+	fn apply_slash() -> Weight {
+		Weight::from_ref_time(196_470_000_u64)
+			.saturating_add(RocksDbWeight::get().reads(6_u64))
+			.saturating_add(RocksDbWeight::get().writes(5_u64))
+	}
+	// Storage: Labs PendingSlashes (r:1 w:1)
+	fn cancel_slash() -> Weight {
+		Weight::from_ref_time(38_106_000_u64)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
 }
@@ -4,23 +4,33 @@ mod mock;
 mod tests {
 	use crate::mock::*;
 
-	use labs::{Error, Event as EventC, Lab, LabInfo, LabVerifierKey, PalletAccount};
+	use labs::{
+		Error, Event as EventC, Lab, LabCountByCountryRegionCity, LabIdsOf, LabInfo, LabOf,
+		LabVerifierKey, LabsByCountryRegionCity, PalletAccount, PendingSlash, TotalStakedAmount,
+	};
 	use orders::PalletAccount as OrderPalletAccount;
 
 	use frame_support::{
 		assert_noop, assert_ok,
+		codec::{Decode, Encode},
 		sp_runtime::{
-			traits::{Hash, Keccak256},
-			SaturatedConversion,
+			traits::{BadOrigin, Hash, Keccak256},
+			Perbill, SaturatedConversion,
 		},
+		storage::unhashed,
+		traits::StorageVersion,
 	};
 	use frame_system::RawOrigin;
 
+	use credentials::CertificationInfo;
 	use genetic_testing::{DnaSampleStatus, DnaSampleTracking, DnaTestResultSubmission};
 	use orders::{Order, OrderStatus};
 	use services::ServiceInfo;
 
-	use primitives_area_code::{CityCode, CountryCode, CountryRegionCode, RegionCode};
+	use primitives_area_code::{
+		CityCode, CountryCode, CountryRegionCode, RegionCode, MAX_AREA_CODE_LENGTH,
+	};
+	use primitives_geo_location::Coordinate;
 	use primitives_stake_status::StakeStatus;
 	use primitives_verification_status::VerificationStatus;
 
@@ -38,17 +48,19 @@ mod tests {
 					box_public_key: Keccak256::hash(
 						"0xDb9Af2d1f3ADD2726A132AA7A65Cc9E6fC5761C3".as_bytes()
 					),
-					name: "DeBio Lab".as_bytes().to_vec(),
-					email: "DeBio Email".as_bytes().to_vec(),
-					country: CountryCode::from_vec("DC".as_bytes().to_vec()),
-					region: RegionCode::from_vec("DB".as_bytes().to_vec()),
-					city: CityCode::from_vec("CITY".as_bytes().to_vec()),
-					address: "DeBio Address".as_bytes().to_vec(),
-					phone_number: "+6281394653625".as_bytes().to_vec(),
-					website: "DeBio Website".as_bytes().to_vec(),
-					latitude: Some("DeBio Latitude".as_bytes().to_vec()),
-					longitude: Some("DeBio Longtitude".as_bytes().to_vec()),
-					profile_image: Some("DeBio Profile Image uwu".as_bytes().to_vec()),
+					name: "DeBio Lab".as_bytes().to_vec().try_into().unwrap(),
+					email: "DeBio Email".as_bytes().to_vec().try_into().unwrap(),
+					country: CountryCode::try_from("ID".as_bytes().to_vec()).unwrap(),
+					region: RegionCode::try_from("JB".as_bytes().to_vec()).unwrap(),
+					city: CityCode::try_from("CITY".as_bytes().to_vec()).unwrap(),
+					address: "DeBio Address".as_bytes().to_vec().try_into().unwrap(),
+					phone_number: "+6281394653625".as_bytes().to_vec().try_into().unwrap(),
+					website: "DeBio Website".as_bytes().to_vec().try_into().unwrap(),
+					latitude: Some("-6.175392".as_bytes().to_vec().try_into().unwrap()),
+					longitude: Some("106.827153".as_bytes().to_vec().try_into().unwrap()),
+					profile_image: Some(
+						"DeBio Profile Image uwu".as_bytes().to_vec().try_into().unwrap()
+					),
 				}
 			));
 
@@ -56,8 +68,8 @@ mod tests {
 				Labs::lab_by_account_id(1),
 				Some(Lab {
 					account_id: 1,
-					services: Vec::new(),
-					certifications: Vec::new(),
+					services: Default::default(),
+					certifications: Default::default(),
 					unstake_at: 0u64.saturated_into(),
 					retrieve_unstake_at: 0u64.saturated_into(),
 					stake_amount: 0u128.saturated_into(),
@@ -67,27 +79,29 @@ mod tests {
 						box_public_key: Keccak256::hash(
 							"0xDb9Af2d1f3ADD2726A132AA7A65Cc9E6fC5761C3".as_bytes()
 						),
-						name: "DeBio Lab".as_bytes().to_vec(),
-						email: "DeBio Email".as_bytes().to_vec(),
-						country: CountryCode::from_vec("DC".as_bytes().to_vec()),
-						region: RegionCode::from_vec("DB".as_bytes().to_vec()),
-						city: CityCode::from_vec("CITY".as_bytes().to_vec()),
-						address: "DeBio Address".as_bytes().to_vec(),
-						phone_number: "+6281394653625".as_bytes().to_vec(),
-						website: "DeBio Website".as_bytes().to_vec(),
-						latitude: Some("DeBio Latitude".as_bytes().to_vec()),
-						longitude: Some("DeBio Longtitude".as_bytes().to_vec()),
-						profile_image: Some("DeBio Profile Image uwu".as_bytes().to_vec()),
+						name: "DeBio Lab".as_bytes().to_vec().try_into().unwrap(),
+						email: "DeBio Email".as_bytes().to_vec().try_into().unwrap(),
+						country: CountryCode::try_from("ID".as_bytes().to_vec()).unwrap(),
+						region: RegionCode::try_from("JB".as_bytes().to_vec()).unwrap(),
+						city: CityCode::try_from("CITY".as_bytes().to_vec()).unwrap(),
+						address: "DeBio Address".as_bytes().to_vec().try_into().unwrap(),
+						phone_number: "+6281394653625".as_bytes().to_vec().try_into().unwrap(),
+						website: "DeBio Website".as_bytes().to_vec().try_into().unwrap(),
+						latitude: Some("-6.175392".as_bytes().to_vec().try_into().unwrap()),
+						longitude: Some("106.827153".as_bytes().to_vec().try_into().unwrap()),
+						profile_image: Some(
+							"DeBio Profile Image uwu".as_bytes().to_vec().try_into().unwrap()
+						),
 					}
 				})
 			);
 
-			let country_region_code = CountryRegionCode::from_vec("DC-DB".as_bytes().to_vec());
-			let city_code = CityCode::from_vec("CITY".as_bytes().to_vec());
+			let country_region_code = CountryRegionCode::from_vec("ID-JB".as_bytes().to_vec());
+			let city_code = CityCode::try_from("CITY".as_bytes().to_vec()).unwrap();
 
 			assert_eq!(
 				Labs::labs_by_country_region_city(&country_region_code, &city_code),
-				Some(vec![1])
+				Some(vec![1].try_into().unwrap())
 			);
 			assert_eq!(Labs::lab_count(), Some(1));
 			assert_eq!(
@@ -106,17 +120,19 @@ mod tests {
 					box_public_key: Keccak256::hash(
 						"0xDb9Af2d1f3ADD2726A132AA7A65Cc9E6fC5761C3".as_bytes()
 					),
-					name: "DeBio Lab".as_bytes().to_vec(),
-					email: "DeBio Email".as_bytes().to_vec(),
-					country: CountryCode::from_vec("DC".as_bytes().to_vec()),
-					region: RegionCode::from_vec("DB".as_bytes().to_vec()),
-					city: CityCode::from_vec("CITY".as_bytes().to_vec()),
-					address: "DeBio Address".as_bytes().to_vec(),
-					phone_number: "+6281394653625".as_bytes().to_vec(),
-					website: "DeBio Website".as_bytes().to_vec(),
-					latitude: Some("DeBio Latitude".as_bytes().to_vec()),
-					longitude: Some("DeBio Longtitude".as_bytes().to_vec()),
-					profile_image: Some("DeBio Profile Image uwu".as_bytes().to_vec()),
+					name: "DeBio Lab".as_bytes().to_vec().try_into().unwrap(),
+					email: "DeBio Email".as_bytes().to_vec().try_into().unwrap(),
+					country: CountryCode::try_from("ID".as_bytes().to_vec()).unwrap(),
+					region: RegionCode::try_from("JB".as_bytes().to_vec()).unwrap(),
+					city: CityCode::try_from("CITY".as_bytes().to_vec()).unwrap(),
+					address: "DeBio Address".as_bytes().to_vec().try_into().unwrap(),
+					phone_number: "+6281394653625".as_bytes().to_vec().try_into().unwrap(),
+					website: "DeBio Website".as_bytes().to_vec().try_into().unwrap(),
+					latitude: Some("-6.175392".as_bytes().to_vec().try_into().unwrap()),
+					longitude: Some("106.827153".as_bytes().to_vec().try_into().unwrap()),
+					profile_image: Some(
+						"DeBio Profile Image uwu".as_bytes().to_vec().try_into().unwrap()
+					),
 				}
 			));
 
@@ -126,17 +142,19 @@ mod tests {
 					box_public_key: Keccak256::hash(
 						"0xDb9Af2d1f3ADD2726A132AA7A65Cc9E6fC5761C3".as_bytes()
 					),
-					name: "DeBio Labs".as_bytes().to_vec(),
-					email: "DeBio Email".as_bytes().to_vec(),
-					country: CountryCode::from_vec("DC".as_bytes().to_vec()),
-					region: RegionCode::from_vec("DB".as_bytes().to_vec()),
-					city: CityCode::from_vec("CITY".as_bytes().to_vec()),
-					address: "DeBio Address".as_bytes().to_vec(),
-					phone_number: "+6281394653625".as_bytes().to_vec(),
-					website: "DeBio Website".as_bytes().to_vec(),
-					latitude: Some("DeBio Latitude".as_bytes().to_vec()),
-					longitude: Some("DeBio Longtitude".as_bytes().to_vec()),
-					profile_image: Some("DeBio Profile Image uwu".as_bytes().to_vec()),
+					name: "DeBio Labs".as_bytes().to_vec().try_into().unwrap(),
+					email: "DeBio Email".as_bytes().to_vec().try_into().unwrap(),
+					country: CountryCode::try_from("ID".as_bytes().to_vec()).unwrap(),
+					region: RegionCode::try_from("JB".as_bytes().to_vec()).unwrap(),
+					city: CityCode::try_from("CITY".as_bytes().to_vec()).unwrap(),
+					address: "DeBio Address".as_bytes().to_vec().try_into().unwrap(),
+					phone_number: "+6281394653625".as_bytes().to_vec().try_into().unwrap(),
+					website: "DeBio Website".as_bytes().to_vec().try_into().unwrap(),
+					latitude: Some("-6.175392".as_bytes().to_vec().try_into().unwrap()),
+					longitude: Some("106.827153".as_bytes().to_vec().try_into().unwrap()),
+					profile_image: Some(
+						"DeBio Profile Image uwu".as_bytes().to_vec().try_into().unwrap()
+					),
 				}
 			));
 
@@ -144,8 +162,8 @@ mod tests {
 				Labs::lab_by_account_id(1),
 				Some(Lab {
 					account_id: 1,
-					services: Vec::new(),
-					certifications: Vec::new(),
+					services: Default::default(),
+					certifications: Default::default(),
 					unstake_at: 0u64.saturated_into(),
 					retrieve_unstake_at: 0u64.saturated_into(),
 					stake_amount: 0u128.saturated_into(),
@@ -155,23 +173,25 @@ mod tests {
 						box_public_key: Keccak256::hash(
 							"0xDb9Af2d1f3ADD2726A132AA7A65Cc9E6fC5761C3".as_bytes()
 						),
-						name: "DeBio Labs".as_bytes().to_vec(),
-						email: "DeBio Email".as_bytes().to_vec(),
-						country: CountryCode::from_vec("DC".as_bytes().to_vec()),
-						region: RegionCode::from_vec("DB".as_bytes().to_vec()),
-						city: CityCode::from_vec("CITY".as_bytes().to_vec()),
-						address: "DeBio Address".as_bytes().to_vec(),
-						phone_number: "+6281394653625".as_bytes().to_vec(),
-						website: "DeBio Website".as_bytes().to_vec(),
-						latitude: Some("DeBio Latitude".as_bytes().to_vec()),
-						longitude: Some("DeBio Longtitude".as_bytes().to_vec()),
-						profile_image: Some("DeBio Profile Image uwu".as_bytes().to_vec()),
+						name: "DeBio Labs".as_bytes().to_vec().try_into().unwrap(),
+						email: "DeBio Email".as_bytes().to_vec().try_into().unwrap(),
+						country: CountryCode::try_from("ID".as_bytes().to_vec()).unwrap(),
+						region: RegionCode::try_from("JB".as_bytes().to_vec()).unwrap(),
+						city: CityCode::try_from("CITY".as_bytes().to_vec()).unwrap(),
+						address: "DeBio Address".as_bytes().to_vec().try_into().unwrap(),
+						phone_number: "+6281394653625".as_bytes().to_vec().try_into().unwrap(),
+						website: "DeBio Website".as_bytes().to_vec().try_into().unwrap(),
+						latitude: Some("-6.175392".as_bytes().to_vec().try_into().unwrap()),
+						longitude: Some("106.827153".as_bytes().to_vec().try_into().unwrap()),
+						profile_image: Some(
+							"DeBio Profile Image uwu".as_bytes().to_vec().try_into().unwrap()
+						),
 					}
 				})
 			);
 
-			let old_country_region_code = CountryRegionCode::from_vec("DC-DB".as_bytes().to_vec());
-			let old_city_code = CityCode::from_vec("CITY".as_bytes().to_vec());
+			let old_country_region_code = CountryRegionCode::from_vec("ID-JB".as_bytes().to_vec());
+			let old_city_code = CityCode::try_from("CITY".as_bytes().to_vec()).unwrap();
 
 			assert_ok!(Labs::update_lab(
 				RuntimeOrigin::signed(1),
@@ -179,17 +199,19 @@ mod tests {
 					box_public_key: Keccak256::hash(
 						"0xDb9Af2d1f3ADD2726A132AA7A65Cc9E6fC5761C3".as_bytes()
 					),
-					name: "DeBio Labs".as_bytes().to_vec(),
-					email: "DeBio Email".as_bytes().to_vec(),
-					country: CountryCode::from_vec("ID".as_bytes().to_vec()),
-					region: RegionCode::from_vec("WJ".as_bytes().to_vec()),
-					city: CityCode::from_vec("CITY".as_bytes().to_vec()),
-					address: "DeBio Address".as_bytes().to_vec(),
-					phone_number: "+6281394653625".as_bytes().to_vec(),
-					website: "DeBio Website".as_bytes().to_vec(),
-					latitude: Some("DeBio Latitude".as_bytes().to_vec()),
-					longitude: Some("DeBio Longtitude".as_bytes().to_vec()),
-					profile_image: Some("DeBio Profile Image uwu".as_bytes().to_vec()),
+					name: "DeBio Labs".as_bytes().to_vec().try_into().unwrap(),
+					email: "DeBio Email".as_bytes().to_vec().try_into().unwrap(),
+					country: CountryCode::try_from("MY".as_bytes().to_vec()).unwrap(),
+					region: RegionCode::try_from("14".as_bytes().to_vec()).unwrap(),
+					city: CityCode::try_from("CITY".as_bytes().to_vec()).unwrap(),
+					address: "DeBio Address".as_bytes().to_vec().try_into().unwrap(),
+					phone_number: "+6281394653625".as_bytes().to_vec().try_into().unwrap(),
+					website: "DeBio Website".as_bytes().to_vec().try_into().unwrap(),
+					latitude: Some("-6.175392".as_bytes().to_vec().try_into().unwrap()),
+					longitude: Some("106.827153".as_bytes().to_vec().try_into().unwrap()),
+					profile_image: Some(
+						"DeBio Profile Image uwu".as_bytes().to_vec().try_into().unwrap()
+					),
 				}
 			));
 
@@ -197,8 +219,8 @@ mod tests {
 				Labs::lab_by_account_id(1),
 				Some(Lab {
 					account_id: 1,
-					services: Vec::new(),
-					certifications: Vec::new(),
+					services: Default::default(),
+					certifications: Default::default(),
 					unstake_at: 0u64.saturated_into(),
 					retrieve_unstake_at: 0u64.saturated_into(),
 					stake_amount: 0u128.saturated_into(),
@@ -208,36 +230,38 @@ mod tests {
 						box_public_key: Keccak256::hash(
 							"0xDb9Af2d1f3ADD2726A132AA7A65Cc9E6fC5761C3".as_bytes()
 						),
-						name: "DeBio Labs".as_bytes().to_vec(),
-						email: "DeBio Email".as_bytes().to_vec(),
-						country: CountryCode::from_vec("ID".as_bytes().to_vec()),
-						region: RegionCode::from_vec("WJ".as_bytes().to_vec()),
-						city: CityCode::from_vec("CITY".as_bytes().to_vec()),
-						address: "DeBio Address".as_bytes().to_vec(),
-						phone_number: "+6281394653625".as_bytes().to_vec(),
-						website: "DeBio Website".as_bytes().to_vec(),
-						latitude: Some("DeBio Latitude".as_bytes().to_vec()),
-						longitude: Some("DeBio Longtitude".as_bytes().to_vec()),
-						profile_image: Some("DeBio Profile Image uwu".as_bytes().to_vec()),
+						name: "DeBio Labs".as_bytes().to_vec().try_into().unwrap(),
+						email: "DeBio Email".as_bytes().to_vec().try_into().unwrap(),
+						country: CountryCode::try_from("MY".as_bytes().to_vec()).unwrap(),
+						region: RegionCode::try_from("14".as_bytes().to_vec()).unwrap(),
+						city: CityCode::try_from("CITY".as_bytes().to_vec()).unwrap(),
+						address: "DeBio Address".as_bytes().to_vec().try_into().unwrap(),
+						phone_number: "+6281394653625".as_bytes().to_vec().try_into().unwrap(),
+						website: "DeBio Website".as_bytes().to_vec().try_into().unwrap(),
+						latitude: Some("-6.175392".as_bytes().to_vec().try_into().unwrap()),
+						longitude: Some("106.827153".as_bytes().to_vec().try_into().unwrap()),
+						profile_image: Some(
+							"DeBio Profile Image uwu".as_bytes().to_vec().try_into().unwrap()
+						),
 					}
 				})
 			);
 
 			assert_eq!(
 				Labs::labs_by_country_region_city(&old_country_region_code, &old_city_code),
-				Some(Vec::new())
+				Some(Default::default())
 			);
 			assert_eq!(
 				Labs::lab_count_by_country_region_city(&old_country_region_code, &old_city_code),
 				Some(0)
 			);
 
-			let new_country_region_code = CountryRegionCode::from_vec("ID-WJ".as_bytes().to_vec());
-			let new_city_code = CityCode::from_vec("CITY".as_bytes().to_vec());
+			let new_country_region_code = CountryRegionCode::from_vec("MY-14".as_bytes().to_vec());
+			let new_city_code = CityCode::try_from("CITY".as_bytes().to_vec()).unwrap();
 
 			assert_eq!(
 				Labs::labs_by_country_region_city(&new_country_region_code, &new_city_code),
-				Some(vec![1])
+				Some(vec![1].try_into().unwrap())
 			);
 			assert_eq!(
 				Labs::lab_count_by_country_region_city(new_country_region_code, new_city_code),
@@ -255,17 +279,19 @@ mod tests {
 					box_public_key: Keccak256::hash(
 						"0xDb9Af2d1f3ADD2726A132AA7A65Cc9E6fC5761C3".as_bytes()
 					),
-					name: "DeBio Lab".as_bytes().to_vec(),
-					email: "DeBio Email".as_bytes().to_vec(),
-					country: CountryCode::from_vec("DC".as_bytes().to_vec()),
-					region: RegionCode::from_vec("DB".as_bytes().to_vec()),
-					city: CityCode::from_vec("CITY".as_bytes().to_vec()),
-					address: "DeBio Address".as_bytes().to_vec(),
-					phone_number: "+6281394653625".as_bytes().to_vec(),
-					website: "DeBio Website".as_bytes().to_vec(),
-					latitude: Some("DeBio Latitude".as_bytes().to_vec()),
-					longitude: Some("DeBio Longtitude".as_bytes().to_vec()),
-					profile_image: Some("DeBio Profile Image uwu".as_bytes().to_vec()),
+					name: "DeBio Lab".as_bytes().to_vec().try_into().unwrap(),
+					email: "DeBio Email".as_bytes().to_vec().try_into().unwrap(),
+					country: CountryCode::try_from("ID".as_bytes().to_vec()).unwrap(),
+					region: RegionCode::try_from("JB".as_bytes().to_vec()).unwrap(),
+					city: CityCode::try_from("CITY".as_bytes().to_vec()).unwrap(),
+					address: "DeBio Address".as_bytes().to_vec().try_into().unwrap(),
+					phone_number: "+6281394653625".as_bytes().to_vec().try_into().unwrap(),
+					website: "DeBio Website".as_bytes().to_vec().try_into().unwrap(),
+					latitude: Some("-6.175392".as_bytes().to_vec().try_into().unwrap()),
+					longitude: Some("106.827153".as_bytes().to_vec().try_into().unwrap()),
+					profile_image: Some(
+						"DeBio Profile Image uwu".as_bytes().to_vec().try_into().unwrap()
+					),
 				}
 			));
 
@@ -281,8 +307,8 @@ mod tests {
 				Labs::lab_by_account_id(1),
 				Some(Lab {
 					account_id: 1,
-					services: Vec::new(),
-					certifications: Vec::new(),
+					services: Default::default(),
+					certifications: Default::default(),
 					unstake_at: 0u64.saturated_into(),
 					retrieve_unstake_at: 0u64.saturated_into(),
 					stake_amount: 0u128.saturated_into(),
@@ -292,17 +318,19 @@ mod tests {
 						box_public_key: Keccak256::hash(
 							"0xDb9Af2d1f3ADD2726A132AA7A65Cc9E6fC5761C3".as_bytes()
 						),
-						name: "DeBio Lab".as_bytes().to_vec(),
-						email: "DeBio Email".as_bytes().to_vec(),
-						country: CountryCode::from_vec("DC".as_bytes().to_vec()),
-						region: RegionCode::from_vec("DB".as_bytes().to_vec()),
-						city: CityCode::from_vec("CITY".as_bytes().to_vec()),
-						address: "DeBio Address".as_bytes().to_vec(),
-						phone_number: "+6281394653625".as_bytes().to_vec(),
-						website: "DeBio Website".as_bytes().to_vec(),
-						latitude: Some("DeBio Latitude".as_bytes().to_vec()),
-						longitude: Some("DeBio Longtitude".as_bytes().to_vec()),
-						profile_image: Some("DeBio Profile Image uwu".as_bytes().to_vec()),
+						name: "DeBio Lab".as_bytes().to_vec().try_into().unwrap(),
+						email: "DeBio Email".as_bytes().to_vec().try_into().unwrap(),
+						country: CountryCode::try_from("ID".as_bytes().to_vec()).unwrap(),
+						region: RegionCode::try_from("JB".as_bytes().to_vec()).unwrap(),
+						city: CityCode::try_from("CITY".as_bytes().to_vec()).unwrap(),
+						address: "DeBio Address".as_bytes().to_vec().try_into().unwrap(),
+						phone_number: "+6281394653625".as_bytes().to_vec().try_into().unwrap(),
+						website: "DeBio Website".as_bytes().to_vec().try_into().unwrap(),
+						latitude: Some("-6.175392".as_bytes().to_vec().try_into().unwrap()),
+						longitude: Some("106.827153".as_bytes().to_vec().try_into().unwrap()),
+						profile_image: Some(
+							"DeBio Profile Image uwu".as_bytes().to_vec().try_into().unwrap()
+						),
 					}
 				})
 			);
@@ -318,22 +346,24 @@ mod tests {
 					box_public_key: Keccak256::hash(
 						"0xDb9Af2d1f3ADD2726A132AA7A65Cc9E6fC5761C3".as_bytes()
 					),
-					name: "DeBio Lab".as_bytes().to_vec(),
-					email: "DeBio Email".as_bytes().to_vec(),
-					country: CountryCode::from_vec("DC".as_bytes().to_vec()),
-					region: RegionCode::from_vec("DB".as_bytes().to_vec()),
-					city: CityCode::from_vec("CITY".as_bytes().to_vec()),
-					address: "DeBio Address".as_bytes().to_vec(),
-					phone_number: "+6281394653625".as_bytes().to_vec(),
-					website: "DeBio Website".as_bytes().to_vec(),
-					latitude: Some("DeBio Latitude".as_bytes().to_vec()),
-					longitude: Some("DeBio Longtitude".as_bytes().to_vec()),
-					profile_image: Some("DeBio Profile Image uwu".as_bytes().to_vec()),
+					name: "DeBio Lab".as_bytes().to_vec().try_into().unwrap(),
+					email: "DeBio Email".as_bytes().to_vec().try_into().unwrap(),
+					country: CountryCode::try_from("ID".as_bytes().to_vec()).unwrap(),
+					region: RegionCode::try_from("JB".as_bytes().to_vec()).unwrap(),
+					city: CityCode::try_from("CITY".as_bytes().to_vec()).unwrap(),
+					address: "DeBio Address".as_bytes().to_vec().try_into().unwrap(),
+					phone_number: "+6281394653625".as_bytes().to_vec().try_into().unwrap(),
+					website: "DeBio Website".as_bytes().to_vec().try_into().unwrap(),
+					latitude: Some("-6.175392".as_bytes().to_vec().try_into().unwrap()),
+					longitude: Some("106.827153".as_bytes().to_vec().try_into().unwrap()),
+					profile_image: Some(
+						"DeBio Profile Image uwu".as_bytes().to_vec().try_into().unwrap()
+					),
 				}
 			));
 
-			let country_region_code = CountryRegionCode::from_vec("DC-DB".as_bytes().to_vec());
-			let city_code = CityCode::from_vec("CITY".as_bytes().to_vec());
+			let country_region_code = CountryRegionCode::from_vec("ID-JB".as_bytes().to_vec());
+			let city_code = CityCode::try_from("CITY".as_bytes().to_vec()).unwrap();
 
 			assert_ok!(Labs::deregister_lab(RuntimeOrigin::signed(1)));
 
@@ -341,7 +371,7 @@ mod tests {
 
 			assert_eq!(
 				Labs::labs_by_country_region_city(&country_region_code, &city_code),
-				Some(Vec::new())
+				Some(Default::default())
 			);
 
 			assert_eq!(Labs::lab_count(), Some(0));
@@ -362,17 +392,19 @@ mod tests {
 					box_public_key: Keccak256::hash(
 						"0xDb9Af2d1f3ADD2726A132AA7A65Cc9E6fC5761C3".as_bytes()
 					),
-					name: "DeBio Lab".as_bytes().to_vec(),
-					email: "DeBio Email".as_bytes().to_vec(),
-					country: CountryCode::from_vec("DC".as_bytes().to_vec()),
-					region: RegionCode::from_vec("DB".as_bytes().to_vec()),
-					city: CityCode::from_vec("CITY".as_bytes().to_vec()),
-					address: "DeBio Address".as_bytes().to_vec(),
-					phone_number: "+6281394653625".as_bytes().to_vec(),
-					website: "DeBio Website".as_bytes().to_vec(),
-					latitude: Some("DeBio Latitude".as_bytes().to_vec()),
-					longitude: Some("DeBio Longtitude".as_bytes().to_vec()),
-					profile_image: Some("DeBio Profile Image uwu".as_bytes().to_vec()),
+					name: "DeBio Lab".as_bytes().to_vec().try_into().unwrap(),
+					email: "DeBio Email".as_bytes().to_vec().try_into().unwrap(),
+					country: CountryCode::try_from("ID".as_bytes().to_vec()).unwrap(),
+					region: RegionCode::try_from("JB".as_bytes().to_vec()).unwrap(),
+					city: CityCode::try_from("CITY".as_bytes().to_vec()).unwrap(),
+					address: "DeBio Address".as_bytes().to_vec().try_into().unwrap(),
+					phone_number: "+6281394653625".as_bytes().to_vec().try_into().unwrap(),
+					website: "DeBio Website".as_bytes().to_vec().try_into().unwrap(),
+					latitude: Some("-6.175392".as_bytes().to_vec().try_into().unwrap()),
+					longitude: Some("106.827153".as_bytes().to_vec().try_into().unwrap()),
+					profile_image: Some(
+						"DeBio Profile Image uwu".as_bytes().to_vec().try_into().unwrap()
+					),
 				}
 			));
 
@@ -383,17 +415,19 @@ mod tests {
 						box_public_key: Keccak256::hash(
 							"0xDb9Af2d1f3ADD2726A132AA7A65Cc9E6fC5761C3".as_bytes()
 						),
-						name: "DeBio Lab".as_bytes().to_vec(),
-						email: "DeBio Email".as_bytes().to_vec(),
-						country: CountryCode::from_vec("DC".as_bytes().to_vec()),
-						region: RegionCode::from_vec("DB".as_bytes().to_vec()),
-						city: CityCode::from_vec("CITY".as_bytes().to_vec()),
-						address: "DeBio Address".as_bytes().to_vec(),
-						phone_number: "+6281394653625".as_bytes().to_vec(),
-						website: "DeBio Website".as_bytes().to_vec(),
-						latitude: Some("DeBio Latitude".as_bytes().to_vec()),
-						longitude: Some("DeBio Longtitude".as_bytes().to_vec()),
-						profile_image: Some("DeBio Profile Image uwu".as_bytes().to_vec()),
+						name: "DeBio Lab".as_bytes().to_vec().try_into().unwrap(),
+						email: "DeBio Email".as_bytes().to_vec().try_into().unwrap(),
+						country: CountryCode::try_from("ID".as_bytes().to_vec()).unwrap(),
+						region: RegionCode::try_from("JB".as_bytes().to_vec()).unwrap(),
+						city: CityCode::try_from("CITY".as_bytes().to_vec()).unwrap(),
+						address: "DeBio Address".as_bytes().to_vec().try_into().unwrap(),
+						phone_number: "+6281394653625".as_bytes().to_vec().try_into().unwrap(),
+						website: "DeBio Website".as_bytes().to_vec().try_into().unwrap(),
+						latitude: Some("-6.175392".as_bytes().to_vec().try_into().unwrap()),
+						longitude: Some("106.827153".as_bytes().to_vec().try_into().unwrap()),
+						profile_image: Some(
+							"DeBio Profile Image uwu".as_bytes().to_vec().try_into().unwrap()
+						),
 					}
 				),
 				Error::<Test>::LabAlreadyRegistered
@@ -401,6 +435,95 @@ mod tests {
 		})
 	}
 
+	#[test]
+	fn register_lab_validates_area_code() {
+		<ExternalityBuilder>::default().existential_deposit(1).build().execute_with(|| {
+			// Codes decoded from an extrinsic skip TryFrom
+			let mut lab_info = lab_info_at("-6.914744", "107.609810");
+			lab_info.country = CountryCode::decode(&mut "DC".as_bytes()).unwrap();
+
+			assert_noop!(
+				Labs::register_lab(RuntimeOrigin::signed(1), lab_info.clone()),
+				Error::<Test>::InvalidCountryCode
+			);
+
+			lab_info.country = CountryCode::try_from("MY".as_bytes().to_vec()).unwrap();
+
+			assert_noop!(
+				Labs::register_lab(RuntimeOrigin::signed(1), lab_info.clone()),
+				Error::<Test>::InvalidRegionCode
+			);
+
+			lab_info.country = CountryCode::decode(&mut "id".as_bytes()).unwrap();
+			lab_info.region = RegionCode::decode(&mut &"jb".encode()[..]).unwrap();
+			lab_info.city = CityCode::decode(&mut &"".encode()[..]).unwrap();
+
+			assert_noop!(
+				Labs::register_lab(RuntimeOrigin::signed(1), lab_info.clone()),
+				Error::<Test>::InvalidCityCode
+			);
+
+			lab_info.city = CityCode::decode(&mut &"city".encode()[..]).unwrap();
+
+			assert_ok!(Labs::register_lab(RuntimeOrigin::signed(1), lab_info));
+
+			let country_region_code = CountryRegionCode::from_vec("ID-JB".as_bytes().to_vec());
+			let city_code = CityCode::try_from("CITY".as_bytes().to_vec()).unwrap();
+
+			assert_eq!(
+				Labs::lab_by_account_id(1).unwrap().info,
+				lab_info_at("-6.914744", "107.609810")
+			);
+			assert_eq!(
+				Labs::labs_by_country_region_city(&country_region_code, &city_code),
+				Some(vec![1].try_into().unwrap())
+			);
+		})
+	}
+
+	#[test]
+	fn register_lab_in_country_without_subdivisions() {
+		<ExternalityBuilder>::default().existential_deposit(1).build().execute_with(|| {
+			// Hong Kong has no ISO 3166-2 subdivisions
+			let mut lab_info = lab_info_at("22.302711", "114.177216");
+			lab_info.country = CountryCode::try_from("HK".as_bytes().to_vec()).unwrap();
+			lab_info.region = RegionCode::decode(&mut &"".encode()[..]).unwrap();
+
+			assert_ok!(Labs::register_lab(RuntimeOrigin::signed(1), lab_info.clone()));
+
+			lab_info.region = RegionCode::try_from("HK".as_bytes().to_vec()).unwrap();
+
+			assert_eq!(Labs::lab_by_account_id(1).unwrap().info, lab_info);
+
+			// The country code is accepted as region as well
+			assert_ok!(Labs::register_lab(RuntimeOrigin::signed(2), lab_info.clone()));
+
+			let country_region_code = CountryRegionCode::from_vec("HK-HK".as_bytes().to_vec());
+			let city_code = CityCode::try_from("CITY".as_bytes().to_vec()).unwrap();
+
+			assert_eq!(
+				Labs::labs_by_country_region_city(&country_region_code, &city_code),
+				Some(vec![1, 2].try_into().unwrap())
+			);
+
+			lab_info.region = RegionCode::try_from("JB".as_bytes().to_vec()).unwrap();
+
+			assert_noop!(
+				Labs::register_lab(RuntimeOrigin::signed(3), lab_info.clone()),
+				Error::<Test>::InvalidRegionCode
+			);
+
+			// Countries with subdivisions still need one
+			lab_info.country = CountryCode::try_from("MY".as_bytes().to_vec()).unwrap();
+			lab_info.region = RegionCode::decode(&mut &"".encode()[..]).unwrap();
+
+			assert_noop!(
+				Labs::register_lab(RuntimeOrigin::signed(3), lab_info),
+				Error::<Test>::InvalidRegionCode
+			);
+		})
+	}
+
 	#[test]
 	fn cant_update_lab_verification_status_when_not_admin() {
 		<ExternalityBuilder>::default().existential_deposit(1).build().execute_with(|| {
@@ -412,17 +535,19 @@ mod tests {
 					box_public_key: Keccak256::hash(
 						"0xDb9Af2d1f3ADD2726A132AA7A65Cc9E6fC5761C3".as_bytes()
 					),
-					name: "DeBio Lab".as_bytes().to_vec(),
-					email: "DeBio Email".as_bytes().to_vec(),
-					country: CountryCode::from_vec("DC".as_bytes().to_vec()),
-					region: RegionCode::from_vec("DB".as_bytes().to_vec()),
-					city: CityCode::from_vec("CITY".as_bytes().to_vec()),
-					address: "DeBio Address".as_bytes().to_vec(),
-					phone_number: "+6281394653625".as_bytes().to_vec(),
-					website: "DeBio Website".as_bytes().to_vec(),
-					latitude: Some("DeBio Latitude".as_bytes().to_vec()),
-					longitude: Some("DeBio Longtitude".as_bytes().to_vec()),
-					profile_image: Some("DeBio Profile Image uwu".as_bytes().to_vec()),
+					name: "DeBio Lab".as_bytes().to_vec().try_into().unwrap(),
+					email: "DeBio Email".as_bytes().to_vec().try_into().unwrap(),
+					country: CountryCode::try_from("ID".as_bytes().to_vec()).unwrap(),
+					region: RegionCode::try_from("JB".as_bytes().to_vec()).unwrap(),
+					city: CityCode::try_from("CITY".as_bytes().to_vec()).unwrap(),
+					address: "DeBio Address".as_bytes().to_vec().try_into().unwrap(),
+					phone_number: "+6281394653625".as_bytes().to_vec().try_into().unwrap(),
+					website: "DeBio Website".as_bytes().to_vec().try_into().unwrap(),
+					latitude: Some("-6.175392".as_bytes().to_vec().try_into().unwrap()),
+					longitude: Some("106.827153".as_bytes().to_vec().try_into().unwrap()),
+					profile_image: Some(
+						"DeBio Profile Image uwu".as_bytes().to_vec().try_into().unwrap()
+					),
 				}
 			));
 
@@ -463,17 +588,19 @@ mod tests {
 						box_public_key: Keccak256::hash(
 							"0xDb9Af2d1f3ADD2726A132AA7A65Cc9E6fC5761C3".as_bytes()
 						),
-						name: "DeBio Lab".as_bytes().to_vec(),
-						email: "DeBio Email".as_bytes().to_vec(),
-						country: CountryCode::from_vec("DC".as_bytes().to_vec()),
-						region: RegionCode::from_vec("DB".as_bytes().to_vec()),
-						city: CityCode::from_vec("CITY".as_bytes().to_vec()),
-						address: "DeBio Address".as_bytes().to_vec(),
-						phone_number: "+6281394653625".as_bytes().to_vec(),
-						website: "DeBio Website".as_bytes().to_vec(),
-						latitude: Some("DeBio Latitude".as_bytes().to_vec()),
-						longitude: Some("DeBio Longtitude".as_bytes().to_vec()),
-						profile_image: Some("DeBio Profile Image uwu".as_bytes().to_vec()),
+						name: "DeBio Lab".as_bytes().to_vec().try_into().unwrap(),
+						email: "DeBio Email".as_bytes().to_vec().try_into().unwrap(),
+						country: CountryCode::try_from("ID".as_bytes().to_vec()).unwrap(),
+						region: RegionCode::try_from("JB".as_bytes().to_vec()).unwrap(),
+						city: CityCode::try_from("CITY".as_bytes().to_vec()).unwrap(),
+						address: "DeBio Address".as_bytes().to_vec().try_into().unwrap(),
+						phone_number: "+6281394653625".as_bytes().to_vec().try_into().unwrap(),
+						website: "DeBio Website".as_bytes().to_vec().try_into().unwrap(),
+						latitude: Some("-6.175392".as_bytes().to_vec().try_into().unwrap()),
+						longitude: Some("106.827153".as_bytes().to_vec().try_into().unwrap()),
+						profile_image: Some(
+							"DeBio Profile Image uwu".as_bytes().to_vec().try_into().unwrap()
+						),
 					}
 				),
 				Error::<Test>::LabDoesNotExist
@@ -507,25 +634,27 @@ mod tests {
 					box_public_key: Keccak256::hash(
 						"0xDb9Af2d1f3ADD2726A132AA7A65Cc9E6fC5761C3".as_bytes()
 					),
-					name: "DeBio Lab".as_bytes().to_vec(),
-					email: "DeBio Email".as_bytes().to_vec(),
-					country: CountryCode::from_vec("DC".as_bytes().to_vec()),
-					region: RegionCode::from_vec("DB".as_bytes().to_vec()),
-					city: CityCode::from_vec("CITY".as_bytes().to_vec()),
-					address: "DeBio Address".as_bytes().to_vec(),
-					phone_number: "+6281394653625".as_bytes().to_vec(),
-					website: "DeBio Website".as_bytes().to_vec(),
-					latitude: Some("DeBio Latitude".as_bytes().to_vec()),
-					longitude: Some("DeBio Longtitude".as_bytes().to_vec()),
-					profile_image: Some("DeBio Profile Image uwu".as_bytes().to_vec()),
+					name: "DeBio Lab".as_bytes().to_vec().try_into().unwrap(),
+					email: "DeBio Email".as_bytes().to_vec().try_into().unwrap(),
+					country: CountryCode::try_from("ID".as_bytes().to_vec()).unwrap(),
+					region: RegionCode::try_from("JB".as_bytes().to_vec()).unwrap(),
+					city: CityCode::try_from("CITY".as_bytes().to_vec()).unwrap(),
+					address: "DeBio Address".as_bytes().to_vec().try_into().unwrap(),
+					phone_number: "+6281394653625".as_bytes().to_vec().try_into().unwrap(),
+					website: "DeBio Website".as_bytes().to_vec().try_into().unwrap(),
+					latitude: Some("-6.175392".as_bytes().to_vec().try_into().unwrap()),
+					longitude: Some("106.827153".as_bytes().to_vec().try_into().unwrap()),
+					profile_image: Some(
+						"DeBio Profile Image uwu".as_bytes().to_vec().try_into().unwrap()
+					),
 				}
 			));
 
 			System::assert_last_event(RuntimeEvent::Labs(EventC::LabRegistered(
 				Lab {
 					account_id: 1,
-					services: Vec::new(),
-					certifications: Vec::new(),
+					services: Default::default(),
+					certifications: Default::default(),
 					unstake_at: 0u64.saturated_into(),
 					retrieve_unstake_at: 0u64.saturated_into(),
 					stake_amount: 0u128.saturated_into(),
@@ -535,17 +664,19 @@ mod tests {
 						box_public_key: Keccak256::hash(
 							"0xDb9Af2d1f3ADD2726A132AA7A65Cc9E6fC5761C3".as_bytes(),
 						),
-						name: "DeBio Lab".as_bytes().to_vec(),
-						email: "DeBio Email".as_bytes().to_vec(),
-						country: CountryCode::from_vec("DC".as_bytes().to_vec()),
-						region: RegionCode::from_vec("DB".as_bytes().to_vec()),
-						city: CityCode::from_vec("CITY".as_bytes().to_vec()),
-						address: "DeBio Address".as_bytes().to_vec(),
-						phone_number: "+6281394653625".as_bytes().to_vec(),
-						website: "DeBio Website".as_bytes().to_vec(),
-						latitude: Some("DeBio Latitude".as_bytes().to_vec()),
-						longitude: Some("DeBio Longtitude".as_bytes().to_vec()),
-						profile_image: Some("DeBio Profile Image uwu".as_bytes().to_vec()),
+						name: "DeBio Lab".as_bytes().to_vec().try_into().unwrap(),
+						email: "DeBio Email".as_bytes().to_vec().try_into().unwrap(),
+						country: CountryCode::try_from("ID".as_bytes().to_vec()).unwrap(),
+						region: RegionCode::try_from("JB".as_bytes().to_vec()).unwrap(),
+						city: CityCode::try_from("CITY".as_bytes().to_vec()).unwrap(),
+						address: "DeBio Address".as_bytes().to_vec().try_into().unwrap(),
+						phone_number: "+6281394653625".as_bytes().to_vec().try_into().unwrap(),
+						website: "DeBio Website".as_bytes().to_vec().try_into().unwrap(),
+						latitude: Some("-6.175392".as_bytes().to_vec().try_into().unwrap()),
+						longitude: Some("106.827153".as_bytes().to_vec().try_into().unwrap()),
+						profile_image: Some(
+							"DeBio Profile Image uwu".as_bytes().to_vec().try_into().unwrap(),
+						),
 					},
 				},
 				1,
@@ -557,25 +688,27 @@ mod tests {
 					box_public_key: Keccak256::hash(
 						"0xDb9Af2d1f3ADD2726A132AA7A65Cc9E6fC5761C3".as_bytes()
 					),
-					name: "DeBio Labs".as_bytes().to_vec(),
-					email: "DeBio Email".as_bytes().to_vec(),
-					country: CountryCode::from_vec("DC".as_bytes().to_vec()),
-					region: RegionCode::from_vec("DB".as_bytes().to_vec()),
-					city: CityCode::from_vec("CITY".as_bytes().to_vec()),
-					address: "DeBio Address".as_bytes().to_vec(),
-					phone_number: "+6281394653625".as_bytes().to_vec(),
-					website: "DeBio Website".as_bytes().to_vec(),
-					latitude: Some("DeBio Latitude".as_bytes().to_vec()),
-					longitude: Some("DeBio Longtitude".as_bytes().to_vec()),
-					profile_image: Some("DeBio Profile Image uwu".as_bytes().to_vec()),
+					name: "DeBio Labs".as_bytes().to_vec().try_into().unwrap(),
+					email: "DeBio Email".as_bytes().to_vec().try_into().unwrap(),
+					country: CountryCode::try_from("ID".as_bytes().to_vec()).unwrap(),
+					region: RegionCode::try_from("JB".as_bytes().to_vec()).unwrap(),
+					city: CityCode::try_from("CITY".as_bytes().to_vec()).unwrap(),
+					address: "DeBio Address".as_bytes().to_vec().try_into().unwrap(),
+					phone_number: "+6281394653625".as_bytes().to_vec().try_into().unwrap(),
+					website: "DeBio Website".as_bytes().to_vec().try_into().unwrap(),
+					latitude: Some("-6.175392".as_bytes().to_vec().try_into().unwrap()),
+					longitude: Some("106.827153".as_bytes().to_vec().try_into().unwrap()),
+					profile_image: Some(
+						"DeBio Profile Image uwu".as_bytes().to_vec().try_into().unwrap()
+					),
 				}
 			));
 
 			System::assert_last_event(RuntimeEvent::Labs(EventC::LabUpdated(
 				Lab {
 					account_id: 1,
-					services: Vec::new(),
-					certifications: Vec::new(),
+					services: Default::default(),
+					certifications: Default::default(),
 					unstake_at: 0u64.saturated_into(),
 					retrieve_unstake_at: 0u64.saturated_into(),
 					stake_amount: 0u128.saturated_into(),
@@ -585,17 +718,19 @@ mod tests {
 						box_public_key: Keccak256::hash(
 							"0xDb9Af2d1f3ADD2726A132AA7A65Cc9E6fC5761C3".as_bytes(),
 						),
-						name: "DeBio Labs".as_bytes().to_vec(),
-						email: "DeBio Email".as_bytes().to_vec(),
-						country: CountryCode::from_vec("DC".as_bytes().to_vec()),
-						region: RegionCode::from_vec("DB".as_bytes().to_vec()),
-						city: CityCode::from_vec("CITY".as_bytes().to_vec()),
-						address: "DeBio Address".as_bytes().to_vec(),
-						phone_number: "+6281394653625".as_bytes().to_vec(),
-						website: "DeBio Website".as_bytes().to_vec(),
-						latitude: Some("DeBio Latitude".as_bytes().to_vec()),
-						longitude: Some("DeBio Longtitude".as_bytes().to_vec()),
-						profile_image: Some("DeBio Profile Image uwu".as_bytes().to_vec()),
+						name: "DeBio Labs".as_bytes().to_vec().try_into().unwrap(),
+						email: "DeBio Email".as_bytes().to_vec().try_into().unwrap(),
+						country: CountryCode::try_from("ID".as_bytes().to_vec()).unwrap(),
+						region: RegionCode::try_from("JB".as_bytes().to_vec()).unwrap(),
+						city: CityCode::try_from("CITY".as_bytes().to_vec()).unwrap(),
+						address: "DeBio Address".as_bytes().to_vec().try_into().unwrap(),
+						phone_number: "+6281394653625".as_bytes().to_vec().try_into().unwrap(),
+						website: "DeBio Website".as_bytes().to_vec().try_into().unwrap(),
+						latitude: Some("-6.175392".as_bytes().to_vec().try_into().unwrap()),
+						longitude: Some("106.827153".as_bytes().to_vec().try_into().unwrap()),
+						profile_image: Some(
+							"DeBio Profile Image uwu".as_bytes().to_vec().try_into().unwrap(),
+						),
 					},
 				},
 				1,
@@ -612,8 +747,8 @@ mod tests {
 			System::assert_last_event(RuntimeEvent::Labs(EventC::LabUpdateVerificationStatus(
 				Lab {
 					account_id: 1,
-					services: Vec::new(),
-					certifications: Vec::new(),
+					services: Default::default(),
+					certifications: Default::default(),
 					unstake_at: 0u64.saturated_into(),
 					retrieve_unstake_at: 0u64.saturated_into(),
 					stake_amount: 0u128.saturated_into(),
@@ -623,17 +758,19 @@ mod tests {
 						box_public_key: Keccak256::hash(
 							"0xDb9Af2d1f3ADD2726A132AA7A65Cc9E6fC5761C3".as_bytes(),
 						),
-						name: "DeBio Labs".as_bytes().to_vec(),
-						email: "DeBio Email".as_bytes().to_vec(),
-						country: CountryCode::from_vec("DC".as_bytes().to_vec()),
-						region: RegionCode::from_vec("DB".as_bytes().to_vec()),
-						city: CityCode::from_vec("CITY".as_bytes().to_vec()),
-						address: "DeBio Address".as_bytes().to_vec(),
-						phone_number: "+6281394653625".as_bytes().to_vec(),
-						website: "DeBio Website".as_bytes().to_vec(),
-						latitude: Some("DeBio Latitude".as_bytes().to_vec()),
-						longitude: Some("DeBio Longtitude".as_bytes().to_vec()),
-						profile_image: Some("DeBio Profile Image uwu".as_bytes().to_vec()),
+						name: "DeBio Labs".as_bytes().to_vec().try_into().unwrap(),
+						email: "DeBio Email".as_bytes().to_vec().try_into().unwrap(),
+						country: CountryCode::try_from("ID".as_bytes().to_vec()).unwrap(),
+						region: RegionCode::try_from("JB".as_bytes().to_vec()).unwrap(),
+						city: CityCode::try_from("CITY".as_bytes().to_vec()).unwrap(),
+						address: "DeBio Address".as_bytes().to_vec().try_into().unwrap(),
+						phone_number: "+6281394653625".as_bytes().to_vec().try_into().unwrap(),
+						website: "DeBio Website".as_bytes().to_vec().try_into().unwrap(),
+						latitude: Some("-6.175392".as_bytes().to_vec().try_into().unwrap()),
+						longitude: Some("106.827153".as_bytes().to_vec().try_into().unwrap()),
+						profile_image: Some(
+							"DeBio Profile Image uwu".as_bytes().to_vec().try_into().unwrap(),
+						),
 					},
 				},
 				2,
@@ -643,8 +780,8 @@ mod tests {
 			System::assert_last_event(RuntimeEvent::Labs(EventC::LabDeregistered(
 				Lab {
 					account_id: 1,
-					services: Vec::new(),
-					certifications: Vec::new(),
+					services: Default::default(),
+					certifications: Default::default(),
 					unstake_at: 0u64.saturated_into(),
 					retrieve_unstake_at: 0u64.saturated_into(),
 					stake_amount: 0u128.saturated_into(),
@@ -654,17 +791,19 @@ mod tests {
 						box_public_key: Keccak256::hash(
 							"0xDb9Af2d1f3ADD2726A132AA7A65Cc9E6fC5761C3".as_bytes(),
 						),
-						name: "DeBio Labs".as_bytes().to_vec(),
-						email: "DeBio Email".as_bytes().to_vec(),
-						country: CountryCode::from_vec("DC".as_bytes().to_vec()),
-						region: RegionCode::from_vec("DB".as_bytes().to_vec()),
-						city: CityCode::from_vec("CITY".as_bytes().to_vec()),
-						address: "DeBio Address".as_bytes().to_vec(),
-						phone_number: "+6281394653625".as_bytes().to_vec(),
-						website: "DeBio Website".as_bytes().to_vec(),
-						latitude: Some("DeBio Latitude".as_bytes().to_vec()),
-						longitude: Some("DeBio Longtitude".as_bytes().to_vec()),
-						profile_image: Some("DeBio Profile Image uwu".as_bytes().to_vec()),
+						name: "DeBio Labs".as_bytes().to_vec().try_into().unwrap(),
+						email: "DeBio Email".as_bytes().to_vec().try_into().unwrap(),
+						country: CountryCode::try_from("ID".as_bytes().to_vec()).unwrap(),
+						region: RegionCode::try_from("JB".as_bytes().to_vec()).unwrap(),
+						city: CityCode::try_from("CITY".as_bytes().to_vec()).unwrap(),
+						address: "DeBio Address".as_bytes().to_vec().try_into().unwrap(),
+						phone_number: "+6281394653625".as_bytes().to_vec().try_into().unwrap(),
+						website: "DeBio Website".as_bytes().to_vec().try_into().unwrap(),
+						latitude: Some("-6.175392".as_bytes().to_vec().try_into().unwrap()),
+						longitude: Some("106.827153".as_bytes().to_vec().try_into().unwrap()),
+						profile_image: Some(
+							"DeBio Profile Image uwu".as_bytes().to_vec().try_into().unwrap(),
+						),
 					},
 				},
 				1,
@@ -697,17 +836,19 @@ mod tests {
 					box_public_key: Keccak256::hash(
 						"0xDb9Af2d1f3ADD2726A132AA7A65Cc9E6fC5761C3".as_bytes()
 					),
-					name: "DeBio Lab".as_bytes().to_vec(),
-					email: "DeBio Email".as_bytes().to_vec(),
-					country: CountryCode::from_vec("DC".as_bytes().to_vec()),
-					region: RegionCode::from_vec("DB".as_bytes().to_vec()),
-					city: CityCode::from_vec("CITY".as_bytes().to_vec()),
-					address: "DeBio Address".as_bytes().to_vec(),
-					phone_number: "+6281394653625".as_bytes().to_vec(),
-					website: "DeBio Website".as_bytes().to_vec(),
-					latitude: Some("DeBio Latitude".as_bytes().to_vec()),
-					longitude: Some("DeBio Longtitude".as_bytes().to_vec()),
-					profile_image: Some("DeBio Profile Image uwu".as_bytes().to_vec()),
+					name: "DeBio Lab".as_bytes().to_vec().try_into().unwrap(),
+					email: "DeBio Email".as_bytes().to_vec().try_into().unwrap(),
+					country: CountryCode::try_from("ID".as_bytes().to_vec()).unwrap(),
+					region: RegionCode::try_from("JB".as_bytes().to_vec()).unwrap(),
+					city: CityCode::try_from("CITY".as_bytes().to_vec()).unwrap(),
+					address: "DeBio Address".as_bytes().to_vec().try_into().unwrap(),
+					phone_number: "+6281394653625".as_bytes().to_vec().try_into().unwrap(),
+					website: "DeBio Website".as_bytes().to_vec().try_into().unwrap(),
+					latitude: Some("-6.175392".as_bytes().to_vec().try_into().unwrap()),
+					longitude: Some("106.827153".as_bytes().to_vec().try_into().unwrap()),
+					profile_image: Some(
+						"DeBio Profile Image uwu".as_bytes().to_vec().try_into().unwrap()
+					),
 				}
 			));
 
@@ -717,8 +858,8 @@ mod tests {
 				Labs::lab_by_account_id(1),
 				Some(Lab {
 					account_id: 1,
-					services: Vec::new(),
-					certifications: Vec::new(),
+					services: Default::default(),
+					certifications: Default::default(),
 					unstake_at: 0u64.saturated_into(),
 					retrieve_unstake_at: 0u64.saturated_into(),
 					stake_amount: 60000000000000000000000u128.saturated_into(),
@@ -728,17 +869,19 @@ mod tests {
 						box_public_key: Keccak256::hash(
 							"0xDb9Af2d1f3ADD2726A132AA7A65Cc9E6fC5761C3".as_bytes()
 						),
-						name: "DeBio Lab".as_bytes().to_vec(),
-						email: "DeBio Email".as_bytes().to_vec(),
-						country: CountryCode::from_vec("DC".as_bytes().to_vec()),
-						region: RegionCode::from_vec("DB".as_bytes().to_vec()),
-						city: CityCode::from_vec("CITY".as_bytes().to_vec()),
-						address: "DeBio Address".as_bytes().to_vec(),
-						phone_number: "+6281394653625".as_bytes().to_vec(),
-						website: "DeBio Website".as_bytes().to_vec(),
-						latitude: Some("DeBio Latitude".as_bytes().to_vec()),
-						longitude: Some("DeBio Longtitude".as_bytes().to_vec()),
-						profile_image: Some("DeBio Profile Image uwu".as_bytes().to_vec()),
+						name: "DeBio Lab".as_bytes().to_vec().try_into().unwrap(),
+						email: "DeBio Email".as_bytes().to_vec().try_into().unwrap(),
+						country: CountryCode::try_from("ID".as_bytes().to_vec()).unwrap(),
+						region: RegionCode::try_from("JB".as_bytes().to_vec()).unwrap(),
+						city: CityCode::try_from("CITY".as_bytes().to_vec()).unwrap(),
+						address: "DeBio Address".as_bytes().to_vec().try_into().unwrap(),
+						phone_number: "+6281394653625".as_bytes().to_vec().try_into().unwrap(),
+						website: "DeBio Website".as_bytes().to_vec().try_into().unwrap(),
+						latitude: Some("-6.175392".as_bytes().to_vec().try_into().unwrap()),
+						longitude: Some("106.827153".as_bytes().to_vec().try_into().unwrap()),
+						profile_image: Some(
+							"DeBio Profile Image uwu".as_bytes().to_vec().try_into().unwrap()
+						),
 					}
 				})
 			);
@@ -773,17 +916,19 @@ mod tests {
 					box_public_key: Keccak256::hash(
 						"0xDb9Af2d1f3ADD2726A132AA7A65Cc9E6fC5761C3".as_bytes()
 					),
-					name: "DeBio Lab".as_bytes().to_vec(),
-					email: "DeBio Email".as_bytes().to_vec(),
-					country: CountryCode::from_vec("DC".as_bytes().to_vec()),
-					region: RegionCode::from_vec("DB".as_bytes().to_vec()),
-					city: CityCode::from_vec("CITY".as_bytes().to_vec()),
-					address: "DeBio Address".as_bytes().to_vec(),
-					phone_number: "+6281394653625".as_bytes().to_vec(),
-					website: "DeBio Website".as_bytes().to_vec(),
-					latitude: Some("DeBio Latitude".as_bytes().to_vec()),
-					longitude: Some("DeBio Longtitude".as_bytes().to_vec()),
-					profile_image: Some("DeBio Profile Image uwu".as_bytes().to_vec()),
+					name: "DeBio Lab".as_bytes().to_vec().try_into().unwrap(),
+					email: "DeBio Email".as_bytes().to_vec().try_into().unwrap(),
+					country: CountryCode::try_from("ID".as_bytes().to_vec()).unwrap(),
+					region: RegionCode::try_from("JB".as_bytes().to_vec()).unwrap(),
+					city: CityCode::try_from("CITY".as_bytes().to_vec()).unwrap(),
+					address: "DeBio Address".as_bytes().to_vec().try_into().unwrap(),
+					phone_number: "+6281394653625".as_bytes().to_vec().try_into().unwrap(),
+					website: "DeBio Website".as_bytes().to_vec().try_into().unwrap(),
+					latitude: Some("-6.175392".as_bytes().to_vec().try_into().unwrap()),
+					longitude: Some("106.827153".as_bytes().to_vec().try_into().unwrap()),
+					profile_image: Some(
+						"DeBio Profile Image uwu".as_bytes().to_vec().try_into().unwrap()
+					),
 				}
 			));
 
@@ -793,8 +938,8 @@ mod tests {
 				Labs::lab_by_account_id(1),
 				Some(Lab {
 					account_id: 1,
-					services: Vec::new(),
-					certifications: Vec::new(),
+					services: Default::default(),
+					certifications: Default::default(),
 					unstake_at: 0u64.saturated_into(),
 					retrieve_unstake_at: 0u64.saturated_into(),
 					stake_amount: 50000000000000000000000u128.saturated_into(),
@@ -804,17 +949,19 @@ mod tests {
 						box_public_key: Keccak256::hash(
 							"0xDb9Af2d1f3ADD2726A132AA7A65Cc9E6fC5761C3".as_bytes()
 						),
-						name: "DeBio Lab".as_bytes().to_vec(),
-						email: "DeBio Email".as_bytes().to_vec(),
-						country: CountryCode::from_vec("DC".as_bytes().to_vec()),
-						region: RegionCode::from_vec("DB".as_bytes().to_vec()),
-						city: CityCode::from_vec("CITY".as_bytes().to_vec()),
-						address: "DeBio Address".as_bytes().to_vec(),
-						phone_number: "+6281394653625".as_bytes().to_vec(),
-						website: "DeBio Website".as_bytes().to_vec(),
-						latitude: Some("DeBio Latitude".as_bytes().to_vec()),
-						longitude: Some("DeBio Longtitude".as_bytes().to_vec()),
-						profile_image: Some("DeBio Profile Image uwu".as_bytes().to_vec()),
+						name: "DeBio Lab".as_bytes().to_vec().try_into().unwrap(),
+						email: "DeBio Email".as_bytes().to_vec().try_into().unwrap(),
+						country: CountryCode::try_from("ID".as_bytes().to_vec()).unwrap(),
+						region: RegionCode::try_from("JB".as_bytes().to_vec()).unwrap(),
+						city: CityCode::try_from("CITY".as_bytes().to_vec()).unwrap(),
+						address: "DeBio Address".as_bytes().to_vec().try_into().unwrap(),
+						phone_number: "+6281394653625".as_bytes().to_vec().try_into().unwrap(),
+						website: "DeBio Website".as_bytes().to_vec().try_into().unwrap(),
+						latitude: Some("-6.175392".as_bytes().to_vec().try_into().unwrap()),
+						longitude: Some("106.827153".as_bytes().to_vec().try_into().unwrap()),
+						profile_image: Some(
+							"DeBio Profile Image uwu".as_bytes().to_vec().try_into().unwrap()
+						),
 					}
 				})
 			);
@@ -845,17 +992,19 @@ mod tests {
 					box_public_key: Keccak256::hash(
 						"0xDb9Af2d1f3ADD2726A132AA7A65Cc9E6fC5761C3".as_bytes()
 					),
-					name: "DeBio Lab".as_bytes().to_vec(),
-					email: "DeBio Email".as_bytes().to_vec(),
-					country: CountryCode::from_vec("DC".as_bytes().to_vec()),
-					region: RegionCode::from_vec("DB".as_bytes().to_vec()),
-					city: CityCode::from_vec("CITY".as_bytes().to_vec()),
-					address: "DeBio Address".as_bytes().to_vec(),
-					phone_number: "+6281394653625".as_bytes().to_vec(),
-					website: "DeBio Website".as_bytes().to_vec(),
-					latitude: Some("DeBio Latitude".as_bytes().to_vec()),
-					longitude: Some("DeBio Longtitude".as_bytes().to_vec()),
-					profile_image: Some("DeBio Profile Image uwu".as_bytes().to_vec()),
+					name: "DeBio Lab".as_bytes().to_vec().try_into().unwrap(),
+					email: "DeBio Email".as_bytes().to_vec().try_into().unwrap(),
+					country: CountryCode::try_from("ID".as_bytes().to_vec()).unwrap(),
+					region: RegionCode::try_from("JB".as_bytes().to_vec()).unwrap(),
+					city: CityCode::try_from("CITY".as_bytes().to_vec()).unwrap(),
+					address: "DeBio Address".as_bytes().to_vec().try_into().unwrap(),
+					phone_number: "+6281394653625".as_bytes().to_vec().try_into().unwrap(),
+					website: "DeBio Website".as_bytes().to_vec().try_into().unwrap(),
+					latitude: Some("-6.175392".as_bytes().to_vec().try_into().unwrap()),
+					longitude: Some("106.827153".as_bytes().to_vec().try_into().unwrap()),
+					profile_image: Some(
+						"DeBio Profile Image uwu".as_bytes().to_vec().try_into().unwrap()
+					),
 				}
 			));
 
@@ -869,8 +1018,8 @@ mod tests {
 				Labs::lab_by_account_id(1),
 				Some(Lab {
 					account_id: 1,
-					services: Vec::new(),
-					certifications: Vec::new(),
+					services: Default::default(),
+					certifications: Default::default(),
 					unstake_at: 0u64.saturated_into(),
 					retrieve_unstake_at: 0u64.saturated_into(),
 					stake_amount: 50000000000000000000000u128.saturated_into(),
@@ -880,17 +1029,19 @@ mod tests {
 						box_public_key: Keccak256::hash(
 							"0xDb9Af2d1f3ADD2726A132AA7A65Cc9E6fC5761C3".as_bytes()
 						),
-						name: "DeBio Lab".as_bytes().to_vec(),
-						email: "DeBio Email".as_bytes().to_vec(),
-						country: CountryCode::from_vec("DC".as_bytes().to_vec()),
-						region: RegionCode::from_vec("DB".as_bytes().to_vec()),
-						city: CityCode::from_vec("CITY".as_bytes().to_vec()),
-						address: "DeBio Address".as_bytes().to_vec(),
-						phone_number: "+6281394653625".as_bytes().to_vec(),
-						website: "DeBio Website".as_bytes().to_vec(),
-						latitude: Some("DeBio Latitude".as_bytes().to_vec()),
-						longitude: Some("DeBio Longtitude".as_bytes().to_vec()),
-						profile_image: Some("DeBio Profile Image uwu".as_bytes().to_vec()),
+						name: "DeBio Lab".as_bytes().to_vec().try_into().unwrap(),
+						email: "DeBio Email".as_bytes().to_vec().try_into().unwrap(),
+						country: CountryCode::try_from("ID".as_bytes().to_vec()).unwrap(),
+						region: RegionCode::try_from("JB".as_bytes().to_vec()).unwrap(),
+						city: CityCode::try_from("CITY".as_bytes().to_vec()).unwrap(),
+						address: "DeBio Address".as_bytes().to_vec().try_into().unwrap(),
+						phone_number: "+6281394653625".as_bytes().to_vec().try_into().unwrap(),
+						website: "DeBio Website".as_bytes().to_vec().try_into().unwrap(),
+						latitude: Some("-6.175392".as_bytes().to_vec().try_into().unwrap()),
+						longitude: Some("106.827153".as_bytes().to_vec().try_into().unwrap()),
+						profile_image: Some(
+							"DeBio Profile Image uwu".as_bytes().to_vec().try_into().unwrap()
+						),
 					}
 				})
 			);
@@ -906,17 +1057,19 @@ mod tests {
 					box_public_key: Keccak256::hash(
 						"0xDb9Af2d1f3ADD2726A132AA7A65Cc9E6fC5761C3".as_bytes()
 					),
-					name: "DeBio Lab".as_bytes().to_vec(),
-					email: "DeBio Email".as_bytes().to_vec(),
-					country: CountryCode::from_vec("DC".as_bytes().to_vec()),
-					region: RegionCode::from_vec("DB".as_bytes().to_vec()),
-					city: CityCode::from_vec("CITY".as_bytes().to_vec()),
-					address: "DeBio Address".as_bytes().to_vec(),
-					phone_number: "+6281394653625".as_bytes().to_vec(),
-					website: "DeBio Website".as_bytes().to_vec(),
-					latitude: Some("DeBio Latitude".as_bytes().to_vec()),
-					longitude: Some("DeBio Longtitude".as_bytes().to_vec()),
-					profile_image: Some("DeBio Profile Image uwu".as_bytes().to_vec()),
+					name: "DeBio Lab".as_bytes().to_vec().try_into().unwrap(),
+					email: "DeBio Email".as_bytes().to_vec().try_into().unwrap(),
+					country: CountryCode::try_from("ID".as_bytes().to_vec()).unwrap(),
+					region: RegionCode::try_from("JB".as_bytes().to_vec()).unwrap(),
+					city: CityCode::try_from("CITY".as_bytes().to_vec()).unwrap(),
+					address: "DeBio Address".as_bytes().to_vec().try_into().unwrap(),
+					phone_number: "+6281394653625".as_bytes().to_vec().try_into().unwrap(),
+					website: "DeBio Website".as_bytes().to_vec().try_into().unwrap(),
+					latitude: Some("-6.175392".as_bytes().to_vec().try_into().unwrap()),
+					longitude: Some("106.827153".as_bytes().to_vec().try_into().unwrap()),
+					profile_image: Some(
+						"DeBio Profile Image uwu".as_bytes().to_vec().try_into().unwrap()
+					),
 				}
 			));
 
@@ -929,8 +1082,8 @@ mod tests {
 				Labs::lab_by_account_id(1),
 				Some(Lab {
 					account_id: 1,
-					services: Vec::new(),
-					certifications: Vec::new(),
+					services: Default::default(),
+					certifications: Default::default(),
 					unstake_at: 0u64.saturated_into(),
 					retrieve_unstake_at: 0u64.saturated_into(),
 					stake_amount: 0u128.saturated_into(),
@@ -940,17 +1093,19 @@ mod tests {
 						box_public_key: Keccak256::hash(
 							"0xDb9Af2d1f3ADD2726A132AA7A65Cc9E6fC5761C3".as_bytes()
 						),
-						name: "DeBio Lab".as_bytes().to_vec(),
-						email: "DeBio Email".as_bytes().to_vec(),
-						country: CountryCode::from_vec("DC".as_bytes().to_vec()),
-						region: RegionCode::from_vec("DB".as_bytes().to_vec()),
-						city: CityCode::from_vec("CITY".as_bytes().to_vec()),
-						address: "DeBio Address".as_bytes().to_vec(),
-						phone_number: "+6281394653625".as_bytes().to_vec(),
-						website: "DeBio Website".as_bytes().to_vec(),
-						latitude: Some("DeBio Latitude".as_bytes().to_vec()),
-						longitude: Some("DeBio Longtitude".as_bytes().to_vec()),
-						profile_image: Some("DeBio Profile Image uwu".as_bytes().to_vec()),
+						name: "DeBio Lab".as_bytes().to_vec().try_into().unwrap(),
+						email: "DeBio Email".as_bytes().to_vec().try_into().unwrap(),
+						country: CountryCode::try_from("ID".as_bytes().to_vec()).unwrap(),
+						region: RegionCode::try_from("JB".as_bytes().to_vec()).unwrap(),
+						city: CityCode::try_from("CITY".as_bytes().to_vec()).unwrap(),
+						address: "DeBio Address".as_bytes().to_vec().try_into().unwrap(),
+						phone_number: "+6281394653625".as_bytes().to_vec().try_into().unwrap(),
+						website: "DeBio Website".as_bytes().to_vec().try_into().unwrap(),
+						latitude: Some("-6.175392".as_bytes().to_vec().try_into().unwrap()),
+						longitude: Some("106.827153".as_bytes().to_vec().try_into().unwrap()),
+						profile_image: Some(
+							"DeBio Profile Image uwu".as_bytes().to_vec().try_into().unwrap()
+						),
 					}
 				})
 			);
@@ -1085,17 +1240,19 @@ mod tests {
 					box_public_key: Keccak256::hash(
 						"0xDb9Af2d1f3ADD2726A132AA7A65Cc9E6fC5761C3".as_bytes()
 					),
-					name: "DeBio Lab".as_bytes().to_vec(),
-					email: "DeBio Email".as_bytes().to_vec(),
-					country: CountryCode::from_vec("DC".as_bytes().to_vec()),
-					region: RegionCode::from_vec("DB".as_bytes().to_vec()),
-					city: CityCode::from_vec("CITY".as_bytes().to_vec()),
-					address: "DeBio Address".as_bytes().to_vec(),
-					phone_number: "+6281394653625".as_bytes().to_vec(),
-					website: "DeBio Website".as_bytes().to_vec(),
-					latitude: Some("DeBio Latitude".as_bytes().to_vec()),
-					longitude: Some("DeBio Longtitude".as_bytes().to_vec()),
-					profile_image: Some("DeBio Profile Image uwu".as_bytes().to_vec()),
+					name: "DeBio Lab".as_bytes().to_vec().try_into().unwrap(),
+					email: "DeBio Email".as_bytes().to_vec().try_into().unwrap(),
+					country: CountryCode::try_from("ID".as_bytes().to_vec()).unwrap(),
+					region: RegionCode::try_from("JB".as_bytes().to_vec()).unwrap(),
+					city: CityCode::try_from("CITY".as_bytes().to_vec()).unwrap(),
+					address: "DeBio Address".as_bytes().to_vec().try_into().unwrap(),
+					phone_number: "+6281394653625".as_bytes().to_vec().try_into().unwrap(),
+					website: "DeBio Website".as_bytes().to_vec().try_into().unwrap(),
+					latitude: Some("-6.175392".as_bytes().to_vec().try_into().unwrap()),
+					longitude: Some("106.827153".as_bytes().to_vec().try_into().unwrap()),
+					profile_image: Some(
+						"DeBio Profile Image uwu".as_bytes().to_vec().try_into().unwrap()
+					),
 				}
 			));
 
@@ -1107,8 +1264,8 @@ mod tests {
 				Labs::lab_by_account_id(1),
 				Some(Lab {
 					account_id: 1,
-					services: Vec::new(),
-					certifications: Vec::new(),
+					services: Default::default(),
+					certifications: Default::default(),
 					unstake_at: 0u64.saturated_into(),
 					retrieve_unstake_at: 0u64.saturated_into(),
 					stake_amount: 0u128.saturated_into(),
@@ -1118,17 +1275,19 @@ mod tests {
 						box_public_key: Keccak256::hash(
 							"0xDb9Af2d1f3ADD2726A132AA7A65Cc9E6fC5761C3".as_bytes()
 						),
-						name: "DeBio Lab".as_bytes().to_vec(),
-						email: "DeBio Email".as_bytes().to_vec(),
-						country: CountryCode::from_vec("DC".as_bytes().to_vec()),
-						region: RegionCode::from_vec("DB".as_bytes().to_vec()),
-						city: CityCode::from_vec("CITY".as_bytes().to_vec()),
-						address: "DeBio Address".as_bytes().to_vec(),
-						phone_number: "+6281394653625".as_bytes().to_vec(),
-						website: "DeBio Website".as_bytes().to_vec(),
-						latitude: Some("DeBio Latitude".as_bytes().to_vec()),
-						longitude: Some("DeBio Longtitude".as_bytes().to_vec()),
-						profile_image: Some("DeBio Profile Image uwu".as_bytes().to_vec()),
+						name: "DeBio Lab".as_bytes().to_vec().try_into().unwrap(),
+						email: "DeBio Email".as_bytes().to_vec().try_into().unwrap(),
+						country: CountryCode::try_from("ID".as_bytes().to_vec()).unwrap(),
+						region: RegionCode::try_from("JB".as_bytes().to_vec()).unwrap(),
+						city: CityCode::try_from("CITY".as_bytes().to_vec()).unwrap(),
+						address: "DeBio Address".as_bytes().to_vec().try_into().unwrap(),
+						phone_number: "+6281394653625".as_bytes().to_vec().try_into().unwrap(),
+						website: "DeBio Website".as_bytes().to_vec().try_into().unwrap(),
+						latitude: Some("-6.175392".as_bytes().to_vec().try_into().unwrap()),
+						longitude: Some("106.827153".as_bytes().to_vec().try_into().unwrap()),
+						profile_image: Some(
+							"DeBio Profile Image uwu".as_bytes().to_vec().try_into().unwrap()
+						),
 					}
 				})
 			);
@@ -1154,17 +1313,19 @@ mod tests {
 					box_public_key: Keccak256::hash(
 						"0xDb9Af2d1f3ADD2726A132AA7A65Cc9E6fC5761C3".as_bytes()
 					),
-					name: "DeBio Lab".as_bytes().to_vec(),
-					email: "DeBio Email".as_bytes().to_vec(),
-					country: CountryCode::from_vec("DC".as_bytes().to_vec()),
-					region: RegionCode::from_vec("DB".as_bytes().to_vec()),
-					city: CityCode::from_vec("CITY".as_bytes().to_vec()),
-					address: "DeBio Address".as_bytes().to_vec(),
-					phone_number: "+6281394653625".as_bytes().to_vec(),
-					website: "DeBio Website".as_bytes().to_vec(),
-					latitude: Some("DeBio Latitude".as_bytes().to_vec()),
-					longitude: Some("DeBio Longtitude".as_bytes().to_vec()),
-					profile_image: Some("DeBio Profile Image uwu".as_bytes().to_vec()),
+					name: "DeBio Lab".as_bytes().to_vec().try_into().unwrap(),
+					email: "DeBio Email".as_bytes().to_vec().try_into().unwrap(),
+					country: CountryCode::try_from("ID".as_bytes().to_vec()).unwrap(),
+					region: RegionCode::try_from("JB".as_bytes().to_vec()).unwrap(),
+					city: CityCode::try_from("CITY".as_bytes().to_vec()).unwrap(),
+					address: "DeBio Address".as_bytes().to_vec().try_into().unwrap(),
+					phone_number: "+6281394653625".as_bytes().to_vec().try_into().unwrap(),
+					website: "DeBio Website".as_bytes().to_vec().try_into().unwrap(),
+					latitude: Some("-6.175392".as_bytes().to_vec().try_into().unwrap()),
+					longitude: Some("106.827153".as_bytes().to_vec().try_into().unwrap()),
+					profile_image: Some(
+						"DeBio Profile Image uwu".as_bytes().to_vec().try_into().unwrap()
+					),
 				}
 			));
 
@@ -1198,17 +1359,19 @@ mod tests {
 					box_public_key: Keccak256::hash(
 						"0xDb9Af2d1f3ADD2726A132AA7A65Cc9E6fC5761C3".as_bytes()
 					),
-					name: "DeBio Lab".as_bytes().to_vec(),
-					email: "DeBio Email".as_bytes().to_vec(),
-					country: CountryCode::from_vec("DC".as_bytes().to_vec()),
-					region: RegionCode::from_vec("DB".as_bytes().to_vec()),
-					city: CityCode::from_vec("CITY".as_bytes().to_vec()),
-					address: "DeBio Address".as_bytes().to_vec(),
-					phone_number: "+6281394653625".as_bytes().to_vec(),
-					website: "DeBio Website".as_bytes().to_vec(),
-					latitude: Some("DeBio Latitude".as_bytes().to_vec()),
-					longitude: Some("DeBio Longtitude".as_bytes().to_vec()),
-					profile_image: Some("DeBio Profile Image uwu".as_bytes().to_vec()),
+					name: "DeBio Lab".as_bytes().to_vec().try_into().unwrap(),
+					email: "DeBio Email".as_bytes().to_vec().try_into().unwrap(),
+					country: CountryCode::try_from("ID".as_bytes().to_vec()).unwrap(),
+					region: RegionCode::try_from("JB".as_bytes().to_vec()).unwrap(),
+					city: CityCode::try_from("CITY".as_bytes().to_vec()).unwrap(),
+					address: "DeBio Address".as_bytes().to_vec().try_into().unwrap(),
+					phone_number: "+6281394653625".as_bytes().to_vec().try_into().unwrap(),
+					website: "DeBio Website".as_bytes().to_vec().try_into().unwrap(),
+					latitude: Some("-6.175392".as_bytes().to_vec().try_into().unwrap()),
+					longitude: Some("106.827153".as_bytes().to_vec().try_into().unwrap()),
+					profile_image: Some(
+						"DeBio Profile Image uwu".as_bytes().to_vec().try_into().unwrap()
+					),
 				}
 			));
 
@@ -1217,15 +1380,25 @@ mod tests {
 			assert_ok!(Services::create_service(
 				RuntimeOrigin::signed(1),
 				ServiceInfo {
-					name: "DeBio name".as_bytes().to_vec(),
-					prices_by_currency: vec![PriceByCurrency::default()],
+					name: "DeBio name".as_bytes().to_vec().try_into().unwrap(),
+					prices_by_currency: vec![PriceByCurrency::default()].try_into().unwrap(),
 					expected_duration: ExpectedDuration::default(),
-					category: "DeBio category".as_bytes().to_vec(),
-					description: "This is my description".as_bytes().to_vec(),
-					test_result_sample: "Test result sample".as_bytes().to_vec(),
-					dna_collection_process: "Dna Collection Process".as_bytes().to_vec(),
-					long_description: Some("This is my long description".as_bytes().to_vec()),
-					image: Some("This is my image".as_bytes().to_vec()),
+					category: "DeBio category".as_bytes().to_vec().try_into().unwrap(),
+					description: "This is my description".as_bytes().to_vec().try_into().unwrap(),
+					test_result_sample: "Test result sample"
+						.as_bytes()
+						.to_vec()
+						.try_into()
+						.unwrap(),
+					dna_collection_process: "Dna Collection Process"
+						.as_bytes()
+						.to_vec()
+						.try_into()
+						.unwrap(),
+					long_description: Some(
+						"This is my long description".as_bytes().to_vec().try_into().unwrap()
+					),
+					image: Some("This is my image".as_bytes().to_vec().try_into().unwrap()),
 				},
 				ServiceFlow::default()
 			));
@@ -1242,7 +1415,7 @@ mod tests {
 			));
 
 			let _order_id = Orders::last_order_by_customer_id(2).unwrap();
-			let _dna_sample = GeneticTesting::dna_samples_by_lab_id(1).unwrap();
+			let _dna_sample = GeneticTesting::dna_samples_by_lab_id(&1);
 
 			assert_eq!(
 				Orders::order_by_id(&_order_id),
@@ -1255,6 +1428,7 @@ mod tests {
 					),
 					seller_id: 1,
 					total_price: 0,
+					paid_amount: 0,
 					asset_id: None,
 					dna_sample_tracking_id: _dna_sample[0].clone(),
 					currency: CurrencyType::default(),
@@ -1299,17 +1473,19 @@ mod tests {
 					box_public_key: Keccak256::hash(
 						"0xDb9Af2d1f3ADD2726A132AA7A65Cc9E6fC5761C3".as_bytes()
 					),
-					name: "DeBio Lab".as_bytes().to_vec(),
-					email: "DeBio Email".as_bytes().to_vec(),
-					country: CountryCode::from_vec("DC".as_bytes().to_vec()),
-					region: RegionCode::from_vec("DB".as_bytes().to_vec()),
-					city: CityCode::from_vec("CITY".as_bytes().to_vec()),
-					address: "DeBio Address".as_bytes().to_vec(),
-					phone_number: "+6281394653625".as_bytes().to_vec(),
-					website: "DeBio Website".as_bytes().to_vec(),
-					latitude: Some("DeBio Latitude".as_bytes().to_vec()),
-					longitude: Some("DeBio Longtitude".as_bytes().to_vec()),
-					profile_image: Some("DeBio Profile Image uwu".as_bytes().to_vec()),
+					name: "DeBio Lab".as_bytes().to_vec().try_into().unwrap(),
+					email: "DeBio Email".as_bytes().to_vec().try_into().unwrap(),
+					country: CountryCode::try_from("ID".as_bytes().to_vec()).unwrap(),
+					region: RegionCode::try_from("JB".as_bytes().to_vec()).unwrap(),
+					city: CityCode::try_from("CITY".as_bytes().to_vec()).unwrap(),
+					address: "DeBio Address".as_bytes().to_vec().try_into().unwrap(),
+					phone_number: "+6281394653625".as_bytes().to_vec().try_into().unwrap(),
+					website: "DeBio Website".as_bytes().to_vec().try_into().unwrap(),
+					latitude: Some("-6.175392".as_bytes().to_vec().try_into().unwrap()),
+					longitude: Some("106.827153".as_bytes().to_vec().try_into().unwrap()),
+					profile_image: Some(
+						"DeBio Profile Image uwu".as_bytes().to_vec().try_into().unwrap()
+					),
 				}
 			));
 
@@ -1318,17 +1494,29 @@ mod tests {
 			assert_ok!(Services::create_service(
 				RuntimeOrigin::signed(1),
 				ServiceInfo {
-					name: "DeBio service name".as_bytes().to_vec(),
-					prices_by_currency: vec![PriceByCurrency::default()],
+					name: "DeBio service name".as_bytes().to_vec().try_into().unwrap(),
+					prices_by_currency: vec![PriceByCurrency::default()].try_into().unwrap(),
 					expected_duration: ExpectedDuration::default(),
-					category: "DeBio service category".as_bytes().to_vec(),
-					description: "DeBio service description".as_bytes().to_vec(),
+					category: "DeBio service category".as_bytes().to_vec().try_into().unwrap(),
+					description: "DeBio service description"
+						.as_bytes()
+						.to_vec()
+						.try_into()
+						.unwrap(),
 					dna_collection_process: "DeBio service dna_collection_process"
 						.as_bytes()
-						.to_vec(),
-					test_result_sample: "DeBio service test_result_sample".as_bytes().to_vec(),
-					long_description: Some("DeBio service long_description".as_bytes().to_vec()),
-					image: Some("DeBio service image".as_bytes().to_vec()),
+						.to_vec()
+						.try_into()
+						.unwrap(),
+					test_result_sample: "DeBio service test_result_sample"
+						.as_bytes()
+						.to_vec()
+						.try_into()
+						.unwrap(),
+					long_description: Some(
+						"DeBio service long_description".as_bytes().to_vec().try_into().unwrap()
+					),
+					image: Some("DeBio service image".as_bytes().to_vec().try_into().unwrap()),
 				},
 				ServiceFlow::default()
 			));
@@ -1344,7 +1532,7 @@ mod tests {
 				None,
 			));
 
-			let _dna_sample = GeneticTesting::dna_samples_by_lab_id(1).unwrap();
+			let _dna_sample = GeneticTesting::dna_samples_by_lab_id(&1);
 			let _order_id = Orders::last_order_by_customer_id(2).unwrap();
 
 			assert_ok!(Orders::set_order_paid(RuntimeOrigin::signed(2), _order_id));
@@ -1390,17 +1578,19 @@ mod tests {
 					box_public_key: Keccak256::hash(
 						"0xDb9Af2d1f3ADD2726A132AA7A65Cc9E6fC5761C3".as_bytes()
 					),
-					name: "DeBio Lab".as_bytes().to_vec(),
-					email: "DeBio Email".as_bytes().to_vec(),
-					country: CountryCode::from_vec("DC".as_bytes().to_vec()),
-					region: RegionCode::from_vec("DB".as_bytes().to_vec()),
-					city: CityCode::from_vec("CITY".as_bytes().to_vec()),
-					address: "DeBio Address".as_bytes().to_vec(),
-					phone_number: "+6281394653625".as_bytes().to_vec(),
-					website: "DeBio Website".as_bytes().to_vec(),
-					latitude: Some("DeBio Latitude".as_bytes().to_vec()),
-					longitude: Some("DeBio Longtitude".as_bytes().to_vec()),
-					profile_image: Some("DeBio Profile Image uwu".as_bytes().to_vec()),
+					name: "DeBio Lab".as_bytes().to_vec().try_into().unwrap(),
+					email: "DeBio Email".as_bytes().to_vec().try_into().unwrap(),
+					country: CountryCode::try_from("ID".as_bytes().to_vec()).unwrap(),
+					region: RegionCode::try_from("JB".as_bytes().to_vec()).unwrap(),
+					city: CityCode::try_from("CITY".as_bytes().to_vec()).unwrap(),
+					address: "DeBio Address".as_bytes().to_vec().try_into().unwrap(),
+					phone_number: "+6281394653625".as_bytes().to_vec().try_into().unwrap(),
+					website: "DeBio Website".as_bytes().to_vec().try_into().unwrap(),
+					latitude: Some("-6.175392".as_bytes().to_vec().try_into().unwrap()),
+					longitude: Some("106.827153".as_bytes().to_vec().try_into().unwrap()),
+					profile_image: Some(
+						"DeBio Profile Image uwu".as_bytes().to_vec().try_into().unwrap()
+					),
 				}
 			));
 
@@ -1414,17 +1604,29 @@ mod tests {
 			assert_ok!(Services::create_service(
 				RuntimeOrigin::signed(1),
 				ServiceInfo {
-					name: "DeBio service name".as_bytes().to_vec(),
-					prices_by_currency: vec![PriceByCurrency::default()],
+					name: "DeBio service name".as_bytes().to_vec().try_into().unwrap(),
+					prices_by_currency: vec![PriceByCurrency::default()].try_into().unwrap(),
 					expected_duration: ExpectedDuration::default(),
-					category: "DeBio service category".as_bytes().to_vec(),
-					description: "DeBio service description".as_bytes().to_vec(),
+					category: "DeBio service category".as_bytes().to_vec().try_into().unwrap(),
+					description: "DeBio service description"
+						.as_bytes()
+						.to_vec()
+						.try_into()
+						.unwrap(),
 					dna_collection_process: "DeBio service dna_collection_process"
 						.as_bytes()
-						.to_vec(),
-					test_result_sample: "DeBio service test_result_sample".as_bytes().to_vec(),
-					long_description: Some("DeBio service long_description".as_bytes().to_vec()),
-					image: Some("DeBio service image".as_bytes().to_vec()),
+						.to_vec()
+						.try_into()
+						.unwrap(),
+					test_result_sample: "DeBio service test_result_sample"
+						.as_bytes()
+						.to_vec()
+						.try_into()
+						.unwrap(),
+					long_description: Some(
+						"DeBio service long_description".as_bytes().to_vec().try_into().unwrap()
+					),
+					image: Some("DeBio service image".as_bytes().to_vec().try_into().unwrap()),
 				},
 				ServiceFlow::default()
 			));
@@ -1440,7 +1642,7 @@ mod tests {
 				None,
 			));
 
-			let _dna_sample = GeneticTesting::dna_samples_by_lab_id(1).unwrap();
+			let _dna_sample = GeneticTesting::dna_samples_by_lab_id(&1);
 			let _order_id = Orders::last_order_by_customer_id(2).unwrap();
 
 			assert_ok!(Orders::set_order_paid(RuntimeOrigin::signed(2), _order_id));
@@ -1448,9 +1650,15 @@ mod tests {
 				RuntimeOrigin::signed(1),
 				_dna_sample[0].clone(),
 				DnaTestResultSubmission {
-					comments: Some("DNA Test Result comments".as_bytes().to_vec()),
-					result_link: Some("DNA Test Result result_link".as_bytes().to_vec()),
-					report_link: Some("DNA Test Result report_link".as_bytes().to_vec())
+					comments: Some(
+						"DNA Test Result comments".as_bytes().to_vec().try_into().unwrap()
+					),
+					result_link: Some(
+						"DNA Test Result result_link".as_bytes().to_vec().try_into().unwrap()
+					),
+					report_link: Some(
+						"DNA Test Result report_link".as_bytes().to_vec().try_into().unwrap()
+					)
 				}
 			));
 
@@ -1462,15 +1670,15 @@ mod tests {
 			assert_eq!(_dna_test_result.owner_id, 2);
 			assert_eq!(
 				_dna_test_result.comments,
-				Some("DNA Test Result comments".as_bytes().to_vec())
+				Some("DNA Test Result comments".as_bytes().to_vec().try_into().unwrap())
 			);
 			assert_eq!(
 				_dna_test_result.result_link,
-				Some("DNA Test Result result_link".as_bytes().to_vec())
+				Some("DNA Test Result result_link".as_bytes().to_vec().try_into().unwrap())
 			);
 			assert_eq!(
 				_dna_test_result.report_link,
-				Some("DNA Test Result report_link".as_bytes().to_vec())
+				Some("DNA Test Result report_link".as_bytes().to_vec().try_into().unwrap())
 			);
 
 			assert_ok!(GeneticTesting::process_dna_sample(
@@ -1508,17 +1716,19 @@ mod tests {
 					box_public_key: Keccak256::hash(
 						"0xDb9Af2d1f3ADD2726A132AA7A65Cc9E6fC5761C3".as_bytes()
 					),
-					name: "DeBio Lab".as_bytes().to_vec(),
-					email: "DeBio Email".as_bytes().to_vec(),
-					country: CountryCode::from_vec("DC".as_bytes().to_vec()),
-					region: RegionCode::from_vec("DB".as_bytes().to_vec()),
-					city: CityCode::from_vec("CITY".as_bytes().to_vec()),
-					address: "DeBio Address".as_bytes().to_vec(),
-					phone_number: "+6281394653625".as_bytes().to_vec(),
-					website: "DeBio Website".as_bytes().to_vec(),
-					latitude: Some("DeBio Latitude".as_bytes().to_vec()),
-					longitude: Some("DeBio Longtitude".as_bytes().to_vec()),
-					profile_image: Some("DeBio Profile Image uwu".as_bytes().to_vec()),
+					name: "DeBio Lab".as_bytes().to_vec().try_into().unwrap(),
+					email: "DeBio Email".as_bytes().to_vec().try_into().unwrap(),
+					country: CountryCode::try_from("ID".as_bytes().to_vec()).unwrap(),
+					region: RegionCode::try_from("JB".as_bytes().to_vec()).unwrap(),
+					city: CityCode::try_from("CITY".as_bytes().to_vec()).unwrap(),
+					address: "DeBio Address".as_bytes().to_vec().try_into().unwrap(),
+					phone_number: "+6281394653625".as_bytes().to_vec().try_into().unwrap(),
+					website: "DeBio Website".as_bytes().to_vec().try_into().unwrap(),
+					latitude: Some("-6.175392".as_bytes().to_vec().try_into().unwrap()),
+					longitude: Some("106.827153".as_bytes().to_vec().try_into().unwrap()),
+					profile_image: Some(
+						"DeBio Profile Image uwu".as_bytes().to_vec().try_into().unwrap()
+					),
 				}
 			));
 
@@ -1530,8 +1740,8 @@ mod tests {
 				Labs::lab_by_account_id(1),
 				Some(Lab {
 					account_id: 1,
-					services: Vec::new(),
-					certifications: Vec::new(),
+					services: Default::default(),
+					certifications: Default::default(),
 					unstake_at: 0u64.saturated_into(),
 					retrieve_unstake_at: 0u64.saturated_into(),
 					stake_amount: 50000000000000000000000u128.saturated_into(),
@@ -1541,17 +1751,19 @@ mod tests {
 						box_public_key: Keccak256::hash(
 							"0xDb9Af2d1f3ADD2726A132AA7A65Cc9E6fC5761C3".as_bytes()
 						),
-						name: "DeBio Lab".as_bytes().to_vec(),
-						email: "DeBio Email".as_bytes().to_vec(),
-						country: CountryCode::from_vec("DC".as_bytes().to_vec()),
-						region: RegionCode::from_vec("DB".as_bytes().to_vec()),
-						city: CityCode::from_vec("CITY".as_bytes().to_vec()),
-						address: "DeBio Address".as_bytes().to_vec(),
-						phone_number: "+6281394653625".as_bytes().to_vec(),
-						website: "DeBio Website".as_bytes().to_vec(),
-						latitude: Some("DeBio Latitude".as_bytes().to_vec()),
-						longitude: Some("DeBio Longtitude".as_bytes().to_vec()),
-						profile_image: Some("DeBio Profile Image uwu".as_bytes().to_vec()),
+						name: "DeBio Lab".as_bytes().to_vec().try_into().unwrap(),
+						email: "DeBio Email".as_bytes().to_vec().try_into().unwrap(),
+						country: CountryCode::try_from("ID".as_bytes().to_vec()).unwrap(),
+						region: RegionCode::try_from("JB".as_bytes().to_vec()).unwrap(),
+						city: CityCode::try_from("CITY".as_bytes().to_vec()).unwrap(),
+						address: "DeBio Address".as_bytes().to_vec().try_into().unwrap(),
+						phone_number: "+6281394653625".as_bytes().to_vec().try_into().unwrap(),
+						website: "DeBio Website".as_bytes().to_vec().try_into().unwrap(),
+						latitude: Some("-6.175392".as_bytes().to_vec().try_into().unwrap()),
+						longitude: Some("106.827153".as_bytes().to_vec().try_into().unwrap()),
+						profile_image: Some(
+							"DeBio Profile Image uwu".as_bytes().to_vec().try_into().unwrap()
+						),
 					}
 				})
 			);
@@ -1562,8 +1774,8 @@ mod tests {
 				Labs::lab_by_account_id(1),
 				Some(Lab {
 					account_id: 1,
-					services: Vec::new(),
-					certifications: Vec::new(),
+					services: Default::default(),
+					certifications: Default::default(),
 					unstake_at: 0u64.saturated_into(),
 					retrieve_unstake_at: 0u64.saturated_into(),
 					stake_amount: 0u128.saturated_into(),
@@ -1573,17 +1785,19 @@ mod tests {
 						box_public_key: Keccak256::hash(
 							"0xDb9Af2d1f3ADD2726A132AA7A65Cc9E6fC5761C3".as_bytes()
 						),
-						name: "DeBio Lab".as_bytes().to_vec(),
-						email: "DeBio Email".as_bytes().to_vec(),
-						country: CountryCode::from_vec("DC".as_bytes().to_vec()),
-						region: RegionCode::from_vec("DB".as_bytes().to_vec()),
-						city: CityCode::from_vec("CITY".as_bytes().to_vec()),
-						address: "DeBio Address".as_bytes().to_vec(),
-						phone_number: "+6281394653625".as_bytes().to_vec(),
-						website: "DeBio Website".as_bytes().to_vec(),
-						latitude: Some("DeBio Latitude".as_bytes().to_vec()),
-						longitude: Some("DeBio Longtitude".as_bytes().to_vec()),
-						profile_image: Some("DeBio Profile Image uwu".as_bytes().to_vec()),
+						name: "DeBio Lab".as_bytes().to_vec().try_into().unwrap(),
+						email: "DeBio Email".as_bytes().to_vec().try_into().unwrap(),
+						country: CountryCode::try_from("ID".as_bytes().to_vec()).unwrap(),
+						region: RegionCode::try_from("JB".as_bytes().to_vec()).unwrap(),
+						city: CityCode::try_from("CITY".as_bytes().to_vec()).unwrap(),
+						address: "DeBio Address".as_bytes().to_vec().try_into().unwrap(),
+						phone_number: "+6281394653625".as_bytes().to_vec().try_into().unwrap(),
+						website: "DeBio Website".as_bytes().to_vec().try_into().unwrap(),
+						latitude: Some("-6.175392".as_bytes().to_vec().try_into().unwrap()),
+						longitude: Some("106.827153".as_bytes().to_vec().try_into().unwrap()),
+						profile_image: Some(
+							"DeBio Profile Image uwu".as_bytes().to_vec().try_into().unwrap()
+						),
 					}
 				})
 			);
@@ -1613,17 +1827,19 @@ mod tests {
 					box_public_key: Keccak256::hash(
 						"0xDb9Af2d1f3ADD2726A132AA7A65Cc9E6fC5761C3".as_bytes()
 					),
-					name: "DeBio Lab".as_bytes().to_vec(),
-					email: "DeBio Email".as_bytes().to_vec(),
-					country: CountryCode::from_vec("DC".as_bytes().to_vec()),
-					region: RegionCode::from_vec("DB".as_bytes().to_vec()),
-					city: CityCode::from_vec("CITY".as_bytes().to_vec()),
-					address: "DeBio Address".as_bytes().to_vec(),
-					phone_number: "+6281394653625".as_bytes().to_vec(),
-					website: "DeBio Website".as_bytes().to_vec(),
-					latitude: Some("DeBio Latitude".as_bytes().to_vec()),
-					longitude: Some("DeBio Longtitude".as_bytes().to_vec()),
-					profile_image: Some("DeBio Profile Image uwu".as_bytes().to_vec()),
+					name: "DeBio Lab".as_bytes().to_vec().try_into().unwrap(),
+					email: "DeBio Email".as_bytes().to_vec().try_into().unwrap(),
+					country: CountryCode::try_from("ID".as_bytes().to_vec()).unwrap(),
+					region: RegionCode::try_from("JB".as_bytes().to_vec()).unwrap(),
+					city: CityCode::try_from("CITY".as_bytes().to_vec()).unwrap(),
+					address: "DeBio Address".as_bytes().to_vec().try_into().unwrap(),
+					phone_number: "+6281394653625".as_bytes().to_vec().try_into().unwrap(),
+					website: "DeBio Website".as_bytes().to_vec().try_into().unwrap(),
+					latitude: Some("-6.175392".as_bytes().to_vec().try_into().unwrap()),
+					longitude: Some("106.827153".as_bytes().to_vec().try_into().unwrap()),
+					profile_image: Some(
+						"DeBio Profile Image uwu".as_bytes().to_vec().try_into().unwrap()
+					),
 				}
 			));
 
@@ -1658,17 +1874,19 @@ mod tests {
 					box_public_key: Keccak256::hash(
 						"0xDb9Af2d1f3ADD2726A132AA7A65Cc9E6fC5761C3".as_bytes()
 					),
-					name: "DeBio Lab".as_bytes().to_vec(),
-					email: "DeBio Email".as_bytes().to_vec(),
-					country: CountryCode::from_vec("DC".as_bytes().to_vec()),
-					region: RegionCode::from_vec("DB".as_bytes().to_vec()),
-					city: CityCode::from_vec("CITY".as_bytes().to_vec()),
-					address: "DeBio Address".as_bytes().to_vec(),
-					phone_number: "+6281394653625".as_bytes().to_vec(),
-					website: "DeBio Website".as_bytes().to_vec(),
-					latitude: Some("DeBio Latitude".as_bytes().to_vec()),
-					longitude: Some("DeBio Longtitude".as_bytes().to_vec()),
-					profile_image: Some("DeBio Profile Image uwu".as_bytes().to_vec()),
+					name: "DeBio Lab".as_bytes().to_vec().try_into().unwrap(),
+					email: "DeBio Email".as_bytes().to_vec().try_into().unwrap(),
+					country: CountryCode::try_from("ID".as_bytes().to_vec()).unwrap(),
+					region: RegionCode::try_from("JB".as_bytes().to_vec()).unwrap(),
+					city: CityCode::try_from("CITY".as_bytes().to_vec()).unwrap(),
+					address: "DeBio Address".as_bytes().to_vec().try_into().unwrap(),
+					phone_number: "+6281394653625".as_bytes().to_vec().try_into().unwrap(),
+					website: "DeBio Website".as_bytes().to_vec().try_into().unwrap(),
+					latitude: Some("-6.175392".as_bytes().to_vec().try_into().unwrap()),
+					longitude: Some("106.827153".as_bytes().to_vec().try_into().unwrap()),
+					profile_image: Some(
+						"DeBio Profile Image uwu".as_bytes().to_vec().try_into().unwrap()
+					),
 				}
 			));
 
@@ -1680,8 +1898,8 @@ mod tests {
 				Labs::lab_by_account_id(1),
 				Some(Lab {
 					account_id: 1,
-					services: Vec::new(),
-					certifications: Vec::new(),
+					services: Default::default(),
+					certifications: Default::default(),
 					unstake_at: 0u64.saturated_into(),
 					retrieve_unstake_at: 100000u64.saturated_into(),
 					stake_amount: 50000000000000000000000u128.saturated_into(),
@@ -1691,17 +1909,19 @@ mod tests {
 						box_public_key: Keccak256::hash(
 							"0xDb9Af2d1f3ADD2726A132AA7A65Cc9E6fC5761C3".as_bytes()
 						),
-						name: "DeBio Lab".as_bytes().to_vec(),
-						email: "DeBio Email".as_bytes().to_vec(),
-						country: CountryCode::from_vec("DC".as_bytes().to_vec()),
-						region: RegionCode::from_vec("DB".as_bytes().to_vec()),
-						city: CityCode::from_vec("CITY".as_bytes().to_vec()),
-						address: "DeBio Address".as_bytes().to_vec(),
-						phone_number: "+6281394653625".as_bytes().to_vec(),
-						website: "DeBio Website".as_bytes().to_vec(),
-						latitude: Some("DeBio Latitude".as_bytes().to_vec()),
-						longitude: Some("DeBio Longtitude".as_bytes().to_vec()),
-						profile_image: Some("DeBio Profile Image uwu".as_bytes().to_vec()),
+						name: "DeBio Lab".as_bytes().to_vec().try_into().unwrap(),
+						email: "DeBio Email".as_bytes().to_vec().try_into().unwrap(),
+						country: CountryCode::try_from("ID".as_bytes().to_vec()).unwrap(),
+						region: RegionCode::try_from("JB".as_bytes().to_vec()).unwrap(),
+						city: CityCode::try_from("CITY".as_bytes().to_vec()).unwrap(),
+						address: "DeBio Address".as_bytes().to_vec().try_into().unwrap(),
+						phone_number: "+6281394653625".as_bytes().to_vec().try_into().unwrap(),
+						website: "DeBio Website".as_bytes().to_vec().try_into().unwrap(),
+						latitude: Some("-6.175392".as_bytes().to_vec().try_into().unwrap()),
+						longitude: Some("106.827153".as_bytes().to_vec().try_into().unwrap()),
+						profile_image: Some(
+							"DeBio Profile Image uwu".as_bytes().to_vec().try_into().unwrap()
+						),
 					}
 				})
 			);
@@ -1737,17 +1957,19 @@ mod tests {
 					box_public_key: Keccak256::hash(
 						"0xDb9Af2d1f3ADD2726A132AA7A65Cc9E6fC5761C3".as_bytes()
 					),
-					name: "DeBio Lab".as_bytes().to_vec(),
-					email: "DeBio Email".as_bytes().to_vec(),
-					country: CountryCode::from_vec("DC".as_bytes().to_vec()),
-					region: RegionCode::from_vec("DB".as_bytes().to_vec()),
-					city: CityCode::from_vec("CITY".as_bytes().to_vec()),
-					address: "DeBio Address".as_bytes().to_vec(),
-					phone_number: "+6281394653625".as_bytes().to_vec(),
-					website: "DeBio Website".as_bytes().to_vec(),
-					latitude: Some("DeBio Latitude".as_bytes().to_vec()),
-					longitude: Some("DeBio Longtitude".as_bytes().to_vec()),
-					profile_image: Some("DeBio Profile Image uwu".as_bytes().to_vec()),
+					name: "DeBio Lab".as_bytes().to_vec().try_into().unwrap(),
+					email: "DeBio Email".as_bytes().to_vec().try_into().unwrap(),
+					country: CountryCode::try_from("ID".as_bytes().to_vec()).unwrap(),
+					region: RegionCode::try_from("JB".as_bytes().to_vec()).unwrap(),
+					city: CityCode::try_from("CITY".as_bytes().to_vec()).unwrap(),
+					address: "DeBio Address".as_bytes().to_vec().try_into().unwrap(),
+					phone_number: "+6281394653625".as_bytes().to_vec().try_into().unwrap(),
+					website: "DeBio Website".as_bytes().to_vec().try_into().unwrap(),
+					latitude: Some("-6.175392".as_bytes().to_vec().try_into().unwrap()),
+					longitude: Some("106.827153".as_bytes().to_vec().try_into().unwrap()),
+					profile_image: Some(
+						"DeBio Profile Image uwu".as_bytes().to_vec().try_into().unwrap()
+					),
 				}
 			));
 
@@ -1768,4 +1990,581 @@ mod tests {
 			);
 		})
 	}
+
+	fn verified_and_staked_lab(account_id: AccountId) {
+		assert_ok!(Balances::set_balance(
+			RawOrigin::Root.into(),
+			account_id,
+			70000000000000000000000u128.saturated_into(),
+			0
+		));
+
+		PalletAccount::<Test>::put(4);
+		LabVerifierKey::<Test>::put(2);
+
+		assert_ok!(Labs::update_minimum_stake_amount(
+			RuntimeOrigin::signed(2),
+			60000000000000000000000u128.saturated_into(),
+		));
+
+		assert_ok!(Labs::register_lab(
+			RuntimeOrigin::signed(account_id),
+			LabInfo {
+				box_public_key: Keccak256::hash(
+					"0xDb9Af2d1f3ADD2726A132AA7A65Cc9E6fC5761C3".as_bytes()
+				),
+				name: "DeBio Lab".as_bytes().to_vec().try_into().unwrap(),
+				email: "DeBio Email".as_bytes().to_vec().try_into().unwrap(),
+				country: CountryCode::try_from("ID".as_bytes().to_vec()).unwrap(),
+				region: RegionCode::try_from("JB".as_bytes().to_vec()).unwrap(),
+				city: CityCode::try_from("CITY".as_bytes().to_vec()).unwrap(),
+				address: "DeBio Address".as_bytes().to_vec().try_into().unwrap(),
+				phone_number: "+6281394653625".as_bytes().to_vec().try_into().unwrap(),
+				website: "DeBio Website".as_bytes().to_vec().try_into().unwrap(),
+				latitude: Some("-6.175392".as_bytes().to_vec().try_into().unwrap()),
+				longitude: Some("106.827153".as_bytes().to_vec().try_into().unwrap()),
+				profile_image: Some(
+					"DeBio Profile Image uwu".as_bytes().to_vec().try_into().unwrap()
+				),
+			}
+		));
+
+		assert_ok!(Labs::update_lab_verification_status(
+			RuntimeOrigin::signed(2),
+			account_id,
+			VerificationStatus::Verified,
+		));
+
+		assert_ok!(Labs::stake_lab(RuntimeOrigin::signed(account_id)));
+	}
+
+	#[test]
+	fn slash_lab_to_customer_works() {
+		<ExternalityBuilder>::default().existential_deposit(1).build().execute_with(|| {
+			verified_and_staked_lab(1);
+
+			assert_ok!(Labs::propose_slash(
+				RuntimeOrigin::root(),
+				1,
+				Perbill::from_percent(10),
+				Some(3),
+			));
+
+			assert_eq!(
+				Labs::pending_slash_by_account_id(1),
+				Some(PendingSlash::new(Perbill::from_percent(10), Some(3), 0))
+			);
+
+			assert_ok!(Labs::apply_slash(RuntimeOrigin::root(), 1));
+
+			let lab = Labs::lab_by_account_id(1).unwrap();
+
+			assert_eq!(lab.stake_amount, 54000000000000000000000u128.saturated_into());
+			assert_eq!(lab.stake_status, StakeStatus::Staked);
+			assert_eq!(lab.verification_status, VerificationStatus::Revoked);
+			assert_eq!(Labs::pending_slash_by_account_id(1), None);
+			assert_eq!(Balances::free_balance(3), 6000000000000000000000u128.saturated_into());
+			assert_eq!(
+				TotalStakedAmount::<Test>::get(),
+				Some(54000000000000000000000u128.saturated_into())
+			);
+
+			System::assert_last_event(RuntimeEvent::Labs(EventC::LabSlashed(
+				lab,
+				6000000000000000000000u128.saturated_into(),
+				3,
+			)));
+		})
+	}
+
+	#[test]
+	fn slash_lab_to_treasury_works() {
+		<ExternalityBuilder>::default().existential_deposit(1).build().execute_with(|| {
+			verified_and_staked_lab(1);
+
+			assert_ok!(Labs::propose_slash(
+				RuntimeOrigin::root(),
+				1,
+				Perbill::from_percent(100),
+				None,
+			));
+
+			assert_noop!(
+				Labs::apply_slash(RuntimeOrigin::root(), 1),
+				Error::<Test>::TreasuryKeyNotFound
+			);
+
+			assert_ok!(Labs::sudo_update_treasury_key(RuntimeOrigin::root(), 5));
+			assert_ok!(Labs::apply_slash(RuntimeOrigin::root(), 1));
+
+			let lab = Labs::lab_by_account_id(1).unwrap();
+
+			assert_eq!(lab.stake_amount, 0u128.saturated_into());
+			assert_eq!(lab.stake_status, StakeStatus::Unstaked);
+			assert_eq!(Balances::free_balance(5), 60000000000000000000000u128.saturated_into());
+		})
+	}
+
+	#[test]
+	fn cant_unstake_lab_when_slash_pending() {
+		<ExternalityBuilder>::default().existential_deposit(1).build().execute_with(|| {
+			verified_and_staked_lab(1);
+
+			assert_ok!(Labs::propose_slash(
+				RuntimeOrigin::root(),
+				1,
+				Perbill::from_percent(10),
+				Some(3),
+			));
+
+			assert_noop!(
+				Labs::unstake_lab(RuntimeOrigin::signed(1)),
+				Error::<Test>::LabHasPendingSlash
+			);
+
+			assert_ok!(Labs::cancel_slash(RuntimeOrigin::root(), 1));
+			assert_ok!(Labs::unstake_lab(RuntimeOrigin::signed(1)));
+
+			assert_ok!(Labs::propose_slash(
+				RuntimeOrigin::root(),
+				1,
+				Perbill::from_percent(10),
+				Some(3),
+			));
+
+			assert_noop!(
+				Labs::retrieve_unstake_amount(RuntimeOrigin::signed(2), 1),
+				Error::<Test>::LabHasPendingSlash
+			);
+		})
+	}
+
+	#[test]
+	fn cant_deregister_lab_when_slash_pending() {
+		<ExternalityBuilder>::default().existential_deposit(1).build().execute_with(|| {
+			verified_and_staked_lab(1);
+
+			assert_ok!(Labs::propose_slash(
+				RuntimeOrigin::root(),
+				1,
+				Perbill::from_percent(10),
+				Some(3),
+			));
+
+			assert_noop!(
+				Labs::deregister_lab(RuntimeOrigin::signed(1)),
+				Error::<Test>::LabHasPendingSlash
+			);
+
+			assert_ok!(Labs::apply_slash(RuntimeOrigin::root(), 1));
+			assert_ok!(Labs::deregister_lab(RuntimeOrigin::signed(1)));
+
+			assert_eq!(Labs::lab_by_account_id(1), None);
+		})
+	}
+
+	#[test]
+	fn cant_propose_slash_when_invalid() {
+		<ExternalityBuilder>::default().existential_deposit(1).build().execute_with(|| {
+			assert_noop!(
+				Labs::propose_slash(RuntimeOrigin::root(), 1, Perbill::from_percent(10), None),
+				Error::<Test>::LabDoesNotExist
+			);
+
+			verified_and_staked_lab(1);
+
+			assert_noop!(
+				Labs::propose_slash(RuntimeOrigin::signed(2), 1, Perbill::from_percent(10), None),
+				BadOrigin
+			);
+
+			assert_ok!(Labs::propose_slash(
+				RuntimeOrigin::root(),
+				1,
+				Perbill::from_percent(10),
+				None,
+			));
+
+			assert_noop!(
+				Labs::propose_slash(RuntimeOrigin::root(), 1, Perbill::from_percent(20), None),
+				Error::<Test>::LabSlashAlreadyPending
+			);
+
+			assert_ok!(Labs::cancel_slash(RuntimeOrigin::root(), 1));
+
+			assert_noop!(
+				Labs::cancel_slash(RuntimeOrigin::root(), 1),
+				Error::<Test>::LabSlashNotPending
+			);
+			assert_noop!(
+				Labs::apply_slash(RuntimeOrigin::root(), 1),
+				Error::<Test>::LabSlashNotPending
+			);
+		})
+	}
+
+	#[test]
+	fn update_lab_verification_status_requires_verified_certification() {
+		<ExternalityBuilder>::default().existential_deposit(1).build().execute_with(|| {
+			RequireVerifiedCertification::set(true);
+
+			assert_ok!(Labs::register_lab(
+				RuntimeOrigin::signed(1),
+				LabInfo {
+					box_public_key: Keccak256::hash(
+						"0xDb9Af2d1f3ADD2726A132AA7A65Cc9E6fC5761C3".as_bytes()
+					),
+					name: "DeBio Lab".as_bytes().to_vec().try_into().unwrap(),
+					email: "DeBio Email".as_bytes().to_vec().try_into().unwrap(),
+					country: CountryCode::try_from("ID".as_bytes().to_vec()).unwrap(),
+					region: RegionCode::try_from("JB".as_bytes().to_vec()).unwrap(),
+					city: CityCode::try_from("CITY".as_bytes().to_vec()).unwrap(),
+					address: "DeBio Address".as_bytes().to_vec().try_into().unwrap(),
+					phone_number: "+6281394653625".as_bytes().to_vec().try_into().unwrap(),
+					website: "DeBio Website".as_bytes().to_vec().try_into().unwrap(),
+					latitude: Some("-6.175392".as_bytes().to_vec().try_into().unwrap()),
+					longitude: Some("106.827153".as_bytes().to_vec().try_into().unwrap()),
+					profile_image: Some(
+						"DeBio Profile Image uwu".as_bytes().to_vec().try_into().unwrap()
+					),
+				}
+			));
+
+			assert_ok!(Certifications::create_credential(
+				RuntimeOrigin::signed(1),
+				CertificationInfo {
+					title: "DeBio title".as_bytes().to_vec().try_into().unwrap(),
+					issuer: "DeBio issuer".as_bytes().to_vec().try_into().unwrap(),
+					month: "DeBio month".as_bytes().to_vec().try_into().unwrap(),
+					year: "DeBio year".as_bytes().to_vec().try_into().unwrap(),
+					description: "DeBio description".as_bytes().to_vec().try_into().unwrap(),
+					supporting_document: Some(
+						"DeBio Profile Image uwu".as_bytes().to_vec().try_into().unwrap()
+					),
+				}
+			));
+
+			let certification_id = Labs::lab_by_account_id(1).unwrap().certifications[0];
+
+			LabVerifierKey::<Test>::put(2);
+
+			assert_noop!(
+				Labs::update_lab_verification_status(
+					RuntimeOrigin::signed(2),
+					1,
+					VerificationStatus::Verified,
+				),
+				Error::<Test>::NoVerifiedCertification
+			);
+
+			// Statuses other than Verified do not need a verified certification
+			assert_ok!(Labs::update_lab_verification_status(
+				RuntimeOrigin::signed(2),
+				1,
+				VerificationStatus::Rejected,
+			));
+
+			assert_ok!(CredentialIssuers::register_issuer(
+				RuntimeOrigin::root(),
+				3,
+				"DeBio Accreditation Body".as_bytes().to_vec()
+			));
+			assert_ok!(CredentialIssuers::verify_credential(
+				RuntimeOrigin::signed(3),
+				certification_id
+			));
+
+			assert_ok!(Labs::update_lab_verification_status(
+				RuntimeOrigin::signed(2),
+				1,
+				VerificationStatus::Verified,
+			));
+
+			assert_eq!(
+				Labs::lab_by_account_id(1).unwrap().verification_status,
+				VerificationStatus::Verified
+			);
+		})
+	}
+
+	#[test]
+	fn cant_register_lab_when_city_is_full() {
+		<ExternalityBuilder>::default().existential_deposit(1).build().execute_with(|| {
+			MaxLabsPerCity::set(1);
+
+			assert_ok!(Labs::register_lab(
+				RuntimeOrigin::signed(1),
+				lab_info_at("-6.914744", "107.609810")
+			));
+
+			assert_noop!(
+				Labs::register_lab(
+					RuntimeOrigin::signed(2),
+					lab_info_at("-6.914744", "107.609810")
+				),
+				Error::<Test>::TooManyLabsInCity
+			);
+
+			// Deregistering frees up the slot
+			assert_ok!(Labs::deregister_lab(RuntimeOrigin::signed(1)));
+			assert_ok!(Labs::register_lab(
+				RuntimeOrigin::signed(2),
+				lab_info_at("-6.914744", "107.609810")
+			));
+		})
+	}
+
+	fn lab_info_at(latitude: &str, longitude: &str) -> LabInfo<sp_core::H256> {
+		LabInfo {
+			box_public_key: Keccak256::hash(
+				"0xDb9Af2d1f3ADD2726A132AA7A65Cc9E6fC5761C3".as_bytes(),
+			),
+			name: "DeBio Lab".as_bytes().to_vec().try_into().unwrap(),
+			email: "DeBio Email".as_bytes().to_vec().try_into().unwrap(),
+			country: CountryCode::try_from("ID".as_bytes().to_vec()).unwrap(),
+			region: RegionCode::try_from("JB".as_bytes().to_vec()).unwrap(),
+			city: CityCode::try_from("CITY".as_bytes().to_vec()).unwrap(),
+			address: "DeBio Address".as_bytes().to_vec().try_into().unwrap(),
+			phone_number: "+6281394653625".as_bytes().to_vec().try_into().unwrap(),
+			website: "DeBio Website".as_bytes().to_vec().try_into().unwrap(),
+			latitude: Some(latitude.as_bytes().to_vec().try_into().unwrap()),
+			longitude: Some(longitude.as_bytes().to_vec().try_into().unwrap()),
+			profile_image: Some("DeBio Profile Image uwu".as_bytes().to_vec().try_into().unwrap()),
+		}
+	}
+
+	fn service_info_in(category: &str) -> ServiceInfo<u128> {
+		ServiceInfo {
+			name: "DeBio name".as_bytes().to_vec().try_into().unwrap(),
+			prices_by_currency: vec![PriceByCurrency::default()].try_into().unwrap(),
+			expected_duration: ExpectedDuration::default(),
+			category: category.as_bytes().to_vec().try_into().unwrap(),
+			description: "This is my description".as_bytes().to_vec().try_into().unwrap(),
+			test_result_sample: "Test result sample".as_bytes().to_vec().try_into().unwrap(),
+			dna_collection_process: "Dna Collection Process"
+				.as_bytes()
+				.to_vec()
+				.try_into()
+				.unwrap(),
+			long_description: Some(
+				"This is my long description".as_bytes().to_vec().try_into().unwrap(),
+			),
+			image: Some("This is my image".as_bytes().to_vec().try_into().unwrap()),
+		}
+	}
+
+	#[test]
+	fn labs_within_radius_works() {
+		<ExternalityBuilder>::default().existential_deposit(1).build().execute_with(|| {
+			LabVerifierKey::<Test>::put(9);
+
+			// Central Jakarta
+			assert_ok!(Labs::register_lab(
+				RuntimeOrigin::signed(1),
+				lab_info_at("-6.175392", "106.827153")
+			));
+			assert_ok!(Services::create_service(
+				RuntimeOrigin::signed(1),
+				service_info_in("DeBio category"),
+				ServiceFlow::default()
+			));
+
+			// South Jakarta
+			assert_ok!(Labs::register_lab(
+				RuntimeOrigin::signed(2),
+				lab_info_at("-6.261493", "106.810600")
+			));
+			assert_ok!(Services::create_service(
+				RuntimeOrigin::signed(2),
+				service_info_in("Other category"),
+				ServiceFlow::default()
+			));
+
+			// Nearby but never verified
+			assert_ok!(Labs::register_lab(
+				RuntimeOrigin::signed(3),
+				lab_info_at("-6.180000", "106.830000")
+			));
+
+			// Bandung, about 120km away
+			assert_ok!(Labs::register_lab(
+				RuntimeOrigin::signed(4),
+				lab_info_at("-6.917464", "107.619123")
+			));
+
+			for lab_id in [1, 2, 4] {
+				assert_ok!(Labs::update_lab_verification_status(
+					RuntimeOrigin::signed(9),
+					lab_id,
+					VerificationStatus::Verified,
+				));
+			}
+
+			assert_eq!(
+				Labs::lab_coordinate_by_account_id(1),
+				Coordinate::new(-6_175_392, 106_827_153)
+			);
+
+			let center = Coordinate::new(-6_200_000, 106_816_666).unwrap();
+			let lab_ids = |radius, category: Option<&str>| {
+				let mut lab_ids = Labs::get_labs_within_radius(
+					&center,
+					radius,
+					category.map(|category| category.as_bytes().to_vec()),
+				)
+				.iter()
+				.map(|lab| lab.account_id)
+				.collect::<Vec<_>>();
+				lab_ids.sort();
+				lab_ids
+			};
+
+			assert_eq!(lab_ids(5_000, None), vec![1]);
+			assert_eq!(lab_ids(10_000, None), vec![1, 2]);
+			assert_eq!(lab_ids(10_000, Some("Other category")), vec![2]);
+			assert_eq!(lab_ids(10_000, Some("Unknown category")), Vec::<u64>::new());
+
+			// Malformed coordinates are rejected
+			assert_noop!(
+				Labs::update_lab(RuntimeOrigin::signed(2), lab_info_at("DeBio Latitude", "106.8")),
+				Error::<Test>::InvalidCoordinate
+			);
+			assert_noop!(
+				Labs::update_lab(
+					RuntimeOrigin::signed(2),
+					LabInfo { longitude: None, ..lab_info_at("-6.261493", "106.810600") }
+				),
+				Error::<Test>::InvalidCoordinate
+			);
+
+			// Leaving out the coordinate removes the lab from the index
+			assert_ok!(Labs::update_lab(
+				RuntimeOrigin::signed(2),
+				LabInfo { latitude: None, longitude: None, ..lab_info_at("0", "0") }
+			));
+			assert_eq!(Labs::lab_coordinate_by_account_id(2), None);
+			assert_eq!(lab_ids(10_000, None), vec![1]);
+
+			assert_ok!(Labs::deregister_lab(RuntimeOrigin::signed(1)));
+			assert_eq!(Labs::lab_coordinate_by_account_id(1), None);
+			assert_eq!(lab_ids(10_000, None), Vec::<u64>::new());
+		})
+	}
+
+	#[test]
+	fn migrate_normalizes_area_codes_and_merges_location_index() {
+		<ExternalityBuilder>::default().existential_deposit(1).build().execute_with(|| {
+			let lab_info = |country, region, city| LabInfo {
+				box_public_key: Keccak256::hash(
+					"0xDb9Af2d1f3ADD2726A132AA7A65Cc9E6fC5761C3".as_bytes(),
+				),
+				name: "DeBio Lab".as_bytes().to_vec().try_into().unwrap(),
+				email: "DeBio Email".as_bytes().to_vec().try_into().unwrap(),
+				country,
+				region,
+				city,
+				address: "DeBio Address".as_bytes().to_vec().try_into().unwrap(),
+				phone_number: "+6281394653625".as_bytes().to_vec().try_into().unwrap(),
+				website: "DeBio Website".as_bytes().to_vec().try_into().unwrap(),
+				latitude: None,
+				longitude: None,
+				profile_image: None,
+			};
+
+			assert_ok!(Labs::register_lab(
+				RuntimeOrigin::signed(1),
+				lab_info(
+					CountryCode::try_from("ID".as_bytes().to_vec()).unwrap(),
+					RegionCode::try_from("JB".as_bytes().to_vec()).unwrap(),
+					CityCode::try_from("CITY".as_bytes().to_vec()).unwrap(),
+				)
+			));
+
+			// A lab stored before area codes were upper cased
+			let country = CountryCode::decode(&mut &b"id"[..]).unwrap();
+			let region = RegionCode::decode(&mut &b"jb".to_vec().encode()[..]).unwrap();
+			let city = CityCode::decode(&mut &b"city".to_vec().encode()[..]).unwrap();
+			let legacy_country_region_code =
+				CountryRegionCode::from_vec("id-jb".as_bytes().to_vec());
+			let lab: LabOf<Test> = Lab::new(2, lab_info(country, region, city.clone()));
+			let lab_ids: LabIdsOf<Test> = vec![2].try_into().unwrap();
+
+			labs::Labs::<Test>::insert(2, lab);
+			LabsByCountryRegionCity::<Test>::insert(&legacy_country_region_code, &city, lab_ids);
+			LabCountByCountryRegionCity::<Test>::insert(&legacy_country_region_code, &city, 1);
+			StorageVersion::new(5).put::<Labs>();
+
+			labs::migrations::migrate::<Test>();
+
+			let country_region_code = CountryRegionCode::from_vec("ID-JB".as_bytes().to_vec());
+			let city_code = CityCode::try_from("CITY".as_bytes().to_vec()).unwrap();
+			let mut lab_ids =
+				Labs::labs_by_country_region_city(&country_region_code, &city_code).unwrap();
+			lab_ids.sort();
+
+			assert_eq!(lab_ids.into_inner(), vec![1, 2]);
+			assert_eq!(
+				Labs::lab_count_by_country_region_city(&country_region_code, &city_code),
+				Some(2)
+			);
+			assert_eq!(Labs::labs_by_country_region_city(&legacy_country_region_code, &city), None);
+			assert_eq!(
+				Labs::lab_count_by_country_region_city(&legacy_country_region_code, &city),
+				None
+			);
+			assert_eq!(Labs::lab_by_account_id(2).unwrap().info.city, city_code);
+			assert_eq!(StorageVersion::get::<Labs>(), StorageVersion::new(6));
+		})
+	}
+
+	#[test]
+	fn migrate_truncates_legacy_area_codes_over_their_bound() {
+		<ExternalityBuilder>::default().existential_deposit(1).build().execute_with(|| {
+			let info = lab_info_at("-6.914744", "107.609810");
+			// Region and city codes were unbounded before, a longer city no longer decodes as
+			// CityCode
+			let legacy_info = (
+				info.box_public_key,
+				info.name.to_vec(),
+				info.email.to_vec(),
+				&info.country,
+				b"jb".to_vec(),
+				vec![b'x'; MAX_AREA_CODE_LENGTH as usize + 1],
+				info.address.to_vec(),
+				info.phone_number.to_vec(),
+				info.website.to_vec(),
+				None::<Vec<u8>>,
+				None::<Vec<u8>>,
+				None::<Vec<u8>>,
+			);
+			let legacy_lab = (
+				1u64,
+				Vec::<sp_core::H256>::new(),
+				Vec::<sp_core::H256>::new(),
+				VerificationStatus::default(),
+				legacy_info,
+				0u128,
+				StakeStatus::default(),
+				0u64,
+				0u64,
+			);
+
+			unhashed::put(&labs::Labs::<Test>::hashed_key_for(1), &legacy_lab);
+			StorageVersion::new(4).put::<Labs>();
+
+			labs::migrations::migrate::<Test>();
+
+			let lab = Labs::lab_by_account_id(1).unwrap();
+			let country_region_code = CountryRegionCode::from_vec("ID-JB".as_bytes().to_vec());
+			let city_code = CityCode::try_from(vec![b'X'; MAX_AREA_CODE_LENGTH as usize]).unwrap();
+
+			assert_eq!(lab.info.region, info.region);
+			assert_eq!(lab.info.city, city_code);
+			assert_eq!(
+				Labs::labs_by_country_region_city(&country_region_code, &city_code),
+				Some(vec![1].try_into().unwrap())
+			);
+			assert_eq!(StorageVersion::get::<Labs>(), StorageVersion::new(6));
+		})
+	}
 }
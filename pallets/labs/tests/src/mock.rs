@@ -1,18 +1,34 @@
-use frame_support::{parameter_types, traits::ConstU128, weights::Weight, PalletId};
+use frame_support::{
+	pallet_prelude::MaxEncodedLen, parameter_types, traits::ConstU128, weights::Weight, PalletId,
+};
 use frame_system as system;
 use pallet_balances::AccountData;
 use scale_info::TypeInfo;
 use sp_core::{Decode, Encode, RuntimeDebug, H256};
 use sp_runtime::{
-	testing::Header,
-	traits::{BlakeTwo256, IdentityLookup},
+	testing::{Header, UintAuthorityId},
+	traits::{BlakeTwo256, Hash, IdentityLookup, Lazy, Verify},
 };
 
 use primitives_profile_roles::ProfileRoles;
 
-#[derive(Clone, Copy, PartialEq, Eq, Encode, Decode, Default, RuntimeDebug, TypeInfo)]
+#[derive(
+	Clone, Copy, PartialEq, Eq, Encode, Decode, Default, RuntimeDebug, TypeInfo, MaxEncodedLen,
+)]
 pub struct EthereumAddress(pub [u8; 20]);
 
+/// `TestSignature` over the hash of the message, so attestations have a maximum encoded length
+#[derive(Clone, PartialEq, Eq, Encode, Decode, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct TestAttestationSignature(pub u64, pub H256);
+
+impl Verify for TestAttestationSignature {
+	type Signer = UintAuthorityId;
+
+	fn verify<L: Lazy<[u8]>>(&self, mut msg: L, signer: &u64) -> bool {
+		signer == &self.0 && BlakeTwo256::hash(msg.get()) == self.1
+	}
+}
+
 type UncheckedExtrinsic = frame_system::mocking::MockUncheckedExtrinsic<Test>;
 type Block = frame_system::mocking::MockBlock<Test>;
 
@@ -28,7 +44,8 @@ frame_support::construct_runtime!(
 		Balances: pallet_balances,
 		Labs: labs,
 		Services: services,
-		Certifications: certifications,
+		Certifications: credentials::<Instance1>,
+		CredentialIssuers: credential_issuers,
 		Orders: orders,
 		GeneticTesting: genetic_testing,
 		Timestamp: pallet_timestamp,
@@ -94,7 +111,17 @@ type Balance = u128;
 parameter_types! {
 	pub static ExistentialDeposit: Balance = 0;
 	pub const LabPalletId: PalletId = PalletId(*b"dbio/lab");
+	pub static RequireVerifiedCertification: bool = false;
+	pub static MaxLabsPerCity: u32 = 100;
 	pub const OrderPalletId: PalletId = PalletId(*b"dbio/ord");
+	pub const RefundGracePeriod: u64 = 1000;
+	pub const MaxExpiredRefundsPerBlock: u32 = 10;
+	pub const MaxBundleItems: u32 = 10;
+	pub const DisputeWindow: u64 = 0;
+	pub const InstallmentInterval: u64 = 1000;
+	pub const MaxInstallments: u32 = 12;
+	pub const MaxOverduePlansPerBlock: u32 = 10;
+	pub const MaxCustodyNoteLength: u32 = 64;
 }
 
 impl pallet_balances::Config for Test {
@@ -150,6 +177,9 @@ impl labs::Config for Test {
 	type UserProfile = UserProfile;
 	type LabWeightInfo = ();
 	type PalletId = LabPalletId;
+	type SlashOrigin = frame_system::EnsureRoot<AccountId>;
+	type RequireVerifiedCertification = RequireVerifiedCertification;
+	type MaxLabsPerCity = MaxLabsPerCity;
 }
 
 impl services::Config for Test {
@@ -159,17 +189,29 @@ impl services::Config for Test {
 	type WeightInfo = ();
 }
 
-impl certifications::Config for Test {
+impl credentials::Config<credentials::Instance1> for Test {
 	type RuntimeEvent = RuntimeEvent;
-	type CertificationOwner = Labs;
+	type CredentialInfo = credentials::CertificationInfo;
+	type CredentialOwner = credentials::legacy::LegacyCertificationOwner<Labs>;
+	type CredentialIssuers = CredentialIssuers;
 	type WeightInfo = ();
 }
 
+impl credential_issuers::Config for Test {
+	type RuntimeEvent = RuntimeEvent;
+	type RegistrarOrigin = frame_system::EnsureRoot<AccountId>;
+	type Credentials = Certifications;
+	type CredentialIssuersWeightInfo = ();
+}
+
 impl genetic_testing::Config for Test {
 	type RuntimeEvent = RuntimeEvent;
 	type Orders = Orders;
 	type RandomnessSource = RandomnessCollectiveFlip;
 	type GeneticTestingWeightInfo = ();
+	type MaxCustodyNoteLength = MaxCustodyNoteLength;
+	type AttestationSignature = TestAttestationSignature;
+	type AttestationSigner = UintAuthorityId;
 }
 
 impl orders::Config for Test {
@@ -180,6 +222,14 @@ impl orders::Config for Test {
 	type Assets = Assets;
 	type OrdersWeightInfo = ();
 	type PalletId = OrderPalletId;
+	type RefundGracePeriod = RefundGracePeriod;
+	type MaxExpiredRefundsPerBlock = MaxExpiredRefundsPerBlock;
+	type MaxBundleItems = MaxBundleItems;
+	type DisputeWindow = DisputeWindow;
+	type ArbiterOrigin = frame_system::EnsureRoot<AccountId>;
+	type InstallmentInterval = InstallmentInterval;
+	type MaxInstallments = MaxInstallments;
+	type MaxOverduePlansPerBlock = MaxOverduePlansPerBlock;
 }
 
 impl user_profile::Config for Test {
@@ -1,9 +1,12 @@
 use super::*;
 
 #[allow(unused)]
-use crate::{MenstrualCycleLog, MenstrualInfo, Pallet as MenstrualCalendar, Symptom};
+use crate::{
+	prediction::DAY_IN_MILLIS, EncryptedMenstrualInfo, MenstrualCycleLog, MenstrualInfo,
+	Pallet as MenstrualCalendar, Symptom, NONCE_LENGTH,
+};
 use frame_benchmarking::{benchmarks, impl_benchmark_test_suite, whitelisted_caller};
-use frame_support::sp_runtime::SaturatedConversion;
+use frame_support::{sp_runtime::SaturatedConversion, traits::Get};
 use frame_system::RawOrigin;
 
 benchmarks! {
@@ -40,7 +43,7 @@ benchmarks! {
 
 		let menstrual_info = MenstrualInfo {
 			date: 0u128.saturated_into(),
-			symptoms: vec![Symptom::from(b"pain")],
+			symptoms: vec![Symptom::from(b"pain")].try_into().unwrap(),
 			menstruation: true,
 		};
 	}: add_menstrual_cycle_log(
@@ -64,7 +67,7 @@ benchmarks! {
 
 		let menstrual_info = MenstrualInfo {
 			date: 0u128.saturated_into(),
-			symptoms: vec![Symptom::from(b"pain")],
+			symptoms: vec![Symptom::from(b"pain")].try_into().unwrap(),
 			menstruation: true,
 		};
 
@@ -80,7 +83,7 @@ benchmarks! {
 			menstrual_ids[0],
 			1u128.saturated_into(),
 			false,
-			vec![Symptom::from(b"headache")],
+			vec![Symptom::from(b"headache")].try_into().unwrap(),
 			0u128.saturated_into(),
 		);
 	}: update_menstrual_cycle_log(
@@ -103,7 +106,7 @@ benchmarks! {
 
 		let menstrual_info = MenstrualInfo {
 			date: 0u128.saturated_into(),
-			symptoms: vec![Symptom::from(b"pain")],
+			symptoms: vec![Symptom::from(b"pain")].try_into().unwrap(),
 			menstruation: true,
 		};
 
@@ -119,6 +122,143 @@ benchmarks! {
 		menstrual_ids[0],
 		cycle_log_ids[0]
 	)
+
+	sync_menstrual_cycle_logs {
+		let n in 1 .. T::MaxMenstrualCycleLogSyncChanges::get();
+		let d in 1 .. T::MaxMenstrualCycleLogSyncDays::get();
+
+		let caller: T::AccountId = whitelisted_caller();
+
+		let caller_origin = T::RuntimeOrigin::from(RawOrigin::Signed(caller.clone()));
+		let _ = MenstrualCalendar::<T>::add_menstrual_calendar(
+			caller_origin.clone(),
+			16
+		);
+
+		let menstrual_ids = MenstrualCalendar::<T>::menstrual_calendar_by_owner(
+			caller.clone()
+		).unwrap();
+
+		// Every log sits on the last day of the range and is removed by the sync
+		let menstrual_infos: Vec<_> = (0..n).map(|_| MenstrualInfo {
+			date: ((d as u64 - 1) * DAY_IN_MILLIS).saturated_into(),
+			symptoms: vec![Symptom::from(b"pain")].try_into().unwrap(),
+			menstruation: true,
+		}).collect();
+		let _ = MenstrualCalendar::<T>::add_menstrual_cycle_log(
+			caller_origin,
+			menstrual_ids[0],
+			menstrual_infos
+		);
+	}: sync_menstrual_cycle_logs(
+		RawOrigin::Signed(caller),
+		menstrual_ids[0],
+		0u64.saturated_into(),
+		(d as u64 * DAY_IN_MILLIS).saturated_into(),
+		Vec::new()
+	)
+
+	add_encrypted_menstrual_cycle_log {
+		let n in 1 .. T::MaxEncryptedLogsPerCall::get();
+		let b in 0 .. T::MaxEncryptedLogsPerCall::get().saturating_mul(T::MaxCiphertextLength::get());
+
+		let caller: T::AccountId = whitelisted_caller();
+
+		let caller_origin = T::RuntimeOrigin::from(RawOrigin::Signed(caller.clone()));
+		let _ = MenstrualCalendar::<T>::add_menstrual_calendar(
+			caller_origin.clone(),
+			16
+		);
+
+		let menstrual_ids = MenstrualCalendar::<T>::menstrual_calendar_by_owner(
+			caller.clone()
+		).unwrap();
+
+		let ciphertext_length = (b / n).min(T::MaxCiphertextLength::get());
+		let encrypted_infos: Vec<_> = (0..n).map(|day| EncryptedMenstrualInfo {
+			date_bucket: (day as u64 * DAY_IN_MILLIS).saturated_into(),
+			ciphertext: vec![0u8; ciphertext_length as usize],
+			nonce: [0u8; NONCE_LENGTH],
+			box_public_key: Default::default(),
+		}).collect();
+	}: add_encrypted_menstrual_cycle_log(
+		RawOrigin::Signed(caller),
+		menstrual_ids[0],
+		encrypted_infos
+	)
+
+	encrypt_menstrual_cycle_log {
+		let caller: T::AccountId = whitelisted_caller();
+
+		let caller_origin = T::RuntimeOrigin::from(RawOrigin::Signed(caller.clone()));
+		let _ = MenstrualCalendar::<T>::add_menstrual_calendar(
+			caller_origin.clone(),
+			16
+		);
+
+		let menstrual_ids = MenstrualCalendar::<T>::menstrual_calendar_by_owner(
+			caller.clone()
+		).unwrap();
+
+		let menstrual_info = MenstrualInfo {
+			date: 0u128.saturated_into(),
+			symptoms: vec![Symptom::from(b"pain")].try_into().unwrap(),
+			menstruation: true,
+		};
+
+		let _ = MenstrualCalendar::<T>::add_menstrual_cycle_log(
+			caller_origin.clone(),
+			menstrual_ids[0],
+			vec![menstrual_info],
+		);
+
+		let cycle_log_ids = MenstrualCalendar::<T>::menstrual_cycle_log_by_owner_id(menstrual_ids[0]).unwrap();
+
+		let encrypted_info = EncryptedMenstrualInfo {
+			date_bucket: 0u128.saturated_into(),
+			ciphertext: vec![0u8; T::MaxCiphertextLength::get() as usize],
+			nonce: [0u8; NONCE_LENGTH],
+			box_public_key: Default::default(),
+		};
+	}: encrypt_menstrual_cycle_log(
+		RawOrigin::Signed(caller),
+		menstrual_ids[0],
+		cycle_log_ids[0],
+		encrypted_info
+	)
+
+	remove_encrypted_menstrual_cycle_log {
+		let caller: T::AccountId = whitelisted_caller();
+
+		let caller_origin = T::RuntimeOrigin::from(RawOrigin::Signed(caller.clone()));
+		let _ = MenstrualCalendar::<T>::add_menstrual_calendar(
+			caller_origin.clone(),
+			16
+		);
+
+		let menstrual_ids = MenstrualCalendar::<T>::menstrual_calendar_by_owner(
+			caller.clone()
+		).unwrap();
+
+		let encrypted_info = EncryptedMenstrualInfo {
+			date_bucket: 0u128.saturated_into(),
+			ciphertext: vec![0u8; T::MaxCiphertextLength::get() as usize],
+			nonce: [0u8; NONCE_LENGTH],
+			box_public_key: Default::default(),
+		};
+
+		let _ = MenstrualCalendar::<T>::add_encrypted_menstrual_cycle_log(
+			caller_origin.clone(),
+			menstrual_ids[0],
+			vec![encrypted_info],
+		);
+
+		let encrypted_log_ids = MenstrualCalendar::<T>::encrypted_menstrual_cycle_log_by_owner_id(menstrual_ids[0]).unwrap();
+	}: remove_encrypted_menstrual_cycle_log(
+		RawOrigin::Signed(caller),
+		menstrual_ids[0],
+		encrypted_log_ids[0]
+	)
 }
 
 impl_benchmark_test_suite! {MenstrualCalendar, crate::mock::ExternalityBuilder::build(), crate::mock::Test}
@@ -1,6 +1,10 @@
 use crate::*;
 
-use frame_support::{codec::Encode, sp_runtime::traits::Hash};
+use frame_support::{
+	codec::Encode,
+	sp_runtime::{traits::Hash, SaturatedConversion},
+	traits::Get,
+};
 
 /// Pallet Methods
 impl<T: Config> Pallet<T> {
@@ -31,12 +35,16 @@ impl<T: Config> Pallet<T> {
 	pub fn add_menstrual_calendar_by_owner(
 		address_id: &T::AccountId,
 		menstrual_calendar_id: &T::Hash,
-	) {
+	) -> Result<(), Error<T>> {
 		let mut menstrual_calendar =
 			MenstrualCalendarByOwner::<T>::get(address_id).unwrap_or_default();
 
-		menstrual_calendar.push(*menstrual_calendar_id);
-		MenstrualCalendarByOwner::<T>::insert(address_id, &menstrual_calendar)
+		menstrual_calendar
+			.try_push(*menstrual_calendar_id)
+			.map_err(|_| Error::<T>::MenstrualCalendarAlreadyExist)?;
+		MenstrualCalendarByOwner::<T>::insert(address_id, &menstrual_calendar);
+
+		Ok(())
 	}
 
 	// Subtract menstrual_calendar by owner
@@ -82,12 +90,16 @@ impl<T: Config> Pallet<T> {
 	pub fn add_menstrual_cycle_log_by_owner(
 		menstrual_calendar_id: &T::Hash,
 		menstrual_cycle_log_id: &T::Hash,
-	) {
+	) -> Result<(), Error<T>> {
 		let mut menstrual_cycle_log =
 			MenstrualCycleLogByOwner::<T>::get(menstrual_calendar_id).unwrap_or_default();
 
-		menstrual_cycle_log.push(*menstrual_cycle_log_id);
-		MenstrualCycleLogByOwner::<T>::insert(menstrual_calendar_id, &menstrual_cycle_log)
+		menstrual_cycle_log
+			.try_push(*menstrual_cycle_log_id)
+			.map_err(|_| Error::<T>::TooManyMenstrualCycleLogs)?;
+		MenstrualCycleLogByOwner::<T>::insert(menstrual_calendar_id, &menstrual_cycle_log);
+
+		Ok(())
 	}
 
 	// Subtract menstrual_cycle_log by owner
@@ -101,6 +113,36 @@ impl<T: Config> Pallet<T> {
 		MenstrualCycleLogByOwner::<T>::insert(menstrual_calendar_id, menstrual_cycle_log);
 	}
 
+	// Add menstrual_cycle_log to the day of its date
+	pub fn add_menstrual_cycle_log_by_date(
+		menstrual_calendar_id: &T::Hash,
+		date: &MomentOf<T>,
+		menstrual_cycle_log_id: &T::Hash,
+	) -> Result<(), Error<T>> {
+		MenstrualCycleLogByDate::<T>::try_append(
+			menstrual_calendar_id,
+			Self::date_bucket(*date),
+			menstrual_cycle_log_id,
+		)
+		.map_err(|_| Error::<T>::TooManyMenstrualCycleLogs)
+	}
+
+	// Subtract menstrual_cycle_log from the day of its date
+	pub fn sub_menstrual_cycle_log_by_date(
+		menstrual_calendar_id: &T::Hash,
+		date: &MomentOf<T>,
+		menstrual_cycle_log_id: &T::Hash,
+	) {
+		let day = Self::date_bucket(*date);
+		let mut ids_by_date = MenstrualCycleLogByDate::<T>::get(menstrual_calendar_id, day);
+		ids_by_date.retain(|x| x != menstrual_cycle_log_id);
+		if ids_by_date.is_empty() {
+			MenstrualCycleLogByDate::<T>::remove(menstrual_calendar_id, day);
+		} else {
+			MenstrualCycleLogByDate::<T>::insert(menstrual_calendar_id, day, ids_by_date);
+		}
+	}
+
 	// Add menstrual_cycle_log count
 	pub fn add_menstrual_cycle_log_count() {
 		let menstrual_cycle_log_count = <MenstrualCycleLogCount<T>>::get().unwrap_or(0);
@@ -132,4 +174,190 @@ impl<T: Config> Pallet<T> {
 			menstrual_cycle_log_count - 1,
 		);
 	}
+
+	pub fn insert_menstrual_cycle_log(
+		address_id: &T::AccountId,
+		menstrual_calendar_id: &T::Hash,
+		menstrual_info: &MenstrualInfoOf<T>,
+	) -> Result<MenstrualCycleLogOf<T>, Error<T>> {
+		let owner_menstrual_cycle_log_count =
+			MenstrualCycleLogCountByOwner::<T>::get(menstrual_calendar_id).unwrap_or(0);
+
+		let menstrual_cycle_log_id = Self::generate_id(
+			address_id,
+			owner_menstrual_cycle_log_count,
+			Some(*menstrual_calendar_id),
+		);
+
+		let now = pallet_timestamp::Pallet::<T>::get();
+		let menstrual_cycle_log = MenstrualCycleLog::new(
+			menstrual_cycle_log_id,
+			*menstrual_calendar_id,
+			menstrual_info.date,
+			menstrual_info.menstruation,
+			menstrual_info.symptoms.clone(),
+			now,
+		);
+
+		Self::add_menstrual_cycle_log_by_owner(menstrual_calendar_id, &menstrual_cycle_log_id)?;
+		Self::add_menstrual_cycle_log_by_date(
+			menstrual_calendar_id,
+			&menstrual_cycle_log.date,
+			&menstrual_cycle_log_id,
+		)?;
+
+		MenstrualCycleLogById::<T>::insert(menstrual_cycle_log_id, &menstrual_cycle_log);
+
+		Self::add_menstrual_cycle_log_count();
+		Self::add_menstrual_cycle_log_count_by_owner(menstrual_calendar_id);
+
+		Ok(menstrual_cycle_log)
+	}
+
+	pub fn delete_menstrual_cycle_log(
+		menstrual_calendar_id: &T::Hash,
+		menstrual_cycle_log_id: &T::Hash,
+	) {
+		if let Some(menstrual_cycle_log) = MenstrualCycleLogById::<T>::take(menstrual_cycle_log_id)
+		{
+			Self::sub_menstrual_cycle_log_by_date(
+				menstrual_calendar_id,
+				&menstrual_cycle_log.date,
+				menstrual_cycle_log_id,
+			);
+		}
+
+		Self::sub_menstrual_cycle_log_by_owner(menstrual_calendar_id, menstrual_cycle_log_id);
+		Self::sub_menstrual_cycle_log_count();
+		Self::sub_menstrual_cycle_log_count_by_owner(menstrual_calendar_id);
+	}
+
+	// Floor a moment to the start of its day
+	pub fn date_bucket(date: MomentOf<T>) -> MomentOf<T> {
+		let date = date.saturated_into::<u64>();
+		(date - date % prediction::DAY_IN_MILLIS).saturated_into()
+	}
+
+	// Total ciphertext bytes, used to weigh adding encrypted logs
+	pub fn ciphertext_length_of(encrypted_infos: &[EncryptedMenstrualInfoOf<T>]) -> u32 {
+		encrypted_infos
+			.iter()
+			.fold(0u32, |total, info| total.saturating_add(info.ciphertext.len() as u32))
+	}
+
+	// Days touched by `[start_date, end_date)`, each of which a sync reads
+	pub fn sync_day_count(start_date: &MomentOf<T>, end_date: &MomentOf<T>) -> u32 {
+		if start_date >= end_date {
+			return 0
+		}
+
+		let first_day = Self::date_bucket(*start_date).saturated_into::<u64>();
+		let last_day = (*end_date).saturated_into::<u64>() - 1;
+
+		((last_day - first_day) / prediction::DAY_IN_MILLIS)
+			.saturating_add(1)
+			.saturated_into()
+	}
+
+	pub fn insert_encrypted_menstrual_cycle_log(
+		address_id: &T::AccountId,
+		menstrual_calendar_id: &T::Hash,
+		encrypted_info: &EncryptedMenstrualInfoOf<T>,
+	) -> Result<EncryptedMenstrualCycleLogOf<T>, Error<T>> {
+		let owner_encrypted_menstrual_cycle_log_count =
+			EncryptedMenstrualCycleLogCountByOwner::<T>::get(menstrual_calendar_id).unwrap_or(0);
+
+		let id = Self::generate_id(
+			address_id,
+			owner_encrypted_menstrual_cycle_log_count,
+			Some(*menstrual_calendar_id),
+		);
+
+		let mut encrypted_info = encrypted_info.clone();
+		encrypted_info.date_bucket = Self::date_bucket(encrypted_info.date_bucket);
+
+		let now = pallet_timestamp::Pallet::<T>::get();
+		let encrypted_menstrual_cycle_log =
+			EncryptedMenstrualCycleLog::new(id, *menstrual_calendar_id, encrypted_info, now)
+				.ok_or(Error::<T>::CiphertextTooLong)?;
+
+		EncryptedMenstrualCycleLogByOwner::<T>::try_append(menstrual_calendar_id, id)
+			.map_err(|_| Error::<T>::TooManyMenstrualCycleLogs)?;
+		EncryptedMenstrualCycleLogByDate::<T>::try_append(
+			menstrual_calendar_id,
+			encrypted_menstrual_cycle_log.date_bucket,
+			id,
+		)
+		.map_err(|_| Error::<T>::TooManyMenstrualCycleLogs)?;
+		EncryptedMenstrualCycleLogById::<T>::insert(id, &encrypted_menstrual_cycle_log);
+		EncryptedMenstrualCycleLogCountByOwner::<T>::insert(
+			menstrual_calendar_id,
+			owner_encrypted_menstrual_cycle_log_count.wrapping_add(1),
+		);
+
+		Ok(encrypted_menstrual_cycle_log)
+	}
+
+	pub fn delete_encrypted_menstrual_cycle_log(
+		encrypted_menstrual_cycle_log: &EncryptedMenstrualCycleLogOf<T>,
+	) {
+		let id = &encrypted_menstrual_cycle_log.id;
+		let menstrual_calendar_id = &encrypted_menstrual_cycle_log.menstrual_calendar_id;
+		let date_bucket = &encrypted_menstrual_cycle_log.date_bucket;
+
+		EncryptedMenstrualCycleLogById::<T>::remove(id);
+		EncryptedMenstrualCycleLogByOwner::<T>::mutate(menstrual_calendar_id, |ids| {
+			if let Some(ids) = ids {
+				ids.retain(|x| x != id);
+			}
+		});
+
+		let mut ids_by_date =
+			EncryptedMenstrualCycleLogByDate::<T>::get(menstrual_calendar_id, date_bucket);
+		ids_by_date.retain(|x| x != id);
+		if ids_by_date.is_empty() {
+			EncryptedMenstrualCycleLogByDate::<T>::remove(menstrual_calendar_id, date_bucket);
+		} else {
+			EncryptedMenstrualCycleLogByDate::<T>::insert(
+				menstrual_calendar_id,
+				date_bucket,
+				ids_by_date,
+			);
+		}
+
+		let count =
+			EncryptedMenstrualCycleLogCountByOwner::<T>::get(menstrual_calendar_id).unwrap_or(1);
+		EncryptedMenstrualCycleLogCountByOwner::<T>::insert(
+			menstrual_calendar_id,
+			count.saturating_sub(1),
+		);
+	}
+
+	pub fn predict_menstrual_cycle(
+		menstrual_calendar_id: &T::Hash,
+	) -> Option<CyclePredictionOf<T>> {
+		let menstrual_calendar = MenstrualCalendarById::<T>::get(menstrual_calendar_id)?;
+		let menstruation_dates: Vec<u64> =
+			MenstrualCycleLogByOwner::<T>::get(menstrual_calendar_id)
+				.unwrap_or_default()
+				.into_iter()
+				.filter_map(MenstrualCycleLogById::<T>::get)
+				.filter(|menstrual_cycle_log| menstrual_cycle_log.menstruation)
+				.map(|menstrual_cycle_log| menstrual_cycle_log.date.saturated_into::<u64>())
+				.collect();
+
+		let prediction =
+			prediction::predict(menstrual_calendar.average_cycle, &menstruation_dates)?;
+
+		Some(CyclePrediction {
+			last_period_start: prediction.last_period_start.saturated_into(),
+			next_period_start: prediction.next_period_start.saturated_into(),
+			ovulation: prediction.ovulation.saturated_into(),
+			fertile_window_start: prediction.fertile_window_start.saturated_into(),
+			fertile_window_end: prediction.fertile_window_end.saturated_into(),
+			average_cycle: prediction.average_cycle,
+			cycle_variance: prediction.cycle_variance,
+			observed_cycles: prediction.observed_cycles,
+		})
+	}
 }
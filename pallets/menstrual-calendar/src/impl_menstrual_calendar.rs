@@ -1,11 +1,16 @@
 use crate::*;
 
+use frame_support::{sp_runtime::SaturatedConversion, traits::Get, BoundedVec};
+
 /// MenstrualCalendar Interface Implementation
 impl<T: Config> MenstrualCalendarInterface<T> for Pallet<T> {
 	type Error = Error<T>;
 	type MenstrualCycleLog = MenstrualCycleLogOf<T>;
 	type MenstrualCalendar = MenstrualCalendarOf<T>;
 	type MenstrualInfo = MenstrualInfoOf<T>;
+	type EncryptedMenstrualCycleLog = EncryptedMenstrualCycleLogOf<T>;
+	type EncryptedMenstrualInfo = EncryptedMenstrualInfoOf<T>;
+	type MenstrualCycleLogSync = MenstrualCycleLogSyncOf<T>;
 	type Date = MomentOf<T>;
 
 	fn add_menstrual_calendar(
@@ -22,10 +27,11 @@ impl<T: Config> MenstrualCalendarInterface<T> for Pallet<T> {
 		let id = Self::generate_id(address_id, total_count, None);
 		let menstrual_calendar = MenstrualCalendar::new(id, address_id.clone(), average_cycle, now);
 
+		Self::add_menstrual_calendar_by_owner(address_id, &id)?;
+
 		// Store to MenstrualCalendarById storage
 		MenstrualCalendarById::<T>::insert(id, &menstrual_calendar);
 
-		Self::add_menstrual_calendar_by_owner(address_id, &id);
 		Self::add_menstrual_calendar_count();
 		Self::add_menstrual_calendar_count_by_owner(address_id);
 
@@ -51,7 +57,10 @@ impl<T: Config> MenstrualCalendarInterface<T> for Pallet<T> {
 
 		// Store to MenstrualCalendarById storage
 		MenstrualCalendarById::<T>::insert(menstrual_calendar_id, &menstrual_calendar);
-		MenstrualCalendarByOwner::<T>::insert(address_id, vec![*menstrual_calendar_id]);
+		MenstrualCalendarByOwner::<T>::insert(
+			address_id,
+			BoundedVec::truncate_from(vec![*menstrual_calendar_id]),
+		);
 
 		Ok(menstrual_calendar)
 	}
@@ -72,33 +81,11 @@ impl<T: Config> MenstrualCalendarInterface<T> for Pallet<T> {
 		let mut menstrual_cycle_logs: Vec<MenstrualCycleLogOf<T>> = Vec::new();
 
 		for menstrual_info in menstrual_infos.iter() {
-			let owner_menstrual_cycle_log_count =
-				MenstrualCycleLogCountByOwner::<T>::get(menstrual_calendar_id).unwrap_or(0);
-
-			let menstrual_cycle_log_id = Self::generate_id(
+			let menstrual_cycle_log = Self::insert_menstrual_cycle_log(
 				address_id,
-				owner_menstrual_cycle_log_count,
-				Some(*menstrual_calendar_id),
-			);
-
-			let now = pallet_timestamp::Pallet::<T>::get();
-			let date = &menstrual_info.date;
-			let symptoms = &menstrual_info.symptoms;
-			let menstruation = menstrual_info.menstruation;
-			let menstrual_cycle_log = MenstrualCycleLog::new(
-				menstrual_cycle_log_id,
-				*menstrual_calendar_id,
-				*date,
-				menstruation,
-				symptoms.to_vec(),
-				now,
-			);
-
-			MenstrualCycleLogById::<T>::insert(menstrual_cycle_log_id, &menstrual_cycle_log);
-
-			Self::add_menstrual_cycle_log_by_owner(menstrual_calendar_id, &menstrual_cycle_log_id);
-			Self::add_menstrual_cycle_log_count();
-			Self::add_menstrual_cycle_log_count_by_owner(menstrual_calendar_id);
+				menstrual_calendar_id,
+				menstrual_info,
+			)?;
 
 			menstrual_cycle_logs.push(menstrual_cycle_log);
 		}
@@ -138,9 +125,22 @@ impl<T: Config> MenstrualCalendarInterface<T> for Pallet<T> {
 				continue
 			}
 
+			if Self::date_bucket(new_menstrual_cycle_log.date) != Self::date_bucket(*date) {
+				Self::sub_menstrual_cycle_log_by_date(
+					menstrual_calendar_id,
+					&new_menstrual_cycle_log.date,
+					menstrual_cycle_log_id,
+				);
+				Self::add_menstrual_cycle_log_by_date(
+					menstrual_calendar_id,
+					date,
+					menstrual_cycle_log_id,
+				)?;
+			}
+
 			new_menstrual_cycle_log.date = *date;
 			new_menstrual_cycle_log.menstruation = menstruation;
-			new_menstrual_cycle_log.symptoms = symptoms.to_vec();
+			new_menstrual_cycle_log.symptoms = symptoms.clone();
 			new_menstrual_cycle_log.updated_at = now;
 
 			MenstrualCycleLogById::<T>::insert(menstrual_cycle_log_id, &new_menstrual_cycle_log);
@@ -171,11 +171,218 @@ impl<T: Config> MenstrualCalendarInterface<T> for Pallet<T> {
 		}
 
 		// Remove menstrual_cycle_log from storage
-		MenstrualCycleLogById::<T>::remove(menstrual_cycle_log_id);
+		Self::delete_menstrual_cycle_log(menstrual_calendar_id, menstrual_cycle_log_id);
+
+		Ok(())
+	}
+
+	fn sync_menstrual_cycle_logs(
+		address_id: &T::AccountId,
+		menstrual_calendar_id: &T::Hash,
+		start_date: &Self::Date,
+		end_date: &Self::Date,
+		menstrual_infos: &[Self::MenstrualInfo],
+	) -> Result<Self::MenstrualCycleLogSync, Self::Error> {
+		let menstrual_calendar = MenstrualCalendarById::<T>::get(menstrual_calendar_id)
+			.ok_or(Error::<T>::MenstrualCalendarDoesNotExist)?;
+
+		if &menstrual_calendar.address_id != address_id {
+			return Err(Error::<T>::NotMenstrualCalendarOwner)
+		}
+
+		if start_date >= end_date {
+			return Err(Error::<T>::InvalidMenstrualCycleLogSyncRange)
+		}
+
+		let days = Self::sync_day_count(start_date, end_date);
+
+		if days > T::MaxMenstrualCycleLogSyncDays::get() {
+			return Err(Error::<T>::MenstrualCycleLogSyncRangeTooLong)
+		}
+
+		let max_changes = T::MaxMenstrualCycleLogSyncChanges::get() as usize;
+
+		if menstrual_infos.len() > max_changes {
+			return Err(Error::<T>::TooManyMenstrualCycleLogChanges)
+		}
+
+		// Logs are matched by day, so at most one entry per day is allowed
+		let mut desired_days: Vec<MomentOf<T>> = Vec::new();
+
+		for menstrual_info in menstrual_infos.iter() {
+			if &menstrual_info.date < start_date || &menstrual_info.date >= end_date {
+				return Err(Error::<T>::MenstrualInfoOutOfRange)
+			}
+
+			let day = Self::date_bucket(menstrual_info.date);
+
+			if desired_days.contains(&day) {
+				return Err(Error::<T>::DuplicateMenstrualInfoDate)
+			}
+
+			desired_days.push(day);
+		}
+
+		// Only the days of the range are read. Every log beyond the matched infos is removed,
+		// so stop once the range holds more logs than the changes allow.
+		let max_existing_logs = menstrual_infos.len().saturating_add(max_changes);
+		let first_day = Self::date_bucket(*start_date).saturated_into::<u64>();
+		let mut existing_logs: Vec<MenstrualCycleLogOf<T>> = Vec::new();
+
+		for offset in 0..days as u64 {
+			let day: MomentOf<T> =
+				(first_day + offset * prediction::DAY_IN_MILLIS).saturated_into();
+
+			for id in MenstrualCycleLogByDate::<T>::get(menstrual_calendar_id, day) {
+				let menstrual_cycle_log = match MenstrualCycleLogById::<T>::get(id) {
+					Some(log) if &log.date >= start_date && &log.date < end_date => log,
+					_ => continue,
+				};
+
+				if existing_logs.len() >= max_existing_logs {
+					return Err(Error::<T>::TooManyMenstrualCycleLogChanges)
+				}
+
+				existing_logs.push(menstrual_cycle_log);
+			}
+		}
+
+		let now = pallet_timestamp::Pallet::<T>::get();
+		let mut sync = MenstrualCycleLogSync::default();
+		let mut new_menstrual_infos: Vec<&MenstrualInfoOf<T>> = Vec::new();
+
+		for (menstrual_info, day) in menstrual_infos.iter().zip(desired_days.iter()) {
+			let position = existing_logs.iter().position(|log| &Self::date_bucket(log.date) == day);
+
+			match position {
+				Some(index) => {
+					let mut menstrual_cycle_log = existing_logs.swap_remove(index);
+
+					if menstrual_cycle_log.date == menstrual_info.date &&
+						menstrual_cycle_log.menstruation == menstrual_info.menstruation &&
+						menstrual_cycle_log.symptoms == menstrual_info.symptoms
+					{
+						continue
+					}
+
+					menstrual_cycle_log.date = menstrual_info.date;
+					menstrual_cycle_log.menstruation = menstrual_info.menstruation;
+					menstrual_cycle_log.symptoms = menstrual_info.symptoms.clone();
+					menstrual_cycle_log.updated_at = now;
+
+					sync.updated.push(menstrual_cycle_log);
+				},
+				None => new_menstrual_infos.push(menstrual_info),
+			}
+		}
+
+		// Whatever is left in the range is not part of the desired set
+		let changes = new_menstrual_infos.len() + sync.updated.len() + existing_logs.len();
+
+		if changes > max_changes {
+			return Err(Error::<T>::TooManyMenstrualCycleLogChanges)
+		}
+
+		for menstrual_cycle_log in sync.updated.iter() {
+			MenstrualCycleLogById::<T>::insert(menstrual_cycle_log.id, menstrual_cycle_log);
+		}
+
+		// Insert before deleting so new ids never reuse a freed count
+		for menstrual_info in new_menstrual_infos {
+			let menstrual_cycle_log = Self::insert_menstrual_cycle_log(
+				address_id,
+				menstrual_calendar_id,
+				menstrual_info,
+			)?;
+
+			sync.added.push(menstrual_cycle_log);
+		}
+
+		for menstrual_cycle_log in existing_logs {
+			Self::delete_menstrual_cycle_log(menstrual_calendar_id, &menstrual_cycle_log.id);
+
+			sync.removed.push(menstrual_cycle_log.id);
+		}
+
+		Ok(sync)
+	}
+
+	fn add_encrypted_menstrual_cycle_log(
+		address_id: &T::AccountId,
+		menstrual_calendar_id: &T::Hash,
+		encrypted_infos: &[Self::EncryptedMenstrualInfo],
+	) -> Result<Vec<Self::EncryptedMenstrualCycleLog>, Self::Error> {
+		if encrypted_infos.len() > T::MaxEncryptedLogsPerCall::get() as usize {
+			return Err(Error::<T>::TooManyEncryptedMenstrualCycleLogs)
+		}
+
+		let menstrual_calendar = MenstrualCalendarById::<T>::get(menstrual_calendar_id)
+			.ok_or(Error::<T>::MenstrualCalendarDoesNotExist)?;
+
+		if &menstrual_calendar.address_id != address_id {
+			return Err(Error::<T>::NotMenstrualCalendarOwner)
+		}
+
+		let mut encrypted_menstrual_cycle_logs: Vec<EncryptedMenstrualCycleLogOf<T>> = Vec::new();
+
+		for encrypted_info in encrypted_infos.iter() {
+			let encrypted_menstrual_cycle_log = Self::insert_encrypted_menstrual_cycle_log(
+				address_id,
+				menstrual_calendar_id,
+				encrypted_info,
+			)?;
+
+			encrypted_menstrual_cycle_logs.push(encrypted_menstrual_cycle_log);
+		}
+
+		Ok(encrypted_menstrual_cycle_logs)
+	}
+
+	fn encrypt_menstrual_cycle_log(
+		address_id: &T::AccountId,
+		menstrual_calendar_id: &T::Hash,
+		menstrual_cycle_log_id: &T::Hash,
+		encrypted_info: &Self::EncryptedMenstrualInfo,
+	) -> Result<Self::EncryptedMenstrualCycleLog, Self::Error> {
+		if encrypted_info.ciphertext.len() > T::MaxCiphertextLength::get() as usize {
+			return Err(Error::<T>::CiphertextTooLong)
+		}
+
+		// Checks ownership and deletes the plaintext original
+		<Self as MenstrualCalendarInterface<T>>::remove_menstrual_cycle_log(
+			address_id,
+			menstrual_calendar_id,
+			menstrual_cycle_log_id,
+		)?;
+
+		Self::insert_encrypted_menstrual_cycle_log(
+			address_id,
+			menstrual_calendar_id,
+			encrypted_info,
+		)
+	}
+
+	fn remove_encrypted_menstrual_cycle_log(
+		address_id: &T::AccountId,
+		menstrual_calendar_id: &T::Hash,
+		encrypted_menstrual_cycle_log_id: &T::Hash,
+	) -> Result<(), Self::Error> {
+		let menstrual_calendar = MenstrualCalendarById::<T>::get(menstrual_calendar_id)
+			.ok_or(Error::<T>::MenstrualCalendarDoesNotExist)?;
+
+		if &menstrual_calendar.address_id != address_id {
+			return Err(Error::<T>::NotMenstrualCalendarOwner)
+		}
+
+		let encrypted_menstrual_cycle_log =
+			EncryptedMenstrualCycleLogById::<T>::get(encrypted_menstrual_cycle_log_id)
+				.ok_or(Error::<T>::EncryptedMenstrualCycleLogDoesNotExist)?;
+
+		if &encrypted_menstrual_cycle_log.menstrual_calendar_id != menstrual_calendar_id {
+			return Err(Error::<T>::NotMenstrualCycleLogOwner)
+		}
 
-		Self::sub_menstrual_cycle_log_by_owner(menstrual_calendar_id, menstrual_cycle_log_id);
-		Self::sub_menstrual_cycle_log_count();
-		Self::sub_menstrual_cycle_log_count_by_owner(menstrual_calendar_id);
+		Self::delete_encrypted_menstrual_cycle_log(&encrypted_menstrual_cycle_log);
 
 		Ok(())
 	}
@@ -5,6 +5,9 @@ pub trait MenstrualCalendarInterface<T: frame_system::Config> {
 	type MenstrualCycleLog;
 	type MenstrualCalendar;
 	type MenstrualInfo;
+	type EncryptedMenstrualCycleLog;
+	type EncryptedMenstrualInfo;
+	type MenstrualCycleLogSync;
 	type Date;
 
 	fn add_menstrual_calendar(
@@ -34,4 +37,31 @@ pub trait MenstrualCalendarInterface<T: frame_system::Config> {
 		menstrual_calendar_id: &T::Hash,
 		menstrual_cycle_log_id: &T::Hash,
 	) -> Result<(), Self::Error>;
+
+	fn sync_menstrual_cycle_logs(
+		address_id: &T::AccountId,
+		menstrual_calendar_id: &T::Hash,
+		start_date: &Self::Date,
+		end_date: &Self::Date,
+		menstrual_infos: &[Self::MenstrualInfo],
+	) -> Result<Self::MenstrualCycleLogSync, Self::Error>;
+
+	fn add_encrypted_menstrual_cycle_log(
+		address_id: &T::AccountId,
+		menstrual_calendar_id: &T::Hash,
+		encrypted_infos: &[Self::EncryptedMenstrualInfo],
+	) -> Result<Vec<Self::EncryptedMenstrualCycleLog>, Self::Error>;
+
+	fn encrypt_menstrual_cycle_log(
+		address_id: &T::AccountId,
+		menstrual_calendar_id: &T::Hash,
+		menstrual_cycle_log_id: &T::Hash,
+		encrypted_info: &Self::EncryptedMenstrualInfo,
+	) -> Result<Self::EncryptedMenstrualCycleLog, Self::Error>;
+
+	fn remove_encrypted_menstrual_cycle_log(
+		address_id: &T::AccountId,
+		menstrual_calendar_id: &T::Hash,
+		encrypted_menstrual_cycle_log_id: &T::Hash,
+	) -> Result<(), Self::Error>;
 }
@@ -15,10 +15,12 @@ pub mod functions;
 pub mod impl_menstrual_calendar;
 pub mod interface;
 pub mod migrations;
+pub mod prediction;
 pub mod types;
 pub mod weights;
 
 use interface::MenstrualCalendarInterface;
+pub use prediction::CyclePrediction;
 use sp_std::prelude::*;
 use traits_menstrual_calendar::MenstrualCalendarProvider;
 use types::*;
@@ -27,7 +29,7 @@ use weights::WeightInfo;
 pub use frame_support::traits::StorageVersion;
 
 /// The current storage version.
-const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
+const STORAGE_VERSION: StorageVersion = StorageVersion::new(3);
 
 #[frame_support::pallet]
 pub mod pallet {
@@ -40,13 +42,28 @@ pub mod pallet {
 	pub trait Config: frame_system::Config + pallet_timestamp::Config {
 		type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
 		type MenstrualCalendarWeightInfo: WeightInfo;
+		/// Maximum length of an encrypted menstrual cycle log payload.
+		#[pallet::constant]
+		type MaxCiphertextLength: Get<u32>;
+		/// Maximum number of inserts, updates and deletes applied by one sync.
+		#[pallet::constant]
+		type MaxMenstrualCycleLogSyncChanges: Get<u32>;
+		/// Maximum number of days covered by the range of one sync.
+		#[pallet::constant]
+		type MaxMenstrualCycleLogSyncDays: Get<u32>;
+		/// Maximum number of encrypted menstrual cycle logs added in one call.
+		#[pallet::constant]
+		type MaxEncryptedLogsPerCall: Get<u32>;
+		/// Maximum number of menstrual cycle logs, and of encrypted ones, indexed per menstrual
+		/// calendar.
+		#[pallet::constant]
+		type MaxMenstrualCycleLogsPerCalendar: Get<u32>;
 	}
 
 	// ----- This is template code, every pallet needs this ---
 	#[pallet::pallet]
 	#[pallet::storage_version(STORAGE_VERSION)]
 	#[pallet::generate_store(pub(super) trait Store)]
-	#[pallet::without_storage_info]
 	pub struct Pallet<T>(_);
 
 	#[pallet::hooks]
@@ -54,6 +71,11 @@ pub mod pallet {
 		fn on_runtime_upgrade() -> Weight {
 			migrations::migrate::<T>()
 		}
+
+		#[cfg(feature = "try-runtime")]
+		fn pre_upgrade() -> Result<Vec<u8>, &'static str> {
+			migrations::pre_upgrade::<T>()
+		}
 	}
 	// --------------------------------------------------------
 
@@ -66,12 +88,21 @@ pub mod pallet {
 	pub type MenstrualCycleLogOf<T> = MenstrualCycleLog<HashOf<T>, MomentOf<T>>;
 	pub type MenstrualCycleLogIdOf<T> = HashOf<T>;
 	pub type MenstrualInfoOf<T> = MenstrualInfo<MomentOf<T>>;
+	pub type MenstrualCycleLogSyncOf<T> = MenstrualCycleLogSync<HashOf<T>, MomentOf<T>>;
+	pub type CyclePredictionOf<T> = CyclePrediction<MomentOf<T>>;
+	pub type EncryptedMenstrualInfoOf<T> = EncryptedMenstrualInfo<HashOf<T>, MomentOf<T>>;
+	pub type EncryptedMenstrualCycleLogOf<T> =
+		EncryptedMenstrualCycleLog<HashOf<T>, MomentOf<T>, <T as Config>::MaxCiphertextLength>;
+	pub type MenstrualCalendarIdsOf<T> =
+		BoundedVec<MenstrualCalendarIdOf<T>, ConstU32<MAX_MENSTRUAL_CALENDARS_PER_OWNER>>;
+	pub type MenstrualCycleLogIdsOf<T> =
+		BoundedVec<MenstrualCycleLogIdOf<T>, <T as Config>::MaxMenstrualCycleLogsPerCalendar>;
 
 	// ------- Storage -------------
 	#[pallet::storage]
 	#[pallet::getter(fn menstrual_calendar_by_owner)]
 	pub type MenstrualCalendarByOwner<T> =
-		StorageMap<_, Blake2_128Concat, AccountIdOf<T>, Vec<MenstrualCalendarIdOf<T>>>;
+		StorageMap<_, Blake2_128Concat, AccountIdOf<T>, MenstrualCalendarIdsOf<T>>;
 
 	#[pallet::storage]
 	#[pallet::getter(fn menstrual_calendar_by_id)]
@@ -90,13 +121,25 @@ pub mod pallet {
 	#[pallet::storage]
 	#[pallet::getter(fn menstrual_cycle_log_by_owner_id)]
 	pub type MenstrualCycleLogByOwner<T> =
-		StorageMap<_, Blake2_128Concat, HashOf<T>, Vec<MenstrualCycleLogIdOf<T>>>;
+		StorageMap<_, Blake2_128Concat, HashOf<T>, MenstrualCycleLogIdsOf<T>>;
 
 	#[pallet::storage]
 	#[pallet::getter(fn menstrual_cycle_log_by_id)]
 	pub type MenstrualCycleLogById<T> =
 		StorageMap<_, Blake2_128Concat, HashOf<T>, MenstrualCycleLogOf<T>>;
 
+	#[pallet::storage]
+	#[pallet::getter(fn menstrual_cycle_log_by_date)]
+	pub type MenstrualCycleLogByDate<T> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		HashOf<T>,
+		Blake2_128Concat,
+		MomentOf<T>,
+		MenstrualCycleLogIdsOf<T>,
+		ValueQuery,
+	>;
+
 	#[pallet::storage]
 	#[pallet::getter(fn menstrual_cycle_log_count)]
 	pub type MenstrualCycleLogCount<T> = StorageValue<_, u64>;
@@ -104,6 +147,33 @@ pub mod pallet {
 	#[pallet::storage]
 	#[pallet::getter(fn menstrual_cycle_log_count_by_owner)]
 	pub type MenstrualCycleLogCountByOwner<T> = StorageMap<_, Blake2_128Concat, HashOf<T>, u64>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn encrypted_menstrual_cycle_log_by_owner_id)]
+	pub type EncryptedMenstrualCycleLogByOwner<T> =
+		StorageMap<_, Blake2_128Concat, HashOf<T>, MenstrualCycleLogIdsOf<T>>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn encrypted_menstrual_cycle_log_by_date)]
+	pub type EncryptedMenstrualCycleLogByDate<T> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		HashOf<T>,
+		Blake2_128Concat,
+		MomentOf<T>,
+		MenstrualCycleLogIdsOf<T>,
+		ValueQuery,
+	>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn encrypted_menstrual_cycle_log_by_id)]
+	pub type EncryptedMenstrualCycleLogById<T> =
+		StorageMap<_, Blake2_128Concat, HashOf<T>, EncryptedMenstrualCycleLogOf<T>>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn encrypted_menstrual_cycle_log_count_by_owner)]
+	pub type EncryptedMenstrualCycleLogCountByOwner<T> =
+		StorageMap<_, Blake2_128Concat, HashOf<T>, u64>;
 	//                                _,  Hasher         ,  Key     ,  Value
 	// -----------------------------
 
@@ -128,6 +198,18 @@ pub mod pallet {
 		//// MenstrualCycleLog deleted
 		/// parameters, [MenstrualCycleLogId, who]
 		MenstrualCycleLogRemoved(HashOf<T>, AccountIdOf<T>),
+		/// Menstrual cycle logs in a date range synced to a desired set
+		/// parameters, [MenstrualCalendarId, MenstrualCycleLogSync, who]
+		MenstrualCycleLogsSynced(HashOf<T>, MenstrualCycleLogSyncOf<T>, AccountIdOf<T>),
+		/// Encrypted menstrual cycle logs added
+		/// parameters, [EncryptedMenstrualCycleLog, who]
+		EncryptedMenstrualCycleLogsAdded(Vec<EncryptedMenstrualCycleLogOf<T>>, AccountIdOf<T>),
+		/// Plaintext menstrual cycle log replaced by an encrypted one
+		/// parameters, [MenstrualCycleLogId, EncryptedMenstrualCycleLog, who]
+		MenstrualCycleLogEncrypted(HashOf<T>, EncryptedMenstrualCycleLogOf<T>, AccountIdOf<T>),
+		/// Encrypted menstrual cycle log deleted
+		/// parameters, [EncryptedMenstrualCycleLogId, who]
+		EncryptedMenstrualCycleLogRemoved(HashOf<T>, AccountIdOf<T>),
 	}
 
 	// Errors inform users that something went wrong.
@@ -145,6 +227,24 @@ pub mod pallet {
 		MenstrualCycleLogDoesNotExist,
 		/// User is not allowed to have more than 1 menstrual calendar
 		MenstrualCalendarAlreadyExist,
+		/// Encrypted payload is longer than `MaxCiphertextLength`
+		CiphertextTooLong,
+		/// Ordering an encrypted menstrual_cycle_log that does not exist
+		EncryptedMenstrualCycleLogDoesNotExist,
+		/// Sync range start is not before its end
+		InvalidMenstrualCycleLogSyncRange,
+		/// Menstrual info date falls outside the sync range
+		MenstrualInfoOutOfRange,
+		/// More than one menstrual info for the same day
+		DuplicateMenstrualInfoDate,
+		/// Sync would apply more than `MaxMenstrualCycleLogSyncChanges` changes
+		TooManyMenstrualCycleLogChanges,
+		/// Sync range covers more than `MaxMenstrualCycleLogSyncDays` days
+		MenstrualCycleLogSyncRangeTooLong,
+		/// More than `MaxEncryptedLogsPerCall` encrypted logs added in one call
+		TooManyEncryptedMenstrualCycleLogs,
+		/// Menstrual calendar already has `MaxMenstrualCycleLogsPerCalendar` menstrual cycle logs
+		TooManyMenstrualCycleLogs,
 	}
 
 	#[pallet::call]
@@ -252,5 +352,125 @@ pub mod pallet {
 				Err(error) => Err(error.into()),
 			}
 		}
+
+		/// Replace the logs of a calendar within `[start_date, end_date)` with `menstrual_infos`.
+		/// Logs are matched by day; unmatched infos are added and unmatched logs removed.
+		/// Every day of the range is read, as is every log in it, so both are charged.
+		#[pallet::weight(T::MenstrualCalendarWeightInfo::sync_menstrual_cycle_logs(
+			(menstrual_infos.len() as u32).saturating_add(T::MaxMenstrualCycleLogSyncChanges::get()),
+			Pallet::<T>::sync_day_count(start_date, end_date)
+				.min(T::MaxMenstrualCycleLogSyncDays::get()),
+		))]
+		pub fn sync_menstrual_cycle_logs(
+			origin: OriginFor<T>,
+			menstrual_calendar_id: HashOf<T>,
+			start_date: MomentOf<T>,
+			end_date: MomentOf<T>,
+			menstrual_infos: Vec<MenstrualInfoOf<T>>,
+		) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+
+			match <Self as MenstrualCalendarInterface<T>>::sync_menstrual_cycle_logs(
+				&who,
+				&menstrual_calendar_id,
+				&start_date,
+				&end_date,
+				&menstrual_infos,
+			) {
+				Ok(menstrual_cycle_log_sync) => {
+					// Logs read are the matched infos plus the removed logs
+					let actual_weight = T::MenstrualCalendarWeightInfo::sync_menstrual_cycle_logs(
+						(menstrual_infos.len() + menstrual_cycle_log_sync.removed.len()) as u32,
+						Self::sync_day_count(&start_date, &end_date),
+					);
+
+					Self::deposit_event(Event::MenstrualCycleLogsSynced(
+						menstrual_calendar_id,
+						menstrual_cycle_log_sync,
+						who,
+					));
+					Ok(Some(actual_weight).into())
+				},
+				Err(error) => Err(error.into()),
+			}
+		}
+
+		#[pallet::weight(T::MenstrualCalendarWeightInfo::add_encrypted_menstrual_cycle_log(
+			encrypted_infos.len() as u32,
+			Pallet::<T>::ciphertext_length_of(encrypted_infos),
+		))]
+		pub fn add_encrypted_menstrual_cycle_log(
+			origin: OriginFor<T>,
+			menstrual_calendar_id: HashOf<T>,
+			encrypted_infos: Vec<EncryptedMenstrualInfoOf<T>>,
+		) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+
+			match <Self as MenstrualCalendarInterface<T>>::add_encrypted_menstrual_cycle_log(
+				&who,
+				&menstrual_calendar_id,
+				&encrypted_infos,
+			) {
+				Ok(encrypted_menstrual_cycle_logs) => {
+					Self::deposit_event(Event::EncryptedMenstrualCycleLogsAdded(
+						encrypted_menstrual_cycle_logs,
+						who,
+					));
+					Ok(().into())
+				},
+				Err(error) => Err(error.into()),
+			}
+		}
+
+		#[pallet::weight(T::MenstrualCalendarWeightInfo::encrypt_menstrual_cycle_log())]
+		pub fn encrypt_menstrual_cycle_log(
+			origin: OriginFor<T>,
+			menstrual_calendar_id: HashOf<T>,
+			menstrual_cycle_log_id: HashOf<T>,
+			encrypted_info: EncryptedMenstrualInfoOf<T>,
+		) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+
+			match <Self as MenstrualCalendarInterface<T>>::encrypt_menstrual_cycle_log(
+				&who,
+				&menstrual_calendar_id,
+				&menstrual_cycle_log_id,
+				&encrypted_info,
+			) {
+				Ok(encrypted_menstrual_cycle_log) => {
+					Self::deposit_event(Event::MenstrualCycleLogEncrypted(
+						menstrual_cycle_log_id,
+						encrypted_menstrual_cycle_log,
+						who,
+					));
+					Ok(().into())
+				},
+				Err(error) => Err(error.into()),
+			}
+		}
+
+		#[pallet::weight(T::MenstrualCalendarWeightInfo::remove_encrypted_menstrual_cycle_log())]
+		pub fn remove_encrypted_menstrual_cycle_log(
+			origin: OriginFor<T>,
+			menstrual_calendar_id: HashOf<T>,
+			encrypted_menstrual_cycle_log_id: HashOf<T>,
+		) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+
+			match <Self as MenstrualCalendarInterface<T>>::remove_encrypted_menstrual_cycle_log(
+				&who,
+				&menstrual_calendar_id,
+				&encrypted_menstrual_cycle_log_id,
+			) {
+				Ok(_) => {
+					Self::deposit_event(Event::EncryptedMenstrualCycleLogRemoved(
+						encrypted_menstrual_cycle_log_id,
+						who,
+					));
+					Ok(().into())
+				},
+				Err(error) => Err(error.into()),
+			}
+		}
 	}
 }
@@ -1,11 +1,30 @@
 use crate::*;
 use frame_support::{
+	log,
 	pallet_prelude::{Decode, Encode},
-	traits::Get,
+	storage::migration::storage_key_iter,
+	traits::{Get, PalletInfoAccess},
 	weights::Weight,
+	Blake2_128Concat, BoundedVec,
 };
 use sp_std::vec::Vec;
 
+/// Fails when a legacy menstrual cycle log index does not fit in
+/// `MaxMenstrualCycleLogsPerCalendar`
+#[cfg(feature = "try-runtime")]
+pub fn pre_upgrade<T: Config>() -> Result<Vec<u8>, &'static str> {
+	let max_menstrual_cycle_logs = T::MaxMenstrualCycleLogsPerCalendar::get() as usize;
+
+	if StorageVersion::get::<Pallet<T>>() < 3 &&
+		version::v3::menstrual_cycle_log_indexes::<T>()
+			.any(|(_, _, ids)| ids.len() > max_menstrual_cycle_logs)
+	{
+		return Err("Menstrual cycle log index holds more ids than MaxMenstrualCycleLogsPerCalendar")
+	}
+
+	Ok(Vec::new())
+}
+
 pub fn migrate<T: Config>() -> Weight {
 	let mut weight: Weight = Weight::zero();
 	let mut version = StorageVersion::get::<Pallet<T>>();
@@ -15,6 +34,16 @@ pub fn migrate<T: Config>() -> Weight {
 		version = StorageVersion::new(1);
 	}
 
+	if version == 1 {
+		weight = weight.saturating_add(version::v2::migrate::<T>());
+		version = StorageVersion::new(2);
+	}
+
+	if version == 2 {
+		weight = weight.saturating_add(version::v3::migrate::<T>());
+		version = StorageVersion::new(3);
+	}
+
 	version.put::<Pallet<T>>();
 	weight
 }
@@ -22,6 +51,42 @@ pub fn migrate<T: Config>() -> Weight {
 mod version {
 	use super::*;
 
+	/// Truncates `value` to the bound and logs the entry when it did not fit.
+	pub fn bounded<I, S: Get<u32>>(value: Vec<I>, entry: &str) -> BoundedVec<I, S> {
+		if value.len() > S::get() as usize {
+			log::warn!(
+				target: "runtime::menstrual-calendar",
+				"{} exceeds its bound of {}, truncated from {} items",
+				entry,
+				S::get(),
+				value.len(),
+			);
+		}
+
+		BoundedVec::truncate_from(value)
+	}
+
+	/// Bounds the symptoms of a menstrual cycle log and the name of each symptom
+	pub fn bounded_symptoms(symptoms: Vec<Vec<u8>>) -> Symptoms {
+		let symptoms = symptoms
+			.into_iter()
+			.map(|name| {
+				if name.len() > MAX_SYMPTOM_LENGTH as usize {
+					log::warn!(
+						target: "runtime::menstrual-calendar",
+						"Symptom exceeds its bound of {}, truncated from {} bytes",
+						MAX_SYMPTOM_LENGTH,
+						name.len(),
+					);
+				}
+
+				Symptom::from(&name)
+			})
+			.collect();
+
+		bounded(symptoms, "Menstrual cycle log symptoms")
+	}
+
 	pub mod v1 {
 		use super::*;
 
@@ -74,8 +139,9 @@ mod version {
 			MenstrualCycleLogById::<T>::translate(|_key, old: OldMenstrualCycleLogOf<T>| {
 				weight = weight.saturating_add(T::DbWeight::get().reads_writes(1, 1));
 
-				let new_symptoms: Vec<Symptom> =
-					old.symptoms.into_iter().map(|symptom| Symptom::from(&symptom.name)).collect();
+				let new_symptoms = bounded_symptoms(
+					old.symptoms.into_iter().map(|symptom| symptom.name).collect(),
+				);
 
 				Some(MenstrualCycleLog {
 					id: old.id,
@@ -91,4 +157,145 @@ mod version {
 			weight
 		}
 	}
+
+	pub mod v2 {
+		use super::*;
+
+		pub fn migrate<T: Config>() -> Weight {
+			let mut weight = T::DbWeight::get().writes(1);
+
+			// Index every menstrual cycle log by the day of its date
+			for (id, menstrual_cycle_log) in MenstrualCycleLogById::<T>::iter() {
+				weight = weight.saturating_add(T::DbWeight::get().reads_writes(1, 1));
+
+				if Pallet::<T>::add_menstrual_cycle_log_by_date(
+					&menstrual_cycle_log.menstrual_calendar_id,
+					&menstrual_cycle_log.date,
+					&id,
+				)
+				.is_err()
+				{
+					log::error!(
+						target: "runtime::menstrual-calendar",
+						"Menstrual cycle log {:?} not indexed by date, its day holds \
+						MaxMenstrualCycleLogsPerCalendar ids",
+						id,
+					);
+				}
+			}
+
+			weight
+		}
+	}
+	pub mod v3 {
+		use super::*;
+
+		#[derive(Encode, Decode)]
+		pub struct OldMenstrualCycleLog<Hash, Moment> {
+			pub id: Hash,
+			pub menstrual_calendar_id: Hash,
+			pub date: Moment,
+			pub menstruation: bool,
+			pub symptoms: Vec<Vec<u8>>,
+			pub created_at: Moment,
+			pub updated_at: Moment,
+		}
+
+		pub type OldMenstrualCycleLogOf<T> = OldMenstrualCycleLog<HashOf<T>, MomentOf<T>>;
+
+		#[derive(Encode, Decode)]
+		pub struct OldEncryptedMenstrualCycleLog<Hash, Moment> {
+			pub id: Hash,
+			pub menstrual_calendar_id: Hash,
+			pub date_bucket: Moment,
+			pub ciphertext: Vec<u8>,
+			pub nonce: [u8; NONCE_LENGTH],
+			pub box_public_key: Hash,
+			pub created_at: Moment,
+			pub updated_at: Moment,
+		}
+
+		pub type OldEncryptedMenstrualCycleLogOf<T> =
+			OldEncryptedMenstrualCycleLog<HashOf<T>, MomentOf<T>>;
+
+		/// Symptoms, ciphertexts and menstrual calendar indexes become bounded, legacy values
+		/// that exceed a bound are truncated and logged. Menstrual cycle log id indexes are
+		/// bounded by `MaxMenstrualCycleLogsPerCalendar` and encode like the lists they replace,
+		/// so they are only checked. An index holding more than
+		/// `MaxMenstrualCycleLogsPerCalendar` ids is left as it is and reported instead of
+		/// dropping ids, raise `MaxMenstrualCycleLogsPerCalendar` above it before upgrading.
+		pub fn migrate<T: Config>() -> Weight {
+			let mut weight = T::DbWeight::get().writes(1);
+
+			MenstrualCalendarByOwner::<T>::translate(|_key, old: Vec<MenstrualCalendarIdOf<T>>| {
+				weight = weight.saturating_add(T::DbWeight::get().reads_writes(1, 1));
+
+				Some(bounded(old, "Menstrual calendar index"))
+			});
+
+			MenstrualCycleLogById::<T>::translate(|_key, old: OldMenstrualCycleLogOf<T>| {
+				weight = weight.saturating_add(T::DbWeight::get().reads_writes(1, 1));
+
+				Some(MenstrualCycleLog {
+					id: old.id,
+					menstrual_calendar_id: old.menstrual_calendar_id,
+					date: old.date,
+					menstruation: old.menstruation,
+					symptoms: bounded_symptoms(old.symptoms),
+					created_at: old.created_at,
+					updated_at: old.updated_at,
+				})
+			});
+
+			EncryptedMenstrualCycleLogById::<T>::translate(
+				|_key, old: OldEncryptedMenstrualCycleLogOf<T>| {
+					weight = weight.saturating_add(T::DbWeight::get().reads_writes(1, 1));
+
+					Some(EncryptedMenstrualCycleLog {
+						id: old.id,
+						menstrual_calendar_id: old.menstrual_calendar_id,
+						date_bucket: old.date_bucket,
+						ciphertext: bounded(old.ciphertext, "Encrypted menstrual cycle log"),
+						nonce: old.nonce,
+						box_public_key: old.box_public_key,
+						created_at: old.created_at,
+						updated_at: old.updated_at,
+					})
+				},
+			);
+
+			let max_menstrual_cycle_logs = T::MaxMenstrualCycleLogsPerCalendar::get() as usize;
+
+			for (index, menstrual_calendar_id, ids) in menstrual_cycle_log_indexes::<T>() {
+				weight = weight.saturating_add(T::DbWeight::get().reads(1));
+
+				if ids.len() > max_menstrual_cycle_logs {
+					log::error!(
+						target: "runtime::menstrual-calendar",
+						"{} of {:?} holds {} ids, more than MaxMenstrualCycleLogsPerCalendar ({})",
+						index,
+						menstrual_calendar_id,
+						ids.len(),
+						max_menstrual_cycle_logs,
+					);
+				}
+			}
+
+			weight
+		}
+
+		/// Plaintext and encrypted menstrual cycle log id indexes as stored before v3
+		pub fn menstrual_cycle_log_indexes<T: Config>(
+		) -> impl Iterator<Item = (&'static str, HashOf<T>, Vec<MenstrualCycleLogIdOf<T>>)> {
+			let indexes = |storage_name: &'static str| {
+				storage_key_iter::<HashOf<T>, Vec<MenstrualCycleLogIdOf<T>>, Blake2_128Concat>(
+					Pallet::<T>::name().as_bytes(),
+					storage_name.as_bytes(),
+				)
+				.map(move |(menstrual_calendar_id, ids)| (storage_name, menstrual_calendar_id, ids))
+			};
+
+			indexes("MenstrualCycleLogByOwner").chain(indexes("EncryptedMenstrualCycleLogByOwner"))
+		}
+	}
 }
@@ -56,8 +56,21 @@ impl frame_system::Config for Test {
 	type MaxConsumers = frame_support::traits::ConstU32<16>;
 }
 
+parameter_types! {
+	pub const MaxCiphertextLength: u32 = 256;
+	pub const MaxMenstrualCycleLogSyncChanges: u32 = 5;
+	pub const MaxEncryptedLogsPerCall: u32 = 5;
+	pub const MaxMenstrualCycleLogSyncDays: u32 = 31;
+	pub const MaxMenstrualCycleLogsPerCalendar: u32 = 8;
+}
+
 impl menstrual_calendar::Config for Test {
 	type RuntimeEvent = RuntimeEvent;
+	type MaxCiphertextLength = MaxCiphertextLength;
+	type MaxMenstrualCycleLogSyncChanges = MaxMenstrualCycleLogSyncChanges;
+	type MaxEncryptedLogsPerCall = MaxEncryptedLogsPerCall;
+	type MaxMenstrualCycleLogSyncDays = MaxMenstrualCycleLogSyncDays;
+	type MaxMenstrualCycleLogsPerCalendar = MaxMenstrualCycleLogsPerCalendar;
 	type MenstrualCalendarWeightInfo = ();
 }
 
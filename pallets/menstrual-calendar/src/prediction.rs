@@ -0,0 +1,112 @@
+use frame_support::pallet_prelude::*;
+use scale_info::TypeInfo;
+use sp_std::vec::Vec;
+
+#[cfg(feature = "std")]
+use serde::{Deserialize, Serialize};
+
+pub const DAY_IN_MILLIS: u64 = 24 * 60 * 60 * 1000;
+
+/// Menstruation days at most this many days apart belong to the same period.
+pub const MAX_PERIOD_GAP_DAYS: u64 = 2;
+/// Cycles shorter than this are treated as logging noise and ignored.
+pub const MIN_CYCLE_DAYS: u64 = 15;
+/// Cycles longer than this are treated as missing logs and ignored.
+pub const MAX_CYCLE_DAYS: u64 = 60;
+/// Days between ovulation and the start of the next period.
+pub const LUTEAL_PHASE_DAYS: u64 = 14;
+/// Days before ovulation in which conception is possible.
+pub const FERTILE_DAYS_BEFORE_OVULATION: u64 = 5;
+/// Days after ovulation in which conception is possible.
+pub const FERTILE_DAYS_AFTER_OVULATION: u64 = 1;
+
+/// Cycle prediction derived from the logged menstruation days of a calendar.
+/// All moments are aligned to the start of a day.
+#[derive(Encode, Decode, Clone, Default, RuntimeDebug, PartialEq, Eq, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub struct CyclePrediction<Moment> {
+	pub last_period_start: Moment,
+	pub next_period_start: Moment,
+	pub ovulation: Moment,
+	pub fertile_window_start: Moment,
+	pub fertile_window_end: Moment,
+	/// Average cycle length in days
+	pub average_cycle: u32,
+	/// Population variance of the observed cycle lengths in days squared
+	pub cycle_variance: u32,
+	/// Number of complete cycles used for the average
+	pub observed_cycles: u32,
+}
+
+/// Returns the days on which a period started, oldest first.
+pub fn period_start_days(menstruation_dates: &[u64]) -> Vec<u64> {
+	let mut days: Vec<u64> = menstruation_dates.iter().map(|date| date / DAY_IN_MILLIS).collect();
+	days.sort_unstable();
+	days.dedup();
+
+	let mut starts: Vec<u64> = Vec::new();
+	let mut previous_day: Option<u64> = None;
+
+	for day in days {
+		match previous_day {
+			Some(previous) if day - previous <= MAX_PERIOD_GAP_DAYS => {},
+			_ => starts.push(day),
+		}
+		previous_day = Some(day);
+	}
+
+	starts
+}
+
+/// Predicts the next cycle from menstruation dates in milliseconds.
+///
+/// The average cycle comes from the gaps between period starts that fall within
+/// `MIN_CYCLE_DAYS..=MAX_CYCLE_DAYS`; `default_cycle` is used when no such gap exists.
+/// Returns `None` when nothing is logged, no usable cycle length is known or a predicted
+/// moment doesn't fit in milliseconds.
+/// Only integer arithmetic is used so every client gets the same result.
+pub fn predict(default_cycle: u8, menstruation_dates: &[u64]) -> Option<CyclePrediction<u64>> {
+	let starts = period_start_days(menstruation_dates);
+	let last_start = *starts.last()?;
+
+	let cycles: Vec<u64> = starts
+		.windows(2)
+		.map(|pair| pair[1] - pair[0])
+		.filter(|cycle| (MIN_CYCLE_DAYS..=MAX_CYCLE_DAYS).contains(cycle))
+		.collect();
+
+	let observed = cycles.len() as u64;
+	let (average_cycle, cycle_variance) = if observed == 0 {
+		(default_cycle as u64, 0)
+	} else {
+		let sum: u64 = cycles.iter().sum();
+		let sum_of_squares: u64 = cycles.iter().map(|cycle| cycle * cycle).sum();
+
+		// Rounded mean and floored variance, (n * Σx² - (Σx)²) / n²
+		let average = (sum + observed / 2) / observed;
+		let variance = (observed * sum_of_squares - sum * sum) / (observed * observed);
+
+		(average, variance)
+	};
+
+	if average_cycle == 0 {
+		return None
+	}
+
+	let next_start = last_start.checked_add(average_cycle)?;
+	let ovulation = next_start.saturating_sub(LUTEAL_PHASE_DAYS).max(last_start);
+	let fertile_window_start =
+		ovulation.saturating_sub(FERTILE_DAYS_BEFORE_OVULATION).max(last_start);
+	let fertile_window_end = ovulation.checked_add(FERTILE_DAYS_AFTER_OVULATION)?;
+
+	Some(CyclePrediction {
+		last_period_start: last_start.checked_mul(DAY_IN_MILLIS)?,
+		next_period_start: next_start.checked_mul(DAY_IN_MILLIS)?,
+		ovulation: ovulation.checked_mul(DAY_IN_MILLIS)?,
+		fertile_window_start: fertile_window_start.checked_mul(DAY_IN_MILLIS)?,
+		fertile_window_end: fertile_window_end.checked_mul(DAY_IN_MILLIS)?,
+		average_cycle: average_cycle as u32,
+		cycle_variance: cycle_variance as u32,
+		observed_cycles: observed as u32,
+	})
+}
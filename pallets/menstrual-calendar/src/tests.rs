@@ -1,10 +1,16 @@
 use crate::{
-	mock::*, Error, MenstrualCalendar as MenstrualCalendarS, MenstrualCycleLog, MenstrualInfo,
-	Symptom,
+	migrations, mock::*, prediction::DAY_IN_MILLIS, CyclePrediction, EncryptedMenstrualCycleLog,
+	EncryptedMenstrualInfo, Error, MenstrualCalendar as MenstrualCalendarS, MenstrualCycleLog,
+	MenstrualCycleLogByDate, MenstrualCycleLogSync, MenstrualInfo, Symptom, MAX_SYMPTOM_LENGTH,
+	NONCE_LENGTH,
 };
 use frame_support::{
 	assert_noop, assert_ok,
+	codec::Encode,
 	sp_runtime::traits::{Hash, Keccak256},
+	storage::migration::put_storage_value,
+	traits::{Get, StorageVersion},
+	Blake2_128Concat, StorageHasher,
 };
 
 #[test]
@@ -55,8 +61,11 @@ fn add_menstrual_cycle_log_works() {
 		assert_ok!(MenstrualCalendar::add_menstrual_calendar(RuntimeOrigin::signed(customer), 16));
 
 		let menstrual_ids = MenstrualCalendar::menstrual_calendar_by_owner(customer).unwrap();
-		let menstrual_info =
-			MenstrualInfo { date: 0, symptoms: vec![Symptom::from(b"pain")], menstruation: true };
+		let menstrual_info = MenstrualInfo {
+			date: 0,
+			symptoms: vec![Symptom::from(b"pain")].try_into().unwrap(),
+			menstruation: true,
+		};
 
 		assert_ok!(MenstrualCalendar::add_menstrual_cycle_log(
 			RuntimeOrigin::signed(customer),
@@ -79,7 +88,7 @@ fn add_menstrual_cycle_log_works() {
 				menstrual_ids[0],
 				0,
 				true,
-				vec![Symptom::from(b"pain")],
+				vec![Symptom::from(b"pain")].try_into().unwrap(),
 				0,
 			)),
 		);
@@ -94,8 +103,11 @@ fn update_menstrual_cycle_log_works() {
 		assert_ok!(MenstrualCalendar::add_menstrual_calendar(RuntimeOrigin::signed(customer), 16));
 
 		let menstrual_ids = MenstrualCalendar::menstrual_calendar_by_owner(customer).unwrap();
-		let menstrual_info =
-			MenstrualInfo { date: 0, symptoms: vec![Symptom::from(b"pain")], menstruation: true };
+		let menstrual_info = MenstrualInfo {
+			date: 0,
+			symptoms: vec![Symptom::from(b"pain")].try_into().unwrap(),
+			menstruation: true,
+		};
 
 		assert_ok!(MenstrualCalendar::add_menstrual_cycle_log(
 			RuntimeOrigin::signed(customer),
@@ -108,9 +120,9 @@ fn update_menstrual_cycle_log_works() {
 		let menstrual_cycle_log = MenstrualCycleLog::new(
 			cycle_log_ids[0],
 			menstrual_ids[0],
-			1,
+			DAY_IN_MILLIS + 1,
 			false,
-			vec![Symptom::from(b"headache")],
+			vec![Symptom::from(b"headache")].try_into().unwrap(),
 			0,
 		);
 		assert_ok!(MenstrualCalendar::update_menstrual_cycle_log(
@@ -123,12 +135,17 @@ fn update_menstrual_cycle_log_works() {
 			Some(MenstrualCycleLog::new(
 				cycle_log_ids[0],
 				menstrual_ids[0],
-				1,
+				DAY_IN_MILLIS + 1,
 				false,
-				vec![Symptom::from(b"headache")],
+				vec![Symptom::from(b"headache")].try_into().unwrap(),
 				0,
 			)),
 		);
+		assert_eq!(MenstrualCalendar::menstrual_cycle_log_by_date(menstrual_ids[0], 0), Vec::new());
+		assert_eq!(
+			MenstrualCalendar::menstrual_cycle_log_by_date(menstrual_ids[0], DAY_IN_MILLIS),
+			cycle_log_ids,
+		);
 	})
 }
 
@@ -140,8 +157,11 @@ fn remove_menstrual_cycle_log_works() {
 		assert_ok!(MenstrualCalendar::add_menstrual_calendar(RuntimeOrigin::signed(customer), 16));
 
 		let menstrual_ids = MenstrualCalendar::menstrual_calendar_by_owner(customer).unwrap();
-		let menstrual_info =
-			MenstrualInfo { date: 0, symptoms: vec![Symptom::from(b"pain")], menstruation: true };
+		let menstrual_info = MenstrualInfo {
+			date: 0,
+			symptoms: vec![Symptom::from(b"pain")].try_into().unwrap(),
+			menstruation: true,
+		};
 
 		assert_ok!(MenstrualCalendar::add_menstrual_cycle_log(
 			RuntimeOrigin::signed(customer),
@@ -161,7 +181,7 @@ fn remove_menstrual_cycle_log_works() {
 		assert_eq!(MenstrualCalendar::menstrual_cycle_log_by_id(cycle_log_ids[0]), None);
 		assert_eq!(
 			MenstrualCalendar::menstrual_cycle_log_by_owner_id(menstrual_ids[0]),
-			Some(Vec::new())
+			Some(Default::default())
 		);
 		assert_eq!(MenstrualCalendar::menstrual_cycle_log_count(), Some(0));
 		assert_eq!(
@@ -220,8 +240,11 @@ fn cant_update_menstrual_calendar_when_not_owner() {
 #[test]
 fn cant_add_menstrual_cycle_log_when_menstrual_calendar_not_exist() {
 	ExternalityBuilder::build().execute_with(|| {
-		let menstrual_info =
-			MenstrualInfo { date: 0, symptoms: vec![Symptom::from(b"pain")], menstruation: true };
+		let menstrual_info = MenstrualInfo {
+			date: 0,
+			symptoms: vec![Symptom::from(b"pain")].try_into().unwrap(),
+			menstruation: true,
+		};
 
 		assert_noop!(
 			MenstrualCalendar::add_menstrual_cycle_log(
@@ -243,8 +266,11 @@ fn cant_add_menstrual_cycle_log_when_not_owner() {
 		assert_ok!(MenstrualCalendar::add_menstrual_calendar(RuntimeOrigin::signed(customer), 16));
 
 		let menstrual_ids = MenstrualCalendar::menstrual_calendar_by_owner(customer).unwrap();
-		let menstrual_info =
-			MenstrualInfo { date: 0, symptoms: vec![Symptom::from(b"pain")], menstruation: true };
+		let menstrual_info = MenstrualInfo {
+			date: 0,
+			symptoms: vec![Symptom::from(b"pain")].try_into().unwrap(),
+			menstruation: true,
+		};
 
 		assert_noop!(
 			MenstrualCalendar::add_menstrual_cycle_log(
@@ -257,6 +283,41 @@ fn cant_add_menstrual_cycle_log_when_not_owner() {
 	})
 }
 
+#[test]
+fn cant_add_more_than_max_menstrual_cycle_logs_per_calendar() {
+	ExternalityBuilder::build().execute_with(|| {
+		let customer = 1;
+
+		assert_ok!(MenstrualCalendar::add_menstrual_calendar(RuntimeOrigin::signed(customer), 28));
+
+		let menstrual_ids = MenstrualCalendar::menstrual_calendar_by_owner(customer).unwrap();
+		let menstrual_infos = |days: core::ops::Range<u64>| {
+			days.map(|day| MenstrualInfo {
+				date: day * DAY_IN_MILLIS,
+				symptoms: Default::default(),
+				menstruation: true,
+			})
+			.collect::<Vec<_>>()
+		};
+		let max_menstrual_cycle_logs = MaxMenstrualCycleLogsPerCalendar::get() as u64;
+
+		assert_ok!(MenstrualCalendar::add_menstrual_cycle_log(
+			RuntimeOrigin::signed(customer),
+			menstrual_ids[0],
+			menstrual_infos(0..max_menstrual_cycle_logs),
+		));
+
+		assert_noop!(
+			MenstrualCalendar::add_menstrual_cycle_log(
+				RuntimeOrigin::signed(customer),
+				menstrual_ids[0],
+				menstrual_infos(max_menstrual_cycle_logs..max_menstrual_cycle_logs + 1),
+			),
+			Error::<Test>::TooManyMenstrualCycleLogs,
+		);
+	})
+}
+
 #[test]
 fn cant_remove_menstrual_cycle_log_when_menstrual_calendar_not_exists() {
 	ExternalityBuilder::build().execute_with(|| {
@@ -282,8 +343,11 @@ fn cant_remove_menstrual_cycle_log_when_not_owner() {
 		assert_ok!(MenstrualCalendar::add_menstrual_calendar(RuntimeOrigin::signed(customer), 16));
 
 		let menstrual_ids = MenstrualCalendar::menstrual_calendar_by_owner(customer).unwrap();
-		let menstrual_info =
-			MenstrualInfo { date: 0, symptoms: vec![Symptom::from(b"pain")], menstruation: true };
+		let menstrual_info = MenstrualInfo {
+			date: 0,
+			symptoms: vec![Symptom::from(b"pain")].try_into().unwrap(),
+			menstruation: true,
+		};
 
 		assert_ok!(MenstrualCalendar::add_menstrual_cycle_log(
 			RuntimeOrigin::signed(customer),
@@ -356,8 +420,11 @@ fn call_event_should_works() {
 			),
 		));
 
-		let menstrual_info =
-			MenstrualInfo { date: 0, symptoms: vec![Symptom::from(b"pain")], menstruation: true };
+		let menstrual_info = MenstrualInfo {
+			date: 0,
+			symptoms: vec![Symptom::from(b"pain")].try_into().unwrap(),
+			menstruation: true,
+		};
 
 		assert_ok!(MenstrualCalendar::add_menstrual_cycle_log(
 			RuntimeOrigin::signed(customer),
@@ -375,7 +442,7 @@ fn call_event_should_works() {
 					menstrual_ids[0],
 					0,
 					true,
-					vec![Symptom::from(b"pain")],
+					vec![Symptom::from(b"pain")].try_into().unwrap(),
 					0,
 				)],
 				customer,
@@ -387,7 +454,7 @@ fn call_event_should_works() {
 			menstrual_ids[0],
 			1,
 			false,
-			vec![Symptom::from(b"headache")],
+			vec![Symptom::from(b"headache")].try_into().unwrap(),
 			0,
 		);
 
@@ -411,3 +478,662 @@ fn call_event_should_works() {
 		));
 	})
 }
+
+#[test]
+fn predict_menstrual_cycle_works() {
+	ExternalityBuilder::build().execute_with(|| {
+		let customer = 1;
+
+		assert_ok!(MenstrualCalendar::add_menstrual_calendar(RuntimeOrigin::signed(customer), 28));
+
+		let menstrual_ids = MenstrualCalendar::menstrual_calendar_by_owner(customer).unwrap();
+		let menstrual_info = |day: u64, menstruation: bool| MenstrualInfo {
+			date: day * DAY_IN_MILLIS,
+			symptoms: Default::default(),
+			menstruation,
+		};
+
+		assert_eq!(MenstrualCalendar::predict_menstrual_cycle(&menstrual_ids[0]), None);
+
+		assert_ok!(MenstrualCalendar::add_menstrual_cycle_log(
+			RuntimeOrigin::signed(customer),
+			menstrual_ids[0],
+			vec![
+				menstrual_info(0, true),
+				menstrual_info(1, true),
+				menstrual_info(2, true),
+				menstrual_info(14, false),
+				menstrual_info(29, true),
+				menstrual_info(28, true),
+				menstrual_info(58, true),
+			],
+		));
+
+		// Cycles of 28 and 30 days
+		assert_eq!(
+			MenstrualCalendar::predict_menstrual_cycle(&menstrual_ids[0]),
+			Some(CyclePrediction {
+				last_period_start: 58 * DAY_IN_MILLIS,
+				next_period_start: 87 * DAY_IN_MILLIS,
+				ovulation: 73 * DAY_IN_MILLIS,
+				fertile_window_start: 68 * DAY_IN_MILLIS,
+				fertile_window_end: 74 * DAY_IN_MILLIS,
+				average_cycle: 29,
+				cycle_variance: 1,
+				observed_cycles: 2,
+			})
+		);
+	})
+}
+
+#[test]
+fn predict_menstrual_cycle_falls_back_to_average_cycle() {
+	ExternalityBuilder::build().execute_with(|| {
+		let customer = 1;
+
+		assert_ok!(MenstrualCalendar::add_menstrual_calendar(RuntimeOrigin::signed(customer), 28));
+
+		let menstrual_ids = MenstrualCalendar::menstrual_calendar_by_owner(customer).unwrap();
+
+		// A gap longer than the maximum cycle is treated as missing logs
+		assert_ok!(MenstrualCalendar::add_menstrual_cycle_log(
+			RuntimeOrigin::signed(customer),
+			menstrual_ids[0],
+			vec![
+				MenstrualInfo {
+					date: 10 * DAY_IN_MILLIS,
+					symptoms: vec![Symptom::from(b"pain")].try_into().unwrap(),
+					menstruation: true,
+				},
+				MenstrualInfo {
+					date: 100 * DAY_IN_MILLIS + 3600,
+					symptoms: Default::default(),
+					menstruation: true,
+				},
+			],
+		));
+
+		assert_eq!(
+			MenstrualCalendar::predict_menstrual_cycle(&menstrual_ids[0]),
+			Some(CyclePrediction {
+				last_period_start: 100 * DAY_IN_MILLIS,
+				next_period_start: 128 * DAY_IN_MILLIS,
+				ovulation: 114 * DAY_IN_MILLIS,
+				fertile_window_start: 109 * DAY_IN_MILLIS,
+				fertile_window_end: 115 * DAY_IN_MILLIS,
+				average_cycle: 28,
+				cycle_variance: 0,
+				observed_cycles: 0,
+			})
+		);
+
+		assert_eq!(
+			MenstrualCalendar::predict_menstrual_cycle(&Keccak256::hash("unknown".as_bytes())),
+			None
+		);
+	})
+}
+
+#[test]
+fn predict_menstrual_cycle_returns_none_when_prediction_overflows() {
+	ExternalityBuilder::build().execute_with(|| {
+		let customer = 1;
+
+		assert_ok!(MenstrualCalendar::add_menstrual_calendar(RuntimeOrigin::signed(customer), 28));
+
+		let menstrual_ids = MenstrualCalendar::menstrual_calendar_by_owner(customer).unwrap();
+
+		assert_ok!(MenstrualCalendar::add_menstrual_cycle_log(
+			RuntimeOrigin::signed(customer),
+			menstrual_ids[0],
+			vec![MenstrualInfo {
+				date: u64::MAX,
+				symptoms: Default::default(),
+				menstruation: true,
+			}],
+		));
+
+		assert_eq!(MenstrualCalendar::predict_menstrual_cycle(&menstrual_ids[0]), None);
+	})
+}
+
+#[test]
+fn add_encrypted_menstrual_cycle_log_works() {
+	ExternalityBuilder::build().execute_with(|| {
+		let customer = 1;
+
+		assert_ok!(MenstrualCalendar::add_menstrual_calendar(RuntimeOrigin::signed(customer), 16));
+
+		let menstrual_ids = MenstrualCalendar::menstrual_calendar_by_owner(customer).unwrap();
+		let box_public_key = Keccak256::hash("box_public_key".as_bytes());
+		let encrypted_info = EncryptedMenstrualInfo {
+			date_bucket: 3 * DAY_IN_MILLIS + 3600,
+			ciphertext: vec![1u8; 64],
+			nonce: [2u8; NONCE_LENGTH],
+			box_public_key,
+		};
+
+		assert_ok!(MenstrualCalendar::add_encrypted_menstrual_cycle_log(
+			RuntimeOrigin::signed(customer),
+			menstrual_ids[0],
+			vec![encrypted_info],
+		));
+
+		let encrypted_log_ids =
+			MenstrualCalendar::encrypted_menstrual_cycle_log_by_owner_id(menstrual_ids[0]).unwrap();
+
+		assert_eq!(
+			MenstrualCalendar::encrypted_menstrual_cycle_log_by_id(encrypted_log_ids[0]),
+			Some(EncryptedMenstrualCycleLog {
+				id: encrypted_log_ids[0],
+				menstrual_calendar_id: menstrual_ids[0],
+				date_bucket: 3 * DAY_IN_MILLIS,
+				ciphertext: vec![1u8; 64].try_into().unwrap(),
+				nonce: [2u8; NONCE_LENGTH],
+				box_public_key,
+				created_at: 0,
+				updated_at: 0,
+			})
+		);
+		assert_eq!(
+			MenstrualCalendar::encrypted_menstrual_cycle_log_by_date(
+				menstrual_ids[0],
+				3 * DAY_IN_MILLIS
+			),
+			encrypted_log_ids
+		);
+		assert_eq!(
+			MenstrualCalendar::encrypted_menstrual_cycle_log_count_by_owner(menstrual_ids[0]),
+			Some(1)
+		);
+	})
+}
+
+#[test]
+fn encrypt_menstrual_cycle_log_works() {
+	ExternalityBuilder::build().execute_with(|| {
+		let customer = 1;
+
+		assert_ok!(MenstrualCalendar::add_menstrual_calendar(RuntimeOrigin::signed(customer), 16));
+
+		let menstrual_ids = MenstrualCalendar::menstrual_calendar_by_owner(customer).unwrap();
+		let menstrual_info = MenstrualInfo {
+			date: DAY_IN_MILLIS,
+			symptoms: vec![Symptom::from(b"pain")].try_into().unwrap(),
+			menstruation: true,
+		};
+
+		assert_ok!(MenstrualCalendar::add_menstrual_cycle_log(
+			RuntimeOrigin::signed(customer),
+			menstrual_ids[0],
+			vec![menstrual_info],
+		));
+
+		let cycle_log_ids =
+			MenstrualCalendar::menstrual_cycle_log_by_owner_id(menstrual_ids[0]).unwrap();
+		let encrypted_info = EncryptedMenstrualInfo {
+			date_bucket: DAY_IN_MILLIS,
+			ciphertext: vec![1u8; 64],
+			nonce: [2u8; NONCE_LENGTH],
+			box_public_key: Keccak256::hash("box_public_key".as_bytes()),
+		};
+
+		assert_ok!(MenstrualCalendar::encrypt_menstrual_cycle_log(
+			RuntimeOrigin::signed(customer),
+			menstrual_ids[0],
+			cycle_log_ids[0],
+			encrypted_info,
+		));
+
+		// The plaintext log is gone
+		assert_eq!(MenstrualCalendar::menstrual_cycle_log_by_id(cycle_log_ids[0]), None);
+		assert_eq!(
+			MenstrualCalendar::menstrual_cycle_log_by_owner_id(menstrual_ids[0]),
+			Some(Default::default())
+		);
+		assert_eq!(MenstrualCalendar::menstrual_cycle_log_count(), Some(0));
+
+		let encrypted_log_ids =
+			MenstrualCalendar::encrypted_menstrual_cycle_log_by_owner_id(menstrual_ids[0]).unwrap();
+
+		assert_eq!(encrypted_log_ids.len(), 1);
+		assert_eq!(
+			MenstrualCalendar::encrypted_menstrual_cycle_log_by_date(
+				menstrual_ids[0],
+				DAY_IN_MILLIS
+			),
+			encrypted_log_ids
+		);
+
+		// Only plaintext logs feed the prediction
+		assert_eq!(MenstrualCalendar::predict_menstrual_cycle(&menstrual_ids[0]), None);
+	})
+}
+
+#[test]
+fn remove_encrypted_menstrual_cycle_log_works() {
+	ExternalityBuilder::build().execute_with(|| {
+		let customer = 1;
+
+		assert_ok!(MenstrualCalendar::add_menstrual_calendar(RuntimeOrigin::signed(customer), 16));
+
+		let menstrual_ids = MenstrualCalendar::menstrual_calendar_by_owner(customer).unwrap();
+		let encrypted_info = EncryptedMenstrualInfo {
+			date_bucket: DAY_IN_MILLIS,
+			ciphertext: vec![1u8; 64],
+			nonce: [2u8; NONCE_LENGTH],
+			box_public_key: Keccak256::hash("box_public_key".as_bytes()),
+		};
+
+		assert_ok!(MenstrualCalendar::add_encrypted_menstrual_cycle_log(
+			RuntimeOrigin::signed(customer),
+			menstrual_ids[0],
+			vec![encrypted_info],
+		));
+
+		let encrypted_log_ids =
+			MenstrualCalendar::encrypted_menstrual_cycle_log_by_owner_id(menstrual_ids[0]).unwrap();
+
+		assert_ok!(MenstrualCalendar::remove_encrypted_menstrual_cycle_log(
+			RuntimeOrigin::signed(customer),
+			menstrual_ids[0],
+			encrypted_log_ids[0],
+		));
+
+		assert_eq!(
+			MenstrualCalendar::encrypted_menstrual_cycle_log_by_id(encrypted_log_ids[0]),
+			None
+		);
+		assert_eq!(
+			MenstrualCalendar::encrypted_menstrual_cycle_log_by_owner_id(menstrual_ids[0]),
+			Some(Default::default())
+		);
+		assert_eq!(
+			MenstrualCalendar::encrypted_menstrual_cycle_log_by_date(
+				menstrual_ids[0],
+				DAY_IN_MILLIS
+			),
+			Vec::new()
+		);
+		assert_eq!(
+			MenstrualCalendar::encrypted_menstrual_cycle_log_count_by_owner(menstrual_ids[0]),
+			Some(0)
+		);
+	})
+}
+
+#[test]
+fn cant_add_encrypted_menstrual_cycle_log_when_ciphertext_too_long() {
+	ExternalityBuilder::build().execute_with(|| {
+		let customer = 1;
+
+		assert_ok!(MenstrualCalendar::add_menstrual_calendar(RuntimeOrigin::signed(customer), 16));
+
+		let menstrual_ids = MenstrualCalendar::menstrual_calendar_by_owner(customer).unwrap();
+		let encrypted_info = EncryptedMenstrualInfo {
+			date_bucket: DAY_IN_MILLIS,
+			ciphertext: vec![1u8; MaxCiphertextLength::get() as usize + 1],
+			nonce: [2u8; NONCE_LENGTH],
+			box_public_key: Keccak256::hash("box_public_key".as_bytes()),
+		};
+
+		assert_noop!(
+			MenstrualCalendar::add_encrypted_menstrual_cycle_log(
+				RuntimeOrigin::signed(customer),
+				menstrual_ids[0],
+				vec![encrypted_info],
+			),
+			Error::<Test>::CiphertextTooLong,
+		);
+	})
+}
+
+#[test]
+fn cant_add_encrypted_menstrual_cycle_log_when_too_many_logs() {
+	ExternalityBuilder::build().execute_with(|| {
+		let customer = 1;
+
+		assert_ok!(MenstrualCalendar::add_menstrual_calendar(RuntimeOrigin::signed(customer), 16));
+
+		let menstrual_ids = MenstrualCalendar::menstrual_calendar_by_owner(customer).unwrap();
+		let encrypted_infos: Vec<_> = (0..MaxEncryptedLogsPerCall::get() as u64 + 1)
+			.map(|day| EncryptedMenstrualInfo {
+				date_bucket: day * DAY_IN_MILLIS,
+				ciphertext: vec![1u8; 64],
+				nonce: [2u8; NONCE_LENGTH],
+				box_public_key: Keccak256::hash("box_public_key".as_bytes()),
+			})
+			.collect();
+
+		assert_noop!(
+			MenstrualCalendar::add_encrypted_menstrual_cycle_log(
+				RuntimeOrigin::signed(customer),
+				menstrual_ids[0],
+				encrypted_infos,
+			),
+			Error::<Test>::TooManyEncryptedMenstrualCycleLogs,
+		);
+	})
+}
+
+#[test]
+fn cant_remove_encrypted_menstrual_cycle_log_when_not_owner() {
+	ExternalityBuilder::build().execute_with(|| {
+		let customer = 1;
+		let other_customer = 2;
+
+		assert_ok!(MenstrualCalendar::add_menstrual_calendar(RuntimeOrigin::signed(customer), 16));
+
+		let menstrual_ids = MenstrualCalendar::menstrual_calendar_by_owner(customer).unwrap();
+		let encrypted_info = EncryptedMenstrualInfo {
+			date_bucket: DAY_IN_MILLIS,
+			ciphertext: vec![1u8; 64],
+			nonce: [2u8; NONCE_LENGTH],
+			box_public_key: Keccak256::hash("box_public_key".as_bytes()),
+		};
+
+		assert_ok!(MenstrualCalendar::add_encrypted_menstrual_cycle_log(
+			RuntimeOrigin::signed(customer),
+			menstrual_ids[0],
+			vec![encrypted_info],
+		));
+
+		let encrypted_log_ids =
+			MenstrualCalendar::encrypted_menstrual_cycle_log_by_owner_id(menstrual_ids[0]).unwrap();
+
+		assert_noop!(
+			MenstrualCalendar::remove_encrypted_menstrual_cycle_log(
+				RuntimeOrigin::signed(other_customer),
+				menstrual_ids[0],
+				encrypted_log_ids[0],
+			),
+			Error::<Test>::NotMenstrualCalendarOwner,
+		);
+	})
+}
+
+#[test]
+fn sync_menstrual_cycle_logs_works() {
+	ExternalityBuilder::build().execute_with(|| {
+		System::set_block_number(1);
+
+		let customer = 1;
+
+		assert_ok!(MenstrualCalendar::add_menstrual_calendar(RuntimeOrigin::signed(customer), 28));
+
+		let menstrual_ids = MenstrualCalendar::menstrual_calendar_by_owner(customer).unwrap();
+
+		assert_ok!(MenstrualCalendar::add_menstrual_cycle_log(
+			RuntimeOrigin::signed(customer),
+			menstrual_ids[0],
+			vec![
+				MenstrualInfo {
+					date: DAY_IN_MILLIS,
+					symptoms: Default::default(),
+					menstruation: true
+				},
+				MenstrualInfo {
+					date: 2 * DAY_IN_MILLIS,
+					symptoms: Default::default(),
+					menstruation: true
+				},
+				MenstrualInfo {
+					date: 5 * DAY_IN_MILLIS,
+					symptoms: Default::default(),
+					menstruation: false
+				},
+				MenstrualInfo {
+					date: 40 * DAY_IN_MILLIS,
+					symptoms: Default::default(),
+					menstruation: true
+				},
+			],
+		));
+
+		let cycle_log_ids =
+			MenstrualCalendar::menstrual_cycle_log_by_owner_id(menstrual_ids[0]).unwrap();
+
+		// Day 1 is unchanged, day 2 gains a symptom, day 3 is new and day 5 is dropped
+		assert_ok!(MenstrualCalendar::sync_menstrual_cycle_logs(
+			RuntimeOrigin::signed(customer),
+			menstrual_ids[0],
+			0,
+			30 * DAY_IN_MILLIS,
+			vec![
+				MenstrualInfo {
+					date: DAY_IN_MILLIS,
+					symptoms: Default::default(),
+					menstruation: true
+				},
+				MenstrualInfo {
+					date: 2 * DAY_IN_MILLIS,
+					symptoms: vec![Symptom::from(b"pain")].try_into().unwrap(),
+					menstruation: true,
+				},
+				MenstrualInfo {
+					date: 3 * DAY_IN_MILLIS,
+					symptoms: Default::default(),
+					menstruation: true
+				},
+			],
+		));
+
+		let synced_ids =
+			MenstrualCalendar::menstrual_cycle_log_by_owner_id(menstrual_ids[0]).unwrap();
+
+		assert_eq!(synced_ids.len(), 4);
+		assert_eq!(synced_ids[..2], cycle_log_ids[..2]);
+		assert_eq!(synced_ids[2], cycle_log_ids[3]);
+		assert_eq!(MenstrualCalendar::menstrual_cycle_log_by_id(cycle_log_ids[2]), None);
+		assert_eq!(MenstrualCalendar::menstrual_cycle_log_count(), Some(4));
+		assert_eq!(
+			MenstrualCalendar::menstrual_cycle_log_count_by_owner(menstrual_ids[0]),
+			Some(4)
+		);
+
+		let updated = MenstrualCalendar::menstrual_cycle_log_by_id(cycle_log_ids[1]).unwrap();
+		let added = MenstrualCalendar::menstrual_cycle_log_by_id(synced_ids[3]).unwrap();
+
+		assert_eq!(updated.symptoms, vec![Symptom::from(b"pain")]);
+		assert_eq!(added.date, 3 * DAY_IN_MILLIS);
+		assert_eq!(
+			MenstrualCalendar::menstrual_cycle_log_by_date(menstrual_ids[0], 3 * DAY_IN_MILLIS),
+			vec![added.id],
+		);
+		assert_eq!(
+			MenstrualCalendar::menstrual_cycle_log_by_date(menstrual_ids[0], 5 * DAY_IN_MILLIS),
+			Vec::new(),
+		);
+
+		System::assert_last_event(RuntimeEvent::MenstrualCalendar(
+			crate::Event::MenstrualCycleLogsSynced(
+				menstrual_ids[0],
+				MenstrualCycleLogSync {
+					added: vec![added],
+					updated: vec![updated],
+					removed: vec![cycle_log_ids[2]],
+				},
+				customer,
+			),
+		));
+	})
+}
+
+#[test]
+fn cant_sync_menstrual_cycle_logs_with_invalid_menstrual_infos() {
+	ExternalityBuilder::build().execute_with(|| {
+		let customer = 1;
+
+		assert_ok!(MenstrualCalendar::add_menstrual_calendar(RuntimeOrigin::signed(customer), 28));
+
+		let menstrual_ids = MenstrualCalendar::menstrual_calendar_by_owner(customer).unwrap();
+		let menstrual_info =
+			|date| MenstrualInfo { date, symptoms: Default::default(), menstruation: true };
+
+		assert_noop!(
+			MenstrualCalendar::sync_menstrual_cycle_logs(
+				RuntimeOrigin::signed(customer),
+				menstrual_ids[0],
+				DAY_IN_MILLIS,
+				DAY_IN_MILLIS,
+				Vec::new(),
+			),
+			Error::<Test>::InvalidMenstrualCycleLogSyncRange,
+		);
+
+		assert_noop!(
+			MenstrualCalendar::sync_menstrual_cycle_logs(
+				RuntimeOrigin::signed(customer),
+				menstrual_ids[0],
+				0,
+				DAY_IN_MILLIS,
+				vec![menstrual_info(DAY_IN_MILLIS)],
+			),
+			Error::<Test>::MenstrualInfoOutOfRange,
+		);
+
+		assert_noop!(
+			MenstrualCalendar::sync_menstrual_cycle_logs(
+				RuntimeOrigin::signed(customer),
+				menstrual_ids[0],
+				0,
+				DAY_IN_MILLIS,
+				vec![menstrual_info(0), menstrual_info(3600)],
+			),
+			Error::<Test>::DuplicateMenstrualInfoDate,
+		);
+
+		assert_noop!(
+			MenstrualCalendar::sync_menstrual_cycle_logs(
+				RuntimeOrigin::signed(customer),
+				menstrual_ids[0],
+				0,
+				30 * DAY_IN_MILLIS,
+				(0..6).map(|day| menstrual_info(day * DAY_IN_MILLIS)).collect(),
+			),
+			Error::<Test>::TooManyMenstrualCycleLogChanges,
+		);
+
+		assert_noop!(
+			MenstrualCalendar::sync_menstrual_cycle_logs(
+				RuntimeOrigin::signed(customer),
+				menstrual_ids[0],
+				0,
+				(MaxMenstrualCycleLogSyncDays::get() as u64 + 1) * DAY_IN_MILLIS,
+				Vec::new(),
+			),
+			Error::<Test>::MenstrualCycleLogSyncRangeTooLong,
+		);
+
+		assert_noop!(
+			MenstrualCalendar::sync_menstrual_cycle_logs(
+				RuntimeOrigin::signed(2),
+				menstrual_ids[0],
+				0,
+				30 * DAY_IN_MILLIS,
+				Vec::new(),
+			),
+			Error::<Test>::NotMenstrualCalendarOwner,
+		);
+	})
+}
+
+#[test]
+fn migrate_menstrual_cycle_logs_by_date_indexes_every_log() {
+	ExternalityBuilder::build().execute_with(|| {
+		let customer = 1;
+
+		assert_ok!(MenstrualCalendar::add_menstrual_calendar(RuntimeOrigin::signed(customer), 28));
+
+		let menstrual_ids = MenstrualCalendar::menstrual_calendar_by_owner(customer).unwrap();
+
+		assert_ok!(MenstrualCalendar::add_menstrual_cycle_log(
+			RuntimeOrigin::signed(customer),
+			menstrual_ids[0],
+			vec![
+				MenstrualInfo {
+					date: DAY_IN_MILLIS,
+					symptoms: Default::default(),
+					menstruation: true
+				},
+				MenstrualInfo {
+					date: DAY_IN_MILLIS + 1,
+					symptoms: Default::default(),
+					menstruation: true
+				},
+				MenstrualInfo {
+					date: 2 * DAY_IN_MILLIS,
+					symptoms: Default::default(),
+					menstruation: true
+				},
+			],
+		));
+
+		let cycle_log_ids =
+			MenstrualCalendar::menstrual_cycle_log_by_owner_id(menstrual_ids[0]).unwrap();
+
+		let _ = MenstrualCycleLogByDate::<Test>::clear(u32::MAX, None);
+		StorageVersion::new(1).put::<MenstrualCalendar>();
+
+		migrations::migrate::<Test>();
+
+		let mut first_day =
+			MenstrualCalendar::menstrual_cycle_log_by_date(menstrual_ids[0], DAY_IN_MILLIS)
+				.into_inner();
+		first_day.sort();
+		let mut expected = cycle_log_ids[..2].to_vec();
+		expected.sort();
+
+		assert_eq!(first_day, expected);
+		assert_eq!(
+			MenstrualCalendar::menstrual_cycle_log_by_date(menstrual_ids[0], 2 * DAY_IN_MILLIS),
+			vec![cycle_log_ids[2]],
+		);
+		assert_eq!(StorageVersion::get::<MenstrualCalendar>(), 3);
+	})
+}
+
+#[test]
+fn migrate_menstrual_cycle_log_symptoms_to_bounded_fields() {
+	ExternalityBuilder::build().execute_with(|| {
+		let id = Keccak256::hash("menstrual_cycle_log".as_bytes());
+		let menstrual_calendar_id = Keccak256::hash("menstrual_calendar".as_bytes());
+		let long_symptom = vec![b'a'; MAX_SYMPTOM_LENGTH as usize + 1];
+
+		put_storage_value(
+			b"MenstrualCalendar",
+			b"MenstrualCycleLogById",
+			&Blake2_128Concat::hash(&id.encode()),
+			(
+				id,
+				menstrual_calendar_id,
+				DAY_IN_MILLIS,
+				true,
+				vec![b"pain".to_vec(), long_symptom.clone()],
+				0u64,
+				0u64,
+			),
+		);
+		StorageVersion::new(2).put::<MenstrualCalendar>();
+
+		migrations::migrate::<Test>();
+
+		assert_eq!(
+			MenstrualCalendar::menstrual_cycle_log_by_id(id),
+			Some(MenstrualCycleLog::new(
+				id,
+				menstrual_calendar_id,
+				DAY_IN_MILLIS,
+				true,
+				vec![
+					Symptom::from(b"pain"),
+					Symptom::from(&long_symptom[..MAX_SYMPTOM_LENGTH as usize])
+				]
+				.try_into()
+				.unwrap(),
+				0,
+			)),
+		);
+		assert_eq!(StorageVersion::get::<MenstrualCalendar>(), 3);
+	})
+}
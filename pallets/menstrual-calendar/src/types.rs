@@ -1,35 +1,48 @@
 use crate::*;
-use frame_support::pallet_prelude::*;
+use frame_support::{
+	pallet_prelude::*, CloneNoBound, EqNoBound, PartialEqNoBound, RuntimeDebugNoBound,
+};
 use scale_info::TypeInfo;
-use sp_std::vec::Vec;
+use sp_std::{fmt::Debug, vec::Vec};
 use traits_menstrual_calendar::{
 	MenstrualCalendar as MenstrualCalendarT, MenstrualCycleLog as MenstrualCycleLogT,
 };
 
+/// Maximum length of the name of a symptom
+pub const MAX_SYMPTOM_LENGTH: u32 = 128;
+/// Maximum number of symptoms of a menstrual cycle log
+pub const MAX_SYMPTOMS_PER_MENSTRUAL_CYCLE_LOG: u32 = 32;
+
+/// Maximum number of menstrual calendars of an account
+pub const MAX_MENSTRUAL_CALENDARS_PER_OWNER: u32 = 1;
+
 // Symptom
-#[derive(Encode, Decode, Clone, Default, RuntimeDebug, PartialEq, Eq, TypeInfo)]
-pub struct Symptom(Vec<u8>);
+#[derive(Encode, Decode, Clone, Default, RuntimeDebug, PartialEq, Eq, TypeInfo, MaxEncodedLen)]
+pub struct Symptom(BoundedVec<u8, ConstU32<MAX_SYMPTOM_LENGTH>>);
 impl Symptom {
+	/// Names longer than MAX_SYMPTOM_LENGTH are truncated
 	pub fn from(name: &[u8]) -> Symptom {
-		Symptom(name.to_vec())
+		Symptom(BoundedVec::truncate_from(name.to_vec()))
 	}
 }
 
-#[derive(Encode, Decode, Clone, Default, RuntimeDebug, PartialEq, Eq, TypeInfo)]
+pub type Symptoms = BoundedVec<Symptom, ConstU32<MAX_SYMPTOMS_PER_MENSTRUAL_CYCLE_LOG>>;
+
+#[derive(Encode, Decode, Clone, Default, RuntimeDebug, PartialEq, Eq, TypeInfo, MaxEncodedLen)]
 pub struct MenstrualInfo<Moment> {
 	pub date: Moment,
-	pub symptoms: Vec<Symptom>,
+	pub symptoms: Symptoms,
 	pub menstruation: bool,
 }
 
 // MenstrualCycleLog
-#[derive(Encode, Decode, Clone, Default, RuntimeDebug, PartialEq, Eq, TypeInfo)]
+#[derive(Encode, Decode, Clone, Default, RuntimeDebug, PartialEq, Eq, TypeInfo, MaxEncodedLen)]
 pub struct MenstrualCycleLog<Hash, Moment> {
 	pub id: Hash,
 	pub menstrual_calendar_id: Hash,
 	pub date: Moment,
 	pub menstruation: bool,
-	pub symptoms: Vec<Symptom>,
+	pub symptoms: Symptoms,
 	pub created_at: Moment,
 	pub updated_at: Moment,
 }
@@ -40,7 +53,7 @@ impl<Hash, Moment: Default> MenstrualCycleLog<Hash, Moment> {
 		menstrual_calendar_id: Hash,
 		date: Moment,
 		menstruation: bool,
-		symptoms: Vec<Symptom>,
+		symptoms: Symptoms,
 		created_at: Moment,
 	) -> Self {
 		Self {
@@ -75,7 +88,103 @@ where
 	}
 }
 
+/// Changes applied by a menstrual cycle log sync
+#[derive(Encode, Decode, Clone, RuntimeDebug, PartialEq, Eq, TypeInfo)]
+pub struct MenstrualCycleLogSync<Hash, Moment> {
+	pub added: Vec<MenstrualCycleLog<Hash, Moment>>,
+	pub updated: Vec<MenstrualCycleLog<Hash, Moment>>,
+	pub removed: Vec<Hash>,
+}
+
+impl<Hash, Moment> Default for MenstrualCycleLogSync<Hash, Moment> {
+	fn default() -> Self {
+		Self { added: Vec::new(), updated: Vec::new(), removed: Vec::new() }
+	}
+}
+
+impl<Hash, Moment> MenstrualCycleLogSync<Hash, Moment> {
+	pub fn changes(&self) -> u32 {
+		(self.added.len() + self.updated.len() + self.removed.len()) as u32
+	}
+}
+
+/// Length of the nonce used to seal an encrypted menstrual cycle log
+pub const NONCE_LENGTH: usize = 24;
+
+/// Menstrual info sealed by the owner. Only the day of `date_bucket` is stored in plaintext.
 #[derive(Encode, Decode, Clone, Default, RuntimeDebug, PartialEq, Eq, TypeInfo)]
+pub struct EncryptedMenstrualInfo<Hash, Moment> {
+	pub date_bucket: Moment,
+	pub ciphertext: Vec<u8>,
+	pub nonce: [u8; NONCE_LENGTH],
+	pub box_public_key: Hash,
+}
+
+// EncryptedMenstrualCycleLog
+#[derive(
+	Encode,
+	Decode,
+	CloneNoBound,
+	PartialEqNoBound,
+	EqNoBound,
+	RuntimeDebugNoBound,
+	TypeInfo,
+	MaxEncodedLen,
+)]
+#[scale_info(skip_type_params(MaxCiphertextLength))]
+#[codec(mel_bound(Hash: MaxEncodedLen, Moment: MaxEncodedLen))]
+pub struct EncryptedMenstrualCycleLog<Hash, Moment, MaxCiphertextLength>
+where
+	Hash: Clone + PartialEq + Eq + Debug,
+	Moment: Clone + PartialEq + Eq + Debug,
+	MaxCiphertextLength: Get<u32>,
+{
+	pub id: Hash,
+	pub menstrual_calendar_id: Hash,
+	pub date_bucket: Moment,
+	pub ciphertext: BoundedVec<u8, MaxCiphertextLength>,
+	pub nonce: [u8; NONCE_LENGTH],
+	pub box_public_key: Hash,
+	pub created_at: Moment,
+	pub updated_at: Moment,
+}
+
+impl<Hash, Moment, MaxCiphertextLength>
+	EncryptedMenstrualCycleLog<Hash, Moment, MaxCiphertextLength>
+where
+	Hash: Clone + PartialEq + Eq + Debug,
+	Moment: Clone + PartialEq + Eq + Debug + Default,
+	MaxCiphertextLength: Get<u32>,
+{
+	/// Returns `None` when the ciphertext is longer than `MaxCiphertextLength`
+	pub fn new(
+		id: Hash,
+		menstrual_calendar_id: Hash,
+		encrypted_info: EncryptedMenstrualInfo<Hash, Moment>,
+		created_at: Moment,
+	) -> Option<Self> {
+		Some(Self {
+			id,
+			menstrual_calendar_id,
+			date_bucket: encrypted_info.date_bucket,
+			ciphertext: encrypted_info.ciphertext.try_into().ok()?,
+			nonce: encrypted_info.nonce,
+			box_public_key: encrypted_info.box_public_key,
+			created_at,
+			updated_at: Moment::default(),
+		})
+	}
+
+	pub fn get_id(&self) -> &Hash {
+		&self.id
+	}
+
+	pub fn get_menstrual_calendar_id(&self) -> &Hash {
+		&self.menstrual_calendar_id
+	}
+}
+
+#[derive(Encode, Decode, Clone, Default, RuntimeDebug, PartialEq, Eq, TypeInfo, MaxEncodedLen)]
 pub struct MenstrualCalendar<AccountId, Hash, Moment> {
 	pub id: Hash,
 	pub address_id: AccountId,
@@ -32,6 +32,10 @@ pub trait WeightInfo {
 	fn add_menstrual_cycle_log() -> Weight;
 	fn update_menstrual_cycle_log() -> Weight;
 	fn remove_menstrual_cycle_log() -> Weight;
+	fn sync_menstrual_cycle_logs(n: u32, d: u32, ) -> Weight;
+	fn add_encrypted_menstrual_cycle_log(n: u32, b: u32, ) -> Weight;
+	fn encrypt_menstrual_cycle_log() -> Weight;
+	fn remove_encrypted_menstrual_cycle_log() -> Weight;
 }
 
 /// Weights for menstrual_calendar using the Substrate node and recommended hardware.
@@ -83,6 +87,66 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(T::DbWeight::get().reads(5_u64))
 			.saturating_add(T::DbWeight::get().writes(4_u64))
 	}
+	// Storage: MenstrualCalendar MenstrualCalendarById (r:1 w:0)
+	// Storage: MenstrualCalendar MenstrualCycleLogByOwner (r:1 w:1)
+	// Storage: Timestamp Now (r:1 w:0)
+	// Storage: MenstrualCalendar MenstrualCycleLogById (r:1 w:1)
+	// Storage: MenstrualCalendar MenstrualCycleLogCountByOwner (r:1 w:1)
+	// Storage: MenstrualCalendar MenstrualCycleLogCount (r:1 w:1)
+	// Storage: MenstrualCalendar MenstrualCycleLogByDate (r:1 w:1)
+	fn sync_menstrual_cycle_logs(n: u32, d: u32, ) -> Weight {
+		Weight::from_ref_time(43_870_000_u64)
+			// Standard Error: 25_000
+			.saturating_add(Weight::from_ref_time(41_020_000_u64).saturating_mul(n as u64))
+			// Standard Error: 4_000
+			.saturating_add(Weight::from_ref_time(4_310_000_u64).saturating_mul(d as u64))
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().reads((4_u64).saturating_mul(n as u64)))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(d as u64)))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+			.saturating_add(T::DbWeight::get().writes((4_u64).saturating_mul(n as u64)))
+	}
+	// Storage: MenstrualCalendar MenstrualCalendarById (r:1 w:0)
+	// Storage: Timestamp Now (r:1 w:0)
+	// Storage: MenstrualCalendar EncryptedMenstrualCycleLogCountByOwner (r:1 w:1)
+	// Storage: MenstrualCalendar EncryptedMenstrualCycleLogByOwner (r:1 w:1)
+	// Storage: MenstrualCalendar EncryptedMenstrualCycleLogByDate (r:1 w:1)
+	// Storage: MenstrualCalendar EncryptedMenstrualCycleLogById (r:0 w:1)
+	fn add_encrypted_menstrual_cycle_log(n: u32, b: u32, ) -> Weight {
+		Weight::from_ref_time(30_100_000_u64)
+			// Standard Error: 21_000
+			.saturating_add(Weight::from_ref_time(38_200_000_u64).saturating_mul(n as u64))
+			// Standard Error: 0
+			.saturating_add(Weight::from_ref_time(2_000_u64).saturating_mul(b as u64))
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().reads((3_u64).saturating_mul(n as u64)))
+			.saturating_add(T::DbWeight::get().writes((4_u64).saturating_mul(n as u64)))
+	}
+	// Storage: MenstrualCalendar MenstrualCalendarById (r:1 w:0)
+	// Storage: MenstrualCalendar MenstrualCycleLogById (r:1 w:1)
+	// Storage: MenstrualCalendar MenstrualCycleLogByOwner (r:1 w:1)
+	// Storage: MenstrualCalendar MenstrualCycleLogCount (r:1 w:1)
+	// Storage: MenstrualCalendar MenstrualCycleLogCountByOwner (r:1 w:1)
+	// Storage: MenstrualCalendar EncryptedMenstrualCycleLogCountByOwner (r:1 w:1)
+	// Storage: Timestamp Now (r:1 w:0)
+	// Storage: MenstrualCalendar EncryptedMenstrualCycleLogByOwner (r:1 w:1)
+	// Storage: MenstrualCalendar EncryptedMenstrualCycleLogByDate (r:1 w:1)
+	// Storage: MenstrualCalendar EncryptedMenstrualCycleLogById (r:0 w:1)
+	fn encrypt_menstrual_cycle_log() -> Weight {
+		Weight::from_ref_time(112_400_000_u64)
+			.saturating_add(T::DbWeight::get().reads(9_u64))
+			.saturating_add(T::DbWeight::get().writes(8_u64))
+	}
+	// Storage: MenstrualCalendar MenstrualCalendarById (r:1 w:0)
+	// Storage: MenstrualCalendar EncryptedMenstrualCycleLogById (r:1 w:1)
+	// Storage: MenstrualCalendar EncryptedMenstrualCycleLogByOwner (r:1 w:1)
+	// Storage: MenstrualCalendar EncryptedMenstrualCycleLogByDate (r:1 w:1)
+	// Storage: MenstrualCalendar EncryptedMenstrualCycleLogCountByOwner (r:1 w:1)
+	fn remove_encrypted_menstrual_cycle_log() -> Weight {
+		Weight::from_ref_time(71_500_000_u64)
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+	}
 }
 
 // For backwards compatibility and tests
@@ -133,4 +197,64 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().reads(5_u64))
 			.saturating_add(RocksDbWeight::get().writes(4_u64))
 	}
+	// Storage: MenstrualCalendar MenstrualCalendarById (r:1 w:0)
+	// Storage: MenstrualCalendar MenstrualCycleLogByOwner (r:1 w:1)
+	// Storage: Timestamp Now (r:1 w:0)
+	// Storage: MenstrualCalendar MenstrualCycleLogById (r:1 w:1)
+	// Storage: MenstrualCalendar MenstrualCycleLogCountByOwner (r:1 w:1)
+	// Storage: MenstrualCalendar MenstrualCycleLogCount (r:1 w:1)
+	// Storage: MenstrualCalendar MenstrualCycleLogByDate (r:1 w:1)
+	fn sync_menstrual_cycle_logs(n: u32, d: u32, ) -> Weight {
+		Weight::from_ref_time(43_870_000_u64)
+			// Standard Error: 25_000
+			.saturating_add(Weight::from_ref_time(41_020_000_u64).saturating_mul(n as u64))
+			// Standard Error: 4_000
+			.saturating_add(Weight::from_ref_time(4_310_000_u64).saturating_mul(d as u64))
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().reads((4_u64).saturating_mul(n as u64)))
+			.saturating_add(RocksDbWeight::get().reads((1_u64).saturating_mul(d as u64)))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+			.saturating_add(RocksDbWeight::get().writes((4_u64).saturating_mul(n as u64)))
+	}
+	// Storage: MenstrualCalendar MenstrualCalendarById (r:1 w:0)
+	// Storage: Timestamp Now (r:1 w:0)
+	// Storage: MenstrualCalendar EncryptedMenstrualCycleLogCountByOwner (r:1 w:1)
+	// Storage: MenstrualCalendar EncryptedMenstrualCycleLogByOwner (r:1 w:1)
+	// Storage: MenstrualCalendar EncryptedMenstrualCycleLogByDate (r:1 w:1)
+	// Storage: MenstrualCalendar EncryptedMenstrualCycleLogById (r:0 w:1)
+	fn add_encrypted_menstrual_cycle_log(n: u32, b: u32, ) -> Weight {
+		Weight::from_ref_time(30_100_000_u64)
+			// Standard Error: 21_000
+			.saturating_add(Weight::from_ref_time(38_200_000_u64).saturating_mul(n as u64))
+			// Standard Error: 0
+			.saturating_add(Weight::from_ref_time(2_000_u64).saturating_mul(b as u64))
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().reads((3_u64).saturating_mul(n as u64)))
+			.saturating_add(RocksDbWeight::get().writes((4_u64).saturating_mul(n as u64)))
+	}
+	// Storage: MenstrualCalendar MenstrualCalendarById (r:1 w:0)
+	// Storage: MenstrualCalendar MenstrualCycleLogById (r:1 w:1)
+	// Storage: MenstrualCalendar MenstrualCycleLogByOwner (r:1 w:1)
+	// Storage: MenstrualCalendar MenstrualCycleLogCount (r:1 w:1)
+	// Storage: MenstrualCalendar MenstrualCycleLogCountByOwner (r:1 w:1)
+	// Storage: MenstrualCalendar EncryptedMenstrualCycleLogCountByOwner (r:1 w:1)
+	// Storage: Timestamp Now (r:1 w:0)
+	// Storage: MenstrualCalendar EncryptedMenstrualCycleLogByOwner (r:1 w:1)
+	// Storage: MenstrualCalendar EncryptedMenstrualCycleLogByDate (r:1 w:1)
+	// Storage: MenstrualCalendar EncryptedMenstrualCycleLogById (r:0 w:1)
+	fn encrypt_menstrual_cycle_log() -> Weight {
+		Weight::from_ref_time(112_400_000_u64)
+			.saturating_add(RocksDbWeight::get().reads(9_u64))
+			.saturating_add(RocksDbWeight::get().writes(8_u64))
+	}
+	// Storage: MenstrualCalendar MenstrualCalendarById (r:1 w:0)
+	// Storage: MenstrualCalendar EncryptedMenstrualCycleLogById (r:1 w:1)
+	// Storage: MenstrualCalendar EncryptedMenstrualCycleLogByOwner (r:1 w:1)
+	// Storage: MenstrualCalendar EncryptedMenstrualCycleLogByDate (r:1 w:1)
+	// Storage: MenstrualCalendar EncryptedMenstrualCycleLogCountByOwner (r:1 w:1)
+	fn remove_encrypted_menstrual_cycle_log() -> Weight {
+		Weight::from_ref_time(71_500_000_u64)
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+	}
 }
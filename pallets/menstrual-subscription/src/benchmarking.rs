@@ -2,11 +2,17 @@ use super::*;
 
 use crate::{AccountKeyType, Config, Pallet as MenstrualSubscription};
 use frame_benchmarking::{account, benchmarks, impl_benchmark_test_suite, whitelisted_caller};
-use frame_support::{sp_runtime::SaturatedConversion, traits::Currency};
+use frame_support::{
+	sp_runtime::{traits::Hash, SaturatedConversion},
+	traits::Currency,
+	weights::Weight,
+	BoundedVec,
+};
 use frame_system::RawOrigin;
 use primitives_duration::MenstrualSubscriptionDuration;
 use primitives_menstrual_status::MenstrualSubscriptionStatus;
 use primitives_price_and_currency::CurrencyType;
+use sp_std::vec::Vec;
 
 const SEED: u32 = 0;
 
@@ -145,6 +151,125 @@ benchmarks! {
 		1000000000000000000u128.saturated_into(),
 		None
 	)
+
+	set_menstrual_subscription_auto_renew {
+		// Initial Account
+		let caller: T::AccountId = whitelisted_caller();
+		let admin: T::AccountId = account("admin", 0, SEED);
+
+		// Set Admin Key
+		let root = <T as frame_system::Config>::RuntimeOrigin::from(RawOrigin::Root);
+		let _ = MenstrualSubscription::<T>::sudo_update_key(root, AccountKeyType::AdminKey(admin.clone()));
+
+		// Set price
+		let admin_origin = <T as frame_system::Config>::RuntimeOrigin::from(RawOrigin::Signed(admin));
+		let _ = MenstrualSubscription::<T>::set_menstrual_subscription_price(
+			admin_origin,
+			MenstrualSubscriptionDuration::default(),
+			CurrencyType::default(),
+			1_000_000_000_000_000_000u128.saturated_into(),
+			None,
+		);
+
+		// Add Subscription
+		let caller_origin = T::RuntimeOrigin::from(RawOrigin::Signed(caller.clone()));
+		let _ = MenstrualSubscription::<T>::add_menstrual_subscription(
+			caller_origin,
+			MenstrualSubscriptionDuration::default(),
+			CurrencyType::DBIO,
+		);
+
+		let ids = MenstrualSubscription::<T>::menstrual_subscription_by_address_id(caller.clone()).unwrap();
+	}: set_menstrual_subscription_auto_renew(
+		RawOrigin::Signed(caller),
+		ids[0],
+		true
+	)
+
+	set_renewal_allowance {
+		let caller: T::AccountId = whitelisted_caller();
+	}: set_renewal_allowance(
+		RawOrigin::Signed(caller),
+		1_000_000_000_000_000_000u128.saturated_into()
+	)
+
+	expire_menstrual_subscription {
+		let n in 0 .. T::MaxMenstrualSubscriptionsPerOwner::get() - 2;
+
+		// Initial Account
+		let caller: T::AccountId = whitelisted_caller();
+		let admin: T::AccountId = account("admin", 0, SEED);
+
+		// Set Admin Key
+		let root = <T as frame_system::Config>::RuntimeOrigin::from(RawOrigin::Root);
+		let _ = MenstrualSubscription::<T>::sudo_update_key(root, AccountKeyType::AdminKey(admin.clone()));
+
+		// Default balance
+		let init_balance = 3_000_000_000_000_000_000u128.saturated_into();
+		let subscription_price = 1_000_000_000_000_000_000u128.saturated_into();
+
+		// Caller init balance
+		let _ = <T as Config>::Currency::deposit_creating(
+			&caller,
+			init_balance
+		);
+
+		// Set price
+		let admin_origin = <T as frame_system::Config>::RuntimeOrigin::from(RawOrigin::Signed(admin));
+		let _ = MenstrualSubscription::<T>::set_menstrual_subscription_price(
+			admin_origin,
+			MenstrualSubscriptionDuration::default(),
+			CurrencyType::default(),
+			subscription_price,
+			None,
+		);
+
+		// Add and pay an auto renewing subscription
+		let caller_origin = T::RuntimeOrigin::from(RawOrigin::Signed(caller.clone()));
+		let _ = MenstrualSubscription::<T>::add_menstrual_subscription(
+			caller_origin.clone(),
+			MenstrualSubscriptionDuration::default(),
+			CurrencyType::DBIO,
+		);
+
+		let ids = MenstrualSubscription::<T>::menstrual_subscription_by_address_id(caller.clone()).unwrap();
+		let _ = MenstrualSubscription::<T>::set_menstrual_subscription_paid(
+			caller_origin.clone(),
+			ids[0],
+		);
+		let _ = MenstrualSubscription::<T>::set_menstrual_subscription_auto_renew(
+			caller_origin.clone(),
+			ids[0],
+			true,
+		);
+		let _ = MenstrualSubscription::<T>::set_renewal_allowance(
+			caller_origin,
+			subscription_price,
+		);
+
+		// Past subscriptions scanned before the expired one when activating the queue
+		let menstrual_subscription = MenstrualSubscription::<T>::menstrual_subscription_by_id(ids[0]).unwrap();
+		let mut owner_ids = Vec::new();
+		for i in 0..n {
+			let id = T::Hashing::hash_of(&(&caller, i));
+			let mut past_subscription = menstrual_subscription.clone();
+			past_subscription.id = id;
+			past_subscription.status = MenstrualSubscriptionStatus::Inactive;
+			MenstrualSubscriptionById::<T>::insert(id, past_subscription);
+			owner_ids.push(id);
+		}
+		owner_ids.push(ids[0]);
+		MenstrualSubscriptionByOwner::<T>::insert(&caller, BoundedVec::truncate_from(owner_ids));
+
+		let expiry = MenstrualSubscription::<T>::subscription_expiry_by_id(ids[0]).unwrap();
+		pallet_timestamp::Pallet::<T>::set_timestamp(expiry);
+	}: {
+		MenstrualSubscription::<T>::expire_menstrual_subscriptions(Weight::from_ref_time(u64::MAX));
+	}
+	verify {
+		let ids = MenstrualSubscription::<T>::menstrual_subscription_by_address_id(caller.clone()).unwrap();
+		assert_eq!(MenstrualSubscription::<T>::active_subscription_by_owner(caller), ids.last().copied());
+	}
 }
 
 impl_benchmark_test_suite! {MenstrualSubscription, crate::mock::ExternalityBuilder::build(), crate::mock::Test}
@@ -6,9 +6,11 @@ use frame_support::{
 		traits::{CheckedSub, Hash},
 		SaturatedConversion,
 	},
-	traits::{fungibles, Currency, ExistenceRequirement, WithdrawReasons},
+	storage::with_storage_layer,
+	traits::{fungibles, Currency, ExistenceRequirement, Get, WithdrawReasons},
+	weights::Weight,
 };
-use primitives_menstrual_status::MenstrualSubscriptionStatus;
+use primitives_menstrual_status::{MenstrualSubscriptionStatus, PaymentStatus};
 use primitives_price_and_currency::CurrencyType;
 use scale_info::prelude::string::String;
 use traits_menstrual_subscription::MenstrualSubscriptionProvider;
@@ -59,12 +61,16 @@ impl<T: Config> Pallet<T> {
 	pub fn add_menstrual_subscription_by_owner(
 		address_id: &T::AccountId,
 		menstrual_subscription_id: &T::Hash,
-	) {
+	) -> Result<(), Error<T>> {
 		let mut menstrual_subscription =
 			MenstrualSubscriptionByOwner::<T>::get(address_id).unwrap_or_default();
 
-		menstrual_subscription.push(*menstrual_subscription_id);
-		MenstrualSubscriptionByOwner::<T>::insert(address_id, &menstrual_subscription)
+		menstrual_subscription
+			.try_push(*menstrual_subscription_id)
+			.map_err(|_| Error::<T>::TooManyMenstrualSubscriptions)?;
+		MenstrualSubscriptionByOwner::<T>::insert(address_id, &menstrual_subscription);
+
+		Ok(())
 	}
 
 	// Subtract menstrual_subscription by owner
@@ -110,6 +116,281 @@ impl<T: Config> Pallet<T> {
 		);
 	}
 
+	pub fn insert_subscription_expiry(menstrual_subscription: &MenstrualSubscriptionOf<T>) {
+		let id = menstrual_subscription.id;
+		let expiry: MomentOf<T> = menstrual_subscription
+			.updated_at
+			.saturated_into::<u64>()
+			.saturating_add(menstrual_subscription.duration.as_millis())
+			.saturated_into();
+
+		Self::remove_subscription_expiry(&id);
+
+		let bucket = Self::expiry_bucket(expiry);
+
+		SubscriptionExpiryById::<T>::insert(id, expiry);
+		SubscriptionExpiries::<T>::insert(bucket, id, expiry);
+
+		if NextExpiryBucket::<T>::get().map_or(true, |cursor| bucket < cursor) {
+			NextExpiryBucket::<T>::put(bucket);
+		}
+	}
+
+	pub fn remove_subscription_expiry(menstrual_subscription_id: &T::Hash) {
+		if let Some(expiry) = SubscriptionExpiryById::<T>::take(menstrual_subscription_id) {
+			SubscriptionExpiries::<T>::remove(
+				Self::expiry_bucket(expiry),
+				menstrual_subscription_id,
+			);
+		}
+	}
+
+	pub fn expiry_bucket(moment: MomentOf<T>) -> u64 {
+		moment.saturated_into::<u64>() / EXPIRY_BUCKET_MILLIS
+	}
+
+	/// Next subscription id queued whose expiry is not after `now`. Buckets are walked from
+	/// `NextExpiryBucket`, which moves past every bucket older than the current one once it
+	/// is drained. Every bucket and entry read is added to `consumed_weight`.
+	pub fn next_expired_subscription_id(
+		now: MomentOf<T>,
+		consumed_weight: &mut Weight,
+		remaining_weight: Weight,
+	) -> Option<T::Hash> {
+		let now_bucket = Self::expiry_bucket(now);
+		let read_weight = T::DbWeight::get().reads(1);
+
+		*consumed_weight = consumed_weight.saturating_add(read_weight);
+		let mut bucket = NextExpiryBucket::<T>::get()?;
+
+		loop {
+			for (menstrual_subscription_id, expiry) in
+				SubscriptionExpiries::<T>::iter_prefix(bucket)
+			{
+				*consumed_weight = consumed_weight.saturating_add(read_weight);
+
+				if expiry <= now {
+					return Some(menstrual_subscription_id)
+				}
+
+				if *consumed_weight > remaining_weight {
+					return None
+				}
+			}
+
+			if bucket >= now_bucket {
+				return None
+			}
+
+			*consumed_weight =
+				consumed_weight.saturating_add(T::DbWeight::get().reads_writes(1, 1));
+
+			if *consumed_weight > remaining_weight {
+				return None
+			}
+
+			bucket += 1;
+			NextExpiryBucket::<T>::put(bucket);
+		}
+	}
+
+	pub fn do_expire_menstrual_subscription(
+		menstrual_subscription_id: &T::Hash,
+	) -> Result<MenstrualSubscriptionOf<T>, Error<T>> {
+		Self::remove_subscription_expiry(menstrual_subscription_id);
+
+		let mut menstrual_subscription =
+			MenstrualSubscriptionById::<T>::get(menstrual_subscription_id)
+				.ok_or(Error::<T>::MenstrualSubscriptionDoesNotExist)?;
+
+		if menstrual_subscription.status != MenstrualSubscriptionStatus::Active {
+			return Err(Error::<T>::MenstrualSubscriptionNotActive)
+		}
+
+		let address_id = &menstrual_subscription.address_id;
+		if ActiveSubscriptionByOwner::<T>::get(address_id) == Some(*menstrual_subscription_id) {
+			ActiveSubscriptionByOwner::<T>::remove(address_id);
+		}
+
+		menstrual_subscription.status = MenstrualSubscriptionStatus::Inactive;
+		menstrual_subscription.updated_at = pallet_timestamp::Pallet::<T>::get();
+
+		MenstrualSubscriptionById::<T>::insert(menstrual_subscription_id, &menstrual_subscription);
+
+		Ok(menstrual_subscription)
+	}
+
+	// Activate the first paid subscription waiting in queue
+	pub fn do_activate_next_menstrual_subscription(
+		address_id: &T::AccountId,
+	) -> Option<MenstrualSubscriptionOf<T>> {
+		if ActiveSubscriptionByOwner::<T>::contains_key(address_id) {
+			return None
+		}
+
+		let subscription_ids =
+			MenstrualSubscriptionByOwner::<T>::get(address_id).unwrap_or_default();
+
+		for subscription_id in subscription_ids {
+			if let Some(mut menstrual_subscription) =
+				MenstrualSubscriptionById::<T>::get(subscription_id)
+			{
+				if menstrual_subscription.status != MenstrualSubscriptionStatus::InQueue ||
+					menstrual_subscription.payment_status != PaymentStatus::Paid
+				{
+					continue
+				}
+
+				menstrual_subscription.status = MenstrualSubscriptionStatus::Active;
+				menstrual_subscription.updated_at = pallet_timestamp::Pallet::<T>::get();
+
+				MenstrualSubscriptionById::<T>::insert(subscription_id, &menstrual_subscription);
+				ActiveSubscriptionByOwner::<T>::insert(address_id, subscription_id);
+				Self::insert_subscription_expiry(&menstrual_subscription);
+
+				return Some(menstrual_subscription)
+			}
+		}
+
+		None
+	}
+
+	// Charge the owner allowance and start a new period for a lapsed subscription
+	pub fn do_renew_menstrual_subscription(
+		expired: &MenstrualSubscriptionOf<T>,
+	) -> Result<MenstrualSubscriptionOf<T>, Error<T>> {
+		let address_id = &expired.address_id;
+		let subscription_price =
+			MenstrualSubscriptionPrices::<T>::get(&expired.duration, &expired.currency)
+				.ok_or(Error::<T>::MenstrualSubscriptionPriceNotExist)?;
+
+		let amount = subscription_price.amount;
+		let allowance = RenewalAllowanceByOwner::<T>::get(address_id)
+			.checked_sub(&amount)
+			.ok_or(Error::<T>::RenewalAllowanceExceeded)?;
+
+		let owner_menstrual_subscription_count =
+			MenstrualSubscriptionCountByOwner::<T>::get(address_id).unwrap_or(0);
+
+		let menstrual_subscription_id = Self::generate_menstrual_subscription_id(
+			address_id,
+			owner_menstrual_subscription_count,
+		);
+
+		// Index the renewal before charging, so a full index never costs the owner
+		Self::add_menstrual_subscription_by_owner(address_id, &menstrual_subscription_id)?;
+
+		if expired.currency == CurrencyType::DBIO {
+			Self::do_burn(address_id, amount)?;
+		} else {
+			Self::do_transfer_to_treasury(
+				&expired.currency,
+				address_id,
+				amount,
+				subscription_price.asset_id,
+			)?;
+		}
+
+		RenewalAllowanceByOwner::<T>::insert(address_id, allowance);
+
+		let now = pallet_timestamp::Pallet::<T>::get();
+
+		let mut menstrual_subscription = MenstrualSubscription::new(
+			menstrual_subscription_id,
+			address_id.clone(),
+			expired.duration.clone(),
+			expired.currency.clone(),
+			now,
+		);
+
+		menstrual_subscription.payment_status = PaymentStatus::Paid;
+		menstrual_subscription.status = MenstrualSubscriptionStatus::Active;
+		menstrual_subscription.auto_renew = true;
+		menstrual_subscription.updated_at = now;
+
+		MenstrualSubscriptionById::<T>::insert(menstrual_subscription_id, &menstrual_subscription);
+		ActiveSubscriptionByOwner::<T>::insert(address_id, menstrual_subscription_id);
+
+		Self::add_menstrual_subscription_count();
+		Self::add_menstrual_subscription_count_by_owner(address_id);
+		Self::insert_subscription_expiry(&menstrual_subscription);
+
+		Ok(menstrual_subscription)
+	}
+
+	pub fn expire_menstrual_subscriptions(remaining_weight: Weight) -> Weight {
+		// Activating the next subscription may read every subscription of the owner
+		let max_expire_weight = T::MenstrualSubscriptionWeightInfo::expire_menstrual_subscription(
+			T::MaxMenstrualSubscriptionsPerOwner::get(),
+		);
+		let max_expiries = T::MaxExpiredSubscriptionsPerBlock::get();
+		let now = pallet_timestamp::Pallet::<T>::get();
+
+		let mut consumed_weight = T::DbWeight::get().reads(1);
+		let mut expired = 0u32;
+
+		while expired < max_expiries &&
+			consumed_weight.saturating_add(max_expire_weight) <= remaining_weight
+		{
+			let menstrual_subscription_id = match Self::next_expired_subscription_id(
+				now,
+				&mut consumed_weight,
+				remaining_weight,
+			) {
+				Some(menstrual_subscription_id) => menstrual_subscription_id,
+				None => break,
+			};
+
+			expired += 1;
+
+			let menstrual_subscription =
+				match Self::do_expire_menstrual_subscription(&menstrual_subscription_id) {
+					Ok(menstrual_subscription) => menstrual_subscription,
+					Err(_) => {
+						consumed_weight = consumed_weight.saturating_add(
+							T::MenstrualSubscriptionWeightInfo::expire_menstrual_subscription(0),
+						);
+						continue
+					},
+				};
+
+			let address_id = &menstrual_subscription.address_id;
+			let owner_subscriptions =
+				MenstrualSubscriptionByOwner::<T>::decode_len(address_id).unwrap_or_default();
+			consumed_weight = consumed_weight.saturating_add(
+				T::MenstrualSubscriptionWeightInfo::expire_menstrual_subscription(
+					owner_subscriptions as u32,
+				),
+			);
+
+			Self::deposit_event(Event::MenstrualSubscriptionExpired(
+				menstrual_subscription.clone(),
+			));
+
+			if let Some(next) = Self::do_activate_next_menstrual_subscription(address_id) {
+				Self::deposit_event(Event::MenstrualSubscriptionActivated(next));
+				continue
+			}
+
+			if !menstrual_subscription.auto_renew {
+				continue
+			}
+
+			// A failed renewal rolls back whatever it already charged or indexed
+			let renewal = with_storage_layer(|| {
+				Self::do_renew_menstrual_subscription(&menstrual_subscription)
+					.map_err(DispatchError::from)
+			});
+
+			match renewal {
+				Ok(renewed) => Self::deposit_event(Event::MenstrualSubscriptionRenewed(renewed)),
+				Err(_) => Self::deposit_event(Event::RenewalFailed(menstrual_subscription)),
+			}
+		}
+
+		consumed_weight
+	}
+
 	pub fn do_validate_asset_id(
 		currency: &CurrencyType,
 		asset_id: Option<AssetId>,
@@ -151,6 +432,30 @@ impl<T: Config> Pallet<T> {
 		Ok(())
 	}
 
+	pub fn do_transfer_to_treasury(
+		currency: &CurrencyType,
+		who: &T::AccountId,
+		amount: BalanceOf<T>,
+		asset_id: Option<AssetId>,
+	) -> Result<(), Error<T>> {
+		if !currency.can_transfer() {
+			return Err(Error::<T>::Token)
+		}
+
+		let asset_id = asset_id.ok_or(Error::<T>::AssetIdNotFound)?;
+		let treasury = TreasuryKey::<T>::get().ok_or(Error::<T>::TreasuryKeyNotFound)?;
+
+		Self::do_transfer(currency, who, &treasury, amount, Some(asset_id))?;
+		Self::deposit_event(Event::MenstrualSubscriptionAssetTransferred(
+			who.clone(),
+			treasury,
+			asset_id,
+			amount,
+		));
+
+		Ok(())
+	}
+
 	pub fn do_transfer(
 		currency: &CurrencyType,
 		sender: &T::AccountId,
@@ -38,10 +38,11 @@ impl<T: Config> MenstrualSubscriptionInterface<T> for Pallet<T> {
 			now,
 		);
 
+		Self::add_menstrual_subscription_by_owner(address_id, &menstrual_subscription_id)?;
+
 		// Store to MenstrualSubscriptionById storage
 		MenstrualSubscriptionById::<T>::insert(menstrual_subscription_id, &menstrual_subscription);
 
-		Self::add_menstrual_subscription_by_owner(address_id, &menstrual_subscription_id);
 		Self::add_menstrual_subscription_count();
 		Self::add_menstrual_subscription_count_by_owner(address_id);
 
@@ -68,6 +69,7 @@ impl<T: Config> MenstrualSubscriptionInterface<T> for Pallet<T> {
 
 		if menstrual_subscription.status == MenstrualSubscriptionStatus::Active {
 			ActiveSubscriptionByOwner::<T>::remove(address);
+			Self::remove_subscription_expiry(menstrual_subscription_id);
 		}
 
 		if status == &MenstrualSubscriptionStatus::Active {
@@ -79,6 +81,10 @@ impl<T: Config> MenstrualSubscriptionInterface<T> for Pallet<T> {
 		menstrual_subscription.status = status.clone();
 		menstrual_subscription.updated_at = now;
 
+		if status == &MenstrualSubscriptionStatus::Active {
+			Self::insert_subscription_expiry(&menstrual_subscription);
+		}
+
 		// Store to MenstrualSubscriptionById storage
 		MenstrualSubscriptionById::<T>::insert(menstrual_subscription_id, &menstrual_subscription);
 
@@ -109,12 +115,17 @@ impl<T: Config> MenstrualSubscriptionInterface<T> for Pallet<T> {
 
 		let amount = subscription_price.amount;
 
-		if currency != &CurrencyType::DBIO {
-			return Err(Error::<T>::Token)
+		if currency == &CurrencyType::DBIO {
+			Self::do_burn(address_id, amount)?;
+		} else {
+			Self::do_transfer_to_treasury(
+				currency,
+				address_id,
+				amount,
+				subscription_price.asset_id,
+			)?;
 		}
 
-		Self::do_burn(address_id, amount)?;
-
 		if Self::active_subscription_by_owner(address_id).is_none() {
 			menstrual_subscription.status = MenstrualSubscriptionStatus::Active;
 			ActiveSubscriptionByOwner::<T>::insert(address_id, menstrual_subscription_id);
@@ -125,6 +136,34 @@ impl<T: Config> MenstrualSubscriptionInterface<T> for Pallet<T> {
 		menstrual_subscription.payment_status = PaymentStatus::Paid;
 		menstrual_subscription.updated_at = now;
 
+		if menstrual_subscription.status == MenstrualSubscriptionStatus::Active {
+			Self::insert_subscription_expiry(&menstrual_subscription);
+		}
+
+		// Store to MenstrualSubscriptionById storage
+		MenstrualSubscriptionById::<T>::insert(menstrual_subscription_id, &menstrual_subscription);
+
+		Ok(menstrual_subscription)
+	}
+
+	fn set_menstrual_subscription_auto_renew(
+		address_id: &T::AccountId,
+		menstrual_subscription_id: &T::Hash,
+		auto_renew: bool,
+	) -> Result<Self::MenstrualSubscription, Self::Error> {
+		let mut menstrual_subscription =
+			MenstrualSubscriptionById::<T>::get(menstrual_subscription_id)
+				.ok_or(Error::<T>::MenstrualSubscriptionDoesNotExist)?;
+
+		if &menstrual_subscription.address_id != address_id {
+			return Err(Error::<T>::NotMenstrualSubscriptionOwner)
+		}
+
+		let now = pallet_timestamp::Pallet::<T>::get();
+
+		menstrual_subscription.auto_renew = auto_renew;
+		menstrual_subscription.updated_at = now;
+
 		// Store to MenstrualSubscriptionById storage
 		MenstrualSubscriptionById::<T>::insert(menstrual_subscription_id, &menstrual_subscription);
 
@@ -24,6 +24,12 @@ pub trait MenstrualSubscriptionInterface<T: frame_system::Config> {
 		menstrual_subscription_id: &T::Hash,
 	) -> Result<Self::MenstrualSubscription, Self::Error>;
 
+	fn set_menstrual_subscription_auto_renew(
+		address_id: &T::AccountId,
+		menstrual_subscription_id: &T::Hash,
+		auto_renew: bool,
+	) -> Result<Self::MenstrualSubscription, Self::Error>;
+
 	fn set_menstrual_subscription_price(
 		duration: &MenstrualSubscriptionDuration,
 		currency: &CurrencyType,
@@ -25,7 +25,7 @@ pub use weights::WeightInfo;
 pub use frame_support::traits::StorageVersion;
 
 /// The current storage version
-const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
+const STORAGE_VERSION: StorageVersion = StorageVersion::new(4);
 
 #[frame_support::pallet]
 pub mod pallet {
@@ -40,7 +40,6 @@ pub mod pallet {
 	use primitives_duration::MenstrualSubscriptionDuration;
 	use primitives_menstrual_status::MenstrualSubscriptionStatus;
 	use primitives_price_and_currency::CurrencyType;
-	use sp_std::vec::Vec;
 
 	#[pallet::config]
 	pub trait Config: frame_system::Config + pallet_timestamp::Config {
@@ -52,6 +51,12 @@ pub mod pallet {
 				Balance = AssetBalance,
 			> + fungibles::InspectMetadata<<Self as frame_system::Config>::AccountId>;
 		type MenstrualSubscriptionWeightInfo: WeightInfo;
+		/// Maximum number of lapsed subscriptions expired in a single block.
+		#[pallet::constant]
+		type MaxExpiredSubscriptionsPerBlock: Get<u32>;
+		/// Maximum number of menstrual subscriptions indexed per owner.
+		#[pallet::constant]
+		type MaxMenstrualSubscriptionsPerOwner: Get<u32>;
 	}
 
 	#[pallet::genesis_config]
@@ -83,7 +88,6 @@ pub mod pallet {
 	#[pallet::pallet]
 	#[pallet::storage_version(STORAGE_VERSION)]
 	#[pallet::generate_store(pub(super) trait Store)]
-	#[pallet::without_storage_info]
 	pub struct Pallet<T>(_);
 
 	#[pallet::hooks]
@@ -91,6 +95,15 @@ pub mod pallet {
 		fn on_runtime_upgrade() -> Weight {
 			migrations::migrate::<T>()
 		}
+
+		fn on_idle(_n: BlockNumberFor<T>, remaining_weight: Weight) -> Weight {
+			Self::expire_menstrual_subscriptions(remaining_weight)
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn pre_upgrade() -> Result<sp_std::vec::Vec<u8>, &'static str> {
+			migrations::pre_upgrade::<T>()
+		}
 	}
 	// --------------------------------------------------------
 
@@ -102,6 +115,8 @@ pub mod pallet {
 		MenstrualSubscription<AccountIdOf<T>, HashOf<T>, MomentOf<T>>;
 	pub type MenstrualSubscriptionPriceOf<T> = MenstrualSubscriptionPrice<BalanceOf<T>>;
 	pub type MenstrualSubscriptionIdOf<T> = HashOf<T>;
+	pub type MenstrualSubscriptionIdsOf<T> =
+		BoundedVec<MenstrualSubscriptionIdOf<T>, <T as Config>::MaxMenstrualSubscriptionsPerOwner>;
 	pub type CurrencyOf<T> = <T as self::Config>::Currency;
 	pub type BalanceOf<T> = <CurrencyOf<T> as Currency<AccountIdOf<T>>>::Balance;
 	pub type AccountKeyTypeOf<T> = AccountKeyType<AccountIdOf<T>>;
@@ -118,13 +133,39 @@ pub mod pallet {
 	#[pallet::storage]
 	#[pallet::getter(fn menstrual_subscription_by_address_id)]
 	pub type MenstrualSubscriptionByOwner<T> =
-		StorageMap<_, Blake2_128Concat, AccountIdOf<T>, Vec<MenstrualSubscriptionIdOf<T>>>;
+		StorageMap<_, Blake2_128Concat, AccountIdOf<T>, MenstrualSubscriptionIdsOf<T>>;
 
 	#[pallet::storage]
 	#[pallet::getter(fn active_subscription_by_owner)]
 	pub type ActiveSubscriptionByOwner<T> =
 		StorageMap<_, Blake2_128Concat, AccountIdOf<T>, HashOf<T>>;
 
+	#[pallet::storage]
+	#[pallet::getter(fn subscription_expiry_by_id)]
+	pub type SubscriptionExpiryById<T> =
+		StorageMap<_, Blake2_128Concat, MenstrualSubscriptionIdOf<T>, MomentOf<T>>;
+
+	/// Active subscriptions expired once their period ends
+	/// (Bucket, MenstrualSubscriptionId) => Expiry
+	#[pallet::storage]
+	pub type SubscriptionExpiries<T> = StorageDoubleMap<
+		_,
+		Twox64Concat,
+		u64,
+		Blake2_128Concat,
+		MenstrualSubscriptionIdOf<T>,
+		MomentOf<T>,
+	>;
+
+	/// Oldest bucket of `SubscriptionExpiries` that may still hold subscriptions
+	#[pallet::storage]
+	pub type NextExpiryBucket<T> = StorageValue<_, u64, OptionQuery>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn renewal_allowance_by_owner)]
+	pub type RenewalAllowanceByOwner<T> =
+		StorageMap<_, Blake2_128Concat, AccountIdOf<T>, BalanceOf<T>, ValueQuery>;
+
 	#[pallet::storage]
 	#[pallet::getter(fn menstrual_subscription_by_id)]
 	pub type MenstrualSubscriptionById<T> =
@@ -164,10 +205,33 @@ pub mod pallet {
 		//// MenstrualSubscription paid
 		/// parameters, [MenstrualSubscription, who]
 		MenstrualSubscriptionPaid(MenstrualSubscriptionOf<T>, AccountIdOf<T>),
+		//// MenstrualSubscription period lapsed
+		/// parameters, [MenstrualSubscription]
+		MenstrualSubscriptionExpired(MenstrualSubscriptionOf<T>),
+		//// MenstrualSubscription promoted from the queue
+		/// parameters, [MenstrualSubscription]
+		MenstrualSubscriptionActivated(MenstrualSubscriptionOf<T>),
+		//// MenstrualSubscription renewed from the owner allowance
+		/// parameters, [MenstrualSubscription]
+		MenstrualSubscriptionRenewed(MenstrualSubscriptionOf<T>),
+		//// Lapsed MenstrualSubscription could not be renewed
+		/// parameters, [MenstrualSubscription]
+		RenewalFailed(MenstrualSubscriptionOf<T>),
+		/// Renewal allowance set
+		/// parameters, [who, allowance]
+		RenewalAllowanceSet(AccountIdOf<T>, BalanceOf<T>),
 		/// Update menstrual subscription admin key successful
 		/// parameters. [who]
 		UpdateMenstrualSubscriptionKeySuccessful(AccountKeyTypeOf<T>),
 		TotalSupplyDecreased(BalanceOf<T>),
+		/// Menstrual subscription paid with an asset transferred to the treasury
+		/// parameters, [who, treasury, asset_id, amount]
+		MenstrualSubscriptionAssetTransferred(
+			AccountIdOf<T>,
+			AccountIdOf<T>,
+			AssetId,
+			BalanceOf<T>,
+		),
 		MenstrualSubscriptionPriceAdded(MenstrualSubscriptionPriceOf<T>),
 	}
 
@@ -186,7 +250,12 @@ pub mod pallet {
 		MenstrualSubscriptionAlreadyPaid,
 		MenstrualSubscriptionNotPaid,
 		MenstrualSubscriptionAlreadyInQueue,
+		MenstrualSubscriptionNotActive,
 		AssetIdNotFound,
+		TreasuryKeyNotFound,
+		RenewalAllowanceExceeded,
+		/// Owner already has `MaxMenstrualSubscriptionsPerOwner` menstrual subscriptions
+		TooManyMenstrualSubscriptions,
 		Module,
 		Other,
 		BadOrigin,
@@ -296,6 +365,45 @@ pub mod pallet {
 			}
 		}
 
+		#[pallet::weight(
+			T::MenstrualSubscriptionWeightInfo::set_menstrual_subscription_auto_renew()
+		)]
+		pub fn set_menstrual_subscription_auto_renew(
+			origin: OriginFor<T>,
+			menstrual_subscription_id: HashOf<T>,
+			auto_renew: bool,
+		) -> DispatchResultWithPostInfo {
+			let account_id = ensure_signed(origin)?;
+
+			match <Self as MenstrualSubscriptionInterface<T>>::set_menstrual_subscription_auto_renew(
+				&account_id,
+				&menstrual_subscription_id,
+				auto_renew,
+			) {
+				Ok(menstrual_subscription) => {
+					Self::deposit_event(Event::MenstrualSubscriptionUpdated(
+						menstrual_subscription,
+					));
+					Ok(().into())
+				},
+				Err(error) => Err(error.into()),
+			}
+		}
+
+		#[pallet::weight(T::MenstrualSubscriptionWeightInfo::set_renewal_allowance())]
+		pub fn set_renewal_allowance(
+			origin: OriginFor<T>,
+			allowance: BalanceOf<T>,
+		) -> DispatchResultWithPostInfo {
+			let account_id = ensure_signed(origin)?;
+
+			RenewalAllowanceByOwner::<T>::insert(&account_id, allowance);
+
+			Self::deposit_event(Event::RenewalAllowanceSet(account_id, allowance));
+
+			Ok(().into())
+		}
+
 		#[pallet::weight(0)]
 		pub fn update_key(
 			origin: OriginFor<T>,
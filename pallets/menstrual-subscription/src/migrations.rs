@@ -1,14 +1,39 @@
 use crate::{
-	AccountIdOf, Config, HashOf, MenstrualSubscription, MenstrualSubscriptionById, MomentOf, Pallet,
+	AccountIdOf, ActiveSubscriptionByOwner, Config, HashOf, MenstrualSubscription,
+	MenstrualSubscriptionById, MenstrualSubscriptionIdOf, MomentOf, Pallet,
 };
 use frame_support::{
+	log,
 	pallet_prelude::{Decode, Encode},
-	traits::Get,
+	storage::migration::storage_key_iter,
+	traits::{Get, PalletInfoAccess},
 	weights::Weight,
+	Blake2_128Concat,
 };
 use primitives_duration::MenstrualSubscriptionDuration;
 use primitives_menstrual_status::{MenstrualSubscriptionStatus, PaymentStatus};
 use primitives_price_and_currency::CurrencyType;
+use sp_std::vec::Vec;
+
+/// Fails when a legacy menstrual subscription index does not fit in
+/// `MaxMenstrualSubscriptionsPerOwner`
+#[cfg(feature = "try-runtime")]
+pub fn pre_upgrade<T: Config>() -> Result<Vec<u8>, &'static str> {
+	use frame_support::traits::StorageVersion;
+
+	let max_menstrual_subscriptions = T::MaxMenstrualSubscriptionsPerOwner::get() as usize;
+
+	if StorageVersion::get::<Pallet<T>>() < 4 &&
+		version::v4::menstrual_subscription_indexes::<T>()
+			.any(|(_, ids)| ids.len() > max_menstrual_subscriptions)
+	{
+		return Err(
+			"Menstrual subscription index holds more ids than MaxMenstrualSubscriptionsPerOwner",
+		)
+	}
+
+	Ok(Vec::new())
+}
 
 pub fn migrate<T: Config>() -> Weight {
 	use frame_support::traits::StorageVersion;
@@ -17,8 +42,27 @@ pub fn migrate<T: Config>() -> Weight {
 	let mut version = StorageVersion::get::<Pallet<T>>();
 
 	if version < 1 {
+		// v1 already translates into the latest layout, so v3 is skipped
 		weight = weight.saturating_add(version::v1::migrate::<T>());
-		version = StorageVersion::new(1);
+		weight = weight.saturating_add(version::v2::migrate::<T>());
+		version = StorageVersion::new(3);
+	}
+
+	if version == 1 {
+		// v2 reads subscriptions with the latest layout, so v3 translates them first
+		weight = weight.saturating_add(version::v3::migrate::<T>());
+		weight = weight.saturating_add(version::v2::migrate::<T>());
+		version = StorageVersion::new(3);
+	}
+
+	if version == 2 {
+		weight = weight.saturating_add(version::v3::migrate::<T>());
+		version = StorageVersion::new(3);
+	}
+
+	if version == 3 {
+		weight = weight.saturating_add(version::v4::migrate::<T>());
+		version = StorageVersion::new(4);
 	}
 
 	version.put::<Pallet<T>>();
@@ -60,6 +104,7 @@ mod version {
 						currency: CurrencyType::DBIO,
 						payment_status: old.payment_status,
 						status: old.status,
+						auto_renew: false,
 						created_at: old.created_at,
 						updated_at: old.updated_at,
 					})
@@ -69,4 +114,105 @@ mod version {
 			weight
 		}
 	}
+
+	pub mod v2 {
+		use super::*;
+
+		pub fn migrate<T: Config>() -> Weight {
+			let mut weight = T::DbWeight::get().writes(1);
+
+			for (_, menstrual_subscription_id) in ActiveSubscriptionByOwner::<T>::iter() {
+				weight = weight.saturating_add(T::DbWeight::get().reads_writes(3, 2));
+
+				if let Some(menstrual_subscription) =
+					MenstrualSubscriptionById::<T>::get(menstrual_subscription_id)
+				{
+					Pallet::<T>::insert_subscription_expiry(&menstrual_subscription);
+				}
+			}
+
+			weight
+		}
+	}
+
+	pub mod v3 {
+		use super::*;
+
+		pub fn migrate<T: Config>() -> Weight {
+			let mut weight = T::DbWeight::get().writes(1);
+
+			#[derive(Encode, Decode)]
+			pub struct OldMenstrualSubscription<AccountId, Hash, Moment> {
+				pub id: Hash,
+				pub address_id: AccountId,
+				pub duration: MenstrualSubscriptionDuration,
+				pub currency: CurrencyType,
+				pub payment_status: PaymentStatus,
+				pub status: MenstrualSubscriptionStatus,
+				pub created_at: Moment,
+				pub updated_at: Moment,
+			}
+
+			pub type OldMenstrualSubscriptionOf<T> =
+				OldMenstrualSubscription<AccountIdOf<T>, HashOf<T>, MomentOf<T>>;
+
+			MenstrualSubscriptionById::<T>::translate(
+				|_key, old: OldMenstrualSubscriptionOf<T>| {
+					weight = weight.saturating_add(T::DbWeight::get().reads_writes(1, 1));
+
+					Some(MenstrualSubscription {
+						id: old.id,
+						address_id: old.address_id,
+						duration: old.duration,
+						currency: old.currency,
+						payment_status: old.payment_status,
+						status: old.status,
+						auto_renew: false,
+						created_at: old.created_at,
+						updated_at: old.updated_at,
+					})
+				},
+			);
+
+			weight
+		}
+	}
+	pub mod v4 {
+		use super::*;
+
+		/// Menstrual subscription id indexes are bounded by `MaxMenstrualSubscriptionsPerOwner`
+		/// and encode like the lists they replace, so they are only checked. An index holding
+		/// more than `MaxMenstrualSubscriptionsPerOwner` ids is left as it is and reported
+		/// instead of dropping ids, raise `MaxMenstrualSubscriptionsPerOwner` above it before
+		/// upgrading.
+		pub fn migrate<T: Config>() -> Weight {
+			let mut weight = T::DbWeight::get().writes(1);
+			let max_menstrual_subscriptions = T::MaxMenstrualSubscriptionsPerOwner::get() as usize;
+
+			for (address_id, ids) in menstrual_subscription_indexes::<T>() {
+				weight = weight.saturating_add(T::DbWeight::get().reads(1));
+
+				if ids.len() > max_menstrual_subscriptions {
+					log::error!(
+						target: "runtime::menstrual-subscription",
+						"Menstrual subscription index of {:?} holds {} ids, more than MaxMenstrualSubscriptionsPerOwner ({})",
+						address_id,
+						ids.len(),
+						max_menstrual_subscriptions,
+					);
+				}
+			}
+
+			weight
+		}
+
+		/// Menstrual subscription id indexes as stored before v4
+		pub fn menstrual_subscription_indexes<T: Config>(
+		) -> impl Iterator<Item = (AccountIdOf<T>, Vec<MenstrualSubscriptionIdOf<T>>)> {
+			storage_key_iter::<AccountIdOf<T>, Vec<MenstrualSubscriptionIdOf<T>>, Blake2_128Concat>(
+				Pallet::<T>::name().as_bytes(),
+				b"MenstrualSubscriptionByOwner",
+			)
+		}
+	}
 }
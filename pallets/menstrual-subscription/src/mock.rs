@@ -63,6 +63,8 @@ type Balance = u64;
 
 parameter_types! {
 	pub static ExistentialDeposit: Balance = 0;
+	pub const MaxExpiredSubscriptionsPerBlock: u32 = 10;
+	pub const MaxMenstrualSubscriptionsPerOwner: u32 = 3;
 }
 
 impl pallet_balances::Config for Test {
@@ -113,6 +115,8 @@ impl menstrual_subscription::Config for Test {
 	type Currency = Balances;
 	type Assets = Assets;
 	type MenstrualSubscriptionWeightInfo = ();
+	type MaxExpiredSubscriptionsPerBlock = MaxExpiredSubscriptionsPerBlock;
+	type MaxMenstrualSubscriptionsPerOwner = MaxMenstrualSubscriptionsPerOwner;
 }
 
 pub type Moment = u64;
@@ -167,14 +171,22 @@ impl ExternalityBuilder {
 		.unwrap();
 
 		pallet_assets::GenesisConfig::<Test> {
-			assets: vec![(1, owner, true, 1)],
-			metadata: vec![(1, b"USDT".to_vec(), b"USDT".to_vec(), 6)],
+			assets: vec![(1, owner, true, 1), (2, owner, true, 1), (3, owner, true, 1)],
+			metadata: vec![
+				(1, b"USDT".to_vec(), b"USDT".to_vec(), 6),
+				(2, b"USN".to_vec(), b"USN".to_vec(), 18),
+				(3, b"USDT.e".to_vec(), b"USDT.e".to_vec(), 6),
+			],
 			accounts: vec![
 				(1, admin, 100),
 				(1, customer, 200),
 				(1, lab, 300),
 				(1, other, 400),
 				(1, treasure, 500),
+				(2, customer, 200),
+				(2, treasure, 500),
+				(3, customer, 200),
+				(3, treasure, 500),
 			],
 		}
 		.assimilate_storage(&mut storage)
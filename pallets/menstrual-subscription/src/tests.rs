@@ -1,10 +1,17 @@
 use crate::{
-	mock::*, AccountKeyType, AdminKey, Error, MenstrualSubscription as MenstrualSubscriptionS,
-	MenstrualSubscriptionPrice, TreasuryKey,
+	migrations, mock::*, AccountKeyType, ActiveSubscriptionByOwner, AdminKey, Error,
+	MenstrualSubscription as MenstrualSubscriptionS, MenstrualSubscriptionById,
+	MenstrualSubscriptionByOwner, MenstrualSubscriptionPrice, MenstrualSubscriptionPrices,
+	RenewalAllowanceByOwner, SubscriptionExpiries, TreasuryKey, WeightInfo,
 };
 use frame_support::{
 	assert_noop, assert_ok,
+	codec::Encode,
 	sp_runtime::traits::{Hash, Keccak256},
+	storage::migration::put_storage_value,
+	traits::{Get, Hooks, StorageVersion},
+	weights::Weight,
+	Blake2_128Concat, StorageHasher,
 };
 use primitives_duration::MenstrualSubscriptionDuration;
 use primitives_menstrual_status::{MenstrualSubscriptionStatus, PaymentStatus};
@@ -42,6 +49,7 @@ fn add_menstrual_subscription_works() {
 			duration: MenstrualSubscriptionDuration::default(),
 			payment_status: PaymentStatus::default(),
 			status: MenstrualSubscriptionStatus::InQueue,
+			auto_renew: false,
 			created_at: 0,
 			updated_at: 0,
 		};
@@ -97,6 +105,7 @@ fn set_menstrual_subscription_paid_works() {
 			duration: MenstrualSubscriptionDuration::default(),
 			payment_status: PaymentStatus::Paid,
 			status: MenstrualSubscriptionStatus::Active,
+			auto_renew: false,
 			created_at: 0,
 			updated_at: 0,
 		};
@@ -188,6 +197,7 @@ fn change_menstrual_subscription_status_works() {
 			duration: MenstrualSubscriptionDuration::default(),
 			payment_status: PaymentStatus::Paid,
 			status: MenstrualSubscriptionStatus::default(),
+			auto_renew: false,
 			created_at: 0,
 			updated_at: 0,
 		};
@@ -298,6 +308,50 @@ fn cant_add_menstrual_subscription_when_already_inqueue() {
 	})
 }
 
+#[test]
+fn cant_add_more_than_max_menstrual_subscriptions_per_owner() {
+	ExternalityBuilder::build().execute_with(|| {
+		let customer = account_key("customer");
+		let admin = account_key("admin");
+
+		AdminKey::<Test>::put(admin);
+
+		assert_ok!(MenstrualSubscription::set_menstrual_subscription_price(
+			RuntimeOrigin::signed(admin),
+			MenstrualSubscriptionDuration::default(),
+			CurrencyType::DBIO,
+			10,
+			None,
+		));
+
+		for _ in 0..MaxMenstrualSubscriptionsPerOwner::get() {
+			assert_ok!(MenstrualSubscription::add_menstrual_subscription(
+				RuntimeOrigin::signed(customer),
+				MenstrualSubscriptionDuration::default(),
+				CurrencyType::DBIO,
+			));
+
+			// Leave the queue so the next subscription can be added
+			let ids =
+				MenstrualSubscription::menstrual_subscription_by_address_id(customer).unwrap();
+			MenstrualSubscriptionById::<Test>::mutate(ids[ids.len() - 1], |subscription| {
+				if let Some(subscription) = subscription {
+					subscription.status = MenstrualSubscriptionStatus::Inactive;
+				}
+			});
+		}
+
+		assert_noop!(
+			MenstrualSubscription::add_menstrual_subscription(
+				RuntimeOrigin::signed(customer),
+				MenstrualSubscriptionDuration::default(),
+				CurrencyType::DBIO,
+			),
+			Error::<Test>::TooManyMenstrualSubscriptions,
+		);
+	})
+}
+
 #[test]
 fn cant_add_menstrual_subcription_when_price_not_exist() {
 	ExternalityBuilder::build().execute_with(|| {
@@ -648,6 +702,7 @@ fn call_event_should_work() {
 			duration: MenstrualSubscriptionDuration::default(),
 			payment_status: PaymentStatus::default(),
 			status: MenstrualSubscriptionStatus::InQueue,
+			auto_renew: false,
 			created_at: 0,
 			updated_at: 0,
 		};
@@ -708,3 +763,774 @@ fn call_event_should_work() {
 		));
 	})
 }
+
+#[test]
+fn expire_menstrual_subscription_promotes_queue_on_idle_works() {
+	ExternalityBuilder::build().execute_with(|| {
+		let customer = account_key("customer");
+		let admin = account_key("admin");
+
+		AdminKey::<Test>::put(admin);
+
+		assert_ok!(MenstrualSubscription::set_menstrual_subscription_price(
+			RuntimeOrigin::signed(admin),
+			MenstrualSubscriptionDuration::default(),
+			CurrencyType::DBIO,
+			10,
+			None,
+		));
+
+		assert_ok!(MenstrualSubscription::add_menstrual_subscription(
+			RuntimeOrigin::signed(customer),
+			MenstrualSubscriptionDuration::default(),
+			CurrencyType::DBIO,
+		));
+
+		let menstrual_subscription_ids =
+			MenstrualSubscription::menstrual_subscription_by_address_id(customer).unwrap();
+
+		assert_ok!(MenstrualSubscription::set_menstrual_subscription_paid(
+			RuntimeOrigin::signed(customer),
+			menstrual_subscription_ids[0]
+		));
+
+		assert_ok!(MenstrualSubscription::add_menstrual_subscription(
+			RuntimeOrigin::signed(customer),
+			MenstrualSubscriptionDuration::default(),
+			CurrencyType::DBIO,
+		));
+
+		let menstrual_subscription_ids =
+			MenstrualSubscription::menstrual_subscription_by_address_id(customer).unwrap();
+
+		assert_ok!(MenstrualSubscription::set_menstrual_subscription_paid(
+			RuntimeOrigin::signed(customer),
+			menstrual_subscription_ids[1]
+		));
+
+		let expiry = MenstrualSubscriptionDuration::default().as_millis();
+
+		assert_eq!(
+			MenstrualSubscription::subscription_expiry_by_id(menstrual_subscription_ids[0]),
+			Some(expiry)
+		);
+		assert_eq!(
+			MenstrualSubscription::subscription_expiry_by_id(menstrual_subscription_ids[1]),
+			None
+		);
+
+		Timestamp::set_timestamp(expiry - 1);
+		MenstrualSubscription::on_idle(1, Weight::from_ref_time(u64::MAX));
+
+		assert_eq!(
+			MenstrualSubscription::active_subscription_by_owner(customer),
+			Some(menstrual_subscription_ids[0]),
+		);
+
+		Timestamp::set_timestamp(expiry);
+		MenstrualSubscription::on_idle(1, Weight::from_ref_time(u64::MAX));
+
+		let expired_subscription =
+			MenstrualSubscription::menstrual_subscription_by_id(menstrual_subscription_ids[0])
+				.unwrap();
+		let activated_subscription =
+			MenstrualSubscription::menstrual_subscription_by_id(menstrual_subscription_ids[1])
+				.unwrap();
+
+		assert_eq!(expired_subscription.status, MenstrualSubscriptionStatus::Inactive);
+		assert_eq!(activated_subscription.status, MenstrualSubscriptionStatus::Active);
+		assert_eq!(activated_subscription.updated_at, expiry);
+
+		assert_eq!(
+			MenstrualSubscription::active_subscription_by_owner(customer),
+			Some(menstrual_subscription_ids[1]),
+		);
+		assert_eq!(
+			MenstrualSubscription::subscription_expiry_by_id(menstrual_subscription_ids[0]),
+			None
+		);
+		assert_eq!(
+			MenstrualSubscription::subscription_expiry_by_id(menstrual_subscription_ids[1]),
+			Some(expiry * 2)
+		);
+		assert_eq!(
+			SubscriptionExpiries::<Test>::iter().collect::<Vec<_>>(),
+			vec![(
+				MenstrualSubscription::expiry_bucket(expiry * 2),
+				menstrual_subscription_ids[1],
+				expiry * 2
+			)]
+		);
+
+		System::assert_has_event(RuntimeEvent::MenstrualSubscription(
+			crate::Event::MenstrualSubscriptionExpired(expired_subscription),
+		));
+		System::assert_last_event(RuntimeEvent::MenstrualSubscription(
+			crate::Event::MenstrualSubscriptionActivated(activated_subscription),
+		));
+	})
+}
+
+#[test]
+fn expire_menstrual_subscription_charges_weight_per_owner_subscription() {
+	ExternalityBuilder::build().execute_with(|| {
+		let customer = account_key("customer");
+		let admin = account_key("admin");
+
+		AdminKey::<Test>::put(admin);
+
+		assert_ok!(MenstrualSubscription::set_menstrual_subscription_price(
+			RuntimeOrigin::signed(admin),
+			MenstrualSubscriptionDuration::default(),
+			CurrencyType::DBIO,
+			10,
+			None,
+		));
+
+		assert_ok!(MenstrualSubscription::add_menstrual_subscription(
+			RuntimeOrigin::signed(customer),
+			MenstrualSubscriptionDuration::default(),
+			CurrencyType::DBIO,
+		));
+
+		let menstrual_subscription_ids =
+			MenstrualSubscription::menstrual_subscription_by_address_id(customer).unwrap();
+
+		assert_ok!(MenstrualSubscription::set_menstrual_subscription_paid(
+			RuntimeOrigin::signed(customer),
+			menstrual_subscription_ids[0]
+		));
+
+		let expiry = MenstrualSubscriptionDuration::default().as_millis();
+		Timestamp::set_timestamp(expiry);
+
+		// Room is kept for scanning every subscription an owner may have
+		let max_expire_weight = <() as WeightInfo>::expire_menstrual_subscription(
+			MaxMenstrualSubscriptionsPerOwner::get(),
+		);
+		let read_weight = <Test as frame_system::Config>::DbWeight::get().reads(1);
+
+		MenstrualSubscription::on_idle(
+			1,
+			read_weight
+				.saturating_add(max_expire_weight)
+				.saturating_sub(Weight::from_ref_time(1)),
+		);
+
+		assert_eq!(
+			MenstrualSubscription::active_subscription_by_owner(customer),
+			Some(menstrual_subscription_ids[0]),
+		);
+
+		// Only the subscriptions the owner actually has are charged
+		let consumed_weight = MenstrualSubscription::on_idle(1, Weight::from_ref_time(u64::MAX));
+
+		assert_eq!(MenstrualSubscription::active_subscription_by_owner(customer), None);
+		assert!(consumed_weight >= <() as WeightInfo>::expire_menstrual_subscription(1));
+		assert!(consumed_weight < max_expire_weight);
+	})
+}
+
+#[test]
+fn change_menstrual_subscription_status_removes_expiry_works() {
+	ExternalityBuilder::build().execute_with(|| {
+		let customer = account_key("customer");
+		let admin = account_key("admin");
+
+		AdminKey::<Test>::put(admin);
+
+		assert_ok!(MenstrualSubscription::set_menstrual_subscription_price(
+			RuntimeOrigin::signed(admin),
+			MenstrualSubscriptionDuration::default(),
+			CurrencyType::DBIO,
+			10,
+			None,
+		));
+
+		assert_ok!(MenstrualSubscription::add_menstrual_subscription(
+			RuntimeOrigin::signed(customer),
+			MenstrualSubscriptionDuration::default(),
+			CurrencyType::DBIO,
+		));
+
+		let menstrual_subscription_ids =
+			MenstrualSubscription::menstrual_subscription_by_address_id(customer).unwrap();
+
+		assert_ok!(MenstrualSubscription::set_menstrual_subscription_paid(
+			RuntimeOrigin::signed(customer),
+			menstrual_subscription_ids[0]
+		));
+
+		assert_ok!(MenstrualSubscription::change_menstrual_subscription_status(
+			RuntimeOrigin::signed(admin),
+			menstrual_subscription_ids[0],
+			MenstrualSubscriptionStatus::Inactive,
+		));
+
+		assert_eq!(
+			MenstrualSubscription::subscription_expiry_by_id(menstrual_subscription_ids[0]),
+			None
+		);
+		assert_eq!(SubscriptionExpiries::<Test>::iter().count(), 0);
+
+		Timestamp::set_timestamp(1000);
+
+		assert_ok!(MenstrualSubscription::change_menstrual_subscription_status(
+			RuntimeOrigin::signed(admin),
+			menstrual_subscription_ids[0],
+			MenstrualSubscriptionStatus::Active,
+		));
+
+		assert_eq!(
+			MenstrualSubscription::subscription_expiry_by_id(menstrual_subscription_ids[0]),
+			Some(1000 + MenstrualSubscriptionDuration::default().as_millis())
+		);
+	})
+}
+
+#[test]
+fn set_menstrual_subscription_paid_with_asset_works() {
+	for (currency, asset_id) in
+		[(CurrencyType::USDT, 1), (CurrencyType::USN, 2), (CurrencyType::USDTE, 3)]
+	{
+		ExternalityBuilder::build().execute_with(|| {
+			let customer = account_key("customer");
+			let treasure = account_key("treasure");
+			let admin = account_key("admin");
+
+			AdminKey::<Test>::put(admin);
+			TreasuryKey::<Test>::put(treasure);
+
+			let total_issuance = Balances::total_issuance();
+
+			assert_ok!(MenstrualSubscription::set_menstrual_subscription_price(
+				RuntimeOrigin::signed(admin),
+				MenstrualSubscriptionDuration::default(),
+				currency.clone(),
+				10,
+				Some(asset_id),
+			));
+
+			assert_ok!(MenstrualSubscription::add_menstrual_subscription(
+				RuntimeOrigin::signed(customer),
+				MenstrualSubscriptionDuration::default(),
+				currency.clone(),
+			));
+
+			let menstrual_subscription_ids =
+				MenstrualSubscription::menstrual_subscription_by_address_id(customer).unwrap();
+
+			assert_ok!(MenstrualSubscription::set_menstrual_subscription_paid(
+				RuntimeOrigin::signed(customer),
+				menstrual_subscription_ids[0]
+			));
+
+			let menstrual_subscription = MenstrualSubscriptionS {
+				id: menstrual_subscription_ids[0],
+				address_id: customer,
+				currency: currency.clone(),
+				duration: MenstrualSubscriptionDuration::default(),
+				payment_status: PaymentStatus::Paid,
+				status: MenstrualSubscriptionStatus::Active,
+				auto_renew: false,
+				created_at: 0,
+				updated_at: 0,
+			};
+
+			assert_eq!(
+				MenstrualSubscription::menstrual_subscription_by_id(menstrual_subscription_ids[0]),
+				Some(menstrual_subscription.clone())
+			);
+
+			assert_eq!(Assets::balance(asset_id, customer), 190);
+			assert_eq!(Assets::balance(asset_id, treasure), 510);
+			assert_eq!(Balances::total_issuance(), total_issuance);
+
+			System::assert_has_event(RuntimeEvent::MenstrualSubscription(
+				crate::Event::MenstrualSubscriptionAssetTransferred(
+					customer, treasure, asset_id, 10,
+				),
+			));
+			System::assert_last_event(RuntimeEvent::MenstrualSubscription(
+				crate::Event::MenstrualSubscriptionPaid(menstrual_subscription, customer),
+			));
+		})
+	}
+}
+
+#[test]
+fn cant_set_menstrual_subscription_paid_with_asset_when_treasury_key_not_found() {
+	ExternalityBuilder::build().execute_with(|| {
+		let customer = account_key("customer");
+		let admin = account_key("admin");
+
+		AdminKey::<Test>::put(admin);
+
+		assert_ok!(MenstrualSubscription::set_menstrual_subscription_price(
+			RuntimeOrigin::signed(admin),
+			MenstrualSubscriptionDuration::default(),
+			CurrencyType::USDT,
+			10,
+			Some(1),
+		));
+
+		assert_ok!(MenstrualSubscription::add_menstrual_subscription(
+			RuntimeOrigin::signed(customer),
+			MenstrualSubscriptionDuration::default(),
+			CurrencyType::USDT,
+		));
+
+		let menstrual_subscription_ids =
+			MenstrualSubscription::menstrual_subscription_by_address_id(customer).unwrap();
+
+		assert_noop!(
+			MenstrualSubscription::set_menstrual_subscription_paid(
+				RuntimeOrigin::signed(customer),
+				menstrual_subscription_ids[0]
+			),
+			Error::<Test>::TreasuryKeyNotFound,
+		);
+	})
+}
+
+#[test]
+fn cant_set_menstrual_subscription_paid_with_asset_when_balance_not_enough() {
+	ExternalityBuilder::build().execute_with(|| {
+		let customer = account_key("customer");
+		let treasure = account_key("treasure");
+		let admin = account_key("admin");
+
+		AdminKey::<Test>::put(admin);
+		TreasuryKey::<Test>::put(treasure);
+
+		assert_ok!(MenstrualSubscription::set_menstrual_subscription_price(
+			RuntimeOrigin::signed(admin),
+			MenstrualSubscriptionDuration::default(),
+			CurrencyType::USDT,
+			1000,
+			Some(1),
+		));
+
+		assert_ok!(MenstrualSubscription::add_menstrual_subscription(
+			RuntimeOrigin::signed(customer),
+			MenstrualSubscriptionDuration::default(),
+			CurrencyType::USDT,
+		));
+
+		let menstrual_subscription_ids =
+			MenstrualSubscription::menstrual_subscription_by_address_id(customer).unwrap();
+
+		assert_noop!(
+			MenstrualSubscription::set_menstrual_subscription_paid(
+				RuntimeOrigin::signed(customer),
+				menstrual_subscription_ids[0]
+			),
+			Error::<Test>::Module,
+		);
+	})
+}
+
+#[test]
+fn cant_set_menstrual_subscription_paid_with_unsupported_currency() {
+	ExternalityBuilder::build().execute_with(|| {
+		let customer = account_key("customer");
+		let treasure = account_key("treasure");
+
+		TreasuryKey::<Test>::put(treasure);
+
+		MenstrualSubscriptionPrices::<Test>::insert(
+			MenstrualSubscriptionDuration::default(),
+			CurrencyType::DAI,
+			MenstrualSubscriptionPrice {
+				duration: MenstrualSubscriptionDuration::default(),
+				currency: CurrencyType::DAI,
+				amount: 10,
+				asset_id: Some(1),
+			},
+		);
+
+		assert_ok!(MenstrualSubscription::add_menstrual_subscription(
+			RuntimeOrigin::signed(customer),
+			MenstrualSubscriptionDuration::default(),
+			CurrencyType::DAI,
+		));
+
+		let menstrual_subscription_ids =
+			MenstrualSubscription::menstrual_subscription_by_address_id(customer).unwrap();
+
+		assert_noop!(
+			MenstrualSubscription::set_menstrual_subscription_paid(
+				RuntimeOrigin::signed(customer),
+				menstrual_subscription_ids[0]
+			),
+			Error::<Test>::Token,
+		);
+	})
+}
+
+#[test]
+fn auto_renew_menstrual_subscription_works() {
+	ExternalityBuilder::build().execute_with(|| {
+		let customer = account_key("customer");
+		let admin = account_key("admin");
+
+		AdminKey::<Test>::put(admin);
+
+		assert_ok!(MenstrualSubscription::set_menstrual_subscription_price(
+			RuntimeOrigin::signed(admin),
+			MenstrualSubscriptionDuration::default(),
+			CurrencyType::DBIO,
+			10,
+			None,
+		));
+
+		assert_ok!(MenstrualSubscription::add_menstrual_subscription(
+			RuntimeOrigin::signed(customer),
+			MenstrualSubscriptionDuration::default(),
+			CurrencyType::DBIO,
+		));
+
+		let menstrual_subscription_ids =
+			MenstrualSubscription::menstrual_subscription_by_address_id(customer).unwrap();
+
+		assert_ok!(MenstrualSubscription::set_menstrual_subscription_paid(
+			RuntimeOrigin::signed(customer),
+			menstrual_subscription_ids[0]
+		));
+
+		assert_ok!(MenstrualSubscription::set_menstrual_subscription_auto_renew(
+			RuntimeOrigin::signed(customer),
+			menstrual_subscription_ids[0],
+			true,
+		));
+
+		assert_ok!(MenstrualSubscription::set_renewal_allowance(
+			RuntimeOrigin::signed(customer),
+			15
+		));
+
+		assert_eq!(Balances::free_balance(customer), 190);
+
+		let expiry = MenstrualSubscriptionDuration::default().as_millis();
+
+		Timestamp::set_timestamp(expiry);
+		MenstrualSubscription::on_idle(1, Weight::from_ref_time(u64::MAX));
+
+		let menstrual_subscription_ids =
+			MenstrualSubscription::menstrual_subscription_by_address_id(customer).unwrap();
+
+		assert_eq!(menstrual_subscription_ids.len(), 2);
+
+		let renewed_subscription = MenstrualSubscriptionS {
+			id: menstrual_subscription_ids[1],
+			address_id: customer,
+			currency: CurrencyType::DBIO,
+			duration: MenstrualSubscriptionDuration::default(),
+			payment_status: PaymentStatus::Paid,
+			status: MenstrualSubscriptionStatus::Active,
+			auto_renew: true,
+			created_at: expiry,
+			updated_at: expiry,
+		};
+
+		assert_eq!(
+			MenstrualSubscription::menstrual_subscription_by_id(menstrual_subscription_ids[1]),
+			Some(renewed_subscription.clone())
+		);
+		assert_eq!(
+			MenstrualSubscription::menstrual_subscription_by_id(menstrual_subscription_ids[0])
+				.unwrap()
+				.status,
+			MenstrualSubscriptionStatus::Inactive
+		);
+		assert_eq!(
+			MenstrualSubscription::active_subscription_by_owner(customer),
+			Some(menstrual_subscription_ids[1]),
+		);
+		assert_eq!(Balances::free_balance(customer), 180);
+		assert_eq!(MenstrualSubscription::renewal_allowance_by_owner(customer), 5);
+
+		System::assert_last_event(RuntimeEvent::MenstrualSubscription(
+			crate::Event::MenstrualSubscriptionRenewed(renewed_subscription.clone()),
+		));
+
+		// Allowance no longer covers the price
+		Timestamp::set_timestamp(expiry * 2);
+		MenstrualSubscription::on_idle(1, Weight::from_ref_time(u64::MAX));
+
+		let mut failed_subscription = renewed_subscription;
+		failed_subscription.status = MenstrualSubscriptionStatus::Inactive;
+		failed_subscription.updated_at = expiry * 2;
+
+		System::assert_last_event(RuntimeEvent::MenstrualSubscription(
+			crate::Event::RenewalFailed(failed_subscription),
+		));
+
+		assert_eq!(MenstrualSubscription::active_subscription_by_owner(customer), None);
+		assert_eq!(Balances::free_balance(customer), 180);
+	})
+}
+
+#[test]
+fn auto_renew_menstrual_subscription_fails_when_balance_not_enough() {
+	ExternalityBuilder::build().execute_with(|| {
+		let customer = account_key("customer");
+		let admin = account_key("admin");
+
+		AdminKey::<Test>::put(admin);
+		RenewalAllowanceByOwner::<Test>::insert(customer, 1000);
+
+		assert_ok!(MenstrualSubscription::set_menstrual_subscription_price(
+			RuntimeOrigin::signed(admin),
+			MenstrualSubscriptionDuration::default(),
+			CurrencyType::DBIO,
+			150,
+			None,
+		));
+
+		assert_ok!(MenstrualSubscription::add_menstrual_subscription(
+			RuntimeOrigin::signed(customer),
+			MenstrualSubscriptionDuration::default(),
+			CurrencyType::DBIO,
+		));
+
+		let menstrual_subscription_ids =
+			MenstrualSubscription::menstrual_subscription_by_address_id(customer).unwrap();
+
+		assert_ok!(MenstrualSubscription::set_menstrual_subscription_paid(
+			RuntimeOrigin::signed(customer),
+			menstrual_subscription_ids[0]
+		));
+
+		assert_ok!(MenstrualSubscription::set_menstrual_subscription_auto_renew(
+			RuntimeOrigin::signed(customer),
+			menstrual_subscription_ids[0],
+			true,
+		));
+
+		let expiry = MenstrualSubscriptionDuration::default().as_millis();
+
+		Timestamp::set_timestamp(expiry);
+		MenstrualSubscription::on_idle(1, Weight::from_ref_time(u64::MAX));
+
+		let menstrual_subscription =
+			MenstrualSubscription::menstrual_subscription_by_id(menstrual_subscription_ids[0])
+				.unwrap();
+
+		assert_eq!(menstrual_subscription.status, MenstrualSubscriptionStatus::Inactive);
+
+		System::assert_last_event(RuntimeEvent::MenstrualSubscription(
+			crate::Event::RenewalFailed(menstrual_subscription),
+		));
+
+		assert_eq!(
+			MenstrualSubscription::menstrual_subscription_by_address_id(customer)
+				.unwrap()
+				.len(),
+			1
+		);
+		assert_eq!(MenstrualSubscription::active_subscription_by_owner(customer), None);
+		assert_eq!(MenstrualSubscription::renewal_allowance_by_owner(customer), 1000);
+		assert_eq!(Balances::free_balance(customer), 50);
+	})
+}
+
+#[test]
+fn auto_renew_menstrual_subscription_fails_when_owner_index_full() {
+	ExternalityBuilder::build().execute_with(|| {
+		let customer = account_key("customer");
+		let admin = account_key("admin");
+
+		AdminKey::<Test>::put(admin);
+
+		assert_ok!(MenstrualSubscription::set_menstrual_subscription_price(
+			RuntimeOrigin::signed(admin),
+			MenstrualSubscriptionDuration::default(),
+			CurrencyType::DBIO,
+			10,
+			None,
+		));
+
+		assert_ok!(MenstrualSubscription::add_menstrual_subscription(
+			RuntimeOrigin::signed(customer),
+			MenstrualSubscriptionDuration::default(),
+			CurrencyType::DBIO,
+		));
+
+		let menstrual_subscription_ids =
+			MenstrualSubscription::menstrual_subscription_by_address_id(customer).unwrap();
+
+		assert_ok!(MenstrualSubscription::set_menstrual_subscription_paid(
+			RuntimeOrigin::signed(customer),
+			menstrual_subscription_ids[0]
+		));
+
+		assert_ok!(MenstrualSubscription::set_menstrual_subscription_auto_renew(
+			RuntimeOrigin::signed(customer),
+			menstrual_subscription_ids[0],
+			true,
+		));
+
+		assert_ok!(MenstrualSubscription::set_renewal_allowance(
+			RuntimeOrigin::signed(customer),
+			15
+		));
+
+		// Fill the owner index so the renewal has no room
+		MenstrualSubscriptionByOwner::<Test>::mutate(customer, |ids| {
+			let ids = ids.get_or_insert_with(Default::default);
+			while ids.try_push(Keccak256::hash(&ids.len().to_le_bytes())).is_ok() {}
+		});
+
+		assert_eq!(Balances::free_balance(customer), 190);
+
+		let expiry = MenstrualSubscriptionDuration::default().as_millis();
+
+		Timestamp::set_timestamp(expiry);
+		MenstrualSubscription::on_idle(1, Weight::from_ref_time(u64::MAX));
+
+		let menstrual_subscription =
+			MenstrualSubscription::menstrual_subscription_by_id(menstrual_subscription_ids[0])
+				.unwrap();
+
+		System::assert_last_event(RuntimeEvent::MenstrualSubscription(
+			crate::Event::RenewalFailed(menstrual_subscription),
+		));
+
+		assert_eq!(
+			MenstrualSubscription::menstrual_subscription_by_address_id(customer)
+				.unwrap()
+				.len(),
+			MaxMenstrualSubscriptionsPerOwner::get() as usize
+		);
+		assert_eq!(MenstrualSubscription::active_subscription_by_owner(customer), None);
+		assert_eq!(MenstrualSubscription::renewal_allowance_by_owner(customer), 15);
+		assert_eq!(Balances::free_balance(customer), 190);
+	})
+}
+
+#[test]
+fn cancel_auto_renew_menstrual_subscription_works() {
+	ExternalityBuilder::build().execute_with(|| {
+		let customer = account_key("customer");
+		let admin = account_key("admin");
+
+		AdminKey::<Test>::put(admin);
+		RenewalAllowanceByOwner::<Test>::insert(customer, 1000);
+
+		assert_ok!(MenstrualSubscription::set_menstrual_subscription_price(
+			RuntimeOrigin::signed(admin),
+			MenstrualSubscriptionDuration::default(),
+			CurrencyType::DBIO,
+			10,
+			None,
+		));
+
+		assert_ok!(MenstrualSubscription::add_menstrual_subscription(
+			RuntimeOrigin::signed(customer),
+			MenstrualSubscriptionDuration::default(),
+			CurrencyType::DBIO,
+		));
+
+		let menstrual_subscription_ids =
+			MenstrualSubscription::menstrual_subscription_by_address_id(customer).unwrap();
+
+		assert_ok!(MenstrualSubscription::set_menstrual_subscription_paid(
+			RuntimeOrigin::signed(customer),
+			menstrual_subscription_ids[0]
+		));
+
+		assert_ok!(MenstrualSubscription::set_menstrual_subscription_auto_renew(
+			RuntimeOrigin::signed(customer),
+			menstrual_subscription_ids[0],
+			true,
+		));
+
+		assert_noop!(
+			MenstrualSubscription::set_menstrual_subscription_auto_renew(
+				RuntimeOrigin::signed(admin),
+				menstrual_subscription_ids[0],
+				false,
+			),
+			Error::<Test>::NotMenstrualSubscriptionOwner
+		);
+
+		assert_ok!(MenstrualSubscription::set_menstrual_subscription_auto_renew(
+			RuntimeOrigin::signed(customer),
+			menstrual_subscription_ids[0],
+			false,
+		));
+
+		let expiry = MenstrualSubscriptionDuration::default().as_millis();
+
+		Timestamp::set_timestamp(expiry);
+		MenstrualSubscription::on_idle(1, Weight::from_ref_time(u64::MAX));
+
+		let menstrual_subscription =
+			MenstrualSubscription::menstrual_subscription_by_id(menstrual_subscription_ids[0])
+				.unwrap();
+
+		System::assert_last_event(RuntimeEvent::MenstrualSubscription(
+			crate::Event::MenstrualSubscriptionExpired(menstrual_subscription),
+		));
+
+		assert_eq!(
+			MenstrualSubscription::menstrual_subscription_by_address_id(customer)
+				.unwrap()
+				.len(),
+			1
+		);
+		assert_eq!(MenstrualSubscription::renewal_allowance_by_owner(customer), 1000);
+		assert_eq!(Balances::free_balance(customer), 190);
+	})
+}
+
+#[test]
+fn migrate_v1_menstrual_subscriptions_queues_active_expiries() {
+	ExternalityBuilder::build().execute_with(|| {
+		let customer = account_key("customer");
+		let id = Keccak256::hash("menstrual_subscription".as_bytes());
+		let updated_at: u64 = 1_000;
+
+		// Menstrual subscription as stored at v1, before `auto_renew`
+		put_storage_value(
+			b"MenstrualSubscription",
+			b"MenstrualSubscriptionById",
+			&Blake2_128Concat::hash(&id.encode()),
+			(
+				id,
+				customer,
+				MenstrualSubscriptionDuration::Monthly,
+				CurrencyType::DBIO,
+				PaymentStatus::Paid,
+				MenstrualSubscriptionStatus::Active,
+				0u64,
+				updated_at,
+			),
+		);
+		ActiveSubscriptionByOwner::<Test>::insert(customer, id);
+		StorageVersion::new(1).put::<MenstrualSubscription>();
+
+		migrations::migrate::<Test>();
+
+		assert_eq!(
+			MenstrualSubscription::menstrual_subscription_by_id(id),
+			Some(MenstrualSubscriptionS {
+				id,
+				address_id: customer,
+				duration: MenstrualSubscriptionDuration::Monthly,
+				currency: CurrencyType::DBIO,
+				payment_status: PaymentStatus::Paid,
+				status: MenstrualSubscriptionStatus::Active,
+				auto_renew: false,
+				created_at: 0,
+				updated_at,
+			})
+		);
+		assert_eq!(
+			MenstrualSubscription::subscription_expiry_by_id(id),
+			Some(updated_at + MenstrualSubscriptionDuration::Monthly.as_millis())
+		);
+		assert_eq!(StorageVersion::get::<MenstrualSubscription>(), 4);
+	})
+}
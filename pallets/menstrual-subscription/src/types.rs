@@ -5,10 +5,14 @@ use primitives_price_and_currency::CurrencyType;
 use scale_info::TypeInfo;
 use traits_menstrual_subscription::MenstrualSubscription as MenstrualSubscriptionT;
 
+/// Subscriptions are queued for `on_idle` in buckets of this many milliseconds, so lapsed
+/// subscriptions are walked in time order without reading the whole queue.
+pub const EXPIRY_BUCKET_MILLIS: u64 = 60 * 60 * 1000;
+
 pub type AssetId = u32;
 pub type AssetBalance = u128;
 
-#[derive(Encode, Decode, Clone, Default, RuntimeDebug, PartialEq, Eq, TypeInfo)]
+#[derive(Encode, Decode, Clone, Default, RuntimeDebug, PartialEq, Eq, TypeInfo, MaxEncodedLen)]
 pub struct MenstrualSubscriptionPrice<Balance> {
 	pub duration: MenstrualSubscriptionDuration,
 	pub currency: CurrencyType,
@@ -32,7 +36,7 @@ pub enum AccountKeyType<AccountId> {
 	AdminKey(AccountId),
 }
 
-#[derive(Encode, Decode, Clone, Default, RuntimeDebug, PartialEq, Eq, TypeInfo)]
+#[derive(Encode, Decode, Clone, Default, RuntimeDebug, PartialEq, Eq, TypeInfo, MaxEncodedLen)]
 pub struct MenstrualSubscription<AccountId, Hash, Moment> {
 	pub id: Hash,
 	pub address_id: AccountId,
@@ -40,6 +44,7 @@ pub struct MenstrualSubscription<AccountId, Hash, Moment> {
 	pub currency: CurrencyType,
 	pub payment_status: PaymentStatus,
 	pub status: MenstrualSubscriptionStatus,
+	pub auto_renew: bool,
 	pub created_at: Moment,
 	pub updated_at: Moment,
 }
@@ -58,6 +63,7 @@ impl<AccountId, Hash, Moment: Default> MenstrualSubscription<AccountId, Hash, Mo
 			currency,
 			payment_status: PaymentStatus::default(),
 			status: MenstrualSubscriptionStatus::InQueue,
+			auto_renew: false,
 			created_at,
 			updated_at: Moment::default(),
 		}
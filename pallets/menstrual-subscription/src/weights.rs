@@ -30,6 +30,9 @@ pub trait WeightInfo {
 	fn change_menstrual_subscription_status() -> Weight;
 	fn set_menstrual_subscription_paid() -> Weight;
 	fn set_menstrual_subscription_price() -> Weight;
+	fn set_menstrual_subscription_auto_renew() -> Weight;
+	fn set_renewal_allowance() -> Weight;
+	fn expire_menstrual_subscription(n: u32, ) -> Weight;
 }
 
 /// Weights for menstrual_subscription using the Substrate node and recommended hardware.
@@ -73,6 +76,38 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(T::DbWeight::get().reads(1_u64))
 			.saturating_add(T::DbWeight::get().writes(1_u64))
 	}
+	// Storage: MenstrualSubscription MenstrualSubscriptionById (r:1 w:1)
+	// Storage: Timestamp Now (r:1 w:0)
+	fn set_menstrual_subscription_auto_renew() -> Weight {
+		Weight::from_ref_time(31_800_000_u64)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	// Storage: MenstrualSubscription RenewalAllowanceByOwner (r:0 w:1)
+	fn set_renewal_allowance() -> Weight {
+		Weight::from_ref_time(18_400_000_u64)
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	// Storage: MenstrualSubscription SubscriptionExpiryById (r:1 w:2)
+	// Storage: MenstrualSubscription SubscriptionExpiries (r:1 w:2)
+	// Storage: MenstrualSubscription MenstrualSubscriptionById (r:1 w:2)
+	// Storage: MenstrualSubscription ActiveSubscriptionByOwner (r:2 w:2)
+	// Storage: MenstrualSubscription MenstrualSubscriptionByOwner (r:1 w:1)
+	// Storage: MenstrualSubscription MenstrualSubscriptionPrices (r:1 w:0)
+	// Storage: MenstrualSubscription RenewalAllowanceByOwner (r:1 w:1)
+	// Storage: MenstrualSubscription MenstrualSubscriptionCount (r:1 w:1)
+	// Storage: MenstrualSubscription MenstrualSubscriptionCountByOwner (r:1 w:1)
+	// Storage: System Account (r:2 w:1)
+	// Storage: Balances TotalIssuance (r:1 w:1)
+	// Storage: Timestamp Now (r:1 w:0)
+	fn expire_menstrual_subscription(n: u32, ) -> Weight {
+		Weight::from_ref_time(148_700_000_u64)
+			// Standard Error: 2_000
+			.saturating_add(Weight::from_ref_time(6_512_000_u64).saturating_mul(n as u64))
+			.saturating_add(T::DbWeight::get().reads(14_u64))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(n as u64)))
+			.saturating_add(T::DbWeight::get().writes(14_u64))
+	}
 }
 
 // For backwards compatibility and tests
@@ -115,4 +150,36 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().reads(1_u64))
 			.saturating_add(RocksDbWeight::get().writes(1_u64))
 	}
+	// Storage: MenstrualSubscription MenstrualSubscriptionById (r:1 w:1)
+	// Storage: Timestamp Now (r:1 w:0)
+	fn set_menstrual_subscription_auto_renew() -> Weight {
+		Weight::from_ref_time(31_800_000_u64)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	// Storage: MenstrualSubscription RenewalAllowanceByOwner (r:0 w:1)
+	fn set_renewal_allowance() -> Weight {
+		Weight::from_ref_time(18_400_000_u64)
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	// Storage: MenstrualSubscription SubscriptionExpiryById (r:1 w:2)
+	// Storage: MenstrualSubscription SubscriptionExpiries (r:1 w:2)
+	// Storage: MenstrualSubscription MenstrualSubscriptionById (r:1 w:2)
+	// Storage: MenstrualSubscription ActiveSubscriptionByOwner (r:2 w:2)
+	// Storage: MenstrualSubscription MenstrualSubscriptionByOwner (r:1 w:1)
+	// Storage: MenstrualSubscription MenstrualSubscriptionPrices (r:1 w:0)
+	// Storage: MenstrualSubscription RenewalAllowanceByOwner (r:1 w:1)
+	// Storage: MenstrualSubscription MenstrualSubscriptionCount (r:1 w:1)
+	// Storage: MenstrualSubscription MenstrualSubscriptionCountByOwner (r:1 w:1)
+	// Storage: System Account (r:2 w:1)
+	// Storage: Balances TotalIssuance (r:1 w:1)
+	// Storage: Timestamp Now (r:1 w:0)
+	fn expire_menstrual_subscription(n: u32, ) -> Weight {
+		Weight::from_ref_time(148_700_000_u64)
+			// Standard Error: 2_000
+			.saturating_add(Weight::from_ref_time(6_512_000_u64).saturating_mul(n as u64))
+			.saturating_add(RocksDbWeight::get().reads(14_u64))
+			.saturating_add(RocksDbWeight::get().reads((1_u64).saturating_mul(n as u64)))
+			.saturating_add(RocksDbWeight::get().writes(14_u64))
+	}
 }
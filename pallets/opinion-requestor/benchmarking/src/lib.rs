@@ -4,17 +4,24 @@ mod mock;
 #[allow(unused)]
 use opinion_requestor::{
 	Config as OpinionRequestorConfig, Pallet as OpinionRequestor, RequestorInfo,
+	MAX_ELECTRONIC_MEDICAL_RECORDS_PER_REQUEST,
 };
 
-use frame_benchmarking::{benchmarks, whitelisted_caller};
+#[allow(unused)]
+use electronic_medical_record::{
+	AccessScope, Config as ElectronicMedicalRecordConfig, Pallet as ElectronicMedicalRecord,
+};
+
+use frame_benchmarking::{account, benchmarks, whitelisted_caller};
 use frame_system::RawOrigin;
 
 use pallet_timestamp::Config as TimestampConfig;
 
 pub struct Pallet<T: Config>(OpinionRequestor<T>);
 
-pub trait Config: OpinionRequestorConfig + TimestampConfig {}
+pub trait Config: OpinionRequestorConfig + ElectronicMedicalRecordConfig + TimestampConfig {}
 
+use frame_support::traits::Get;
 use opinion_requestor::Call;
 use sp_std::vec::Vec;
 
@@ -22,11 +29,11 @@ benchmarks! {
 	request_opinion {
 		let caller: T::AccountId = whitelisted_caller();
 		let info = RequestorInfo::new(
-			b"category",
-			b"description",
-			&Vec::new(),
-			&Vec::new(),
-			b"myriad_url",
+			b"category".to_vec().try_into().unwrap(),
+			b"description".to_vec().try_into().unwrap(),
+			Default::default(),
+			Default::default(),
+			b"myriad_url".to_vec().try_into().unwrap(),
 		);
 
 	}: request_opinion(RawOrigin::Signed(caller), info)
@@ -36,11 +43,11 @@ benchmarks! {
 		let caller_origin = T::RuntimeOrigin::from(RawOrigin::Signed(caller.clone()));
 
 		let info = RequestorInfo::new(
-			b"category",
-			b"description",
-			&Vec::new(),
-			&Vec::new(),
-			b"myriad_url",
+			b"category".to_vec().try_into().unwrap(),
+			b"description".to_vec().try_into().unwrap(),
+			Default::default(),
+			Default::default(),
+			b"myriad_url".to_vec().try_into().unwrap(),
 		);
 
 		let _ = OpinionRequestor::<T>::request_opinion(caller_origin, info);
@@ -48,11 +55,108 @@ benchmarks! {
 		let requestor_ids = OpinionRequestor::<T>::opinion_requestor_by_owner(caller.clone());
 		let requestor_id = requestor_ids[0];
 		let updated_info = RequestorInfo::new(
-			b"new_category",
-			b"description",
-			&Vec::new(),
-			&Vec::new(),
-			b"myriad_url",
+			b"new_category".to_vec().try_into().unwrap(),
+			b"description".to_vec().try_into().unwrap(),
+			Default::default(),
+			Default::default(),
+			b"myriad_url".to_vec().try_into().unwrap(),
 		);
 	}: update_requestor_info(RawOrigin::Signed(caller), requestor_id, updated_info)
+
+	invite_professional {
+		let caller: T::AccountId = whitelisted_caller();
+		let caller_origin = T::RuntimeOrigin::from(RawOrigin::Signed(caller.clone()));
+		let professional: T::AccountId = account("professional", 0, 0);
+
+		let info = RequestorInfo::new(
+			b"category".to_vec().try_into().unwrap(),
+			b"description".to_vec().try_into().unwrap(),
+			Default::default(),
+			Default::default(),
+			b"myriad_url".to_vec().try_into().unwrap(),
+		);
+
+		let _ = OpinionRequestor::<T>::request_opinion(caller_origin, info);
+
+		let requestor_ids = OpinionRequestor::<T>::opinion_requestor_by_owner(caller.clone());
+		let requestor_id = requestor_ids[0];
+	}: invite_professional(RawOrigin::Signed(caller), requestor_id, professional)
+
+	uninvite_professional {
+		let caller: T::AccountId = whitelisted_caller();
+		let caller_origin = T::RuntimeOrigin::from(RawOrigin::Signed(caller.clone()));
+		let professional: T::AccountId = account("professional", 0, 0);
+
+		let info = RequestorInfo::new(
+			b"category".to_vec().try_into().unwrap(),
+			b"description".to_vec().try_into().unwrap(),
+			Default::default(),
+			Default::default(),
+			b"myriad_url".to_vec().try_into().unwrap(),
+		);
+
+		let _ = OpinionRequestor::<T>::request_opinion(caller_origin.clone(), info);
+
+		let requestor_ids = OpinionRequestor::<T>::opinion_requestor_by_owner(caller.clone());
+		let requestor_id = requestor_ids[0];
+
+		let _ = OpinionRequestor::<T>::invite_professional(
+			caller_origin,
+			requestor_id,
+			professional.clone(),
+		);
+	}: uninvite_professional(RawOrigin::Signed(caller), requestor_id, professional)
+
+	accept_invitation {
+		// A request only keeps records of its owner
+		let r in 0 .. MAX_ELECTRONIC_MEDICAL_RECORDS_PER_REQUEST
+			.min(<T as ElectronicMedicalRecordConfig>::MaxRecordsPerOwner::get());
+
+		let caller: T::AccountId = whitelisted_caller();
+		let caller_origin = T::RuntimeOrigin::from(RawOrigin::Signed(caller.clone()));
+		let professional: T::AccountId = account("professional", 0, 0);
+
+		for _ in 0 .. r {
+			let _ = ElectronicMedicalRecord::<T>::add_electronic_medical_record(
+				caller_origin.clone(),
+				b"DeBio EMR".to_vec(),
+				b"DeBio EMR Category".to_vec(),
+				Vec::new(),
+			);
+		}
+
+		let electronic_medical_record_ids =
+			ElectronicMedicalRecord::<T>::electronic_medical_record_by_owner_id(caller.clone())
+				.map(|ids| ids.into_inner())
+				.unwrap_or_default();
+
+		for electronic_medical_record_id in electronic_medical_record_ids.iter() {
+			let _ = ElectronicMedicalRecord::<T>::grant_access(
+				caller_origin.clone(),
+				professional.clone(),
+				AccessScope::ElectronicMedicalRecord(*electronic_medical_record_id),
+				b"Second opinion".to_vec(),
+				1_000u32.into(),
+			);
+		}
+
+		let info = RequestorInfo::new(
+			b"category".to_vec().try_into().unwrap(),
+			b"description".to_vec().try_into().unwrap(),
+			electronic_medical_record_ids.try_into().unwrap(),
+			Default::default(),
+			b"myriad_url".to_vec().try_into().unwrap(),
+		);
+
+		let _ = OpinionRequestor::<T>::request_opinion(caller_origin.clone(), info);
+
+		let requestor_ids = OpinionRequestor::<T>::opinion_requestor_by_owner(caller);
+		let requestor_id = requestor_ids[0];
+
+		let _ = OpinionRequestor::<T>::invite_professional(
+			caller_origin,
+			requestor_id,
+			professional.clone(),
+		);
+	}: accept_invitation(RawOrigin::Signed(professional), requestor_id)
 }
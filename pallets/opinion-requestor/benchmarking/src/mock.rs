@@ -95,14 +95,33 @@ impl pallet_balances::Config for Test {
 	type WeightInfo = ();
 }
 
+parameter_types! {
+	pub const MaxTitleLength: u32 = 256;
+	pub const MaxDescriptionLength: u32 = 1024;
+	pub const MaxRecordLinkLength: u32 = 512;
+	pub const MaxFilesPerRecord: u32 = 50;
+	pub const MaxRecordsPerOwner: u32 = 1_000;
+	pub const MaxPurposeLength: u32 = 256;
+	pub const MaxGranteesPerScope: u32 = 100;
+}
+
 impl electronic_medical_record::Config for Test {
 	type RuntimeEvent = RuntimeEvent;
 	type ElectronicMedicalRecord = ElectronicMedicalRecord;
 	type ElectronicMedicalRecordWeightInfo = ();
+	type MaxTitleLength = MaxTitleLength;
+	type MaxDescriptionLength = MaxDescriptionLength;
+	type MaxRecordLinkLength = MaxRecordLinkLength;
+	type MaxFilesPerRecord = MaxFilesPerRecord;
+	type MaxRecordsPerOwner = MaxRecordsPerOwner;
+	type MaxPurposeLength = MaxPurposeLength;
+	type MaxGranteesPerScope = MaxGranteesPerScope;
 }
 
 impl opinion_requestor::Config for Test {
 	type RuntimeEvent = RuntimeEvent;
 	type ElectronicMedicalRecord = ElectronicMedicalRecord;
 	type OpinionRequestorWeightInfo = ();
+	type MaxInvitedProfessionals = frame_support::traits::ConstU32<10>;
+	type MaxRequestsPerOwner = frame_support::traits::ConstU32<100>;
 }
@@ -17,6 +17,11 @@ impl<T: Config> Pallet<T> {
 		let seed = &account_bytes;
 		T::Hashing::hash(seed)
 	}
+
+	pub fn electronic_medical_record_count(requestor_id: &T::Hash) -> u32 {
+		OpinionRequestors::<T>::get(requestor_id)
+			.map_or(0, |requestor| requestor.info().electronic_medical_record_ids().len() as u32)
+	}
 }
 
 impl<T: Config> OpinionRequestorCountT<T> for Pallet<T> {
@@ -50,7 +55,28 @@ impl<T: Config> OpinionRequestorCountT<T> for Pallet<T> {
 
 impl<T: Config> OpinionRequestorProvider<T> for Pallet<T> {
 	fn can_give_opinion(requestor_id: &T::Hash) -> bool {
-		OpinionRequestors::<T>::contains_key(requestor_id)
+		match OpinionRequestors::<T>::get(requestor_id) {
+			Some(requestor) => requestor.info().has_room_for_opinion(),
+			None => false,
+		}
+	}
+
+	fn requestor_owner(requestor_id: &T::Hash) -> Option<T::AccountId> {
+		OpinionRequestors::<T>::get(requestor_id)
+			.map(|opinion_requestor| opinion_requestor.account_id().clone())
+	}
+
+	fn is_professional_invited(requestor_id: &T::Hash, account_id: &T::AccountId) -> bool {
+		InvitedProfessionals::<T>::get(requestor_id)
+			.iter()
+			.any(|invitation| invitation.professional() == account_id)
+	}
+
+	fn has_accepted_invitation(requestor_id: &T::Hash, account_id: &T::AccountId) -> bool {
+		InvitedProfessionals::<T>::get(requestor_id).iter().any(|invitation| {
+			invitation.professional() == account_id &&
+				invitation.status() == &InvitationStatus::Accepted
+		})
 	}
 
 	fn associate(requestor_id: &T::Hash, opinion_id: &T::Hash) {
@@ -59,7 +85,8 @@ impl<T: Config> OpinionRequestorProvider<T> for Pallet<T> {
 			|result: &mut Option<OpinionRequestorOf<T>>| match result {
 				None => (),
 				Some(opinion_requestor) => {
-					opinion_requestor.add_opinion(*opinion_id);
+					// can_give_opinion rejects requests without room for another opinion
+					let _ = opinion_requestor.add_opinion(*opinion_id);
 				},
 			},
 		);
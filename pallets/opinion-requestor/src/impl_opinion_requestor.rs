@@ -1,6 +1,8 @@
 use crate::*;
-use sp_std::vec::Vec;
-use traits_electronic_medical_record::ElectronicMedicalRecordFilesProvider;
+use frame_support::BoundedVec;
+use traits_electronic_medical_record::{
+	ElectronicMedicalRecordAccess, ElectronicMedicalRecordFilesProvider,
+};
 use traits_opinion_requestor::OpinionRequestorCountT;
 
 impl<T: Config> OpinionRequestorInterface<T> for Pallet<T> {
@@ -23,16 +25,17 @@ impl<T: Config> OpinionRequestorInterface<T> for Pallet<T> {
 
 		let mut requestor_info = info;
 
-		requestor_info.update_opinion_ids(&Vec::new());
-		requestor_info.update_electronic_medical_record_ids(&valid_ids);
+		// Valid ids are a subset of the bounded ids of the request
+		requestor_info.update_opinion_ids(BoundedVec::default());
+		requestor_info.update_electronic_medical_record_ids(BoundedVec::truncate_from(valid_ids));
 
 		let now = pallet_timestamp::Pallet::<T>::get();
 		let requestor = OpinionRequestor::new(&id, account_id, &requestor_info, now);
 
+		OpinionRequestorByOwner::<T>::try_mutate(account_id, |result| {
+			result.try_push(id).map_err(|_| Error::<T>::TooManyRequests)
+		})?;
 		OpinionRequestors::<T>::insert(id, &requestor);
-		OpinionRequestorByOwner::<T>::mutate(account_id, |result: &mut Vec<HashOf<T>>| {
-			result.push(id);
-		});
 
 		Self::add_opinion_requestor_count(1);
 		Self::add_opinion_requestor_count_by_owner(account_id, 1);
@@ -58,11 +61,13 @@ impl<T: Config> OpinionRequestorInterface<T> for Pallet<T> {
 			electronic_medical_record_ids,
 		);
 
-		let opinion_ids = requestor.info().opinion_ids();
+		let opinion_ids = requestor.info().opinion_ids().to_vec();
 		let mut requestor_info = info;
 
-		requestor_info.update_electronic_medical_record_ids(&valid_ids);
-		requestor_info.update_opinion_ids(opinion_ids);
+		// Valid ids are a subset of the bounded ids of the request and the opinion ids are
+		// already bounded
+		requestor_info.update_electronic_medical_record_ids(BoundedVec::truncate_from(valid_ids));
+		requestor_info.update_opinion_ids(BoundedVec::truncate_from(opinion_ids));
 
 		requestor.update_info(requestor_info.clone(), now);
 
@@ -70,4 +75,91 @@ impl<T: Config> OpinionRequestorInterface<T> for Pallet<T> {
 
 		Ok(requestor_info)
 	}
+
+	fn invite_professional(
+		requestor_id: &T::Hash,
+		account_id: &T::AccountId,
+		professional: &T::AccountId,
+	) -> Result<(), Self::Error> {
+		let _ = OpinionRequestors::<T>::get(requestor_id)
+			.ok_or(Error::<T>::NotFound)?
+			.is_authorized_owner(account_id)
+			.ok_or(Error::<T>::Unauthorized)?;
+
+		let mut invitations = InvitedProfessionals::<T>::get(requestor_id);
+
+		if invitations.iter().any(|invitation| invitation.professional() == professional) {
+			return Err(Error::<T>::AlreadyInvited)
+		}
+
+		invitations
+			.try_push(Invitation::new(professional.clone()))
+			.map_err(|_| Error::<T>::TooManyInvitedProfessionals)?;
+
+		InvitedProfessionals::<T>::insert(requestor_id, invitations);
+
+		Ok(())
+	}
+
+	fn uninvite_professional(
+		requestor_id: &T::Hash,
+		account_id: &T::AccountId,
+		professional: &T::AccountId,
+	) -> Result<(), Self::Error> {
+		let _ = OpinionRequestors::<T>::get(requestor_id)
+			.ok_or(Error::<T>::NotFound)?
+			.is_authorized_owner(account_id)
+			.ok_or(Error::<T>::Unauthorized)?;
+
+		let mut invitations = InvitedProfessionals::<T>::get(requestor_id);
+		let position = invitations
+			.iter()
+			.position(|invitation| invitation.professional() == professional)
+			.ok_or(Error::<T>::NotInvited)?;
+
+		invitations.remove(position);
+
+		if invitations.is_empty() {
+			InvitedProfessionals::<T>::remove(requestor_id);
+		} else {
+			InvitedProfessionals::<T>::insert(requestor_id, invitations);
+		}
+
+		Ok(())
+	}
+
+	fn accept_invitation(
+		requestor_id: &T::Hash,
+		professional: &T::AccountId,
+	) -> Result<(), Self::Error> {
+		let requestor = OpinionRequestors::<T>::get(requestor_id).ok_or(Error::<T>::NotFound)?;
+
+		let mut invitations = InvitedProfessionals::<T>::get(requestor_id);
+		let invitation = invitations
+			.iter_mut()
+			.find(|invitation| invitation.professional() == professional)
+			.ok_or(Error::<T>::NotInvited)?;
+
+		if invitation.status() == &InvitationStatus::Accepted {
+			return Err(Error::<T>::InvitationAlreadyAccepted)
+		}
+
+		let can_read_records =
+			requestor.info().electronic_medical_record_ids().iter().all(|record_id| {
+				T::ElectronicMedicalRecord::can_read_electronic_medical_record(
+					professional,
+					record_id,
+				)
+			});
+
+		if !can_read_records {
+			return Err(Error::<T>::ElectronicMedicalRecordNotShared)
+		}
+
+		invitation.accept();
+
+		InvitedProfessionals::<T>::insert(requestor_id, invitations);
+
+		Ok(())
+	}
 }
@@ -13,4 +13,18 @@ pub trait OpinionRequestorInterface<T: frame_system::Config> {
 		account_id: &T::AccountId,
 		info: Self::RequestorInfo,
 	) -> Result<Self::RequestorInfo, Self::Error>;
+
+	fn invite_professional(
+		id: &T::Hash,
+		account_id: &T::AccountId,
+		professional: &T::AccountId,
+	) -> Result<(), Self::Error>;
+
+	fn uninvite_professional(
+		id: &T::Hash,
+		account_id: &T::AccountId,
+		professional: &T::AccountId,
+	) -> Result<(), Self::Error>;
+
+	fn accept_invitation(id: &T::Hash, professional: &T::AccountId) -> Result<(), Self::Error>;
 }
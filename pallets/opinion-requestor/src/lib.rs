@@ -16,7 +16,7 @@ use interface::OpinionRequestorInterface;
 use weights::WeightInfo;
 
 /// The current storage version.
-const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
+const STORAGE_VERSION: StorageVersion = StorageVersion::new(3);
 
 #[frame_support::pallet]
 pub mod pallet {
@@ -25,18 +25,22 @@ pub mod pallet {
 	use frame_support::pallet_prelude::*;
 	use frame_system::pallet_prelude::*;
 	use sp_std::vec::Vec;
-	use traits_electronic_medical_record::ElectronicMedicalRecordFilesProvider;
+	use traits_electronic_medical_record::{
+		ElectronicMedicalRecordAccess, ElectronicMedicalRecordFilesProvider,
+	};
 
 	pub type AccountIdOf<T> = <T as frame_system::Config>::AccountId;
 	pub type HashOf<T> = <T as frame_system::Config>::Hash;
 	pub type MomentOf<T> = <T as pallet_timestamp::Config>::Moment;
 	pub type OpinionRequestorOf<T> = OpinionRequestor<AccountIdOf<T>, HashOf<T>, MomentOf<T>>;
 	pub type RequestorInfoOf<T> = RequestorInfo<HashOf<T>>;
+	pub type InvitationsOf<T> =
+		BoundedVec<Invitation<AccountIdOf<T>>, <T as Config>::MaxInvitedProfessionals>;
+	pub type RequestorIdsOf<T> = BoundedVec<HashOf<T>, <T as Config>::MaxRequestsPerOwner>;
 
 	#[pallet::pallet]
 	#[pallet::storage_version(STORAGE_VERSION)]
 	#[pallet::generate_store(pub(super) trait Store)]
-	#[pallet::without_storage_info]
 	pub struct Pallet<T>(_);
 
 	#[pallet::hooks]
@@ -44,13 +48,25 @@ pub mod pallet {
 		fn on_runtime_upgrade() -> Weight {
 			migrations::migrate::<T>()
 		}
+
+		#[cfg(feature = "try-runtime")]
+		fn pre_upgrade() -> Result<Vec<u8>, &'static str> {
+			migrations::pre_upgrade::<T>()
+		}
 	}
 
 	#[pallet::config]
 	pub trait Config: frame_system::Config + pallet_timestamp::Config {
 		type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
-		type ElectronicMedicalRecord: ElectronicMedicalRecordFilesProvider<Self>;
+		type ElectronicMedicalRecord: ElectronicMedicalRecordFilesProvider<Self>
+			+ ElectronicMedicalRecordAccess<Self>;
 		type OpinionRequestorWeightInfo: WeightInfo;
+		/// Maximum number of professionals invited to one opinion request.
+		#[pallet::constant]
+		type MaxInvitedProfessionals: Get<u32>;
+		/// Maximum number of opinion requests indexed per owner.
+		#[pallet::constant]
+		type MaxRequestsPerOwner: Get<u32>;
 	}
 
 	#[pallet::storage]
@@ -61,7 +77,7 @@ pub mod pallet {
 	#[pallet::storage]
 	#[pallet::getter(fn opinion_requestor_by_owner)]
 	pub type OpinionRequestorByOwner<T> =
-		StorageMap<_, Blake2_128Concat, AccountIdOf<T>, Vec<HashOf<T>>, ValueQuery>;
+		StorageMap<_, Blake2_128Concat, AccountIdOf<T>, RequestorIdsOf<T>, ValueQuery>;
 
 	#[pallet::storage]
 	#[pallet::getter(fn opinion_requestor_count)]
@@ -72,17 +88,38 @@ pub mod pallet {
 	pub type OpinionRequestorCountByOwner<T> =
 		StorageMap<_, Blake2_128Concat, AccountIdOf<T>, u64, ValueQuery>;
 
+	#[pallet::storage]
+	#[pallet::getter(fn invited_professionals)]
+	pub type InvitedProfessionals<T> =
+		StorageMap<_, Blake2_128Concat, HashOf<T>, InvitationsOf<T>, ValueQuery>;
+
 	#[pallet::event]
 	#[pallet::generate_deposit(pub(super) fn deposit_event)]
 	pub enum Event<T: Config> {
 		OpinionRequested(AccountIdOf<T>, OpinionRequestorOf<T>),
 		OpinionRequestorInfoUpdated(AccountIdOf<T>, RequestorInfoOf<T>),
+		/// Owner, requestor id, invited professional
+		ProfessionalInvited(AccountIdOf<T>, HashOf<T>, AccountIdOf<T>),
+		/// Owner, requestor id, uninvited professional
+		ProfessionalUninvited(AccountIdOf<T>, HashOf<T>, AccountIdOf<T>),
+		/// Professional, requestor id
+		InvitationAccepted(AccountIdOf<T>, HashOf<T>),
 	}
 
 	#[pallet::error]
 	pub enum Error<T> {
 		NotFound,
 		Unauthorized,
+		AlreadyInvited,
+		NotInvited,
+		/// The request already invites MaxInvitedProfessionals professionals
+		TooManyInvitedProfessionals,
+		/// The professional has already accepted the invitation
+		InvitationAlreadyAccepted,
+		/// The professional has not been granted access to every record of the request
+		ElectronicMedicalRecordNotShared,
+		/// The owner already has MaxRequestsPerOwner opinion requests
+		TooManyRequests,
 	}
 
 	#[pallet::call]
@@ -123,5 +160,74 @@ pub mod pallet {
 				Err(error) => Err(error.into()),
 			}
 		}
+
+		#[pallet::weight(T::OpinionRequestorWeightInfo::invite_professional())]
+		pub fn invite_professional(
+			origin: OriginFor<T>,
+			requestor_id: HashOf<T>,
+			professional: AccountIdOf<T>,
+		) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+
+			match <Self as OpinionRequestorInterface<T>>::invite_professional(
+				&requestor_id,
+				&who,
+				&professional,
+			) {
+				Ok(_) => {
+					Self::deposit_event(Event::ProfessionalInvited(
+						who,
+						requestor_id,
+						professional,
+					));
+					Ok(().into())
+				},
+				Err(error) => Err(error.into()),
+			}
+		}
+
+		#[pallet::weight(T::OpinionRequestorWeightInfo::uninvite_professional())]
+		pub fn uninvite_professional(
+			origin: OriginFor<T>,
+			requestor_id: HashOf<T>,
+			professional: AccountIdOf<T>,
+		) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+
+			match <Self as OpinionRequestorInterface<T>>::uninvite_professional(
+				&requestor_id,
+				&who,
+				&professional,
+			) {
+				Ok(_) => {
+					Self::deposit_event(Event::ProfessionalUninvited(
+						who,
+						requestor_id,
+						professional,
+					));
+					Ok(().into())
+				},
+				Err(error) => Err(error.into()),
+			}
+		}
+
+		/// Every record of the request is checked for a grant to the professional
+		#[pallet::weight(T::OpinionRequestorWeightInfo::accept_invitation(
+			Pallet::<T>::electronic_medical_record_count(requestor_id)
+		))]
+		pub fn accept_invitation(
+			origin: OriginFor<T>,
+			requestor_id: HashOf<T>,
+		) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+
+			match <Self as OpinionRequestorInterface<T>>::accept_invitation(&requestor_id, &who) {
+				Ok(_) => {
+					Self::deposit_event(Event::InvitationAccepted(who, requestor_id));
+					Ok(().into())
+				},
+				Err(error) => Err(error.into()),
+			}
+		}
 	}
 }
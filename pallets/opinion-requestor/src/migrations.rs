@@ -1,11 +1,29 @@
 use crate::*;
 use frame_support::{
+	log,
 	pallet_prelude::{Decode, Encode},
-	traits::Get,
+	storage::migration::storage_key_iter,
+	traits::{Get, PalletInfoAccess},
 	weights::Weight,
+	Blake2_128Concat, BoundedVec,
 };
 use sp_std::vec::Vec;
 
+/// Fails when a legacy opinion request index does not fit in `MaxRequestsPerOwner`
+#[cfg(feature = "try-runtime")]
+pub fn pre_upgrade<T: Config>() -> Result<Vec<u8>, &'static str> {
+	let max_requests = T::MaxRequestsPerOwner::get() as usize;
+
+	if StorageVersion::get::<Pallet<T>>() < 3 &&
+		version::v3::requestor_indexes::<T>()
+			.any(|(_, requestor_ids)| requestor_ids.len() > max_requests)
+	{
+		return Err("Opinion request index holds more ids than MaxRequestsPerOwner")
+	}
+
+	Ok(Vec::new())
+}
+
 pub fn migrate<T: Config>() -> Weight {
 	let mut weight: Weight = Weight::zero();
 	let mut version = StorageVersion::get::<Pallet<T>>();
@@ -15,6 +33,16 @@ pub fn migrate<T: Config>() -> Weight {
 		version = StorageVersion::new(1);
 	}
 
+	if version == 1 {
+		weight = weight.saturating_add(version::v2::migrate::<T>());
+		version = StorageVersion::new(2);
+	}
+
+	if version == 2 {
+		weight = weight.saturating_add(version::v3::migrate::<T>());
+		version = StorageVersion::new(3);
+	}
+
 	version.put::<Pallet<T>>();
 	weight
 }
@@ -22,6 +50,38 @@ pub fn migrate<T: Config>() -> Weight {
 mod version {
 	use super::*;
 
+	/// Truncates `value` to the bound and logs the entry when it did not fit.
+	fn bounded<I, S: Get<u32>>(value: Vec<I>, entry: &str) -> BoundedVec<I, S> {
+		if value.len() > S::get() as usize {
+			log::warn!(
+				target: "runtime::opinion-requestor",
+				"{} exceeds its bound of {}, truncated from {} items",
+				entry,
+				S::get(),
+				value.len(),
+			);
+		}
+
+		BoundedVec::truncate_from(value)
+	}
+
+	/// Legacy values that exceed a bound are truncated and logged.
+	pub fn bounded_requestor_info<Hash: PartialEq + Eq + Clone>(
+		category: Vec<u8>,
+		description: Vec<u8>,
+		electronic_medical_record_ids: Vec<Hash>,
+		opinion_ids: Vec<Hash>,
+		myriad_post_id: Vec<u8>,
+	) -> RequestorInfo<Hash> {
+		RequestorInfo::new(
+			bounded(category, "Requestor info category"),
+			bounded(description, "Requestor info description"),
+			bounded(electronic_medical_record_ids, "Requestor info electronic medical record ids"),
+			bounded(opinion_ids, "Requestor info opinion ids"),
+			bounded(myriad_post_id, "Requestor info myriad post id"),
+		)
+	}
+
 	pub mod v1 {
 		use super::*;
 
@@ -53,12 +113,12 @@ mod version {
 				weight = weight.saturating_add(T::DbWeight::get().reads_writes(1, 1));
 
 				let old_requestor_info = old.info;
-				let new_requester_info = RequestorInfo::new(
-					&old_requestor_info.category,
-					&old_requestor_info.description,
-					&old_requestor_info.electronic_medical_record_ids,
-					&old_requestor_info.opinion_ids,
-					&old_requestor_info.myriad_url,
+				let new_requester_info = bounded_requestor_info(
+					old_requestor_info.category,
+					old_requestor_info.description,
+					old_requestor_info.electronic_medical_record_ids,
+					old_requestor_info.opinion_ids,
+					old_requestor_info.myriad_url,
 				);
 
 				let mut new_opinion_requestor = OpinionRequestor::new(
@@ -76,4 +136,122 @@ mod version {
 			weight
 		}
 	}
+
+	pub mod v2 {
+		use super::*;
+
+		/// Invited professionals become bounded invitations, each still waiting to be accepted.
+		/// Requests inviting more than MaxInvitedProfessionals keep the earliest invitations.
+		pub fn migrate<T: Config>() -> Weight {
+			let mut weight = T::DbWeight::get().writes(1);
+
+			InvitedProfessionals::<T>::translate(
+				|requestor_id, professionals: Vec<AccountIdOf<T>>| {
+					weight = weight.saturating_add(T::DbWeight::get().reads_writes(1, 1));
+
+					let max_invited_professionals = T::MaxInvitedProfessionals::get() as usize;
+					if professionals.len() > max_invited_professionals {
+						log::warn!(
+							target: "runtime::opinion-requestor",
+							"request {:?} invites {} professionals, truncated to {}",
+							requestor_id,
+							professionals.len(),
+							max_invited_professionals,
+						);
+					}
+
+					let invitations = professionals.into_iter().map(Invitation::new).collect();
+
+					Some(InvitationsOf::<T>::truncate_from(invitations))
+				},
+			);
+
+			weight
+		}
+	}
+
+	pub mod v3 {
+		use super::*;
+
+		#[derive(Encode, Decode)]
+		pub struct OldRequestorInfo<Hash> {
+			category: Vec<u8>,
+			description: Vec<u8>,
+			electronic_medical_record_ids: Vec<Hash>,
+			opinion_ids: Vec<Hash>,
+			myriad_post_id: Vec<u8>,
+		}
+
+		#[derive(Encode, Decode)]
+		pub struct OldOpinionRequestor<AccountId, Hash, Moment> {
+			id: Hash,
+			account_id: AccountId,
+			info: OldRequestorInfo<Hash>,
+			created_at: Moment,
+			updated_at: Moment,
+		}
+
+		pub type OldOpinionRequestorOf<T> =
+			OldOpinionRequestor<AccountIdOf<T>, HashOf<T>, MomentOf<T>>;
+
+		/// Requestor info fields become bounded, legacy values that exceed a bound are truncated
+		/// and logged. Request id indexes are bounded by `MaxRequestsPerOwner` and encode like
+		/// the lists they replace, so they are only checked. An index holding more than
+		/// `MaxRequestsPerOwner` ids is left as it is and reported instead of dropping request
+		/// ids, raise `MaxRequestsPerOwner` above it before upgrading.
+		pub fn migrate<T: Config>() -> Weight {
+			let mut weight = T::DbWeight::get().writes(1);
+
+			OpinionRequestors::<T>::translate(|_key, old: OldOpinionRequestorOf<T>| {
+				weight = weight.saturating_add(T::DbWeight::get().reads_writes(1, 1));
+
+				let old_requestor_info = old.info;
+				let requestor_info = bounded_requestor_info(
+					old_requestor_info.category,
+					old_requestor_info.description,
+					old_requestor_info.electronic_medical_record_ids,
+					old_requestor_info.opinion_ids,
+					old_requestor_info.myriad_post_id,
+				);
+
+				let mut opinion_requestor = OpinionRequestor::new(
+					&old.id,
+					&old.account_id,
+					&requestor_info,
+					old.created_at,
+				);
+
+				opinion_requestor.update_info(requestor_info, old.updated_at);
+
+				Some(opinion_requestor)
+			});
+
+			let max_requests = T::MaxRequestsPerOwner::get() as usize;
+
+			for (owner_id, requestor_ids) in requestor_indexes::<T>() {
+				weight = weight.saturating_add(T::DbWeight::get().reads(1));
+
+				if requestor_ids.len() > max_requests {
+					log::error!(
+						target: "runtime::opinion-requestor",
+						"Opinion request index of {:?} holds {} ids, more than MaxRequestsPerOwner ({})",
+						owner_id,
+						requestor_ids.len(),
+						max_requests,
+					);
+				}
+			}
+
+			weight
+		}
+
+		/// Opinion request id indexes as stored before v3
+		pub fn requestor_indexes<T: Config>(
+		) -> impl Iterator<Item = (AccountIdOf<T>, Vec<HashOf<T>>)> {
+			storage_key_iter::<AccountIdOf<T>, Vec<HashOf<T>>, Blake2_128Concat>(
+				Pallet::<T>::name().as_bytes(),
+				b"OpinionRequestorByOwner",
+			)
+		}
+	}
 }
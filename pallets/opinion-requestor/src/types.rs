@@ -1,11 +1,25 @@
 use frame_support::{
-	pallet_prelude::{Decode, Encode},
-	RuntimeDebug,
+	pallet_prelude::{ConstU32, Decode, Encode, MaxEncodedLen},
+	BoundedVec, RuntimeDebug,
 };
 use scale_info::TypeInfo;
-use sp_std::vec::Vec;
 
-#[derive(Encode, Decode, Clone, Default, RuntimeDebug, PartialEq, Eq, TypeInfo)]
+/// Maximum length of the category and myriad post id of an opinion request
+pub const MAX_REQUESTOR_INFO_LENGTH: u32 = 512;
+/// Maximum length of the description of an opinion request
+pub const MAX_REQUESTOR_DESCRIPTION_LENGTH: u32 = 4096;
+/// Maximum number of electronic medical records shared with an opinion request
+pub const MAX_ELECTRONIC_MEDICAL_RECORDS_PER_REQUEST: u32 = 100;
+/// Maximum number of opinions given on an opinion request
+pub const MAX_OPINIONS_PER_REQUEST: u32 = 100;
+
+pub type RequestorText = BoundedVec<u8, ConstU32<MAX_REQUESTOR_INFO_LENGTH>>;
+pub type RequestorDescription = BoundedVec<u8, ConstU32<MAX_REQUESTOR_DESCRIPTION_LENGTH>>;
+pub type RequestElectronicMedicalRecordIds<Hash> =
+	BoundedVec<Hash, ConstU32<MAX_ELECTRONIC_MEDICAL_RECORDS_PER_REQUEST>>;
+pub type RequestOpinionIds<Hash> = BoundedVec<Hash, ConstU32<MAX_OPINIONS_PER_REQUEST>>;
+
+#[derive(Encode, Decode, Clone, Default, RuntimeDebug, PartialEq, Eq, TypeInfo, MaxEncodedLen)]
 pub struct OpinionRequestor<AccountId, Hash, Moment>
 where
 	AccountId: Clone + PartialEq + Eq,
@@ -61,8 +75,9 @@ where
 	}
 
 	// SET Method
-	pub fn add_opinion(&mut self, opinion_id: Hash) {
-		self.info.add_opinion(opinion_id);
+	/// Returns the opinion id back when the request already has MAX_OPINIONS_PER_REQUEST opinions
+	pub fn add_opinion(&mut self, opinion_id: Hash) -> Result<(), Hash> {
+		self.info.add_opinion(opinion_id)
 	}
 
 	pub fn remove_opinion(&mut self, opinion_id: Hash) {
@@ -75,35 +90,29 @@ where
 	}
 }
 
-#[derive(Encode, Decode, Clone, Default, RuntimeDebug, PartialEq, Eq, TypeInfo)]
+#[derive(Encode, Decode, Clone, Default, RuntimeDebug, PartialEq, Eq, TypeInfo, MaxEncodedLen)]
 pub struct RequestorInfo<Hash>
 where
 	Hash: PartialEq + Eq + Clone,
 {
-	category: Vec<u8>,
-	description: Vec<u8>,
-	electronic_medical_record_ids: Vec<Hash>,
-	opinion_ids: Vec<Hash>,
-	myriad_post_id: Vec<u8>,
+	category: RequestorText,
+	description: RequestorDescription,
+	electronic_medical_record_ids: RequestElectronicMedicalRecordIds<Hash>,
+	opinion_ids: RequestOpinionIds<Hash>,
+	myriad_post_id: RequestorText,
 }
 impl<Hash> RequestorInfo<Hash>
 where
 	Hash: PartialEq + Eq + Clone,
 {
 	pub fn new(
-		category: &[u8],
-		description: &[u8],
-		electronic_medical_record_ids: &[Hash],
-		opinion_ids: &[Hash],
-		myriad_post_id: &[u8],
+		category: RequestorText,
+		description: RequestorDescription,
+		electronic_medical_record_ids: RequestElectronicMedicalRecordIds<Hash>,
+		opinion_ids: RequestOpinionIds<Hash>,
+		myriad_post_id: RequestorText,
 	) -> Self {
-		Self {
-			category: category.to_vec(),
-			description: description.to_vec(),
-			electronic_medical_record_ids: electronic_medical_record_ids.to_vec(),
-			opinion_ids: opinion_ids.to_vec(),
-			myriad_post_id: myriad_post_id.to_vec(),
-		}
+		Self { category, description, electronic_medical_record_ids, opinion_ids, myriad_post_id }
 	}
 
 	// GET Method
@@ -116,8 +125,12 @@ where
 	}
 
 	// SET Method
-	pub fn add_opinion(&mut self, opinion_id: Hash) {
-		self.opinion_ids.push(opinion_id);
+	pub fn add_opinion(&mut self, opinion_id: Hash) -> Result<(), Hash> {
+		self.opinion_ids.try_push(opinion_id)
+	}
+
+	pub fn has_room_for_opinion(&self) -> bool {
+		self.opinion_ids.len() < MAX_OPINIONS_PER_REQUEST as usize
 	}
 
 	pub fn remove_opinion(&mut self, opinion_id: Hash) {
@@ -128,11 +141,51 @@ where
 		}
 	}
 
-	pub fn update_opinion_ids(&mut self, opinion_ids: &[Hash]) {
-		self.opinion_ids = opinion_ids.to_vec();
+	pub fn update_opinion_ids(&mut self, opinion_ids: RequestOpinionIds<Hash>) {
+		self.opinion_ids = opinion_ids;
+	}
+
+	pub fn update_electronic_medical_record_ids(
+		&mut self,
+		electronic_medical_record_ids: RequestElectronicMedicalRecordIds<Hash>,
+	) {
+		self.electronic_medical_record_ids = electronic_medical_record_ids;
+	}
+}
+
+#[derive(Encode, Decode, Clone, RuntimeDebug, PartialEq, Eq, TypeInfo, MaxEncodedLen)]
+pub enum InvitationStatus {
+	Invited,
+	Accepted,
+}
+impl Default for InvitationStatus {
+	fn default() -> Self {
+		InvitationStatus::Invited
+	}
+}
+
+/// A professional invited to give an opinion, who accepts before submitting it.
+#[derive(Encode, Decode, Clone, Default, RuntimeDebug, PartialEq, Eq, TypeInfo, MaxEncodedLen)]
+pub struct Invitation<AccountId> {
+	professional: AccountId,
+	status: InvitationStatus,
+}
+impl<AccountId> Invitation<AccountId> {
+	pub fn new(professional: AccountId) -> Self {
+		Self { professional, status: InvitationStatus::default() }
+	}
+
+	// GET Method
+	pub fn professional(&self) -> &AccountId {
+		&self.professional
 	}
 
-	pub fn update_electronic_medical_record_ids(&mut self, electronic_medical_record_ids: &[Hash]) {
-		self.electronic_medical_record_ids = electronic_medical_record_ids.to_vec();
+	pub fn status(&self) -> &InvitationStatus {
+		&self.status
+	}
+
+	// SET Method
+	pub fn accept(&mut self) {
+		self.status = InvitationStatus::Accepted;
 	}
 }
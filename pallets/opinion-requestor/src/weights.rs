@@ -29,6 +29,9 @@ use sp_std::marker::PhantomData;
 pub trait WeightInfo {
 	fn request_opinion() -> Weight;
 	fn update_requestor_info() -> Weight;
+	fn invite_professional() -> Weight;
+	fn uninvite_professional() -> Weight;
+	fn accept_invitation(r: u32, ) -> Weight;
 }
 
 /// Weights for opinion_requestor_benchmarking using the Substrate node and recommended hardware.
@@ -51,6 +54,33 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(T::DbWeight::get().reads(2_u64))
 			.saturating_add(T::DbWeight::get().writes(1_u64))
 	}
+	// Storage: OpinionRequestor OpinionRequestors (r:1 w:0)
+	// Storage: OpinionRequestor InvitedProfessionals (r:1 w:1)
+	fn invite_professional() -> Weight {
+		Weight::from_ref_time(38_100_000_u64)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	// Storage: OpinionRequestor OpinionRequestors (r:1 w:0)
+	// Storage: OpinionRequestor InvitedProfessionals (r:1 w:1)
+	fn uninvite_professional() -> Weight {
+		Weight::from_ref_time(39_200_000_u64)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	// Storage: OpinionRequestor OpinionRequestors (r:1 w:0)
+	// Storage: OpinionRequestor InvitedProfessionals (r:1 w:1)
+	// Storage: ElectronicMedicalRecord ElectronicMedicalRecordById (r:1 w:0)
+	// Storage: Timestamp Now (r:1 w:0)
+	// Storage: ElectronicMedicalRecord AccessGrants (r:1 w:0)
+	fn accept_invitation(r: u32, ) -> Weight {
+		Weight::from_ref_time(31_400_000_u64)
+			// Standard Error: 3_000
+			.saturating_add(Weight::from_ref_time(7_200_000_u64).saturating_mul(r as u64))
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().reads((2_u64).saturating_mul(r as u64)))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
 }
 
 // For backwards compatibility and tests
@@ -72,4 +102,31 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().reads(2_u64))
 			.saturating_add(RocksDbWeight::get().writes(1_u64))
 	}
+	// Storage: OpinionRequestor OpinionRequestors (r:1 w:0)
+	// Storage: OpinionRequestor InvitedProfessionals (r:1 w:1)
+	fn invite_professional() -> Weight {
+		Weight::from_ref_time(38_100_000_u64)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	// Storage: OpinionRequestor OpinionRequestors (r:1 w:0)
+	// Storage: OpinionRequestor InvitedProfessionals (r:1 w:1)
+	fn uninvite_professional() -> Weight {
+		Weight::from_ref_time(39_200_000_u64)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	// Storage: OpinionRequestor OpinionRequestors (r:1 w:0)
+	// Storage: OpinionRequestor InvitedProfessionals (r:1 w:1)
+	// Storage: ElectronicMedicalRecord ElectronicMedicalRecordById (r:1 w:0)
+	// Storage: Timestamp Now (r:1 w:0)
+	// Storage: ElectronicMedicalRecord AccessGrants (r:1 w:0)
+	fn accept_invitation(r: u32, ) -> Weight {
+		Weight::from_ref_time(31_400_000_u64)
+			// Standard Error: 3_000
+			.saturating_add(Weight::from_ref_time(7_200_000_u64).saturating_mul(r as u64))
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().reads((2_u64).saturating_mul(r as u64)))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
 }
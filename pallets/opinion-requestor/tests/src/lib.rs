@@ -5,14 +5,21 @@ mod mock;
 mod test {
 	use crate::mock::*;
 
+	use electronic_medical_record::AccessScope;
 	use frame_support::{
 		assert_noop, assert_ok,
+		codec::Encode,
 		sp_runtime::traits::{Hash, Keccak256},
+		storage::migration::put_storage_value,
+		traits::StorageVersion,
+		Blake2_128Concat, BoundedVec, StorageHasher,
 	};
 	use opinion_requestor::{
-		Error, Event as OpinionRequestorEvent, OpinionRequestor as OpinionRequestorStruct,
-		RequestorInfo,
+		migrations, Error, Event as OpinionRequestorEvent, Invitation, InvitationStatus,
+		OpinionRequestor as OpinionRequestorStruct, RequestorInfo,
+		MAX_REQUESTOR_DESCRIPTION_LENGTH,
 	};
+	use sp_core::H256;
 
 	#[test]
 	fn request_opinion_works() {
@@ -31,21 +38,23 @@ mod test {
 				Keccak256::hash("0xDb9Af2d1f3ADD2726A132AA7A65Cc9E6fC5761C3".as_bytes());
 
 			let info = RequestorInfo::new(
-				b"category",
-				b"description",
-				&[electronical_medical_record_id, random_electronical_medical_record_id],
-				&Vec::new(),
-				b"myriad_url",
+				b"category".to_vec().try_into().unwrap(),
+				b"description".to_vec().try_into().unwrap(),
+				vec![electronical_medical_record_id, random_electronical_medical_record_id]
+					.try_into()
+					.unwrap(),
+				Default::default(),
+				b"myriad_url".to_vec().try_into().unwrap(),
 			);
 
 			assert_ok!(OpinionRequestor::request_opinion(RuntimeOrigin::signed(1), info));
 
 			let info = RequestorInfo::new(
-				b"category",
-				b"description",
-				&[electronical_medical_record_id],
-				&Vec::new(),
-				b"myriad_url",
+				b"category".to_vec().try_into().unwrap(),
+				b"description".to_vec().try_into().unwrap(),
+				vec![electronical_medical_record_id].try_into().unwrap(),
+				Default::default(),
+				b"myriad_url".to_vec().try_into().unwrap(),
 			);
 
 			let requestor_ids = OpinionRequestor::opinion_requestor_by_owner(1);
@@ -61,11 +70,11 @@ mod test {
 	fn update_requestor_info_works() {
 		ExternalityBuilder::build().execute_with(|| {
 			let info = RequestorInfo::new(
-				b"category",
-				b"description",
-				&Vec::new(),
-				&Vec::new(),
-				b"myriad_url",
+				b"category".to_vec().try_into().unwrap(),
+				b"description".to_vec().try_into().unwrap(),
+				Default::default(),
+				Default::default(),
+				b"myriad_url".to_vec().try_into().unwrap(),
 			);
 
 			assert_ok!(OpinionRequestor::request_opinion(RuntimeOrigin::signed(1), info));
@@ -85,11 +94,11 @@ mod test {
 			let electronical_medical_record_id = electronic_medical_record_ids[0];
 
 			let updated_info = RequestorInfo::new(
-				b"category",
-				b"description",
-				&[electronical_medical_record_id],
-				&Vec::new(),
-				b"myriad_url",
+				b"category".to_vec().try_into().unwrap(),
+				b"description".to_vec().try_into().unwrap(),
+				vec![electronical_medical_record_id].try_into().unwrap(),
+				Default::default(),
+				b"myriad_url".to_vec().try_into().unwrap(),
 			);
 
 			let requestor = OpinionRequestorStruct::new(&requestor_id, &1, &updated_info, 0);
@@ -109,11 +118,11 @@ mod test {
 			let requestor_id =
 				Keccak256::hash("0xDb9Af2d1f3ADD2726A132AA7A65Cc9E6fC5761C3".as_bytes());
 			let info = RequestorInfo::new(
-				b"category",
-				b"description",
-				&Vec::new(),
-				&Vec::new(),
-				b"myriad_url",
+				b"category".to_vec().try_into().unwrap(),
+				b"description".to_vec().try_into().unwrap(),
+				Default::default(),
+				Default::default(),
+				b"myriad_url".to_vec().try_into().unwrap(),
 			);
 
 			assert_noop!(
@@ -131,11 +140,11 @@ mod test {
 	fn cant_update_requestor_info_when_not_owner() {
 		ExternalityBuilder::build().execute_with(|| {
 			let info = RequestorInfo::new(
-				b"category",
-				b"description",
-				&Vec::new(),
-				&Vec::new(),
-				b"myriad_url",
+				b"category".to_vec().try_into().unwrap(),
+				b"description".to_vec().try_into().unwrap(),
+				Default::default(),
+				Default::default(),
+				b"myriad_url".to_vec().try_into().unwrap(),
 			);
 
 			assert_ok!(OpinionRequestor::request_opinion(RuntimeOrigin::signed(1), info));
@@ -155,11 +164,11 @@ mod test {
 			let electronical_medical_record_id = electronic_medical_record_ids[0];
 
 			let updated_info = RequestorInfo::new(
-				b"category",
-				b"description",
-				&[electronical_medical_record_id],
-				&Vec::new(),
-				b"myriad_url",
+				b"category".to_vec().try_into().unwrap(),
+				b"description".to_vec().try_into().unwrap(),
+				vec![electronical_medical_record_id].try_into().unwrap(),
+				Default::default(),
+				b"myriad_url".to_vec().try_into().unwrap(),
 			);
 
 			assert_noop!(
@@ -173,17 +182,305 @@ mod test {
 		});
 	}
 
+	#[test]
+	fn invite_and_uninvite_professional_works() {
+		ExternalityBuilder::build().execute_with(|| {
+			let info = RequestorInfo::new(
+				b"category".to_vec().try_into().unwrap(),
+				b"description".to_vec().try_into().unwrap(),
+				Default::default(),
+				Default::default(),
+				b"myriad_url".to_vec().try_into().unwrap(),
+			);
+
+			assert_ok!(OpinionRequestor::request_opinion(RuntimeOrigin::signed(1), info));
+
+			let requestor_ids = OpinionRequestor::opinion_requestor_by_owner(1);
+			let requestor_id = requestor_ids[0];
+
+			assert_ok!(OpinionRequestor::invite_professional(
+				RuntimeOrigin::signed(1),
+				requestor_id,
+				2
+			));
+			assert_eq!(
+				OpinionRequestor::invited_professionals(requestor_id).into_inner(),
+				vec![Invitation::new(2)]
+			);
+
+			assert_noop!(
+				OpinionRequestor::invite_professional(RuntimeOrigin::signed(1), requestor_id, 2),
+				Error::<Test>::AlreadyInvited,
+			);
+
+			assert_ok!(OpinionRequestor::uninvite_professional(
+				RuntimeOrigin::signed(1),
+				requestor_id,
+				2
+			));
+			assert!(OpinionRequestor::invited_professionals(requestor_id).is_empty());
+
+			assert_noop!(
+				OpinionRequestor::uninvite_professional(RuntimeOrigin::signed(1), requestor_id, 2),
+				Error::<Test>::NotInvited,
+			);
+		});
+	}
+
+	#[test]
+	fn cant_invite_professional_when_not_owner() {
+		ExternalityBuilder::build().execute_with(|| {
+			let info = RequestorInfo::new(
+				b"category".to_vec().try_into().unwrap(),
+				b"description".to_vec().try_into().unwrap(),
+				Default::default(),
+				Default::default(),
+				b"myriad_url".to_vec().try_into().unwrap(),
+			);
+
+			assert_ok!(OpinionRequestor::request_opinion(RuntimeOrigin::signed(1), info));
+
+			let requestor_ids = OpinionRequestor::opinion_requestor_by_owner(1);
+			let requestor_id = requestor_ids[0];
+
+			assert_noop!(
+				OpinionRequestor::invite_professional(RuntimeOrigin::signed(2), requestor_id, 2),
+				Error::<Test>::Unauthorized,
+			);
+		});
+	}
+
+	#[test]
+	fn accept_invitation_works() {
+		ExternalityBuilder::build().execute_with(|| {
+			let info = RequestorInfo::new(
+				b"category".to_vec().try_into().unwrap(),
+				b"description".to_vec().try_into().unwrap(),
+				Default::default(),
+				Default::default(),
+				b"myriad_url".to_vec().try_into().unwrap(),
+			);
+
+			assert_ok!(OpinionRequestor::request_opinion(RuntimeOrigin::signed(1), info));
+
+			let requestor_ids = OpinionRequestor::opinion_requestor_by_owner(1);
+			let requestor_id = requestor_ids[0];
+
+			assert_noop!(
+				OpinionRequestor::accept_invitation(RuntimeOrigin::signed(2), requestor_id),
+				Error::<Test>::NotInvited,
+			);
+
+			assert_ok!(OpinionRequestor::invite_professional(
+				RuntimeOrigin::signed(1),
+				requestor_id,
+				2
+			));
+			assert_ok!(OpinionRequestor::accept_invitation(RuntimeOrigin::signed(2), requestor_id));
+
+			let invitations = OpinionRequestor::invited_professionals(requestor_id);
+			assert_eq!(invitations[0].professional(), &2);
+			assert_eq!(invitations[0].status(), &InvitationStatus::Accepted);
+
+			assert_noop!(
+				OpinionRequestor::accept_invitation(RuntimeOrigin::signed(2), requestor_id),
+				Error::<Test>::InvitationAlreadyAccepted,
+			);
+			assert_noop!(
+				OpinionRequestor::accept_invitation(
+					RuntimeOrigin::signed(2),
+					Keccak256::hash("requestor_id".as_bytes())
+				),
+				Error::<Test>::NotFound,
+			);
+		});
+	}
+
+	#[test]
+	fn cant_accept_invitation_when_record_not_shared() {
+		ExternalityBuilder::build().execute_with(|| {
+			assert_ok!(ElectronicMedicalRecord::add_electronic_medical_record(
+				RuntimeOrigin::signed(1),
+				"Electronic Medical Record Title".as_bytes().to_vec(),
+				"Electronic Medical Record Category".as_bytes().to_vec(),
+				Vec::new(),
+			));
+
+			let electronic_medical_record_ids =
+				ElectronicMedicalRecord::electronic_medical_record_by_owner_id(1).unwrap();
+			let electronic_medical_record_id = electronic_medical_record_ids[0];
+
+			let info = RequestorInfo::new(
+				b"category".to_vec().try_into().unwrap(),
+				b"description".to_vec().try_into().unwrap(),
+				vec![electronic_medical_record_id].try_into().unwrap(),
+				Default::default(),
+				b"myriad_url".to_vec().try_into().unwrap(),
+			);
+
+			assert_ok!(OpinionRequestor::request_opinion(RuntimeOrigin::signed(1), info));
+
+			let requestor_ids = OpinionRequestor::opinion_requestor_by_owner(1);
+			let requestor_id = requestor_ids[0];
+
+			assert_ok!(OpinionRequestor::invite_professional(
+				RuntimeOrigin::signed(1),
+				requestor_id,
+				2
+			));
+
+			assert_noop!(
+				OpinionRequestor::accept_invitation(RuntimeOrigin::signed(2), requestor_id),
+				Error::<Test>::ElectronicMedicalRecordNotShared,
+			);
+
+			assert_ok!(ElectronicMedicalRecord::grant_access(
+				RuntimeOrigin::signed(1),
+				2,
+				AccessScope::ElectronicMedicalRecord(electronic_medical_record_id),
+				b"Second opinion".to_vec(),
+				1000
+			));
+
+			assert_ok!(OpinionRequestor::accept_invitation(RuntimeOrigin::signed(2), requestor_id));
+		});
+	}
+
+	#[test]
+	fn cant_invite_more_than_max_invited_professionals() {
+		ExternalityBuilder::build().execute_with(|| {
+			let info = RequestorInfo::new(
+				b"category".to_vec().try_into().unwrap(),
+				b"description".to_vec().try_into().unwrap(),
+				Default::default(),
+				Default::default(),
+				b"myriad_url".to_vec().try_into().unwrap(),
+			);
+
+			assert_ok!(OpinionRequestor::request_opinion(RuntimeOrigin::signed(1), info));
+
+			let requestor_ids = OpinionRequestor::opinion_requestor_by_owner(1);
+			let requestor_id = requestor_ids[0];
+
+			// MaxInvitedProfessionals is 3 in the mock
+			for professional in 2..5 {
+				assert_ok!(OpinionRequestor::invite_professional(
+					RuntimeOrigin::signed(1),
+					requestor_id,
+					professional
+				));
+			}
+
+			assert_noop!(
+				OpinionRequestor::invite_professional(RuntimeOrigin::signed(1), requestor_id, 5),
+				Error::<Test>::TooManyInvitedProfessionals,
+			);
+		});
+	}
+
+	#[test]
+	fn cant_request_more_than_max_requests_per_owner() {
+		ExternalityBuilder::build().execute_with(|| {
+			let info = RequestorInfo::new(
+				b"category".to_vec().try_into().unwrap(),
+				b"description".to_vec().try_into().unwrap(),
+				Default::default(),
+				Default::default(),
+				b"myriad_url".to_vec().try_into().unwrap(),
+			);
+
+			// MaxRequestsPerOwner is 3 in the mock
+			for _ in 0..3 {
+				assert_ok!(OpinionRequestor::request_opinion(
+					RuntimeOrigin::signed(1),
+					info.clone()
+				));
+			}
+
+			assert_noop!(
+				OpinionRequestor::request_opinion(RuntimeOrigin::signed(1), info),
+				Error::<Test>::TooManyRequests,
+			);
+		});
+	}
+
+	#[test]
+	fn migrate_requestor_info_to_bounded_fields() {
+		ExternalityBuilder::build().execute_with(|| {
+			let requestor_id = Keccak256::hash("requestor_id".as_bytes());
+			let description = vec![0u8; MAX_REQUESTOR_DESCRIPTION_LENGTH as usize + 1];
+
+			put_storage_value(
+				b"OpinionRequestor",
+				b"OpinionRequestors",
+				&Blake2_128Concat::hash(&requestor_id.encode()),
+				(
+					requestor_id,
+					1u64,
+					(
+						b"category".to_vec(),
+						description.clone(),
+						Vec::<H256>::new(),
+						Vec::<H256>::new(),
+						b"myriad_url".to_vec(),
+					),
+					0u64,
+					1u64,
+				),
+			);
+			StorageVersion::new(2).put::<OpinionRequestor>();
+
+			migrations::migrate::<Test>();
+
+			let info = RequestorInfo::new(
+				b"category".to_vec().try_into().unwrap(),
+				BoundedVec::truncate_from(description),
+				Default::default(),
+				Default::default(),
+				b"myriad_url".to_vec().try_into().unwrap(),
+			);
+			let mut requestor = OpinionRequestorStruct::new(&requestor_id, &1, &info, 0);
+			requestor.update_info(info, 1);
+
+			assert_eq!(OpinionRequestor::opinion_requestor_by_id(requestor_id), Some(requestor));
+			assert_eq!(StorageVersion::get::<OpinionRequestor>(), StorageVersion::new(3));
+		});
+	}
+
+	#[test]
+	fn migrate_invited_professionals_to_bounded_invitations() {
+		ExternalityBuilder::build().execute_with(|| {
+			let requestor_id = Keccak256::hash("requestor_id".as_bytes());
+
+			put_storage_value(
+				b"OpinionRequestor",
+				b"InvitedProfessionals",
+				&Blake2_128Concat::hash(&requestor_id.encode()),
+				vec![2u64, 3, 4, 5],
+			);
+			StorageVersion::new(1).put::<OpinionRequestor>();
+
+			migrations::migrate::<Test>();
+
+			assert_eq!(
+				OpinionRequestor::invited_professionals(requestor_id).into_inner(),
+				vec![Invitation::new(2), Invitation::new(3), Invitation::new(4)]
+			);
+			assert_eq!(StorageVersion::get::<OpinionRequestor>(), StorageVersion::new(3));
+		});
+	}
+
 	#[test]
 	fn call_event_should_work() {
 		ExternalityBuilder::build().execute_with(|| {
 			System::set_block_number(1);
 
 			let info = RequestorInfo::new(
-				b"category",
-				b"description",
-				&Vec::new(),
-				&Vec::new(),
-				b"myriad_url",
+				b"category".to_vec().try_into().unwrap(),
+				b"description".to_vec().try_into().unwrap(),
+				Default::default(),
+				Default::default(),
+				b"myriad_url".to_vec().try_into().unwrap(),
 			);
 
 			assert_ok!(OpinionRequestor::request_opinion(RuntimeOrigin::signed(1), info.clone()));
@@ -197,11 +494,11 @@ mod test {
 			));
 
 			let updated_info = RequestorInfo::new(
-				b"category",
-				b"description",
-				&Vec::new(),
-				&Vec::new(),
-				b"myriad_url",
+				b"category".to_vec().try_into().unwrap(),
+				b"description".to_vec().try_into().unwrap(),
+				Default::default(),
+				Default::default(),
+				b"myriad_url".to_vec().try_into().unwrap(),
 			);
 
 			assert_ok!(OpinionRequestor::update_requestor_info(
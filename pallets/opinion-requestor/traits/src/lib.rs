@@ -10,7 +10,11 @@ pub trait OpinionRequestorCountT<T: Config> {
 }
 
 pub trait OpinionRequestorProvider<T: Config> {
+	/// The request exists and has room for another opinion
 	fn can_give_opinion(requestor_id: &T::Hash) -> bool;
+	fn requestor_owner(requestor_id: &T::Hash) -> Option<T::AccountId>;
+	fn is_professional_invited(requestor_id: &T::Hash, account_id: &T::AccountId) -> bool;
+	fn has_accepted_invitation(requestor_id: &T::Hash, account_id: &T::AccountId) -> bool;
 	fn associate(requestor_id: &T::Hash, opinion_id: &T::Hash);
 	fn disassociate(requestor_id: &T::Hash, opinion_id: &T::Hash);
 }
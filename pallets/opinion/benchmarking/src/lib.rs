@@ -7,167 +7,248 @@ use opinion_requestor::{
 };
 
 #[allow(unused)]
-use opinion::{Config as OpinionConfig, OpinionAdminKey, OpinionInfo, Pallet as Opinion, Status};
+use opinion::{
+	Config as OpinionConfig, OpinionAdminKey, OpinionInfo, OpinionTreasuryKey, Pallet as Opinion,
+	Status,
+};
 
 use frame_benchmarking::{account, benchmarks, whitelisted_caller};
+use frame_support::{
+	sp_runtime::SaturatedConversion,
+	traits::{Currency, Get},
+};
 use frame_system::RawOrigin;
 use primitives_price_and_currency::CurrencyType;
 
 use pallet_timestamp::Config as TimestampConfig;
 
+#[allow(unused)]
+use health_professional::{
+	Config as HealthProfessionalConfig, HealthProfessional, HealthProfessionalInfo,
+	HealthProfessionals,
+};
+use primitives_stake_status::StakeStatus;
+use primitives_verification_status::VerificationStatus;
+
 pub struct Pallet<T: Config>(Opinion<T>);
 
-pub trait Config: OpinionRequestorConfig + OpinionConfig + TimestampConfig {}
+pub trait Config:
+	OpinionRequestorConfig + OpinionConfig + TimestampConfig + HealthProfessionalConfig
+{
+}
 
 use opinion::Call;
 use sp_std::vec::Vec;
 
 const SEED: u32 = 0;
 
+fn verified_health_professional<T: Config>(account_id: &T::AccountId) {
+	let mut health_professional =
+		HealthProfessional::new(account_id, &HealthProfessionalInfo::default());
+
+	health_professional.update_verification_status(&VerificationStatus::Verified);
+	health_professional.update_stake_status(StakeStatus::Staked, Default::default());
+
+	HealthProfessionals::<T>::insert(account_id, health_professional);
+}
+
+fn invited_professional<T: Config>(customer: &T::AccountId, doctor: &T::AccountId) -> T::Hash {
+	let customer_origin = T::RuntimeOrigin::from(RawOrigin::Signed(customer.clone()));
+	let doctor_origin = T::RuntimeOrigin::from(RawOrigin::Signed(doctor.clone()));
+
+	verified_health_professional::<T>(doctor);
+
+	let info = RequestorInfo::new(
+		b"category".to_vec().try_into().unwrap(),
+		b"description".to_vec().try_into().unwrap(),
+		Default::default(),
+		Default::default(),
+		b"myriad_url".to_vec().try_into().unwrap(),
+	);
+
+	let _ = OpinionRequestor::<T>::request_opinion(customer_origin.clone(), info);
+
+	let requestor_ids = OpinionRequestor::<T>::opinion_requestor_by_owner(customer.clone());
+	let requestor_id = requestor_ids[0];
+
+	let _ =
+		OpinionRequestor::<T>::invite_professional(customer_origin, requestor_id, doctor.clone());
+	let _ = OpinionRequestor::<T>::accept_invitation(doctor_origin, requestor_id);
+
+	requestor_id
+}
+
+fn paid_professional<T: Config>(customer: &T::AccountId, doctor: &T::AccountId) -> T::Hash {
+	let customer_origin = T::RuntimeOrigin::from(RawOrigin::Signed(customer.clone()));
+
+	let _ =
+		<T as OpinionConfig>::Currency::deposit_creating(customer, 1_000_000u128.saturated_into());
+
+	let requestor_id = invited_professional::<T>(customer, doctor);
+
+	let _ = Opinion::<T>::pay(
+		customer_origin,
+		requestor_id,
+		doctor.clone(),
+		CurrencyType::DBIO,
+		None,
+		1000,
+	);
+
+	requestor_id
+}
+
+fn opinion_info() -> OpinionInfo {
+	OpinionInfo::new(
+		b"description".to_vec().try_into().unwrap(),
+		b"myriad_url".to_vec().try_into().unwrap(),
+		None,
+		CurrencyType::DBIO,
+		1000,
+	)
+}
+
+fn submitted_opinion<T: Config>(customer: &T::AccountId, doctor: &T::AccountId) -> T::Hash {
+	let doctor_origin = T::RuntimeOrigin::from(RawOrigin::Signed(doctor.clone()));
+
+	let requestor_id = invited_professional::<T>(customer, doctor);
+
+	let _ = Opinion::<T>::submit(doctor_origin, requestor_id, opinion_info());
+
+	let opinion_ids = Opinion::<T>::opinion_by_owner(doctor.clone());
+	opinion_ids[0]
+}
+
+fn delivered_opinion<T: Config>(customer: &T::AccountId, doctor: &T::AccountId) -> T::Hash {
+	let doctor_origin = T::RuntimeOrigin::from(RawOrigin::Signed(doctor.clone()));
+
+	let requestor_id = paid_professional::<T>(customer, doctor);
+
+	let _ = Opinion::<T>::submit(doctor_origin, requestor_id, opinion_info());
+
+	let opinion_ids = Opinion::<T>::opinion_by_owner(doctor.clone());
+	opinion_ids[0]
+}
+
 benchmarks! {
-	create {
+	hide {
 		let caller: T::AccountId = OpinionAdminKey::<T>::get().unwrap();
 		let doctor: T::AccountId = account("doctor", 0, SEED);
 		let customer: T::AccountId = whitelisted_caller();
-		let customer_origin = T::RuntimeOrigin::from(RawOrigin::Signed(customer.clone()));
-
-		let info = RequestorInfo::new(
-			b"category",
-			b"description",
-			&Vec::new(),
-			&Vec::new(),
-			b"myriad_url",
-		);
 
-		let _ = OpinionRequestor::<T>::request_opinion(customer_origin, info);
+		let opinion_id = submitted_opinion::<T>(&customer, &doctor);
+	}: hide(RawOrigin::Signed(caller), opinion_id)
 
-		let requestor_ids = OpinionRequestor::<T>::opinion_requestor_by_owner(customer);
-		let requestor_id = requestor_ids[0];
-
-		let info = OpinionInfo::new(
-			b"description".to_vec(),
-			b"myriad_url".to_vec(),
-			None,
-			CurrencyType::DBIO,
-			1000,
-		);
-	}: create(RawOrigin::Signed(caller), requestor_id, doctor, info)
-
-	update {
+	unhide {
 		let caller: T::AccountId = OpinionAdminKey::<T>::get().unwrap();
 		let doctor: T::AccountId = account("doctor", 0, SEED);
 		let customer: T::AccountId = whitelisted_caller();
 
 		let caller_origin = T::RuntimeOrigin::from(RawOrigin::Signed(caller.clone()));
-		let customer_origin = T::RuntimeOrigin::from(RawOrigin::Signed(customer.clone()));
-
-		let info = RequestorInfo::new(
-			b"category",
-			b"description",
-			&Vec::new(),
-			&Vec::new(),
-			b"myriad_url",
-		);
 
-		let _ = OpinionRequestor::<T>::request_opinion(customer_origin, info);
+		let opinion_id = submitted_opinion::<T>(&customer, &doctor);
 
-		let requestor_ids = OpinionRequestor::<T>::opinion_requestor_by_owner(customer);
-		let requestor_id = requestor_ids[0];
+		let _ = Opinion::<T>::hide(caller_origin, opinion_id);
+	}: unhide(RawOrigin::Signed(caller), opinion_id)
 
-		let info = OpinionInfo::new(
-			b"description".to_vec(),
-			b"myriad_url".to_vec(),
-			None,
-			CurrencyType::DBIO,
-			1000,
-		);
+	update_status {
+		let caller: T::AccountId = OpinionAdminKey::<T>::get().unwrap();
+		let doctor: T::AccountId = account("doctor", 0, SEED);
+		let customer: T::AccountId = whitelisted_caller();
 
-		let _ = Opinion::<T>::create(caller_origin, requestor_id, doctor.clone(), info);
+		let opinion_id = delivered_opinion::<T>(&customer, &doctor);
 
-		let opinion_ids = Opinion::<T>::opinion_by_owner(doctor);
-		let opinion_id = opinion_ids[0];
+		OpinionTreasuryKey::<T>::put(account::<T::AccountId>("treasury", 0, SEED));
+	}: update_status(RawOrigin::Signed(caller), opinion_id, Status::Accepted)
 
-		let updated_info = OpinionInfo::new(
-			b"description".to_vec(),
-			b"url".to_vec(),
-			None,
-			CurrencyType::DBIO,
-			1000,
-		);
-	}: update(RawOrigin::Signed(caller), opinion_id, updated_info)
+	delete {
+		let caller: T::AccountId = OpinionAdminKey::<T>::get().unwrap();
+		let doctor: T::AccountId = account("doctor", 0, SEED);
+		let customer: T::AccountId = whitelisted_caller();
 
-	update_status {
+		let opinion_id = submitted_opinion::<T>(&customer, &doctor);
+	}: delete(RawOrigin::Signed(caller), opinion_id)
+
+	update_admin_key {
 		let caller: T::AccountId = OpinionAdminKey::<T>::get().unwrap();
+		let new_admin: T::AccountId = whitelisted_caller();
+	}: update_admin_key(RawOrigin::Signed(caller), new_admin)
+
+	pay {
 		let doctor: T::AccountId = account("doctor", 0, SEED);
 		let customer: T::AccountId = whitelisted_caller();
 
-		let caller_origin = T::RuntimeOrigin::from(RawOrigin::Signed(caller.clone()));
-		let customer_origin = T::RuntimeOrigin::from(RawOrigin::Signed(customer.clone()));
-
-		let info = RequestorInfo::new(
-			b"category",
-			b"description",
-			&Vec::new(),
-			&Vec::new(),
-			b"myriad_url",
-		);
+		let _ = <T as OpinionConfig>::Currency::deposit_creating(&customer, 1_000_000u128.saturated_into());
 
-		let _ = OpinionRequestor::<T>::request_opinion(customer_origin, info);
+		let requestor_id = invited_professional::<T>(&customer, &doctor);
+	}: pay(RawOrigin::Signed(customer), requestor_id, doctor, CurrencyType::DBIO, None, 1000)
 
-		let requestor_ids = OpinionRequestor::<T>::opinion_requestor_by_owner(customer);
-		let requestor_id = requestor_ids[0];
+	accept {
+		let doctor: T::AccountId = account("doctor", 0, SEED);
+		let customer: T::AccountId = whitelisted_caller();
 
-		let info = OpinionInfo::new(
-			b"description".to_vec(),
-			b"myriad_url".to_vec(),
-			None,
-			CurrencyType::DBIO,
-			1000,
-		);
+		let opinion_id = delivered_opinion::<T>(&customer, &doctor);
 
-		let _ = Opinion::<T>::create(caller_origin, requestor_id, doctor.clone(), info);
+		OpinionTreasuryKey::<T>::put(account::<T::AccountId>("treasury", 0, SEED));
+	}: accept(RawOrigin::Signed(customer), opinion_id)
 
-		let opinion_ids = Opinion::<T>::opinion_by_owner(doctor);
-		let opinion_id = opinion_ids[0];
-	}: update_status(RawOrigin::Signed(caller), opinion_id, Status::Paid)
+	refund {
+		let doctor: T::AccountId = account("doctor", 0, SEED);
+		let customer: T::AccountId = whitelisted_caller();
 
-	delete {
+		let requestor_id = paid_professional::<T>(&customer, &doctor);
+
+		let timeout = <T as OpinionConfig>::PaymentTimeout::get();
+		pallet_timestamp::Now::<T>::put(timeout.saturated_into::<T::Moment>());
+	}: refund(RawOrigin::Signed(customer), requestor_id, doctor)
+
+	reject {
 		let caller: T::AccountId = OpinionAdminKey::<T>::get().unwrap();
 		let doctor: T::AccountId = account("doctor", 0, SEED);
 		let customer: T::AccountId = whitelisted_caller();
 
-		let caller_origin = T::RuntimeOrigin::from(RawOrigin::Signed(caller.clone()));
-		let customer_origin = T::RuntimeOrigin::from(RawOrigin::Signed(customer.clone()));
-
-		let info = RequestorInfo::new(
-			b"category",
-			b"description",
-			&Vec::new(),
-			&Vec::new(),
-			b"myriad_url",
-		);
+		let opinion_id = delivered_opinion::<T>(&customer, &doctor);
+	}: reject(RawOrigin::Signed(caller), opinion_id)
+
+	submit {
+		let doctor: T::AccountId = account("doctor", 0, SEED);
+		let customer: T::AccountId = whitelisted_caller();
+
+		let requestor_id = invited_professional::<T>(&customer, &doctor);
+		let info = opinion_info();
+	}: submit(RawOrigin::Signed(doctor), requestor_id, info)
 
-		let _ = OpinionRequestor::<T>::request_opinion(customer_origin, info);
+	edit {
+		let doctor: T::AccountId = account("doctor", 0, SEED);
+		let customer: T::AccountId = whitelisted_caller();
 
-		let requestor_ids = OpinionRequestor::<T>::opinion_requestor_by_owner(customer);
-		let requestor_id = requestor_ids[0];
+		let opinion_id = submitted_opinion::<T>(&customer, &doctor);
 
-		let info = OpinionInfo::new(
-			b"description".to_vec(),
-			b"myriad_url".to_vec(),
+		let updated_info = OpinionInfo::new(
+			b"new_description".to_vec().try_into().unwrap(),
+			b"myriad_url".to_vec().try_into().unwrap(),
 			None,
 			CurrencyType::DBIO,
-			1000,
+			2000,
 		);
+	}: edit(RawOrigin::Signed(doctor), opinion_id, updated_info)
 
-		let _ = Opinion::<T>::create(caller_origin, requestor_id, doctor.clone(), info);
+	withdraw {
+		let doctor: T::AccountId = account("doctor", 0, SEED);
+		let customer: T::AccountId = whitelisted_caller();
 
-		let opinion_ids = Opinion::<T>::opinion_by_owner(doctor);
-		let opinion_id = opinion_ids[0];
-	}: delete(RawOrigin::Signed(caller), opinion_id)
+		let opinion_id = submitted_opinion::<T>(&customer, &doctor);
+	}: withdraw(RawOrigin::Signed(doctor), opinion_id)
 
-	update_admin_key {
-		let caller: T::AccountId = OpinionAdminKey::<T>::get().unwrap();
-		let new_admin: T::AccountId = whitelisted_caller();
-	}: update_admin_key(RawOrigin::Signed(caller), new_admin)
+	claim {
+		let doctor: T::AccountId = account("doctor", 0, SEED);
+		let customer: T::AccountId = whitelisted_caller();
+
+		let opinion_id = delivered_opinion::<T>(&customer, &doctor);
+
+		OpinionTreasuryKey::<T>::put(account::<T::AccountId>("treasury", 0, SEED));
+
+		let timeout = <T as OpinionConfig>::PaymentTimeout::get();
+		pallet_timestamp::Now::<T>::put(timeout.saturated_into::<T::Moment>());
+	}: claim(RawOrigin::Signed(doctor), opinion_id)
 }
@@ -1,11 +1,12 @@
 #![cfg(test)]
 
-use frame_support::{construct_runtime, parameter_types, traits::ConstU64};
+use frame_support::{construct_runtime, parameter_types, traits::ConstU64, PalletId};
 use pallet_balances::AccountData;
 use sp_core::H256;
 use sp_runtime::{
 	testing::Header,
 	traits::{BlakeTwo256, IdentityLookup},
+	Perbill,
 };
 
 type UncheckedExtrinsic = frame_system::mocking::MockUncheckedExtrinsic<Test>;
@@ -26,6 +27,8 @@ construct_runtime!(
 		Timestamp: pallet_timestamp,
 		Assets: pallet_assets,
 		ElectronicMedicalRecord: electronic_medical_record,
+		HealthProfessional: health_professional,
+		HealthProfessionalQualification: credentials::<Instance5>,
 
 	}
 );
@@ -126,16 +129,57 @@ impl pallet_balances::Config for Test {
 	type WeightInfo = ();
 }
 
+parameter_types! {
+	pub const MaxTitleLength: u32 = 256;
+	pub const MaxDescriptionLength: u32 = 1024;
+	pub const MaxRecordLinkLength: u32 = 512;
+	pub const MaxFilesPerRecord: u32 = 50;
+	pub const MaxRecordsPerOwner: u32 = 1_000;
+	pub const MaxPurposeLength: u32 = 256;
+	pub const MaxGranteesPerScope: u32 = 100;
+}
+
 impl electronic_medical_record::Config for Test {
 	type RuntimeEvent = RuntimeEvent;
 	type ElectronicMedicalRecord = ElectronicMedicalRecord;
 	type ElectronicMedicalRecordWeightInfo = ();
+	type MaxTitleLength = MaxTitleLength;
+	type MaxDescriptionLength = MaxDescriptionLength;
+	type MaxRecordLinkLength = MaxRecordLinkLength;
+	type MaxFilesPerRecord = MaxFilesPerRecord;
+	type MaxRecordsPerOwner = MaxRecordsPerOwner;
+	type MaxPurposeLength = MaxPurposeLength;
+	type MaxGranteesPerScope = MaxGranteesPerScope;
+}
+
+impl health_professional::Config for Test {
+	type RuntimeEvent = RuntimeEvent;
+	type Currency = Balances;
+	type HealthProfessionalQualifications = HealthProfessionalQualification;
+	type HealthProfessionalWeightInfo = ();
+}
+
+impl credentials::Config<credentials::Instance5> for Test {
+	type RuntimeEvent = RuntimeEvent;
+	type CredentialInfo = credentials::HealthProfessionalQualificationInfo;
+	type CredentialOwner =
+		credentials::legacy::LegacyHealthProfessionalQualificationOwner<HealthProfessional>;
+	type CredentialIssuers = ();
+	type WeightInfo = ();
 }
 
 impl opinion_requestor::Config for Test {
 	type RuntimeEvent = RuntimeEvent;
 	type ElectronicMedicalRecord = ElectronicMedicalRecord;
 	type OpinionRequestorWeightInfo = ();
+	type MaxInvitedProfessionals = frame_support::traits::ConstU32<10>;
+	type MaxRequestsPerOwner = frame_support::traits::ConstU32<100>;
+}
+
+parameter_types! {
+	pub const OpinionPalletId: PalletId = PalletId(*b"dbio/opn");
+	pub const OpinionPlatformFee: Perbill = Perbill::from_percent(5);
+	pub const OpinionPaymentTimeout: u64 = 1_000;
 }
 
 impl opinion::Config for Test {
@@ -143,5 +187,10 @@ impl opinion::Config for Test {
 	type Currency = Balances;
 	type Assets = Assets;
 	type OpinionRequestor = OpinionRequestor;
+	type HealthProfessionals = HealthProfessional;
 	type OpinionWeightInfo = ();
+	type PalletId = OpinionPalletId;
+	type PlatformFee = OpinionPlatformFee;
+	type PaymentTimeout = OpinionPaymentTimeout;
+	type MaxOpinionsPerOwner = frame_support::traits::ConstU32<100>;
 }
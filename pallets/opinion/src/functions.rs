@@ -1,9 +1,18 @@
 use crate::*;
-use frame_support::{codec::Encode, sp_runtime::traits::Hash, traits::fungibles};
+use frame_support::{
+	codec::Encode,
+	dispatch::DispatchError,
+	sp_runtime::{
+		traits::{AccountIdConversion, Hash, Zero},
+		SaturatedConversion,
+	},
+	traits::{fungibles, Currency, ExistenceRequirement, Get},
+};
 use primitives_price_and_currency::CurrencyType;
 use scale_info::prelude::string::String;
-use sp_std::vec::Vec;
-use traits_opinion::OpinionCountT;
+use traits_health_professional::HealthProfessionalProvider;
+use traits_opinion::{OpinionCountT, OpinionProvider};
+use traits_opinion_requestor::OpinionRequestorProvider;
 
 impl<T: Config> Pallet<T> {
 	pub fn generate_opinion_id(account_id: &T::AccountId, total_opinion: u64) -> T::Hash {
@@ -45,14 +54,328 @@ impl<T: Config> Pallet<T> {
 		Ok(())
 	}
 
-	pub fn add_opinion_id(account_id: &T::AccountId, opinion_id: &T::Hash) {
-		OpinionByOwner::<T>::mutate(account_id, |opinions: &mut Vec<T::Hash>| {
-			opinions.push(*opinion_id);
-		});
+	pub fn is_verified_health_professional(account_id: &T::AccountId) -> Result<(), Error<T>> {
+		if !T::HealthProfessionals::is_verified_and_staked(account_id) {
+			return Err(Error::<T>::NotVerifiedHealthProfessional)
+		}
+
+		Ok(())
+	}
+
+	/// The account ID that holds the escrowed payments
+	pub fn pallet_account_id() -> T::AccountId {
+		T::PalletId::get().into_account_truncating()
+	}
+
+	pub fn is_request_owner(
+		account_id: &T::AccountId,
+		requestor_id: &T::Hash,
+	) -> Result<(), Error<T>> {
+		let owner =
+			T::OpinionRequestor::requestor_owner(requestor_id).ok_or(Error::<T>::NotFound)?;
+
+		if &owner != account_id {
+			return Err(Error::<T>::Unauthorized)
+		}
+
+		Ok(())
+	}
+
+	pub fn is_requestor_owner(
+		account_id: &T::AccountId,
+		opinion: &OpinionOf<T>,
+	) -> Result<(), Error<T>> {
+		Self::is_request_owner(account_id, opinion.requestor_id())
+	}
+
+	/// Fails until `PaymentTimeout` has passed since `since`.
+	pub fn ensure_timeout_passed(since: MomentOf<T>) -> Result<(), Error<T>> {
+		let now = pallet_timestamp::Pallet::<T>::get().saturated_into::<u64>();
+		let expires_at = since.saturated_into::<u64>().saturating_add(T::PaymentTimeout::get());
+
+		if now < expires_at {
+			return Err(Error::<T>::PaymentNotYetExpired)
+		}
+
+		Ok(())
+	}
+
+	/// The escrow the opinion was delivered against.
+	pub fn delivered_escrow(
+		opinion_id: &T::Hash,
+		opinion: &OpinionOf<T>,
+	) -> Result<OpinionEscrowOf<T>, Error<T>> {
+		OpinionEscrows::<T>::get(opinion.requestor_id(), opinion.professional_id())
+			.filter(|escrow| escrow.opinion_id.as_ref() == Some(opinion_id))
+			.ok_or(Error::<T>::OpinionNotPaid)
+	}
+
+	/// Pay the escrowed payment to the professional, minus the platform fee, and mark the
+	/// opinion accepted.
+	pub fn do_release_escrow(
+		opinion_id: &T::Hash,
+		opinion: &mut OpinionOf<T>,
+		escrow: &OpinionEscrowOf<T>,
+	) -> Result<(u128, u128), Error<T>> {
+		let pallet_account_id = Self::pallet_account_id();
+
+		let fee = T::PlatformFee::get() * escrow.amount;
+		let professional_amount = escrow.amount.saturating_sub(fee);
+
+		if fee > 0 {
+			let treasury_key =
+				OpinionTreasuryKey::<T>::get().ok_or(Error::<T>::TreasuryKeyNotFound)?;
+
+			Self::do_transfer(
+				&escrow.currency,
+				&pallet_account_id,
+				&treasury_key,
+				fee,
+				escrow.asset_id,
+				false,
+			)?;
+		}
+
+		Self::do_transfer(
+			&escrow.currency,
+			&pallet_account_id,
+			opinion.professional_id(),
+			professional_amount,
+			escrow.asset_id,
+			false,
+		)?;
+
+		opinion.update_status(&Status::Accepted);
+
+		Opinions::<T>::insert(opinion_id, &*opinion);
+		OpinionEscrows::<T>::remove(opinion.requestor_id(), opinion.professional_id());
+
+		Ok((professional_amount, fee))
+	}
+
+	/// Send the escrowed payment back to the payer and mark the opinion refunded.
+	pub fn do_refund_escrow(
+		opinion_id: &T::Hash,
+		opinion: &mut OpinionOf<T>,
+		escrow: &OpinionEscrowOf<T>,
+	) -> Result<(), Error<T>> {
+		Self::do_return_escrow(opinion.requestor_id(), opinion.professional_id(), escrow)?;
+
+		opinion.update_status(&Status::Refunded);
+
+		Opinions::<T>::insert(opinion_id, &*opinion);
+
+		Ok(())
+	}
+
+	/// Send the escrowed payment back to the payer and drop the escrow.
+	pub fn do_return_escrow(
+		requestor_id: &T::Hash,
+		professional: &T::AccountId,
+		escrow: &OpinionEscrowOf<T>,
+	) -> Result<(), Error<T>> {
+		Self::do_transfer(
+			&escrow.currency,
+			&Self::pallet_account_id(),
+			&escrow.payer,
+			escrow.amount,
+			escrow.asset_id,
+			false,
+		)?;
+
+		OpinionEscrows::<T>::remove(requestor_id, professional);
+
+		Ok(())
+	}
+
+	/// Whether `sender` can transfer `amount`. Only keep alive transfers need to leave the
+	/// minimum balance behind, so the escrow account can pay out its last escrow.
+	pub fn do_balance_sufficient(
+		sender: &T::AccountId,
+		amount: u128,
+		asset_id: Option<u32>,
+		keep_alive: bool,
+	) -> Result<(), Error<T>> {
+		if let Some(asset_id) = asset_id {
+			let min_asset_balance = if keep_alive {
+				<T::Assets as fungibles::Inspect<T::AccountId>>::minimum_balance(asset_id)
+			} else {
+				0
+			};
+			let current_asset_balance =
+				<T::Assets as fungibles::Inspect<T::AccountId>>::balance(asset_id, sender);
+			let transferable_asset_balance =
+				current_asset_balance.saturating_sub(min_asset_balance);
+
+			if amount > transferable_asset_balance {
+				return Err(Error::<T>::InsufficientBalance)
+			}
+
+			return Ok(())
+		}
+
+		let minimum_balance =
+			if keep_alive { CurrencyOf::<T>::minimum_balance() } else { Zero::zero() };
+		let current_balance = CurrencyOf::<T>::free_balance(sender);
+		let transferable_balance = if current_balance >= minimum_balance {
+			current_balance - minimum_balance
+		} else {
+			Zero::zero()
+		};
+
+		if amount > transferable_balance.saturated_into::<u128>() {
+			return Err(Error::<T>::InsufficientBalance)
+		}
+
+		Ok(())
+	}
+
+	pub fn do_transfer(
+		currency: &CurrencyType,
+		sender: &T::AccountId,
+		receiver: &T::AccountId,
+		amount: u128,
+		asset_id: Option<u32>,
+		keep_alive: bool,
+	) -> Result<(), Error<T>> {
+		if amount.is_zero() {
+			return Ok(())
+		}
+
+		Self::do_balance_sufficient(sender, amount, asset_id, keep_alive)?;
+
+		let result = if currency == &CurrencyType::DBIO {
+			let existence = if keep_alive {
+				ExistenceRequirement::KeepAlive
+			} else {
+				ExistenceRequirement::AllowDeath
+			};
+
+			CurrencyOf::<T>::transfer(sender, receiver, amount.saturated_into(), existence)
+		} else {
+			let asset_id = asset_id.ok_or(Error::<T>::AssetIdNotFound)?;
+
+			<T::Assets as fungibles::Transfer<T::AccountId>>::transfer(
+				asset_id, sender, receiver, amount, keep_alive,
+			)
+			.map(|_| ())
+		};
+
+		if let Err(dispatch) = result {
+			return match dispatch {
+				DispatchError::Other(_) => Err(Error::<T>::Other),
+				DispatchError::CannotLookup => Err(Error::<T>::CannotLookup),
+				DispatchError::BadOrigin => Err(Error::<T>::BadOrigin),
+				DispatchError::TooManyConsumers => Err(Error::<T>::TooManyConsumers),
+				DispatchError::ConsumerRemaining => Err(Error::<T>::ConsumerRemaining),
+				DispatchError::NoProviders => Err(Error::<T>::NoProviders),
+				DispatchError::Token(_) => Err(Error::<T>::Token),
+				DispatchError::Arithmetic(_) => Err(Error::<T>::Arithmetic),
+				DispatchError::Module(_) => Err(Error::<T>::Module),
+				DispatchError::Transactional(_) => Err(Error::<T>::Arithmetic),
+			}
+		}
+
+		Ok(())
+	}
+
+	pub fn do_add_opinion(
+		requestor_id: &T::Hash,
+		professional: &T::AccountId,
+		info: &OpinionInfo,
+	) -> Result<OpinionOf<T>, Error<T>> {
+		let total_opinion = Self::opinion_count();
+		let now = pallet_timestamp::Pallet::<T>::get();
+		let opinion_id = Self::generate_opinion_id(professional, total_opinion);
+
+		let mut opinion = Opinion::new(&opinion_id, requestor_id, professional, info, now);
+
+		let asset_id = *info.asset_id();
+		let currency = info.currency();
+		let asset_id = Self::do_validate_asset_id(currency, asset_id)?;
+
+		opinion.update_asset_id(asset_id);
+
+		// An opinion submitted against a paid request is delivered right away
+		let mut escrow = OpinionEscrows::<T>::get(requestor_id, professional);
+
+		if let Some(escrow) = escrow.as_mut() {
+			if escrow.is_delivered() {
+				return Err(Error::<T>::OpinionAlreadyDelivered)
+			}
+
+			if &escrow.currency != currency ||
+				escrow.asset_id != asset_id ||
+				escrow.amount != info.amount()
+			{
+				return Err(Error::<T>::OpinionPriceMismatch)
+			}
+
+			escrow.deliver(opinion_id, now);
+			opinion.update_status(&Status::Paid);
+		}
+
+		Self::add_opinion_id(professional, &opinion_id)?;
+
+		Opinions::<T>::insert(opinion_id, &opinion);
+
+		if let Some(escrow) = escrow {
+			OpinionEscrows::<T>::insert(requestor_id, professional, &escrow);
+		}
+
+		T::OpinionRequestor::associate(requestor_id, &opinion_id);
+
+		Self::add_opinion_count(1);
+		Self::add_opinion_count_by_owner(professional, 1);
+
+		Ok(opinion)
+	}
+
+	pub fn do_update_opinion(
+		opinion_id: &T::Hash,
+		opinion: &mut OpinionOf<T>,
+		info: &OpinionInfo,
+	) -> Result<(), Error<T>> {
+		let asset_id = *info.asset_id();
+		let currency = info.currency();
+		let asset_id = Self::do_validate_asset_id(currency, asset_id)?;
+
+		opinion.update_info(info);
+		opinion.update_asset_id(asset_id);
+
+		Opinions::<T>::insert(opinion_id, &*opinion);
+
+		Ok(())
+	}
+
+	pub fn do_remove_opinion(opinion_id: &T::Hash, opinion: &OpinionOf<T>) -> Result<(), Error<T>> {
+		if opinion.status() == &Status::Paid {
+			return Err(Error::<T>::OpinionPaymentInEscrow)
+		}
+
+		let requestor_id = opinion.requestor_id();
+		let owner = opinion.professional_id();
+
+		Opinions::<T>::remove(opinion_id);
+		HiddenOpinions::<T>::remove(opinion_id);
+
+		T::OpinionRequestor::disassociate(requestor_id, opinion_id);
+
+		Self::substract_opinion_count(1);
+		Self::substract_opinion_count_by_owner(owner, 1);
+		Self::remove_opinion_id(owner, opinion_id);
+
+		Ok(())
+	}
+
+	pub fn add_opinion_id(account_id: &T::AccountId, opinion_id: &T::Hash) -> Result<(), Error<T>> {
+		OpinionByOwner::<T>::try_mutate(account_id, |opinions| {
+			opinions.try_push(*opinion_id).map_err(|_| Error::<T>::TooManyOwnedOpinions)
+		})
 	}
 
 	pub fn remove_opinion_id(account_id: &T::AccountId, opinion_id: &T::Hash) {
-		OpinionByOwner::<T>::mutate(account_id, |opinions: &mut Vec<T::Hash>| {
+		OpinionByOwner::<T>::mutate(account_id, |opinions| {
 			let position = opinions.iter().position(|x| x == opinion_id);
 
 			if let Some(index) = position {
@@ -90,3 +413,20 @@ impl<T: Config> OpinionCountT<T> for Pallet<T> {
 		});
 	}
 }
+
+impl<T: Config> OpinionProvider<T> for Pallet<T> {
+	fn paid_opinion_professional(
+		opinion_id: &T::Hash,
+		account_id: &T::AccountId,
+	) -> Option<T::AccountId> {
+		let opinion = Opinions::<T>::get(opinion_id)?;
+
+		if !matches!(opinion.status(), Status::Paid | Status::Accepted) {
+			return None
+		}
+
+		Self::is_requestor_owner(account_id, &opinion).ok()?;
+
+		Some(opinion.professional_id().clone())
+	}
+}
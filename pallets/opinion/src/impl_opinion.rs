@@ -1,111 +1,255 @@
 use crate::*;
-use traits_opinion::OpinionCountT;
+use primitives_price_and_currency::CurrencyType;
 use traits_opinion_requestor::OpinionRequestorProvider;
 
 impl<T: Config> OpinionInterface<T> for Pallet<T> {
 	type Error = Error<T>;
 	type Opinion = OpinionOf<T>;
 	type OpinionInfo = OpinionInfo;
+	type OpinionEscrow = OpinionEscrowOf<T>;
 
-	fn add_opinion(
-		admin: &T::AccountId,
-		requestor_id: &T::Hash,
-		account_id: &T::AccountId,
-		info: &OpinionInfo,
-	) -> Result<Self::Opinion, Self::Error> {
+	fn hide_opinion(admin: &T::AccountId, opinion_id: &T::Hash) -> Result<(), Self::Error> {
 		Self::is_admin(admin)?;
 
-		if !T::OpinionRequestor::can_give_opinion(requestor_id) {
+		if !Opinions::<T>::contains_key(opinion_id) {
 			return Err(Error::<T>::NotFound)
 		}
 
-		let total_opinion = Self::opinion_count();
-		let now = pallet_timestamp::Pallet::<T>::get();
-		let opinion_id = Self::generate_opinion_id(admin, total_opinion);
+		if HiddenOpinions::<T>::contains_key(opinion_id) {
+			return Err(Error::<T>::OpinionHidden)
+		}
 
-		let mut opinion = Opinion::new(&opinion_id, requestor_id, account_id, info, now);
+		HiddenOpinions::<T>::insert(opinion_id, ());
 
-		let asset_id = *info.asset_id();
-		let currency = info.currency();
-		let asset_id = Self::do_validate_asset_id(currency, asset_id)?;
+		Ok(())
+	}
 
-		opinion.update_asset_id(asset_id);
+	fn unhide_opinion(admin: &T::AccountId, opinion_id: &T::Hash) -> Result<(), Self::Error> {
+		Self::is_admin(admin)?;
 
-		Opinions::<T>::insert(opinion_id, &opinion);
+		if !HiddenOpinions::<T>::contains_key(opinion_id) {
+			return Err(Error::<T>::OpinionNotHidden)
+		}
 
-		T::OpinionRequestor::associate(requestor_id, &opinion_id);
+		HiddenOpinions::<T>::remove(opinion_id);
 
-		Self::add_opinion_count(1);
-		Self::add_opinion_count_by_owner(account_id, 1);
-		Self::add_opinion_id(account_id, &opinion_id);
+		Ok(())
+	}
 
-		Ok(opinion)
+	fn remove_opinion(account_id: &T::AccountId, opinion_id: &T::Hash) -> Result<(), Self::Error> {
+		Self::is_admin(account_id)?;
+
+		let opinion = Opinions::<T>::get(opinion_id).ok_or(Error::<T>::NotFound)?;
+
+		Self::do_remove_opinion(opinion_id, &opinion)
 	}
 
-	fn update_opinion(
+	fn update_status(
 		account_id: &T::AccountId,
 		opinion_id: &T::Hash,
-		info: &OpinionInfo,
-	) -> Result<Self::Opinion, Self::Error> {
+		status: &Status,
+	) -> Result<(), Self::Error> {
 		Self::is_admin(account_id)?;
 
 		let mut opinion = Opinions::<T>::get(opinion_id).ok_or(Error::<T>::NotFound)?;
 
-		let asset_id = *info.asset_id();
-		let currency = info.currency();
-		let asset_id = Self::do_validate_asset_id(currency, asset_id)?;
+		// Only the requestor pays, the admin can settle the escrow either way
+		match status {
+			Status::Accepted => {
+				let escrow = Self::delivered_escrow(opinion_id, &opinion)?;
 
-		opinion.update_info(info);
-		opinion.update_asset_id(asset_id);
+				Self::do_release_escrow(opinion_id, &mut opinion, &escrow)?;
+			},
+			Status::Refunded => {
+				let escrow = Self::delivered_escrow(opinion_id, &opinion)?;
 
-		Opinions::<T>::insert(opinion_id, &opinion);
+				Self::do_refund_escrow(opinion_id, &mut opinion, &escrow)?;
+			},
+			Status::Unpaid | Status::Paid => return Err(Error::<T>::InvalidStatus),
+		}
 
-		Ok(opinion)
+		Ok(())
 	}
 
-	fn remove_opinion(account_id: &T::AccountId, opinion_id: &T::Hash) -> Result<(), Self::Error> {
-		Self::is_admin(account_id)?;
+	fn update_admin_key(
+		admin: &T::AccountId,
+		account_id: &T::AccountId,
+	) -> Result<(), Self::Error> {
+		Self::is_admin(admin)?;
 
-		let opinion = Opinions::<T>::get(opinion_id).ok_or(Error::<T>::NotFound)?;
+		OpinionAdminKey::<T>::put(account_id);
+
+		Ok(())
+	}
+
+	fn pay_opinion(
+		account_id: &T::AccountId,
+		requestor_id: &T::Hash,
+		professional: &T::AccountId,
+		currency: &CurrencyType,
+		asset_id: Option<u32>,
+		amount: u128,
+	) -> Result<Self::OpinionEscrow, Self::Error> {
+		Self::is_request_owner(account_id, requestor_id)?;
+
+		if !T::OpinionRequestor::is_professional_invited(requestor_id, professional) {
+			return Err(Error::<T>::NotInvited)
+		}
 
-		let requestor_id = opinion.requestor_id();
-		let owner = opinion.professional_id();
+		if OpinionEscrows::<T>::contains_key(requestor_id, professional) {
+			return Err(Error::<T>::OpinionAlreadyPaid)
+		}
 
-		Opinions::<T>::remove(opinion_id);
+		let asset_id = Self::do_validate_asset_id(currency, asset_id)?;
 
-		T::OpinionRequestor::disassociate(requestor_id, opinion_id);
+		Self::do_transfer(
+			currency,
+			account_id,
+			&Self::pallet_account_id(),
+			amount,
+			asset_id,
+			true,
+		)?;
 
-		Self::substract_opinion_count(1);
-		Self::substract_opinion_count_by_owner(owner, 1);
-		Self::remove_opinion_id(owner, opinion_id);
+		let now = pallet_timestamp::Pallet::<T>::get();
+		let escrow =
+			OpinionEscrow::new(account_id.clone(), currency.clone(), asset_id, amount, now);
 
-		Ok(())
+		OpinionEscrows::<T>::insert(requestor_id, professional, &escrow);
+
+		Ok(escrow)
 	}
 
-	fn update_status(
+	fn accept_opinion(
 		account_id: &T::AccountId,
 		opinion_id: &T::Hash,
-		status: &Status,
-	) -> Result<(), Self::Error> {
-		Self::is_admin(account_id)?;
-
+	) -> Result<(u128, u128), Self::Error> {
 		let mut opinion = Opinions::<T>::get(opinion_id).ok_or(Error::<T>::NotFound)?;
 
-		opinion.update_status(status);
+		Self::is_requestor_owner(account_id, &opinion)?;
 
-		Opinions::<T>::insert(opinion_id, opinion);
+		let escrow = Self::delivered_escrow(opinion_id, &opinion)?;
 
-		Ok(())
+		Self::do_release_escrow(opinion_id, &mut opinion, &escrow)
 	}
 
-	fn update_admin_key(
-		admin: &T::AccountId,
+	fn refund_opinion(
 		account_id: &T::AccountId,
-	) -> Result<(), Self::Error> {
+		requestor_id: &T::Hash,
+		professional: &T::AccountId,
+	) -> Result<Self::OpinionEscrow, Self::Error> {
+		Self::is_request_owner(account_id, requestor_id)?;
+
+		let escrow = OpinionEscrows::<T>::get(requestor_id, professional)
+			.ok_or(Error::<T>::OpinionNotPaid)?;
+
+		// Once delivered the requestor has read the opinion, so only the admin can refund it
+		if escrow.is_delivered() {
+			return Err(Error::<T>::OpinionAlreadyDelivered)
+		}
+
+		Self::ensure_timeout_passed(escrow.paid_at)?;
+		Self::do_return_escrow(requestor_id, professional, &escrow)?;
+
+		Ok(escrow)
+	}
+
+	fn claim_opinion(
+		professional: &T::AccountId,
+		opinion_id: &T::Hash,
+	) -> Result<(u128, u128), Self::Error> {
+		let mut opinion = Opinions::<T>::get(opinion_id)
+			.ok_or(Error::<T>::NotFound)?
+			.is_authorized_owner(professional)
+			.ok_or(Error::<T>::Unauthorized)?;
+
+		// Hidden opinions wait for the admin to settle them
+		if HiddenOpinions::<T>::contains_key(opinion_id) {
+			return Err(Error::<T>::OpinionHidden)
+		}
+
+		let escrow = Self::delivered_escrow(opinion_id, &opinion)?;
+		let delivered_at = escrow.delivered_at.ok_or(Error::<T>::OpinionNotPaid)?;
+
+		Self::ensure_timeout_passed(delivered_at)?;
+		Self::do_release_escrow(opinion_id, &mut opinion, &escrow)
+	}
+
+	fn reject_opinion(
+		admin: &T::AccountId,
+		opinion_id: &T::Hash,
+	) -> Result<Self::OpinionEscrow, Self::Error> {
 		Self::is_admin(admin)?;
 
-		OpinionAdminKey::<T>::put(account_id);
+		let mut opinion = Opinions::<T>::get(opinion_id).ok_or(Error::<T>::NotFound)?;
+		let escrow = Self::delivered_escrow(opinion_id, &opinion)?;
 
-		Ok(())
+		Self::do_refund_escrow(opinion_id, &mut opinion, &escrow)?;
+
+		Ok(escrow)
+	}
+
+	fn submit_opinion(
+		professional: &T::AccountId,
+		requestor_id: &T::Hash,
+		info: &OpinionInfo,
+	) -> Result<Self::Opinion, Self::Error> {
+		Self::is_verified_health_professional(professional)?;
+
+		if T::OpinionRequestor::requestor_owner(requestor_id).is_none() {
+			return Err(Error::<T>::NotFound)
+		}
+
+		if !T::OpinionRequestor::is_professional_invited(requestor_id, professional) {
+			return Err(Error::<T>::NotInvited)
+		}
+
+		if !T::OpinionRequestor::has_accepted_invitation(requestor_id, professional) {
+			return Err(Error::<T>::InvitationNotAccepted)
+		}
+
+		if !T::OpinionRequestor::can_give_opinion(requestor_id) {
+			return Err(Error::<T>::TooManyOpinions)
+		}
+
+		Self::do_add_opinion(requestor_id, professional, info)
+	}
+
+	fn edit_opinion(
+		professional: &T::AccountId,
+		opinion_id: &T::Hash,
+		info: &OpinionInfo,
+	) -> Result<Self::Opinion, Self::Error> {
+		Self::is_verified_health_professional(professional)?;
+
+		let mut opinion = Opinions::<T>::get(opinion_id)
+			.ok_or(Error::<T>::NotFound)?
+			.is_authorized_owner(professional)
+			.ok_or(Error::<T>::Unauthorized)?;
+
+		if opinion.status() != &Status::Unpaid {
+			return Err(Error::<T>::OpinionAlreadyPaid)
+		}
+
+		Self::do_update_opinion(opinion_id, &mut opinion, info)?;
+
+		Ok(opinion)
+	}
+
+	fn withdraw_opinion(
+		professional: &T::AccountId,
+		opinion_id: &T::Hash,
+	) -> Result<(), Self::Error> {
+		let opinion = Opinions::<T>::get(opinion_id)
+			.ok_or(Error::<T>::NotFound)?
+			.is_authorized_owner(professional)
+			.ok_or(Error::<T>::Unauthorized)?;
+
+		// Paid, accepted and refunded opinions are part of the payment history
+		if opinion.status() != &Status::Unpaid {
+			return Err(Error::<T>::OpinionAlreadyPaid)
+		}
+
+		Self::do_remove_opinion(opinion_id, &opinion)
 	}
 }
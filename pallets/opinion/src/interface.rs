@@ -1,22 +1,15 @@
 use crate::Status;
+use primitives_price_and_currency::CurrencyType;
 
 pub trait OpinionInterface<T: frame_system::Config> {
 	type Error;
 	type Opinion;
 	type OpinionInfo;
+	type OpinionEscrow;
 
-	fn add_opinion(
-		admin: &T::AccountId,
-		requestor_id: &T::Hash,
-		account_id: &T::AccountId,
-		info: &Self::OpinionInfo,
-	) -> Result<Self::Opinion, Self::Error>;
+	fn hide_opinion(admin: &T::AccountId, opinion_id: &T::Hash) -> Result<(), Self::Error>;
 
-	fn update_opinion(
-		account_id: &T::AccountId,
-		opinion_id: &T::Hash,
-		info: &Self::OpinionInfo,
-	) -> Result<Self::Opinion, Self::Error>;
+	fn unhide_opinion(admin: &T::AccountId, opinion_id: &T::Hash) -> Result<(), Self::Error>;
 
 	fn remove_opinion(account_id: &T::AccountId, opinion_id: &T::Hash) -> Result<(), Self::Error>;
 
@@ -28,4 +21,56 @@ pub trait OpinionInterface<T: frame_system::Config> {
 
 	fn update_admin_key(admin: &T::AccountId, account_id: &T::AccountId)
 		-> Result<(), Self::Error>;
+
+	/// Escrows the payment for an invited professional before the opinion is delivered.
+	fn pay_opinion(
+		account_id: &T::AccountId,
+		requestor_id: &T::Hash,
+		professional: &T::AccountId,
+		currency: &CurrencyType,
+		asset_id: Option<u32>,
+		amount: u128,
+	) -> Result<Self::OpinionEscrow, Self::Error>;
+
+	/// Returns the amounts sent to the professional and to the treasury.
+	fn accept_opinion(
+		account_id: &T::AccountId,
+		opinion_id: &T::Hash,
+	) -> Result<(u128, u128), Self::Error>;
+
+	/// Returns the payment for a professional who did not deliver an opinion in time.
+	fn refund_opinion(
+		account_id: &T::AccountId,
+		requestor_id: &T::Hash,
+		professional: &T::AccountId,
+	) -> Result<Self::OpinionEscrow, Self::Error>;
+
+	/// Releases the payment of a delivered opinion the requestor did not accept in time.
+	/// Returns the amounts sent to the professional and to the treasury.
+	fn claim_opinion(
+		professional: &T::AccountId,
+		opinion_id: &T::Hash,
+	) -> Result<(u128, u128), Self::Error>;
+
+	fn reject_opinion(
+		admin: &T::AccountId,
+		opinion_id: &T::Hash,
+	) -> Result<Self::OpinionEscrow, Self::Error>;
+
+	fn submit_opinion(
+		professional: &T::AccountId,
+		requestor_id: &T::Hash,
+		info: &Self::OpinionInfo,
+	) -> Result<Self::Opinion, Self::Error>;
+
+	fn edit_opinion(
+		professional: &T::AccountId,
+		opinion_id: &T::Hash,
+		info: &Self::OpinionInfo,
+	) -> Result<Self::Opinion, Self::Error>;
+
+	fn withdraw_opinion(
+		professional: &T::AccountId,
+		opinion_id: &T::Hash,
+	) -> Result<(), Self::Error>;
 }
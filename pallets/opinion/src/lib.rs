@@ -5,6 +5,7 @@ pub use pallet::*;
 pub mod functions;
 pub mod impl_opinion;
 pub mod interface;
+pub mod migrations;
 pub mod types;
 pub mod weights;
 
@@ -15,7 +16,7 @@ use interface::OpinionInterface;
 use weights::WeightInfo;
 
 /// The current storage version.
-const STORAGE_VERSION: StorageVersion = StorageVersion::new(0);
+const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
 
 #[frame_support::pallet]
 pub mod pallet {
@@ -23,37 +24,66 @@ pub mod pallet {
 
 	use frame_support::{
 		pallet_prelude::*,
+		sp_runtime::Perbill,
 		traits::{fungibles, Currency},
+		PalletId,
 	};
 	use frame_system::pallet_prelude::*;
-	use sp_std::vec::Vec;
+	use primitives_price_and_currency::CurrencyType;
+	use traits_health_professional::HealthProfessionalProvider;
 	use traits_opinion_requestor::OpinionRequestorProvider;
 
 	pub type AccountIdOf<T> = <T as frame_system::Config>::AccountId;
 	pub type HashOf<T> = <T as frame_system::Config>::Hash;
 	pub type MomentOf<T> = <T as pallet_timestamp::Config>::Moment;
 	pub type OpinionOf<T> = Opinion<AccountIdOf<T>, HashOf<T>, MomentOf<T>>;
+	pub type OpinionEscrowOf<T> = OpinionEscrow<AccountIdOf<T>, HashOf<T>, MomentOf<T>>;
+	pub type OpinionIdsOf<T> = BoundedVec<HashOf<T>, <T as Config>::MaxOpinionsPerOwner>;
+	pub type CurrencyOf<T> = <T as self::Config>::Currency;
+	pub type BalanceOf<T> = <CurrencyOf<T> as Currency<AccountIdOf<T>>>::Balance;
 
 	#[pallet::pallet]
 	#[pallet::storage_version(STORAGE_VERSION)]
 	#[pallet::generate_store(pub(super) trait Store)]
-	#[pallet::without_storage_info]
 	pub struct Pallet<T>(_);
 
 	#[pallet::hooks]
-	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {}
+	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+		fn on_runtime_upgrade() -> Weight {
+			migrations::migrate::<T>()
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn pre_upgrade() -> Result<sp_std::vec::Vec<u8>, &'static str> {
+			migrations::pre_upgrade::<T>()
+		}
+	}
 
 	#[pallet::config]
 	pub trait Config: frame_system::Config + pallet_timestamp::Config {
 		type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
 		type Currency: Currency<<Self as frame_system::Config>::AccountId>;
 		type Assets: fungibles::InspectMetadata<
-			<Self as frame_system::Config>::AccountId,
-			AssetId = u32,
-			Balance = u128,
-		>;
+				<Self as frame_system::Config>::AccountId,
+				AssetId = u32,
+				Balance = u128,
+			> + fungibles::Transfer<<Self as frame_system::Config>::AccountId>;
 		type OpinionRequestor: OpinionRequestorProvider<Self>;
+		type HealthProfessionals: HealthProfessionalProvider<Self>;
 		type OpinionWeightInfo: WeightInfo;
+		/// Account that holds requestor payments in escrow.
+		#[pallet::constant]
+		type PalletId: Get<PalletId>;
+		/// Share of an accepted opinion payment sent to the treasury.
+		#[pallet::constant]
+		type PlatformFee: Get<Perbill>;
+		/// Time in milliseconds after payment before the requestor can refund an undelivered
+		/// opinion, and after delivery before the professional can claim an unaccepted one.
+		#[pallet::constant]
+		type PaymentTimeout: Get<u64>;
+		/// Maximum number of opinions indexed per health professional.
+		#[pallet::constant]
+		type MaxOpinionsPerOwner: Get<u32>;
 	}
 
 	#[pallet::storage]
@@ -63,7 +93,7 @@ pub mod pallet {
 	#[pallet::storage]
 	#[pallet::getter(fn opinion_by_owner)]
 	pub type OpinionByOwner<T> =
-		StorageMap<_, Blake2_128Concat, AccountIdOf<T>, Vec<HashOf<T>>, ValueQuery>;
+		StorageMap<_, Blake2_128Concat, AccountIdOf<T>, OpinionIdsOf<T>, ValueQuery>;
 
 	#[pallet::storage]
 	#[pallet::getter(fn opinion_count)]
@@ -78,6 +108,28 @@ pub mod pallet {
 	#[pallet::getter(fn admin_key)]
 	pub type OpinionAdminKey<T: Config> = StorageValue<_, T::AccountId, OptionQuery>;
 
+	#[pallet::storage]
+	#[pallet::getter(fn treasury_key)]
+	pub type OpinionTreasuryKey<T: Config> = StorageValue<_, T::AccountId, OptionQuery>;
+
+	/// Payments escrowed for an invited professional
+	/// (RequestorId, Professional) => Escrow
+	#[pallet::storage]
+	#[pallet::getter(fn escrow_by_request)]
+	pub type OpinionEscrows<T> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		HashOf<T>,
+		Blake2_128Concat,
+		AccountIdOf<T>,
+		OpinionEscrowOf<T>,
+	>;
+
+	/// Opinions hidden by the admin, they stay on chain but their payment can't be claimed.
+	#[pallet::storage]
+	#[pallet::getter(fn hidden_opinion)]
+	pub type HiddenOpinions<T> = StorageMap<_, Blake2_128Concat, HashOf<T>, ()>;
+
 	#[pallet::event]
 	#[pallet::generate_deposit(pub(super) fn deposit_event)]
 	pub enum Event<T: Config> {
@@ -86,17 +138,33 @@ pub mod pallet {
 		OpinionRemoved(AccountIdOf<T>, HashOf<T>),
 		OpinionStatusUpdated(AccountIdOf<T>, HashOf<T>, Status),
 		AdminKeyUpdated(AccountIdOf<T>),
+		TreasuryKeyUpdated(AccountIdOf<T>),
+		/// parameters, [who, requestor_id, professional, amount]
+		OpinionPaid(AccountIdOf<T>, HashOf<T>, AccountIdOf<T>, u128),
+		/// parameters, [who, opinion_id, professional_amount, fee]
+		OpinionAccepted(AccountIdOf<T>, HashOf<T>, u128, u128),
+		/// parameters, [who, requestor_id, professional, amount]
+		OpinionRefunded(AccountIdOf<T>, HashOf<T>, AccountIdOf<T>, u128),
+		/// parameters, [admin, opinion_id, amount]
+		OpinionRejected(AccountIdOf<T>, HashOf<T>, u128),
+		/// parameters, [admin, opinion_id]
+		OpinionHidden(AccountIdOf<T>, HashOf<T>),
+		/// parameters, [admin, opinion_id]
+		OpinionUnhidden(AccountIdOf<T>, HashOf<T>),
+		/// parameters, [professional, opinion_id, professional_amount, fee]
+		OpinionPaymentClaimed(AccountIdOf<T>, HashOf<T>, u128, u128),
 	}
 
 	#[pallet::genesis_config]
 	pub struct GenesisConfig<T: Config> {
 		pub admin_key: Option<T::AccountId>,
+		pub treasury_key: Option<T::AccountId>,
 	}
 
 	#[cfg(feature = "std")]
 	impl<T: Config> Default for GenesisConfig<T> {
 		fn default() -> Self {
-			Self { admin_key: None }
+			Self { admin_key: None, treasury_key: None }
 		}
 	}
 
@@ -106,6 +174,10 @@ pub mod pallet {
 			if let Some(ref admin_key) = self.admin_key {
 				OpinionAdminKey::<T>::put(admin_key);
 			}
+
+			if let Some(ref treasury_key) = self.treasury_key {
+				OpinionTreasuryKey::<T>::put(treasury_key);
+			}
 		}
 	}
 
@@ -113,44 +185,71 @@ pub mod pallet {
 	pub enum Error<T> {
 		NotFound,
 		Unauthorized,
+		/// Opinion has already been paid for
+		OpinionAlreadyPaid,
+		/// Opinion has no payment in escrow
+		OpinionNotPaid,
+		/// Opinion payment is still in escrow
+		OpinionPaymentInEscrow,
+		/// Refund or claim not allowed, payment timeout has not passed yet
+		PaymentNotYetExpired,
+		/// Opinion was delivered against the payment, only the admin can refund it
+		OpinionAlreadyDelivered,
+		/// Opinion currency or amount differs from the escrowed payment
+		OpinionPriceMismatch,
+		/// Opinions are only paid by the requestor, the admin can only accept or refund them
+		InvalidStatus,
+		/// Caller is not a verified and staked health professional
+		NotVerifiedHealthProfessional,
+		/// Health professional was not invited by the requestor
+		NotInvited,
+		/// Health professional has not accepted the requestor's invitation yet
+		InvitationNotAccepted,
+		/// The request already has MAX_OPINIONS_PER_REQUEST opinions
+		TooManyOpinions,
+		/// Health professional already has MaxOpinionsPerOwner opinions
+		TooManyOwnedOpinions,
+		/// Opinion is hidden by the admin
+		OpinionHidden,
+		/// Opinion is not hidden
+		OpinionNotHidden,
+		AssetIdNotFound,
+		TreasuryKeyNotFound,
+		InsufficientBalance,
+		// Transfer errors
+		Other,
+		CannotLookup,
+		BadOrigin,
+		TooManyConsumers,
+		ConsumerRemaining,
+		NoProviders,
+		Token,
+		Arithmetic,
+		Module,
 	}
 
 	#[pallet::call]
 	impl<T: Config> Pallet<T> {
-		#[pallet::weight(T::OpinionWeightInfo::create())]
-		pub fn create(
-			origin: OriginFor<T>,
-			requestor_id: HashOf<T>,
-			account_id: AccountIdOf<T>,
-			info: OpinionInfo,
-		) -> DispatchResultWithPostInfo {
+		#[pallet::weight(T::OpinionWeightInfo::hide())]
+		pub fn hide(origin: OriginFor<T>, opinion_id: HashOf<T>) -> DispatchResultWithPostInfo {
 			let who = ensure_signed(origin)?;
 
-			match <Self as OpinionInterface<T>>::add_opinion(
-				&who,
-				&requestor_id,
-				&account_id,
-				&info,
-			) {
-				Ok(opinion) => {
-					Self::deposit_event(Event::OpinionAdded(who, opinion));
+			match <Self as OpinionInterface<T>>::hide_opinion(&who, &opinion_id) {
+				Ok(()) => {
+					Self::deposit_event(Event::OpinionHidden(who, opinion_id));
 					Ok(().into())
 				},
 				Err(error) => Err(error.into()),
 			}
 		}
 
-		#[pallet::weight(T::OpinionWeightInfo::update())]
-		pub fn update(
-			origin: OriginFor<T>,
-			opinion_id: HashOf<T>,
-			info: OpinionInfo,
-		) -> DispatchResultWithPostInfo {
+		#[pallet::weight(T::OpinionWeightInfo::unhide())]
+		pub fn unhide(origin: OriginFor<T>, opinion_id: HashOf<T>) -> DispatchResultWithPostInfo {
 			let who = ensure_signed(origin)?;
 
-			match <Self as OpinionInterface<T>>::update_opinion(&who, &opinion_id, &info) {
-				Ok(opinion) => {
-					Self::deposit_event(Event::OpinionUpdated(who, opinion));
+			match <Self as OpinionInterface<T>>::unhide_opinion(&who, &opinion_id) {
+				Ok(()) => {
+					Self::deposit_event(Event::OpinionUnhidden(who, opinion_id));
 					Ok(().into())
 				},
 				Err(error) => Err(error.into()),
@@ -203,6 +302,153 @@ pub mod pallet {
 			}
 		}
 
+		#[pallet::weight(T::OpinionWeightInfo::submit())]
+		pub fn submit(
+			origin: OriginFor<T>,
+			requestor_id: HashOf<T>,
+			info: OpinionInfo,
+		) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+
+			match <Self as OpinionInterface<T>>::submit_opinion(&who, &requestor_id, &info) {
+				Ok(opinion) => {
+					Self::deposit_event(Event::OpinionAdded(who, opinion));
+					Ok(().into())
+				},
+				Err(error) => Err(error.into()),
+			}
+		}
+
+		#[pallet::weight(T::OpinionWeightInfo::edit())]
+		pub fn edit(
+			origin: OriginFor<T>,
+			opinion_id: HashOf<T>,
+			info: OpinionInfo,
+		) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+
+			match <Self as OpinionInterface<T>>::edit_opinion(&who, &opinion_id, &info) {
+				Ok(opinion) => {
+					Self::deposit_event(Event::OpinionUpdated(who, opinion));
+					Ok(().into())
+				},
+				Err(error) => Err(error.into()),
+			}
+		}
+
+		#[pallet::weight(T::OpinionWeightInfo::withdraw())]
+		pub fn withdraw(origin: OriginFor<T>, opinion_id: HashOf<T>) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+
+			match <Self as OpinionInterface<T>>::withdraw_opinion(&who, &opinion_id) {
+				Ok(()) => {
+					Self::deposit_event(Event::OpinionRemoved(who, opinion_id));
+					Ok(().into())
+				},
+				Err(error) => Err(error.into()),
+			}
+		}
+
+		#[pallet::weight(T::OpinionWeightInfo::pay())]
+		pub fn pay(
+			origin: OriginFor<T>,
+			requestor_id: HashOf<T>,
+			professional: AccountIdOf<T>,
+			currency: CurrencyType,
+			asset_id: Option<u32>,
+			amount: u128,
+		) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+
+			match <Self as OpinionInterface<T>>::pay_opinion(
+				&who,
+				&requestor_id,
+				&professional,
+				&currency,
+				asset_id,
+				amount,
+			) {
+				Ok(escrow) => {
+					Self::deposit_event(Event::OpinionPaid(
+						who,
+						requestor_id,
+						professional,
+						escrow.amount,
+					));
+					Ok(().into())
+				},
+				Err(error) => Err(error.into()),
+			}
+		}
+
+		#[pallet::weight(T::OpinionWeightInfo::accept())]
+		pub fn accept(origin: OriginFor<T>, opinion_id: HashOf<T>) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+
+			match <Self as OpinionInterface<T>>::accept_opinion(&who, &opinion_id) {
+				Ok((professional_amount, fee)) => {
+					Self::deposit_event(Event::OpinionAccepted(
+						who,
+						opinion_id,
+						professional_amount,
+						fee,
+					));
+					Ok(().into())
+				},
+				Err(error) => Err(error.into()),
+			}
+		}
+
+		#[pallet::weight(T::OpinionWeightInfo::refund())]
+		pub fn refund(
+			origin: OriginFor<T>,
+			requestor_id: HashOf<T>,
+			professional: AccountIdOf<T>,
+		) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+
+			match <Self as OpinionInterface<T>>::refund_opinion(&who, &requestor_id, &professional)
+			{
+				Ok(escrow) => {
+					Self::deposit_event(Event::OpinionRefunded(
+						who,
+						requestor_id,
+						professional,
+						escrow.amount,
+					));
+					Ok(().into())
+				},
+				Err(error) => Err(error.into()),
+			}
+		}
+
+		#[pallet::weight(T::OpinionWeightInfo::reject())]
+		pub fn reject(origin: OriginFor<T>, opinion_id: HashOf<T>) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+
+			match <Self as OpinionInterface<T>>::reject_opinion(&who, &opinion_id) {
+				Ok(escrow) => {
+					Self::deposit_event(Event::OpinionRejected(who, opinion_id, escrow.amount));
+					Ok(().into())
+				},
+				Err(error) => Err(error.into()),
+			}
+		}
+
+		#[pallet::weight(0)]
+		pub fn sudo_update_treasury_key(
+			origin: OriginFor<T>,
+			account_id: T::AccountId,
+		) -> DispatchResultWithPostInfo {
+			ensure_root(origin)?;
+
+			OpinionTreasuryKey::<T>::put(&account_id);
+
+			Self::deposit_event(Event::TreasuryKeyUpdated(account_id));
+
+			Ok(Pays::No.into())
+		}
+
 		#[pallet::weight(0)]
 		pub fn sudo_update_admin_key(
 			origin: OriginFor<T>,
@@ -216,5 +462,23 @@ pub mod pallet {
 
 			Ok(Pays::No.into())
 		}
+
+		#[pallet::weight(T::OpinionWeightInfo::claim())]
+		pub fn claim(origin: OriginFor<T>, opinion_id: HashOf<T>) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+
+			match <Self as OpinionInterface<T>>::claim_opinion(&who, &opinion_id) {
+				Ok((professional_amount, fee)) => {
+					Self::deposit_event(Event::OpinionPaymentClaimed(
+						who,
+						opinion_id,
+						professional_amount,
+						fee,
+					));
+					Ok(().into())
+				},
+				Err(error) => Err(error.into()),
+			}
+		}
 	}
 }
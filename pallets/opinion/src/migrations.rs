@@ -0,0 +1,142 @@
+use crate::*;
+use frame_support::{
+	log,
+	pallet_prelude::{Decode, Encode},
+	storage::migration::storage_key_iter,
+	traits::{Get, PalletInfoAccess},
+	weights::Weight,
+	Blake2_128Concat, BoundedVec,
+};
+use primitives_price_and_currency::CurrencyType;
+use sp_std::vec::Vec;
+
+/// Fails when a legacy opinion index does not fit in `MaxOpinionsPerOwner`
+#[cfg(feature = "try-runtime")]
+pub fn pre_upgrade<T: Config>() -> Result<Vec<u8>, &'static str> {
+	let max_opinions = T::MaxOpinionsPerOwner::get() as usize;
+
+	if StorageVersion::get::<Pallet<T>>() < 1 &&
+		version::v1::opinion_indexes::<T>()
+			.any(|(_, opinion_ids)| opinion_ids.len() > max_opinions)
+	{
+		return Err("Opinion index holds more ids than MaxOpinionsPerOwner")
+	}
+
+	Ok(Vec::new())
+}
+
+pub fn migrate<T: Config>() -> Weight {
+	let mut weight: Weight = Weight::zero();
+	let mut version = StorageVersion::get::<Pallet<T>>();
+
+	if version < 1 {
+		weight = weight.saturating_add(version::v1::migrate::<T>());
+		version = StorageVersion::new(1);
+	}
+
+	version.put::<Pallet<T>>();
+	weight
+}
+
+mod version {
+	use super::*;
+
+	pub mod v1 {
+		use super::*;
+
+		#[derive(Encode, Decode)]
+		pub struct OldOpinionInfo {
+			pub description: Vec<u8>,
+			pub myriad_url: Vec<u8>,
+			pub asset_id: Option<u32>,
+			pub currency: CurrencyType,
+			pub amount: u128,
+		}
+
+		#[derive(Encode, Decode)]
+		pub struct OldOpinion<AccountId, Hash, Moment> {
+			pub id: Hash,
+			pub requestor_id: Hash,
+			pub professional_id: AccountId,
+			pub info: OldOpinionInfo,
+			pub status: Status,
+			pub created_at: Moment,
+		}
+
+		/// Truncates `value` to the bound and logs the entry when it did not fit.
+		fn bounded<S: Get<u32>>(value: Vec<u8>, entry: &str) -> BoundedVec<u8, S> {
+			if value.len() > S::get() as usize {
+				log::warn!(
+					target: "runtime::opinion",
+					"{} exceeds its bound of {}, truncated from {} bytes",
+					entry,
+					S::get(),
+					value.len(),
+				);
+			}
+
+			BoundedVec::truncate_from(value)
+		}
+
+		/// Opinion descriptions and myriad urls become bounded, legacy values that exceed a
+		/// bound are truncated and logged. Opinion id indexes are bounded by
+		/// `MaxOpinionsPerOwner` and encode like the lists they replace, so they are only
+		/// checked. An index holding more than `MaxOpinionsPerOwner` ids is left as it is and
+		/// reported instead of dropping ids, raise `MaxOpinionsPerOwner` above it before
+		/// upgrading.
+		pub fn migrate<T: Config>() -> Weight {
+			let mut weight = T::DbWeight::get().writes(1);
+
+			Opinions::<T>::translate(
+				|_key, old: OldOpinion<AccountIdOf<T>, HashOf<T>, MomentOf<T>>| {
+					weight = weight.saturating_add(T::DbWeight::get().reads_writes(1, 1));
+
+					let info = OpinionInfo::new(
+						bounded(old.info.description, "Opinion description"),
+						bounded(old.info.myriad_url, "Opinion myriad url"),
+						old.info.asset_id,
+						old.info.currency,
+						old.info.amount,
+					);
+					let mut opinion = Opinion::new(
+						&old.id,
+						&old.requestor_id,
+						&old.professional_id,
+						&info,
+						old.created_at,
+					);
+					opinion.update_status(&old.status);
+
+					Some(opinion)
+				},
+			);
+
+			let max_opinions = T::MaxOpinionsPerOwner::get() as usize;
+
+			for (owner_id, opinion_ids) in opinion_indexes::<T>() {
+				weight = weight.saturating_add(T::DbWeight::get().reads(1));
+
+				if opinion_ids.len() > max_opinions {
+					log::error!(
+						target: "runtime::opinion",
+						"Opinion index of {:?} holds {} ids, more than MaxOpinionsPerOwner ({})",
+						owner_id,
+						opinion_ids.len(),
+						max_opinions,
+					);
+				}
+			}
+
+			weight
+		}
+
+		/// Opinion id indexes as stored before v1
+		pub fn opinion_indexes<T: Config>() -> impl Iterator<Item = (AccountIdOf<T>, Vec<HashOf<T>>)>
+		{
+			storage_key_iter::<AccountIdOf<T>, Vec<HashOf<T>>, Blake2_128Concat>(
+				Pallet::<T>::name().as_bytes(),
+				b"OpinionByOwner",
+			)
+		}
+	}
+}
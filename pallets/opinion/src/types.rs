@@ -1,12 +1,19 @@
 use frame_support::{
-	pallet_prelude::{Decode, Encode},
-	RuntimeDebug,
+	pallet_prelude::{ConstU32, Decode, Encode, MaxEncodedLen},
+	BoundedVec, RuntimeDebug,
 };
 use primitives_price_and_currency::CurrencyType;
 use scale_info::TypeInfo;
-use sp_std::vec::Vec;
 
-#[derive(Encode, Decode, Clone, Default, RuntimeDebug, PartialEq, Eq, TypeInfo)]
+/// Maximum length of the description of an opinion
+pub const MAX_OPINION_DESCRIPTION_LENGTH: u32 = 4096;
+/// Maximum length of the myriad url of an opinion
+pub const MAX_OPINION_URL_LENGTH: u32 = 512;
+
+pub type OpinionDescription = BoundedVec<u8, ConstU32<MAX_OPINION_DESCRIPTION_LENGTH>>;
+pub type OpinionUrl = BoundedVec<u8, ConstU32<MAX_OPINION_URL_LENGTH>>;
+
+#[derive(Encode, Decode, Clone, Default, RuntimeDebug, PartialEq, Eq, TypeInfo, MaxEncodedLen)]
 pub struct Opinion<AccountId, Hash, Moment>
 where
 	AccountId: Clone + PartialEq + Eq,
@@ -59,6 +66,14 @@ where
 		&self.professional_id
 	}
 
+	pub fn info(&self) -> &OpinionInfo {
+		&self.info
+	}
+
+	pub fn status(&self) -> &Status {
+		&self.status
+	}
+
 	pub fn update_info(&mut self, info: &OpinionInfo) {
 		self.info = info.clone();
 	}
@@ -72,18 +87,18 @@ where
 	}
 }
 
-#[derive(Encode, Decode, Clone, Default, RuntimeDebug, PartialEq, Eq, TypeInfo)]
+#[derive(Encode, Decode, Clone, Default, RuntimeDebug, PartialEq, Eq, TypeInfo, MaxEncodedLen)]
 pub struct OpinionInfo {
-	description: Vec<u8>,
-	myriad_url: Vec<u8>,
+	description: OpinionDescription,
+	myriad_url: OpinionUrl,
 	asset_id: Option<u32>,
 	currency: CurrencyType,
 	amount: u128,
 }
 impl OpinionInfo {
 	pub fn new(
-		description: Vec<u8>,
-		myriad_url: Vec<u8>,
+		description: OpinionDescription,
+		myriad_url: OpinionUrl,
 		asset_id: Option<u32>,
 		currency: CurrencyType,
 		amount: u128,
@@ -98,15 +113,55 @@ impl OpinionInfo {
 	pub fn currency(&self) -> &CurrencyType {
 		&self.currency
 	}
+
+	pub fn amount(&self) -> u128 {
+		self.amount
+	}
 }
 
-#[derive(Encode, Decode, Clone, RuntimeDebug, PartialEq, Eq, TypeInfo)]
+#[derive(Encode, Decode, Clone, RuntimeDebug, PartialEq, Eq, TypeInfo, MaxEncodedLen)]
 pub enum Status {
 	Unpaid,
 	Paid,
+	Accepted,
+	Refunded,
 }
 impl Default for Status {
 	fn default() -> Self {
 		Status::Unpaid
 	}
 }
+
+/// Requestor payment held by the pallet account from the moment an invited professional is paid
+/// for, until the delivered opinion is accepted or the payment is refunded.
+#[derive(Encode, Decode, Clone, Default, RuntimeDebug, PartialEq, Eq, TypeInfo, MaxEncodedLen)]
+pub struct OpinionEscrow<AccountId, Hash, Moment> {
+	pub payer: AccountId,
+	pub currency: CurrencyType,
+	pub asset_id: Option<u32>,
+	pub amount: u128,
+	pub paid_at: Moment,
+	/// Opinion submitted against the payment
+	pub opinion_id: Option<Hash>,
+	pub delivered_at: Option<Moment>,
+}
+impl<AccountId, Hash, Moment> OpinionEscrow<AccountId, Hash, Moment> {
+	pub fn new(
+		payer: AccountId,
+		currency: CurrencyType,
+		asset_id: Option<u32>,
+		amount: u128,
+		paid_at: Moment,
+	) -> Self {
+		Self { payer, currency, asset_id, amount, paid_at, opinion_id: None, delivered_at: None }
+	}
+
+	pub fn is_delivered(&self) -> bool {
+		self.opinion_id.is_some()
+	}
+
+	pub fn deliver(&mut self, opinion_id: Hash, delivered_at: Moment) {
+		self.opinion_id = Some(opinion_id);
+		self.delivered_at = Some(delivered_at);
+	}
+}
@@ -27,42 +27,48 @@ use sp_std::marker::PhantomData;
 
 /// Weight functions needed for opinion_benchmarking.
 pub trait WeightInfo {
-	fn create() -> Weight;
-	fn update() -> Weight;
+	fn hide() -> Weight;
+	fn unhide() -> Weight;
 	fn update_status() -> Weight;
 	fn delete() -> Weight;
 	fn update_admin_key() -> Weight;
+	fn pay() -> Weight;
+	fn accept() -> Weight;
+	fn refund() -> Weight;
+	fn reject() -> Weight;
+	fn submit() -> Weight;
+	fn edit() -> Weight;
+	fn withdraw() -> Weight;
+	fn claim() -> Weight;
 }
 
 /// Weights for opinion_benchmarking using the Substrate node and recommended hardware.
 pub struct SubstrateWeight<T>(PhantomData<T>);
 impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	// Storage: Opinion OpinionAdminKey (r:1 w:0)
-	// Storage: OpinionRequestor OpinionRequestors (r:1 w:1)
-	// Storage: Opinion OpinionCount (r:1 w:1)
-	// Storage: Timestamp Now (r:1 w:0)
-	// Storage: System Account (r:1 w:0)
-	// Storage: Opinion OpinionCountByOwner (r:1 w:1)
-	// Storage: Opinion OpinionByOwner (r:1 w:1)
-	// Storage: Opinion Opinions (r:0 w:1)
-	fn create() -> Weight {
-		Weight::from_ref_time(282_200_000_u64)
-			.saturating_add(T::DbWeight::get().reads(7_u64))
-			.saturating_add(T::DbWeight::get().writes(5_u64))
+	// Storage: Opinion Opinions (r:1 w:0)
+	// Storage: Opinion HiddenOpinions (r:1 w:1)
+	fn hide() -> Weight {
+		Weight::from_ref_time(47_200_000_u64)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
 	}
 	// Storage: Opinion OpinionAdminKey (r:1 w:0)
-	// Storage: Opinion Opinions (r:1 w:1)
-	fn update() -> Weight {
-		Weight::from_ref_time(54_600_000_u64)
+	// Storage: Opinion HiddenOpinions (r:1 w:1)
+	fn unhide() -> Weight {
+		Weight::from_ref_time(41_800_000_u64)
 			.saturating_add(T::DbWeight::get().reads(2_u64))
 			.saturating_add(T::DbWeight::get().writes(1_u64))
 	}
 	// Storage: Opinion OpinionAdminKey (r:1 w:0)
 	// Storage: Opinion Opinions (r:1 w:1)
+	// Storage: Opinion OpinionEscrows (r:1 w:1)
+	// Storage: Opinion OpinionTreasuryKey (r:1 w:0)
+	// Storage: System Account (r:3 w:3)
 	fn update_status() -> Weight {
-		Weight::from_ref_time(41_700_000_u64)
-			.saturating_add(T::DbWeight::get().reads(2_u64))
-			.saturating_add(T::DbWeight::get().writes(1_u64))
+		Weight::from_ref_time(149_300_000_u64)
+			.saturating_add(T::DbWeight::get().reads(7_u64))
+			.saturating_add(T::DbWeight::get().writes(5_u64))
 	}
 	// Storage: Opinion OpinionAdminKey (r:1 w:0)
 	// Storage: Opinion Opinions (r:1 w:1)
@@ -70,10 +76,11 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	// Storage: Opinion OpinionCount (r:1 w:1)
 	// Storage: Opinion OpinionCountByOwner (r:1 w:1)
 	// Storage: Opinion OpinionByOwner (r:1 w:1)
+	// Storage: Opinion HiddenOpinions (r:0 w:1)
 	fn delete() -> Weight {
 		Weight::from_ref_time(257_900_000_u64)
 			.saturating_add(T::DbWeight::get().reads(6_u64))
-			.saturating_add(T::DbWeight::get().writes(5_u64))
+			.saturating_add(T::DbWeight::get().writes(6_u64))
 	}
 	// Storage: Opinion OpinionAdminKey (r:1 w:1)
 	fn update_admin_key() -> Weight {
@@ -81,36 +88,118 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(T::DbWeight::get().reads(1_u64))
 			.saturating_add(T::DbWeight::get().writes(1_u64))
 	}
-}
-
-// For backwards compatibility and tests
-impl WeightInfo for () {
+	// Storage: Opinion Opinions (r:1 w:1)
+	// Storage: OpinionRequestor OpinionRequestors (r:1 w:0)
+	// Storage: Opinion OpinionEscrows (r:1 w:1)
+	// Storage: Opinion HiddenOpinions (r:1 w:0)
+	// Storage: System Account (r:2 w:2)
+	// Storage: Timestamp Now (r:1 w:0)
+	fn pay() -> Weight {
+		Weight::from_ref_time(118_400_000_u64)
+			.saturating_add(T::DbWeight::get().reads(7_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+	}
+	// Storage: Opinion Opinions (r:1 w:1)
+	// Storage: OpinionRequestor OpinionRequestors (r:1 w:0)
+	// Storage: Opinion OpinionEscrows (r:1 w:1)
+	// Storage: Opinion OpinionTreasuryKey (r:1 w:0)
+	// Storage: System Account (r:3 w:3)
+	fn accept() -> Weight {
+		Weight::from_ref_time(146_700_000_u64)
+			.saturating_add(T::DbWeight::get().reads(7_u64))
+			.saturating_add(T::DbWeight::get().writes(5_u64))
+	}
+	// Storage: Opinion Opinions (r:1 w:1)
+	// Storage: OpinionRequestor OpinionRequestors (r:1 w:0)
+	// Storage: Opinion OpinionEscrows (r:1 w:1)
+	// Storage: Timestamp Now (r:1 w:0)
+	// Storage: System Account (r:2 w:2)
+	fn refund() -> Weight {
+		Weight::from_ref_time(112_900_000_u64)
+			.saturating_add(T::DbWeight::get().reads(6_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+	}
 	// Storage: Opinion OpinionAdminKey (r:1 w:0)
+	// Storage: Opinion Opinions (r:1 w:1)
+	// Storage: Opinion OpinionEscrows (r:1 w:1)
+	// Storage: System Account (r:2 w:2)
+	fn reject() -> Weight {
+		Weight::from_ref_time(104_300_000_u64)
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+	}
+	// Storage: HealthProfessional HealthProfessionals (r:1 w:0)
 	// Storage: OpinionRequestor OpinionRequestors (r:1 w:1)
+	// Storage: OpinionRequestor InvitedProfessionals (r:1 w:0)
 	// Storage: Opinion OpinionCount (r:1 w:1)
 	// Storage: Timestamp Now (r:1 w:0)
 	// Storage: System Account (r:1 w:0)
 	// Storage: Opinion OpinionCountByOwner (r:1 w:1)
 	// Storage: Opinion OpinionByOwner (r:1 w:1)
 	// Storage: Opinion Opinions (r:0 w:1)
-	fn create() -> Weight {
-		Weight::from_ref_time(282_200_000_u64)
-			.saturating_add(RocksDbWeight::get().reads(7_u64))
-			.saturating_add(RocksDbWeight::get().writes(5_u64))
+	fn submit() -> Weight {
+		Weight::from_ref_time(291_700_000_u64)
+			.saturating_add(T::DbWeight::get().reads(8_u64))
+			.saturating_add(T::DbWeight::get().writes(5_u64))
+	}
+	// Storage: HealthProfessional HealthProfessionals (r:1 w:0)
+	// Storage: Opinion Opinions (r:1 w:1)
+	fn edit() -> Weight {
+		Weight::from_ref_time(61_300_000_u64)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
 	}
-	// Storage: Opinion OpinionAdminKey (r:1 w:0)
 	// Storage: Opinion Opinions (r:1 w:1)
-	fn update() -> Weight {
-		Weight::from_ref_time(54_600_000_u64)
+	// Storage: Opinion OpinionEscrows (r:1 w:0)
+	// Storage: OpinionRequestor OpinionRequestors (r:1 w:1)
+	// Storage: Opinion OpinionCount (r:1 w:1)
+	// Storage: Opinion OpinionCountByOwner (r:1 w:1)
+	// Storage: Opinion OpinionByOwner (r:1 w:1)
+	// Storage: Opinion HiddenOpinions (r:0 w:1)
+	fn withdraw() -> Weight {
+		Weight::from_ref_time(249_800_000_u64)
+			.saturating_add(T::DbWeight::get().reads(6_u64))
+			.saturating_add(T::DbWeight::get().writes(6_u64))
+	}
+	// Storage: Opinion Opinions (r:1 w:1)
+	// Storage: Opinion HiddenOpinions (r:1 w:0)
+	// Storage: Opinion OpinionEscrows (r:1 w:1)
+	// Storage: Timestamp Now (r:1 w:0)
+	// Storage: Opinion OpinionTreasuryKey (r:1 w:0)
+	// Storage: System Account (r:3 w:3)
+	fn claim() -> Weight {
+		Weight::from_ref_time(146_700_000_u64)
+			.saturating_add(T::DbWeight::get().reads(8_u64))
+			.saturating_add(T::DbWeight::get().writes(5_u64))
+	}
+}
+
+// For backwards compatibility and tests
+impl WeightInfo for () {
+	// Storage: Opinion OpinionAdminKey (r:1 w:0)
+	// Storage: Opinion Opinions (r:1 w:0)
+	// Storage: Opinion HiddenOpinions (r:1 w:1)
+	fn hide() -> Weight {
+		Weight::from_ref_time(47_200_000_u64)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	// Storage: Opinion OpinionAdminKey (r:1 w:0)
+	// Storage: Opinion HiddenOpinions (r:1 w:1)
+	fn unhide() -> Weight {
+		Weight::from_ref_time(41_800_000_u64)
 			.saturating_add(RocksDbWeight::get().reads(2_u64))
 			.saturating_add(RocksDbWeight::get().writes(1_u64))
 	}
 	// Storage: Opinion OpinionAdminKey (r:1 w:0)
 	// Storage: Opinion Opinions (r:1 w:1)
+	// Storage: Opinion OpinionEscrows (r:1 w:1)
+	// Storage: Opinion OpinionTreasuryKey (r:1 w:0)
+	// Storage: System Account (r:3 w:3)
 	fn update_status() -> Weight {
-		Weight::from_ref_time(41_700_000_u64)
-			.saturating_add(RocksDbWeight::get().reads(2_u64))
-			.saturating_add(RocksDbWeight::get().writes(1_u64))
+		Weight::from_ref_time(149_300_000_u64)
+			.saturating_add(RocksDbWeight::get().reads(7_u64))
+			.saturating_add(RocksDbWeight::get().writes(5_u64))
 	}
 	// Storage: Opinion OpinionAdminKey (r:1 w:0)
 	// Storage: Opinion Opinions (r:1 w:1)
@@ -118,10 +207,11 @@ impl WeightInfo for () {
 	// Storage: Opinion OpinionCount (r:1 w:1)
 	// Storage: Opinion OpinionCountByOwner (r:1 w:1)
 	// Storage: Opinion OpinionByOwner (r:1 w:1)
+	// Storage: Opinion HiddenOpinions (r:0 w:1)
 	fn delete() -> Weight {
 		Weight::from_ref_time(257_900_000_u64)
 			.saturating_add(RocksDbWeight::get().reads(6_u64))
-			.saturating_add(RocksDbWeight::get().writes(5_u64))
+			.saturating_add(RocksDbWeight::get().writes(6_u64))
 	}
 	// Storage: Opinion OpinionAdminKey (r:1 w:1)
 	fn update_admin_key() -> Weight {
@@ -129,4 +219,88 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().reads(1_u64))
 			.saturating_add(RocksDbWeight::get().writes(1_u64))
 	}
+	// Storage: Opinion Opinions (r:1 w:1)
+	// Storage: OpinionRequestor OpinionRequestors (r:1 w:0)
+	// Storage: Opinion OpinionEscrows (r:1 w:1)
+	// Storage: Opinion HiddenOpinions (r:1 w:0)
+	// Storage: System Account (r:2 w:2)
+	// Storage: Timestamp Now (r:1 w:0)
+	fn pay() -> Weight {
+		Weight::from_ref_time(118_400_000_u64)
+			.saturating_add(RocksDbWeight::get().reads(7_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+	}
+	// Storage: Opinion Opinions (r:1 w:1)
+	// Storage: OpinionRequestor OpinionRequestors (r:1 w:0)
+	// Storage: Opinion OpinionEscrows (r:1 w:1)
+	// Storage: Opinion OpinionTreasuryKey (r:1 w:0)
+	// Storage: System Account (r:3 w:3)
+	fn accept() -> Weight {
+		Weight::from_ref_time(146_700_000_u64)
+			.saturating_add(RocksDbWeight::get().reads(7_u64))
+			.saturating_add(RocksDbWeight::get().writes(5_u64))
+	}
+	// Storage: Opinion Opinions (r:1 w:1)
+	// Storage: OpinionRequestor OpinionRequestors (r:1 w:0)
+	// Storage: Opinion OpinionEscrows (r:1 w:1)
+	// Storage: Timestamp Now (r:1 w:0)
+	// Storage: System Account (r:2 w:2)
+	fn refund() -> Weight {
+		Weight::from_ref_time(112_900_000_u64)
+			.saturating_add(RocksDbWeight::get().reads(6_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+	}
+	// Storage: Opinion OpinionAdminKey (r:1 w:0)
+	// Storage: Opinion Opinions (r:1 w:1)
+	// Storage: Opinion OpinionEscrows (r:1 w:1)
+	// Storage: System Account (r:2 w:2)
+	fn reject() -> Weight {
+		Weight::from_ref_time(104_300_000_u64)
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+	}
+	// Storage: HealthProfessional HealthProfessionals (r:1 w:0)
+	// Storage: OpinionRequestor OpinionRequestors (r:1 w:1)
+	// Storage: OpinionRequestor InvitedProfessionals (r:1 w:0)
+	// Storage: Opinion OpinionCount (r:1 w:1)
+	// Storage: Timestamp Now (r:1 w:0)
+	// Storage: System Account (r:1 w:0)
+	// Storage: Opinion OpinionCountByOwner (r:1 w:1)
+	// Storage: Opinion OpinionByOwner (r:1 w:1)
+	// Storage: Opinion Opinions (r:0 w:1)
+	fn submit() -> Weight {
+		Weight::from_ref_time(291_700_000_u64)
+			.saturating_add(RocksDbWeight::get().reads(8_u64))
+			.saturating_add(RocksDbWeight::get().writes(5_u64))
+	}
+	// Storage: HealthProfessional HealthProfessionals (r:1 w:0)
+	// Storage: Opinion Opinions (r:1 w:1)
+	fn edit() -> Weight {
+		Weight::from_ref_time(61_300_000_u64)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	// Storage: Opinion Opinions (r:1 w:1)
+	// Storage: Opinion OpinionEscrows (r:1 w:0)
+	// Storage: OpinionRequestor OpinionRequestors (r:1 w:1)
+	// Storage: Opinion OpinionCount (r:1 w:1)
+	// Storage: Opinion OpinionCountByOwner (r:1 w:1)
+	// Storage: Opinion OpinionByOwner (r:1 w:1)
+	// Storage: Opinion HiddenOpinions (r:0 w:1)
+	fn withdraw() -> Weight {
+		Weight::from_ref_time(249_800_000_u64)
+			.saturating_add(RocksDbWeight::get().reads(6_u64))
+			.saturating_add(RocksDbWeight::get().writes(6_u64))
+	}
+	// Storage: Opinion Opinions (r:1 w:1)
+	// Storage: Opinion HiddenOpinions (r:1 w:0)
+	// Storage: Opinion OpinionEscrows (r:1 w:1)
+	// Storage: Timestamp Now (r:1 w:0)
+	// Storage: Opinion OpinionTreasuryKey (r:1 w:0)
+	// Storage: System Account (r:3 w:3)
+	fn claim() -> Weight {
+		Weight::from_ref_time(146_700_000_u64)
+			.saturating_add(RocksDbWeight::get().reads(8_u64))
+			.saturating_add(RocksDbWeight::get().writes(5_u64))
+	}
 }
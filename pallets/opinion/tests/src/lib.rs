@@ -9,28 +9,75 @@ mod test {
 
 	use frame_support::{
 		assert_noop, assert_ok,
+		codec::Encode,
 		sp_runtime::traits::{Hash, Keccak256},
+		storage::migration::put_storage_value,
+		traits::{Get, StorageVersion},
+		Blake2_128Concat, BoundedVec, StorageHasher,
 	};
+	use health_professional::{HealthProfessional, HealthProfessionalInfo, HealthProfessionals};
 	use opinion::{
-		Error, Event as OpinionEvent, Opinion as OpinionStruct, OpinionAdminKey, OpinionInfo,
-		Status,
+		migrations, Error, Event as OpinionEvent, Opinion as OpinionStruct, OpinionAdminKey,
+		OpinionInfo, OpinionTreasuryKey, Status, MAX_OPINION_DESCRIPTION_LENGTH,
 	};
 	use opinion_requestor::RequestorInfo;
 	use primitives_price_and_currency::CurrencyType;
+	use primitives_stake_status::StakeStatus;
+	use primitives_verification_status::VerificationStatus;
+	use sp_core::H256;
+
+	fn verified_health_professional(account_id: AccountId) {
+		let mut health_professional =
+			HealthProfessional::new(&account_id, &HealthProfessionalInfo::default());
+
+		health_professional.update_verification_status(&VerificationStatus::Verified);
+		health_professional.update_stake_status(StakeStatus::Staked, 1_000);
+
+		HealthProfessionals::<Test>::insert(account_id, health_professional);
+	}
+
+	fn invited_professional(customer: AccountId, doctor: AccountId, requestor_id: H256) {
+		verified_health_professional(doctor);
+
+		assert_ok!(OpinionRequestor::invite_professional(
+			RuntimeOrigin::signed(customer),
+			requestor_id,
+			doctor
+		));
+		assert_ok!(OpinionRequestor::accept_invitation(
+			RuntimeOrigin::signed(doctor),
+			requestor_id
+		));
+	}
+
+	fn paid_professional(customer: AccountId, doctor: AccountId, requestor_id: H256) {
+		invited_professional(customer, doctor, requestor_id);
+
+		assert_ok!(Opinion::pay(
+			RuntimeOrigin::signed(customer),
+			requestor_id,
+			doctor,
+			CurrencyType::DBIO,
+			None,
+			1000
+		));
+	}
 
 	#[test]
-	fn add_opinion_works() {
+	fn hide_and_unhide_opinion_works() {
 		ExternalityBuilder::build().execute_with(|| {
 			let admin = 1;
 			let doctor = 2;
 			let customer = 3;
 
+			OpinionAdminKey::<Test>::put(admin);
+
 			let info = RequestorInfo::new(
-				b"category",
-				b"description",
-				&Vec::new(),
-				&Vec::new(),
-				b"myriad_url",
+				b"category".to_vec().try_into().unwrap(),
+				b"description".to_vec().try_into().unwrap(),
+				Default::default(),
+				Default::default(),
+				b"myriad_url".to_vec().try_into().unwrap(),
 			);
 
 			assert_ok!(OpinionRequestor::request_opinion(RuntimeOrigin::signed(customer), info));
@@ -38,51 +85,54 @@ mod test {
 			let requestor_ids = OpinionRequestor::opinion_requestor_by_owner(customer);
 			let requestor_id = requestor_ids[0];
 
-			OpinionAdminKey::<Test>::put(admin);
+			invited_professional(customer, doctor, requestor_id);
 
 			let info = OpinionInfo::new(
-				b"description".to_vec(),
-				b"myriad_url".to_vec(),
+				b"description".to_vec().try_into().unwrap(),
+				b"myriad_url".to_vec().try_into().unwrap(),
 				None,
 				CurrencyType::DBIO,
 				1000,
 			);
 
-			assert_ok!(Opinion::create(
-				RuntimeOrigin::signed(admin),
-				requestor_id,
-				doctor,
-				info.clone()
-			));
+			assert_ok!(Opinion::submit(RuntimeOrigin::signed(doctor), requestor_id, info));
 
 			let opinion_ids = Opinion::opinion_by_owner(doctor);
 			let opinion_id = opinion_ids[0];
 
-			let opinion = OpinionStruct::new(&opinion_id, &requestor_id, &doctor, &info, 0);
+			assert_ok!(Opinion::hide(RuntimeOrigin::signed(admin), opinion_id));
 
-			let opinion_requestor =
-				OpinionRequestor::opinion_requestor_by_id(requestor_id).unwrap();
+			assert_eq!(Opinion::hidden_opinion(opinion_id), Some(()));
+			assert!(Opinion::opinion_by_id(opinion_id).is_some());
 
-			assert_eq!(Opinion::opinion_by_id(opinion_id), Some(opinion));
-			assert_eq!(Opinion::opinion_count(), 1);
-			assert_eq!(Opinion::opinion_count_by_owner(doctor), 1);
-			assert_eq!(opinion_requestor.opinion_ids(), vec![opinion_id]);
+			assert_noop!(
+				Opinion::hide(RuntimeOrigin::signed(admin), opinion_id),
+				Error::<Test>::OpinionHidden,
+			);
+
+			assert_ok!(Opinion::unhide(RuntimeOrigin::signed(admin), opinion_id));
+
+			assert_eq!(Opinion::hidden_opinion(opinion_id), None);
 		});
 	}
 
 	#[test]
-	fn update_opinion_info_works() {
+	fn cant_claim_opinion_when_hidden() {
 		ExternalityBuilder::build().execute_with(|| {
 			let admin = 1;
 			let doctor = 2;
 			let customer = 3;
+			let treasury = 5;
+
+			OpinionAdminKey::<Test>::put(admin);
+			OpinionTreasuryKey::<Test>::put(treasury);
 
 			let info = RequestorInfo::new(
-				b"category",
-				b"description",
-				&Vec::new(),
-				&Vec::new(),
-				b"myriad_url",
+				b"category".to_vec().try_into().unwrap(),
+				b"description".to_vec().try_into().unwrap(),
+				Default::default(),
+				Default::default(),
+				b"myriad_url".to_vec().try_into().unwrap(),
 			);
 
 			assert_ok!(OpinionRequestor::request_opinion(RuntimeOrigin::signed(customer), info));
@@ -90,38 +140,34 @@ mod test {
 			let requestor_ids = OpinionRequestor::opinion_requestor_by_owner(customer);
 			let requestor_id = requestor_ids[0];
 
-			OpinionAdminKey::<Test>::put(admin);
-
 			let info = OpinionInfo::new(
-				b"description".to_vec(),
-				b"myriad_url".to_vec(),
+				b"description".to_vec().try_into().unwrap(),
+				b"myriad_url".to_vec().try_into().unwrap(),
 				None,
 				CurrencyType::DBIO,
 				1000,
 			);
 
-			assert_ok!(Opinion::create(RuntimeOrigin::signed(admin), requestor_id, doctor, info));
+			paid_professional(customer, doctor, requestor_id);
+
+			assert_ok!(Opinion::submit(RuntimeOrigin::signed(doctor), requestor_id, info));
 
 			let opinion_ids = Opinion::opinion_by_owner(doctor);
 			let opinion_id = opinion_ids[0];
 
-			let updated_info = OpinionInfo::new(
-				b"description".to_vec(),
-				b"myriad_url".to_vec(),
-				Some(1),
-				CurrencyType::USDT,
-				1000,
-			);
+			assert_ok!(Opinion::hide(RuntimeOrigin::signed(admin), opinion_id));
 
-			assert_ok!(Opinion::update(
-				RuntimeOrigin::signed(admin),
-				opinion_id,
-				updated_info.clone()
-			));
+			Timestamp::set_timestamp(OpinionPaymentTimeout::get());
 
-			let opinion = OpinionStruct::new(&opinion_id, &requestor_id, &doctor, &updated_info, 0);
+			assert_noop!(
+				Opinion::claim(RuntimeOrigin::signed(doctor), opinion_id),
+				Error::<Test>::OpinionHidden,
+			);
 
-			assert_eq!(Opinion::opinion_by_id(opinion_id), Some(opinion));
+			assert_ok!(Opinion::unhide(RuntimeOrigin::signed(admin), opinion_id));
+			assert_ok!(Opinion::claim(RuntimeOrigin::signed(doctor), opinion_id));
+
+			assert_eq!(Opinion::opinion_by_id(opinion_id).unwrap().status(), &Status::Accepted);
 		});
 	}
 
@@ -132,12 +178,14 @@ mod test {
 			let doctor = 2;
 			let customer = 3;
 
+			OpinionAdminKey::<Test>::put(admin);
+
 			let info = RequestorInfo::new(
-				b"category",
-				b"description",
-				&Vec::new(),
-				&Vec::new(),
-				b"myriad_url",
+				b"category".to_vec().try_into().unwrap(),
+				b"description".to_vec().try_into().unwrap(),
+				Default::default(),
+				Default::default(),
+				b"myriad_url".to_vec().try_into().unwrap(),
 			);
 
 			assert_ok!(OpinionRequestor::request_opinion(RuntimeOrigin::signed(customer), info));
@@ -145,27 +193,29 @@ mod test {
 			let requestor_ids = OpinionRequestor::opinion_requestor_by_owner(customer);
 			let requestor_id = requestor_ids[0];
 
-			OpinionAdminKey::<Test>::put(admin);
+			invited_professional(customer, doctor, requestor_id);
 
 			let info = OpinionInfo::new(
-				b"description".to_vec(),
-				b"myriad_url".to_vec(),
+				b"description".to_vec().try_into().unwrap(),
+				b"myriad_url".to_vec().try_into().unwrap(),
 				None,
 				CurrencyType::DBIO,
 				1000,
 			);
 
-			assert_ok!(Opinion::create(RuntimeOrigin::signed(admin), requestor_id, doctor, info));
+			assert_ok!(Opinion::submit(RuntimeOrigin::signed(doctor), requestor_id, info));
 
 			let opinion_ids = Opinion::opinion_by_owner(doctor);
 			let opinion_id = opinion_ids[0];
 
+			assert_ok!(Opinion::hide(RuntimeOrigin::signed(admin), opinion_id));
 			assert_ok!(Opinion::delete(RuntimeOrigin::signed(admin), opinion_id));
 
 			let opinion_requestor =
 				OpinionRequestor::opinion_requestor_by_id(requestor_id).unwrap();
 
 			assert_eq!(Opinion::opinion_by_id(opinion_id), None);
+			assert_eq!(Opinion::hidden_opinion(opinion_id), None);
 			assert_eq!(Opinion::opinion_count(), 0);
 			assert_eq!(Opinion::opinion_count_by_owner(doctor), 0);
 			assert_eq!(opinion_requestor.opinion_ids(), Vec::new());
@@ -178,13 +228,15 @@ mod test {
 			let admin = 1;
 			let doctor = 2;
 			let customer = 3;
+			let other_doctor = 4;
+			let treasury = 5;
 
 			let info = RequestorInfo::new(
-				b"category",
-				b"description",
-				&Vec::new(),
-				&Vec::new(),
-				b"myriad_url",
+				b"category".to_vec().try_into().unwrap(),
+				b"description".to_vec().try_into().unwrap(),
+				Default::default(),
+				Default::default(),
+				b"myriad_url".to_vec().try_into().unwrap(),
 			);
 
 			assert_ok!(OpinionRequestor::request_opinion(RuntimeOrigin::signed(customer), info));
@@ -192,37 +244,124 @@ mod test {
 			let requestor_ids = OpinionRequestor::opinion_requestor_by_owner(customer);
 			let requestor_id = requestor_ids[0];
 
-			OpinionAdminKey::<Test>::put(admin);
-
 			let info = OpinionInfo::new(
-				b"description".to_vec(),
-				b"myriad_url".to_vec(),
+				b"description".to_vec().try_into().unwrap(),
+				b"myriad_url".to_vec().try_into().unwrap(),
 				None,
 				CurrencyType::DBIO,
 				1000,
 			);
 
-			assert_ok!(Opinion::create(
-				RuntimeOrigin::signed(admin),
+			OpinionAdminKey::<Test>::put(admin);
+			OpinionTreasuryKey::<Test>::put(treasury);
+
+			paid_professional(customer, doctor, requestor_id);
+			paid_professional(customer, other_doctor, requestor_id);
+
+			assert_ok!(Opinion::submit(RuntimeOrigin::signed(doctor), requestor_id, info.clone()));
+			assert_ok!(Opinion::submit(
+				RuntimeOrigin::signed(other_doctor),
 				requestor_id,
-				doctor,
 				info.clone()
 			));
 
-			let opinion_ids = Opinion::opinion_by_owner(doctor);
-			let opinion_id = opinion_ids[0];
-
-			let mut opinion = OpinionStruct::new(&opinion_id, &requestor_id, &doctor, &info, 0);
+			let opinion_id = Opinion::opinion_by_owner(doctor)[0];
+			let other_opinion_id = Opinion::opinion_by_owner(other_doctor)[0];
 
 			assert_ok!(Opinion::update_status(
 				RuntimeOrigin::signed(admin),
 				opinion_id,
-				Status::Paid
+				Status::Accepted
 			));
 
-			opinion.update_status(&Status::Paid);
+			System::assert_last_event(RuntimeEvent::Opinion(OpinionEvent::OpinionStatusUpdated(
+				admin,
+				opinion_id,
+				Status::Accepted,
+			)));
+
+			let mut opinion = OpinionStruct::new(&opinion_id, &requestor_id, &doctor, &info, 0);
+
+			opinion.update_status(&Status::Accepted);
 
 			assert_eq!(Opinion::opinion_by_id(opinion_id), Some(opinion));
+			assert_eq!(Opinion::escrow_by_request(requestor_id, doctor), None);
+			assert_eq!(Balances::free_balance(doctor), 950);
+			assert_eq!(Balances::free_balance(treasury), 50);
+
+			assert_ok!(Opinion::update_status(
+				RuntimeOrigin::signed(admin),
+				other_opinion_id,
+				Status::Refunded
+			));
+
+			let mut opinion =
+				OpinionStruct::new(&other_opinion_id, &requestor_id, &other_doctor, &info, 0);
+
+			opinion.update_status(&Status::Refunded);
+
+			assert_eq!(Opinion::opinion_by_id(other_opinion_id), Some(opinion));
+			assert_eq!(Opinion::escrow_by_request(requestor_id, other_doctor), None);
+			assert_eq!(Balances::free_balance(customer), 9_000);
+		});
+	}
+
+	#[test]
+	fn cant_update_opinion_status_without_escrow() {
+		ExternalityBuilder::build().execute_with(|| {
+			let admin = 1;
+			let doctor = 2;
+			let customer = 3;
+
+			let info = RequestorInfo::new(
+				b"category".to_vec().try_into().unwrap(),
+				b"description".to_vec().try_into().unwrap(),
+				Default::default(),
+				Default::default(),
+				b"myriad_url".to_vec().try_into().unwrap(),
+			);
+
+			assert_ok!(OpinionRequestor::request_opinion(RuntimeOrigin::signed(customer), info));
+
+			let requestor_ids = OpinionRequestor::opinion_requestor_by_owner(customer);
+			let requestor_id = requestor_ids[0];
+
+			OpinionAdminKey::<Test>::put(admin);
+
+			let info = OpinionInfo::new(
+				b"description".to_vec().try_into().unwrap(),
+				b"myriad_url".to_vec().try_into().unwrap(),
+				None,
+				CurrencyType::DBIO,
+				1000,
+			);
+
+			invited_professional(customer, doctor, requestor_id);
+
+			assert_ok!(Opinion::submit(RuntimeOrigin::signed(doctor), requestor_id, info));
+
+			let opinion_ids = Opinion::opinion_by_owner(doctor);
+			let opinion_id = opinion_ids[0];
+
+			assert_noop!(
+				Opinion::update_status(RuntimeOrigin::signed(admin), opinion_id, Status::Paid),
+				Error::<Test>::InvalidStatus,
+			);
+
+			assert_noop!(
+				Opinion::update_status(RuntimeOrigin::signed(admin), opinion_id, Status::Accepted),
+				Error::<Test>::OpinionNotPaid,
+			);
+
+			assert_noop!(
+				Opinion::update_status(RuntimeOrigin::signed(admin), opinion_id, Status::Refunded),
+				Error::<Test>::OpinionNotPaid,
+			);
+
+			assert_noop!(
+				Opinion::update_status(RuntimeOrigin::signed(admin), opinion_id, Status::Unpaid),
+				Error::<Test>::InvalidStatus,
+			);
 		});
 	}
 
@@ -252,18 +391,21 @@ mod test {
 	}
 
 	#[test]
-	fn cant_add_opinion_when_not_admin() {
+	fn cant_hide_opinion_when_not_admin() {
 		ExternalityBuilder::build().execute_with(|| {
 			let admin = 1;
 			let doctor = 2;
 			let customer = 3;
+			let other_admin = 4;
+
+			OpinionAdminKey::<Test>::put(admin);
 
 			let info = RequestorInfo::new(
-				b"category",
-				b"description",
-				&Vec::new(),
-				&Vec::new(),
-				b"myriad_url",
+				b"category".to_vec().try_into().unwrap(),
+				b"description".to_vec().try_into().unwrap(),
+				Default::default(),
+				Default::default(),
+				b"myriad_url".to_vec().try_into().unwrap(),
 			);
 
 			assert_ok!(OpinionRequestor::request_opinion(RuntimeOrigin::signed(customer), info));
@@ -271,59 +413,57 @@ mod test {
 			let requestor_ids = OpinionRequestor::opinion_requestor_by_owner(customer);
 			let requestor_id = requestor_ids[0];
 
+			invited_professional(customer, doctor, requestor_id);
+
 			let info = OpinionInfo::new(
-				b"description".to_vec(),
-				b"myriad_url".to_vec(),
+				b"description".to_vec().try_into().unwrap(),
+				b"myriad_url".to_vec().try_into().unwrap(),
 				None,
 				CurrencyType::DBIO,
 				1000,
 			);
 
+			assert_ok!(Opinion::submit(RuntimeOrigin::signed(doctor), requestor_id, info));
+
+			let opinion_ids = Opinion::opinion_by_owner(doctor);
+			let opinion_id = opinion_ids[0];
+
 			assert_noop!(
-				Opinion::create(RuntimeOrigin::signed(admin), requestor_id, doctor, info),
+				Opinion::hide(RuntimeOrigin::signed(other_admin), opinion_id),
 				Error::<Test>::Unauthorized,
 			);
 		});
 	}
 
 	#[test]
-	fn cant_add_opinion_when_not_found() {
+	fn cant_hide_opinion_when_not_found() {
 		ExternalityBuilder::build().execute_with(|| {
-			let doctor = 1;
 			let admin = 2;
 
-			let requestor_id =
+			let opinion_id =
 				Keccak256::hash("0xDb9Af2d1f3ADD2726A132AA7A65Cc9E6fC5761C3".as_bytes());
-			let info = OpinionInfo::new(
-				b"description".to_vec(),
-				b"myriad_url".to_vec(),
-				None,
-				CurrencyType::DBIO,
-				1000,
-			);
 
 			OpinionAdminKey::<Test>::put(admin);
 
 			assert_noop!(
-				Opinion::create(RuntimeOrigin::signed(admin), requestor_id, doctor, info),
+				Opinion::hide(RuntimeOrigin::signed(admin), opinion_id),
 				Error::<Test>::NotFound,
 			);
 		});
 	}
 
 	#[test]
-	fn cant_add_opinion_when_asset_not_found() {
+	fn cant_submit_opinion_when_asset_not_found() {
 		ExternalityBuilder::build().execute_with(|| {
-			let admin = 1;
 			let doctor = 2;
 			let customer = 3;
 
 			let info = RequestorInfo::new(
-				b"category",
-				b"description",
-				&Vec::new(),
-				&Vec::new(),
-				b"myriad_url",
+				b"category".to_vec().try_into().unwrap(),
+				b"description".to_vec().try_into().unwrap(),
+				Default::default(),
+				Default::default(),
+				b"myriad_url".to_vec().try_into().unwrap(),
 			);
 
 			assert_ok!(OpinionRequestor::request_opinion(RuntimeOrigin::signed(customer), info));
@@ -331,37 +471,39 @@ mod test {
 			let requestor_ids = OpinionRequestor::opinion_requestor_by_owner(customer);
 			let requestor_id = requestor_ids[0];
 
-			OpinionAdminKey::<Test>::put(admin);
+			invited_professional(customer, doctor, requestor_id);
 
 			let info = OpinionInfo::new(
-				b"description".to_vec(),
-				b"myriad_url".to_vec(),
+				b"description".to_vec().try_into().unwrap(),
+				b"myriad_url".to_vec().try_into().unwrap(),
 				None,
 				CurrencyType::USDT,
 				1000,
 			);
 
 			assert_noop!(
-				Opinion::create(RuntimeOrigin::signed(admin), requestor_id, doctor, info),
+				Opinion::submit(RuntimeOrigin::signed(doctor), requestor_id, info),
 				Error::<Test>::NotFound,
 			);
 		});
 	}
 
 	#[test]
-	fn cant_update_opinion_info_when_not_admin() {
+	fn cant_unhide_opinion_when_not_admin() {
 		ExternalityBuilder::build().execute_with(|| {
 			let admin = 1;
 			let doctor = 2;
 			let customer = 3;
 			let other_admin = 4;
 
+			OpinionAdminKey::<Test>::put(admin);
+
 			let info = RequestorInfo::new(
-				b"category",
-				b"description",
-				&Vec::new(),
-				&Vec::new(),
-				b"myriad_url",
+				b"category".to_vec().try_into().unwrap(),
+				b"description".to_vec().try_into().unwrap(),
+				Default::default(),
+				Default::default(),
+				b"myriad_url".to_vec().try_into().unwrap(),
 			);
 
 			assert_ok!(OpinionRequestor::request_opinion(RuntimeOrigin::signed(customer), info));
@@ -369,73 +511,86 @@ mod test {
 			let requestor_ids = OpinionRequestor::opinion_requestor_by_owner(customer);
 			let requestor_id = requestor_ids[0];
 
-			OpinionAdminKey::<Test>::put(admin);
+			invited_professional(customer, doctor, requestor_id);
 
 			let info = OpinionInfo::new(
-				b"description".to_vec(),
-				b"myriad_url".to_vec(),
+				b"description".to_vec().try_into().unwrap(),
+				b"myriad_url".to_vec().try_into().unwrap(),
 				None,
 				CurrencyType::DBIO,
 				1000,
 			);
 
-			assert_ok!(Opinion::create(RuntimeOrigin::signed(admin), requestor_id, doctor, info));
+			assert_ok!(Opinion::submit(RuntimeOrigin::signed(doctor), requestor_id, info));
 
 			let opinion_ids = Opinion::opinion_by_owner(doctor);
 			let opinion_id = opinion_ids[0];
 
-			let updated_info = OpinionInfo::new(
-				b"description".to_vec(),
-				b"myriad_url".to_vec(),
-				Some(1),
-				CurrencyType::USDT,
-				1000,
-			);
+			assert_ok!(Opinion::hide(RuntimeOrigin::signed(admin), opinion_id));
 
 			assert_noop!(
-				Opinion::update(RuntimeOrigin::signed(other_admin), opinion_id, updated_info),
+				Opinion::unhide(RuntimeOrigin::signed(other_admin), opinion_id),
 				Error::<Test>::Unauthorized,
 			);
 		});
 	}
 
 	#[test]
-	fn cant_update_opinion_info_when_not_found() {
+	fn cant_unhide_opinion_when_not_hidden() {
 		ExternalityBuilder::build().execute_with(|| {
-			let admin = 2;
+			let admin = 1;
+			let doctor = 2;
+			let customer = 3;
+
+			OpinionAdminKey::<Test>::put(admin);
+
+			let info = RequestorInfo::new(
+				b"category".to_vec().try_into().unwrap(),
+				b"description".to_vec().try_into().unwrap(),
+				Default::default(),
+				Default::default(),
+				b"myriad_url".to_vec().try_into().unwrap(),
+			);
+
+			assert_ok!(OpinionRequestor::request_opinion(RuntimeOrigin::signed(customer), info));
+
+			let requestor_ids = OpinionRequestor::opinion_requestor_by_owner(customer);
+			let requestor_id = requestor_ids[0];
+
+			invited_professional(customer, doctor, requestor_id);
 
-			let opinion_id =
-				Keccak256::hash("0xDb9Af2d1f3ADD2726A132AA7A65Cc9E6fC5761C3".as_bytes());
 			let info = OpinionInfo::new(
-				b"description".to_vec(),
-				b"myriad_url".to_vec(),
+				b"description".to_vec().try_into().unwrap(),
+				b"myriad_url".to_vec().try_into().unwrap(),
 				None,
 				CurrencyType::DBIO,
 				1000,
 			);
 
-			OpinionAdminKey::<Test>::put(admin);
+			assert_ok!(Opinion::submit(RuntimeOrigin::signed(doctor), requestor_id, info));
+
+			let opinion_ids = Opinion::opinion_by_owner(doctor);
+			let opinion_id = opinion_ids[0];
 
 			assert_noop!(
-				Opinion::update(RuntimeOrigin::signed(admin), opinion_id, info),
-				Error::<Test>::NotFound,
+				Opinion::unhide(RuntimeOrigin::signed(admin), opinion_id),
+				Error::<Test>::OpinionNotHidden,
 			);
 		});
 	}
 
 	#[test]
-	fn cant_update_opinion_info_when_asset_not_found() {
+	fn cant_edit_opinion_when_asset_not_found() {
 		ExternalityBuilder::build().execute_with(|| {
-			let admin = 1;
 			let doctor = 2;
 			let customer = 3;
 
 			let info = RequestorInfo::new(
-				b"category",
-				b"description",
-				&Vec::new(),
-				&Vec::new(),
-				b"myriad_url",
+				b"category".to_vec().try_into().unwrap(),
+				b"description".to_vec().try_into().unwrap(),
+				Default::default(),
+				Default::default(),
+				b"myriad_url".to_vec().try_into().unwrap(),
 			);
 
 			assert_ok!(OpinionRequestor::request_opinion(RuntimeOrigin::signed(customer), info));
@@ -443,31 +598,31 @@ mod test {
 			let requestor_ids = OpinionRequestor::opinion_requestor_by_owner(customer);
 			let requestor_id = requestor_ids[0];
 
-			OpinionAdminKey::<Test>::put(admin);
+			invited_professional(customer, doctor, requestor_id);
 
 			let info = OpinionInfo::new(
-				b"description".to_vec(),
-				b"myriad_url".to_vec(),
+				b"description".to_vec().try_into().unwrap(),
+				b"myriad_url".to_vec().try_into().unwrap(),
 				None,
 				CurrencyType::DBIO,
 				1000,
 			);
 
-			assert_ok!(Opinion::create(RuntimeOrigin::signed(admin), requestor_id, doctor, info));
+			assert_ok!(Opinion::submit(RuntimeOrigin::signed(doctor), requestor_id, info));
 
 			let opinion_ids = Opinion::opinion_by_owner(doctor);
 			let opinion_id = opinion_ids[0];
 
 			let updated_info = OpinionInfo::new(
-				b"description".to_vec(),
-				b"myriad_url".to_vec(),
+				b"description".to_vec().try_into().unwrap(),
+				b"myriad_url".to_vec().try_into().unwrap(),
 				None,
 				CurrencyType::USDT,
 				1000,
 			);
 
 			assert_noop!(
-				Opinion::update(RuntimeOrigin::signed(admin), opinion_id, updated_info),
+				Opinion::edit(RuntimeOrigin::signed(doctor), opinion_id, updated_info),
 				Error::<Test>::NotFound,
 			);
 		});
@@ -513,11 +668,11 @@ mod test {
 			let customer = 3;
 
 			let info = RequestorInfo::new(
-				b"category",
-				b"description",
-				&Vec::new(),
-				&Vec::new(),
-				b"myriad_url",
+				b"category".to_vec().try_into().unwrap(),
+				b"description".to_vec().try_into().unwrap(),
+				Default::default(),
+				Default::default(),
+				b"myriad_url".to_vec().try_into().unwrap(),
 			);
 
 			assert_ok!(OpinionRequestor::request_opinion(RuntimeOrigin::signed(customer), info));
@@ -526,8 +681,8 @@ mod test {
 			let requestor_id = requestor_ids[0];
 
 			let info = OpinionInfo::new(
-				b"description".to_vec(),
-				b"myriad_url".to_vec(),
+				b"description".to_vec().try_into().unwrap(),
+				b"myriad_url".to_vec().try_into().unwrap(),
 				None,
 				CurrencyType::DBIO,
 				1000,
@@ -535,7 +690,9 @@ mod test {
 
 			OpinionAdminKey::<Test>::put(admin);
 
-			assert_ok!(Opinion::create(RuntimeOrigin::signed(admin), requestor_id, doctor, info));
+			invited_professional(customer, doctor, requestor_id);
+
+			assert_ok!(Opinion::submit(RuntimeOrigin::signed(doctor), requestor_id, info));
 
 			let opinion_ids = Opinion::opinion_by_owner(doctor);
 			let opinion_id = opinion_ids[0];
@@ -593,11 +750,11 @@ mod test {
 			OpinionAdminKey::<Test>::put(admin);
 
 			let info = RequestorInfo::new(
-				b"category",
-				b"description",
-				&Vec::new(),
-				&Vec::new(),
-				b"myriad_url",
+				b"category".to_vec().try_into().unwrap(),
+				b"description".to_vec().try_into().unwrap(),
+				Default::default(),
+				Default::default(),
+				b"myriad_url".to_vec().try_into().unwrap(),
 			);
 
 			assert_ok!(OpinionRequestor::request_opinion(RuntimeOrigin::signed(customer), info));
@@ -606,19 +763,16 @@ mod test {
 			let requestor_id = requestor_ids[0];
 
 			let info = OpinionInfo::new(
-				b"description".to_vec(),
-				b"myriad_url".to_vec(),
+				b"description".to_vec().try_into().unwrap(),
+				b"myriad_url".to_vec().try_into().unwrap(),
 				None,
 				CurrencyType::DBIO,
 				1000,
 			);
 
-			assert_ok!(Opinion::create(
-				RuntimeOrigin::signed(admin),
-				requestor_id,
-				doctor,
-				info.clone()
-			));
+			invited_professional(customer, doctor, requestor_id);
+
+			assert_ok!(Opinion::submit(RuntimeOrigin::signed(doctor), requestor_id, info.clone()));
 
 			let opinion_ids = Opinion::opinion_by_owner(doctor);
 			let opinion_id = opinion_ids[0];
@@ -626,35 +780,19 @@ mod test {
 			let opinion = OpinionStruct::new(&opinion_id, &requestor_id, &doctor, &info, 0);
 
 			System::assert_last_event(RuntimeEvent::Opinion(OpinionEvent::OpinionAdded(
-				admin, opinion,
+				doctor, opinion,
 			)));
 
-			let updated_info = OpinionInfo::new(
-				b"description".to_vec(),
-				b"myriad_url".to_vec(),
-				Some(1),
-				CurrencyType::USDT,
-				1000,
-			);
-
-			let opinion = OpinionStruct::new(&opinion_id, &requestor_id, &doctor, &updated_info, 0);
-
-			assert_ok!(Opinion::update(RuntimeOrigin::signed(admin), opinion_id, updated_info));
+			assert_ok!(Opinion::hide(RuntimeOrigin::signed(admin), opinion_id));
 
-			System::assert_last_event(RuntimeEvent::Opinion(OpinionEvent::OpinionUpdated(
-				admin, opinion,
+			System::assert_last_event(RuntimeEvent::Opinion(OpinionEvent::OpinionHidden(
+				admin, opinion_id,
 			)));
 
-			assert_ok!(Opinion::update_status(
-				RuntimeOrigin::signed(admin),
-				opinion_id,
-				Status::Paid
-			));
+			assert_ok!(Opinion::unhide(RuntimeOrigin::signed(admin), opinion_id));
 
-			System::assert_last_event(RuntimeEvent::Opinion(OpinionEvent::OpinionStatusUpdated(
-				admin,
-				opinion_id,
-				Status::Paid,
+			System::assert_last_event(RuntimeEvent::Opinion(OpinionEvent::OpinionUnhidden(
+				admin, opinion_id,
 			)));
 
 			assert_ok!(Opinion::delete(RuntimeOrigin::signed(admin), opinion_id));
@@ -672,4 +810,884 @@ mod test {
 			)))
 		});
 	}
+
+	#[test]
+	fn pay_and_accept_opinion_works() {
+		ExternalityBuilder::build().execute_with(|| {
+			let admin = 1;
+			let doctor = 2;
+			let customer = 3;
+			let treasury = 5;
+			let pallet_account_id = Opinion::pallet_account_id();
+
+			OpinionAdminKey::<Test>::put(admin);
+			OpinionTreasuryKey::<Test>::put(treasury);
+
+			let info = RequestorInfo::new(
+				b"category".to_vec().try_into().unwrap(),
+				b"description".to_vec().try_into().unwrap(),
+				Default::default(),
+				Default::default(),
+				b"myriad_url".to_vec().try_into().unwrap(),
+			);
+
+			assert_ok!(OpinionRequestor::request_opinion(RuntimeOrigin::signed(customer), info));
+
+			let requestor_ids = OpinionRequestor::opinion_requestor_by_owner(customer);
+			let requestor_id = requestor_ids[0];
+
+			let info = OpinionInfo::new(
+				b"description".to_vec().try_into().unwrap(),
+				b"myriad_url".to_vec().try_into().unwrap(),
+				None,
+				CurrencyType::DBIO,
+				1000,
+			);
+
+			invited_professional(customer, doctor, requestor_id);
+
+			assert_ok!(Opinion::pay(
+				RuntimeOrigin::signed(customer),
+				requestor_id,
+				doctor,
+				CurrencyType::DBIO,
+				None,
+				1000
+			));
+
+			System::assert_last_event(RuntimeEvent::Opinion(OpinionEvent::OpinionPaid(
+				customer,
+				requestor_id,
+				doctor,
+				1_000,
+			)));
+
+			let escrow = Opinion::escrow_by_request(requestor_id, doctor).unwrap();
+
+			assert_eq!(Balances::free_balance(customer), 9_000);
+			assert_eq!(Balances::free_balance(pallet_account_id), 1_000);
+			assert_eq!(escrow.amount, 1_000);
+			assert!(!escrow.is_delivered());
+
+			assert_ok!(Opinion::submit(RuntimeOrigin::signed(doctor), requestor_id, info));
+
+			let opinion_ids = Opinion::opinion_by_owner(doctor);
+			let opinion_id = opinion_ids[0];
+			let escrow = Opinion::escrow_by_request(requestor_id, doctor).unwrap();
+
+			assert_eq!(Opinion::opinion_by_id(opinion_id).unwrap().status(), &Status::Paid);
+			assert_eq!(escrow.opinion_id, Some(opinion_id));
+
+			assert_ok!(Opinion::accept(RuntimeOrigin::signed(customer), opinion_id));
+
+			System::assert_last_event(RuntimeEvent::Opinion(OpinionEvent::OpinionAccepted(
+				customer, opinion_id, 950, 50,
+			)));
+
+			assert_eq!(Balances::free_balance(doctor), 950);
+			assert_eq!(Balances::free_balance(treasury), 50);
+			assert_eq!(Balances::free_balance(pallet_account_id), 0);
+			assert_eq!(Opinion::opinion_by_id(opinion_id).unwrap().status(), &Status::Accepted);
+			assert_eq!(Opinion::escrow_by_request(requestor_id, doctor), None);
+		});
+	}
+
+	#[test]
+	fn refund_opinion_works_after_payment_timeout() {
+		ExternalityBuilder::build().execute_with(|| {
+			let admin = 1;
+			let doctor = 2;
+			let customer = 3;
+
+			OpinionAdminKey::<Test>::put(admin);
+
+			let info = RequestorInfo::new(
+				b"category".to_vec().try_into().unwrap(),
+				b"description".to_vec().try_into().unwrap(),
+				Default::default(),
+				Default::default(),
+				b"myriad_url".to_vec().try_into().unwrap(),
+			);
+
+			assert_ok!(OpinionRequestor::request_opinion(RuntimeOrigin::signed(customer), info));
+
+			let requestor_ids = OpinionRequestor::opinion_requestor_by_owner(customer);
+			let requestor_id = requestor_ids[0];
+
+			let info = OpinionInfo::new(
+				b"description".to_vec().try_into().unwrap(),
+				b"myriad_url".to_vec().try_into().unwrap(),
+				None,
+				CurrencyType::DBIO,
+				1000,
+			);
+
+			paid_professional(customer, doctor, requestor_id);
+
+			assert_noop!(
+				Opinion::refund(RuntimeOrigin::signed(doctor), requestor_id, doctor),
+				Error::<Test>::Unauthorized,
+			);
+
+			assert_noop!(
+				Opinion::refund(RuntimeOrigin::signed(customer), requestor_id, doctor),
+				Error::<Test>::PaymentNotYetExpired,
+			);
+
+			Timestamp::set_timestamp(OpinionPaymentTimeout::get());
+
+			assert_ok!(Opinion::refund(RuntimeOrigin::signed(customer), requestor_id, doctor));
+
+			System::assert_last_event(RuntimeEvent::Opinion(OpinionEvent::OpinionRefunded(
+				customer,
+				requestor_id,
+				doctor,
+				1_000,
+			)));
+
+			assert_eq!(Balances::free_balance(customer), 10_000);
+			assert_eq!(Opinion::escrow_by_request(requestor_id, doctor), None);
+
+			// The professional can no longer deliver against the refunded payment
+			assert_ok!(Opinion::submit(RuntimeOrigin::signed(doctor), requestor_id, info));
+
+			let opinion_ids = Opinion::opinion_by_owner(doctor);
+
+			assert_eq!(Opinion::opinion_by_id(opinion_ids[0]).unwrap().status(), &Status::Unpaid);
+		});
+	}
+
+	#[test]
+	fn cant_refund_opinion_after_delivery() {
+		ExternalityBuilder::build().execute_with(|| {
+			let admin = 1;
+			let doctor = 2;
+			let customer = 3;
+
+			OpinionAdminKey::<Test>::put(admin);
+
+			let info = RequestorInfo::new(
+				b"category".to_vec().try_into().unwrap(),
+				b"description".to_vec().try_into().unwrap(),
+				Default::default(),
+				Default::default(),
+				b"myriad_url".to_vec().try_into().unwrap(),
+			);
+
+			assert_ok!(OpinionRequestor::request_opinion(RuntimeOrigin::signed(customer), info));
+
+			let requestor_ids = OpinionRequestor::opinion_requestor_by_owner(customer);
+			let requestor_id = requestor_ids[0];
+
+			let info = OpinionInfo::new(
+				b"description".to_vec().try_into().unwrap(),
+				b"myriad_url".to_vec().try_into().unwrap(),
+				None,
+				CurrencyType::DBIO,
+				1000,
+			);
+
+			paid_professional(customer, doctor, requestor_id);
+
+			assert_ok!(Opinion::submit(RuntimeOrigin::signed(doctor), requestor_id, info));
+
+			Timestamp::set_timestamp(OpinionPaymentTimeout::get());
+
+			assert_noop!(
+				Opinion::refund(RuntimeOrigin::signed(customer), requestor_id, doctor),
+				Error::<Test>::OpinionAlreadyDelivered,
+			);
+		});
+	}
+
+	#[test]
+	fn claim_opinion_works_after_payment_timeout() {
+		ExternalityBuilder::build().execute_with(|| {
+			let admin = 1;
+			let doctor = 2;
+			let customer = 3;
+			let treasury = 5;
+			let delivered_at = 500;
+
+			OpinionAdminKey::<Test>::put(admin);
+			OpinionTreasuryKey::<Test>::put(treasury);
+
+			let info = RequestorInfo::new(
+				b"category".to_vec().try_into().unwrap(),
+				b"description".to_vec().try_into().unwrap(),
+				Default::default(),
+				Default::default(),
+				b"myriad_url".to_vec().try_into().unwrap(),
+			);
+
+			assert_ok!(OpinionRequestor::request_opinion(RuntimeOrigin::signed(customer), info));
+
+			let requestor_ids = OpinionRequestor::opinion_requestor_by_owner(customer);
+			let requestor_id = requestor_ids[0];
+
+			let info = OpinionInfo::new(
+				b"description".to_vec().try_into().unwrap(),
+				b"myriad_url".to_vec().try_into().unwrap(),
+				None,
+				CurrencyType::DBIO,
+				1000,
+			);
+
+			paid_professional(customer, doctor, requestor_id);
+
+			Timestamp::set_timestamp(delivered_at);
+
+			assert_ok!(Opinion::submit(RuntimeOrigin::signed(doctor), requestor_id, info));
+
+			let opinion_ids = Opinion::opinion_by_owner(doctor);
+			let opinion_id = opinion_ids[0];
+
+			// The timeout runs from the delivery, not from the payment
+			Timestamp::set_timestamp(OpinionPaymentTimeout::get());
+
+			assert_noop!(
+				Opinion::claim(RuntimeOrigin::signed(doctor), opinion_id),
+				Error::<Test>::PaymentNotYetExpired,
+			);
+
+			Timestamp::set_timestamp(delivered_at + OpinionPaymentTimeout::get());
+
+			assert_noop!(
+				Opinion::claim(RuntimeOrigin::signed(customer), opinion_id),
+				Error::<Test>::Unauthorized,
+			);
+
+			assert_ok!(Opinion::claim(RuntimeOrigin::signed(doctor), opinion_id));
+
+			System::assert_last_event(RuntimeEvent::Opinion(OpinionEvent::OpinionPaymentClaimed(
+				doctor, opinion_id, 950, 50,
+			)));
+
+			assert_eq!(Balances::free_balance(doctor), 950);
+			assert_eq!(Balances::free_balance(treasury), 50);
+			assert_eq!(Opinion::opinion_by_id(opinion_id).unwrap().status(), &Status::Accepted);
+			assert_eq!(Opinion::escrow_by_request(requestor_id, doctor), None);
+		});
+	}
+
+	#[test]
+	fn cant_submit_opinion_when_price_differs_from_payment() {
+		ExternalityBuilder::build().execute_with(|| {
+			let doctor = 2;
+			let customer = 3;
+
+			let info = RequestorInfo::new(
+				b"category".to_vec().try_into().unwrap(),
+				b"description".to_vec().try_into().unwrap(),
+				Default::default(),
+				Default::default(),
+				b"myriad_url".to_vec().try_into().unwrap(),
+			);
+
+			assert_ok!(OpinionRequestor::request_opinion(RuntimeOrigin::signed(customer), info));
+
+			let requestor_ids = OpinionRequestor::opinion_requestor_by_owner(customer);
+			let requestor_id = requestor_ids[0];
+
+			let info = OpinionInfo::new(
+				b"description".to_vec().try_into().unwrap(),
+				b"myriad_url".to_vec().try_into().unwrap(),
+				None,
+				CurrencyType::DBIO,
+				1000,
+			);
+
+			paid_professional(customer, doctor, requestor_id);
+
+			let info = OpinionInfo::new(
+				b"description".to_vec().try_into().unwrap(),
+				b"myriad_url".to_vec().try_into().unwrap(),
+				None,
+				CurrencyType::DBIO,
+				2000,
+			);
+
+			assert_noop!(
+				Opinion::submit(RuntimeOrigin::signed(doctor), requestor_id, info),
+				Error::<Test>::OpinionPriceMismatch,
+			);
+		});
+	}
+
+	#[test]
+	fn reject_opinion_works() {
+		ExternalityBuilder::build().execute_with(|| {
+			let admin = 1;
+			let doctor = 2;
+			let customer = 3;
+
+			OpinionAdminKey::<Test>::put(admin);
+
+			let info = RequestorInfo::new(
+				b"category".to_vec().try_into().unwrap(),
+				b"description".to_vec().try_into().unwrap(),
+				Default::default(),
+				Default::default(),
+				b"myriad_url".to_vec().try_into().unwrap(),
+			);
+
+			assert_ok!(OpinionRequestor::request_opinion(RuntimeOrigin::signed(customer), info));
+
+			let requestor_ids = OpinionRequestor::opinion_requestor_by_owner(customer);
+			let requestor_id = requestor_ids[0];
+
+			let info = OpinionInfo::new(
+				b"description".to_vec().try_into().unwrap(),
+				b"myriad_url".to_vec().try_into().unwrap(),
+				None,
+				CurrencyType::DBIO,
+				1000,
+			);
+
+			paid_professional(customer, doctor, requestor_id);
+
+			assert_ok!(Opinion::submit(RuntimeOrigin::signed(doctor), requestor_id, info));
+
+			let opinion_ids = Opinion::opinion_by_owner(doctor);
+			let opinion_id = opinion_ids[0];
+
+			assert_noop!(
+				Opinion::delete(RuntimeOrigin::signed(admin), opinion_id),
+				Error::<Test>::OpinionPaymentInEscrow,
+			);
+
+			assert_ok!(Opinion::reject(RuntimeOrigin::signed(admin), opinion_id));
+
+			System::assert_last_event(RuntimeEvent::Opinion(OpinionEvent::OpinionRejected(
+				admin, opinion_id, 1_000,
+			)));
+
+			assert_eq!(Balances::free_balance(customer), 10_000);
+			assert_eq!(Opinion::opinion_by_id(opinion_id).unwrap().status(), &Status::Refunded);
+		});
+	}
+
+	#[test]
+	fn last_escrow_is_paid_out_with_existential_deposit() {
+		ExternalityBuilder::build().execute_with(|| {
+			let admin = 1;
+			let doctor = 2;
+			let customer = 3;
+			let other_doctor = 4;
+			let treasury = 5;
+
+			assert!(ExistentialDeposit::get() > 0);
+
+			OpinionAdminKey::<Test>::put(admin);
+			OpinionTreasuryKey::<Test>::put(treasury);
+
+			let info = RequestorInfo::new(
+				b"category".to_vec().try_into().unwrap(),
+				b"description".to_vec().try_into().unwrap(),
+				Default::default(),
+				Default::default(),
+				b"myriad_url".to_vec().try_into().unwrap(),
+			);
+
+			assert_ok!(OpinionRequestor::request_opinion(RuntimeOrigin::signed(customer), info));
+
+			let requestor_ids = OpinionRequestor::opinion_requestor_by_owner(customer);
+			let requestor_id = requestor_ids[0];
+
+			let info = OpinionInfo::new(
+				b"description".to_vec().try_into().unwrap(),
+				b"myriad_url".to_vec().try_into().unwrap(),
+				None,
+				CurrencyType::DBIO,
+				1000,
+			);
+
+			paid_professional(customer, doctor, requestor_id);
+			paid_professional(customer, other_doctor, requestor_id);
+
+			assert_ok!(Opinion::submit(RuntimeOrigin::signed(doctor), requestor_id, info.clone()));
+			assert_ok!(Opinion::submit(RuntimeOrigin::signed(other_doctor), requestor_id, info));
+
+			let opinion_id = Opinion::opinion_by_owner(doctor)[0];
+			let other_opinion_id = Opinion::opinion_by_owner(other_doctor)[0];
+			let pallet_account_id = Opinion::pallet_account_id();
+
+			assert_eq!(Balances::free_balance(pallet_account_id), 2_000);
+
+			assert_ok!(Opinion::accept(RuntimeOrigin::signed(customer), opinion_id));
+
+			assert_eq!(Balances::free_balance(pallet_account_id), 1_000);
+
+			// The last escrow empties the escrow account
+			assert_ok!(Opinion::reject(RuntimeOrigin::signed(admin), other_opinion_id));
+
+			assert_eq!(Balances::free_balance(pallet_account_id), 0);
+			assert_eq!(Balances::free_balance(customer), 9_000);
+			assert_eq!(Balances::free_balance(doctor), 950);
+			assert_eq!(Balances::free_balance(treasury), 50);
+			assert_eq!(Opinion::escrow_by_request(requestor_id, other_doctor), None);
+		});
+	}
+
+	#[test]
+	fn cant_pay_opinion_when_not_requestor_or_already_paid() {
+		ExternalityBuilder::build().execute_with(|| {
+			let admin = 1;
+			let doctor = 2;
+			let customer = 3;
+			let other_doctor = 4;
+
+			OpinionAdminKey::<Test>::put(admin);
+
+			let info = RequestorInfo::new(
+				b"category".to_vec().try_into().unwrap(),
+				b"description".to_vec().try_into().unwrap(),
+				Default::default(),
+				Default::default(),
+				b"myriad_url".to_vec().try_into().unwrap(),
+			);
+
+			assert_ok!(OpinionRequestor::request_opinion(RuntimeOrigin::signed(customer), info));
+
+			let requestor_ids = OpinionRequestor::opinion_requestor_by_owner(customer);
+			let requestor_id = requestor_ids[0];
+
+			let info = OpinionInfo::new(
+				b"description".to_vec().try_into().unwrap(),
+				b"myriad_url".to_vec().try_into().unwrap(),
+				None,
+				CurrencyType::DBIO,
+				1000,
+			);
+
+			invited_professional(customer, doctor, requestor_id);
+
+			assert_noop!(
+				Opinion::pay(
+					RuntimeOrigin::signed(doctor),
+					requestor_id,
+					doctor,
+					CurrencyType::DBIO,
+					None,
+					1000
+				),
+				Error::<Test>::Unauthorized,
+			);
+
+			assert_noop!(
+				Opinion::pay(
+					RuntimeOrigin::signed(customer),
+					requestor_id,
+					other_doctor,
+					CurrencyType::DBIO,
+					None,
+					1000
+				),
+				Error::<Test>::NotInvited,
+			);
+
+			assert_ok!(Opinion::pay(
+				RuntimeOrigin::signed(customer),
+				requestor_id,
+				doctor,
+				CurrencyType::DBIO,
+				None,
+				1000
+			));
+
+			assert_noop!(
+				Opinion::pay(
+					RuntimeOrigin::signed(customer),
+					requestor_id,
+					doctor,
+					CurrencyType::DBIO,
+					None,
+					1000
+				),
+				Error::<Test>::OpinionAlreadyPaid,
+			);
+
+			assert_ok!(Opinion::submit(RuntimeOrigin::signed(doctor), requestor_id, info));
+
+			let opinion_ids = Opinion::opinion_by_owner(doctor);
+			let opinion_id = opinion_ids[0];
+
+			assert_noop!(
+				Opinion::accept(RuntimeOrigin::signed(customer), opinion_id),
+				Error::<Test>::TreasuryKeyNotFound,
+			);
+		});
+	}
+
+	#[test]
+	fn submit_edit_and_withdraw_opinion_works() {
+		ExternalityBuilder::build().execute_with(|| {
+			let doctor = 2;
+			let customer = 3;
+
+			verified_health_professional(doctor);
+
+			let info = RequestorInfo::new(
+				b"category".to_vec().try_into().unwrap(),
+				b"description".to_vec().try_into().unwrap(),
+				Default::default(),
+				Default::default(),
+				b"myriad_url".to_vec().try_into().unwrap(),
+			);
+
+			assert_ok!(OpinionRequestor::request_opinion(RuntimeOrigin::signed(customer), info));
+
+			let requestor_ids = OpinionRequestor::opinion_requestor_by_owner(customer);
+			let requestor_id = requestor_ids[0];
+
+			assert_ok!(OpinionRequestor::invite_professional(
+				RuntimeOrigin::signed(customer),
+				requestor_id,
+				doctor
+			));
+			assert_ok!(OpinionRequestor::accept_invitation(
+				RuntimeOrigin::signed(doctor),
+				requestor_id
+			));
+
+			let info = OpinionInfo::new(
+				b"description".to_vec().try_into().unwrap(),
+				b"myriad_url".to_vec().try_into().unwrap(),
+				None,
+				CurrencyType::DBIO,
+				1000,
+			);
+
+			assert_ok!(Opinion::submit(RuntimeOrigin::signed(doctor), requestor_id, info.clone()));
+
+			let opinion_ids = Opinion::opinion_by_owner(doctor);
+			let opinion_id = opinion_ids[0];
+			let opinion = OpinionStruct::new(&opinion_id, &requestor_id, &doctor, &info, 0);
+
+			assert_eq!(Opinion::opinion_by_id(opinion_id), Some(opinion));
+			assert_eq!(
+				OpinionRequestor::opinion_requestor_by_id(requestor_id)
+					.unwrap()
+					.info()
+					.opinion_ids(),
+				vec![opinion_id]
+			);
+
+			let updated_info = OpinionInfo::new(
+				b"new_description".to_vec().try_into().unwrap(),
+				b"myriad_url".to_vec().try_into().unwrap(),
+				None,
+				CurrencyType::DBIO,
+				2000,
+			);
+
+			assert_ok!(Opinion::edit(
+				RuntimeOrigin::signed(doctor),
+				opinion_id,
+				updated_info.clone()
+			));
+
+			let opinion = OpinionStruct::new(&opinion_id, &requestor_id, &doctor, &updated_info, 0);
+
+			System::assert_last_event(RuntimeEvent::Opinion(OpinionEvent::OpinionUpdated(
+				doctor,
+				opinion.clone(),
+			)));
+			assert_eq!(Opinion::opinion_by_id(opinion_id), Some(opinion));
+
+			assert_ok!(Opinion::withdraw(RuntimeOrigin::signed(doctor), opinion_id));
+
+			System::assert_last_event(RuntimeEvent::Opinion(OpinionEvent::OpinionRemoved(
+				doctor, opinion_id,
+			)));
+			assert_eq!(Opinion::opinion_by_id(opinion_id), None);
+			assert_eq!(Opinion::opinion_count_by_owner(doctor), 0);
+		});
+	}
+
+	#[test]
+	fn cant_submit_opinion_when_not_verified_or_not_accepted() {
+		ExternalityBuilder::build().execute_with(|| {
+			let doctor = 2;
+			let customer = 3;
+
+			let info = RequestorInfo::new(
+				b"category".to_vec().try_into().unwrap(),
+				b"description".to_vec().try_into().unwrap(),
+				Default::default(),
+				Default::default(),
+				b"myriad_url".to_vec().try_into().unwrap(),
+			);
+
+			assert_ok!(OpinionRequestor::request_opinion(RuntimeOrigin::signed(customer), info));
+
+			let requestor_ids = OpinionRequestor::opinion_requestor_by_owner(customer);
+			let requestor_id = requestor_ids[0];
+
+			let info = OpinionInfo::new(
+				b"description".to_vec().try_into().unwrap(),
+				b"myriad_url".to_vec().try_into().unwrap(),
+				None,
+				CurrencyType::DBIO,
+				1000,
+			);
+
+			assert_noop!(
+				Opinion::submit(RuntimeOrigin::signed(doctor), requestor_id, info.clone()),
+				Error::<Test>::NotVerifiedHealthProfessional,
+			);
+
+			verified_health_professional(doctor);
+
+			assert_noop!(
+				Opinion::submit(RuntimeOrigin::signed(doctor), requestor_id, info.clone()),
+				Error::<Test>::NotInvited,
+			);
+
+			assert_ok!(OpinionRequestor::invite_professional(
+				RuntimeOrigin::signed(customer),
+				requestor_id,
+				doctor
+			));
+
+			assert_noop!(
+				Opinion::submit(RuntimeOrigin::signed(doctor), requestor_id, info.clone()),
+				Error::<Test>::InvitationNotAccepted,
+			);
+
+			let random_requestor_id =
+				Keccak256::hash("0xDb9Af2d1f3ADD2726A132AA7A65Cc9E6fC5761C3".as_bytes());
+
+			assert_noop!(
+				Opinion::submit(RuntimeOrigin::signed(doctor), random_requestor_id, info),
+				Error::<Test>::NotFound,
+			);
+		});
+	}
+
+	#[test]
+	fn cant_edit_or_withdraw_opinion_when_not_owner_or_paid() {
+		ExternalityBuilder::build().execute_with(|| {
+			let doctor = 2;
+			let customer = 3;
+			let other_doctor = 4;
+
+			verified_health_professional(doctor);
+			verified_health_professional(other_doctor);
+
+			let info = RequestorInfo::new(
+				b"category".to_vec().try_into().unwrap(),
+				b"description".to_vec().try_into().unwrap(),
+				Default::default(),
+				Default::default(),
+				b"myriad_url".to_vec().try_into().unwrap(),
+			);
+
+			assert_ok!(OpinionRequestor::request_opinion(RuntimeOrigin::signed(customer), info));
+
+			let requestor_ids = OpinionRequestor::opinion_requestor_by_owner(customer);
+			let requestor_id = requestor_ids[0];
+
+			assert_ok!(OpinionRequestor::invite_professional(
+				RuntimeOrigin::signed(customer),
+				requestor_id,
+				doctor
+			));
+			assert_ok!(OpinionRequestor::accept_invitation(
+				RuntimeOrigin::signed(doctor),
+				requestor_id
+			));
+			assert_ok!(Opinion::pay(
+				RuntimeOrigin::signed(customer),
+				requestor_id,
+				doctor,
+				CurrencyType::DBIO,
+				None,
+				1000
+			));
+
+			let info = OpinionInfo::new(
+				b"description".to_vec().try_into().unwrap(),
+				b"myriad_url".to_vec().try_into().unwrap(),
+				None,
+				CurrencyType::DBIO,
+				1000,
+			);
+
+			assert_ok!(Opinion::submit(RuntimeOrigin::signed(doctor), requestor_id, info.clone()));
+
+			let opinion_ids = Opinion::opinion_by_owner(doctor);
+			let opinion_id = opinion_ids[0];
+
+			assert_noop!(
+				Opinion::edit(RuntimeOrigin::signed(other_doctor), opinion_id, info.clone()),
+				Error::<Test>::Unauthorized,
+			);
+
+			assert_noop!(
+				Opinion::withdraw(RuntimeOrigin::signed(other_doctor), opinion_id),
+				Error::<Test>::Unauthorized,
+			);
+
+			assert_noop!(
+				Opinion::edit(RuntimeOrigin::signed(doctor), opinion_id, info),
+				Error::<Test>::OpinionAlreadyPaid,
+			);
+
+			assert_noop!(
+				Opinion::withdraw(RuntimeOrigin::signed(doctor), opinion_id),
+				Error::<Test>::OpinionAlreadyPaid,
+			);
+		});
+	}
+
+	#[test]
+	fn cant_withdraw_opinion_when_accepted_or_refunded() {
+		ExternalityBuilder::build().execute_with(|| {
+			let admin = 1;
+			let doctor = 2;
+			let customer = 3;
+			let other_doctor = 4;
+			let treasury = 5;
+
+			OpinionAdminKey::<Test>::put(admin);
+			OpinionTreasuryKey::<Test>::put(treasury);
+
+			let info = RequestorInfo::new(
+				b"category".to_vec().try_into().unwrap(),
+				b"description".to_vec().try_into().unwrap(),
+				Default::default(),
+				Default::default(),
+				b"myriad_url".to_vec().try_into().unwrap(),
+			);
+
+			assert_ok!(OpinionRequestor::request_opinion(RuntimeOrigin::signed(customer), info));
+
+			let requestor_ids = OpinionRequestor::opinion_requestor_by_owner(customer);
+			let requestor_id = requestor_ids[0];
+
+			let info = OpinionInfo::new(
+				b"description".to_vec().try_into().unwrap(),
+				b"myriad_url".to_vec().try_into().unwrap(),
+				None,
+				CurrencyType::DBIO,
+				1000,
+			);
+
+			paid_professional(customer, doctor, requestor_id);
+			paid_professional(customer, other_doctor, requestor_id);
+
+			assert_ok!(Opinion::submit(RuntimeOrigin::signed(doctor), requestor_id, info.clone()));
+			assert_ok!(Opinion::submit(RuntimeOrigin::signed(other_doctor), requestor_id, info));
+
+			let opinion_id = Opinion::opinion_by_owner(doctor)[0];
+			let other_opinion_id = Opinion::opinion_by_owner(other_doctor)[0];
+
+			assert_ok!(Opinion::accept(RuntimeOrigin::signed(customer), opinion_id));
+			assert_ok!(Opinion::reject(RuntimeOrigin::signed(admin), other_opinion_id));
+
+			assert_noop!(
+				Opinion::withdraw(RuntimeOrigin::signed(doctor), opinion_id),
+				Error::<Test>::OpinionAlreadyPaid,
+			);
+
+			assert_noop!(
+				Opinion::withdraw(RuntimeOrigin::signed(other_doctor), other_opinion_id),
+				Error::<Test>::OpinionAlreadyPaid,
+			);
+
+			assert!(Opinion::opinion_by_id(opinion_id).is_some());
+			assert!(Opinion::opinion_by_id(other_opinion_id).is_some());
+		});
+	}
+
+	#[test]
+	fn cant_submit_more_than_max_opinions_per_owner() {
+		ExternalityBuilder::build().execute_with(|| {
+			let doctor = 2;
+			let customer = 3;
+
+			let info = RequestorInfo::new(
+				b"category".to_vec().try_into().unwrap(),
+				b"description".to_vec().try_into().unwrap(),
+				Default::default(),
+				Default::default(),
+				b"myriad_url".to_vec().try_into().unwrap(),
+			);
+
+			assert_ok!(OpinionRequestor::request_opinion(RuntimeOrigin::signed(customer), info));
+
+			let requestor_ids = OpinionRequestor::opinion_requestor_by_owner(customer);
+			let requestor_id = requestor_ids[0];
+
+			invited_professional(customer, doctor, requestor_id);
+
+			let info = OpinionInfo::new(
+				b"description".to_vec().try_into().unwrap(),
+				b"myriad_url".to_vec().try_into().unwrap(),
+				None,
+				CurrencyType::DBIO,
+				1000,
+			);
+
+			// MaxOpinionsPerOwner is 3 in the mock
+			for _ in 0..3 {
+				assert_ok!(Opinion::submit(
+					RuntimeOrigin::signed(doctor),
+					requestor_id,
+					info.clone()
+				));
+			}
+
+			assert_noop!(
+				Opinion::submit(RuntimeOrigin::signed(doctor), requestor_id, info),
+				Error::<Test>::TooManyOwnedOpinions,
+			);
+		});
+	}
+
+	#[test]
+	fn migrate_opinion_info_to_bounded_fields() {
+		ExternalityBuilder::build().execute_with(|| {
+			let opinion_id = Keccak256::hash("opinion_id".as_bytes());
+			let requestor_id = Keccak256::hash("requestor_id".as_bytes());
+			let description = vec![0u8; MAX_OPINION_DESCRIPTION_LENGTH as usize + 1];
+
+			put_storage_value(
+				b"Opinion",
+				b"Opinions",
+				&Blake2_128Concat::hash(&opinion_id.encode()),
+				(
+					opinion_id,
+					requestor_id,
+					2u64,
+					(
+						description.clone(),
+						b"myriad_url".to_vec(),
+						None::<u32>,
+						CurrencyType::DBIO,
+						1000u128,
+					),
+					Status::Paid,
+					0u64,
+				),
+			);
+			StorageVersion::new(0).put::<Opinion>();
+
+			migrations::migrate::<Test>();
+
+			let info = OpinionInfo::new(
+				BoundedVec::truncate_from(description),
+				b"myriad_url".to_vec().try_into().unwrap(),
+				None,
+				CurrencyType::DBIO,
+				1000,
+			);
+			let mut opinion = OpinionStruct::new(&opinion_id, &requestor_id, &2, &info, 0);
+			opinion.update_status(&Status::Paid);
+
+			assert_eq!(Opinion::opinion_by_id(opinion_id), Some(opinion));
+			assert_eq!(StorageVersion::get::<Opinion>(), StorageVersion::new(1));
+		});
+	}
 }
@@ -8,3 +8,11 @@ pub trait OpinionCountT<T: Config> {
 	fn add_opinion_count_by_owner(account_id: &T::AccountId, value: u64);
 	fn substract_opinion_count_by_owner(account_id: &T::AccountId, value: u64);
 }
+
+pub trait OpinionProvider<T: Config> {
+	/// Returns the professional behind an opinion that `account_id` has paid for.
+	fn paid_opinion_professional(
+		opinion_id: &T::Hash,
+		account_id: &T::AccountId,
+	) -> Option<T::AccountId>;
+}
@@ -23,13 +23,13 @@ use primitives_price_and_currency::PriceByCurrency;
 
 #[allow(unused)]
 use orders::Pallet as Orders;
-use orders::{AccountKeyType, Config as OrdersConfig, EscrowKey};
+use orders::{AccountKeyType, Config as OrdersConfig, EscrowKey, MAX_DISPUTE_REASON_LENGTH};
 
 #[allow(unused)]
 use genetic_testing::Pallet as GeneticTesting;
 use genetic_testing::{Config as GeneticTestingConfig, DnaSampleStatus, DnaTestResultSubmission};
 
-use frame_benchmarking::{benchmarks, vec, whitelisted_caller};
+use frame_benchmarking::{benchmarks, vec, whitelisted_caller, Vec};
 use frame_system::RawOrigin;
 
 pub struct Pallet<T: Config>(Orders<T>);
@@ -39,7 +39,7 @@ pub trait Config:
 {
 }
 
-use frame_support::sp_runtime::traits::Hash;
+use frame_support::sp_runtime::{traits::Hash, Perbill};
 use orders::Call;
 use primitives_area_code::{CityCode, CountryCode, RegionCode};
 
@@ -50,17 +50,17 @@ benchmarks! {
 
 		let lab = LabInfo {
 			box_public_key: T::Hashing::hash("0xDb9Af2d1f3ADD2726A132AA7A65Cc9E6fC5761C3".as_bytes()),
-			name: "DeBio Lab".as_bytes().to_vec(),
-			email: "DeBio Email".as_bytes().to_vec(),
-			country: CountryCode::from_vec("DC".as_bytes().to_vec()),
-			region: RegionCode::from_vec("DBIO".as_bytes().to_vec()),
-			city: CityCode::from_vec("City".as_bytes().to_vec()),
-			address: "DeBio Address".as_bytes().to_vec(),
-			phone_number: "+6281394653625".as_bytes().to_vec(),
-			website: "DeBio Website".as_bytes().to_vec(),
-			latitude: Some("DeBio Latitude".as_bytes().to_vec()),
-			longitude: Some("DeBio Longtitude".as_bytes().to_vec()),
-			profile_image: Some("DeBio Profile Image uwu".as_bytes().to_vec()),
+			name: "DeBio Lab".as_bytes().to_vec().try_into().unwrap(),
+			email: "DeBio Email".as_bytes().to_vec().try_into().unwrap(),
+			country: CountryCode::try_from("ID".as_bytes().to_vec()).unwrap(),
+			region: RegionCode::try_from("JK".as_bytes().to_vec()).unwrap(),
+			city: CityCode::try_from("City".as_bytes().to_vec()).unwrap(),
+			address: "DeBio Address".as_bytes().to_vec().try_into().unwrap(),
+			phone_number: "+6281394653625".as_bytes().to_vec().try_into().unwrap(),
+			website: "DeBio Website".as_bytes().to_vec().try_into().unwrap(),
+			latitude: Some("-6.175392".as_bytes().to_vec().try_into().unwrap()),
+			longitude: Some("106.827153".as_bytes().to_vec().try_into().unwrap()),
+			profile_image: Some("DeBio Profile Image uwu".as_bytes().to_vec().try_into().unwrap()),
 		};
 		let _add_labs = Labs::<T>::register_lab(caller_origin.clone(), lab);
 
@@ -68,17 +68,17 @@ benchmarks! {
 		let _set_eth_address = UserProfile::<T>::set_eth_address(caller_origin.clone(), eth_address);
 
 		let service_info = ServiceInfo {
-			name: "DeBio name".as_bytes().to_vec(),
+			name: "DeBio name".as_bytes().to_vec().try_into().unwrap(),
 			prices_by_currency: vec![
 				PriceByCurrency::default()
-			],
+			].try_into().unwrap(),
 			expected_duration: ExpectedDuration::default(),
-			category: "DeBio category".as_bytes().to_vec(),
-			description: "This is my description".as_bytes().to_vec(),
-			test_result_sample: "Test result sample".as_bytes().to_vec(),
-			dna_collection_process : "Dna Collection Process".as_bytes().to_vec(),
-			long_description: Some("This is my long description".as_bytes().to_vec()),
-			image: Some("This is my image".as_bytes().to_vec()),
+			category: "DeBio category".as_bytes().to_vec().try_into().unwrap(),
+			description: "This is my description".as_bytes().to_vec().try_into().unwrap(),
+			test_result_sample: "Test result sample".as_bytes().to_vec().try_into().unwrap(),
+			dna_collection_process : "Dna Collection Process".as_bytes().to_vec().try_into().unwrap(),
+			long_description: Some("This is my long description".as_bytes().to_vec().try_into().unwrap()),
+			image: Some("This is my image".as_bytes().to_vec().try_into().unwrap()),
 		};
 		let _create_service = Services::<T>::create_service(caller_origin, service_info, ServiceFlow::default());
 
@@ -93,23 +93,76 @@ benchmarks! {
 		None
 	)
 
+	create_bundle_order {
+		let n in 1 .. T::MaxBundleItems::get();
+
+		let caller: T::AccountId = EscrowKey::<T>::get().unwrap();
+		let caller_origin = T::RuntimeOrigin::from(RawOrigin::Signed(caller.clone()));
+
+		let lab = LabInfo {
+			box_public_key: T::Hashing::hash("0xDb9Af2d1f3ADD2726A132AA7A65Cc9E6fC5761C3".as_bytes()),
+			name: "DeBio Lab".as_bytes().to_vec().try_into().unwrap(),
+			email: "DeBio Email".as_bytes().to_vec().try_into().unwrap(),
+			country: CountryCode::try_from("ID".as_bytes().to_vec()).unwrap(),
+			region: RegionCode::try_from("JK".as_bytes().to_vec()).unwrap(),
+			city: CityCode::try_from("City".as_bytes().to_vec()).unwrap(),
+			address: "DeBio Address".as_bytes().to_vec().try_into().unwrap(),
+			phone_number: "+6281394653625".as_bytes().to_vec().try_into().unwrap(),
+			website: "DeBio Website".as_bytes().to_vec().try_into().unwrap(),
+			latitude: Some("-6.175392".as_bytes().to_vec().try_into().unwrap()),
+			longitude: Some("106.827153".as_bytes().to_vec().try_into().unwrap()),
+			profile_image: Some("DeBio Profile Image uwu".as_bytes().to_vec().try_into().unwrap()),
+		};
+		let _add_labs = Labs::<T>::register_lab(caller_origin.clone(), lab);
+
+		let eth_address = <T as UserProfileConfig>::EthereumAddress::default();
+		let _set_eth_address = UserProfile::<T>::set_eth_address(caller_origin.clone(), eth_address);
+
+		for _ in 0 .. n {
+			let service_info = ServiceInfo {
+				name: "DeBio name".as_bytes().to_vec().try_into().unwrap(),
+				prices_by_currency: vec![
+					PriceByCurrency::default()
+				].try_into().unwrap(),
+				expected_duration: ExpectedDuration::default(),
+				category: "DeBio category".as_bytes().to_vec().try_into().unwrap(),
+				description: "This is my description".as_bytes().to_vec().try_into().unwrap(),
+				test_result_sample: "Test result sample".as_bytes().to_vec().try_into().unwrap(),
+				dna_collection_process : "Dna Collection Process".as_bytes().to_vec().try_into().unwrap(),
+				long_description: Some("This is my long description".as_bytes().to_vec().try_into().unwrap()),
+				image: Some("This is my image".as_bytes().to_vec().try_into().unwrap()),
+			};
+			let _create_service = Services::<T>::create_service(caller_origin.clone(), service_info, ServiceFlow::default());
+		}
+
+		let _lab = Labs::<T>::lab_by_account_id(caller.clone())
+			.unwrap();
+		let _items = _lab.services.iter().map(|service_id| (*service_id, 0)).collect::<Vec<_>>();
+	}: create_bundle_order(
+		RawOrigin::Signed(caller),
+		_items,
+		T::Hashing::hash("0xhJ7TRe456FADD2726A132ABJK5RCc9E6fC5869F4".as_bytes()),
+		StakingRequestService,
+		None
+	)
+
 	cancel_order {
 		let caller: T::AccountId = EscrowKey::<T>::get().unwrap();
 		let caller_origin = T::RuntimeOrigin::from(RawOrigin::Signed(caller.clone()));
 
 		let lab = LabInfo {
 			box_public_key: T::Hashing::hash("0xDb9Af2d1f3ADD2726A132AA7A65Cc9E6fC5761C3".as_bytes()),
-			name: "DeBio Lab".as_bytes().to_vec(),
-			email: "DeBio Email".as_bytes().to_vec(),
-			country: CountryCode::from_vec("DC".as_bytes().to_vec()),
-			region: RegionCode::from_vec("DBIO".as_bytes().to_vec()),
-			city: CityCode::from_vec("City".as_bytes().to_vec()),
-			address: "DeBio Address".as_bytes().to_vec(),
-			phone_number: "+6281394653625".as_bytes().to_vec(),
-			website: "DeBio Website".as_bytes().to_vec(),
-			latitude: Some("DeBio Latitude".as_bytes().to_vec()),
-			longitude: Some("DeBio Longtitude".as_bytes().to_vec()),
-			profile_image: Some("DeBio Profile Image uwu".as_bytes().to_vec()),
+			name: "DeBio Lab".as_bytes().to_vec().try_into().unwrap(),
+			email: "DeBio Email".as_bytes().to_vec().try_into().unwrap(),
+			country: CountryCode::try_from("ID".as_bytes().to_vec()).unwrap(),
+			region: RegionCode::try_from("JK".as_bytes().to_vec()).unwrap(),
+			city: CityCode::try_from("City".as_bytes().to_vec()).unwrap(),
+			address: "DeBio Address".as_bytes().to_vec().try_into().unwrap(),
+			phone_number: "+6281394653625".as_bytes().to_vec().try_into().unwrap(),
+			website: "DeBio Website".as_bytes().to_vec().try_into().unwrap(),
+			latitude: Some("-6.175392".as_bytes().to_vec().try_into().unwrap()),
+			longitude: Some("106.827153".as_bytes().to_vec().try_into().unwrap()),
+			profile_image: Some("DeBio Profile Image uwu".as_bytes().to_vec().try_into().unwrap()),
 		};
 		let _add_labs = Labs::<T>::register_lab(caller_origin.clone(), lab);
 
@@ -117,17 +170,17 @@ benchmarks! {
 		let _set_eth_address = UserProfile::<T>::set_eth_address(caller_origin.clone(), eth_address);
 
 		let service_info = ServiceInfo {
-			name: "DeBio name".as_bytes().to_vec(),
+			name: "DeBio name".as_bytes().to_vec().try_into().unwrap(),
 			prices_by_currency: vec![
 				PriceByCurrency::default()
-			],
+			].try_into().unwrap(),
 			expected_duration: ExpectedDuration::default(),
-			category: "DeBio category".as_bytes().to_vec(),
-			description: "This is my description".as_bytes().to_vec(),
-			test_result_sample: "Test result sample".as_bytes().to_vec(),
-			dna_collection_process : "Dna Collection Process".as_bytes().to_vec(),
-			long_description: Some("This is my long description".as_bytes().to_vec()),
-			image: Some("This is my image".as_bytes().to_vec()),
+			category: "DeBio category".as_bytes().to_vec().try_into().unwrap(),
+			description: "This is my description".as_bytes().to_vec().try_into().unwrap(),
+			test_result_sample: "Test result sample".as_bytes().to_vec().try_into().unwrap(),
+			dna_collection_process : "Dna Collection Process".as_bytes().to_vec().try_into().unwrap(),
+			long_description: Some("This is my long description".as_bytes().to_vec().try_into().unwrap()),
+			image: Some("This is my image".as_bytes().to_vec().try_into().unwrap()),
 		};
 		let _create_service = Services::<T>::create_service(caller_origin.clone(), service_info, ServiceFlow::default());
 
@@ -143,8 +196,7 @@ benchmarks! {
 			None,
 		);
 
-		let _order_id_list = Orders::<T>::orders_by_lab_id(caller.clone())
-			.unwrap();
+		let _order_id_list = Orders::<T>::orders_by_lab_id(&caller);
 		let _order = Orders::<T>::order_by_id(_order_id_list[0])
 			.unwrap();
 	}: cancel_order(
@@ -158,17 +210,17 @@ benchmarks! {
 
 		let lab = LabInfo {
 			box_public_key: T::Hashing::hash("0xDb9Af2d1f3ADD2726A132AA7A65Cc9E6fC5761C3".as_bytes()),
-			name: "DeBio Lab".as_bytes().to_vec(),
-			email: "DeBio Email".as_bytes().to_vec(),
-			country: CountryCode::from_vec("DC".as_bytes().to_vec()),
-			region: RegionCode::from_vec("DBIO".as_bytes().to_vec()),
-			city: CityCode::from_vec("City".as_bytes().to_vec()),
-			address: "DeBio Address".as_bytes().to_vec(),
-			phone_number: "+6281394653625".as_bytes().to_vec(),
-			website: "DeBio Website".as_bytes().to_vec(),
-			latitude: Some("DeBio Latitude".as_bytes().to_vec()),
-			longitude: Some("DeBio Longtitude".as_bytes().to_vec()),
-			profile_image: Some("DeBio Profile Image uwu".as_bytes().to_vec()),
+			name: "DeBio Lab".as_bytes().to_vec().try_into().unwrap(),
+			email: "DeBio Email".as_bytes().to_vec().try_into().unwrap(),
+			country: CountryCode::try_from("ID".as_bytes().to_vec()).unwrap(),
+			region: RegionCode::try_from("JK".as_bytes().to_vec()).unwrap(),
+			city: CityCode::try_from("City".as_bytes().to_vec()).unwrap(),
+			address: "DeBio Address".as_bytes().to_vec().try_into().unwrap(),
+			phone_number: "+6281394653625".as_bytes().to_vec().try_into().unwrap(),
+			website: "DeBio Website".as_bytes().to_vec().try_into().unwrap(),
+			latitude: Some("-6.175392".as_bytes().to_vec().try_into().unwrap()),
+			longitude: Some("106.827153".as_bytes().to_vec().try_into().unwrap()),
+			profile_image: Some("DeBio Profile Image uwu".as_bytes().to_vec().try_into().unwrap()),
 		};
 		let _add_labs = Labs::<T>::register_lab(caller_origin.clone(), lab);
 
@@ -176,17 +228,17 @@ benchmarks! {
 		let _set_eth_address = UserProfile::<T>::set_eth_address(caller_origin.clone(), eth_address);
 
 		let service_info = ServiceInfo {
-			name: "DeBio name".as_bytes().to_vec(),
+			name: "DeBio name".as_bytes().to_vec().try_into().unwrap(),
 			prices_by_currency: vec![
 				PriceByCurrency::default()
-			],
+			].try_into().unwrap(),
 			expected_duration: ExpectedDuration::default(),
-			category: "DeBio category".as_bytes().to_vec(),
-			description: "This is my description".as_bytes().to_vec(),
-			test_result_sample: "Test result sample".as_bytes().to_vec(),
-			dna_collection_process : "Dna Collection Process".as_bytes().to_vec(),
-			long_description: Some("This is my long description".as_bytes().to_vec()),
-			image: Some("This is my image".as_bytes().to_vec()),
+			category: "DeBio category".as_bytes().to_vec().try_into().unwrap(),
+			description: "This is my description".as_bytes().to_vec().try_into().unwrap(),
+			test_result_sample: "Test result sample".as_bytes().to_vec().try_into().unwrap(),
+			dna_collection_process : "Dna Collection Process".as_bytes().to_vec().try_into().unwrap(),
+			long_description: Some("This is my long description".as_bytes().to_vec().try_into().unwrap()),
+			image: Some("This is my image".as_bytes().to_vec().try_into().unwrap()),
 		};
 		let _create_service = Services::<T>::create_service(caller_origin.clone(), service_info, ServiceFlow::default());
 
@@ -202,8 +254,7 @@ benchmarks! {
 			None,
 		);
 
-		let _order_id_list = Orders::<T>::orders_by_lab_id(caller.clone())
-			.unwrap();
+		let _order_id_list = Orders::<T>::orders_by_lab_id(&caller);
 		let _order = Orders::<T>::order_by_id(_order_id_list[0])
 			.unwrap();
 	}: set_order_paid(
@@ -217,17 +268,17 @@ benchmarks! {
 
 		let lab = LabInfo {
 			box_public_key: T::Hashing::hash("0xDb9Af2d1f3ADD2726A132AA7A65Cc9E6fC5761C3".as_bytes()),
-			name: "DeBio Lab".as_bytes().to_vec(),
-			email: "DeBio Email".as_bytes().to_vec(),
-			country: CountryCode::from_vec("DC".as_bytes().to_vec()),
-			region: RegionCode::from_vec("DBIO".as_bytes().to_vec()),
-			city: CityCode::from_vec("City".as_bytes().to_vec()),
-			address: "DeBio Address".as_bytes().to_vec(),
-			phone_number: "+6281394653625".as_bytes().to_vec(),
-			website: "DeBio Website".as_bytes().to_vec(),
-			latitude: Some("DeBio Latitude".as_bytes().to_vec()),
-			longitude: Some("DeBio Longtitude".as_bytes().to_vec()),
-			profile_image: Some("DeBio Profile Image uwu".as_bytes().to_vec()),
+			name: "DeBio Lab".as_bytes().to_vec().try_into().unwrap(),
+			email: "DeBio Email".as_bytes().to_vec().try_into().unwrap(),
+			country: CountryCode::try_from("ID".as_bytes().to_vec()).unwrap(),
+			region: RegionCode::try_from("JK".as_bytes().to_vec()).unwrap(),
+			city: CityCode::try_from("City".as_bytes().to_vec()).unwrap(),
+			address: "DeBio Address".as_bytes().to_vec().try_into().unwrap(),
+			phone_number: "+6281394653625".as_bytes().to_vec().try_into().unwrap(),
+			website: "DeBio Website".as_bytes().to_vec().try_into().unwrap(),
+			latitude: Some("-6.175392".as_bytes().to_vec().try_into().unwrap()),
+			longitude: Some("106.827153".as_bytes().to_vec().try_into().unwrap()),
+			profile_image: Some("DeBio Profile Image uwu".as_bytes().to_vec().try_into().unwrap()),
 		};
 		let _add_labs = Labs::<T>::register_lab(caller_origin.clone(), lab);
 
@@ -235,17 +286,17 @@ benchmarks! {
 		let _set_eth_address = UserProfile::<T>::set_eth_address(caller_origin.clone(), eth_address);
 
 		let service_info = ServiceInfo {
-			name: "DeBio name".as_bytes().to_vec(),
+			name: "DeBio name".as_bytes().to_vec().try_into().unwrap(),
 			prices_by_currency: vec![
 				PriceByCurrency::default()
-			],
+			].try_into().unwrap(),
 			expected_duration: ExpectedDuration::default(),
-			category: "DeBio category".as_bytes().to_vec(),
-			description: "This is my description".as_bytes().to_vec(),
-			test_result_sample: "Test result sample".as_bytes().to_vec(),
-			dna_collection_process : "Dna Collection Process".as_bytes().to_vec(),
-			long_description: Some("This is my long description".as_bytes().to_vec()),
-			image: Some("This is my image".as_bytes().to_vec()),
+			category: "DeBio category".as_bytes().to_vec().try_into().unwrap(),
+			description: "This is my description".as_bytes().to_vec().try_into().unwrap(),
+			test_result_sample: "Test result sample".as_bytes().to_vec().try_into().unwrap(),
+			dna_collection_process : "Dna Collection Process".as_bytes().to_vec().try_into().unwrap(),
+			long_description: Some("This is my long description".as_bytes().to_vec().try_into().unwrap()),
+			image: Some("This is my image".as_bytes().to_vec().try_into().unwrap()),
 		};
 		let _create_service = Services::<T>::create_service(caller_origin.clone(), service_info, ServiceFlow::default());
 
@@ -261,8 +312,7 @@ benchmarks! {
 			None,
 		);
 
-		let _order_id_list = Orders::<T>::orders_by_lab_id(caller.clone())
-			.unwrap();
+		let _order_id_list = Orders::<T>::orders_by_lab_id(&caller);
 		let _order = Orders::<T>::order_by_id(_order_id_list[0])
 			.unwrap();
 
@@ -272,9 +322,9 @@ benchmarks! {
 		);
 
 		let _dna_test_result = DnaTestResultSubmission {
-			comments: Some("DNA Test Result comments".as_bytes().to_vec()),
-			result_link: Some("DNA Test Result result_link".as_bytes().to_vec()),
-			report_link: Some("DNA Test Result report_link".as_bytes().to_vec())
+			comments: Some("DNA Test Result comments".as_bytes().to_vec().try_into().unwrap()),
+			result_link: Some("DNA Test Result result_link".as_bytes().to_vec().try_into().unwrap()),
+			report_link: Some("DNA Test Result report_link".as_bytes().to_vec().try_into().unwrap())
 		};
 
 		let _submit_test_result = GeneticTesting::<T>::submit_test_result(
@@ -299,17 +349,17 @@ benchmarks! {
 
 		let lab = LabInfo {
 			box_public_key: T::Hashing::hash("0xDb9Af2d1f3ADD2726A132AA7A65Cc9E6fC5761C3".as_bytes()),
-			name: "DeBio Lab".as_bytes().to_vec(),
-			email: "DeBio Email".as_bytes().to_vec(),
-			country: CountryCode::from_vec("DC".as_bytes().to_vec()),
-			region: RegionCode::from_vec("DBIO".as_bytes().to_vec()),
-			city: CityCode::from_vec("City".as_bytes().to_vec()),
-			address: "DeBio Address".as_bytes().to_vec(),
-			phone_number: "+6281394653625".as_bytes().to_vec(),
-			website: "DeBio Website".as_bytes().to_vec(),
-			latitude: Some("DeBio Latitude".as_bytes().to_vec()),
-			longitude: Some("DeBio Longtitude".as_bytes().to_vec()),
-			profile_image: Some("DeBio Profile Image uwu".as_bytes().to_vec()),
+			name: "DeBio Lab".as_bytes().to_vec().try_into().unwrap(),
+			email: "DeBio Email".as_bytes().to_vec().try_into().unwrap(),
+			country: CountryCode::try_from("ID".as_bytes().to_vec()).unwrap(),
+			region: RegionCode::try_from("JK".as_bytes().to_vec()).unwrap(),
+			city: CityCode::try_from("City".as_bytes().to_vec()).unwrap(),
+			address: "DeBio Address".as_bytes().to_vec().try_into().unwrap(),
+			phone_number: "+6281394653625".as_bytes().to_vec().try_into().unwrap(),
+			website: "DeBio Website".as_bytes().to_vec().try_into().unwrap(),
+			latitude: Some("-6.175392".as_bytes().to_vec().try_into().unwrap()),
+			longitude: Some("106.827153".as_bytes().to_vec().try_into().unwrap()),
+			profile_image: Some("DeBio Profile Image uwu".as_bytes().to_vec().try_into().unwrap()),
 		};
 		let _add_labs = Labs::<T>::register_lab(caller_origin.clone(), lab);
 
@@ -317,17 +367,17 @@ benchmarks! {
 		let _set_eth_address = UserProfile::<T>::set_eth_address(caller_origin.clone(), eth_address);
 
 		let service_info = ServiceInfo {
-			name: "DeBio name".as_bytes().to_vec(),
+			name: "DeBio name".as_bytes().to_vec().try_into().unwrap(),
 			prices_by_currency: vec![
 				PriceByCurrency::default()
-			],
+			].try_into().unwrap(),
 			expected_duration: ExpectedDuration::default(),
-			category: "DeBio category".as_bytes().to_vec(),
-			description: "This is my description".as_bytes().to_vec(),
-			test_result_sample: "Test result sample".as_bytes().to_vec(),
-			dna_collection_process : "Dna Collection Process".as_bytes().to_vec(),
-			long_description: Some("This is my long description".as_bytes().to_vec()),
-			image: Some("This is my image".as_bytes().to_vec()),
+			category: "DeBio category".as_bytes().to_vec().try_into().unwrap(),
+			description: "This is my description".as_bytes().to_vec().try_into().unwrap(),
+			test_result_sample: "Test result sample".as_bytes().to_vec().try_into().unwrap(),
+			dna_collection_process : "Dna Collection Process".as_bytes().to_vec().try_into().unwrap(),
+			long_description: Some("This is my long description".as_bytes().to_vec().try_into().unwrap()),
+			image: Some("This is my image".as_bytes().to_vec().try_into().unwrap()),
 		};
 		let _create_service = Services::<T>::create_service(caller_origin.clone(), service_info, ServiceFlow::default());
 
@@ -343,8 +393,7 @@ benchmarks! {
 			None,
 		);
 
-		let _order_id_list = Orders::<T>::orders_by_lab_id(caller.clone())
-			.unwrap();
+		let _order_id_list = Orders::<T>::orders_by_lab_id(&caller);
 		let _order = Orders::<T>::order_by_id(_order_id_list[0])
 			.unwrap();
 
@@ -364,6 +413,534 @@ benchmarks! {
 		_order.id
 	)
 
+	claim_expired_refund {
+		let caller: T::AccountId = EscrowKey::<T>::get().unwrap();
+		let caller_origin = T::RuntimeOrigin::from(RawOrigin::Signed(caller.clone()));
+
+		let lab = LabInfo {
+			box_public_key: T::Hashing::hash("0xDb9Af2d1f3ADD2726A132AA7A65Cc9E6fC5761C3".as_bytes()),
+			name: "DeBio Lab".as_bytes().to_vec().try_into().unwrap(),
+			email: "DeBio Email".as_bytes().to_vec().try_into().unwrap(),
+			country: CountryCode::try_from("ID".as_bytes().to_vec()).unwrap(),
+			region: RegionCode::try_from("JK".as_bytes().to_vec()).unwrap(),
+			city: CityCode::try_from("City".as_bytes().to_vec()).unwrap(),
+			address: "DeBio Address".as_bytes().to_vec().try_into().unwrap(),
+			phone_number: "+6281394653625".as_bytes().to_vec().try_into().unwrap(),
+			website: "DeBio Website".as_bytes().to_vec().try_into().unwrap(),
+			latitude: Some("-6.175392".as_bytes().to_vec().try_into().unwrap()),
+			longitude: Some("106.827153".as_bytes().to_vec().try_into().unwrap()),
+			profile_image: Some("DeBio Profile Image uwu".as_bytes().to_vec().try_into().unwrap()),
+		};
+		let _add_labs = Labs::<T>::register_lab(caller_origin.clone(), lab);
+
+		let eth_address = <T as UserProfileConfig>::EthereumAddress::default();
+		let _set_eth_address = UserProfile::<T>::set_eth_address(caller_origin.clone(), eth_address);
+
+		let service_info = ServiceInfo {
+			name: "DeBio name".as_bytes().to_vec().try_into().unwrap(),
+			prices_by_currency: vec![
+				PriceByCurrency::default()
+			].try_into().unwrap(),
+			expected_duration: ExpectedDuration::default(),
+			category: "DeBio category".as_bytes().to_vec().try_into().unwrap(),
+			description: "This is my description".as_bytes().to_vec().try_into().unwrap(),
+			test_result_sample: "Test result sample".as_bytes().to_vec().try_into().unwrap(),
+			dna_collection_process : "Dna Collection Process".as_bytes().to_vec().try_into().unwrap(),
+			long_description: Some("This is my long description".as_bytes().to_vec().try_into().unwrap()),
+			image: Some("This is my image".as_bytes().to_vec().try_into().unwrap()),
+		};
+		let _create_service = Services::<T>::create_service(caller_origin.clone(), service_info, ServiceFlow::default());
+
+		let _lab = Labs::<T>::lab_by_account_id(caller.clone())
+			.unwrap();
+
+		let _create_order = Orders::<T>::create_order(
+			caller_origin.clone(),
+			_lab.services[0],
+			0,
+			T::Hashing::hash("0xhJ7TRe456FADD2726A132ABJK5RCc9E6fC5869F4".as_bytes()),
+			StakingRequestService,
+			None,
+		);
+
+		let _order_id_list = Orders::<T>::orders_by_lab_id(&caller);
+		let _order = Orders::<T>::order_by_id(_order_id_list[0])
+			.unwrap();
+
+		let _set_order_paid = Orders::<T>::set_order_paid(
+			caller_origin.clone(),
+			_order.id
+		);
+
+		let _deadline = Orders::<T>::refund_deadline_by_order_id(_order.id)
+			.unwrap();
+		pallet_timestamp::Pallet::<T>::set_timestamp(_deadline);
+	}: claim_expired_refund(
+		RawOrigin::Signed(caller),
+		_order.id
+	)
+
+	open_dispute {
+		let caller: T::AccountId = EscrowKey::<T>::get().unwrap();
+		let caller_origin = T::RuntimeOrigin::from(RawOrigin::Signed(caller.clone()));
+
+		let lab = LabInfo {
+			box_public_key: T::Hashing::hash("0xDb9Af2d1f3ADD2726A132AA7A65Cc9E6fC5761C3".as_bytes()),
+			name: "DeBio Lab".as_bytes().to_vec().try_into().unwrap(),
+			email: "DeBio Email".as_bytes().to_vec().try_into().unwrap(),
+			country: CountryCode::try_from("ID".as_bytes().to_vec()).unwrap(),
+			region: RegionCode::try_from("JK".as_bytes().to_vec()).unwrap(),
+			city: CityCode::try_from("City".as_bytes().to_vec()).unwrap(),
+			address: "DeBio Address".as_bytes().to_vec().try_into().unwrap(),
+			phone_number: "+6281394653625".as_bytes().to_vec().try_into().unwrap(),
+			website: "DeBio Website".as_bytes().to_vec().try_into().unwrap(),
+			latitude: Some("-6.175392".as_bytes().to_vec().try_into().unwrap()),
+			longitude: Some("106.827153".as_bytes().to_vec().try_into().unwrap()),
+			profile_image: Some("DeBio Profile Image uwu".as_bytes().to_vec().try_into().unwrap()),
+		};
+		let _add_labs = Labs::<T>::register_lab(caller_origin.clone(), lab);
+
+		let eth_address = <T as UserProfileConfig>::EthereumAddress::default();
+		let _set_eth_address = UserProfile::<T>::set_eth_address(caller_origin.clone(), eth_address);
+
+		let service_info = ServiceInfo {
+			name: "DeBio name".as_bytes().to_vec().try_into().unwrap(),
+			prices_by_currency: vec![
+				PriceByCurrency::default()
+			].try_into().unwrap(),
+			expected_duration: ExpectedDuration::default(),
+			category: "DeBio category".as_bytes().to_vec().try_into().unwrap(),
+			description: "This is my description".as_bytes().to_vec().try_into().unwrap(),
+			test_result_sample: "Test result sample".as_bytes().to_vec().try_into().unwrap(),
+			dna_collection_process : "Dna Collection Process".as_bytes().to_vec().try_into().unwrap(),
+			long_description: Some("This is my long description".as_bytes().to_vec().try_into().unwrap()),
+			image: Some("This is my image".as_bytes().to_vec().try_into().unwrap()),
+		};
+		let _create_service = Services::<T>::create_service(caller_origin.clone(), service_info, ServiceFlow::default());
+
+		let _lab = Labs::<T>::lab_by_account_id(caller.clone())
+			.unwrap();
+
+		let _create_order = Orders::<T>::create_order(
+			caller_origin.clone(),
+			_lab.services[0],
+			0,
+			T::Hashing::hash("0xhJ7TRe456FADD2726A132ABJK5RCc9E6fC5869F4".as_bytes()),
+			RequestTest,
+			None,
+		);
+
+		let _order_id_list = Orders::<T>::orders_by_lab_id(&caller);
+		let _order = Orders::<T>::order_by_id(_order_id_list[0])
+			.unwrap();
+
+		let _set_order_paid = Orders::<T>::set_order_paid(
+			caller_origin.clone(),
+			_order.id
+		);
+
+		let _dna_test_result = DnaTestResultSubmission {
+			comments: Some("DNA Test Result comments".as_bytes().to_vec().try_into().unwrap()),
+			result_link: Some("DNA Test Result result_link".as_bytes().to_vec().try_into().unwrap()),
+			report_link: Some("DNA Test Result report_link".as_bytes().to_vec().try_into().unwrap())
+		};
+
+		let _submit_test_result = GeneticTesting::<T>::submit_test_result(
+			caller_origin.clone(),
+			_order.dna_sample_tracking_id.clone(),
+			_dna_test_result
+		);
+
+		let _ = GeneticTesting::<T>::process_dna_sample(
+			caller_origin.clone(),
+			_order.dna_sample_tracking_id,
+			DnaSampleStatus::ResultReady
+		);
+
+		let _fulfill_order = Orders::<T>::fulfill_order(
+			caller_origin.clone(),
+			_order.id
+		);
+	}: open_dispute(
+		RawOrigin::Signed(caller),
+		_order.id,
+		vec![b'X'; MAX_DISPUTE_REASON_LENGTH as usize]
+	)
+
+	resolve_dispute {
+		let caller: T::AccountId = EscrowKey::<T>::get().unwrap();
+		let caller_origin = T::RuntimeOrigin::from(RawOrigin::Signed(caller.clone()));
+
+		let lab = LabInfo {
+			box_public_key: T::Hashing::hash("0xDb9Af2d1f3ADD2726A132AA7A65Cc9E6fC5761C3".as_bytes()),
+			name: "DeBio Lab".as_bytes().to_vec().try_into().unwrap(),
+			email: "DeBio Email".as_bytes().to_vec().try_into().unwrap(),
+			country: CountryCode::try_from("ID".as_bytes().to_vec()).unwrap(),
+			region: RegionCode::try_from("JK".as_bytes().to_vec()).unwrap(),
+			city: CityCode::try_from("City".as_bytes().to_vec()).unwrap(),
+			address: "DeBio Address".as_bytes().to_vec().try_into().unwrap(),
+			phone_number: "+6281394653625".as_bytes().to_vec().try_into().unwrap(),
+			website: "DeBio Website".as_bytes().to_vec().try_into().unwrap(),
+			latitude: Some("-6.175392".as_bytes().to_vec().try_into().unwrap()),
+			longitude: Some("106.827153".as_bytes().to_vec().try_into().unwrap()),
+			profile_image: Some("DeBio Profile Image uwu".as_bytes().to_vec().try_into().unwrap()),
+		};
+		let _add_labs = Labs::<T>::register_lab(caller_origin.clone(), lab);
+
+		let eth_address = <T as UserProfileConfig>::EthereumAddress::default();
+		let _set_eth_address = UserProfile::<T>::set_eth_address(caller_origin.clone(), eth_address);
+
+		let service_info = ServiceInfo {
+			name: "DeBio name".as_bytes().to_vec().try_into().unwrap(),
+			prices_by_currency: vec![
+				PriceByCurrency::default()
+			].try_into().unwrap(),
+			expected_duration: ExpectedDuration::default(),
+			category: "DeBio category".as_bytes().to_vec().try_into().unwrap(),
+			description: "This is my description".as_bytes().to_vec().try_into().unwrap(),
+			test_result_sample: "Test result sample".as_bytes().to_vec().try_into().unwrap(),
+			dna_collection_process : "Dna Collection Process".as_bytes().to_vec().try_into().unwrap(),
+			long_description: Some("This is my long description".as_bytes().to_vec().try_into().unwrap()),
+			image: Some("This is my image".as_bytes().to_vec().try_into().unwrap()),
+		};
+		let _create_service = Services::<T>::create_service(caller_origin.clone(), service_info, ServiceFlow::default());
+
+		let _lab = Labs::<T>::lab_by_account_id(caller.clone())
+			.unwrap();
+
+		let _create_order = Orders::<T>::create_order(
+			caller_origin.clone(),
+			_lab.services[0],
+			0,
+			T::Hashing::hash("0xhJ7TRe456FADD2726A132ABJK5RCc9E6fC5869F4".as_bytes()),
+			RequestTest,
+			None,
+		);
+
+		let _order_id_list = Orders::<T>::orders_by_lab_id(&caller);
+		let _order = Orders::<T>::order_by_id(_order_id_list[0])
+			.unwrap();
+
+		let _set_order_paid = Orders::<T>::set_order_paid(
+			caller_origin.clone(),
+			_order.id
+		);
+
+		let _dna_test_result = DnaTestResultSubmission {
+			comments: Some("DNA Test Result comments".as_bytes().to_vec().try_into().unwrap()),
+			result_link: Some("DNA Test Result result_link".as_bytes().to_vec().try_into().unwrap()),
+			report_link: Some("DNA Test Result report_link".as_bytes().to_vec().try_into().unwrap())
+		};
+
+		let _submit_test_result = GeneticTesting::<T>::submit_test_result(
+			caller_origin.clone(),
+			_order.dna_sample_tracking_id.clone(),
+			_dna_test_result
+		);
+
+		let _ = GeneticTesting::<T>::process_dna_sample(
+			caller_origin.clone(),
+			_order.dna_sample_tracking_id,
+			DnaSampleStatus::ResultReady
+		);
+
+		let _fulfill_order = Orders::<T>::fulfill_order(
+			caller_origin.clone(),
+			_order.id
+		);
+
+		let _open_dispute = Orders::<T>::open_dispute(
+			caller_origin,
+			_order.id,
+			"Dispute reason".as_bytes().to_vec()
+		);
+	}: resolve_dispute(
+		RawOrigin::Root,
+		_order.id,
+		Perbill::from_percent(50)
+	)
+
+	release_order_settlement {
+		let caller: T::AccountId = EscrowKey::<T>::get().unwrap();
+		let caller_origin = T::RuntimeOrigin::from(RawOrigin::Signed(caller.clone()));
+
+		let lab = LabInfo {
+			box_public_key: T::Hashing::hash("0xDb9Af2d1f3ADD2726A132AA7A65Cc9E6fC5761C3".as_bytes()),
+			name: "DeBio Lab".as_bytes().to_vec().try_into().unwrap(),
+			email: "DeBio Email".as_bytes().to_vec().try_into().unwrap(),
+			country: CountryCode::try_from("ID".as_bytes().to_vec()).unwrap(),
+			region: RegionCode::try_from("JK".as_bytes().to_vec()).unwrap(),
+			city: CityCode::try_from("City".as_bytes().to_vec()).unwrap(),
+			address: "DeBio Address".as_bytes().to_vec().try_into().unwrap(),
+			phone_number: "+6281394653625".as_bytes().to_vec().try_into().unwrap(),
+			website: "DeBio Website".as_bytes().to_vec().try_into().unwrap(),
+			latitude: Some("-6.175392".as_bytes().to_vec().try_into().unwrap()),
+			longitude: Some("106.827153".as_bytes().to_vec().try_into().unwrap()),
+			profile_image: Some("DeBio Profile Image uwu".as_bytes().to_vec().try_into().unwrap()),
+		};
+		let _add_labs = Labs::<T>::register_lab(caller_origin.clone(), lab);
+
+		let eth_address = <T as UserProfileConfig>::EthereumAddress::default();
+		let _set_eth_address = UserProfile::<T>::set_eth_address(caller_origin.clone(), eth_address);
+
+		let service_info = ServiceInfo {
+			name: "DeBio name".as_bytes().to_vec().try_into().unwrap(),
+			prices_by_currency: vec![
+				PriceByCurrency::default()
+			].try_into().unwrap(),
+			expected_duration: ExpectedDuration::default(),
+			category: "DeBio category".as_bytes().to_vec().try_into().unwrap(),
+			description: "This is my description".as_bytes().to_vec().try_into().unwrap(),
+			test_result_sample: "Test result sample".as_bytes().to_vec().try_into().unwrap(),
+			dna_collection_process : "Dna Collection Process".as_bytes().to_vec().try_into().unwrap(),
+			long_description: Some("This is my long description".as_bytes().to_vec().try_into().unwrap()),
+			image: Some("This is my image".as_bytes().to_vec().try_into().unwrap()),
+		};
+		let _create_service = Services::<T>::create_service(caller_origin.clone(), service_info, ServiceFlow::default());
+
+		let _lab = Labs::<T>::lab_by_account_id(caller.clone())
+			.unwrap();
+
+		let _create_order = Orders::<T>::create_order(
+			caller_origin.clone(),
+			_lab.services[0],
+			0,
+			T::Hashing::hash("0xhJ7TRe456FADD2726A132ABJK5RCc9E6fC5869F4".as_bytes()),
+			RequestTest,
+			None,
+		);
+
+		let _order_id_list = Orders::<T>::orders_by_lab_id(&caller);
+		let _order = Orders::<T>::order_by_id(_order_id_list[0])
+			.unwrap();
+
+		let _set_order_paid = Orders::<T>::set_order_paid(
+			caller_origin.clone(),
+			_order.id
+		);
+
+		let _dna_test_result = DnaTestResultSubmission {
+			comments: Some("DNA Test Result comments".as_bytes().to_vec().try_into().unwrap()),
+			result_link: Some("DNA Test Result result_link".as_bytes().to_vec().try_into().unwrap()),
+			report_link: Some("DNA Test Result report_link".as_bytes().to_vec().try_into().unwrap())
+		};
+
+		let _submit_test_result = GeneticTesting::<T>::submit_test_result(
+			caller_origin.clone(),
+			_order.dna_sample_tracking_id.clone(),
+			_dna_test_result
+		);
+
+		let _ = GeneticTesting::<T>::process_dna_sample(
+			caller_origin.clone(),
+			_order.dna_sample_tracking_id,
+			DnaSampleStatus::ResultReady
+		);
+
+		let _fulfill_order = Orders::<T>::fulfill_order(
+			caller_origin.clone(),
+			_order.id
+		);
+
+		let _settlement = Orders::<T>::order_settlement_by_order_id(_order.id)
+			.unwrap();
+		pallet_timestamp::Pallet::<T>::set_timestamp(_settlement.release_at);
+	}: release_order_settlement(
+		RawOrigin::Signed(caller),
+		_order.id
+	)
+
+	create_payment_plan {
+		let caller: T::AccountId = EscrowKey::<T>::get().unwrap();
+		let caller_origin = T::RuntimeOrigin::from(RawOrigin::Signed(caller.clone()));
+
+		let lab = LabInfo {
+			box_public_key: T::Hashing::hash("0xDb9Af2d1f3ADD2726A132AA7A65Cc9E6fC5761C3".as_bytes()),
+			name: "DeBio Lab".as_bytes().to_vec().try_into().unwrap(),
+			email: "DeBio Email".as_bytes().to_vec().try_into().unwrap(),
+			country: CountryCode::try_from("ID".as_bytes().to_vec()).unwrap(),
+			region: RegionCode::try_from("JK".as_bytes().to_vec()).unwrap(),
+			city: CityCode::try_from("City".as_bytes().to_vec()).unwrap(),
+			address: "DeBio Address".as_bytes().to_vec().try_into().unwrap(),
+			phone_number: "+6281394653625".as_bytes().to_vec().try_into().unwrap(),
+			website: "DeBio Website".as_bytes().to_vec().try_into().unwrap(),
+			latitude: Some("-6.175392".as_bytes().to_vec().try_into().unwrap()),
+			longitude: Some("106.827153".as_bytes().to_vec().try_into().unwrap()),
+			profile_image: Some("DeBio Profile Image uwu".as_bytes().to_vec().try_into().unwrap()),
+		};
+		let _add_labs = Labs::<T>::register_lab(caller_origin.clone(), lab);
+
+		let eth_address = <T as UserProfileConfig>::EthereumAddress::default();
+		let _set_eth_address = UserProfile::<T>::set_eth_address(caller_origin.clone(), eth_address);
+
+		let service_info = ServiceInfo {
+			name: "DeBio name".as_bytes().to_vec().try_into().unwrap(),
+			prices_by_currency: vec![
+				PriceByCurrency::default()
+			].try_into().unwrap(),
+			expected_duration: ExpectedDuration::default(),
+			category: "DeBio category".as_bytes().to_vec().try_into().unwrap(),
+			description: "This is my description".as_bytes().to_vec().try_into().unwrap(),
+			test_result_sample: "Test result sample".as_bytes().to_vec().try_into().unwrap(),
+			dna_collection_process : "Dna Collection Process".as_bytes().to_vec().try_into().unwrap(),
+			long_description: Some("This is my long description".as_bytes().to_vec().try_into().unwrap()),
+			image: Some("This is my image".as_bytes().to_vec().try_into().unwrap()),
+		};
+		let _create_service = Services::<T>::create_service(caller_origin.clone(), service_info, ServiceFlow::default());
+
+		let _lab = Labs::<T>::lab_by_account_id(caller.clone())
+			.unwrap();
+
+		let _create_order = Orders::<T>::create_order(
+			caller_origin.clone(),
+			_lab.services[0],
+			0,
+			T::Hashing::hash("0xhJ7TRe456FADD2726A132ABJK5RCc9E6fC5869F4".as_bytes()),
+			StakingRequestService,
+			None,
+		);
+
+		let _order_id_list = Orders::<T>::orders_by_lab_id(&caller);
+		let _order = Orders::<T>::order_by_id(_order_id_list[0])
+			.unwrap();
+	}: create_payment_plan(
+		RawOrigin::Signed(caller),
+		_order.id,
+		2
+	)
+
+	pay_installment {
+		let caller: T::AccountId = EscrowKey::<T>::get().unwrap();
+		let caller_origin = T::RuntimeOrigin::from(RawOrigin::Signed(caller.clone()));
+
+		let lab = LabInfo {
+			box_public_key: T::Hashing::hash("0xDb9Af2d1f3ADD2726A132AA7A65Cc9E6fC5761C3".as_bytes()),
+			name: "DeBio Lab".as_bytes().to_vec().try_into().unwrap(),
+			email: "DeBio Email".as_bytes().to_vec().try_into().unwrap(),
+			country: CountryCode::try_from("ID".as_bytes().to_vec()).unwrap(),
+			region: RegionCode::try_from("JK".as_bytes().to_vec()).unwrap(),
+			city: CityCode::try_from("City".as_bytes().to_vec()).unwrap(),
+			address: "DeBio Address".as_bytes().to_vec().try_into().unwrap(),
+			phone_number: "+6281394653625".as_bytes().to_vec().try_into().unwrap(),
+			website: "DeBio Website".as_bytes().to_vec().try_into().unwrap(),
+			latitude: Some("-6.175392".as_bytes().to_vec().try_into().unwrap()),
+			longitude: Some("106.827153".as_bytes().to_vec().try_into().unwrap()),
+			profile_image: Some("DeBio Profile Image uwu".as_bytes().to_vec().try_into().unwrap()),
+		};
+		let _add_labs = Labs::<T>::register_lab(caller_origin.clone(), lab);
+
+		let eth_address = <T as UserProfileConfig>::EthereumAddress::default();
+		let _set_eth_address = UserProfile::<T>::set_eth_address(caller_origin.clone(), eth_address);
+
+		let service_info = ServiceInfo {
+			name: "DeBio name".as_bytes().to_vec().try_into().unwrap(),
+			prices_by_currency: vec![
+				PriceByCurrency::default()
+			].try_into().unwrap(),
+			expected_duration: ExpectedDuration::default(),
+			category: "DeBio category".as_bytes().to_vec().try_into().unwrap(),
+			description: "This is my description".as_bytes().to_vec().try_into().unwrap(),
+			test_result_sample: "Test result sample".as_bytes().to_vec().try_into().unwrap(),
+			dna_collection_process : "Dna Collection Process".as_bytes().to_vec().try_into().unwrap(),
+			long_description: Some("This is my long description".as_bytes().to_vec().try_into().unwrap()),
+			image: Some("This is my image".as_bytes().to_vec().try_into().unwrap()),
+		};
+		let _create_service = Services::<T>::create_service(caller_origin.clone(), service_info, ServiceFlow::default());
+
+		let _lab = Labs::<T>::lab_by_account_id(caller.clone())
+			.unwrap();
+
+		let _create_order = Orders::<T>::create_order(
+			caller_origin.clone(),
+			_lab.services[0],
+			0,
+			T::Hashing::hash("0xhJ7TRe456FADD2726A132ABJK5RCc9E6fC5869F4".as_bytes()),
+			StakingRequestService,
+			None,
+		);
+
+		let _order_id_list = Orders::<T>::orders_by_lab_id(&caller);
+		let _order = Orders::<T>::order_by_id(_order_id_list[0])
+			.unwrap();
+
+		let _create_payment_plan = Orders::<T>::create_payment_plan(
+			caller_origin,
+			_order.id,
+			2
+		);
+	}: pay_installment(
+		RawOrigin::Signed(caller),
+		_order.id
+	)
+
+	cancel_overdue_order {
+		let caller: T::AccountId = EscrowKey::<T>::get().unwrap();
+		let caller_origin = T::RuntimeOrigin::from(RawOrigin::Signed(caller.clone()));
+
+		let lab = LabInfo {
+			box_public_key: T::Hashing::hash("0xDb9Af2d1f3ADD2726A132AA7A65Cc9E6fC5761C3".as_bytes()),
+			name: "DeBio Lab".as_bytes().to_vec().try_into().unwrap(),
+			email: "DeBio Email".as_bytes().to_vec().try_into().unwrap(),
+			country: CountryCode::try_from("ID".as_bytes().to_vec()).unwrap(),
+			region: RegionCode::try_from("JK".as_bytes().to_vec()).unwrap(),
+			city: CityCode::try_from("City".as_bytes().to_vec()).unwrap(),
+			address: "DeBio Address".as_bytes().to_vec().try_into().unwrap(),
+			phone_number: "+6281394653625".as_bytes().to_vec().try_into().unwrap(),
+			website: "DeBio Website".as_bytes().to_vec().try_into().unwrap(),
+			latitude: Some("-6.175392".as_bytes().to_vec().try_into().unwrap()),
+			longitude: Some("106.827153".as_bytes().to_vec().try_into().unwrap()),
+			profile_image: Some("DeBio Profile Image uwu".as_bytes().to_vec().try_into().unwrap()),
+		};
+		let _add_labs = Labs::<T>::register_lab(caller_origin.clone(), lab);
+
+		let eth_address = <T as UserProfileConfig>::EthereumAddress::default();
+		let _set_eth_address = UserProfile::<T>::set_eth_address(caller_origin.clone(), eth_address);
+
+		let service_info = ServiceInfo {
+			name: "DeBio name".as_bytes().to_vec().try_into().unwrap(),
+			prices_by_currency: vec![
+				PriceByCurrency::default()
+			].try_into().unwrap(),
+			expected_duration: ExpectedDuration::default(),
+			category: "DeBio category".as_bytes().to_vec().try_into().unwrap(),
+			description: "This is my description".as_bytes().to_vec().try_into().unwrap(),
+			test_result_sample: "Test result sample".as_bytes().to_vec().try_into().unwrap(),
+			dna_collection_process : "Dna Collection Process".as_bytes().to_vec().try_into().unwrap(),
+			long_description: Some("This is my long description".as_bytes().to_vec().try_into().unwrap()),
+			image: Some("This is my image".as_bytes().to_vec().try_into().unwrap()),
+		};
+		let _create_service = Services::<T>::create_service(caller_origin.clone(), service_info, ServiceFlow::default());
+
+		let _lab = Labs::<T>::lab_by_account_id(caller.clone())
+			.unwrap();
+
+		let _create_order = Orders::<T>::create_order(
+			caller_origin.clone(),
+			_lab.services[0],
+			0,
+			T::Hashing::hash("0xhJ7TRe456FADD2726A132ABJK5RCc9E6fC5869F4".as_bytes()),
+			StakingRequestService,
+			None,
+		);
+
+		let _order_id_list = Orders::<T>::orders_by_lab_id(&caller);
+		let _order = Orders::<T>::order_by_id(_order_id_list[0])
+			.unwrap();
+
+		let _create_payment_plan = Orders::<T>::create_payment_plan(
+			caller_origin,
+			_order.id,
+			2
+		);
+
+		let _payment_plan = Orders::<T>::payment_plan_by_order_id(_order.id)
+			.unwrap();
+		pallet_timestamp::Pallet::<T>::set_timestamp(_payment_plan.installments[0].due_at + 1u32.into());
+	}: cancel_overdue_order(
+		RawOrigin::Signed(caller),
+		_order.id
+	)
+
 	update_key {
 		let caller: T::AccountId = EscrowKey::<T>::get().unwrap();
 		let caller2: T::AccountId = whitelisted_caller();
@@ -3,12 +3,17 @@ use crate::*;
 use frame_support::{
 	pallet_prelude::*,
 	sp_runtime::{
-		traits::{Hash, Zero},
+		traits::{Hash, Saturating, Zero},
 		SaturatedConversion,
 	},
+	storage::{
+		with_storage_layer, IterableStorageDoubleMap, StorageDoubleMap as StorageDoubleMapT,
+		StorageValue as StorageValueT,
+	},
 	traits::{fungibles, Currency, ExistenceRequirement},
 };
-use primitives_price_and_currency::CurrencyType;
+use primitives_platform_fee::FeeScheduleProvider;
+use primitives_price_and_currency::{CurrencyType, Price};
 use scale_info::prelude::string::String;
 use sp_std::vec;
 use traits_order::OrderProvider;
@@ -28,6 +33,25 @@ impl<T: Config> Pallet<T> {
 	}
 
 	pub fn update_order_status(order_id: &T::Hash, status: OrderStatus) -> Option<OrderOf<T>> {
+		if status != OrderStatus::Paid {
+			Self::remove_refund_deadline(order_id);
+		}
+
+		if status != OrderStatus::Unpaid {
+			Self::remove_installment_deadline(order_id);
+		}
+
+		// Failed bundle items keep their status, they have been settled already
+		OrderItems::<T>::mutate_exists(order_id, |order_items| {
+			if let Some(order_items) = order_items {
+				for order_item in order_items.iter_mut() {
+					if order_item.status != OrderStatus::Failed {
+						order_item.status = status.clone();
+					}
+				}
+			}
+		});
+
 		Orders::<T>::mutate(order_id, |order| match order {
 			None => None,
 			Some(order) => {
@@ -38,6 +62,37 @@ impl<T: Config> Pallet<T> {
 		})
 	}
 
+	pub fn add_order_paid_amount(order_id: &T::Hash, amount: BalanceOf<T>) -> Option<OrderOf<T>> {
+		Orders::<T>::mutate(order_id, |order| match order {
+			None => None,
+			Some(order) => {
+				order.paid_amount += amount;
+				order.updated_at = pallet_timestamp::Pallet::<T>::get();
+				Some(order.clone())
+			},
+		})
+	}
+
+	pub fn dna_sample_tracking_ids_of(order: &OrderOf<T>) -> Vec<DnaSampleTrackingId> {
+		match OrderItems::<T>::get(order.id) {
+			Some(order_items) => order_items
+				.into_iter()
+				.map(|order_item| order_item.dna_sample_tracking_id)
+				.collect(),
+			None => vec![order.dna_sample_tracking_id.clone()],
+		}
+	}
+
+	pub fn order_prices_of(
+		prices: Vec<Price<BalanceOf<T>>>,
+	) -> Result<OrderPrices<BalanceOf<T>>, Error<T>> {
+		prices.try_into().map_err(|_| Error::<T>::TooManyOrderPrices)
+	}
+
+	pub fn total_price_of(prices: &[Price<BalanceOf<T>>]) -> BalanceOf<T> {
+		prices.iter().fold(Zero::zero(), |total, price| total + price.value)
+	}
+
 	pub fn insert_order_to_storage(order: &OrderOf<T>) {
 		Orders::<T>::insert(order.id, order);
 		LastOrderByCustomer::<T>::insert(&order.customer_id, order.id);
@@ -47,76 +102,359 @@ impl<T: Config> Pallet<T> {
 	}
 
 	pub fn insert_order_id_into_orders_by_seller(order: &OrderOf<T>) {
-		match OrdersBySeller::<T>::get(&order.seller_id) {
-			None => {
-				OrdersBySeller::<T>::insert(&order.seller_id, vec![order.id]);
-			},
-			Some(mut orders) => {
-				orders.push(order.id);
-				OrdersBySeller::<T>::insert(&order.seller_id, orders);
-			},
-		}
+		OrdersBySeller::<T>::insert(&order.seller_id, order.id, ());
 	}
 
 	pub fn insert_order_id_into_orders_by_customer(order: &OrderOf<T>) {
-		match OrdersByCustomer::<T>::get(&order.customer_id) {
-			None => {
-				OrdersByCustomer::<T>::insert(&order.customer_id, vec![order.id]);
-			},
-			Some(mut orders) => {
-				orders.push(order.id);
-				OrdersByCustomer::<T>::insert(&order.customer_id, orders);
-			},
-		}
+		OrdersByCustomer::<T>::insert(&order.customer_id, order.id, ());
 	}
 
 	pub fn insert_order_id_into_pending_orders_by_seller(order: &OrderOf<T>) {
-		match PendingOrdersBySeller::<T>::get(&order.seller_id) {
-			None => {
-				PendingOrdersBySeller::<T>::insert(&order.seller_id, vec![order.id]);
-			},
-			Some(mut orders) => {
-				orders.push(order.id);
-				PendingOrdersBySeller::<T>::insert(&order.seller_id, orders);
-			},
-		}
+		PendingOrdersBySeller::<T>::insert(&order.seller_id, order.id, ());
 	}
 
 	pub fn remove_order_id_from_pending_orders_by_seller(
 		seller_id: &T::AccountId,
 		order_id: &T::Hash,
 	) {
-		let mut orders = PendingOrdersBySeller::<T>::get(seller_id).unwrap_or_default();
-		orders.retain(|o_id| o_id != order_id);
-		PendingOrdersBySeller::<T>::insert(seller_id, orders);
+		PendingOrdersBySeller::<T>::remove(seller_id, order_id);
 	}
 
 	pub fn remove_order_id_from_orders_by_seller(seller_id: &T::AccountId, order_id: &T::Hash) {
-		let mut orders = OrdersBySeller::<T>::get(seller_id).unwrap_or_default();
-		orders.retain(|o_id| o_id != order_id);
-		OrdersBySeller::<T>::insert(seller_id, orders);
+		OrdersBySeller::<T>::remove(seller_id, order_id);
 	}
 
 	pub fn remove_order_id_from_orders_by_customer(customer_id: &T::AccountId, order_id: &T::Hash) {
-		let mut orders = OrdersByCustomer::<T>::get(customer_id).unwrap_or_default();
-		orders.retain(|o_id| o_id != order_id);
-		OrdersByCustomer::<T>::insert(customer_id, orders);
+		OrdersByCustomer::<T>::remove(customer_id, order_id);
+	}
+
+	pub fn orders_by_customer_id(customer_id: &T::AccountId) -> Vec<T::Hash> {
+		OrdersByCustomer::<T>::iter_key_prefix(customer_id).collect()
+	}
+
+	pub fn orders_by_lab_id(seller_id: &T::AccountId) -> Vec<T::Hash> {
+		OrdersBySeller::<T>::iter_key_prefix(seller_id).collect()
+	}
+
+	pub fn pending_orders_by_seller_id(seller_id: &T::AccountId) -> Vec<T::Hash> {
+		PendingOrdersBySeller::<T>::iter_key_prefix(seller_id).collect()
+	}
+
+	/// A bundle order can only be refunded once its slowest service is overdue
+	pub fn insert_refund_deadline(order: &OrderOf<T>) {
+		let service_ids = match OrderItems::<T>::get(order.id) {
+			Some(order_items) =>
+				order_items.iter().map(|order_item| order_item.service_id).collect(),
+			None => vec![order.service_id],
+		};
+
+		let expected_duration = service_ids
+			.iter()
+			.filter_map(T::Services::service_by_id)
+			.map(|service| service.get_expected_duration().as_millis())
+			.max()
+			.unwrap_or_default();
+
+		let deadline: MomentOf<T> = order
+			.updated_at
+			.saturated_into::<u64>()
+			.saturating_add(expected_duration)
+			.saturating_add(T::RefundGracePeriod::get())
+			.saturated_into();
+
+		RefundDeadlineByOrder::<T>::insert(order.id, deadline);
+		Self::queue_order_id::<RefundDeadlines<T>, NextRefundBucket<T>>(&order.id, deadline);
+	}
+
+	pub fn remove_refund_deadline(order_id: &T::Hash) {
+		if let Some(deadline) = RefundDeadlineByOrder::<T>::take(order_id) {
+			RefundDeadlines::<T>::remove(Self::deadline_bucket(deadline), order_id);
+		}
+	}
+
+	/// Refund an order whose deadline passed. Rejected bundle items were refunded when they were
+	/// rejected, so only the remaining items are refunded.
+	pub fn do_refund_expired_order(order: &OrderOf<T>) -> Result<OrderOf<T>, Error<T>> {
+		let (tracking_ids, refund_amount) = match OrderItems::<T>::get(order.id) {
+			Some(order_items) => {
+				let remaining_items =
+					order_items.iter().filter(|item| item.status != OrderStatus::Failed);
+				let tracking_ids = remaining_items
+					.clone()
+					.map(|item| item.dna_sample_tracking_id.clone())
+					.collect();
+				let refund_amount = remaining_items
+					.fold(Zero::zero(), |total: BalanceOf<T>, item| {
+						total.saturating_add(item.total_price)
+					});
+				(tracking_ids, refund_amount)
+			},
+			None => (vec![order.dna_sample_tracking_id.clone()], order.total_price),
+		};
+
+		for tracking_id in tracking_ids.iter() {
+			if let Some(dna_sample) = T::GeneticTesting::dna_sample_by_tracking_id(tracking_id) {
+				if !dna_sample.is_registered() {
+					return Err(Error::<T>::OngoingOrderCannotBeRefunded)
+				}
+			}
+		}
+
+		if order.currency.can_transfer() {
+			let pallet_id = Self::pallet_id().ok_or(Error::<T>::PalletAccountNotFound)?;
+
+			// Joins the held refunds of rejected items, so they are released together
+			Self::do_settle_order(
+				order,
+				&pallet_id,
+				vec![(order.customer_id.clone(), refund_amount)],
+			)?;
+		}
+
+		Self::remove_order_id_from_pending_orders_by_seller(&order.seller_id, &order.id);
+
+		Self::update_order_status(&order.id, OrderStatus::Refunded).ok_or(Error::<T>::OrderNotFound)
+	}
+
+	pub fn refund_expired_orders(remaining_weight: Weight) -> Weight {
+		let refund_weight = T::OrdersWeightInfo::claim_expired_refund();
+		let max_refunds = T::MaxExpiredRefundsPerBlock::get();
+		let now = pallet_timestamp::Pallet::<T>::get();
+
+		let mut consumed_weight = T::DbWeight::get().reads(1);
+		let mut refunded = 0u32;
+
+		while refunded < max_refunds &&
+			consumed_weight.saturating_add(refund_weight) <= remaining_weight
+		{
+			let (_, order_id) = match Self::next_due_order_id::<
+				RefundDeadlines<T>,
+				NextRefundBucket<T>,
+			>(now, &mut consumed_weight, remaining_weight)
+			{
+				Some(entry) => entry,
+				None => break,
+			};
+
+			consumed_weight = consumed_weight.saturating_add(refund_weight);
+			refunded += 1;
+
+			let result = Self::order_by_id(&order_id)
+				.and_then(|order| order.can_refunded())
+				.ok_or(Error::<T>::OrderCannotBeRefunded)
+				.and_then(|order| Self::do_refund_expired_order(&order));
+
+			match result {
+				Ok(order) => Self::deposit_event(Event::OrderRefundedExpired(order)),
+				Err(_) => Self::remove_refund_deadline(&order_id),
+			}
+		}
+
+		consumed_weight
+	}
+
+	pub fn insert_installment_deadline(order_id: &T::Hash, due_at: MomentOf<T>) {
+		InstallmentDeadlineByOrder::<T>::insert(order_id, due_at);
+		Self::queue_order_id::<InstallmentDeadlines<T>, NextInstallmentBucket<T>>(order_id, due_at);
+	}
+
+	pub fn remove_installment_deadline(order_id: &T::Hash) {
+		if let Some(due_at) = InstallmentDeadlineByOrder::<T>::take(order_id) {
+			InstallmentDeadlines::<T>::remove(Self::deadline_bucket(due_at), order_id);
+		}
+	}
+
+	/// Cancel a partially funded order whose next installment is overdue and
+	/// refund what has been paid so far.
+	pub fn do_cancel_overdue_order(order: &OrderOf<T>) -> Result<OrderOf<T>, Error<T>> {
+		let mut order_status = OrderStatus::Cancelled;
+
+		if order.currency.can_transfer() && !order.paid_amount.is_zero() {
+			let pallet_id = Self::pallet_id().ok_or(Error::<T>::PalletAccountNotFound)?;
+
+			order_status = OrderStatus::Refunded;
+
+			Self::do_transfer(
+				&order.currency,
+				&pallet_id,
+				&order.customer_id,
+				order.paid_amount,
+				order.asset_id,
+				false,
+			)?;
+		}
+
+		for tracking_id in Self::dna_sample_tracking_ids_of(order).iter() {
+			let _ = T::GeneticTesting::delete_dna_sample(tracking_id);
+		}
+
+		Self::remove_order_id_from_pending_orders_by_seller(&order.seller_id, &order.id);
+
+		Self::update_order_status(&order.id, order_status).ok_or(Error::<T>::OrderNotFound)
+	}
+
+	pub fn cancel_overdue_orders(remaining_weight: Weight) -> Weight {
+		let cancel_weight = T::OrdersWeightInfo::cancel_overdue_order();
+		let max_cancellations = T::MaxOverduePlansPerBlock::get();
+		let now = pallet_timestamp::Pallet::<T>::get();
+
+		let mut consumed_weight = T::DbWeight::get().reads(1);
+		let mut cancelled = 0u32;
+
+		// An installment is overdue once `now` is past its due moment
+		let overdue_at: MomentOf<T> = match now.saturated_into::<u64>().checked_sub(1) {
+			Some(overdue_at) => overdue_at.saturated_into(),
+			None => return consumed_weight,
+		};
+
+		while cancelled < max_cancellations &&
+			consumed_weight.saturating_add(cancel_weight) <= remaining_weight
+		{
+			let (_, order_id) = match Self::next_due_order_id::<
+				InstallmentDeadlines<T>,
+				NextInstallmentBucket<T>,
+			>(overdue_at, &mut consumed_weight, remaining_weight)
+			{
+				Some(entry) => entry,
+				None => break,
+			};
+
+			consumed_weight = consumed_weight.saturating_add(cancel_weight);
+			cancelled += 1;
+
+			let result = Self::order_by_id(&order_id)
+				.and_then(|order| order.can_paid())
+				.ok_or(Error::<T>::OrderCannotBeCancelled)
+				.and_then(|order| Self::do_cancel_overdue_order(&order));
+
+			match result {
+				Ok(order) => Self::deposit_event(Event::OrderOverdueCancelled(order)),
+				Err(_) => Self::remove_installment_deadline(&order_id),
+			}
+		}
+
+		consumed_weight
 	}
 
-	pub fn order_can_be_refunded(order: &OrderOf<T>) -> bool {
+	pub fn order_can_be_refunded(order: &OrderOf<T>) -> Result<bool, Error<T>> {
 		let dna_sample =
-			T::GeneticTesting::dna_sample_by_tracking_id(&order.dna_sample_tracking_id).unwrap();
-		if !dna_sample.is_rejected() {
-			return false
+			T::GeneticTesting::dna_sample_by_tracking_id(&order.dna_sample_tracking_id)
+				.ok_or(Error::<T>::DnaSampleNotFound)?;
+
+		Ok(dna_sample.is_rejected())
+	}
+
+	/// Refund every rejected item of a paid bundle order, the order itself only
+	/// fails once all of its items have been rejected. An item whose refund can not be
+	/// transferred stays paid, so the refund is retried on the next rejection.
+	pub fn update_order_items_failed(
+		order: &OrderOf<T>,
+		mut order_items: OrderItemsOf<T>,
+		pallet_id: &T::AccountId,
+	) {
+		for order_item in order_items.iter_mut() {
+			if order_item.status != OrderStatus::Paid {
+				continue
+			}
+
+			let is_rejected =
+				T::GeneticTesting::dna_sample_by_tracking_id(&order_item.dna_sample_tracking_id)
+					.map(|dna_sample| dna_sample.is_rejected())
+					.unwrap_or(false);
+
+			if !is_rejected {
+				continue
+			}
+
+			let testing_price = Self::total_price_of(&order_item.prices);
+			let qc_price = Self::total_price_of(&order_item.additional_prices);
+
+			let transfers = vec![
+				(order.get_seller_id().clone(), qc_price),
+				(order.customer_id.clone(), testing_price),
+			];
+
+			// Refunds stay disputable with the rest of the order until the dispute window closes
+			let refunded = with_storage_layer(|| {
+				Self::do_settle_order(order, pallet_id, transfers).map_err(DispatchError::from)
+			});
+
+			if refunded.is_err() {
+				Self::deposit_event(Event::OrderItemRefundFailed(
+					order.clone(),
+					order_item.dna_sample_tracking_id.clone(),
+				));
+				continue
+			}
+
+			order_item.status = OrderStatus::Failed;
 		}
-		true
+
+		let is_failed =
+			order_items.iter().all(|order_item| order_item.status == OrderStatus::Failed);
+
+		OrderItems::<T>::insert(order.id, order_items);
+
+		if is_failed {
+			Self::update_order_status(&order.id, OrderStatus::Failed);
+		}
+	}
+
+	/// A bundle order stays pending until every item is either rejected or has its result ready
+	pub fn is_order_items_finished(order_id: &T::Hash) -> bool {
+		let order_items = match OrderItems::<T>::get(order_id) {
+			Some(order_items) => order_items,
+			None => return true,
+		};
+
+		order_items.iter().all(|order_item| {
+			order_item.status == OrderStatus::Failed ||
+				T::GeneticTesting::dna_sample_by_tracking_id(&order_item.dna_sample_tracking_id)
+					.map(|dna_sample| dna_sample.process_success())
+					.unwrap_or(true)
+		})
+	}
+
+	pub fn get_orders_by_customer_id(
+		customer_id: &T::AccountId,
+		status: Option<OrderStatus>,
+		offset: u32,
+		limit: u32,
+	) -> Vec<OrderOf<T>> {
+		let order_ids = OrdersByCustomer::<T>::iter_key_prefix(customer_id);
+		Self::paginate_orders(order_ids, status, offset, limit)
+	}
+
+	pub fn get_orders_by_seller_id(
+		seller_id: &T::AccountId,
+		status: Option<OrderStatus>,
+		offset: u32,
+		limit: u32,
+	) -> Vec<OrderOf<T>> {
+		let order_ids = OrdersBySeller::<T>::iter_key_prefix(seller_id);
+		Self::paginate_orders(order_ids, status, offset, limit)
+	}
+
+	/// The status filter is applied before the offset, so pages stay contiguous per status.
+	/// Orders are paged in the key order of the index, which is stable between calls but not
+	/// chronological. Only the orders up to the end of the page are read, and a page holds at
+	/// most `MAX_PAGE_LIMIT` orders.
+	pub fn paginate_orders(
+		order_ids: impl Iterator<Item = T::Hash>,
+		status: Option<OrderStatus>,
+		offset: u32,
+		limit: u32,
+	) -> Vec<OrderOf<T>> {
+		order_ids
+			.filter_map(Orders::<T>::get)
+			.filter(|order| status.as_ref().map_or(true, |status| &order.status == status))
+			.skip(offset as usize)
+			.take(limit.min(MAX_PAGE_LIMIT) as usize)
+			.collect()
 	}
 
 	pub fn is_pending_order_ids_by_seller_exist(account_id: &T::AccountId) -> bool {
-		match PendingOrdersBySeller::<T>::get(account_id) {
-			Some(_arr) => !_arr.is_empty(),
-			None => false,
-		}
+		PendingOrdersBySeller::<T>::iter_key_prefix(account_id).next().is_some()
 	}
 
 	pub fn do_validate_asset_id(
@@ -176,6 +514,188 @@ impl<T: Config> Pallet<T> {
 		Ok(())
 	}
 
+	pub fn do_transfers(
+		order: &OrderOf<T>,
+		pallet_id: &T::AccountId,
+		transfers: &[(T::AccountId, BalanceOf<T>)],
+	) -> Result<(), Error<T>> {
+		for (account_id, amount) in transfers.iter() {
+			if amount.is_zero() {
+				continue
+			}
+
+			Self::do_transfer(
+				&order.currency,
+				pallet_id,
+				account_id,
+				*amount,
+				order.asset_id,
+				false,
+			)?;
+		}
+
+		Ok(())
+	}
+
+	/// Pay out the order right away, or keep the funds locked in the pallet account
+	/// until the dispute window closes. Funds already locked for the order, like the refunds
+	/// of rejected bundle items, are added up with the new ones and wait for the new window.
+	pub fn do_settle_order(
+		order: &OrderOf<T>,
+		pallet_id: &T::AccountId,
+		transfers: SettlementTransfersOf<T>,
+	) -> Result<(), Error<T>> {
+		let dispute_window = T::DisputeWindow::get();
+
+		if dispute_window.is_zero() {
+			return Self::do_transfers(order, pallet_id, &transfers)
+		}
+
+		let release_at: MomentOf<T> = pallet_timestamp::Pallet::<T>::get()
+			.saturated_into::<u64>()
+			.saturating_add(dispute_window)
+			.saturated_into();
+
+		let settlement = Self::order_settlement_by_order_id(order.id);
+		let mut settled_transfers = settlement
+			.as_ref()
+			.map(|settlement| settlement.transfers.to_vec())
+			.unwrap_or_default();
+
+		for (account_id, amount) in transfers {
+			match settled_transfers.iter_mut().find(|(settled_id, _)| *settled_id == account_id) {
+				Some((_, settled_amount)) =>
+					*settled_amount = settled_amount.saturating_add(amount),
+				None => settled_transfers.push((account_id, amount)),
+			}
+		}
+
+		let transfers = SettlementTransfers::try_from(settled_transfers)
+			.map_err(|_| Error::<T>::TooManySettlementTransfers)?;
+
+		if let Some(settlement) = settlement {
+			Self::unqueue_settlement_release(&order.id, &settlement);
+		}
+
+		OrderSettlements::<T>::insert(order.id, OrderSettlement::new(transfers, release_at));
+		Self::queue_order_id::<SettlementReleases<T>, NextSettlementBucket<T>>(
+			&order.id, release_at,
+		);
+
+		Ok(())
+	}
+
+	/// Take the locked funds of an order out of storage and off the release queue
+	pub fn take_order_settlement(order_id: &T::Hash) -> Option<OrderSettlementOf<T>> {
+		let settlement = OrderSettlements::<T>::take(order_id)?;
+		Self::unqueue_settlement_release(order_id, &settlement);
+		Some(settlement)
+	}
+
+	pub fn unqueue_settlement_release(order_id: &T::Hash, settlement: &OrderSettlementOf<T>) {
+		SettlementReleases::<T>::remove(Self::deadline_bucket(settlement.release_at), order_id);
+	}
+
+	/// Release the locked funds of orders whose dispute window has closed.
+	pub fn release_order_settlements(remaining_weight: Weight) -> Weight {
+		let release_weight = T::OrdersWeightInfo::release_order_settlement();
+		let now = pallet_timestamp::Pallet::<T>::get();
+
+		let mut consumed_weight = T::DbWeight::get().reads(1);
+
+		while consumed_weight.saturating_add(release_weight) <= remaining_weight {
+			let (bucket, order_id) = match Self::next_due_order_id::<
+				SettlementReleases<T>,
+				NextSettlementBucket<T>,
+			>(now, &mut consumed_weight, remaining_weight)
+			{
+				Some(entry) => entry,
+				None => break,
+			};
+
+			consumed_weight = consumed_weight.saturating_add(release_weight);
+
+			let result = with_storage_layer(|| {
+				<Self as OrderInterface<T>>::release_order_settlement(&order_id)
+					.map_err(DispatchError::from)
+			});
+
+			match result {
+				Ok(order) => Self::deposit_event(Event::OrderSettlementReleased(order)),
+				Err(_) => SettlementReleases::<T>::remove(bucket, order_id),
+			}
+		}
+
+		consumed_weight
+	}
+
+	pub fn deadline_bucket(moment: MomentOf<T>) -> u64 {
+		moment.saturated_into::<u64>() / DEADLINE_BUCKET_MILLIS
+	}
+
+	/// Queue an order id under the bucket of its deadline, the cursor moves back when the
+	/// bucket is older than every bucket still queued.
+	pub fn queue_order_id<Queue, Cursor>(order_id: &T::Hash, deadline: MomentOf<T>)
+	where
+		Queue: StorageDoubleMapT<u64, T::Hash, MomentOf<T>>,
+		Cursor: StorageValueT<u64, Query = Option<u64>>,
+	{
+		let bucket = Self::deadline_bucket(deadline);
+
+		Queue::insert(bucket, order_id, deadline);
+
+		if Cursor::get().map_or(true, |cursor| bucket < cursor) {
+			Cursor::put(bucket);
+		}
+	}
+
+	/// Next bucket and order id queued whose deadline is not after `now`. Buckets are walked from
+	/// the cursor, which moves past every bucket older than the current one once it is drained.
+	/// Every bucket and entry read is added to `consumed_weight`.
+	pub fn next_due_order_id<Queue, Cursor>(
+		now: MomentOf<T>,
+		consumed_weight: &mut Weight,
+		remaining_weight: Weight,
+	) -> Option<(u64, T::Hash)>
+	where
+		Queue: IterableStorageDoubleMap<u64, T::Hash, MomentOf<T>>,
+		Cursor: StorageValueT<u64, Query = Option<u64>>,
+	{
+		let now_bucket = Self::deadline_bucket(now);
+		let read_weight = T::DbWeight::get().reads(1);
+
+		*consumed_weight = consumed_weight.saturating_add(read_weight);
+		let mut bucket = Cursor::get()?;
+
+		loop {
+			for (order_id, deadline) in Queue::iter_prefix(bucket) {
+				*consumed_weight = consumed_weight.saturating_add(read_weight);
+
+				if deadline <= now {
+					return Some((bucket, order_id))
+				}
+
+				if *consumed_weight > remaining_weight {
+					return None
+				}
+			}
+
+			if bucket >= now_bucket {
+				return None
+			}
+
+			*consumed_weight =
+				consumed_weight.saturating_add(T::DbWeight::get().reads_writes(1, 1));
+
+			if *consumed_weight > remaining_weight {
+				return None
+			}
+
+			bucket += 1;
+			Cursor::put(bucket);
+		}
+	}
+
 	pub fn do_transfer(
 		currency: &CurrencyType,
 		sender: &T::AccountId,
@@ -245,11 +765,19 @@ impl<T: Config> OrderEventEmitter<T> for Pallet<T> {
 	fn emit_event_order_failed(order_id: &HashOf<T>) {
 		match Self::order_by_id(order_id) {
 			None => Self::deposit_event(Event::OrderNotFound),
+			Some(order) if order.status == OrderStatus::Paid =>
+				Self::deposit_event(Event::OrderPartiallyRefunded(order)),
 			Some(order) => Self::deposit_event(Event::OrderFailed(order)),
 		}
 	}
 }
 
+impl<T: Config> FeeScheduleProvider<AccountIdOf<T>, BalanceOf<T>> for Pallet<T> {
+	fn fee_schedule() -> FeeScheduleOf<T> {
+		PlatformFeeSchedule::<T>::get()
+	}
+}
+
 impl<T: Config> OrderStatusUpdater<T> for Pallet<T> {
 	fn update_status_failed(order_id: &HashOf<T>) -> bool {
 		match Self::order_by_id(order_id) {
@@ -270,6 +798,11 @@ impl<T: Config> OrderStatusUpdater<T> for Pallet<T> {
 
 				let pallet_id = pallet_id.unwrap();
 
+				if let Some(order_items) = Self::order_items_by_order_id(order_id) {
+					Self::update_order_items_failed(&order, order_items, &pallet_id);
+					return true
+				}
+
 				let mut testing_price = Zero::zero();
 				let mut qc_price = Zero::zero();
 
@@ -281,25 +814,20 @@ impl<T: Config> OrderStatusUpdater<T> for Pallet<T> {
 					qc_price += price.value;
 				}
 
-				// TODO: check balance;
-
-				let _ = Self::do_transfer(
-					&order.currency,
-					&pallet_id,
-					order.get_seller_id(),
-					qc_price,
-					order.asset_id,
-					false,
-				);
-
-				let _ = Self::do_transfer(
-					&order.currency,
-					&pallet_id,
-					&order.customer_id,
-					testing_price,
-					order.asset_id,
-					false,
-				);
+				let transfers = vec![
+					(order.get_seller_id().clone(), qc_price),
+					(order.customer_id.clone(), testing_price),
+				];
+
+				// Failed orders stay disputable until the dispute window closes, like refunded ones
+				let settled = with_storage_layer(|| {
+					Self::do_settle_order(&order, &pallet_id, transfers)
+						.map_err(DispatchError::from)
+				});
+
+				if settled.is_err() {
+					return false
+				}
 
 				Self::update_order_status(&order.id, OrderStatus::Failed);
 
@@ -312,6 +840,10 @@ impl<T: Config> OrderStatusUpdater<T> for Pallet<T> {
 		seller_id: &AccountIdOf<T>,
 		order_id: &HashOf<T>,
 	) {
+		if !Self::is_order_items_finished(order_id) {
+			return
+		}
+
 		Self::remove_order_id_from_pending_orders_by_seller(seller_id, order_id);
 	}
 
@@ -1,9 +1,16 @@
 use crate::*;
 
-use frame_support::sp_runtime::{traits::Zero, SaturatedConversion};
+use frame_support::sp_runtime::{
+	traits::{CheckedAdd, Saturating, Zero},
+	Perbill, SaturatedConversion,
+};
+use primitives_price_and_currency::CurrencyType;
 
 impl<T: Config> OrderInterface<T> for Pallet<T> {
 	type Order = OrderOf<T>;
+	type FeeSplit = FeeSplitOf<T>;
+	type DisputeResolution = DisputeResolutionOf<T>;
+	type PaymentPlan = PaymentPlanOf<T>;
 	type Error = Error<T>;
 
 	fn create_order(
@@ -51,8 +58,8 @@ impl<T: Config> OrderInterface<T> for Pallet<T> {
 			asset_id,
 			currency.clone(),
 			order_flow,
-			prices.clone(),
-			additional_prices.clone(),
+			Self::order_prices_of(prices.to_vec())?,
+			Self::order_prices_of(additional_prices.to_vec())?,
 			*total_price,
 			now,
 			now,
@@ -63,6 +70,108 @@ impl<T: Config> OrderInterface<T> for Pallet<T> {
 		Ok(order)
 	}
 
+	fn create_bundle_order(
+		customer_id: &T::AccountId,
+		items: &[(T::Hash, u32)],
+		customer_box_public_key: &T::Hash,
+		order_flow: ServiceFlow,
+		asset_id: Option<u32>,
+	) -> Result<Self::Order, Self::Error> {
+		let (first_service_id, _) = items.first().ok_or(Error::<T>::EmptyBundleOrder)?;
+
+		if items.len() > T::MaxBundleItems::get() as usize {
+			return Err(Error::<T>::TooManyBundleItems)
+		}
+
+		let mut seller_id: Option<T::AccountId> = None;
+		let mut currency: Option<CurrencyType> = None;
+		let mut prices_by_service = Vec::new();
+		let mut prices = Vec::new();
+		let mut additional_prices = Vec::new();
+
+		for (service_id, price_index) in items.iter() {
+			let service =
+				T::Services::service_by_id(service_id).ok_or(Error::<T>::ServiceDoesNotExist)?;
+
+			let price_by_currency = service
+				.get_prices_by_currency()
+				.get(*price_index as usize)
+				.cloned()
+				.ok_or(Error::<T>::PriceIndexNotFound)?;
+
+			let service_seller_id = service.get_owner_id();
+			if seller_id.get_or_insert_with(|| service_seller_id.clone()) != service_seller_id {
+				return Err(Error::<T>::BundleSellerMismatch)
+			}
+
+			let service_currency = &price_by_currency.currency;
+			if currency.get_or_insert_with(|| service_currency.clone()) != service_currency {
+				return Err(Error::<T>::BundleCurrencyMismatch)
+			}
+
+			prices.extend(price_by_currency.price_components.iter().cloned());
+			additional_prices.extend(price_by_currency.additional_prices.iter().cloned());
+			prices_by_service.push((*service_id, price_by_currency));
+		}
+
+		let prices = Self::order_prices_of(prices)?;
+		let additional_prices = Self::order_prices_of(additional_prices)?;
+		let seller_id = seller_id.ok_or(Error::<T>::EmptyBundleOrder)?;
+		let currency = currency.ok_or(Error::<T>::EmptyBundleOrder)?;
+		let asset_id = Self::do_validate_asset_id(&currency, asset_id)?;
+
+		let order_id = Self::generate_order_id(customer_id, first_service_id);
+		let now = pallet_timestamp::Pallet::<T>::get();
+
+		let mut total_price: BalanceOf<T> = Zero::zero();
+		let mut order_items = Vec::new();
+
+		for (service_id, price_by_currency) in prices_by_service {
+			// Initialize DnaSample for every item
+			let dna_sample =
+				T::GeneticTesting::register_dna_sample(&seller_id, customer_id, &order_id)
+					.map_err(|_| Error::<T>::DnaSampleInitalizationError)?;
+
+			total_price = total_price
+				.checked_add(&price_by_currency.total_price)
+				.ok_or(Error::<T>::TotalPriceOverflow)?;
+
+			order_items.push(OrderItem::new(
+				service_id,
+				dna_sample.get_tracking_id().clone(),
+				price_by_currency.price_components,
+				price_by_currency.additional_prices,
+				price_by_currency.total_price,
+			));
+		}
+
+		// Bundle size is checked against MaxBundleItems above
+		let order_items: OrderItemsOf<T> =
+			order_items.try_into().map_err(|_| Error::<T>::TooManyBundleItems)?;
+
+		let order = Order::new(
+			order_id,
+			*first_service_id,
+			customer_id.clone(),
+			*customer_box_public_key,
+			seller_id,
+			order_items[0].dna_sample_tracking_id.clone(),
+			asset_id,
+			currency,
+			order_flow,
+			prices,
+			additional_prices,
+			total_price,
+			now,
+			now,
+		);
+
+		Self::insert_order_to_storage(&order);
+		OrderItems::<T>::insert(order_id, &order_items);
+
+		Ok(order)
+	}
+
 	fn cancel_order(
 		customer_id: &T::AccountId,
 		order_id: &T::Hash,
@@ -74,19 +183,21 @@ impl<T: Config> OrderInterface<T> for Pallet<T> {
 			.can_cancelled()
 			.ok_or(Error::<T>::OrderCannotBeCancelled)?;
 
-		let dna_sample_opt =
-			T::GeneticTesting::dna_sample_by_tracking_id(&order.dna_sample_tracking_id);
+		let tracking_ids = Self::dna_sample_tracking_ids_of(&order);
 
-		if let Some(dna_sample) = dna_sample_opt {
-			if !dna_sample.is_registered() {
-				return Err(Error::<T>::OngoingOrderCannotBeCancelled)
+		for tracking_id in tracking_ids.iter() {
+			if let Some(dna_sample) = T::GeneticTesting::dna_sample_by_tracking_id(tracking_id) {
+				if !dna_sample.is_registered() {
+					return Err(Error::<T>::OngoingOrderCannotBeCancelled)
+				}
 			}
 		}
 
 		let can_transfer = order.currency.can_transfer();
 		let mut order_status = OrderStatus::Cancelled;
 
-		if can_transfer && order.status == OrderStatus::Paid {
+		// Partially funded orders get back what has been paid so far
+		if can_transfer && !order.paid_amount.is_zero() {
 			let pallet_id = Self::pallet_id().ok_or(Error::<T>::PalletAccountNotFound)?;
 
 			order_status = OrderStatus::Refunded;
@@ -95,14 +206,16 @@ impl<T: Config> OrderInterface<T> for Pallet<T> {
 				&order.currency,
 				&pallet_id,
 				&order.customer_id,
-				order.total_price,
+				order.paid_amount,
 				order.asset_id,
 				false,
 			)?;
 		}
 
-		// Delete dna sample associated with the order
-		let _ = T::GeneticTesting::delete_dna_sample(&order.dna_sample_tracking_id);
+		// Delete dna samples associated with the order
+		for tracking_id in tracking_ids.iter() {
+			let _ = T::GeneticTesting::delete_dna_sample(tracking_id);
+		}
 		Self::remove_order_id_from_pending_orders_by_seller(&order.seller_id, &order.id);
 		let order =
 			Self::update_order_status(order_id, order_status).ok_or(Error::<T>::OrderNotFound)?;
@@ -127,6 +240,7 @@ impl<T: Config> OrderInterface<T> for Pallet<T> {
 		}
 
 		let order = order.can_paid().ok_or(Error::<T>::OrderCannotBePaid)?;
+		let remaining_amount = order.total_price.saturating_sub(order.paid_amount);
 
 		if order.currency.can_transfer() {
 			let pallet_id = Self::pallet_id().ok_or(Error::<T>::PalletAccountNotFound)?;
@@ -135,22 +249,33 @@ impl<T: Config> OrderInterface<T> for Pallet<T> {
 				&order.currency,
 				&order.customer_id,
 				&pallet_id,
-				order.total_price,
+				remaining_amount,
 				order.asset_id,
 				true,
 			)?;
 		}
 
+		// Paying the rest at once settles every remaining installment
+		PaymentPlans::<T>::mutate(order_id, |payment_plan| {
+			if let Some(payment_plan) = payment_plan {
+				payment_plan.paid_installments = payment_plan.installments.len() as u32;
+			}
+		});
+
+		Self::add_order_paid_amount(order_id, remaining_amount);
+
 		let order = Self::update_order_status(order_id, OrderStatus::Paid)
 			.ok_or(Error::<T>::OrderNotFound)?;
 
+		Self::insert_refund_deadline(&order);
+
 		Ok(order)
 	}
 
 	fn fulfill_order(
 		seller_id: &T::AccountId,
 		order_id: &T::Hash,
-	) -> Result<Self::Order, Self::Error> {
+	) -> Result<(Self::Order, Self::FeeSplit), Self::Error> {
 		let order = Orders::<T>::get(order_id)
 			.ok_or(Error::<T>::OrderNotFound)?
 			.is_authorized_seller(seller_id)
@@ -158,46 +283,44 @@ impl<T: Config> OrderInterface<T> for Pallet<T> {
 			.can_fulfilled()
 			.ok_or(Error::<T>::OrderCannotBeFulfilled)?;
 
-		let dna_sample_opt =
-			T::GeneticTesting::dna_sample_by_tracking_id(&order.dna_sample_tracking_id);
-
-		if let Some(dna_sample) = dna_sample_opt {
-			if !dna_sample.process_success() {
-				return Err(Error::<T>::DnaSampleNotSuccessfullyProcessed)
+		// Rejected bundle items were already refunded and are left out of the payout
+		let order_items = Self::order_items_by_order_id(order_id);
+		let (tracking_ids, payable_price) = match &order_items {
+			Some(order_items) => {
+				let paid_items = order_items.iter().filter(|item| item.status == OrderStatus::Paid);
+				let tracking_ids =
+					paid_items.clone().map(|item| item.dna_sample_tracking_id.clone()).collect();
+				let payable_price =
+					paid_items.fold(Zero::zero(), |total, item| total + item.total_price);
+				(tracking_ids, payable_price)
+			},
+			None => (vec![order.dna_sample_tracking_id.clone()], order.total_price),
+		};
+
+		for tracking_id in tracking_ids.iter() {
+			if let Some(dna_sample) = T::GeneticTesting::dna_sample_by_tracking_id(tracking_id) {
+				if !dna_sample.process_success() {
+					return Err(Error::<T>::DnaSampleNotSuccessfullyProcessed)
+				}
 			}
 		}
 
+		// Fee split is reported even when the payment is settled outside the chain
+		let fee_split = Self::fee_schedule().split(&order.currency, payable_price);
+
 		if order.currency.can_transfer() {
 			let treasury_key = Self::treasury_key().ok_or(Error::<T>::PalletAccountNotFound)?;
 			let pallet_id = Self::pallet_id().ok_or(Error::<T>::PalletAccountNotFound)?;
-			let price_substracted_value: BalanceOf<T> = order.total_price / 20u128.saturated_into();
-			let total_price_paid = order.total_price - price_substracted_value;
 
-			// Transfer 5% to treasury
-			Self::do_transfer(
-				&order.currency,
-				&pallet_id,
-				&treasury_key,
-				price_substracted_value,
-				order.asset_id,
-				false,
-			)?;
+			let transfers = fee_split.transfers(&treasury_key, order.get_seller_id());
 
-			// Withhold 5%
-			Self::do_transfer(
-				&order.currency,
-				&pallet_id,
-				order.get_seller_id(),
-				total_price_paid,
-				order.asset_id,
-				false,
-			)?;
+			Self::do_settle_order(&order, &pallet_id, transfers)?;
 		}
 
 		let order = Self::update_order_status(order_id, OrderStatus::Fulfilled)
 			.ok_or(Error::<T>::OrderNotFound)?;
 
-		Ok(order)
+		Ok((order, fee_split))
 	}
 
 	fn set_order_refunded(
@@ -213,7 +336,12 @@ impl<T: Config> OrderInterface<T> for Pallet<T> {
 			.can_refunded()
 			.ok_or(Error::<T>::OrderCannotBeRefunded)?;
 
-		if !Self::order_can_be_refunded(&order) {
+		// Bundle items are refunded one by one as their dna samples are rejected
+		if OrderItems::<T>::contains_key(order_id) {
+			return Err(Error::<T>::OrderCannotBeRefunded)
+		}
+
+		if !Self::order_can_be_refunded(&order)? {
 			return Err(Error::<T>::OrderNotYetExpired)
 		}
 
@@ -230,23 +358,12 @@ impl<T: Config> OrderInterface<T> for Pallet<T> {
 				qc_price += price.value;
 			}
 
-			Self::do_transfer(
-				&order.currency,
-				&pallet_id,
-				order.get_seller_id(),
-				qc_price,
-				order.asset_id,
-				false,
-			)?;
+			let transfers = vec![
+				(order.get_seller_id().clone(), qc_price),
+				(order.customer_id.clone(), testing_price),
+			];
 
-			Self::do_transfer(
-				&order.currency,
-				&pallet_id,
-				&order.customer_id,
-				testing_price,
-				order.asset_id,
-				false,
-			)?;
+			Self::do_settle_order(&order, &pallet_id, transfers)?;
 		}
 
 		let order = Self::update_order_status(order_id, OrderStatus::Refunded)
@@ -254,4 +371,225 @@ impl<T: Config> OrderInterface<T> for Pallet<T> {
 
 		Ok(order)
 	}
+
+	fn claim_expired_refund(order_id: &T::Hash) -> Result<Self::Order, Self::Error> {
+		let order = Orders::<T>::get(order_id)
+			.ok_or(Error::<T>::OrderNotFound)?
+			.can_refunded()
+			.ok_or(Error::<T>::OrderCannotBeRefunded)?;
+
+		let deadline =
+			Self::refund_deadline_by_order_id(order_id).ok_or(Error::<T>::OrderCannotBeRefunded)?;
+
+		if pallet_timestamp::Pallet::<T>::get() < deadline {
+			return Err(Error::<T>::OrderNotYetExpired)
+		}
+
+		Self::do_refund_expired_order(&order)
+	}
+
+	fn open_dispute(
+		account_id: &T::AccountId,
+		order_id: &T::Hash,
+		reason: &[u8],
+	) -> Result<Self::Order, Self::Error> {
+		let order = Orders::<T>::get(order_id).ok_or(Error::<T>::OrderNotFound)?;
+
+		if account_id != &order.customer_id && account_id != &order.seller_id {
+			return Err(Error::<T>::Unauthorized)
+		}
+
+		let order = order.can_disputed().ok_or(Error::<T>::OrderCannotBeDisputed)?;
+		let settlement = Self::order_settlement_by_order_id(order_id)
+			.ok_or(Error::<T>::OrderCannotBeDisputed)?;
+
+		let now = pallet_timestamp::Pallet::<T>::get();
+
+		if now >= settlement.release_at {
+			return Err(Error::<T>::DisputeWindowClosed)
+		}
+
+		let reason: DisputeReason =
+			reason.to_vec().try_into().map_err(|_| Error::<T>::DisputeReasonTooLong)?;
+		let dispute = Dispute::new(account_id.clone(), reason, order.status, now);
+
+		// Disputed funds wait for the arbiter instead of the end of the window
+		Self::unqueue_settlement_release(order_id, &settlement);
+		Disputes::<T>::insert(order_id, dispute);
+
+		Self::update_order_status(order_id, OrderStatus::Disputed).ok_or(Error::<T>::OrderNotFound)
+	}
+
+	fn resolve_dispute(
+		order_id: &T::Hash,
+		customer_share: Perbill,
+	) -> Result<(Self::Order, Self::DisputeResolution), Self::Error> {
+		let order = Orders::<T>::get(order_id).ok_or(Error::<T>::OrderNotFound)?;
+
+		if order.status != OrderStatus::Disputed {
+			return Err(Error::<T>::OrderNotDisputed)
+		}
+
+		let mut dispute =
+			Self::dispute_by_order_id(order_id).ok_or(Error::<T>::OrderNotDisputed)?;
+		let settlement =
+			Self::take_order_settlement(order_id).ok_or(Error::<T>::OrderSettlementNotFound)?;
+
+		// Fee shares are paid as settled, only what the customer and the seller were owed
+		// is split between them
+		let (disputed_amount, mut transfers) =
+			settlement.split_parties(&order.customer_id, &order.seller_id);
+		let customer_amount = customer_share.mul_floor(disputed_amount);
+		let seller_amount = disputed_amount - customer_amount;
+
+		let pallet_id = Self::pallet_id().ok_or(Error::<T>::PalletAccountNotFound)?;
+		transfers.push((order.customer_id.clone(), customer_amount));
+		transfers.push((order.seller_id.clone(), seller_amount));
+
+		Self::do_transfers(&order, &pallet_id, &transfers)?;
+
+		let resolution = DisputeResolution { customer_share, customer_amount, seller_amount };
+
+		dispute.resolution = Some(resolution.clone());
+		Disputes::<T>::insert(order_id, dispute);
+
+		let order = Self::update_order_status(order_id, OrderStatus::Resolved)
+			.ok_or(Error::<T>::OrderNotFound)?;
+
+		Ok((order, resolution))
+	}
+
+	fn create_payment_plan(
+		customer_id: &T::AccountId,
+		order_id: &T::Hash,
+		installments: u32,
+	) -> Result<(Self::Order, Self::PaymentPlan), Self::Error> {
+		let order = Orders::<T>::get(order_id)
+			.ok_or(Error::<T>::OrderNotFound)?
+			.is_authorized_customer(customer_id)
+			.ok_or(Error::<T>::Unauthorized)?
+			.can_paid()
+			.ok_or(Error::<T>::OrderCannotBePaid)?;
+
+		if !order.currency.can_transfer() {
+			return Err(Error::<T>::PaymentPlanNotSupported)
+		}
+
+		if PaymentPlans::<T>::contains_key(order_id) {
+			return Err(Error::<T>::PaymentPlanAlreadyExists)
+		}
+
+		if installments == 0 || installments > T::MaxInstallments::get() {
+			return Err(Error::<T>::InvalidInstallmentCount)
+		}
+
+		let now = pallet_timestamp::Pallet::<T>::get();
+		let interval: MomentOf<T> = T::InstallmentInterval::get().saturated_into();
+		let payment_plan = PaymentPlan::new(order.total_price, installments, now, interval)
+			.ok_or(Error::<T>::InvalidInstallmentCount)?;
+
+		if let Some(installment) = payment_plan.next_installment() {
+			Self::insert_installment_deadline(order_id, installment.due_at);
+		}
+
+		PaymentPlans::<T>::insert(order_id, &payment_plan);
+
+		Ok((order, payment_plan))
+	}
+
+	fn pay_installment(
+		customer_id: &T::AccountId,
+		order_id: &T::Hash,
+	) -> Result<(Self::Order, u32), Self::Error> {
+		let order = Orders::<T>::get(order_id)
+			.ok_or(Error::<T>::OrderNotFound)?
+			.is_authorized_customer(customer_id)
+			.ok_or(Error::<T>::Unauthorized)?
+			.can_paid()
+			.ok_or(Error::<T>::OrderCannotBePaid)?;
+
+		let mut payment_plan =
+			Self::payment_plan_by_order_id(order_id).ok_or(Error::<T>::PaymentPlanNotFound)?;
+		let installment =
+			payment_plan.next_installment().cloned().ok_or(Error::<T>::OrderCannotBePaid)?;
+
+		let pallet_id = Self::pallet_id().ok_or(Error::<T>::PalletAccountNotFound)?;
+
+		Self::do_transfer(
+			&order.currency,
+			&order.customer_id,
+			&pallet_id,
+			installment.amount,
+			order.asset_id,
+			true,
+		)?;
+
+		payment_plan.paid_installments += 1;
+
+		Self::remove_installment_deadline(order_id);
+
+		if let Some(installment) = payment_plan.next_installment() {
+			Self::insert_installment_deadline(order_id, installment.due_at);
+		}
+
+		let paid_installments = payment_plan.paid_installments;
+		let is_completed = payment_plan.is_completed();
+
+		PaymentPlans::<T>::insert(order_id, payment_plan);
+
+		let order = Self::add_order_paid_amount(order_id, installment.amount)
+			.ok_or(Error::<T>::OrderNotFound)?;
+
+		if !is_completed {
+			return Ok((order, paid_installments))
+		}
+
+		let order = Self::update_order_status(order_id, OrderStatus::Paid)
+			.ok_or(Error::<T>::OrderNotFound)?;
+
+		Self::insert_refund_deadline(&order);
+
+		Ok((order, paid_installments))
+	}
+
+	fn cancel_overdue_order(order_id: &T::Hash) -> Result<Self::Order, Self::Error> {
+		let order = Orders::<T>::get(order_id)
+			.ok_or(Error::<T>::OrderNotFound)?
+			.can_paid()
+			.ok_or(Error::<T>::OrderCannotBeCancelled)?;
+
+		let installment = Self::payment_plan_by_order_id(order_id)
+			.ok_or(Error::<T>::PaymentPlanNotFound)?
+			.next_installment()
+			.cloned()
+			.ok_or(Error::<T>::InstallmentNotYetOverdue)?;
+
+		if pallet_timestamp::Pallet::<T>::get() <= installment.due_at {
+			return Err(Error::<T>::InstallmentNotYetOverdue)
+		}
+
+		Self::do_cancel_overdue_order(&order)
+	}
+
+	fn release_order_settlement(order_id: &T::Hash) -> Result<Self::Order, Self::Error> {
+		let order = Orders::<T>::get(order_id)
+			.ok_or(Error::<T>::OrderNotFound)?
+			.can_disputed()
+			.ok_or(Error::<T>::OrderSettlementNotFound)?;
+
+		let settlement = Self::order_settlement_by_order_id(order_id)
+			.ok_or(Error::<T>::OrderSettlementNotFound)?;
+
+		if pallet_timestamp::Pallet::<T>::get() < settlement.release_at {
+			return Err(Error::<T>::DisputeWindowNotYetClosed)
+		}
+
+		let pallet_id = Self::pallet_id().ok_or(Error::<T>::PalletAccountNotFound)?;
+
+		Self::do_transfers(&order, &pallet_id, &settlement.transfers)?;
+
+		Self::take_order_settlement(order_id);
+
+		Ok(order)
+	}
 }
@@ -1,7 +1,11 @@
+use frame_support::sp_runtime::Perbill;
 use traits_services::types::ServiceFlow;
 
 pub trait OrderInterface<T: frame_system::Config> {
 	type Order;
+	type FeeSplit;
+	type DisputeResolution;
+	type PaymentPlan;
 	type Error;
 
 	fn create_order(
@@ -12,6 +16,13 @@ pub trait OrderInterface<T: frame_system::Config> {
 		order_flow: ServiceFlow,
 		asset_id: Option<u32>,
 	) -> Result<Self::Order, Self::Error>;
+	fn create_bundle_order(
+		customer_id: &T::AccountId,
+		items: &[(T::Hash, u32)],
+		customer_box_public_key: &T::Hash,
+		order_flow: ServiceFlow,
+		asset_id: Option<u32>,
+	) -> Result<Self::Order, Self::Error>;
 	fn cancel_order(
 		customer_id: &T::AccountId,
 		order_id: &T::Hash,
@@ -24,9 +35,34 @@ pub trait OrderInterface<T: frame_system::Config> {
 	fn fulfill_order(
 		seller_id: &T::AccountId,
 		order_id: &T::Hash,
-	) -> Result<Self::Order, Self::Error>;
+	) -> Result<(Self::Order, Self::FeeSplit), Self::Error>;
 	fn set_order_refunded(
 		escrow_account_id: &T::AccountId,
 		order_id: &T::Hash,
 	) -> Result<Self::Order, Self::Error>;
+	// claim_expired_refund can be called by anyone once the refund deadline has passed
+	fn claim_expired_refund(order_id: &T::Hash) -> Result<Self::Order, Self::Error>;
+	// open_dispute can be called by the customer or the seller while the dispute window is open
+	fn open_dispute(
+		account_id: &T::AccountId,
+		order_id: &T::Hash,
+		reason: &[u8],
+	) -> Result<Self::Order, Self::Error>;
+	// resolve_dispute should only be called by the arbiter origin
+	fn resolve_dispute(
+		order_id: &T::Hash,
+		customer_share: Perbill,
+	) -> Result<(Self::Order, Self::DisputeResolution), Self::Error>;
+	fn release_order_settlement(order_id: &T::Hash) -> Result<Self::Order, Self::Error>;
+	fn create_payment_plan(
+		customer_id: &T::AccountId,
+		order_id: &T::Hash,
+		installments: u32,
+	) -> Result<(Self::Order, Self::PaymentPlan), Self::Error>;
+	fn pay_installment(
+		customer_id: &T::AccountId,
+		order_id: &T::Hash,
+	) -> Result<(Self::Order, u32), Self::Error>;
+	// cancel_overdue_order can be called by anyone once an installment is overdue
+	fn cancel_overdue_order(order_id: &T::Hash) -> Result<Self::Order, Self::Error>;
 }
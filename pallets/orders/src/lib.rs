@@ -17,7 +17,7 @@ pub mod weights;
 
 pub use interface::OrderInterface;
 pub use sp_std::{prelude::*, vec};
-pub use traits_genetic_testing::{DnaSampleTracking, GeneticTestingProvider};
+pub use traits_genetic_testing::{DnaSampleTracking, DnaSampleTrackingId, GeneticTestingProvider};
 pub use traits_order::{OrderEventEmitter, OrderStatusUpdater};
 pub use traits_services::{types::ServiceFlow, ServiceInfo, ServicesProvider};
 pub use types::*;
@@ -26,7 +26,7 @@ pub use weights::WeightInfo;
 pub use frame_support::traits::StorageVersion;
 
 /// The current storage version.
-const STORAGE_VERSION: StorageVersion = StorageVersion::new(2);
+const STORAGE_VERSION: StorageVersion = StorageVersion::new(4);
 
 #[frame_support::pallet]
 pub mod pallet {
@@ -35,7 +35,7 @@ pub mod pallet {
 	use frame_support::{
 		dispatch::DispatchResultWithPostInfo,
 		pallet_prelude::*,
-		sp_runtime::traits::AccountIdConversion,
+		sp_runtime::{traits::AccountIdConversion, Perbill},
 		traits::{tokens::fungibles, Currency},
 		PalletId,
 	};
@@ -55,13 +55,37 @@ pub mod pallet {
 		type OrdersWeightInfo: WeightInfo;
 		#[pallet::constant]
 		type PalletId: Get<PalletId>;
+		/// Time in milliseconds added on top of the service expected duration
+		/// before a paid order can be refunded.
+		#[pallet::constant]
+		type RefundGracePeriod: Get<u64>;
+		/// Maximum number of expired orders refunded in a single block.
+		#[pallet::constant]
+		type MaxExpiredRefundsPerBlock: Get<u32>;
+		/// Maximum number of services in a single bundle order.
+		#[pallet::constant]
+		type MaxBundleItems: Get<u32>;
+		/// Time in milliseconds a fulfilled or refunded order stays open for disputes
+		/// before its funds are released. Zero settles orders immediately.
+		#[pallet::constant]
+		type DisputeWindow: Get<u64>;
+		/// Origin allowed to resolve order disputes.
+		type ArbiterOrigin: EnsureOrigin<<Self as frame_system::Config>::RuntimeOrigin>;
+		/// Time in milliseconds between two installments of a payment plan.
+		#[pallet::constant]
+		type InstallmentInterval: Get<u64>;
+		/// Maximum number of installments in a payment plan.
+		#[pallet::constant]
+		type MaxInstallments: Get<u32>;
+		/// Maximum number of overdue payment plans cancelled in a single block.
+		#[pallet::constant]
+		type MaxOverduePlansPerBlock: Get<u32>;
 	}
 
 	// ----- This is template code, every pallet needs this ---
 	#[pallet::pallet]
 	#[pallet::storage_version(STORAGE_VERSION)]
 	#[pallet::generate_store(pub(super) trait Store)]
-	#[pallet::without_storage_info]
 	pub struct Pallet<T>(_);
 
 	#[pallet::hooks]
@@ -69,6 +93,18 @@ pub mod pallet {
 		fn on_runtime_upgrade() -> Weight {
 			migrations::migrate::<T>()
 		}
+
+		fn on_idle(_n: BlockNumberFor<T>, remaining_weight: Weight) -> Weight {
+			let mut consumed_weight = Self::refund_expired_orders(remaining_weight);
+
+			consumed_weight = consumed_weight.saturating_add(Self::cancel_overdue_orders(
+				remaining_weight.saturating_sub(consumed_weight),
+			));
+
+			consumed_weight.saturating_add(Self::release_order_settlements(
+				remaining_weight.saturating_sub(consumed_weight),
+			))
+		}
 	}
 	// --------------------------------------------------------
 
@@ -78,22 +114,82 @@ pub mod pallet {
 	pub type Orders<T> = StorageMap<_, Blake2_128Concat, HashOf<T>, OrderOf<T>>;
 
 	#[pallet::storage]
-	#[pallet::getter(fn orders_by_customer_id)]
-	pub type OrdersByCustomer<T> = StorageMap<_, Blake2_128Concat, AccountIdOf<T>, OrderIdsOf<T>>;
+	#[pallet::getter(fn order_items_by_order_id)]
+	pub type OrderItems<T> = StorageMap<_, Blake2_128Concat, HashOf<T>, OrderItemsOf<T>>;
+
+	/// Get order ids by customer id
+	/// (AccountId, OrderId) => ()
+	#[pallet::storage]
+	pub type OrdersByCustomer<T> =
+		StorageDoubleMap<_, Blake2_128Concat, AccountIdOf<T>, Blake2_128Concat, HashOf<T>, ()>;
 
+	/// Get order ids by seller id
+	/// (AccountId, OrderId) => ()
 	#[pallet::storage]
-	#[pallet::getter(fn orders_by_lab_id)]
-	pub type OrdersBySeller<T> = StorageMap<_, Blake2_128Concat, AccountIdOf<T>, OrderIdsOf<T>>;
+	pub type OrdersBySeller<T> =
+		StorageDoubleMap<_, Blake2_128Concat, AccountIdOf<T>, Blake2_128Concat, HashOf<T>, ()>;
 
+	/// Get pending order ids by seller id
+	/// (AccountId, OrderId) => ()
 	#[pallet::storage]
-	#[pallet::getter(fn pending_genetic_analysis_orders_by_genetic_analyst_id)]
 	pub type PendingOrdersBySeller<T> =
-		StorageMap<_, Blake2_128Concat, AccountIdOf<T>, OrderIdsOf<T>>;
+		StorageDoubleMap<_, Blake2_128Concat, AccountIdOf<T>, Blake2_128Concat, HashOf<T>, ()>;
 
 	#[pallet::storage]
 	#[pallet::getter(fn last_order_by_customer_id)]
 	pub type LastOrderByCustomer<T> = StorageMap<_, Blake2_128Concat, AccountIdOf<T>, HashOf<T>>;
 
+	#[pallet::storage]
+	#[pallet::getter(fn refund_deadline_by_order_id)]
+	pub type RefundDeadlineByOrder<T> = StorageMap<_, Blake2_128Concat, HashOf<T>, MomentOf<T>>;
+
+	/// Paid orders refunded once their deadline passes
+	/// (Bucket, OrderId) => Deadline
+	#[pallet::storage]
+	pub type RefundDeadlines<T> =
+		StorageDoubleMap<_, Twox64Concat, u64, Blake2_128Concat, HashOf<T>, MomentOf<T>>;
+
+	/// Oldest bucket of `RefundDeadlines` that may still hold orders
+	#[pallet::storage]
+	pub type NextRefundBucket<T> = StorageValue<_, u64, OptionQuery>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn payment_plan_by_order_id)]
+	pub type PaymentPlans<T> = StorageMap<_, Blake2_128Concat, HashOf<T>, PaymentPlanOf<T>>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn installment_deadline_by_order_id)]
+	pub type InstallmentDeadlineByOrder<T> =
+		StorageMap<_, Blake2_128Concat, HashOf<T>, MomentOf<T>>;
+
+	/// Payment plans cancelled once their next installment is overdue
+	/// (Bucket, OrderId) => DueAt
+	#[pallet::storage]
+	pub type InstallmentDeadlines<T> =
+		StorageDoubleMap<_, Twox64Concat, u64, Blake2_128Concat, HashOf<T>, MomentOf<T>>;
+
+	/// Oldest bucket of `InstallmentDeadlines` that may still hold orders
+	#[pallet::storage]
+	pub type NextInstallmentBucket<T> = StorageValue<_, u64, OptionQuery>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn order_settlement_by_order_id)]
+	pub type OrderSettlements<T> = StorageMap<_, Blake2_128Concat, HashOf<T>, OrderSettlementOf<T>>;
+
+	/// Orders whose locked funds are released once the dispute window closes
+	/// (Bucket, OrderId) => ReleaseAt
+	#[pallet::storage]
+	pub type SettlementReleases<T> =
+		StorageDoubleMap<_, Twox64Concat, u64, Blake2_128Concat, HashOf<T>, MomentOf<T>>;
+
+	/// Oldest bucket of `SettlementReleases` that may still hold orders
+	#[pallet::storage]
+	pub type NextSettlementBucket<T> = StorageValue<_, u64, OptionQuery>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn dispute_by_order_id)]
+	pub type Disputes<T> = StorageMap<_, Blake2_128Concat, HashOf<T>, DisputeOf<T>>;
+
 	#[pallet::storage]
 	#[pallet::getter(fn admin_key)]
 	pub type EscrowKey<T: Config> = StorageValue<_, T::AccountId, OptionQuery>;
@@ -105,6 +201,10 @@ pub mod pallet {
 	#[pallet::storage]
 	#[pallet::getter(fn pallet_id)]
 	pub type PalletAccount<T: Config> = StorageValue<_, T::AccountId, OptionQuery>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn fee_schedule)]
+	pub type PlatformFeeSchedule<T: Config> = StorageValue<_, FeeScheduleOf<T>, ValueQuery>;
 	// -----------------------------------------
 
 	// ----- Genesis Configs ------------------
@@ -148,11 +248,17 @@ pub mod pallet {
 		/// parameters, [Order]
 		OrderPaid(OrderOf<T>),
 		/// Order Fulfilled
-		/// parameters, [Order]
-		OrderFulfilled(OrderOf<T>),
+		/// parameters, [Order, FeeSplit]
+		OrderFulfilled(OrderOf<T>, FeeSplitOf<T>),
 		/// Order Refunded
 		/// parameters, [Order]
 		OrderRefunded(OrderOf<T>),
+		/// Order Refunded after its deadline passed
+		/// parameters, [Order]
+		OrderRefundedExpired(OrderOf<T>),
+		/// Bundle order item refunded after its dna sample was rejected
+		/// parameters, [Order]
+		OrderPartiallyRefunded(OrderOf<T>),
 		/// Order Cancelled
 		/// parameters, [Order]
 		OrderCancelled(OrderOf<T>),
@@ -165,6 +271,30 @@ pub mod pallet {
 		/// Order Failed
 		/// parameters, [Order]
 		OrderFailed(OrderOf<T>),
+		/// Platform fee schedule updated
+		/// parameters, [FeeSchedule]
+		FeeScheduleUpdated(FeeScheduleOf<T>),
+		/// Order disputed
+		/// parameters, [Order, who]
+		DisputeOpened(OrderOf<T>, AccountIdOf<T>),
+		/// Order dispute resolved by the arbiter
+		/// parameters, [Order, DisputeResolution]
+		DisputeResolved(OrderOf<T>, DisputeResolutionOf<T>),
+		/// Locked order funds released after the dispute window
+		/// parameters, [Order]
+		OrderSettlementReleased(OrderOf<T>),
+		/// Payment plan created for an unpaid order
+		/// parameters, [Order, PaymentPlan]
+		PaymentPlanCreated(OrderOf<T>, PaymentPlanOf<T>),
+		/// Order installment paid
+		/// parameters, [Order, paid installments]
+		OrderInstallmentPaid(OrderOf<T>, u32),
+		/// Order cancelled because an installment is overdue
+		/// parameters, [Order]
+		OrderOverdueCancelled(OrderOf<T>),
+		/// Refund of a rejected bundle item failed, the item stays paid
+		/// parameters, [Order, DnaSampleTrackingId]
+		OrderItemRefundFailed(OrderOf<T>, DnaSampleTrackingId),
 	}
 
 	#[pallet::error]
@@ -200,6 +330,46 @@ pub mod pallet {
 		OrderCannotBePaid,
 		OrderCannotBeRefunded,
 		OrderCannotBeFulfilled,
+		/// Dna sample has been received by the lab, order can not be refunded
+		OngoingOrderCannotBeRefunded,
+		/// Fee beneficiaries share more than the whole fee or a currency is set twice
+		InvalidFeeSchedule,
+		/// Bundle order has no items
+		EmptyBundleOrder,
+		/// Bundle order has more items than allowed
+		TooManyBundleItems,
+		/// Every service in a bundle order must belong to the same seller
+		BundleSellerMismatch,
+		/// Every service in a bundle order must be paid with the same currency
+		BundleCurrencyMismatch,
+		/// Only fulfilled, refunded or failed orders with locked funds can be disputed
+		OrderCannotBeDisputed,
+		/// Dispute window of the order has already closed
+		DisputeWindowClosed,
+		/// Funds can not be released while the dispute window is still open
+		DisputeWindowNotYetClosed,
+		/// Order is not under dispute
+		OrderNotDisputed,
+		/// Order has no locked funds
+		OrderSettlementNotFound,
+		/// Dispute reason is longer than MAX_DISPUTE_REASON_LENGTH
+		DisputeReasonTooLong,
+		/// Installment count is zero or above the maximum
+		InvalidInstallmentCount,
+		/// Payment plans are only available for currencies paid on chain
+		PaymentPlanNotSupported,
+		PaymentPlanAlreadyExists,
+		PaymentPlanNotFound,
+		/// No installment of the order is overdue yet
+		InstallmentNotYetOverdue,
+		/// Dna sample of the order does not exist
+		DnaSampleNotFound,
+		/// Bundle order has more price components than MAX_ORDER_PRICES
+		TooManyOrderPrices,
+		/// Settlement pays out more than MAX_SETTLEMENT_TRANSFERS transfers
+		TooManySettlementTransfers,
+		/// Total price of a bundle order does not fit in the balance type
+		TotalPriceOverflow,
 		Module,
 		Other,
 		BadOrigin,
@@ -241,6 +411,31 @@ pub mod pallet {
 			}
 		}
 
+		#[pallet::weight(T::OrdersWeightInfo::create_bundle_order(items.len() as u32))]
+		pub fn create_bundle_order(
+			origin: OriginFor<T>,
+			items: Vec<(T::Hash, u32)>,
+			customer_box_public_key: T::Hash,
+			order_flow: ServiceFlow,
+			asset_id: Option<u32>,
+		) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+
+			match <Self as OrderInterface<T>>::create_bundle_order(
+				&who,
+				&items,
+				&customer_box_public_key,
+				order_flow,
+				asset_id,
+			) {
+				Ok(order) => {
+					Self::deposit_event(Event::<T>::OrderCreated(order));
+					Ok(().into())
+				},
+				Err(error) => Err(error.into()),
+			}
+		}
+
 		#[pallet::weight(T::OrdersWeightInfo::cancel_order())]
 		pub fn cancel_order(origin: OriginFor<T>, order_id: T::Hash) -> DispatchResultWithPostInfo {
 			let who = ensure_signed(origin)?;
@@ -278,8 +473,8 @@ pub mod pallet {
 			let who = ensure_signed(origin)?;
 
 			match <Self as OrderInterface<T>>::fulfill_order(&who, &order_id) {
-				Ok(order) => {
-					Self::deposit_event(Event::<T>::OrderFulfilled(order));
+				Ok((order, fee_split)) => {
+					Self::deposit_event(Event::<T>::OrderFulfilled(order, fee_split));
 					Ok(().into())
 				},
 				Err(error) => Err(error.into()),
@@ -302,6 +497,131 @@ pub mod pallet {
 			}
 		}
 
+		#[pallet::weight(T::OrdersWeightInfo::claim_expired_refund())]
+		pub fn claim_expired_refund(
+			origin: OriginFor<T>,
+			order_id: T::Hash,
+		) -> DispatchResultWithPostInfo {
+			let _ = ensure_signed(origin)?;
+
+			match <Self as OrderInterface<T>>::claim_expired_refund(&order_id) {
+				Ok(order) => {
+					Self::deposit_event(Event::<T>::OrderRefundedExpired(order));
+					Ok(().into())
+				},
+				Err(error) => Err(error.into()),
+			}
+		}
+
+		#[pallet::weight(T::OrdersWeightInfo::open_dispute())]
+		pub fn open_dispute(
+			origin: OriginFor<T>,
+			order_id: T::Hash,
+			reason: Vec<u8>,
+		) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+
+			match <Self as OrderInterface<T>>::open_dispute(&who, &order_id, &reason) {
+				Ok(order) => {
+					Self::deposit_event(Event::<T>::DisputeOpened(order, who));
+					Ok(().into())
+				},
+				Err(error) => Err(error.into()),
+			}
+		}
+
+		#[pallet::weight(T::OrdersWeightInfo::resolve_dispute())]
+		pub fn resolve_dispute(
+			origin: OriginFor<T>,
+			order_id: T::Hash,
+			customer_share: Perbill,
+		) -> DispatchResultWithPostInfo {
+			T::ArbiterOrigin::ensure_origin(origin)?;
+
+			match <Self as OrderInterface<T>>::resolve_dispute(&order_id, customer_share) {
+				Ok((order, resolution)) => {
+					Self::deposit_event(Event::<T>::DisputeResolved(order, resolution));
+					Ok(().into())
+				},
+				Err(error) => Err(error.into()),
+			}
+		}
+
+		#[pallet::weight(T::OrdersWeightInfo::release_order_settlement())]
+		pub fn release_order_settlement(
+			origin: OriginFor<T>,
+			order_id: T::Hash,
+		) -> DispatchResultWithPostInfo {
+			let _ = ensure_signed(origin)?;
+
+			match <Self as OrderInterface<T>>::release_order_settlement(&order_id) {
+				Ok(order) => {
+					Self::deposit_event(Event::<T>::OrderSettlementReleased(order));
+					Ok(().into())
+				},
+				Err(error) => Err(error.into()),
+			}
+		}
+
+		#[pallet::weight(T::OrdersWeightInfo::create_payment_plan())]
+		pub fn create_payment_plan(
+			origin: OriginFor<T>,
+			order_id: T::Hash,
+			installments: u32,
+		) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+
+			match <Self as OrderInterface<T>>::create_payment_plan(&who, &order_id, installments) {
+				Ok((order, payment_plan)) => {
+					Self::deposit_event(Event::<T>::PaymentPlanCreated(order, payment_plan));
+					Ok(().into())
+				},
+				Err(error) => Err(error.into()),
+			}
+		}
+
+		#[pallet::weight(T::OrdersWeightInfo::pay_installment())]
+		pub fn pay_installment(
+			origin: OriginFor<T>,
+			order_id: T::Hash,
+		) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+
+			match <Self as OrderInterface<T>>::pay_installment(&who, &order_id) {
+				Ok((order, paid_installments)) => {
+					let is_paid = order.status == OrderStatus::Paid;
+
+					Self::deposit_event(Event::<T>::OrderInstallmentPaid(
+						order.clone(),
+						paid_installments,
+					));
+
+					if is_paid {
+						Self::deposit_event(Event::<T>::OrderPaid(order));
+					}
+
+					Ok(().into())
+				},
+				Err(error) => Err(error.into()),
+			}
+		}
+
+		#[pallet::weight(T::OrdersWeightInfo::cancel_overdue_order())]
+		pub fn cancel_overdue_order(
+			origin: OriginFor<T>,
+			order_id: T::Hash,
+		) -> DispatchResultWithPostInfo {
+			let _ = ensure_signed(origin)?;
+
+			match <Self as OrderInterface<T>>::cancel_overdue_order(&order_id) {
+				Ok(order) => {
+					Self::deposit_event(Event::<T>::OrderOverdueCancelled(order));
+					Ok(().into())
+				},
+				Err(error) => Err(error.into()),
+			}
+		}
+
 		#[pallet::weight(T::OrdersWeightInfo::update_key())]
 		pub fn update_key(
 			origin: OriginFor<T>,
@@ -343,5 +663,20 @@ pub mod pallet {
 
 			Ok(Pays::No.into())
 		}
+
+		#[pallet::weight(0)]
+		pub fn sudo_update_fee_schedule(
+			origin: OriginFor<T>,
+			fee_schedule: FeeScheduleOf<T>,
+		) -> DispatchResultWithPostInfo {
+			ensure_root(origin)?;
+			ensure!(fee_schedule.is_valid(), Error::<T>::InvalidFeeSchedule);
+
+			PlatformFeeSchedule::<T>::put(&fee_schedule);
+
+			Self::deposit_event(Event::FeeScheduleUpdated(fee_schedule));
+
+			Ok(Pays::No.into())
+		}
 	}
 }
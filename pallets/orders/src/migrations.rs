@@ -1,8 +1,9 @@
 use crate::{
-	AccountIdOf, BalanceOf, Config, CurrencyOf, HashOf, MomentOf, Order, OrderOf, OrderStatus,
-	Orders, Pallet, PalletAccount,
+	AccountIdOf, BalanceOf, Config, CurrencyOf, HashOf, MomentOf, Order, OrderStatus, Pallet,
+	PalletAccount,
 };
 use frame_support::{
+	log,
 	pallet_prelude::*,
 	sp_runtime::traits::{AccountIdConversion, Zero},
 	traits::{fungibles, Currency, ExistenceRequirement, Get},
@@ -29,6 +30,16 @@ pub fn migrate<T: Config>() -> Weight {
 		version = StorageVersion::new(2);
 	}
 
+	if version == 2 {
+		weight = weight.saturating_add(version::v3::migrate::<T>());
+		version = StorageVersion::new(3);
+	}
+
+	if version == 3 {
+		weight = weight.saturating_add(version::v4::migrate::<T>());
+		version = StorageVersion::new(4);
+	}
+
 	version.put::<Pallet<T>>();
 	weight
 }
@@ -36,6 +47,64 @@ pub fn migrate<T: Config>() -> Weight {
 mod version {
 	use super::*;
 
+	/// Price as stored before its component was bounded
+	#[derive(Encode, Decode, PartialEq, Eq)]
+	pub struct OldPrice<Balance> {
+		pub component: Vec<u8>,
+		pub value: Balance,
+	}
+
+	/// Order layout from v1 until `paid_amount` was added in v3
+	#[derive(Encode, Decode, PartialEq, Eq)]
+	pub struct OrderV1<Hash, AccountId, Balance, Moment> {
+		pub id: Hash,
+		pub service_id: Hash,
+		pub customer_id: AccountId,
+		pub customer_box_public_key: Hash,
+		pub seller_id: AccountId,
+		pub dna_sample_tracking_id: DnaSampleTrackingId,
+		pub asset_id: Option<u32>,
+		pub currency: CurrencyType,
+		pub prices: Vec<OldPrice<Balance>>,
+		pub additional_prices: Vec<OldPrice<Balance>>,
+		pub total_price: Balance,
+		pub status: OrderStatus,
+		pub order_flow: ServiceFlow,
+		pub created_at: Moment,
+		pub updated_at: Moment,
+	}
+
+	pub type OrderV1Of<T> = OrderV1<HashOf<T>, AccountIdOf<T>, BalanceOf<T>, MomentOf<T>>;
+
+	/// Truncates `value` to the bound and logs the entry when it did not fit.
+	fn bounded<I, S: Get<u32>>(value: Vec<I>, entry: &str) -> BoundedVec<I, S> {
+		if value.len() > S::get() as usize {
+			log::warn!(
+				target: "runtime::orders",
+				"{} exceeds its bound of {}, truncated from {} items",
+				entry,
+				S::get(),
+				value.len(),
+			);
+		}
+
+		BoundedVec::truncate_from(value)
+	}
+
+	fn bounded_prices<Balance>(prices: Vec<OldPrice<Balance>>) -> Vec<Price<Balance>> {
+		prices
+			.into_iter()
+			.map(|price| Price {
+				component: bounded(price.component, "order price component"),
+				value: price.value,
+			})
+			.collect()
+	}
+
+	/// `Orders` as stored before v3, used by the v1 and v2 migrations
+	#[frame_support::storage_alias]
+	pub type Orders<T: Config> = StorageMap<Pallet<T>, Blake2_128Concat, HashOf<T>, OrderV1Of<T>>;
+
 	pub mod v1 {
 		use super::*;
 
@@ -51,8 +120,8 @@ mod version {
 				pub seller_id: AccountId,
 				pub dna_sample_tracking_id: DnaSampleTrackingId,
 				pub currency: CurrencyType,
-				pub prices: Vec<Price<Balance>>,
-				pub additional_prices: Vec<Price<Balance>>,
+				pub prices: Vec<OldPrice<Balance>>,
+				pub additional_prices: Vec<OldPrice<Balance>>,
 				pub status: OrderStatus,
 				pub order_flow: ServiceFlow,
 				pub created_at: Moment,
@@ -98,7 +167,7 @@ mod version {
 					total_price += addition_price.value;
 				}
 
-				Some(Order {
+				Some(OrderV1 {
 					id: old_order.id,
 					service_id: old_order.service_id,
 					customer_id: old_order.customer_id,
@@ -132,7 +201,7 @@ mod version {
 
 			PalletAccount::<T>::put(&receiver);
 
-			Orders::<T>::translate(|order_id: HashOf<T>, order: OrderOf<T>| {
+			Orders::<T>::translate(|order_id: HashOf<T>, order: OrderV1Of<T>| {
 				weight = weight.saturating_add(T::DbWeight::get().reads_writes(1, 1));
 
 				let sender: T::AccountId = PALLET_ID.into_sub_account_truncating(order_id);
@@ -168,4 +237,118 @@ mod version {
 			weight
 		}
 	}
+
+	pub mod v3 {
+		use super::*;
+
+		/// Paid orders are queued for a refund once their deadline passes. No order has a
+		/// payment plan yet, so there are no installment deadlines to queue. Prices become
+		/// bounded, longer legacy price lists and price components are truncated and logged.
+		pub fn migrate<T: Config>() -> Weight {
+			let mut weight = T::DbWeight::get().writes(1);
+
+			crate::Orders::<T>::translate(|_key, old_order: OrderV1Of<T>| {
+				weight = weight.saturating_add(T::DbWeight::get().reads_writes(1, 1));
+
+				// Orders were always paid in full before payment plans existed
+				let paid_amount = match old_order.status {
+					OrderStatus::Unpaid | OrderStatus::Cancelled => Zero::zero(),
+					_ => old_order.total_price,
+				};
+
+				let order = Order {
+					id: old_order.id,
+					service_id: old_order.service_id,
+					customer_id: old_order.customer_id,
+					customer_box_public_key: old_order.customer_box_public_key,
+					seller_id: old_order.seller_id,
+					dna_sample_tracking_id: old_order.dna_sample_tracking_id,
+					asset_id: old_order.asset_id,
+					currency: old_order.currency,
+					prices: bounded(bounded_prices(old_order.prices), "order prices"),
+					additional_prices: bounded(
+						bounded_prices(old_order.additional_prices),
+						"order additional prices",
+					),
+					total_price: old_order.total_price,
+					paid_amount,
+					status: old_order.status,
+					order_flow: old_order.order_flow,
+					created_at: old_order.created_at,
+					updated_at: old_order.updated_at,
+				};
+
+				if order.status == OrderStatus::Paid {
+					weight = weight.saturating_add(T::DbWeight::get().reads_writes(3, 3));
+
+					Pallet::<T>::insert_refund_deadline(&order);
+				}
+
+				Some(order)
+			});
+
+			weight
+		}
+	}
+
+	pub mod v4 {
+		use super::*;
+
+		#[frame_support::storage_alias]
+		type OrdersByCustomer<T: Config> =
+			StorageMap<Pallet<T>, Blake2_128Concat, AccountIdOf<T>, Vec<HashOf<T>>>;
+
+		#[frame_support::storage_alias]
+		type OrdersBySeller<T: Config> =
+			StorageMap<Pallet<T>, Blake2_128Concat, AccountIdOf<T>, Vec<HashOf<T>>>;
+
+		#[frame_support::storage_alias]
+		type PendingOrdersBySeller<T: Config> =
+			StorageMap<Pallet<T>, Blake2_128Concat, AccountIdOf<T>, Vec<HashOf<T>>>;
+
+		/// Order id indexes move from one list per account to one key per order id, every order
+		/// id is kept
+		pub fn migrate<T: Config>() -> Weight {
+			let mut weight = T::DbWeight::get().writes(1);
+
+			weight = weight.saturating_add(move_index::<T>(
+				OrdersByCustomer::<T>::drain().collect(),
+				|customer_id, order_id| {
+					crate::OrdersByCustomer::<T>::insert(customer_id, order_id, ())
+				},
+			));
+
+			weight = weight.saturating_add(move_index::<T>(
+				OrdersBySeller::<T>::drain().collect(),
+				|seller_id, order_id| crate::OrdersBySeller::<T>::insert(seller_id, order_id, ()),
+			));
+
+			weight = weight.saturating_add(move_index::<T>(
+				PendingOrdersBySeller::<T>::drain().collect(),
+				|seller_id, order_id| {
+					crate::PendingOrdersBySeller::<T>::insert(seller_id, order_id, ())
+				},
+			));
+
+			weight
+		}
+
+		fn move_index<T: Config>(
+			index: Vec<(AccountIdOf<T>, Vec<HashOf<T>>)>,
+			insert: impl Fn(&AccountIdOf<T>, &HashOf<T>),
+		) -> Weight {
+			let mut weight = Weight::zero();
+
+			for (account_id, order_ids) in index {
+				weight = weight
+					.saturating_add(T::DbWeight::get().reads_writes(1, order_ids.len() as u64 + 1));
+
+				for order_id in order_ids.iter() {
+					insert(&account_id, order_id);
+				}
+			}
+
+			weight
+		}
+	}
 }
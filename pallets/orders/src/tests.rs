@@ -1,18 +1,32 @@
 use crate::{
-	mock::*, AccountKeyType, Error, EscrowKey, Order, OrderStatus, PalletAccount, TreasuryKey,
+	migrations, mock::*, AccountKeyType, DisputeResolution, Error, EscrowKey, Installment,
+	NextRefundBucket, Order, OrderItem, OrderOf, OrderStatus, PalletAccount, PaymentPlan,
+	RefundDeadlines, SettlementReleases, TreasuryKey, MAX_DISPUTE_REASON_LENGTH, MAX_ORDER_PRICES,
 };
+use codec::Encode;
 use frame_support::{
 	assert_noop, assert_ok,
-	sp_runtime::traits::{Hash, Keccak256},
+	sp_runtime::{
+		traits::{BadOrigin, Hash, Keccak256},
+		Perbill,
+	},
+	storage::migration::put_storage_value,
+	traits::{Hooks, StorageVersion},
+	weights::Weight,
+	Blake2_128Concat, StorageHasher,
 };
 use genetic_testing::{DnaSampleStatus, DnaTestResultSubmission};
 use labs::LabInfo;
 use primitives_area_code::{CityCode, CountryCode, RegionCode};
 use services::ServiceInfo;
+use sp_core::H256;
 use traits_services::types::ServiceFlow;
 
-use primitives_duration::ExpectedDuration;
-use primitives_price_and_currency::{CurrencyType, Price, PriceByCurrency};
+use primitives_duration::{DurationType, ExpectedDuration};
+use primitives_platform_fee::{CurrencyFeeRate, FeeBeneficiary, FeeRate, FeeSchedule, FeeSplit};
+use primitives_price_and_currency::{
+	CurrencyType, Price, PriceByCurrency, MAX_PRICE_COMPONENT_LENGTH,
+};
 
 #[test]
 fn create_order_works() {
@@ -26,17 +40,19 @@ fn create_order_works() {
 				box_public_key: Keccak256::hash(
 					"0xDb9Af2d1f3ADD2726A132AA7A65Cc9E6fC5761C3".as_bytes()
 				),
-				name: "DeBio Lab".as_bytes().to_vec(),
-				email: "DeBio Email".as_bytes().to_vec(),
-				country: CountryCode::from_vec("DC".as_bytes().to_vec()),
-				region: RegionCode::from_vec("DB".as_bytes().to_vec()),
-				city: CityCode::from_vec("CITY".as_bytes().to_vec()),
-				address: "DeBio Address".as_bytes().to_vec(),
-				phone_number: "+6281394653625".as_bytes().to_vec(),
-				website: "DeBio Website".as_bytes().to_vec(),
-				latitude: Some("DeBio Latitude".as_bytes().to_vec()),
-				longitude: Some("DeBio Longtitude".as_bytes().to_vec()),
-				profile_image: Some("DeBio Profile Image uwu".as_bytes().to_vec()),
+				name: "DeBio Lab".as_bytes().to_vec().try_into().unwrap(),
+				email: "DeBio Email".as_bytes().to_vec().try_into().unwrap(),
+				country: CountryCode::try_from("ID".as_bytes().to_vec()).unwrap(),
+				region: RegionCode::try_from("JB".as_bytes().to_vec()).unwrap(),
+				city: CityCode::try_from("CITY".as_bytes().to_vec()).unwrap(),
+				address: "DeBio Address".as_bytes().to_vec().try_into().unwrap(),
+				phone_number: "+6281394653625".as_bytes().to_vec().try_into().unwrap(),
+				website: "DeBio Website".as_bytes().to_vec().try_into().unwrap(),
+				latitude: Some("-6.175392".as_bytes().to_vec().try_into().unwrap()),
+				longitude: Some("106.827153".as_bytes().to_vec().try_into().unwrap()),
+				profile_image: Some(
+					"DeBio Profile Image uwu".as_bytes().to_vec().try_into().unwrap()
+				),
 			}
 		));
 
@@ -48,29 +64,57 @@ fn create_order_works() {
 		let prices_by_currency_dbio = PriceByCurrency {
 			currency: CurrencyType::DBIO,
 			total_price: 10,
-			price_components: vec![Price { component: b"testing_price".to_vec(), value: 5 }],
-			additional_prices: vec![Price { component: b"qc_price".to_vec(), value: 5 }],
+			price_components: vec![Price {
+				component: b"testing_price".to_vec().try_into().unwrap(),
+				value: 5,
+			}]
+			.try_into()
+			.unwrap(),
+			additional_prices: vec![Price {
+				component: b"qc_price".to_vec().try_into().unwrap(),
+				value: 5,
+			}]
+			.try_into()
+			.unwrap(),
 		};
 
 		let prices_by_currency_usdt = PriceByCurrency {
 			currency: CurrencyType::USDT,
 			total_price: 10,
-			price_components: vec![Price { component: b"testing_price".to_vec(), value: 5 }],
-			additional_prices: vec![Price { component: b"qc_price".to_vec(), value: 5 }],
+			price_components: vec![Price {
+				component: b"testing_price".to_vec().try_into().unwrap(),
+				value: 5,
+			}]
+			.try_into()
+			.unwrap(),
+			additional_prices: vec![Price {
+				component: b"qc_price".to_vec().try_into().unwrap(),
+				value: 5,
+			}]
+			.try_into()
+			.unwrap(),
 		};
 
 		assert_ok!(Services::create_service(
 			RuntimeOrigin::signed(lab),
 			ServiceInfo {
-				name: "DeBio name".as_bytes().to_vec(),
-				prices_by_currency: vec![prices_by_currency_dbio.clone(), prices_by_currency_usdt],
+				name: "DeBio name".as_bytes().to_vec().try_into().unwrap(),
+				prices_by_currency: vec![prices_by_currency_dbio.clone(), prices_by_currency_usdt]
+					.try_into()
+					.unwrap(),
 				expected_duration: ExpectedDuration::default(),
-				category: "DeBio category".as_bytes().to_vec(),
-				description: "This is my description".as_bytes().to_vec(),
-				test_result_sample: "Test result sample".as_bytes().to_vec(),
-				dna_collection_process: "Dna Collection Process".as_bytes().to_vec(),
-				long_description: Some("This is my long description".as_bytes().to_vec()),
-				image: Some("This is my image".as_bytes().to_vec()),
+				category: "DeBio category".as_bytes().to_vec().try_into().unwrap(),
+				description: "This is my description".as_bytes().to_vec().try_into().unwrap(),
+				test_result_sample: "Test result sample".as_bytes().to_vec().try_into().unwrap(),
+				dna_collection_process: "Dna Collection Process"
+					.as_bytes()
+					.to_vec()
+					.try_into()
+					.unwrap(),
+				long_description: Some(
+					"This is my long description".as_bytes().to_vec().try_into().unwrap()
+				),
+				image: Some("This is my image".as_bytes().to_vec().try_into().unwrap()),
 			},
 			ServiceFlow::default()
 		));
@@ -88,7 +132,7 @@ fn create_order_works() {
 		));
 
 		let _order_id = Orders::last_order_by_customer_id(customer).unwrap();
-		let _dna_sample = GeneticTesting::dna_samples_by_lab_id(lab).unwrap();
+		let _dna_sample = GeneticTesting::dna_samples_by_lab_id(&lab);
 
 		assert_eq!(
 			Orders::order_by_id(&_order_id),
@@ -103,9 +147,14 @@ fn create_order_works() {
 				dna_sample_tracking_id: _dna_sample[0].clone(),
 				asset_id: None,
 				total_price: 10,
+				paid_amount: 0,
 				currency: CurrencyType::DBIO,
-				prices: prices_by_currency_dbio.price_components,
-				additional_prices: prices_by_currency_dbio.additional_prices,
+				prices: prices_by_currency_dbio.price_components.to_vec().try_into().unwrap(),
+				additional_prices: prices_by_currency_dbio
+					.additional_prices
+					.to_vec()
+					.try_into()
+					.unwrap(),
 				status: OrderStatus::default(),
 				order_flow: ServiceFlow::StakingRequestService,
 				created_at: 0,
@@ -113,12 +162,9 @@ fn create_order_works() {
 			})
 		);
 
-		assert_eq!(Orders::orders_by_lab_id(lab), Some(vec![_order_id]));
-		assert_eq!(Orders::orders_by_customer_id(customer), Some(vec![_order_id]));
-		assert_eq!(
-			Orders::pending_genetic_analysis_orders_by_genetic_analyst_id(lab),
-			Some(vec![_order_id])
-		);
+		assert_eq!(Orders::orders_by_lab_id(&lab), vec![_order_id]);
+		assert_eq!(Orders::orders_by_customer_id(&customer), vec![_order_id]);
+		assert_eq!(Orders::pending_orders_by_seller_id(&lab), vec![_order_id]);
 	})
 }
 
@@ -134,17 +180,19 @@ fn cancel_order_works_when_order_status_unpaid() {
 				box_public_key: Keccak256::hash(
 					"0xDb9Af2d1f3ADD2726A132AA7A65Cc9E6fC5761C3".as_bytes()
 				),
-				name: "DeBio Lab".as_bytes().to_vec(),
-				email: "DeBio Email".as_bytes().to_vec(),
-				country: CountryCode::from_vec("DC".as_bytes().to_vec()),
-				region: RegionCode::from_vec("DB".as_bytes().to_vec()),
-				city: CityCode::from_vec("CITY".as_bytes().to_vec()),
-				address: "DeBio Address".as_bytes().to_vec(),
-				phone_number: "+6281394653625".as_bytes().to_vec(),
-				website: "DeBio Website".as_bytes().to_vec(),
-				latitude: Some("DeBio Latitude".as_bytes().to_vec()),
-				longitude: Some("DeBio Longtitude".as_bytes().to_vec()),
-				profile_image: Some("DeBio Profile Image uwu".as_bytes().to_vec()),
+				name: "DeBio Lab".as_bytes().to_vec().try_into().unwrap(),
+				email: "DeBio Email".as_bytes().to_vec().try_into().unwrap(),
+				country: CountryCode::try_from("ID".as_bytes().to_vec()).unwrap(),
+				region: RegionCode::try_from("JB".as_bytes().to_vec()).unwrap(),
+				city: CityCode::try_from("CITY".as_bytes().to_vec()).unwrap(),
+				address: "DeBio Address".as_bytes().to_vec().try_into().unwrap(),
+				phone_number: "+6281394653625".as_bytes().to_vec().try_into().unwrap(),
+				website: "DeBio Website".as_bytes().to_vec().try_into().unwrap(),
+				latitude: Some("-6.175392".as_bytes().to_vec().try_into().unwrap()),
+				longitude: Some("106.827153".as_bytes().to_vec().try_into().unwrap()),
+				profile_image: Some(
+					"DeBio Profile Image uwu".as_bytes().to_vec().try_into().unwrap()
+				),
 			}
 		));
 
@@ -156,22 +204,38 @@ fn cancel_order_works_when_order_status_unpaid() {
 		let prices_by_currency_dbio = PriceByCurrency {
 			currency: CurrencyType::DBIO,
 			total_price: 10,
-			price_components: vec![Price { component: b"testing_price".to_vec(), value: 5 }],
-			additional_prices: vec![Price { component: b"qc_price".to_vec(), value: 5 }],
+			price_components: vec![Price {
+				component: b"testing_price".to_vec().try_into().unwrap(),
+				value: 5,
+			}]
+			.try_into()
+			.unwrap(),
+			additional_prices: vec![Price {
+				component: b"qc_price".to_vec().try_into().unwrap(),
+				value: 5,
+			}]
+			.try_into()
+			.unwrap(),
 		};
 
 		assert_ok!(Services::create_service(
 			RuntimeOrigin::signed(lab),
 			ServiceInfo {
-				name: "DeBio name".as_bytes().to_vec(),
-				prices_by_currency: vec![prices_by_currency_dbio.clone()],
+				name: "DeBio name".as_bytes().to_vec().try_into().unwrap(),
+				prices_by_currency: vec![prices_by_currency_dbio.clone()].try_into().unwrap(),
 				expected_duration: ExpectedDuration::default(),
-				category: "DeBio category".as_bytes().to_vec(),
-				description: "This is my description".as_bytes().to_vec(),
-				test_result_sample: "Test result sample".as_bytes().to_vec(),
-				dna_collection_process: "Dna Collection Process".as_bytes().to_vec(),
-				long_description: Some("This is my long description".as_bytes().to_vec()),
-				image: Some("This is my image".as_bytes().to_vec()),
+				category: "DeBio category".as_bytes().to_vec().try_into().unwrap(),
+				description: "This is my description".as_bytes().to_vec().try_into().unwrap(),
+				test_result_sample: "Test result sample".as_bytes().to_vec().try_into().unwrap(),
+				dna_collection_process: "Dna Collection Process"
+					.as_bytes()
+					.to_vec()
+					.try_into()
+					.unwrap(),
+				long_description: Some(
+					"This is my long description".as_bytes().to_vec().try_into().unwrap()
+				),
+				image: Some("This is my image".as_bytes().to_vec().try_into().unwrap()),
 			},
 			ServiceFlow::default()
 		));
@@ -188,7 +252,7 @@ fn cancel_order_works_when_order_status_unpaid() {
 		));
 
 		let _order_id = Orders::last_order_by_customer_id(customer).unwrap();
-		let _dna_sample = GeneticTesting::dna_samples_by_lab_id(lab).unwrap();
+		let _dna_sample = GeneticTesting::dna_samples_by_lab_id(&lab);
 
 		assert_ok!(Orders::cancel_order(RuntimeOrigin::signed(customer), _order_id));
 
@@ -205,9 +269,14 @@ fn cancel_order_works_when_order_status_unpaid() {
 				dna_sample_tracking_id: _dna_sample[0].clone(),
 				asset_id: None,
 				total_price: 10,
+				paid_amount: 0,
 				currency: CurrencyType::DBIO,
-				prices: prices_by_currency_dbio.price_components,
-				additional_prices: prices_by_currency_dbio.additional_prices,
+				prices: prices_by_currency_dbio.price_components.to_vec().try_into().unwrap(),
+				additional_prices: prices_by_currency_dbio
+					.additional_prices
+					.to_vec()
+					.try_into()
+					.unwrap(),
 				status: OrderStatus::Cancelled,
 				order_flow: ServiceFlow::StakingRequestService,
 				created_at: 0,
@@ -217,10 +286,7 @@ fn cancel_order_works_when_order_status_unpaid() {
 
 		assert_eq!(GeneticTesting::dna_sample_by_tracking_id(_dna_sample[0].clone()), None);
 
-		assert_eq!(
-			Orders::pending_genetic_analysis_orders_by_genetic_analyst_id(lab),
-			Some(Vec::new()),
-		);
+		assert_eq!(Orders::pending_orders_by_seller_id(&lab), Vec::new(),);
 	})
 }
 
@@ -240,17 +306,19 @@ fn set_order_paid_with_dbio_works() {
 				box_public_key: Keccak256::hash(
 					"0xDb9Af2d1f3ADD2726A132AA7A65Cc9E6fC5761C3".as_bytes()
 				),
-				name: "DeBio Lab".as_bytes().to_vec(),
-				email: "DeBio Email".as_bytes().to_vec(),
-				country: CountryCode::from_vec("DC".as_bytes().to_vec()),
-				region: RegionCode::from_vec("DB".as_bytes().to_vec()),
-				city: CityCode::from_vec("CITY".as_bytes().to_vec()),
-				address: "DeBio Address".as_bytes().to_vec(),
-				phone_number: "+6281394653625".as_bytes().to_vec(),
-				website: "DeBio Website".as_bytes().to_vec(),
-				latitude: Some("DeBio Latitude".as_bytes().to_vec()),
-				longitude: Some("DeBio Longtitude".as_bytes().to_vec()),
-				profile_image: Some("DeBio Profile Image uwu".as_bytes().to_vec()),
+				name: "DeBio Lab".as_bytes().to_vec().try_into().unwrap(),
+				email: "DeBio Email".as_bytes().to_vec().try_into().unwrap(),
+				country: CountryCode::try_from("ID".as_bytes().to_vec()).unwrap(),
+				region: RegionCode::try_from("JB".as_bytes().to_vec()).unwrap(),
+				city: CityCode::try_from("CITY".as_bytes().to_vec()).unwrap(),
+				address: "DeBio Address".as_bytes().to_vec().try_into().unwrap(),
+				phone_number: "+6281394653625".as_bytes().to_vec().try_into().unwrap(),
+				website: "DeBio Website".as_bytes().to_vec().try_into().unwrap(),
+				latitude: Some("-6.175392".as_bytes().to_vec().try_into().unwrap()),
+				longitude: Some("106.827153".as_bytes().to_vec().try_into().unwrap()),
+				profile_image: Some(
+					"DeBio Profile Image uwu".as_bytes().to_vec().try_into().unwrap()
+				),
 			}
 		));
 
@@ -262,22 +330,38 @@ fn set_order_paid_with_dbio_works() {
 		let prices_by_currency_dbio = PriceByCurrency {
 			currency: CurrencyType::DBIO,
 			total_price: 10,
-			price_components: vec![Price { component: b"testing_price".to_vec(), value: 5 }],
-			additional_prices: vec![Price { component: b"qc_price".to_vec(), value: 5 }],
+			price_components: vec![Price {
+				component: b"testing_price".to_vec().try_into().unwrap(),
+				value: 5,
+			}]
+			.try_into()
+			.unwrap(),
+			additional_prices: vec![Price {
+				component: b"qc_price".to_vec().try_into().unwrap(),
+				value: 5,
+			}]
+			.try_into()
+			.unwrap(),
 		};
 
 		assert_ok!(Services::create_service(
 			RuntimeOrigin::signed(lab),
 			ServiceInfo {
-				name: "DeBio name".as_bytes().to_vec(),
-				prices_by_currency: vec![prices_by_currency_dbio.clone()],
+				name: "DeBio name".as_bytes().to_vec().try_into().unwrap(),
+				prices_by_currency: vec![prices_by_currency_dbio.clone()].try_into().unwrap(),
 				expected_duration: ExpectedDuration::default(),
-				category: "DeBio category".as_bytes().to_vec(),
-				description: "This is my description".as_bytes().to_vec(),
-				test_result_sample: "Test result sample".as_bytes().to_vec(),
-				dna_collection_process: "Dna Collection Process".as_bytes().to_vec(),
-				long_description: Some("This is my long description".as_bytes().to_vec()),
-				image: Some("This is my image".as_bytes().to_vec()),
+				category: "DeBio category".as_bytes().to_vec().try_into().unwrap(),
+				description: "This is my description".as_bytes().to_vec().try_into().unwrap(),
+				test_result_sample: "Test result sample".as_bytes().to_vec().try_into().unwrap(),
+				dna_collection_process: "Dna Collection Process"
+					.as_bytes()
+					.to_vec()
+					.try_into()
+					.unwrap(),
+				long_description: Some(
+					"This is my long description".as_bytes().to_vec().try_into().unwrap()
+				),
+				image: Some("This is my image".as_bytes().to_vec().try_into().unwrap()),
 			},
 			ServiceFlow::default()
 		));
@@ -294,7 +378,7 @@ fn set_order_paid_with_dbio_works() {
 		));
 
 		let _order_id = Orders::last_order_by_customer_id(customer).unwrap();
-		let _dna_sample = GeneticTesting::dna_samples_by_lab_id(lab).unwrap();
+		let _dna_sample = GeneticTesting::dna_samples_by_lab_id(&lab);
 
 		EscrowKey::<Test>::put(admin);
 
@@ -313,9 +397,14 @@ fn set_order_paid_with_dbio_works() {
 				dna_sample_tracking_id: _dna_sample[0].clone(),
 				asset_id: None,
 				total_price: 10,
+				paid_amount: 10,
 				currency: CurrencyType::default(),
-				prices: prices_by_currency_dbio.price_components,
-				additional_prices: prices_by_currency_dbio.additional_prices,
+				prices: prices_by_currency_dbio.price_components.to_vec().try_into().unwrap(),
+				additional_prices: prices_by_currency_dbio
+					.additional_prices
+					.to_vec()
+					.try_into()
+					.unwrap(),
 				status: OrderStatus::Paid,
 				order_flow: ServiceFlow::StakingRequestService,
 				created_at: 0,
@@ -344,17 +433,19 @@ fn set_order_paid_with_app_chain_token_works() {
 				box_public_key: Keccak256::hash(
 					"0xDb9Af2d1f3ADD2726A132AA7A65Cc9E6fC5761C3".as_bytes()
 				),
-				name: "DeBio Lab".as_bytes().to_vec(),
-				email: "DeBio Email".as_bytes().to_vec(),
-				country: CountryCode::from_vec("DC".as_bytes().to_vec()),
-				region: RegionCode::from_vec("DB".as_bytes().to_vec()),
-				city: CityCode::from_vec("CITY".as_bytes().to_vec()),
-				address: "DeBio Address".as_bytes().to_vec(),
-				phone_number: "+6281394653625".as_bytes().to_vec(),
-				website: "DeBio Website".as_bytes().to_vec(),
-				latitude: Some("DeBio Latitude".as_bytes().to_vec()),
-				longitude: Some("DeBio Longtitude".as_bytes().to_vec()),
-				profile_image: Some("DeBio Profile Image uwu".as_bytes().to_vec()),
+				name: "DeBio Lab".as_bytes().to_vec().try_into().unwrap(),
+				email: "DeBio Email".as_bytes().to_vec().try_into().unwrap(),
+				country: CountryCode::try_from("ID".as_bytes().to_vec()).unwrap(),
+				region: RegionCode::try_from("JB".as_bytes().to_vec()).unwrap(),
+				city: CityCode::try_from("CITY".as_bytes().to_vec()).unwrap(),
+				address: "DeBio Address".as_bytes().to_vec().try_into().unwrap(),
+				phone_number: "+6281394653625".as_bytes().to_vec().try_into().unwrap(),
+				website: "DeBio Website".as_bytes().to_vec().try_into().unwrap(),
+				latitude: Some("-6.175392".as_bytes().to_vec().try_into().unwrap()),
+				longitude: Some("106.827153".as_bytes().to_vec().try_into().unwrap()),
+				profile_image: Some(
+					"DeBio Profile Image uwu".as_bytes().to_vec().try_into().unwrap()
+				),
 			}
 		));
 
@@ -366,22 +457,38 @@ fn set_order_paid_with_app_chain_token_works() {
 		let prices_by_currency_usdt = PriceByCurrency {
 			currency: CurrencyType::USDT,
 			total_price: 10,
-			price_components: vec![Price { component: b"testing_price".to_vec(), value: 5 }],
-			additional_prices: vec![Price { component: b"qc_price".to_vec(), value: 5 }],
+			price_components: vec![Price {
+				component: b"testing_price".to_vec().try_into().unwrap(),
+				value: 5,
+			}]
+			.try_into()
+			.unwrap(),
+			additional_prices: vec![Price {
+				component: b"qc_price".to_vec().try_into().unwrap(),
+				value: 5,
+			}]
+			.try_into()
+			.unwrap(),
 		};
 
 		assert_ok!(Services::create_service(
 			RuntimeOrigin::signed(lab),
 			ServiceInfo {
-				name: "DeBio name".as_bytes().to_vec(),
-				prices_by_currency: vec![prices_by_currency_usdt.clone()],
+				name: "DeBio name".as_bytes().to_vec().try_into().unwrap(),
+				prices_by_currency: vec![prices_by_currency_usdt.clone()].try_into().unwrap(),
 				expected_duration: ExpectedDuration::default(),
-				category: "DeBio category".as_bytes().to_vec(),
-				description: "This is my description".as_bytes().to_vec(),
-				test_result_sample: "Test result sample".as_bytes().to_vec(),
-				dna_collection_process: "Dna Collection Process".as_bytes().to_vec(),
-				long_description: Some("This is my long description".as_bytes().to_vec()),
-				image: Some("This is my image".as_bytes().to_vec()),
+				category: "DeBio category".as_bytes().to_vec().try_into().unwrap(),
+				description: "This is my description".as_bytes().to_vec().try_into().unwrap(),
+				test_result_sample: "Test result sample".as_bytes().to_vec().try_into().unwrap(),
+				dna_collection_process: "Dna Collection Process"
+					.as_bytes()
+					.to_vec()
+					.try_into()
+					.unwrap(),
+				long_description: Some(
+					"This is my long description".as_bytes().to_vec().try_into().unwrap()
+				),
+				image: Some("This is my image".as_bytes().to_vec().try_into().unwrap()),
 			},
 			ServiceFlow::default()
 		));
@@ -399,7 +506,7 @@ fn set_order_paid_with_app_chain_token_works() {
 		));
 
 		let _order_id = Orders::last_order_by_customer_id(customer).unwrap();
-		let _dna_sample = GeneticTesting::dna_samples_by_lab_id(lab).unwrap();
+		let _dna_sample = GeneticTesting::dna_samples_by_lab_id(&lab);
 
 		EscrowKey::<Test>::put(admin);
 
@@ -418,9 +525,14 @@ fn set_order_paid_with_app_chain_token_works() {
 				dna_sample_tracking_id: _dna_sample[0].clone(),
 				asset_id: Some(asset_id),
 				total_price: 10,
+				paid_amount: 10,
 				currency: CurrencyType::USDT,
-				prices: prices_by_currency_usdt.price_components,
-				additional_prices: prices_by_currency_usdt.additional_prices,
+				prices: prices_by_currency_usdt.price_components.to_vec().try_into().unwrap(),
+				additional_prices: prices_by_currency_usdt
+					.additional_prices
+					.to_vec()
+					.try_into()
+					.unwrap(),
 				status: OrderStatus::Paid,
 				order_flow: ServiceFlow::StakingRequestService,
 				created_at: 0,
@@ -448,17 +560,19 @@ fn cancel_order_works_when_order_status_paid() {
 				box_public_key: Keccak256::hash(
 					"0xDb9Af2d1f3ADD2726A132AA7A65Cc9E6fC5761C3".as_bytes()
 				),
-				name: "DeBio Lab".as_bytes().to_vec(),
-				email: "DeBio Email".as_bytes().to_vec(),
-				country: CountryCode::from_vec("DC".as_bytes().to_vec()),
-				region: RegionCode::from_vec("DB".as_bytes().to_vec()),
-				city: CityCode::from_vec("CITY".as_bytes().to_vec()),
-				address: "DeBio Address".as_bytes().to_vec(),
-				phone_number: "+6281394653625".as_bytes().to_vec(),
-				website: "DeBio Website".as_bytes().to_vec(),
-				latitude: Some("DeBio Latitude".as_bytes().to_vec()),
-				longitude: Some("DeBio Longtitude".as_bytes().to_vec()),
-				profile_image: Some("DeBio Profile Image uwu".as_bytes().to_vec()),
+				name: "DeBio Lab".as_bytes().to_vec().try_into().unwrap(),
+				email: "DeBio Email".as_bytes().to_vec().try_into().unwrap(),
+				country: CountryCode::try_from("ID".as_bytes().to_vec()).unwrap(),
+				region: RegionCode::try_from("JB".as_bytes().to_vec()).unwrap(),
+				city: CityCode::try_from("CITY".as_bytes().to_vec()).unwrap(),
+				address: "DeBio Address".as_bytes().to_vec().try_into().unwrap(),
+				phone_number: "+6281394653625".as_bytes().to_vec().try_into().unwrap(),
+				website: "DeBio Website".as_bytes().to_vec().try_into().unwrap(),
+				latitude: Some("-6.175392".as_bytes().to_vec().try_into().unwrap()),
+				longitude: Some("106.827153".as_bytes().to_vec().try_into().unwrap()),
+				profile_image: Some(
+					"DeBio Profile Image uwu".as_bytes().to_vec().try_into().unwrap()
+				),
 			}
 		));
 
@@ -470,22 +584,38 @@ fn cancel_order_works_when_order_status_paid() {
 		let prices_by_currency_dbio = PriceByCurrency {
 			currency: CurrencyType::DBIO,
 			total_price: 10,
-			price_components: vec![Price { component: b"testing_price".to_vec(), value: 5 }],
-			additional_prices: vec![Price { component: b"qc_price".to_vec(), value: 5 }],
+			price_components: vec![Price {
+				component: b"testing_price".to_vec().try_into().unwrap(),
+				value: 5,
+			}]
+			.try_into()
+			.unwrap(),
+			additional_prices: vec![Price {
+				component: b"qc_price".to_vec().try_into().unwrap(),
+				value: 5,
+			}]
+			.try_into()
+			.unwrap(),
 		};
 
 		assert_ok!(Services::create_service(
 			RuntimeOrigin::signed(lab),
 			ServiceInfo {
-				name: "DeBio name".as_bytes().to_vec(),
-				prices_by_currency: vec![prices_by_currency_dbio.clone()],
+				name: "DeBio name".as_bytes().to_vec().try_into().unwrap(),
+				prices_by_currency: vec![prices_by_currency_dbio.clone()].try_into().unwrap(),
 				expected_duration: ExpectedDuration::default(),
-				category: "DeBio category".as_bytes().to_vec(),
-				description: "This is my description".as_bytes().to_vec(),
-				test_result_sample: "Test result sample".as_bytes().to_vec(),
-				dna_collection_process: "Dna Collection Process".as_bytes().to_vec(),
-				long_description: Some("This is my long description".as_bytes().to_vec()),
-				image: Some("This is my image".as_bytes().to_vec()),
+				category: "DeBio category".as_bytes().to_vec().try_into().unwrap(),
+				description: "This is my description".as_bytes().to_vec().try_into().unwrap(),
+				test_result_sample: "Test result sample".as_bytes().to_vec().try_into().unwrap(),
+				dna_collection_process: "Dna Collection Process"
+					.as_bytes()
+					.to_vec()
+					.try_into()
+					.unwrap(),
+				long_description: Some(
+					"This is my long description".as_bytes().to_vec().try_into().unwrap()
+				),
+				image: Some("This is my image".as_bytes().to_vec().try_into().unwrap()),
 			},
 			ServiceFlow::default()
 		));
@@ -502,7 +632,7 @@ fn cancel_order_works_when_order_status_paid() {
 		));
 
 		let _order_id = Orders::last_order_by_customer_id(customer).unwrap();
-		let _dna_sample = GeneticTesting::dna_samples_by_lab_id(lab).unwrap();
+		let _dna_sample = GeneticTesting::dna_samples_by_lab_id(&lab);
 
 		assert_ok!(Orders::set_order_paid(RuntimeOrigin::signed(customer), _order_id));
 
@@ -524,9 +654,14 @@ fn cancel_order_works_when_order_status_paid() {
 				dna_sample_tracking_id: _dna_sample[0].clone(),
 				asset_id: None,
 				total_price: 10,
+				paid_amount: 10,
 				currency: CurrencyType::DBIO,
-				prices: prices_by_currency_dbio.price_components,
-				additional_prices: prices_by_currency_dbio.additional_prices,
+				prices: prices_by_currency_dbio.price_components.to_vec().try_into().unwrap(),
+				additional_prices: prices_by_currency_dbio
+					.additional_prices
+					.to_vec()
+					.try_into()
+					.unwrap(),
 				status: OrderStatus::Refunded,
 				order_flow: ServiceFlow::StakingRequestService,
 				created_at: 0,
@@ -536,10 +671,7 @@ fn cancel_order_works_when_order_status_paid() {
 
 		assert_eq!(GeneticTesting::dna_sample_by_tracking_id(_dna_sample[0].clone()), None);
 
-		assert_eq!(
-			Orders::pending_genetic_analysis_orders_by_genetic_analyst_id(lab),
-			Some(Vec::new()),
-		);
+		assert_eq!(Orders::pending_orders_by_seller_id(&lab), Vec::new(),);
 
 		assert_eq!(Balances::free_balance(customer), 200);
 		assert_eq!(Balances::free_balance(pallet_id), 1);
@@ -564,17 +696,19 @@ fn fulfill_order_works() {
 				box_public_key: Keccak256::hash(
 					"0xDb9Af2d1f3ADD2726A132AA7A65Cc9E6fC5761C3".as_bytes()
 				),
-				name: "DeBio Lab".as_bytes().to_vec(),
-				email: "DeBio Email".as_bytes().to_vec(),
-				country: CountryCode::from_vec("DC".as_bytes().to_vec()),
-				region: RegionCode::from_vec("DB".as_bytes().to_vec()),
-				city: CityCode::from_vec("CITY".as_bytes().to_vec()),
-				address: "DeBio Address".as_bytes().to_vec(),
-				phone_number: "+6281394653625".as_bytes().to_vec(),
-				website: "DeBio Website".as_bytes().to_vec(),
-				latitude: Some("DeBio Latitude".as_bytes().to_vec()),
-				longitude: Some("DeBio Longtitude".as_bytes().to_vec()),
-				profile_image: Some("DeBio Profile Image uwu".as_bytes().to_vec()),
+				name: "DeBio Lab".as_bytes().to_vec().try_into().unwrap(),
+				email: "DeBio Email".as_bytes().to_vec().try_into().unwrap(),
+				country: CountryCode::try_from("ID".as_bytes().to_vec()).unwrap(),
+				region: RegionCode::try_from("JB".as_bytes().to_vec()).unwrap(),
+				city: CityCode::try_from("CITY".as_bytes().to_vec()).unwrap(),
+				address: "DeBio Address".as_bytes().to_vec().try_into().unwrap(),
+				phone_number: "+6281394653625".as_bytes().to_vec().try_into().unwrap(),
+				website: "DeBio Website".as_bytes().to_vec().try_into().unwrap(),
+				latitude: Some("-6.175392".as_bytes().to_vec().try_into().unwrap()),
+				longitude: Some("106.827153".as_bytes().to_vec().try_into().unwrap()),
+				profile_image: Some(
+					"DeBio Profile Image uwu".as_bytes().to_vec().try_into().unwrap()
+				),
 			}
 		));
 
@@ -586,22 +720,38 @@ fn fulfill_order_works() {
 		let prices_by_currency_dbio = PriceByCurrency {
 			currency: CurrencyType::DBIO,
 			total_price: 30,
-			price_components: vec![Price { component: b"testing_price".to_vec(), value: 20 }],
-			additional_prices: vec![Price { component: b"qc_price".to_vec(), value: 20 }],
+			price_components: vec![Price {
+				component: b"testing_price".to_vec().try_into().unwrap(),
+				value: 20,
+			}]
+			.try_into()
+			.unwrap(),
+			additional_prices: vec![Price {
+				component: b"qc_price".to_vec().try_into().unwrap(),
+				value: 20,
+			}]
+			.try_into()
+			.unwrap(),
 		};
 
 		assert_ok!(Services::create_service(
 			RuntimeOrigin::signed(lab),
 			ServiceInfo {
-				name: "DeBio name".as_bytes().to_vec(),
-				prices_by_currency: vec![prices_by_currency_dbio.clone()],
+				name: "DeBio name".as_bytes().to_vec().try_into().unwrap(),
+				prices_by_currency: vec![prices_by_currency_dbio.clone()].try_into().unwrap(),
 				expected_duration: ExpectedDuration::default(),
-				category: "DeBio category".as_bytes().to_vec(),
-				description: "This is my description".as_bytes().to_vec(),
-				test_result_sample: "Test result sample".as_bytes().to_vec(),
-				dna_collection_process: "Dna Collection Process".as_bytes().to_vec(),
-				long_description: Some("This is my long description".as_bytes().to_vec()),
-				image: Some("This is my image".as_bytes().to_vec()),
+				category: "DeBio category".as_bytes().to_vec().try_into().unwrap(),
+				description: "This is my description".as_bytes().to_vec().try_into().unwrap(),
+				test_result_sample: "Test result sample".as_bytes().to_vec().try_into().unwrap(),
+				dna_collection_process: "Dna Collection Process"
+					.as_bytes()
+					.to_vec()
+					.try_into()
+					.unwrap(),
+				long_description: Some(
+					"This is my long description".as_bytes().to_vec().try_into().unwrap()
+				),
+				image: Some("This is my image".as_bytes().to_vec().try_into().unwrap()),
 			},
 			ServiceFlow::default()
 		));
@@ -618,7 +768,7 @@ fn fulfill_order_works() {
 		));
 
 		let _order_id = Orders::last_order_by_customer_id(customer).unwrap();
-		let _dna_sample = GeneticTesting::dna_samples_by_lab_id(lab).unwrap();
+		let _dna_sample = GeneticTesting::dna_samples_by_lab_id(&lab);
 
 		assert_ok!(Orders::set_order_paid(RuntimeOrigin::signed(customer), _order_id));
 
@@ -626,9 +776,9 @@ fn fulfill_order_works() {
 			RuntimeOrigin::signed(lab),
 			_dna_sample[0].clone(),
 			DnaTestResultSubmission {
-				comments: Some("comment".as_bytes().to_vec()),
-				result_link: Some("result_link".as_bytes().to_vec()),
-				report_link: Some("report_link".as_bytes().to_vec()),
+				comments: Some("comment".as_bytes().to_vec().try_into().unwrap()),
+				result_link: Some("result_link".as_bytes().to_vec().try_into().unwrap()),
+				report_link: Some("report_link".as_bytes().to_vec().try_into().unwrap()),
 			}
 		));
 
@@ -655,9 +805,14 @@ fn fulfill_order_works() {
 				dna_sample_tracking_id: _dna_sample[0].clone(),
 				asset_id: None,
 				total_price: 40,
+				paid_amount: 40,
 				currency: CurrencyType::default(),
-				prices: prices_by_currency_dbio.price_components,
-				additional_prices: prices_by_currency_dbio.additional_prices,
+				prices: prices_by_currency_dbio.price_components.to_vec().try_into().unwrap(),
+				additional_prices: prices_by_currency_dbio
+					.additional_prices
+					.to_vec()
+					.try_into()
+					.unwrap(),
 				status: OrderStatus::Fulfilled,
 				order_flow: ServiceFlow::StakingRequestService,
 				created_at: 0,
@@ -672,6 +827,214 @@ fn fulfill_order_works() {
 	})
 }
 
+#[test]
+fn fulfill_order_with_fee_schedule_works() {
+	<ExternalityBuilder>::default().existential_deposit(1).build().execute_with(|| {
+		let lab = account_key("lab");
+		let admin = account_key("admin");
+		let customer = account_key("customer");
+		let pallet_id = account_key("pallet_id");
+		let treasury_key = account_key("treasury_key");
+
+		PalletAccount::<Test>::put(pallet_id);
+		TreasuryKey::<Test>::put(treasury_key);
+
+		assert_ok!(Labs::register_lab(
+			RuntimeOrigin::signed(lab),
+			LabInfo {
+				box_public_key: Keccak256::hash(
+					"0xDb9Af2d1f3ADD2726A132AA7A65Cc9E6fC5761C3".as_bytes()
+				),
+				name: "DeBio Lab".as_bytes().to_vec().try_into().unwrap(),
+				email: "DeBio Email".as_bytes().to_vec().try_into().unwrap(),
+				country: CountryCode::try_from("ID".as_bytes().to_vec()).unwrap(),
+				region: RegionCode::try_from("JB".as_bytes().to_vec()).unwrap(),
+				city: CityCode::try_from("CITY".as_bytes().to_vec()).unwrap(),
+				address: "DeBio Address".as_bytes().to_vec().try_into().unwrap(),
+				phone_number: "+6281394653625".as_bytes().to_vec().try_into().unwrap(),
+				website: "DeBio Website".as_bytes().to_vec().try_into().unwrap(),
+				latitude: Some("-6.175392".as_bytes().to_vec().try_into().unwrap()),
+				longitude: Some("106.827153".as_bytes().to_vec().try_into().unwrap()),
+				profile_image: Some(
+					"DeBio Profile Image uwu".as_bytes().to_vec().try_into().unwrap()
+				),
+			}
+		));
+
+		assert_ok!(UserProfile::set_eth_address(
+			RuntimeOrigin::signed(lab),
+			EthereumAddress([b'X'; 20])
+		));
+
+		let prices_by_currency_dbio = PriceByCurrency {
+			currency: CurrencyType::DBIO,
+			total_price: 30,
+			price_components: vec![Price {
+				component: b"testing_price".to_vec().try_into().unwrap(),
+				value: 20,
+			}]
+			.try_into()
+			.unwrap(),
+			additional_prices: vec![Price {
+				component: b"qc_price".to_vec().try_into().unwrap(),
+				value: 20,
+			}]
+			.try_into()
+			.unwrap(),
+		};
+
+		assert_ok!(Services::create_service(
+			RuntimeOrigin::signed(lab),
+			ServiceInfo {
+				name: "DeBio name".as_bytes().to_vec().try_into().unwrap(),
+				prices_by_currency: vec![prices_by_currency_dbio.clone()].try_into().unwrap(),
+				expected_duration: ExpectedDuration::default(),
+				category: "DeBio category".as_bytes().to_vec().try_into().unwrap(),
+				description: "This is my description".as_bytes().to_vec().try_into().unwrap(),
+				test_result_sample: "Test result sample".as_bytes().to_vec().try_into().unwrap(),
+				dna_collection_process: "Dna Collection Process"
+					.as_bytes()
+					.to_vec()
+					.try_into()
+					.unwrap(),
+				long_description: Some(
+					"This is my long description".as_bytes().to_vec().try_into().unwrap()
+				),
+				image: Some("This is my image".as_bytes().to_vec().try_into().unwrap()),
+			},
+			ServiceFlow::default()
+		));
+
+		let _lab = Labs::lab_by_account_id(lab).unwrap();
+
+		assert_ok!(Orders::create_order(
+			RuntimeOrigin::signed(customer),
+			_lab.services[0],
+			0,
+			Keccak256::hash("0xhJ7TRe456FADD2726A132ABJK5RCc9E6fC5869F4".as_bytes()),
+			ServiceFlow::StakingRequestService,
+			None,
+		));
+
+		let _order_id = Orders::last_order_by_customer_id(customer).unwrap();
+		let _dna_sample = GeneticTesting::dna_samples_by_lab_id(&lab);
+
+		assert_ok!(Orders::set_order_paid(RuntimeOrigin::signed(customer), _order_id));
+
+		assert_ok!(GeneticTesting::submit_test_result(
+			RuntimeOrigin::signed(lab),
+			_dna_sample[0].clone(),
+			DnaTestResultSubmission {
+				comments: Some("comment".as_bytes().to_vec().try_into().unwrap()),
+				result_link: Some("result_link".as_bytes().to_vec().try_into().unwrap()),
+				report_link: Some("report_link".as_bytes().to_vec().try_into().unwrap()),
+			}
+		));
+
+		assert_ok!(GeneticTesting::process_dna_sample(
+			RuntimeOrigin::signed(lab),
+			_dna_sample[0].clone(),
+			DnaSampleStatus::ResultReady,
+		));
+
+		EscrowKey::<Test>::put(admin);
+
+		assert_ok!(Orders::sudo_update_fee_schedule(
+			RuntimeOrigin::root(),
+			FeeSchedule {
+				default_rate: FeeRate { percentage: Perbill::from_percent(10), flat_fee: Some(2) },
+				currency_rates: vec![CurrencyFeeRate {
+					currency: CurrencyType::USDT,
+					rate: FeeRate { percentage: Perbill::from_percent(1), flat_fee: None },
+				}]
+				.try_into()
+				.unwrap(),
+				beneficiaries: vec![FeeBeneficiary {
+					account_id: admin,
+					share: Perbill::from_percent(50)
+				}]
+				.try_into()
+				.unwrap(),
+			}
+		));
+
+		assert_ok!(Orders::fulfill_order(RuntimeOrigin::signed(lab), _order_id));
+
+		System::assert_last_event(RuntimeEvent::Orders(crate::Event::OrderFulfilled(
+			Orders::order_by_id(&_order_id).unwrap(),
+			FeeSplit {
+				seller_amount: 34,
+				treasury_amount: 3,
+				beneficiary_amounts: vec![(admin, 3)],
+			},
+		)));
+
+		assert_eq!(
+			Orders::order_by_id(&_order_id),
+			Some(Order {
+				id: _order_id,
+				service_id: _lab.services[0],
+				customer_id: customer,
+				customer_box_public_key: Keccak256::hash(
+					"0xhJ7TRe456FADD2726A132ABJK5RCc9E6fC5869F4".as_bytes()
+				),
+				seller_id: lab,
+				dna_sample_tracking_id: _dna_sample[0].clone(),
+				asset_id: None,
+				total_price: 40,
+				paid_amount: 40,
+				currency: CurrencyType::default(),
+				prices: prices_by_currency_dbio.price_components.to_vec().try_into().unwrap(),
+				additional_prices: prices_by_currency_dbio
+					.additional_prices
+					.to_vec()
+					.try_into()
+					.unwrap(),
+				status: OrderStatus::Fulfilled,
+				order_flow: ServiceFlow::StakingRequestService,
+				created_at: 0,
+				updated_at: 0
+			})
+		);
+
+		assert_eq!(Balances::free_balance(customer), 160);
+		assert_eq!(Balances::free_balance(lab), 334);
+		assert_eq!(Balances::free_balance(admin), 103);
+		assert_eq!(Balances::free_balance(pallet_id), 1);
+		assert_eq!(Balances::free_balance(treasury_key), 403);
+	})
+}
+
+#[test]
+fn cant_update_fee_schedule_when_beneficiaries_exceed_fee() {
+	<ExternalityBuilder>::default().existential_deposit(1).build().execute_with(|| {
+		assert_noop!(
+			Orders::sudo_update_fee_schedule(
+				RuntimeOrigin::root(),
+				FeeSchedule {
+					default_rate: FeeRate { percentage: Perbill::from_percent(5), flat_fee: None },
+					currency_rates: Default::default(),
+					beneficiaries: vec![
+						FeeBeneficiary {
+							account_id: account_key("admin"),
+							share: Perbill::from_percent(60)
+						},
+						FeeBeneficiary {
+							account_id: account_key("other"),
+							share: Perbill::from_percent(60)
+						},
+					]
+					.try_into()
+					.unwrap(),
+				}
+			),
+			Error::<Test>::InvalidFeeSchedule
+		);
+
+		assert_eq!(Orders::fee_schedule(), FeeSchedule::default());
+	})
+}
+
 #[test]
 fn set_order_refunded_works() {
 	<ExternalityBuilder>::default().existential_deposit(1).build().execute_with(|| {
@@ -689,17 +1052,19 @@ fn set_order_refunded_works() {
 				box_public_key: Keccak256::hash(
 					"0xDb9Af2d1f3ADD2726A132AA7A65Cc9E6fC5761C3".as_bytes()
 				),
-				name: "DeBio Lab".as_bytes().to_vec(),
-				email: "DeBio Email".as_bytes().to_vec(),
-				country: CountryCode::from_vec("DC".as_bytes().to_vec()),
-				region: RegionCode::from_vec("DB".as_bytes().to_vec()),
-				city: CityCode::from_vec("CITY".as_bytes().to_vec()),
-				address: "DeBio Address".as_bytes().to_vec(),
-				phone_number: "+6281394653625".as_bytes().to_vec(),
-				website: "DeBio Website".as_bytes().to_vec(),
-				latitude: Some("DeBio Latitude".as_bytes().to_vec()),
-				longitude: Some("DeBio Longtitude".as_bytes().to_vec()),
-				profile_image: Some("DeBio Profile Image uwu".as_bytes().to_vec()),
+				name: "DeBio Lab".as_bytes().to_vec().try_into().unwrap(),
+				email: "DeBio Email".as_bytes().to_vec().try_into().unwrap(),
+				country: CountryCode::try_from("ID".as_bytes().to_vec()).unwrap(),
+				region: RegionCode::try_from("JB".as_bytes().to_vec()).unwrap(),
+				city: CityCode::try_from("CITY".as_bytes().to_vec()).unwrap(),
+				address: "DeBio Address".as_bytes().to_vec().try_into().unwrap(),
+				phone_number: "+6281394653625".as_bytes().to_vec().try_into().unwrap(),
+				website: "DeBio Website".as_bytes().to_vec().try_into().unwrap(),
+				latitude: Some("-6.175392".as_bytes().to_vec().try_into().unwrap()),
+				longitude: Some("106.827153".as_bytes().to_vec().try_into().unwrap()),
+				profile_image: Some(
+					"DeBio Profile Image uwu".as_bytes().to_vec().try_into().unwrap()
+				),
 			}
 		));
 
@@ -711,22 +1076,38 @@ fn set_order_refunded_works() {
 		let prices_by_currency_dbio = PriceByCurrency {
 			currency: CurrencyType::DBIO,
 			total_price: 10,
-			price_components: vec![Price { component: b"testing_price".to_vec(), value: 5 }],
-			additional_prices: vec![Price { component: b"qc_price".to_vec(), value: 5 }],
+			price_components: vec![Price {
+				component: b"testing_price".to_vec().try_into().unwrap(),
+				value: 5,
+			}]
+			.try_into()
+			.unwrap(),
+			additional_prices: vec![Price {
+				component: b"qc_price".to_vec().try_into().unwrap(),
+				value: 5,
+			}]
+			.try_into()
+			.unwrap(),
 		};
 
 		assert_ok!(Services::create_service(
 			RuntimeOrigin::signed(lab),
 			ServiceInfo {
-				name: "DeBio name".as_bytes().to_vec(),
-				prices_by_currency: vec![prices_by_currency_dbio.clone()],
+				name: "DeBio name".as_bytes().to_vec().try_into().unwrap(),
+				prices_by_currency: vec![prices_by_currency_dbio.clone()].try_into().unwrap(),
 				expected_duration: ExpectedDuration::default(),
-				category: "DeBio category".as_bytes().to_vec(),
-				description: "This is my description".as_bytes().to_vec(),
-				test_result_sample: "Test result sample".as_bytes().to_vec(),
-				dna_collection_process: "Dna Collection Process".as_bytes().to_vec(),
-				long_description: Some("This is my long description".as_bytes().to_vec()),
-				image: Some("This is my image".as_bytes().to_vec()),
+				category: "DeBio category".as_bytes().to_vec().try_into().unwrap(),
+				description: "This is my description".as_bytes().to_vec().try_into().unwrap(),
+				test_result_sample: "Test result sample".as_bytes().to_vec().try_into().unwrap(),
+				dna_collection_process: "Dna Collection Process"
+					.as_bytes()
+					.to_vec()
+					.try_into()
+					.unwrap(),
+				long_description: Some(
+					"This is my long description".as_bytes().to_vec().try_into().unwrap()
+				),
+				image: Some("This is my image".as_bytes().to_vec().try_into().unwrap()),
 			},
 			ServiceFlow::default()
 		));
@@ -743,7 +1124,7 @@ fn set_order_refunded_works() {
 		));
 
 		let _order_id = Orders::last_order_by_customer_id(customer).unwrap();
-		let _dna_sample = GeneticTesting::dna_samples_by_lab_id(lab).unwrap();
+		let _dna_sample = GeneticTesting::dna_samples_by_lab_id(&lab);
 
 		assert_ok!(Orders::set_order_paid(RuntimeOrigin::signed(customer), _order_id));
 
@@ -751,9 +1132,9 @@ fn set_order_refunded_works() {
 			RuntimeOrigin::signed(lab),
 			_dna_sample[0].clone(),
 			DnaTestResultSubmission {
-				comments: Some("comment".as_bytes().to_vec()),
-				result_link: Some("result_link".as_bytes().to_vec()),
-				report_link: Some("report_link".as_bytes().to_vec()),
+				comments: Some("comment".as_bytes().to_vec().try_into().unwrap()),
+				result_link: Some("result_link".as_bytes().to_vec().try_into().unwrap()),
+				report_link: Some("report_link".as_bytes().to_vec().try_into().unwrap()),
 			}
 		));
 
@@ -778,9 +1159,14 @@ fn set_order_refunded_works() {
 				dna_sample_tracking_id: _dna_sample[0].clone(),
 				asset_id: None,
 				total_price: 10,
+				paid_amount: 10,
 				currency: CurrencyType::default(),
-				prices: prices_by_currency_dbio.price_components,
-				additional_prices: prices_by_currency_dbio.additional_prices,
+				prices: prices_by_currency_dbio.price_components.to_vec().try_into().unwrap(),
+				additional_prices: prices_by_currency_dbio
+					.additional_prices
+					.to_vec()
+					.try_into()
+					.unwrap(),
 				status: OrderStatus::Refunded,
 				order_flow: ServiceFlow::StakingRequestService,
 				created_at: 0,
@@ -825,17 +1211,19 @@ fn cant_create_order_when_price_index_not_found() {
 				box_public_key: Keccak256::hash(
 					"0xDb9Af2d1f3ADD2726A132AA7A65Cc9E6fC5761C3".as_bytes()
 				),
-				name: "DeBio Lab".as_bytes().to_vec(),
-				email: "DeBio Email".as_bytes().to_vec(),
-				country: CountryCode::from_vec("DC".as_bytes().to_vec()),
-				region: RegionCode::from_vec("DB".as_bytes().to_vec()),
-				city: CityCode::from_vec("CITY".as_bytes().to_vec()),
-				address: "DeBio Address".as_bytes().to_vec(),
-				phone_number: "+6281394653625".as_bytes().to_vec(),
-				website: "DeBio Website".as_bytes().to_vec(),
-				latitude: Some("DeBio Latitude".as_bytes().to_vec()),
-				longitude: Some("DeBio Longtitude".as_bytes().to_vec()),
-				profile_image: Some("DeBio Profile Image uwu".as_bytes().to_vec()),
+				name: "DeBio Lab".as_bytes().to_vec().try_into().unwrap(),
+				email: "DeBio Email".as_bytes().to_vec().try_into().unwrap(),
+				country: CountryCode::try_from("ID".as_bytes().to_vec()).unwrap(),
+				region: RegionCode::try_from("JB".as_bytes().to_vec()).unwrap(),
+				city: CityCode::try_from("CITY".as_bytes().to_vec()).unwrap(),
+				address: "DeBio Address".as_bytes().to_vec().try_into().unwrap(),
+				phone_number: "+6281394653625".as_bytes().to_vec().try_into().unwrap(),
+				website: "DeBio Website".as_bytes().to_vec().try_into().unwrap(),
+				latitude: Some("-6.175392".as_bytes().to_vec().try_into().unwrap()),
+				longitude: Some("106.827153".as_bytes().to_vec().try_into().unwrap()),
+				profile_image: Some(
+					"DeBio Profile Image uwu".as_bytes().to_vec().try_into().unwrap()
+				),
 			}
 		));
 
@@ -847,22 +1235,38 @@ fn cant_create_order_when_price_index_not_found() {
 		let prices_by_currency_dbio = PriceByCurrency {
 			currency: CurrencyType::DBIO,
 			total_price: 10,
-			price_components: vec![Price { component: b"testing_price".to_vec(), value: 5 }],
-			additional_prices: vec![Price { component: b"qc_price".to_vec(), value: 5 }],
+			price_components: vec![Price {
+				component: b"testing_price".to_vec().try_into().unwrap(),
+				value: 5,
+			}]
+			.try_into()
+			.unwrap(),
+			additional_prices: vec![Price {
+				component: b"qc_price".to_vec().try_into().unwrap(),
+				value: 5,
+			}]
+			.try_into()
+			.unwrap(),
 		};
 
 		assert_ok!(Services::create_service(
 			RuntimeOrigin::signed(lab),
 			ServiceInfo {
-				name: "DeBio name".as_bytes().to_vec(),
-				prices_by_currency: vec![prices_by_currency_dbio],
+				name: "DeBio name".as_bytes().to_vec().try_into().unwrap(),
+				prices_by_currency: vec![prices_by_currency_dbio].try_into().unwrap(),
 				expected_duration: ExpectedDuration::default(),
-				category: "DeBio category".as_bytes().to_vec(),
-				description: "This is my description".as_bytes().to_vec(),
-				test_result_sample: "Test result sample".as_bytes().to_vec(),
-				dna_collection_process: "Dna Collection Process".as_bytes().to_vec(),
-				long_description: Some("This is my long description".as_bytes().to_vec()),
-				image: Some("This is my image".as_bytes().to_vec()),
+				category: "DeBio category".as_bytes().to_vec().try_into().unwrap(),
+				description: "This is my description".as_bytes().to_vec().try_into().unwrap(),
+				test_result_sample: "Test result sample".as_bytes().to_vec().try_into().unwrap(),
+				dna_collection_process: "Dna Collection Process"
+					.as_bytes()
+					.to_vec()
+					.try_into()
+					.unwrap(),
+				long_description: Some(
+					"This is my long description".as_bytes().to_vec().try_into().unwrap()
+				),
+				image: Some("This is my image".as_bytes().to_vec().try_into().unwrap()),
 			},
 			ServiceFlow::default()
 		));
@@ -895,17 +1299,19 @@ fn cant_create_order_when_asset_id_not_found() {
 				box_public_key: Keccak256::hash(
 					"0xDb9Af2d1f3ADD2726A132AA7A65Cc9E6fC5761C3".as_bytes()
 				),
-				name: "DeBio Lab".as_bytes().to_vec(),
-				email: "DeBio Email".as_bytes().to_vec(),
-				country: CountryCode::from_vec("DC".as_bytes().to_vec()),
-				region: RegionCode::from_vec("DB".as_bytes().to_vec()),
-				city: CityCode::from_vec("CITY".as_bytes().to_vec()),
-				address: "DeBio Address".as_bytes().to_vec(),
-				phone_number: "+6281394653625".as_bytes().to_vec(),
-				website: "DeBio Website".as_bytes().to_vec(),
-				latitude: Some("DeBio Latitude".as_bytes().to_vec()),
-				longitude: Some("DeBio Longtitude".as_bytes().to_vec()),
-				profile_image: Some("DeBio Profile Image uwu".as_bytes().to_vec()),
+				name: "DeBio Lab".as_bytes().to_vec().try_into().unwrap(),
+				email: "DeBio Email".as_bytes().to_vec().try_into().unwrap(),
+				country: CountryCode::try_from("ID".as_bytes().to_vec()).unwrap(),
+				region: RegionCode::try_from("JB".as_bytes().to_vec()).unwrap(),
+				city: CityCode::try_from("CITY".as_bytes().to_vec()).unwrap(),
+				address: "DeBio Address".as_bytes().to_vec().try_into().unwrap(),
+				phone_number: "+6281394653625".as_bytes().to_vec().try_into().unwrap(),
+				website: "DeBio Website".as_bytes().to_vec().try_into().unwrap(),
+				latitude: Some("-6.175392".as_bytes().to_vec().try_into().unwrap()),
+				longitude: Some("106.827153".as_bytes().to_vec().try_into().unwrap()),
+				profile_image: Some(
+					"DeBio Profile Image uwu".as_bytes().to_vec().try_into().unwrap()
+				),
 			}
 		));
 
@@ -917,22 +1323,38 @@ fn cant_create_order_when_asset_id_not_found() {
 		let prices_by_currency_usdt = PriceByCurrency {
 			currency: CurrencyType::USDT,
 			total_price: 10,
-			price_components: vec![Price { component: b"testing_price".to_vec(), value: 5 }],
-			additional_prices: vec![Price { component: b"qc_price".to_vec(), value: 5 }],
+			price_components: vec![Price {
+				component: b"testing_price".to_vec().try_into().unwrap(),
+				value: 5,
+			}]
+			.try_into()
+			.unwrap(),
+			additional_prices: vec![Price {
+				component: b"qc_price".to_vec().try_into().unwrap(),
+				value: 5,
+			}]
+			.try_into()
+			.unwrap(),
 		};
 
 		assert_ok!(Services::create_service(
 			RuntimeOrigin::signed(lab),
 			ServiceInfo {
-				name: "DeBio name".as_bytes().to_vec(),
-				prices_by_currency: vec![prices_by_currency_usdt],
+				name: "DeBio name".as_bytes().to_vec().try_into().unwrap(),
+				prices_by_currency: vec![prices_by_currency_usdt].try_into().unwrap(),
 				expected_duration: ExpectedDuration::default(),
-				category: "DeBio category".as_bytes().to_vec(),
-				description: "This is my description".as_bytes().to_vec(),
-				test_result_sample: "Test result sample".as_bytes().to_vec(),
-				dna_collection_process: "Dna Collection Process".as_bytes().to_vec(),
-				long_description: Some("This is my long description".as_bytes().to_vec()),
-				image: Some("This is my image".as_bytes().to_vec()),
+				category: "DeBio category".as_bytes().to_vec().try_into().unwrap(),
+				description: "This is my description".as_bytes().to_vec().try_into().unwrap(),
+				test_result_sample: "Test result sample".as_bytes().to_vec().try_into().unwrap(),
+				dna_collection_process: "Dna Collection Process"
+					.as_bytes()
+					.to_vec()
+					.try_into()
+					.unwrap(),
+				long_description: Some(
+					"This is my long description".as_bytes().to_vec().try_into().unwrap()
+				),
+				image: Some("This is my image".as_bytes().to_vec().try_into().unwrap()),
 			},
 			ServiceFlow::default()
 		));
@@ -980,17 +1402,19 @@ fn cant_cancel_order_when_order_ongoing() {
 				box_public_key: Keccak256::hash(
 					"0xDb9Af2d1f3ADD2726A132AA7A65Cc9E6fC5761C3".as_bytes()
 				),
-				name: "DeBio Lab".as_bytes().to_vec(),
-				email: "DeBio Email".as_bytes().to_vec(),
-				country: CountryCode::from_vec("DC".as_bytes().to_vec()),
-				region: RegionCode::from_vec("DB".as_bytes().to_vec()),
-				city: CityCode::from_vec("CITY".as_bytes().to_vec()),
-				address: "DeBio Address".as_bytes().to_vec(),
-				phone_number: "+6281394653625".as_bytes().to_vec(),
-				website: "DeBio Website".as_bytes().to_vec(),
-				latitude: Some("DeBio Latitude".as_bytes().to_vec()),
-				longitude: Some("DeBio Longtitude".as_bytes().to_vec()),
-				profile_image: Some("DeBio Profile Image uwu".as_bytes().to_vec()),
+				name: "DeBio Lab".as_bytes().to_vec().try_into().unwrap(),
+				email: "DeBio Email".as_bytes().to_vec().try_into().unwrap(),
+				country: CountryCode::try_from("ID".as_bytes().to_vec()).unwrap(),
+				region: RegionCode::try_from("JB".as_bytes().to_vec()).unwrap(),
+				city: CityCode::try_from("CITY".as_bytes().to_vec()).unwrap(),
+				address: "DeBio Address".as_bytes().to_vec().try_into().unwrap(),
+				phone_number: "+6281394653625".as_bytes().to_vec().try_into().unwrap(),
+				website: "DeBio Website".as_bytes().to_vec().try_into().unwrap(),
+				latitude: Some("-6.175392".as_bytes().to_vec().try_into().unwrap()),
+				longitude: Some("106.827153".as_bytes().to_vec().try_into().unwrap()),
+				profile_image: Some(
+					"DeBio Profile Image uwu".as_bytes().to_vec().try_into().unwrap()
+				),
 			}
 		));
 
@@ -1002,22 +1426,38 @@ fn cant_cancel_order_when_order_ongoing() {
 		let prices_by_currency_dbio = PriceByCurrency {
 			currency: CurrencyType::DBIO,
 			total_price: 10,
-			price_components: vec![Price { component: b"testing_price".to_vec(), value: 5 }],
-			additional_prices: vec![Price { component: b"qc_price".to_vec(), value: 5 }],
+			price_components: vec![Price {
+				component: b"testing_price".to_vec().try_into().unwrap(),
+				value: 5,
+			}]
+			.try_into()
+			.unwrap(),
+			additional_prices: vec![Price {
+				component: b"qc_price".to_vec().try_into().unwrap(),
+				value: 5,
+			}]
+			.try_into()
+			.unwrap(),
 		};
 
 		assert_ok!(Services::create_service(
 			RuntimeOrigin::signed(lab),
 			ServiceInfo {
-				name: "DeBio name".as_bytes().to_vec(),
-				prices_by_currency: vec![prices_by_currency_dbio],
+				name: "DeBio name".as_bytes().to_vec().try_into().unwrap(),
+				prices_by_currency: vec![prices_by_currency_dbio].try_into().unwrap(),
 				expected_duration: ExpectedDuration::default(),
-				category: "DeBio category".as_bytes().to_vec(),
-				description: "This is my description".as_bytes().to_vec(),
-				test_result_sample: "Test result sample".as_bytes().to_vec(),
-				dna_collection_process: "Dna Collection Process".as_bytes().to_vec(),
-				long_description: Some("This is my long description".as_bytes().to_vec()),
-				image: Some("This is my image".as_bytes().to_vec()),
+				category: "DeBio category".as_bytes().to_vec().try_into().unwrap(),
+				description: "This is my description".as_bytes().to_vec().try_into().unwrap(),
+				test_result_sample: "Test result sample".as_bytes().to_vec().try_into().unwrap(),
+				dna_collection_process: "Dna Collection Process"
+					.as_bytes()
+					.to_vec()
+					.try_into()
+					.unwrap(),
+				long_description: Some(
+					"This is my long description".as_bytes().to_vec().try_into().unwrap()
+				),
+				image: Some("This is my image".as_bytes().to_vec().try_into().unwrap()),
 			},
 			ServiceFlow::default()
 		));
@@ -1034,7 +1474,7 @@ fn cant_cancel_order_when_order_ongoing() {
 		));
 
 		let _order_id = Orders::last_order_by_customer_id(customer).unwrap();
-		let _dna_sample = GeneticTesting::dna_samples_by_lab_id(lab).unwrap();
+		let _dna_sample = GeneticTesting::dna_samples_by_lab_id(&lab);
 
 		assert_ok!(Orders::set_order_paid(RuntimeOrigin::signed(customer), _order_id));
 		assert_ok!(GeneticTesting::process_dna_sample(
@@ -1078,17 +1518,19 @@ fn cant_cancel_order_when_unathorized_user() {
 				box_public_key: Keccak256::hash(
 					"0xDb9Af2d1f3ADD2726A132AA7A65Cc9E6fC5761C3".as_bytes()
 				),
-				name: "DeBio Lab".as_bytes().to_vec(),
-				email: "DeBio Email".as_bytes().to_vec(),
-				country: CountryCode::from_vec("DC".as_bytes().to_vec()),
-				region: RegionCode::from_vec("DB".as_bytes().to_vec()),
-				city: CityCode::from_vec("CITY".as_bytes().to_vec()),
-				address: "DeBio Address".as_bytes().to_vec(),
-				phone_number: "+6281394653625".as_bytes().to_vec(),
-				website: "DeBio Website".as_bytes().to_vec(),
-				latitude: Some("DeBio Latitude".as_bytes().to_vec()),
-				longitude: Some("DeBio Longtitude".as_bytes().to_vec()),
-				profile_image: Some("DeBio Profile Image uwu".as_bytes().to_vec()),
+				name: "DeBio Lab".as_bytes().to_vec().try_into().unwrap(),
+				email: "DeBio Email".as_bytes().to_vec().try_into().unwrap(),
+				country: CountryCode::try_from("ID".as_bytes().to_vec()).unwrap(),
+				region: RegionCode::try_from("JB".as_bytes().to_vec()).unwrap(),
+				city: CityCode::try_from("CITY".as_bytes().to_vec()).unwrap(),
+				address: "DeBio Address".as_bytes().to_vec().try_into().unwrap(),
+				phone_number: "+6281394653625".as_bytes().to_vec().try_into().unwrap(),
+				website: "DeBio Website".as_bytes().to_vec().try_into().unwrap(),
+				latitude: Some("-6.175392".as_bytes().to_vec().try_into().unwrap()),
+				longitude: Some("106.827153".as_bytes().to_vec().try_into().unwrap()),
+				profile_image: Some(
+					"DeBio Profile Image uwu".as_bytes().to_vec().try_into().unwrap()
+				),
 			}
 		));
 
@@ -1100,22 +1542,38 @@ fn cant_cancel_order_when_unathorized_user() {
 		let prices_by_currency_dbio = PriceByCurrency {
 			currency: CurrencyType::DBIO,
 			total_price: 10,
-			price_components: vec![Price { component: b"testing_price".to_vec(), value: 5 }],
-			additional_prices: vec![Price { component: b"qc_price".to_vec(), value: 5 }],
+			price_components: vec![Price {
+				component: b"testing_price".to_vec().try_into().unwrap(),
+				value: 5,
+			}]
+			.try_into()
+			.unwrap(),
+			additional_prices: vec![Price {
+				component: b"qc_price".to_vec().try_into().unwrap(),
+				value: 5,
+			}]
+			.try_into()
+			.unwrap(),
 		};
 
 		assert_ok!(Services::create_service(
 			RuntimeOrigin::signed(lab),
 			ServiceInfo {
-				name: "DeBio name".as_bytes().to_vec(),
-				prices_by_currency: vec![prices_by_currency_dbio],
+				name: "DeBio name".as_bytes().to_vec().try_into().unwrap(),
+				prices_by_currency: vec![prices_by_currency_dbio].try_into().unwrap(),
 				expected_duration: ExpectedDuration::default(),
-				category: "DeBio category".as_bytes().to_vec(),
-				description: "This is my description".as_bytes().to_vec(),
-				test_result_sample: "Test result sample".as_bytes().to_vec(),
-				dna_collection_process: "Dna Collection Process".as_bytes().to_vec(),
-				long_description: Some("This is my long description".as_bytes().to_vec()),
-				image: Some("This is my image".as_bytes().to_vec()),
+				category: "DeBio category".as_bytes().to_vec().try_into().unwrap(),
+				description: "This is my description".as_bytes().to_vec().try_into().unwrap(),
+				test_result_sample: "Test result sample".as_bytes().to_vec().try_into().unwrap(),
+				dna_collection_process: "Dna Collection Process"
+					.as_bytes()
+					.to_vec()
+					.try_into()
+					.unwrap(),
+				long_description: Some(
+					"This is my long description".as_bytes().to_vec().try_into().unwrap()
+				),
+				image: Some("This is my image".as_bytes().to_vec().try_into().unwrap()),
 			},
 			ServiceFlow::default()
 		));
@@ -1132,7 +1590,7 @@ fn cant_cancel_order_when_unathorized_user() {
 		));
 
 		let _order_id = Orders::last_order_by_customer_id(customer).unwrap();
-		let _dna_sample = GeneticTesting::dna_samples_by_lab_id(lab).unwrap();
+		let _dna_sample = GeneticTesting::dna_samples_by_lab_id(&lab);
 
 		assert_noop!(
 			Orders::cancel_order(RuntimeOrigin::signed(other_customer), _order_id),
@@ -1153,17 +1611,19 @@ fn cant_cancel_order_when_order_already_finished() {
 				box_public_key: Keccak256::hash(
 					"0xDb9Af2d1f3ADD2726A132AA7A65Cc9E6fC5761C3".as_bytes()
 				),
-				name: "DeBio Lab".as_bytes().to_vec(),
-				email: "DeBio Email".as_bytes().to_vec(),
-				country: CountryCode::from_vec("DC".as_bytes().to_vec()),
-				region: RegionCode::from_vec("DB".as_bytes().to_vec()),
-				city: CityCode::from_vec("CITY".as_bytes().to_vec()),
-				address: "DeBio Address".as_bytes().to_vec(),
-				phone_number: "+6281394653625".as_bytes().to_vec(),
-				website: "DeBio Website".as_bytes().to_vec(),
-				latitude: Some("DeBio Latitude".as_bytes().to_vec()),
-				longitude: Some("DeBio Longtitude".as_bytes().to_vec()),
-				profile_image: Some("DeBio Profile Image uwu".as_bytes().to_vec()),
+				name: "DeBio Lab".as_bytes().to_vec().try_into().unwrap(),
+				email: "DeBio Email".as_bytes().to_vec().try_into().unwrap(),
+				country: CountryCode::try_from("ID".as_bytes().to_vec()).unwrap(),
+				region: RegionCode::try_from("JB".as_bytes().to_vec()).unwrap(),
+				city: CityCode::try_from("CITY".as_bytes().to_vec()).unwrap(),
+				address: "DeBio Address".as_bytes().to_vec().try_into().unwrap(),
+				phone_number: "+6281394653625".as_bytes().to_vec().try_into().unwrap(),
+				website: "DeBio Website".as_bytes().to_vec().try_into().unwrap(),
+				latitude: Some("-6.175392".as_bytes().to_vec().try_into().unwrap()),
+				longitude: Some("106.827153".as_bytes().to_vec().try_into().unwrap()),
+				profile_image: Some(
+					"DeBio Profile Image uwu".as_bytes().to_vec().try_into().unwrap()
+				),
 			}
 		));
 
@@ -1175,22 +1635,38 @@ fn cant_cancel_order_when_order_already_finished() {
 		let prices_by_currency_dbio = PriceByCurrency {
 			currency: CurrencyType::DBIO,
 			total_price: 10,
-			price_components: vec![Price { component: b"testing_price".to_vec(), value: 5 }],
-			additional_prices: vec![Price { component: b"qc_price".to_vec(), value: 5 }],
+			price_components: vec![Price {
+				component: b"testing_price".to_vec().try_into().unwrap(),
+				value: 5,
+			}]
+			.try_into()
+			.unwrap(),
+			additional_prices: vec![Price {
+				component: b"qc_price".to_vec().try_into().unwrap(),
+				value: 5,
+			}]
+			.try_into()
+			.unwrap(),
 		};
 
 		assert_ok!(Services::create_service(
 			RuntimeOrigin::signed(lab),
 			ServiceInfo {
-				name: "DeBio name".as_bytes().to_vec(),
-				prices_by_currency: vec![prices_by_currency_dbio],
+				name: "DeBio name".as_bytes().to_vec().try_into().unwrap(),
+				prices_by_currency: vec![prices_by_currency_dbio].try_into().unwrap(),
 				expected_duration: ExpectedDuration::default(),
-				category: "DeBio category".as_bytes().to_vec(),
-				description: "This is my description".as_bytes().to_vec(),
-				test_result_sample: "Test result sample".as_bytes().to_vec(),
-				dna_collection_process: "Dna Collection Process".as_bytes().to_vec(),
-				long_description: Some("This is my long description".as_bytes().to_vec()),
-				image: Some("This is my image".as_bytes().to_vec()),
+				category: "DeBio category".as_bytes().to_vec().try_into().unwrap(),
+				description: "This is my description".as_bytes().to_vec().try_into().unwrap(),
+				test_result_sample: "Test result sample".as_bytes().to_vec().try_into().unwrap(),
+				dna_collection_process: "Dna Collection Process"
+					.as_bytes()
+					.to_vec()
+					.try_into()
+					.unwrap(),
+				long_description: Some(
+					"This is my long description".as_bytes().to_vec().try_into().unwrap()
+				),
+				image: Some("This is my image".as_bytes().to_vec().try_into().unwrap()),
 			},
 			ServiceFlow::default()
 		));
@@ -1207,7 +1683,7 @@ fn cant_cancel_order_when_order_already_finished() {
 		));
 
 		let _order_id = Orders::last_order_by_customer_id(customer).unwrap();
-		let _dna_sample = GeneticTesting::dna_samples_by_lab_id(lab).unwrap();
+		let _dna_sample = GeneticTesting::dna_samples_by_lab_id(&lab);
 
 		assert_ok!(Orders::cancel_order(RuntimeOrigin::signed(customer), _order_id));
 
@@ -1231,17 +1707,19 @@ fn cant_set_order_paid_when_unauthorized() {
 				box_public_key: Keccak256::hash(
 					"0xDb9Af2d1f3ADD2726A132AA7A65Cc9E6fC5761C3".as_bytes()
 				),
-				name: "DeBio Lab".as_bytes().to_vec(),
-				email: "DeBio Email".as_bytes().to_vec(),
-				country: CountryCode::from_vec("DC".as_bytes().to_vec()),
-				region: RegionCode::from_vec("DB".as_bytes().to_vec()),
-				city: CityCode::from_vec("CITY".as_bytes().to_vec()),
-				address: "DeBio Address".as_bytes().to_vec(),
-				phone_number: "+6281394653625".as_bytes().to_vec(),
-				website: "DeBio Website".as_bytes().to_vec(),
-				latitude: Some("DeBio Latitude".as_bytes().to_vec()),
-				longitude: Some("DeBio Longtitude".as_bytes().to_vec()),
-				profile_image: Some("DeBio Profile Image uwu".as_bytes().to_vec()),
+				name: "DeBio Lab".as_bytes().to_vec().try_into().unwrap(),
+				email: "DeBio Email".as_bytes().to_vec().try_into().unwrap(),
+				country: CountryCode::try_from("ID".as_bytes().to_vec()).unwrap(),
+				region: RegionCode::try_from("JB".as_bytes().to_vec()).unwrap(),
+				city: CityCode::try_from("CITY".as_bytes().to_vec()).unwrap(),
+				address: "DeBio Address".as_bytes().to_vec().try_into().unwrap(),
+				phone_number: "+6281394653625".as_bytes().to_vec().try_into().unwrap(),
+				website: "DeBio Website".as_bytes().to_vec().try_into().unwrap(),
+				latitude: Some("-6.175392".as_bytes().to_vec().try_into().unwrap()),
+				longitude: Some("106.827153".as_bytes().to_vec().try_into().unwrap()),
+				profile_image: Some(
+					"DeBio Profile Image uwu".as_bytes().to_vec().try_into().unwrap()
+				),
 			}
 		));
 
@@ -1253,22 +1731,38 @@ fn cant_set_order_paid_when_unauthorized() {
 		let prices_by_currency_dbio = PriceByCurrency {
 			currency: CurrencyType::DBIO,
 			total_price: 10,
-			price_components: vec![Price { component: b"testing_price".to_vec(), value: 5 }],
-			additional_prices: vec![Price { component: b"qc_price".to_vec(), value: 5 }],
+			price_components: vec![Price {
+				component: b"testing_price".to_vec().try_into().unwrap(),
+				value: 5,
+			}]
+			.try_into()
+			.unwrap(),
+			additional_prices: vec![Price {
+				component: b"qc_price".to_vec().try_into().unwrap(),
+				value: 5,
+			}]
+			.try_into()
+			.unwrap(),
 		};
 
 		assert_ok!(Services::create_service(
 			RuntimeOrigin::signed(lab),
 			ServiceInfo {
-				name: "DeBio name".as_bytes().to_vec(),
-				prices_by_currency: vec![prices_by_currency_dbio],
+				name: "DeBio name".as_bytes().to_vec().try_into().unwrap(),
+				prices_by_currency: vec![prices_by_currency_dbio].try_into().unwrap(),
 				expected_duration: ExpectedDuration::default(),
-				category: "DeBio category".as_bytes().to_vec(),
-				description: "This is my description".as_bytes().to_vec(),
-				test_result_sample: "Test result sample".as_bytes().to_vec(),
-				dna_collection_process: "Dna Collection Process".as_bytes().to_vec(),
-				long_description: Some("This is my long description".as_bytes().to_vec()),
-				image: Some("This is my image".as_bytes().to_vec()),
+				category: "DeBio category".as_bytes().to_vec().try_into().unwrap(),
+				description: "This is my description".as_bytes().to_vec().try_into().unwrap(),
+				test_result_sample: "Test result sample".as_bytes().to_vec().try_into().unwrap(),
+				dna_collection_process: "Dna Collection Process"
+					.as_bytes()
+					.to_vec()
+					.try_into()
+					.unwrap(),
+				long_description: Some(
+					"This is my long description".as_bytes().to_vec().try_into().unwrap()
+				),
+				image: Some("This is my image".as_bytes().to_vec().try_into().unwrap()),
 			},
 			ServiceFlow::default()
 		));
@@ -1321,17 +1815,19 @@ fn cant_set_order_paid_when_already_finished() {
 				box_public_key: Keccak256::hash(
 					"0xDb9Af2d1f3ADD2726A132AA7A65Cc9E6fC5761C3".as_bytes()
 				),
-				name: "DeBio Lab".as_bytes().to_vec(),
-				email: "DeBio Email".as_bytes().to_vec(),
-				country: CountryCode::from_vec("DC".as_bytes().to_vec()),
-				region: RegionCode::from_vec("DB".as_bytes().to_vec()),
-				city: CityCode::from_vec("CITY".as_bytes().to_vec()),
-				address: "DeBio Address".as_bytes().to_vec(),
-				phone_number: "+6281394653625".as_bytes().to_vec(),
-				website: "DeBio Website".as_bytes().to_vec(),
-				latitude: Some("DeBio Latitude".as_bytes().to_vec()),
-				longitude: Some("DeBio Longtitude".as_bytes().to_vec()),
-				profile_image: Some("DeBio Profile Image uwu".as_bytes().to_vec()),
+				name: "DeBio Lab".as_bytes().to_vec().try_into().unwrap(),
+				email: "DeBio Email".as_bytes().to_vec().try_into().unwrap(),
+				country: CountryCode::try_from("ID".as_bytes().to_vec()).unwrap(),
+				region: RegionCode::try_from("JB".as_bytes().to_vec()).unwrap(),
+				city: CityCode::try_from("CITY".as_bytes().to_vec()).unwrap(),
+				address: "DeBio Address".as_bytes().to_vec().try_into().unwrap(),
+				phone_number: "+6281394653625".as_bytes().to_vec().try_into().unwrap(),
+				website: "DeBio Website".as_bytes().to_vec().try_into().unwrap(),
+				latitude: Some("-6.175392".as_bytes().to_vec().try_into().unwrap()),
+				longitude: Some("106.827153".as_bytes().to_vec().try_into().unwrap()),
+				profile_image: Some(
+					"DeBio Profile Image uwu".as_bytes().to_vec().try_into().unwrap()
+				),
 			}
 		));
 
@@ -1343,22 +1839,38 @@ fn cant_set_order_paid_when_already_finished() {
 		let prices_by_currency_dbio = PriceByCurrency {
 			currency: CurrencyType::DBIO,
 			total_price: 10,
-			price_components: vec![Price { component: b"testing_price".to_vec(), value: 5 }],
-			additional_prices: vec![Price { component: b"qc_price".to_vec(), value: 5 }],
+			price_components: vec![Price {
+				component: b"testing_price".to_vec().try_into().unwrap(),
+				value: 5,
+			}]
+			.try_into()
+			.unwrap(),
+			additional_prices: vec![Price {
+				component: b"qc_price".to_vec().try_into().unwrap(),
+				value: 5,
+			}]
+			.try_into()
+			.unwrap(),
 		};
 
 		assert_ok!(Services::create_service(
 			RuntimeOrigin::signed(lab),
 			ServiceInfo {
-				name: "DeBio name".as_bytes().to_vec(),
-				prices_by_currency: vec![prices_by_currency_dbio],
+				name: "DeBio name".as_bytes().to_vec().try_into().unwrap(),
+				prices_by_currency: vec![prices_by_currency_dbio].try_into().unwrap(),
 				expected_duration: ExpectedDuration::default(),
-				category: "DeBio category".as_bytes().to_vec(),
-				description: "This is my description".as_bytes().to_vec(),
-				test_result_sample: "Test result sample".as_bytes().to_vec(),
-				dna_collection_process: "Dna Collection Process".as_bytes().to_vec(),
-				long_description: Some("This is my long description".as_bytes().to_vec()),
-				image: Some("This is my image".as_bytes().to_vec()),
+				category: "DeBio category".as_bytes().to_vec().try_into().unwrap(),
+				description: "This is my description".as_bytes().to_vec().try_into().unwrap(),
+				test_result_sample: "Test result sample".as_bytes().to_vec().try_into().unwrap(),
+				dna_collection_process: "Dna Collection Process"
+					.as_bytes()
+					.to_vec()
+					.try_into()
+					.unwrap(),
+				long_description: Some(
+					"This is my long description".as_bytes().to_vec().try_into().unwrap()
+				),
+				image: Some("This is my image".as_bytes().to_vec().try_into().unwrap()),
 			},
 			ServiceFlow::default()
 		));
@@ -1419,17 +1931,19 @@ fn cant_fulfill_order_when_unauthorized() {
 				box_public_key: Keccak256::hash(
 					"0xDb9Af2d1f3ADD2726A132AA7A65Cc9E6fC5761C3".as_bytes()
 				),
-				name: "DeBio Lab".as_bytes().to_vec(),
-				email: "DeBio Email".as_bytes().to_vec(),
-				country: CountryCode::from_vec("DC".as_bytes().to_vec()),
-				region: RegionCode::from_vec("DB".as_bytes().to_vec()),
-				city: CityCode::from_vec("CITY".as_bytes().to_vec()),
-				address: "DeBio Address".as_bytes().to_vec(),
-				phone_number: "+6281394653625".as_bytes().to_vec(),
-				website: "DeBio Website".as_bytes().to_vec(),
-				latitude: Some("DeBio Latitude".as_bytes().to_vec()),
-				longitude: Some("DeBio Longtitude".as_bytes().to_vec()),
-				profile_image: Some("DeBio Profile Image uwu".as_bytes().to_vec()),
+				name: "DeBio Lab".as_bytes().to_vec().try_into().unwrap(),
+				email: "DeBio Email".as_bytes().to_vec().try_into().unwrap(),
+				country: CountryCode::try_from("ID".as_bytes().to_vec()).unwrap(),
+				region: RegionCode::try_from("JB".as_bytes().to_vec()).unwrap(),
+				city: CityCode::try_from("CITY".as_bytes().to_vec()).unwrap(),
+				address: "DeBio Address".as_bytes().to_vec().try_into().unwrap(),
+				phone_number: "+6281394653625".as_bytes().to_vec().try_into().unwrap(),
+				website: "DeBio Website".as_bytes().to_vec().try_into().unwrap(),
+				latitude: Some("-6.175392".as_bytes().to_vec().try_into().unwrap()),
+				longitude: Some("106.827153".as_bytes().to_vec().try_into().unwrap()),
+				profile_image: Some(
+					"DeBio Profile Image uwu".as_bytes().to_vec().try_into().unwrap()
+				),
 			}
 		));
 
@@ -1441,22 +1955,38 @@ fn cant_fulfill_order_when_unauthorized() {
 		let prices_by_currency_dbio = PriceByCurrency {
 			currency: CurrencyType::DBIO,
 			total_price: 10,
-			price_components: vec![Price { component: b"testing_price".to_vec(), value: 5 }],
-			additional_prices: vec![Price { component: b"qc_price".to_vec(), value: 5 }],
+			price_components: vec![Price {
+				component: b"testing_price".to_vec().try_into().unwrap(),
+				value: 5,
+			}]
+			.try_into()
+			.unwrap(),
+			additional_prices: vec![Price {
+				component: b"qc_price".to_vec().try_into().unwrap(),
+				value: 5,
+			}]
+			.try_into()
+			.unwrap(),
 		};
 
 		assert_ok!(Services::create_service(
 			RuntimeOrigin::signed(lab),
 			ServiceInfo {
-				name: "DeBio name".as_bytes().to_vec(),
-				prices_by_currency: vec![prices_by_currency_dbio],
+				name: "DeBio name".as_bytes().to_vec().try_into().unwrap(),
+				prices_by_currency: vec![prices_by_currency_dbio].try_into().unwrap(),
 				expected_duration: ExpectedDuration::default(),
-				category: "DeBio category".as_bytes().to_vec(),
-				description: "This is my description".as_bytes().to_vec(),
-				test_result_sample: "Test result sample".as_bytes().to_vec(),
-				dna_collection_process: "Dna Collection Process".as_bytes().to_vec(),
-				long_description: Some("This is my long description".as_bytes().to_vec()),
-				image: Some("This is my image".as_bytes().to_vec()),
+				category: "DeBio category".as_bytes().to_vec().try_into().unwrap(),
+				description: "This is my description".as_bytes().to_vec().try_into().unwrap(),
+				test_result_sample: "Test result sample".as_bytes().to_vec().try_into().unwrap(),
+				dna_collection_process: "Dna Collection Process"
+					.as_bytes()
+					.to_vec()
+					.try_into()
+					.unwrap(),
+				long_description: Some(
+					"This is my long description".as_bytes().to_vec().try_into().unwrap()
+				),
+				image: Some("This is my image".as_bytes().to_vec().try_into().unwrap()),
 			},
 			ServiceFlow::default()
 		));
@@ -1473,7 +2003,7 @@ fn cant_fulfill_order_when_unauthorized() {
 		));
 
 		let _order_id = Orders::last_order_by_customer_id(customer).unwrap();
-		let _dna_sample = GeneticTesting::dna_samples_by_lab_id(lab).unwrap();
+		let _dna_sample = GeneticTesting::dna_samples_by_lab_id(&lab);
 
 		assert_ok!(Orders::set_order_paid(RuntimeOrigin::signed(customer), _order_id));
 
@@ -1481,9 +2011,9 @@ fn cant_fulfill_order_when_unauthorized() {
 			RuntimeOrigin::signed(lab),
 			_dna_sample[0].clone(),
 			DnaTestResultSubmission {
-				comments: Some("comment".as_bytes().to_vec()),
-				result_link: Some("result_link".as_bytes().to_vec()),
-				report_link: Some("report_link".as_bytes().to_vec()),
+				comments: Some("comment".as_bytes().to_vec().try_into().unwrap()),
+				result_link: Some("result_link".as_bytes().to_vec().try_into().unwrap()),
+				report_link: Some("report_link".as_bytes().to_vec().try_into().unwrap()),
 			}
 		));
 
@@ -1518,17 +2048,19 @@ fn cant_fulfill_order_when_dna_sample_not_process() {
 				box_public_key: Keccak256::hash(
 					"0xDb9Af2d1f3ADD2726A132AA7A65Cc9E6fC5761C3".as_bytes()
 				),
-				name: "DeBio Lab".as_bytes().to_vec(),
-				email: "DeBio Email".as_bytes().to_vec(),
-				country: CountryCode::from_vec("DC".as_bytes().to_vec()),
-				region: RegionCode::from_vec("DB".as_bytes().to_vec()),
-				city: CityCode::from_vec("CITY".as_bytes().to_vec()),
-				address: "DeBio Address".as_bytes().to_vec(),
-				phone_number: "+6281394653625".as_bytes().to_vec(),
-				website: "DeBio Website".as_bytes().to_vec(),
-				latitude: Some("DeBio Latitude".as_bytes().to_vec()),
-				longitude: Some("DeBio Longtitude".as_bytes().to_vec()),
-				profile_image: Some("DeBio Profile Image uwu".as_bytes().to_vec()),
+				name: "DeBio Lab".as_bytes().to_vec().try_into().unwrap(),
+				email: "DeBio Email".as_bytes().to_vec().try_into().unwrap(),
+				country: CountryCode::try_from("ID".as_bytes().to_vec()).unwrap(),
+				region: RegionCode::try_from("JB".as_bytes().to_vec()).unwrap(),
+				city: CityCode::try_from("CITY".as_bytes().to_vec()).unwrap(),
+				address: "DeBio Address".as_bytes().to_vec().try_into().unwrap(),
+				phone_number: "+6281394653625".as_bytes().to_vec().try_into().unwrap(),
+				website: "DeBio Website".as_bytes().to_vec().try_into().unwrap(),
+				latitude: Some("-6.175392".as_bytes().to_vec().try_into().unwrap()),
+				longitude: Some("106.827153".as_bytes().to_vec().try_into().unwrap()),
+				profile_image: Some(
+					"DeBio Profile Image uwu".as_bytes().to_vec().try_into().unwrap()
+				),
 			}
 		));
 
@@ -1540,15 +2072,21 @@ fn cant_fulfill_order_when_dna_sample_not_process() {
 		assert_ok!(Services::create_service(
 			RuntimeOrigin::signed(lab),
 			ServiceInfo {
-				name: "DeBio name".as_bytes().to_vec(),
-				prices_by_currency: vec![PriceByCurrency::default()],
+				name: "DeBio name".as_bytes().to_vec().try_into().unwrap(),
+				prices_by_currency: vec![PriceByCurrency::default()].try_into().unwrap(),
 				expected_duration: ExpectedDuration::default(),
-				category: "DeBio category".as_bytes().to_vec(),
-				description: "This is my description".as_bytes().to_vec(),
-				test_result_sample: "Test result sample".as_bytes().to_vec(),
-				dna_collection_process: "Dna Collection Process".as_bytes().to_vec(),
-				long_description: Some("This is my long description".as_bytes().to_vec()),
-				image: Some("This is my image".as_bytes().to_vec()),
+				category: "DeBio category".as_bytes().to_vec().try_into().unwrap(),
+				description: "This is my description".as_bytes().to_vec().try_into().unwrap(),
+				test_result_sample: "Test result sample".as_bytes().to_vec().try_into().unwrap(),
+				dna_collection_process: "Dna Collection Process"
+					.as_bytes()
+					.to_vec()
+					.try_into()
+					.unwrap(),
+				long_description: Some(
+					"This is my long description".as_bytes().to_vec().try_into().unwrap()
+				),
+				image: Some("This is my image".as_bytes().to_vec().try_into().unwrap()),
 			},
 			ServiceFlow::default()
 		));
@@ -1595,17 +2133,19 @@ fn cant_fulfill_order_when_already_fulfilled() {
 				box_public_key: Keccak256::hash(
 					"0xDb9Af2d1f3ADD2726A132AA7A65Cc9E6fC5761C3".as_bytes()
 				),
-				name: "DeBio Lab".as_bytes().to_vec(),
-				email: "DeBio Email".as_bytes().to_vec(),
-				country: CountryCode::from_vec("DC".as_bytes().to_vec()),
-				region: RegionCode::from_vec("DB".as_bytes().to_vec()),
-				city: CityCode::from_vec("CITY".as_bytes().to_vec()),
-				address: "DeBio Address".as_bytes().to_vec(),
-				phone_number: "+6281394653625".as_bytes().to_vec(),
-				website: "DeBio Website".as_bytes().to_vec(),
-				latitude: Some("DeBio Latitude".as_bytes().to_vec()),
-				longitude: Some("DeBio Longtitude".as_bytes().to_vec()),
-				profile_image: Some("DeBio Profile Image uwu".as_bytes().to_vec()),
+				name: "DeBio Lab".as_bytes().to_vec().try_into().unwrap(),
+				email: "DeBio Email".as_bytes().to_vec().try_into().unwrap(),
+				country: CountryCode::try_from("ID".as_bytes().to_vec()).unwrap(),
+				region: RegionCode::try_from("JB".as_bytes().to_vec()).unwrap(),
+				city: CityCode::try_from("CITY".as_bytes().to_vec()).unwrap(),
+				address: "DeBio Address".as_bytes().to_vec().try_into().unwrap(),
+				phone_number: "+6281394653625".as_bytes().to_vec().try_into().unwrap(),
+				website: "DeBio Website".as_bytes().to_vec().try_into().unwrap(),
+				latitude: Some("-6.175392".as_bytes().to_vec().try_into().unwrap()),
+				longitude: Some("106.827153".as_bytes().to_vec().try_into().unwrap()),
+				profile_image: Some(
+					"DeBio Profile Image uwu".as_bytes().to_vec().try_into().unwrap()
+				),
 			}
 		));
 
@@ -1617,22 +2157,38 @@ fn cant_fulfill_order_when_already_fulfilled() {
 		let prices_by_currency_dbio = PriceByCurrency {
 			currency: CurrencyType::DBIO,
 			total_price: 10,
-			price_components: vec![Price { component: b"testing_price".to_vec(), value: 5 }],
-			additional_prices: vec![Price { component: b"qc_price".to_vec(), value: 5 }],
+			price_components: vec![Price {
+				component: b"testing_price".to_vec().try_into().unwrap(),
+				value: 5,
+			}]
+			.try_into()
+			.unwrap(),
+			additional_prices: vec![Price {
+				component: b"qc_price".to_vec().try_into().unwrap(),
+				value: 5,
+			}]
+			.try_into()
+			.unwrap(),
 		};
 
 		assert_ok!(Services::create_service(
 			RuntimeOrigin::signed(lab),
 			ServiceInfo {
-				name: "DeBio name".as_bytes().to_vec(),
-				prices_by_currency: vec![prices_by_currency_dbio],
+				name: "DeBio name".as_bytes().to_vec().try_into().unwrap(),
+				prices_by_currency: vec![prices_by_currency_dbio].try_into().unwrap(),
 				expected_duration: ExpectedDuration::default(),
-				category: "DeBio category".as_bytes().to_vec(),
-				description: "This is my description".as_bytes().to_vec(),
-				test_result_sample: "Test result sample".as_bytes().to_vec(),
-				dna_collection_process: "Dna Collection Process".as_bytes().to_vec(),
-				long_description: Some("This is my long description".as_bytes().to_vec()),
-				image: Some("This is my image".as_bytes().to_vec()),
+				category: "DeBio category".as_bytes().to_vec().try_into().unwrap(),
+				description: "This is my description".as_bytes().to_vec().try_into().unwrap(),
+				test_result_sample: "Test result sample".as_bytes().to_vec().try_into().unwrap(),
+				dna_collection_process: "Dna Collection Process"
+					.as_bytes()
+					.to_vec()
+					.try_into()
+					.unwrap(),
+				long_description: Some(
+					"This is my long description".as_bytes().to_vec().try_into().unwrap()
+				),
+				image: Some("This is my image".as_bytes().to_vec().try_into().unwrap()),
 			},
 			ServiceFlow::default()
 		));
@@ -1649,16 +2205,16 @@ fn cant_fulfill_order_when_already_fulfilled() {
 		));
 
 		let _order_id = Orders::last_order_by_customer_id(customer).unwrap();
-		let _dna_sample = GeneticTesting::dna_samples_by_lab_id(lab).unwrap();
+		let _dna_sample = GeneticTesting::dna_samples_by_lab_id(&lab);
 
 		assert_ok!(Orders::set_order_paid(RuntimeOrigin::signed(customer), _order_id));
 		assert_ok!(GeneticTesting::submit_test_result(
 			RuntimeOrigin::signed(lab),
 			_dna_sample[0].clone(),
 			DnaTestResultSubmission {
-				comments: Some("comment".as_bytes().to_vec()),
-				result_link: Some("result_link".as_bytes().to_vec()),
-				report_link: Some("report_link".as_bytes().to_vec()),
+				comments: Some("comment".as_bytes().to_vec().try_into().unwrap()),
+				result_link: Some("result_link".as_bytes().to_vec().try_into().unwrap()),
+				report_link: Some("report_link".as_bytes().to_vec().try_into().unwrap()),
 			}
 		));
 
@@ -1695,17 +2251,19 @@ fn cant_set_order_refunded_when_not_yet_expired() {
 				box_public_key: Keccak256::hash(
 					"0xDb9Af2d1f3ADD2726A132AA7A65Cc9E6fC5761C3".as_bytes()
 				),
-				name: "DeBio Lab".as_bytes().to_vec(),
-				email: "DeBio Email".as_bytes().to_vec(),
-				country: CountryCode::from_vec("DC".as_bytes().to_vec()),
-				region: RegionCode::from_vec("DB".as_bytes().to_vec()),
-				city: CityCode::from_vec("CITY".as_bytes().to_vec()),
-				address: "DeBio Address".as_bytes().to_vec(),
-				phone_number: "+6281394653625".as_bytes().to_vec(),
-				website: "DeBio Website".as_bytes().to_vec(),
-				latitude: Some("DeBio Latitude".as_bytes().to_vec()),
-				longitude: Some("DeBio Longtitude".as_bytes().to_vec()),
-				profile_image: Some("DeBio Profile Image uwu".as_bytes().to_vec()),
+				name: "DeBio Lab".as_bytes().to_vec().try_into().unwrap(),
+				email: "DeBio Email".as_bytes().to_vec().try_into().unwrap(),
+				country: CountryCode::try_from("ID".as_bytes().to_vec()).unwrap(),
+				region: RegionCode::try_from("JB".as_bytes().to_vec()).unwrap(),
+				city: CityCode::try_from("CITY".as_bytes().to_vec()).unwrap(),
+				address: "DeBio Address".as_bytes().to_vec().try_into().unwrap(),
+				phone_number: "+6281394653625".as_bytes().to_vec().try_into().unwrap(),
+				website: "DeBio Website".as_bytes().to_vec().try_into().unwrap(),
+				latitude: Some("-6.175392".as_bytes().to_vec().try_into().unwrap()),
+				longitude: Some("106.827153".as_bytes().to_vec().try_into().unwrap()),
+				profile_image: Some(
+					"DeBio Profile Image uwu".as_bytes().to_vec().try_into().unwrap()
+				),
 			}
 		));
 
@@ -1717,22 +2275,38 @@ fn cant_set_order_refunded_when_not_yet_expired() {
 		let prices_by_currency_dbio = PriceByCurrency {
 			currency: CurrencyType::DBIO,
 			total_price: 10,
-			price_components: vec![Price { component: b"testing_price".to_vec(), value: 5 }],
-			additional_prices: vec![Price { component: b"qc_price".to_vec(), value: 5 }],
+			price_components: vec![Price {
+				component: b"testing_price".to_vec().try_into().unwrap(),
+				value: 5,
+			}]
+			.try_into()
+			.unwrap(),
+			additional_prices: vec![Price {
+				component: b"qc_price".to_vec().try_into().unwrap(),
+				value: 5,
+			}]
+			.try_into()
+			.unwrap(),
 		};
 
 		assert_ok!(Services::create_service(
 			RuntimeOrigin::signed(lab),
 			ServiceInfo {
-				name: "DeBio name".as_bytes().to_vec(),
-				prices_by_currency: vec![prices_by_currency_dbio],
+				name: "DeBio name".as_bytes().to_vec().try_into().unwrap(),
+				prices_by_currency: vec![prices_by_currency_dbio].try_into().unwrap(),
 				expected_duration: ExpectedDuration::default(),
-				category: "DeBio category".as_bytes().to_vec(),
-				description: "This is my description".as_bytes().to_vec(),
-				test_result_sample: "Test result sample".as_bytes().to_vec(),
-				dna_collection_process: "Dna Collection Process".as_bytes().to_vec(),
-				long_description: Some("This is my long description".as_bytes().to_vec()),
-				image: Some("This is my image".as_bytes().to_vec()),
+				category: "DeBio category".as_bytes().to_vec().try_into().unwrap(),
+				description: "This is my description".as_bytes().to_vec().try_into().unwrap(),
+				test_result_sample: "Test result sample".as_bytes().to_vec().try_into().unwrap(),
+				dna_collection_process: "Dna Collection Process"
+					.as_bytes()
+					.to_vec()
+					.try_into()
+					.unwrap(),
+				long_description: Some(
+					"This is my long description".as_bytes().to_vec().try_into().unwrap()
+				),
+				image: Some("This is my image".as_bytes().to_vec().try_into().unwrap()),
 			},
 			ServiceFlow::default()
 		));
@@ -1749,7 +2323,7 @@ fn cant_set_order_refunded_when_not_yet_expired() {
 		));
 
 		let _order_id = Orders::last_order_by_customer_id(customer).unwrap();
-		let _dna_sample = GeneticTesting::dna_samples_by_lab_id(lab).unwrap();
+		let _dna_sample = GeneticTesting::dna_samples_by_lab_id(&lab);
 
 		EscrowKey::<Test>::put(admin);
 
@@ -1778,17 +2352,19 @@ fn cant_set_order_refunded_when_already_refunded() {
 				box_public_key: Keccak256::hash(
 					"0xDb9Af2d1f3ADD2726A132AA7A65Cc9E6fC5761C3".as_bytes()
 				),
-				name: "DeBio Lab".as_bytes().to_vec(),
-				email: "DeBio Email".as_bytes().to_vec(),
-				country: CountryCode::from_vec("DC".as_bytes().to_vec()),
-				region: RegionCode::from_vec("DB".as_bytes().to_vec()),
-				city: CityCode::from_vec("CITY".as_bytes().to_vec()),
-				address: "DeBio Address".as_bytes().to_vec(),
-				phone_number: "+6281394653625".as_bytes().to_vec(),
-				website: "DeBio Website".as_bytes().to_vec(),
-				latitude: Some("DeBio Latitude".as_bytes().to_vec()),
-				longitude: Some("DeBio Longtitude".as_bytes().to_vec()),
-				profile_image: Some("DeBio Profile Image uwu".as_bytes().to_vec()),
+				name: "DeBio Lab".as_bytes().to_vec().try_into().unwrap(),
+				email: "DeBio Email".as_bytes().to_vec().try_into().unwrap(),
+				country: CountryCode::try_from("ID".as_bytes().to_vec()).unwrap(),
+				region: RegionCode::try_from("JB".as_bytes().to_vec()).unwrap(),
+				city: CityCode::try_from("CITY".as_bytes().to_vec()).unwrap(),
+				address: "DeBio Address".as_bytes().to_vec().try_into().unwrap(),
+				phone_number: "+6281394653625".as_bytes().to_vec().try_into().unwrap(),
+				website: "DeBio Website".as_bytes().to_vec().try_into().unwrap(),
+				latitude: Some("-6.175392".as_bytes().to_vec().try_into().unwrap()),
+				longitude: Some("106.827153".as_bytes().to_vec().try_into().unwrap()),
+				profile_image: Some(
+					"DeBio Profile Image uwu".as_bytes().to_vec().try_into().unwrap()
+				),
 			}
 		));
 
@@ -1800,22 +2376,38 @@ fn cant_set_order_refunded_when_already_refunded() {
 		let prices_by_currency_dbio = PriceByCurrency {
 			currency: CurrencyType::DBIO,
 			total_price: 10,
-			price_components: vec![Price { component: b"testing_price".to_vec(), value: 5 }],
-			additional_prices: vec![Price { component: b"qc_price".to_vec(), value: 5 }],
+			price_components: vec![Price {
+				component: b"testing_price".to_vec().try_into().unwrap(),
+				value: 5,
+			}]
+			.try_into()
+			.unwrap(),
+			additional_prices: vec![Price {
+				component: b"qc_price".to_vec().try_into().unwrap(),
+				value: 5,
+			}]
+			.try_into()
+			.unwrap(),
 		};
 
 		assert_ok!(Services::create_service(
 			RuntimeOrigin::signed(lab),
 			ServiceInfo {
-				name: "DeBio name".as_bytes().to_vec(),
-				prices_by_currency: vec![prices_by_currency_dbio],
+				name: "DeBio name".as_bytes().to_vec().try_into().unwrap(),
+				prices_by_currency: vec![prices_by_currency_dbio].try_into().unwrap(),
 				expected_duration: ExpectedDuration::default(),
-				category: "DeBio category".as_bytes().to_vec(),
-				description: "This is my description".as_bytes().to_vec(),
-				test_result_sample: "Test result sample".as_bytes().to_vec(),
-				dna_collection_process: "Dna Collection Process".as_bytes().to_vec(),
-				long_description: Some("This is my long description".as_bytes().to_vec()),
-				image: Some("This is my image".as_bytes().to_vec()),
+				category: "DeBio category".as_bytes().to_vec().try_into().unwrap(),
+				description: "This is my description".as_bytes().to_vec().try_into().unwrap(),
+				test_result_sample: "Test result sample".as_bytes().to_vec().try_into().unwrap(),
+				dna_collection_process: "Dna Collection Process"
+					.as_bytes()
+					.to_vec()
+					.try_into()
+					.unwrap(),
+				long_description: Some(
+					"This is my long description".as_bytes().to_vec().try_into().unwrap()
+				),
+				image: Some("This is my image".as_bytes().to_vec().try_into().unwrap()),
 			},
 			ServiceFlow::default()
 		));
@@ -1832,7 +2424,7 @@ fn cant_set_order_refunded_when_already_refunded() {
 		));
 
 		let _order_id = Orders::last_order_by_customer_id(customer).unwrap();
-		let _dna_sample = GeneticTesting::dna_samples_by_lab_id(lab).unwrap();
+		let _dna_sample = GeneticTesting::dna_samples_by_lab_id(&lab);
 
 		assert_ok!(Orders::set_order_paid(RuntimeOrigin::signed(customer), _order_id));
 
@@ -1840,9 +2432,9 @@ fn cant_set_order_refunded_when_already_refunded() {
 			RuntimeOrigin::signed(lab),
 			_dna_sample[0].clone(),
 			DnaTestResultSubmission {
-				comments: Some("comment".as_bytes().to_vec()),
-				result_link: Some("result_link".as_bytes().to_vec()),
-				report_link: Some("report_link".as_bytes().to_vec()),
+				comments: Some("comment".as_bytes().to_vec().try_into().unwrap()),
+				result_link: Some("result_link".as_bytes().to_vec().try_into().unwrap()),
+				report_link: Some("report_link".as_bytes().to_vec().try_into().unwrap()),
 			}
 		));
 
@@ -1864,18 +2456,13 @@ fn cant_set_order_refunded_when_already_refunded() {
 }
 
 #[test]
-fn call_event_should_work() {
+fn claim_expired_refund_works() {
 	<ExternalityBuilder>::default().existential_deposit(1).build().execute_with(|| {
-		System::set_block_number(1);
-
 		let lab = account_key("lab");
-		let admin = account_key("admin");
 		let customer = account_key("customer");
 		let pallet_id = account_key("pallet_id");
-		let treasury_key = account_key("treasury_key");
 
 		PalletAccount::<Test>::put(pallet_id);
-		TreasuryKey::<Test>::put(treasury_key);
 
 		assert_ok!(Labs::register_lab(
 			RuntimeOrigin::signed(lab),
@@ -1883,17 +2470,19 @@ fn call_event_should_work() {
 				box_public_key: Keccak256::hash(
 					"0xDb9Af2d1f3ADD2726A132AA7A65Cc9E6fC5761C3".as_bytes()
 				),
-				name: "DeBio Lab".as_bytes().to_vec(),
-				email: "DeBio Email".as_bytes().to_vec(),
-				country: CountryCode::from_vec("DC".as_bytes().to_vec()),
-				region: RegionCode::from_vec("DB".as_bytes().to_vec()),
-				city: CityCode::from_vec("CITY".as_bytes().to_vec()),
-				address: "DeBio Address".as_bytes().to_vec(),
-				phone_number: "+6281394653625".as_bytes().to_vec(),
-				website: "DeBio Website".as_bytes().to_vec(),
-				latitude: Some("DeBio Latitude".as_bytes().to_vec()),
-				longitude: Some("DeBio Longtitude".as_bytes().to_vec()),
-				profile_image: Some("DeBio Profile Image uwu".as_bytes().to_vec()),
+				name: "DeBio Lab".as_bytes().to_vec().try_into().unwrap(),
+				email: "DeBio Email".as_bytes().to_vec().try_into().unwrap(),
+				country: CountryCode::try_from("ID".as_bytes().to_vec()).unwrap(),
+				region: RegionCode::try_from("JB".as_bytes().to_vec()).unwrap(),
+				city: CityCode::try_from("CITY".as_bytes().to_vec()).unwrap(),
+				address: "DeBio Address".as_bytes().to_vec().try_into().unwrap(),
+				phone_number: "+6281394653625".as_bytes().to_vec().try_into().unwrap(),
+				website: "DeBio Website".as_bytes().to_vec().try_into().unwrap(),
+				latitude: Some("-6.175392".as_bytes().to_vec().try_into().unwrap()),
+				longitude: Some("106.827153".as_bytes().to_vec().try_into().unwrap()),
+				profile_image: Some(
+					"DeBio Profile Image uwu".as_bytes().to_vec().try_into().unwrap()
+				),
 			}
 		));
 
@@ -1905,22 +2494,38 @@ fn call_event_should_work() {
 		let prices_by_currency_dbio = PriceByCurrency {
 			currency: CurrencyType::DBIO,
 			total_price: 10,
-			price_components: vec![Price { component: b"testing_price".to_vec(), value: 5 }],
-			additional_prices: vec![Price { component: b"qc_price".to_vec(), value: 5 }],
+			price_components: vec![Price {
+				component: b"testing_price".to_vec().try_into().unwrap(),
+				value: 5,
+			}]
+			.try_into()
+			.unwrap(),
+			additional_prices: vec![Price {
+				component: b"qc_price".to_vec().try_into().unwrap(),
+				value: 5,
+			}]
+			.try_into()
+			.unwrap(),
 		};
 
 		assert_ok!(Services::create_service(
 			RuntimeOrigin::signed(lab),
 			ServiceInfo {
-				name: "DeBio name".as_bytes().to_vec(),
-				prices_by_currency: vec![prices_by_currency_dbio.clone()],
+				name: "DeBio name".as_bytes().to_vec().try_into().unwrap(),
+				prices_by_currency: vec![prices_by_currency_dbio.clone()].try_into().unwrap(),
 				expected_duration: ExpectedDuration::default(),
-				category: "DeBio category".as_bytes().to_vec(),
-				description: "This is my description".as_bytes().to_vec(),
-				test_result_sample: "Test result sample".as_bytes().to_vec(),
-				dna_collection_process: "Dna Collection Process".as_bytes().to_vec(),
-				long_description: Some("This is my long description".as_bytes().to_vec()),
-				image: Some("This is my image".as_bytes().to_vec()),
+				category: "DeBio category".as_bytes().to_vec().try_into().unwrap(),
+				description: "This is my description".as_bytes().to_vec().try_into().unwrap(),
+				test_result_sample: "Test result sample".as_bytes().to_vec().try_into().unwrap(),
+				dna_collection_process: "Dna Collection Process"
+					.as_bytes()
+					.to_vec()
+					.try_into()
+					.unwrap(),
+				long_description: Some(
+					"This is my long description".as_bytes().to_vec().try_into().unwrap()
+				),
+				image: Some("This is my image".as_bytes().to_vec().try_into().unwrap()),
 			},
 			ServiceFlow::default()
 		));
@@ -1937,105 +2542,3193 @@ fn call_event_should_work() {
 		));
 
 		let _order_id = Orders::last_order_by_customer_id(customer).unwrap();
-		let _dna_sample = GeneticTesting::dna_samples_by_lab_id(lab).unwrap();
+		let _dna_sample = GeneticTesting::dna_samples_by_lab_id(&lab);
 
-		System::assert_last_event(RuntimeEvent::Orders(crate::Event::OrderCreated(Order {
-			id: _order_id,
-			service_id: _lab.services[0],
-			customer_id: customer,
-			customer_box_public_key: Keccak256::hash(
-				"0xhJ7TRe456FADD2726A132ABJK5RCc9E6fC5869F4".as_bytes(),
-			),
-			seller_id: lab,
-			dna_sample_tracking_id: _dna_sample[0].clone(),
-			asset_id: None,
-			total_price: 10,
-			currency: CurrencyType::default(),
-			prices: prices_by_currency_dbio.price_components.clone(),
-			additional_prices: prices_by_currency_dbio.additional_prices.clone(),
-			status: OrderStatus::default(),
-			order_flow: ServiceFlow::StakingRequestService,
-			created_at: 0,
-			updated_at: 0,
-		})));
+		assert_ok!(Orders::set_order_paid(RuntimeOrigin::signed(customer), _order_id));
 
-		assert_ok!(Orders::cancel_order(RuntimeOrigin::signed(customer), _order_id));
+		assert_eq!(Orders::refund_deadline_by_order_id(_order_id), Some(1000));
+		assert_eq!(
+			RefundDeadlines::<Test>::iter_prefix(0).collect::<Vec<_>>(),
+			vec![(_order_id, 1000)]
+		);
 
-		System::assert_last_event(RuntimeEvent::Orders(crate::Event::OrderCancelled(Order {
-			id: _order_id,
-			service_id: _lab.services[0],
-			customer_id: customer,
-			customer_box_public_key: Keccak256::hash(
-				"0xhJ7TRe456FADD2726A132ABJK5RCc9E6fC5869F4".as_bytes(),
-			),
-			seller_id: lab,
-			dna_sample_tracking_id: _dna_sample[0].clone(),
-			asset_id: None,
-			total_price: 10,
-			currency: CurrencyType::default(),
-			prices: prices_by_currency_dbio.price_components.clone(),
-			additional_prices: prices_by_currency_dbio.additional_prices.clone(),
-			status: OrderStatus::Cancelled,
-			order_flow: ServiceFlow::StakingRequestService,
-			created_at: 0,
-			updated_at: 0,
-		})));
+		Timestamp::set_timestamp(1000);
 
-		assert_ok!(Orders::create_order(
-			RuntimeOrigin::signed(customer),
-			_lab.services[0],
-			0,
-			Keccak256::hash("0xhJ7TRe456FADD2726A132ABJK5RCc9E6fC5869F4".as_bytes()),
-			ServiceFlow::StakingRequestService,
-			None,
+		assert_ok!(Orders::claim_expired_refund(
+			RuntimeOrigin::signed(account_key("other")),
+			_order_id
 		));
 
-		let _order_id = Orders::last_order_by_customer_id(customer).unwrap();
-		let _dna_sample = GeneticTesting::dna_samples_by_lab_id(lab).unwrap();
+		assert_eq!(
+			Orders::order_by_id(&_order_id),
+			Some(Order {
+				id: _order_id,
+				service_id: _lab.services[0],
+				customer_id: customer,
+				customer_box_public_key: Keccak256::hash(
+					"0xhJ7TRe456FADD2726A132ABJK5RCc9E6fC5869F4".as_bytes()
+				),
+				seller_id: lab,
+				dna_sample_tracking_id: _dna_sample[0].clone(),
+				asset_id: None,
+				total_price: 10,
+				paid_amount: 10,
+				currency: CurrencyType::DBIO,
+				prices: prices_by_currency_dbio.price_components.to_vec().try_into().unwrap(),
+				additional_prices: prices_by_currency_dbio
+					.additional_prices
+					.to_vec()
+					.try_into()
+					.unwrap(),
+				status: OrderStatus::Refunded,
+				order_flow: ServiceFlow::StakingRequestService,
+				created_at: 0,
+				updated_at: 1000
+			})
+		);
 
-		EscrowKey::<Test>::put(admin);
+		assert_eq!(Orders::refund_deadline_by_order_id(_order_id), None);
+		assert_eq!(RefundDeadlines::<Test>::iter().count(), 0);
+		assert_eq!(Balances::free_balance(customer), 200);
+		assert_eq!(Balances::free_balance(pallet_id), 1);
+	})
+}
 
-		assert_ok!(Orders::set_order_paid(RuntimeOrigin::signed(customer), _order_id));
+#[test]
+fn refund_expired_orders_on_idle_works() {
+	<ExternalityBuilder>::default().existential_deposit(1).build().execute_with(|| {
+		let lab = account_key("lab");
+		let customer = account_key("customer");
+		let pallet_id = account_key("pallet_id");
 
-		System::assert_last_event(RuntimeEvent::Orders(crate::Event::OrderPaid(Order {
-			id: _order_id,
-			service_id: _lab.services[0],
-			customer_id: customer,
-			customer_box_public_key: Keccak256::hash(
-				"0xhJ7TRe456FADD2726A132ABJK5RCc9E6fC5869F4".as_bytes(),
-			),
-			seller_id: lab,
-			dna_sample_tracking_id: _dna_sample[0].clone(),
-			asset_id: None,
-			total_price: 10,
-			currency: CurrencyType::default(),
-			prices: prices_by_currency_dbio.price_components.clone(),
-			additional_prices: prices_by_currency_dbio.additional_prices.clone(),
-			status: OrderStatus::Paid,
-			order_flow: ServiceFlow::StakingRequestService,
-			created_at: 0,
-			updated_at: 0,
-		})));
+		PalletAccount::<Test>::put(pallet_id);
+
+		assert_ok!(Labs::register_lab(
+			RuntimeOrigin::signed(lab),
+			LabInfo {
+				box_public_key: Keccak256::hash(
+					"0xDb9Af2d1f3ADD2726A132AA7A65Cc9E6fC5761C3".as_bytes()
+				),
+				name: "DeBio Lab".as_bytes().to_vec().try_into().unwrap(),
+				email: "DeBio Email".as_bytes().to_vec().try_into().unwrap(),
+				country: CountryCode::try_from("ID".as_bytes().to_vec()).unwrap(),
+				region: RegionCode::try_from("JB".as_bytes().to_vec()).unwrap(),
+				city: CityCode::try_from("CITY".as_bytes().to_vec()).unwrap(),
+				address: "DeBio Address".as_bytes().to_vec().try_into().unwrap(),
+				phone_number: "+6281394653625".as_bytes().to_vec().try_into().unwrap(),
+				website: "DeBio Website".as_bytes().to_vec().try_into().unwrap(),
+				latitude: Some("-6.175392".as_bytes().to_vec().try_into().unwrap()),
+				longitude: Some("106.827153".as_bytes().to_vec().try_into().unwrap()),
+				profile_image: Some(
+					"DeBio Profile Image uwu".as_bytes().to_vec().try_into().unwrap()
+				),
+			}
+		));
+
+		assert_ok!(UserProfile::set_eth_address(
+			RuntimeOrigin::signed(lab),
+			EthereumAddress([b'X'; 20])
+		));
+
+		let prices_by_currency_dbio = PriceByCurrency {
+			currency: CurrencyType::DBIO,
+			total_price: 10,
+			price_components: vec![Price {
+				component: b"testing_price".to_vec().try_into().unwrap(),
+				value: 5,
+			}]
+			.try_into()
+			.unwrap(),
+			additional_prices: vec![Price {
+				component: b"qc_price".to_vec().try_into().unwrap(),
+				value: 5,
+			}]
+			.try_into()
+			.unwrap(),
+		};
+
+		assert_ok!(Services::create_service(
+			RuntimeOrigin::signed(lab),
+			ServiceInfo {
+				name: "DeBio name".as_bytes().to_vec().try_into().unwrap(),
+				prices_by_currency: vec![prices_by_currency_dbio.clone()].try_into().unwrap(),
+				expected_duration: ExpectedDuration::default(),
+				category: "DeBio category".as_bytes().to_vec().try_into().unwrap(),
+				description: "This is my description".as_bytes().to_vec().try_into().unwrap(),
+				test_result_sample: "Test result sample".as_bytes().to_vec().try_into().unwrap(),
+				dna_collection_process: "Dna Collection Process"
+					.as_bytes()
+					.to_vec()
+					.try_into()
+					.unwrap(),
+				long_description: Some(
+					"This is my long description".as_bytes().to_vec().try_into().unwrap()
+				),
+				image: Some("This is my image".as_bytes().to_vec().try_into().unwrap()),
+			},
+			ServiceFlow::default()
+		));
+
+		let _lab = Labs::lab_by_account_id(lab).unwrap();
+
+		assert_ok!(Orders::create_order(
+			RuntimeOrigin::signed(customer),
+			_lab.services[0],
+			0,
+			Keccak256::hash("0xhJ7TRe456FADD2726A132ABJK5RCc9E6fC5869F4".as_bytes()),
+			ServiceFlow::StakingRequestService,
+			None,
+		));
+
+		let _order_id = Orders::last_order_by_customer_id(customer).unwrap();
+		let _dna_sample = GeneticTesting::dna_samples_by_lab_id(&lab);
+
+		assert_ok!(Orders::set_order_paid(RuntimeOrigin::signed(customer), _order_id));
+
+		Orders::on_idle(1, Weight::from_ref_time(u64::MAX));
+
+		assert_eq!(Orders::order_by_id(&_order_id).unwrap().status, OrderStatus::Paid);
+
+		Timestamp::set_timestamp(1000);
+
+		Orders::on_idle(1, Weight::from_ref_time(u64::MAX));
+
+		assert_eq!(
+			Orders::order_by_id(&_order_id),
+			Some(Order {
+				id: _order_id,
+				service_id: _lab.services[0],
+				customer_id: customer,
+				customer_box_public_key: Keccak256::hash(
+					"0xhJ7TRe456FADD2726A132ABJK5RCc9E6fC5869F4".as_bytes()
+				),
+				seller_id: lab,
+				dna_sample_tracking_id: _dna_sample[0].clone(),
+				asset_id: None,
+				total_price: 10,
+				paid_amount: 10,
+				currency: CurrencyType::DBIO,
+				prices: prices_by_currency_dbio.price_components.to_vec().try_into().unwrap(),
+				additional_prices: prices_by_currency_dbio
+					.additional_prices
+					.to_vec()
+					.try_into()
+					.unwrap(),
+				status: OrderStatus::Refunded,
+				order_flow: ServiceFlow::StakingRequestService,
+				created_at: 0,
+				updated_at: 1000
+			})
+		);
+
+		assert_eq!(Orders::refund_deadline_by_order_id(_order_id), None);
+		assert_eq!(RefundDeadlines::<Test>::iter().count(), 0);
+		assert_eq!(Balances::free_balance(customer), 200);
+		assert_eq!(Balances::free_balance(pallet_id), 1);
+	})
+}
+
+#[test]
+fn cant_claim_expired_refund_when_not_yet_expired() {
+	<ExternalityBuilder>::default().existential_deposit(1).build().execute_with(|| {
+		let lab = account_key("lab");
+		let customer = account_key("customer");
+		let pallet_id = account_key("pallet_id");
+
+		PalletAccount::<Test>::put(pallet_id);
+
+		assert_ok!(Labs::register_lab(
+			RuntimeOrigin::signed(lab),
+			LabInfo {
+				box_public_key: Keccak256::hash(
+					"0xDb9Af2d1f3ADD2726A132AA7A65Cc9E6fC5761C3".as_bytes()
+				),
+				name: "DeBio Lab".as_bytes().to_vec().try_into().unwrap(),
+				email: "DeBio Email".as_bytes().to_vec().try_into().unwrap(),
+				country: CountryCode::try_from("ID".as_bytes().to_vec()).unwrap(),
+				region: RegionCode::try_from("JB".as_bytes().to_vec()).unwrap(),
+				city: CityCode::try_from("CITY".as_bytes().to_vec()).unwrap(),
+				address: "DeBio Address".as_bytes().to_vec().try_into().unwrap(),
+				phone_number: "+6281394653625".as_bytes().to_vec().try_into().unwrap(),
+				website: "DeBio Website".as_bytes().to_vec().try_into().unwrap(),
+				latitude: Some("-6.175392".as_bytes().to_vec().try_into().unwrap()),
+				longitude: Some("106.827153".as_bytes().to_vec().try_into().unwrap()),
+				profile_image: Some(
+					"DeBio Profile Image uwu".as_bytes().to_vec().try_into().unwrap()
+				),
+			}
+		));
+
+		assert_ok!(UserProfile::set_eth_address(
+			RuntimeOrigin::signed(lab),
+			EthereumAddress([b'X'; 20])
+		));
+
+		let prices_by_currency_dbio = PriceByCurrency {
+			currency: CurrencyType::DBIO,
+			total_price: 10,
+			price_components: vec![Price {
+				component: b"testing_price".to_vec().try_into().unwrap(),
+				value: 5,
+			}]
+			.try_into()
+			.unwrap(),
+			additional_prices: vec![Price {
+				component: b"qc_price".to_vec().try_into().unwrap(),
+				value: 5,
+			}]
+			.try_into()
+			.unwrap(),
+		};
+
+		assert_ok!(Services::create_service(
+			RuntimeOrigin::signed(lab),
+			ServiceInfo {
+				name: "DeBio name".as_bytes().to_vec().try_into().unwrap(),
+				prices_by_currency: vec![prices_by_currency_dbio.clone()].try_into().unwrap(),
+				expected_duration: ExpectedDuration::default(),
+				category: "DeBio category".as_bytes().to_vec().try_into().unwrap(),
+				description: "This is my description".as_bytes().to_vec().try_into().unwrap(),
+				test_result_sample: "Test result sample".as_bytes().to_vec().try_into().unwrap(),
+				dna_collection_process: "Dna Collection Process"
+					.as_bytes()
+					.to_vec()
+					.try_into()
+					.unwrap(),
+				long_description: Some(
+					"This is my long description".as_bytes().to_vec().try_into().unwrap()
+				),
+				image: Some("This is my image".as_bytes().to_vec().try_into().unwrap()),
+			},
+			ServiceFlow::default()
+		));
+
+		let _lab = Labs::lab_by_account_id(lab).unwrap();
+
+		assert_ok!(Orders::create_order(
+			RuntimeOrigin::signed(customer),
+			_lab.services[0],
+			0,
+			Keccak256::hash("0xhJ7TRe456FADD2726A132ABJK5RCc9E6fC5869F4".as_bytes()),
+			ServiceFlow::StakingRequestService,
+			None,
+		));
+
+		let _order_id = Orders::last_order_by_customer_id(customer).unwrap();
+		let _dna_sample = GeneticTesting::dna_samples_by_lab_id(&lab);
+
+		assert_ok!(Orders::set_order_paid(RuntimeOrigin::signed(customer), _order_id));
+
+		assert_noop!(
+			Orders::claim_expired_refund(RuntimeOrigin::signed(customer), _order_id),
+			Error::<Test>::OrderNotYetExpired
+		);
+	})
+}
+
+#[test]
+fn cant_claim_expired_refund_when_dna_sample_arrived() {
+	<ExternalityBuilder>::default().existential_deposit(1).build().execute_with(|| {
+		let lab = account_key("lab");
+		let customer = account_key("customer");
+		let pallet_id = account_key("pallet_id");
+
+		PalletAccount::<Test>::put(pallet_id);
+
+		assert_ok!(Labs::register_lab(
+			RuntimeOrigin::signed(lab),
+			LabInfo {
+				box_public_key: Keccak256::hash(
+					"0xDb9Af2d1f3ADD2726A132AA7A65Cc9E6fC5761C3".as_bytes()
+				),
+				name: "DeBio Lab".as_bytes().to_vec().try_into().unwrap(),
+				email: "DeBio Email".as_bytes().to_vec().try_into().unwrap(),
+				country: CountryCode::try_from("ID".as_bytes().to_vec()).unwrap(),
+				region: RegionCode::try_from("JB".as_bytes().to_vec()).unwrap(),
+				city: CityCode::try_from("CITY".as_bytes().to_vec()).unwrap(),
+				address: "DeBio Address".as_bytes().to_vec().try_into().unwrap(),
+				phone_number: "+6281394653625".as_bytes().to_vec().try_into().unwrap(),
+				website: "DeBio Website".as_bytes().to_vec().try_into().unwrap(),
+				latitude: Some("-6.175392".as_bytes().to_vec().try_into().unwrap()),
+				longitude: Some("106.827153".as_bytes().to_vec().try_into().unwrap()),
+				profile_image: Some(
+					"DeBio Profile Image uwu".as_bytes().to_vec().try_into().unwrap()
+				),
+			}
+		));
+
+		assert_ok!(UserProfile::set_eth_address(
+			RuntimeOrigin::signed(lab),
+			EthereumAddress([b'X'; 20])
+		));
+
+		let prices_by_currency_dbio = PriceByCurrency {
+			currency: CurrencyType::DBIO,
+			total_price: 10,
+			price_components: vec![Price {
+				component: b"testing_price".to_vec().try_into().unwrap(),
+				value: 5,
+			}]
+			.try_into()
+			.unwrap(),
+			additional_prices: vec![Price {
+				component: b"qc_price".to_vec().try_into().unwrap(),
+				value: 5,
+			}]
+			.try_into()
+			.unwrap(),
+		};
+
+		assert_ok!(Services::create_service(
+			RuntimeOrigin::signed(lab),
+			ServiceInfo {
+				name: "DeBio name".as_bytes().to_vec().try_into().unwrap(),
+				prices_by_currency: vec![prices_by_currency_dbio.clone()].try_into().unwrap(),
+				expected_duration: ExpectedDuration::default(),
+				category: "DeBio category".as_bytes().to_vec().try_into().unwrap(),
+				description: "This is my description".as_bytes().to_vec().try_into().unwrap(),
+				test_result_sample: "Test result sample".as_bytes().to_vec().try_into().unwrap(),
+				dna_collection_process: "Dna Collection Process"
+					.as_bytes()
+					.to_vec()
+					.try_into()
+					.unwrap(),
+				long_description: Some(
+					"This is my long description".as_bytes().to_vec().try_into().unwrap()
+				),
+				image: Some("This is my image".as_bytes().to_vec().try_into().unwrap()),
+			},
+			ServiceFlow::default()
+		));
+
+		let _lab = Labs::lab_by_account_id(lab).unwrap();
+
+		assert_ok!(Orders::create_order(
+			RuntimeOrigin::signed(customer),
+			_lab.services[0],
+			0,
+			Keccak256::hash("0xhJ7TRe456FADD2726A132ABJK5RCc9E6fC5869F4".as_bytes()),
+			ServiceFlow::StakingRequestService,
+			None,
+		));
+
+		let _order_id = Orders::last_order_by_customer_id(customer).unwrap();
+		let _dna_sample = GeneticTesting::dna_samples_by_lab_id(&lab);
+
+		assert_ok!(Orders::set_order_paid(RuntimeOrigin::signed(customer), _order_id));
+
+		assert_ok!(GeneticTesting::process_dna_sample(
+			RuntimeOrigin::signed(lab),
+			_dna_sample[0].clone(),
+			DnaSampleStatus::Arrived,
+		));
+
+		Timestamp::set_timestamp(1000);
+
+		assert_noop!(
+			Orders::claim_expired_refund(RuntimeOrigin::signed(customer), _order_id),
+			Error::<Test>::OngoingOrderCannotBeRefunded
+		);
+	})
+}
+
+#[test]
+fn create_bundle_order_works() {
+	<ExternalityBuilder>::default().existential_deposit(1).build().execute_with(|| {
+		let customer = account_key("customer");
+		let lab = account_key("lab");
+
+		assert_ok!(Labs::register_lab(
+			RuntimeOrigin::signed(lab),
+			LabInfo {
+				box_public_key: Keccak256::hash(
+					"0xDb9Af2d1f3ADD2726A132AA7A65Cc9E6fC5761C3".as_bytes()
+				),
+				name: "DeBio Lab".as_bytes().to_vec().try_into().unwrap(),
+				email: "DeBio Email".as_bytes().to_vec().try_into().unwrap(),
+				country: CountryCode::try_from("ID".as_bytes().to_vec()).unwrap(),
+				region: RegionCode::try_from("JB".as_bytes().to_vec()).unwrap(),
+				city: CityCode::try_from("CITY".as_bytes().to_vec()).unwrap(),
+				address: "DeBio Address".as_bytes().to_vec().try_into().unwrap(),
+				phone_number: "+6281394653625".as_bytes().to_vec().try_into().unwrap(),
+				website: "DeBio Website".as_bytes().to_vec().try_into().unwrap(),
+				latitude: Some("-6.175392".as_bytes().to_vec().try_into().unwrap()),
+				longitude: Some("106.827153".as_bytes().to_vec().try_into().unwrap()),
+				profile_image: Some(
+					"DeBio Profile Image uwu".as_bytes().to_vec().try_into().unwrap()
+				),
+			}
+		));
+
+		assert_ok!(UserProfile::set_eth_address(
+			RuntimeOrigin::signed(lab),
+			EthereumAddress([b'X'; 20])
+		));
+
+		let prices_by_currency_dbio = PriceByCurrency {
+			currency: CurrencyType::DBIO,
+			total_price: 40,
+			price_components: vec![Price {
+				component: b"testing_price".to_vec().try_into().unwrap(),
+				value: 20,
+			}]
+			.try_into()
+			.unwrap(),
+			additional_prices: vec![Price {
+				component: b"qc_price".to_vec().try_into().unwrap(),
+				value: 20,
+			}]
+			.try_into()
+			.unwrap(),
+		};
+
+		let other_prices_by_currency_dbio = PriceByCurrency {
+			currency: CurrencyType::DBIO,
+			total_price: 20,
+			price_components: vec![Price {
+				component: b"testing_price".to_vec().try_into().unwrap(),
+				value: 10,
+			}]
+			.try_into()
+			.unwrap(),
+			additional_prices: vec![Price {
+				component: b"qc_price".to_vec().try_into().unwrap(),
+				value: 10,
+			}]
+			.try_into()
+			.unwrap(),
+		};
+
+		for prices_by_currency in
+			[prices_by_currency_dbio.clone(), other_prices_by_currency_dbio.clone()]
+		{
+			assert_ok!(Services::create_service(
+				RuntimeOrigin::signed(lab),
+				ServiceInfo {
+					name: "DeBio name".as_bytes().to_vec().try_into().unwrap(),
+					prices_by_currency: vec![prices_by_currency].try_into().unwrap(),
+					expected_duration: ExpectedDuration::default(),
+					category: "DeBio category".as_bytes().to_vec().try_into().unwrap(),
+					description: "This is my description".as_bytes().to_vec().try_into().unwrap(),
+					test_result_sample: "Test result sample"
+						.as_bytes()
+						.to_vec()
+						.try_into()
+						.unwrap(),
+					dna_collection_process: "Dna Collection Process"
+						.as_bytes()
+						.to_vec()
+						.try_into()
+						.unwrap(),
+					long_description: Some(
+						"This is my long description".as_bytes().to_vec().try_into().unwrap()
+					),
+					image: Some("This is my image".as_bytes().to_vec().try_into().unwrap()),
+				},
+				ServiceFlow::default()
+			));
+		}
+
+		let _lab = Labs::lab_by_account_id(lab).unwrap();
+
+		assert_ok!(Orders::create_bundle_order(
+			RuntimeOrigin::signed(customer),
+			vec![(_lab.services[0], 0), (_lab.services[1], 0)],
+			Keccak256::hash("0xhJ7TRe456FADD2726A132ABJK5RCc9E6fC5869F4".as_bytes()),
+			ServiceFlow::StakingRequestService,
+			None,
+		));
+
+		let _order_id = Orders::last_order_by_customer_id(customer).unwrap();
+		let _order_items = Orders::order_items_by_order_id(_order_id).unwrap();
+
+		assert_eq!(_order_items.len(), 2);
+		assert_ne!(_order_items[0].dna_sample_tracking_id, _order_items[1].dna_sample_tracking_id);
+		assert_eq!(
+			_order_items[1],
+			OrderItem {
+				service_id: _lab.services[1],
+				dna_sample_tracking_id: _order_items[1].dna_sample_tracking_id.clone(),
+				prices: other_prices_by_currency_dbio.price_components.to_vec().try_into().unwrap(),
+				additional_prices: other_prices_by_currency_dbio
+					.additional_prices
+					.to_vec()
+					.try_into()
+					.unwrap(),
+				total_price: 20,
+				status: OrderStatus::Unpaid,
+			}
+		);
+
+		assert_eq!(
+			Orders::order_by_id(&_order_id),
+			Some(Order {
+				id: _order_id,
+				service_id: _lab.services[0],
+				customer_id: customer,
+				customer_box_public_key: Keccak256::hash(
+					"0xhJ7TRe456FADD2726A132ABJK5RCc9E6fC5869F4".as_bytes()
+				),
+				seller_id: lab,
+				dna_sample_tracking_id: _order_items[0].dna_sample_tracking_id.clone(),
+				asset_id: None,
+				total_price: 60,
+				paid_amount: 0,
+				currency: CurrencyType::DBIO,
+				prices: vec![
+					Price { component: b"testing_price".to_vec().try_into().unwrap(), value: 20 },
+					Price { component: b"testing_price".to_vec().try_into().unwrap(), value: 10 },
+				]
+				.try_into()
+				.unwrap(),
+				additional_prices: vec![
+					Price { component: b"qc_price".to_vec().try_into().unwrap(), value: 20 },
+					Price { component: b"qc_price".to_vec().try_into().unwrap(), value: 10 },
+				]
+				.try_into()
+				.unwrap(),
+				status: OrderStatus::Unpaid,
+				order_flow: ServiceFlow::StakingRequestService,
+				created_at: 0,
+				updated_at: 0
+			})
+		);
+
+		assert_eq!(GeneticTesting::dna_samples_by_lab_id(&lab).len(), 2);
+	})
+}
+
+#[test]
+fn bundle_order_refund_deadline_waits_for_slowest_service() {
+	<ExternalityBuilder>::default().existential_deposit(1).build().execute_with(|| {
+		let customer = account_key("customer");
+		let lab = account_key("lab");
+		let pallet_id = account_key("pallet_id");
+
+		PalletAccount::<Test>::put(pallet_id);
+
+		assert_ok!(Labs::register_lab(
+			RuntimeOrigin::signed(lab),
+			LabInfo {
+				box_public_key: Keccak256::hash(
+					"0xDb9Af2d1f3ADD2726A132AA7A65Cc9E6fC5761C3".as_bytes()
+				),
+				name: "DeBio Lab".as_bytes().to_vec().try_into().unwrap(),
+				email: "DeBio Email".as_bytes().to_vec().try_into().unwrap(),
+				country: CountryCode::try_from("ID".as_bytes().to_vec()).unwrap(),
+				region: RegionCode::try_from("JB".as_bytes().to_vec()).unwrap(),
+				city: CityCode::try_from("CITY".as_bytes().to_vec()).unwrap(),
+				address: "DeBio Address".as_bytes().to_vec().try_into().unwrap(),
+				phone_number: "+6281394653625".as_bytes().to_vec().try_into().unwrap(),
+				website: "DeBio Website".as_bytes().to_vec().try_into().unwrap(),
+				latitude: Some("-6.175392".as_bytes().to_vec().try_into().unwrap()),
+				longitude: Some("106.827153".as_bytes().to_vec().try_into().unwrap()),
+				profile_image: Some(
+					"DeBio Profile Image uwu".as_bytes().to_vec().try_into().unwrap()
+				),
+			}
+		));
+
+		assert_ok!(UserProfile::set_eth_address(
+			RuntimeOrigin::signed(lab),
+			EthereumAddress([b'X'; 20])
+		));
+
+		let prices_by_currency_dbio = PriceByCurrency {
+			currency: CurrencyType::DBIO,
+			total_price: 40,
+			price_components: vec![Price {
+				component: b"testing_price".to_vec().try_into().unwrap(),
+				value: 20,
+			}]
+			.try_into()
+			.unwrap(),
+			additional_prices: vec![Price {
+				component: b"qc_price".to_vec().try_into().unwrap(),
+				value: 20,
+			}]
+			.try_into()
+			.unwrap(),
+		};
+
+		let other_prices_by_currency_dbio = PriceByCurrency {
+			currency: CurrencyType::DBIO,
+			total_price: 20,
+			price_components: vec![Price {
+				component: b"testing_price".to_vec().try_into().unwrap(),
+				value: 10,
+			}]
+			.try_into()
+			.unwrap(),
+			additional_prices: vec![Price {
+				component: b"qc_price".to_vec().try_into().unwrap(),
+				value: 10,
+			}]
+			.try_into()
+			.unwrap(),
+		};
+
+		for (prices_by_currency, hours) in
+			[(prices_by_currency_dbio.clone(), 1), (other_prices_by_currency_dbio.clone(), 3)]
+		{
+			assert_ok!(Services::create_service(
+				RuntimeOrigin::signed(lab),
+				ServiceInfo {
+					name: "DeBio name".as_bytes().to_vec().try_into().unwrap(),
+					prices_by_currency: vec![prices_by_currency].try_into().unwrap(),
+					expected_duration: ExpectedDuration {
+						duration: hours,
+						duration_type: DurationType::Hours,
+					},
+					category: "DeBio category".as_bytes().to_vec().try_into().unwrap(),
+					description: "This is my description".as_bytes().to_vec().try_into().unwrap(),
+					test_result_sample: "Test result sample"
+						.as_bytes()
+						.to_vec()
+						.try_into()
+						.unwrap(),
+					dna_collection_process: "Dna Collection Process"
+						.as_bytes()
+						.to_vec()
+						.try_into()
+						.unwrap(),
+					long_description: Some(
+						"This is my long description".as_bytes().to_vec().try_into().unwrap()
+					),
+					image: Some("This is my image".as_bytes().to_vec().try_into().unwrap()),
+				},
+				ServiceFlow::default()
+			));
+		}
+
+		let _lab = Labs::lab_by_account_id(lab).unwrap();
+
+		assert_ok!(Orders::create_bundle_order(
+			RuntimeOrigin::signed(customer),
+			vec![(_lab.services[0], 0), (_lab.services[1], 0)],
+			Keccak256::hash("0xhJ7TRe456FADD2726A132ABJK5RCc9E6fC5869F4".as_bytes()),
+			ServiceFlow::StakingRequestService,
+			None,
+		));
+
+		let _order_id = Orders::last_order_by_customer_id(customer).unwrap();
+
+		assert_ok!(Orders::set_order_paid(RuntimeOrigin::signed(customer), _order_id));
+
+		let deadline = 3 * 60 * 60 * 1000 + 1000;
+
+		assert_eq!(Orders::refund_deadline_by_order_id(_order_id), Some(deadline));
+		assert_eq!(RefundDeadlines::<Test>::get(3, _order_id), Some(deadline));
+	})
+}
+
+#[test]
+fn bundle_order_refunds_rejected_items_and_fulfills_the_rest() {
+	<ExternalityBuilder>::default().existential_deposit(1).build().execute_with(|| {
+		System::set_block_number(1);
+
+		let lab = account_key("lab");
+		let customer = account_key("customer");
+		let pallet_id = account_key("pallet_id");
+		let treasury_key = account_key("treasury_key");
+
+		PalletAccount::<Test>::put(pallet_id);
+		TreasuryKey::<Test>::put(treasury_key);
+
+		assert_ok!(Labs::register_lab(
+			RuntimeOrigin::signed(lab),
+			LabInfo {
+				box_public_key: Keccak256::hash(
+					"0xDb9Af2d1f3ADD2726A132AA7A65Cc9E6fC5761C3".as_bytes()
+				),
+				name: "DeBio Lab".as_bytes().to_vec().try_into().unwrap(),
+				email: "DeBio Email".as_bytes().to_vec().try_into().unwrap(),
+				country: CountryCode::try_from("ID".as_bytes().to_vec()).unwrap(),
+				region: RegionCode::try_from("JB".as_bytes().to_vec()).unwrap(),
+				city: CityCode::try_from("CITY".as_bytes().to_vec()).unwrap(),
+				address: "DeBio Address".as_bytes().to_vec().try_into().unwrap(),
+				phone_number: "+6281394653625".as_bytes().to_vec().try_into().unwrap(),
+				website: "DeBio Website".as_bytes().to_vec().try_into().unwrap(),
+				latitude: Some("-6.175392".as_bytes().to_vec().try_into().unwrap()),
+				longitude: Some("106.827153".as_bytes().to_vec().try_into().unwrap()),
+				profile_image: Some(
+					"DeBio Profile Image uwu".as_bytes().to_vec().try_into().unwrap()
+				),
+			}
+		));
+
+		assert_ok!(UserProfile::set_eth_address(
+			RuntimeOrigin::signed(lab),
+			EthereumAddress([b'X'; 20])
+		));
+
+		let prices_by_currency_dbio = PriceByCurrency {
+			currency: CurrencyType::DBIO,
+			total_price: 40,
+			price_components: vec![Price {
+				component: b"testing_price".to_vec().try_into().unwrap(),
+				value: 20,
+			}]
+			.try_into()
+			.unwrap(),
+			additional_prices: vec![Price {
+				component: b"qc_price".to_vec().try_into().unwrap(),
+				value: 20,
+			}]
+			.try_into()
+			.unwrap(),
+		};
+
+		let other_prices_by_currency_dbio = PriceByCurrency {
+			currency: CurrencyType::DBIO,
+			total_price: 20,
+			price_components: vec![Price {
+				component: b"testing_price".to_vec().try_into().unwrap(),
+				value: 10,
+			}]
+			.try_into()
+			.unwrap(),
+			additional_prices: vec![Price {
+				component: b"qc_price".to_vec().try_into().unwrap(),
+				value: 10,
+			}]
+			.try_into()
+			.unwrap(),
+		};
+
+		for prices_by_currency in [prices_by_currency_dbio, other_prices_by_currency_dbio] {
+			assert_ok!(Services::create_service(
+				RuntimeOrigin::signed(lab),
+				ServiceInfo {
+					name: "DeBio name".as_bytes().to_vec().try_into().unwrap(),
+					prices_by_currency: vec![prices_by_currency].try_into().unwrap(),
+					expected_duration: ExpectedDuration::default(),
+					category: "DeBio category".as_bytes().to_vec().try_into().unwrap(),
+					description: "This is my description".as_bytes().to_vec().try_into().unwrap(),
+					test_result_sample: "Test result sample"
+						.as_bytes()
+						.to_vec()
+						.try_into()
+						.unwrap(),
+					dna_collection_process: "Dna Collection Process"
+						.as_bytes()
+						.to_vec()
+						.try_into()
+						.unwrap(),
+					long_description: Some(
+						"This is my long description".as_bytes().to_vec().try_into().unwrap()
+					),
+					image: Some("This is my image".as_bytes().to_vec().try_into().unwrap()),
+				},
+				ServiceFlow::default()
+			));
+		}
+
+		let _lab = Labs::lab_by_account_id(lab).unwrap();
+
+		assert_ok!(Orders::create_bundle_order(
+			RuntimeOrigin::signed(customer),
+			vec![(_lab.services[0], 0), (_lab.services[1], 0)],
+			Keccak256::hash("0xhJ7TRe456FADD2726A132ABJK5RCc9E6fC5869F4".as_bytes()),
+			ServiceFlow::StakingRequestService,
+			None,
+		));
+
+		let _order_id = Orders::last_order_by_customer_id(customer).unwrap();
+		let _order_items = Orders::order_items_by_order_id(_order_id).unwrap();
+
+		assert_ok!(Orders::set_order_paid(RuntimeOrigin::signed(customer), _order_id));
+
+		assert_eq!(Balances::free_balance(customer), 140);
+		assert_eq!(Balances::free_balance(pallet_id), 61);
+
+		assert_ok!(GeneticTesting::reject_dna_sample(
+			RuntimeOrigin::signed(lab),
+			_order_items[0].dna_sample_tracking_id.clone(),
+			"Rejected title".as_bytes().to_vec(),
+			"Rejected description".as_bytes().to_vec(),
+		));
+
+		System::assert_has_event(RuntimeEvent::Orders(crate::Event::OrderPartiallyRefunded(
+			Orders::order_by_id(&_order_id).unwrap(),
+		)));
+
+		assert_eq!(Orders::order_by_id(&_order_id).unwrap().status, OrderStatus::Paid);
+		assert_eq!(
+			Orders::order_items_by_order_id(_order_id).unwrap()[0].status,
+			OrderStatus::Failed
+		);
+		assert_eq!(Orders::pending_orders_by_seller_id(&lab), vec![_order_id]);
+
+		assert_eq!(Balances::free_balance(customer), 160);
+		assert_eq!(Balances::free_balance(lab), 320);
+		assert_eq!(Balances::free_balance(pallet_id), 21);
+
+		assert_ok!(GeneticTesting::submit_test_result(
+			RuntimeOrigin::signed(lab),
+			_order_items[1].dna_sample_tracking_id.clone(),
+			DnaTestResultSubmission {
+				comments: Some("comment".as_bytes().to_vec().try_into().unwrap()),
+				result_link: Some("result_link".as_bytes().to_vec().try_into().unwrap()),
+				report_link: Some("report_link".as_bytes().to_vec().try_into().unwrap()),
+			}
+		));
+
+		assert_ok!(GeneticTesting::process_dna_sample(
+			RuntimeOrigin::signed(lab),
+			_order_items[1].dna_sample_tracking_id.clone(),
+			DnaSampleStatus::ResultReady,
+		));
+
+		assert_eq!(Orders::pending_orders_by_seller_id(&lab), vec![]);
+
+		assert_ok!(Orders::fulfill_order(RuntimeOrigin::signed(lab), _order_id));
+
+		let _order_items = Orders::order_items_by_order_id(_order_id).unwrap();
+
+		assert_eq!(Orders::order_by_id(&_order_id).unwrap().status, OrderStatus::Fulfilled);
+		assert_eq!(_order_items[0].status, OrderStatus::Failed);
+		assert_eq!(_order_items[1].status, OrderStatus::Fulfilled);
+
+		assert_eq!(Balances::free_balance(customer), 160);
+		assert_eq!(Balances::free_balance(lab), 339);
+		assert_eq!(Balances::free_balance(pallet_id), 1);
+		assert_eq!(Balances::free_balance(treasury_key), 401);
+	})
+}
+
+#[test]
+fn bundle_order_holds_rejected_item_refunds_until_the_dispute_window_closes() {
+	<ExternalityBuilder>::default().existential_deposit(1).build().execute_with(|| {
+		System::set_block_number(1);
+
+		DisputeWindow::set(1000);
+
+		let lab = account_key("lab");
+		let customer = account_key("customer");
+		let pallet_id = account_key("pallet_id");
+		let treasury_key = account_key("treasury_key");
+
+		PalletAccount::<Test>::put(pallet_id);
+		TreasuryKey::<Test>::put(treasury_key);
+
+		assert_ok!(Labs::register_lab(
+			RuntimeOrigin::signed(lab),
+			LabInfo {
+				box_public_key: Keccak256::hash(
+					"0xDb9Af2d1f3ADD2726A132AA7A65Cc9E6fC5761C3".as_bytes()
+				),
+				name: "DeBio Lab".as_bytes().to_vec().try_into().unwrap(),
+				email: "DeBio Email".as_bytes().to_vec().try_into().unwrap(),
+				country: CountryCode::try_from("ID".as_bytes().to_vec()).unwrap(),
+				region: RegionCode::try_from("JB".as_bytes().to_vec()).unwrap(),
+				city: CityCode::try_from("CITY".as_bytes().to_vec()).unwrap(),
+				address: "DeBio Address".as_bytes().to_vec().try_into().unwrap(),
+				phone_number: "+6281394653625".as_bytes().to_vec().try_into().unwrap(),
+				website: "DeBio Website".as_bytes().to_vec().try_into().unwrap(),
+				latitude: Some("-6.175392".as_bytes().to_vec().try_into().unwrap()),
+				longitude: Some("106.827153".as_bytes().to_vec().try_into().unwrap()),
+				profile_image: Some(
+					"DeBio Profile Image uwu".as_bytes().to_vec().try_into().unwrap()
+				),
+			}
+		));
+
+		assert_ok!(UserProfile::set_eth_address(
+			RuntimeOrigin::signed(lab),
+			EthereumAddress([b'X'; 20])
+		));
+
+		let prices_by_currency_dbio = PriceByCurrency {
+			currency: CurrencyType::DBIO,
+			total_price: 40,
+			price_components: vec![Price {
+				component: b"testing_price".to_vec().try_into().unwrap(),
+				value: 20,
+			}]
+			.try_into()
+			.unwrap(),
+			additional_prices: vec![Price {
+				component: b"qc_price".to_vec().try_into().unwrap(),
+				value: 20,
+			}]
+			.try_into()
+			.unwrap(),
+		};
+
+		let other_prices_by_currency_dbio = PriceByCurrency {
+			currency: CurrencyType::DBIO,
+			total_price: 20,
+			price_components: vec![Price {
+				component: b"testing_price".to_vec().try_into().unwrap(),
+				value: 10,
+			}]
+			.try_into()
+			.unwrap(),
+			additional_prices: vec![Price {
+				component: b"qc_price".to_vec().try_into().unwrap(),
+				value: 10,
+			}]
+			.try_into()
+			.unwrap(),
+		};
+
+		for prices_by_currency in [prices_by_currency_dbio, other_prices_by_currency_dbio] {
+			assert_ok!(Services::create_service(
+				RuntimeOrigin::signed(lab),
+				ServiceInfo {
+					name: "DeBio name".as_bytes().to_vec().try_into().unwrap(),
+					prices_by_currency: vec![prices_by_currency].try_into().unwrap(),
+					expected_duration: ExpectedDuration::default(),
+					category: "DeBio category".as_bytes().to_vec().try_into().unwrap(),
+					description: "This is my description".as_bytes().to_vec().try_into().unwrap(),
+					test_result_sample: "Test result sample"
+						.as_bytes()
+						.to_vec()
+						.try_into()
+						.unwrap(),
+					dna_collection_process: "Dna Collection Process"
+						.as_bytes()
+						.to_vec()
+						.try_into()
+						.unwrap(),
+					long_description: Some(
+						"This is my long description".as_bytes().to_vec().try_into().unwrap()
+					),
+					image: Some("This is my image".as_bytes().to_vec().try_into().unwrap()),
+				},
+				ServiceFlow::default()
+			));
+		}
+
+		let _lab = Labs::lab_by_account_id(lab).unwrap();
+
+		assert_ok!(Orders::create_bundle_order(
+			RuntimeOrigin::signed(customer),
+			vec![(_lab.services[0], 0), (_lab.services[1], 0)],
+			Keccak256::hash("0xhJ7TRe456FADD2726A132ABJK5RCc9E6fC5869F4".as_bytes()),
+			ServiceFlow::StakingRequestService,
+			None,
+		));
+
+		let _order_id = Orders::last_order_by_customer_id(customer).unwrap();
+		let _order_items = Orders::order_items_by_order_id(_order_id).unwrap();
+
+		assert_ok!(Orders::set_order_paid(RuntimeOrigin::signed(customer), _order_id));
+
+		assert_eq!(Balances::free_balance(customer), 140);
+		assert_eq!(Balances::free_balance(pallet_id), 61);
+
+		assert_ok!(GeneticTesting::reject_dna_sample(
+			RuntimeOrigin::signed(lab),
+			_order_items[0].dna_sample_tracking_id.clone(),
+			"Rejected title".as_bytes().to_vec(),
+			"Rejected description".as_bytes().to_vec(),
+		));
+
+		System::assert_has_event(RuntimeEvent::Orders(crate::Event::OrderPartiallyRefunded(
+			Orders::order_by_id(&_order_id).unwrap(),
+		)));
+
+		assert_eq!(Orders::order_by_id(&_order_id).unwrap().status, OrderStatus::Paid);
+		assert_eq!(
+			Orders::order_items_by_order_id(_order_id).unwrap()[0].status,
+			OrderStatus::Failed
+		);
+		assert_eq!(Orders::pending_orders_by_seller_id(&lab), vec![_order_id]);
+
+		// The refund waits in the settlement of the order instead of being paid out
+		assert_eq!(Balances::free_balance(customer), 140);
+		assert_eq!(Balances::free_balance(lab), 300);
+		assert_eq!(Balances::free_balance(pallet_id), 61);
+		assert!(Orders::order_settlement_by_order_id(_order_id).is_some());
+
+		assert_noop!(
+			Orders::open_dispute(
+				RuntimeOrigin::signed(customer),
+				_order_id,
+				"Sample was not damaged".as_bytes().to_vec()
+			),
+			Error::<Test>::OrderCannotBeDisputed
+		);
+
+		assert_ok!(GeneticTesting::submit_test_result(
+			RuntimeOrigin::signed(lab),
+			_order_items[1].dna_sample_tracking_id.clone(),
+			DnaTestResultSubmission {
+				comments: Some("comment".as_bytes().to_vec().try_into().unwrap()),
+				result_link: Some("result_link".as_bytes().to_vec().try_into().unwrap()),
+				report_link: Some("report_link".as_bytes().to_vec().try_into().unwrap()),
+			}
+		));
+
+		assert_ok!(GeneticTesting::process_dna_sample(
+			RuntimeOrigin::signed(lab),
+			_order_items[1].dna_sample_tracking_id.clone(),
+			DnaSampleStatus::ResultReady,
+		));
+
+		assert_eq!(Orders::pending_orders_by_seller_id(&lab), vec![]);
+
+		assert_ok!(Orders::fulfill_order(RuntimeOrigin::signed(lab), _order_id));
+
+		let _order_items = Orders::order_items_by_order_id(_order_id).unwrap();
+
+		assert_eq!(Orders::order_by_id(&_order_id).unwrap().status, OrderStatus::Fulfilled);
+		assert_eq!(_order_items[0].status, OrderStatus::Failed);
+		assert_eq!(_order_items[1].status, OrderStatus::Fulfilled);
+
+		// The payout joins the held refund and both wait for the dispute window
+		assert_eq!(Balances::free_balance(customer), 140);
+		assert_eq!(Balances::free_balance(lab), 300);
+		assert_eq!(Balances::free_balance(pallet_id), 61);
+
+		Timestamp::set_timestamp(1000);
+		Orders::on_idle(1, Weight::from_ref_time(u64::MAX));
+
+		assert_eq!(Orders::order_settlement_by_order_id(_order_id), None);
+		assert_eq!(Balances::free_balance(customer), 160);
+		assert_eq!(Balances::free_balance(lab), 339);
+		assert_eq!(Balances::free_balance(pallet_id), 1);
+		assert_eq!(Balances::free_balance(treasury_key), 401);
+	})
+}
+
+#[test]
+fn cant_refund_bundle_order_after_an_item_is_rejected() {
+	<ExternalityBuilder>::default().existential_deposit(1).build().execute_with(|| {
+		System::set_block_number(1);
+
+		let lab = account_key("lab");
+		let customer = account_key("customer");
+		let pallet_id = account_key("pallet_id");
+		let treasury_key = account_key("treasury_key");
+		let admin = account_key("admin");
+
+		PalletAccount::<Test>::put(pallet_id);
+		TreasuryKey::<Test>::put(treasury_key);
+		EscrowKey::<Test>::put(admin);
+
+		assert_ok!(Labs::register_lab(
+			RuntimeOrigin::signed(lab),
+			LabInfo {
+				box_public_key: Keccak256::hash(
+					"0xDb9Af2d1f3ADD2726A132AA7A65Cc9E6fC5761C3".as_bytes()
+				),
+				name: "DeBio Lab".as_bytes().to_vec().try_into().unwrap(),
+				email: "DeBio Email".as_bytes().to_vec().try_into().unwrap(),
+				country: CountryCode::try_from("ID".as_bytes().to_vec()).unwrap(),
+				region: RegionCode::try_from("JB".as_bytes().to_vec()).unwrap(),
+				city: CityCode::try_from("CITY".as_bytes().to_vec()).unwrap(),
+				address: "DeBio Address".as_bytes().to_vec().try_into().unwrap(),
+				phone_number: "+6281394653625".as_bytes().to_vec().try_into().unwrap(),
+				website: "DeBio Website".as_bytes().to_vec().try_into().unwrap(),
+				latitude: Some("-6.175392".as_bytes().to_vec().try_into().unwrap()),
+				longitude: Some("106.827153".as_bytes().to_vec().try_into().unwrap()),
+				profile_image: Some(
+					"DeBio Profile Image uwu".as_bytes().to_vec().try_into().unwrap()
+				),
+			}
+		));
+
+		assert_ok!(UserProfile::set_eth_address(
+			RuntimeOrigin::signed(lab),
+			EthereumAddress([b'X'; 20])
+		));
+
+		let prices_by_currency_dbio = PriceByCurrency {
+			currency: CurrencyType::DBIO,
+			total_price: 40,
+			price_components: vec![Price {
+				component: b"testing_price".to_vec().try_into().unwrap(),
+				value: 20,
+			}]
+			.try_into()
+			.unwrap(),
+			additional_prices: vec![Price {
+				component: b"qc_price".to_vec().try_into().unwrap(),
+				value: 20,
+			}]
+			.try_into()
+			.unwrap(),
+		};
+
+		let other_prices_by_currency_dbio = PriceByCurrency {
+			currency: CurrencyType::DBIO,
+			total_price: 20,
+			price_components: vec![Price {
+				component: b"testing_price".to_vec().try_into().unwrap(),
+				value: 10,
+			}]
+			.try_into()
+			.unwrap(),
+			additional_prices: vec![Price {
+				component: b"qc_price".to_vec().try_into().unwrap(),
+				value: 10,
+			}]
+			.try_into()
+			.unwrap(),
+		};
+
+		for prices_by_currency in [prices_by_currency_dbio, other_prices_by_currency_dbio] {
+			assert_ok!(Services::create_service(
+				RuntimeOrigin::signed(lab),
+				ServiceInfo {
+					name: "DeBio name".as_bytes().to_vec().try_into().unwrap(),
+					prices_by_currency: vec![prices_by_currency].try_into().unwrap(),
+					expected_duration: ExpectedDuration::default(),
+					category: "DeBio category".as_bytes().to_vec().try_into().unwrap(),
+					description: "This is my description".as_bytes().to_vec().try_into().unwrap(),
+					test_result_sample: "Test result sample"
+						.as_bytes()
+						.to_vec()
+						.try_into()
+						.unwrap(),
+					dna_collection_process: "Dna Collection Process"
+						.as_bytes()
+						.to_vec()
+						.try_into()
+						.unwrap(),
+					long_description: Some(
+						"This is my long description".as_bytes().to_vec().try_into().unwrap()
+					),
+					image: Some("This is my image".as_bytes().to_vec().try_into().unwrap()),
+				},
+				ServiceFlow::default()
+			));
+		}
+
+		let _lab = Labs::lab_by_account_id(lab).unwrap();
+
+		assert_ok!(Orders::create_bundle_order(
+			RuntimeOrigin::signed(customer),
+			vec![(_lab.services[0], 0), (_lab.services[1], 0)],
+			Keccak256::hash("0xhJ7TRe456FADD2726A132ABJK5RCc9E6fC5869F4".as_bytes()),
+			ServiceFlow::StakingRequestService,
+			None,
+		));
+
+		let _order_id = Orders::last_order_by_customer_id(customer).unwrap();
+		let _order_items = Orders::order_items_by_order_id(_order_id).unwrap();
+
+		assert_ok!(Orders::set_order_paid(RuntimeOrigin::signed(customer), _order_id));
+
+		assert_eq!(Balances::free_balance(customer), 140);
+		assert_eq!(Balances::free_balance(pallet_id), 61);
+
+		assert_ok!(GeneticTesting::reject_dna_sample(
+			RuntimeOrigin::signed(lab),
+			_order_items[0].dna_sample_tracking_id.clone(),
+			"Rejected title".as_bytes().to_vec(),
+			"Rejected description".as_bytes().to_vec(),
+		));
+
+		assert_eq!(Balances::free_balance(customer), 160);
+		assert_eq!(Balances::free_balance(pallet_id), 21);
+
+		// The rejected item is already refunded and the other item is still in the lab
+		assert_noop!(
+			Orders::set_order_refunded(RuntimeOrigin::signed(admin), _order_id),
+			Error::<Test>::OrderCannotBeRefunded
+		);
+	})
+}
+
+#[test]
+fn claim_expired_refund_of_bundle_order_skips_rejected_items() {
+	<ExternalityBuilder>::default().existential_deposit(1).build().execute_with(|| {
+		System::set_block_number(1);
+
+		let lab = account_key("lab");
+		let customer = account_key("customer");
+		let pallet_id = account_key("pallet_id");
+		let treasury_key = account_key("treasury_key");
+
+		PalletAccount::<Test>::put(pallet_id);
+		TreasuryKey::<Test>::put(treasury_key);
+
+		assert_ok!(Labs::register_lab(
+			RuntimeOrigin::signed(lab),
+			LabInfo {
+				box_public_key: Keccak256::hash(
+					"0xDb9Af2d1f3ADD2726A132AA7A65Cc9E6fC5761C3".as_bytes()
+				),
+				name: "DeBio Lab".as_bytes().to_vec().try_into().unwrap(),
+				email: "DeBio Email".as_bytes().to_vec().try_into().unwrap(),
+				country: CountryCode::try_from("ID".as_bytes().to_vec()).unwrap(),
+				region: RegionCode::try_from("JB".as_bytes().to_vec()).unwrap(),
+				city: CityCode::try_from("CITY".as_bytes().to_vec()).unwrap(),
+				address: "DeBio Address".as_bytes().to_vec().try_into().unwrap(),
+				phone_number: "+6281394653625".as_bytes().to_vec().try_into().unwrap(),
+				website: "DeBio Website".as_bytes().to_vec().try_into().unwrap(),
+				latitude: Some("-6.175392".as_bytes().to_vec().try_into().unwrap()),
+				longitude: Some("106.827153".as_bytes().to_vec().try_into().unwrap()),
+				profile_image: Some(
+					"DeBio Profile Image uwu".as_bytes().to_vec().try_into().unwrap()
+				),
+			}
+		));
+
+		assert_ok!(UserProfile::set_eth_address(
+			RuntimeOrigin::signed(lab),
+			EthereumAddress([b'X'; 20])
+		));
+
+		let prices_by_currency_dbio = PriceByCurrency {
+			currency: CurrencyType::DBIO,
+			total_price: 40,
+			price_components: vec![Price {
+				component: b"testing_price".to_vec().try_into().unwrap(),
+				value: 20,
+			}]
+			.try_into()
+			.unwrap(),
+			additional_prices: vec![Price {
+				component: b"qc_price".to_vec().try_into().unwrap(),
+				value: 20,
+			}]
+			.try_into()
+			.unwrap(),
+		};
+
+		let other_prices_by_currency_dbio = PriceByCurrency {
+			currency: CurrencyType::DBIO,
+			total_price: 20,
+			price_components: vec![Price {
+				component: b"testing_price".to_vec().try_into().unwrap(),
+				value: 10,
+			}]
+			.try_into()
+			.unwrap(),
+			additional_prices: vec![Price {
+				component: b"qc_price".to_vec().try_into().unwrap(),
+				value: 10,
+			}]
+			.try_into()
+			.unwrap(),
+		};
+
+		for prices_by_currency in [prices_by_currency_dbio, other_prices_by_currency_dbio] {
+			assert_ok!(Services::create_service(
+				RuntimeOrigin::signed(lab),
+				ServiceInfo {
+					name: "DeBio name".as_bytes().to_vec().try_into().unwrap(),
+					prices_by_currency: vec![prices_by_currency].try_into().unwrap(),
+					expected_duration: ExpectedDuration::default(),
+					category: "DeBio category".as_bytes().to_vec().try_into().unwrap(),
+					description: "This is my description".as_bytes().to_vec().try_into().unwrap(),
+					test_result_sample: "Test result sample"
+						.as_bytes()
+						.to_vec()
+						.try_into()
+						.unwrap(),
+					dna_collection_process: "Dna Collection Process"
+						.as_bytes()
+						.to_vec()
+						.try_into()
+						.unwrap(),
+					long_description: Some(
+						"This is my long description".as_bytes().to_vec().try_into().unwrap()
+					),
+					image: Some("This is my image".as_bytes().to_vec().try_into().unwrap()),
+				},
+				ServiceFlow::default()
+			));
+		}
+
+		let _lab = Labs::lab_by_account_id(lab).unwrap();
+
+		assert_ok!(Orders::create_bundle_order(
+			RuntimeOrigin::signed(customer),
+			vec![(_lab.services[0], 0), (_lab.services[1], 0)],
+			Keccak256::hash("0xhJ7TRe456FADD2726A132ABJK5RCc9E6fC5869F4".as_bytes()),
+			ServiceFlow::StakingRequestService,
+			None,
+		));
+
+		let _order_id = Orders::last_order_by_customer_id(customer).unwrap();
+		let _order_items = Orders::order_items_by_order_id(_order_id).unwrap();
+
+		assert_ok!(Orders::set_order_paid(RuntimeOrigin::signed(customer), _order_id));
+
+		assert_eq!(Balances::free_balance(customer), 140);
+		assert_eq!(Balances::free_balance(pallet_id), 61);
+
+		assert_ok!(GeneticTesting::reject_dna_sample(
+			RuntimeOrigin::signed(lab),
+			_order_items[0].dna_sample_tracking_id.clone(),
+			"Rejected title".as_bytes().to_vec(),
+			"Rejected description".as_bytes().to_vec(),
+		));
+
+		assert_eq!(Balances::free_balance(customer), 160);
+		assert_eq!(Balances::free_balance(pallet_id), 21);
+
+		let deadline = Orders::refund_deadline_by_order_id(_order_id).unwrap();
+		Timestamp::set_timestamp(deadline);
+
+		// Only the item still waiting for its sample is refunded
+		assert_ok!(Orders::claim_expired_refund(
+			RuntimeOrigin::signed(account_key("other")),
+			_order_id
+		));
+
+		assert_eq!(Orders::order_by_id(&_order_id).unwrap().status, OrderStatus::Refunded);
+		assert_eq!(
+			Orders::order_items_by_order_id(_order_id).unwrap()[0].status,
+			OrderStatus::Failed
+		);
+		assert_eq!(Balances::free_balance(customer), 180);
+		assert_eq!(Balances::free_balance(lab), 320);
+		assert_eq!(Balances::free_balance(pallet_id), 1);
+	})
+}
+
+#[test]
+fn bundle_order_item_stays_paid_when_refund_fails() {
+	<ExternalityBuilder>::default().existential_deposit(1).build().execute_with(|| {
+		System::set_block_number(1);
+
+		let lab = account_key("lab");
+		let customer = account_key("customer");
+		let pallet_id = account_key("pallet_id");
+		let treasury_key = account_key("treasury_key");
+
+		PalletAccount::<Test>::put(pallet_id);
+		TreasuryKey::<Test>::put(treasury_key);
+
+		assert_ok!(Labs::register_lab(
+			RuntimeOrigin::signed(lab),
+			LabInfo {
+				box_public_key: Keccak256::hash(
+					"0xDb9Af2d1f3ADD2726A132AA7A65Cc9E6fC5761C3".as_bytes()
+				),
+				name: "DeBio Lab".as_bytes().to_vec().try_into().unwrap(),
+				email: "DeBio Email".as_bytes().to_vec().try_into().unwrap(),
+				country: CountryCode::try_from("ID".as_bytes().to_vec()).unwrap(),
+				region: RegionCode::try_from("JB".as_bytes().to_vec()).unwrap(),
+				city: CityCode::try_from("CITY".as_bytes().to_vec()).unwrap(),
+				address: "DeBio Address".as_bytes().to_vec().try_into().unwrap(),
+				phone_number: "+6281394653625".as_bytes().to_vec().try_into().unwrap(),
+				website: "DeBio Website".as_bytes().to_vec().try_into().unwrap(),
+				latitude: Some("-6.175392".as_bytes().to_vec().try_into().unwrap()),
+				longitude: Some("106.827153".as_bytes().to_vec().try_into().unwrap()),
+				profile_image: Some(
+					"DeBio Profile Image uwu".as_bytes().to_vec().try_into().unwrap()
+				),
+			}
+		));
+
+		assert_ok!(UserProfile::set_eth_address(
+			RuntimeOrigin::signed(lab),
+			EthereumAddress([b'X'; 20])
+		));
+
+		let prices_by_currency_dbio = PriceByCurrency {
+			currency: CurrencyType::DBIO,
+			total_price: 40,
+			price_components: vec![Price {
+				component: b"testing_price".to_vec().try_into().unwrap(),
+				value: 20,
+			}]
+			.try_into()
+			.unwrap(),
+			additional_prices: vec![Price {
+				component: b"qc_price".to_vec().try_into().unwrap(),
+				value: 20,
+			}]
+			.try_into()
+			.unwrap(),
+		};
+
+		let other_prices_by_currency_dbio = PriceByCurrency {
+			currency: CurrencyType::DBIO,
+			total_price: 20,
+			price_components: vec![Price {
+				component: b"testing_price".to_vec().try_into().unwrap(),
+				value: 10,
+			}]
+			.try_into()
+			.unwrap(),
+			additional_prices: vec![Price {
+				component: b"qc_price".to_vec().try_into().unwrap(),
+				value: 10,
+			}]
+			.try_into()
+			.unwrap(),
+		};
+
+		for prices_by_currency in [prices_by_currency_dbio, other_prices_by_currency_dbio] {
+			assert_ok!(Services::create_service(
+				RuntimeOrigin::signed(lab),
+				ServiceInfo {
+					name: "DeBio name".as_bytes().to_vec().try_into().unwrap(),
+					prices_by_currency: vec![prices_by_currency].try_into().unwrap(),
+					expected_duration: ExpectedDuration::default(),
+					category: "DeBio category".as_bytes().to_vec().try_into().unwrap(),
+					description: "This is my description".as_bytes().to_vec().try_into().unwrap(),
+					test_result_sample: "Test result sample"
+						.as_bytes()
+						.to_vec()
+						.try_into()
+						.unwrap(),
+					dna_collection_process: "Dna Collection Process"
+						.as_bytes()
+						.to_vec()
+						.try_into()
+						.unwrap(),
+					long_description: Some(
+						"This is my long description".as_bytes().to_vec().try_into().unwrap()
+					),
+					image: Some("This is my image".as_bytes().to_vec().try_into().unwrap()),
+				},
+				ServiceFlow::default()
+			));
+		}
+
+		let _lab = Labs::lab_by_account_id(lab).unwrap();
+
+		assert_ok!(Orders::create_bundle_order(
+			RuntimeOrigin::signed(customer),
+			vec![(_lab.services[0], 0), (_lab.services[1], 0)],
+			Keccak256::hash("0xhJ7TRe456FADD2726A132ABJK5RCc9E6fC5869F4".as_bytes()),
+			ServiceFlow::StakingRequestService,
+			None,
+		));
+
+		let _order_id = Orders::last_order_by_customer_id(customer).unwrap();
+		let _order_items = Orders::order_items_by_order_id(_order_id).unwrap();
+
+		assert_ok!(Orders::set_order_paid(RuntimeOrigin::signed(customer), _order_id));
+
+		assert_eq!(Balances::free_balance(customer), 140);
+		assert_eq!(Balances::free_balance(pallet_id), 61);
+
+		// Refunds are paid from an account without funds
+		PalletAccount::<Test>::put(account_key("empty_pallet_id"));
+
+		assert_ok!(GeneticTesting::reject_dna_sample(
+			RuntimeOrigin::signed(lab),
+			_order_items[0].dna_sample_tracking_id.clone(),
+			"Rejected title".as_bytes().to_vec(),
+			"Rejected description".as_bytes().to_vec(),
+		));
+
+		System::assert_has_event(RuntimeEvent::Orders(crate::Event::OrderItemRefundFailed(
+			Orders::order_by_id(&_order_id).unwrap(),
+			_order_items[0].dna_sample_tracking_id.clone(),
+		)));
+
+		assert_eq!(
+			Orders::order_items_by_order_id(_order_id).unwrap()[0].status,
+			OrderStatus::Paid
+		);
+		assert_eq!(Balances::free_balance(customer), 140);
+		assert_eq!(Balances::free_balance(pallet_id), 61);
+
+		PalletAccount::<Test>::put(pallet_id);
+
+		// The failed refund is retried with the next rejected item
+		assert_ok!(GeneticTesting::reject_dna_sample(
+			RuntimeOrigin::signed(lab),
+			_order_items[1].dna_sample_tracking_id.clone(),
+			"Rejected title".as_bytes().to_vec(),
+			"Rejected description".as_bytes().to_vec(),
+		));
+
+		let _order_items = Orders::order_items_by_order_id(_order_id).unwrap();
+
+		assert_eq!(_order_items[0].status, OrderStatus::Failed);
+		assert_eq!(_order_items[1].status, OrderStatus::Failed);
+		assert_eq!(Orders::order_by_id(&_order_id).unwrap().status, OrderStatus::Failed);
+		assert_eq!(Balances::free_balance(customer), 170);
+		assert_eq!(Balances::free_balance(pallet_id), 1);
+	})
+}
+
+#[test]
+fn cant_create_bundle_order_when_empty() {
+	<ExternalityBuilder>::default().existential_deposit(1).build().execute_with(|| {
+		assert_noop!(
+			Orders::create_bundle_order(
+				RuntimeOrigin::signed(account_key("customer")),
+				vec![],
+				Keccak256::hash("0xhJ7TRe456FADD2726A132ABJK5RCc9E6fC5869F4".as_bytes()),
+				ServiceFlow::StakingRequestService,
+				None,
+			),
+			Error::<Test>::EmptyBundleOrder
+		);
+	})
+}
+
+#[test]
+fn cant_create_bundle_order_when_sellers_differ() {
+	<ExternalityBuilder>::default().existential_deposit(1).build().execute_with(|| {
+		let customer = account_key("customer");
+		let lab = account_key("lab");
+		let other_lab = account_key("other_lab");
+
+		for lab in [lab, other_lab] {
+			assert_ok!(Labs::register_lab(
+				RuntimeOrigin::signed(lab),
+				LabInfo {
+					box_public_key: Keccak256::hash(
+						"0xDb9Af2d1f3ADD2726A132AA7A65Cc9E6fC5761C3".as_bytes()
+					),
+					name: "DeBio Lab".as_bytes().to_vec().try_into().unwrap(),
+					email: "DeBio Email".as_bytes().to_vec().try_into().unwrap(),
+					country: CountryCode::try_from("ID".as_bytes().to_vec()).unwrap(),
+					region: RegionCode::try_from("JB".as_bytes().to_vec()).unwrap(),
+					city: CityCode::try_from("CITY".as_bytes().to_vec()).unwrap(),
+					address: "DeBio Address".as_bytes().to_vec().try_into().unwrap(),
+					phone_number: "+6281394653625".as_bytes().to_vec().try_into().unwrap(),
+					website: "DeBio Website".as_bytes().to_vec().try_into().unwrap(),
+					latitude: Some("-6.175392".as_bytes().to_vec().try_into().unwrap()),
+					longitude: Some("106.827153".as_bytes().to_vec().try_into().unwrap()),
+					profile_image: Some(
+						"DeBio Profile Image uwu".as_bytes().to_vec().try_into().unwrap()
+					),
+				}
+			));
+
+			assert_ok!(UserProfile::set_eth_address(
+				RuntimeOrigin::signed(lab),
+				EthereumAddress([b'X'; 20])
+			));
+
+			assert_ok!(Services::create_service(
+				RuntimeOrigin::signed(lab),
+				ServiceInfo {
+					name: "DeBio name".as_bytes().to_vec().try_into().unwrap(),
+					prices_by_currency: vec![PriceByCurrency {
+						currency: CurrencyType::DBIO,
+						total_price: 20,
+						price_components: vec![Price {
+							component: b"testing_price".to_vec().try_into().unwrap(),
+							value: 10
+						}]
+						.try_into()
+						.unwrap(),
+						additional_prices: vec![Price {
+							component: b"qc_price".to_vec().try_into().unwrap(),
+							value: 10
+						}]
+						.try_into()
+						.unwrap(),
+					}]
+					.try_into()
+					.unwrap(),
+					expected_duration: ExpectedDuration::default(),
+					category: "DeBio category".as_bytes().to_vec().try_into().unwrap(),
+					description: "This is my description".as_bytes().to_vec().try_into().unwrap(),
+					test_result_sample: "Test result sample"
+						.as_bytes()
+						.to_vec()
+						.try_into()
+						.unwrap(),
+					dna_collection_process: "Dna Collection Process"
+						.as_bytes()
+						.to_vec()
+						.try_into()
+						.unwrap(),
+					long_description: Some(
+						"This is my long description".as_bytes().to_vec().try_into().unwrap()
+					),
+					image: Some("This is my image".as_bytes().to_vec().try_into().unwrap()),
+				},
+				ServiceFlow::default()
+			));
+		}
+
+		let _lab = Labs::lab_by_account_id(lab).unwrap();
+		let _other_lab = Labs::lab_by_account_id(other_lab).unwrap();
+
+		assert_noop!(
+			Orders::create_bundle_order(
+				RuntimeOrigin::signed(customer),
+				vec![(_lab.services[0], 0), (_other_lab.services[0], 0)],
+				Keccak256::hash("0xhJ7TRe456FADD2726A132ABJK5RCc9E6fC5869F4".as_bytes()),
+				ServiceFlow::StakingRequestService,
+				None,
+			),
+			Error::<Test>::BundleSellerMismatch
+		);
+	})
+}
+
+#[test]
+fn open_and_resolve_dispute_works() {
+	<ExternalityBuilder>::default().existential_deposit(1).build().execute_with(|| {
+		System::set_block_number(1);
+
+		DisputeWindow::set(1000);
+
+		let lab = account_key("lab");
+		let customer = account_key("customer");
+		let pallet_id = account_key("pallet_id");
+		let treasury_key = account_key("treasury_key");
+
+		PalletAccount::<Test>::put(pallet_id);
+		TreasuryKey::<Test>::put(treasury_key);
+
+		assert_ok!(Labs::register_lab(
+			RuntimeOrigin::signed(lab),
+			LabInfo {
+				box_public_key: Keccak256::hash(
+					"0xDb9Af2d1f3ADD2726A132AA7A65Cc9E6fC5761C3".as_bytes()
+				),
+				name: "DeBio Lab".as_bytes().to_vec().try_into().unwrap(),
+				email: "DeBio Email".as_bytes().to_vec().try_into().unwrap(),
+				country: CountryCode::try_from("ID".as_bytes().to_vec()).unwrap(),
+				region: RegionCode::try_from("JB".as_bytes().to_vec()).unwrap(),
+				city: CityCode::try_from("CITY".as_bytes().to_vec()).unwrap(),
+				address: "DeBio Address".as_bytes().to_vec().try_into().unwrap(),
+				phone_number: "+6281394653625".as_bytes().to_vec().try_into().unwrap(),
+				website: "DeBio Website".as_bytes().to_vec().try_into().unwrap(),
+				latitude: Some("-6.175392".as_bytes().to_vec().try_into().unwrap()),
+				longitude: Some("106.827153".as_bytes().to_vec().try_into().unwrap()),
+				profile_image: Some(
+					"DeBio Profile Image uwu".as_bytes().to_vec().try_into().unwrap()
+				),
+			}
+		));
+
+		assert_ok!(UserProfile::set_eth_address(
+			RuntimeOrigin::signed(lab),
+			EthereumAddress([b'X'; 20])
+		));
+
+		let prices_by_currency_dbio = PriceByCurrency {
+			currency: CurrencyType::DBIO,
+			total_price: 30,
+			price_components: vec![Price {
+				component: b"testing_price".to_vec().try_into().unwrap(),
+				value: 20,
+			}]
+			.try_into()
+			.unwrap(),
+			additional_prices: vec![Price {
+				component: b"qc_price".to_vec().try_into().unwrap(),
+				value: 20,
+			}]
+			.try_into()
+			.unwrap(),
+		};
+
+		assert_ok!(Services::create_service(
+			RuntimeOrigin::signed(lab),
+			ServiceInfo {
+				name: "DeBio name".as_bytes().to_vec().try_into().unwrap(),
+				prices_by_currency: vec![prices_by_currency_dbio.clone()].try_into().unwrap(),
+				expected_duration: ExpectedDuration::default(),
+				category: "DeBio category".as_bytes().to_vec().try_into().unwrap(),
+				description: "This is my description".as_bytes().to_vec().try_into().unwrap(),
+				test_result_sample: "Test result sample".as_bytes().to_vec().try_into().unwrap(),
+				dna_collection_process: "Dna Collection Process"
+					.as_bytes()
+					.to_vec()
+					.try_into()
+					.unwrap(),
+				long_description: Some(
+					"This is my long description".as_bytes().to_vec().try_into().unwrap()
+				),
+				image: Some("This is my image".as_bytes().to_vec().try_into().unwrap()),
+			},
+			ServiceFlow::default()
+		));
+
+		let _lab = Labs::lab_by_account_id(lab).unwrap();
+
+		assert_ok!(Orders::create_order(
+			RuntimeOrigin::signed(customer),
+			_lab.services[0],
+			0,
+			Keccak256::hash("0xhJ7TRe456FADD2726A132ABJK5RCc9E6fC5869F4".as_bytes()),
+			ServiceFlow::StakingRequestService,
+			None,
+		));
+
+		let _order_id = Orders::last_order_by_customer_id(customer).unwrap();
+		let _dna_sample = GeneticTesting::dna_samples_by_lab_id(&lab);
+
+		assert_ok!(Orders::set_order_paid(RuntimeOrigin::signed(customer), _order_id));
+
+		assert_ok!(GeneticTesting::submit_test_result(
+			RuntimeOrigin::signed(lab),
+			_dna_sample[0].clone(),
+			DnaTestResultSubmission {
+				comments: Some("comment".as_bytes().to_vec().try_into().unwrap()),
+				result_link: Some("result_link".as_bytes().to_vec().try_into().unwrap()),
+				report_link: Some("report_link".as_bytes().to_vec().try_into().unwrap()),
+			}
+		));
+
+		assert_ok!(GeneticTesting::process_dna_sample(
+			RuntimeOrigin::signed(lab),
+			_dna_sample[0].clone(),
+			DnaSampleStatus::ResultReady,
+		));
+
+		assert_ok!(Orders::fulfill_order(RuntimeOrigin::signed(lab), _order_id));
+
+		assert_eq!(Balances::free_balance(lab), 300);
+		assert_eq!(Balances::free_balance(pallet_id), 41);
+		assert_eq!(Balances::free_balance(treasury_key), 400);
+
+		assert_ok!(Orders::open_dispute(
+			RuntimeOrigin::signed(customer),
+			_order_id,
+			"Result does not match the sample".as_bytes().to_vec()
+		));
+
+		System::assert_last_event(RuntimeEvent::Orders(crate::Event::DisputeOpened(
+			Orders::order_by_id(&_order_id).unwrap(),
+			customer,
+		)));
+
+		assert_eq!(Orders::order_by_id(&_order_id).unwrap().status, OrderStatus::Disputed);
+
+		assert_ok!(Orders::resolve_dispute(
+			RuntimeOrigin::root(),
+			_order_id,
+			Perbill::from_percent(50)
+		));
+
+		let resolution = DisputeResolution {
+			customer_share: Perbill::from_percent(50),
+			customer_amount: 19,
+			seller_amount: 19,
+		};
+
+		System::assert_last_event(RuntimeEvent::Orders(crate::Event::DisputeResolved(
+			Orders::order_by_id(&_order_id).unwrap(),
+			resolution.clone(),
+		)));
+
+		assert_eq!(Orders::order_by_id(&_order_id).unwrap().status, OrderStatus::Resolved);
+		assert_eq!(Orders::dispute_by_order_id(_order_id).unwrap().resolution, Some(resolution));
+		assert_eq!(Orders::order_settlement_by_order_id(_order_id), None);
+
+		assert_eq!(Balances::free_balance(customer), 179);
+		assert_eq!(Balances::free_balance(lab), 319);
+		assert_eq!(Balances::free_balance(pallet_id), 1);
+		assert_eq!(Balances::free_balance(treasury_key), 402);
+	})
+}
+
+#[test]
+fn release_order_settlement_works() {
+	<ExternalityBuilder>::default().existential_deposit(1).build().execute_with(|| {
+		DisputeWindow::set(1000);
+
+		let lab = account_key("lab");
+		let customer = account_key("customer");
+		let pallet_id = account_key("pallet_id");
+		let treasury_key = account_key("treasury_key");
+
+		PalletAccount::<Test>::put(pallet_id);
+		TreasuryKey::<Test>::put(treasury_key);
+
+		assert_ok!(Labs::register_lab(
+			RuntimeOrigin::signed(lab),
+			LabInfo {
+				box_public_key: Keccak256::hash(
+					"0xDb9Af2d1f3ADD2726A132AA7A65Cc9E6fC5761C3".as_bytes()
+				),
+				name: "DeBio Lab".as_bytes().to_vec().try_into().unwrap(),
+				email: "DeBio Email".as_bytes().to_vec().try_into().unwrap(),
+				country: CountryCode::try_from("ID".as_bytes().to_vec()).unwrap(),
+				region: RegionCode::try_from("JB".as_bytes().to_vec()).unwrap(),
+				city: CityCode::try_from("CITY".as_bytes().to_vec()).unwrap(),
+				address: "DeBio Address".as_bytes().to_vec().try_into().unwrap(),
+				phone_number: "+6281394653625".as_bytes().to_vec().try_into().unwrap(),
+				website: "DeBio Website".as_bytes().to_vec().try_into().unwrap(),
+				latitude: Some("-6.175392".as_bytes().to_vec().try_into().unwrap()),
+				longitude: Some("106.827153".as_bytes().to_vec().try_into().unwrap()),
+				profile_image: Some(
+					"DeBio Profile Image uwu".as_bytes().to_vec().try_into().unwrap()
+				),
+			}
+		));
+
+		assert_ok!(UserProfile::set_eth_address(
+			RuntimeOrigin::signed(lab),
+			EthereumAddress([b'X'; 20])
+		));
+
+		let prices_by_currency_dbio = PriceByCurrency {
+			currency: CurrencyType::DBIO,
+			total_price: 30,
+			price_components: vec![Price {
+				component: b"testing_price".to_vec().try_into().unwrap(),
+				value: 20,
+			}]
+			.try_into()
+			.unwrap(),
+			additional_prices: vec![Price {
+				component: b"qc_price".to_vec().try_into().unwrap(),
+				value: 20,
+			}]
+			.try_into()
+			.unwrap(),
+		};
+
+		assert_ok!(Services::create_service(
+			RuntimeOrigin::signed(lab),
+			ServiceInfo {
+				name: "DeBio name".as_bytes().to_vec().try_into().unwrap(),
+				prices_by_currency: vec![prices_by_currency_dbio.clone()].try_into().unwrap(),
+				expected_duration: ExpectedDuration::default(),
+				category: "DeBio category".as_bytes().to_vec().try_into().unwrap(),
+				description: "This is my description".as_bytes().to_vec().try_into().unwrap(),
+				test_result_sample: "Test result sample".as_bytes().to_vec().try_into().unwrap(),
+				dna_collection_process: "Dna Collection Process"
+					.as_bytes()
+					.to_vec()
+					.try_into()
+					.unwrap(),
+				long_description: Some(
+					"This is my long description".as_bytes().to_vec().try_into().unwrap()
+				),
+				image: Some("This is my image".as_bytes().to_vec().try_into().unwrap()),
+			},
+			ServiceFlow::default()
+		));
+
+		let _lab = Labs::lab_by_account_id(lab).unwrap();
+
+		assert_ok!(Orders::create_order(
+			RuntimeOrigin::signed(customer),
+			_lab.services[0],
+			0,
+			Keccak256::hash("0xhJ7TRe456FADD2726A132ABJK5RCc9E6fC5869F4".as_bytes()),
+			ServiceFlow::StakingRequestService,
+			None,
+		));
+
+		let _order_id = Orders::last_order_by_customer_id(customer).unwrap();
+		let _dna_sample = GeneticTesting::dna_samples_by_lab_id(&lab);
+
+		assert_ok!(Orders::set_order_paid(RuntimeOrigin::signed(customer), _order_id));
+
+		assert_ok!(GeneticTesting::submit_test_result(
+			RuntimeOrigin::signed(lab),
+			_dna_sample[0].clone(),
+			DnaTestResultSubmission {
+				comments: Some("comment".as_bytes().to_vec().try_into().unwrap()),
+				result_link: Some("result_link".as_bytes().to_vec().try_into().unwrap()),
+				report_link: Some("report_link".as_bytes().to_vec().try_into().unwrap()),
+			}
+		));
+
+		assert_ok!(GeneticTesting::process_dna_sample(
+			RuntimeOrigin::signed(lab),
+			_dna_sample[0].clone(),
+			DnaSampleStatus::ResultReady,
+		));
+
+		assert_ok!(Orders::fulfill_order(RuntimeOrigin::signed(lab), _order_id));
+
+		assert_noop!(
+			Orders::release_order_settlement(RuntimeOrigin::signed(customer), _order_id),
+			Error::<Test>::DisputeWindowNotYetClosed
+		);
+
+		Timestamp::set_timestamp(1000);
+
+		assert_ok!(Orders::release_order_settlement(RuntimeOrigin::signed(customer), _order_id));
+
+		assert_eq!(Orders::order_by_id(&_order_id).unwrap().status, OrderStatus::Fulfilled);
+		assert_eq!(Orders::order_settlement_by_order_id(_order_id), None);
+
+		assert_eq!(Balances::free_balance(customer), 160);
+		assert_eq!(Balances::free_balance(lab), 338);
+		assert_eq!(Balances::free_balance(pallet_id), 1);
+		assert_eq!(Balances::free_balance(treasury_key), 402);
+	})
+}
+
+#[test]
+fn order_settlement_released_on_idle_works() {
+	<ExternalityBuilder>::default().existential_deposit(1).build().execute_with(|| {
+		DisputeWindow::set(1000);
+
+		let lab = account_key("lab");
+		let customer = account_key("customer");
+		let pallet_id = account_key("pallet_id");
+		let treasury_key = account_key("treasury_key");
+
+		PalletAccount::<Test>::put(pallet_id);
+		TreasuryKey::<Test>::put(treasury_key);
+
+		assert_ok!(Labs::register_lab(
+			RuntimeOrigin::signed(lab),
+			LabInfo {
+				box_public_key: Keccak256::hash(
+					"0xDb9Af2d1f3ADD2726A132AA7A65Cc9E6fC5761C3".as_bytes()
+				),
+				name: "DeBio Lab".as_bytes().to_vec().try_into().unwrap(),
+				email: "DeBio Email".as_bytes().to_vec().try_into().unwrap(),
+				country: CountryCode::try_from("ID".as_bytes().to_vec()).unwrap(),
+				region: RegionCode::try_from("JB".as_bytes().to_vec()).unwrap(),
+				city: CityCode::try_from("CITY".as_bytes().to_vec()).unwrap(),
+				address: "DeBio Address".as_bytes().to_vec().try_into().unwrap(),
+				phone_number: "+6281394653625".as_bytes().to_vec().try_into().unwrap(),
+				website: "DeBio Website".as_bytes().to_vec().try_into().unwrap(),
+				latitude: Some("-6.175392".as_bytes().to_vec().try_into().unwrap()),
+				longitude: Some("106.827153".as_bytes().to_vec().try_into().unwrap()),
+				profile_image: Some(
+					"DeBio Profile Image uwu".as_bytes().to_vec().try_into().unwrap()
+				),
+			}
+		));
+
+		assert_ok!(UserProfile::set_eth_address(
+			RuntimeOrigin::signed(lab),
+			EthereumAddress([b'X'; 20])
+		));
+
+		let prices_by_currency_dbio = PriceByCurrency {
+			currency: CurrencyType::DBIO,
+			total_price: 30,
+			price_components: vec![Price {
+				component: b"testing_price".to_vec().try_into().unwrap(),
+				value: 20,
+			}]
+			.try_into()
+			.unwrap(),
+			additional_prices: vec![Price {
+				component: b"qc_price".to_vec().try_into().unwrap(),
+				value: 20,
+			}]
+			.try_into()
+			.unwrap(),
+		};
+
+		assert_ok!(Services::create_service(
+			RuntimeOrigin::signed(lab),
+			ServiceInfo {
+				name: "DeBio name".as_bytes().to_vec().try_into().unwrap(),
+				prices_by_currency: vec![prices_by_currency_dbio.clone()].try_into().unwrap(),
+				expected_duration: ExpectedDuration::default(),
+				category: "DeBio category".as_bytes().to_vec().try_into().unwrap(),
+				description: "This is my description".as_bytes().to_vec().try_into().unwrap(),
+				test_result_sample: "Test result sample".as_bytes().to_vec().try_into().unwrap(),
+				dna_collection_process: "Dna Collection Process"
+					.as_bytes()
+					.to_vec()
+					.try_into()
+					.unwrap(),
+				long_description: Some(
+					"This is my long description".as_bytes().to_vec().try_into().unwrap()
+				),
+				image: Some("This is my image".as_bytes().to_vec().try_into().unwrap()),
+			},
+			ServiceFlow::default()
+		));
+
+		let _lab = Labs::lab_by_account_id(lab).unwrap();
+
+		assert_ok!(Orders::create_order(
+			RuntimeOrigin::signed(customer),
+			_lab.services[0],
+			0,
+			Keccak256::hash("0xhJ7TRe456FADD2726A132ABJK5RCc9E6fC5869F4".as_bytes()),
+			ServiceFlow::StakingRequestService,
+			None,
+		));
+
+		let _order_id = Orders::last_order_by_customer_id(customer).unwrap();
+		let _dna_sample = GeneticTesting::dna_samples_by_lab_id(&lab);
+
+		assert_ok!(Orders::set_order_paid(RuntimeOrigin::signed(customer), _order_id));
+
+		assert_ok!(GeneticTesting::submit_test_result(
+			RuntimeOrigin::signed(lab),
+			_dna_sample[0].clone(),
+			DnaTestResultSubmission {
+				comments: Some("comment".as_bytes().to_vec().try_into().unwrap()),
+				result_link: Some("result_link".as_bytes().to_vec().try_into().unwrap()),
+				report_link: Some("report_link".as_bytes().to_vec().try_into().unwrap()),
+			}
+		));
+
+		assert_ok!(GeneticTesting::process_dna_sample(
+			RuntimeOrigin::signed(lab),
+			_dna_sample[0].clone(),
+			DnaSampleStatus::ResultReady,
+		));
+
+		assert_ok!(Orders::fulfill_order(RuntimeOrigin::signed(lab), _order_id));
+
+		Orders::on_idle(1, Weight::from_ref_time(u64::MAX));
+
+		assert!(Orders::order_settlement_by_order_id(_order_id).is_some());
+
+		Timestamp::set_timestamp(1000);
+
+		Orders::on_idle(1, Weight::from_ref_time(u64::MAX));
+
+		System::assert_last_event(RuntimeEvent::Orders(crate::Event::OrderSettlementReleased(
+			Orders::order_by_id(&_order_id).unwrap(),
+		)));
+
+		assert_eq!(Orders::order_settlement_by_order_id(_order_id), None);
+		assert_eq!(SettlementReleases::<Test>::iter().count(), 0);
+
+		assert_eq!(Balances::free_balance(customer), 160);
+		assert_eq!(Balances::free_balance(lab), 338);
+		assert_eq!(Balances::free_balance(pallet_id), 1);
+		assert_eq!(Balances::free_balance(treasury_key), 402);
+	})
+}
+
+#[test]
+fn failed_order_settles_through_dispute_window() {
+	<ExternalityBuilder>::default().existential_deposit(1).build().execute_with(|| {
+		System::set_block_number(1);
+
+		DisputeWindow::set(1000);
+
+		let lab = account_key("lab");
+		let customer = account_key("customer");
+		let pallet_id = account_key("pallet_id");
+		let treasury_key = account_key("treasury_key");
+
+		PalletAccount::<Test>::put(pallet_id);
+		TreasuryKey::<Test>::put(treasury_key);
+
+		assert_ok!(Labs::register_lab(
+			RuntimeOrigin::signed(lab),
+			LabInfo {
+				box_public_key: Keccak256::hash(
+					"0xDb9Af2d1f3ADD2726A132AA7A65Cc9E6fC5761C3".as_bytes()
+				),
+				name: "DeBio Lab".as_bytes().to_vec().try_into().unwrap(),
+				email: "DeBio Email".as_bytes().to_vec().try_into().unwrap(),
+				country: CountryCode::try_from("ID".as_bytes().to_vec()).unwrap(),
+				region: RegionCode::try_from("JB".as_bytes().to_vec()).unwrap(),
+				city: CityCode::try_from("CITY".as_bytes().to_vec()).unwrap(),
+				address: "DeBio Address".as_bytes().to_vec().try_into().unwrap(),
+				phone_number: "+6281394653625".as_bytes().to_vec().try_into().unwrap(),
+				website: "DeBio Website".as_bytes().to_vec().try_into().unwrap(),
+				latitude: Some("-6.175392".as_bytes().to_vec().try_into().unwrap()),
+				longitude: Some("106.827153".as_bytes().to_vec().try_into().unwrap()),
+				profile_image: Some(
+					"DeBio Profile Image uwu".as_bytes().to_vec().try_into().unwrap()
+				),
+			}
+		));
+
+		assert_ok!(UserProfile::set_eth_address(
+			RuntimeOrigin::signed(lab),
+			EthereumAddress([b'X'; 20])
+		));
+
+		let prices_by_currency_dbio = PriceByCurrency {
+			currency: CurrencyType::DBIO,
+			total_price: 30,
+			price_components: vec![Price {
+				component: b"testing_price".to_vec().try_into().unwrap(),
+				value: 20,
+			}]
+			.try_into()
+			.unwrap(),
+			additional_prices: vec![Price {
+				component: b"qc_price".to_vec().try_into().unwrap(),
+				value: 20,
+			}]
+			.try_into()
+			.unwrap(),
+		};
+
+		assert_ok!(Services::create_service(
+			RuntimeOrigin::signed(lab),
+			ServiceInfo {
+				name: "DeBio name".as_bytes().to_vec().try_into().unwrap(),
+				prices_by_currency: vec![prices_by_currency_dbio.clone()].try_into().unwrap(),
+				expected_duration: ExpectedDuration::default(),
+				category: "DeBio category".as_bytes().to_vec().try_into().unwrap(),
+				description: "This is my description".as_bytes().to_vec().try_into().unwrap(),
+				test_result_sample: "Test result sample".as_bytes().to_vec().try_into().unwrap(),
+				dna_collection_process: "Dna Collection Process"
+					.as_bytes()
+					.to_vec()
+					.try_into()
+					.unwrap(),
+				long_description: Some(
+					"This is my long description".as_bytes().to_vec().try_into().unwrap()
+				),
+				image: Some("This is my image".as_bytes().to_vec().try_into().unwrap()),
+			},
+			ServiceFlow::default()
+		));
+
+		let _lab = Labs::lab_by_account_id(lab).unwrap();
+
+		assert_ok!(Orders::create_order(
+			RuntimeOrigin::signed(customer),
+			_lab.services[0],
+			0,
+			Keccak256::hash("0xhJ7TRe456FADD2726A132ABJK5RCc9E6fC5869F4".as_bytes()),
+			ServiceFlow::StakingRequestService,
+			None,
+		));
+
+		let _order_id = Orders::last_order_by_customer_id(customer).unwrap();
+		let _dna_sample = GeneticTesting::dna_samples_by_lab_id(&lab);
+
+		assert_ok!(Orders::set_order_paid(RuntimeOrigin::signed(customer), _order_id));
+
+		assert_eq!(Balances::free_balance(customer), 160);
+		assert_eq!(Balances::free_balance(pallet_id), 41);
+
+		assert_ok!(GeneticTesting::reject_dna_sample(
+			RuntimeOrigin::signed(lab),
+			_dna_sample[0].clone(),
+			"Rejected title".as_bytes().to_vec(),
+			"Rejected description".as_bytes().to_vec(),
+		));
+
+		assert_eq!(Orders::order_by_id(&_order_id).unwrap().status, OrderStatus::Failed);
+		assert!(Orders::order_settlement_by_order_id(_order_id).is_some());
+
+		assert_eq!(Balances::free_balance(customer), 160);
+		assert_eq!(Balances::free_balance(lab), 300);
+		assert_eq!(Balances::free_balance(pallet_id), 41);
+
+		assert_ok!(Orders::open_dispute(
+			RuntimeOrigin::signed(customer),
+			_order_id,
+			"Sample was not damaged".as_bytes().to_vec()
+		));
+
+		assert_eq!(Orders::order_by_id(&_order_id).unwrap().status, OrderStatus::Disputed);
+		assert_eq!(SettlementReleases::<Test>::iter().count(), 0);
+	})
+}
+
+#[test]
+fn cant_open_dispute_when_reason_too_long() {
+	<ExternalityBuilder>::default().existential_deposit(1).build().execute_with(|| {
+		DisputeWindow::set(1000);
+
+		let lab = account_key("lab");
+		let customer = account_key("customer");
+		let pallet_id = account_key("pallet_id");
+		let treasury_key = account_key("treasury_key");
+
+		PalletAccount::<Test>::put(pallet_id);
+		TreasuryKey::<Test>::put(treasury_key);
+
+		assert_ok!(Labs::register_lab(
+			RuntimeOrigin::signed(lab),
+			LabInfo {
+				box_public_key: Keccak256::hash(
+					"0xDb9Af2d1f3ADD2726A132AA7A65Cc9E6fC5761C3".as_bytes()
+				),
+				name: "DeBio Lab".as_bytes().to_vec().try_into().unwrap(),
+				email: "DeBio Email".as_bytes().to_vec().try_into().unwrap(),
+				country: CountryCode::try_from("ID".as_bytes().to_vec()).unwrap(),
+				region: RegionCode::try_from("JB".as_bytes().to_vec()).unwrap(),
+				city: CityCode::try_from("CITY".as_bytes().to_vec()).unwrap(),
+				address: "DeBio Address".as_bytes().to_vec().try_into().unwrap(),
+				phone_number: "+6281394653625".as_bytes().to_vec().try_into().unwrap(),
+				website: "DeBio Website".as_bytes().to_vec().try_into().unwrap(),
+				latitude: Some("-6.175392".as_bytes().to_vec().try_into().unwrap()),
+				longitude: Some("106.827153".as_bytes().to_vec().try_into().unwrap()),
+				profile_image: Some(
+					"DeBio Profile Image uwu".as_bytes().to_vec().try_into().unwrap()
+				),
+			}
+		));
+
+		assert_ok!(UserProfile::set_eth_address(
+			RuntimeOrigin::signed(lab),
+			EthereumAddress([b'X'; 20])
+		));
+
+		let prices_by_currency_dbio = PriceByCurrency {
+			currency: CurrencyType::DBIO,
+			total_price: 30,
+			price_components: vec![Price {
+				component: b"testing_price".to_vec().try_into().unwrap(),
+				value: 20,
+			}]
+			.try_into()
+			.unwrap(),
+			additional_prices: vec![Price {
+				component: b"qc_price".to_vec().try_into().unwrap(),
+				value: 20,
+			}]
+			.try_into()
+			.unwrap(),
+		};
+
+		assert_ok!(Services::create_service(
+			RuntimeOrigin::signed(lab),
+			ServiceInfo {
+				name: "DeBio name".as_bytes().to_vec().try_into().unwrap(),
+				prices_by_currency: vec![prices_by_currency_dbio.clone()].try_into().unwrap(),
+				expected_duration: ExpectedDuration::default(),
+				category: "DeBio category".as_bytes().to_vec().try_into().unwrap(),
+				description: "This is my description".as_bytes().to_vec().try_into().unwrap(),
+				test_result_sample: "Test result sample".as_bytes().to_vec().try_into().unwrap(),
+				dna_collection_process: "Dna Collection Process"
+					.as_bytes()
+					.to_vec()
+					.try_into()
+					.unwrap(),
+				long_description: Some(
+					"This is my long description".as_bytes().to_vec().try_into().unwrap()
+				),
+				image: Some("This is my image".as_bytes().to_vec().try_into().unwrap()),
+			},
+			ServiceFlow::default()
+		));
+
+		let _lab = Labs::lab_by_account_id(lab).unwrap();
+
+		assert_ok!(Orders::create_order(
+			RuntimeOrigin::signed(customer),
+			_lab.services[0],
+			0,
+			Keccak256::hash("0xhJ7TRe456FADD2726A132ABJK5RCc9E6fC5869F4".as_bytes()),
+			ServiceFlow::StakingRequestService,
+			None,
+		));
+
+		let _order_id = Orders::last_order_by_customer_id(customer).unwrap();
+		let _dna_sample = GeneticTesting::dna_samples_by_lab_id(&lab);
+
+		assert_ok!(Orders::set_order_paid(RuntimeOrigin::signed(customer), _order_id));
+
+		assert_ok!(GeneticTesting::submit_test_result(
+			RuntimeOrigin::signed(lab),
+			_dna_sample[0].clone(),
+			DnaTestResultSubmission {
+				comments: Some("comment".as_bytes().to_vec().try_into().unwrap()),
+				result_link: Some("result_link".as_bytes().to_vec().try_into().unwrap()),
+				report_link: Some("report_link".as_bytes().to_vec().try_into().unwrap()),
+			}
+		));
+
+		assert_ok!(GeneticTesting::process_dna_sample(
+			RuntimeOrigin::signed(lab),
+			_dna_sample[0].clone(),
+			DnaSampleStatus::ResultReady,
+		));
+
+		assert_ok!(Orders::fulfill_order(RuntimeOrigin::signed(lab), _order_id));
+
+		assert_noop!(
+			Orders::open_dispute(
+				RuntimeOrigin::signed(customer),
+				_order_id,
+				vec![b'X'; MAX_DISPUTE_REASON_LENGTH as usize + 1]
+			),
+			Error::<Test>::DisputeReasonTooLong
+		);
+	})
+}
+
+#[test]
+fn cant_open_dispute_when_window_closed() {
+	<ExternalityBuilder>::default().existential_deposit(1).build().execute_with(|| {
+		DisputeWindow::set(1000);
+
+		let lab = account_key("lab");
+		let customer = account_key("customer");
+		let pallet_id = account_key("pallet_id");
+		let treasury_key = account_key("treasury_key");
+
+		PalletAccount::<Test>::put(pallet_id);
+		TreasuryKey::<Test>::put(treasury_key);
+
+		assert_ok!(Labs::register_lab(
+			RuntimeOrigin::signed(lab),
+			LabInfo {
+				box_public_key: Keccak256::hash(
+					"0xDb9Af2d1f3ADD2726A132AA7A65Cc9E6fC5761C3".as_bytes()
+				),
+				name: "DeBio Lab".as_bytes().to_vec().try_into().unwrap(),
+				email: "DeBio Email".as_bytes().to_vec().try_into().unwrap(),
+				country: CountryCode::try_from("ID".as_bytes().to_vec()).unwrap(),
+				region: RegionCode::try_from("JB".as_bytes().to_vec()).unwrap(),
+				city: CityCode::try_from("CITY".as_bytes().to_vec()).unwrap(),
+				address: "DeBio Address".as_bytes().to_vec().try_into().unwrap(),
+				phone_number: "+6281394653625".as_bytes().to_vec().try_into().unwrap(),
+				website: "DeBio Website".as_bytes().to_vec().try_into().unwrap(),
+				latitude: Some("-6.175392".as_bytes().to_vec().try_into().unwrap()),
+				longitude: Some("106.827153".as_bytes().to_vec().try_into().unwrap()),
+				profile_image: Some(
+					"DeBio Profile Image uwu".as_bytes().to_vec().try_into().unwrap()
+				),
+			}
+		));
+
+		assert_ok!(UserProfile::set_eth_address(
+			RuntimeOrigin::signed(lab),
+			EthereumAddress([b'X'; 20])
+		));
+
+		let prices_by_currency_dbio = PriceByCurrency {
+			currency: CurrencyType::DBIO,
+			total_price: 30,
+			price_components: vec![Price {
+				component: b"testing_price".to_vec().try_into().unwrap(),
+				value: 20,
+			}]
+			.try_into()
+			.unwrap(),
+			additional_prices: vec![Price {
+				component: b"qc_price".to_vec().try_into().unwrap(),
+				value: 20,
+			}]
+			.try_into()
+			.unwrap(),
+		};
+
+		assert_ok!(Services::create_service(
+			RuntimeOrigin::signed(lab),
+			ServiceInfo {
+				name: "DeBio name".as_bytes().to_vec().try_into().unwrap(),
+				prices_by_currency: vec![prices_by_currency_dbio.clone()].try_into().unwrap(),
+				expected_duration: ExpectedDuration::default(),
+				category: "DeBio category".as_bytes().to_vec().try_into().unwrap(),
+				description: "This is my description".as_bytes().to_vec().try_into().unwrap(),
+				test_result_sample: "Test result sample".as_bytes().to_vec().try_into().unwrap(),
+				dna_collection_process: "Dna Collection Process"
+					.as_bytes()
+					.to_vec()
+					.try_into()
+					.unwrap(),
+				long_description: Some(
+					"This is my long description".as_bytes().to_vec().try_into().unwrap()
+				),
+				image: Some("This is my image".as_bytes().to_vec().try_into().unwrap()),
+			},
+			ServiceFlow::default()
+		));
+
+		let _lab = Labs::lab_by_account_id(lab).unwrap();
+
+		assert_ok!(Orders::create_order(
+			RuntimeOrigin::signed(customer),
+			_lab.services[0],
+			0,
+			Keccak256::hash("0xhJ7TRe456FADD2726A132ABJK5RCc9E6fC5869F4".as_bytes()),
+			ServiceFlow::StakingRequestService,
+			None,
+		));
+
+		let _order_id = Orders::last_order_by_customer_id(customer).unwrap();
+		let _dna_sample = GeneticTesting::dna_samples_by_lab_id(&lab);
+
+		assert_ok!(Orders::set_order_paid(RuntimeOrigin::signed(customer), _order_id));
+
+		assert_ok!(GeneticTesting::submit_test_result(
+			RuntimeOrigin::signed(lab),
+			_dna_sample[0].clone(),
+			DnaTestResultSubmission {
+				comments: Some("comment".as_bytes().to_vec().try_into().unwrap()),
+				result_link: Some("result_link".as_bytes().to_vec().try_into().unwrap()),
+				report_link: Some("report_link".as_bytes().to_vec().try_into().unwrap()),
+			}
+		));
+
+		assert_ok!(GeneticTesting::process_dna_sample(
+			RuntimeOrigin::signed(lab),
+			_dna_sample[0].clone(),
+			DnaSampleStatus::ResultReady,
+		));
+
+		assert_ok!(Orders::fulfill_order(RuntimeOrigin::signed(lab), _order_id));
+
+		Timestamp::set_timestamp(1000);
+
+		assert_noop!(
+			Orders::open_dispute(
+				RuntimeOrigin::signed(customer),
+				_order_id,
+				"Result does not match the sample".as_bytes().to_vec()
+			),
+			Error::<Test>::DisputeWindowClosed
+		);
+	})
+}
+
+#[test]
+fn cant_resolve_dispute_when_not_arbiter() {
+	<ExternalityBuilder>::default().existential_deposit(1).build().execute_with(|| {
+		DisputeWindow::set(1000);
+
+		let lab = account_key("lab");
+		let customer = account_key("customer");
+		let pallet_id = account_key("pallet_id");
+		let treasury_key = account_key("treasury_key");
+
+		PalletAccount::<Test>::put(pallet_id);
+		TreasuryKey::<Test>::put(treasury_key);
+
+		assert_ok!(Labs::register_lab(
+			RuntimeOrigin::signed(lab),
+			LabInfo {
+				box_public_key: Keccak256::hash(
+					"0xDb9Af2d1f3ADD2726A132AA7A65Cc9E6fC5761C3".as_bytes()
+				),
+				name: "DeBio Lab".as_bytes().to_vec().try_into().unwrap(),
+				email: "DeBio Email".as_bytes().to_vec().try_into().unwrap(),
+				country: CountryCode::try_from("ID".as_bytes().to_vec()).unwrap(),
+				region: RegionCode::try_from("JB".as_bytes().to_vec()).unwrap(),
+				city: CityCode::try_from("CITY".as_bytes().to_vec()).unwrap(),
+				address: "DeBio Address".as_bytes().to_vec().try_into().unwrap(),
+				phone_number: "+6281394653625".as_bytes().to_vec().try_into().unwrap(),
+				website: "DeBio Website".as_bytes().to_vec().try_into().unwrap(),
+				latitude: Some("-6.175392".as_bytes().to_vec().try_into().unwrap()),
+				longitude: Some("106.827153".as_bytes().to_vec().try_into().unwrap()),
+				profile_image: Some(
+					"DeBio Profile Image uwu".as_bytes().to_vec().try_into().unwrap()
+				),
+			}
+		));
+
+		assert_ok!(UserProfile::set_eth_address(
+			RuntimeOrigin::signed(lab),
+			EthereumAddress([b'X'; 20])
+		));
+
+		let prices_by_currency_dbio = PriceByCurrency {
+			currency: CurrencyType::DBIO,
+			total_price: 30,
+			price_components: vec![Price {
+				component: b"testing_price".to_vec().try_into().unwrap(),
+				value: 20,
+			}]
+			.try_into()
+			.unwrap(),
+			additional_prices: vec![Price {
+				component: b"qc_price".to_vec().try_into().unwrap(),
+				value: 20,
+			}]
+			.try_into()
+			.unwrap(),
+		};
+
+		assert_ok!(Services::create_service(
+			RuntimeOrigin::signed(lab),
+			ServiceInfo {
+				name: "DeBio name".as_bytes().to_vec().try_into().unwrap(),
+				prices_by_currency: vec![prices_by_currency_dbio.clone()].try_into().unwrap(),
+				expected_duration: ExpectedDuration::default(),
+				category: "DeBio category".as_bytes().to_vec().try_into().unwrap(),
+				description: "This is my description".as_bytes().to_vec().try_into().unwrap(),
+				test_result_sample: "Test result sample".as_bytes().to_vec().try_into().unwrap(),
+				dna_collection_process: "Dna Collection Process"
+					.as_bytes()
+					.to_vec()
+					.try_into()
+					.unwrap(),
+				long_description: Some(
+					"This is my long description".as_bytes().to_vec().try_into().unwrap()
+				),
+				image: Some("This is my image".as_bytes().to_vec().try_into().unwrap()),
+			},
+			ServiceFlow::default()
+		));
+
+		let _lab = Labs::lab_by_account_id(lab).unwrap();
+
+		assert_ok!(Orders::create_order(
+			RuntimeOrigin::signed(customer),
+			_lab.services[0],
+			0,
+			Keccak256::hash("0xhJ7TRe456FADD2726A132ABJK5RCc9E6fC5869F4".as_bytes()),
+			ServiceFlow::StakingRequestService,
+			None,
+		));
+
+		let _order_id = Orders::last_order_by_customer_id(customer).unwrap();
+		let _dna_sample = GeneticTesting::dna_samples_by_lab_id(&lab);
+
+		assert_ok!(Orders::set_order_paid(RuntimeOrigin::signed(customer), _order_id));
+
+		assert_ok!(GeneticTesting::submit_test_result(
+			RuntimeOrigin::signed(lab),
+			_dna_sample[0].clone(),
+			DnaTestResultSubmission {
+				comments: Some("comment".as_bytes().to_vec().try_into().unwrap()),
+				result_link: Some("result_link".as_bytes().to_vec().try_into().unwrap()),
+				report_link: Some("report_link".as_bytes().to_vec().try_into().unwrap()),
+			}
+		));
+
+		assert_ok!(GeneticTesting::process_dna_sample(
+			RuntimeOrigin::signed(lab),
+			_dna_sample[0].clone(),
+			DnaSampleStatus::ResultReady,
+		));
+
+		assert_ok!(Orders::fulfill_order(RuntimeOrigin::signed(lab), _order_id));
+
+		assert_ok!(Orders::open_dispute(
+			RuntimeOrigin::signed(lab),
+			_order_id,
+			"Customer sample was contaminated".as_bytes().to_vec()
+		));
+
+		assert_noop!(
+			Orders::resolve_dispute(
+				RuntimeOrigin::signed(customer),
+				_order_id,
+				Perbill::from_percent(100)
+			),
+			BadOrigin
+		);
+	})
+}
+
+#[test]
+fn pay_installments_works() {
+	<ExternalityBuilder>::default().existential_deposit(1).build().execute_with(|| {
+		System::set_block_number(1);
+
+		let lab = account_key("lab");
+		let customer = account_key("customer");
+		let pallet_id = account_key("pallet_id");
+
+		PalletAccount::<Test>::put(pallet_id);
+
+		assert_ok!(Labs::register_lab(
+			RuntimeOrigin::signed(lab),
+			LabInfo {
+				box_public_key: Keccak256::hash(
+					"0xDb9Af2d1f3ADD2726A132AA7A65Cc9E6fC5761C3".as_bytes()
+				),
+				name: "DeBio Lab".as_bytes().to_vec().try_into().unwrap(),
+				email: "DeBio Email".as_bytes().to_vec().try_into().unwrap(),
+				country: CountryCode::try_from("ID".as_bytes().to_vec()).unwrap(),
+				region: RegionCode::try_from("JB".as_bytes().to_vec()).unwrap(),
+				city: CityCode::try_from("CITY".as_bytes().to_vec()).unwrap(),
+				address: "DeBio Address".as_bytes().to_vec().try_into().unwrap(),
+				phone_number: "+6281394653625".as_bytes().to_vec().try_into().unwrap(),
+				website: "DeBio Website".as_bytes().to_vec().try_into().unwrap(),
+				latitude: Some("-6.175392".as_bytes().to_vec().try_into().unwrap()),
+				longitude: Some("106.827153".as_bytes().to_vec().try_into().unwrap()),
+				profile_image: Some(
+					"DeBio Profile Image uwu".as_bytes().to_vec().try_into().unwrap()
+				),
+			}
+		));
+
+		assert_ok!(UserProfile::set_eth_address(
+			RuntimeOrigin::signed(lab),
+			EthereumAddress([b'X'; 20])
+		));
+
+		let prices_by_currency_dbio = PriceByCurrency {
+			currency: CurrencyType::DBIO,
+			total_price: 10,
+			price_components: vec![Price {
+				component: b"testing_price".to_vec().try_into().unwrap(),
+				value: 5,
+			}]
+			.try_into()
+			.unwrap(),
+			additional_prices: vec![Price {
+				component: b"qc_price".to_vec().try_into().unwrap(),
+				value: 5,
+			}]
+			.try_into()
+			.unwrap(),
+		};
+
+		assert_ok!(Services::create_service(
+			RuntimeOrigin::signed(lab),
+			ServiceInfo {
+				name: "DeBio name".as_bytes().to_vec().try_into().unwrap(),
+				prices_by_currency: vec![prices_by_currency_dbio.clone()].try_into().unwrap(),
+				expected_duration: ExpectedDuration::default(),
+				category: "DeBio category".as_bytes().to_vec().try_into().unwrap(),
+				description: "This is my description".as_bytes().to_vec().try_into().unwrap(),
+				test_result_sample: "Test result sample".as_bytes().to_vec().try_into().unwrap(),
+				dna_collection_process: "Dna Collection Process"
+					.as_bytes()
+					.to_vec()
+					.try_into()
+					.unwrap(),
+				long_description: Some(
+					"This is my long description".as_bytes().to_vec().try_into().unwrap()
+				),
+				image: Some("This is my image".as_bytes().to_vec().try_into().unwrap()),
+			},
+			ServiceFlow::default()
+		));
+
+		let _lab = Labs::lab_by_account_id(lab).unwrap();
+
+		assert_ok!(Orders::create_order(
+			RuntimeOrigin::signed(customer),
+			_lab.services[0],
+			0,
+			Keccak256::hash("0xhJ7TRe456FADD2726A132ABJK5RCc9E6fC5869F4".as_bytes()),
+			ServiceFlow::StakingRequestService,
+			None,
+		));
+
+		let _order_id = Orders::last_order_by_customer_id(customer).unwrap();
+
+		assert_ok!(Orders::create_payment_plan(RuntimeOrigin::signed(customer), _order_id, 3));
+
+		assert_eq!(
+			Orders::payment_plan_by_order_id(_order_id),
+			Some(PaymentPlan {
+				installments: vec![
+					Installment { amount: 3, due_at: 1000 },
+					Installment { amount: 3, due_at: 2000 },
+					Installment { amount: 4, due_at: 3000 },
+				]
+				.try_into()
+				.unwrap(),
+				paid_installments: 0,
+			})
+		);
+		assert_eq!(Orders::installment_deadline_by_order_id(_order_id), Some(1000));
+		assert_eq!(InstallmentDeadlines::<Test>::get(0, _order_id), Some(1000));
+
+		assert_ok!(Orders::pay_installment(RuntimeOrigin::signed(customer), _order_id));
+
+		System::assert_last_event(RuntimeEvent::Orders(crate::Event::OrderInstallmentPaid(
+			Orders::order_by_id(&_order_id).unwrap(),
+			1,
+		)));
+
+		let _order = Orders::order_by_id(&_order_id).unwrap();
+
+		assert_eq!(_order.status, OrderStatus::Unpaid);
+		assert_eq!(_order.paid_amount, 3);
+		assert_eq!(Orders::installment_deadline_by_order_id(_order_id), Some(2000));
+		assert_eq!(InstallmentDeadlines::<Test>::get(0, _order_id), Some(2000));
+
+		assert_ok!(Orders::pay_installment(RuntimeOrigin::signed(customer), _order_id));
+		assert_ok!(Orders::pay_installment(RuntimeOrigin::signed(customer), _order_id));
+
+		System::assert_last_event(RuntimeEvent::Orders(crate::Event::OrderPaid(
+			Orders::order_by_id(&_order_id).unwrap(),
+		)));
+
+		let _order = Orders::order_by_id(&_order_id).unwrap();
+
+		assert_eq!(_order.status, OrderStatus::Paid);
+		assert_eq!(_order.paid_amount, 10);
+		assert_eq!(Orders::installment_deadline_by_order_id(_order_id), None);
+		assert_eq!(InstallmentDeadlines::<Test>::iter().count(), 0);
+
+		assert_noop!(
+			Orders::pay_installment(RuntimeOrigin::signed(customer), _order_id),
+			Error::<Test>::OrderCannotBePaid
+		);
+
+		assert_eq!(Balances::free_balance(customer), 190);
+		assert_eq!(Balances::free_balance(pallet_id), 11);
+	})
+}
+
+#[test]
+fn cancel_order_refunds_paid_installments() {
+	<ExternalityBuilder>::default().existential_deposit(1).build().execute_with(|| {
+		let lab = account_key("lab");
+		let customer = account_key("customer");
+		let pallet_id = account_key("pallet_id");
+
+		PalletAccount::<Test>::put(pallet_id);
+
+		assert_ok!(Labs::register_lab(
+			RuntimeOrigin::signed(lab),
+			LabInfo {
+				box_public_key: Keccak256::hash(
+					"0xDb9Af2d1f3ADD2726A132AA7A65Cc9E6fC5761C3".as_bytes()
+				),
+				name: "DeBio Lab".as_bytes().to_vec().try_into().unwrap(),
+				email: "DeBio Email".as_bytes().to_vec().try_into().unwrap(),
+				country: CountryCode::try_from("ID".as_bytes().to_vec()).unwrap(),
+				region: RegionCode::try_from("JB".as_bytes().to_vec()).unwrap(),
+				city: CityCode::try_from("CITY".as_bytes().to_vec()).unwrap(),
+				address: "DeBio Address".as_bytes().to_vec().try_into().unwrap(),
+				phone_number: "+6281394653625".as_bytes().to_vec().try_into().unwrap(),
+				website: "DeBio Website".as_bytes().to_vec().try_into().unwrap(),
+				latitude: Some("-6.175392".as_bytes().to_vec().try_into().unwrap()),
+				longitude: Some("106.827153".as_bytes().to_vec().try_into().unwrap()),
+				profile_image: Some(
+					"DeBio Profile Image uwu".as_bytes().to_vec().try_into().unwrap()
+				),
+			}
+		));
+
+		assert_ok!(UserProfile::set_eth_address(
+			RuntimeOrigin::signed(lab),
+			EthereumAddress([b'X'; 20])
+		));
+
+		let prices_by_currency_dbio = PriceByCurrency {
+			currency: CurrencyType::DBIO,
+			total_price: 10,
+			price_components: vec![Price {
+				component: b"testing_price".to_vec().try_into().unwrap(),
+				value: 5,
+			}]
+			.try_into()
+			.unwrap(),
+			additional_prices: vec![Price {
+				component: b"qc_price".to_vec().try_into().unwrap(),
+				value: 5,
+			}]
+			.try_into()
+			.unwrap(),
+		};
+
+		assert_ok!(Services::create_service(
+			RuntimeOrigin::signed(lab),
+			ServiceInfo {
+				name: "DeBio name".as_bytes().to_vec().try_into().unwrap(),
+				prices_by_currency: vec![prices_by_currency_dbio.clone()].try_into().unwrap(),
+				expected_duration: ExpectedDuration::default(),
+				category: "DeBio category".as_bytes().to_vec().try_into().unwrap(),
+				description: "This is my description".as_bytes().to_vec().try_into().unwrap(),
+				test_result_sample: "Test result sample".as_bytes().to_vec().try_into().unwrap(),
+				dna_collection_process: "Dna Collection Process"
+					.as_bytes()
+					.to_vec()
+					.try_into()
+					.unwrap(),
+				long_description: Some(
+					"This is my long description".as_bytes().to_vec().try_into().unwrap()
+				),
+				image: Some("This is my image".as_bytes().to_vec().try_into().unwrap()),
+			},
+			ServiceFlow::default()
+		));
+
+		let _lab = Labs::lab_by_account_id(lab).unwrap();
+
+		assert_ok!(Orders::create_order(
+			RuntimeOrigin::signed(customer),
+			_lab.services[0],
+			0,
+			Keccak256::hash("0xhJ7TRe456FADD2726A132ABJK5RCc9E6fC5869F4".as_bytes()),
+			ServiceFlow::StakingRequestService,
+			None,
+		));
+
+		let _order_id = Orders::last_order_by_customer_id(customer).unwrap();
+
+		assert_ok!(Orders::create_payment_plan(RuntimeOrigin::signed(customer), _order_id, 2));
+		assert_ok!(Orders::pay_installment(RuntimeOrigin::signed(customer), _order_id));
+
+		assert_eq!(Balances::free_balance(customer), 195);
+		assert_eq!(Balances::free_balance(pallet_id), 6);
+
+		assert_ok!(Orders::cancel_order(RuntimeOrigin::signed(customer), _order_id));
+
+		let _order = Orders::order_by_id(&_order_id).unwrap();
+
+		assert_eq!(_order.status, OrderStatus::Refunded);
+		assert_eq!(Orders::installment_deadline_by_order_id(_order_id), None);
+		assert_eq!(InstallmentDeadlines::<Test>::iter().count(), 0);
+
+		assert_eq!(Balances::free_balance(customer), 200);
+		assert_eq!(Balances::free_balance(pallet_id), 1);
+	})
+}
+
+#[test]
+fn overdue_payment_plan_cancelled_on_idle_works() {
+	<ExternalityBuilder>::default().existential_deposit(1).build().execute_with(|| {
+		System::set_block_number(1);
+
+		let lab = account_key("lab");
+		let customer = account_key("customer");
+		let pallet_id = account_key("pallet_id");
+
+		PalletAccount::<Test>::put(pallet_id);
+
+		assert_ok!(Labs::register_lab(
+			RuntimeOrigin::signed(lab),
+			LabInfo {
+				box_public_key: Keccak256::hash(
+					"0xDb9Af2d1f3ADD2726A132AA7A65Cc9E6fC5761C3".as_bytes()
+				),
+				name: "DeBio Lab".as_bytes().to_vec().try_into().unwrap(),
+				email: "DeBio Email".as_bytes().to_vec().try_into().unwrap(),
+				country: CountryCode::try_from("ID".as_bytes().to_vec()).unwrap(),
+				region: RegionCode::try_from("JB".as_bytes().to_vec()).unwrap(),
+				city: CityCode::try_from("CITY".as_bytes().to_vec()).unwrap(),
+				address: "DeBio Address".as_bytes().to_vec().try_into().unwrap(),
+				phone_number: "+6281394653625".as_bytes().to_vec().try_into().unwrap(),
+				website: "DeBio Website".as_bytes().to_vec().try_into().unwrap(),
+				latitude: Some("-6.175392".as_bytes().to_vec().try_into().unwrap()),
+				longitude: Some("106.827153".as_bytes().to_vec().try_into().unwrap()),
+				profile_image: Some(
+					"DeBio Profile Image uwu".as_bytes().to_vec().try_into().unwrap()
+				),
+			}
+		));
+
+		assert_ok!(UserProfile::set_eth_address(
+			RuntimeOrigin::signed(lab),
+			EthereumAddress([b'X'; 20])
+		));
+
+		let prices_by_currency_dbio = PriceByCurrency {
+			currency: CurrencyType::DBIO,
+			total_price: 10,
+			price_components: vec![Price {
+				component: b"testing_price".to_vec().try_into().unwrap(),
+				value: 5,
+			}]
+			.try_into()
+			.unwrap(),
+			additional_prices: vec![Price {
+				component: b"qc_price".to_vec().try_into().unwrap(),
+				value: 5,
+			}]
+			.try_into()
+			.unwrap(),
+		};
+
+		assert_ok!(Services::create_service(
+			RuntimeOrigin::signed(lab),
+			ServiceInfo {
+				name: "DeBio name".as_bytes().to_vec().try_into().unwrap(),
+				prices_by_currency: vec![prices_by_currency_dbio.clone()].try_into().unwrap(),
+				expected_duration: ExpectedDuration::default(),
+				category: "DeBio category".as_bytes().to_vec().try_into().unwrap(),
+				description: "This is my description".as_bytes().to_vec().try_into().unwrap(),
+				test_result_sample: "Test result sample".as_bytes().to_vec().try_into().unwrap(),
+				dna_collection_process: "Dna Collection Process"
+					.as_bytes()
+					.to_vec()
+					.try_into()
+					.unwrap(),
+				long_description: Some(
+					"This is my long description".as_bytes().to_vec().try_into().unwrap()
+				),
+				image: Some("This is my image".as_bytes().to_vec().try_into().unwrap()),
+			},
+			ServiceFlow::default()
+		));
+
+		let _lab = Labs::lab_by_account_id(lab).unwrap();
+
+		assert_ok!(Orders::create_order(
+			RuntimeOrigin::signed(customer),
+			_lab.services[0],
+			0,
+			Keccak256::hash("0xhJ7TRe456FADD2726A132ABJK5RCc9E6fC5869F4".as_bytes()),
+			ServiceFlow::StakingRequestService,
+			None,
+		));
+
+		let _order_id = Orders::last_order_by_customer_id(customer).unwrap();
+
+		assert_ok!(Orders::create_payment_plan(RuntimeOrigin::signed(customer), _order_id, 2));
+		assert_ok!(Orders::pay_installment(RuntimeOrigin::signed(customer), _order_id));
+
+		Timestamp::set_timestamp(2000);
+
+		Orders::on_idle(1, Weight::from_ref_time(u64::MAX));
+
+		assert_eq!(Orders::order_by_id(&_order_id).unwrap().status, OrderStatus::Unpaid);
+
+		assert_noop!(
+			Orders::cancel_overdue_order(RuntimeOrigin::signed(lab), _order_id),
+			Error::<Test>::InstallmentNotYetOverdue
+		);
+
+		Timestamp::set_timestamp(2001);
+
+		Orders::on_idle(1, Weight::from_ref_time(u64::MAX));
+
+		System::assert_last_event(RuntimeEvent::Orders(crate::Event::OrderOverdueCancelled(
+			Orders::order_by_id(&_order_id).unwrap(),
+		)));
+
+		assert_eq!(Orders::order_by_id(&_order_id).unwrap().status, OrderStatus::Refunded);
+		assert_eq!(Orders::installment_deadline_by_order_id(_order_id), None);
+		assert_eq!(InstallmentDeadlines::<Test>::iter().count(), 0);
+
+		assert_eq!(Balances::free_balance(customer), 200);
+		assert_eq!(Balances::free_balance(pallet_id), 1);
+	})
+}
+
+#[test]
+fn cant_create_payment_plan_when_installments_exceed_max() {
+	<ExternalityBuilder>::default().existential_deposit(1).build().execute_with(|| {
+		let lab = account_key("lab");
+		let customer = account_key("customer");
+		let pallet_id = account_key("pallet_id");
+
+		PalletAccount::<Test>::put(pallet_id);
+
+		assert_ok!(Labs::register_lab(
+			RuntimeOrigin::signed(lab),
+			LabInfo {
+				box_public_key: Keccak256::hash(
+					"0xDb9Af2d1f3ADD2726A132AA7A65Cc9E6fC5761C3".as_bytes()
+				),
+				name: "DeBio Lab".as_bytes().to_vec().try_into().unwrap(),
+				email: "DeBio Email".as_bytes().to_vec().try_into().unwrap(),
+				country: CountryCode::try_from("ID".as_bytes().to_vec()).unwrap(),
+				region: RegionCode::try_from("JB".as_bytes().to_vec()).unwrap(),
+				city: CityCode::try_from("CITY".as_bytes().to_vec()).unwrap(),
+				address: "DeBio Address".as_bytes().to_vec().try_into().unwrap(),
+				phone_number: "+6281394653625".as_bytes().to_vec().try_into().unwrap(),
+				website: "DeBio Website".as_bytes().to_vec().try_into().unwrap(),
+				latitude: Some("-6.175392".as_bytes().to_vec().try_into().unwrap()),
+				longitude: Some("106.827153".as_bytes().to_vec().try_into().unwrap()),
+				profile_image: Some(
+					"DeBio Profile Image uwu".as_bytes().to_vec().try_into().unwrap()
+				),
+			}
+		));
+
+		assert_ok!(UserProfile::set_eth_address(
+			RuntimeOrigin::signed(lab),
+			EthereumAddress([b'X'; 20])
+		));
+
+		let prices_by_currency_dbio = PriceByCurrency {
+			currency: CurrencyType::DBIO,
+			total_price: 10,
+			price_components: vec![Price {
+				component: b"testing_price".to_vec().try_into().unwrap(),
+				value: 5,
+			}]
+			.try_into()
+			.unwrap(),
+			additional_prices: vec![Price {
+				component: b"qc_price".to_vec().try_into().unwrap(),
+				value: 5,
+			}]
+			.try_into()
+			.unwrap(),
+		};
 
-		assert_ok!(GeneticTesting::submit_test_result(
+		assert_ok!(Services::create_service(
 			RuntimeOrigin::signed(lab),
-			_dna_sample[0].clone(),
-			DnaTestResultSubmission {
-				comments: Some("comment".as_bytes().to_vec()),
-				result_link: Some("result_link".as_bytes().to_vec()),
-				report_link: Some("report_link".as_bytes().to_vec()),
+			ServiceInfo {
+				name: "DeBio name".as_bytes().to_vec().try_into().unwrap(),
+				prices_by_currency: vec![prices_by_currency_dbio.clone()].try_into().unwrap(),
+				expected_duration: ExpectedDuration::default(),
+				category: "DeBio category".as_bytes().to_vec().try_into().unwrap(),
+				description: "This is my description".as_bytes().to_vec().try_into().unwrap(),
+				test_result_sample: "Test result sample".as_bytes().to_vec().try_into().unwrap(),
+				dna_collection_process: "Dna Collection Process"
+					.as_bytes()
+					.to_vec()
+					.try_into()
+					.unwrap(),
+				long_description: Some(
+					"This is my long description".as_bytes().to_vec().try_into().unwrap()
+				),
+				image: Some("This is my image".as_bytes().to_vec().try_into().unwrap()),
+			},
+			ServiceFlow::default()
+		));
+
+		let _lab = Labs::lab_by_account_id(lab).unwrap();
+
+		assert_ok!(Orders::create_order(
+			RuntimeOrigin::signed(customer),
+			_lab.services[0],
+			0,
+			Keccak256::hash("0xhJ7TRe456FADD2726A132ABJK5RCc9E6fC5869F4".as_bytes()),
+			ServiceFlow::StakingRequestService,
+			None,
+		));
+
+		let _order_id = Orders::last_order_by_customer_id(customer).unwrap();
+
+		assert_noop!(
+			Orders::create_payment_plan(RuntimeOrigin::signed(customer), _order_id, 13),
+			Error::<Test>::InvalidInstallmentCount
+		);
+
+		assert_noop!(
+			Orders::create_payment_plan(RuntimeOrigin::signed(customer), _order_id, 0),
+			Error::<Test>::InvalidInstallmentCount
+		);
+
+		assert_eq!(Orders::payment_plan_by_order_id(_order_id), None);
+		assert_eq!(Balances::free_balance(pallet_id), 1);
+	})
+}
+
+#[test]
+fn call_event_should_work() {
+	<ExternalityBuilder>::default().existential_deposit(1).build().execute_with(|| {
+		System::set_block_number(1);
+
+		let lab = account_key("lab");
+		let admin = account_key("admin");
+		let customer = account_key("customer");
+		let pallet_id = account_key("pallet_id");
+		let treasury_key = account_key("treasury_key");
+
+		PalletAccount::<Test>::put(pallet_id);
+		TreasuryKey::<Test>::put(treasury_key);
+
+		assert_ok!(Labs::register_lab(
+			RuntimeOrigin::signed(lab),
+			LabInfo {
+				box_public_key: Keccak256::hash(
+					"0xDb9Af2d1f3ADD2726A132AA7A65Cc9E6fC5761C3".as_bytes()
+				),
+				name: "DeBio Lab".as_bytes().to_vec().try_into().unwrap(),
+				email: "DeBio Email".as_bytes().to_vec().try_into().unwrap(),
+				country: CountryCode::try_from("ID".as_bytes().to_vec()).unwrap(),
+				region: RegionCode::try_from("JB".as_bytes().to_vec()).unwrap(),
+				city: CityCode::try_from("CITY".as_bytes().to_vec()).unwrap(),
+				address: "DeBio Address".as_bytes().to_vec().try_into().unwrap(),
+				phone_number: "+6281394653625".as_bytes().to_vec().try_into().unwrap(),
+				website: "DeBio Website".as_bytes().to_vec().try_into().unwrap(),
+				latitude: Some("-6.175392".as_bytes().to_vec().try_into().unwrap()),
+				longitude: Some("106.827153".as_bytes().to_vec().try_into().unwrap()),
+				profile_image: Some(
+					"DeBio Profile Image uwu".as_bytes().to_vec().try_into().unwrap()
+				),
 			}
 		));
 
-		assert_ok!(GeneticTesting::process_dna_sample(
+		assert_ok!(UserProfile::set_eth_address(
 			RuntimeOrigin::signed(lab),
-			_dna_sample[0].clone(),
-			DnaSampleStatus::ResultReady,
+			EthereumAddress([b'X'; 20])
 		));
 
-		assert_ok!(Orders::fulfill_order(RuntimeOrigin::signed(lab), _order_id));
+		let prices_by_currency_dbio = PriceByCurrency {
+			currency: CurrencyType::DBIO,
+			total_price: 10,
+			price_components: vec![Price {
+				component: b"testing_price".to_vec().try_into().unwrap(),
+				value: 5,
+			}]
+			.try_into()
+			.unwrap(),
+			additional_prices: vec![Price {
+				component: b"qc_price".to_vec().try_into().unwrap(),
+				value: 5,
+			}]
+			.try_into()
+			.unwrap(),
+		};
+
+		assert_ok!(Services::create_service(
+			RuntimeOrigin::signed(lab),
+			ServiceInfo {
+				name: "DeBio name".as_bytes().to_vec().try_into().unwrap(),
+				prices_by_currency: vec![prices_by_currency_dbio.clone()].try_into().unwrap(),
+				expected_duration: ExpectedDuration::default(),
+				category: "DeBio category".as_bytes().to_vec().try_into().unwrap(),
+				description: "This is my description".as_bytes().to_vec().try_into().unwrap(),
+				test_result_sample: "Test result sample".as_bytes().to_vec().try_into().unwrap(),
+				dna_collection_process: "Dna Collection Process"
+					.as_bytes()
+					.to_vec()
+					.try_into()
+					.unwrap(),
+				long_description: Some(
+					"This is my long description".as_bytes().to_vec().try_into().unwrap()
+				),
+				image: Some("This is my image".as_bytes().to_vec().try_into().unwrap()),
+			},
+			ServiceFlow::default()
+		));
+
+		let _lab = Labs::lab_by_account_id(lab).unwrap();
+
+		assert_ok!(Orders::create_order(
+			RuntimeOrigin::signed(customer),
+			_lab.services[0],
+			0,
+			Keccak256::hash("0xhJ7TRe456FADD2726A132ABJK5RCc9E6fC5869F4".as_bytes()),
+			ServiceFlow::StakingRequestService,
+			None,
+		));
+
+		let _order_id = Orders::last_order_by_customer_id(customer).unwrap();
+		let _dna_sample = GeneticTesting::dna_samples_by_lab_id(&lab);
+
+		System::assert_last_event(RuntimeEvent::Orders(crate::Event::OrderCreated(Order {
+			id: _order_id,
+			service_id: _lab.services[0],
+			customer_id: customer,
+			customer_box_public_key: Keccak256::hash(
+				"0xhJ7TRe456FADD2726A132ABJK5RCc9E6fC5869F4".as_bytes(),
+			),
+			seller_id: lab,
+			dna_sample_tracking_id: _dna_sample[0].clone(),
+			asset_id: None,
+			total_price: 10,
+			paid_amount: 0,
+			currency: CurrencyType::default(),
+			prices: prices_by_currency_dbio.price_components.to_vec().try_into().unwrap(),
+			additional_prices: prices_by_currency_dbio
+				.additional_prices
+				.to_vec()
+				.try_into()
+				.unwrap(),
+			status: OrderStatus::default(),
+			order_flow: ServiceFlow::StakingRequestService,
+			created_at: 0,
+			updated_at: 0,
+		})));
+
+		assert_ok!(Orders::cancel_order(RuntimeOrigin::signed(customer), _order_id));
+
+		System::assert_last_event(RuntimeEvent::Orders(crate::Event::OrderCancelled(Order {
+			id: _order_id,
+			service_id: _lab.services[0],
+			customer_id: customer,
+			customer_box_public_key: Keccak256::hash(
+				"0xhJ7TRe456FADD2726A132ABJK5RCc9E6fC5869F4".as_bytes(),
+			),
+			seller_id: lab,
+			dna_sample_tracking_id: _dna_sample[0].clone(),
+			asset_id: None,
+			total_price: 10,
+			paid_amount: 0,
+			currency: CurrencyType::default(),
+			prices: prices_by_currency_dbio.price_components.to_vec().try_into().unwrap(),
+			additional_prices: prices_by_currency_dbio
+				.additional_prices
+				.to_vec()
+				.try_into()
+				.unwrap(),
+			status: OrderStatus::Cancelled,
+			order_flow: ServiceFlow::StakingRequestService,
+			created_at: 0,
+			updated_at: 0,
+		})));
+
+		assert_ok!(Orders::create_order(
+			RuntimeOrigin::signed(customer),
+			_lab.services[0],
+			0,
+			Keccak256::hash("0xhJ7TRe456FADD2726A132ABJK5RCc9E6fC5869F4".as_bytes()),
+			ServiceFlow::StakingRequestService,
+			None,
+		));
+
+		let _order_id = Orders::last_order_by_customer_id(customer).unwrap();
+		let _dna_sample = GeneticTesting::dna_samples_by_lab_id(&lab);
+
+		EscrowKey::<Test>::put(admin);
+
+		assert_ok!(Orders::set_order_paid(RuntimeOrigin::signed(customer), _order_id));
 
-		System::assert_last_event(RuntimeEvent::Orders(crate::Event::OrderFulfilled(Order {
+		System::assert_last_event(RuntimeEvent::Orders(crate::Event::OrderPaid(Order {
 			id: _order_id,
 			service_id: _lab.services[0],
 			customer_id: customer,
@@ -2046,17 +5739,311 @@ fn call_event_should_work() {
 			dna_sample_tracking_id: _dna_sample[0].clone(),
 			asset_id: None,
 			total_price: 10,
+			paid_amount: 10,
 			currency: CurrencyType::default(),
-			prices: prices_by_currency_dbio.price_components.clone(),
-			additional_prices: prices_by_currency_dbio.additional_prices,
-			status: OrderStatus::Fulfilled,
+			prices: prices_by_currency_dbio.price_components.to_vec().try_into().unwrap(),
+			additional_prices: prices_by_currency_dbio
+				.additional_prices
+				.to_vec()
+				.try_into()
+				.unwrap(),
+			status: OrderStatus::Paid,
 			order_flow: ServiceFlow::StakingRequestService,
 			created_at: 0,
 			updated_at: 0,
 		})));
+
+		assert_ok!(GeneticTesting::submit_test_result(
+			RuntimeOrigin::signed(lab),
+			_dna_sample[0].clone(),
+			DnaTestResultSubmission {
+				comments: Some("comment".as_bytes().to_vec().try_into().unwrap()),
+				result_link: Some("result_link".as_bytes().to_vec().try_into().unwrap()),
+				report_link: Some("report_link".as_bytes().to_vec().try_into().unwrap()),
+			}
+		));
+
+		assert_ok!(GeneticTesting::process_dna_sample(
+			RuntimeOrigin::signed(lab),
+			_dna_sample[0].clone(),
+			DnaSampleStatus::ResultReady,
+		));
+
+		assert_ok!(Orders::fulfill_order(RuntimeOrigin::signed(lab), _order_id));
+
+		System::assert_last_event(RuntimeEvent::Orders(crate::Event::OrderFulfilled(
+			Order {
+				id: _order_id,
+				service_id: _lab.services[0],
+				customer_id: customer,
+				customer_box_public_key: Keccak256::hash(
+					"0xhJ7TRe456FADD2726A132ABJK5RCc9E6fC5869F4".as_bytes(),
+				),
+				seller_id: lab,
+				dna_sample_tracking_id: _dna_sample[0].clone(),
+				asset_id: None,
+				total_price: 10,
+				paid_amount: 10,
+				currency: CurrencyType::default(),
+				prices: prices_by_currency_dbio.price_components.to_vec().try_into().unwrap(),
+				additional_prices: prices_by_currency_dbio
+					.additional_prices
+					.to_vec()
+					.try_into()
+					.unwrap(),
+				status: OrderStatus::Fulfilled,
+				order_flow: ServiceFlow::StakingRequestService,
+				created_at: 0,
+				updated_at: 0,
+			},
+			FeeSplit { seller_amount: 10, treasury_amount: 0, beneficiary_amounts: vec![] },
+		)));
 	});
 }
 
+#[test]
+fn get_orders_by_account_filters_and_paginates() {
+	<ExternalityBuilder>::default().existential_deposit(1).build().execute_with(|| {
+		let customer = account_key("customer");
+		let lab = account_key("lab");
+
+		assert_ok!(Labs::register_lab(
+			RuntimeOrigin::signed(lab),
+			LabInfo {
+				box_public_key: Keccak256::hash(
+					"0xDb9Af2d1f3ADD2726A132AA7A65Cc9E6fC5761C3".as_bytes()
+				),
+				name: "DeBio Lab".as_bytes().to_vec().try_into().unwrap(),
+				email: "DeBio Email".as_bytes().to_vec().try_into().unwrap(),
+				country: CountryCode::try_from("ID".as_bytes().to_vec()).unwrap(),
+				region: RegionCode::try_from("JB".as_bytes().to_vec()).unwrap(),
+				city: CityCode::try_from("CITY".as_bytes().to_vec()).unwrap(),
+				address: "DeBio Address".as_bytes().to_vec().try_into().unwrap(),
+				phone_number: "+6281394653625".as_bytes().to_vec().try_into().unwrap(),
+				website: "DeBio Website".as_bytes().to_vec().try_into().unwrap(),
+				latitude: Some("-6.175392".as_bytes().to_vec().try_into().unwrap()),
+				longitude: Some("106.827153".as_bytes().to_vec().try_into().unwrap()),
+				profile_image: Some(
+					"DeBio Profile Image uwu".as_bytes().to_vec().try_into().unwrap()
+				),
+			}
+		));
+
+		assert_ok!(UserProfile::set_eth_address(
+			RuntimeOrigin::signed(lab),
+			EthereumAddress([b'X'; 20])
+		));
+
+		let prices_by_currency_dbio = PriceByCurrency {
+			currency: CurrencyType::DBIO,
+			total_price: 10,
+			price_components: vec![Price {
+				component: b"testing_price".to_vec().try_into().unwrap(),
+				value: 5,
+			}]
+			.try_into()
+			.unwrap(),
+			additional_prices: vec![Price {
+				component: b"qc_price".to_vec().try_into().unwrap(),
+				value: 5,
+			}]
+			.try_into()
+			.unwrap(),
+		};
+
+		assert_ok!(Services::create_service(
+			RuntimeOrigin::signed(lab),
+			ServiceInfo {
+				name: "DeBio name".as_bytes().to_vec().try_into().unwrap(),
+				prices_by_currency: vec![prices_by_currency_dbio].try_into().unwrap(),
+				expected_duration: ExpectedDuration::default(),
+				category: "DeBio category".as_bytes().to_vec().try_into().unwrap(),
+				description: "This is my description".as_bytes().to_vec().try_into().unwrap(),
+				test_result_sample: "Test result sample".as_bytes().to_vec().try_into().unwrap(),
+				dna_collection_process: "Dna Collection Process"
+					.as_bytes()
+					.to_vec()
+					.try_into()
+					.unwrap(),
+				long_description: Some(
+					"This is my long description".as_bytes().to_vec().try_into().unwrap()
+				),
+				image: Some("This is my image".as_bytes().to_vec().try_into().unwrap()),
+			},
+			ServiceFlow::default()
+		));
+
+		let _lab = Labs::lab_by_account_id(lab).unwrap();
+
+		let mut order_ids = Vec::new();
+		for i in 0..3 {
+			Timestamp::set_timestamp(i + 1);
+
+			assert_ok!(Orders::create_order(
+				RuntimeOrigin::signed(customer),
+				_lab.services[0],
+				0,
+				Keccak256::hash("0xhJ7TRe456FADD2726A132ABJK5RCc9E6fC5869F4".as_bytes()),
+				ServiceFlow::StakingRequestService,
+				None
+			));
+
+			order_ids.push(Orders::last_order_by_customer_id(customer).unwrap());
+			System::inc_account_nonce(customer);
+		}
+
+		assert_ok!(Orders::cancel_order(RuntimeOrigin::signed(customer), order_ids[1]));
+
+		let ids_of = |orders: Vec<OrderOf<Test>>| -> Vec<_> {
+			orders.into_iter().map(|order| order.id).collect()
+		};
+
+		// Pages follow the key order of the index
+		let indexed_order_ids = Orders::orders_by_customer_id(&customer);
+		let unpaid_order_ids: Vec<H256> =
+			indexed_order_ids.iter().copied().filter(|id| id != &order_ids[1]).collect();
+
+		assert_eq!(
+			ids_of(Orders::get_orders_by_customer_id(&customer, None, 0, u32::MAX)),
+			indexed_order_ids
+		);
+		assert_eq!(
+			ids_of(Orders::get_orders_by_customer_id(&customer, Some(OrderStatus::Unpaid), 0, 10)),
+			unpaid_order_ids
+		);
+		assert_eq!(
+			ids_of(Orders::get_orders_by_customer_id(&customer, Some(OrderStatus::Unpaid), 1, 1)),
+			vec![unpaid_order_ids[1]]
+		);
+		assert_eq!(
+			ids_of(Orders::get_orders_by_seller_id(&lab, Some(OrderStatus::Cancelled), 0, 10)),
+			vec![order_ids[1]]
+		);
+		assert_eq!(ids_of(Orders::get_orders_by_seller_id(&lab, None, 3, 10)), vec![]);
+	})
+}
+
+#[test]
+fn migrate_order_indexes_keeps_every_order_id() {
+	<ExternalityBuilder>::default().existential_deposit(1).build().execute_with(|| {
+		let customer = account_key("customer");
+		let lab = account_key("lab");
+		let order_ids: Vec<H256> = (0..3u8).map(|i| Keccak256::hash(&[i])).collect::<Vec<_>>();
+
+		put_storage_value(
+			b"Orders",
+			b"OrdersByCustomer",
+			&Blake2_128Concat::hash(&customer.encode()),
+			order_ids.clone(),
+		);
+		put_storage_value(
+			b"Orders",
+			b"OrdersBySeller",
+			&Blake2_128Concat::hash(&lab.encode()),
+			order_ids.clone(),
+		);
+		put_storage_value(
+			b"Orders",
+			b"PendingOrdersBySeller",
+			&Blake2_128Concat::hash(&lab.encode()),
+			vec![order_ids[2]],
+		);
+		StorageVersion::new(3).put::<Orders>();
+
+		migrations::migrate::<Test>();
+
+		let mut expected_order_ids = order_ids.clone();
+		expected_order_ids.sort();
+		let sorted = |mut order_ids: Vec<H256>| {
+			order_ids.sort();
+			order_ids
+		};
+
+		assert_eq!(sorted(Orders::orders_by_customer_id(&customer)), expected_order_ids);
+		assert_eq!(sorted(Orders::orders_by_lab_id(&lab)), expected_order_ids);
+		assert_eq!(Orders::pending_orders_by_seller_id(&lab), vec![order_ids[2]]);
+		assert_eq!(StorageVersion::get::<Orders>(), StorageVersion::new(4));
+	})
+}
+
+#[test]
+fn migrate_paid_orders_queues_their_refund_deadline() {
+	<ExternalityBuilder>::default().existential_deposit(1).build().execute_with(|| {
+		#[derive(Encode)]
+		struct OrderV1 {
+			id: H256,
+			service_id: H256,
+			customer_id: u64,
+			customer_box_public_key: H256,
+			seller_id: u64,
+			dna_sample_tracking_id: crate::DnaSampleTrackingId,
+			asset_id: Option<u32>,
+			currency: CurrencyType,
+			prices: Vec<(Vec<u8>, u64)>,
+			additional_prices: Vec<Price<u64>>,
+			total_price: u64,
+			status: OrderStatus,
+			order_flow: ServiceFlow,
+			created_at: u64,
+			updated_at: u64,
+		}
+
+		let order_ids: Vec<H256> = (0..2u8).map(|i| Keccak256::hash(&[i])).collect();
+		let statuses = [OrderStatus::Paid, OrderStatus::Fulfilled];
+		let qc_price = Price { component: b"qc_price".to_vec().try_into().unwrap(), value: 0 };
+		let additional_prices = [Vec::new(), vec![qc_price; MAX_ORDER_PRICES as usize + 1]];
+		let long_component = vec![b'a'; MAX_PRICE_COMPONENT_LENGTH as usize + 1];
+
+		for ((order_id, status), additional_prices) in
+			order_ids.iter().zip(statuses).zip(additional_prices)
+		{
+			put_storage_value(
+				b"Orders",
+				b"Orders",
+				&Blake2_128Concat::hash(&order_id.encode()),
+				OrderV1 {
+					id: *order_id,
+					service_id: Keccak256::hash("service".as_bytes()),
+					customer_id: account_key("customer"),
+					customer_box_public_key: Keccak256::hash("box".as_bytes()),
+					seller_id: account_key("lab"),
+					dna_sample_tracking_id: Default::default(),
+					asset_id: None,
+					currency: CurrencyType::DBIO,
+					prices: vec![(long_component.clone(), 10)],
+					additional_prices,
+					total_price: 10,
+					status,
+					order_flow: ServiceFlow::default(),
+					created_at: 5000,
+					updated_at: 5000,
+				},
+			);
+		}
+		StorageVersion::new(2).put::<Orders>();
+
+		migrations::migrate::<Test>();
+
+		// Unknown services have no expected duration, only the grace period is added
+		assert_eq!(Orders::order_by_id(order_ids[0]).unwrap().paid_amount, 10);
+		assert_eq!(Orders::refund_deadline_by_order_id(order_ids[0]), Some(6000));
+		assert_eq!(RefundDeadlines::<Test>::get(0, order_ids[0]), Some(6000));
+		assert_eq!(NextRefundBucket::<Test>::get(), Some(0));
+		assert_eq!(Orders::refund_deadline_by_order_id(order_ids[1]), None);
+		// Price lists longer than their bound are truncated
+		assert_eq!(
+			Orders::order_by_id(order_ids[1]).unwrap().additional_prices.len(),
+			MAX_ORDER_PRICES as usize
+		);
+		// Price components longer than their bound are truncated instead of dropping the order
+		assert_eq!(
+			Orders::order_by_id(order_ids[0]).unwrap().prices[0].component.to_vec(),
+			long_component[..MAX_PRICE_COMPONENT_LENGTH as usize].to_vec()
+		);
+		assert_eq!(StorageVersion::get::<Orders>(), StorageVersion::new(4));
+	})
+}
+
 #[test]
 fn update_key_works() {
 	<ExternalityBuilder>::default().existential_deposit(1).build().execute_with(|| {
@@ -1,15 +1,47 @@
 use crate::*;
 
-use frame_support::{pallet_prelude::*, traits::Currency};
-use primitives_price_and_currency::{CurrencyType, Price};
+use frame_support::{
+	pallet_prelude::*,
+	sp_runtime::{
+		traits::{AtLeast32Bit, AtLeast32BitUnsigned, Zero},
+		Perbill,
+	},
+	traits::Currency,
+	CloneNoBound, EqNoBound, PartialEqNoBound, RuntimeDebugNoBound,
+};
+use primitives_platform_fee::{FeeSchedule, FeeSplit, MAX_FEE_BENEFICIARIES};
+use primitives_price_and_currency::{CurrencyType, Price, PriceComponents};
 use scale_info::TypeInfo;
-use sp_std::vec::Vec;
+#[cfg(feature = "std")]
+use serde::{Deserialize, Serialize};
+use sp_std::{fmt::Debug, vec::Vec};
 use traits_genetic_testing::DnaSampleTrackingId;
 use traits_order::OrderInfo;
 use traits_services::types::ServiceFlow;
 
+pub const MAX_DISPUTE_REASON_LENGTH: u32 = 1024;
+
+/// Orders are queued for `on_idle` in buckets of this many milliseconds, so expired
+/// entries are walked in time order without reading the whole queue.
+pub const DEADLINE_BUCKET_MILLIS: u64 = 60 * 60 * 1000;
+
+/// Most orders returned by a single page.
+pub const MAX_PAGE_LIMIT: u32 = 100;
+
+/// Most price components or additional prices of an order, summed over the services of a
+/// bundle order.
+pub const MAX_ORDER_PRICES: u32 = 256;
+
+/// Most transfers a settlement pays out: the treasury, every fee beneficiary, the seller and
+/// the customer.
+pub const MAX_SETTLEMENT_TRANSFERS: u32 = MAX_FEE_BENEFICIARIES + 3;
+
 pub type AssetId = u32;
 pub type AssetBalance = u128;
+pub type DisputeReason = BoundedVec<u8, ConstU32<MAX_DISPUTE_REASON_LENGTH>>;
+pub type OrderPrices<Balance> = BoundedVec<Price<Balance>, ConstU32<MAX_ORDER_PRICES>>;
+pub type SettlementTransfers<AccountId, Balance> =
+	BoundedVec<(AccountId, Balance), ConstU32<MAX_SETTLEMENT_TRANSFERS>>;
 
 pub type AccountIdOf<T> = <T as frame_system::Config>::AccountId;
 pub type MomentOf<T> = <T as pallet_timestamp::Config>::Moment;
@@ -17,10 +49,19 @@ pub type HashOf<T> = <T as frame_system::Config>::Hash;
 pub type CurrencyOf<T> = <T as self::Config>::Currency;
 pub type BalanceOf<T> = <CurrencyOf<T> as Currency<AccountIdOf<T>>>::Balance;
 pub type OrderOf<T> = Order<HashOf<T>, AccountIdOf<T>, BalanceOf<T>, MomentOf<T>>;
-pub type OrderIdsOf<T> = Vec<HashOf<T>>;
+pub type OrderItemOf<T> = OrderItem<HashOf<T>, BalanceOf<T>>;
+pub type OrderItemsOf<T> = BoundedVec<OrderItemOf<T>, <T as Config>::MaxBundleItems>;
 pub type AccountKeyTypeOf<T> = AccountKeyType<AccountIdOf<T>>;
+pub type FeeScheduleOf<T> = FeeSchedule<AccountIdOf<T>, BalanceOf<T>>;
+pub type FeeSplitOf<T> = FeeSplit<AccountIdOf<T>, BalanceOf<T>>;
+pub type SettlementTransfersOf<T> = Vec<(AccountIdOf<T>, BalanceOf<T>)>;
+pub type OrderSettlementOf<T> = OrderSettlement<AccountIdOf<T>, BalanceOf<T>, MomentOf<T>>;
+pub type DisputeOf<T> = Dispute<AccountIdOf<T>, BalanceOf<T>, MomentOf<T>>;
+pub type DisputeResolutionOf<T> = DisputeResolution<BalanceOf<T>>;
+pub type PaymentPlanOf<T> = PaymentPlan<BalanceOf<T>, MomentOf<T>, <T as Config>::MaxInstallments>;
 
-#[derive(Encode, Decode, Clone, RuntimeDebug, PartialEq, Eq, TypeInfo)]
+#[derive(Encode, Decode, Clone, RuntimeDebug, PartialEq, Eq, TypeInfo, MaxEncodedLen)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
 pub enum OrderStatus {
 	Unpaid,
 	Paid,
@@ -28,6 +69,8 @@ pub enum OrderStatus {
 	Refunded,
 	Cancelled,
 	Failed,
+	Disputed,
+	Resolved,
 }
 impl Default for OrderStatus {
 	fn default() -> Self {
@@ -35,7 +78,8 @@ impl Default for OrderStatus {
 	}
 }
 
-#[derive(Encode, Decode, Clone, Default, RuntimeDebug, PartialEq, Eq, TypeInfo)]
+#[derive(Encode, Decode, Clone, Default, RuntimeDebug, PartialEq, Eq, TypeInfo, MaxEncodedLen)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
 pub struct Order<Hash, AccountId, Balance, Moment> {
 	pub id: Hash,
 	pub service_id: Hash,
@@ -45,9 +89,10 @@ pub struct Order<Hash, AccountId, Balance, Moment> {
 	pub dna_sample_tracking_id: DnaSampleTrackingId,
 	pub asset_id: Option<u32>,
 	pub currency: CurrencyType,
-	pub prices: Vec<Price<Balance>>,
-	pub additional_prices: Vec<Price<Balance>>,
+	pub prices: OrderPrices<Balance>,
+	pub additional_prices: OrderPrices<Balance>,
 	pub total_price: Balance,
+	pub paid_amount: Balance,
 	pub status: OrderStatus,
 	pub order_flow: ServiceFlow,
 	pub created_at: Moment,
@@ -57,6 +102,7 @@ pub struct Order<Hash, AccountId, Balance, Moment> {
 impl<Hash, AccountId, Balance, Moment> Order<Hash, AccountId, Balance, Moment>
 where
 	AccountId: PartialEq + Eq,
+	Balance: Zero,
 {
 	pub fn new(
 		id: Hash,
@@ -68,8 +114,8 @@ where
 		asset_id: Option<u32>,
 		currency: CurrencyType,
 		order_flow: ServiceFlow,
-		prices: Vec<Price<Balance>>,
-		additional_prices: Vec<Price<Balance>>,
+		prices: OrderPrices<Balance>,
+		additional_prices: OrderPrices<Balance>,
 		total_price: Balance,
 		created_at: Moment,
 		updated_at: Moment,
@@ -88,6 +134,7 @@ where
 			status: OrderStatus::default(),
 			order_flow,
 			total_price,
+			paid_amount: Zero::zero(),
 			created_at,
 			updated_at,
 		}
@@ -158,6 +205,15 @@ where
 		}
 	}
 
+	pub fn can_disputed(self) -> Option<Self> {
+		match self.status {
+			OrderStatus::Fulfilled => Some(self),
+			OrderStatus::Refunded => Some(self),
+			OrderStatus::Failed => Some(self),
+			_ => None,
+		}
+	}
+
 	pub fn can_refunded(self) -> Option<Self> {
 		if self.status == OrderStatus::Paid {
 			Some(self)
@@ -204,6 +260,170 @@ where
 	fn is_order_from_service(&self, service_id: &T::Hash) -> bool {
 		&self.service_id == service_id
 	}
+
+	fn seller_id(&self) -> &T::AccountId {
+		&self.seller_id
+	}
+}
+
+/// A single service inside a bundle order, tracked by its own dna sample
+#[derive(Encode, Decode, Clone, Default, RuntimeDebug, PartialEq, Eq, TypeInfo, MaxEncodedLen)]
+pub struct OrderItem<Hash, Balance> {
+	pub service_id: Hash,
+	pub dna_sample_tracking_id: DnaSampleTrackingId,
+	pub prices: PriceComponents<Balance>,
+	pub additional_prices: PriceComponents<Balance>,
+	pub total_price: Balance,
+	pub status: OrderStatus,
+}
+impl<Hash, Balance> OrderItem<Hash, Balance> {
+	pub fn new(
+		service_id: Hash,
+		dna_sample_tracking_id: DnaSampleTrackingId,
+		prices: PriceComponents<Balance>,
+		additional_prices: PriceComponents<Balance>,
+		total_price: Balance,
+	) -> Self {
+		Self {
+			service_id,
+			dna_sample_tracking_id,
+			prices,
+			additional_prices,
+			total_price,
+			status: OrderStatus::default(),
+		}
+	}
+}
+
+/// Funds of a fulfilled, refunded or failed order held in the pallet account until the
+/// dispute window closes
+#[derive(Encode, Decode, Clone, RuntimeDebug, PartialEq, Eq, TypeInfo, MaxEncodedLen)]
+pub struct OrderSettlement<AccountId, Balance, Moment> {
+	pub transfers: SettlementTransfers<AccountId, Balance>,
+	pub release_at: Moment,
+}
+impl<AccountId, Balance, Moment> OrderSettlement<AccountId, Balance, Moment>
+where
+	Balance: AtLeast32BitUnsigned + Copy,
+{
+	pub fn new(transfers: SettlementTransfers<AccountId, Balance>, release_at: Moment) -> Self {
+		Self { transfers, release_at }
+	}
+
+	/// Amount owed to the customer and the seller together, and the fee shares owed to
+	/// everyone else
+	pub fn split_parties(
+		self,
+		customer_id: &AccountId,
+		seller_id: &AccountId,
+	) -> (Balance, Vec<(AccountId, Balance)>)
+	where
+		AccountId: PartialEq,
+	{
+		let (parties, fees): (Vec<_>, Vec<_>) = self
+			.transfers
+			.into_iter()
+			.partition(|(account_id, _)| account_id == customer_id || account_id == seller_id);
+
+		let party_amount = parties.iter().fold(Zero::zero(), |total, (_, amount)| total + *amount);
+
+		(party_amount, fees)
+	}
+}
+
+#[derive(Encode, Decode, Clone, RuntimeDebug, PartialEq, Eq, TypeInfo, MaxEncodedLen)]
+pub struct DisputeResolution<Balance> {
+	pub customer_share: Perbill,
+	pub customer_amount: Balance,
+	pub seller_amount: Balance,
+}
+
+#[derive(Encode, Decode, Clone, RuntimeDebug, PartialEq, Eq, TypeInfo, MaxEncodedLen)]
+pub struct Dispute<AccountId, Balance, Moment> {
+	pub opened_by: AccountId,
+	pub reason: DisputeReason,
+	pub previous_status: OrderStatus,
+	pub opened_at: Moment,
+	pub resolution: Option<DisputeResolution<Balance>>,
+}
+impl<AccountId, Balance, Moment> Dispute<AccountId, Balance, Moment> {
+	pub fn new(
+		opened_by: AccountId,
+		reason: DisputeReason,
+		previous_status: OrderStatus,
+		opened_at: Moment,
+	) -> Self {
+		Self { opened_by, reason, previous_status, opened_at, resolution: None }
+	}
+}
+
+#[derive(Encode, Decode, Clone, RuntimeDebug, PartialEq, Eq, TypeInfo, MaxEncodedLen)]
+pub struct Installment<Balance, Moment> {
+	pub amount: Balance,
+	pub due_at: Moment,
+}
+
+/// Installments an unpaid order is funded with, the order becomes `Paid` once the
+/// last installment is paid
+#[derive(
+	Encode,
+	Decode,
+	CloneNoBound,
+	PartialEqNoBound,
+	EqNoBound,
+	RuntimeDebugNoBound,
+	TypeInfo,
+	MaxEncodedLen,
+)]
+#[scale_info(skip_type_params(MaxInstallments))]
+#[codec(mel_bound(Balance: MaxEncodedLen, Moment: MaxEncodedLen))]
+pub struct PaymentPlan<Balance, Moment, MaxInstallments>
+where
+	Balance: Clone + PartialEq + Eq + Debug,
+	Moment: Clone + PartialEq + Eq + Debug,
+	MaxInstallments: Get<u32>,
+{
+	pub installments: BoundedVec<Installment<Balance, Moment>, MaxInstallments>,
+	pub paid_installments: u32,
+}
+impl<Balance, Moment, MaxInstallments> PaymentPlan<Balance, Moment, MaxInstallments>
+where
+	Balance: AtLeast32BitUnsigned + Copy + Debug,
+	Moment: AtLeast32Bit + Copy + Debug,
+	MaxInstallments: Get<u32>,
+{
+	/// Split `total_price` into `count` installments due every `interval` starting from `now`,
+	/// the remainder of the division is added to the last installment.
+	/// Returns `None` when `count` is more than `MaxInstallments`.
+	pub fn new(total_price: Balance, count: u32, now: Moment, interval: Moment) -> Option<Self> {
+		let installment_amount = total_price / Balance::from(count);
+		let remainder = total_price - installment_amount * Balance::from(count);
+
+		let installments = (1..=count)
+			.map(|index| {
+				let amount = if index == count {
+					installment_amount + remainder
+				} else {
+					installment_amount
+				};
+				let due_at = now.saturating_add(interval.saturating_mul(Moment::from(index)));
+
+				Installment { amount, due_at }
+			})
+			.collect::<Vec<_>>();
+
+		let installments = BoundedVec::try_from(installments).ok()?;
+
+		Some(Self { installments, paid_installments: 0 })
+	}
+
+	pub fn next_installment(&self) -> Option<&Installment<Balance, Moment>> {
+		self.installments.get(self.paid_installments as usize)
+	}
+
+	pub fn is_completed(&self) -> bool {
+		self.paid_installments as usize >= self.installments.len()
+	}
 }
 
 #[derive(Encode, Decode, Clone, RuntimeDebug, PartialEq, Eq, TypeInfo)]
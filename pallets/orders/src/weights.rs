@@ -29,10 +29,18 @@ use sp_std::marker::PhantomData;
 /// Weight functions needed for orders.
 pub trait WeightInfo {
 	fn create_order() -> Weight;
+	fn create_bundle_order(n: u32, ) -> Weight;
 	fn cancel_order() -> Weight;
 	fn set_order_paid() -> Weight;
 	fn fulfill_order() -> Weight;
 	fn set_order_refunded() -> Weight;
+	fn claim_expired_refund() -> Weight;
+	fn open_dispute() -> Weight;
+	fn resolve_dispute() -> Weight;
+	fn release_order_settlement() -> Weight;
+	fn create_payment_plan() -> Weight;
+	fn pay_installment() -> Weight;
+	fn cancel_overdue_order() -> Weight;
 	fn update_key() -> Weight;
 }
 
@@ -56,6 +64,28 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(T::DbWeight::get().reads(10_u64))
 			.saturating_add(T::DbWeight::get().writes(8_u64))
 	}
+	// Storage: Services Services (r:1 w:0)
+	// Storage: System Account (r:1 w:0)
+	// Storage: Timestamp Now (r:1 w:0)
+	// Storage: RandomnessCollectiveFlip RandomMaterial (r:1 w:0)
+	// Storage: GeneticTesting DnaSamples (r:1 w:1)
+	// Storage: GeneticTesting DnaSamplesByOwner (r:1 w:1)
+	// Storage: GeneticTesting DnaSamplesByLab (r:1 w:1)
+	// Storage: Orders OrdersBySeller (r:1 w:1)
+	// Storage: Orders PendingOrdersBySeller (r:1 w:1)
+	// Storage: Orders OrdersByCustomer (r:1 w:1)
+	// Storage: Orders Orders (r:0 w:1)
+	// Storage: Orders LastOrderByCustomer (r:0 w:1)
+	// Storage: Orders OrderItems (r:0 w:1)
+	fn create_bundle_order(n: u32, ) -> Weight {
+		Weight::from_ref_time(104_218_000_u64)
+			// Standard Error: 41_000
+			.saturating_add(Weight::from_ref_time(63_905_000_u64).saturating_mul(n as u64))
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().reads((5_u64).saturating_mul(n as u64)))
+			.saturating_add(T::DbWeight::get().writes(6_u64))
+			.saturating_add(T::DbWeight::get().writes((3_u64).saturating_mul(n as u64)))
+	}
 	// Storage: Orders Orders (r:1 w:1)
 	// Storage: GeneticTesting DnaSamples (r:1 w:1)
 	// Storage: Timestamp Now (r:1 w:0)
@@ -67,27 +97,113 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	// Storage: Orders EscrowKey (r:1 w:0)
 	// Storage: Orders Orders (r:1 w:1)
 	// Storage: Timestamp Now (r:1 w:0)
+	// Storage: Services Services (r:1 w:0)
+	// Storage: Orders RefundDeadlines (r:1 w:1)
+	// Storage: Orders RefundDeadlineByOrder (r:0 w:1)
 	fn set_order_paid() -> Weight {
-		Weight::from_ref_time(66_688_000_u64)
-			.saturating_add(T::DbWeight::get().reads(3_u64))
-			.saturating_add(T::DbWeight::get().writes(1_u64))
+		Weight::from_ref_time(72_341_000_u64)
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
 	}
 	// Storage: Orders Orders (r:1 w:1)
 	// Storage: GeneticTesting DnaSamples (r:1 w:0)
 	// Storage: Timestamp Now (r:1 w:0)
+	// Storage: Orders OrderSettlements (r:0 w:1)
 	fn fulfill_order() -> Weight {
-		Weight::from_ref_time(41_668_000_u64)
+		Weight::from_ref_time(43_912_000_u64)
 			.saturating_add(T::DbWeight::get().reads(3_u64))
-			.saturating_add(T::DbWeight::get().writes(1_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
 	}
 	// Storage: Orders EscrowKey (r:1 w:0)
 	// Storage: Orders Orders (r:1 w:1)
 	// Storage: GeneticTesting DnaSamples (r:1 w:0)
 	// Storage: Timestamp Now (r:1 w:0)
+	// Storage: Orders OrderSettlements (r:0 w:1)
 	fn set_order_refunded() -> Weight {
-		Weight::from_ref_time(44_955_000_u64)
+		Weight::from_ref_time(46_837_000_u64)
 			.saturating_add(T::DbWeight::get().reads(4_u64))
-			.saturating_add(T::DbWeight::get().writes(1_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	// Storage: Orders Orders (r:1 w:1)
+	// Storage: Orders RefundDeadlineByOrder (r:1 w:1)
+	// Storage: Orders RefundDeadlines (r:1 w:1)
+	// Storage: Timestamp Now (r:1 w:0)
+	// Storage: GeneticTesting DnaSamples (r:1 w:0)
+	// Storage: Orders PalletAccount (r:1 w:0)
+	// Storage: System Account (r:2 w:2)
+	// Storage: Orders PendingOrdersBySeller (r:1 w:1)
+	fn claim_expired_refund() -> Weight {
+		Weight::from_ref_time(58_127_000_u64)
+			.saturating_add(T::DbWeight::get().reads(9_u64))
+			.saturating_add(T::DbWeight::get().writes(6_u64))
+	}
+	// Storage: Orders Orders (r:1 w:1)
+	// Storage: Orders OrderSettlements (r:1 w:0)
+	// Storage: Timestamp Now (r:1 w:0)
+	// Storage: Orders Disputes (r:0 w:1)
+	fn open_dispute() -> Weight {
+		Weight::from_ref_time(38_406_000_u64)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	// Storage: Orders Orders (r:1 w:1)
+	// Storage: Orders Disputes (r:1 w:1)
+	// Storage: Orders OrderSettlements (r:1 w:1)
+	// Storage: Orders PalletAccount (r:1 w:0)
+	// Storage: System Account (r:3 w:3)
+	// Storage: Timestamp Now (r:1 w:0)
+	fn resolve_dispute() -> Weight {
+		Weight::from_ref_time(71_254_000_u64)
+			.saturating_add(T::DbWeight::get().reads(8_u64))
+			.saturating_add(T::DbWeight::get().writes(6_u64))
+	}
+	// Storage: Orders Orders (r:1 w:0)
+	// Storage: Orders OrderSettlements (r:1 w:1)
+	// Storage: Timestamp Now (r:1 w:0)
+	// Storage: Orders PalletAccount (r:1 w:0)
+	// Storage: System Account (r:3 w:3)
+	fn release_order_settlement() -> Weight {
+		Weight::from_ref_time(64_530_000_u64)
+			.saturating_add(T::DbWeight::get().reads(7_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+	}
+	// Storage: Orders Orders (r:1 w:0)
+	// Storage: Orders PaymentPlans (r:1 w:1)
+	// Storage: Timestamp Now (r:1 w:0)
+	// Storage: Orders InstallmentDeadlines (r:1 w:1)
+	fn create_payment_plan() -> Weight {
+		Weight::from_ref_time(36_182_000_u64)
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	// Storage: Orders Orders (r:1 w:1)
+	// Storage: Orders PaymentPlans (r:1 w:1)
+	// Storage: Orders PalletAccount (r:1 w:0)
+	// Storage: System Account (r:2 w:2)
+	// Storage: Orders InstallmentDeadlines (r:1 w:1)
+	// Storage: Timestamp Now (r:1 w:0)
+	// Storage: Services Services (r:1 w:0)
+	// Storage: Orders RefundDeadlines (r:1 w:1)
+	// Storage: Orders RefundDeadlineByOrder (r:1 w:1)
+	fn pay_installment() -> Weight {
+		Weight::from_ref_time(79_514_000_u64)
+			.saturating_add(T::DbWeight::get().reads(10_u64))
+			.saturating_add(T::DbWeight::get().writes(7_u64))
+	}
+	// Storage: Orders Orders (r:1 w:1)
+	// Storage: Orders PaymentPlans (r:1 w:0)
+	// Storage: Timestamp Now (r:1 w:0)
+	// Storage: Orders PalletAccount (r:1 w:0)
+	// Storage: System Account (r:2 w:2)
+	// Storage: Orders OrderItems (r:1 w:0)
+	// Storage: GeneticTesting DnaSamples (r:0 w:1)
+	// Storage: Orders PendingOrdersBySeller (r:1 w:1)
+	// Storage: Orders RefundDeadlineByOrder (r:1 w:0)
+	// Storage: Orders InstallmentDeadlines (r:1 w:1)
+	fn cancel_overdue_order() -> Weight {
+		Weight::from_ref_time(74_906_000_u64)
+			.saturating_add(T::DbWeight::get().reads(11_u64))
+			.saturating_add(T::DbWeight::get().writes(6_u64))
 	}
 	// Storage: Orders EscrowKey (r:1 w:1)
 	fn update_key() -> Weight {
@@ -116,6 +232,28 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().reads(10_u64))
 			.saturating_add(RocksDbWeight::get().writes(8_u64))
 	}
+	// Storage: Services Services (r:1 w:0)
+	// Storage: System Account (r:1 w:0)
+	// Storage: Timestamp Now (r:1 w:0)
+	// Storage: RandomnessCollectiveFlip RandomMaterial (r:1 w:0)
+	// Storage: GeneticTesting DnaSamples (r:1 w:1)
+	// Storage: GeneticTesting DnaSamplesByOwner (r:1 w:1)
+	// Storage: GeneticTesting DnaSamplesByLab (r:1 w:1)
+	// Storage: Orders OrdersBySeller (r:1 w:1)
+	// Storage: Orders PendingOrdersBySeller (r:1 w:1)
+	// Storage: Orders OrdersByCustomer (r:1 w:1)
+	// Storage: Orders Orders (r:0 w:1)
+	// Storage: Orders LastOrderByCustomer (r:0 w:1)
+	// Storage: Orders OrderItems (r:0 w:1)
+	fn create_bundle_order(n: u32, ) -> Weight {
+		Weight::from_ref_time(104_218_000_u64)
+			// Standard Error: 41_000
+			.saturating_add(Weight::from_ref_time(63_905_000_u64).saturating_mul(n as u64))
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(RocksDbWeight::get().reads((5_u64).saturating_mul(n as u64)))
+			.saturating_add(RocksDbWeight::get().writes(6_u64))
+			.saturating_add(RocksDbWeight::get().writes((3_u64).saturating_mul(n as u64)))
+	}
 	// Storage: Orders Orders (r:1 w:1)
 	// Storage: GeneticTesting DnaSamples (r:1 w:1)
 	// Storage: Timestamp Now (r:1 w:0)
@@ -127,27 +265,113 @@ impl WeightInfo for () {
 	// Storage: Orders EscrowKey (r:1 w:0)
 	// Storage: Orders Orders (r:1 w:1)
 	// Storage: Timestamp Now (r:1 w:0)
+	// Storage: Services Services (r:1 w:0)
+	// Storage: Orders RefundDeadlines (r:1 w:1)
+	// Storage: Orders RefundDeadlineByOrder (r:0 w:1)
 	fn set_order_paid() -> Weight {
-		Weight::from_ref_time(66_688_000_u64)
-			.saturating_add(RocksDbWeight::get().reads(3_u64))
-			.saturating_add(RocksDbWeight::get().writes(1_u64))
+		Weight::from_ref_time(72_341_000_u64)
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
 	}
 	// Storage: Orders Orders (r:1 w:1)
 	// Storage: GeneticTesting DnaSamples (r:1 w:0)
 	// Storage: Timestamp Now (r:1 w:0)
+	// Storage: Orders OrderSettlements (r:0 w:1)
 	fn fulfill_order() -> Weight {
-		Weight::from_ref_time(41_668_000_u64)
+		Weight::from_ref_time(43_912_000_u64)
 			.saturating_add(RocksDbWeight::get().reads(3_u64))
-			.saturating_add(RocksDbWeight::get().writes(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
 	}
 	// Storage: Orders EscrowKey (r:1 w:0)
 	// Storage: Orders Orders (r:1 w:1)
 	// Storage: GeneticTesting DnaSamples (r:1 w:0)
 	// Storage: Timestamp Now (r:1 w:0)
+	// Storage: Orders OrderSettlements (r:0 w:1)
 	fn set_order_refunded() -> Weight {
-		Weight::from_ref_time(44_955_000_u64)
+		Weight::from_ref_time(46_837_000_u64)
 			.saturating_add(RocksDbWeight::get().reads(4_u64))
-			.saturating_add(RocksDbWeight::get().writes(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	// Storage: Orders Orders (r:1 w:1)
+	// Storage: Orders RefundDeadlineByOrder (r:1 w:1)
+	// Storage: Orders RefundDeadlines (r:1 w:1)
+	// Storage: Timestamp Now (r:1 w:0)
+	// Storage: GeneticTesting DnaSamples (r:1 w:0)
+	// Storage: Orders PalletAccount (r:1 w:0)
+	// Storage: System Account (r:2 w:2)
+	// Storage: Orders PendingOrdersBySeller (r:1 w:1)
+	fn claim_expired_refund() -> Weight {
+		Weight::from_ref_time(58_127_000_u64)
+			.saturating_add(RocksDbWeight::get().reads(9_u64))
+			.saturating_add(RocksDbWeight::get().writes(6_u64))
+	}
+	// Storage: Orders Orders (r:1 w:1)
+	// Storage: Orders OrderSettlements (r:1 w:0)
+	// Storage: Timestamp Now (r:1 w:0)
+	// Storage: Orders Disputes (r:0 w:1)
+	fn open_dispute() -> Weight {
+		Weight::from_ref_time(38_406_000_u64)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	// Storage: Orders Orders (r:1 w:1)
+	// Storage: Orders Disputes (r:1 w:1)
+	// Storage: Orders OrderSettlements (r:1 w:1)
+	// Storage: Orders PalletAccount (r:1 w:0)
+	// Storage: System Account (r:3 w:3)
+	// Storage: Timestamp Now (r:1 w:0)
+	fn resolve_dispute() -> Weight {
+		Weight::from_ref_time(71_254_000_u64)
+			.saturating_add(RocksDbWeight::get().reads(8_u64))
+			.saturating_add(RocksDbWeight::get().writes(6_u64))
+	}
+	// Storage: Orders Orders (r:1 w:0)
+	// Storage: Orders OrderSettlements (r:1 w:1)
+	// Storage: Timestamp Now (r:1 w:0)
+	// Storage: Orders PalletAccount (r:1 w:0)
+	// Storage: System Account (r:3 w:3)
+	fn release_order_settlement() -> Weight {
+		Weight::from_ref_time(64_530_000_u64)
+			.saturating_add(RocksDbWeight::get().reads(7_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+	}
+	// Storage: Orders Orders (r:1 w:0)
+	// Storage: Orders PaymentPlans (r:1 w:1)
+	// Storage: Timestamp Now (r:1 w:0)
+	// Storage: Orders InstallmentDeadlines (r:1 w:1)
+	fn create_payment_plan() -> Weight {
+		Weight::from_ref_time(36_182_000_u64)
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	// Storage: Orders Orders (r:1 w:1)
+	// Storage: Orders PaymentPlans (r:1 w:1)
+	// Storage: Orders PalletAccount (r:1 w:0)
+	// Storage: System Account (r:2 w:2)
+	// Storage: Orders InstallmentDeadlines (r:1 w:1)
+	// Storage: Timestamp Now (r:1 w:0)
+	// Storage: Services Services (r:1 w:0)
+	// Storage: Orders RefundDeadlines (r:1 w:1)
+	// Storage: Orders RefundDeadlineByOrder (r:1 w:1)
+	fn pay_installment() -> Weight {
+		Weight::from_ref_time(79_514_000_u64)
+			.saturating_add(RocksDbWeight::get().reads(10_u64))
+			.saturating_add(RocksDbWeight::get().writes(7_u64))
+	}
+	// Storage: Orders Orders (r:1 w:1)
+	// Storage: Orders PaymentPlans (r:1 w:0)
+	// Storage: Timestamp Now (r:1 w:0)
+	// Storage: Orders PalletAccount (r:1 w:0)
+	// Storage: System Account (r:2 w:2)
+	// Storage: Orders OrderItems (r:1 w:0)
+	// Storage: GeneticTesting DnaSamples (r:0 w:1)
+	// Storage: Orders PendingOrdersBySeller (r:1 w:1)
+	// Storage: Orders RefundDeadlineByOrder (r:1 w:0)
+	// Storage: Orders InstallmentDeadlines (r:1 w:1)
+	fn cancel_overdue_order() -> Weight {
+		Weight::from_ref_time(74_906_000_u64)
+			.saturating_add(RocksDbWeight::get().reads(11_u64))
+			.saturating_add(RocksDbWeight::get().writes(6_u64))
 	}
 	// Storage: Orders EscrowKey (r:1 w:1)
 	fn update_key() -> Weight {
@@ -25,6 +25,7 @@ pub trait OrderInfo<T: Config> {
 	fn is_order_to_lab(&self, account_id: &T::AccountId) -> bool;
 	fn is_account_order(&self, account_id: &T::AccountId) -> bool;
 	fn is_order_from_service(&self, service_id: &T::Hash) -> bool;
+	fn seller_id(&self) -> &T::AccountId;
 }
 
 pub trait OrderProvider<T: Config> {
@@ -0,0 +1,84 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+mod mock;
+
+#[allow(unused)]
+use reviews::Pallet as Reviews;
+use reviews::{Config as ReviewsConfig, TransactionType};
+
+use orders::{Config as OrdersConfig, Order, OrderStatus, Orders};
+
+use frame_benchmarking::{account, benchmarks, whitelisted_caller};
+use frame_support::sp_runtime::traits::{Hash, Zero};
+use frame_system::RawOrigin;
+use primitives_price_and_currency::CurrencyType;
+
+use pallet_timestamp::Config as TimestampConfig;
+
+pub struct Pallet<T: Config>(Reviews<T>);
+
+pub trait Config: ReviewsConfig + OrdersConfig + TimestampConfig {}
+
+use reviews::Call;
+
+const SEED: u32 = 0;
+
+fn fulfilled_order<T: Config>(customer: &T::AccountId, lab: &T::AccountId) -> T::Hash {
+	let order_id = T::Hashing::hash(b"order");
+	let now = pallet_timestamp::Pallet::<T>::get();
+
+	let mut order = Order::new(
+		order_id,
+		order_id,
+		customer.clone(),
+		order_id,
+		lab.clone(),
+		Default::default(),
+		None,
+		CurrencyType::DBIO,
+		Default::default(),
+		Default::default(),
+		Default::default(),
+		Zero::zero(),
+		now,
+		now,
+	);
+
+	order.status = OrderStatus::Fulfilled;
+
+	Orders::<T>::insert(order_id, order);
+
+	order_id
+}
+
+benchmarks! {
+	submit_review {
+		let caller: T::AccountId = whitelisted_caller();
+		let lab: T::AccountId = account("lab", 0, SEED);
+
+		let order_id = fulfilled_order::<T>(&caller, &lab);
+		let comment_hash = T::Hashing::hash(b"comment");
+	}: submit_review(RawOrigin::Signed(caller), TransactionType::Order, order_id, 5, comment_hash)
+
+	hide_review {
+		let caller: T::AccountId = whitelisted_caller();
+		let caller_origin = T::RuntimeOrigin::from(RawOrigin::Signed(caller.clone()));
+		let lab: T::AccountId = account("lab", 0, SEED);
+
+		let order_id = fulfilled_order::<T>(&caller, &lab);
+		let comment_hash = T::Hashing::hash(b"comment");
+
+		let _ = Reviews::<T>::submit_review(caller_origin, TransactionType::Order, order_id, 5, comment_hash);
+	}: hide_review(RawOrigin::Root, order_id)
+
+	unhide_review {
+		let caller: T::AccountId = whitelisted_caller();
+		let caller_origin = T::RuntimeOrigin::from(RawOrigin::Signed(caller.clone()));
+		let lab: T::AccountId = account("lab", 0, SEED);
+
+		let order_id = fulfilled_order::<T>(&caller, &lab);
+		let comment_hash = T::Hashing::hash(b"comment");
+
+		let _ = Reviews::<T>::submit_review(caller_origin, TransactionType::Order, order_id, 5, comment_hash);
+		let _ = Reviews::<T>::hide_review(RawOrigin::Root.into(), order_id);
+	}: unhide_review(RawOrigin::Root, order_id)
+}
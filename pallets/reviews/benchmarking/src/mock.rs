@@ -0,0 +1,400 @@
+#![cfg(test)]
+
+use frame_support::{
+	construct_runtime,
+	pallet_prelude::MaxEncodedLen,
+	parameter_types,
+	traits::{ConstU64, GenesisBuild},
+	PalletId,
+};
+use frame_system::EnsureRoot;
+use pallet_balances::AccountData;
+use scale_info::TypeInfo;
+use sp_core::{Decode, Encode, RuntimeDebug, H256};
+use sp_io::TestExternalities;
+use sp_runtime::{
+	testing::{Header, UintAuthorityId},
+	traits::{BlakeTwo256, Hash, IdentityLookup, Lazy, Verify},
+	Perbill,
+};
+
+use primitives_ethereum_address::EthereumAddress;
+use primitives_profile_roles::ProfileRoles;
+
+/// `TestSignature` over the hash of the message, so attestations have a maximum encoded length
+#[derive(Clone, PartialEq, Eq, Encode, Decode, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct TestAttestationSignature(pub u64, pub H256);
+
+impl Verify for TestAttestationSignature {
+	type Signer = UintAuthorityId;
+
+	fn verify<L: Lazy<[u8]>>(&self, mut msg: L, signer: &u64) -> bool {
+		signer == &self.0 && BlakeTwo256::hash(msg.get()) == self.1
+	}
+}
+
+type UncheckedExtrinsic = frame_system::mocking::MockUncheckedExtrinsic<Test>;
+type Block = frame_system::mocking::MockBlock<Test>;
+
+pub type AccountId = u64;
+
+construct_runtime!(
+	pub enum Test where
+		Block = Block,
+		NodeBlock = Block,
+		UncheckedExtrinsic = UncheckedExtrinsic,
+	{
+		System: frame_system,
+		Balances: pallet_balances,
+		Timestamp: pallet_timestamp,
+		RandomnessCollectiveFlip: pallet_randomness_collective_flip,
+		Assets: pallet_assets,
+		UserProfile: user_profile,
+		Labs: labs,
+		Services: services,
+		Certifications: credentials::<Instance1>,
+		Orders: orders,
+		GeneticTesting: genetic_testing,
+		GeneticData: genetic_data,
+		GeneticAnalysts: genetic_analysts,
+		GeneticAnalystServices: genetic_analyst_services,
+		GeneticAnalystQualifications: credentials::<Instance4>,
+		GeneticAnalysis: genetic_analysis,
+		GeneticAnalysisOrders: genetic_analysis_orders,
+		ElectronicMedicalRecord: electronic_medical_record,
+		HealthProfessional: health_professional,
+		HealthProfessionalQualification: credentials::<Instance5>,
+		OpinionRequestor: opinion_requestor,
+		Opinion: opinion,
+		Reviews: reviews,
+	}
+);
+
+parameter_types! {
+	pub const BlockHashCount: u64 = 250;
+	pub const SS58Prefix: u8 = 42;
+}
+
+impl frame_system::Config for Test {
+	type BaseCallFilter = frame_support::traits::Everything;
+	type BlockWeights = ();
+	type BlockLength = ();
+	type AccountId = AccountId;
+	type RuntimeCall = RuntimeCall;
+	type Lookup = IdentityLookup<Self::AccountId>;
+	type Index = u64;
+	type BlockNumber = u64;
+	type Hash = H256;
+	type Hashing = BlakeTwo256;
+	type Header = Header;
+	type RuntimeEvent = RuntimeEvent;
+	type RuntimeOrigin = RuntimeOrigin;
+	type BlockHashCount = BlockHashCount;
+	type DbWeight = ();
+	type Version = ();
+	type PalletInfo = PalletInfo;
+	type OnNewAccount = ();
+	type OnKilledAccount = ();
+	type AccountData = AccountData<Balance>;
+	type SystemWeightInfo = ();
+	type SS58Prefix = SS58Prefix;
+	type OnSetCode = ();
+	type MaxConsumers = frame_support::traits::ConstU32<16>;
+}
+
+pub type Moment = u64;
+pub const MILLISECS_PER_BLOCK: Moment = 6000;
+pub const SLOT_DURATION: Moment = MILLISECS_PER_BLOCK;
+
+parameter_types! {
+	pub const MinimumPeriod: Moment = SLOT_DURATION / 2;
+}
+
+impl pallet_timestamp::Config for Test {
+	/// A timestamp: milliseconds since the unix epoch.
+	type Moment = Moment;
+	type OnTimestampSet = ();
+	type MinimumPeriod = MinimumPeriod;
+	type WeightInfo = ();
+}
+
+impl pallet_randomness_collective_flip::Config for Test {}
+
+type Balance = u64;
+
+parameter_types! {
+	pub const ExistentialDeposit: Balance = 0;
+}
+
+impl pallet_balances::Config for Test {
+	type MaxLocks = ();
+	type MaxReserves = ();
+	type ReserveIdentifier = [u8; 8];
+	/// The type for recording an account's balance.
+	type Balance = Balance;
+	/// The ubiquitous event type.
+	type RuntimeEvent = RuntimeEvent;
+	type DustRemoval = ();
+	type ExistentialDeposit = ExistentialDeposit;
+	type AccountStore = System;
+	type WeightInfo = ();
+}
+
+pub type AssetId = u32;
+pub type AssetBalance = u128;
+
+parameter_types! {
+	pub const ApprovalDeposit: Balance = 1;
+	pub const AssetDeposit: Balance = 1;
+	pub const MetadataDepositBase: Balance = 1;
+	pub const MetadataDepositPerByte: Balance = 1;
+	pub const StringLimit: u32 = 50;
+}
+
+impl pallet_assets::Config for Test {
+	type RuntimeEvent = RuntimeEvent;
+	type Balance = AssetBalance;
+	type AssetId = AssetId;
+	type Currency = Balances;
+	type ForceOrigin = EnsureRoot<AccountId>;
+	type AssetAccountDeposit = ConstU64<10>;
+	type AssetDeposit = AssetDeposit;
+	type MetadataDepositBase = MetadataDepositBase;
+	type MetadataDepositPerByte = MetadataDepositPerByte;
+	type ApprovalDeposit = ApprovalDeposit;
+	type StringLimit = StringLimit;
+	type Freezer = ();
+	type Extra = ();
+	type WeightInfo = ();
+}
+
+impl user_profile::Config for Test {
+	type RuntimeEvent = RuntimeEvent;
+	type EthereumAddress = EthereumAddress;
+	type ProfileRoles = ProfileRoles;
+	type WeightInfo = ();
+}
+
+parameter_types! {
+	pub const LabPalletId: PalletId = PalletId(*b"dbio/lab");
+	pub const OrderPalletId: PalletId = PalletId(*b"dbio/ord");
+	pub const RefundGracePeriod: u64 = 1000;
+	pub const MaxExpiredRefundsPerBlock: u32 = 10;
+	pub const MaxBundleItems: u32 = 10;
+	pub const DisputeWindow: u64 = 0;
+	pub const InstallmentInterval: u64 = 1000;
+	pub const MaxInstallments: u32 = 12;
+	pub const MaxOverduePlansPerBlock: u32 = 10;
+	pub const MaxCustodyNoteLength: u32 = 64;
+}
+
+impl labs::Config for Test {
+	type RuntimeEvent = RuntimeEvent;
+	type Currency = Balances;
+	type Services = Services;
+	type Orders = Orders;
+	type Certifications = Certifications;
+	type EthereumAddress = EthereumAddress;
+	type ProfileRoles = ProfileRoles;
+	type UserProfile = UserProfile;
+	type LabWeightInfo = ();
+	type PalletId = LabPalletId;
+	type SlashOrigin = EnsureRoot<AccountId>;
+	type RequireVerifiedCertification = frame_support::traits::ConstBool<false>;
+	type MaxLabsPerCity = frame_support::traits::ConstU32<100>;
+}
+
+impl services::Config for Test {
+	type RuntimeEvent = RuntimeEvent;
+	type Currency = Balances;
+	type ServiceOwner = Labs;
+	type WeightInfo = ();
+}
+
+impl credentials::Config<credentials::Instance1> for Test {
+	type RuntimeEvent = RuntimeEvent;
+	type CredentialInfo = credentials::CertificationInfo;
+	type CredentialOwner = credentials::legacy::LegacyCertificationOwner<Labs>;
+	type CredentialIssuers = ();
+	type WeightInfo = ();
+}
+
+impl orders::Config for Test {
+	type RuntimeEvent = RuntimeEvent;
+	type Services = Services;
+	type GeneticTesting = GeneticTesting;
+	type Currency = Balances;
+	type Assets = Assets;
+	type OrdersWeightInfo = ();
+	type PalletId = OrderPalletId;
+	type RefundGracePeriod = RefundGracePeriod;
+	type MaxExpiredRefundsPerBlock = MaxExpiredRefundsPerBlock;
+	type MaxBundleItems = MaxBundleItems;
+	type DisputeWindow = DisputeWindow;
+	type ArbiterOrigin = EnsureRoot<AccountId>;
+	type InstallmentInterval = InstallmentInterval;
+	type MaxInstallments = MaxInstallments;
+	type MaxOverduePlansPerBlock = MaxOverduePlansPerBlock;
+}
+
+impl genetic_testing::Config for Test {
+	type RuntimeEvent = RuntimeEvent;
+	type Orders = Orders;
+	type RandomnessSource = RandomnessCollectiveFlip;
+	type GeneticTestingWeightInfo = ();
+	type MaxCustodyNoteLength = MaxCustodyNoteLength;
+	type AttestationSignature = TestAttestationSignature;
+	type AttestationSigner = UintAuthorityId;
+}
+
+parameter_types! {
+	pub const GeneticAnalystPalletId: PalletId = PalletId(*b"dbio/gen");
+	pub const GeneticAnalysisOrdersEscrowPalletId: PalletId = PalletId(*b"dbio/esc");
+}
+
+impl genetic_data::Config for Test {
+	type RuntimeEvent = RuntimeEvent;
+	type GeneticDataWeightInfo = ();
+	type MaxGeneticDataPerOwner = frame_support::traits::ConstU32<1_000>;
+}
+
+impl genetic_analysts::Config for Test {
+	type RuntimeEvent = RuntimeEvent;
+	type Currency = Balances;
+	type PalletId = GeneticAnalystPalletId;
+	type RequireVerifiedQualification = frame_support::traits::ConstBool<false>;
+	type GeneticAnalysisOrders = GeneticAnalysisOrders;
+	type GeneticAnalystServices = GeneticAnalystServices;
+	type GeneticAnalystQualifications = GeneticAnalystQualifications;
+	type EthereumAddress = EthereumAddress;
+	type ProfileRoles = ProfileRoles;
+	type UserProfile = UserProfile;
+	type GeneticAnalystWeightInfo = ();
+}
+
+impl genetic_analyst_services::Config for Test {
+	type RuntimeEvent = RuntimeEvent;
+	type Currency = Balances;
+	type GeneticAnalystServiceOwner = GeneticAnalysts;
+	type WeightInfo = ();
+}
+
+impl credentials::Config<credentials::Instance4> for Test {
+	type RuntimeEvent = RuntimeEvent;
+	type CredentialInfo = credentials::GeneticAnalystQualificationInfo;
+	type CredentialOwner =
+		credentials::legacy::LegacyGeneticAnalystQualificationOwner<GeneticAnalysts>;
+	type CredentialIssuers = ();
+	type WeightInfo = ();
+}
+
+impl genetic_analysis::Config for Test {
+	type RuntimeEvent = RuntimeEvent;
+	type RandomnessSource = RandomnessCollectiveFlip;
+	type GeneticAnalysisOrders = GeneticAnalysisOrders;
+	type GeneticAnalysisWeightInfo = ();
+}
+
+impl genetic_analysis_orders::Config for Test {
+	type RuntimeEvent = RuntimeEvent;
+	type Currency = Balances;
+	type Assets = Assets;
+	type GeneticData = GeneticData;
+	type GeneticAnalysts = GeneticAnalysts;
+	type GeneticAnalysis = GeneticAnalysis;
+	type GeneticAnalystServices = GeneticAnalystServices;
+	type GeneticAnalysisOrdersWeightInfo = ();
+	type PlatformFee = ();
+	type PalletId = GeneticAnalysisOrdersEscrowPalletId;
+}
+
+parameter_types! {
+	pub const MaxTitleLength: u32 = 256;
+	pub const MaxDescriptionLength: u32 = 1024;
+	pub const MaxRecordLinkLength: u32 = 512;
+	pub const MaxFilesPerRecord: u32 = 50;
+	pub const MaxRecordsPerOwner: u32 = 1_000;
+	pub const MaxPurposeLength: u32 = 256;
+	pub const MaxGranteesPerScope: u32 = 100;
+}
+
+impl electronic_medical_record::Config for Test {
+	type RuntimeEvent = RuntimeEvent;
+	type ElectronicMedicalRecord = ElectronicMedicalRecord;
+	type ElectronicMedicalRecordWeightInfo = ();
+	type MaxTitleLength = MaxTitleLength;
+	type MaxDescriptionLength = MaxDescriptionLength;
+	type MaxRecordLinkLength = MaxRecordLinkLength;
+	type MaxFilesPerRecord = MaxFilesPerRecord;
+	type MaxRecordsPerOwner = MaxRecordsPerOwner;
+	type MaxPurposeLength = MaxPurposeLength;
+	type MaxGranteesPerScope = MaxGranteesPerScope;
+}
+
+impl health_professional::Config for Test {
+	type RuntimeEvent = RuntimeEvent;
+	type Currency = Balances;
+	type HealthProfessionalQualifications = HealthProfessionalQualification;
+	type HealthProfessionalWeightInfo = ();
+}
+
+impl credentials::Config<credentials::Instance5> for Test {
+	type RuntimeEvent = RuntimeEvent;
+	type CredentialInfo = credentials::HealthProfessionalQualificationInfo;
+	type CredentialOwner =
+		credentials::legacy::LegacyHealthProfessionalQualificationOwner<HealthProfessional>;
+	type CredentialIssuers = ();
+	type WeightInfo = ();
+}
+
+impl opinion_requestor::Config for Test {
+	type RuntimeEvent = RuntimeEvent;
+	type ElectronicMedicalRecord = ElectronicMedicalRecord;
+	type OpinionRequestorWeightInfo = ();
+	type MaxInvitedProfessionals = frame_support::traits::ConstU32<10>;
+	type MaxRequestsPerOwner = frame_support::traits::ConstU32<100>;
+}
+
+parameter_types! {
+	pub const OpinionPalletId: PalletId = PalletId(*b"dbio/opn");
+	pub const OpinionPlatformFee: Perbill = Perbill::from_percent(5);
+	pub const OpinionPaymentTimeout: u64 = 1_000;
+}
+
+impl opinion::Config for Test {
+	type RuntimeEvent = RuntimeEvent;
+	type Currency = Balances;
+	type Assets = Assets;
+	type OpinionRequestor = OpinionRequestor;
+	type HealthProfessionals = HealthProfessional;
+	type OpinionWeightInfo = ();
+	type PalletId = OpinionPalletId;
+	type PlatformFee = OpinionPlatformFee;
+	type PaymentTimeout = OpinionPaymentTimeout;
+	type MaxOpinionsPerOwner = frame_support::traits::ConstU32<100>;
+}
+
+impl reviews::Config for Test {
+	type RuntimeEvent = RuntimeEvent;
+	type Orders = Orders;
+	type GeneticAnalysisOrders = GeneticAnalysisOrders;
+	type Opinions = Opinion;
+	type ModeratorOrigin = EnsureRoot<AccountId>;
+	type ReviewsWeightInfo = ();
+}
+
+pub struct ExternalityBuilder {}
+
+impl ExternalityBuilder {
+	pub fn build() -> TestExternalities {
+		let mut storage = frame_system::GenesisConfig::default().build_storage::<Test>().unwrap();
+
+		pallet_balances::GenesisConfig::<Test> { balances: vec![(3, 10_000)] }
+			.assimilate_storage(&mut storage)
+			.unwrap();
+
+		let mut ext = TestExternalities::new(storage);
+		ext.execute_with(|| System::set_block_number(1));
+		ext
+	}
+}
@@ -0,0 +1,46 @@
+use crate::*;
+use sp_std::vec::Vec;
+use traits_genetic_analysis_orders::{GeneticAnalysisOrderInfo, GeneticAnalysisOrderProvider};
+use traits_opinion::OpinionProvider;
+use traits_order::{OrderInfo, OrderProvider};
+
+impl<T: Config> Pallet<T> {
+	/// Returns the provider a reviewer may rate for the given transaction.
+	pub fn reviewed_provider(
+		reviewer: &T::AccountId,
+		transaction_type: &TransactionType,
+		transaction_id: &T::Hash,
+	) -> Result<T::AccountId, Error<T>> {
+		match transaction_type {
+			TransactionType::Order => T::Orders::get_order_by_id(transaction_id)
+				.filter(|order| order.is_order_fullfilled() && order.is_account_order(reviewer))
+				.map(|order| order.seller_id().clone()),
+			TransactionType::GeneticAnalysisOrder =>
+				T::GeneticAnalysisOrders::get_genetic_analysis_order_by_id(transaction_id)
+					.filter(|order| {
+						order.is_genetic_analysis_order_fulfilled() &&
+							order.is_account_genetic_analysis_order(reviewer)
+					})
+					.map(|order| order.seller_id().clone()),
+			TransactionType::Opinion =>
+				T::Opinions::paid_opinion_professional(transaction_id, reviewer),
+		}
+		.ok_or(Error::<T>::NotEligible)
+	}
+
+	/// Hidden reviews are skipped before the offset is applied. Reviews are paged in the key
+	/// order of the index, which is stable between calls but not chronological, and at most
+	/// `MAX_PAGE_LIMIT` reviews are returned.
+	pub fn get_reviews_by_provider(
+		provider: &T::AccountId,
+		offset: u32,
+		limit: u32,
+	) -> Vec<ReviewOf<T>> {
+		ReviewsByProvider::<T>::iter_key_prefix(provider)
+			.filter_map(Reviews::<T>::get)
+			.filter(|review| !review.hidden)
+			.skip(offset as usize)
+			.take(limit.min(MAX_PAGE_LIMIT) as usize)
+			.collect()
+	}
+}
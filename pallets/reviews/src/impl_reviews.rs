@@ -0,0 +1,77 @@
+use crate::*;
+
+impl<T: Config> ReviewsInterface<T> for Pallet<T> {
+	type Error = Error<T>;
+	type Review = ReviewOf<T>;
+	type TransactionType = TransactionType;
+
+	fn submit_review(
+		reviewer: &T::AccountId,
+		transaction_type: &Self::TransactionType,
+		transaction_id: &T::Hash,
+		rating: u8,
+		comment_hash: &T::Hash,
+	) -> Result<Self::Review, Self::Error> {
+		if !(MIN_RATING..=MAX_RATING).contains(&rating) {
+			return Err(Error::<T>::InvalidRating)
+		}
+
+		if Reviews::<T>::contains_key(transaction_id) {
+			return Err(Error::<T>::ReviewAlreadyExists)
+		}
+
+		let provider = Self::reviewed_provider(reviewer, transaction_type, transaction_id)?;
+		let now = pallet_timestamp::Pallet::<T>::get();
+		let review = Review::new(
+			*transaction_id,
+			*transaction_type,
+			reviewer.clone(),
+			provider.clone(),
+			rating,
+			*comment_hash,
+			now,
+		);
+
+		Reviews::<T>::insert(transaction_id, &review);
+		ReviewsByProvider::<T>::insert(&provider, transaction_id, ());
+		ProviderRatings::<T>::mutate(&provider, |provider_rating| {
+			provider_rating.add_rating(rating);
+		});
+
+		Ok(review)
+	}
+
+	fn hide_review(transaction_id: &T::Hash) -> Result<Self::Review, Self::Error> {
+		let mut review = Reviews::<T>::get(transaction_id).ok_or(Error::<T>::NotFound)?;
+
+		if review.hidden {
+			return Err(Error::<T>::ReviewAlreadyHidden)
+		}
+
+		review.hidden = true;
+
+		Reviews::<T>::insert(transaction_id, &review);
+		ProviderRatings::<T>::mutate(&review.provider, |provider_rating| {
+			provider_rating.remove_rating(review.rating);
+		});
+
+		Ok(review)
+	}
+
+	fn unhide_review(transaction_id: &T::Hash) -> Result<Self::Review, Self::Error> {
+		let mut review = Reviews::<T>::get(transaction_id).ok_or(Error::<T>::NotFound)?;
+
+		if !review.hidden {
+			return Err(Error::<T>::ReviewNotHidden)
+		}
+
+		review.hidden = false;
+
+		Reviews::<T>::insert(transaction_id, &review);
+		ProviderRatings::<T>::mutate(&review.provider, |provider_rating| {
+			provider_rating.add_rating(review.rating);
+		});
+
+		Ok(review)
+	}
+}
@@ -0,0 +1,17 @@
+pub trait ReviewsInterface<T: frame_system::Config> {
+	type Error;
+	type Review;
+	type TransactionType;
+
+	fn submit_review(
+		reviewer: &T::AccountId,
+		transaction_type: &Self::TransactionType,
+		transaction_id: &T::Hash,
+		rating: u8,
+		comment_hash: &T::Hash,
+	) -> Result<Self::Review, Self::Error>;
+
+	fn hide_review(transaction_id: &T::Hash) -> Result<Self::Review, Self::Error>;
+
+	fn unhide_review(transaction_id: &T::Hash) -> Result<Self::Review, Self::Error>;
+}
@@ -0,0 +1,153 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+
+pub use pallet::*;
+
+pub mod functions;
+pub mod impl_reviews;
+pub mod interface;
+pub mod types;
+pub mod weights;
+
+pub use types::*;
+
+use frame_support::traits::StorageVersion;
+use interface::ReviewsInterface;
+use weights::WeightInfo;
+
+/// The current storage version.
+const STORAGE_VERSION: StorageVersion = StorageVersion::new(0);
+
+#[frame_support::pallet]
+pub mod pallet {
+	use super::*;
+
+	use frame_support::pallet_prelude::*;
+	use frame_system::pallet_prelude::*;
+	use traits_genetic_analysis_orders::GeneticAnalysisOrderProvider;
+	use traits_opinion::OpinionProvider;
+	use traits_order::OrderProvider;
+
+	pub type AccountIdOf<T> = <T as frame_system::Config>::AccountId;
+	pub type HashOf<T> = <T as frame_system::Config>::Hash;
+	pub type MomentOf<T> = <T as pallet_timestamp::Config>::Moment;
+	pub type ReviewOf<T> = Review<AccountIdOf<T>, HashOf<T>, MomentOf<T>>;
+
+	#[pallet::pallet]
+	#[pallet::storage_version(STORAGE_VERSION)]
+	#[pallet::generate_store(pub(super) trait Store)]
+	pub struct Pallet<T>(_);
+
+	#[pallet::hooks]
+	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {}
+
+	#[pallet::config]
+	pub trait Config: frame_system::Config + pallet_timestamp::Config {
+		type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+		type Orders: OrderProvider<Self>;
+		type GeneticAnalysisOrders: GeneticAnalysisOrderProvider<Self>;
+		type Opinions: OpinionProvider<Self>;
+		/// Origin allowed to hide abusive reviews.
+		type ModeratorOrigin: EnsureOrigin<<Self as frame_system::Config>::RuntimeOrigin>;
+		type ReviewsWeightInfo: WeightInfo;
+	}
+
+	#[pallet::storage]
+	#[pallet::getter(fn review_by_transaction_id)]
+	pub type Reviews<T> = StorageMap<_, Blake2_128Concat, HashOf<T>, ReviewOf<T>>;
+
+	/// Get review ids by provider id
+	/// (AccountId, TransactionId) => ()
+	#[pallet::storage]
+	pub type ReviewsByProvider<T> =
+		StorageDoubleMap<_, Blake2_128Concat, AccountIdOf<T>, Blake2_128Concat, HashOf<T>, ()>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn rating_by_provider)]
+	pub type ProviderRatings<T> =
+		StorageMap<_, Blake2_128Concat, AccountIdOf<T>, ProviderRating, ValueQuery>;
+
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(super) fn deposit_event)]
+	pub enum Event<T: Config> {
+		/// parameters, [who, review]
+		ReviewSubmitted(AccountIdOf<T>, ReviewOf<T>),
+		/// parameters, [review]
+		ReviewHidden(ReviewOf<T>),
+		/// parameters, [review]
+		ReviewUnhidden(ReviewOf<T>),
+	}
+
+	#[pallet::error]
+	pub enum Error<T> {
+		/// Review does not exist
+		NotFound,
+		/// Rating is outside of `MIN_RATING..=MAX_RATING`
+		InvalidRating,
+		/// Transaction does not exist, is not completed or was not made by the reviewer
+		NotEligible,
+		/// Transaction has already been reviewed
+		ReviewAlreadyExists,
+		ReviewAlreadyHidden,
+		ReviewNotHidden,
+	}
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		#[pallet::weight(T::ReviewsWeightInfo::submit_review())]
+		pub fn submit_review(
+			origin: OriginFor<T>,
+			transaction_type: TransactionType,
+			transaction_id: HashOf<T>,
+			rating: u8,
+			comment_hash: HashOf<T>,
+		) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+
+			match <Self as ReviewsInterface<T>>::submit_review(
+				&who,
+				&transaction_type,
+				&transaction_id,
+				rating,
+				&comment_hash,
+			) {
+				Ok(review) => {
+					Self::deposit_event(Event::ReviewSubmitted(who, review));
+					Ok(().into())
+				},
+				Err(error) => Err(error.into()),
+			}
+		}
+
+		#[pallet::weight(T::ReviewsWeightInfo::hide_review())]
+		pub fn hide_review(
+			origin: OriginFor<T>,
+			transaction_id: HashOf<T>,
+		) -> DispatchResultWithPostInfo {
+			T::ModeratorOrigin::ensure_origin(origin)?;
+
+			match <Self as ReviewsInterface<T>>::hide_review(&transaction_id) {
+				Ok(review) => {
+					Self::deposit_event(Event::ReviewHidden(review));
+					Ok(().into())
+				},
+				Err(error) => Err(error.into()),
+			}
+		}
+
+		#[pallet::weight(T::ReviewsWeightInfo::unhide_review())]
+		pub fn unhide_review(
+			origin: OriginFor<T>,
+			transaction_id: HashOf<T>,
+		) -> DispatchResultWithPostInfo {
+			T::ModeratorOrigin::ensure_origin(origin)?;
+
+			match <Self as ReviewsInterface<T>>::unhide_review(&transaction_id) {
+				Ok(review) => {
+					Self::deposit_event(Event::ReviewUnhidden(review));
+					Ok(().into())
+				},
+				Err(error) => Err(error.into()),
+			}
+		}
+	}
+}
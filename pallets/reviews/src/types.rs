@@ -0,0 +1,97 @@
+use frame_support::{
+	pallet_prelude::{Decode, Encode, MaxEncodedLen},
+	RuntimeDebug,
+};
+use scale_info::TypeInfo;
+
+#[cfg(feature = "std")]
+use serde::{Deserialize, Serialize};
+
+/// Lowest rating a customer can give.
+pub const MIN_RATING: u8 = 1;
+/// Highest rating a customer can give.
+pub const MAX_RATING: u8 = 5;
+/// Most reviews returned by a single page.
+pub const MAX_PAGE_LIMIT: u32 = 100;
+
+/// Kind of completed transaction a review is attached to.
+#[derive(Encode, Decode, Clone, Copy, RuntimeDebug, PartialEq, Eq, TypeInfo, MaxEncodedLen)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub enum TransactionType {
+	/// Fulfilled lab order, reviews the lab
+	Order,
+	/// Fulfilled genetic analysis order, reviews the genetic analyst
+	GeneticAnalysisOrder,
+	/// Paid opinion, reviews the health professional
+	Opinion,
+}
+impl Default for TransactionType {
+	fn default() -> Self {
+		TransactionType::Order
+	}
+}
+
+#[derive(Encode, Decode, Clone, Default, RuntimeDebug, PartialEq, Eq, TypeInfo, MaxEncodedLen)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub struct Review<AccountId, Hash, Moment> {
+	pub transaction_id: Hash,
+	pub transaction_type: TransactionType,
+	pub reviewer: AccountId,
+	pub provider: AccountId,
+	pub rating: u8,
+	pub comment_hash: Hash,
+	pub hidden: bool,
+	pub created_at: Moment,
+}
+impl<AccountId, Hash, Moment> Review<AccountId, Hash, Moment> {
+	pub fn new(
+		transaction_id: Hash,
+		transaction_type: TransactionType,
+		reviewer: AccountId,
+		provider: AccountId,
+		rating: u8,
+		comment_hash: Hash,
+		created_at: Moment,
+	) -> Self {
+		Self {
+			transaction_id,
+			transaction_type,
+			reviewer,
+			provider,
+			rating,
+			comment_hash,
+			hidden: false,
+			created_at,
+		}
+	}
+}
+
+/// Aggregated rating of a provider over all of its visible reviews.
+#[derive(Encode, Decode, Clone, Default, RuntimeDebug, PartialEq, Eq, TypeInfo, MaxEncodedLen)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub struct ProviderRating {
+	/// Sum of all visible ratings
+	pub total_rating: u64,
+	/// Number of visible reviews
+	pub review_count: u32,
+}
+impl ProviderRating {
+	pub fn add_rating(&mut self, rating: u8) {
+		self.total_rating = self.total_rating.saturating_add(rating as u64);
+		self.review_count = self.review_count.saturating_add(1);
+	}
+
+	pub fn remove_rating(&mut self, rating: u8) {
+		self.total_rating = self.total_rating.saturating_sub(rating as u64);
+		self.review_count = self.review_count.saturating_sub(1);
+	}
+
+	/// Average rating multiplied by 100, rounded down.
+	pub fn average_rating(&self) -> u64 {
+		if self.review_count == 0 {
+			return 0
+		}
+
+		self.total_rating.saturating_mul(100) / self.review_count as u64
+	}
+}
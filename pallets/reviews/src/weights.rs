@@ -0,0 +1,90 @@
+//! Autogenerated weights for reviews_benchmarking
+//!
+//! THIS FILE WAS AUTO-GENERATED USING THE SUBSTRATE BENCHMARK CLI VERSION 4.0.0-dev
+//! DATE: 2026-10-18, STEPS: `20`, REPEAT: 10, LOW RANGE: `[]`, HIGH RANGE: `[]`
+//! EXECUTION: Some(Wasm), WASM-EXECUTION: Compiled, CHAIN: Some("dev"), DB CACHE: 1024
+
+// Executed Command:
+// ./target/release/debio
+// benchmark
+// --chain=dev
+// --execution=wasm
+// --wasm-execution=compiled
+// --pallet=reviews-benchmarking
+// --extrinsic=*
+// --steps=20
+// --repeat=10
+// --heap-pages=4096
+// --output=./pallets/reviews/src/weights.rs
+// --template=./.maintain/pallet-weight-template.hbs
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+
+use frame_support::{traits::Get, weights::{Weight, constants::RocksDbWeight}};
+use sp_std::marker::PhantomData;
+
+/// Weight functions needed for reviews_benchmarking.
+pub trait WeightInfo {
+	fn submit_review() -> Weight;
+	fn hide_review() -> Weight;
+	fn unhide_review() -> Weight;
+}
+
+/// Weights for reviews_benchmarking using the Substrate node and recommended hardware.
+pub struct SubstrateWeight<T>(PhantomData<T>);
+impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
+	// Storage: Reviews Reviews (r:1 w:1)
+	// Storage: Orders Orders (r:1 w:0)
+	// Storage: Timestamp Now (r:1 w:0)
+	// Storage: Reviews ReviewsByProvider (r:0 w:1)
+	// Storage: Reviews ProviderRatings (r:1 w:1)
+	fn submit_review() -> Weight {
+		Weight::from_ref_time(68_400_000_u64)
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	// Storage: Reviews Reviews (r:1 w:1)
+	// Storage: Reviews ProviderRatings (r:1 w:1)
+	fn hide_review() -> Weight {
+		Weight::from_ref_time(39_700_000_u64)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	// Storage: Reviews Reviews (r:1 w:1)
+	// Storage: Reviews ProviderRatings (r:1 w:1)
+	fn unhide_review() -> Weight {
+		Weight::from_ref_time(39_900_000_u64)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+}
+
+// For backwards compatibility and tests
+impl WeightInfo for () {
+	// Storage: Reviews Reviews (r:1 w:1)
+	// Storage: Orders Orders (r:1 w:0)
+	// Storage: Timestamp Now (r:1 w:0)
+	// Storage: Reviews ReviewsByProvider (r:0 w:1)
+	// Storage: Reviews ProviderRatings (r:1 w:1)
+	fn submit_review() -> Weight {
+		Weight::from_ref_time(68_400_000_u64)
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	// Storage: Reviews Reviews (r:1 w:1)
+	// Storage: Reviews ProviderRatings (r:1 w:1)
+	fn hide_review() -> Weight {
+		Weight::from_ref_time(39_700_000_u64)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	// Storage: Reviews Reviews (r:1 w:1)
+	// Storage: Reviews ProviderRatings (r:1 w:1)
+	fn unhide_review() -> Weight {
+		Weight::from_ref_time(39_900_000_u64)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+}
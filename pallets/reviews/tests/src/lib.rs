@@ -0,0 +1,357 @@
+mod mock;
+
+#[cfg(test)]
+
+mod test {
+	use crate::mock::*;
+
+	use frame_support::{
+		assert_noop, assert_ok,
+		sp_runtime::traits::{Hash, Keccak256},
+	};
+	use genetic_analysis_orders::{
+		GeneticAnalysisOrder, GeneticAnalysisOrderStatus, GeneticAnalysisOrders as GAOrders,
+	};
+	use health_professional::{HealthProfessional, HealthProfessionalInfo, HealthProfessionals};
+	use opinion::{OpinionInfo, Opinions, Status};
+	use opinion_requestor::RequestorInfo;
+	use orders::{Order, OrderStatus, Orders as OrderStorage};
+	use primitives_price_and_currency::CurrencyType;
+	use primitives_stake_status::StakeStatus;
+	use primitives_verification_status::VerificationStatus;
+	use reviews::{
+		Error, Event as ReviewsEvent, ProviderRating, Review, ReviewsByProvider, TransactionType,
+	};
+	use sp_core::H256;
+	use sp_runtime::traits::BadOrigin;
+
+	fn fulfilled_order(customer: AccountId, lab: AccountId) -> H256 {
+		let order_id = Keccak256::hash("order".as_bytes());
+
+		OrderStorage::<Test>::insert(
+			order_id,
+			Order {
+				id: order_id,
+				customer_id: customer,
+				seller_id: lab,
+				status: OrderStatus::Fulfilled,
+				..Default::default()
+			},
+		);
+
+		order_id
+	}
+
+	fn fulfilled_genetic_analysis_order(customer: AccountId, analyst: AccountId) -> H256 {
+		let genetic_analysis_order_id = Keccak256::hash("genetic_analysis_order".as_bytes());
+
+		GAOrders::<Test>::insert(
+			genetic_analysis_order_id,
+			GeneticAnalysisOrder {
+				id: genetic_analysis_order_id,
+				customer_id: customer,
+				seller_id: analyst,
+				status: GeneticAnalysisOrderStatus::Fulfilled,
+				..Default::default()
+			},
+		);
+
+		genetic_analysis_order_id
+	}
+
+	fn paid_opinion(customer: AccountId, doctor: AccountId) -> H256 {
+		let mut health_professional =
+			HealthProfessional::new(&doctor, &HealthProfessionalInfo::default());
+
+		health_professional.update_verification_status(&VerificationStatus::Verified);
+		health_professional.update_stake_status(StakeStatus::Staked, 1_000);
+
+		HealthProfessionals::<Test>::insert(doctor, health_professional);
+
+		let info = RequestorInfo::new(
+			b"category".to_vec().try_into().unwrap(),
+			b"description".to_vec().try_into().unwrap(),
+			Default::default(),
+			Default::default(),
+			b"myriad_url".to_vec().try_into().unwrap(),
+		);
+
+		assert_ok!(OpinionRequestor::request_opinion(RuntimeOrigin::signed(customer), info));
+
+		let requestor_id = OpinionRequestor::opinion_requestor_by_owner(customer)[0];
+
+		assert_ok!(OpinionRequestor::invite_professional(
+			RuntimeOrigin::signed(customer),
+			requestor_id,
+			doctor
+		));
+
+		assert_ok!(OpinionRequestor::accept_invitation(
+			RuntimeOrigin::signed(doctor),
+			requestor_id
+		));
+
+		let info = OpinionInfo::new(
+			b"description".to_vec().try_into().unwrap(),
+			b"myriad_url".to_vec().try_into().unwrap(),
+			None,
+			CurrencyType::DBIO,
+			1000,
+		);
+
+		assert_ok!(Opinion::submit(RuntimeOrigin::signed(doctor), requestor_id, info));
+
+		let opinion_id = Opinion::opinion_by_owner(doctor)[0];
+
+		Opinions::<Test>::mutate(opinion_id, |opinion| {
+			if let Some(opinion) = opinion {
+				opinion.update_status(&Status::Paid);
+			}
+		});
+
+		opinion_id
+	}
+
+	#[test]
+	fn submit_review_for_order_works() {
+		ExternalityBuilder::build().execute_with(|| {
+			let customer = 1;
+			let lab = 2;
+
+			let order_id = fulfilled_order(customer, lab);
+			let comment_hash = Keccak256::hash("comment".as_bytes());
+
+			assert_ok!(Reviews::submit_review(
+				RuntimeOrigin::signed(customer),
+				TransactionType::Order,
+				order_id,
+				4,
+				comment_hash,
+			));
+
+			let review =
+				Review::new(order_id, TransactionType::Order, customer, lab, 4, comment_hash, 0);
+
+			assert_eq!(Reviews::review_by_transaction_id(order_id), Some(review.clone()));
+			assert!(ReviewsByProvider::<Test>::contains_key(lab, order_id));
+			assert_eq!(
+				Reviews::rating_by_provider(lab),
+				ProviderRating { total_rating: 4, review_count: 1 }
+			);
+			assert_eq!(Reviews::get_reviews_by_provider(&lab, 0, 10), vec![review.clone()]);
+
+			System::assert_last_event(RuntimeEvent::Reviews(ReviewsEvent::ReviewSubmitted(
+				customer, review,
+			)));
+		})
+	}
+
+	#[test]
+	fn submit_review_for_genetic_analysis_order_works() {
+		ExternalityBuilder::build().execute_with(|| {
+			let customer = 1;
+			let analyst = 2;
+
+			let genetic_analysis_order_id = fulfilled_genetic_analysis_order(customer, analyst);
+			let comment_hash = Keccak256::hash("comment".as_bytes());
+
+			assert_ok!(Reviews::submit_review(
+				RuntimeOrigin::signed(customer),
+				TransactionType::GeneticAnalysisOrder,
+				genetic_analysis_order_id,
+				5,
+				comment_hash,
+			));
+
+			assert_eq!(
+				Reviews::rating_by_provider(analyst),
+				ProviderRating { total_rating: 5, review_count: 1 }
+			);
+		})
+	}
+
+	#[test]
+	fn submit_review_for_opinion_works() {
+		ExternalityBuilder::build().execute_with(|| {
+			let doctor = 2;
+			let customer = 3;
+
+			let opinion_id = paid_opinion(customer, doctor);
+			let comment_hash = Keccak256::hash("comment".as_bytes());
+
+			assert_ok!(Reviews::submit_review(
+				RuntimeOrigin::signed(customer),
+				TransactionType::Opinion,
+				opinion_id,
+				3,
+				comment_hash,
+			));
+
+			assert_eq!(
+				Reviews::rating_by_provider(doctor),
+				ProviderRating { total_rating: 3, review_count: 1 }
+			);
+		})
+	}
+
+	#[test]
+	fn cant_submit_review_when_invalid_rating() {
+		ExternalityBuilder::build().execute_with(|| {
+			let order_id = fulfilled_order(1, 2);
+			let comment_hash = Keccak256::hash("comment".as_bytes());
+
+			assert_noop!(
+				Reviews::submit_review(
+					RuntimeOrigin::signed(1),
+					TransactionType::Order,
+					order_id,
+					0,
+					comment_hash,
+				),
+				Error::<Test>::InvalidRating
+			);
+
+			assert_noop!(
+				Reviews::submit_review(
+					RuntimeOrigin::signed(1),
+					TransactionType::Order,
+					order_id,
+					6,
+					comment_hash,
+				),
+				Error::<Test>::InvalidRating
+			);
+		})
+	}
+
+	#[test]
+	fn cant_submit_review_when_not_eligible() {
+		ExternalityBuilder::build().execute_with(|| {
+			let order_id = fulfilled_order(1, 2);
+			let comment_hash = Keccak256::hash("comment".as_bytes());
+
+			assert_noop!(
+				Reviews::submit_review(
+					RuntimeOrigin::signed(3),
+					TransactionType::Order,
+					order_id,
+					4,
+					comment_hash,
+				),
+				Error::<Test>::NotEligible
+			);
+
+			OrderStorage::<Test>::mutate(order_id, |order| {
+				if let Some(order) = order {
+					order.status = OrderStatus::Paid;
+				}
+			});
+
+			assert_noop!(
+				Reviews::submit_review(
+					RuntimeOrigin::signed(1),
+					TransactionType::Order,
+					order_id,
+					4,
+					comment_hash,
+				),
+				Error::<Test>::NotEligible
+			);
+
+			assert_noop!(
+				Reviews::submit_review(
+					RuntimeOrigin::signed(1),
+					TransactionType::GeneticAnalysisOrder,
+					order_id,
+					4,
+					comment_hash,
+				),
+				Error::<Test>::NotEligible
+			);
+		})
+	}
+
+	#[test]
+	fn cant_submit_review_when_already_exists() {
+		ExternalityBuilder::build().execute_with(|| {
+			let order_id = fulfilled_order(1, 2);
+			let comment_hash = Keccak256::hash("comment".as_bytes());
+
+			assert_ok!(Reviews::submit_review(
+				RuntimeOrigin::signed(1),
+				TransactionType::Order,
+				order_id,
+				4,
+				comment_hash,
+			));
+
+			assert_noop!(
+				Reviews::submit_review(
+					RuntimeOrigin::signed(1),
+					TransactionType::Order,
+					order_id,
+					5,
+					comment_hash,
+				),
+				Error::<Test>::ReviewAlreadyExists
+			);
+		})
+	}
+
+	#[test]
+	fn hide_and_unhide_review_works() {
+		ExternalityBuilder::build().execute_with(|| {
+			let customer = 1;
+			let lab = 2;
+
+			let order_id = fulfilled_order(customer, lab);
+			let comment_hash = Keccak256::hash("comment".as_bytes());
+
+			assert_ok!(Reviews::submit_review(
+				RuntimeOrigin::signed(customer),
+				TransactionType::Order,
+				order_id,
+				4,
+				comment_hash,
+			));
+
+			assert_noop!(
+				Reviews::hide_review(RuntimeOrigin::signed(customer), order_id),
+				BadOrigin
+			);
+			assert_noop!(
+				Reviews::unhide_review(RuntimeOrigin::root(), order_id),
+				Error::<Test>::ReviewNotHidden
+			);
+
+			assert_ok!(Reviews::hide_review(RuntimeOrigin::root(), order_id));
+
+			assert_eq!(Reviews::rating_by_provider(lab), ProviderRating::default());
+			assert_eq!(Reviews::get_reviews_by_provider(&lab, 0, 10), Vec::new());
+			assert_noop!(
+				Reviews::hide_review(RuntimeOrigin::root(), order_id),
+				Error::<Test>::ReviewAlreadyHidden
+			);
+
+			assert_ok!(Reviews::unhide_review(RuntimeOrigin::root(), order_id));
+
+			assert_eq!(
+				Reviews::rating_by_provider(lab),
+				ProviderRating { total_rating: 4, review_count: 1 }
+			);
+			assert_eq!(Reviews::rating_by_provider(lab).average_rating(), 400);
+		})
+	}
+
+	#[test]
+	fn cant_hide_review_when_not_found() {
+		ExternalityBuilder::build().execute_with(|| {
+			let order_id = Keccak256::hash("order".as_bytes());
+
+			assert_noop!(
+				Reviews::hide_review(RuntimeOrigin::root(), order_id),
+				Error::<Test>::NotFound
+			);
+		})
+	}
+}
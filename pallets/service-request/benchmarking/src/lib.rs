@@ -17,9 +17,7 @@ use pallet_timestamp::{Config as TimestampConfig, Now};
 use services::{Config as ServiceConfig, Pallet as Services, ServiceInfo};
 
 #[allow(unused)]
-use service_request::{
-	AdminKey, Call, Config as ServiceRequestConfig, Pallet as ServiceRequest, RequestByAccountId,
-};
+use service_request::{AdminKey, Call, Config as ServiceRequestConfig, Pallet as ServiceRequest};
 
 use primitives_area_code::{CityCode, CountryCode, RegionCode};
 use primitives_duration::ExpectedDuration;
@@ -58,9 +56,9 @@ benchmarks! {
 		);
 
 		// Create request
-		let country = "Indonesia".as_bytes().to_vec();
-		let region = "West Java".as_bytes().to_vec();
-		let city = "Bogor".as_bytes().to_vec();
+		let country = "ID".as_bytes().to_vec();
+		let region = "JB".as_bytes().to_vec();
+		let city = "BOGOR".as_bytes().to_vec();
 		let service_category = "Vaksin".as_bytes().to_vec();
 	}: create_request(
 		RawOrigin::Signed(caller),
@@ -89,15 +87,15 @@ benchmarks! {
 		let origin = <T as frame_system::Config>::RuntimeOrigin::from(RawOrigin::Signed(caller.clone()));
 		let _new_request = ServiceRequest::<T>::create_request(
 			origin,
-			"Indonesia".as_bytes().to_vec(),
-			"West Java".as_bytes().to_vec(),
-			"Bogor".as_bytes().to_vec(),
+			"ID".as_bytes().to_vec(),
+			"JB".as_bytes().to_vec(),
+			"BOGOR".as_bytes().to_vec(),
 			"Vaksin".as_bytes().to_vec(),
 			total_staked
 		);
 
 		// Unstake
-		let request_ids = RequestByAccountId::<T>::get(caller.clone());
+		let request_ids = ServiceRequest::<T>::request_by_account_id(&caller);
 		let request_id = request_ids[0];
 	}: unstake(
 		RawOrigin::Signed(caller),
@@ -121,15 +119,15 @@ benchmarks! {
 		let origin = <T as frame_system::Config>::RuntimeOrigin::from(RawOrigin::Signed(caller.clone()));
 		let _new_request = ServiceRequest::<T>::create_request(
 			origin.clone(),
-			"Indonesia".as_bytes().to_vec(),
-			"West Java".as_bytes().to_vec(),
-			"Bogor".as_bytes().to_vec(),
+			"ID".as_bytes().to_vec(),
+			"JB".as_bytes().to_vec(),
+			"BOGOR".as_bytes().to_vec(),
 			"Vaksin".as_bytes().to_vec(),
 			total_staked
 		);
 
 		// Unstake
-		let request_ids = RequestByAccountId::<T>::get(caller.clone());
+		let request_ids = ServiceRequest::<T>::request_by_account_id(&caller);
 		let request_id = request_ids[0];
 		let _request_unstake = ServiceRequest::<T>::unstake(origin, request_id);
 
@@ -160,17 +158,17 @@ benchmarks! {
 		// Set lab info
 		let lab = LabInfo {
 			box_public_key: T::Hashing::hash("0xDb9Af2d1f3ADD2726A132AA7A65Cc9E6fC5761C3".as_bytes()),
-			name: "DeBio Lab".as_bytes().to_vec(),
-			email: "DeBio Email".as_bytes().to_vec(),
-			country: CountryCode::from_vec("DC".as_bytes().to_vec()),
-			region: RegionCode::from_vec("DB".as_bytes().to_vec()),
-			city: CityCode::from_vec("CITY".as_bytes().to_vec()),
-			address: "DeBio Address".as_bytes().to_vec(),
-			phone_number: "+6281394653625".as_bytes().to_vec(),
-			website: "DeBio Website".as_bytes().to_vec(),
-			latitude: Some("DeBio Latitude".as_bytes().to_vec()),
-			longitude: Some("DeBio Longtitude".as_bytes().to_vec()),
-			profile_image: Some("DeBio Profile Image uwu".as_bytes().to_vec()),
+			name: "DeBio Lab".as_bytes().to_vec().try_into().unwrap(),
+			email: "DeBio Email".as_bytes().to_vec().try_into().unwrap(),
+			country: CountryCode::try_from("ID".as_bytes().to_vec()).unwrap(),
+			region: RegionCode::try_from("JB".as_bytes().to_vec()).unwrap(),
+			city: CityCode::try_from("CITY".as_bytes().to_vec()).unwrap(),
+			address: "DeBio Address".as_bytes().to_vec().try_into().unwrap(),
+			phone_number: "+6281394653625".as_bytes().to_vec().try_into().unwrap(),
+			website: "DeBio Website".as_bytes().to_vec().try_into().unwrap(),
+			latitude: Some("-6.175392".as_bytes().to_vec().try_into().unwrap()),
+			longitude: Some("106.827153".as_bytes().to_vec().try_into().unwrap()),
+			profile_image: Some("DeBio Profile Image uwu".as_bytes().to_vec().try_into().unwrap()),
 		};
 
 		// register lab
@@ -187,33 +185,33 @@ benchmarks! {
 		let cust_origin = <T as frame_system::Config>::RuntimeOrigin::from(RawOrigin::Signed(customer.clone()));
 		let _new_request = ServiceRequest::<T>::create_request(
 			cust_origin,
-			"Indonesia".as_bytes().to_vec(),
-			"West Java".as_bytes().to_vec(),
-			"Bogor".as_bytes().to_vec(),
+			"ID".as_bytes().to_vec(),
+			"JB".as_bytes().to_vec(),
+			"BOGOR".as_bytes().to_vec(),
 			"Vaksin".as_bytes().to_vec(),
 			total_staked
 		);
 
-		let request_ids = RequestByAccountId::<T>::get(customer);
+		let request_ids = ServiceRequest::<T>::request_by_account_id(&customer);
 		let request_id = request_ids[0];
 
 		let prices_by_currency_dbio = PriceByCurrency {
 			currency: CurrencyType::DBIO,
 			total_price,
-			price_components: vec![Price { component: b"testing_price".to_vec(), value: testing_price }],
-			additional_prices: vec![Price { component: b"qc_price".to_vec(), value: qc_price }],
+			price_components: vec![Price { component: b"testing_price".to_vec().try_into().unwrap(), value: testing_price }].try_into().unwrap(),
+			additional_prices: vec![Price { component: b"qc_price".to_vec().try_into().unwrap(), value: qc_price }].try_into().unwrap(),
 		};
 
 		let service_info = ServiceInfo {
-			name: "DeBio service name".as_bytes().to_vec(),
-			prices_by_currency: vec![prices_by_currency_dbio],
+			name: "DeBio service name".as_bytes().to_vec().try_into().unwrap(),
+			prices_by_currency: vec![prices_by_currency_dbio].try_into().unwrap(),
 			expected_duration: ExpectedDuration::default(),
-			category: "DeBio service category".as_bytes().to_vec(),
-			description: "DeBio service description".as_bytes().to_vec(),
-			dna_collection_process: "DeBio service dna_collection_process".as_bytes().to_vec(),
-			test_result_sample: "DeBio service test_result_sample".as_bytes().to_vec(),
-			long_description: Some("DeBio service long_description".as_bytes().to_vec()),
-			image: Some("DeBio service image".as_bytes().to_vec()),
+			category: "DeBio service category".as_bytes().to_vec().try_into().unwrap(),
+			description: "DeBio service description".as_bytes().to_vec().try_into().unwrap(),
+			dna_collection_process: "DeBio service dna_collection_process".as_bytes().to_vec().try_into().unwrap(),
+			test_result_sample: "DeBio service test_result_sample".as_bytes().to_vec().try_into().unwrap(),
+			long_description: Some("DeBio service long_description".as_bytes().to_vec().try_into().unwrap()),
+			image: Some("DeBio service image".as_bytes().to_vec().try_into().unwrap()),
 		};
 
 		let _services = Services::<T>::create_service(
@@ -247,17 +245,17 @@ benchmarks! {
 		// Set lab info
 		let lab_info = LabInfo {
 			box_public_key: T::Hashing::hash("0xDb9Af2d1f3ADD2726A132AA7A65Cc9E6fC5761C3".as_bytes()),
-			name: "DeBio Lab".as_bytes().to_vec(),
-			email: "DeBio Email".as_bytes().to_vec(),
-			country: CountryCode::from_vec("DC".as_bytes().to_vec()),
-			region: RegionCode::from_vec("DB".as_bytes().to_vec()),
-			city: CityCode::from_vec("CITY".as_bytes().to_vec()),
-			address: "DeBio Address".as_bytes().to_vec(),
-			phone_number: "+6281394653625".as_bytes().to_vec(),
-			website: "DeBio Website".as_bytes().to_vec(),
-			latitude: Some("DeBio Latitude".as_bytes().to_vec()),
-			longitude: Some("DeBio Longtitude".as_bytes().to_vec()),
-			profile_image: Some("DeBio Profile Image uwu".as_bytes().to_vec()),
+			name: "DeBio Lab".as_bytes().to_vec().try_into().unwrap(),
+			email: "DeBio Email".as_bytes().to_vec().try_into().unwrap(),
+			country: CountryCode::try_from("ID".as_bytes().to_vec()).unwrap(),
+			region: RegionCode::try_from("JB".as_bytes().to_vec()).unwrap(),
+			city: CityCode::try_from("CITY".as_bytes().to_vec()).unwrap(),
+			address: "DeBio Address".as_bytes().to_vec().try_into().unwrap(),
+			phone_number: "+6281394653625".as_bytes().to_vec().try_into().unwrap(),
+			website: "DeBio Website".as_bytes().to_vec().try_into().unwrap(),
+			latitude: Some("-6.175392".as_bytes().to_vec().try_into().unwrap()),
+			longitude: Some("106.827153".as_bytes().to_vec().try_into().unwrap()),
+			profile_image: Some("DeBio Profile Image uwu".as_bytes().to_vec().try_into().unwrap()),
 		};
 
 		// register lab
@@ -278,33 +276,33 @@ benchmarks! {
 		let cust_origin = <T as frame_system::Config>::RuntimeOrigin::from(RawOrigin::Signed(caller.clone()));
 		let _new_request = ServiceRequest::<T>::create_request(
 			cust_origin.clone(),
-			"Indonesia".as_bytes().to_vec(),
-			"West Java".as_bytes().to_vec(),
-			"Bogor".as_bytes().to_vec(),
+			"ID".as_bytes().to_vec(),
+			"JB".as_bytes().to_vec(),
+			"BOGOR".as_bytes().to_vec(),
 			"Vaksin".as_bytes().to_vec(),
 			total_staked
 		);
 
-		let request_ids = RequestByAccountId::<T>::get(caller.clone());
+		let request_ids = ServiceRequest::<T>::request_by_account_id(&caller);
 		let request_id = request_ids[0];
 
 		let prices_by_currency_dbio = PriceByCurrency {
 			currency: CurrencyType::DBIO,
 			total_price,
-			price_components: vec![Price { component: b"testing_price".to_vec(), value: testing_price }],
-			additional_prices: vec![Price { component: b"qc_price".to_vec(), value: qc_price }],
+			price_components: vec![Price { component: b"testing_price".to_vec().try_into().unwrap(), value: testing_price }].try_into().unwrap(),
+			additional_prices: vec![Price { component: b"qc_price".to_vec().try_into().unwrap(), value: qc_price }].try_into().unwrap(),
 		};
 
 		let service_info = ServiceInfo {
-			name: "DeBio service name".as_bytes().to_vec(),
-			prices_by_currency: vec![prices_by_currency_dbio],
+			name: "DeBio service name".as_bytes().to_vec().try_into().unwrap(),
+			prices_by_currency: vec![prices_by_currency_dbio].try_into().unwrap(),
 			expected_duration: ExpectedDuration::default(),
-			category: "DeBio service category".as_bytes().to_vec(),
-			description: "DeBio service description".as_bytes().to_vec(),
-			dna_collection_process: "DeBio service dna_collection_process".as_bytes().to_vec(),
-			test_result_sample: "DeBio service test_result_sample".as_bytes().to_vec(),
-			long_description: Some("DeBio service long_description".as_bytes().to_vec()),
-			image: Some("DeBio service image".as_bytes().to_vec()),
+			category: "DeBio service category".as_bytes().to_vec().try_into().unwrap(),
+			description: "DeBio service description".as_bytes().to_vec().try_into().unwrap(),
+			dna_collection_process: "DeBio service dna_collection_process".as_bytes().to_vec().try_into().unwrap(),
+			test_result_sample: "DeBio service test_result_sample".as_bytes().to_vec().try_into().unwrap(),
+			long_description: Some("DeBio service long_description".as_bytes().to_vec().try_into().unwrap()),
+			image: Some("DeBio service image".as_bytes().to_vec().try_into().unwrap()),
 		};
 
 		let _ = Services::<T>::create_service(
@@ -355,17 +353,17 @@ benchmarks! {
 		// Seet lab info
 		let lab = LabInfo {
 			box_public_key: T::Hashing::hash("0xDb9Af2d1f3ADD2726A132AA7A65Cc9E6fC5761C3".as_bytes()),
-			name: "DeBio Lab".as_bytes().to_vec(),
-			email: "DeBio Email".as_bytes().to_vec(),
-			country: CountryCode::from_vec("DC".as_bytes().to_vec()),
-			region: RegionCode::from_vec("DB".as_bytes().to_vec()),
-			city: CityCode::from_vec("CITY".as_bytes().to_vec()),
-			address: "DeBio Address".as_bytes().to_vec(),
-			phone_number: "+6281394653625".as_bytes().to_vec(),
-			website: "DeBio Website".as_bytes().to_vec(),
-			latitude: Some("DeBio Latitude".as_bytes().to_vec()),
-			longitude: Some("DeBio Longtitude".as_bytes().to_vec()),
-			profile_image: Some("DeBio Profile Image uwu".as_bytes().to_vec()),
+			name: "DeBio Lab".as_bytes().to_vec().try_into().unwrap(),
+			email: "DeBio Email".as_bytes().to_vec().try_into().unwrap(),
+			country: CountryCode::try_from("ID".as_bytes().to_vec()).unwrap(),
+			region: RegionCode::try_from("JB".as_bytes().to_vec()).unwrap(),
+			city: CityCode::try_from("CITY".as_bytes().to_vec()).unwrap(),
+			address: "DeBio Address".as_bytes().to_vec().try_into().unwrap(),
+			phone_number: "+6281394653625".as_bytes().to_vec().try_into().unwrap(),
+			website: "DeBio Website".as_bytes().to_vec().try_into().unwrap(),
+			latitude: Some("-6.175392".as_bytes().to_vec().try_into().unwrap()),
+			longitude: Some("106.827153".as_bytes().to_vec().try_into().unwrap()),
+			profile_image: Some("DeBio Profile Image uwu".as_bytes().to_vec().try_into().unwrap()),
 		};
 
 		// Register lab
@@ -386,34 +384,34 @@ benchmarks! {
 		let cust_origin = <T as frame_system::Config>::RuntimeOrigin::from(RawOrigin::Signed(customer.clone()));
 		let _new_request = ServiceRequest::<T>::create_request(
 			cust_origin.clone(),
-			"Indonesia".as_bytes().to_vec(),
-			"West Java".as_bytes().to_vec(),
-			"Bogor".as_bytes().to_vec(),
+			"ID".as_bytes().to_vec(),
+			"JB".as_bytes().to_vec(),
+			"BOGOR".as_bytes().to_vec(),
 			"Vaksin".as_bytes().to_vec(),
 			total_staked
 		);
 
-		let request_ids = RequestByAccountId::<T>::get(customer.clone());
+		let request_ids = ServiceRequest::<T>::request_by_account_id(&customer);
 		let request_id = request_ids[0];
 
 		// Set service info
 		let prices_by_currency_dbio = PriceByCurrency {
 			currency: CurrencyType::DBIO,
 			total_price,
-			price_components: vec![Price { component: b"testing_price".to_vec(), value: testing_price }],
-			additional_prices: vec![Price { component: b"qc_price".to_vec(), value: qc_price }],
+			price_components: vec![Price { component: b"testing_price".to_vec().try_into().unwrap(), value: testing_price }].try_into().unwrap(),
+			additional_prices: vec![Price { component: b"qc_price".to_vec().try_into().unwrap(), value: qc_price }].try_into().unwrap(),
 		};
 
 		let service_info = ServiceInfo {
-			name: "DeBio service name".as_bytes().to_vec(),
-			prices_by_currency: vec![prices_by_currency_dbio],
+			name: "DeBio service name".as_bytes().to_vec().try_into().unwrap(),
+			prices_by_currency: vec![prices_by_currency_dbio].try_into().unwrap(),
 			expected_duration: ExpectedDuration::default(),
-			category: "DeBio service category".as_bytes().to_vec(),
-			description: "DeBio service description".as_bytes().to_vec(),
-			dna_collection_process: "DeBio service dna_collection_process".as_bytes().to_vec(),
-			test_result_sample: "DeBio service test_result_sample".as_bytes().to_vec(),
-			long_description: Some("DeBio service long_description".as_bytes().to_vec()),
-			image: Some("DeBio service image".as_bytes().to_vec()),
+			category: "DeBio service category".as_bytes().to_vec().try_into().unwrap(),
+			description: "DeBio service description".as_bytes().to_vec().try_into().unwrap(),
+			dna_collection_process: "DeBio service dna_collection_process".as_bytes().to_vec().try_into().unwrap(),
+			test_result_sample: "DeBio service test_result_sample".as_bytes().to_vec().try_into().unwrap(),
+			long_description: Some("DeBio service long_description".as_bytes().to_vec().try_into().unwrap()),
+			image: Some("DeBio service image".as_bytes().to_vec().try_into().unwrap()),
 		};
 
 		// claim request
@@ -448,15 +446,15 @@ benchmarks! {
 		);
 
 		let _ = Orders::<T>::set_order_paid(cust_origin, order_id);
-		let dna_sample = GeneticTesting::<T>::dna_samples_by_lab_id(caller.clone()).unwrap();
+		let dna_sample = GeneticTesting::<T>::dna_samples_by_lab_id(&caller);
 
 		let _ = GeneticTesting::<T>::submit_test_result(
 			lab_origin.clone(),
 			dna_sample[0].clone(),
 			DnaTestResultSubmission {
-				comments: Some("comment".as_bytes().to_vec()),
-				result_link: Some("result_link".as_bytes().to_vec()),
-				report_link: Some("report_link".as_bytes().to_vec()),
+				comments: Some("comment".as_bytes().to_vec().try_into().unwrap()),
+				result_link: Some("result_link".as_bytes().to_vec().try_into().unwrap()),
+				report_link: Some("report_link".as_bytes().to_vec().try_into().unwrap()),
 			}
 		);
 
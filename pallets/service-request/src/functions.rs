@@ -4,6 +4,7 @@ use frame_support::{
 	dispatch::DispatchError, sp_runtime::traits::AccountIdConversion, traits::ExistenceRequirement,
 	PalletId,
 };
+use primitives_area_code::{AreaCode, CityCode, CountryCode, RegionCode};
 
 pub const PALLET_ID: PalletId = PalletId(*b"reqsrvc!");
 
@@ -32,6 +33,70 @@ impl<T: Config> Pallet<T> {
 		T::Hashing::hash(&seed)
 	}
 
+	/// Validates the location against ISO 3166 and returns it upper cased, so the same location
+	/// always maps to the same ServiceCountRequest key
+	pub fn normalize_location(
+		country: Vec<u8>,
+		region: Vec<u8>,
+		city: Vec<u8>,
+	) -> Result<(Country, Region, City), Error<T>> {
+		let country_code = CountryCode::try_from(country)?;
+		let region_code = RegionCode::try_from_country(&country_code, region)?;
+		let city_code = CityCode::try_from(city)?;
+
+		let country =
+			country_code.to_vec().try_into().map_err(|_| Error::<T>::InvalidCountryCode)?;
+		let region = region_code.to_vec().try_into().map_err(|_| Error::<T>::InvalidRegionCode)?;
+		let city = city_code.to_vec().try_into().map_err(|_| Error::<T>::InvalidCityCode)?;
+
+		Ok((country, region, city))
+	}
+
+	pub fn get_requests_by_requester_id(
+		requester_id: &T::AccountId,
+		status: Option<RequestStatus>,
+		offset: u32,
+		limit: u32,
+	) -> Vec<RequestOf<T>> {
+		let request_ids = RequestByAccountId::<T>::iter_key_prefix(requester_id);
+		Self::paginate_requests(request_ids, status, offset, limit)
+	}
+
+	pub fn get_requests_by_lab_id(
+		lab_id: &T::AccountId,
+		status: Option<RequestStatus>,
+		offset: u32,
+		limit: u32,
+	) -> Vec<RequestOf<T>> {
+		let request_ids = RequestsByLabId::<T>::iter_key_prefix(lab_id);
+		Self::paginate_requests(request_ids, status, offset, limit)
+	}
+
+	/// The status filter is applied before the offset, so pages stay contiguous per status.
+	/// Requests are paged in the key order of the index, which is stable between calls but not
+	/// chronological. A page holds at most `MAX_PAGE_LIMIT` requests.
+	pub fn paginate_requests(
+		request_ids: impl Iterator<Item = T::Hash>,
+		status: Option<RequestStatus>,
+		offset: u32,
+		limit: u32,
+	) -> Vec<RequestOf<T>> {
+		request_ids
+			.filter_map(RequestById::<T>::get)
+			.filter(|request| status.as_ref().map_or(true, |status| &request.status == status))
+			.skip(offset as usize)
+			.take(limit.min(MAX_PAGE_LIMIT) as usize)
+			.collect()
+	}
+
+	pub fn request_by_account_id(requester_id: &T::AccountId) -> Vec<T::Hash> {
+		RequestByAccountId::<T>::iter_key_prefix(requester_id).collect()
+	}
+
+	pub fn requests_by_lab_id(lab_id: &T::AccountId) -> Vec<T::Hash> {
+		RequestsByLabId::<T>::iter_key_prefix(lab_id).collect()
+	}
+
 	pub fn do_transfer(
 		sender: &T::AccountId,
 		receiver: &T::AccountId,
@@ -58,3 +123,5 @@ impl<T: Config> Pallet<T> {
 		Ok(())
 	}
 }
+
+primitives_area_code::impl_from_area_code_error!(Error);
@@ -24,6 +24,10 @@ impl<T: Config> SeviceRequestInterface<T> for Pallet<T> {
 			return Err(Error::<T>::NotValidAmount)
 		}
 
+		let (country, region, city) = Self::normalize_location(country, region, city)?;
+		let service_category: ServiceCategory =
+			service_category.try_into().map_err(|_| Error::<T>::ServiceCategoryTooLong)?;
+
 		let request_id =
 			Self::generate_request_id(requester_id, &country, &region, &city, &service_category);
 
@@ -39,20 +43,21 @@ impl<T: Config> SeviceRequestInterface<T> for Pallet<T> {
 		let request = Request::new(
 			request_id,
 			requester_id,
-			&country,
-			&region,
-			&city,
-			&service_category,
+			country,
+			region,
+			city,
+			service_category,
 			staking_amount,
 			now,
 		);
 
 		RequestById::<T>::insert(request_id, &request);
-		RequestByAccountId::<T>::mutate(requester_id, |request_ids| request_ids.push(request_id));
+		RequestByAccountId::<T>::insert(requester_id, request_id, ());
 		StakingAccountIdByRequestId::<T>::insert(request_id, Self::staking_account_id(request_id));
-		ServiceCountRequest::<T>::mutate((country, region, city, service_category), |value| {
-			*value = value.wrapping_add(1);
-		});
+		ServiceCountRequest::<T>::mutate(
+			(&request.country, &request.region, &request.city, &request.service_category),
+			|value| *value = value.wrapping_add(1),
+		);
 
 		Ok(request)
 	}
@@ -157,15 +162,11 @@ impl<T: Config> SeviceRequestInterface<T> for Pallet<T> {
 
 			claimed_request = Some(request);
 		} else {
-			RequestsByLabId::<T>::try_mutate(lab_id, |value| {
-				if value.iter().any(|x| x == request_id) {
-					return Err(Error::<T>::RequestAlreadyInList)
-				}
-
-				value.push(*request_id);
+			if RequestsByLabId::<T>::contains_key(lab_id, request_id) {
+				return Err(Error::<T>::RequestAlreadyInList)
+			}
 
-				Ok(())
-			})?;
+			RequestsByLabId::<T>::insert(lab_id, request_id, ());
 		}
 
 		Ok(claimed_request)
@@ -283,9 +284,7 @@ impl<T: Config> SeviceRequestInterface<T> for Pallet<T> {
 				RequestByOrderId::<T>::remove(order_id);
 
 				// Removed from customer request list
-				RequestByAccountId::<T>::mutate(&request.requester_address, |request_ids| {
-					request_ids.retain(|&x| x != *request_id);
-				});
+				RequestByAccountId::<T>::remove(&request.requester_address, request_id);
 
 				// Update service count request
 				ServiceCountRequest::<T>::mutate(
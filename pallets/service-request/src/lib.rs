@@ -35,7 +35,7 @@ pub use weights::WeightInfo;
 pub use frame_support::traits::StorageVersion;
 
 /// The current storage version.
-const STORAGE_VERSION: StorageVersion = StorageVersion::new(2);
+const STORAGE_VERSION: StorageVersion = StorageVersion::new(5);
 
 #[frame_support::pallet]
 pub mod pallet {
@@ -116,6 +116,14 @@ pub mod pallet {
 		AssetNotExists,
 		OrderNotFound,
 		ServiceNotFound,
+		/// Country is not an ISO 3166-1 alpha-2 code
+		InvalidCountryCode,
+		/// Region is not an ISO 3166-2 subdivision of the country
+		InvalidRegionCode,
+		/// City code is empty, too long or not printable
+		InvalidCityCode,
+		/// Service category is longer than MAX_SERVICE_CATEGORY_LENGTH
+		ServiceCategoryTooLong,
 	}
 
 	#[pallet::hooks]
@@ -128,7 +136,6 @@ pub mod pallet {
 	#[pallet::pallet]
 	#[pallet::storage_version(STORAGE_VERSION)]
 	#[pallet::generate_store(pub(super) trait Store)]
-	#[pallet::without_storage_info]
 	pub struct Pallet<T>(_);
 
 	#[pallet::storage]
@@ -142,10 +149,10 @@ pub mod pallet {
 		StorageMap<_, Blake2_128Concat, HashOf<T>, AccountIdOf<T>, OptionQuery>;
 
 	/// Get Request by Account Id
+	/// (AccountId, RequestId) => ()
 	#[pallet::storage]
-	#[pallet::getter(fn request_by_account_id)]
 	pub type RequestByAccountId<T> =
-		StorageMap<_, Blake2_128Concat, AccountIdOf<T>, Vec<HashOf<T>>, ValueQuery>;
+		StorageDoubleMap<_, Blake2_128Concat, AccountIdOf<T>, Blake2_128Concat, HashOf<T>, ()>;
 
 	/// Get Request by RequestId
 	#[pallet::storage]
@@ -153,10 +160,10 @@ pub mod pallet {
 	pub type RequestById<T> = StorageMap<_, Blake2_128Concat, HashOf<T>, RequestOf<T>>;
 
 	/// Get Request by LabId
+	/// (AccountId, RequestId) => ()
 	#[pallet::storage]
-	#[pallet::getter(fn requests_by_lab_id)]
 	pub type RequestsByLabId<T> =
-		StorageMap<_, Blake2_128Concat, AccountIdOf<T>, Vec<HashOf<T>>, ValueQuery>;
+		StorageDoubleMap<_, Blake2_128Concat, AccountIdOf<T>, Blake2_128Concat, HashOf<T>, ()>;
 
 	/// Get Request by OrderId
 	#[pallet::storage]
@@ -1,13 +1,14 @@
 use crate::{
-	AccountIdOf, BalanceOf, Config, HashOf, Pallet, Request as NewRequest, RequestById,
-	RequestByOrderId, RequestStatus,
+	AccountIdOf, BalanceOf, City, Config, Country, HashOf, Pallet, Region, Request as NewRequest,
+	RequestById, RequestByOrderId, RequestOf, RequestStatus, ServiceCountRequest,
 };
 use frame_support::{
+	log,
 	pallet_prelude::{Decode, Encode},
 	storage_alias,
 	traits::Get,
 	weights::Weight,
-	Blake2_128Concat,
+	Blake2_128Concat, BoundedVec,
 };
 use sp_std::vec::Vec;
 
@@ -27,6 +28,21 @@ pub fn migrate<T: Config>() -> Weight {
 		version = StorageVersion::new(2);
 	}
 
+	if version == 2 {
+		weight = weight.saturating_add(version::v3::migrate::<T>());
+		version = StorageVersion::new(3);
+	}
+
+	if version == 3 {
+		weight = weight.saturating_add(version::v4::migrate::<T>());
+		version = StorageVersion::new(4);
+	}
+
+	if version == 4 {
+		weight = weight.saturating_add(version::v5::migrate::<T>());
+		version = StorageVersion::new(5);
+	}
+
 	version.put::<Pallet<T>>();
 	weight
 }
@@ -34,6 +50,21 @@ pub fn migrate<T: Config>() -> Weight {
 mod version {
 	use super::*;
 
+	/// Truncates `value` to the bound and logs the entry when it did not fit.
+	fn bounded<I, S: Get<u32>>(value: Vec<I>, entry: &str) -> BoundedVec<I, S> {
+		if value.len() > S::get() as usize {
+			log::warn!(
+				target: "runtime::service-request",
+				"{} exceeds its bound of {}, truncated from {} items",
+				entry,
+				S::get(),
+				value.len(),
+			);
+		}
+
+		BoundedVec::truncate_from(value)
+	}
+
 	pub mod v1 {
 		use super::*;
 
@@ -265,10 +296,10 @@ mod version {
 					lab_address: request.lab_address,
 					service_id,
 					order_id,
-					country: request.country,
-					region: request.region,
-					city: request.city,
-					service_category: request.service_category,
+					country: bounded(request.country, "request country"),
+					region: bounded(request.region, "request region"),
+					city: bounded(request.city, "request city"),
+					service_category: bounded(request.service_category, "request service category"),
 					staking_amount: request.staking_amount,
 					status: request.status,
 					created_at: request.created_at,
@@ -301,4 +332,169 @@ mod version {
 			weight
 		}
 	}
+
+	pub mod v3 {
+		use super::*;
+
+		#[storage_alias]
+		type RequestByAccountId<T: Config> =
+			StorageMap<Pallet<T>, Blake2_128Concat, AccountIdOf<T>, Vec<HashOf<T>>>;
+
+		#[storage_alias]
+		type RequestsByLabId<T: Config> =
+			StorageMap<Pallet<T>, Blake2_128Concat, AccountIdOf<T>, Vec<HashOf<T>>>;
+
+		/// Request id indexes move from one list per account to one key per request id, every
+		/// request id is kept
+		pub fn migrate<T: Config>() -> Weight {
+			let mut weight = T::DbWeight::get().writes(1);
+
+			weight = weight.saturating_add(move_index::<T>(
+				RequestByAccountId::<T>::drain().collect(),
+				|requester_id, request_id| {
+					crate::RequestByAccountId::<T>::insert(requester_id, request_id, ())
+				},
+			));
+
+			weight = weight.saturating_add(move_index::<T>(
+				RequestsByLabId::<T>::drain().collect(),
+				|lab_id, request_id| crate::RequestsByLabId::<T>::insert(lab_id, request_id, ()),
+			));
+
+			weight
+		}
+
+		fn move_index<T: Config>(
+			index: Vec<(AccountIdOf<T>, Vec<HashOf<T>>)>,
+			insert: impl Fn(&AccountIdOf<T>, &HashOf<T>),
+		) -> Weight {
+			let mut weight = Weight::zero();
+
+			for (account_id, request_ids) in index {
+				weight = weight.saturating_add(
+					T::DbWeight::get().reads_writes(1, request_ids.len() as u64 + 1),
+				);
+
+				for request_id in request_ids.iter() {
+					insert(&account_id, request_id);
+				}
+			}
+
+			weight
+		}
+	}
+
+	pub mod v4 {
+		use super::*;
+
+		/// Request locations and service categories become bounded. Legacy values that exceed a
+		/// bound are truncated and logged.
+		pub fn migrate<T: Config>() -> Weight {
+			let mut weight = T::DbWeight::get().writes(1);
+
+			#[derive(Decode, Encode)]
+			pub struct OldRequest<AccountId, Balance, Hash> {
+				pub hash: Hash,
+				pub requester_address: AccountId,
+				pub lab_address: Option<AccountId>,
+				pub service_id: Option<Hash>,
+				pub order_id: Option<Hash>,
+				pub country: Vec<u8>,
+				pub region: Vec<u8>,
+				pub city: Vec<u8>,
+				pub service_category: Vec<u8>,
+				pub staking_amount: Balance,
+				pub status: RequestStatus,
+				pub created_at: u128,
+				pub updated_at: Option<u128>,
+				pub unstaked_at: Option<u128>,
+			}
+
+			pub type OldRequestOf<T> = OldRequest<AccountIdOf<T>, BalanceOf<T>, HashOf<T>>;
+
+			RequestById::<T>::translate(|_request_id, request: OldRequestOf<T>| {
+				weight = weight.saturating_add(T::DbWeight::get().reads_writes(1, 1));
+
+				Some(NewRequest {
+					hash: request.hash,
+					requester_address: request.requester_address,
+					lab_address: request.lab_address,
+					service_id: request.service_id,
+					order_id: request.order_id,
+					country: bounded(request.country, "request country"),
+					region: bounded(request.region, "request region"),
+					city: bounded(request.city, "request city"),
+					service_category: bounded(request.service_category, "request service category"),
+					staking_amount: request.staking_amount,
+					status: request.status,
+					created_at: request.created_at,
+					updated_at: request.updated_at,
+					unstaked_at: request.unstaked_at,
+				})
+			});
+
+			weight
+		}
+	}
+
+	pub mod v5 {
+		use super::*;
+
+		/// Validates and upper cases a location stored before locations were validated. Locations
+		/// which are not ISO 3166 are only upper cased and logged.
+		fn normalized_location<T: Config>(
+			country: &Country,
+			region: &Region,
+			city: &City,
+		) -> (Country, Region, City) {
+			Pallet::<T>::normalize_location(country.to_vec(), region.to_vec(), city.to_vec())
+				.unwrap_or_else(|error| {
+					log::warn!(
+						target: "runtime::service-request",
+						"location {:?} {:?} {:?} is not ISO 3166 ({:?}), only upper cased",
+						country,
+						region,
+						city,
+						error,
+					);
+
+					(
+						BoundedVec::truncate_from(country.to_ascii_uppercase()),
+						BoundedVec::truncate_from(region.to_ascii_uppercase()),
+						BoundedVec::truncate_from(city.to_ascii_uppercase()),
+					)
+				})
+		}
+
+		/// Request locations are normalized, and ServiceCountRequest entries are re-keyed by the
+		/// normalized location so differently cased locations are merged into one count.
+		pub fn migrate<T: Config>() -> Weight {
+			let mut weight = T::DbWeight::get().writes(1);
+
+			RequestById::<T>::translate(|_request_id, mut request: RequestOf<T>| {
+				weight = weight.saturating_add(T::DbWeight::get().reads_writes(1, 1));
+
+				let (country, region, city) =
+					normalized_location::<T>(&request.country, &request.region, &request.city);
+				request.country = country;
+				request.region = region;
+				request.city = city;
+
+				Some(request)
+			});
+
+			let service_counts = ServiceCountRequest::<T>::drain().collect::<Vec<_>>();
+			for ((country, region, city, service_category), count) in service_counts {
+				weight = weight.saturating_add(T::DbWeight::get().reads_writes(2, 2));
+
+				let (country, region, city) = normalized_location::<T>(&country, &region, &city);
+				ServiceCountRequest::<T>::mutate(
+					(&country, &region, &city, &service_category),
+					|value| *value = value.saturating_add(count),
+				);
+			}
+
+			weight
+		}
+	}
 }
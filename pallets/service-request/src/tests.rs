@@ -1,7 +1,15 @@
-use crate::{mock::*, AdminKey, Error, Request, RequestStatus};
+use crate::{
+	migrations, mock::*, AdminKey, City, Country, Error, Region, Request, RequestById, RequestOf,
+	RequestStatus, RequestsByLabId, ServiceCategory, ServiceCountRequest,
+	MAX_SERVICE_CATEGORY_LENGTH,
+};
+use codec::Encode;
 use frame_support::{
 	assert_noop, assert_ok,
 	sp_runtime::traits::{Hash, Keccak256},
+	storage::migration::put_storage_value,
+	traits::StorageVersion,
+	Blake2_128Concat, StorageHasher,
 };
 use genetic_testing::{DnaSampleStatus, DnaTestResultSubmission};
 use labs::{LabInfo, LabVerifierKey};
@@ -12,6 +20,7 @@ use primitives_duration::ExpectedDuration;
 use primitives_price_and_currency::{CurrencyType, Price, PriceByCurrency};
 use primitives_verification_status::VerificationStatus;
 use services::ServiceInfo;
+use sp_core::H256;
 use traits_services::types::ServiceFlow;
 
 #[test]
@@ -21,14 +30,14 @@ fn create_request_works() {
 
 		assert_ok!(ServiceRequest::create_request(
 			RuntimeOrigin::signed(customer),
-			String::from("Indonesia").into_bytes(),
-			String::from("West Java").into_bytes(),
-			String::from("Bogor").into_bytes(),
+			String::from("ID").into_bytes(),
+			String::from("JB").into_bytes(),
+			String::from("BOGOR").into_bytes(),
 			String::from("Vaksin").into_bytes(),
 			10
 		));
 
-		let request_id = ServiceRequest::request_by_account_id(customer)[0];
+		let request_id = ServiceRequest::request_by_account_id(&customer)[0];
 
 		assert_eq!(
 			ServiceRequest::request_by_id(request_id),
@@ -38,10 +47,10 @@ fn create_request_works() {
 				lab_address: None,
 				service_id: None,
 				order_id: None,
-				country: String::from("Indonesia").into_bytes(),
-				region: String::from("West Java").into_bytes(),
-				city: String::from("Bogor").into_bytes(),
-				service_category: String::from("Vaksin").into_bytes(),
+				country: String::from("ID").into_bytes().try_into().unwrap(),
+				region: String::from("JB").into_bytes().try_into().unwrap(),
+				city: String::from("BOGOR").into_bytes().try_into().unwrap(),
+				service_category: String::from("Vaksin").into_bytes().try_into().unwrap(),
 				staking_amount: 10,
 				status: RequestStatus::Open,
 				created_at: 0,
@@ -52,10 +61,10 @@ fn create_request_works() {
 
 		assert_eq!(
 			ServiceRequest::service_count_request((
-				String::from("Indonesia").into_bytes(),
-				String::from("West Java").into_bytes(),
-				String::from("Bogor").into_bytes(),
-				String::from("Vaksin").into_bytes(),
+				Country::try_from(String::from("ID").into_bytes()).unwrap(),
+				Region::try_from(String::from("JB").into_bytes()).unwrap(),
+				City::try_from(String::from("BOGOR").into_bytes()).unwrap(),
+				ServiceCategory::try_from(String::from("Vaksin").into_bytes()).unwrap(),
 			)),
 			1,
 		);
@@ -79,14 +88,14 @@ fn unstake_works() {
 
 		assert_ok!(ServiceRequest::create_request(
 			RuntimeOrigin::signed(customer),
-			String::from("Indonesia").into_bytes(),
-			String::from("West Java").into_bytes(),
-			String::from("Bogor").into_bytes(),
+			String::from("ID").into_bytes(),
+			String::from("JB").into_bytes(),
+			String::from("BOGOR").into_bytes(),
 			String::from("Vaksin").into_bytes(),
 			10
 		));
 
-		let request_id = ServiceRequest::request_by_account_id(customer)[0];
+		let request_id = ServiceRequest::request_by_account_id(&customer)[0];
 
 		assert_ok!(ServiceRequest::unstake(RuntimeOrigin::signed(customer), request_id));
 
@@ -98,10 +107,10 @@ fn unstake_works() {
 				lab_address: None,
 				service_id: None,
 				order_id: None,
-				country: String::from("Indonesia").into_bytes(),
-				region: String::from("West Java").into_bytes(),
-				city: String::from("Bogor").into_bytes(),
-				service_category: String::from("Vaksin").into_bytes(),
+				country: String::from("ID").into_bytes().try_into().unwrap(),
+				region: String::from("JB").into_bytes().try_into().unwrap(),
+				city: String::from("BOGOR").into_bytes().try_into().unwrap(),
+				service_category: String::from("Vaksin").into_bytes().try_into().unwrap(),
 				staking_amount: 10,
 				status: RequestStatus::WaitingForUnstaked,
 				created_at: 0,
@@ -119,14 +128,14 @@ fn retrieve_unstake_works() {
 
 		assert_ok!(ServiceRequest::create_request(
 			RuntimeOrigin::signed(customer),
-			String::from("Indonesia").into_bytes(),
-			String::from("West Java").into_bytes(),
-			String::from("Bogor").into_bytes(),
+			String::from("ID").into_bytes(),
+			String::from("JB").into_bytes(),
+			String::from("BOGOR").into_bytes(),
 			String::from("Vaksin").into_bytes(),
 			10
 		));
 
-		let request_id = ServiceRequest::request_by_account_id(customer)[0];
+		let request_id = ServiceRequest::request_by_account_id(&customer)[0];
 
 		assert_ok!(ServiceRequest::unstake(RuntimeOrigin::signed(customer), request_id));
 
@@ -145,10 +154,10 @@ fn retrieve_unstake_works() {
 				lab_address: None,
 				service_id: None,
 				order_id: None,
-				country: String::from("Indonesia").into_bytes(),
-				region: String::from("West Java").into_bytes(),
-				city: String::from("Bogor").into_bytes(),
-				service_category: String::from("Vaksin").into_bytes(),
+				country: String::from("ID").into_bytes().try_into().unwrap(),
+				region: String::from("JB").into_bytes().try_into().unwrap(),
+				city: String::from("BOGOR").into_bytes().try_into().unwrap(),
+				service_category: String::from("Vaksin").into_bytes().try_into().unwrap(),
 				staking_amount: 10,
 				status: RequestStatus::Unstaked,
 				created_at: 0,
@@ -174,14 +183,14 @@ fn claim_request_works() {
 		// Customer create request
 		assert_ok!(ServiceRequest::create_request(
 			RuntimeOrigin::signed(customer),
-			String::from("Indonesia").into_bytes(),
-			String::from("West Java").into_bytes(),
-			String::from("Bogor").into_bytes(),
+			String::from("ID").into_bytes(),
+			String::from("JB").into_bytes(),
+			String::from("BOGOR").into_bytes(),
 			String::from("Vaksin").into_bytes(),
 			10
 		));
 
-		let request_id = ServiceRequest::request_by_account_id(customer)[0];
+		let request_id = ServiceRequest::request_by_account_id(&customer)[0];
 
 		// Register lab
 		assert_ok!(Labs::register_lab(
@@ -190,17 +199,19 @@ fn claim_request_works() {
 				box_public_key: Keccak256::hash(
 					"0xDb9Af2d1f3ADD2726A132AA7A65Cc9E6fC5761C3".as_bytes()
 				),
-				name: "DeBio Lab".as_bytes().to_vec(),
-				email: "DeBio Email".as_bytes().to_vec(),
-				country: CountryCode::from_vec("DC".as_bytes().to_vec()),
-				region: RegionCode::from_vec("DB".as_bytes().to_vec()),
-				city: CityCode::from_vec("CITY".as_bytes().to_vec()),
-				address: "DeBio Address".as_bytes().to_vec(),
-				phone_number: "+6281394653625".as_bytes().to_vec(),
-				website: "DeBio Website".as_bytes().to_vec(),
-				latitude: Some("DeBio Latitude".as_bytes().to_vec()),
-				longitude: Some("DeBio Longtitude".as_bytes().to_vec()),
-				profile_image: Some("DeBio Profile Image uwu".as_bytes().to_vec()),
+				name: "DeBio Lab".as_bytes().to_vec().try_into().unwrap(),
+				email: "DeBio Email".as_bytes().to_vec().try_into().unwrap(),
+				country: CountryCode::try_from("ID".as_bytes().to_vec()).unwrap(),
+				region: RegionCode::try_from("JB".as_bytes().to_vec()).unwrap(),
+				city: CityCode::try_from("CITY".as_bytes().to_vec()).unwrap(),
+				address: "DeBio Address".as_bytes().to_vec().try_into().unwrap(),
+				phone_number: "+6281394653625".as_bytes().to_vec().try_into().unwrap(),
+				website: "DeBio Website".as_bytes().to_vec().try_into().unwrap(),
+				latitude: Some("-6.175392".as_bytes().to_vec().try_into().unwrap()),
+				longitude: Some("106.827153".as_bytes().to_vec().try_into().unwrap()),
+				profile_image: Some(
+					"DeBio Profile Image uwu".as_bytes().to_vec().try_into().unwrap()
+				),
 			}
 		));
 
@@ -215,22 +226,42 @@ fn claim_request_works() {
 		let prices_by_currency_dbio = PriceByCurrency {
 			currency: CurrencyType::DBIO,
 			total_price: 10,
-			price_components: vec![Price { component: b"testing_price".to_vec(), value: 5 }],
-			additional_prices: vec![Price { component: b"qc_price".to_vec(), value: 5 }],
+			price_components: vec![Price {
+				component: b"testing_price".to_vec().try_into().unwrap(),
+				value: 5,
+			}]
+			.try_into()
+			.unwrap(),
+			additional_prices: vec![Price {
+				component: b"qc_price".to_vec().try_into().unwrap(),
+				value: 5,
+			}]
+			.try_into()
+			.unwrap(),
 		};
 
 		assert_ok!(Services::create_service(
 			RuntimeOrigin::signed(lab),
 			ServiceInfo {
-				name: "DeBio service name".as_bytes().to_vec(),
-				prices_by_currency: vec![prices_by_currency_dbio],
+				name: "DeBio service name".as_bytes().to_vec().try_into().unwrap(),
+				prices_by_currency: vec![prices_by_currency_dbio].try_into().unwrap(),
 				expected_duration: ExpectedDuration::default(),
-				category: "DeBio service category".as_bytes().to_vec(),
-				description: "DeBio service description".as_bytes().to_vec(),
-				dna_collection_process: "DeBio service dna_collection_process".as_bytes().to_vec(),
-				test_result_sample: "DeBio service test_result_sample".as_bytes().to_vec(),
-				long_description: Some("DeBio service long_description".as_bytes().to_vec()),
-				image: Some("DeBio service image".as_bytes().to_vec()),
+				category: "DeBio service category".as_bytes().to_vec().try_into().unwrap(),
+				description: "DeBio service description".as_bytes().to_vec().try_into().unwrap(),
+				dna_collection_process: "DeBio service dna_collection_process"
+					.as_bytes()
+					.to_vec()
+					.try_into()
+					.unwrap(),
+				test_result_sample: "DeBio service test_result_sample"
+					.as_bytes()
+					.to_vec()
+					.try_into()
+					.unwrap(),
+				long_description: Some(
+					"DeBio service long_description".as_bytes().to_vec().try_into().unwrap()
+				),
+				image: Some("DeBio service image".as_bytes().to_vec().try_into().unwrap()),
 			},
 			ServiceFlow::default()
 		));
@@ -252,10 +283,10 @@ fn claim_request_works() {
 				lab_address: Some(lab),
 				service_id: Some(service_id),
 				order_id: None,
-				country: String::from("Indonesia").into_bytes(),
-				region: String::from("West Java").into_bytes(),
-				city: String::from("Bogor").into_bytes(),
-				service_category: String::from("Vaksin").into_bytes(),
+				country: String::from("ID").into_bytes().try_into().unwrap(),
+				region: String::from("JB").into_bytes().try_into().unwrap(),
+				city: String::from("BOGOR").into_bytes().try_into().unwrap(),
+				service_category: String::from("Vaksin").into_bytes().try_into().unwrap(),
 				staking_amount: 10,
 				status: RequestStatus::Claimed,
 				created_at: 0,
@@ -276,31 +307,33 @@ fn process_request_works() {
 		// Customer create request
 		assert_ok!(ServiceRequest::create_request(
 			RuntimeOrigin::signed(customer),
-			String::from("Indonesia").into_bytes(),
-			String::from("West Java").into_bytes(),
-			String::from("Bogor").into_bytes(),
+			String::from("ID").into_bytes(),
+			String::from("JB").into_bytes(),
+			String::from("BOGOR").into_bytes(),
 			String::from("Vaksin").into_bytes(),
 			10
 		));
 
-		let request_id = ServiceRequest::request_by_account_id(customer)[0];
+		let request_id = ServiceRequest::request_by_account_id(&customer)[0];
 
 		// Register lab
 		assert_ok!(Labs::register_lab(
 			RuntimeOrigin::signed(lab),
 			LabInfo {
 				box_public_key: Keccak256::hash("box_public_key".as_bytes()),
-				name: "DeBio Lab".as_bytes().to_vec(),
-				email: "DeBio Email".as_bytes().to_vec(),
-				country: CountryCode::from_vec("DC".as_bytes().to_vec()),
-				region: RegionCode::from_vec("DB".as_bytes().to_vec()),
-				city: CityCode::from_vec("CITY".as_bytes().to_vec()),
-				address: "DeBio Address".as_bytes().to_vec(),
-				phone_number: "+6281394653625".as_bytes().to_vec(),
-				website: "DeBio Website".as_bytes().to_vec(),
-				latitude: Some("DeBio Latitude".as_bytes().to_vec()),
-				longitude: Some("DeBio Longtitude".as_bytes().to_vec()),
-				profile_image: Some("DeBio Profile Image uwu".as_bytes().to_vec()),
+				name: "DeBio Lab".as_bytes().to_vec().try_into().unwrap(),
+				email: "DeBio Email".as_bytes().to_vec().try_into().unwrap(),
+				country: CountryCode::try_from("ID".as_bytes().to_vec()).unwrap(),
+				region: RegionCode::try_from("JB".as_bytes().to_vec()).unwrap(),
+				city: CityCode::try_from("CITY".as_bytes().to_vec()).unwrap(),
+				address: "DeBio Address".as_bytes().to_vec().try_into().unwrap(),
+				phone_number: "+6281394653625".as_bytes().to_vec().try_into().unwrap(),
+				website: "DeBio Website".as_bytes().to_vec().try_into().unwrap(),
+				latitude: Some("-6.175392".as_bytes().to_vec().try_into().unwrap()),
+				longitude: Some("106.827153".as_bytes().to_vec().try_into().unwrap()),
+				profile_image: Some(
+					"DeBio Profile Image uwu".as_bytes().to_vec().try_into().unwrap()
+				),
 			}
 		));
 
@@ -315,22 +348,42 @@ fn process_request_works() {
 		let prices_by_currency_dbio = PriceByCurrency {
 			currency: CurrencyType::DBIO,
 			total_price: 10,
-			price_components: vec![Price { component: b"testing_price".to_vec(), value: 5 }],
-			additional_prices: vec![Price { component: b"qc_price".to_vec(), value: 5 }],
+			price_components: vec![Price {
+				component: b"testing_price".to_vec().try_into().unwrap(),
+				value: 5,
+			}]
+			.try_into()
+			.unwrap(),
+			additional_prices: vec![Price {
+				component: b"qc_price".to_vec().try_into().unwrap(),
+				value: 5,
+			}]
+			.try_into()
+			.unwrap(),
 		};
 
 		assert_ok!(Services::create_service(
 			RuntimeOrigin::signed(lab),
 			ServiceInfo {
-				name: "DeBio service name".as_bytes().to_vec(),
-				prices_by_currency: vec![prices_by_currency_dbio],
+				name: "DeBio service name".as_bytes().to_vec().try_into().unwrap(),
+				prices_by_currency: vec![prices_by_currency_dbio].try_into().unwrap(),
 				expected_duration: ExpectedDuration::default(),
-				category: "DeBio service category".as_bytes().to_vec(),
-				description: "DeBio service description".as_bytes().to_vec(),
-				dna_collection_process: "DeBio service dna_collection_process".as_bytes().to_vec(),
-				test_result_sample: "DeBio service test_result_sample".as_bytes().to_vec(),
-				long_description: Some("DeBio service long_description".as_bytes().to_vec()),
-				image: Some("DeBio service image".as_bytes().to_vec()),
+				category: "DeBio service category".as_bytes().to_vec().try_into().unwrap(),
+				description: "DeBio service description".as_bytes().to_vec().try_into().unwrap(),
+				dna_collection_process: "DeBio service dna_collection_process"
+					.as_bytes()
+					.to_vec()
+					.try_into()
+					.unwrap(),
+				test_result_sample: "DeBio service test_result_sample"
+					.as_bytes()
+					.to_vec()
+					.try_into()
+					.unwrap(),
+				long_description: Some(
+					"DeBio service long_description".as_bytes().to_vec().try_into().unwrap()
+				),
+				image: Some("DeBio service image".as_bytes().to_vec().try_into().unwrap()),
 			},
 			ServiceFlow::default()
 		));
@@ -369,10 +422,10 @@ fn process_request_works() {
 				lab_address: Some(lab),
 				service_id: Some(service_id),
 				order_id: Some(order_id),
-				country: String::from("Indonesia").into_bytes(),
-				region: String::from("West Java").into_bytes(),
-				city: String::from("Bogor").into_bytes(),
-				service_category: String::from("Vaksin").into_bytes(),
+				country: String::from("ID").into_bytes().try_into().unwrap(),
+				region: String::from("JB").into_bytes().try_into().unwrap(),
+				city: String::from("BOGOR").into_bytes().try_into().unwrap(),
+				service_category: String::from("Vaksin").into_bytes().try_into().unwrap(),
 				staking_amount: 10,
 				status: RequestStatus::Processed,
 				created_at: 0,
@@ -398,31 +451,33 @@ fn finalize_request_works() {
 		// Customer create request
 		assert_ok!(ServiceRequest::create_request(
 			RuntimeOrigin::signed(customer),
-			String::from("Indonesia").into_bytes(),
-			String::from("West Java").into_bytes(),
-			String::from("Bogor").into_bytes(),
+			String::from("ID").into_bytes(),
+			String::from("JB").into_bytes(),
+			String::from("BOGOR").into_bytes(),
 			String::from("Vaksin").into_bytes(),
 			10
 		));
 
-		let request_id = ServiceRequest::request_by_account_id(customer)[0];
+		let request_id = ServiceRequest::request_by_account_id(&customer)[0];
 
 		// Register lab
 		assert_ok!(Labs::register_lab(
 			RuntimeOrigin::signed(lab),
 			LabInfo {
 				box_public_key: Keccak256::hash("box_public_key".as_bytes()),
-				name: "DeBio Lab".as_bytes().to_vec(),
-				email: "DeBio Email".as_bytes().to_vec(),
-				country: CountryCode::from_vec("DC".as_bytes().to_vec()),
-				region: RegionCode::from_vec("DB".as_bytes().to_vec()),
-				city: CityCode::from_vec("CITY".as_bytes().to_vec()),
-				address: "DeBio Address".as_bytes().to_vec(),
-				phone_number: "+6281394653625".as_bytes().to_vec(),
-				website: "DeBio Website".as_bytes().to_vec(),
-				latitude: Some("DeBio Latitude".as_bytes().to_vec()),
-				longitude: Some("DeBio Longtitude".as_bytes().to_vec()),
-				profile_image: Some("DeBio Profile Image uwu".as_bytes().to_vec()),
+				name: "DeBio Lab".as_bytes().to_vec().try_into().unwrap(),
+				email: "DeBio Email".as_bytes().to_vec().try_into().unwrap(),
+				country: CountryCode::try_from("ID".as_bytes().to_vec()).unwrap(),
+				region: RegionCode::try_from("JB".as_bytes().to_vec()).unwrap(),
+				city: CityCode::try_from("CITY".as_bytes().to_vec()).unwrap(),
+				address: "DeBio Address".as_bytes().to_vec().try_into().unwrap(),
+				phone_number: "+6281394653625".as_bytes().to_vec().try_into().unwrap(),
+				website: "DeBio Website".as_bytes().to_vec().try_into().unwrap(),
+				latitude: Some("-6.175392".as_bytes().to_vec().try_into().unwrap()),
+				longitude: Some("106.827153".as_bytes().to_vec().try_into().unwrap()),
+				profile_image: Some(
+					"DeBio Profile Image uwu".as_bytes().to_vec().try_into().unwrap()
+				),
 			}
 		));
 
@@ -437,22 +492,42 @@ fn finalize_request_works() {
 		let prices_by_currency_dbio = PriceByCurrency {
 			currency: CurrencyType::DBIO,
 			total_price: 10,
-			price_components: vec![Price { component: b"testing_price".to_vec(), value: 5 }],
-			additional_prices: vec![Price { component: b"qc_price".to_vec(), value: 5 }],
+			price_components: vec![Price {
+				component: b"testing_price".to_vec().try_into().unwrap(),
+				value: 5,
+			}]
+			.try_into()
+			.unwrap(),
+			additional_prices: vec![Price {
+				component: b"qc_price".to_vec().try_into().unwrap(),
+				value: 5,
+			}]
+			.try_into()
+			.unwrap(),
 		};
 
 		assert_ok!(Services::create_service(
 			RuntimeOrigin::signed(lab),
 			ServiceInfo {
-				name: "DeBio service name".as_bytes().to_vec(),
-				prices_by_currency: vec![prices_by_currency_dbio],
+				name: "DeBio service name".as_bytes().to_vec().try_into().unwrap(),
+				prices_by_currency: vec![prices_by_currency_dbio].try_into().unwrap(),
 				expected_duration: ExpectedDuration::default(),
-				category: "DeBio service category".as_bytes().to_vec(),
-				description: "DeBio service description".as_bytes().to_vec(),
-				dna_collection_process: "DeBio service dna_collection_process".as_bytes().to_vec(),
-				test_result_sample: "DeBio service test_result_sample".as_bytes().to_vec(),
-				long_description: Some("DeBio service long_description".as_bytes().to_vec()),
-				image: Some("DeBio service image".as_bytes().to_vec()),
+				category: "DeBio service category".as_bytes().to_vec().try_into().unwrap(),
+				description: "DeBio service description".as_bytes().to_vec().try_into().unwrap(),
+				dna_collection_process: "DeBio service dna_collection_process"
+					.as_bytes()
+					.to_vec()
+					.try_into()
+					.unwrap(),
+				test_result_sample: "DeBio service test_result_sample"
+					.as_bytes()
+					.to_vec()
+					.try_into()
+					.unwrap(),
+				long_description: Some(
+					"DeBio service long_description".as_bytes().to_vec().try_into().unwrap()
+				),
+				image: Some("DeBio service image".as_bytes().to_vec().try_into().unwrap()),
 			},
 			ServiceFlow::default()
 		));
@@ -483,7 +558,7 @@ fn finalize_request_works() {
 			order_id,
 		));
 
-		let dna_sample = GeneticTesting::dna_samples_by_lab_id(lab).unwrap();
+		let dna_sample = GeneticTesting::dna_samples_by_lab_id(&lab);
 
 		assert_ok!(Orders::set_order_paid(RuntimeOrigin::signed(customer), order_id));
 
@@ -493,9 +568,9 @@ fn finalize_request_works() {
 			RuntimeOrigin::signed(lab),
 			dna_sample[0].clone(),
 			DnaTestResultSubmission {
-				comments: Some("comment".as_bytes().to_vec()),
-				result_link: Some("result_link".as_bytes().to_vec()),
-				report_link: Some("report_link".as_bytes().to_vec()),
+				comments: Some("comment".as_bytes().to_vec().try_into().unwrap()),
+				result_link: Some("result_link".as_bytes().to_vec().try_into().unwrap()),
+				report_link: Some("report_link".as_bytes().to_vec().try_into().unwrap()),
 			}
 		));
 
@@ -519,10 +594,10 @@ fn finalize_request_works() {
 				lab_address: Some(lab),
 				service_id: Some(service_id),
 				order_id: Some(order_id),
-				country: String::from("Indonesia").into_bytes(),
-				region: String::from("West Java").into_bytes(),
-				city: String::from("Bogor").into_bytes(),
-				service_category: String::from("Vaksin").into_bytes(),
+				country: String::from("ID").into_bytes().try_into().unwrap(),
+				region: String::from("JB").into_bytes().try_into().unwrap(),
+				city: String::from("BOGOR").into_bytes().try_into().unwrap(),
+				service_category: String::from("Vaksin").into_bytes().try_into().unwrap(),
 				staking_amount: 10,
 				status: RequestStatus::Finalized,
 				created_at: 0,
@@ -533,15 +608,15 @@ fn finalize_request_works() {
 
 		assert_eq!(
 			ServiceRequest::service_count_request((
-				String::from("Indonesia").into_bytes(),
-				String::from("West Java").into_bytes(),
-				String::from("Bogor").into_bytes(),
-				String::from("Vaksin").into_bytes(),
+				Country::try_from(String::from("ID").into_bytes()).unwrap(),
+				Region::try_from(String::from("JB").into_bytes()).unwrap(),
+				City::try_from(String::from("BOGOR").into_bytes()).unwrap(),
+				ServiceCategory::try_from(String::from("Vaksin").into_bytes()).unwrap(),
 			)),
 			0
 		);
 
-		assert_eq!(ServiceRequest::request_by_account_id(customer), Vec::new(),);
+		assert_eq!(ServiceRequest::request_by_account_id(&customer), Vec::new(),);
 
 		let staking_account_id = ServiceRequest::staking_account_id(request_id);
 
@@ -558,9 +633,9 @@ fn cant_create_request_when_stacking_amount_zero() {
 		assert_noop!(
 			ServiceRequest::create_request(
 				RuntimeOrigin::signed(customer),
-				String::from("Indonesia").into_bytes(),
-				String::from("West Java").into_bytes(),
-				String::from("Bogor").into_bytes(),
+				String::from("ID").into_bytes(),
+				String::from("JB").into_bytes(),
+				String::from("BOGOR").into_bytes(),
 				String::from("Vaksin").into_bytes(),
 				0
 			),
@@ -577,9 +652,9 @@ fn cant_create_request_when_balance_not_enough() {
 		assert_noop!(
 			ServiceRequest::create_request(
 				RuntimeOrigin::signed(other),
-				String::from("Indonesia").into_bytes(),
-				String::from("West Java").into_bytes(),
-				String::from("Bogor").into_bytes(),
+				String::from("ID").into_bytes(),
+				String::from("JB").into_bytes(),
+				String::from("BOGOR").into_bytes(),
 				String::from("Vaksin").into_bytes(),
 				500
 			),
@@ -588,6 +663,315 @@ fn cant_create_request_when_balance_not_enough() {
 	})
 }
 
+#[test]
+fn cant_create_request_when_location_invalid() {
+	<ExternalityBuilder>::default().existential_deposit(2).build().execute_with(|| {
+		let customer = account_key("customer");
+
+		assert_noop!(
+			ServiceRequest::create_request(
+				RuntimeOrigin::signed(customer),
+				String::from("Indonesia").into_bytes(),
+				String::from("JB").into_bytes(),
+				String::from("BOGOR").into_bytes(),
+				String::from("Vaksin").into_bytes(),
+				10
+			),
+			Error::<Test>::InvalidCountryCode
+		);
+
+		assert_noop!(
+			ServiceRequest::create_request(
+				RuntimeOrigin::signed(customer),
+				String::from("MY").into_bytes(),
+				String::from("JB").into_bytes(),
+				String::from("BOGOR").into_bytes(),
+				String::from("Vaksin").into_bytes(),
+				10
+			),
+			Error::<Test>::InvalidRegionCode
+		);
+
+		assert_noop!(
+			ServiceRequest::create_request(
+				RuntimeOrigin::signed(customer),
+				String::from("ID").into_bytes(),
+				String::from("JB").into_bytes(),
+				Vec::new(),
+				String::from("Vaksin").into_bytes(),
+				10
+			),
+			Error::<Test>::InvalidCityCode
+		);
+	})
+}
+
+#[test]
+fn create_request_in_country_without_subdivisions_works() {
+	<ExternalityBuilder>::default().existential_deposit(2).build().execute_with(|| {
+		let customer = account_key("customer");
+
+		// Macao has no ISO 3166-2 subdivisions, an empty region maps to the country code
+		assert_ok!(ServiceRequest::create_request(
+			RuntimeOrigin::signed(customer),
+			String::from("MO").into_bytes(),
+			Vec::new(),
+			String::from("MACAO").into_bytes(),
+			String::from("Vaksin").into_bytes(),
+			10
+		));
+
+		assert_ok!(ServiceRequest::create_request(
+			RuntimeOrigin::signed(customer),
+			String::from("mo").into_bytes(),
+			String::from("mo").into_bytes(),
+			String::from("MACAO").into_bytes(),
+			String::from("Vaksin").into_bytes(),
+			10
+		));
+
+		let request_id = ServiceRequest::request_by_account_id(&customer)[0];
+		let request = ServiceRequest::request_by_id(request_id).unwrap();
+
+		assert_eq!(request.region.to_vec(), String::from("MO").into_bytes());
+		assert_eq!(
+			ServiceRequest::service_count_request((
+				Country::try_from(String::from("MO").into_bytes()).unwrap(),
+				Region::try_from(String::from("MO").into_bytes()).unwrap(),
+				City::try_from(String::from("MACAO").into_bytes()).unwrap(),
+				ServiceCategory::try_from(String::from("Vaksin").into_bytes()).unwrap(),
+			)),
+			2,
+		);
+
+		assert_noop!(
+			ServiceRequest::create_request(
+				RuntimeOrigin::signed(customer),
+				String::from("MO").into_bytes(),
+				String::from("JB").into_bytes(),
+				String::from("MACAO").into_bytes(),
+				String::from("Vaksin").into_bytes(),
+				10
+			),
+			Error::<Test>::InvalidRegionCode
+		);
+
+		assert_noop!(
+			ServiceRequest::create_request(
+				RuntimeOrigin::signed(customer),
+				String::from("ID").into_bytes(),
+				Vec::new(),
+				String::from("BOGOR").into_bytes(),
+				String::from("Vaksin").into_bytes(),
+				10
+			),
+			Error::<Test>::InvalidRegionCode
+		);
+	})
+}
+
+#[test]
+fn cant_create_request_when_service_category_too_long() {
+	<ExternalityBuilder>::default().existential_deposit(2).build().execute_with(|| {
+		let customer = account_key("customer");
+
+		assert_noop!(
+			ServiceRequest::create_request(
+				RuntimeOrigin::signed(customer),
+				String::from("ID").into_bytes(),
+				String::from("JB").into_bytes(),
+				String::from("BOGOR").into_bytes(),
+				vec![b'X'; MAX_SERVICE_CATEGORY_LENGTH as usize + 1],
+				10
+			),
+			Error::<Test>::ServiceCategoryTooLong
+		);
+	})
+}
+
+#[test]
+fn create_request_indexes_every_request() {
+	<ExternalityBuilder>::default().existential_deposit(0).build().execute_with(|| {
+		let customer = account_key("customer");
+
+		for city in ["BOGOR", "Bandung", "DEPOK"] {
+			assert_ok!(ServiceRequest::create_request(
+				RuntimeOrigin::signed(customer),
+				String::from("ID").into_bytes(),
+				String::from("JB").into_bytes(),
+				String::from(city).into_bytes(),
+				String::from("Vaksin").into_bytes(),
+				10
+			));
+
+			System::inc_account_nonce(customer);
+		}
+
+		assert_eq!(ServiceRequest::request_by_account_id(&customer).len(), 3);
+	})
+}
+
+#[test]
+fn get_requests_filters_and_paginates() {
+	<ExternalityBuilder>::default().existential_deposit(0).build().execute_with(|| {
+		let customer = account_key("customer");
+		let lab = account_key("lab");
+
+		let mut request_ids: Vec<H256> = Vec::new();
+		for city in ["BOGOR", "Bandung", "DEPOK"] {
+			assert_ok!(ServiceRequest::create_request(
+				RuntimeOrigin::signed(customer),
+				String::from("ID").into_bytes(),
+				String::from("JB").into_bytes(),
+				String::from(city).into_bytes(),
+				String::from("Vaksin").into_bytes(),
+				10
+			));
+
+			let request_id = ServiceRequest::request_by_account_id(&customer)
+				.into_iter()
+				.find(|request_id| !request_ids.contains(request_id))
+				.unwrap();
+			request_ids.push(request_id);
+			RequestsByLabId::<Test>::insert(lab, request_id, ());
+
+			System::inc_account_nonce(customer);
+		}
+
+		assert_ok!(ServiceRequest::unstake(RuntimeOrigin::signed(customer), request_ids[2]));
+
+		let ids_of = |requests: Vec<RequestOf<Test>>| -> Vec<H256> {
+			requests.into_iter().map(|request| request.hash).collect()
+		};
+
+		// Requests are paged in the key order of the index
+		let indexed_request_ids = ServiceRequest::request_by_account_id(&customer);
+		let open_request_ids: Vec<H256> =
+			indexed_request_ids.iter().copied().filter(|id| id != &request_ids[2]).collect();
+
+		assert_eq!(
+			ids_of(ServiceRequest::get_requests_by_requester_id(&customer, None, 0, u32::MAX)),
+			indexed_request_ids
+		);
+		assert_eq!(
+			ids_of(ServiceRequest::get_requests_by_requester_id(&customer, None, 1, 1)),
+			vec![indexed_request_ids[1]]
+		);
+		assert_eq!(
+			ids_of(ServiceRequest::get_requests_by_requester_id(
+				&customer,
+				Some(RequestStatus::Open),
+				0,
+				10
+			)),
+			open_request_ids
+		);
+		assert_eq!(
+			ids_of(ServiceRequest::get_requests_by_requester_id(
+				&customer,
+				Some(RequestStatus::Open),
+				1,
+				10
+			)),
+			vec![open_request_ids[1]]
+		);
+		assert_eq!(
+			ids_of(ServiceRequest::get_requests_by_lab_id(
+				&lab,
+				Some(RequestStatus::WaitingForUnstaked),
+				0,
+				10
+			)),
+			vec![request_ids[2]]
+		);
+		assert_eq!(ServiceRequest::get_requests_by_lab_id(&lab, None, 3, 10), Vec::new());
+		assert_eq!(ServiceRequest::get_requests_by_lab_id(&lab, None, 0, 0), Vec::new());
+	})
+}
+
+#[test]
+fn migrate_request_indexes_keeps_every_request_id() {
+	<ExternalityBuilder>::default().existential_deposit(0).build().execute_with(|| {
+		let customer = account_key("customer");
+		let lab = account_key("lab");
+		let mut request_ids: Vec<H256> = (0..3u8).map(|i| Keccak256::hash(&[i])).collect();
+
+		put_storage_value(
+			b"ServiceRequest",
+			b"RequestByAccountId",
+			&Blake2_128Concat::hash(&customer.encode()),
+			request_ids.clone(),
+		);
+		put_storage_value(
+			b"ServiceRequest",
+			b"RequestsByLabId",
+			&Blake2_128Concat::hash(&lab.encode()),
+			request_ids.clone(),
+		);
+		StorageVersion::new(2).put::<ServiceRequest>();
+
+		migrations::migrate::<Test>();
+
+		request_ids.sort();
+		let sorted = |mut request_ids: Vec<H256>| {
+			request_ids.sort();
+			request_ids
+		};
+
+		assert_eq!(sorted(ServiceRequest::request_by_account_id(&customer)), request_ids);
+		assert_eq!(sorted(ServiceRequest::requests_by_lab_id(&lab)), request_ids);
+		assert_eq!(StorageVersion::get::<ServiceRequest>(), StorageVersion::new(5));
+	})
+}
+
+#[test]
+fn migrate_normalizes_request_locations_and_merges_service_counts() {
+	<ExternalityBuilder>::default().existential_deposit(0).build().execute_with(|| {
+		let customer = account_key("customer");
+		let location = |country: &str, region: &str, city: &str| {
+			(
+				Country::try_from(country.as_bytes().to_vec()).unwrap(),
+				Region::try_from(region.as_bytes().to_vec()).unwrap(),
+				City::try_from(city.as_bytes().to_vec()).unwrap(),
+			)
+		};
+		let service_category = ServiceCategory::try_from(b"Vaksin".to_vec()).unwrap();
+
+		let (country, region, city) = location("id", "jb", "bogor");
+		let request_id = Keccak256::hash(&[1]);
+		RequestById::<Test>::insert(
+			request_id,
+			Request::new(
+				request_id,
+				&customer,
+				country.clone(),
+				region.clone(),
+				city.clone(),
+				service_category.clone(),
+				10,
+				0,
+			),
+		);
+		ServiceCountRequest::<Test>::insert((&country, &region, &city, &service_category), 2);
+
+		let (country, region, city) = location("ID", "JB", "BOGOR");
+		ServiceCountRequest::<Test>::insert((&country, &region, &city, &service_category), 1);
+
+		StorageVersion::new(4).put::<ServiceRequest>();
+
+		migrations::migrate::<Test>();
+
+		let request = ServiceRequest::request_by_id(request_id).unwrap();
+		assert_eq!((request.country, request.region, request.city), location("ID", "JB", "BOGOR"));
+		assert_eq!(
+			ServiceRequest::service_count_request((&country, &region, &city, &service_category)),
+			3
+		);
+		assert_eq!(ServiceCountRequest::<Test>::iter().count(), 1);
+		assert_eq!(StorageVersion::get::<ServiceRequest>(), StorageVersion::new(5));
+	})
+}
+
 #[test]
 fn cant_retrive_unstake_amount_unstake_claim_process_and_finalize_request_when_not_exists() {
 	<ExternalityBuilder>::default().existential_deposit(0).build().execute_with(|| {
@@ -650,14 +1034,14 @@ fn cant_claim_and_process_request_when_already_unstaked() {
 		// Customer create request
 		assert_ok!(ServiceRequest::create_request(
 			RuntimeOrigin::signed(customer),
-			String::from("Indonesia").into_bytes(),
-			String::from("West Java").into_bytes(),
-			String::from("Bogor").into_bytes(),
+			String::from("ID").into_bytes(),
+			String::from("JB").into_bytes(),
+			String::from("BOGOR").into_bytes(),
 			String::from("Vaksin").into_bytes(),
 			10
 		));
 
-		let request_id = ServiceRequest::request_by_account_id(customer)[0];
+		let request_id = ServiceRequest::request_by_account_id(&customer)[0];
 
 		assert_ok!(ServiceRequest::unstake(RuntimeOrigin::signed(customer), request_id));
 
@@ -691,14 +1075,14 @@ fn cant_claim_request_when_already_claimed() {
 		// Customer create request
 		assert_ok!(ServiceRequest::create_request(
 			RuntimeOrigin::signed(customer),
-			String::from("Indonesia").into_bytes(),
-			String::from("West Java").into_bytes(),
-			String::from("Bogor").into_bytes(),
+			String::from("ID").into_bytes(),
+			String::from("JB").into_bytes(),
+			String::from("BOGOR").into_bytes(),
 			String::from("Vaksin").into_bytes(),
 			10
 		));
 
-		let request_id = ServiceRequest::request_by_account_id(customer)[0];
+		let request_id = ServiceRequest::request_by_account_id(&customer)[0];
 
 		// Register lab
 		assert_ok!(Labs::register_lab(
@@ -707,17 +1091,19 @@ fn cant_claim_request_when_already_claimed() {
 				box_public_key: Keccak256::hash(
 					"0xDb9Af2d1f3ADD2726A132AA7A65Cc9E6fC5761C3".as_bytes()
 				),
-				name: "DeBio Lab".as_bytes().to_vec(),
-				email: "DeBio Email".as_bytes().to_vec(),
-				country: CountryCode::from_vec("DC".as_bytes().to_vec()),
-				region: RegionCode::from_vec("DB".as_bytes().to_vec()),
-				city: CityCode::from_vec("CITY".as_bytes().to_vec()),
-				address: "DeBio Address".as_bytes().to_vec(),
-				phone_number: "+6281394653625".as_bytes().to_vec(),
-				website: "DeBio Website".as_bytes().to_vec(),
-				latitude: Some("DeBio Latitude".as_bytes().to_vec()),
-				longitude: Some("DeBio Longtitude".as_bytes().to_vec()),
-				profile_image: Some("DeBio Profile Image uwu".as_bytes().to_vec()),
+				name: "DeBio Lab".as_bytes().to_vec().try_into().unwrap(),
+				email: "DeBio Email".as_bytes().to_vec().try_into().unwrap(),
+				country: CountryCode::try_from("ID".as_bytes().to_vec()).unwrap(),
+				region: RegionCode::try_from("JB".as_bytes().to_vec()).unwrap(),
+				city: CityCode::try_from("CITY".as_bytes().to_vec()).unwrap(),
+				address: "DeBio Address".as_bytes().to_vec().try_into().unwrap(),
+				phone_number: "+6281394653625".as_bytes().to_vec().try_into().unwrap(),
+				website: "DeBio Website".as_bytes().to_vec().try_into().unwrap(),
+				latitude: Some("-6.175392".as_bytes().to_vec().try_into().unwrap()),
+				longitude: Some("106.827153".as_bytes().to_vec().try_into().unwrap()),
+				profile_image: Some(
+					"DeBio Profile Image uwu".as_bytes().to_vec().try_into().unwrap()
+				),
 			}
 		));
 
@@ -732,22 +1118,42 @@ fn cant_claim_request_when_already_claimed() {
 		let prices_by_currency_dbio = PriceByCurrency {
 			currency: CurrencyType::DBIO,
 			total_price: 10,
-			price_components: vec![Price { component: b"testing_price".to_vec(), value: 5 }],
-			additional_prices: vec![Price { component: b"qc_price".to_vec(), value: 5 }],
+			price_components: vec![Price {
+				component: b"testing_price".to_vec().try_into().unwrap(),
+				value: 5,
+			}]
+			.try_into()
+			.unwrap(),
+			additional_prices: vec![Price {
+				component: b"qc_price".to_vec().try_into().unwrap(),
+				value: 5,
+			}]
+			.try_into()
+			.unwrap(),
 		};
 
 		assert_ok!(Services::create_service(
 			RuntimeOrigin::signed(lab),
 			ServiceInfo {
-				name: "DeBio service name".as_bytes().to_vec(),
-				prices_by_currency: vec![prices_by_currency_dbio],
+				name: "DeBio service name".as_bytes().to_vec().try_into().unwrap(),
+				prices_by_currency: vec![prices_by_currency_dbio].try_into().unwrap(),
 				expected_duration: ExpectedDuration::default(),
-				category: "DeBio service category".as_bytes().to_vec(),
-				description: "DeBio service description".as_bytes().to_vec(),
-				dna_collection_process: "DeBio service dna_collection_process".as_bytes().to_vec(),
-				test_result_sample: "DeBio service test_result_sample".as_bytes().to_vec(),
-				long_description: Some("DeBio service long_description".as_bytes().to_vec()),
-				image: Some("DeBio service image".as_bytes().to_vec()),
+				category: "DeBio service category".as_bytes().to_vec().try_into().unwrap(),
+				description: "DeBio service description".as_bytes().to_vec().try_into().unwrap(),
+				dna_collection_process: "DeBio service dna_collection_process"
+					.as_bytes()
+					.to_vec()
+					.try_into()
+					.unwrap(),
+				test_result_sample: "DeBio service test_result_sample"
+					.as_bytes()
+					.to_vec()
+					.try_into()
+					.unwrap(),
+				long_description: Some(
+					"DeBio service long_description".as_bytes().to_vec().try_into().unwrap()
+				),
+				image: Some("DeBio service image".as_bytes().to_vec().try_into().unwrap()),
 			},
 			ServiceFlow::default()
 		));
@@ -783,14 +1189,14 @@ fn cant_claim_request_when_already_processed_or_finalized() {
 		// Customer create request
 		assert_ok!(ServiceRequest::create_request(
 			RuntimeOrigin::signed(customer),
-			String::from("Indonesia").into_bytes(),
-			String::from("West Java").into_bytes(),
-			String::from("Bogor").into_bytes(),
+			String::from("ID").into_bytes(),
+			String::from("JB").into_bytes(),
+			String::from("BOGOR").into_bytes(),
 			String::from("Vaksin").into_bytes(),
 			10
 		));
 
-		let request_id = ServiceRequest::request_by_account_id(customer)[0];
+		let request_id = ServiceRequest::request_by_account_id(&customer)[0];
 
 		// Register lab
 		assert_ok!(Labs::register_lab(
@@ -799,17 +1205,19 @@ fn cant_claim_request_when_already_processed_or_finalized() {
 				box_public_key: Keccak256::hash(
 					"0xDb9Af2d1f3ADD2726A132AA7A65Cc9E6fC5761C3".as_bytes()
 				),
-				name: "DeBio Lab".as_bytes().to_vec(),
-				email: "DeBio Email".as_bytes().to_vec(),
-				country: CountryCode::from_vec("DC".as_bytes().to_vec()),
-				region: RegionCode::from_vec("DB".as_bytes().to_vec()),
-				city: CityCode::from_vec("CITY".as_bytes().to_vec()),
-				address: "DeBio Address".as_bytes().to_vec(),
-				phone_number: "+6281394653625".as_bytes().to_vec(),
-				website: "DeBio Website".as_bytes().to_vec(),
-				latitude: Some("DeBio Latitude".as_bytes().to_vec()),
-				longitude: Some("DeBio Longtitude".as_bytes().to_vec()),
-				profile_image: Some("DeBio Profile Image uwu".as_bytes().to_vec()),
+				name: "DeBio Lab".as_bytes().to_vec().try_into().unwrap(),
+				email: "DeBio Email".as_bytes().to_vec().try_into().unwrap(),
+				country: CountryCode::try_from("ID".as_bytes().to_vec()).unwrap(),
+				region: RegionCode::try_from("JB".as_bytes().to_vec()).unwrap(),
+				city: CityCode::try_from("CITY".as_bytes().to_vec()).unwrap(),
+				address: "DeBio Address".as_bytes().to_vec().try_into().unwrap(),
+				phone_number: "+6281394653625".as_bytes().to_vec().try_into().unwrap(),
+				website: "DeBio Website".as_bytes().to_vec().try_into().unwrap(),
+				latitude: Some("-6.175392".as_bytes().to_vec().try_into().unwrap()),
+				longitude: Some("106.827153".as_bytes().to_vec().try_into().unwrap()),
+				profile_image: Some(
+					"DeBio Profile Image uwu".as_bytes().to_vec().try_into().unwrap()
+				),
 			}
 		));
 
@@ -824,22 +1232,42 @@ fn cant_claim_request_when_already_processed_or_finalized() {
 		let prices_by_currency_dbio = PriceByCurrency {
 			currency: CurrencyType::DBIO,
 			total_price: 10,
-			price_components: vec![Price { component: b"testing_price".to_vec(), value: 5 }],
-			additional_prices: vec![Price { component: b"qc_price".to_vec(), value: 5 }],
+			price_components: vec![Price {
+				component: b"testing_price".to_vec().try_into().unwrap(),
+				value: 5,
+			}]
+			.try_into()
+			.unwrap(),
+			additional_prices: vec![Price {
+				component: b"qc_price".to_vec().try_into().unwrap(),
+				value: 5,
+			}]
+			.try_into()
+			.unwrap(),
 		};
 
 		assert_ok!(Services::create_service(
 			RuntimeOrigin::signed(lab),
 			ServiceInfo {
-				name: "DeBio service name".as_bytes().to_vec(),
-				prices_by_currency: vec![prices_by_currency_dbio],
+				name: "DeBio service name".as_bytes().to_vec().try_into().unwrap(),
+				prices_by_currency: vec![prices_by_currency_dbio].try_into().unwrap(),
 				expected_duration: ExpectedDuration::default(),
-				category: "DeBio service category".as_bytes().to_vec(),
-				description: "DeBio service description".as_bytes().to_vec(),
-				dna_collection_process: "DeBio service dna_collection_process".as_bytes().to_vec(),
-				test_result_sample: "DeBio service test_result_sample".as_bytes().to_vec(),
-				long_description: Some("DeBio service long_description".as_bytes().to_vec()),
-				image: Some("DeBio service image".as_bytes().to_vec()),
+				category: "DeBio service category".as_bytes().to_vec().try_into().unwrap(),
+				description: "DeBio service description".as_bytes().to_vec().try_into().unwrap(),
+				dna_collection_process: "DeBio service dna_collection_process"
+					.as_bytes()
+					.to_vec()
+					.try_into()
+					.unwrap(),
+				test_result_sample: "DeBio service test_result_sample"
+					.as_bytes()
+					.to_vec()
+					.try_into()
+					.unwrap(),
+				long_description: Some(
+					"DeBio service long_description".as_bytes().to_vec().try_into().unwrap()
+				),
+				image: Some("DeBio service image".as_bytes().to_vec().try_into().unwrap()),
 			},
 			ServiceFlow::default()
 		));
@@ -875,7 +1303,7 @@ fn cant_claim_request_when_already_processed_or_finalized() {
 			Error::<Test>::RequestUnableToClaimed
 		);
 
-		let dna_sample = GeneticTesting::dna_samples_by_lab_id(lab).unwrap();
+		let dna_sample = GeneticTesting::dna_samples_by_lab_id(&lab);
 
 		assert_ok!(Orders::set_order_paid(RuntimeOrigin::signed(customer), order_id));
 
@@ -883,9 +1311,9 @@ fn cant_claim_request_when_already_processed_or_finalized() {
 			RuntimeOrigin::signed(lab),
 			dna_sample[0].clone(),
 			DnaTestResultSubmission {
-				comments: Some("comment".as_bytes().to_vec()),
-				result_link: Some("result_link".as_bytes().to_vec()),
-				report_link: Some("report_link".as_bytes().to_vec()),
+				comments: Some("comment".as_bytes().to_vec().try_into().unwrap()),
+				result_link: Some("result_link".as_bytes().to_vec().try_into().unwrap()),
+				report_link: Some("report_link".as_bytes().to_vec().try_into().unwrap()),
 			}
 		));
 
@@ -919,14 +1347,14 @@ fn cant_claim_and_finalize_request_when_lab_not_exists() {
 		// Customer create request
 		assert_ok!(ServiceRequest::create_request(
 			RuntimeOrigin::signed(customer),
-			String::from("Indonesia").into_bytes(),
-			String::from("West Java").into_bytes(),
-			String::from("Bogor").into_bytes(),
+			String::from("ID").into_bytes(),
+			String::from("JB").into_bytes(),
+			String::from("BOGOR").into_bytes(),
 			String::from("Vaksin").into_bytes(),
 			10
 		));
 
-		let request_id = ServiceRequest::request_by_account_id(customer)[0];
+		let request_id = ServiceRequest::request_by_account_id(&customer)[0];
 
 		assert_noop!(
 			ServiceRequest::claim_request(
@@ -942,17 +1370,19 @@ fn cant_claim_and_finalize_request_when_lab_not_exists() {
 			RuntimeOrigin::signed(lab),
 			LabInfo {
 				box_public_key: Keccak256::hash("box_public_key".as_bytes()),
-				name: "DeBio Lab".as_bytes().to_vec(),
-				email: "DeBio Email".as_bytes().to_vec(),
-				country: CountryCode::from_vec("DC".as_bytes().to_vec()),
-				region: RegionCode::from_vec("DB".as_bytes().to_vec()),
-				city: CityCode::from_vec("CITY".as_bytes().to_vec()),
-				address: "DeBio Address".as_bytes().to_vec(),
-				phone_number: "+6281394653625".as_bytes().to_vec(),
-				website: "DeBio Website".as_bytes().to_vec(),
-				latitude: Some("DeBio Latitude".as_bytes().to_vec()),
-				longitude: Some("DeBio Longtitude".as_bytes().to_vec()),
-				profile_image: Some("DeBio Profile Image uwu".as_bytes().to_vec()),
+				name: "DeBio Lab".as_bytes().to_vec().try_into().unwrap(),
+				email: "DeBio Email".as_bytes().to_vec().try_into().unwrap(),
+				country: CountryCode::try_from("ID".as_bytes().to_vec()).unwrap(),
+				region: RegionCode::try_from("JB".as_bytes().to_vec()).unwrap(),
+				city: CityCode::try_from("CITY".as_bytes().to_vec()).unwrap(),
+				address: "DeBio Address".as_bytes().to_vec().try_into().unwrap(),
+				phone_number: "+6281394653625".as_bytes().to_vec().try_into().unwrap(),
+				website: "DeBio Website".as_bytes().to_vec().try_into().unwrap(),
+				latitude: Some("-6.175392".as_bytes().to_vec().try_into().unwrap()),
+				longitude: Some("106.827153".as_bytes().to_vec().try_into().unwrap()),
+				profile_image: Some(
+					"DeBio Profile Image uwu".as_bytes().to_vec().try_into().unwrap()
+				),
 			}
 		));
 
@@ -967,22 +1397,42 @@ fn cant_claim_and_finalize_request_when_lab_not_exists() {
 		let prices_by_currency_dbio = PriceByCurrency {
 			currency: CurrencyType::DBIO,
 			total_price: 10,
-			price_components: vec![Price { component: b"testing_price".to_vec(), value: 5 }],
-			additional_prices: vec![Price { component: b"qc_price".to_vec(), value: 5 }],
+			price_components: vec![Price {
+				component: b"testing_price".to_vec().try_into().unwrap(),
+				value: 5,
+			}]
+			.try_into()
+			.unwrap(),
+			additional_prices: vec![Price {
+				component: b"qc_price".to_vec().try_into().unwrap(),
+				value: 5,
+			}]
+			.try_into()
+			.unwrap(),
 		};
 
 		assert_ok!(Services::create_service(
 			RuntimeOrigin::signed(lab),
 			ServiceInfo {
-				name: "DeBio service name".as_bytes().to_vec(),
-				prices_by_currency: vec![prices_by_currency_dbio],
+				name: "DeBio service name".as_bytes().to_vec().try_into().unwrap(),
+				prices_by_currency: vec![prices_by_currency_dbio].try_into().unwrap(),
 				expected_duration: ExpectedDuration::default(),
-				category: "DeBio service category".as_bytes().to_vec(),
-				description: "DeBio service description".as_bytes().to_vec(),
-				dna_collection_process: "DeBio service dna_collection_process".as_bytes().to_vec(),
-				test_result_sample: "DeBio service test_result_sample".as_bytes().to_vec(),
-				long_description: Some("DeBio service long_description".as_bytes().to_vec()),
-				image: Some("DeBio service image".as_bytes().to_vec()),
+				category: "DeBio service category".as_bytes().to_vec().try_into().unwrap(),
+				description: "DeBio service description".as_bytes().to_vec().try_into().unwrap(),
+				dna_collection_process: "DeBio service dna_collection_process"
+					.as_bytes()
+					.to_vec()
+					.try_into()
+					.unwrap(),
+				test_result_sample: "DeBio service test_result_sample"
+					.as_bytes()
+					.to_vec()
+					.try_into()
+					.unwrap(),
+				long_description: Some(
+					"DeBio service long_description".as_bytes().to_vec().try_into().unwrap()
+				),
+				image: Some("DeBio service image".as_bytes().to_vec().try_into().unwrap()),
 			},
 			ServiceFlow::default()
 		));
@@ -1030,14 +1480,14 @@ fn cant_claim_request_when_service_not_exists() {
 		// Customer create request
 		assert_ok!(ServiceRequest::create_request(
 			RuntimeOrigin::signed(customer),
-			String::from("Indonesia").into_bytes(),
-			String::from("West Java").into_bytes(),
-			String::from("Bogor").into_bytes(),
+			String::from("ID").into_bytes(),
+			String::from("JB").into_bytes(),
+			String::from("BOGOR").into_bytes(),
 			String::from("Vaksin").into_bytes(),
 			10
 		));
 
-		let request_id = ServiceRequest::request_by_account_id(customer)[0];
+		let request_id = ServiceRequest::request_by_account_id(&customer)[0];
 
 		// Register lab
 		assert_ok!(Labs::register_lab(
@@ -1046,17 +1496,19 @@ fn cant_claim_request_when_service_not_exists() {
 				box_public_key: Keccak256::hash(
 					"0xDb9Af2d1f3ADD2726A132AA7A65Cc9E6fC5761C3".as_bytes()
 				),
-				name: "DeBio Lab".as_bytes().to_vec(),
-				email: "DeBio Email".as_bytes().to_vec(),
-				country: CountryCode::from_vec("DC".as_bytes().to_vec()),
-				region: RegionCode::from_vec("DB".as_bytes().to_vec()),
-				city: CityCode::from_vec("CITY".as_bytes().to_vec()),
-				address: "DeBio Address".as_bytes().to_vec(),
-				phone_number: "+6281394653625".as_bytes().to_vec(),
-				website: "DeBio Website".as_bytes().to_vec(),
-				latitude: Some("DeBio Latitude".as_bytes().to_vec()),
-				longitude: Some("DeBio Longtitude".as_bytes().to_vec()),
-				profile_image: Some("DeBio Profile Image uwu".as_bytes().to_vec()),
+				name: "DeBio Lab".as_bytes().to_vec().try_into().unwrap(),
+				email: "DeBio Email".as_bytes().to_vec().try_into().unwrap(),
+				country: CountryCode::try_from("ID".as_bytes().to_vec()).unwrap(),
+				region: RegionCode::try_from("JB".as_bytes().to_vec()).unwrap(),
+				city: CityCode::try_from("CITY".as_bytes().to_vec()).unwrap(),
+				address: "DeBio Address".as_bytes().to_vec().try_into().unwrap(),
+				phone_number: "+6281394653625".as_bytes().to_vec().try_into().unwrap(),
+				website: "DeBio Website".as_bytes().to_vec().try_into().unwrap(),
+				latitude: Some("-6.175392".as_bytes().to_vec().try_into().unwrap()),
+				longitude: Some("106.827153".as_bytes().to_vec().try_into().unwrap()),
+				profile_image: Some(
+					"DeBio Profile Image uwu".as_bytes().to_vec().try_into().unwrap()
+				),
 			}
 		));
 
@@ -1090,14 +1542,14 @@ fn cant_claim_request_when_not_service_owner() {
 		// Customer create request
 		assert_ok!(ServiceRequest::create_request(
 			RuntimeOrigin::signed(customer),
-			String::from("Indonesia").into_bytes(),
-			String::from("West Java").into_bytes(),
-			String::from("Bogor").into_bytes(),
+			String::from("ID").into_bytes(),
+			String::from("JB").into_bytes(),
+			String::from("BOGOR").into_bytes(),
 			String::from("Vaksin").into_bytes(),
 			10
 		));
 
-		let request_id = ServiceRequest::request_by_account_id(customer)[0];
+		let request_id = ServiceRequest::request_by_account_id(&customer)[0];
 
 		// Register lab
 		assert_ok!(Labs::register_lab(
@@ -1106,17 +1558,19 @@ fn cant_claim_request_when_not_service_owner() {
 				box_public_key: Keccak256::hash(
 					"0xDb9Af2d1f3ADD2726A132AA7A65Cc9E6fC5761C3".as_bytes()
 				),
-				name: "DeBio Lab".as_bytes().to_vec(),
-				email: "DeBio Email".as_bytes().to_vec(),
-				country: CountryCode::from_vec("DC".as_bytes().to_vec()),
-				region: RegionCode::from_vec("DB".as_bytes().to_vec()),
-				city: CityCode::from_vec("CITY".as_bytes().to_vec()),
-				address: "DeBio Address".as_bytes().to_vec(),
-				phone_number: "+6281394653625".as_bytes().to_vec(),
-				website: "DeBio Website".as_bytes().to_vec(),
-				latitude: Some("DeBio Latitude".as_bytes().to_vec()),
-				longitude: Some("DeBio Longtitude".as_bytes().to_vec()),
-				profile_image: Some("DeBio Profile Image uwu".as_bytes().to_vec()),
+				name: "DeBio Lab".as_bytes().to_vec().try_into().unwrap(),
+				email: "DeBio Email".as_bytes().to_vec().try_into().unwrap(),
+				country: CountryCode::try_from("ID".as_bytes().to_vec()).unwrap(),
+				region: RegionCode::try_from("JB".as_bytes().to_vec()).unwrap(),
+				city: CityCode::try_from("CITY".as_bytes().to_vec()).unwrap(),
+				address: "DeBio Address".as_bytes().to_vec().try_into().unwrap(),
+				phone_number: "+6281394653625".as_bytes().to_vec().try_into().unwrap(),
+				website: "DeBio Website".as_bytes().to_vec().try_into().unwrap(),
+				latitude: Some("-6.175392".as_bytes().to_vec().try_into().unwrap()),
+				longitude: Some("106.827153".as_bytes().to_vec().try_into().unwrap()),
+				profile_image: Some(
+					"DeBio Profile Image uwu".as_bytes().to_vec().try_into().unwrap()
+				),
 			}
 		));
 
@@ -1127,17 +1581,19 @@ fn cant_claim_request_when_not_service_owner() {
 				box_public_key: Keccak256::hash(
 					"0xDb9Af2d1f3ADD2726A132AA7A65Cc9E6fC5761C3".as_bytes()
 				),
-				name: "DeBio Lab".as_bytes().to_vec(),
-				email: "DeBio Email".as_bytes().to_vec(),
-				country: CountryCode::from_vec("DC".as_bytes().to_vec()),
-				region: RegionCode::from_vec("DB".as_bytes().to_vec()),
-				city: CityCode::from_vec("CITY".as_bytes().to_vec()),
-				address: "DeBio Address".as_bytes().to_vec(),
-				phone_number: "+6281394653625".as_bytes().to_vec(),
-				website: "DeBio Website".as_bytes().to_vec(),
-				latitude: Some("DeBio Latitude".as_bytes().to_vec()),
-				longitude: Some("DeBio Longtitude".as_bytes().to_vec()),
-				profile_image: Some("DeBio Profile Image uwu".as_bytes().to_vec()),
+				name: "DeBio Lab".as_bytes().to_vec().try_into().unwrap(),
+				email: "DeBio Email".as_bytes().to_vec().try_into().unwrap(),
+				country: CountryCode::try_from("ID".as_bytes().to_vec()).unwrap(),
+				region: RegionCode::try_from("JB".as_bytes().to_vec()).unwrap(),
+				city: CityCode::try_from("CITY".as_bytes().to_vec()).unwrap(),
+				address: "DeBio Address".as_bytes().to_vec().try_into().unwrap(),
+				phone_number: "+6281394653625".as_bytes().to_vec().try_into().unwrap(),
+				website: "DeBio Website".as_bytes().to_vec().try_into().unwrap(),
+				latitude: Some("-6.175392".as_bytes().to_vec().try_into().unwrap()),
+				longitude: Some("106.827153".as_bytes().to_vec().try_into().unwrap()),
+				profile_image: Some(
+					"DeBio Profile Image uwu".as_bytes().to_vec().try_into().unwrap()
+				),
 			}
 		));
 
@@ -1152,22 +1608,42 @@ fn cant_claim_request_when_not_service_owner() {
 		let prices_by_currency_dbio = PriceByCurrency {
 			currency: CurrencyType::DBIO,
 			total_price: 10,
-			price_components: vec![Price { component: b"testing_price".to_vec(), value: 5 }],
-			additional_prices: vec![Price { component: b"qc_price".to_vec(), value: 5 }],
+			price_components: vec![Price {
+				component: b"testing_price".to_vec().try_into().unwrap(),
+				value: 5,
+			}]
+			.try_into()
+			.unwrap(),
+			additional_prices: vec![Price {
+				component: b"qc_price".to_vec().try_into().unwrap(),
+				value: 5,
+			}]
+			.try_into()
+			.unwrap(),
 		};
 
 		assert_ok!(Services::create_service(
 			RuntimeOrigin::signed(lab),
 			ServiceInfo {
-				name: "DeBio service name".as_bytes().to_vec(),
-				prices_by_currency: vec![prices_by_currency_dbio],
+				name: "DeBio service name".as_bytes().to_vec().try_into().unwrap(),
+				prices_by_currency: vec![prices_by_currency_dbio].try_into().unwrap(),
 				expected_duration: ExpectedDuration::default(),
-				category: "DeBio service category".as_bytes().to_vec(),
-				description: "DeBio service description".as_bytes().to_vec(),
-				dna_collection_process: "DeBio service dna_collection_process".as_bytes().to_vec(),
-				test_result_sample: "DeBio service test_result_sample".as_bytes().to_vec(),
-				long_description: Some("DeBio service long_description".as_bytes().to_vec()),
-				image: Some("DeBio service image".as_bytes().to_vec()),
+				category: "DeBio service category".as_bytes().to_vec().try_into().unwrap(),
+				description: "DeBio service description".as_bytes().to_vec().try_into().unwrap(),
+				dna_collection_process: "DeBio service dna_collection_process"
+					.as_bytes()
+					.to_vec()
+					.try_into()
+					.unwrap(),
+				test_result_sample: "DeBio service test_result_sample"
+					.as_bytes()
+					.to_vec()
+					.try_into()
+					.unwrap(),
+				long_description: Some(
+					"DeBio service long_description".as_bytes().to_vec().try_into().unwrap()
+				),
+				image: Some("DeBio service image".as_bytes().to_vec().try_into().unwrap()),
 			},
 			ServiceFlow::default()
 		));
@@ -1193,31 +1669,33 @@ fn cant_process_request_when_unathorized_customer() {
 		// Customer create request
 		assert_ok!(ServiceRequest::create_request(
 			RuntimeOrigin::signed(customer),
-			String::from("Indonesia").into_bytes(),
-			String::from("West Java").into_bytes(),
-			String::from("Bogor").into_bytes(),
+			String::from("ID").into_bytes(),
+			String::from("JB").into_bytes(),
+			String::from("BOGOR").into_bytes(),
 			String::from("Vaksin").into_bytes(),
 			10
 		));
 
-		let request_id = ServiceRequest::request_by_account_id(customer)[0];
+		let request_id = ServiceRequest::request_by_account_id(&customer)[0];
 
 		// Register lab
 		assert_ok!(Labs::register_lab(
 			RuntimeOrigin::signed(lab),
 			LabInfo {
 				box_public_key: Keccak256::hash("box_public_key".as_bytes()),
-				name: "DeBio Lab".as_bytes().to_vec(),
-				email: "DeBio Email".as_bytes().to_vec(),
-				country: CountryCode::from_vec("DC".as_bytes().to_vec()),
-				region: RegionCode::from_vec("DB".as_bytes().to_vec()),
-				city: CityCode::from_vec("CITY".as_bytes().to_vec()),
-				address: "DeBio Address".as_bytes().to_vec(),
-				phone_number: "+6281394653625".as_bytes().to_vec(),
-				website: "DeBio Website".as_bytes().to_vec(),
-				latitude: Some("DeBio Latitude".as_bytes().to_vec()),
-				longitude: Some("DeBio Longtitude".as_bytes().to_vec()),
-				profile_image: Some("DeBio Profile Image uwu".as_bytes().to_vec()),
+				name: "DeBio Lab".as_bytes().to_vec().try_into().unwrap(),
+				email: "DeBio Email".as_bytes().to_vec().try_into().unwrap(),
+				country: CountryCode::try_from("ID".as_bytes().to_vec()).unwrap(),
+				region: RegionCode::try_from("JB".as_bytes().to_vec()).unwrap(),
+				city: CityCode::try_from("CITY".as_bytes().to_vec()).unwrap(),
+				address: "DeBio Address".as_bytes().to_vec().try_into().unwrap(),
+				phone_number: "+6281394653625".as_bytes().to_vec().try_into().unwrap(),
+				website: "DeBio Website".as_bytes().to_vec().try_into().unwrap(),
+				latitude: Some("-6.175392".as_bytes().to_vec().try_into().unwrap()),
+				longitude: Some("106.827153".as_bytes().to_vec().try_into().unwrap()),
+				profile_image: Some(
+					"DeBio Profile Image uwu".as_bytes().to_vec().try_into().unwrap()
+				),
 			}
 		));
 
@@ -1232,22 +1710,42 @@ fn cant_process_request_when_unathorized_customer() {
 		let prices_by_currency_dbio = PriceByCurrency {
 			currency: CurrencyType::DBIO,
 			total_price: 10,
-			price_components: vec![Price { component: b"testing_price".to_vec(), value: 5 }],
-			additional_prices: vec![Price { component: b"qc_price".to_vec(), value: 5 }],
+			price_components: vec![Price {
+				component: b"testing_price".to_vec().try_into().unwrap(),
+				value: 5,
+			}]
+			.try_into()
+			.unwrap(),
+			additional_prices: vec![Price {
+				component: b"qc_price".to_vec().try_into().unwrap(),
+				value: 5,
+			}]
+			.try_into()
+			.unwrap(),
 		};
 
 		assert_ok!(Services::create_service(
 			RuntimeOrigin::signed(lab),
 			ServiceInfo {
-				name: "DeBio service name".as_bytes().to_vec(),
-				prices_by_currency: vec![prices_by_currency_dbio],
+				name: "DeBio service name".as_bytes().to_vec().try_into().unwrap(),
+				prices_by_currency: vec![prices_by_currency_dbio].try_into().unwrap(),
 				expected_duration: ExpectedDuration::default(),
-				category: "DeBio service category".as_bytes().to_vec(),
-				description: "DeBio service description".as_bytes().to_vec(),
-				dna_collection_process: "DeBio service dna_collection_process".as_bytes().to_vec(),
-				test_result_sample: "DeBio service test_result_sample".as_bytes().to_vec(),
-				long_description: Some("DeBio service long_description".as_bytes().to_vec()),
-				image: Some("DeBio service image".as_bytes().to_vec()),
+				category: "DeBio service category".as_bytes().to_vec().try_into().unwrap(),
+				description: "DeBio service description".as_bytes().to_vec().try_into().unwrap(),
+				dna_collection_process: "DeBio service dna_collection_process"
+					.as_bytes()
+					.to_vec()
+					.try_into()
+					.unwrap(),
+				test_result_sample: "DeBio service test_result_sample"
+					.as_bytes()
+					.to_vec()
+					.try_into()
+					.unwrap(),
+				long_description: Some(
+					"DeBio service long_description".as_bytes().to_vec().try_into().unwrap()
+				),
+				image: Some("DeBio service image".as_bytes().to_vec().try_into().unwrap()),
 			},
 			ServiceFlow::default()
 		));
@@ -1282,31 +1780,33 @@ fn cant_process_request_when_order_not_found() {
 		// Customer create request
 		assert_ok!(ServiceRequest::create_request(
 			RuntimeOrigin::signed(customer),
-			String::from("Indonesia").into_bytes(),
-			String::from("West Java").into_bytes(),
-			String::from("Bogor").into_bytes(),
+			String::from("ID").into_bytes(),
+			String::from("JB").into_bytes(),
+			String::from("BOGOR").into_bytes(),
 			String::from("Vaksin").into_bytes(),
 			10
 		));
 
-		let request_id = ServiceRequest::request_by_account_id(customer)[0];
+		let request_id = ServiceRequest::request_by_account_id(&customer)[0];
 
 		// Register lab
 		assert_ok!(Labs::register_lab(
 			RuntimeOrigin::signed(lab),
 			LabInfo {
 				box_public_key: Keccak256::hash("box_public_key".as_bytes()),
-				name: "DeBio Lab".as_bytes().to_vec(),
-				email: "DeBio Email".as_bytes().to_vec(),
-				country: CountryCode::from_vec("DC".as_bytes().to_vec()),
-				region: RegionCode::from_vec("DB".as_bytes().to_vec()),
-				city: CityCode::from_vec("CITY".as_bytes().to_vec()),
-				address: "DeBio Address".as_bytes().to_vec(),
-				phone_number: "+6281394653625".as_bytes().to_vec(),
-				website: "DeBio Website".as_bytes().to_vec(),
-				latitude: Some("DeBio Latitude".as_bytes().to_vec()),
-				longitude: Some("DeBio Longtitude".as_bytes().to_vec()),
-				profile_image: Some("DeBio Profile Image uwu".as_bytes().to_vec()),
+				name: "DeBio Lab".as_bytes().to_vec().try_into().unwrap(),
+				email: "DeBio Email".as_bytes().to_vec().try_into().unwrap(),
+				country: CountryCode::try_from("ID".as_bytes().to_vec()).unwrap(),
+				region: RegionCode::try_from("JB".as_bytes().to_vec()).unwrap(),
+				city: CityCode::try_from("CITY".as_bytes().to_vec()).unwrap(),
+				address: "DeBio Address".as_bytes().to_vec().try_into().unwrap(),
+				phone_number: "+6281394653625".as_bytes().to_vec().try_into().unwrap(),
+				website: "DeBio Website".as_bytes().to_vec().try_into().unwrap(),
+				latitude: Some("-6.175392".as_bytes().to_vec().try_into().unwrap()),
+				longitude: Some("106.827153".as_bytes().to_vec().try_into().unwrap()),
+				profile_image: Some(
+					"DeBio Profile Image uwu".as_bytes().to_vec().try_into().unwrap()
+				),
 			}
 		));
 
@@ -1321,22 +1821,42 @@ fn cant_process_request_when_order_not_found() {
 		let prices_by_currency_dbio = PriceByCurrency {
 			currency: CurrencyType::DBIO,
 			total_price: 10,
-			price_components: vec![Price { component: b"testing_price".to_vec(), value: 5 }],
-			additional_prices: vec![Price { component: b"qc_price".to_vec(), value: 5 }],
+			price_components: vec![Price {
+				component: b"testing_price".to_vec().try_into().unwrap(),
+				value: 5,
+			}]
+			.try_into()
+			.unwrap(),
+			additional_prices: vec![Price {
+				component: b"qc_price".to_vec().try_into().unwrap(),
+				value: 5,
+			}]
+			.try_into()
+			.unwrap(),
 		};
 
 		assert_ok!(Services::create_service(
 			RuntimeOrigin::signed(lab),
 			ServiceInfo {
-				name: "DeBio service name".as_bytes().to_vec(),
-				prices_by_currency: vec![prices_by_currency_dbio],
+				name: "DeBio service name".as_bytes().to_vec().try_into().unwrap(),
+				prices_by_currency: vec![prices_by_currency_dbio].try_into().unwrap(),
 				expected_duration: ExpectedDuration::default(),
-				category: "DeBio service category".as_bytes().to_vec(),
-				description: "DeBio service description".as_bytes().to_vec(),
-				dna_collection_process: "DeBio service dna_collection_process".as_bytes().to_vec(),
-				test_result_sample: "DeBio service test_result_sample".as_bytes().to_vec(),
-				long_description: Some("DeBio service long_description".as_bytes().to_vec()),
-				image: Some("DeBio service image".as_bytes().to_vec()),
+				category: "DeBio service category".as_bytes().to_vec().try_into().unwrap(),
+				description: "DeBio service description".as_bytes().to_vec().try_into().unwrap(),
+				dna_collection_process: "DeBio service dna_collection_process"
+					.as_bytes()
+					.to_vec()
+					.try_into()
+					.unwrap(),
+				test_result_sample: "DeBio service test_result_sample"
+					.as_bytes()
+					.to_vec()
+					.try_into()
+					.unwrap(),
+				long_description: Some(
+					"DeBio service long_description".as_bytes().to_vec().try_into().unwrap()
+				),
+				image: Some("DeBio service image".as_bytes().to_vec().try_into().unwrap()),
 			},
 			ServiceFlow::default()
 		));
@@ -1376,31 +1896,33 @@ fn cant_process_request_when_order_fullfilled() {
 		// Customer create request
 		assert_ok!(ServiceRequest::create_request(
 			RuntimeOrigin::signed(customer),
-			String::from("Indonesia").into_bytes(),
-			String::from("West Java").into_bytes(),
-			String::from("Bogor").into_bytes(),
+			String::from("ID").into_bytes(),
+			String::from("JB").into_bytes(),
+			String::from("BOGOR").into_bytes(),
 			String::from("Vaksin").into_bytes(),
 			10
 		));
 
-		let request_id = ServiceRequest::request_by_account_id(customer)[0];
+		let request_id = ServiceRequest::request_by_account_id(&customer)[0];
 
 		// Register lab
 		assert_ok!(Labs::register_lab(
 			RuntimeOrigin::signed(lab),
 			LabInfo {
 				box_public_key: Keccak256::hash("box_public_key".as_bytes()),
-				name: "DeBio Lab".as_bytes().to_vec(),
-				email: "DeBio Email".as_bytes().to_vec(),
-				country: CountryCode::from_vec("DC".as_bytes().to_vec()),
-				region: RegionCode::from_vec("DB".as_bytes().to_vec()),
-				city: CityCode::from_vec("CITY".as_bytes().to_vec()),
-				address: "DeBio Address".as_bytes().to_vec(),
-				phone_number: "+6281394653625".as_bytes().to_vec(),
-				website: "DeBio Website".as_bytes().to_vec(),
-				latitude: Some("DeBio Latitude".as_bytes().to_vec()),
-				longitude: Some("DeBio Longtitude".as_bytes().to_vec()),
-				profile_image: Some("DeBio Profile Image uwu".as_bytes().to_vec()),
+				name: "DeBio Lab".as_bytes().to_vec().try_into().unwrap(),
+				email: "DeBio Email".as_bytes().to_vec().try_into().unwrap(),
+				country: CountryCode::try_from("ID".as_bytes().to_vec()).unwrap(),
+				region: RegionCode::try_from("JB".as_bytes().to_vec()).unwrap(),
+				city: CityCode::try_from("CITY".as_bytes().to_vec()).unwrap(),
+				address: "DeBio Address".as_bytes().to_vec().try_into().unwrap(),
+				phone_number: "+6281394653625".as_bytes().to_vec().try_into().unwrap(),
+				website: "DeBio Website".as_bytes().to_vec().try_into().unwrap(),
+				latitude: Some("-6.175392".as_bytes().to_vec().try_into().unwrap()),
+				longitude: Some("106.827153".as_bytes().to_vec().try_into().unwrap()),
+				profile_image: Some(
+					"DeBio Profile Image uwu".as_bytes().to_vec().try_into().unwrap()
+				),
 			}
 		));
 
@@ -1415,22 +1937,42 @@ fn cant_process_request_when_order_fullfilled() {
 		let prices_by_currency_dbio = PriceByCurrency {
 			currency: CurrencyType::DBIO,
 			total_price: 10,
-			price_components: vec![Price { component: b"testing_price".to_vec(), value: 5 }],
-			additional_prices: vec![Price { component: b"qc_price".to_vec(), value: 5 }],
+			price_components: vec![Price {
+				component: b"testing_price".to_vec().try_into().unwrap(),
+				value: 5,
+			}]
+			.try_into()
+			.unwrap(),
+			additional_prices: vec![Price {
+				component: b"qc_price".to_vec().try_into().unwrap(),
+				value: 5,
+			}]
+			.try_into()
+			.unwrap(),
 		};
 
 		assert_ok!(Services::create_service(
 			RuntimeOrigin::signed(lab),
 			ServiceInfo {
-				name: "DeBio service name".as_bytes().to_vec(),
-				prices_by_currency: vec![prices_by_currency_dbio],
+				name: "DeBio service name".as_bytes().to_vec().try_into().unwrap(),
+				prices_by_currency: vec![prices_by_currency_dbio].try_into().unwrap(),
 				expected_duration: ExpectedDuration::default(),
-				category: "DeBio service category".as_bytes().to_vec(),
-				description: "DeBio service description".as_bytes().to_vec(),
-				dna_collection_process: "DeBio service dna_collection_process".as_bytes().to_vec(),
-				test_result_sample: "DeBio service test_result_sample".as_bytes().to_vec(),
-				long_description: Some("DeBio service long_description".as_bytes().to_vec()),
-				image: Some("DeBio service image".as_bytes().to_vec()),
+				category: "DeBio service category".as_bytes().to_vec().try_into().unwrap(),
+				description: "DeBio service description".as_bytes().to_vec().try_into().unwrap(),
+				dna_collection_process: "DeBio service dna_collection_process"
+					.as_bytes()
+					.to_vec()
+					.try_into()
+					.unwrap(),
+				test_result_sample: "DeBio service test_result_sample"
+					.as_bytes()
+					.to_vec()
+					.try_into()
+					.unwrap(),
+				long_description: Some(
+					"DeBio service long_description".as_bytes().to_vec().try_into().unwrap()
+				),
+				image: Some("DeBio service image".as_bytes().to_vec().try_into().unwrap()),
 			},
 			ServiceFlow::default()
 		));
@@ -1461,7 +2003,7 @@ fn cant_process_request_when_order_fullfilled() {
 			order_id,
 		));
 
-		let dna_sample = GeneticTesting::dna_samples_by_lab_id(lab).unwrap();
+		let dna_sample = GeneticTesting::dna_samples_by_lab_id(&lab);
 
 		assert_ok!(Orders::set_order_paid(RuntimeOrigin::signed(customer), order_id));
 
@@ -1471,9 +2013,9 @@ fn cant_process_request_when_order_fullfilled() {
 			RuntimeOrigin::signed(lab),
 			dna_sample[0].clone(),
 			DnaTestResultSubmission {
-				comments: Some("comment".as_bytes().to_vec()),
-				result_link: Some("result_link".as_bytes().to_vec()),
-				report_link: Some("report_link".as_bytes().to_vec()),
+				comments: Some("comment".as_bytes().to_vec().try_into().unwrap()),
+				result_link: Some("result_link".as_bytes().to_vec().try_into().unwrap()),
+				report_link: Some("report_link".as_bytes().to_vec().try_into().unwrap()),
 			}
 		));
 
@@ -1507,31 +2049,33 @@ fn cant_process_request_when_order_from_other_lab() {
 		// Customer create request
 		assert_ok!(ServiceRequest::create_request(
 			RuntimeOrigin::signed(customer),
-			String::from("Indonesia").into_bytes(),
-			String::from("West Java").into_bytes(),
-			String::from("Bogor").into_bytes(),
+			String::from("ID").into_bytes(),
+			String::from("JB").into_bytes(),
+			String::from("BOGOR").into_bytes(),
 			String::from("Vaksin").into_bytes(),
 			10
 		));
 
-		let request_id = ServiceRequest::request_by_account_id(customer)[0];
+		let request_id = ServiceRequest::request_by_account_id(&customer)[0];
 
 		// Register lab
 		assert_ok!(Labs::register_lab(
 			RuntimeOrigin::signed(lab),
 			LabInfo {
 				box_public_key: Keccak256::hash("box_public_key".as_bytes()),
-				name: "DeBio Lab".as_bytes().to_vec(),
-				email: "DeBio Email".as_bytes().to_vec(),
-				country: CountryCode::from_vec("DC".as_bytes().to_vec()),
-				region: RegionCode::from_vec("DB".as_bytes().to_vec()),
-				city: CityCode::from_vec("CITY".as_bytes().to_vec()),
-				address: "DeBio Address".as_bytes().to_vec(),
-				phone_number: "+6281394653625".as_bytes().to_vec(),
-				website: "DeBio Website".as_bytes().to_vec(),
-				latitude: Some("DeBio Latitude".as_bytes().to_vec()),
-				longitude: Some("DeBio Longtitude".as_bytes().to_vec()),
-				profile_image: Some("DeBio Profile Image uwu".as_bytes().to_vec()),
+				name: "DeBio Lab".as_bytes().to_vec().try_into().unwrap(),
+				email: "DeBio Email".as_bytes().to_vec().try_into().unwrap(),
+				country: CountryCode::try_from("ID".as_bytes().to_vec()).unwrap(),
+				region: RegionCode::try_from("JB".as_bytes().to_vec()).unwrap(),
+				city: CityCode::try_from("CITY".as_bytes().to_vec()).unwrap(),
+				address: "DeBio Address".as_bytes().to_vec().try_into().unwrap(),
+				phone_number: "+6281394653625".as_bytes().to_vec().try_into().unwrap(),
+				website: "DeBio Website".as_bytes().to_vec().try_into().unwrap(),
+				latitude: Some("-6.175392".as_bytes().to_vec().try_into().unwrap()),
+				longitude: Some("106.827153".as_bytes().to_vec().try_into().unwrap()),
+				profile_image: Some(
+					"DeBio Profile Image uwu".as_bytes().to_vec().try_into().unwrap()
+				),
 			}
 		));
 
@@ -1540,17 +2084,19 @@ fn cant_process_request_when_order_from_other_lab() {
 			RuntimeOrigin::signed(other_lab),
 			LabInfo {
 				box_public_key: Keccak256::hash("box_public_key".as_bytes()),
-				name: "DeBio Lab".as_bytes().to_vec(),
-				email: "DeBio Email".as_bytes().to_vec(),
-				country: CountryCode::from_vec("DC".as_bytes().to_vec()),
-				region: RegionCode::from_vec("DB".as_bytes().to_vec()),
-				city: CityCode::from_vec("CITY".as_bytes().to_vec()),
-				address: "DeBio Address".as_bytes().to_vec(),
-				phone_number: "+6281394653625".as_bytes().to_vec(),
-				website: "DeBio Website".as_bytes().to_vec(),
-				latitude: Some("DeBio Latitude".as_bytes().to_vec()),
-				longitude: Some("DeBio Longtitude".as_bytes().to_vec()),
-				profile_image: Some("DeBio Profile Image uwu".as_bytes().to_vec()),
+				name: "DeBio Lab".as_bytes().to_vec().try_into().unwrap(),
+				email: "DeBio Email".as_bytes().to_vec().try_into().unwrap(),
+				country: CountryCode::try_from("ID".as_bytes().to_vec()).unwrap(),
+				region: RegionCode::try_from("JB".as_bytes().to_vec()).unwrap(),
+				city: CityCode::try_from("CITY".as_bytes().to_vec()).unwrap(),
+				address: "DeBio Address".as_bytes().to_vec().try_into().unwrap(),
+				phone_number: "+6281394653625".as_bytes().to_vec().try_into().unwrap(),
+				website: "DeBio Website".as_bytes().to_vec().try_into().unwrap(),
+				latitude: Some("-6.175392".as_bytes().to_vec().try_into().unwrap()),
+				longitude: Some("106.827153".as_bytes().to_vec().try_into().unwrap()),
+				profile_image: Some(
+					"DeBio Profile Image uwu".as_bytes().to_vec().try_into().unwrap()
+				),
 			}
 		));
 
@@ -1565,22 +2111,42 @@ fn cant_process_request_when_order_from_other_lab() {
 		let prices_by_currency_dbio = PriceByCurrency {
 			currency: CurrencyType::DBIO,
 			total_price: 10,
-			price_components: vec![Price { component: b"testing_price".to_vec(), value: 5 }],
-			additional_prices: vec![Price { component: b"qc_price".to_vec(), value: 5 }],
+			price_components: vec![Price {
+				component: b"testing_price".to_vec().try_into().unwrap(),
+				value: 5,
+			}]
+			.try_into()
+			.unwrap(),
+			additional_prices: vec![Price {
+				component: b"qc_price".to_vec().try_into().unwrap(),
+				value: 5,
+			}]
+			.try_into()
+			.unwrap(),
 		};
 
 		assert_ok!(Services::create_service(
 			RuntimeOrigin::signed(lab),
 			ServiceInfo {
-				name: "DeBio service name".as_bytes().to_vec(),
-				prices_by_currency: vec![prices_by_currency_dbio.clone()],
+				name: "DeBio service name".as_bytes().to_vec().try_into().unwrap(),
+				prices_by_currency: vec![prices_by_currency_dbio.clone()].try_into().unwrap(),
 				expected_duration: ExpectedDuration::default(),
-				category: "DeBio service category".as_bytes().to_vec(),
-				description: "DeBio service description".as_bytes().to_vec(),
-				dna_collection_process: "DeBio service dna_collection_process".as_bytes().to_vec(),
-				test_result_sample: "DeBio service test_result_sample".as_bytes().to_vec(),
-				long_description: Some("DeBio service long_description".as_bytes().to_vec()),
-				image: Some("DeBio service image".as_bytes().to_vec()),
+				category: "DeBio service category".as_bytes().to_vec().try_into().unwrap(),
+				description: "DeBio service description".as_bytes().to_vec().try_into().unwrap(),
+				dna_collection_process: "DeBio service dna_collection_process"
+					.as_bytes()
+					.to_vec()
+					.try_into()
+					.unwrap(),
+				test_result_sample: "DeBio service test_result_sample"
+					.as_bytes()
+					.to_vec()
+					.try_into()
+					.unwrap(),
+				long_description: Some(
+					"DeBio service long_description".as_bytes().to_vec().try_into().unwrap()
+				),
+				image: Some("DeBio service image".as_bytes().to_vec().try_into().unwrap()),
 			},
 			ServiceFlow::default()
 		));
@@ -1588,15 +2154,25 @@ fn cant_process_request_when_order_from_other_lab() {
 		assert_ok!(Services::create_service(
 			RuntimeOrigin::signed(other_lab),
 			ServiceInfo {
-				name: "DeBio service name".as_bytes().to_vec(),
-				prices_by_currency: vec![prices_by_currency_dbio],
+				name: "DeBio service name".as_bytes().to_vec().try_into().unwrap(),
+				prices_by_currency: vec![prices_by_currency_dbio].try_into().unwrap(),
 				expected_duration: ExpectedDuration::default(),
-				category: "DeBio service category".as_bytes().to_vec(),
-				description: "DeBio service description".as_bytes().to_vec(),
-				dna_collection_process: "DeBio service dna_collection_process".as_bytes().to_vec(),
-				test_result_sample: "DeBio service test_result_sample".as_bytes().to_vec(),
-				long_description: Some("DeBio service long_description".as_bytes().to_vec()),
-				image: Some("DeBio service image".as_bytes().to_vec()),
+				category: "DeBio service category".as_bytes().to_vec().try_into().unwrap(),
+				description: "DeBio service description".as_bytes().to_vec().try_into().unwrap(),
+				dna_collection_process: "DeBio service dna_collection_process"
+					.as_bytes()
+					.to_vec()
+					.try_into()
+					.unwrap(),
+				test_result_sample: "DeBio service test_result_sample"
+					.as_bytes()
+					.to_vec()
+					.try_into()
+					.unwrap(),
+				long_description: Some(
+					"DeBio service long_description".as_bytes().to_vec().try_into().unwrap()
+				),
+				image: Some("DeBio service image".as_bytes().to_vec().try_into().unwrap()),
 			},
 			ServiceFlow::default()
 		));
@@ -1642,31 +2218,33 @@ fn cant_process_request_when_order_not_customer() {
 		// Customer create request
 		assert_ok!(ServiceRequest::create_request(
 			RuntimeOrigin::signed(customer),
-			String::from("Indonesia").into_bytes(),
-			String::from("West Java").into_bytes(),
-			String::from("Bogor").into_bytes(),
+			String::from("ID").into_bytes(),
+			String::from("JB").into_bytes(),
+			String::from("BOGOR").into_bytes(),
 			String::from("Vaksin").into_bytes(),
 			10
 		));
 
-		let request_id = ServiceRequest::request_by_account_id(customer)[0];
+		let request_id = ServiceRequest::request_by_account_id(&customer)[0];
 
 		// Register lab
 		assert_ok!(Labs::register_lab(
 			RuntimeOrigin::signed(lab),
 			LabInfo {
 				box_public_key: Keccak256::hash("box_public_key".as_bytes()),
-				name: "DeBio Lab".as_bytes().to_vec(),
-				email: "DeBio Email".as_bytes().to_vec(),
-				country: CountryCode::from_vec("DC".as_bytes().to_vec()),
-				region: RegionCode::from_vec("DB".as_bytes().to_vec()),
-				city: CityCode::from_vec("CITY".as_bytes().to_vec()),
-				address: "DeBio Address".as_bytes().to_vec(),
-				phone_number: "+6281394653625".as_bytes().to_vec(),
-				website: "DeBio Website".as_bytes().to_vec(),
-				latitude: Some("DeBio Latitude".as_bytes().to_vec()),
-				longitude: Some("DeBio Longtitude".as_bytes().to_vec()),
-				profile_image: Some("DeBio Profile Image uwu".as_bytes().to_vec()),
+				name: "DeBio Lab".as_bytes().to_vec().try_into().unwrap(),
+				email: "DeBio Email".as_bytes().to_vec().try_into().unwrap(),
+				country: CountryCode::try_from("ID".as_bytes().to_vec()).unwrap(),
+				region: RegionCode::try_from("JB".as_bytes().to_vec()).unwrap(),
+				city: CityCode::try_from("CITY".as_bytes().to_vec()).unwrap(),
+				address: "DeBio Address".as_bytes().to_vec().try_into().unwrap(),
+				phone_number: "+6281394653625".as_bytes().to_vec().try_into().unwrap(),
+				website: "DeBio Website".as_bytes().to_vec().try_into().unwrap(),
+				latitude: Some("-6.175392".as_bytes().to_vec().try_into().unwrap()),
+				longitude: Some("106.827153".as_bytes().to_vec().try_into().unwrap()),
+				profile_image: Some(
+					"DeBio Profile Image uwu".as_bytes().to_vec().try_into().unwrap()
+				),
 			}
 		));
 
@@ -1681,22 +2259,42 @@ fn cant_process_request_when_order_not_customer() {
 		let prices_by_currency_dbio = PriceByCurrency {
 			currency: CurrencyType::DBIO,
 			total_price: 10,
-			price_components: vec![Price { component: b"testing_price".to_vec(), value: 5 }],
-			additional_prices: vec![Price { component: b"qc_price".to_vec(), value: 5 }],
+			price_components: vec![Price {
+				component: b"testing_price".to_vec().try_into().unwrap(),
+				value: 5,
+			}]
+			.try_into()
+			.unwrap(),
+			additional_prices: vec![Price {
+				component: b"qc_price".to_vec().try_into().unwrap(),
+				value: 5,
+			}]
+			.try_into()
+			.unwrap(),
 		};
 
 		assert_ok!(Services::create_service(
 			RuntimeOrigin::signed(lab),
 			ServiceInfo {
-				name: "DeBio service name".as_bytes().to_vec(),
-				prices_by_currency: vec![prices_by_currency_dbio],
+				name: "DeBio service name".as_bytes().to_vec().try_into().unwrap(),
+				prices_by_currency: vec![prices_by_currency_dbio].try_into().unwrap(),
 				expected_duration: ExpectedDuration::default(),
-				category: "DeBio service category".as_bytes().to_vec(),
-				description: "DeBio service description".as_bytes().to_vec(),
-				dna_collection_process: "DeBio service dna_collection_process".as_bytes().to_vec(),
-				test_result_sample: "DeBio service test_result_sample".as_bytes().to_vec(),
-				long_description: Some("DeBio service long_description".as_bytes().to_vec()),
-				image: Some("DeBio service image".as_bytes().to_vec()),
+				category: "DeBio service category".as_bytes().to_vec().try_into().unwrap(),
+				description: "DeBio service description".as_bytes().to_vec().try_into().unwrap(),
+				dna_collection_process: "DeBio service dna_collection_process"
+					.as_bytes()
+					.to_vec()
+					.try_into()
+					.unwrap(),
+				test_result_sample: "DeBio service test_result_sample"
+					.as_bytes()
+					.to_vec()
+					.try_into()
+					.unwrap(),
+				long_description: Some(
+					"DeBio service long_description".as_bytes().to_vec().try_into().unwrap()
+				),
+				image: Some("DeBio service image".as_bytes().to_vec().try_into().unwrap()),
 			},
 			ServiceFlow::default()
 		));
@@ -1738,31 +2336,33 @@ fn cant_process_request_when_order_from_wrong_service() {
 		// Customer create request
 		assert_ok!(ServiceRequest::create_request(
 			RuntimeOrigin::signed(customer),
-			String::from("Indonesia").into_bytes(),
-			String::from("West Java").into_bytes(),
-			String::from("Bogor").into_bytes(),
+			String::from("ID").into_bytes(),
+			String::from("JB").into_bytes(),
+			String::from("BOGOR").into_bytes(),
 			String::from("Vaksin").into_bytes(),
 			10
 		));
 
-		let request_id = ServiceRequest::request_by_account_id(customer)[0];
+		let request_id = ServiceRequest::request_by_account_id(&customer)[0];
 
 		// Register lab
 		assert_ok!(Labs::register_lab(
 			RuntimeOrigin::signed(lab),
 			LabInfo {
 				box_public_key: Keccak256::hash("box_public_key".as_bytes()),
-				name: "DeBio Lab".as_bytes().to_vec(),
-				email: "DeBio Email".as_bytes().to_vec(),
-				country: CountryCode::from_vec("DC".as_bytes().to_vec()),
-				region: RegionCode::from_vec("DB".as_bytes().to_vec()),
-				city: CityCode::from_vec("CITY".as_bytes().to_vec()),
-				address: "DeBio Address".as_bytes().to_vec(),
-				phone_number: "+6281394653625".as_bytes().to_vec(),
-				website: "DeBio Website".as_bytes().to_vec(),
-				latitude: Some("DeBio Latitude".as_bytes().to_vec()),
-				longitude: Some("DeBio Longtitude".as_bytes().to_vec()),
-				profile_image: Some("DeBio Profile Image uwu".as_bytes().to_vec()),
+				name: "DeBio Lab".as_bytes().to_vec().try_into().unwrap(),
+				email: "DeBio Email".as_bytes().to_vec().try_into().unwrap(),
+				country: CountryCode::try_from("ID".as_bytes().to_vec()).unwrap(),
+				region: RegionCode::try_from("JB".as_bytes().to_vec()).unwrap(),
+				city: CityCode::try_from("CITY".as_bytes().to_vec()).unwrap(),
+				address: "DeBio Address".as_bytes().to_vec().try_into().unwrap(),
+				phone_number: "+6281394653625".as_bytes().to_vec().try_into().unwrap(),
+				website: "DeBio Website".as_bytes().to_vec().try_into().unwrap(),
+				latitude: Some("-6.175392".as_bytes().to_vec().try_into().unwrap()),
+				longitude: Some("106.827153".as_bytes().to_vec().try_into().unwrap()),
+				profile_image: Some(
+					"DeBio Profile Image uwu".as_bytes().to_vec().try_into().unwrap()
+				),
 			}
 		));
 
@@ -1777,22 +2377,42 @@ fn cant_process_request_when_order_from_wrong_service() {
 		let prices_by_currency_dbio = PriceByCurrency {
 			currency: CurrencyType::DBIO,
 			total_price: 10,
-			price_components: vec![Price { component: b"testing_price".to_vec(), value: 5 }],
-			additional_prices: vec![Price { component: b"qc_price".to_vec(), value: 5 }],
+			price_components: vec![Price {
+				component: b"testing_price".to_vec().try_into().unwrap(),
+				value: 5,
+			}]
+			.try_into()
+			.unwrap(),
+			additional_prices: vec![Price {
+				component: b"qc_price".to_vec().try_into().unwrap(),
+				value: 5,
+			}]
+			.try_into()
+			.unwrap(),
 		};
 
 		assert_ok!(Services::create_service(
 			RuntimeOrigin::signed(lab),
 			ServiceInfo {
-				name: "DeBio service name".as_bytes().to_vec(),
-				prices_by_currency: vec![prices_by_currency_dbio.clone()],
+				name: "DeBio service name".as_bytes().to_vec().try_into().unwrap(),
+				prices_by_currency: vec![prices_by_currency_dbio.clone()].try_into().unwrap(),
 				expected_duration: ExpectedDuration::default(),
-				category: "DeBio service category".as_bytes().to_vec(),
-				description: "DeBio service description".as_bytes().to_vec(),
-				dna_collection_process: "DeBio service dna_collection_process".as_bytes().to_vec(),
-				test_result_sample: "DeBio service test_result_sample".as_bytes().to_vec(),
-				long_description: Some("DeBio service long_description".as_bytes().to_vec()),
-				image: Some("DeBio service image".as_bytes().to_vec()),
+				category: "DeBio service category".as_bytes().to_vec().try_into().unwrap(),
+				description: "DeBio service description".as_bytes().to_vec().try_into().unwrap(),
+				dna_collection_process: "DeBio service dna_collection_process"
+					.as_bytes()
+					.to_vec()
+					.try_into()
+					.unwrap(),
+				test_result_sample: "DeBio service test_result_sample"
+					.as_bytes()
+					.to_vec()
+					.try_into()
+					.unwrap(),
+				long_description: Some(
+					"DeBio service long_description".as_bytes().to_vec().try_into().unwrap()
+				),
+				image: Some("DeBio service image".as_bytes().to_vec().try_into().unwrap()),
 			},
 			ServiceFlow::default()
 		));
@@ -1800,15 +2420,25 @@ fn cant_process_request_when_order_from_wrong_service() {
 		assert_ok!(Services::create_service(
 			RuntimeOrigin::signed(lab),
 			ServiceInfo {
-				name: "DeBio service name".as_bytes().to_vec(),
-				prices_by_currency: vec![prices_by_currency_dbio],
+				name: "DeBio service name".as_bytes().to_vec().try_into().unwrap(),
+				prices_by_currency: vec![prices_by_currency_dbio].try_into().unwrap(),
 				expected_duration: ExpectedDuration::default(),
-				category: "DeBio service category".as_bytes().to_vec(),
-				description: "DeBio service description".as_bytes().to_vec(),
-				dna_collection_process: "DeBio service dna_collection_process".as_bytes().to_vec(),
-				test_result_sample: "DeBio service test_result_sample".as_bytes().to_vec(),
-				long_description: Some("DeBio service long_description".as_bytes().to_vec()),
-				image: Some("DeBio service image".as_bytes().to_vec()),
+				category: "DeBio service category".as_bytes().to_vec().try_into().unwrap(),
+				description: "DeBio service description".as_bytes().to_vec().try_into().unwrap(),
+				dna_collection_process: "DeBio service dna_collection_process"
+					.as_bytes()
+					.to_vec()
+					.try_into()
+					.unwrap(),
+				test_result_sample: "DeBio service test_result_sample"
+					.as_bytes()
+					.to_vec()
+					.try_into()
+					.unwrap(),
+				long_description: Some(
+					"DeBio service long_description".as_bytes().to_vec().try_into().unwrap()
+				),
+				image: Some("DeBio service image".as_bytes().to_vec().try_into().unwrap()),
 			},
 			ServiceFlow::default()
 		));
@@ -1856,31 +2486,33 @@ fn cant_process_request_when_request_is_on_processed_or_finalized() {
 		// Customer create request
 		assert_ok!(ServiceRequest::create_request(
 			RuntimeOrigin::signed(customer),
-			String::from("Indonesia").into_bytes(),
-			String::from("West Java").into_bytes(),
-			String::from("Bogor").into_bytes(),
+			String::from("ID").into_bytes(),
+			String::from("JB").into_bytes(),
+			String::from("BOGOR").into_bytes(),
 			String::from("Vaksin").into_bytes(),
 			10
 		));
 
-		let request_id = ServiceRequest::request_by_account_id(customer)[0];
+		let request_id = ServiceRequest::request_by_account_id(&customer)[0];
 
 		// Register lab
 		assert_ok!(Labs::register_lab(
 			RuntimeOrigin::signed(lab),
 			LabInfo {
 				box_public_key: Keccak256::hash("box_public_key".as_bytes()),
-				name: "DeBio Lab".as_bytes().to_vec(),
-				email: "DeBio Email".as_bytes().to_vec(),
-				country: CountryCode::from_vec("DC".as_bytes().to_vec()),
-				region: RegionCode::from_vec("DB".as_bytes().to_vec()),
-				city: CityCode::from_vec("CITY".as_bytes().to_vec()),
-				address: "DeBio Address".as_bytes().to_vec(),
-				phone_number: "+6281394653625".as_bytes().to_vec(),
-				website: "DeBio Website".as_bytes().to_vec(),
-				latitude: Some("DeBio Latitude".as_bytes().to_vec()),
-				longitude: Some("DeBio Longtitude".as_bytes().to_vec()),
-				profile_image: Some("DeBio Profile Image uwu".as_bytes().to_vec()),
+				name: "DeBio Lab".as_bytes().to_vec().try_into().unwrap(),
+				email: "DeBio Email".as_bytes().to_vec().try_into().unwrap(),
+				country: CountryCode::try_from("ID".as_bytes().to_vec()).unwrap(),
+				region: RegionCode::try_from("JB".as_bytes().to_vec()).unwrap(),
+				city: CityCode::try_from("CITY".as_bytes().to_vec()).unwrap(),
+				address: "DeBio Address".as_bytes().to_vec().try_into().unwrap(),
+				phone_number: "+6281394653625".as_bytes().to_vec().try_into().unwrap(),
+				website: "DeBio Website".as_bytes().to_vec().try_into().unwrap(),
+				latitude: Some("-6.175392".as_bytes().to_vec().try_into().unwrap()),
+				longitude: Some("106.827153".as_bytes().to_vec().try_into().unwrap()),
+				profile_image: Some(
+					"DeBio Profile Image uwu".as_bytes().to_vec().try_into().unwrap()
+				),
 			}
 		));
 
@@ -1895,22 +2527,42 @@ fn cant_process_request_when_request_is_on_processed_or_finalized() {
 		let prices_by_currency_dbio = PriceByCurrency {
 			currency: CurrencyType::DBIO,
 			total_price: 10,
-			price_components: vec![Price { component: b"testing_price".to_vec(), value: 5 }],
-			additional_prices: vec![Price { component: b"qc_price".to_vec(), value: 5 }],
+			price_components: vec![Price {
+				component: b"testing_price".to_vec().try_into().unwrap(),
+				value: 5,
+			}]
+			.try_into()
+			.unwrap(),
+			additional_prices: vec![Price {
+				component: b"qc_price".to_vec().try_into().unwrap(),
+				value: 5,
+			}]
+			.try_into()
+			.unwrap(),
 		};
 
 		assert_ok!(Services::create_service(
 			RuntimeOrigin::signed(lab),
 			ServiceInfo {
-				name: "DeBio service name".as_bytes().to_vec(),
-				prices_by_currency: vec![prices_by_currency_dbio],
+				name: "DeBio service name".as_bytes().to_vec().try_into().unwrap(),
+				prices_by_currency: vec![prices_by_currency_dbio].try_into().unwrap(),
 				expected_duration: ExpectedDuration::default(),
-				category: "DeBio service category".as_bytes().to_vec(),
-				description: "DeBio service description".as_bytes().to_vec(),
-				dna_collection_process: "DeBio service dna_collection_process".as_bytes().to_vec(),
-				test_result_sample: "DeBio service test_result_sample".as_bytes().to_vec(),
-				long_description: Some("DeBio service long_description".as_bytes().to_vec()),
-				image: Some("DeBio service image".as_bytes().to_vec()),
+				category: "DeBio service category".as_bytes().to_vec().try_into().unwrap(),
+				description: "DeBio service description".as_bytes().to_vec().try_into().unwrap(),
+				dna_collection_process: "DeBio service dna_collection_process"
+					.as_bytes()
+					.to_vec()
+					.try_into()
+					.unwrap(),
+				test_result_sample: "DeBio service test_result_sample"
+					.as_bytes()
+					.to_vec()
+					.try_into()
+					.unwrap(),
+				long_description: Some(
+					"DeBio service long_description".as_bytes().to_vec().try_into().unwrap()
+				),
+				image: Some("DeBio service image".as_bytes().to_vec().try_into().unwrap()),
 			},
 			ServiceFlow::default()
 		));
@@ -1946,7 +2598,7 @@ fn cant_process_request_when_request_is_on_processed_or_finalized() {
 			Error::<Test>::RequestUnableToProccess
 		);
 
-		let dna_sample = GeneticTesting::dna_samples_by_lab_id(lab).unwrap();
+		let dna_sample = GeneticTesting::dna_samples_by_lab_id(&lab);
 
 		assert_ok!(Orders::set_order_paid(RuntimeOrigin::signed(customer), order_id));
 
@@ -1956,9 +2608,9 @@ fn cant_process_request_when_request_is_on_processed_or_finalized() {
 			RuntimeOrigin::signed(lab),
 			dna_sample[0].clone(),
 			DnaTestResultSubmission {
-				comments: Some("comment".as_bytes().to_vec()),
-				result_link: Some("result_link".as_bytes().to_vec()),
-				report_link: Some("report_link".as_bytes().to_vec()),
+				comments: Some("comment".as_bytes().to_vec().try_into().unwrap()),
+				result_link: Some("result_link".as_bytes().to_vec().try_into().unwrap()),
+				report_link: Some("report_link".as_bytes().to_vec().try_into().unwrap()),
 			}
 		));
 
@@ -1991,14 +2643,14 @@ fn cant_retrieve_unstake_when_not_unauthorized() {
 		// Customer create request
 		assert_ok!(ServiceRequest::create_request(
 			RuntimeOrigin::signed(customer),
-			String::from("Indonesia").into_bytes(),
-			String::from("West Java").into_bytes(),
-			String::from("Bogor").into_bytes(),
+			String::from("ID").into_bytes(),
+			String::from("JB").into_bytes(),
+			String::from("BOGOR").into_bytes(),
 			String::from("Vaksin").into_bytes(),
 			10
 		));
 
-		let request_id = ServiceRequest::request_by_account_id(customer)[0];
+		let request_id = ServiceRequest::request_by_account_id(&customer)[0];
 
 		// Register lab
 		assert_ok!(Labs::register_lab(
@@ -2007,17 +2659,19 @@ fn cant_retrieve_unstake_when_not_unauthorized() {
 				box_public_key: Keccak256::hash(
 					"0xDb9Af2d1f3ADD2726A132AA7A65Cc9E6fC5761C3".as_bytes()
 				),
-				name: "DeBio Lab".as_bytes().to_vec(),
-				email: "DeBio Email".as_bytes().to_vec(),
-				country: CountryCode::from_vec("DC".as_bytes().to_vec()),
-				region: RegionCode::from_vec("DB".as_bytes().to_vec()),
-				city: CityCode::from_vec("CITY".as_bytes().to_vec()),
-				address: "DeBio Address".as_bytes().to_vec(),
-				phone_number: "+6281394653625".as_bytes().to_vec(),
-				website: "DeBio Website".as_bytes().to_vec(),
-				latitude: Some("DeBio Latitude".as_bytes().to_vec()),
-				longitude: Some("DeBio Longtitude".as_bytes().to_vec()),
-				profile_image: Some("DeBio Profile Image uwu".as_bytes().to_vec()),
+				name: "DeBio Lab".as_bytes().to_vec().try_into().unwrap(),
+				email: "DeBio Email".as_bytes().to_vec().try_into().unwrap(),
+				country: CountryCode::try_from("ID".as_bytes().to_vec()).unwrap(),
+				region: RegionCode::try_from("JB".as_bytes().to_vec()).unwrap(),
+				city: CityCode::try_from("CITY".as_bytes().to_vec()).unwrap(),
+				address: "DeBio Address".as_bytes().to_vec().try_into().unwrap(),
+				phone_number: "+6281394653625".as_bytes().to_vec().try_into().unwrap(),
+				website: "DeBio Website".as_bytes().to_vec().try_into().unwrap(),
+				latitude: Some("-6.175392".as_bytes().to_vec().try_into().unwrap()),
+				longitude: Some("106.827153".as_bytes().to_vec().try_into().unwrap()),
+				profile_image: Some(
+					"DeBio Profile Image uwu".as_bytes().to_vec().try_into().unwrap()
+				),
 			}
 		));
 
@@ -2037,14 +2691,14 @@ fn cant_retrieve_unstake_when_not_unstaked() {
 		// Customer create request
 		assert_ok!(ServiceRequest::create_request(
 			RuntimeOrigin::signed(customer),
-			String::from("Indonesia").into_bytes(),
-			String::from("West Java").into_bytes(),
-			String::from("Bogor").into_bytes(),
+			String::from("ID").into_bytes(),
+			String::from("JB").into_bytes(),
+			String::from("BOGOR").into_bytes(),
 			String::from("Vaksin").into_bytes(),
 			10
 		));
 
-		let request_id = ServiceRequest::request_by_account_id(customer)[0];
+		let request_id = ServiceRequest::request_by_account_id(&customer)[0];
 
 		// Register lab
 		assert_ok!(Labs::register_lab(
@@ -2053,17 +2707,19 @@ fn cant_retrieve_unstake_when_not_unstaked() {
 				box_public_key: Keccak256::hash(
 					"0xDb9Af2d1f3ADD2726A132AA7A65Cc9E6fC5761C3".as_bytes()
 				),
-				name: "DeBio Lab".as_bytes().to_vec(),
-				email: "DeBio Email".as_bytes().to_vec(),
-				country: CountryCode::from_vec("DC".as_bytes().to_vec()),
-				region: RegionCode::from_vec("DB".as_bytes().to_vec()),
-				city: CityCode::from_vec("CITY".as_bytes().to_vec()),
-				address: "DeBio Address".as_bytes().to_vec(),
-				phone_number: "+6281394653625".as_bytes().to_vec(),
-				website: "DeBio Website".as_bytes().to_vec(),
-				latitude: Some("DeBio Latitude".as_bytes().to_vec()),
-				longitude: Some("DeBio Longtitude".as_bytes().to_vec()),
-				profile_image: Some("DeBio Profile Image uwu".as_bytes().to_vec()),
+				name: "DeBio Lab".as_bytes().to_vec().try_into().unwrap(),
+				email: "DeBio Email".as_bytes().to_vec().try_into().unwrap(),
+				country: CountryCode::try_from("ID".as_bytes().to_vec()).unwrap(),
+				region: RegionCode::try_from("JB".as_bytes().to_vec()).unwrap(),
+				city: CityCode::try_from("CITY".as_bytes().to_vec()).unwrap(),
+				address: "DeBio Address".as_bytes().to_vec().try_into().unwrap(),
+				phone_number: "+6281394653625".as_bytes().to_vec().try_into().unwrap(),
+				website: "DeBio Website".as_bytes().to_vec().try_into().unwrap(),
+				latitude: Some("-6.175392".as_bytes().to_vec().try_into().unwrap()),
+				longitude: Some("106.827153".as_bytes().to_vec().try_into().unwrap()),
+				profile_image: Some(
+					"DeBio Profile Image uwu".as_bytes().to_vec().try_into().unwrap()
+				),
 			}
 		));
 
@@ -2081,14 +2737,14 @@ fn cant_retrieve_unstake_when_unstaked_periode_not_fulfilled() {
 
 		assert_ok!(ServiceRequest::create_request(
 			RuntimeOrigin::signed(customer),
-			String::from("Indonesia").into_bytes(),
-			String::from("West Java").into_bytes(),
-			String::from("Bogor").into_bytes(),
+			String::from("ID").into_bytes(),
+			String::from("JB").into_bytes(),
+			String::from("BOGOR").into_bytes(),
 			String::from("Vaksin").into_bytes(),
 			10
 		));
 
-		let request_id = ServiceRequest::request_by_account_id(customer)[0];
+		let request_id = ServiceRequest::request_by_account_id(&customer)[0];
 
 		assert_ok!(ServiceRequest::unstake(RuntimeOrigin::signed(customer), request_id));
 
@@ -2114,31 +2770,33 @@ fn cant_finalize_requst_when_request_is_not_on_processed() {
 		// Customer create request
 		assert_ok!(ServiceRequest::create_request(
 			RuntimeOrigin::signed(customer),
-			String::from("Indonesia").into_bytes(),
-			String::from("West Java").into_bytes(),
-			String::from("Bogor").into_bytes(),
+			String::from("ID").into_bytes(),
+			String::from("JB").into_bytes(),
+			String::from("BOGOR").into_bytes(),
 			String::from("Vaksin").into_bytes(),
 			10
 		));
 
-		let request_id = ServiceRequest::request_by_account_id(customer)[0];
+		let request_id = ServiceRequest::request_by_account_id(&customer)[0];
 
 		// Register lab
 		assert_ok!(Labs::register_lab(
 			RuntimeOrigin::signed(lab),
 			LabInfo {
 				box_public_key: Keccak256::hash("box_public_key".as_bytes()),
-				name: "DeBio Lab".as_bytes().to_vec(),
-				email: "DeBio Email".as_bytes().to_vec(),
-				country: CountryCode::from_vec("DC".as_bytes().to_vec()),
-				region: RegionCode::from_vec("DB".as_bytes().to_vec()),
-				city: CityCode::from_vec("CITY".as_bytes().to_vec()),
-				address: "DeBio Address".as_bytes().to_vec(),
-				phone_number: "+6281394653625".as_bytes().to_vec(),
-				website: "DeBio Website".as_bytes().to_vec(),
-				latitude: Some("DeBio Latitude".as_bytes().to_vec()),
-				longitude: Some("DeBio Longtitude".as_bytes().to_vec()),
-				profile_image: Some("DeBio Profile Image uwu".as_bytes().to_vec()),
+				name: "DeBio Lab".as_bytes().to_vec().try_into().unwrap(),
+				email: "DeBio Email".as_bytes().to_vec().try_into().unwrap(),
+				country: CountryCode::try_from("ID".as_bytes().to_vec()).unwrap(),
+				region: RegionCode::try_from("JB".as_bytes().to_vec()).unwrap(),
+				city: CityCode::try_from("CITY".as_bytes().to_vec()).unwrap(),
+				address: "DeBio Address".as_bytes().to_vec().try_into().unwrap(),
+				phone_number: "+6281394653625".as_bytes().to_vec().try_into().unwrap(),
+				website: "DeBio Website".as_bytes().to_vec().try_into().unwrap(),
+				latitude: Some("-6.175392".as_bytes().to_vec().try_into().unwrap()),
+				longitude: Some("106.827153".as_bytes().to_vec().try_into().unwrap()),
+				profile_image: Some(
+					"DeBio Profile Image uwu".as_bytes().to_vec().try_into().unwrap()
+				),
 			}
 		));
 
@@ -2153,22 +2811,42 @@ fn cant_finalize_requst_when_request_is_not_on_processed() {
 		let prices_by_currency_dbio = PriceByCurrency {
 			currency: CurrencyType::DBIO,
 			total_price: 10,
-			price_components: vec![Price { component: b"testing_price".to_vec(), value: 5 }],
-			additional_prices: vec![Price { component: b"qc_price".to_vec(), value: 5 }],
+			price_components: vec![Price {
+				component: b"testing_price".to_vec().try_into().unwrap(),
+				value: 5,
+			}]
+			.try_into()
+			.unwrap(),
+			additional_prices: vec![Price {
+				component: b"qc_price".to_vec().try_into().unwrap(),
+				value: 5,
+			}]
+			.try_into()
+			.unwrap(),
 		};
 
 		assert_ok!(Services::create_service(
 			RuntimeOrigin::signed(lab),
 			ServiceInfo {
-				name: "DeBio service name".as_bytes().to_vec(),
-				prices_by_currency: vec![prices_by_currency_dbio],
+				name: "DeBio service name".as_bytes().to_vec().try_into().unwrap(),
+				prices_by_currency: vec![prices_by_currency_dbio].try_into().unwrap(),
 				expected_duration: ExpectedDuration::default(),
-				category: "DeBio service category".as_bytes().to_vec(),
-				description: "DeBio service description".as_bytes().to_vec(),
-				dna_collection_process: "DeBio service dna_collection_process".as_bytes().to_vec(),
-				test_result_sample: "DeBio service test_result_sample".as_bytes().to_vec(),
-				long_description: Some("DeBio service long_description".as_bytes().to_vec()),
-				image: Some("DeBio service image".as_bytes().to_vec()),
+				category: "DeBio service category".as_bytes().to_vec().try_into().unwrap(),
+				description: "DeBio service description".as_bytes().to_vec().try_into().unwrap(),
+				dna_collection_process: "DeBio service dna_collection_process"
+					.as_bytes()
+					.to_vec()
+					.try_into()
+					.unwrap(),
+				test_result_sample: "DeBio service test_result_sample"
+					.as_bytes()
+					.to_vec()
+					.try_into()
+					.unwrap(),
+				long_description: Some(
+					"DeBio service long_description".as_bytes().to_vec().try_into().unwrap()
+				),
+				image: Some("DeBio service image".as_bytes().to_vec().try_into().unwrap()),
 			},
 			ServiceFlow::default()
 		));
@@ -2199,7 +2877,7 @@ fn cant_finalize_requst_when_request_is_not_on_processed() {
 			order_id,
 		));
 
-		let dna_sample = GeneticTesting::dna_samples_by_lab_id(lab).unwrap();
+		let dna_sample = GeneticTesting::dna_samples_by_lab_id(&lab);
 
 		assert_ok!(Orders::set_order_paid(RuntimeOrigin::signed(customer), order_id));
 
@@ -2209,9 +2887,9 @@ fn cant_finalize_requst_when_request_is_not_on_processed() {
 			RuntimeOrigin::signed(lab),
 			dna_sample[0].clone(),
 			DnaTestResultSubmission {
-				comments: Some("comment".as_bytes().to_vec()),
-				result_link: Some("result_link".as_bytes().to_vec()),
-				report_link: Some("report_link".as_bytes().to_vec()),
+				comments: Some("comment".as_bytes().to_vec().try_into().unwrap()),
+				result_link: Some("result_link".as_bytes().to_vec().try_into().unwrap()),
+				report_link: Some("report_link".as_bytes().to_vec().try_into().unwrap()),
 			}
 		));
 
@@ -2247,31 +2925,33 @@ fn cant_finalize_request_when_order_not_fullfilled() {
 		// Customer create request
 		assert_ok!(ServiceRequest::create_request(
 			RuntimeOrigin::signed(customer),
-			String::from("Indonesia").into_bytes(),
-			String::from("West Java").into_bytes(),
-			String::from("Bogor").into_bytes(),
+			String::from("ID").into_bytes(),
+			String::from("JB").into_bytes(),
+			String::from("BOGOR").into_bytes(),
 			String::from("Vaksin").into_bytes(),
 			10
 		));
 
-		let request_id = ServiceRequest::request_by_account_id(customer)[0];
+		let request_id = ServiceRequest::request_by_account_id(&customer)[0];
 
 		// Register lab
 		assert_ok!(Labs::register_lab(
 			RuntimeOrigin::signed(lab),
 			LabInfo {
 				box_public_key: Keccak256::hash("box_public_key".as_bytes()),
-				name: "DeBio Lab".as_bytes().to_vec(),
-				email: "DeBio Email".as_bytes().to_vec(),
-				country: CountryCode::from_vec("DC".as_bytes().to_vec()),
-				region: RegionCode::from_vec("DB".as_bytes().to_vec()),
-				city: CityCode::from_vec("CITY".as_bytes().to_vec()),
-				address: "DeBio Address".as_bytes().to_vec(),
-				phone_number: "+6281394653625".as_bytes().to_vec(),
-				website: "DeBio Website".as_bytes().to_vec(),
-				latitude: Some("DeBio Latitude".as_bytes().to_vec()),
-				longitude: Some("DeBio Longtitude".as_bytes().to_vec()),
-				profile_image: Some("DeBio Profile Image uwu".as_bytes().to_vec()),
+				name: "DeBio Lab".as_bytes().to_vec().try_into().unwrap(),
+				email: "DeBio Email".as_bytes().to_vec().try_into().unwrap(),
+				country: CountryCode::try_from("ID".as_bytes().to_vec()).unwrap(),
+				region: RegionCode::try_from("JB".as_bytes().to_vec()).unwrap(),
+				city: CityCode::try_from("CITY".as_bytes().to_vec()).unwrap(),
+				address: "DeBio Address".as_bytes().to_vec().try_into().unwrap(),
+				phone_number: "+6281394653625".as_bytes().to_vec().try_into().unwrap(),
+				website: "DeBio Website".as_bytes().to_vec().try_into().unwrap(),
+				latitude: Some("-6.175392".as_bytes().to_vec().try_into().unwrap()),
+				longitude: Some("106.827153".as_bytes().to_vec().try_into().unwrap()),
+				profile_image: Some(
+					"DeBio Profile Image uwu".as_bytes().to_vec().try_into().unwrap()
+				),
 			}
 		));
 
@@ -2286,22 +2966,42 @@ fn cant_finalize_request_when_order_not_fullfilled() {
 		let prices_by_currency_dbio = PriceByCurrency {
 			currency: CurrencyType::DBIO,
 			total_price: 10,
-			price_components: vec![Price { component: b"testing_price".to_vec(), value: 5 }],
-			additional_prices: vec![Price { component: b"qc_price".to_vec(), value: 5 }],
+			price_components: vec![Price {
+				component: b"testing_price".to_vec().try_into().unwrap(),
+				value: 5,
+			}]
+			.try_into()
+			.unwrap(),
+			additional_prices: vec![Price {
+				component: b"qc_price".to_vec().try_into().unwrap(),
+				value: 5,
+			}]
+			.try_into()
+			.unwrap(),
 		};
 
 		assert_ok!(Services::create_service(
 			RuntimeOrigin::signed(lab),
 			ServiceInfo {
-				name: "DeBio service name".as_bytes().to_vec(),
-				prices_by_currency: vec![prices_by_currency_dbio],
+				name: "DeBio service name".as_bytes().to_vec().try_into().unwrap(),
+				prices_by_currency: vec![prices_by_currency_dbio].try_into().unwrap(),
 				expected_duration: ExpectedDuration::default(),
-				category: "DeBio service category".as_bytes().to_vec(),
-				description: "DeBio service description".as_bytes().to_vec(),
-				dna_collection_process: "DeBio service dna_collection_process".as_bytes().to_vec(),
-				test_result_sample: "DeBio service test_result_sample".as_bytes().to_vec(),
-				long_description: Some("DeBio service long_description".as_bytes().to_vec()),
-				image: Some("DeBio service image".as_bytes().to_vec()),
+				category: "DeBio service category".as_bytes().to_vec().try_into().unwrap(),
+				description: "DeBio service description".as_bytes().to_vec().try_into().unwrap(),
+				dna_collection_process: "DeBio service dna_collection_process"
+					.as_bytes()
+					.to_vec()
+					.try_into()
+					.unwrap(),
+				test_result_sample: "DeBio service test_result_sample"
+					.as_bytes()
+					.to_vec()
+					.try_into()
+					.unwrap(),
+				long_description: Some(
+					"DeBio service long_description".as_bytes().to_vec().try_into().unwrap()
+				),
+				image: Some("DeBio service image".as_bytes().to_vec().try_into().unwrap()),
 			},
 			ServiceFlow::default()
 		));
@@ -2332,7 +3032,7 @@ fn cant_finalize_request_when_order_not_fullfilled() {
 			order_id,
 		));
 
-		let dna_sample = GeneticTesting::dna_samples_by_lab_id(lab).unwrap();
+		let dna_sample = GeneticTesting::dna_samples_by_lab_id(&lab);
 
 		assert_ok!(Orders::set_order_paid(RuntimeOrigin::signed(customer), order_id));
 
@@ -2342,9 +3042,9 @@ fn cant_finalize_request_when_order_not_fullfilled() {
 			RuntimeOrigin::signed(lab),
 			dna_sample[0].clone(),
 			DnaTestResultSubmission {
-				comments: Some("comment".as_bytes().to_vec()),
-				result_link: Some("result_link".as_bytes().to_vec()),
-				report_link: Some("report_link".as_bytes().to_vec()),
+				comments: Some("comment".as_bytes().to_vec().try_into().unwrap()),
+				result_link: Some("result_link".as_bytes().to_vec().try_into().unwrap()),
+				report_link: Some("report_link".as_bytes().to_vec().try_into().unwrap()),
 			}
 		));
 
@@ -2380,14 +3080,14 @@ fn call_event_should_work() {
 		// Customer create request
 		assert_ok!(ServiceRequest::create_request(
 			RuntimeOrigin::signed(customer),
-			String::from("Indonesia").into_bytes(),
-			String::from("West Java").into_bytes(),
-			String::from("Bogor").into_bytes(),
+			String::from("ID").into_bytes(),
+			String::from("JB").into_bytes(),
+			String::from("BOGOR").into_bytes(),
 			String::from("Vaksin").into_bytes(),
 			10
 		));
 
-		let request_id = ServiceRequest::request_by_account_id(customer)[0];
+		let request_id = ServiceRequest::request_by_account_id(&customer)[0];
 
 		System::assert_last_event(RuntimeEvent::ServiceRequest(
 			crate::Event::ServiceRequestCreated(
@@ -2398,10 +3098,10 @@ fn call_event_should_work() {
 					lab_address: None,
 					service_id: None,
 					order_id: None,
-					country: String::from("Indonesia").into_bytes(),
-					region: String::from("West Java").into_bytes(),
-					city: String::from("Bogor").into_bytes(),
-					service_category: String::from("Vaksin").into_bytes(),
+					country: String::from("ID").into_bytes().try_into().unwrap(),
+					region: String::from("JB").into_bytes().try_into().unwrap(),
+					city: String::from("BOGOR").into_bytes().try_into().unwrap(),
+					service_category: String::from("Vaksin").into_bytes().try_into().unwrap(),
 					staking_amount: 10,
 					status: RequestStatus::Open,
 					created_at: 0,
@@ -2416,17 +3116,19 @@ fn call_event_should_work() {
 			RuntimeOrigin::signed(lab),
 			LabInfo {
 				box_public_key: Keccak256::hash("box_public_key".as_bytes()),
-				name: "DeBio Lab".as_bytes().to_vec(),
-				email: "DeBio Email".as_bytes().to_vec(),
-				country: CountryCode::from_vec("DC".as_bytes().to_vec()),
-				region: RegionCode::from_vec("DB".as_bytes().to_vec()),
-				city: CityCode::from_vec("CITY".as_bytes().to_vec()),
-				address: "DeBio Address".as_bytes().to_vec(),
-				phone_number: "+6281394653625".as_bytes().to_vec(),
-				website: "DeBio Website".as_bytes().to_vec(),
-				latitude: Some("DeBio Latitude".as_bytes().to_vec()),
-				longitude: Some("DeBio Longtitude".as_bytes().to_vec()),
-				profile_image: Some("DeBio Profile Image uwu".as_bytes().to_vec()),
+				name: "DeBio Lab".as_bytes().to_vec().try_into().unwrap(),
+				email: "DeBio Email".as_bytes().to_vec().try_into().unwrap(),
+				country: CountryCode::try_from("ID".as_bytes().to_vec()).unwrap(),
+				region: RegionCode::try_from("JB".as_bytes().to_vec()).unwrap(),
+				city: CityCode::try_from("CITY".as_bytes().to_vec()).unwrap(),
+				address: "DeBio Address".as_bytes().to_vec().try_into().unwrap(),
+				phone_number: "+6281394653625".as_bytes().to_vec().try_into().unwrap(),
+				website: "DeBio Website".as_bytes().to_vec().try_into().unwrap(),
+				latitude: Some("-6.175392".as_bytes().to_vec().try_into().unwrap()),
+				longitude: Some("106.827153".as_bytes().to_vec().try_into().unwrap()),
+				profile_image: Some(
+					"DeBio Profile Image uwu".as_bytes().to_vec().try_into().unwrap()
+				),
 			}
 		));
 
@@ -2441,22 +3143,42 @@ fn call_event_should_work() {
 		let prices_by_currency_dbio = PriceByCurrency {
 			currency: CurrencyType::DBIO,
 			total_price: 10,
-			price_components: vec![Price { component: b"testing_price".to_vec(), value: 5 }],
-			additional_prices: vec![Price { component: b"qc_price".to_vec(), value: 5 }],
+			price_components: vec![Price {
+				component: b"testing_price".to_vec().try_into().unwrap(),
+				value: 5,
+			}]
+			.try_into()
+			.unwrap(),
+			additional_prices: vec![Price {
+				component: b"qc_price".to_vec().try_into().unwrap(),
+				value: 5,
+			}]
+			.try_into()
+			.unwrap(),
 		};
 
 		assert_ok!(Services::create_service(
 			RuntimeOrigin::signed(lab),
 			ServiceInfo {
-				name: "DeBio service name".as_bytes().to_vec(),
-				prices_by_currency: vec![prices_by_currency_dbio],
+				name: "DeBio service name".as_bytes().to_vec().try_into().unwrap(),
+				prices_by_currency: vec![prices_by_currency_dbio].try_into().unwrap(),
 				expected_duration: ExpectedDuration::default(),
-				category: "DeBio service category".as_bytes().to_vec(),
-				description: "DeBio service description".as_bytes().to_vec(),
-				dna_collection_process: "DeBio service dna_collection_process".as_bytes().to_vec(),
-				test_result_sample: "DeBio service test_result_sample".as_bytes().to_vec(),
-				long_description: Some("DeBio service long_description".as_bytes().to_vec()),
-				image: Some("DeBio service image".as_bytes().to_vec()),
+				category: "DeBio service category".as_bytes().to_vec().try_into().unwrap(),
+				description: "DeBio service description".as_bytes().to_vec().try_into().unwrap(),
+				dna_collection_process: "DeBio service dna_collection_process"
+					.as_bytes()
+					.to_vec()
+					.try_into()
+					.unwrap(),
+				test_result_sample: "DeBio service test_result_sample"
+					.as_bytes()
+					.to_vec()
+					.try_into()
+					.unwrap(),
+				long_description: Some(
+					"DeBio service long_description".as_bytes().to_vec().try_into().unwrap()
+				),
+				image: Some("DeBio service image".as_bytes().to_vec().try_into().unwrap()),
 			},
 			ServiceFlow::default()
 		));
@@ -2499,7 +3221,7 @@ fn call_event_should_work() {
 			crate::Event::ServiceRequestUpdated(request_id, RequestStatus::Processed, request),
 		));
 
-		let dna_sample = GeneticTesting::dna_samples_by_lab_id(lab).unwrap();
+		let dna_sample = GeneticTesting::dna_samples_by_lab_id(&lab);
 
 		assert_ok!(Orders::set_order_paid(RuntimeOrigin::signed(customer), order_id));
 
@@ -2509,9 +3231,9 @@ fn call_event_should_work() {
 			RuntimeOrigin::signed(lab),
 			dna_sample[0].clone(),
 			DnaTestResultSubmission {
-				comments: Some("comment".as_bytes().to_vec()),
-				result_link: Some("result_link".as_bytes().to_vec()),
-				report_link: Some("report_link".as_bytes().to_vec()),
+				comments: Some("comment".as_bytes().to_vec().try_into().unwrap()),
+				result_link: Some("result_link".as_bytes().to_vec().try_into().unwrap()),
+				report_link: Some("report_link".as_bytes().to_vec().try_into().unwrap()),
 			}
 		));
 
@@ -2,12 +2,20 @@ use crate::*;
 
 use frame_support::{sp_runtime::traits::Saturating, traits::Currency};
 use scale_info::TypeInfo;
-use sp_std::vec::Vec;
+#[cfg(feature = "std")]
+use serde::{Deserialize, Serialize};
 
-pub type Country = Vec<u8>;
-pub type Region = Vec<u8>;
-pub type City = Vec<u8>;
-pub type ServiceCategory = Vec<u8>;
+/// Longest country, region or city of a request
+pub const MAX_LOCATION_LENGTH: u32 = 64;
+/// Longest service category of a request
+pub const MAX_SERVICE_CATEGORY_LENGTH: u32 = 512;
+/// Most requests returned by a single page
+pub const MAX_PAGE_LIMIT: u32 = 100;
+
+pub type Country = BoundedVec<u8, ConstU32<MAX_LOCATION_LENGTH>>;
+pub type Region = BoundedVec<u8, ConstU32<MAX_LOCATION_LENGTH>>;
+pub type City = BoundedVec<u8, ConstU32<MAX_LOCATION_LENGTH>>;
+pub type ServiceCategory = BoundedVec<u8, ConstU32<MAX_SERVICE_CATEGORY_LENGTH>>;
 
 pub type AccountIdOf<T> = <T as frame_system::Config>::AccountId;
 pub type CurrencyOf<T> = <T as self::Config>::Currency;
@@ -15,7 +23,8 @@ pub type BalanceOf<T> = <CurrencyOf<T> as Currency<AccountIdOf<T>>>::Balance;
 pub type HashOf<T> = <T as frame_system::Config>::Hash;
 pub type RequestOf<T> = Request<AccountIdOf<T>, BalanceOf<T>, HashOf<T>>;
 
-#[derive(Encode, Decode, Clone, RuntimeDebug, PartialEq, Eq, TypeInfo)]
+#[derive(Encode, Decode, Clone, RuntimeDebug, PartialEq, Eq, TypeInfo, MaxEncodedLen)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
 pub enum RequestStatus {
 	Open,
 	WaitingForUnstaked,
@@ -31,17 +40,18 @@ impl Default for RequestStatus {
 	}
 }
 
-#[derive(Clone, Decode, Default, Encode, Eq, PartialEq, RuntimeDebug, TypeInfo)]
+#[derive(Clone, Decode, Default, Encode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
 pub struct Request<AccountId, Balance, Hash> {
 	pub hash: Hash,
 	pub requester_address: AccountId,
 	pub lab_address: Option<AccountId>,
 	pub service_id: Option<Hash>,
 	pub order_id: Option<Hash>,
-	pub country: Vec<u8>,
-	pub region: Vec<u8>,
-	pub city: Vec<u8>,
-	pub service_category: Vec<u8>,
+	pub country: Country,
+	pub region: Region,
+	pub city: City,
+	pub service_category: ServiceCategory,
 	pub staking_amount: Balance,
 	pub status: RequestStatus,
 	pub created_at: u128,
@@ -57,10 +67,10 @@ where
 	pub fn new(
 		hash: Hash,
 		requester_address: &AccountId,
-		country: &[u8],
-		region: &[u8],
-		city: &[u8],
-		service_category: &[u8],
+		country: Country,
+		region: Region,
+		city: City,
+		service_category: ServiceCategory,
 		staking_amount: Balance,
 		created_at: u128,
 	) -> Self {
@@ -70,10 +80,10 @@ where
 			lab_address: None,
 			service_id: None,
 			order_id: None,
-			country: country.to_vec(),
-			region: region.to_vec(),
-			city: city.to_vec(),
-			service_category: service_category.to_vec(),
+			country,
+			region,
+			city,
+			service_category,
 			staking_amount,
 			status: RequestStatus::default(),
 			created_at,
@@ -35,17 +35,17 @@ benchmarks! {
 		let caller_origin = T::RuntimeOrigin::from(RawOrigin::Signed(caller.clone()));
 		let lab = LabInfo {
 			box_public_key: T::Hashing::hash("0xDb9Af2d1f3ADD2726A132AA7A65Cc9E6fC5761C3".as_bytes()),
-			name: "DeBio Lab".as_bytes().to_vec(),
-			email: "DeBio Email".as_bytes().to_vec(),
-			country: CountryCode::from_vec("DC".as_bytes().to_vec()),
-			region: RegionCode::from_vec("DBIO".as_bytes().to_vec()),
-			city: CityCode::from_vec("City".as_bytes().to_vec()),
-			address: "DeBio Address".as_bytes().to_vec(),
-			phone_number: "+6281394653625".as_bytes().to_vec(),
-			website: "DeBio Website".as_bytes().to_vec(),
-			latitude: Some("DeBio Latitude".as_bytes().to_vec()),
-			longitude: Some("DeBio Longtitude".as_bytes().to_vec()),
-			profile_image: Some("DeBio Profile Image uwu".as_bytes().to_vec()),
+			name: "DeBio Lab".as_bytes().to_vec().try_into().unwrap(),
+			email: "DeBio Email".as_bytes().to_vec().try_into().unwrap(),
+			country: CountryCode::try_from("ID".as_bytes().to_vec()).unwrap(),
+			region: RegionCode::try_from("JK".as_bytes().to_vec()).unwrap(),
+			city: CityCode::try_from("City".as_bytes().to_vec()).unwrap(),
+			address: "DeBio Address".as_bytes().to_vec().try_into().unwrap(),
+			phone_number: "+6281394653625".as_bytes().to_vec().try_into().unwrap(),
+			website: "DeBio Website".as_bytes().to_vec().try_into().unwrap(),
+			latitude: Some("-6.175392".as_bytes().to_vec().try_into().unwrap()),
+			longitude: Some("106.827153".as_bytes().to_vec().try_into().unwrap()),
+			profile_image: Some("DeBio Profile Image uwu".as_bytes().to_vec().try_into().unwrap()),
 		};
 		let _add_labs = Labs::<T>::register_lab(caller_origin.clone(), lab);
 
@@ -53,17 +53,17 @@ benchmarks! {
 		let _set_eth_address = UserProfile::<T>::set_eth_address(caller_origin, eth_address);
 
 		let service_info = ServiceInfo {
-			name: "DeBio name".as_bytes().to_vec(),
+			name: "DeBio name".as_bytes().to_vec().try_into().unwrap(),
 			prices_by_currency: vec![
 				PriceByCurrency::default()
-			],
+			].try_into().unwrap(),
 			expected_duration: ExpectedDuration::default(),
-			category: "DeBio category".as_bytes().to_vec(),
-			description: "This is my description".as_bytes().to_vec(),
-			test_result_sample: "Test result sample".as_bytes().to_vec(),
-			dna_collection_process : "Dna Collection Process".as_bytes().to_vec(),
-			long_description: Some("This is my long description".as_bytes().to_vec()),
-			image: Some("This is my image".as_bytes().to_vec()),
+			category: "DeBio category".as_bytes().to_vec().try_into().unwrap(),
+			description: "This is my description".as_bytes().to_vec().try_into().unwrap(),
+			test_result_sample: "Test result sample".as_bytes().to_vec().try_into().unwrap(),
+			dna_collection_process : "Dna Collection Process".as_bytes().to_vec().try_into().unwrap(),
+			long_description: Some("This is my long description".as_bytes().to_vec().try_into().unwrap()),
+			image: Some("This is my image".as_bytes().to_vec().try_into().unwrap()),
 		};
 	}: create_service(RawOrigin::Signed(caller), service_info, ServiceFlow::default())
 
@@ -72,17 +72,17 @@ benchmarks! {
 		let caller_origin = T::RuntimeOrigin::from(RawOrigin::Signed(caller.clone()));
 		let lab_info = LabInfo {
 			box_public_key: T::Hashing::hash("0xDb9Af2d1f3ADD2726A132AA7A65Cc9E6fC5761C3".as_bytes()),
-			name: "DeBio Lab".as_bytes().to_vec(),
-			email: "DeBio Email".as_bytes().to_vec(),
-			country: CountryCode::from_vec("DC".as_bytes().to_vec()),
-			region: RegionCode::from_vec("DBIO".as_bytes().to_vec()),
-			city: CityCode::from_vec("City".as_bytes().to_vec()),
-			address: "DeBio Address".as_bytes().to_vec(),
-			phone_number: "+6281394653625".as_bytes().to_vec(),
-			website: "DeBio Website".as_bytes().to_vec(),
-			latitude: Some("DeBio Latitude".as_bytes().to_vec()),
-			longitude: Some("DeBio Longtitude".as_bytes().to_vec()),
-			profile_image: Some("DeBio Profile Image uwu".as_bytes().to_vec()),
+			name: "DeBio Lab".as_bytes().to_vec().try_into().unwrap(),
+			email: "DeBio Email".as_bytes().to_vec().try_into().unwrap(),
+			country: CountryCode::try_from("ID".as_bytes().to_vec()).unwrap(),
+			region: RegionCode::try_from("JK".as_bytes().to_vec()).unwrap(),
+			city: CityCode::try_from("City".as_bytes().to_vec()).unwrap(),
+			address: "DeBio Address".as_bytes().to_vec().try_into().unwrap(),
+			phone_number: "+6281394653625".as_bytes().to_vec().try_into().unwrap(),
+			website: "DeBio Website".as_bytes().to_vec().try_into().unwrap(),
+			latitude: Some("-6.175392".as_bytes().to_vec().try_into().unwrap()),
+			longitude: Some("106.827153".as_bytes().to_vec().try_into().unwrap()),
+			profile_image: Some("DeBio Profile Image uwu".as_bytes().to_vec().try_into().unwrap()),
 		};
 		let _add_labs = Labs::<T>::register_lab(caller_origin.clone(), lab_info);
 
@@ -90,17 +90,17 @@ benchmarks! {
 		let _set_eth_address = UserProfile::<T>::set_eth_address(caller_origin.clone(), eth_address);
 
 		let old_service_info = ServiceInfo {
-			name: "DeBio name".as_bytes().to_vec(),
+			name: "DeBio name".as_bytes().to_vec().try_into().unwrap(),
 			prices_by_currency: vec![
 				PriceByCurrency::default()
-			],
+			].try_into().unwrap(),
 			expected_duration: ExpectedDuration::default(),
-			category: "DeBio category".as_bytes().to_vec(),
-			description: "This is my description".as_bytes().to_vec(),
-			test_result_sample: "Test result sample".as_bytes().to_vec(),
-			dna_collection_process : "Dna Collection Process".as_bytes().to_vec(),
-			long_description: Some("This is my long description".as_bytes().to_vec()),
-			image: Some("This is my image".as_bytes().to_vec()),
+			category: "DeBio category".as_bytes().to_vec().try_into().unwrap(),
+			description: "This is my description".as_bytes().to_vec().try_into().unwrap(),
+			test_result_sample: "Test result sample".as_bytes().to_vec().try_into().unwrap(),
+			dna_collection_process : "Dna Collection Process".as_bytes().to_vec().try_into().unwrap(),
+			long_description: Some("This is my long description".as_bytes().to_vec().try_into().unwrap()),
+			image: Some("This is my image".as_bytes().to_vec().try_into().unwrap()),
 		};
 		let _create_service = Services::<T>::create_service(caller_origin, old_service_info, ServiceFlow::default());
 
@@ -108,17 +108,17 @@ benchmarks! {
 			.unwrap();
 
 		let new_service_info = ServiceInfo {
-			name: "DeBio name 2".as_bytes().to_vec(),
+			name: "DeBio name 2".as_bytes().to_vec().try_into().unwrap(),
 			prices_by_currency: vec![
 				PriceByCurrency::default()
-			],
+			].try_into().unwrap(),
 			expected_duration: ExpectedDuration::default(),
-			category: "DeBio category 2".as_bytes().to_vec(),
-			description: "This is my description 2".as_bytes().to_vec(),
-			test_result_sample: "Test result sample 2".as_bytes().to_vec(),
-			dna_collection_process : "Dna Collection Process 2".as_bytes().to_vec(),
-			long_description: Some("This is my long description 2".as_bytes().to_vec()),
-			image: Some("This is my image 2".as_bytes().to_vec()),
+			category: "DeBio category 2".as_bytes().to_vec().try_into().unwrap(),
+			description: "This is my description 2".as_bytes().to_vec().try_into().unwrap(),
+			test_result_sample: "Test result sample 2".as_bytes().to_vec().try_into().unwrap(),
+			dna_collection_process : "Dna Collection Process 2".as_bytes().to_vec().try_into().unwrap(),
+			long_description: Some("This is my long description 2".as_bytes().to_vec().try_into().unwrap()),
+			image: Some("This is my image 2".as_bytes().to_vec().try_into().unwrap()),
 		};
 	}: update_service(RawOrigin::Signed(caller), _lab.services[0], new_service_info)
 
@@ -127,17 +127,17 @@ benchmarks! {
 		let caller_origin = T::RuntimeOrigin::from(RawOrigin::Signed(caller.clone()));
 		let lab_info = LabInfo {
 			box_public_key: T::Hashing::hash("0xDb9Af2d1f3ADD2726A132AA7A65Cc9E6fC5761C3".as_bytes()),
-			name: "DeBio Lab".as_bytes().to_vec(),
-			email: "DeBio Email".as_bytes().to_vec(),
-			country: CountryCode::from_vec("DC".as_bytes().to_vec()),
-			region: RegionCode::from_vec("DBIO".as_bytes().to_vec()),
-			city: CityCode::from_vec("City".as_bytes().to_vec()),
-			address: "DeBio Address".as_bytes().to_vec(),
-			phone_number: "+6281394653625".as_bytes().to_vec(),
-			website: "DeBio Website".as_bytes().to_vec(),
-			latitude: Some("DeBio Latitude".as_bytes().to_vec()),
-			longitude: Some("DeBio Longtitude".as_bytes().to_vec()),
-			profile_image: Some("DeBio Profile Image uwu".as_bytes().to_vec()),
+			name: "DeBio Lab".as_bytes().to_vec().try_into().unwrap(),
+			email: "DeBio Email".as_bytes().to_vec().try_into().unwrap(),
+			country: CountryCode::try_from("ID".as_bytes().to_vec()).unwrap(),
+			region: RegionCode::try_from("JK".as_bytes().to_vec()).unwrap(),
+			city: CityCode::try_from("City".as_bytes().to_vec()).unwrap(),
+			address: "DeBio Address".as_bytes().to_vec().try_into().unwrap(),
+			phone_number: "+6281394653625".as_bytes().to_vec().try_into().unwrap(),
+			website: "DeBio Website".as_bytes().to_vec().try_into().unwrap(),
+			latitude: Some("-6.175392".as_bytes().to_vec().try_into().unwrap()),
+			longitude: Some("106.827153".as_bytes().to_vec().try_into().unwrap()),
+			profile_image: Some("DeBio Profile Image uwu".as_bytes().to_vec().try_into().unwrap()),
 		};
 		let _add_labs = Labs::<T>::register_lab(caller_origin.clone(), lab_info);
 
@@ -145,17 +145,17 @@ benchmarks! {
 		let _set_eth_address = UserProfile::<T>::set_eth_address(caller_origin.clone(), eth_address);
 
 		let old_service_info = ServiceInfo {
-			name: "DeBio name".as_bytes().to_vec(),
+			name: "DeBio name".as_bytes().to_vec().try_into().unwrap(),
 			prices_by_currency: vec![
 				PriceByCurrency::default()
-			],
+			].try_into().unwrap(),
 			expected_duration: ExpectedDuration::default(),
-			category: "DeBio category".as_bytes().to_vec(),
-			description: "This is my description".as_bytes().to_vec(),
-			test_result_sample: "Test result sample".as_bytes().to_vec(),
-			dna_collection_process : "Dna Collection Process".as_bytes().to_vec(),
-			long_description: Some("This is my long description".as_bytes().to_vec()),
-			image: Some("This is my image".as_bytes().to_vec()),
+			category: "DeBio category".as_bytes().to_vec().try_into().unwrap(),
+			description: "This is my description".as_bytes().to_vec().try_into().unwrap(),
+			test_result_sample: "Test result sample".as_bytes().to_vec().try_into().unwrap(),
+			dna_collection_process : "Dna Collection Process".as_bytes().to_vec().try_into().unwrap(),
+			long_description: Some("This is my long description".as_bytes().to_vec().try_into().unwrap()),
+			image: Some("This is my image".as_bytes().to_vec().try_into().unwrap()),
 		};
 		let _create_service = Services::<T>::create_service(caller_origin, old_service_info, ServiceFlow::default());
 
@@ -1,11 +1,16 @@
-use crate::{AccountIdOf, BalanceOf, Config, HashOf, Pallet, Service, ServiceInfo, Services};
+use crate::{
+	AccountIdOf, BalanceOf, Config, HashOf, Pallet, PricesByCurrency, Service, ServiceInfo,
+	ServiceInfoOf, Services,
+};
 use frame_support::{
+	log,
 	pallet_prelude::{Decode, Encode},
 	traits::Get,
 	weights::Weight,
+	BoundedVec,
 };
 use primitives_duration::{DurationType, ExpectedDuration};
-use primitives_price_and_currency::PriceByCurrency;
+use primitives_price_and_currency::{CurrencyType, Price, PriceByCurrency};
 use sp_std::vec::Vec;
 use traits_services::types::ServiceFlow;
 
@@ -20,6 +25,11 @@ pub fn migrate<T: Config>() -> Weight {
 		version = StorageVersion::new(1);
 	}
 
+	if version < 2 {
+		weight = weight.saturating_add(version::v2::migrate::<T>());
+		version = StorageVersion::new(2);
+	}
+
 	version.put::<Pallet<T>>();
 	weight
 }
@@ -27,6 +37,103 @@ pub fn migrate<T: Config>() -> Weight {
 mod version {
 	use super::*;
 
+	#[derive(Encode, Decode)]
+	pub struct OldPrice<Balance> {
+		pub component: Vec<u8>,
+		pub value: Balance,
+	}
+
+	#[derive(Encode, Decode)]
+	pub struct OldPriceByCurrency<Balance> {
+		pub currency: CurrencyType,
+		pub total_price: Balance,
+		pub price_components: Vec<OldPrice<Balance>>,
+		pub additional_prices: Vec<OldPrice<Balance>>,
+	}
+
+	/// Service info fields before they were bounded
+	#[derive(Encode, Decode)]
+	pub struct UnboundedServiceInfo<Balance> {
+		pub name: Vec<u8>,
+		pub prices_by_currency: Vec<OldPriceByCurrency<Balance>>,
+		pub expected_duration: ExpectedDuration,
+		pub category: Vec<u8>,
+		pub description: Vec<u8>,
+		pub dna_collection_process: Vec<u8>,
+		pub test_result_sample: Vec<u8>,
+		pub long_description: Option<Vec<u8>>,
+		pub image: Option<Vec<u8>>,
+	}
+
+	/// Truncates `value` to the bound and logs the entry when it did not fit.
+	fn bounded<I, S: Get<u32>>(value: Vec<I>, entry: &str) -> BoundedVec<I, S> {
+		if value.len() > S::get() as usize {
+			log::warn!(
+				target: "runtime::services",
+				"{} exceeds its bound of {}, truncated from {} items",
+				entry,
+				S::get(),
+				value.len(),
+			);
+		}
+
+		BoundedVec::truncate_from(value)
+	}
+
+	fn bounded_prices<Balance>(prices: Vec<OldPrice<Balance>>) -> Vec<Price<Balance>> {
+		prices
+			.into_iter()
+			.map(|price| Price {
+				component: bounded(price.component, "price component"),
+				value: price.value,
+			})
+			.collect()
+	}
+
+	fn bounded_prices_by_currency<Balance>(
+		prices_by_currency: Vec<OldPriceByCurrency<Balance>>,
+	) -> PricesByCurrency<Balance> {
+		let prices_by_currency = prices_by_currency
+			.into_iter()
+			.map(|price_by_currency| PriceByCurrency {
+				currency: price_by_currency.currency,
+				total_price: price_by_currency.total_price,
+				price_components: bounded(
+					bounded_prices(price_by_currency.price_components),
+					"price components",
+				),
+				additional_prices: bounded(
+					bounded_prices(price_by_currency.additional_prices),
+					"additional prices",
+				),
+			})
+			.collect();
+
+		bounded(prices_by_currency, "prices by currency")
+	}
+
+	/// Legacy values that exceed a bound are truncated and logged.
+	pub fn bounded_service_info<T: Config>(
+		info: UnboundedServiceInfo<BalanceOf<T>>,
+	) -> ServiceInfoOf<T> {
+		ServiceInfo {
+			name: bounded(info.name, "service name"),
+			prices_by_currency: bounded_prices_by_currency(info.prices_by_currency),
+			expected_duration: info.expected_duration,
+			category: bounded(info.category, "service category"),
+			description: bounded(info.description, "service description"),
+			dna_collection_process: bounded(
+				info.dna_collection_process,
+				"service dna collection process",
+			),
+			test_result_sample: bounded(info.test_result_sample, "service test result sample"),
+			long_description: info
+				.long_description
+				.map(|long_description| bounded(long_description, "service long description")),
+			image: info.image.map(|image| bounded(image, "service image")),
+		}
+	}
+
 	pub mod v1 {
 		use super::*;
 
@@ -42,7 +149,7 @@ mod version {
 			#[derive(Encode, Decode)]
 			pub struct OldServiceInfo<Balance> {
 				pub name: Vec<u8>,
-				pub prices_by_currency: Vec<PriceByCurrency<Balance>>,
+				pub prices_by_currency: Vec<OldPriceByCurrency<Balance>>,
 				pub expected_duration: OldExpectedDuration,
 				pub category: Vec<u8>,
 				pub description: Vec<u8>, // TODO: limit the length
@@ -65,27 +172,27 @@ mod version {
 			Services::<T>::translate(|_key, old_services: OldServiceOf<T>| {
 				weight = weight.saturating_add(T::DbWeight::get().reads_writes(1, 1));
 
-				let old_service_info = &old_services.info;
+				let old_service_info = old_services.info;
 
-				let old_expected_duration = &old_service_info.expected_duration;
+				let old_expected_duration = old_service_info.expected_duration;
 				let old_duration = old_expected_duration.duration;
-				let old_duration_type = old_expected_duration.duration_type.clone();
+				let old_duration_type = old_expected_duration.duration_type;
 				let expected_duration = ExpectedDuration {
 					duration: old_duration as u64,
 					duration_type: old_duration_type,
 				};
 
-				let service_info = ServiceInfo {
-					name: old_service_info.name.clone(),
-					prices_by_currency: old_service_info.prices_by_currency.clone(),
+				let service_info = bounded_service_info::<T>(UnboundedServiceInfo {
+					name: old_service_info.name,
+					prices_by_currency: old_service_info.prices_by_currency,
 					expected_duration,
-					category: old_service_info.category.clone(),
-					description: old_service_info.description.clone(),
-					dna_collection_process: old_service_info.dna_collection_process.clone(),
-					test_result_sample: old_service_info.test_result_sample.clone(),
-					long_description: old_service_info.long_description.clone(),
-					image: old_service_info.image.clone(),
-				};
+					category: old_service_info.category,
+					description: old_service_info.description,
+					dna_collection_process: old_service_info.dna_collection_process,
+					test_result_sample: old_service_info.test_result_sample,
+					long_description: old_service_info.long_description,
+					image: old_service_info.image,
+				});
 
 				Some(Service {
 					id: old_services.id,
@@ -98,4 +205,36 @@ mod version {
 			weight
 		}
 	}
+
+	pub mod v2 {
+		use super::*;
+
+		/// Service info fields and price components become bounded.
+		pub fn migrate<T: Config>() -> Weight {
+			let mut weight = T::DbWeight::get().writes(1);
+
+			#[derive(Encode, Decode)]
+			pub struct OldService<AccountId, Hash, Balance> {
+				pub id: Hash,
+				pub owner_id: AccountId,
+				pub info: UnboundedServiceInfo<Balance>,
+				pub service_flow: ServiceFlow,
+			}
+
+			pub type OldServiceOf<T> = OldService<AccountIdOf<T>, HashOf<T>, BalanceOf<T>>;
+
+			Services::<T>::translate(|_key, old_service: OldServiceOf<T>| {
+				weight = weight.saturating_add(T::DbWeight::get().reads_writes(1, 1));
+
+				Some(Service {
+					id: old_service.id,
+					owner_id: old_service.owner_id,
+					info: bounded_service_info::<T>(old_service.info),
+					service_flow: old_service.service_flow,
+				})
+			});
+
+			weight
+		}
+	}
 }
@@ -4,7 +4,6 @@ use frame_support::{pallet_prelude::*, traits::Currency};
 use primitives_duration::ExpectedDuration;
 use primitives_price_and_currency::PriceByCurrency;
 use scale_info::TypeInfo;
-use sp_std::vec::Vec;
 use traits_services::{types::ServiceFlow, ServiceInfo as ServiceInfoT};
 
 pub type AccountIdOf<T> = <T as frame_system::Config>::AccountId;
@@ -15,22 +14,34 @@ pub type ServiceOf<T> = Service<AccountIdOf<T>, HashOf<T>, BalanceOf<T>>;
 pub type ServiceInfoOf<T> = ServiceInfo<BalanceOf<T>>;
 pub type ServiceIdOf<T> = HashOf<T>;
 
+/// Maximum length of the short text fields of a service, e.g. its name or image url
+pub const MAX_SERVICE_TEXT_LENGTH: u32 = 512;
+/// Maximum length of the description and long description of a service
+pub const MAX_SERVICE_DESCRIPTION_LENGTH: u32 = 4096;
+/// Maximum number of currencies a service can be priced in
+pub const MAX_PRICES_BY_CURRENCY: u32 = 16;
+
+pub type ServiceText = BoundedVec<u8, ConstU32<MAX_SERVICE_TEXT_LENGTH>>;
+pub type ServiceDescription = BoundedVec<u8, ConstU32<MAX_SERVICE_DESCRIPTION_LENGTH>>;
+pub type PricesByCurrency<Balance> =
+	BoundedVec<PriceByCurrency<Balance>, ConstU32<MAX_PRICES_BY_CURRENCY>>;
+
 /// ServiceInfo struct
 /// Information that is mutable by user
-#[derive(Encode, Decode, Clone, Default, RuntimeDebug, PartialEq, Eq, TypeInfo)]
+#[derive(Encode, Decode, Clone, Default, RuntimeDebug, PartialEq, Eq, TypeInfo, MaxEncodedLen)]
 pub struct ServiceInfo<Balance> {
-	pub name: Vec<u8>,
-	pub prices_by_currency: Vec<PriceByCurrency<Balance>>,
+	pub name: ServiceText,
+	pub prices_by_currency: PricesByCurrency<Balance>,
 	pub expected_duration: ExpectedDuration,
-	pub category: Vec<u8>,
-	pub description: Vec<u8>, // TODO: limit the length
-	pub dna_collection_process: Vec<u8>,
-	pub test_result_sample: Vec<u8>,
-	pub long_description: Option<Vec<u8>>,
-	pub image: Option<Vec<u8>>,
+	pub category: ServiceText,
+	pub description: ServiceDescription,
+	pub dna_collection_process: ServiceText,
+	pub test_result_sample: ServiceText,
+	pub long_description: Option<ServiceDescription>,
+	pub image: Option<ServiceText>,
 }
 
-#[derive(Encode, Decode, Clone, Default, RuntimeDebug, PartialEq, Eq, TypeInfo)]
+#[derive(Encode, Decode, Clone, Default, RuntimeDebug, PartialEq, Eq, TypeInfo, MaxEncodedLen)]
 pub struct Service<AccountId, Hash, Balance> {
 	pub id: Hash,
 	pub owner_id: AccountId,
@@ -59,9 +70,17 @@ impl<AccountId, Hash, Balance> Service<AccountId, Hash, Balance> {
 		&self.service_flow
 	}
 
-	pub fn get_prices_by_currency(&self) -> &Vec<PriceByCurrency<Balance>> {
+	pub fn get_prices_by_currency(&self) -> &[PriceByCurrency<Balance>] {
 		&self.info.prices_by_currency
 	}
+
+	pub fn get_expected_duration(&self) -> &ExpectedDuration {
+		&self.info.expected_duration
+	}
+
+	pub fn get_category(&self) -> &[u8] {
+		&self.info.category
+	}
 }
 
 impl<T, AccountId, Hash, Balance> ServiceInfoT<T, Balance> for Service<AccountId, Hash, Balance>
@@ -78,9 +97,15 @@ where
 	fn get_service_flow(&self) -> &ServiceFlow {
 		self.get_service_flow()
 	}
-	fn get_prices_by_currency(&self) -> &Vec<PriceByCurrency<Balance>> {
+	fn get_prices_by_currency(&self) -> &[PriceByCurrency<Balance>] {
 		self.get_prices_by_currency()
 	}
+	fn get_expected_duration(&self) -> &ExpectedDuration {
+		self.get_expected_duration()
+	}
+	fn get_category(&self) -> &[u8] {
+		self.get_category()
+	}
 	fn is_service_owner(&self, account_id: &T::AccountId) -> bool {
 		self.get_owner_id() == account_id
 	}
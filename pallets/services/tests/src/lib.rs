@@ -26,17 +26,19 @@ mod tests {
 					box_public_key: Keccak256::hash(
 						"0xDb9Af2d1f3ADD2726A132AA7A65Cc9E6fC5761C3".as_bytes()
 					),
-					name: "DeBio Lab".as_bytes().to_vec(),
-					email: "DeBio Email".as_bytes().to_vec(),
-					country: CountryCode::from_vec("DC".as_bytes().to_vec()),
-					region: RegionCode::from_vec("DB".as_bytes().to_vec()),
-					city: CityCode::from_vec("CITY".as_bytes().to_vec()),
-					address: "DeBio Address".as_bytes().to_vec(),
-					phone_number: "+6281394653625".as_bytes().to_vec(),
-					website: "DeBio Website".as_bytes().to_vec(),
-					latitude: Some("DeBio Latitude".as_bytes().to_vec()),
-					longitude: Some("DeBio Longtitude".as_bytes().to_vec()),
-					profile_image: Some("DeBio Profile Image uwu".as_bytes().to_vec()),
+					name: "DeBio Lab".as_bytes().to_vec().try_into().unwrap(),
+					email: "DeBio Email".as_bytes().to_vec().try_into().unwrap(),
+					country: CountryCode::try_from("ID".as_bytes().to_vec()).unwrap(),
+					region: RegionCode::try_from("JB".as_bytes().to_vec()).unwrap(),
+					city: CityCode::try_from("CITY".as_bytes().to_vec()).unwrap(),
+					address: "DeBio Address".as_bytes().to_vec().try_into().unwrap(),
+					phone_number: "+6281394653625".as_bytes().to_vec().try_into().unwrap(),
+					website: "DeBio Website".as_bytes().to_vec().try_into().unwrap(),
+					latitude: Some("-6.175392".as_bytes().to_vec().try_into().unwrap()),
+					longitude: Some("106.827153".as_bytes().to_vec().try_into().unwrap()),
+					profile_image: Some(
+						"DeBio Profile Image uwu".as_bytes().to_vec().try_into().unwrap()
+					),
 				}
 			));
 
@@ -48,17 +50,29 @@ mod tests {
 			assert_ok!(Services::create_service(
 				RuntimeOrigin::signed(1),
 				ServiceInfo {
-					name: "DeBio service name".as_bytes().to_vec(),
-					prices_by_currency: vec![PriceByCurrency::default()],
+					name: "DeBio service name".as_bytes().to_vec().try_into().unwrap(),
+					prices_by_currency: vec![PriceByCurrency::default()].try_into().unwrap(),
 					expected_duration: ExpectedDuration::default(),
-					category: "DeBio service category".as_bytes().to_vec(),
-					description: "DeBio service description".as_bytes().to_vec(),
+					category: "DeBio service category".as_bytes().to_vec().try_into().unwrap(),
+					description: "DeBio service description"
+						.as_bytes()
+						.to_vec()
+						.try_into()
+						.unwrap(),
 					dna_collection_process: "DeBio service dna_collection_process"
 						.as_bytes()
-						.to_vec(),
-					test_result_sample: "DeBio service test_result_sample".as_bytes().to_vec(),
-					long_description: Some("DeBio service long_description".as_bytes().to_vec()),
-					image: Some("DeBio service image".as_bytes().to_vec()),
+						.to_vec()
+						.try_into()
+						.unwrap(),
+					test_result_sample: "DeBio service test_result_sample"
+						.as_bytes()
+						.to_vec()
+						.try_into()
+						.unwrap(),
+					long_description: Some(
+						"DeBio service long_description".as_bytes().to_vec().try_into().unwrap()
+					),
+					image: Some("DeBio service image".as_bytes().to_vec().try_into().unwrap()),
 				},
 				ServiceFlow::default()
 			));
@@ -71,19 +85,33 @@ mod tests {
 					id: lab.services[0],
 					owner_id: 1,
 					info: ServiceInfo {
-						name: "DeBio service name".as_bytes().to_vec(),
-						prices_by_currency: vec![PriceByCurrency::default()],
+						name: "DeBio service name".as_bytes().to_vec().try_into().unwrap(),
+						prices_by_currency: vec![PriceByCurrency::default()].try_into().unwrap(),
 						expected_duration: ExpectedDuration::default(),
-						category: "DeBio service category".as_bytes().to_vec(),
-						description: "DeBio service description".as_bytes().to_vec(),
+						category: "DeBio service category".as_bytes().to_vec().try_into().unwrap(),
+						description: "DeBio service description"
+							.as_bytes()
+							.to_vec()
+							.try_into()
+							.unwrap(),
 						dna_collection_process: "DeBio service dna_collection_process"
 							.as_bytes()
-							.to_vec(),
-						test_result_sample: "DeBio service test_result_sample".as_bytes().to_vec(),
+							.to_vec()
+							.try_into()
+							.unwrap(),
+						test_result_sample: "DeBio service test_result_sample"
+							.as_bytes()
+							.to_vec()
+							.try_into()
+							.unwrap(),
 						long_description: Some(
-							"DeBio service long_description".as_bytes().to_vec()
+							"DeBio service long_description"
+								.as_bytes()
+								.to_vec()
+								.try_into()
+								.unwrap()
 						),
-						image: Some("DeBio service image".as_bytes().to_vec()),
+						image: Some("DeBio service image".as_bytes().to_vec().try_into().unwrap()),
 					},
 					service_flow: ServiceFlow::default()
 				})
@@ -102,17 +130,19 @@ mod tests {
 					box_public_key: Keccak256::hash(
 						"0xDb9Af2d1f3ADD2726A132AA7A65Cc9E6fC5761C3".as_bytes()
 					),
-					name: "DeBio Lab".as_bytes().to_vec(),
-					email: "DeBio Email".as_bytes().to_vec(),
-					country: CountryCode::from_vec("DC".as_bytes().to_vec()),
-					region: RegionCode::from_vec("DB".as_bytes().to_vec()),
-					city: CityCode::from_vec("CITY".as_bytes().to_vec()),
-					address: "DeBio Address".as_bytes().to_vec(),
-					phone_number: "+6281394653625".as_bytes().to_vec(),
-					website: "DeBio Website".as_bytes().to_vec(),
-					latitude: Some("DeBio Latitude".as_bytes().to_vec()),
-					longitude: Some("DeBio Longtitude".as_bytes().to_vec()),
-					profile_image: Some("DeBio Profile Image uwu".as_bytes().to_vec()),
+					name: "DeBio Lab".as_bytes().to_vec().try_into().unwrap(),
+					email: "DeBio Email".as_bytes().to_vec().try_into().unwrap(),
+					country: CountryCode::try_from("ID".as_bytes().to_vec()).unwrap(),
+					region: RegionCode::try_from("JB".as_bytes().to_vec()).unwrap(),
+					city: CityCode::try_from("CITY".as_bytes().to_vec()).unwrap(),
+					address: "DeBio Address".as_bytes().to_vec().try_into().unwrap(),
+					phone_number: "+6281394653625".as_bytes().to_vec().try_into().unwrap(),
+					website: "DeBio Website".as_bytes().to_vec().try_into().unwrap(),
+					latitude: Some("-6.175392".as_bytes().to_vec().try_into().unwrap()),
+					longitude: Some("106.827153".as_bytes().to_vec().try_into().unwrap()),
+					profile_image: Some(
+						"DeBio Profile Image uwu".as_bytes().to_vec().try_into().unwrap()
+					),
 				}
 			));
 
@@ -124,17 +154,29 @@ mod tests {
 			assert_ok!(Services::create_service(
 				RuntimeOrigin::signed(1),
 				ServiceInfo {
-					name: "DeBio service name".as_bytes().to_vec(),
-					prices_by_currency: vec![PriceByCurrency::default()],
+					name: "DeBio service name".as_bytes().to_vec().try_into().unwrap(),
+					prices_by_currency: vec![PriceByCurrency::default()].try_into().unwrap(),
 					expected_duration: ExpectedDuration::default(),
-					category: "DeBio service category".as_bytes().to_vec(),
-					description: "DeBio service description".as_bytes().to_vec(),
+					category: "DeBio service category".as_bytes().to_vec().try_into().unwrap(),
+					description: "DeBio service description"
+						.as_bytes()
+						.to_vec()
+						.try_into()
+						.unwrap(),
 					dna_collection_process: "DeBio service dna_collection_process"
 						.as_bytes()
-						.to_vec(),
-					test_result_sample: "DeBio service test_result_sample".as_bytes().to_vec(),
-					long_description: Some("DeBio service long_description".as_bytes().to_vec()),
-					image: Some("DeBio service image".as_bytes().to_vec()),
+						.to_vec()
+						.try_into()
+						.unwrap(),
+					test_result_sample: "DeBio service test_result_sample"
+						.as_bytes()
+						.to_vec()
+						.try_into()
+						.unwrap(),
+					long_description: Some(
+						"DeBio service long_description".as_bytes().to_vec().try_into().unwrap()
+					),
+					image: Some("DeBio service image".as_bytes().to_vec().try_into().unwrap()),
 				},
 				ServiceFlow::default()
 			));
@@ -145,17 +187,29 @@ mod tests {
 				RuntimeOrigin::signed(1),
 				lab.services[0],
 				ServiceInfo {
-					name: "DeBio service name 2".as_bytes().to_vec(),
-					prices_by_currency: vec![PriceByCurrency::default()],
+					name: "DeBio service name 2".as_bytes().to_vec().try_into().unwrap(),
+					prices_by_currency: vec![PriceByCurrency::default()].try_into().unwrap(),
 					expected_duration: ExpectedDuration::default(),
-					category: "DeBio service category 2".as_bytes().to_vec(),
-					description: "DeBio service description 2".as_bytes().to_vec(),
+					category: "DeBio service category 2".as_bytes().to_vec().try_into().unwrap(),
+					description: "DeBio service description 2"
+						.as_bytes()
+						.to_vec()
+						.try_into()
+						.unwrap(),
 					dna_collection_process: "DeBio service dna_collection_process 2"
 						.as_bytes()
-						.to_vec(),
-					test_result_sample: "DeBio service test_result_sample 2".as_bytes().to_vec(),
-					long_description: Some("DeBio service long_description 2".as_bytes().to_vec()),
-					image: Some("DeBio service image 2".as_bytes().to_vec()),
+						.to_vec()
+						.try_into()
+						.unwrap(),
+					test_result_sample: "DeBio service test_result_sample 2"
+						.as_bytes()
+						.to_vec()
+						.try_into()
+						.unwrap(),
+					long_description: Some(
+						"DeBio service long_description 2".as_bytes().to_vec().try_into().unwrap()
+					),
+					image: Some("DeBio service image 2".as_bytes().to_vec().try_into().unwrap()),
 				}
 			));
 
@@ -165,21 +219,39 @@ mod tests {
 					id: lab.services[0],
 					owner_id: 1,
 					info: ServiceInfo {
-						name: "DeBio service name 2".as_bytes().to_vec(),
-						prices_by_currency: vec![PriceByCurrency::default()],
+						name: "DeBio service name 2".as_bytes().to_vec().try_into().unwrap(),
+						prices_by_currency: vec![PriceByCurrency::default()].try_into().unwrap(),
 						expected_duration: ExpectedDuration::default(),
-						category: "DeBio service category 2".as_bytes().to_vec(),
-						description: "DeBio service description 2".as_bytes().to_vec(),
+						category: "DeBio service category 2"
+							.as_bytes()
+							.to_vec()
+							.try_into()
+							.unwrap(),
+						description: "DeBio service description 2"
+							.as_bytes()
+							.to_vec()
+							.try_into()
+							.unwrap(),
 						dna_collection_process: "DeBio service dna_collection_process 2"
 							.as_bytes()
-							.to_vec(),
+							.to_vec()
+							.try_into()
+							.unwrap(),
 						test_result_sample: "DeBio service test_result_sample 2"
 							.as_bytes()
-							.to_vec(),
+							.to_vec()
+							.try_into()
+							.unwrap(),
 						long_description: Some(
-							"DeBio service long_description 2".as_bytes().to_vec()
+							"DeBio service long_description 2"
+								.as_bytes()
+								.to_vec()
+								.try_into()
+								.unwrap()
+						),
+						image: Some(
+							"DeBio service image 2".as_bytes().to_vec().try_into().unwrap()
 						),
-						image: Some("DeBio service image 2".as_bytes().to_vec()),
 					},
 					service_flow: ServiceFlow::default()
 				})
@@ -198,17 +270,19 @@ mod tests {
 					box_public_key: Keccak256::hash(
 						"0xDb9Af2d1f3ADD2726A132AA7A65Cc9E6fC5761C3".as_bytes()
 					),
-					name: "DeBio Lab".as_bytes().to_vec(),
-					email: "DeBio Email".as_bytes().to_vec(),
-					country: CountryCode::from_vec("DC".as_bytes().to_vec()),
-					region: RegionCode::from_vec("DB".as_bytes().to_vec()),
-					city: CityCode::from_vec("CITY".as_bytes().to_vec()),
-					address: "DeBio Address".as_bytes().to_vec(),
-					phone_number: "+6281394653625".as_bytes().to_vec(),
-					website: "DeBio Website".as_bytes().to_vec(),
-					latitude: Some("DeBio Latitude".as_bytes().to_vec()),
-					longitude: Some("DeBio Longtitude".as_bytes().to_vec()),
-					profile_image: Some("DeBio Profile Image uwu".as_bytes().to_vec()),
+					name: "DeBio Lab".as_bytes().to_vec().try_into().unwrap(),
+					email: "DeBio Email".as_bytes().to_vec().try_into().unwrap(),
+					country: CountryCode::try_from("ID".as_bytes().to_vec()).unwrap(),
+					region: RegionCode::try_from("JB".as_bytes().to_vec()).unwrap(),
+					city: CityCode::try_from("CITY".as_bytes().to_vec()).unwrap(),
+					address: "DeBio Address".as_bytes().to_vec().try_into().unwrap(),
+					phone_number: "+6281394653625".as_bytes().to_vec().try_into().unwrap(),
+					website: "DeBio Website".as_bytes().to_vec().try_into().unwrap(),
+					latitude: Some("-6.175392".as_bytes().to_vec().try_into().unwrap()),
+					longitude: Some("106.827153".as_bytes().to_vec().try_into().unwrap()),
+					profile_image: Some(
+						"DeBio Profile Image uwu".as_bytes().to_vec().try_into().unwrap()
+					),
 				}
 			));
 
@@ -220,17 +294,29 @@ mod tests {
 			assert_ok!(Services::create_service(
 				RuntimeOrigin::signed(1),
 				ServiceInfo {
-					name: "DeBio service name".as_bytes().to_vec(),
-					prices_by_currency: vec![PriceByCurrency::default()],
+					name: "DeBio service name".as_bytes().to_vec().try_into().unwrap(),
+					prices_by_currency: vec![PriceByCurrency::default()].try_into().unwrap(),
 					expected_duration: ExpectedDuration::default(),
-					category: "DeBio service category".as_bytes().to_vec(),
-					description: "DeBio service description".as_bytes().to_vec(),
+					category: "DeBio service category".as_bytes().to_vec().try_into().unwrap(),
+					description: "DeBio service description"
+						.as_bytes()
+						.to_vec()
+						.try_into()
+						.unwrap(),
 					dna_collection_process: "DeBio service dna_collection_process"
 						.as_bytes()
-						.to_vec(),
-					test_result_sample: "DeBio service test_result_sample".as_bytes().to_vec(),
-					long_description: Some("DeBio service long_description".as_bytes().to_vec()),
-					image: Some("DeBio service image".as_bytes().to_vec()),
+						.to_vec()
+						.try_into()
+						.unwrap(),
+					test_result_sample: "DeBio service test_result_sample"
+						.as_bytes()
+						.to_vec()
+						.try_into()
+						.unwrap(),
+					long_description: Some(
+						"DeBio service long_description".as_bytes().to_vec().try_into().unwrap()
+					),
+					image: Some("DeBio service image".as_bytes().to_vec().try_into().unwrap()),
 				},
 				ServiceFlow::default()
 			));
@@ -250,19 +336,33 @@ mod tests {
 				Services::create_service(
 					RuntimeOrigin::signed(1),
 					ServiceInfo {
-						name: "DeBio service name".as_bytes().to_vec(),
-						prices_by_currency: vec![PriceByCurrency::default()],
+						name: "DeBio service name".as_bytes().to_vec().try_into().unwrap(),
+						prices_by_currency: vec![PriceByCurrency::default()].try_into().unwrap(),
 						expected_duration: ExpectedDuration::default(),
-						category: "DeBio service category".as_bytes().to_vec(),
-						description: "DeBio service description".as_bytes().to_vec(),
+						category: "DeBio service category".as_bytes().to_vec().try_into().unwrap(),
+						description: "DeBio service description"
+							.as_bytes()
+							.to_vec()
+							.try_into()
+							.unwrap(),
 						dna_collection_process: "DeBio service dna_collection_process"
 							.as_bytes()
-							.to_vec(),
-						test_result_sample: "DeBio service test_result_sample".as_bytes().to_vec(),
+							.to_vec()
+							.try_into()
+							.unwrap(),
+						test_result_sample: "DeBio service test_result_sample"
+							.as_bytes()
+							.to_vec()
+							.try_into()
+							.unwrap(),
 						long_description: Some(
-							"DeBio service long_description".as_bytes().to_vec()
+							"DeBio service long_description"
+								.as_bytes()
+								.to_vec()
+								.try_into()
+								.unwrap()
 						),
-						image: Some("DeBio service image".as_bytes().to_vec()),
+						image: Some("DeBio service image".as_bytes().to_vec().try_into().unwrap()),
 					},
 					ServiceFlow::default()
 				),
@@ -280,17 +380,19 @@ mod tests {
 					box_public_key: Keccak256::hash(
 						"0xDb9Af2d1f3ADD2726A132AA7A65Cc9E6fC5761C3".as_bytes()
 					),
-					name: "DeBio Lab".as_bytes().to_vec(),
-					email: "DeBio Email".as_bytes().to_vec(),
-					country: CountryCode::from_vec("DC".as_bytes().to_vec()),
-					region: RegionCode::from_vec("DB".as_bytes().to_vec()),
-					city: CityCode::from_vec("CITY".as_bytes().to_vec()),
-					address: "DeBio Address".as_bytes().to_vec(),
-					phone_number: "+6281394653625".as_bytes().to_vec(),
-					website: "DeBio Website".as_bytes().to_vec(),
-					latitude: Some("DeBio Latitude".as_bytes().to_vec()),
-					longitude: Some("DeBio Longtitude".as_bytes().to_vec()),
-					profile_image: Some("DeBio Profile Image uwu".as_bytes().to_vec()),
+					name: "DeBio Lab".as_bytes().to_vec().try_into().unwrap(),
+					email: "DeBio Email".as_bytes().to_vec().try_into().unwrap(),
+					country: CountryCode::try_from("ID".as_bytes().to_vec()).unwrap(),
+					region: RegionCode::try_from("JB".as_bytes().to_vec()).unwrap(),
+					city: CityCode::try_from("CITY".as_bytes().to_vec()).unwrap(),
+					address: "DeBio Address".as_bytes().to_vec().try_into().unwrap(),
+					phone_number: "+6281394653625".as_bytes().to_vec().try_into().unwrap(),
+					website: "DeBio Website".as_bytes().to_vec().try_into().unwrap(),
+					latitude: Some("-6.175392".as_bytes().to_vec().try_into().unwrap()),
+					longitude: Some("106.827153".as_bytes().to_vec().try_into().unwrap()),
+					profile_image: Some(
+						"DeBio Profile Image uwu".as_bytes().to_vec().try_into().unwrap()
+					),
 				}
 			));
 
@@ -299,21 +401,39 @@ mod tests {
 					RuntimeOrigin::signed(1),
 					Keccak256::hash("0xDb9Af2d1f3ADD2726A132AA7A65Cc9E6fC5761C3".as_bytes()),
 					ServiceInfo {
-						name: "DeBio service name 2".as_bytes().to_vec(),
-						prices_by_currency: vec![PriceByCurrency::default()],
+						name: "DeBio service name 2".as_bytes().to_vec().try_into().unwrap(),
+						prices_by_currency: vec![PriceByCurrency::default()].try_into().unwrap(),
 						expected_duration: ExpectedDuration::default(),
-						category: "DeBio service category 2".as_bytes().to_vec(),
-						description: "DeBio service description 2".as_bytes().to_vec(),
+						category: "DeBio service category 2"
+							.as_bytes()
+							.to_vec()
+							.try_into()
+							.unwrap(),
+						description: "DeBio service description 2"
+							.as_bytes()
+							.to_vec()
+							.try_into()
+							.unwrap(),
 						dna_collection_process: "DeBio service dna_collection_process 2"
 							.as_bytes()
-							.to_vec(),
+							.to_vec()
+							.try_into()
+							.unwrap(),
 						test_result_sample: "DeBio service test_result_sample 2"
 							.as_bytes()
-							.to_vec(),
+							.to_vec()
+							.try_into()
+							.unwrap(),
 						long_description: Some(
-							"DeBio service long_description 2".as_bytes().to_vec()
+							"DeBio service long_description 2"
+								.as_bytes()
+								.to_vec()
+								.try_into()
+								.unwrap()
+						),
+						image: Some(
+							"DeBio service image 2".as_bytes().to_vec().try_into().unwrap()
 						),
-						image: Some("DeBio service image 2".as_bytes().to_vec()),
 					}
 				),
 				Error::<Test>::ServiceDoesNotExist
@@ -330,17 +450,19 @@ mod tests {
 					box_public_key: Keccak256::hash(
 						"0xDb9Af2d1f3ADD2726A132AA7A65Cc9E6fC5761C3".as_bytes()
 					),
-					name: "DeBio Lab".as_bytes().to_vec(),
-					email: "DeBio Email".as_bytes().to_vec(),
-					country: CountryCode::from_vec("DC".as_bytes().to_vec()),
-					region: RegionCode::from_vec("DB".as_bytes().to_vec()),
-					city: CityCode::from_vec("CITY".as_bytes().to_vec()),
-					address: "DeBio Address".as_bytes().to_vec(),
-					phone_number: "+6281394653625".as_bytes().to_vec(),
-					website: "DeBio Website".as_bytes().to_vec(),
-					latitude: Some("DeBio Latitude".as_bytes().to_vec()),
-					longitude: Some("DeBio Longtitude".as_bytes().to_vec()),
-					profile_image: Some("DeBio Profile Image uwu".as_bytes().to_vec()),
+					name: "DeBio Lab".as_bytes().to_vec().try_into().unwrap(),
+					email: "DeBio Email".as_bytes().to_vec().try_into().unwrap(),
+					country: CountryCode::try_from("ID".as_bytes().to_vec()).unwrap(),
+					region: RegionCode::try_from("JB".as_bytes().to_vec()).unwrap(),
+					city: CityCode::try_from("CITY".as_bytes().to_vec()).unwrap(),
+					address: "DeBio Address".as_bytes().to_vec().try_into().unwrap(),
+					phone_number: "+6281394653625".as_bytes().to_vec().try_into().unwrap(),
+					website: "DeBio Website".as_bytes().to_vec().try_into().unwrap(),
+					latitude: Some("-6.175392".as_bytes().to_vec().try_into().unwrap()),
+					longitude: Some("106.827153".as_bytes().to_vec().try_into().unwrap()),
+					profile_image: Some(
+						"DeBio Profile Image uwu".as_bytes().to_vec().try_into().unwrap()
+					),
 				}
 			));
 
@@ -352,17 +474,29 @@ mod tests {
 			assert_ok!(Services::create_service(
 				RuntimeOrigin::signed(1),
 				ServiceInfo {
-					name: "DeBio service name".as_bytes().to_vec(),
-					prices_by_currency: vec![PriceByCurrency::default()],
+					name: "DeBio service name".as_bytes().to_vec().try_into().unwrap(),
+					prices_by_currency: vec![PriceByCurrency::default()].try_into().unwrap(),
 					expected_duration: ExpectedDuration::default(),
-					category: "DeBio service category".as_bytes().to_vec(),
-					description: "DeBio service description".as_bytes().to_vec(),
+					category: "DeBio service category".as_bytes().to_vec().try_into().unwrap(),
+					description: "DeBio service description"
+						.as_bytes()
+						.to_vec()
+						.try_into()
+						.unwrap(),
 					dna_collection_process: "DeBio service dna_collection_process"
 						.as_bytes()
-						.to_vec(),
-					test_result_sample: "DeBio service test_result_sample".as_bytes().to_vec(),
-					long_description: Some("DeBio service long_description".as_bytes().to_vec()),
-					image: Some("DeBio service image".as_bytes().to_vec()),
+						.to_vec()
+						.try_into()
+						.unwrap(),
+					test_result_sample: "DeBio service test_result_sample"
+						.as_bytes()
+						.to_vec()
+						.try_into()
+						.unwrap(),
+					long_description: Some(
+						"DeBio service long_description".as_bytes().to_vec().try_into().unwrap()
+					),
+					image: Some("DeBio service image".as_bytes().to_vec().try_into().unwrap()),
 				},
 				ServiceFlow::default()
 			));
@@ -374,21 +508,39 @@ mod tests {
 					RuntimeOrigin::signed(2),
 					lab.services[0],
 					ServiceInfo {
-						name: "DeBio service name 2".as_bytes().to_vec(),
-						prices_by_currency: vec![PriceByCurrency::default()],
+						name: "DeBio service name 2".as_bytes().to_vec().try_into().unwrap(),
+						prices_by_currency: vec![PriceByCurrency::default()].try_into().unwrap(),
 						expected_duration: ExpectedDuration::default(),
-						category: "DeBio service category 2".as_bytes().to_vec(),
-						description: "DeBio service description 2".as_bytes().to_vec(),
+						category: "DeBio service category 2"
+							.as_bytes()
+							.to_vec()
+							.try_into()
+							.unwrap(),
+						description: "DeBio service description 2"
+							.as_bytes()
+							.to_vec()
+							.try_into()
+							.unwrap(),
 						dna_collection_process: "DeBio service dna_collection_process 2"
 							.as_bytes()
-							.to_vec(),
+							.to_vec()
+							.try_into()
+							.unwrap(),
 						test_result_sample: "DeBio service test_result_sample 2"
 							.as_bytes()
-							.to_vec(),
+							.to_vec()
+							.try_into()
+							.unwrap(),
 						long_description: Some(
-							"DeBio service long_description 2".as_bytes().to_vec()
+							"DeBio service long_description 2"
+								.as_bytes()
+								.to_vec()
+								.try_into()
+								.unwrap()
+						),
+						image: Some(
+							"DeBio service image 2".as_bytes().to_vec().try_into().unwrap()
 						),
-						image: Some("DeBio service image 2".as_bytes().to_vec()),
 					}
 				),
 				Error::<Test>::NotServiceOwner
@@ -405,17 +557,19 @@ mod tests {
 					box_public_key: Keccak256::hash(
 						"0xDb9Af2d1f3ADD2726A132AA7A65Cc9E6fC5761C3".as_bytes()
 					),
-					name: "DeBio Lab".as_bytes().to_vec(),
-					email: "DeBio Email".as_bytes().to_vec(),
-					country: CountryCode::from_vec("DC".as_bytes().to_vec()),
-					region: RegionCode::from_vec("DB".as_bytes().to_vec()),
-					city: CityCode::from_vec("CITY".as_bytes().to_vec()),
-					address: "DeBio Address".as_bytes().to_vec(),
-					phone_number: "+6281394653625".as_bytes().to_vec(),
-					website: "DeBio Website".as_bytes().to_vec(),
-					latitude: Some("DeBio Latitude".as_bytes().to_vec()),
-					longitude: Some("DeBio Longtitude".as_bytes().to_vec()),
-					profile_image: Some("DeBio Profile Image uwu".as_bytes().to_vec()),
+					name: "DeBio Lab".as_bytes().to_vec().try_into().unwrap(),
+					email: "DeBio Email".as_bytes().to_vec().try_into().unwrap(),
+					country: CountryCode::try_from("ID".as_bytes().to_vec()).unwrap(),
+					region: RegionCode::try_from("JB".as_bytes().to_vec()).unwrap(),
+					city: CityCode::try_from("CITY".as_bytes().to_vec()).unwrap(),
+					address: "DeBio Address".as_bytes().to_vec().try_into().unwrap(),
+					phone_number: "+6281394653625".as_bytes().to_vec().try_into().unwrap(),
+					website: "DeBio Website".as_bytes().to_vec().try_into().unwrap(),
+					latitude: Some("-6.175392".as_bytes().to_vec().try_into().unwrap()),
+					longitude: Some("106.827153".as_bytes().to_vec().try_into().unwrap()),
+					profile_image: Some(
+						"DeBio Profile Image uwu".as_bytes().to_vec().try_into().unwrap()
+					),
 				}
 			));
 
@@ -438,17 +592,19 @@ mod tests {
 					box_public_key: Keccak256::hash(
 						"0xDb9Af2d1f3ADD2726A132AA7A65Cc9E6fC5761C3".as_bytes()
 					),
-					name: "DeBio Lab".as_bytes().to_vec(),
-					email: "DeBio Email".as_bytes().to_vec(),
-					country: CountryCode::from_vec("DC".as_bytes().to_vec()),
-					region: RegionCode::from_vec("DB".as_bytes().to_vec()),
-					city: CityCode::from_vec("CITY".as_bytes().to_vec()),
-					address: "DeBio Address".as_bytes().to_vec(),
-					phone_number: "+6281394653625".as_bytes().to_vec(),
-					website: "DeBio Website".as_bytes().to_vec(),
-					latitude: Some("DeBio Latitude".as_bytes().to_vec()),
-					longitude: Some("DeBio Longtitude".as_bytes().to_vec()),
-					profile_image: Some("DeBio Profile Image uwu".as_bytes().to_vec()),
+					name: "DeBio Lab".as_bytes().to_vec().try_into().unwrap(),
+					email: "DeBio Email".as_bytes().to_vec().try_into().unwrap(),
+					country: CountryCode::try_from("ID".as_bytes().to_vec()).unwrap(),
+					region: RegionCode::try_from("JB".as_bytes().to_vec()).unwrap(),
+					city: CityCode::try_from("CITY".as_bytes().to_vec()).unwrap(),
+					address: "DeBio Address".as_bytes().to_vec().try_into().unwrap(),
+					phone_number: "+6281394653625".as_bytes().to_vec().try_into().unwrap(),
+					website: "DeBio Website".as_bytes().to_vec().try_into().unwrap(),
+					latitude: Some("-6.175392".as_bytes().to_vec().try_into().unwrap()),
+					longitude: Some("106.827153".as_bytes().to_vec().try_into().unwrap()),
+					profile_image: Some(
+						"DeBio Profile Image uwu".as_bytes().to_vec().try_into().unwrap()
+					),
 				}
 			));
 
@@ -460,17 +616,29 @@ mod tests {
 			assert_ok!(Services::create_service(
 				RuntimeOrigin::signed(1),
 				ServiceInfo {
-					name: "DeBio service name".as_bytes().to_vec(),
-					prices_by_currency: vec![PriceByCurrency::default()],
+					name: "DeBio service name".as_bytes().to_vec().try_into().unwrap(),
+					prices_by_currency: vec![PriceByCurrency::default()].try_into().unwrap(),
 					expected_duration: ExpectedDuration::default(),
-					category: "DeBio service category".as_bytes().to_vec(),
-					description: "DeBio service description".as_bytes().to_vec(),
+					category: "DeBio service category".as_bytes().to_vec().try_into().unwrap(),
+					description: "DeBio service description"
+						.as_bytes()
+						.to_vec()
+						.try_into()
+						.unwrap(),
 					dna_collection_process: "DeBio service dna_collection_process"
 						.as_bytes()
-						.to_vec(),
-					test_result_sample: "DeBio service test_result_sample".as_bytes().to_vec(),
-					long_description: Some("DeBio service long_description".as_bytes().to_vec()),
-					image: Some("DeBio service image".as_bytes().to_vec()),
+						.to_vec()
+						.try_into()
+						.unwrap(),
+					test_result_sample: "DeBio service test_result_sample"
+						.as_bytes()
+						.to_vec()
+						.try_into()
+						.unwrap(),
+					long_description: Some(
+						"DeBio service long_description".as_bytes().to_vec().try_into().unwrap()
+					),
+					image: Some("DeBio service image".as_bytes().to_vec().try_into().unwrap()),
 				},
 				ServiceFlow::default()
 			));
@@ -1,6 +1,7 @@
 #![cfg_attr(not(feature = "std"), no_std)]
 
 use frame_system::Config;
+use primitives_duration::ExpectedDuration;
 use primitives_price_and_currency::PriceByCurrency;
 use sp_std::prelude::*;
 
@@ -10,9 +11,12 @@ pub mod types {
 		pallet_prelude::*,
 	};
 	use scale_info::TypeInfo;
+	#[cfg(feature = "std")]
+	use serde::{Deserialize, Serialize};
 	use sp_std::prelude::*;
 
-	#[derive(Encode, Decode, Clone, RuntimeDebug, PartialEq, Eq, TypeInfo)]
+	#[derive(Encode, Decode, Clone, RuntimeDebug, PartialEq, Eq, TypeInfo, MaxEncodedLen)]
+	#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
 	pub enum ServiceFlow {
 		RequestTest,
 		StakingRequestService,
@@ -27,8 +31,10 @@ pub mod types {
 pub trait ServiceInfo<T: Config, Balance> {
 	fn get_id(&self) -> &T::Hash;
 	fn get_owner_id(&self) -> &T::AccountId;
-	fn get_prices_by_currency(&self) -> &Vec<PriceByCurrency<Balance>>;
+	fn get_prices_by_currency(&self) -> &[PriceByCurrency<Balance>];
 	fn get_service_flow(&self) -> &types::ServiceFlow;
+	fn get_expected_duration(&self) -> &ExpectedDuration;
+	fn get_category(&self) -> &[u8];
 	fn is_service_owner(&self, account_id: &T::AccountId) -> bool;
 }
 
@@ -47,6 +47,7 @@ pub mod pallet {
 			+ Decode
 			+ Default
 			+ TypeInfo
+			+ MaxEncodedLen
 			+ sp_std::fmt::Debug;
 		type ProfileRoles: Clone
 			+ Copy
@@ -58,6 +59,7 @@ pub mod pallet {
 			+ Default
 			+ TypeInfo
 			+ ProfileRolesTrait
+			+ MaxEncodedLen
 			+ sp_std::fmt::Debug;
 
 		/// Weight information for extrinsics in this pallet.
@@ -88,7 +90,6 @@ pub mod pallet {
 	// ----- This is template code, every pallet needs this ---
 	#[pallet::pallet]
 	#[pallet::generate_store(pub(super) trait Store)]
-	#[pallet::without_storage_info]
 	pub struct Pallet<T>(PhantomData<T>);
 
 	#[pallet::hooks]
@@ -0,0 +1,619 @@
+// ISO 3166 codes, sorted so they can be binary searched
+// Generated from the iso-codes 4.15.0 ISO 3166-1 and ISO 3166-2 lists
+
+/// ISO 3166-1 alpha-2 country codes
+pub const COUNTRY_CODES: &[[u8; 2]] = &[
+	*b"AD", *b"AE", *b"AF", *b"AG", *b"AI", *b"AL", *b"AM", *b"AO", *b"AQ", *b"AR", *b"AS", *b"AT",
+	*b"AU", *b"AW", *b"AX", *b"AZ", *b"BA", *b"BB", *b"BD", *b"BE", *b"BF", *b"BG", *b"BH", *b"BI",
+	*b"BJ", *b"BL", *b"BM", *b"BN", *b"BO", *b"BQ", *b"BR", *b"BS", *b"BT", *b"BV", *b"BW", *b"BY",
+	*b"BZ", *b"CA", *b"CC", *b"CD", *b"CF", *b"CG", *b"CH", *b"CI", *b"CK", *b"CL", *b"CM", *b"CN",
+	*b"CO", *b"CR", *b"CU", *b"CV", *b"CW", *b"CX", *b"CY", *b"CZ", *b"DE", *b"DJ", *b"DK", *b"DM",
+	*b"DO", *b"DZ", *b"EC", *b"EE", *b"EG", *b"EH", *b"ER", *b"ES", *b"ET", *b"FI", *b"FJ", *b"FK",
+	*b"FM", *b"FO", *b"FR", *b"GA", *b"GB", *b"GD", *b"GE", *b"GF", *b"GG", *b"GH", *b"GI", *b"GL",
+	*b"GM", *b"GN", *b"GP", *b"GQ", *b"GR", *b"GS", *b"GT", *b"GU", *b"GW", *b"GY", *b"HK", *b"HM",
+	*b"HN", *b"HR", *b"HT", *b"HU", *b"ID", *b"IE", *b"IL", *b"IM", *b"IN", *b"IO", *b"IQ", *b"IR",
+	*b"IS", *b"IT", *b"JE", *b"JM", *b"JO", *b"JP", *b"KE", *b"KG", *b"KH", *b"KI", *b"KM", *b"KN",
+	*b"KP", *b"KR", *b"KW", *b"KY", *b"KZ", *b"LA", *b"LB", *b"LC", *b"LI", *b"LK", *b"LR", *b"LS",
+	*b"LT", *b"LU", *b"LV", *b"LY", *b"MA", *b"MC", *b"MD", *b"ME", *b"MF", *b"MG", *b"MH", *b"MK",
+	*b"ML", *b"MM", *b"MN", *b"MO", *b"MP", *b"MQ", *b"MR", *b"MS", *b"MT", *b"MU", *b"MV", *b"MW",
+	*b"MX", *b"MY", *b"MZ", *b"NA", *b"NC", *b"NE", *b"NF", *b"NG", *b"NI", *b"NL", *b"NO", *b"NP",
+	*b"NR", *b"NU", *b"NZ", *b"OM", *b"PA", *b"PE", *b"PF", *b"PG", *b"PH", *b"PK", *b"PL", *b"PM",
+	*b"PN", *b"PR", *b"PS", *b"PT", *b"PW", *b"PY", *b"QA", *b"RE", *b"RO", *b"RS", *b"RU", *b"RW",
+	*b"SA", *b"SB", *b"SC", *b"SD", *b"SE", *b"SG", *b"SH", *b"SI", *b"SJ", *b"SK", *b"SL", *b"SM",
+	*b"SN", *b"SO", *b"SR", *b"SS", *b"ST", *b"SV", *b"SX", *b"SY", *b"SZ", *b"TC", *b"TD", *b"TF",
+	*b"TG", *b"TH", *b"TJ", *b"TK", *b"TL", *b"TM", *b"TN", *b"TO", *b"TR", *b"TT", *b"TV", *b"TW",
+	*b"TZ", *b"UA", *b"UG", *b"UM", *b"US", *b"UY", *b"UZ", *b"VA", *b"VC", *b"VE", *b"VG", *b"VI",
+	*b"VN", *b"VU", *b"WF", *b"WS", *b"YE", *b"YT", *b"ZA", *b"ZM", *b"ZW",
+];
+
+/// ISO 3166-2 subdivision codes, `XX-YYY`
+pub const SUBDIVISION_CODES: &[&[u8]] = &[
+	b"AD-02", b"AD-03", b"AD-04", b"AD-05", b"AD-06", b"AD-07", b"AD-08", b"AE-AJ", b"AE-AZ",
+	b"AE-DU", b"AE-FU", b"AE-RK", b"AE-SH", b"AE-UQ", b"AF-BAL", b"AF-BAM", b"AF-BDG", b"AF-BDS",
+	b"AF-BGL", b"AF-DAY", b"AF-FRA", b"AF-FYB", b"AF-GHA", b"AF-GHO", b"AF-HEL", b"AF-HER",
+	b"AF-JOW", b"AF-KAB", b"AF-KAN", b"AF-KAP", b"AF-KDZ", b"AF-KHO", b"AF-KNR", b"AF-LAG",
+	b"AF-LOG", b"AF-NAN", b"AF-NIM", b"AF-NUR", b"AF-PAN", b"AF-PAR", b"AF-PIA", b"AF-PKA",
+	b"AF-SAM", b"AF-SAR", b"AF-TAK", b"AF-URU", b"AF-WAR", b"AF-ZAB", b"AG-03", b"AG-04", b"AG-05",
+	b"AG-06", b"AG-07", b"AG-08", b"AG-10", b"AG-11", b"AL-01", b"AL-02", b"AL-03", b"AL-04",
+	b"AL-05", b"AL-06", b"AL-07", b"AL-08", b"AL-09", b"AL-10", b"AL-11", b"AL-12", b"AM-AG",
+	b"AM-AR", b"AM-AV", b"AM-ER", b"AM-GR", b"AM-KT", b"AM-LO", b"AM-SH", b"AM-SU", b"AM-TV",
+	b"AM-VD", b"AO-BGO", b"AO-BGU", b"AO-BIE", b"AO-CAB", b"AO-CCU", b"AO-CNN", b"AO-CNO",
+	b"AO-CUS", b"AO-HUA", b"AO-HUI", b"AO-LNO", b"AO-LSU", b"AO-LUA", b"AO-MAL", b"AO-MOX",
+	b"AO-NAM", b"AO-UIG", b"AO-ZAI", b"AR-A", b"AR-B", b"AR-C", b"AR-D", b"AR-E", b"AR-F", b"AR-G",
+	b"AR-H", b"AR-J", b"AR-K", b"AR-L", b"AR-M", b"AR-N", b"AR-P", b"AR-Q", b"AR-R", b"AR-S",
+	b"AR-T", b"AR-U", b"AR-V", b"AR-W", b"AR-X", b"AR-Y", b"AR-Z", b"AT-1", b"AT-2", b"AT-3",
+	b"AT-4", b"AT-5", b"AT-6", b"AT-7", b"AT-8", b"AT-9", b"AU-ACT", b"AU-NSW", b"AU-NT",
+	b"AU-QLD", b"AU-SA", b"AU-TAS", b"AU-VIC", b"AU-WA", b"AZ-ABS", b"AZ-AGA", b"AZ-AGC",
+	b"AZ-AGM", b"AZ-AGS", b"AZ-AGU", b"AZ-AST", b"AZ-BA", b"AZ-BAB", b"AZ-BAL", b"AZ-BAR",
+	b"AZ-BEY", b"AZ-BIL", b"AZ-CAB", b"AZ-CAL", b"AZ-CUL", b"AZ-DAS", b"AZ-FUZ", b"AZ-GA",
+	b"AZ-GAD", b"AZ-GOR", b"AZ-GOY", b"AZ-GYG", b"AZ-HAC", b"AZ-IMI", b"AZ-ISM", b"AZ-KAL",
+	b"AZ-KAN", b"AZ-KUR", b"AZ-LA", b"AZ-LAC", b"AZ-LAN", b"AZ-LER", b"AZ-MAS", b"AZ-MI", b"AZ-NA",
+	b"AZ-NEF", b"AZ-NV", b"AZ-NX", b"AZ-OGU", b"AZ-ORD", b"AZ-QAB", b"AZ-QAX", b"AZ-QAZ",
+	b"AZ-QBA", b"AZ-QBI", b"AZ-QOB", b"AZ-QUS", b"AZ-SA", b"AZ-SAB", b"AZ-SAD", b"AZ-SAH",
+	b"AZ-SAK", b"AZ-SAL", b"AZ-SAR", b"AZ-SAT", b"AZ-SBN", b"AZ-SIY", b"AZ-SKR", b"AZ-SM",
+	b"AZ-SMI", b"AZ-SMX", b"AZ-SR", b"AZ-SUS", b"AZ-TAR", b"AZ-TOV", b"AZ-UCA", b"AZ-XA",
+	b"AZ-XAC", b"AZ-XCI", b"AZ-XIZ", b"AZ-XVD", b"AZ-YAR", b"AZ-YE", b"AZ-YEV", b"AZ-ZAN",
+	b"AZ-ZAQ", b"AZ-ZAR", b"BA-BIH", b"BA-BRC", b"BA-SRP", b"BB-01", b"BB-02", b"BB-03", b"BB-04",
+	b"BB-05", b"BB-06", b"BB-07", b"BB-08", b"BB-09", b"BB-10", b"BB-11", b"BD-01", b"BD-02",
+	b"BD-03", b"BD-04", b"BD-05", b"BD-06", b"BD-07", b"BD-08", b"BD-09", b"BD-10", b"BD-11",
+	b"BD-12", b"BD-13", b"BD-14", b"BD-15", b"BD-16", b"BD-17", b"BD-18", b"BD-19", b"BD-20",
+	b"BD-21", b"BD-22", b"BD-23", b"BD-24", b"BD-25", b"BD-26", b"BD-27", b"BD-28", b"BD-29",
+	b"BD-30", b"BD-31", b"BD-32", b"BD-33", b"BD-34", b"BD-35", b"BD-36", b"BD-37", b"BD-38",
+	b"BD-39", b"BD-40", b"BD-41", b"BD-42", b"BD-43", b"BD-44", b"BD-45", b"BD-46", b"BD-47",
+	b"BD-48", b"BD-49", b"BD-50", b"BD-51", b"BD-52", b"BD-53", b"BD-54", b"BD-55", b"BD-56",
+	b"BD-57", b"BD-58", b"BD-59", b"BD-60", b"BD-61", b"BD-62", b"BD-63", b"BD-64", b"BD-A",
+	b"BD-B", b"BD-C", b"BD-D", b"BD-E", b"BD-F", b"BD-G", b"BD-H", b"BE-BRU", b"BE-VAN", b"BE-VBR",
+	b"BE-VLG", b"BE-VLI", b"BE-VOV", b"BE-VWV", b"BE-WAL", b"BE-WBR", b"BE-WHT", b"BE-WLG",
+	b"BE-WLX", b"BE-WNA", b"BF-01", b"BF-02", b"BF-03", b"BF-04", b"BF-05", b"BF-06", b"BF-07",
+	b"BF-08", b"BF-09", b"BF-10", b"BF-11", b"BF-12", b"BF-13", b"BF-BAL", b"BF-BAM", b"BF-BAN",
+	b"BF-BAZ", b"BF-BGR", b"BF-BLG", b"BF-BLK", b"BF-COM", b"BF-GAN", b"BF-GNA", b"BF-GOU",
+	b"BF-HOU", b"BF-IOB", b"BF-KAD", b"BF-KEN", b"BF-KMD", b"BF-KMP", b"BF-KOP", b"BF-KOS",
+	b"BF-KOT", b"BF-KOW", b"BF-LER", b"BF-LOR", b"BF-MOU", b"BF-NAM", b"BF-NAO", b"BF-NAY",
+	b"BF-NOU", b"BF-OUB", b"BF-OUD", b"BF-PAS", b"BF-PON", b"BF-SEN", b"BF-SIS", b"BF-SMT",
+	b"BF-SNG", b"BF-SOM", b"BF-SOR", b"BF-TAP", b"BF-TUI", b"BF-YAG", b"BF-YAT", b"BF-ZIR",
+	b"BF-ZON", b"BF-ZOU", b"BG-01", b"BG-02", b"BG-03", b"BG-04", b"BG-05", b"BG-06", b"BG-07",
+	b"BG-08", b"BG-09", b"BG-10", b"BG-11", b"BG-12", b"BG-13", b"BG-14", b"BG-15", b"BG-16",
+	b"BG-17", b"BG-18", b"BG-19", b"BG-20", b"BG-21", b"BG-22", b"BG-23", b"BG-24", b"BG-25",
+	b"BG-26", b"BG-27", b"BG-28", b"BH-13", b"BH-14", b"BH-15", b"BH-17", b"BI-BB", b"BI-BL",
+	b"BI-BM", b"BI-BR", b"BI-CA", b"BI-CI", b"BI-GI", b"BI-KI", b"BI-KR", b"BI-KY", b"BI-MA",
+	b"BI-MU", b"BI-MW", b"BI-MY", b"BI-NG", b"BI-RM", b"BI-RT", b"BI-RY", b"BJ-AK", b"BJ-AL",
+	b"BJ-AQ", b"BJ-BO", b"BJ-CO", b"BJ-DO", b"BJ-KO", b"BJ-LI", b"BJ-MO", b"BJ-OU", b"BJ-PL",
+	b"BJ-ZO", b"BN-BE", b"BN-BM", b"BN-TE", b"BN-TU", b"BO-B", b"BO-C", b"BO-H", b"BO-L", b"BO-N",
+	b"BO-O", b"BO-P", b"BO-S", b"BO-T", b"BQ-BO", b"BQ-SA", b"BQ-SE", b"BR-AC", b"BR-AL", b"BR-AM",
+	b"BR-AP", b"BR-BA", b"BR-CE", b"BR-DF", b"BR-ES", b"BR-GO", b"BR-MA", b"BR-MG", b"BR-MS",
+	b"BR-MT", b"BR-PA", b"BR-PB", b"BR-PE", b"BR-PI", b"BR-PR", b"BR-RJ", b"BR-RN", b"BR-RO",
+	b"BR-RR", b"BR-RS", b"BR-SC", b"BR-SE", b"BR-SP", b"BR-TO", b"BS-AK", b"BS-BI", b"BS-BP",
+	b"BS-BY", b"BS-CE", b"BS-CI", b"BS-CK", b"BS-CO", b"BS-CS", b"BS-EG", b"BS-EX", b"BS-FP",
+	b"BS-GC", b"BS-HI", b"BS-HT", b"BS-IN", b"BS-LI", b"BS-MC", b"BS-MG", b"BS-MI", b"BS-NE",
+	b"BS-NO", b"BS-NP", b"BS-NS", b"BS-RC", b"BS-RI", b"BS-SA", b"BS-SE", b"BS-SO", b"BS-SS",
+	b"BS-SW", b"BS-WG", b"BT-11", b"BT-12", b"BT-13", b"BT-14", b"BT-15", b"BT-21", b"BT-22",
+	b"BT-23", b"BT-24", b"BT-31", b"BT-32", b"BT-33", b"BT-34", b"BT-41", b"BT-42", b"BT-43",
+	b"BT-44", b"BT-45", b"BT-GA", b"BT-TY", b"BW-CE", b"BW-CH", b"BW-FR", b"BW-GA", b"BW-GH",
+	b"BW-JW", b"BW-KG", b"BW-KL", b"BW-KW", b"BW-LO", b"BW-NE", b"BW-NW", b"BW-SE", b"BW-SO",
+	b"BW-SP", b"BW-ST", b"BY-BR", b"BY-HM", b"BY-HO", b"BY-HR", b"BY-MA", b"BY-MI", b"BY-VI",
+	b"BZ-BZ", b"BZ-CY", b"BZ-CZL", b"BZ-OW", b"BZ-SC", b"BZ-TOL", b"CA-AB", b"CA-BC", b"CA-MB",
+	b"CA-NB", b"CA-NL", b"CA-NS", b"CA-NT", b"CA-NU", b"CA-ON", b"CA-PE", b"CA-QC", b"CA-SK",
+	b"CA-YT", b"CD-BC", b"CD-BU", b"CD-EQ", b"CD-HK", b"CD-HL", b"CD-HU", b"CD-IT", b"CD-KC",
+	b"CD-KE", b"CD-KG", b"CD-KL", b"CD-KN", b"CD-KS", b"CD-LO", b"CD-LU", b"CD-MA", b"CD-MN",
+	b"CD-MO", b"CD-NK", b"CD-NU", b"CD-SA", b"CD-SK", b"CD-SU", b"CD-TA", b"CD-TO", b"CD-TU",
+	b"CF-AC", b"CF-BB", b"CF-BGF", b"CF-BK", b"CF-HK", b"CF-HM", b"CF-HS", b"CF-KB", b"CF-KG",
+	b"CF-LB", b"CF-MB", b"CF-MP", b"CF-NM", b"CF-OP", b"CF-SE", b"CF-UK", b"CF-VK", b"CG-11",
+	b"CG-12", b"CG-13", b"CG-14", b"CG-15", b"CG-16", b"CG-2", b"CG-5", b"CG-7", b"CG-8", b"CG-9",
+	b"CG-BZV", b"CH-AG", b"CH-AI", b"CH-AR", b"CH-BE", b"CH-BL", b"CH-BS", b"CH-FR", b"CH-GE",
+	b"CH-GL", b"CH-GR", b"CH-JU", b"CH-LU", b"CH-NE", b"CH-NW", b"CH-OW", b"CH-SG", b"CH-SH",
+	b"CH-SO", b"CH-SZ", b"CH-TG", b"CH-TI", b"CH-UR", b"CH-VD", b"CH-VS", b"CH-ZG", b"CH-ZH",
+	b"CI-AB", b"CI-BS", b"CI-CM", b"CI-DN", b"CI-GD", b"CI-LC", b"CI-LG", b"CI-MG", b"CI-SM",
+	b"CI-SV", b"CI-VB", b"CI-WR", b"CI-YM", b"CI-ZZ", b"CL-AI", b"CL-AN", b"CL-AP", b"CL-AR",
+	b"CL-AT", b"CL-BI", b"CL-CO", b"CL-LI", b"CL-LL", b"CL-LR", b"CL-MA", b"CL-ML", b"CL-NB",
+	b"CL-RM", b"CL-TA", b"CL-VS", b"CM-AD", b"CM-CE", b"CM-EN", b"CM-ES", b"CM-LT", b"CM-NO",
+	b"CM-NW", b"CM-OU", b"CM-SU", b"CM-SW", b"CN-AH", b"CN-BJ", b"CN-CQ", b"CN-FJ", b"CN-GD",
+	b"CN-GS", b"CN-GX", b"CN-GZ", b"CN-HA", b"CN-HB", b"CN-HE", b"CN-HI", b"CN-HK", b"CN-HL",
+	b"CN-HN", b"CN-JL", b"CN-JS", b"CN-JX", b"CN-LN", b"CN-MO", b"CN-NM", b"CN-NX", b"CN-QH",
+	b"CN-SC", b"CN-SD", b"CN-SH", b"CN-SN", b"CN-SX", b"CN-TJ", b"CN-TW", b"CN-XJ", b"CN-XZ",
+	b"CN-YN", b"CN-ZJ", b"CO-AMA", b"CO-ANT", b"CO-ARA", b"CO-ATL", b"CO-BOL", b"CO-BOY",
+	b"CO-CAL", b"CO-CAQ", b"CO-CAS", b"CO-CAU", b"CO-CES", b"CO-CHO", b"CO-COR", b"CO-CUN",
+	b"CO-DC", b"CO-GUA", b"CO-GUV", b"CO-HUI", b"CO-LAG", b"CO-MAG", b"CO-MET", b"CO-NAR",
+	b"CO-NSA", b"CO-PUT", b"CO-QUI", b"CO-RIS", b"CO-SAN", b"CO-SAP", b"CO-SUC", b"CO-TOL",
+	b"CO-VAC", b"CO-VAU", b"CO-VID", b"CR-A", b"CR-C", b"CR-G", b"CR-H", b"CR-L", b"CR-P",
+	b"CR-SJ", b"CU-01", b"CU-03", b"CU-04", b"CU-05", b"CU-06", b"CU-07", b"CU-08", b"CU-09",
+	b"CU-10", b"CU-11", b"CU-12", b"CU-13", b"CU-14", b"CU-15", b"CU-16", b"CU-99", b"CV-B",
+	b"CV-BR", b"CV-BV", b"CV-CA", b"CV-CF", b"CV-CR", b"CV-MA", b"CV-MO", b"CV-PA", b"CV-PN",
+	b"CV-PR", b"CV-RB", b"CV-RG", b"CV-RS", b"CV-S", b"CV-SD", b"CV-SF", b"CV-SL", b"CV-SM",
+	b"CV-SO", b"CV-SS", b"CV-SV", b"CV-TA", b"CV-TS", b"CY-01", b"CY-02", b"CY-03", b"CY-04",
+	b"CY-05", b"CY-06", b"CZ-10", b"CZ-20", b"CZ-201", b"CZ-202", b"CZ-203", b"CZ-204", b"CZ-205",
+	b"CZ-206", b"CZ-207", b"CZ-208", b"CZ-209", b"CZ-20A", b"CZ-20B", b"CZ-20C", b"CZ-31",
+	b"CZ-311", b"CZ-312", b"CZ-313", b"CZ-314", b"CZ-315", b"CZ-316", b"CZ-317", b"CZ-32",
+	b"CZ-321", b"CZ-322", b"CZ-323", b"CZ-324", b"CZ-325", b"CZ-326", b"CZ-327", b"CZ-41",
+	b"CZ-411", b"CZ-412", b"CZ-413", b"CZ-42", b"CZ-421", b"CZ-422", b"CZ-423", b"CZ-424",
+	b"CZ-425", b"CZ-426", b"CZ-427", b"CZ-51", b"CZ-511", b"CZ-512", b"CZ-513", b"CZ-514",
+	b"CZ-52", b"CZ-521", b"CZ-522", b"CZ-523", b"CZ-524", b"CZ-525", b"CZ-53", b"CZ-531",
+	b"CZ-532", b"CZ-533", b"CZ-534", b"CZ-63", b"CZ-631", b"CZ-632", b"CZ-633", b"CZ-634",
+	b"CZ-635", b"CZ-64", b"CZ-641", b"CZ-642", b"CZ-643", b"CZ-644", b"CZ-645", b"CZ-646",
+	b"CZ-647", b"CZ-71", b"CZ-711", b"CZ-712", b"CZ-713", b"CZ-714", b"CZ-715", b"CZ-72",
+	b"CZ-721", b"CZ-722", b"CZ-723", b"CZ-724", b"CZ-80", b"CZ-801", b"CZ-802", b"CZ-803",
+	b"CZ-804", b"CZ-805", b"CZ-806", b"DE-BB", b"DE-BE", b"DE-BW", b"DE-BY", b"DE-HB", b"DE-HE",
+	b"DE-HH", b"DE-MV", b"DE-NI", b"DE-NW", b"DE-RP", b"DE-SH", b"DE-SL", b"DE-SN", b"DE-ST",
+	b"DE-TH", b"DJ-AR", b"DJ-AS", b"DJ-DI", b"DJ-DJ", b"DJ-OB", b"DJ-TA", b"DK-81", b"DK-82",
+	b"DK-83", b"DK-84", b"DK-85", b"DM-02", b"DM-03", b"DM-04", b"DM-05", b"DM-06", b"DM-07",
+	b"DM-08", b"DM-09", b"DM-10", b"DM-11", b"DO-01", b"DO-02", b"DO-03", b"DO-04", b"DO-05",
+	b"DO-06", b"DO-07", b"DO-08", b"DO-09", b"DO-10", b"DO-11", b"DO-12", b"DO-13", b"DO-14",
+	b"DO-15", b"DO-16", b"DO-17", b"DO-18", b"DO-19", b"DO-20", b"DO-21", b"DO-22", b"DO-23",
+	b"DO-24", b"DO-25", b"DO-26", b"DO-27", b"DO-28", b"DO-29", b"DO-30", b"DO-31", b"DO-32",
+	b"DO-33", b"DO-34", b"DO-35", b"DO-36", b"DO-37", b"DO-38", b"DO-39", b"DO-40", b"DO-41",
+	b"DO-42", b"DZ-01", b"DZ-02", b"DZ-03", b"DZ-04", b"DZ-05", b"DZ-06", b"DZ-07", b"DZ-08",
+	b"DZ-09", b"DZ-10", b"DZ-11", b"DZ-12", b"DZ-13", b"DZ-14", b"DZ-15", b"DZ-16", b"DZ-17",
+	b"DZ-18", b"DZ-19", b"DZ-20", b"DZ-21", b"DZ-22", b"DZ-23", b"DZ-24", b"DZ-25", b"DZ-26",
+	b"DZ-27", b"DZ-28", b"DZ-29", b"DZ-30", b"DZ-31", b"DZ-32", b"DZ-33", b"DZ-34", b"DZ-35",
+	b"DZ-36", b"DZ-37", b"DZ-38", b"DZ-39", b"DZ-40", b"DZ-41", b"DZ-42", b"DZ-43", b"DZ-44",
+	b"DZ-45", b"DZ-46", b"DZ-47", b"DZ-48", b"EC-A", b"EC-B", b"EC-C", b"EC-D", b"EC-E", b"EC-F",
+	b"EC-G", b"EC-H", b"EC-I", b"EC-L", b"EC-M", b"EC-N", b"EC-O", b"EC-P", b"EC-R", b"EC-S",
+	b"EC-SD", b"EC-SE", b"EC-T", b"EC-U", b"EC-W", b"EC-X", b"EC-Y", b"EC-Z", b"EE-130", b"EE-141",
+	b"EE-142", b"EE-171", b"EE-184", b"EE-191", b"EE-198", b"EE-205", b"EE-214", b"EE-245",
+	b"EE-247", b"EE-251", b"EE-255", b"EE-272", b"EE-283", b"EE-284", b"EE-291", b"EE-293",
+	b"EE-296", b"EE-303", b"EE-305", b"EE-317", b"EE-321", b"EE-338", b"EE-353", b"EE-37",
+	b"EE-39", b"EE-424", b"EE-430", b"EE-431", b"EE-432", b"EE-441", b"EE-442", b"EE-446",
+	b"EE-45", b"EE-478", b"EE-480", b"EE-486", b"EE-50", b"EE-503", b"EE-511", b"EE-514", b"EE-52",
+	b"EE-528", b"EE-557", b"EE-56", b"EE-567", b"EE-586", b"EE-60", b"EE-615", b"EE-618",
+	b"EE-622", b"EE-624", b"EE-638", b"EE-64", b"EE-651", b"EE-653", b"EE-661", b"EE-663",
+	b"EE-668", b"EE-68", b"EE-689", b"EE-698", b"EE-708", b"EE-71", b"EE-712", b"EE-714",
+	b"EE-719", b"EE-726", b"EE-732", b"EE-735", b"EE-74", b"EE-784", b"EE-79", b"EE-792",
+	b"EE-793", b"EE-796", b"EE-803", b"EE-809", b"EE-81", b"EE-824", b"EE-834", b"EE-84",
+	b"EE-855", b"EE-87", b"EE-890", b"EE-897", b"EE-899", b"EE-901", b"EE-903", b"EE-907",
+	b"EE-917", b"EE-919", b"EE-928", b"EG-ALX", b"EG-ASN", b"EG-AST", b"EG-BA", b"EG-BH",
+	b"EG-BNS", b"EG-C", b"EG-DK", b"EG-DT", b"EG-FYM", b"EG-GH", b"EG-GZ", b"EG-IS", b"EG-JS",
+	b"EG-KB", b"EG-KFS", b"EG-KN", b"EG-LX", b"EG-MN", b"EG-MNF", b"EG-MT", b"EG-PTS", b"EG-SHG",
+	b"EG-SHR", b"EG-SIN", b"EG-SUZ", b"EG-WAD", b"ER-AN", b"ER-DK", b"ER-DU", b"ER-GB", b"ER-MA",
+	b"ER-SK", b"ES-A", b"ES-AB", b"ES-AL", b"ES-AN", b"ES-AR", b"ES-AS", b"ES-AV", b"ES-B",
+	b"ES-BA", b"ES-BI", b"ES-BU", b"ES-C", b"ES-CA", b"ES-CB", b"ES-CC", b"ES-CE", b"ES-CL",
+	b"ES-CM", b"ES-CN", b"ES-CO", b"ES-CR", b"ES-CS", b"ES-CT", b"ES-CU", b"ES-EX", b"ES-GA",
+	b"ES-GC", b"ES-GI", b"ES-GR", b"ES-GU", b"ES-H", b"ES-HU", b"ES-IB", b"ES-J", b"ES-L",
+	b"ES-LE", b"ES-LO", b"ES-LU", b"ES-M", b"ES-MA", b"ES-MC", b"ES-MD", b"ES-ML", b"ES-MU",
+	b"ES-NA", b"ES-NC", b"ES-O", b"ES-OR", b"ES-P", b"ES-PM", b"ES-PO", b"ES-PV", b"ES-RI",
+	b"ES-S", b"ES-SA", b"ES-SE", b"ES-SG", b"ES-SO", b"ES-SS", b"ES-T", b"ES-TE", b"ES-TF",
+	b"ES-TO", b"ES-V", b"ES-VA", b"ES-VC", b"ES-VI", b"ES-Z", b"ES-ZA", b"ET-AA", b"ET-AF",
+	b"ET-AM", b"ET-BE", b"ET-DD", b"ET-GA", b"ET-HA", b"ET-OR", b"ET-SN", b"ET-SO", b"ET-TI",
+	b"FI-01", b"FI-02", b"FI-03", b"FI-04", b"FI-05", b"FI-06", b"FI-07", b"FI-08", b"FI-09",
+	b"FI-10", b"FI-11", b"FI-12", b"FI-13", b"FI-14", b"FI-15", b"FI-16", b"FI-17", b"FI-18",
+	b"FI-19", b"FJ-01", b"FJ-02", b"FJ-03", b"FJ-04", b"FJ-05", b"FJ-06", b"FJ-07", b"FJ-08",
+	b"FJ-09", b"FJ-10", b"FJ-11", b"FJ-12", b"FJ-13", b"FJ-14", b"FJ-C", b"FJ-E", b"FJ-N", b"FJ-R",
+	b"FJ-W", b"FM-KSA", b"FM-PNI", b"FM-TRK", b"FM-YAP", b"FR-01", b"FR-02", b"FR-03", b"FR-04",
+	b"FR-05", b"FR-06", b"FR-07", b"FR-08", b"FR-09", b"FR-10", b"FR-11", b"FR-12", b"FR-13",
+	b"FR-14", b"FR-15", b"FR-16", b"FR-17", b"FR-18", b"FR-19", b"FR-20R", b"FR-21", b"FR-22",
+	b"FR-23", b"FR-24", b"FR-25", b"FR-26", b"FR-27", b"FR-28", b"FR-29", b"FR-2A", b"FR-2B",
+	b"FR-30", b"FR-31", b"FR-32", b"FR-33", b"FR-34", b"FR-35", b"FR-36", b"FR-37", b"FR-38",
+	b"FR-39", b"FR-40", b"FR-41", b"FR-42", b"FR-43", b"FR-44", b"FR-45", b"FR-46", b"FR-47",
+	b"FR-48", b"FR-49", b"FR-50", b"FR-51", b"FR-52", b"FR-53", b"FR-54", b"FR-55", b"FR-56",
+	b"FR-57", b"FR-58", b"FR-59", b"FR-60", b"FR-61", b"FR-62", b"FR-63", b"FR-64", b"FR-65",
+	b"FR-66", b"FR-67", b"FR-68", b"FR-69", b"FR-70", b"FR-71", b"FR-72", b"FR-73", b"FR-74",
+	b"FR-75", b"FR-76", b"FR-77", b"FR-78", b"FR-79", b"FR-80", b"FR-81", b"FR-82", b"FR-83",
+	b"FR-84", b"FR-85", b"FR-86", b"FR-87", b"FR-88", b"FR-89", b"FR-90", b"FR-91", b"FR-92",
+	b"FR-93", b"FR-94", b"FR-95", b"FR-971", b"FR-972", b"FR-973", b"FR-974", b"FR-976", b"FR-ARA",
+	b"FR-BFC", b"FR-BL", b"FR-BRE", b"FR-CP", b"FR-CVL", b"FR-GES", b"FR-GF", b"FR-GP", b"FR-HDF",
+	b"FR-IDF", b"FR-MF", b"FR-MQ", b"FR-NAQ", b"FR-NC", b"FR-NOR", b"FR-OCC", b"FR-PAC", b"FR-PDL",
+	b"FR-PF", b"FR-PM", b"FR-RE", b"FR-TF", b"FR-WF", b"FR-YT", b"GA-1", b"GA-2", b"GA-3", b"GA-4",
+	b"GA-5", b"GA-6", b"GA-7", b"GA-8", b"GA-9", b"GB-ABC", b"GB-ABD", b"GB-ABE", b"GB-AGB",
+	b"GB-AGY", b"GB-AND", b"GB-ANN", b"GB-ANS", b"GB-BAS", b"GB-BBD", b"GB-BCP", b"GB-BDF",
+	b"GB-BDG", b"GB-BEN", b"GB-BEX", b"GB-BFS", b"GB-BGE", b"GB-BGW", b"GB-BIR", b"GB-BKM",
+	b"GB-BNE", b"GB-BNH", b"GB-BNS", b"GB-BOL", b"GB-BPL", b"GB-BRC", b"GB-BRD", b"GB-BRY",
+	b"GB-BST", b"GB-BUR", b"GB-CAM", b"GB-CAY", b"GB-CBF", b"GB-CCG", b"GB-CGN", b"GB-CHE",
+	b"GB-CHW", b"GB-CLD", b"GB-CLK", b"GB-CMA", b"GB-CMD", b"GB-CMN", b"GB-CON", b"GB-COV",
+	b"GB-CRF", b"GB-CRY", b"GB-CWY", b"GB-DAL", b"GB-DBY", b"GB-DEN", b"GB-DER", b"GB-DEV",
+	b"GB-DGY", b"GB-DNC", b"GB-DND", b"GB-DOR", b"GB-DRS", b"GB-DUD", b"GB-DUR", b"GB-EAL",
+	b"GB-EAY", b"GB-EDH", b"GB-EDU", b"GB-ELN", b"GB-ELS", b"GB-ENF", b"GB-ENG", b"GB-ERW",
+	b"GB-ERY", b"GB-ESS", b"GB-ESX", b"GB-FAL", b"GB-FIF", b"GB-FLN", b"GB-FMO", b"GB-GAT",
+	b"GB-GLG", b"GB-GLS", b"GB-GRE", b"GB-GWN", b"GB-HAL", b"GB-HAM", b"GB-HAV", b"GB-HCK",
+	b"GB-HEF", b"GB-HIL", b"GB-HLD", b"GB-HMF", b"GB-HNS", b"GB-HPL", b"GB-HRT", b"GB-HRW",
+	b"GB-HRY", b"GB-IOS", b"GB-IOW", b"GB-ISL", b"GB-IVC", b"GB-KEC", b"GB-KEN", b"GB-KHL",
+	b"GB-KIR", b"GB-KTT", b"GB-KWL", b"GB-LAN", b"GB-LBC", b"GB-LBH", b"GB-LCE", b"GB-LDS",
+	b"GB-LEC", b"GB-LEW", b"GB-LIN", b"GB-LIV", b"GB-LND", b"GB-LUT", b"GB-MAN", b"GB-MDB",
+	b"GB-MDW", b"GB-MEA", b"GB-MIK", b"GB-MLN", b"GB-MON", b"GB-MRT", b"GB-MRY", b"GB-MTY",
+	b"GB-MUL", b"GB-NAY", b"GB-NBL", b"GB-NEL", b"GB-NET", b"GB-NFK", b"GB-NGM", b"GB-NIR",
+	b"GB-NLK", b"GB-NLN", b"GB-NMD", b"GB-NSM", b"GB-NTH", b"GB-NTL", b"GB-NTT", b"GB-NTY",
+	b"GB-NWM", b"GB-NWP", b"GB-NYK", b"GB-OLD", b"GB-ORK", b"GB-OXF", b"GB-PEM", b"GB-PKN",
+	b"GB-PLY", b"GB-POR", b"GB-POW", b"GB-PTE", b"GB-RCC", b"GB-RCH", b"GB-RCT", b"GB-RDB",
+	b"GB-RDG", b"GB-RFW", b"GB-RIC", b"GB-ROT", b"GB-RUT", b"GB-SAW", b"GB-SAY", b"GB-SCB",
+	b"GB-SCT", b"GB-SFK", b"GB-SFT", b"GB-SGC", b"GB-SHF", b"GB-SHN", b"GB-SHR", b"GB-SKP",
+	b"GB-SLF", b"GB-SLG", b"GB-SLK", b"GB-SND", b"GB-SOL", b"GB-SOM", b"GB-SOS", b"GB-SRY",
+	b"GB-STE", b"GB-STG", b"GB-STH", b"GB-STN", b"GB-STS", b"GB-STT", b"GB-STY", b"GB-SWA",
+	b"GB-SWD", b"GB-SWK", b"GB-TAM", b"GB-TFW", b"GB-THR", b"GB-TOB", b"GB-TOF", b"GB-TRF",
+	b"GB-TWH", b"GB-VGL", b"GB-WAR", b"GB-WBK", b"GB-WDU", b"GB-WFT", b"GB-WGN", b"GB-WIL",
+	b"GB-WKF", b"GB-WLL", b"GB-WLN", b"GB-WLS", b"GB-WLV", b"GB-WND", b"GB-WNM", b"GB-WOK",
+	b"GB-WOR", b"GB-WRL", b"GB-WRT", b"GB-WRX", b"GB-WSM", b"GB-WSX", b"GB-YOR", b"GB-ZET",
+	b"GD-01", b"GD-02", b"GD-03", b"GD-04", b"GD-05", b"GD-06", b"GD-10", b"GE-AB", b"GE-AJ",
+	b"GE-GU", b"GE-IM", b"GE-KA", b"GE-KK", b"GE-MM", b"GE-RL", b"GE-SJ", b"GE-SK", b"GE-SZ",
+	b"GE-TB", b"GH-AA", b"GH-AF", b"GH-AH", b"GH-BE", b"GH-BO", b"GH-CP", b"GH-EP", b"GH-NE",
+	b"GH-NP", b"GH-OT", b"GH-SV", b"GH-TV", b"GH-UE", b"GH-UW", b"GH-WN", b"GH-WP", b"GL-AV",
+	b"GL-KU", b"GL-QE", b"GL-QT", b"GL-SM", b"GM-B", b"GM-L", b"GM-M", b"GM-N", b"GM-U", b"GM-W",
+	b"GN-B", b"GN-BE", b"GN-BF", b"GN-BK", b"GN-C", b"GN-CO", b"GN-D", b"GN-DB", b"GN-DI",
+	b"GN-DL", b"GN-DU", b"GN-F", b"GN-FA", b"GN-FO", b"GN-FR", b"GN-GA", b"GN-GU", b"GN-K",
+	b"GN-KA", b"GN-KB", b"GN-KD", b"GN-KE", b"GN-KN", b"GN-KO", b"GN-KS", b"GN-L", b"GN-LA",
+	b"GN-LE", b"GN-LO", b"GN-M", b"GN-MC", b"GN-MD", b"GN-ML", b"GN-MM", b"GN-N", b"GN-NZ",
+	b"GN-PI", b"GN-SI", b"GN-TE", b"GN-TO", b"GN-YO", b"GQ-AN", b"GQ-BN", b"GQ-BS", b"GQ-C",
+	b"GQ-CS", b"GQ-DJ", b"GQ-I", b"GQ-KN", b"GQ-LI", b"GQ-WN", b"GR-69", b"GR-A", b"GR-B", b"GR-C",
+	b"GR-D", b"GR-E", b"GR-F", b"GR-G", b"GR-H", b"GR-I", b"GR-J", b"GR-K", b"GR-L", b"GR-M",
+	b"GT-AV", b"GT-BV", b"GT-CM", b"GT-CQ", b"GT-ES", b"GT-GU", b"GT-HU", b"GT-IZ", b"GT-JA",
+	b"GT-JU", b"GT-PE", b"GT-PR", b"GT-QC", b"GT-QZ", b"GT-RE", b"GT-SA", b"GT-SM", b"GT-SO",
+	b"GT-SR", b"GT-SU", b"GT-TO", b"GT-ZA", b"GW-BA", b"GW-BL", b"GW-BM", b"GW-BS", b"GW-CA",
+	b"GW-GA", b"GW-L", b"GW-N", b"GW-OI", b"GW-QU", b"GW-S", b"GW-TO", b"GY-BA", b"GY-CU",
+	b"GY-DE", b"GY-EB", b"GY-ES", b"GY-MA", b"GY-PM", b"GY-PT", b"GY-UD", b"GY-UT", b"HN-AT",
+	b"HN-CH", b"HN-CL", b"HN-CM", b"HN-CP", b"HN-CR", b"HN-EP", b"HN-FM", b"HN-GD", b"HN-IB",
+	b"HN-IN", b"HN-LE", b"HN-LP", b"HN-OC", b"HN-OL", b"HN-SB", b"HN-VA", b"HN-YO", b"HR-01",
+	b"HR-02", b"HR-03", b"HR-04", b"HR-05", b"HR-06", b"HR-07", b"HR-08", b"HR-09", b"HR-10",
+	b"HR-11", b"HR-12", b"HR-13", b"HR-14", b"HR-15", b"HR-16", b"HR-17", b"HR-18", b"HR-19",
+	b"HR-20", b"HR-21", b"HT-AR", b"HT-CE", b"HT-GA", b"HT-ND", b"HT-NE", b"HT-NI", b"HT-NO",
+	b"HT-OU", b"HT-SD", b"HT-SE", b"HU-BA", b"HU-BC", b"HU-BE", b"HU-BK", b"HU-BU", b"HU-BZ",
+	b"HU-CS", b"HU-DE", b"HU-DU", b"HU-EG", b"HU-ER", b"HU-FE", b"HU-GS", b"HU-GY", b"HU-HB",
+	b"HU-HE", b"HU-HV", b"HU-JN", b"HU-KE", b"HU-KM", b"HU-KV", b"HU-MI", b"HU-NK", b"HU-NO",
+	b"HU-NY", b"HU-PE", b"HU-PS", b"HU-SD", b"HU-SF", b"HU-SH", b"HU-SK", b"HU-SN", b"HU-SO",
+	b"HU-SS", b"HU-ST", b"HU-SZ", b"HU-TB", b"HU-TO", b"HU-VA", b"HU-VE", b"HU-VM", b"HU-ZA",
+	b"HU-ZE", b"ID-AC", b"ID-BA", b"ID-BB", b"ID-BE", b"ID-BT", b"ID-GO", b"ID-JA", b"ID-JB",
+	b"ID-JI", b"ID-JK", b"ID-JT", b"ID-JW", b"ID-KA", b"ID-KB", b"ID-KI", b"ID-KR", b"ID-KS",
+	b"ID-KT", b"ID-KU", b"ID-LA", b"ID-MA", b"ID-ML", b"ID-MU", b"ID-NB", b"ID-NT", b"ID-NU",
+	b"ID-PA", b"ID-PB", b"ID-PP", b"ID-RI", b"ID-SA", b"ID-SB", b"ID-SG", b"ID-SL", b"ID-SM",
+	b"ID-SN", b"ID-SR", b"ID-SS", b"ID-ST", b"ID-SU", b"ID-YO", b"IE-C", b"IE-CE", b"IE-CN",
+	b"IE-CO", b"IE-CW", b"IE-D", b"IE-DL", b"IE-G", b"IE-KE", b"IE-KK", b"IE-KY", b"IE-L",
+	b"IE-LD", b"IE-LH", b"IE-LK", b"IE-LM", b"IE-LS", b"IE-M", b"IE-MH", b"IE-MN", b"IE-MO",
+	b"IE-OY", b"IE-RN", b"IE-SO", b"IE-TA", b"IE-U", b"IE-WD", b"IE-WH", b"IE-WW", b"IE-WX",
+	b"IL-D", b"IL-HA", b"IL-JM", b"IL-M", b"IL-TA", b"IL-Z", b"IN-AN", b"IN-AP", b"IN-AR",
+	b"IN-AS", b"IN-BR", b"IN-CH", b"IN-CT", b"IN-DH", b"IN-DL", b"IN-GA", b"IN-GJ", b"IN-HP",
+	b"IN-HR", b"IN-JH", b"IN-JK", b"IN-KA", b"IN-KL", b"IN-LA", b"IN-LD", b"IN-MH", b"IN-ML",
+	b"IN-MN", b"IN-MP", b"IN-MZ", b"IN-NL", b"IN-OR", b"IN-PB", b"IN-PY", b"IN-RJ", b"IN-SK",
+	b"IN-TG", b"IN-TN", b"IN-TR", b"IN-UP", b"IN-UT", b"IN-WB", b"IQ-AN", b"IQ-AR", b"IQ-BA",
+	b"IQ-BB", b"IQ-BG", b"IQ-DA", b"IQ-DI", b"IQ-DQ", b"IQ-KA", b"IQ-KI", b"IQ-MA", b"IQ-MU",
+	b"IQ-NA", b"IQ-NI", b"IQ-QA", b"IQ-SD", b"IQ-SU", b"IQ-WA", b"IR-00", b"IR-01", b"IR-02",
+	b"IR-03", b"IR-04", b"IR-05", b"IR-06", b"IR-07", b"IR-08", b"IR-09", b"IR-10", b"IR-11",
+	b"IR-12", b"IR-13", b"IR-14", b"IR-15", b"IR-16", b"IR-17", b"IR-18", b"IR-19", b"IR-20",
+	b"IR-21", b"IR-22", b"IR-23", b"IR-24", b"IR-25", b"IR-26", b"IR-27", b"IR-28", b"IR-29",
+	b"IR-30", b"IS-1", b"IS-2", b"IS-3", b"IS-4", b"IS-5", b"IS-6", b"IS-7", b"IS-8", b"IS-AKH",
+	b"IS-AKN", b"IS-AKU", b"IS-ARN", b"IS-ASA", b"IS-BFJ", b"IS-BLA", b"IS-BLO", b"IS-BOG",
+	b"IS-BOL", b"IS-DAB", b"IS-DAV", b"IS-DJU", b"IS-EOM", b"IS-EYF", b"IS-FJD", b"IS-FJL",
+	b"IS-FLA", b"IS-FLD", b"IS-FLR", b"IS-GAR", b"IS-GOG", b"IS-GRN", b"IS-GRU", b"IS-GRY",
+	b"IS-HAF", b"IS-HEL", b"IS-HRG", b"IS-HRU", b"IS-HUT", b"IS-HUV", b"IS-HVA", b"IS-HVE",
+	b"IS-ISA", b"IS-KAL", b"IS-KJO", b"IS-KOP", b"IS-LAN", b"IS-MOS", b"IS-MYR", b"IS-NOR",
+	b"IS-RGE", b"IS-RGY", b"IS-RHH", b"IS-RKN", b"IS-RKV", b"IS-SBH", b"IS-SBT", b"IS-SDN",
+	b"IS-SDV", b"IS-SEL", b"IS-SEY", b"IS-SFA", b"IS-SHF", b"IS-SKF", b"IS-SKG", b"IS-SKO",
+	b"IS-SKU", b"IS-SNF", b"IS-SOG", b"IS-SOL", b"IS-SSF", b"IS-SSS", b"IS-STR", b"IS-STY",
+	b"IS-SVG", b"IS-TAL", b"IS-THG", b"IS-TJO", b"IS-VEM", b"IS-VER", b"IS-VOP", b"IT-21",
+	b"IT-23", b"IT-25", b"IT-32", b"IT-34", b"IT-36", b"IT-42", b"IT-45", b"IT-52", b"IT-55",
+	b"IT-57", b"IT-62", b"IT-65", b"IT-67", b"IT-72", b"IT-75", b"IT-77", b"IT-78", b"IT-82",
+	b"IT-88", b"IT-AG", b"IT-AL", b"IT-AN", b"IT-AP", b"IT-AQ", b"IT-AR", b"IT-AT", b"IT-AV",
+	b"IT-BA", b"IT-BG", b"IT-BI", b"IT-BL", b"IT-BN", b"IT-BO", b"IT-BR", b"IT-BS", b"IT-BT",
+	b"IT-BZ", b"IT-CA", b"IT-CB", b"IT-CE", b"IT-CH", b"IT-CL", b"IT-CN", b"IT-CO", b"IT-CR",
+	b"IT-CS", b"IT-CT", b"IT-CZ", b"IT-EN", b"IT-FC", b"IT-FE", b"IT-FG", b"IT-FI", b"IT-FM",
+	b"IT-FR", b"IT-GE", b"IT-GO", b"IT-GR", b"IT-IM", b"IT-IS", b"IT-KR", b"IT-LC", b"IT-LE",
+	b"IT-LI", b"IT-LO", b"IT-LT", b"IT-LU", b"IT-MB", b"IT-MC", b"IT-ME", b"IT-MI", b"IT-MN",
+	b"IT-MO", b"IT-MS", b"IT-MT", b"IT-NA", b"IT-NO", b"IT-NU", b"IT-OR", b"IT-PA", b"IT-PC",
+	b"IT-PD", b"IT-PE", b"IT-PG", b"IT-PI", b"IT-PN", b"IT-PO", b"IT-PR", b"IT-PT", b"IT-PU",
+	b"IT-PV", b"IT-PZ", b"IT-RA", b"IT-RC", b"IT-RE", b"IT-RG", b"IT-RI", b"IT-RM", b"IT-RN",
+	b"IT-RO", b"IT-SA", b"IT-SI", b"IT-SO", b"IT-SP", b"IT-SR", b"IT-SS", b"IT-SU", b"IT-SV",
+	b"IT-TA", b"IT-TE", b"IT-TN", b"IT-TO", b"IT-TP", b"IT-TR", b"IT-TS", b"IT-TV", b"IT-UD",
+	b"IT-VA", b"IT-VB", b"IT-VC", b"IT-VE", b"IT-VI", b"IT-VR", b"IT-VT", b"IT-VV", b"JM-01",
+	b"JM-02", b"JM-03", b"JM-04", b"JM-05", b"JM-06", b"JM-07", b"JM-08", b"JM-09", b"JM-10",
+	b"JM-11", b"JM-12", b"JM-13", b"JM-14", b"JO-AJ", b"JO-AM", b"JO-AQ", b"JO-AT", b"JO-AZ",
+	b"JO-BA", b"JO-IR", b"JO-JA", b"JO-KA", b"JO-MA", b"JO-MD", b"JO-MN", b"JP-01", b"JP-02",
+	b"JP-03", b"JP-04", b"JP-05", b"JP-06", b"JP-07", b"JP-08", b"JP-09", b"JP-10", b"JP-11",
+	b"JP-12", b"JP-13", b"JP-14", b"JP-15", b"JP-16", b"JP-17", b"JP-18", b"JP-19", b"JP-20",
+	b"JP-21", b"JP-22", b"JP-23", b"JP-24", b"JP-25", b"JP-26", b"JP-27", b"JP-28", b"JP-29",
+	b"JP-30", b"JP-31", b"JP-32", b"JP-33", b"JP-34", b"JP-35", b"JP-36", b"JP-37", b"JP-38",
+	b"JP-39", b"JP-40", b"JP-41", b"JP-42", b"JP-43", b"JP-44", b"JP-45", b"JP-46", b"JP-47",
+	b"KE-01", b"KE-02", b"KE-03", b"KE-04", b"KE-05", b"KE-06", b"KE-07", b"KE-08", b"KE-09",
+	b"KE-10", b"KE-11", b"KE-12", b"KE-13", b"KE-14", b"KE-15", b"KE-16", b"KE-17", b"KE-18",
+	b"KE-19", b"KE-20", b"KE-21", b"KE-22", b"KE-23", b"KE-24", b"KE-25", b"KE-26", b"KE-27",
+	b"KE-28", b"KE-29", b"KE-30", b"KE-31", b"KE-32", b"KE-33", b"KE-34", b"KE-35", b"KE-36",
+	b"KE-37", b"KE-38", b"KE-39", b"KE-40", b"KE-41", b"KE-42", b"KE-43", b"KE-44", b"KE-45",
+	b"KE-46", b"KE-47", b"KG-B", b"KG-C", b"KG-GB", b"KG-GO", b"KG-J", b"KG-N", b"KG-O", b"KG-T",
+	b"KG-Y", b"KH-1", b"KH-10", b"KH-11", b"KH-12", b"KH-13", b"KH-14", b"KH-15", b"KH-16",
+	b"KH-17", b"KH-18", b"KH-19", b"KH-2", b"KH-20", b"KH-21", b"KH-22", b"KH-23", b"KH-24",
+	b"KH-25", b"KH-3", b"KH-4", b"KH-5", b"KH-6", b"KH-7", b"KH-8", b"KH-9", b"KI-G", b"KI-L",
+	b"KI-P", b"KM-A", b"KM-G", b"KM-M", b"KN-01", b"KN-02", b"KN-03", b"KN-04", b"KN-05", b"KN-06",
+	b"KN-07", b"KN-08", b"KN-09", b"KN-10", b"KN-11", b"KN-12", b"KN-13", b"KN-15", b"KN-K",
+	b"KN-N", b"KP-01", b"KP-02", b"KP-03", b"KP-04", b"KP-05", b"KP-06", b"KP-07", b"KP-08",
+	b"KP-09", b"KP-10", b"KP-13", b"KP-14", b"KR-11", b"KR-26", b"KR-27", b"KR-28", b"KR-29",
+	b"KR-30", b"KR-31", b"KR-41", b"KR-42", b"KR-43", b"KR-44", b"KR-45", b"KR-46", b"KR-47",
+	b"KR-48", b"KR-49", b"KR-50", b"KW-AH", b"KW-FA", b"KW-HA", b"KW-JA", b"KW-KU", b"KW-MU",
+	b"KZ-AKM", b"KZ-AKT", b"KZ-ALA", b"KZ-ALM", b"KZ-AST", b"KZ-ATY", b"KZ-KAR", b"KZ-KUS",
+	b"KZ-KZY", b"KZ-MAN", b"KZ-PAV", b"KZ-SEV", b"KZ-SHY", b"KZ-VOS", b"KZ-YUZ", b"KZ-ZAP",
+	b"KZ-ZHA", b"LA-AT", b"LA-BK", b"LA-BL", b"LA-CH", b"LA-HO", b"LA-KH", b"LA-LM", b"LA-LP",
+	b"LA-OU", b"LA-PH", b"LA-SL", b"LA-SV", b"LA-VI", b"LA-VT", b"LA-XA", b"LA-XE", b"LA-XI",
+	b"LA-XS", b"LB-AK", b"LB-AS", b"LB-BA", b"LB-BH", b"LB-BI", b"LB-JA", b"LB-JL", b"LB-NA",
+	b"LC-01", b"LC-02", b"LC-03", b"LC-05", b"LC-06", b"LC-07", b"LC-08", b"LC-10", b"LC-11",
+	b"LC-12", b"LI-01", b"LI-02", b"LI-03", b"LI-04", b"LI-05", b"LI-06", b"LI-07", b"LI-08",
+	b"LI-09", b"LI-10", b"LI-11", b"LK-1", b"LK-11", b"LK-12", b"LK-13", b"LK-2", b"LK-21",
+	b"LK-22", b"LK-23", b"LK-3", b"LK-31", b"LK-32", b"LK-33", b"LK-4", b"LK-41", b"LK-42",
+	b"LK-43", b"LK-44", b"LK-45", b"LK-5", b"LK-51", b"LK-52", b"LK-53", b"LK-6", b"LK-61",
+	b"LK-62", b"LK-7", b"LK-71", b"LK-72", b"LK-8", b"LK-81", b"LK-82", b"LK-9", b"LK-91",
+	b"LK-92", b"LR-BG", b"LR-BM", b"LR-CM", b"LR-GB", b"LR-GG", b"LR-GK", b"LR-GP", b"LR-LO",
+	b"LR-MG", b"LR-MO", b"LR-MY", b"LR-NI", b"LR-RG", b"LR-RI", b"LR-SI", b"LS-A", b"LS-B",
+	b"LS-C", b"LS-D", b"LS-E", b"LS-F", b"LS-G", b"LS-H", b"LS-J", b"LS-K", b"LT-01", b"LT-02",
+	b"LT-03", b"LT-04", b"LT-05", b"LT-06", b"LT-07", b"LT-08", b"LT-09", b"LT-10", b"LT-11",
+	b"LT-12", b"LT-13", b"LT-14", b"LT-15", b"LT-16", b"LT-17", b"LT-18", b"LT-19", b"LT-20",
+	b"LT-21", b"LT-22", b"LT-23", b"LT-24", b"LT-25", b"LT-26", b"LT-27", b"LT-28", b"LT-29",
+	b"LT-30", b"LT-31", b"LT-32", b"LT-33", b"LT-34", b"LT-35", b"LT-36", b"LT-37", b"LT-38",
+	b"LT-39", b"LT-40", b"LT-41", b"LT-42", b"LT-43", b"LT-44", b"LT-45", b"LT-46", b"LT-47",
+	b"LT-48", b"LT-49", b"LT-50", b"LT-51", b"LT-52", b"LT-53", b"LT-54", b"LT-55", b"LT-56",
+	b"LT-57", b"LT-58", b"LT-59", b"LT-60", b"LT-AL", b"LT-KL", b"LT-KU", b"LT-MR", b"LT-PN",
+	b"LT-SA", b"LT-TA", b"LT-TE", b"LT-UT", b"LT-VL", b"LU-CA", b"LU-CL", b"LU-DI", b"LU-EC",
+	b"LU-ES", b"LU-GR", b"LU-LU", b"LU-ME", b"LU-RD", b"LU-RM", b"LU-VD", b"LU-WI", b"LV-001",
+	b"LV-002", b"LV-003", b"LV-004", b"LV-005", b"LV-006", b"LV-007", b"LV-008", b"LV-009",
+	b"LV-010", b"LV-011", b"LV-012", b"LV-013", b"LV-014", b"LV-015", b"LV-016", b"LV-017",
+	b"LV-018", b"LV-019", b"LV-020", b"LV-021", b"LV-022", b"LV-023", b"LV-024", b"LV-025",
+	b"LV-026", b"LV-027", b"LV-028", b"LV-029", b"LV-030", b"LV-031", b"LV-032", b"LV-033",
+	b"LV-034", b"LV-035", b"LV-036", b"LV-037", b"LV-038", b"LV-039", b"LV-040", b"LV-041",
+	b"LV-042", b"LV-043", b"LV-044", b"LV-045", b"LV-046", b"LV-047", b"LV-048", b"LV-049",
+	b"LV-050", b"LV-051", b"LV-052", b"LV-053", b"LV-054", b"LV-055", b"LV-056", b"LV-057",
+	b"LV-058", b"LV-059", b"LV-060", b"LV-061", b"LV-062", b"LV-063", b"LV-064", b"LV-065",
+	b"LV-066", b"LV-067", b"LV-068", b"LV-069", b"LV-070", b"LV-071", b"LV-072", b"LV-073",
+	b"LV-074", b"LV-075", b"LV-076", b"LV-077", b"LV-078", b"LV-079", b"LV-080", b"LV-081",
+	b"LV-082", b"LV-083", b"LV-084", b"LV-085", b"LV-086", b"LV-087", b"LV-088", b"LV-089",
+	b"LV-090", b"LV-091", b"LV-092", b"LV-093", b"LV-094", b"LV-095", b"LV-096", b"LV-097",
+	b"LV-098", b"LV-099", b"LV-100", b"LV-101", b"LV-102", b"LV-103", b"LV-104", b"LV-105",
+	b"LV-106", b"LV-107", b"LV-108", b"LV-109", b"LV-110", b"LV-DGV", b"LV-JEL", b"LV-JKB",
+	b"LV-JUR", b"LV-LPX", b"LV-REZ", b"LV-RIX", b"LV-VEN", b"LV-VMR", b"LY-BA", b"LY-BU", b"LY-DR",
+	b"LY-GT", b"LY-JA", b"LY-JG", b"LY-JI", b"LY-JU", b"LY-KF", b"LY-MB", b"LY-MI", b"LY-MJ",
+	b"LY-MQ", b"LY-NL", b"LY-NQ", b"LY-SB", b"LY-SR", b"LY-TB", b"LY-WA", b"LY-WD", b"LY-WS",
+	b"LY-ZA", b"MA-01", b"MA-02", b"MA-03", b"MA-04", b"MA-05", b"MA-06", b"MA-07", b"MA-08",
+	b"MA-09", b"MA-10", b"MA-11", b"MA-12", b"MA-AGD", b"MA-AOU", b"MA-ASZ", b"MA-AZI", b"MA-BEM",
+	b"MA-BER", b"MA-BES", b"MA-BOD", b"MA-BOM", b"MA-BRR", b"MA-CAS", b"MA-CHE", b"MA-CHI",
+	b"MA-CHT", b"MA-DRI", b"MA-ERR", b"MA-ESI", b"MA-ESM", b"MA-FAH", b"MA-FES", b"MA-FIG",
+	b"MA-FQH", b"MA-GUE", b"MA-GUF", b"MA-HAJ", b"MA-HAO", b"MA-HOC", b"MA-IFR", b"MA-INE",
+	b"MA-JDI", b"MA-JRA", b"MA-KEN", b"MA-KES", b"MA-KHE", b"MA-KHN", b"MA-KHO", b"MA-LAA",
+	b"MA-LAR", b"MA-MAR", b"MA-MDF", b"MA-MED", b"MA-MEK", b"MA-MID", b"MA-MOH", b"MA-MOU",
+	b"MA-NAD", b"MA-NOU", b"MA-OUA", b"MA-OUD", b"MA-OUJ", b"MA-OUZ", b"MA-RAB", b"MA-REH",
+	b"MA-SAF", b"MA-SAL", b"MA-SEF", b"MA-SET", b"MA-SIB", b"MA-SIF", b"MA-SIK", b"MA-SIL",
+	b"MA-SKH", b"MA-TAF", b"MA-TAI", b"MA-TAO", b"MA-TAR", b"MA-TAT", b"MA-TAZ", b"MA-TET",
+	b"MA-TIN", b"MA-TIZ", b"MA-TNG", b"MA-TNT", b"MA-YUS", b"MA-ZAG", b"MC-CL", b"MC-CO", b"MC-FO",
+	b"MC-GA", b"MC-JE", b"MC-LA", b"MC-MA", b"MC-MC", b"MC-MG", b"MC-MO", b"MC-MU", b"MC-PH",
+	b"MC-SD", b"MC-SO", b"MC-SP", b"MC-SR", b"MC-VR", b"MD-AN", b"MD-BA", b"MD-BD", b"MD-BR",
+	b"MD-BS", b"MD-CA", b"MD-CL", b"MD-CM", b"MD-CR", b"MD-CS", b"MD-CT", b"MD-CU", b"MD-DO",
+	b"MD-DR", b"MD-DU", b"MD-ED", b"MD-FA", b"MD-FL", b"MD-GA", b"MD-GL", b"MD-HI", b"MD-IA",
+	b"MD-LE", b"MD-NI", b"MD-OC", b"MD-OR", b"MD-RE", b"MD-RI", b"MD-SD", b"MD-SI", b"MD-SN",
+	b"MD-SO", b"MD-ST", b"MD-SV", b"MD-TA", b"MD-TE", b"MD-UN", b"ME-01", b"ME-02", b"ME-03",
+	b"ME-04", b"ME-05", b"ME-06", b"ME-07", b"ME-08", b"ME-09", b"ME-10", b"ME-11", b"ME-12",
+	b"ME-13", b"ME-14", b"ME-15", b"ME-16", b"ME-17", b"ME-18", b"ME-19", b"ME-20", b"ME-21",
+	b"ME-22", b"ME-23", b"ME-24", b"MG-A", b"MG-D", b"MG-F", b"MG-M", b"MG-T", b"MG-U", b"MH-ALK",
+	b"MH-ALL", b"MH-ARN", b"MH-AUR", b"MH-EBO", b"MH-ENI", b"MH-JAB", b"MH-JAL", b"MH-KIL",
+	b"MH-KWA", b"MH-L", b"MH-LAE", b"MH-LIB", b"MH-LIK", b"MH-MAJ", b"MH-MAL", b"MH-MEJ",
+	b"MH-MIL", b"MH-NMK", b"MH-NMU", b"MH-RON", b"MH-T", b"MH-UJA", b"MH-UTI", b"MH-WTH",
+	b"MH-WTJ", b"MK-101", b"MK-102", b"MK-103", b"MK-104", b"MK-105", b"MK-106", b"MK-107",
+	b"MK-108", b"MK-109", b"MK-201", b"MK-202", b"MK-203", b"MK-204", b"MK-205", b"MK-206",
+	b"MK-207", b"MK-208", b"MK-209", b"MK-210", b"MK-211", b"MK-301", b"MK-303", b"MK-304",
+	b"MK-307", b"MK-308", b"MK-310", b"MK-311", b"MK-312", b"MK-313", b"MK-401", b"MK-402",
+	b"MK-403", b"MK-404", b"MK-405", b"MK-406", b"MK-407", b"MK-408", b"MK-409", b"MK-410",
+	b"MK-501", b"MK-502", b"MK-503", b"MK-504", b"MK-505", b"MK-506", b"MK-507", b"MK-508",
+	b"MK-509", b"MK-601", b"MK-602", b"MK-603", b"MK-604", b"MK-605", b"MK-606", b"MK-607",
+	b"MK-608", b"MK-609", b"MK-701", b"MK-702", b"MK-703", b"MK-704", b"MK-705", b"MK-706",
+	b"MK-801", b"MK-802", b"MK-803", b"MK-804", b"MK-805", b"MK-806", b"MK-807", b"MK-808",
+	b"MK-809", b"MK-810", b"MK-811", b"MK-812", b"MK-813", b"MK-814", b"MK-815", b"MK-816",
+	b"MK-817", b"ML-1", b"ML-10", b"ML-2", b"ML-3", b"ML-4", b"ML-5", b"ML-6", b"ML-7", b"ML-8",
+	b"ML-9", b"ML-BKO", b"MM-01", b"MM-02", b"MM-03", b"MM-04", b"MM-05", b"MM-06", b"MM-07",
+	b"MM-11", b"MM-12", b"MM-13", b"MM-14", b"MM-15", b"MM-16", b"MM-17", b"MM-18", b"MN-035",
+	b"MN-037", b"MN-039", b"MN-041", b"MN-043", b"MN-046", b"MN-047", b"MN-049", b"MN-051",
+	b"MN-053", b"MN-055", b"MN-057", b"MN-059", b"MN-061", b"MN-063", b"MN-064", b"MN-065",
+	b"MN-067", b"MN-069", b"MN-071", b"MN-073", b"MN-1", b"MR-01", b"MR-02", b"MR-03", b"MR-04",
+	b"MR-05", b"MR-06", b"MR-07", b"MR-08", b"MR-09", b"MR-10", b"MR-11", b"MR-12", b"MR-13",
+	b"MR-14", b"MR-15", b"MT-01", b"MT-02", b"MT-03", b"MT-04", b"MT-05", b"MT-06", b"MT-07",
+	b"MT-08", b"MT-09", b"MT-10", b"MT-11", b"MT-12", b"MT-13", b"MT-14", b"MT-15", b"MT-16",
+	b"MT-17", b"MT-18", b"MT-19", b"MT-20", b"MT-21", b"MT-22", b"MT-23", b"MT-24", b"MT-25",
+	b"MT-26", b"MT-27", b"MT-28", b"MT-29", b"MT-30", b"MT-31", b"MT-32", b"MT-33", b"MT-34",
+	b"MT-35", b"MT-36", b"MT-37", b"MT-38", b"MT-39", b"MT-40", b"MT-41", b"MT-42", b"MT-43",
+	b"MT-44", b"MT-45", b"MT-46", b"MT-47", b"MT-48", b"MT-49", b"MT-50", b"MT-51", b"MT-52",
+	b"MT-53", b"MT-54", b"MT-55", b"MT-56", b"MT-57", b"MT-58", b"MT-59", b"MT-60", b"MT-61",
+	b"MT-62", b"MT-63", b"MT-64", b"MT-65", b"MT-66", b"MT-67", b"MT-68", b"MU-AG", b"MU-BL",
+	b"MU-CC", b"MU-FL", b"MU-GP", b"MU-MO", b"MU-PA", b"MU-PL", b"MU-PW", b"MU-RO", b"MU-RR",
+	b"MU-SA", b"MV-00", b"MV-01", b"MV-02", b"MV-03", b"MV-04", b"MV-05", b"MV-07", b"MV-08",
+	b"MV-12", b"MV-13", b"MV-14", b"MV-17", b"MV-20", b"MV-23", b"MV-24", b"MV-25", b"MV-26",
+	b"MV-27", b"MV-28", b"MV-29", b"MV-MLE", b"MW-BA", b"MW-BL", b"MW-C", b"MW-CK", b"MW-CR",
+	b"MW-CT", b"MW-DE", b"MW-DO", b"MW-KR", b"MW-KS", b"MW-LI", b"MW-LK", b"MW-MC", b"MW-MG",
+	b"MW-MH", b"MW-MU", b"MW-MW", b"MW-MZ", b"MW-N", b"MW-NB", b"MW-NE", b"MW-NI", b"MW-NK",
+	b"MW-NS", b"MW-NU", b"MW-PH", b"MW-RU", b"MW-S", b"MW-SA", b"MW-TH", b"MW-ZO", b"MX-AGU",
+	b"MX-BCN", b"MX-BCS", b"MX-CAM", b"MX-CHH", b"MX-CHP", b"MX-CMX", b"MX-COA", b"MX-COL",
+	b"MX-DUR", b"MX-GRO", b"MX-GUA", b"MX-HID", b"MX-JAL", b"MX-MEX", b"MX-MIC", b"MX-MOR",
+	b"MX-NAY", b"MX-NLE", b"MX-OAX", b"MX-PUE", b"MX-QUE", b"MX-ROO", b"MX-SIN", b"MX-SLP",
+	b"MX-SON", b"MX-TAB", b"MX-TAM", b"MX-TLA", b"MX-VER", b"MX-YUC", b"MX-ZAC", b"MY-01",
+	b"MY-02", b"MY-03", b"MY-04", b"MY-05", b"MY-06", b"MY-07", b"MY-08", b"MY-09", b"MY-10",
+	b"MY-11", b"MY-12", b"MY-13", b"MY-14", b"MY-15", b"MY-16", b"MZ-A", b"MZ-B", b"MZ-G", b"MZ-I",
+	b"MZ-L", b"MZ-MPM", b"MZ-N", b"MZ-P", b"MZ-Q", b"MZ-S", b"MZ-T", b"NA-CA", b"NA-ER", b"NA-HA",
+	b"NA-KA", b"NA-KE", b"NA-KH", b"NA-KU", b"NA-KW", b"NA-OD", b"NA-OH", b"NA-ON", b"NA-OS",
+	b"NA-OT", b"NA-OW", b"NE-1", b"NE-2", b"NE-3", b"NE-4", b"NE-5", b"NE-6", b"NE-7", b"NE-8",
+	b"NG-AB", b"NG-AD", b"NG-AK", b"NG-AN", b"NG-BA", b"NG-BE", b"NG-BO", b"NG-BY", b"NG-CR",
+	b"NG-DE", b"NG-EB", b"NG-ED", b"NG-EK", b"NG-EN", b"NG-FC", b"NG-GO", b"NG-IM", b"NG-JI",
+	b"NG-KD", b"NG-KE", b"NG-KN", b"NG-KO", b"NG-KT", b"NG-KW", b"NG-LA", b"NG-NA", b"NG-NI",
+	b"NG-OG", b"NG-ON", b"NG-OS", b"NG-OY", b"NG-PL", b"NG-RI", b"NG-SO", b"NG-TA", b"NG-YO",
+	b"NG-ZA", b"NI-AN", b"NI-AS", b"NI-BO", b"NI-CA", b"NI-CI", b"NI-CO", b"NI-ES", b"NI-GR",
+	b"NI-JI", b"NI-LE", b"NI-MD", b"NI-MN", b"NI-MS", b"NI-MT", b"NI-NS", b"NI-RI", b"NI-SJ",
+	b"NL-AW", b"NL-BQ1", b"NL-BQ2", b"NL-BQ3", b"NL-CW", b"NL-DR", b"NL-FL", b"NL-FR", b"NL-GE",
+	b"NL-GR", b"NL-LI", b"NL-NB", b"NL-NH", b"NL-OV", b"NL-SX", b"NL-UT", b"NL-ZE", b"NL-ZH",
+	b"NO-03", b"NO-11", b"NO-15", b"NO-18", b"NO-21", b"NO-22", b"NO-30", b"NO-34", b"NO-38",
+	b"NO-42", b"NO-46", b"NO-50", b"NO-54", b"NP-1", b"NP-2", b"NP-3", b"NP-4", b"NP-5", b"NP-BA",
+	b"NP-BH", b"NP-DH", b"NP-GA", b"NP-JA", b"NP-KA", b"NP-KO", b"NP-LU", b"NP-MA", b"NP-ME",
+	b"NP-NA", b"NP-P1", b"NP-P2", b"NP-P3", b"NP-P4", b"NP-P5", b"NP-P6", b"NP-P7", b"NP-RA",
+	b"NP-SA", b"NP-SE", b"NR-01", b"NR-02", b"NR-03", b"NR-04", b"NR-05", b"NR-06", b"NR-07",
+	b"NR-08", b"NR-09", b"NR-10", b"NR-11", b"NR-12", b"NR-13", b"NR-14", b"NZ-AUK", b"NZ-BOP",
+	b"NZ-CAN", b"NZ-CIT", b"NZ-GIS", b"NZ-HKB", b"NZ-MBH", b"NZ-MWT", b"NZ-NSN", b"NZ-NTL",
+	b"NZ-OTA", b"NZ-STL", b"NZ-TAS", b"NZ-TKI", b"NZ-WGN", b"NZ-WKO", b"NZ-WTC", b"OM-BJ",
+	b"OM-BS", b"OM-BU", b"OM-DA", b"OM-MA", b"OM-MU", b"OM-SJ", b"OM-SS", b"OM-WU", b"OM-ZA",
+	b"OM-ZU", b"PA-1", b"PA-10", b"PA-2", b"PA-3", b"PA-4", b"PA-5", b"PA-6", b"PA-7", b"PA-8",
+	b"PA-9", b"PA-EM", b"PA-KY", b"PA-NB", b"PE-AMA", b"PE-ANC", b"PE-APU", b"PE-ARE", b"PE-AYA",
+	b"PE-CAJ", b"PE-CAL", b"PE-CUS", b"PE-HUC", b"PE-HUV", b"PE-ICA", b"PE-JUN", b"PE-LAL",
+	b"PE-LAM", b"PE-LIM", b"PE-LMA", b"PE-LOR", b"PE-MDD", b"PE-MOQ", b"PE-PAS", b"PE-PIU",
+	b"PE-PUN", b"PE-SAM", b"PE-TAC", b"PE-TUM", b"PE-UCA", b"PG-CPK", b"PG-CPM", b"PG-EBR",
+	b"PG-EHG", b"PG-EPW", b"PG-ESW", b"PG-GPK", b"PG-HLA", b"PG-JWK", b"PG-MBA", b"PG-MPL",
+	b"PG-MPM", b"PG-MRL", b"PG-NCD", b"PG-NIK", b"PG-NPP", b"PG-NSB", b"PG-SAN", b"PG-SHM",
+	b"PG-WBK", b"PG-WHM", b"PG-WPD", b"PH-00", b"PH-01", b"PH-02", b"PH-03", b"PH-05", b"PH-06",
+	b"PH-07", b"PH-08", b"PH-09", b"PH-10", b"PH-11", b"PH-12", b"PH-13", b"PH-14", b"PH-15",
+	b"PH-40", b"PH-41", b"PH-ABR", b"PH-AGN", b"PH-AGS", b"PH-AKL", b"PH-ALB", b"PH-ANT",
+	b"PH-APA", b"PH-AUR", b"PH-BAN", b"PH-BAS", b"PH-BEN", b"PH-BIL", b"PH-BOH", b"PH-BTG",
+	b"PH-BTN", b"PH-BUK", b"PH-BUL", b"PH-CAG", b"PH-CAM", b"PH-CAN", b"PH-CAP", b"PH-CAS",
+	b"PH-CAT", b"PH-CAV", b"PH-CEB", b"PH-COM", b"PH-DAO", b"PH-DAS", b"PH-DAV", b"PH-DIN",
+	b"PH-DVO", b"PH-EAS", b"PH-GUI", b"PH-IFU", b"PH-ILI", b"PH-ILN", b"PH-ILS", b"PH-ISA",
+	b"PH-KAL", b"PH-LAG", b"PH-LAN", b"PH-LAS", b"PH-LEY", b"PH-LUN", b"PH-MAD", b"PH-MAG",
+	b"PH-MAS", b"PH-MDC", b"PH-MDR", b"PH-MOU", b"PH-MSC", b"PH-MSR", b"PH-NCO", b"PH-NEC",
+	b"PH-NER", b"PH-NSA", b"PH-NUE", b"PH-NUV", b"PH-PAM", b"PH-PAN", b"PH-PLW", b"PH-QUE",
+	b"PH-QUI", b"PH-RIZ", b"PH-ROM", b"PH-SAR", b"PH-SCO", b"PH-SIG", b"PH-SLE", b"PH-SLU",
+	b"PH-SOR", b"PH-SUK", b"PH-SUN", b"PH-SUR", b"PH-TAR", b"PH-TAW", b"PH-WSA", b"PH-ZAN",
+	b"PH-ZAS", b"PH-ZMB", b"PH-ZSI", b"PK-BA", b"PK-GB", b"PK-IS", b"PK-JK", b"PK-KP", b"PK-PB",
+	b"PK-SD", b"PL-02", b"PL-04", b"PL-06", b"PL-08", b"PL-10", b"PL-12", b"PL-14", b"PL-16",
+	b"PL-18", b"PL-20", b"PL-22", b"PL-24", b"PL-26", b"PL-28", b"PL-30", b"PL-32", b"PS-BTH",
+	b"PS-DEB", b"PS-GZA", b"PS-HBN", b"PS-JEM", b"PS-JEN", b"PS-JRH", b"PS-KYS", b"PS-NBS",
+	b"PS-NGZ", b"PS-QQA", b"PS-RBH", b"PS-RFH", b"PS-SLT", b"PS-TBS", b"PS-TKM", b"PT-01",
+	b"PT-02", b"PT-03", b"PT-04", b"PT-05", b"PT-06", b"PT-07", b"PT-08", b"PT-09", b"PT-10",
+	b"PT-11", b"PT-12", b"PT-13", b"PT-14", b"PT-15", b"PT-16", b"PT-17", b"PT-18", b"PT-20",
+	b"PT-30", b"PW-002", b"PW-004", b"PW-010", b"PW-050", b"PW-100", b"PW-150", b"PW-212",
+	b"PW-214", b"PW-218", b"PW-222", b"PW-224", b"PW-226", b"PW-227", b"PW-228", b"PW-350",
+	b"PW-370", b"PY-1", b"PY-10", b"PY-11", b"PY-12", b"PY-13", b"PY-14", b"PY-15", b"PY-16",
+	b"PY-19", b"PY-2", b"PY-3", b"PY-4", b"PY-5", b"PY-6", b"PY-7", b"PY-8", b"PY-9", b"PY-ASU",
+	b"QA-DA", b"QA-KH", b"QA-MS", b"QA-RA", b"QA-SH", b"QA-US", b"QA-WA", b"QA-ZA", b"RO-AB",
+	b"RO-AG", b"RO-AR", b"RO-B", b"RO-BC", b"RO-BH", b"RO-BN", b"RO-BR", b"RO-BT", b"RO-BV",
+	b"RO-BZ", b"RO-CJ", b"RO-CL", b"RO-CS", b"RO-CT", b"RO-CV", b"RO-DB", b"RO-DJ", b"RO-GJ",
+	b"RO-GL", b"RO-GR", b"RO-HD", b"RO-HR", b"RO-IF", b"RO-IL", b"RO-IS", b"RO-MH", b"RO-MM",
+	b"RO-MS", b"RO-NT", b"RO-OT", b"RO-PH", b"RO-SB", b"RO-SJ", b"RO-SM", b"RO-SV", b"RO-TL",
+	b"RO-TM", b"RO-TR", b"RO-VL", b"RO-VN", b"RO-VS", b"RS-00", b"RS-01", b"RS-02", b"RS-03",
+	b"RS-04", b"RS-05", b"RS-06", b"RS-07", b"RS-08", b"RS-09", b"RS-10", b"RS-11", b"RS-12",
+	b"RS-13", b"RS-14", b"RS-15", b"RS-16", b"RS-17", b"RS-18", b"RS-19", b"RS-20", b"RS-21",
+	b"RS-22", b"RS-23", b"RS-24", b"RS-25", b"RS-26", b"RS-27", b"RS-28", b"RS-29", b"RS-KM",
+	b"RS-VO", b"RU-AD", b"RU-AL", b"RU-ALT", b"RU-AMU", b"RU-ARK", b"RU-AST", b"RU-BA", b"RU-BEL",
+	b"RU-BRY", b"RU-BU", b"RU-CE", b"RU-CHE", b"RU-CHU", b"RU-CU", b"RU-DA", b"RU-IN", b"RU-IRK",
+	b"RU-IVA", b"RU-KAM", b"RU-KB", b"RU-KC", b"RU-KDA", b"RU-KEM", b"RU-KGD", b"RU-KGN",
+	b"RU-KHA", b"RU-KHM", b"RU-KIR", b"RU-KK", b"RU-KL", b"RU-KLU", b"RU-KO", b"RU-KOS", b"RU-KR",
+	b"RU-KRS", b"RU-KYA", b"RU-LEN", b"RU-LIP", b"RU-MAG", b"RU-ME", b"RU-MO", b"RU-MOS",
+	b"RU-MOW", b"RU-MUR", b"RU-NEN", b"RU-NGR", b"RU-NIZ", b"RU-NVS", b"RU-OMS", b"RU-ORE",
+	b"RU-ORL", b"RU-PER", b"RU-PNZ", b"RU-PRI", b"RU-PSK", b"RU-ROS", b"RU-RYA", b"RU-SA",
+	b"RU-SAK", b"RU-SAM", b"RU-SAR", b"RU-SE", b"RU-SMO", b"RU-SPE", b"RU-STA", b"RU-SVE",
+	b"RU-TA", b"RU-TAM", b"RU-TOM", b"RU-TUL", b"RU-TVE", b"RU-TY", b"RU-TYU", b"RU-UD", b"RU-ULY",
+	b"RU-VGG", b"RU-VLA", b"RU-VLG", b"RU-VOR", b"RU-YAN", b"RU-YAR", b"RU-YEV", b"RU-ZAB",
+	b"RW-01", b"RW-02", b"RW-03", b"RW-04", b"RW-05", b"SA-01", b"SA-02", b"SA-03", b"SA-04",
+	b"SA-05", b"SA-06", b"SA-07", b"SA-08", b"SA-09", b"SA-10", b"SA-11", b"SA-12", b"SA-14",
+	b"SB-CE", b"SB-CH", b"SB-CT", b"SB-GU", b"SB-IS", b"SB-MK", b"SB-ML", b"SB-RB", b"SB-TE",
+	b"SB-WE", b"SC-01", b"SC-02", b"SC-03", b"SC-04", b"SC-05", b"SC-06", b"SC-07", b"SC-08",
+	b"SC-09", b"SC-10", b"SC-11", b"SC-12", b"SC-13", b"SC-14", b"SC-15", b"SC-16", b"SC-17",
+	b"SC-18", b"SC-19", b"SC-20", b"SC-21", b"SC-22", b"SC-23", b"SC-24", b"SC-25", b"SC-26",
+	b"SC-27", b"SD-DC", b"SD-DE", b"SD-DN", b"SD-DS", b"SD-DW", b"SD-GD", b"SD-GK", b"SD-GZ",
+	b"SD-KA", b"SD-KH", b"SD-KN", b"SD-KS", b"SD-NB", b"SD-NO", b"SD-NR", b"SD-NW", b"SD-RS",
+	b"SD-SI", b"SE-AB", b"SE-AC", b"SE-BD", b"SE-C", b"SE-D", b"SE-E", b"SE-F", b"SE-G", b"SE-H",
+	b"SE-I", b"SE-K", b"SE-M", b"SE-N", b"SE-O", b"SE-S", b"SE-T", b"SE-U", b"SE-W", b"SE-X",
+	b"SE-Y", b"SE-Z", b"SG-01", b"SG-02", b"SG-03", b"SG-04", b"SG-05", b"SH-AC", b"SH-HL",
+	b"SH-TA", b"SI-001", b"SI-002", b"SI-003", b"SI-004", b"SI-005", b"SI-006", b"SI-007",
+	b"SI-008", b"SI-009", b"SI-010", b"SI-011", b"SI-012", b"SI-013", b"SI-014", b"SI-015",
+	b"SI-016", b"SI-017", b"SI-018", b"SI-019", b"SI-020", b"SI-021", b"SI-022", b"SI-023",
+	b"SI-024", b"SI-025", b"SI-026", b"SI-027", b"SI-028", b"SI-029", b"SI-030", b"SI-031",
+	b"SI-032", b"SI-033", b"SI-034", b"SI-035", b"SI-036", b"SI-037", b"SI-038", b"SI-039",
+	b"SI-040", b"SI-041", b"SI-042", b"SI-043", b"SI-044", b"SI-045", b"SI-046", b"SI-047",
+	b"SI-048", b"SI-049", b"SI-050", b"SI-051", b"SI-052", b"SI-053", b"SI-054", b"SI-055",
+	b"SI-056", b"SI-057", b"SI-058", b"SI-059", b"SI-060", b"SI-061", b"SI-062", b"SI-063",
+	b"SI-064", b"SI-065", b"SI-066", b"SI-067", b"SI-068", b"SI-069", b"SI-070", b"SI-071",
+	b"SI-072", b"SI-073", b"SI-074", b"SI-075", b"SI-076", b"SI-077", b"SI-078", b"SI-079",
+	b"SI-080", b"SI-081", b"SI-082", b"SI-083", b"SI-084", b"SI-085", b"SI-086", b"SI-087",
+	b"SI-088", b"SI-089", b"SI-090", b"SI-091", b"SI-092", b"SI-093", b"SI-094", b"SI-095",
+	b"SI-096", b"SI-097", b"SI-098", b"SI-099", b"SI-100", b"SI-101", b"SI-102", b"SI-103",
+	b"SI-104", b"SI-105", b"SI-106", b"SI-107", b"SI-108", b"SI-109", b"SI-110", b"SI-111",
+	b"SI-112", b"SI-113", b"SI-114", b"SI-115", b"SI-116", b"SI-117", b"SI-118", b"SI-119",
+	b"SI-120", b"SI-121", b"SI-122", b"SI-123", b"SI-124", b"SI-125", b"SI-126", b"SI-127",
+	b"SI-128", b"SI-129", b"SI-130", b"SI-131", b"SI-132", b"SI-133", b"SI-134", b"SI-135",
+	b"SI-136", b"SI-137", b"SI-138", b"SI-139", b"SI-140", b"SI-141", b"SI-142", b"SI-143",
+	b"SI-144", b"SI-146", b"SI-147", b"SI-148", b"SI-149", b"SI-150", b"SI-151", b"SI-152",
+	b"SI-153", b"SI-154", b"SI-155", b"SI-156", b"SI-157", b"SI-158", b"SI-159", b"SI-160",
+	b"SI-161", b"SI-162", b"SI-163", b"SI-164", b"SI-165", b"SI-166", b"SI-167", b"SI-168",
+	b"SI-169", b"SI-170", b"SI-171", b"SI-172", b"SI-173", b"SI-174", b"SI-175", b"SI-176",
+	b"SI-177", b"SI-178", b"SI-179", b"SI-180", b"SI-181", b"SI-182", b"SI-183", b"SI-184",
+	b"SI-185", b"SI-186", b"SI-187", b"SI-188", b"SI-189", b"SI-190", b"SI-191", b"SI-192",
+	b"SI-193", b"SI-194", b"SI-195", b"SI-196", b"SI-197", b"SI-198", b"SI-199", b"SI-200",
+	b"SI-201", b"SI-202", b"SI-203", b"SI-204", b"SI-205", b"SI-206", b"SI-207", b"SI-208",
+	b"SI-209", b"SI-210", b"SI-211", b"SI-212", b"SI-213", b"SK-BC", b"SK-BL", b"SK-KI", b"SK-NI",
+	b"SK-PV", b"SK-TA", b"SK-TC", b"SK-ZI", b"SL-E", b"SL-N", b"SL-NW", b"SL-S", b"SL-W", b"SM-01",
+	b"SM-02", b"SM-03", b"SM-04", b"SM-05", b"SM-06", b"SM-07", b"SM-08", b"SM-09", b"SN-DB",
+	b"SN-DK", b"SN-FK", b"SN-KA", b"SN-KD", b"SN-KE", b"SN-KL", b"SN-LG", b"SN-MT", b"SN-SE",
+	b"SN-SL", b"SN-TC", b"SN-TH", b"SN-ZG", b"SO-AW", b"SO-BK", b"SO-BN", b"SO-BR", b"SO-BY",
+	b"SO-GA", b"SO-GE", b"SO-HI", b"SO-JD", b"SO-JH", b"SO-MU", b"SO-NU", b"SO-SA", b"SO-SD",
+	b"SO-SH", b"SO-SO", b"SO-TO", b"SO-WO", b"SR-BR", b"SR-CM", b"SR-CR", b"SR-MA", b"SR-NI",
+	b"SR-PM", b"SR-PR", b"SR-SA", b"SR-SI", b"SR-WA", b"SS-BN", b"SS-BW", b"SS-EC", b"SS-EE",
+	b"SS-EW", b"SS-JG", b"SS-LK", b"SS-NU", b"SS-UY", b"SS-WR", b"ST-01", b"ST-02", b"ST-03",
+	b"ST-04", b"ST-05", b"ST-06", b"ST-P", b"SV-AH", b"SV-CA", b"SV-CH", b"SV-CU", b"SV-LI",
+	b"SV-MO", b"SV-PA", b"SV-SA", b"SV-SM", b"SV-SO", b"SV-SS", b"SV-SV", b"SV-UN", b"SV-US",
+	b"SY-DI", b"SY-DR", b"SY-DY", b"SY-HA", b"SY-HI", b"SY-HL", b"SY-HM", b"SY-ID", b"SY-LA",
+	b"SY-QU", b"SY-RA", b"SY-RD", b"SY-SU", b"SY-TA", b"SZ-HH", b"SZ-LU", b"SZ-MA", b"SZ-SH",
+	b"TD-BA", b"TD-BG", b"TD-BO", b"TD-CB", b"TD-EE", b"TD-EO", b"TD-GR", b"TD-HL", b"TD-KA",
+	b"TD-LC", b"TD-LO", b"TD-LR", b"TD-MA", b"TD-MC", b"TD-ME", b"TD-MO", b"TD-ND", b"TD-OD",
+	b"TD-SA", b"TD-SI", b"TD-TA", b"TD-TI", b"TD-WF", b"TG-C", b"TG-K", b"TG-M", b"TG-P", b"TG-S",
+	b"TH-10", b"TH-11", b"TH-12", b"TH-13", b"TH-14", b"TH-15", b"TH-16", b"TH-17", b"TH-18",
+	b"TH-19", b"TH-20", b"TH-21", b"TH-22", b"TH-23", b"TH-24", b"TH-25", b"TH-26", b"TH-27",
+	b"TH-30", b"TH-31", b"TH-32", b"TH-33", b"TH-34", b"TH-35", b"TH-36", b"TH-37", b"TH-38",
+	b"TH-39", b"TH-40", b"TH-41", b"TH-42", b"TH-43", b"TH-44", b"TH-45", b"TH-46", b"TH-47",
+	b"TH-48", b"TH-49", b"TH-50", b"TH-51", b"TH-52", b"TH-53", b"TH-54", b"TH-55", b"TH-56",
+	b"TH-57", b"TH-58", b"TH-60", b"TH-61", b"TH-62", b"TH-63", b"TH-64", b"TH-65", b"TH-66",
+	b"TH-67", b"TH-70", b"TH-71", b"TH-72", b"TH-73", b"TH-74", b"TH-75", b"TH-76", b"TH-77",
+	b"TH-80", b"TH-81", b"TH-82", b"TH-83", b"TH-84", b"TH-85", b"TH-86", b"TH-90", b"TH-91",
+	b"TH-92", b"TH-93", b"TH-94", b"TH-95", b"TH-96", b"TH-S", b"TJ-DU", b"TJ-GB", b"TJ-KT",
+	b"TJ-RA", b"TJ-SU", b"TL-AL", b"TL-AN", b"TL-BA", b"TL-BO", b"TL-CO", b"TL-DI", b"TL-ER",
+	b"TL-LA", b"TL-LI", b"TL-MF", b"TL-MT", b"TL-OE", b"TL-VI", b"TM-A", b"TM-B", b"TM-D", b"TM-L",
+	b"TM-M", b"TM-S", b"TN-11", b"TN-12", b"TN-13", b"TN-14", b"TN-21", b"TN-22", b"TN-23",
+	b"TN-31", b"TN-32", b"TN-33", b"TN-34", b"TN-41", b"TN-42", b"TN-43", b"TN-51", b"TN-52",
+	b"TN-53", b"TN-61", b"TN-71", b"TN-72", b"TN-73", b"TN-81", b"TN-82", b"TN-83", b"TO-01",
+	b"TO-02", b"TO-03", b"TO-04", b"TO-05", b"TR-01", b"TR-02", b"TR-03", b"TR-04", b"TR-05",
+	b"TR-06", b"TR-07", b"TR-08", b"TR-09", b"TR-10", b"TR-11", b"TR-12", b"TR-13", b"TR-14",
+	b"TR-15", b"TR-16", b"TR-17", b"TR-18", b"TR-19", b"TR-20", b"TR-21", b"TR-22", b"TR-23",
+	b"TR-24", b"TR-25", b"TR-26", b"TR-27", b"TR-28", b"TR-29", b"TR-30", b"TR-31", b"TR-32",
+	b"TR-33", b"TR-34", b"TR-35", b"TR-36", b"TR-37", b"TR-38", b"TR-39", b"TR-40", b"TR-41",
+	b"TR-42", b"TR-43", b"TR-44", b"TR-45", b"TR-46", b"TR-47", b"TR-48", b"TR-49", b"TR-50",
+	b"TR-51", b"TR-52", b"TR-53", b"TR-54", b"TR-55", b"TR-56", b"TR-57", b"TR-58", b"TR-59",
+	b"TR-60", b"TR-61", b"TR-62", b"TR-63", b"TR-64", b"TR-65", b"TR-66", b"TR-67", b"TR-68",
+	b"TR-69", b"TR-70", b"TR-71", b"TR-72", b"TR-73", b"TR-74", b"TR-75", b"TR-76", b"TR-77",
+	b"TR-78", b"TR-79", b"TR-80", b"TR-81", b"TT-ARI", b"TT-CHA", b"TT-CTT", b"TT-DMN", b"TT-MRC",
+	b"TT-PED", b"TT-POS", b"TT-PRT", b"TT-PTF", b"TT-SFO", b"TT-SGE", b"TT-SIP", b"TT-SJL",
+	b"TT-TOB", b"TT-TUP", b"TV-FUN", b"TV-NIT", b"TV-NKF", b"TV-NKL", b"TV-NMA", b"TV-NMG",
+	b"TV-NUI", b"TV-VAI", b"TW-CHA", b"TW-CYI", b"TW-CYQ", b"TW-HSQ", b"TW-HSZ", b"TW-HUA",
+	b"TW-ILA", b"TW-KEE", b"TW-KHH", b"TW-KIN", b"TW-LIE", b"TW-MIA", b"TW-NAN", b"TW-NWT",
+	b"TW-PEN", b"TW-PIF", b"TW-TAO", b"TW-TNN", b"TW-TPE", b"TW-TTT", b"TW-TXG", b"TW-YUN",
+	b"TZ-01", b"TZ-02", b"TZ-03", b"TZ-04", b"TZ-05", b"TZ-06", b"TZ-07", b"TZ-08", b"TZ-09",
+	b"TZ-10", b"TZ-11", b"TZ-12", b"TZ-13", b"TZ-14", b"TZ-15", b"TZ-16", b"TZ-17", b"TZ-18",
+	b"TZ-19", b"TZ-20", b"TZ-21", b"TZ-22", b"TZ-23", b"TZ-24", b"TZ-25", b"TZ-26", b"TZ-27",
+	b"TZ-28", b"TZ-29", b"TZ-30", b"TZ-31", b"UA-05", b"UA-07", b"UA-09", b"UA-12", b"UA-14",
+	b"UA-18", b"UA-21", b"UA-23", b"UA-26", b"UA-30", b"UA-32", b"UA-35", b"UA-40", b"UA-43",
+	b"UA-46", b"UA-48", b"UA-51", b"UA-53", b"UA-56", b"UA-59", b"UA-61", b"UA-63", b"UA-65",
+	b"UA-68", b"UA-71", b"UA-74", b"UA-77", b"UG-101", b"UG-102", b"UG-103", b"UG-104", b"UG-105",
+	b"UG-106", b"UG-107", b"UG-108", b"UG-109", b"UG-110", b"UG-111", b"UG-112", b"UG-113",
+	b"UG-114", b"UG-115", b"UG-116", b"UG-117", b"UG-118", b"UG-119", b"UG-120", b"UG-121",
+	b"UG-122", b"UG-123", b"UG-124", b"UG-125", b"UG-126", b"UG-201", b"UG-202", b"UG-203",
+	b"UG-204", b"UG-205", b"UG-206", b"UG-207", b"UG-208", b"UG-209", b"UG-210", b"UG-211",
+	b"UG-212", b"UG-213", b"UG-214", b"UG-215", b"UG-216", b"UG-217", b"UG-218", b"UG-219",
+	b"UG-220", b"UG-221", b"UG-222", b"UG-223", b"UG-224", b"UG-225", b"UG-226", b"UG-227",
+	b"UG-228", b"UG-229", b"UG-230", b"UG-231", b"UG-232", b"UG-233", b"UG-234", b"UG-235",
+	b"UG-236", b"UG-237", b"UG-301", b"UG-302", b"UG-303", b"UG-304", b"UG-305", b"UG-306",
+	b"UG-307", b"UG-308", b"UG-309", b"UG-310", b"UG-311", b"UG-312", b"UG-313", b"UG-314",
+	b"UG-315", b"UG-316", b"UG-317", b"UG-318", b"UG-319", b"UG-320", b"UG-321", b"UG-322",
+	b"UG-323", b"UG-324", b"UG-325", b"UG-326", b"UG-327", b"UG-328", b"UG-329", b"UG-330",
+	b"UG-331", b"UG-332", b"UG-333", b"UG-334", b"UG-335", b"UG-336", b"UG-337", b"UG-401",
+	b"UG-402", b"UG-403", b"UG-404", b"UG-405", b"UG-406", b"UG-407", b"UG-408", b"UG-409",
+	b"UG-410", b"UG-411", b"UG-412", b"UG-413", b"UG-414", b"UG-415", b"UG-416", b"UG-417",
+	b"UG-418", b"UG-419", b"UG-420", b"UG-421", b"UG-422", b"UG-423", b"UG-424", b"UG-425",
+	b"UG-426", b"UG-427", b"UG-428", b"UG-429", b"UG-430", b"UG-431", b"UG-432", b"UG-433",
+	b"UG-434", b"UG-435", b"UG-C", b"UG-E", b"UG-N", b"UG-W", b"UM-67", b"UM-71", b"UM-76",
+	b"UM-79", b"UM-81", b"UM-84", b"UM-86", b"UM-89", b"UM-95", b"US-AK", b"US-AL", b"US-AR",
+	b"US-AS", b"US-AZ", b"US-CA", b"US-CO", b"US-CT", b"US-DC", b"US-DE", b"US-FL", b"US-GA",
+	b"US-GU", b"US-HI", b"US-IA", b"US-ID", b"US-IL", b"US-IN", b"US-KS", b"US-KY", b"US-LA",
+	b"US-MA", b"US-MD", b"US-ME", b"US-MI", b"US-MN", b"US-MO", b"US-MP", b"US-MS", b"US-MT",
+	b"US-NC", b"US-ND", b"US-NE", b"US-NH", b"US-NJ", b"US-NM", b"US-NV", b"US-NY", b"US-OH",
+	b"US-OK", b"US-OR", b"US-PA", b"US-PR", b"US-RI", b"US-SC", b"US-SD", b"US-TN", b"US-TX",
+	b"US-UM", b"US-UT", b"US-VA", b"US-VI", b"US-VT", b"US-WA", b"US-WI", b"US-WV", b"US-WY",
+	b"UY-AR", b"UY-CA", b"UY-CL", b"UY-CO", b"UY-DU", b"UY-FD", b"UY-FS", b"UY-LA", b"UY-MA",
+	b"UY-MO", b"UY-PA", b"UY-RN", b"UY-RO", b"UY-RV", b"UY-SA", b"UY-SJ", b"UY-SO", b"UY-TA",
+	b"UY-TT", b"UZ-AN", b"UZ-BU", b"UZ-FA", b"UZ-JI", b"UZ-NG", b"UZ-NW", b"UZ-QA", b"UZ-QR",
+	b"UZ-SA", b"UZ-SI", b"UZ-SU", b"UZ-TK", b"UZ-TO", b"UZ-XO", b"VC-01", b"VC-02", b"VC-03",
+	b"VC-04", b"VC-05", b"VC-06", b"VE-A", b"VE-B", b"VE-C", b"VE-D", b"VE-E", b"VE-F", b"VE-G",
+	b"VE-H", b"VE-I", b"VE-J", b"VE-K", b"VE-L", b"VE-M", b"VE-N", b"VE-O", b"VE-P", b"VE-R",
+	b"VE-S", b"VE-T", b"VE-U", b"VE-V", b"VE-W", b"VE-X", b"VE-Y", b"VE-Z", b"VN-01", b"VN-02",
+	b"VN-03", b"VN-04", b"VN-05", b"VN-06", b"VN-07", b"VN-09", b"VN-13", b"VN-14", b"VN-18",
+	b"VN-20", b"VN-21", b"VN-22", b"VN-23", b"VN-24", b"VN-25", b"VN-26", b"VN-27", b"VN-28",
+	b"VN-29", b"VN-30", b"VN-31", b"VN-32", b"VN-33", b"VN-34", b"VN-35", b"VN-36", b"VN-37",
+	b"VN-39", b"VN-40", b"VN-41", b"VN-43", b"VN-44", b"VN-45", b"VN-46", b"VN-47", b"VN-49",
+	b"VN-50", b"VN-51", b"VN-52", b"VN-53", b"VN-54", b"VN-55", b"VN-56", b"VN-57", b"VN-58",
+	b"VN-59", b"VN-61", b"VN-63", b"VN-66", b"VN-67", b"VN-68", b"VN-69", b"VN-70", b"VN-71",
+	b"VN-72", b"VN-73", b"VN-CT", b"VN-DN", b"VN-HN", b"VN-HP", b"VN-SG", b"VU-MAP", b"VU-PAM",
+	b"VU-SAM", b"VU-SEE", b"VU-TAE", b"VU-TOB", b"WF-AL", b"WF-SG", b"WF-UV", b"WS-AA", b"WS-AL",
+	b"WS-AT", b"WS-FA", b"WS-GE", b"WS-GI", b"WS-PA", b"WS-SA", b"WS-TU", b"WS-VF", b"WS-VS",
+	b"YE-AB", b"YE-AD", b"YE-AM", b"YE-BA", b"YE-DA", b"YE-DH", b"YE-HD", b"YE-HJ", b"YE-HU",
+	b"YE-IB", b"YE-JA", b"YE-LA", b"YE-MA", b"YE-MR", b"YE-MW", b"YE-RA", b"YE-SA", b"YE-SD",
+	b"YE-SH", b"YE-SN", b"YE-SU", b"YE-TA", b"ZA-EC", b"ZA-FS", b"ZA-GP", b"ZA-KZN", b"ZA-LP",
+	b"ZA-MP", b"ZA-NC", b"ZA-NW", b"ZA-WC", b"ZM-01", b"ZM-02", b"ZM-03", b"ZM-04", b"ZM-05",
+	b"ZM-06", b"ZM-07", b"ZM-08", b"ZM-09", b"ZM-10", b"ZW-BU", b"ZW-HA", b"ZW-MA", b"ZW-MC",
+	b"ZW-ME", b"ZW-MI", b"ZW-MN", b"ZW-MS", b"ZW-MV", b"ZW-MW",
+];
@@ -1,27 +1,79 @@
 #![cfg_attr(not(feature = "std"), no_std)]
 
 use frame_support::{
-	codec::{Decode, Encode},
+	codec::{Decode, Encode, MaxEncodedLen},
 	scale_info::TypeInfo,
 	sp_runtime::RuntimeDebug,
-	sp_std::{convert::TryInto, vec::Vec},
+	sp_std::{convert::TryFrom, vec::Vec},
+	traits::ConstU32,
+	BoundedVec,
 };
+#[cfg(feature = "std")]
+use serde::{Deserialize, Serialize};
+
+mod iso3166;
+
+use iso3166::{COUNTRY_CODES, SUBDIVISION_CODES};
+
+/// Longest ISO 3166-2 subdivision code, without the country prefix.
+pub const MAX_REGION_CODE_LENGTH: usize = 3;
+
+/// Longest accepted city code.
+pub const MAX_CITY_CODE_LENGTH: usize = 64;
+
+/// Longest stored region or city code. Codes built through `TryFrom` are never longer, the bound
+/// leaves room for codes stored before they were validated.
+pub const MAX_AREA_CODE_LENGTH: u32 = 64;
+
+/// Longest stored country-region code, the country code and a dash in front of a region code.
+pub const MAX_COUNTRY_REGION_CODE_LENGTH: u32 = MAX_AREA_CODE_LENGTH + 3;
+
+type AreaCodeBytes = BoundedVec<u8, ConstU32<MAX_AREA_CODE_LENGTH>>;
 
 pub trait AreaCode {
 	fn to_vec(self) -> Vec<u8>;
 }
 
+#[derive(Encode, Decode, Clone, Copy, RuntimeDebug, PartialEq, Eq, TypeInfo)]
+pub enum AreaCodeError {
+	/// Not an ISO 3166-1 alpha-2 country code
+	InvalidCountryCode,
+	/// Not an ISO 3166-2 subdivision of the country
+	InvalidRegionCode,
+	/// Empty, too long or containing non printable characters
+	InvalidCityCode,
+}
+
 // CountryCode -> XX
-#[derive(Encode, Decode, Clone, Default, RuntimeDebug, PartialEq, Eq, TypeInfo)]
+#[derive(Encode, Decode, Clone, Default, RuntimeDebug, PartialEq, Eq, TypeInfo, MaxEncodedLen)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
 pub struct CountryCode([u8; 2]);
 
+impl TryFrom<Vec<u8>> for CountryCode {
+	type Error = AreaCodeError;
+
+	/// Upper cases the code and checks it against ISO 3166-1 alpha-2.
+	fn try_from(vec_id: Vec<u8>) -> Result<Self, Self::Error> {
+		let code = match vec_id.as_slice() {
+			[first, second] => [first.to_ascii_uppercase(), second.to_ascii_uppercase()],
+			_ => return Err(AreaCodeError::InvalidCountryCode),
+		};
+
+		if COUNTRY_CODES.binary_search(&code).is_err() {
+			return Err(AreaCodeError::InvalidCountryCode)
+		}
+
+		Ok(Self(code))
+	}
+}
+
 impl CountryCode {
-	pub fn from_vec(_vec_id: Vec<u8>) -> Self {
-		let _array = _vec_id.try_into().unwrap_or_else(|_vec_id: Vec<u8>| {
-			panic!("Expected a Vec of length {} but it was {}", 2, _vec_id.len())
-		});
+	/// Whether ISO 3166-2 lists any subdivision for the country, e.g. HK, MO and VA have none.
+	pub fn has_subdivisions(&self) -> bool {
+		let prefix = [self.0[0], self.0[1], b'-'];
+		let index = SUBDIVISION_CODES.partition_point(|code| *code < &prefix[..]);
 
-		Self(_array)
+		SUBDIVISION_CODES.get(index).map_or(false, |code| code.starts_with(&prefix))
 	}
 }
 
@@ -32,12 +84,66 @@ impl AreaCode for CountryCode {
 }
 
 // RegionCode -> YY
-#[derive(Encode, Decode, Clone, Default, RuntimeDebug, PartialEq, Eq, TypeInfo)]
-pub struct RegionCode(Vec<u8>);
+#[derive(Encode, Decode, Clone, Default, RuntimeDebug, PartialEq, Eq, TypeInfo, MaxEncodedLen)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub struct RegionCode(AreaCodeBytes);
 
 impl RegionCode {
-	pub fn from_vec(_vec_id: Vec<u8>) -> Self {
-		Self(_vec_id)
+	/// Wraps a code without validating it, truncated to `MAX_AREA_CODE_LENGTH`. Only meant for
+	/// codes stored before they were validated.
+	pub fn from_vec(vec_id: Vec<u8>) -> Self {
+		Self(BoundedVec::truncate_from(vec_id))
+	}
+
+	/// Checks a region against the country it belongs to. Countries without ISO 3166-2
+	/// subdivisions accept an empty region or the country code, both stored as the country code.
+	pub fn try_from_country(
+		country_code: &CountryCode,
+		vec_id: Vec<u8>,
+	) -> Result<Self, AreaCodeError> {
+		if vec_id.is_empty() && !country_code.has_subdivisions() {
+			return Ok(Self(BoundedVec::truncate_from(country_code.clone().to_vec())))
+		}
+
+		let region_code = Self::try_from(vec_id)?;
+
+		if !region_code.is_subdivision_of(country_code) {
+			return Err(AreaCodeError::InvalidRegionCode)
+		}
+
+		Ok(region_code)
+	}
+
+	/// Whether `XX-YY` is an ISO 3166-2 subdivision code. Countries without subdivisions only
+	/// have the country code itself as region.
+	pub fn is_subdivision_of(&self, country_code: &CountryCode) -> bool {
+		if !country_code.has_subdivisions() {
+			return self.0[..] == country_code.0[..]
+		}
+
+		let country_region_code =
+			CountryRegionCode::build_country_region_code(country_code, self).to_vec();
+
+		SUBDIVISION_CODES.binary_search(&country_region_code.as_slice()).is_ok()
+	}
+}
+
+impl TryFrom<Vec<u8>> for RegionCode {
+	type Error = AreaCodeError;
+
+	/// Upper cases the code and checks it has the shape of an ISO 3166-2 subdivision code.
+	/// Use [`RegionCode::is_subdivision_of`] to check it against a country.
+	fn try_from(mut vec_id: Vec<u8>) -> Result<Self, Self::Error> {
+		if vec_id.is_empty() ||
+			vec_id.len() > MAX_REGION_CODE_LENGTH ||
+			!vec_id.iter().all(|c| c.is_ascii_alphanumeric())
+		{
+			return Err(AreaCodeError::InvalidRegionCode)
+		}
+
+		vec_id.make_ascii_uppercase();
+
+		Ok(Self(BoundedVec::truncate_from(vec_id)))
 	}
 }
 
@@ -48,12 +154,33 @@ impl AreaCode for RegionCode {
 }
 
 // CityCode -> ZZZZ
-#[derive(Encode, Decode, Clone, Default, RuntimeDebug, PartialEq, Eq, TypeInfo)]
-pub struct CityCode(Vec<u8>); // city_code -> ZZZZ
+#[derive(Encode, Decode, Clone, Default, RuntimeDebug, PartialEq, Eq, TypeInfo, MaxEncodedLen)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub struct CityCode(AreaCodeBytes); // city_code -> ZZZZ
+
+impl TryFrom<Vec<u8>> for CityCode {
+	type Error = AreaCodeError;
+
+	/// Upper cases the code so the same city always maps to the same key.
+	fn try_from(mut vec_id: Vec<u8>) -> Result<Self, Self::Error> {
+		if vec_id.is_empty() ||
+			vec_id.len() > MAX_CITY_CODE_LENGTH ||
+			!vec_id.iter().all(|c| c.is_ascii_graphic() || *c == b' ')
+		{
+			return Err(AreaCodeError::InvalidCityCode)
+		}
+
+		vec_id.make_ascii_uppercase();
+
+		Ok(Self(BoundedVec::truncate_from(vec_id)))
+	}
+}
 
 impl CityCode {
-	pub fn from_vec(_vec_id: Vec<u8>) -> Self {
-		Self(_vec_id)
+	/// Wraps a code without validating it, truncated to `MAX_AREA_CODE_LENGTH`. Only meant for
+	/// codes stored before they were validated.
+	pub fn from_vec(vec_id: Vec<u8>) -> Self {
+		Self(BoundedVec::truncate_from(vec_id))
 	}
 }
 
@@ -64,12 +191,13 @@ impl AreaCode for CityCode {
 }
 
 // CountryCode-RegionCode -> XX-YY
-#[derive(Encode, Decode, Clone, Default, RuntimeDebug, PartialEq, Eq, TypeInfo)]
-pub struct CountryRegionCode(Vec<u8>);
+#[derive(Encode, Decode, Clone, Default, RuntimeDebug, PartialEq, Eq, TypeInfo, MaxEncodedLen)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub struct CountryRegionCode(BoundedVec<u8, ConstU32<MAX_COUNTRY_REGION_CODE_LENGTH>>);
 
 impl CountryRegionCode {
 	pub fn from_vec(_vec_id: Vec<u8>) -> Self {
-		Self(_vec_id)
+		Self(BoundedVec::truncate_from(_vec_id))
 	}
 
 	/// Concatenate CountryCode with RegionCode with a '-'
@@ -96,3 +224,49 @@ impl AreaCode for CountryRegionCode {
 		self.0.iter().map(|c| *c as u8).collect::<Vec<u8>>()
 	}
 }
+
+/// Validate and normalize codes which were not built through `TryFrom`, e.g. decoded from an
+/// extrinsic. The region must be an ISO 3166-2 subdivision of the country, see
+/// [`RegionCode::try_from_country`].
+pub fn normalize_area_code(
+	country_code: &CountryCode,
+	region_code: &RegionCode,
+	city_code: &CityCode,
+) -> Result<(CountryCode, RegionCode, CityCode), AreaCodeError> {
+	let country_code = CountryCode::try_from(country_code.clone().to_vec())?;
+	let region_code = RegionCode::try_from_country(&country_code, region_code.clone().to_vec())?;
+	let city_code = CityCode::try_from(city_code.clone().to_vec())?;
+
+	Ok((country_code, region_code, city_code))
+}
+
+/// Upper case codes stored before they were validated. Codes which fail [`normalize_area_code`]
+/// are kept, but the same area still maps to a single key.
+pub fn upper_case_area_code(
+	country_code: &CountryCode,
+	region_code: &RegionCode,
+	city_code: &CityCode,
+) -> (CountryCode, RegionCode, CityCode) {
+	(
+		CountryCode(country_code.0.map(|c| c.to_ascii_uppercase())),
+		RegionCode(BoundedVec::truncate_from(region_code.0.to_ascii_uppercase())),
+		CityCode(BoundedVec::truncate_from(city_code.0.to_ascii_uppercase())),
+	)
+}
+
+/// Maps [`AreaCodeError`] onto the `InvalidCountryCode`, `InvalidRegionCode` and
+/// `InvalidCityCode` variants of a pallet `Error<T>`, so `?` works on area code results.
+#[macro_export]
+macro_rules! impl_from_area_code_error {
+	($error:ident) => {
+		impl<T: Config> From<$crate::AreaCodeError> for $error<T> {
+			fn from(error: $crate::AreaCodeError) -> Self {
+				match error {
+					$crate::AreaCodeError::InvalidCountryCode => $error::<T>::InvalidCountryCode,
+					$crate::AreaCodeError::InvalidRegionCode => $error::<T>::InvalidRegionCode,
+					$crate::AreaCodeError::InvalidCityCode => $error::<T>::InvalidCityCode,
+				}
+			}
+		}
+	};
+}
@@ -1,12 +1,12 @@
 #![cfg_attr(not(feature = "std"), no_std)]
 
 use frame_support::{
-	codec::{Decode, Encode},
+	codec::{Decode, Encode, MaxEncodedLen},
 	RuntimeDebug,
 };
 use scale_info::TypeInfo;
 
-#[derive(Encode, Decode, Clone, RuntimeDebug, PartialEq, Eq, TypeInfo)]
+#[derive(Encode, Decode, Clone, RuntimeDebug, PartialEq, Eq, TypeInfo, MaxEncodedLen)]
 pub enum DurationType {
 	WorkingDays,
 	Hours,
@@ -18,13 +18,30 @@ impl Default for DurationType {
 	}
 }
 
-#[derive(Encode, Decode, Clone, Default, RuntimeDebug, PartialEq, Eq, TypeInfo)]
+#[derive(Encode, Decode, Clone, Default, RuntimeDebug, PartialEq, Eq, TypeInfo, MaxEncodedLen)]
 pub struct ExpectedDuration {
 	pub duration: u64,
 	pub duration_type: DurationType,
 }
+impl ExpectedDuration {
+	/// Converts the duration into milliseconds.
+	/// Working days are counted as calendar days with the weekends added back in.
+	pub fn as_millis(&self) -> u64 {
+		const HOUR_IN_MILLIS: u64 = 60 * 60 * 1000;
+		const DAY_IN_MILLIS: u64 = 24 * HOUR_IN_MILLIS;
 
-#[derive(Encode, Decode, Clone, RuntimeDebug, PartialEq, Eq, TypeInfo)]
+		match self.duration_type {
+			DurationType::Hours => self.duration.saturating_mul(HOUR_IN_MILLIS),
+			DurationType::Days => self.duration.saturating_mul(DAY_IN_MILLIS),
+			DurationType::WorkingDays => {
+				let weekends = self.duration.saturating_sub(1) / 5 * 2;
+				self.duration.saturating_add(weekends).saturating_mul(DAY_IN_MILLIS)
+			},
+		}
+	}
+}
+
+#[derive(Encode, Decode, Clone, RuntimeDebug, PartialEq, Eq, TypeInfo, MaxEncodedLen)]
 pub enum MenstrualSubscriptionDuration {
 	Monthly,
 	Quarterly,
@@ -35,3 +52,16 @@ impl Default for MenstrualSubscriptionDuration {
 		MenstrualSubscriptionDuration::Monthly
 	}
 }
+impl MenstrualSubscriptionDuration {
+	/// Converts the subscription period into milliseconds.
+	/// A month is counted as 30 days and a year as 365 days.
+	pub fn as_millis(&self) -> u64 {
+		const DAY_IN_MILLIS: u64 = 24 * 60 * 60 * 1000;
+
+		match self {
+			MenstrualSubscriptionDuration::Monthly => 30 * DAY_IN_MILLIS,
+			MenstrualSubscriptionDuration::Quarterly => 90 * DAY_IN_MILLIS,
+			MenstrualSubscriptionDuration::Yearly => 365 * DAY_IN_MILLIS,
+		}
+	}
+}
@@ -0,0 +1,249 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use frame_support::{
+	codec::{Decode, Encode, MaxEncodedLen},
+	scale_info::TypeInfo,
+	sp_runtime::RuntimeDebug,
+	sp_std::vec::Vec,
+};
+#[cfg(feature = "std")]
+use serde::{Deserialize, Serialize};
+
+/// Number of base32 characters of the geohash used to index locations.
+/// A precision 4 cell spans about 39km x 19.5km at the equator.
+pub const GEOHASH_PRECISION: usize = 4;
+
+/// Largest radius, in meters, accepted by radius searches.
+pub const MAX_SEARCH_RADIUS: u32 = 100_000;
+
+// Fixed point scale of a Coordinate, coordinates are stored in micro degrees
+const MICRO_DEGREES: i64 = 1_000_000;
+const MAX_LATITUDE: i64 = 90 * MICRO_DEGREES;
+const MAX_LONGITUDE: i64 = 180 * MICRO_DEGREES;
+
+// Length of one degree of latitude, in meters
+const METERS_PER_DEGREE: i64 = 111_195;
+
+const GEOHASH_BASE32: &[u8; 32] = b"0123456789bcdefghjkmnpqrstuvwxyz";
+
+// cos(degree) * 10_000 for every whole degree from 0 to 90
+const COSINE_TABLE: [i64; 91] = [
+	10000, 9998, 9994, 9986, 9976, 9962, 9945, 9925, 9903, 9877, 9848, 9816, 9781, 9744, 9703,
+	9659, 9613, 9563, 9511, 9455, 9397, 9336, 9272, 9205, 9135, 9063, 8988, 8910, 8829, 8746, 8660,
+	8572, 8480, 8387, 8290, 8192, 8090, 7986, 7880, 7771, 7660, 7547, 7431, 7314, 7193, 7071, 6947,
+	6820, 6691, 6561, 6428, 6293, 6157, 6018, 5878, 5736, 5592, 5446, 5299, 5150, 5000, 4848, 4695,
+	4540, 4384, 4226, 4067, 3907, 3746, 3584, 3420, 3256, 3090, 2924, 2756, 2588, 2419, 2250, 2079,
+	1908, 1736, 1564, 1392, 1219, 1045, 872, 698, 523, 349, 175, 0,
+];
+
+// Coordinate -> latitude and longitude in micro degrees
+// Only integer arithmetic is used so every node computes the same result
+#[derive(
+	Encode, Decode, Clone, Copy, Default, RuntimeDebug, PartialEq, Eq, TypeInfo, MaxEncodedLen,
+)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub struct Coordinate {
+	pub latitude: i32,
+	pub longitude: i32,
+}
+
+impl Coordinate {
+	pub fn new(latitude: i32, longitude: i32) -> Option<Self> {
+		if (latitude as i64).abs() > MAX_LATITUDE || (longitude as i64).abs() > MAX_LONGITUDE {
+			return None
+		}
+
+		Some(Self { latitude, longitude })
+	}
+
+	/// Parse decimal degrees such as `-6.175392` and `106.827153`.
+	/// Digits beyond the sixth decimal place are truncated.
+	pub fn from_bytes(latitude: &[u8], longitude: &[u8]) -> Option<Self> {
+		let latitude = parse_micro_degrees(latitude)?;
+		let longitude = parse_micro_degrees(longitude)?;
+
+		if latitude.abs() > MAX_LATITUDE || longitude.abs() > MAX_LONGITUDE {
+			return None
+		}
+
+		Some(Self { latitude: latitude as i32, longitude: longitude as i32 })
+	}
+
+	pub fn geohash(&self) -> GeoHash {
+		let (lat_bits, lon_bits) = geohash_bits();
+
+		GeoHash::from_cell(
+			cell_index(self.latitude as i64, MAX_LATITUDE, lat_bits),
+			cell_index(self.longitude as i64, MAX_LONGITUDE, lon_bits),
+		)
+	}
+
+	/// Whether `other` lies within `radius` meters of this coordinate, using an equirectangular
+	/// approximation which is accurate enough at city scale.
+	pub fn is_within(&self, other: &Coordinate, radius: u32) -> bool {
+		let mean_latitude = (self.latitude as i64 + other.latitude as i64) / 2;
+
+		let mut delta_longitude = (other.longitude as i64 - self.longitude as i64).abs();
+		if delta_longitude > MAX_LONGITUDE {
+			delta_longitude = 2 * MAX_LONGITUDE - delta_longitude;
+		}
+
+		let x = (delta_longitude * cosine(mean_latitude) / 10_000) as i128;
+		let y = (other.latitude as i64 - self.latitude as i64) as i128;
+		let radius = micro_degrees_from_meters(radius) as i128;
+
+		x * x + y * y <= radius * radius
+	}
+
+	/// Geohash cells which together cover every point within `radius` meters of this coordinate.
+	pub fn covering_geohashes(&self, radius: u32) -> Vec<GeoHash> {
+		let (lat_bits, lon_bits) = geohash_bits();
+		let lon_cells = 1i64 << lon_bits;
+
+		let delta_latitude = micro_degrees_from_meters(radius);
+		let lowest_latitude = (self.latitude as i64 - delta_latitude).max(-MAX_LATITUDE);
+		let highest_latitude = (self.latitude as i64 + delta_latitude).min(MAX_LATITUDE);
+
+		// Longitude degrees shrink towards the poles, widen the range with the smallest cosine
+		let widest_latitude = lowest_latitude.abs().max(highest_latitude.abs());
+		let cosine = cosine(widest_latitude);
+		let delta_longitude = if cosine == 0 {
+			MAX_LONGITUDE
+		} else {
+			(delta_latitude * 10_000 / cosine).min(MAX_LONGITUDE)
+		};
+
+		let lat_start = cell_index(lowest_latitude, MAX_LATITUDE, lat_bits);
+		let lat_end = cell_index(highest_latitude, MAX_LATITUDE, lat_bits);
+
+		// Longitude may wrap around the antimeridian, so the cell range is computed unclamped
+		let lon_start = ((self.longitude as i64 - delta_longitude + MAX_LONGITUDE) * lon_cells)
+			.div_euclid(2 * MAX_LONGITUDE);
+		let lon_end = ((self.longitude as i64 + delta_longitude + MAX_LONGITUDE) * lon_cells)
+			.div_euclid(2 * MAX_LONGITUDE);
+		let lon_count = (lon_end - lon_start + 1).min(lon_cells);
+
+		let mut geohashes = Vec::new();
+		for lat_index in lat_start..=lat_end {
+			for lon_offset in 0..lon_count {
+				let lon_index = (lon_start + lon_offset).rem_euclid(lon_cells);
+				geohashes.push(GeoHash::from_cell(lat_index as u32, lon_index as u32));
+			}
+		}
+
+		geohashes
+	}
+}
+
+// GeoHash -> base32 geohash of GEOHASH_PRECISION characters, e.g. "qqgu"
+#[derive(
+	Encode, Decode, Clone, Copy, Default, RuntimeDebug, PartialEq, Eq, TypeInfo, MaxEncodedLen,
+)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub struct GeoHash([u8; GEOHASH_PRECISION]);
+
+impl GeoHash {
+	fn from_cell(lat_index: u32, lon_index: u32) -> Self {
+		let (lat_bits, lon_bits) = geohash_bits();
+
+		// Geohash bits alternate between longitude and latitude, starting with longitude
+		let mut hash: u64 = 0;
+		let (mut lat_bit, mut lon_bit) = (lat_bits, lon_bits);
+		for position in 0..(lat_bits + lon_bits) {
+			let bit = if position % 2 == 0 {
+				lon_bit -= 1;
+				(lon_index >> lon_bit) & 1
+			} else {
+				lat_bit -= 1;
+				(lat_index >> lat_bit) & 1
+			};
+			hash = (hash << 1) | bit as u64;
+		}
+
+		let mut characters = [0u8; GEOHASH_PRECISION];
+		for (position, character) in characters.iter_mut().enumerate() {
+			let shift = 5 * (GEOHASH_PRECISION - 1 - position);
+			*character = GEOHASH_BASE32[((hash >> shift) & 0b11111) as usize];
+		}
+
+		Self(characters)
+	}
+
+	pub fn to_vec(self) -> Vec<u8> {
+		self.0.to_vec()
+	}
+}
+
+// Returns (latitude bits, longitude bits) of a geohash of GEOHASH_PRECISION characters
+fn geohash_bits() -> (u32, u32) {
+	let bits = 5 * GEOHASH_PRECISION as u32;
+
+	(bits / 2, bits - bits / 2)
+}
+
+// Index of the cell containing value when [-max, max] is split in 2^bits cells
+fn cell_index(value: i64, max: i64, bits: u32) -> u32 {
+	let cells = 1i64 << bits;
+	let index = (value + max) * cells / (2 * max);
+
+	index.clamp(0, cells - 1) as u32
+}
+
+fn micro_degrees_from_meters(meters: u32) -> i64 {
+	meters as i64 * MICRO_DEGREES / METERS_PER_DEGREE
+}
+
+// cos(latitude) * 10_000, linearly interpolated between whole degrees
+fn cosine(latitude: i64) -> i64 {
+	let latitude = latitude.abs().min(MAX_LATITUDE);
+	let degree = (latitude / MICRO_DEGREES) as usize;
+	let fraction = latitude % MICRO_DEGREES;
+
+	if degree >= 90 {
+		return 0
+	}
+
+	let (current, next) = (COSINE_TABLE[degree], COSINE_TABLE[degree + 1]);
+	current - (current - next) * fraction / MICRO_DEGREES
+}
+
+// Parse "[-+]DDD[.FFFFFF]" into micro degrees
+fn parse_micro_degrees(bytes: &[u8]) -> Option<i64> {
+	let (negative, digits) = match bytes.first() {
+		Some(b'-') => (true, &bytes[1..]),
+		Some(b'+') => (false, &bytes[1..]),
+		_ => (false, bytes),
+	};
+
+	let mut parts = digits.splitn(2, |c| *c == b'.');
+	let whole = parts.next().unwrap_or_default();
+	let fraction = parts.next().unwrap_or_default();
+
+	if whole.is_empty() || whole.len() > 3 {
+		return None
+	}
+
+	let mut value: i64 = 0;
+	for digit in whole {
+		if !digit.is_ascii_digit() {
+			return None
+		}
+		value = value * 10 + (digit - b'0') as i64;
+	}
+
+	let mut scale = MICRO_DEGREES;
+	let mut fraction_value: i64 = 0;
+	for digit in fraction {
+		if !digit.is_ascii_digit() {
+			return None
+		}
+		if scale > 1 {
+			scale /= 10;
+			fraction_value += (digit - b'0') as i64 * scale;
+		}
+	}
+
+	let micro_degrees = value * MICRO_DEGREES + fraction_value;
+
+	Some(if negative { -micro_degrees } else { micro_degrees })
+}
@@ -1,13 +1,13 @@
 #![cfg_attr(not(feature = "std"), no_std)]
 
 use frame_support::{
-	codec::{Decode, Encode},
+	codec::{Decode, Encode, MaxEncodedLen},
 	RuntimeDebug,
 };
 use scale_info::TypeInfo;
 
 // PaymentStatus
-#[derive(Encode, Decode, Clone, RuntimeDebug, PartialEq, Eq, TypeInfo)]
+#[derive(Encode, Decode, Clone, RuntimeDebug, PartialEq, Eq, TypeInfo, MaxEncodedLen)]
 pub enum PaymentStatus {
 	Unpaid,
 	Paid,
@@ -33,7 +33,7 @@ impl PaymentStatusTrait for PaymentStatus {
 }
 
 // MenstrualSubscriptionStatus
-#[derive(Encode, Decode, Clone, RuntimeDebug, PartialEq, Eq, TypeInfo)]
+#[derive(Encode, Decode, Clone, RuntimeDebug, PartialEq, Eq, TypeInfo, MaxEncodedLen)]
 pub enum MenstrualSubscriptionStatus {
 	Inactive,
 	Active,
@@ -0,0 +1,161 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use frame_support::{
+	codec::{Decode, Encode, MaxEncodedLen},
+	sp_runtime::{
+		traits::{AtLeast32BitUnsigned, Saturating, Zero},
+		Perbill,
+	},
+	traits::ConstU32,
+	BoundedVec, RuntimeDebug,
+};
+use primitives_price_and_currency::CurrencyType;
+use scale_info::TypeInfo;
+use sp_std::{vec, vec::Vec};
+
+/// Maximum number of per-currency overrides in a fee schedule
+pub const MAX_CURRENCY_FEE_RATES: u32 = 16;
+/// Maximum number of beneficiaries sharing the platform fee
+pub const MAX_FEE_BENEFICIARIES: u32 = 16;
+
+pub type CurrencyFeeRates<Balance> =
+	BoundedVec<CurrencyFeeRate<Balance>, ConstU32<MAX_CURRENCY_FEE_RATES>>;
+pub type FeeBeneficiaries<AccountId> =
+	BoundedVec<FeeBeneficiary<AccountId>, ConstU32<MAX_FEE_BENEFICIARIES>>;
+
+/// Fee charged by the platform on a fulfilled order.
+#[derive(Encode, Decode, Clone, Default, RuntimeDebug, PartialEq, Eq, TypeInfo, MaxEncodedLen)]
+pub struct FeeRate<Balance> {
+	pub percentage: Perbill,
+	pub flat_fee: Option<Balance>,
+}
+impl<Balance: AtLeast32BitUnsigned + Copy> FeeRate<Balance> {
+	/// Total fee for the given price, never more than the price itself.
+	pub fn fee_of(&self, total_price: Balance) -> Balance {
+		let flat_fee = self.flat_fee.unwrap_or_else(Zero::zero);
+		let fee = self.percentage.mul_floor(total_price).saturating_add(flat_fee);
+
+		if fee > total_price {
+			total_price
+		} else {
+			fee
+		}
+	}
+}
+
+#[derive(Encode, Decode, Clone, Default, RuntimeDebug, PartialEq, Eq, TypeInfo, MaxEncodedLen)]
+pub struct CurrencyFeeRate<Balance> {
+	pub currency: CurrencyType,
+	pub rate: FeeRate<Balance>,
+}
+
+/// Account receiving a share of the platform fee, e.g. a referral or the rewards pallet.
+#[derive(Encode, Decode, Clone, Default, RuntimeDebug, PartialEq, Eq, TypeInfo, MaxEncodedLen)]
+pub struct FeeBeneficiary<AccountId> {
+	pub account_id: AccountId,
+	pub share: Perbill,
+}
+
+#[derive(Encode, Decode, Clone, RuntimeDebug, PartialEq, Eq, TypeInfo, MaxEncodedLen)]
+pub struct FeeSchedule<AccountId, Balance> {
+	pub default_rate: FeeRate<Balance>,
+	pub currency_rates: CurrencyFeeRates<Balance>,
+	pub beneficiaries: FeeBeneficiaries<AccountId>,
+}
+impl<AccountId, Balance> Default for FeeSchedule<AccountId, Balance> {
+	fn default() -> Self {
+		// 5% to the treasury
+		Self {
+			default_rate: FeeRate { percentage: Perbill::from_percent(5), flat_fee: None },
+			currency_rates: BoundedVec::default(),
+			beneficiaries: BoundedVec::default(),
+		}
+	}
+}
+impl<AccountId, Balance> FeeSchedule<AccountId, Balance>
+where
+	AccountId: Clone,
+	Balance: AtLeast32BitUnsigned + Copy,
+{
+	/// A schedule is valid when the beneficiaries do not claim more than the whole fee
+	/// and each currency has at most one override.
+	pub fn is_valid(&self) -> bool {
+		let mut total_share = Perbill::zero();
+
+		for beneficiary in self.beneficiaries.iter() {
+			if beneficiary.share > Perbill::one().saturating_sub(total_share) {
+				return false
+			}
+			total_share = total_share.saturating_add(beneficiary.share);
+		}
+
+		for (index, currency_rate) in self.currency_rates.iter().enumerate() {
+			let duplicate = self.currency_rates[index + 1..]
+				.iter()
+				.any(|rate| rate.currency == currency_rate.currency);
+
+			if duplicate {
+				return false
+			}
+		}
+
+		true
+	}
+
+	pub fn rate_of(&self, currency: &CurrencyType) -> &FeeRate<Balance> {
+		self.currency_rates
+			.iter()
+			.find(|currency_rate| &currency_rate.currency == currency)
+			.map(|currency_rate| &currency_rate.rate)
+			.unwrap_or(&self.default_rate)
+	}
+
+	pub fn split(
+		&self,
+		currency: &CurrencyType,
+		total_price: Balance,
+	) -> FeeSplit<AccountId, Balance> {
+		let fee = self.rate_of(currency).fee_of(total_price);
+		let mut treasury_amount = fee;
+		let mut beneficiary_amounts = Vec::new();
+
+		for beneficiary in self.beneficiaries.iter() {
+			let amount = beneficiary.share.mul_floor(fee).min(treasury_amount);
+			treasury_amount -= amount;
+			beneficiary_amounts.push((beneficiary.account_id.clone(), amount));
+		}
+
+		FeeSplit { seller_amount: total_price - fee, treasury_amount, beneficiary_amounts }
+	}
+}
+
+/// How the total price of a fulfilled order is paid out.
+#[derive(Encode, Decode, Clone, Default, RuntimeDebug, PartialEq, Eq, TypeInfo)]
+pub struct FeeSplit<AccountId, Balance> {
+	pub seller_amount: Balance,
+	pub treasury_amount: Balance,
+	pub beneficiary_amounts: Vec<(AccountId, Balance)>,
+}
+impl<AccountId: Clone, Balance: Copy> FeeSplit<AccountId, Balance> {
+	/// Payouts of the split: the treasury first, then the beneficiaries, then the seller.
+	pub fn transfers(
+		&self,
+		treasury_key: &AccountId,
+		seller_id: &AccountId,
+	) -> Vec<(AccountId, Balance)> {
+		let mut transfers = vec![(treasury_key.clone(), self.treasury_amount)];
+		transfers.extend(self.beneficiary_amounts.iter().cloned());
+		transfers.push((seller_id.clone(), self.seller_amount));
+		transfers
+	}
+}
+
+/// Source of the platform fee schedule shared by the order pallets.
+pub trait FeeScheduleProvider<AccountId, Balance> {
+	fn fee_schedule() -> FeeSchedule<AccountId, Balance>;
+}
+impl<AccountId, Balance> FeeScheduleProvider<AccountId, Balance> for () {
+	fn fee_schedule() -> FeeSchedule<AccountId, Balance> {
+		FeeSchedule::default()
+	}
+}
@@ -1,13 +1,22 @@
 #![cfg_attr(not(feature = "std"), no_std)]
 
 use frame_support::{
-	codec::{Decode, Encode},
-	RuntimeDebug,
+	codec::{Decode, Encode, MaxEncodedLen},
+	traits::ConstU32,
+	BoundedVec, RuntimeDebug,
 };
 use scale_info::TypeInfo;
-use sp_std::vec::Vec;
+#[cfg(feature = "std")]
+use serde::{Deserialize, Serialize};
 
-#[derive(Encode, Decode, Clone, RuntimeDebug, PartialEq, Eq, TypeInfo)]
+/// Longest name of a price component.
+pub const MAX_PRICE_COMPONENT_LENGTH: u32 = 64;
+
+/// Most price components or additional prices in a single currency.
+pub const MAX_PRICE_COMPONENTS: u32 = 16;
+
+#[derive(Encode, Decode, Clone, RuntimeDebug, PartialEq, Eq, TypeInfo, MaxEncodedLen)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
 pub enum CurrencyType {
 	DAI,
 	ETH,
@@ -64,16 +73,21 @@ impl CurrencyType {
 	}
 }
 
-#[derive(Encode, Decode, Clone, Default, RuntimeDebug, PartialEq, Eq, TypeInfo)]
+pub type PriceComponent = BoundedVec<u8, ConstU32<MAX_PRICE_COMPONENT_LENGTH>>;
+pub type PriceComponents<Balance> = BoundedVec<Price<Balance>, ConstU32<MAX_PRICE_COMPONENTS>>;
+
+#[derive(Encode, Decode, Clone, Default, RuntimeDebug, PartialEq, Eq, TypeInfo, MaxEncodedLen)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
 pub struct Price<Balance> {
-	pub component: Vec<u8>,
+	pub component: PriceComponent,
 	pub value: Balance,
 }
 
-#[derive(Encode, Decode, Clone, Default, RuntimeDebug, PartialEq, Eq, TypeInfo)]
+#[derive(Encode, Decode, Clone, Default, RuntimeDebug, PartialEq, Eq, TypeInfo, MaxEncodedLen)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
 pub struct PriceByCurrency<Balance> {
 	pub currency: CurrencyType,
 	pub total_price: Balance,
-	pub price_components: Vec<Price<Balance>>,
-	pub additional_prices: Vec<Price<Balance>>,
+	pub price_components: PriceComponents<Balance>,
+	pub additional_prices: PriceComponents<Balance>,
 }
@@ -1,21 +1,94 @@
 #![cfg_attr(not(feature = "std"), no_std)]
 
 use frame_support::{
-	codec::{Decode, Encode},
+	codec::{Decode, Encode, MaxEncodedLen},
 	RuntimeDebug,
 };
 use scale_info::TypeInfo;
-use sp_std::{convert::TryInto, vec::Vec};
+use sp_std::{convert::TryFrom, vec::Vec};
 
-#[derive(Encode, Decode, Clone, Default, RuntimeDebug, PartialEq, Eq, TypeInfo)]
-pub struct TrackingId([u8; 21]);
+/// Number of characters of a tracking id, including the check character.
+pub const TRACKING_ID_LENGTH: usize = 21;
+
+#[derive(Encode, Decode, Clone, Copy, RuntimeDebug, PartialEq, Eq, TypeInfo)]
+pub enum TrackingIdError {
+	/// Tracking id is not TRACKING_ID_LENGTH characters long
+	InvalidLength,
+	/// Tracking id contains a character outside of tracking_id_generator::SAFE
+	InvalidCharacter,
+	/// Tracking id fails its check character and is not a known legacy tracking id
+	InvalidCheckCharacter,
+}
+
+#[derive(Encode, Decode, Clone, Default, RuntimeDebug, PartialEq, Eq, TypeInfo, MaxEncodedLen)]
+pub struct TrackingId([u8; TRACKING_ID_LENGTH]);
 impl TrackingId {
-	pub fn from_vec(_vec_id: Vec<u8>) -> Self {
-		let _array = _vec_id.try_into().unwrap_or_else(|_vec_id: Vec<u8>| {
-			panic!("Expected a Vec of length {} but it was {}", 21, _vec_id.len())
-		});
+	/// Whether the last character is the Luhn mod 36 check character of the others.
+	/// Tracking ids generated before the check character was introduced usually fail this.
+	pub fn has_valid_check_character(&self) -> bool {
+		has_valid_check_character(&self.0)
+	}
+}
+
+impl TryFrom<Vec<u8>> for TrackingId {
+	type Error = TrackingIdError;
+
+	fn try_from(vec_id: Vec<u8>) -> Result<Self, Self::Error> {
+		normalize_tracking_id(vec_id).map(Self)
+	}
+}
+
+impl From<TrackingId> for [u8; TRACKING_ID_LENGTH] {
+	fn from(tracking_id: TrackingId) -> Self {
+		tracking_id.0
+	}
+}
+
+/// Upper cases a hand typed tracking id and checks its length and characters.
+/// The check character is not verified so legacy tracking ids are still accepted.
+pub fn normalize_tracking_id(
+	mut vec_id: Vec<u8>,
+) -> Result<[u8; TRACKING_ID_LENGTH], TrackingIdError> {
+	vec_id.make_ascii_uppercase();
+
+	if vec_id.iter().any(|c| code_point(*c).is_none()) {
+		return Err(TrackingIdError::InvalidCharacter)
+	}
+
+	vec_id.try_into().map_err(|_| TrackingIdError::InvalidLength)
+}
+
+/// Whether the last character of `id` is the Luhn mod 36 check character of the others.
+pub fn has_valid_check_character(id: &[u8]) -> bool {
+	match id.split_last() {
+		Some((check_character, payload)) => Some(*check_character) == check_character_of(payload),
+		None => false,
+	}
+}
+
+/// Luhn mod 36 check character of `payload`, None if it contains a character outside of SAFE.
+pub fn check_character_of(payload: &[u8]) -> Option<u8> {
+	let base = tracking_id_generator::SAFE.len();
+
+	// Double every other code point, starting from the rightmost one
+	let mut sum = 0;
+	for (position, character) in payload.iter().rev().enumerate() {
+		let factor = if position % 2 == 0 { 2 } else { 1 };
+		let addend = code_point(*character)? * factor;
+		sum += addend / base + addend % base;
+	}
+
+	let check_code_point = (base - sum % base) % base;
+
+	Some(tracking_id_generator::SAFE[check_code_point] as u8)
+}
 
-		Self(_array)
+// Index of the character in SAFE
+fn code_point(character: u8) -> Option<usize> {
+	match character {
+		b'0'..=b'9' => Some((character - b'0') as usize),
+		b'A'..=b'Z' => Some((character - b'A') as usize + 10),
+		_ => None,
 	}
 }
 
@@ -30,28 +103,31 @@ pub mod tracking_id_generator {
 		'S', 'T', 'U', 'V', 'W', 'X', 'Y', 'Z',
 	];
 
+	/// Generate TRACKING_ID_LENGTH - 1 random characters followed by their check character
 	pub fn generate(seed: Vec<u8>) -> TrackingId {
 		let alphabet = &SAFE;
-		let size = 21;
+		let size = TRACKING_ID_LENGTH - 1;
 		let mask = alphabet.len().next_power_of_two() - 1;
 
 		// Assert that the masking does not truncate the alphabet. (See #9)
 		debug_assert!(alphabet.len() <= mask + 1);
 
-		let mut id = Vec::new();
+		let mut id = [0u8; TRACKING_ID_LENGTH];
+		let mut len = 0;
 
 		loop {
 			for &byte in &seed {
 				let byte = byte as usize & mask;
 
 				if alphabet.len() > byte {
-					id.push(alphabet[byte]);
+					id[len] = alphabet[byte] as u8;
+					len += 1;
 
-					if id.len() == size {
-						let _vec_id = id.iter().map(|c| *c as u8).collect::<Vec<_>>();
+					if len == size {
+						// Every character comes from SAFE, so the check character always exists
+						id[size] = check_character_of(&id[..size]).unwrap_or_default();
 
-						let _tracking_id = TrackingId::from_vec(_vec_id);
-						return _tracking_id
+						return TrackingId(id)
 					}
 				}
 			}
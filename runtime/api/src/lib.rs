@@ -0,0 +1,163 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+// Lints triggered by the code `decl_runtime_apis!` generates.
+#![allow(clippy::too_many_arguments, clippy::unnecessary_mut_passed)]
+
+use codec::Codec;
+use sp_std::vec::Vec;
+
+sp_api::decl_runtime_apis! {
+	/// Read-only queries over the orders pallet.
+	///
+	/// Listing calls filter by `status` first, then skip `offset` entries and return at most
+	/// `limit` of what remains, capped at 100. Entries come in the key order of the account
+	/// index: the same between calls, but not the order they were created in.
+	pub trait OrdersApi<AccountId, Hash, Order, OrderStatus>
+	where
+		AccountId: Codec,
+		Hash: Codec,
+		Order: Codec,
+		OrderStatus: Codec,
+	{
+		fn order_by_id(order_id: Hash) -> Option<Order>;
+		fn orders_by_customer_id(
+			customer_id: AccountId,
+			status: Option<OrderStatus>,
+			offset: u32,
+			limit: u32,
+		) -> Vec<Order>;
+		fn orders_by_seller_id(
+			seller_id: AccountId,
+			status: Option<OrderStatus>,
+			offset: u32,
+			limit: u32,
+		) -> Vec<Order>;
+	}
+
+	/// Read-only queries over the genetic testing pallet.
+	///
+	/// Listing calls paginate the same way as [`OrdersApi`]. The custody log is returned in full,
+	/// oldest entry first. `verify_test_result` tells whether a content hash matches the one the
+	/// lab attested for the test result. Tracking ids are upper cased before the lookup; a tracking
+	/// id with a bad character, or a bad check character and no legacy dna sample, is an error.
+	pub trait GeneticTestingApi<
+		AccountId,
+		Hash,
+		TrackingId,
+		DnaSample,
+		DnaSampleStatus,
+		DnaTestResult,
+		CustodyEntry,
+		TestResultAttestation,
+		TrackingIdError,
+	>
+	where
+		AccountId: Codec,
+		Hash: Codec,
+		TrackingId: Codec,
+		DnaSample: Codec,
+		DnaSampleStatus: Codec,
+		DnaTestResult: Codec,
+		CustodyEntry: Codec,
+		TestResultAttestation: Codec,
+		TrackingIdError: Codec,
+	{
+		fn dna_sample_by_tracking_id(
+			tracking_id: TrackingId,
+		) -> Result<Option<DnaSample>, TrackingIdError>;
+		fn dna_samples_by_owner_id(
+			owner_id: AccountId,
+			status: Option<DnaSampleStatus>,
+			offset: u32,
+			limit: u32,
+		) -> Vec<DnaSample>;
+		fn dna_samples_by_lab_id(
+			lab_id: AccountId,
+			status: Option<DnaSampleStatus>,
+			offset: u32,
+			limit: u32,
+		) -> Vec<DnaSample>;
+		fn dna_test_result_by_tracking_id(
+			tracking_id: TrackingId,
+		) -> Result<Option<DnaTestResult>, TrackingIdError>;
+		fn custody_log_by_tracking_id(
+			tracking_id: TrackingId,
+		) -> Result<Vec<CustodyEntry>, TrackingIdError>;
+		fn test_result_attestation_by_tracking_id(
+			tracking_id: TrackingId,
+		) -> Result<Option<TestResultAttestation>, TrackingIdError>;
+		fn verify_test_result(
+			tracking_id: TrackingId,
+			content_hash: Hash,
+		) -> Result<bool, TrackingIdError>;
+	}
+
+	/// Read-only queries over the service request pallet.
+	///
+	/// Listing calls paginate the same way as [`OrdersApi`].
+	pub trait ServiceRequestApi<AccountId, Hash, Request, RequestStatus>
+	where
+		AccountId: Codec,
+		Hash: Codec,
+		Request: Codec,
+		RequestStatus: Codec,
+	{
+		fn request_by_id(request_id: Hash) -> Option<Request>;
+		fn requests_by_requester_id(
+			requester_id: AccountId,
+			status: Option<RequestStatus>,
+			offset: u32,
+			limit: u32,
+		) -> Vec<Request>;
+		fn requests_by_lab_id(
+			lab_id: AccountId,
+			status: Option<RequestStatus>,
+			offset: u32,
+			limit: u32,
+		) -> Vec<Request>;
+	}
+	/// Read-only queries over the menstrual calendar pallet.
+	///
+	/// Predictions are computed from the logged menstruation days of the calendar with integer
+	/// arithmetic only, so every client receives the same result for the same data.
+	pub trait MenstrualCalendarApi<Hash, CyclePrediction>
+	where
+		Hash: Codec,
+		CyclePrediction: Codec,
+	{
+		fn predict_menstrual_cycle(menstrual_calendar_id: Hash) -> Option<CyclePrediction>;
+	}
+
+	/// Read-only queries over the reviews pallet.
+	///
+	/// Hidden reviews are left out of provider listings and ratings. A provider listing returns
+	/// at most 100 reviews per call, in the key order of the provider index.
+	pub trait ReviewsApi<AccountId, Hash, Review, ProviderRating>
+	where
+		AccountId: Codec,
+		Hash: Codec,
+		Review: Codec,
+		ProviderRating: Codec,
+	{
+		fn review_by_transaction_id(transaction_id: Hash) -> Option<Review>;
+		fn reviews_by_provider(provider: AccountId, offset: u32, limit: u32) -> Vec<Review>;
+		fn rating_by_provider(provider: AccountId) -> ProviderRating;
+	}
+	/// Read-only geographic queries over the labs and hospitals pallets.
+	///
+	/// Only labs and hospitals whose latitude and longitude parsed at registration are indexed.
+	/// The radius is in meters and capped at 100km. Labs are limited to verified ones, and to
+	/// those offering a service of `category` when it is given.
+	pub trait GeoSearchApi<Coordinate, Lab, Hospital>
+	where
+		Coordinate: Codec,
+		Lab: Codec,
+		Hospital: Codec,
+	{
+		fn labs_within_radius(
+			center: Coordinate,
+			radius: u32,
+			category: Option<Vec<u8>>,
+		) -> Vec<Lab>;
+		fn hospitals_within_radius(center: Coordinate, radius: u32) -> Vec<Hospital>;
+	}
+}
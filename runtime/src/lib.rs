@@ -36,7 +36,8 @@ use frame_support::{
 	dispatch::DispatchClass,
 	parameter_types,
 	traits::{
-		AsEnsureOriginWithArg, ConstU128, ConstU16, ConstU32, Everything, KeyOwnerProofSystem,
+		AsEnsureOriginWithArg, ConstBool, ConstU128, ConstU16, ConstU32, Everything,
+		KeyOwnerProofSystem,
 	},
 	weights::{
 		constants::{BlockExecutionWeight, ExtrinsicBaseWeight, RocksDbWeight, WEIGHT_PER_SECOND},
@@ -124,6 +125,32 @@ pub type CollectionId = u128;
 pub type ItemId = u128;
 pub type AssetId = u32;
 
+/// Order as returned by the orders runtime API.
+pub type Order = orders::Order<Hash, AccountId, Balance, Moment>;
+/// DNA sample as returned by the genetic testing runtime API.
+pub type DnaSample = genetic_testing::DnaSample<AccountId, Hash, Moment>;
+/// DNA test result as returned by the genetic testing runtime API.
+pub type DnaTestResult = genetic_testing::DnaTestResult<AccountId, Hash, Moment>;
+/// DNA sample custody log entry as returned by the genetic testing runtime API.
+pub type CustodyEntry = genetic_testing::CustodyEntry<AccountId, Hash, Moment>;
+/// Test result attestation as returned by the genetic testing runtime API.
+pub type TestResultAttestation = genetic_testing::TestResultAttestation<Hash, Signature, Moment>;
+/// Service request as returned by the service request runtime API.
+pub type Request = service_request::Request<AccountId, Balance, Hash>;
+/// Menstrual cycle prediction as returned by the menstrual calendar runtime API.
+pub type CyclePrediction = menstrual_calendar::CyclePrediction<Moment>;
+/// Review as returned by the reviews runtime API.
+pub type Review = reviews::Review<AccountId, Hash, Moment>;
+/// Lab as returned by the geo search runtime API.
+pub type Lab = labs::Lab<AccountId, Hash, Moment, Balance>;
+/// Hospital as returned by the geo search runtime API.
+pub type Hospital = hospitals::Hospital<AccountId, Hash>;
+pub use genetic_testing::{DnaSampleStatus, DnaSampleTrackingId, TrackingIdError};
+pub use orders::OrderStatus;
+pub use primitives_geo_location::Coordinate;
+pub use reviews::ProviderRating;
+pub use service_request::RequestStatus;
+
 pub struct OctopusAppCrypto;
 
 #[cfg(feature = "runtime-benchmarks")]
@@ -145,21 +172,23 @@ mod benches {
 		[rewards, Rewards]
 		[labs_benchmarking, LabsBench::<Runtime>]
 		[services_benchmarking, ServicesBench::<Runtime>]
-		[certifications_benchmarking, CertificationsBench::<Runtime>]
 		[orders_benchmarking, OrdersBench::<Runtime>]
-		[hospital_certifications_benchmarking, HospitalCertificationsBench::<Runtime>]
-		[doctor_certifications_benchmarking, DoctorCertificationsBench::<Runtime>]
 		[genetic_analysts_benchmarking, GeneticAnalystsBench::<Runtime>]
 		[genetic_analyst_services_benchmarking, GeneticAnalystServicesBench::<Runtime>]
-		[genetic_analyst_qualifications_benchmarking, GeneticAnalystQualificationsBench::<Runtime>]
 		[service_request_benchmarking, ServiceRequestBench::<Runtime>]
 		[genetic_testing_benchmarking, GeneticTestingBench::<Runtime>]
 		[genetic_analysis_orders_benchmarking, GeneticAnalysisOrdersBench::<Runtime>]
 		[genetic_analysis_benchmarking, GeneticAnalysisBench::<Runtime>]
 		[health_professional_benchmarking, HealthProfessionalBench::<Runtime>]
-		[health_professional_qualification_benchmarking, HealthProfessionalQualificationBench::<Runtime>]
 		[opinion_requestor_benchmarking, OpinionRequestorBench::<Runtime>]
 		[opinion_benchmarking, OpinionBench::<Runtime>]
+		[reviews_benchmarking, ReviewsBench::<Runtime>]
+		[credential_issuers, CredentialIssuers]
+		[credentials, Certifications]
+		[credentials, DoctorCertifications]
+		[credentials, HospitalCertifications]
+		[credentials, GeneticAnalystQualifications]
+		[credentials, HealthProfessionalQualification]
 	);
 }
 
@@ -219,10 +248,10 @@ pub const VERSION: RuntimeVersion = RuntimeVersion {
 	//   `spec_version`, and `authoring_version` are the same between Wasm and native.
 	// This value is set to 100 to notify Polkadot-JS App (https://polkadot.js.org/apps) to use
 	//   the compatible custom types.
-	spec_version: 2041,
+	spec_version: 2042,
 	impl_version: 1,
 	apis: RUNTIME_API_VERSIONS,
-	transaction_version: 2,
+	transaction_version: 3,
 	state_version: 1,
 };
 
@@ -731,6 +760,21 @@ parameter_types! {
 	pub const GeneticAnalysisOrdersEscrowPalletId: PalletId = PalletId(*b"dbio/esc");
 	pub const LabPalletId: PalletId = PalletId(*b"dbio/lab");
 	pub const OrderPalletId: PalletId = PalletId(*b"dbio/ord");
+	pub const OpinionPalletId: PalletId = PalletId(*b"dbio/opn");
+}
+
+parameter_types! {
+	// Seven days in milliseconds
+	pub const RefundGracePeriod: Moment = 7 * 24 * 60 * 60 * 1000;
+	pub const MaxExpiredRefundsPerBlock: u32 = 50;
+	pub const MaxBundleItems: u32 = 10;
+	// Three days in milliseconds
+	pub const DisputeWindow: Moment = 3 * 24 * 60 * 60 * 1000;
+	// Thirty days in milliseconds
+	pub const InstallmentInterval: Moment = 30 * 24 * 60 * 60 * 1000;
+	pub const MaxInstallments: u32 = 12;
+	pub const MaxOverduePlansPerBlock: u32 = 50;
+	pub const MaxCustodyNoteLength: u32 = 256;
 }
 
 parameter_types! {
@@ -749,6 +793,9 @@ impl labs::Config for Runtime {
 	type ProfileRoles = ProfileRoles;
 	type UserProfile = UserProfile;
 	type LabWeightInfo = ();
+	type SlashOrigin = EnsureRoot<AccountId>;
+	type RequireVerifiedCertification = ConstBool<false>;
+	type MaxLabsPerCity = ConstU32<1_000>;
 }
 
 impl rewards::Config for Runtime {
@@ -766,6 +813,7 @@ impl hospitals::Config for Runtime {
 	type ProfileRoles = ProfileRoles;
 	type UserProfile = UserProfile;
 	type WeightInfo = ();
+	type MaxHospitalsPerCity = ConstU32<1_000>;
 }
 
 impl doctors::Config for Runtime {
@@ -776,6 +824,7 @@ impl doctors::Config for Runtime {
 	type ProfileRoles = ProfileRoles;
 	type UserProfile = UserProfile;
 	type WeightInfo = ();
+	type MaxDoctorsPerCity = ConstU32<1_000>;
 }
 
 impl services::Config for Runtime {
@@ -804,6 +853,14 @@ impl orders::Config for Runtime {
 	type Assets = OctopusAssets;
 	type OrdersWeightInfo = ();
 	type PalletId = OrderPalletId;
+	type RefundGracePeriod = RefundGracePeriod;
+	type MaxExpiredRefundsPerBlock = MaxExpiredRefundsPerBlock;
+	type MaxBundleItems = MaxBundleItems;
+	type DisputeWindow = DisputeWindow;
+	type ArbiterOrigin = EnsureRoot<AccountId>;
+	type InstallmentInterval = InstallmentInterval;
+	type MaxInstallments = MaxInstallments;
+	type MaxOverduePlansPerBlock = MaxOverduePlansPerBlock;
 }
 
 impl genetic_testing::Config for Runtime {
@@ -811,18 +868,39 @@ impl genetic_testing::Config for Runtime {
 	type Orders = Orders;
 	type RandomnessSource = RandomnessCollectiveFlip;
 	type GeneticTestingWeightInfo = ();
+	type MaxCustodyNoteLength = MaxCustodyNoteLength;
+	type AttestationSignature = Signature;
+	type AttestationSigner = <Signature as Verify>::Signer;
+}
+
+parameter_types! {
+	pub const MaxCiphertextLength: u32 = 4096;
+	pub const MaxMenstrualCycleLogSyncChanges: u32 = 62;
+	pub const MaxEncryptedLogsPerCall: u32 = 31;
+	pub const MaxMenstrualCycleLogSyncDays: u32 = 93;
 }
 
 impl menstrual_calendar::Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
+	type MaxCiphertextLength = MaxCiphertextLength;
+	type MaxMenstrualCycleLogSyncChanges = MaxMenstrualCycleLogSyncChanges;
+	type MaxEncryptedLogsPerCall = MaxEncryptedLogsPerCall;
+	type MaxMenstrualCycleLogSyncDays = MaxMenstrualCycleLogSyncDays;
+	type MaxMenstrualCycleLogsPerCalendar = ConstU32<1_000>;
 	type MenstrualCalendarWeightInfo = ();
 }
 
+parameter_types! {
+	pub const MaxExpiredSubscriptionsPerBlock: u32 = 50;
+}
+
 impl menstrual_subscription::Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
 	type Currency = Balances;
 	type Assets = OctopusAssets;
 	type MenstrualSubscriptionWeightInfo = ();
+	type MaxExpiredSubscriptionsPerBlock = MaxExpiredSubscriptionsPerBlock;
+	type MaxMenstrualSubscriptionsPerOwner = ConstU32<1_000>;
 }
 
 impl user_profile::Config for Runtime {
@@ -832,34 +910,64 @@ impl user_profile::Config for Runtime {
 	type WeightInfo = ();
 }
 
+parameter_types! {
+	pub const MaxTitleLength: u32 = 256;
+	pub const MaxDescriptionLength: u32 = 1024;
+	pub const MaxRecordLinkLength: u32 = 512;
+	pub const MaxFilesPerRecord: u32 = 50;
+	pub const MaxRecordsPerOwner: u32 = 1_000;
+	pub const MaxPurposeLength: u32 = 256;
+	pub const MaxGranteesPerScope: u32 = 100;
+}
+
 impl electronic_medical_record::Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
 	type ElectronicMedicalRecord = ElectronicMedicalRecord;
 	type ElectronicMedicalRecordWeightInfo = ();
+	type MaxTitleLength = MaxTitleLength;
+	type MaxDescriptionLength = MaxDescriptionLength;
+	type MaxRecordLinkLength = MaxRecordLinkLength;
+	type MaxFilesPerRecord = MaxFilesPerRecord;
+	type MaxRecordsPerOwner = MaxRecordsPerOwner;
+	type MaxPurposeLength = MaxPurposeLength;
+	type MaxGranteesPerScope = MaxGranteesPerScope;
 }
 
-impl certifications::Config for Runtime {
+impl credentials::Config<credentials::Instance1> for Runtime {
 	type RuntimeEvent = RuntimeEvent;
-	type CertificationOwner = Labs;
+	type CredentialInfo = credentials::CertificationInfo;
+	type CredentialOwner = credentials::legacy::LegacyCertificationOwner<Labs>;
+	type CredentialIssuers = CredentialIssuers;
 	type WeightInfo = ();
+	#[cfg(feature = "runtime-benchmarks")]
+	type BenchmarkHelper = credentials_benchmarking::LabCredentialsBenchmarkHelper;
 }
 
-impl doctor_certifications::Config for Runtime {
+impl credentials::Config<credentials::Instance2> for Runtime {
 	type RuntimeEvent = RuntimeEvent;
-	type DoctorCertificationOwner = Doctors;
+	type CredentialInfo = credentials::CertificationInfo;
+	type CredentialOwner = credentials::legacy::LegacyDoctorCertificationOwner<Doctors>;
+	type CredentialIssuers = CredentialIssuers;
 	type WeightInfo = ();
+	#[cfg(feature = "runtime-benchmarks")]
+	type BenchmarkHelper = credentials_benchmarking::DoctorCredentialsBenchmarkHelper;
 }
 
-impl hospital_certifications::Config for Runtime {
+impl credentials::Config<credentials::Instance3> for Runtime {
 	type RuntimeEvent = RuntimeEvent;
-	type HospitalCertificationOwner = Hospitals;
+	type CredentialInfo = credentials::CertificationInfo;
+	type CredentialOwner = credentials::legacy::LegacyHospitalCertificationOwner<Hospitals>;
+	type CredentialIssuers = CredentialIssuers;
 	type WeightInfo = ();
+	#[cfg(feature = "runtime-benchmarks")]
+	type BenchmarkHelper = credentials_benchmarking::HospitalCredentialsBenchmarkHelper;
 }
 
 impl genetic_analysts::Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
 	type Currency = Balances;
 	type PalletId = GeneticAnalystPalletId;
+	type RequireVerifiedQualification = ConstBool<false>;
 	type GeneticAnalysisOrders = GeneticAnalysisOrders;
 	type GeneticAnalystServices = GeneticAnalystServices;
 	type GeneticAnalystQualifications = GeneticAnalystQualifications;
@@ -879,12 +987,18 @@ impl genetic_analyst_services::Config for Runtime {
 impl genetic_data::Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
 	type GeneticDataWeightInfo = ();
+	type MaxGeneticDataPerOwner = ConstU32<1_000>;
 }
 
-impl genetic_analyst_qualifications::Config for Runtime {
+impl credentials::Config<credentials::Instance4> for Runtime {
 	type RuntimeEvent = RuntimeEvent;
-	type GeneticAnalystQualificationOwner = GeneticAnalysts;
+	type CredentialInfo = credentials::GeneticAnalystQualificationInfo;
+	type CredentialOwner =
+		credentials::legacy::LegacyGeneticAnalystQualificationOwner<GeneticAnalysts>;
+	type CredentialIssuers = CredentialIssuers;
 	type WeightInfo = ();
+	#[cfg(feature = "runtime-benchmarks")]
+	type BenchmarkHelper = credentials_benchmarking::GeneticAnalystCredentialsBenchmarkHelper;
 }
 
 impl genetic_analysis::Config for Runtime {
@@ -903,6 +1017,7 @@ impl genetic_analysis_orders::Config for Runtime {
 	type GeneticAnalysis = GeneticAnalysis;
 	type GeneticAnalystServices = GeneticAnalystServices;
 	type GeneticAnalysisOrdersWeightInfo = ();
+	type PlatformFee = Orders;
 	type PalletId = GeneticAnalysisOrdersEscrowPalletId;
 }
 
@@ -913,16 +1028,215 @@ impl health_professional::Config for Runtime {
 	type HealthProfessionalWeightInfo = ();
 }
 
-impl health_professional_qualification::Config for Runtime {
+impl credentials::Config<credentials::Instance5> for Runtime {
 	type RuntimeEvent = RuntimeEvent;
-	type HealthProfessionalQualificationOwner = HealthProfessional;
+	type CredentialInfo = credentials::HealthProfessionalQualificationInfo;
+	type CredentialOwner =
+		credentials::legacy::LegacyHealthProfessionalQualificationOwner<HealthProfessional>;
+	type CredentialIssuers = CredentialIssuers;
 	type WeightInfo = ();
+	#[cfg(feature = "runtime-benchmarks")]
+	type BenchmarkHelper = credentials_benchmarking::HealthProfessionalCredentialsBenchmarkHelper;
+}
+
+/// Registers the owner pallet account each credentials instance needs before creating credentials.
+#[cfg(feature = "runtime-benchmarks")]
+mod credentials_benchmarking {
+	use super::*;
+	use primitives_area_code::{CityCode, CountryCode, RegionCode};
+
+	fn country() -> CountryCode {
+		CountryCode::try_from(b"ID".to_vec()).unwrap()
+	}
+
+	fn region() -> RegionCode {
+		RegionCode::try_from(b"JK".to_vec()).unwrap()
+	}
+
+	fn city() -> CityCode {
+		CityCode::try_from(b"Jakarta".to_vec()).unwrap()
+	}
+
+	pub struct LabCredentialsBenchmarkHelper;
+	impl credentials::BenchmarkHelper<AccountId, credentials::CertificationInfo>
+		for LabCredentialsBenchmarkHelper
+	{
+		fn register_owner(owner_id: &AccountId) {
+			let lab_info = labs::LabInfo {
+				box_public_key: Hash::repeat_byte(1),
+				name: b"DeBio Lab".to_vec().try_into().unwrap(),
+				email: b"DeBio Email".to_vec().try_into().unwrap(),
+				country: country(),
+				region: region(),
+				city: city(),
+				address: b"DeBio Address".to_vec().try_into().unwrap(),
+				phone_number: b"+6281394653625".to_vec().try_into().unwrap(),
+				website: b"DeBio Website".to_vec().try_into().unwrap(),
+				latitude: None,
+				longitude: None,
+				profile_image: None,
+			};
+			let _ = Labs::register_lab(RuntimeOrigin::signed(owner_id.clone()), lab_info);
+		}
+
+		fn credential_info() -> credentials::CertificationInfo {
+			Default::default()
+		}
+	}
+
+	pub struct DoctorCredentialsBenchmarkHelper;
+	impl credentials::BenchmarkHelper<AccountId, credentials::CertificationInfo>
+		for DoctorCredentialsBenchmarkHelper
+	{
+		fn register_owner(owner_id: &AccountId) {
+			let doctor_info = doctors::DoctorInfo {
+				name: b"DeBio Doctor".to_vec().try_into().unwrap(),
+				email: b"DeBio Email".to_vec().try_into().unwrap(),
+				country: country(),
+				region: region(),
+				city: city(),
+				address: b"DeBio Address".to_vec().try_into().unwrap(),
+				latitude: None,
+				longitude: None,
+				profile_image: None,
+			};
+			let _ = Doctors::register_doctor(RuntimeOrigin::signed(owner_id.clone()), doctor_info);
+		}
+
+		fn credential_info() -> credentials::CertificationInfo {
+			Default::default()
+		}
+	}
+
+	pub struct HospitalCredentialsBenchmarkHelper;
+	impl credentials::BenchmarkHelper<AccountId, credentials::CertificationInfo>
+		for HospitalCredentialsBenchmarkHelper
+	{
+		fn register_owner(owner_id: &AccountId) {
+			let hospital_info = hospitals::HospitalInfo {
+				name: b"DeBio Hospital".to_vec().try_into().unwrap(),
+				email: b"DeBio Email".to_vec().try_into().unwrap(),
+				country: country(),
+				region: region(),
+				city: city(),
+				address: b"DeBio Address".to_vec().try_into().unwrap(),
+				latitude: None,
+				longitude: None,
+				profile_image: None,
+			};
+			let _ = Hospitals::register_hospital(
+				RuntimeOrigin::signed(owner_id.clone()),
+				hospital_info,
+			);
+		}
+
+		fn credential_info() -> credentials::CertificationInfo {
+			Default::default()
+		}
+	}
+
+	pub struct GeneticAnalystCredentialsBenchmarkHelper;
+	impl credentials::BenchmarkHelper<AccountId, credentials::GeneticAnalystQualificationInfo>
+		for GeneticAnalystCredentialsBenchmarkHelper
+	{
+		fn register_owner(owner_id: &AccountId) {
+			let genetic_analyst_info = genetic_analysts::GeneticAnalystInfo {
+				box_public_key: Hash::repeat_byte(1),
+				first_name: b"First Name".to_vec().try_into().unwrap(),
+				last_name: b"Last Name".to_vec().try_into().unwrap(),
+				gender: b"Gender".to_vec().try_into().unwrap(),
+				date_of_birth: Moment::default(),
+				email: b"Email".to_vec().try_into().unwrap(),
+				phone_number: b"+6893026516".to_vec().try_into().unwrap(),
+				specialization: b"DeBio Genetic Analyst".to_vec().try_into().unwrap(),
+				profile_link: b"DeBio Genetic Analyst profile_link".to_vec().try_into().unwrap(),
+				profile_image: None,
+			};
+			let _ = GeneticAnalysts::register_genetic_analyst(
+				RuntimeOrigin::signed(owner_id.clone()),
+				genetic_analyst_info,
+			);
+		}
+
+		// Genetic analyst qualifications need at least one experience
+		fn credential_info() -> credentials::GeneticAnalystQualificationInfo {
+			credentials::GeneticAnalystQualificationInfo {
+				experience: vec![credentials::Experience {
+					title: b"DeBio title".to_vec().try_into().unwrap(),
+				}]
+				.try_into()
+				.unwrap(),
+				certification: None,
+			}
+		}
+	}
+
+	pub struct HealthProfessionalCredentialsBenchmarkHelper;
+	impl credentials::BenchmarkHelper<AccountId, credentials::HealthProfessionalQualificationInfo>
+		for HealthProfessionalCredentialsBenchmarkHelper
+	{
+		fn register_owner(owner_id: &AccountId) {
+			let health_professional_info = health_professional::HealthProfessionalInfo {
+				box_public_key: Hash::repeat_byte(1),
+				first_name: b"First Name".to_vec().try_into().unwrap(),
+				last_name: b"Last Name".to_vec().try_into().unwrap(),
+				myriad_username: b"debiouser".to_vec().try_into().unwrap(),
+				gender: b"Gender".to_vec().try_into().unwrap(),
+				date_of_birth: Moment::default(),
+				email: b"Email".to_vec().try_into().unwrap(),
+				phone_number: b"+6893026516".to_vec().try_into().unwrap(),
+				role: b"doctor".to_vec().try_into().unwrap(),
+				category: b"Mental Health".to_vec().try_into().unwrap(),
+				profile_link: None,
+				profile_image: None,
+				anonymous: false,
+			};
+			let _ = HealthProfessional::register(
+				RuntimeOrigin::signed(owner_id.clone()),
+				health_professional_info,
+			);
+		}
+
+		fn credential_info() -> credentials::HealthProfessionalQualificationInfo {
+			Default::default()
+		}
+	}
+
+	/// Countersigned credentials need to exist, so create a lab certification to verify.
+	pub struct CredentialIssuersBenchmarkHelper;
+	impl credential_issuers::BenchmarkHelper<Hash> for CredentialIssuersBenchmarkHelper {
+		fn credential_id() -> Hash {
+			let owner_id: AccountId = frame_benchmarking::account("lab", 0, 0);
+
+			<LabCredentialsBenchmarkHelper as credentials::BenchmarkHelper<
+				AccountId,
+				credentials::CertificationInfo,
+			>>::register_owner(&owner_id);
+
+			let credential =
+				<Certifications as credentials::CredentialInterface<Runtime>>::create_credential(
+					&owner_id,
+					&Default::default(),
+				)
+				.unwrap();
+
+			credential.id
+		}
+	}
 }
 
 impl opinion_requestor::Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
 	type ElectronicMedicalRecord = ElectronicMedicalRecord;
 	type OpinionRequestorWeightInfo = ();
+	type MaxInvitedProfessionals = ConstU32<50>;
+	type MaxRequestsPerOwner = ConstU32<1_000>;
+}
+
+parameter_types! {
+	pub const OpinionPlatformFee: Perbill = Perbill::from_percent(5);
+	// Fourteen days in milliseconds
+	pub const OpinionPaymentTimeout: Moment = 14 * 24 * 60 * 60 * 1000;
 }
 
 impl opinion::Config for Runtime {
@@ -930,7 +1244,36 @@ impl opinion::Config for Runtime {
 	type Currency = Balances;
 	type Assets = OctopusAssets;
 	type OpinionRequestor = OpinionRequestor;
+	type HealthProfessionals = HealthProfessional;
 	type OpinionWeightInfo = ();
+	type PalletId = OpinionPalletId;
+	type PlatformFee = OpinionPlatformFee;
+	type PaymentTimeout = OpinionPaymentTimeout;
+	type MaxOpinionsPerOwner = ConstU32<1_000>;
+}
+
+impl reviews::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type Orders = Orders;
+	type GeneticAnalysisOrders = GeneticAnalysisOrders;
+	type Opinions = Opinion;
+	type ModeratorOrigin = EnsureRoot<AccountId>;
+	type ReviewsWeightInfo = ();
+}
+
+impl credential_issuers::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type RegistrarOrigin = EnsureRoot<AccountId>;
+	type Credentials = (
+		Certifications,
+		DoctorCertifications,
+		HospitalCertifications,
+		GeneticAnalystQualifications,
+		HealthProfessionalQualification,
+	);
+	type CredentialIssuersWeightInfo = ();
+	#[cfg(feature = "runtime-benchmarks")]
+	type BenchmarkHelper = credentials_benchmarking::CredentialIssuersBenchmarkHelper;
 }
 
 // ------------------------------
@@ -957,6 +1300,33 @@ type Migrations = (
 	// This migration is used to set the interval value in the upward-messages. It should be
 	// deleted after the upgrade.
 	SetIntervalValueRuntimeUpgrade,
+	// These migrations move the legacy certification and qualification storage into the
+	// credentials pallet instances. They should be deleted after the upgrade.
+	credentials::migrations::MigrateFromLegacy<
+		Runtime,
+		credentials::Instance1,
+		credentials::migrations::LegacyCertifications,
+	>,
+	credentials::migrations::MigrateFromLegacy<
+		Runtime,
+		credentials::Instance2,
+		credentials::migrations::LegacyDoctorCertifications,
+	>,
+	credentials::migrations::MigrateFromLegacy<
+		Runtime,
+		credentials::Instance3,
+		credentials::migrations::LegacyHospitalCertifications,
+	>,
+	credentials::migrations::MigrateFromLegacy<
+		Runtime,
+		credentials::Instance4,
+		credentials::migrations::LegacyGeneticAnalystQualifications,
+	>,
+	credentials::migrations::MigrateFromLegacy<
+		Runtime,
+		credentials::Instance5,
+		credentials::migrations::LegacyHealthProfessionalQualifications,
+	>,
 );
 
 /// Please set the value of interval according to your own needs.
@@ -1009,24 +1379,28 @@ construct_runtime!(
 		MenstrualSubscription: menstrual_subscription,
 		UserProfile: user_profile,
 		ElectronicMedicalRecord: electronic_medical_record,
-		Certifications: certifications,
+		Certifications: credentials::<Instance1>,
 		Doctors: doctors,
-		DoctorCertifications: doctor_certifications,
+		DoctorCertifications: credentials::<Instance2>,
 		Hospitals: hospitals,
-		HospitalCertifications: hospital_certifications,
+		HospitalCertifications: credentials::<Instance3>,
 		GeneticData: genetic_data,
 		GeneticAnalysts: genetic_analysts,
 		GeneticAnalystServices: genetic_analyst_services,
-		GeneticAnalystQualifications: genetic_analyst_qualifications,
+		GeneticAnalystQualifications: credentials::<Instance4>,
 		GeneticAnalysis: genetic_analysis,
 		GeneticAnalysisOrders: genetic_analysis_orders,
 		HealthProfessional: health_professional,
-		HealthProfessionalQualification: health_professional_qualification,
+		HealthProfessionalQualification: credentials::<Instance5>,
 		OpinionRequestor: opinion_requestor,
 		Opinion: opinion,
 
 		// Myriad pallets
 		Tipping: pallet_tipping,
+
+		// New pallets are appended to keep the existing pallet indices
+		Reviews: reviews,
+		CredentialIssuers: credential_issuers,
 	}
 );
 
@@ -1190,6 +1564,161 @@ impl_runtime_apis! {
 		}
 	}
 
+	impl debio_runtime_api::OrdersApi<Block, AccountId, Hash, Order, OrderStatus> for Runtime {
+		fn order_by_id(order_id: Hash) -> Option<Order> {
+			Orders::order_by_id(order_id)
+		}
+
+		fn orders_by_customer_id(
+			customer_id: AccountId,
+			status: Option<OrderStatus>,
+			offset: u32,
+			limit: u32,
+		) -> Vec<Order> {
+			Orders::get_orders_by_customer_id(&customer_id, status, offset, limit)
+		}
+
+		fn orders_by_seller_id(
+			seller_id: AccountId,
+			status: Option<OrderStatus>,
+			offset: u32,
+			limit: u32,
+		) -> Vec<Order> {
+			Orders::get_orders_by_seller_id(&seller_id, status, offset, limit)
+		}
+	}
+
+	impl debio_runtime_api::GeneticTestingApi<
+		Block,
+		AccountId,
+		Hash,
+		DnaSampleTrackingId,
+		DnaSample,
+		DnaSampleStatus,
+		DnaTestResult,
+		CustodyEntry,
+		TestResultAttestation,
+		TrackingIdError,
+	> for Runtime {
+		fn dna_sample_by_tracking_id(
+			tracking_id: DnaSampleTrackingId,
+		) -> Result<Option<DnaSample>, TrackingIdError> {
+			GeneticTesting::normalize_tracking_id(tracking_id)
+				.map(GeneticTesting::dna_sample_by_tracking_id)
+		}
+
+		fn dna_samples_by_owner_id(
+			owner_id: AccountId,
+			status: Option<DnaSampleStatus>,
+			offset: u32,
+			limit: u32,
+		) -> Vec<DnaSample> {
+			GeneticTesting::get_dna_samples_by_owner_id(&owner_id, status, offset, limit)
+		}
+
+		fn dna_samples_by_lab_id(
+			lab_id: AccountId,
+			status: Option<DnaSampleStatus>,
+			offset: u32,
+			limit: u32,
+		) -> Vec<DnaSample> {
+			GeneticTesting::get_dna_samples_by_lab_id(&lab_id, status, offset, limit)
+		}
+
+		fn dna_test_result_by_tracking_id(
+			tracking_id: DnaSampleTrackingId,
+		) -> Result<Option<DnaTestResult>, TrackingIdError> {
+			GeneticTesting::normalize_tracking_id(tracking_id)
+				.map(GeneticTesting::dna_test_result_by_tracking_id)
+		}
+
+		fn custody_log_by_tracking_id(
+			tracking_id: DnaSampleTrackingId,
+		) -> Result<Vec<CustodyEntry>, TrackingIdError> {
+			GeneticTesting::normalize_tracking_id(tracking_id)
+				.map(|tracking_id| GeneticTesting::custody_log_by_tracking_id(tracking_id))
+		}
+
+		fn test_result_attestation_by_tracking_id(
+			tracking_id: DnaSampleTrackingId,
+		) -> Result<Option<TestResultAttestation>, TrackingIdError> {
+			GeneticTesting::normalize_tracking_id(tracking_id)
+				.map(GeneticTesting::test_result_attestation_by_tracking_id)
+		}
+
+		fn verify_test_result(
+			tracking_id: DnaSampleTrackingId,
+			content_hash: Hash,
+		) -> Result<bool, TrackingIdError> {
+			GeneticTesting::normalize_tracking_id(tracking_id)
+				.map(|tracking_id| GeneticTesting::verify_test_result(&tracking_id, &content_hash))
+		}
+	}
+
+	impl debio_runtime_api::ServiceRequestApi<
+		Block,
+		AccountId,
+		Hash,
+		Request,
+		RequestStatus,
+	> for Runtime {
+		fn request_by_id(request_id: Hash) -> Option<Request> {
+			ServiceRequest::request_by_id(request_id)
+		}
+
+		fn requests_by_requester_id(
+			requester_id: AccountId,
+			status: Option<RequestStatus>,
+			offset: u32,
+			limit: u32,
+		) -> Vec<Request> {
+			ServiceRequest::get_requests_by_requester_id(&requester_id, status, offset, limit)
+		}
+
+		fn requests_by_lab_id(
+			lab_id: AccountId,
+			status: Option<RequestStatus>,
+			offset: u32,
+			limit: u32,
+		) -> Vec<Request> {
+			ServiceRequest::get_requests_by_lab_id(&lab_id, status, offset, limit)
+		}
+	}
+
+	impl debio_runtime_api::MenstrualCalendarApi<Block, Hash, CyclePrediction> for Runtime {
+		fn predict_menstrual_cycle(menstrual_calendar_id: Hash) -> Option<CyclePrediction> {
+			MenstrualCalendar::predict_menstrual_cycle(&menstrual_calendar_id)
+		}
+	}
+
+	impl debio_runtime_api::ReviewsApi<Block, AccountId, Hash, Review, ProviderRating> for Runtime {
+		fn review_by_transaction_id(transaction_id: Hash) -> Option<Review> {
+			Reviews::review_by_transaction_id(transaction_id)
+		}
+
+		fn reviews_by_provider(provider: AccountId, offset: u32, limit: u32) -> Vec<Review> {
+			Reviews::get_reviews_by_provider(&provider, offset, limit)
+		}
+
+		fn rating_by_provider(provider: AccountId) -> ProviderRating {
+			Reviews::rating_by_provider(provider)
+		}
+	}
+
+	impl debio_runtime_api::GeoSearchApi<Block, Coordinate, Lab, Hospital> for Runtime {
+		fn labs_within_radius(
+			center: Coordinate,
+			radius: u32,
+			category: Option<Vec<u8>>,
+		) -> Vec<Lab> {
+			Labs::get_labs_within_radius(&center, radius, category)
+		}
+
+		fn hospitals_within_radius(center: Coordinate, radius: u32) -> Vec<Hospital> {
+			Hospitals::get_hospitals_within_radius(&center, radius)
+		}
+	}
+
 	impl sp_mmr_primitives::MmrApi<Block, Hash> for Runtime {
 		fn generate_proof(leaf_index: u64) -> Result<(mmr::EncodableOpaqueLeaf, mmr::Proof<Hash>), mmr::Error> {
 			Mmr::generate_batch_proof(vec![leaf_index])